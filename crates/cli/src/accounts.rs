@@ -31,6 +31,9 @@ pub struct FieldData {
 #[template(path = "accounts_struct.askama", escape = "none", ext = ".askama")]
 pub struct AccountsStructTemplate<'a> {
     pub account: &'a AccountData,
+    /// Whether `Pubkey` and byte-array fields serialize as base58 and base64
+    /// strings, through `carbon_serde`.
+    pub carbon_serde: bool,
 }
 
 #[derive(Template)]
@@ -36,6 +36,10 @@ pub struct ParseOptions {
     #[arg(help = "Generate a directory or a crate.")]
     pub as_crate: bool,
 
+    #[arg(long = "raw-serde", default_value_t = false)]
+    #[arg(help = "Keep pubkeys and byte arrays as arrays of numbers in serialized output.")]
+    pub raw_serde: bool,
+
     #[arg(short, long = "standard", default_value = "anchor")]
     #[arg(help = "Specify the IDL standard to parse.")]
     pub standard: IdlStandard,
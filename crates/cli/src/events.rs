@@ -1,8 +1,9 @@
 use {
-    crate::{idl::Idl, legacy_idl::LegacyIdl, util::idl_type_to_rust_type},
+    crate::{idl::Idl, legacy_idl::LegacyIdl, types::derives_eq, util::idl_type_to_rust_type},
     askama::Template,
     heck::{ToSnakeCase, ToUpperCamelCase},
     sha2::{Digest, Sha256},
+    std::collections::HashSet,
 };
 
 #[allow(dead_code)]
@@ -15,6 +16,17 @@ pub struct EventData {
     pub requires_imports: bool,
 }
 
+impl EventData {
+    /// Returns whether the event can derive `Eq` and `Hash`, given the defined
+    /// types that cannot.
+    pub fn derives_eq(&self, types_without_eq: &HashSet<String>) -> bool {
+        derives_eq(
+            self.args.iter().map(|arg| arg.rust_type.as_str()),
+            types_without_eq,
+        )
+    }
+}
+
 #[allow(dead_code)]
 #[derive(Debug)]
 pub struct ArgumentData {
@@ -26,6 +38,11 @@ pub struct ArgumentData {
 #[template(path = "events_struct.askama", escape = "none", ext = ".askama")]
 pub struct EventsStructTemplate<'a> {
     pub event: &'a EventData,
+    /// Whether the event derives `Eq` and `Hash` besides `PartialEq`.
+    pub derives_eq: bool,
+    /// Whether `Pubkey` and byte-array fields serialize as base58 and base64
+    /// strings, through `carbon_serde`.
+    pub carbon_serde: bool,
 }
 
 pub fn legacy_process_events(idl: &LegacyIdl) -> Vec<EventData> {
@@ -8,9 +8,9 @@ use {
             },
             utils::{parse_event_hints, read_codama_idl},
         },
-        instructions::{InstructionsModTemplate, InstructionsStructTemplate},
-        types::TypeStructTemplate,
-        util::is_big_array,
+        instructions::{min_accounts, InstructionsModTemplate, InstructionsStructTemplate},
+        types::{types_without_eq, TypeStructTemplate},
+        util::{decoder_root_content, is_big_array},
     },
     anyhow::{bail, Result},
     askama::Template,
@@ -23,8 +23,9 @@ pub fn parse_codama(
     output: String,
     as_crate: bool,
     event_hints: Option<String>,
+    carbon_serde: bool,
 ) -> Result<()> {
-    let (accounts_data, instructions_data, types_data, events_data, program_name) =
+    let (accounts_data, instructions_data, types_data, events_data, program_name, program_id) =
         match read_codama_idl(&path) {
             Ok(idl) => {
                 let accounts_data = process_codama_accounts(&idl.program);
@@ -34,6 +35,7 @@ pub fn parse_codama(
                 let (types_data, events_data) =
                     process_codama_defined_types(&idl.program, &event_hints);
                 let program_name = idl.program.name;
+                let program_id = idl.program.public_key;
 
                 (
                     accounts_data,
@@ -41,6 +43,7 @@ pub fn parse_codama(
                     types_data,
                     events_data,
                     program_name,
+                    program_id,
                 )
             }
             Err(error) => {
@@ -52,6 +55,7 @@ pub fn parse_codama(
     let decoder_name_kebab = program_name.to_kebab_case();
     let program_struct_name = format!("{}Account", program_name.to_upper_camel_case());
     let program_instruction_enum = format!("{}Instruction", program_name.to_upper_camel_case());
+    let program_event_enum = format!("{}Event", program_name.to_upper_camel_case());
 
     let crate_dir = if output.ends_with("/") {
         if as_crate {
@@ -87,8 +91,14 @@ pub fn parse_codama(
     let types_dir = format!("{}/types", src_dir);
     fs::create_dir_all(&types_dir).expect("Failed to create types directory");
 
+    let types_without_eq = types_without_eq(&types_data);
+
     for type_data in &types_data {
-        let template = TypeStructTemplate { type_data };
+        let template = TypeStructTemplate {
+            type_data,
+            derives_eq: !types_without_eq.contains(&type_data.name),
+            carbon_serde,
+        };
         let rendered = template.render().unwrap();
         let filename = format!("{}/{}.rs", types_dir, type_data.name.to_snake_case());
         fs::write(&filename, rendered).expect("Failed to write type struct file");
@@ -117,7 +127,10 @@ pub fn parse_codama(
     fs::create_dir_all(&accounts_dir).expect("Failed to create accounts directory");
 
     for account in &accounts_data {
-        let template = AccountsStructTemplate { account };
+        let template = AccountsStructTemplate {
+            account,
+            carbon_serde,
+        };
         let rendered = template.render().unwrap();
         let filename = format!("{}/{}.rs", accounts_dir, account.module_name);
         fs::write(&filename, rendered).expect("Failed to write account struct file");
@@ -142,7 +155,12 @@ pub fn parse_codama(
     fs::create_dir_all(&instructions_dir).expect("Failed to create instructions directory");
 
     for instruction in &instructions_data {
-        let template = InstructionsStructTemplate { instruction };
+        let template = InstructionsStructTemplate {
+            instruction,
+            has_program_id: program_id.is_some(),
+            derives_eq: instruction.derives_eq(&types_without_eq),
+            carbon_serde,
+        };
         let rendered = template.render().unwrap();
         let filename = format!("{}/{}.rs", instructions_dir, instruction.module_name);
         fs::write(&filename, rendered).expect("Failed to write instruction struct file");
@@ -150,18 +168,34 @@ pub fn parse_codama(
     }
 
     for event in &events_data {
-        let template = EventsStructTemplate { event };
+        let template = EventsStructTemplate {
+            event,
+            derives_eq: event.derives_eq(&types_without_eq),
+            carbon_serde,
+        };
         let rendered = template.render().unwrap();
         let filename = format!("{}/{}.rs", instructions_dir, event.module_name);
         fs::write(&filename, rendered).expect("Failed to write event struct file");
         println!("Generated {}", filename);
     }
 
+    let events_derive_eq = events_data
+        .iter()
+        .all(|event| event.derives_eq(&types_without_eq));
     let instructions_mod_template = InstructionsModTemplate {
         instructions: &instructions_data,
         decoder_name: decoder_name.clone(),
+        program_name: program_name.clone(),
         program_instruction_enum: program_instruction_enum.clone(),
+        program_event_enum: program_event_enum.clone(),
         events: &events_data,
+        program_id: program_id.clone(),
+        min_accounts: min_accounts(&instructions_data, &events_data),
+        instructions_derive_eq: instructions_data
+            .iter()
+            .all(|instruction| instruction.derives_eq(&types_without_eq))
+            && events_derive_eq,
+        events_derive_eq,
     };
     let instructions_mod_rendered = instructions_mod_template.render().unwrap();
     let instructions_mod_filename = format!("{}/mod.rs", instructions_dir);
@@ -172,10 +206,7 @@ pub fn parse_codama(
     println!("Generated {}", instructions_mod_filename);
 
     if as_crate {
-        let lib_rs_content = format!(
-            "pub struct {decoder_name};\npub mod accounts;\npub mod instructions;\npub mod types;",
-            decoder_name = decoder_name
-        );
+        let lib_rs_content = decoder_root_content(&decoder_name, program_id.as_deref());
         let lib_rs_filename = format!("{}/lib.rs", src_dir);
         fs::write(&lib_rs_filename, lib_rs_content).expect("Failed to write lib.rs file");
         println!("Generated {}", lib_rs_filename);
@@ -211,10 +242,7 @@ serde = {{ workspace = true }}
             .expect("Failed to write Cargo.toml file");
         println!("Generated {}", cargo_toml_filename);
     } else {
-        let mod_rs_content = format!(
-            "pub struct {decoder_name};\npub mod accounts;\npub mod instructions;\npub mod types;",
-            decoder_name = decoder_name
-        );
+        let mod_rs_content = decoder_root_content(&decoder_name, program_id.as_deref());
         let mod_rs_filename = format!("{}/mod.rs", src_dir);
         fs::write(&mod_rs_filename, mod_rs_content).expect("Failed to write mod.rs file");
         println!("Generated {}", mod_rs_filename);
@@ -10,6 +10,8 @@ pub struct RootNode {
 #[serde(rename_all = "camelCase")]
 pub struct ProgramNode {
     pub name: String,
+    #[serde(default)]
+    pub public_key: Option<String>,
     pub accounts: Vec<AccountNode>,
     pub instructions: Vec<InstructionNode>,
     pub defined_types: Vec<DefinedTypeNode>,
@@ -5,12 +5,12 @@ use {
         },
         events::{legacy_process_events, process_events, EventsStructTemplate},
         instructions::{
-            legacy_process_instructions, process_instructions, InstructionsModTemplate,
-            InstructionsStructTemplate,
+            legacy_process_instructions, min_accounts, process_instructions,
+            InstructionsModTemplate, InstructionsStructTemplate,
         },
         project::{DataSourceData, DecoderData, MetricsData, ProjectTemplate},
-        types::{legacy_process_types, process_types, TypeStructTemplate},
-        util::{is_big_array, legacy_read_idl, read_idl},
+        types::{legacy_process_types, process_types, types_without_eq, TypeStructTemplate},
+        util::{decoder_root_content, is_big_array, legacy_read_idl, read_idl},
     },
     anyhow::{bail, Result},
     askama::Template,
@@ -21,8 +21,8 @@ use {
     },
 };
 
-pub fn parse(path: String, output: String, as_crate: bool) -> Result<()> {
-    let (accounts_data, instructions_data, types_data, events_data, program_name) =
+pub fn parse(path: String, output: String, as_crate: bool, carbon_serde: bool) -> Result<()> {
+    let (accounts_data, instructions_data, types_data, events_data, program_name, program_id) =
         match read_idl(&path) {
             Ok(idl) => {
                 let accounts_data = process_accounts(&idl);
@@ -30,6 +30,7 @@ pub fn parse(path: String, output: String, as_crate: bool) -> Result<()> {
                 let types_data = process_types(&idl);
                 let events_data = process_events(&idl);
                 let program_name = idl.metadata.name;
+                let program_id = Some(idl.address).filter(|address| !address.is_empty());
 
                 (
                     accounts_data,
@@ -37,6 +38,7 @@ pub fn parse(path: String, output: String, as_crate: bool) -> Result<()> {
                     types_data,
                     events_data,
                     program_name,
+                    program_id,
                 )
             }
             Err(_legacy_idl_err) => match legacy_read_idl(&path) {
@@ -46,6 +48,7 @@ pub fn parse(path: String, output: String, as_crate: bool) -> Result<()> {
                     let types_data = legacy_process_types(&idl);
                     let events_data = legacy_process_events(&idl);
                     let program_name = idl.name;
+                    let program_id = idl.metadata.and_then(|metadata| metadata.address);
 
                     (
                         accounts_data,
@@ -53,6 +56,7 @@ pub fn parse(path: String, output: String, as_crate: bool) -> Result<()> {
                         types_data,
                         events_data,
                         program_name,
+                        program_id,
                     )
                 }
                 Err(idl_err) => {
@@ -65,6 +69,7 @@ pub fn parse(path: String, output: String, as_crate: bool) -> Result<()> {
     let decoder_name_kebab = program_name.to_kebab_case();
     let program_struct_name = format!("{}Account", program_name.to_upper_camel_case());
     let program_instruction_enum = format!("{}Instruction", program_name.to_upper_camel_case());
+    let program_event_enum = format!("{}Event", program_name.to_upper_camel_case());
 
     let crate_dir = if output.ends_with("/") {
         if as_crate {
@@ -100,8 +105,14 @@ pub fn parse(path: String, output: String, as_crate: bool) -> Result<()> {
     let types_dir = format!("{}/types", src_dir);
     fs::create_dir_all(&types_dir).expect("Failed to create types directory");
 
+    let types_without_eq = types_without_eq(&types_data);
+
     for type_data in &types_data {
-        let template = TypeStructTemplate { type_data };
+        let template = TypeStructTemplate {
+            type_data,
+            derives_eq: !types_without_eq.contains(&type_data.name),
+            carbon_serde,
+        };
         let rendered = template.render().unwrap();
         let filename = format!("{}/{}.rs", types_dir, type_data.name.to_snake_case());
         fs::write(&filename, rendered).expect("Failed to write type struct file");
@@ -130,7 +141,10 @@ pub fn parse(path: String, output: String, as_crate: bool) -> Result<()> {
     fs::create_dir_all(&accounts_dir).expect("Failed to create accounts directory");
 
     for account in &accounts_data {
-        let template = AccountsStructTemplate { account };
+        let template = AccountsStructTemplate {
+            account,
+            carbon_serde,
+        };
         let rendered = template.render().unwrap();
         let filename = format!("{}/{}.rs", accounts_dir, account.module_name);
         fs::write(&filename, rendered).expect("Failed to write account struct file");
@@ -155,7 +169,12 @@ pub fn parse(path: String, output: String, as_crate: bool) -> Result<()> {
     fs::create_dir_all(&instructions_dir).expect("Failed to create instructions directory");
 
     for instruction in &instructions_data {
-        let template = InstructionsStructTemplate { instruction };
+        let template = InstructionsStructTemplate {
+            instruction,
+            has_program_id: program_id.is_some(),
+            derives_eq: instruction.derives_eq(&types_without_eq),
+            carbon_serde,
+        };
         let rendered = template.render().unwrap();
         let filename = format!("{}/{}.rs", instructions_dir, instruction.module_name);
         fs::write(&filename, rendered).expect("Failed to write instruction struct file");
@@ -163,18 +182,34 @@ pub fn parse(path: String, output: String, as_crate: bool) -> Result<()> {
     }
 
     for event in &events_data {
-        let template = EventsStructTemplate { event };
+        let template = EventsStructTemplate {
+            event,
+            derives_eq: event.derives_eq(&types_without_eq),
+            carbon_serde,
+        };
         let rendered = template.render().unwrap();
         let filename = format!("{}/{}.rs", instructions_dir, event.module_name);
         fs::write(&filename, rendered).expect("Failed to write event struct file");
         println!("Generated {}", filename);
     }
 
+    let events_derive_eq = events_data
+        .iter()
+        .all(|event| event.derives_eq(&types_without_eq));
     let instructions_mod_template = InstructionsModTemplate {
         instructions: &instructions_data,
         decoder_name: decoder_name.clone(),
+        program_name: program_name.clone(),
         program_instruction_enum: program_instruction_enum.clone(),
+        program_event_enum: program_event_enum.clone(),
         events: &events_data,
+        program_id: program_id.clone(),
+        min_accounts: min_accounts(&instructions_data, &events_data),
+        instructions_derive_eq: instructions_data
+            .iter()
+            .all(|instruction| instruction.derives_eq(&types_without_eq))
+            && events_derive_eq,
+        events_derive_eq,
     };
     let instructions_mod_rendered = instructions_mod_template.render().unwrap();
     let instructions_mod_filename = format!("{}/mod.rs", instructions_dir);
@@ -185,10 +220,7 @@ pub fn parse(path: String, output: String, as_crate: bool) -> Result<()> {
     println!("Generated {}", instructions_mod_filename);
 
     if as_crate {
-        let lib_rs_content = format!(
-            "pub struct {decoder_name};\npub mod accounts;\npub mod instructions;\npub mod types;",
-            decoder_name = decoder_name
-        );
+        let lib_rs_content = decoder_root_content(&decoder_name, program_id.as_deref());
         let lib_rs_filename = format!("{}/lib.rs", src_dir);
         fs::write(&lib_rs_filename, lib_rs_content).expect("Failed to write lib.rs file");
         println!("Generated {}", lib_rs_filename);
@@ -224,10 +256,7 @@ serde = {{ workspace = true }}
             .expect("Failed to write Cargo.toml file");
         println!("Generated {}", cargo_toml_filename);
     } else {
-        let mod_rs_content = format!(
-            "pub struct {decoder_name};\npub mod accounts;\npub mod instructions;\npub mod types;",
-            decoder_name = decoder_name
-        );
+        let mod_rs_content = decoder_root_content(&decoder_name, program_id.as_deref());
         let mod_rs_filename = format!("{}/mod.rs", src_dir);
         fs::write(&mod_rs_filename, mod_rs_content).expect("Failed to write mod.rs file");
         println!("Generated {}", mod_rs_filename);
@@ -3,11 +3,13 @@ use {
         events::EventData,
         idl::Idl,
         legacy_idl::{LegacyIdl, LegacyIdlInstructionDiscriminant},
+        types::derives_eq,
         util::idl_type_to_rust_type,
     },
     askama::Template,
     heck::{ToSnakeCase, ToUpperCamelCase},
     sha2::{Digest, Sha256},
+    std::collections::HashSet,
 };
 
 #[allow(dead_code)]
@@ -21,6 +23,21 @@ pub struct InstructionData {
     pub requires_imports: bool,
 }
 
+impl InstructionData {
+    pub fn has_optional_accounts(&self) -> bool {
+        self.accounts.iter().any(|account| account.is_optional)
+    }
+
+    /// Returns whether the instruction can derive `Eq` and `Hash`, given the
+    /// defined types that cannot.
+    pub fn derives_eq(&self, types_without_eq: &HashSet<String>) -> bool {
+        derives_eq(
+            self.args.iter().map(|arg| arg.rust_type.as_str()),
+            types_without_eq,
+        )
+    }
+}
+
 #[allow(dead_code)]
 #[derive(Debug)]
 pub struct ArgumentData {
@@ -41,6 +58,14 @@ pub struct AccountMetaData {
 #[template(path = "instructions_struct.askama", escape = "none", ext = ".askama")]
 pub struct InstructionsStructTemplate<'a> {
     pub instruction: &'a InstructionData,
+    /// Whether the decoder declares `PROGRAM_ID`, which Anchor clients pass in
+    /// place of optional accounts they leave out.
+    pub has_program_id: bool,
+    /// Whether the instruction derives `Eq` and `Hash` besides `PartialEq`.
+    pub derives_eq: bool,
+    /// Whether `Pubkey` and byte-array fields serialize as base58 and base64
+    /// strings, through `carbon_serde`.
+    pub carbon_serde: bool,
 }
 
 #[derive(Template)]
@@ -48,8 +73,40 @@ pub struct InstructionsStructTemplate<'a> {
 pub struct InstructionsModTemplate<'a> {
     pub instructions: &'a Vec<InstructionData>,
     pub decoder_name: String,
+    /// The name of the program in the IDL, which the decoder reports as its
+    /// `decoder_name`.
+    pub program_name: String,
     pub program_instruction_enum: String,
+    pub program_event_enum: String,
     pub events: &'a Vec<EventData>,
+    pub program_id: Option<String>,
+    pub min_accounts: usize,
+    /// Whether every instruction and event derives `Eq` and `Hash`, so that
+    /// the instruction enum can as well.
+    pub instructions_derive_eq: bool,
+    /// Whether every event derives `Eq` and `Hash`, so that the event enum can
+    /// as well.
+    pub events_derive_eq: bool,
+}
+
+/// Returns the fewest required accounts taken by any instruction of the
+/// program, which the generated decoder reports as `min_accounts`.
+///
+/// Events are decoded from the self-CPI instruction that emits them, which
+/// carries a single account, the event authority.
+pub fn min_accounts(instructions: &[InstructionData], events: &[EventData]) -> usize {
+    instructions
+        .iter()
+        .map(|instruction| {
+            instruction
+                .accounts
+                .iter()
+                .filter(|account| !account.is_optional)
+                .count()
+        })
+        .chain((!events.is_empty()).then_some(1))
+        .min()
+        .unwrap_or(0)
 }
 
 pub fn legacy_process_instructions(idl: &LegacyIdl) -> Vec<InstructionData> {
@@ -126,8 +183,7 @@ pub fn process_instructions(idl: &Idl) -> Vec<InstructionData> {
                 name: account.name.to_snake_case(),
                 is_mut: account.writable.unwrap_or(false),
                 is_signer: account.signer.unwrap_or(false),
-                // TODO: Check
-                is_optional: false,
+                is_optional: account.optional.unwrap_or(false),
             });
         }
 
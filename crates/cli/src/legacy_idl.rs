@@ -17,6 +17,14 @@ pub struct LegacyIdl {
     pub events: Vec<LegacyIdlEvent>,
     #[serde(default)]
     pub errors: Vec<LegacyIdlError>,
+    #[serde(default)]
+    pub metadata: Option<LegacyIdlMetadata>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LegacyIdlMetadata {
+    #[serde(default)]
+    pub address: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
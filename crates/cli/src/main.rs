@@ -49,8 +49,9 @@ fn process_prompts() -> InquireResult<()> {
                                 .with_validator(required!("Please type a path to output folder"))
                                 .prompt()?;
                             let as_crate = Confirm::new("Generate as crate?").prompt()?;
+                            let carbon_serde = confirm_carbon_serde()?;
 
-                            handlers::parse(path, output_dir, as_crate)
+                            handlers::parse(path, output_dir, as_crate, carbon_serde)
                                 .map_err(|e| InquireError::Custom(e.into()))?;
                         }
                         IdlStandard::Codama => {
@@ -64,8 +65,15 @@ fn process_prompts() -> InquireResult<()> {
                                 .with_validator(required!("Please type a path to output folder"))
                                 .prompt()?;
                             let as_crate = Confirm::new("Generate as crate?").prompt()?;
-                            handlers::parse_codama(path, output_dir, as_crate, Some(event_hints))
-                                .map_err(|e| InquireError::Custom(e.into()))?;
+                            let carbon_serde = confirm_carbon_serde()?;
+                            handlers::parse_codama(
+                                path,
+                                output_dir,
+                                as_crate,
+                                Some(event_hints),
+                                carbon_serde,
+                            )
+                            .map_err(|e| InquireError::Custom(e.into()))?;
                         }
                     }
                 }
@@ -76,9 +84,16 @@ fn process_prompts() -> InquireResult<()> {
                         .with_validator(required!("Please type a path to output folder"))
                         .prompt()?;
                     let as_crate = Confirm::new("Generate as crate?").prompt()?;
-
-                    handlers::process_pda_idl(program_address, &url, output_dir, as_crate)
-                        .map_err(|e| InquireError::Custom(e.into()))?;
+                    let carbon_serde = confirm_carbon_serde()?;
+
+                    handlers::process_pda_idl(
+                        program_address,
+                        &url,
+                        output_dir,
+                        as_crate,
+                        carbon_serde,
+                    )
+                    .map_err(|e| InquireError::Custom(e.into()))?;
                 }
                 _ => unreachable!(),
             }
@@ -176,6 +191,7 @@ fn process_cli_params(cli: Cli) -> InquireResult<()> {
                         options.output,
                         options.as_crate,
                         options.event_hints,
+                        !options.raw_serde,
                     )
                     .map_err(|e| InquireError::Custom(e.into()))?;
                 }
@@ -186,7 +202,7 @@ fn process_cli_params(cli: Cli) -> InquireResult<()> {
                                 .to_string(),
                         ));
                     }
-                    handlers::parse(path, options.output, options.as_crate)
+                    handlers::parse(path, options.output, options.as_crate, !options.raw_serde)
                         .map_err(|e| InquireError::Custom(e.into()))?;
                 }
             },
@@ -199,8 +215,14 @@ fn process_cli_params(cli: Cli) -> InquireResult<()> {
                             .to_string(),
                     ))?;
 
-                handlers::process_pda_idl(program_address, url, options.output, options.as_crate)
-                    .map_err(|e| InquireError::Custom(e.into()))?;
+                handlers::process_pda_idl(
+                    program_address,
+                    url,
+                    options.output,
+                    options.as_crate,
+                    !options.raw_serde,
+                )
+                .map_err(|e| InquireError::Custom(e.into()))?;
             }
         },
         Commands::Scaffold(options) => {
@@ -217,3 +239,9 @@ fn process_cli_params(cli: Cli) -> InquireResult<()> {
 
     Ok(())
 }
+
+fn confirm_carbon_serde() -> InquireResult<bool> {
+    Confirm::new("Serialize pubkeys and byte arrays as base58 and base64 strings?")
+        .with_default(true)
+        .prompt()
+}
@@ -6,6 +6,7 @@ use {
     },
     askama::Template,
     heck::ToSnakeCase,
+    std::collections::HashSet,
 };
 
 #[allow(dead_code)]
@@ -47,10 +48,79 @@ pub enum EnumVariantFields {
     Unnamed(Vec<String>),
 }
 
+impl TypeData {
+    /// Returns the Rust types of the fields of the struct or of all variants
+    /// of the enum.
+    pub fn field_types(&self) -> Vec<&str> {
+        match &self.kind {
+            TypeKind::Struct => self
+                .fields
+                .iter()
+                .map(|field| field.rust_type.as_str())
+                .collect(),
+            TypeKind::Enum(variants) => variants
+                .iter()
+                .flat_map(|variant| match &variant.fields {
+                    Some(EnumVariantFields::Named(fields)) => fields
+                        .iter()
+                        .map(|field| field.rust_type.as_str())
+                        .collect(),
+                    Some(EnumVariantFields::Unnamed(rust_types)) => {
+                        rust_types.iter().map(String::as_str).collect()
+                    }
+                    None => Vec::new(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Returns the names of the defined types that cannot derive `Eq` and `Hash`,
+/// because they hold a float or a `HashMap`, directly or through another
+/// defined type.
+pub fn types_without_eq(types_data: &[TypeData]) -> HashSet<String> {
+    let mut without_eq = HashSet::new();
+
+    loop {
+        let newly_found = types_data
+            .iter()
+            .filter(|type_data| !without_eq.contains(&type_data.name))
+            .filter(|type_data| !derives_eq(type_data.field_types(), &without_eq))
+            .map(|type_data| type_data.name.clone())
+            .collect::<Vec<_>>();
+
+        if newly_found.is_empty() {
+            return without_eq;
+        }
+        without_eq.extend(newly_found);
+    }
+}
+
+/// Returns whether a generated type whose fields have `rust_types` can derive
+/// `Eq` and `Hash`. Floats implement neither and `HashMap` does not implement
+/// `Hash`, so types holding them only derive `PartialEq`.
+pub fn derives_eq<'a>(
+    rust_types: impl IntoIterator<Item = &'a str>,
+    types_without_eq: &HashSet<String>,
+) -> bool {
+    rust_types.into_iter().all(|rust_type| {
+        rust_type
+            .split(|c: char| !c.is_alphanumeric() && c != '_')
+            .all(|name| {
+                !matches!(name, "f32" | "f64" | "HashMap") && !types_without_eq.contains(name)
+            })
+    })
+}
+
 #[derive(Template)]
 #[template(path = "types_struct.askama", escape = "none", ext = ".askama")]
 pub struct TypeStructTemplate<'a> {
     pub type_data: &'a TypeData,
+    /// Whether the type derives `Eq` and `Hash` besides `PartialEq`.
+    pub derives_eq: bool,
+    /// Whether `Pubkey` and byte-array fields serialize as base58 and base64
+    /// strings, through `carbon_serde`.
+    pub carbon_serde: bool,
 }
 
 pub fn legacy_process_types(idl: &LegacyIdl) -> Vec<TypeData> {
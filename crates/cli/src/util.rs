@@ -30,6 +30,28 @@ pub fn read_idl(idl_path: &str) -> Result<Idl> {
     }
 }
 
+/// Renders the root module of a decoder, declaring `PROGRAM_ID` when the IDL
+/// provides the program address.
+pub fn decoder_root_content(decoder_name: &str, program_id: Option<&str>) -> String {
+    let mut content = String::new();
+
+    if program_id.is_some() {
+        content.push_str("use solana_pubkey::Pubkey;\n\n");
+    }
+
+    content.push_str(&format!(
+        "pub struct {decoder_name};\npub mod accounts;\npub mod instructions;\npub mod types;"
+    ));
+
+    if let Some(program_id) = program_id {
+        content.push_str(&format!(
+            "\n\npub const PROGRAM_ID: Pubkey =\n    Pubkey::from_str_const(\"{program_id}\");\n"
+        ));
+    }
+
+    content
+}
+
 pub fn idl_type_to_rust_type(idl_type: &LegacyIdlType) -> (String, bool) {
     match idl_type {
         LegacyIdlType::Primitive(s) => match s.as_str() {
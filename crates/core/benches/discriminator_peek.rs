@@ -0,0 +1,76 @@
+//! Compares the time spent counting a million instructions by type with a full
+//! decode and with `CarbonDeserialize::matches_discriminator`.
+//!
+//! Run with `cargo bench -p carbon-core --bench discriminator_peek`.
+
+use {
+    carbon_core::{
+        deserialize::{CarbonDeserialize, CarbonSerialize},
+        CarbonDeserialize,
+    },
+    solana_pubkey::Pubkey,
+    std::{
+        hint::black_box,
+        time::{Duration, Instant},
+    },
+};
+
+const INSTRUCTIONS: usize = 1_000_000;
+
+/// An instruction with arguments typical of a swap.
+#[allow(dead_code)]
+#[derive(CarbonDeserialize, Debug)]
+#[carbon(discriminator = "0xf8c69e91e17587c8")]
+struct Swap {
+    amount_in: u64,
+    minimum_amount_out: u64,
+    route: Vec<Pubkey>,
+    memo: Option<String>,
+}
+
+/// An instruction of another type, which the router does not count.
+#[allow(dead_code)]
+#[derive(CarbonDeserialize, Debug)]
+#[carbon(discriminator = "0xafaf6d1f0d989bed")]
+struct Initialize {
+    amount: u64,
+}
+
+/// Counts the instructions classified as swaps by `is_swap` and returns the
+/// count and the elapsed time.
+fn run(instructions: &[Vec<u8>], is_swap: impl Fn(&[u8]) -> bool) -> (usize, Duration) {
+    let start = Instant::now();
+    let swaps = instructions
+        .iter()
+        .filter(|data| is_swap(black_box(data.as_slice())))
+        .count();
+
+    (swaps, start.elapsed())
+}
+
+fn main() {
+    let swap = Swap {
+        amount_in: 1_000_000,
+        minimum_amount_out: 990_000,
+        route: vec![Pubkey::new_unique(); 3],
+        memo: Some("carbon".to_string()),
+    }
+    .to_bytes();
+    let initialize = Initialize { amount: 5 }.to_bytes();
+    let instructions: Vec<Vec<u8>> = (0..INSTRUCTIONS)
+        .map(|index| {
+            if index % 2 == 0 {
+                swap.clone()
+            } else {
+                initialize.clone()
+            }
+        })
+        .collect();
+
+    let (decoded, decode_elapsed) = run(&instructions, |data| Swap::deserialize(data).is_some());
+    let (peeked, peek_elapsed) = run(&instructions, Swap::matches_discriminator);
+
+    assert_eq!(decoded, peeked);
+    println!("full decode: {INSTRUCTIONS} instructions, {decoded} swaps, {decode_elapsed:.2?}");
+    println!("discriminator peek: {INSTRUCTIONS} instructions, {peeked} swaps, {peek_elapsed:.2?}");
+}
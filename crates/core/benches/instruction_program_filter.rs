@@ -0,0 +1,166 @@
+//! Compares the decode attempts and time spent by 20 instruction pipes with
+//! and without `InstructionDecoder::program_ids`.
+//!
+//! Run with `cargo bench -p carbon-core --bench instruction_program_filter`.
+
+use {
+    async_trait::async_trait,
+    carbon_core::{
+        error::CarbonResult,
+        instruction::{
+            DecodedInstruction, InstructionDecoder, InstructionMetadata, InstructionPipe,
+            InstructionPipes, InstructionProcessorInputType, NestedInstruction, NestedInstructions,
+            DEFAULT_MAX_INSTRUCTION_DEPTH,
+        },
+        metrics::MetricsCollection,
+        processor::Processor,
+    },
+    solana_instruction::Instruction,
+    solana_pubkey::Pubkey,
+    std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        time::{Duration, Instant},
+    },
+};
+
+const DECODERS: usize = 20;
+const INSTRUCTIONS: usize = 10_000;
+
+/// Decodes instructions starting with its 8-byte discriminator, like the
+/// generated decoders do.
+struct BenchDecoder {
+    program_id: [Pubkey; 1],
+    discriminator: [u8; 8],
+    filter_by_program: bool,
+    attempts: Arc<AtomicUsize>,
+}
+
+impl<'a> InstructionDecoder<'a> for BenchDecoder {
+    type InstructionType = u64;
+
+    fn try_decode_instruction(
+        &self,
+        instruction: &'a Instruction,
+    ) -> CarbonResult<Option<DecodedInstruction<Self::InstructionType>>> {
+        self.attempts.fetch_add(1, Ordering::Relaxed);
+
+        if !instruction.data.starts_with(&self.discriminator) {
+            return Ok(None);
+        }
+
+        Ok(Some(DecodedInstruction {
+            program_id: instruction.program_id,
+            data: instruction.data.len() as u64,
+            accounts: instruction.accounts.clone(),
+            discriminator: self.discriminator.to_vec(),
+            raw_len: instruction.data.len(),
+            num_accounts: instruction.accounts.len(),
+            raw_data: instruction.data.clone(),
+        }))
+    }
+
+    fn program_ids(&self) -> &[Pubkey] {
+        if self.filter_by_program {
+            &self.program_id
+        } else {
+            &[]
+        }
+    }
+}
+
+struct NoopProcessor;
+
+#[async_trait]
+impl Processor for NoopProcessor {
+    type InputType = InstructionProcessorInputType<u64>;
+
+    async fn process(
+        &mut self,
+        _data: Self::InputType,
+        _metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        Ok(())
+    }
+}
+
+fn discriminator(decoder: usize) -> [u8; 8] {
+    [decoder as u8; 8]
+}
+
+/// Runs every instruction through every pipe and returns the number of decode
+/// attempts and the elapsed time.
+fn run(
+    program_ids: &[Pubkey],
+    instructions: &[NestedInstruction],
+    filter_by_program: bool,
+) -> (usize, Duration) {
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let mut pipes: Vec<InstructionPipe<u64>> = program_ids
+        .iter()
+        .enumerate()
+        .map(|(decoder, program_id)| InstructionPipe {
+            decoder: Arc::new(BenchDecoder {
+                program_id: [*program_id],
+                discriminator: discriminator(decoder),
+                filter_by_program,
+                attempts: attempts.clone(),
+            }),
+            processor: Box::new(NoopProcessor),
+            dead_letter_sink: None,
+            error_policy: None,
+            decoder_claims: Default::default(),
+            max_depth: DEFAULT_MAX_INSTRUCTION_DEPTH,
+            top_level_only: false,
+            decode_rates: Default::default(),
+        })
+        .collect();
+    let metrics = Arc::new(MetricsCollection::new(vec![]));
+
+    let start = Instant::now();
+    futures::executor::block_on(async {
+        for instruction in instructions {
+            for pipe in pipes.iter_mut() {
+                pipe.run(instruction, metrics.clone()).await.unwrap();
+            }
+        }
+    });
+
+    (attempts.load(Ordering::Relaxed), start.elapsed())
+}
+
+fn main() {
+    let program_ids: Vec<Pubkey> = (0..DECODERS).map(|_| Pubkey::new_unique()).collect();
+    let instructions: Vec<NestedInstruction> = (0..INSTRUCTIONS)
+        .map(|index| {
+            let decoder = index % DECODERS;
+            NestedInstruction {
+                metadata: InstructionMetadata {
+                    transaction_metadata: Default::default(),
+                    stack_height: 1,
+                    index: index as u32,
+                    accounts_resolved: true,
+                    compute_units: None,
+                    absolute_path: vec![],
+                    transaction_context: None,
+                },
+                instruction: Instruction {
+                    program_id: program_ids[decoder],
+                    accounts: vec![],
+                    data: [discriminator(decoder).to_vec(), vec![0; 32]].concat(),
+                },
+                inner_instructions: NestedInstructions::default(),
+            }
+        })
+        .collect();
+
+    for (label, filter_by_program) in [("without program_ids", false), ("with program_ids", true)] {
+        let (attempts, elapsed) = run(&program_ids, &instructions, filter_by_program);
+        println!(
+            "{label}: {DECODERS} decoders, {INSTRUCTIONS} instructions, {attempts} decode attempts, {:.2?}",
+            elapsed
+        );
+    }
+}
@@ -51,12 +51,12 @@
 //! - All components support asynchronous processing to enable concurrent data
 //!   handling in the pipeline.
 
+#[cfg(feature = "std")]
 use {
     crate::{error::CarbonResult, metrics::MetricsCollection, processor::Processor},
     async_trait::async_trait,
-    solana_pubkey::Pubkey,
-    std::sync::Arc,
 };
+use {alloc::sync::Arc, solana_pubkey::Pubkey};
 
 /// Holds metadata for an account update, including the slot and public key.
 ///
@@ -69,10 +69,16 @@ use {
 ///
 /// - `slot`: The Solana slot number where the account was updated.
 /// - `pubkey`: The public key of the account.
+/// - `is_snapshot`: Whether the account was loaded from a snapshot before the
+///   live updates, rather than delivered because it changed.
+/// - `source`: The name of the datasource the update came from, set by
+///   `MergedDatasource`, or `None`.
 #[derive(Debug, Clone)]
 pub struct AccountMetadata {
     pub slot: u64,
     pub pubkey: Pubkey,
+    pub is_snapshot: bool,
+    pub source: Option<Arc<str>>,
 }
 
 /// Represents the decoded data of a Solana account, including account-specific
@@ -126,6 +132,7 @@ pub trait AccountDecoder<'a> {
 /// The input type for the account processor.
 ///
 /// - `T`: The account type, as determined by the decoder.
+#[cfg(feature = "std")]
 pub type AccountProcessorInputType<T> = (AccountMetadata, DecodedAccount<T>);
 
 /// A processing pipe that decodes and processes Solana account updates.
@@ -146,8 +153,9 @@ pub type AccountProcessorInputType<T> = (AccountMetadata, DecodedAccount<T>);
 ///   structured form.
 /// - `processor`: A `Processor` that handles the processing logic for decoded
 ///   accounts.
+#[cfg(feature = "std")]
 pub struct AccountPipe<T: Send> {
-    pub decoder: Box<dyn for<'a> AccountDecoder<'a, AccountType = T> + Send + Sync + 'static>,
+    pub decoder: Arc<dyn for<'a> AccountDecoder<'a, AccountType = T> + Send + Sync + 'static>,
     pub processor: Box<dyn Processor<InputType = AccountProcessorInputType<T>> + Send + Sync>,
 }
 
@@ -178,6 +186,7 @@ pub struct AccountPipe<T: Send> {
 /// - `account_with_metadata`: A tuple containing account metadata and the
 ///   Solana account data.
 /// - `metrics`: A list of `Metrics` objects for recording and tracking metrics.
+#[cfg(feature = "std")]
 #[async_trait]
 pub trait AccountPipes: Send + Sync {
     async fn run(
@@ -185,10 +194,23 @@ pub trait AccountPipes: Send + Sync {
         account_with_metadata: (AccountMetadata, solana_account::Account),
         metrics: Arc<MetricsCollection>,
     ) -> CarbonResult<()>;
+
+    /// Shuts down the pipe's processor when the pipeline shuts down.
+    async fn shutdown(&mut self) -> CarbonResult<()> {
+        Ok(())
+    }
+
+    /// Creates another instance of the pipe for the pipeline to run
+    /// concurrently with this one, or `None` if the pipe must handle one
+    /// update at a time.
+    fn fork(&self) -> Option<Box<dyn AccountPipes>> {
+        None
+    }
 }
 
+#[cfg(feature = "std")]
 #[async_trait]
-impl<T: Send> AccountPipes for AccountPipe<T> {
+impl<T: Send + 'static> AccountPipes for AccountPipe<T> {
     async fn run(
         &mut self,
         account_with_metadata: (AccountMetadata, solana_account::Account),
@@ -206,4 +228,15 @@ impl<T: Send> AccountPipes for AccountPipe<T> {
         }
         Ok(())
     }
+
+    async fn shutdown(&mut self) -> CarbonResult<()> {
+        self.processor.shutdown().await
+    }
+
+    fn fork(&self) -> Option<Box<dyn AccountPipes>> {
+        Some(Box::new(AccountPipe {
+            decoder: self.decoder.clone(),
+            processor: self.processor.fork()?,
+        }))
+    }
 }
@@ -7,14 +7,27 @@
 //! `AccountDeletionPipes`, you can create custom behavior for managing
 //! the deletion of accounts and associated resources, integrating with metrics
 //! for monitoring.
+//!
+//! Not every datasource reports closed accounts as deletions; many deliver
+//! them as an ordinary account update with zero lamports, or with the System
+//! program as the new owner. An `AccountCloseDetector`, enabled with
+//! `PipelineBuilder::detect_account_closes`, recognises such updates so that
+//! the pipeline routes them to the account deletion pipes instead.
 
 use {
     crate::{
-        datasource::AccountDeletion, error::CarbonResult, metrics::MetricsCollection,
+        datasource::{AccountDeletion, AccountUpdate},
+        error::CarbonResult,
+        merge::LruSet,
+        metrics::MetricsCollection,
         processor::Processor,
     },
     async_trait::async_trait,
-    std::sync::Arc,
+    solana_pubkey::Pubkey,
+    std::{
+        collections::HashSet,
+        sync::{Arc, Mutex, PoisonError},
+    },
 };
 
 /// A processing pipe for handling account deletions.
@@ -149,6 +162,18 @@ pub trait AccountDeletionPipes: Send + Sync {
         account_deletion: AccountDeletion,
         metrics: Arc<MetricsCollection>,
     ) -> CarbonResult<()>;
+
+    /// Shuts down the pipe's processor when the pipeline shuts down.
+    async fn shutdown(&mut self) -> CarbonResult<()> {
+        Ok(())
+    }
+
+    /// Creates another instance of the pipe for the pipeline to run
+    /// concurrently with this one, or `None` if the pipe must handle one
+    /// update at a time.
+    fn fork(&self) -> Option<Box<dyn AccountDeletionPipes>> {
+        None
+    }
 }
 
 #[async_trait]
@@ -167,4 +192,147 @@ impl AccountDeletionPipes for AccountDeletionPipe {
 
         Ok(())
     }
+
+    async fn shutdown(&mut self) -> CarbonResult<()> {
+        self.processor.shutdown().await
+    }
+
+    fn fork(&self) -> Option<Box<dyn AccountDeletionPipes>> {
+        Some(Box::new(AccountDeletionPipe {
+            processor: self.processor.fork()?,
+        }))
+    }
+}
+
+/// Recognises account updates that report a closed account.
+///
+/// An update is a close if the account has zero lamports, or if it is owned by
+/// the System program while the detector last saw it owned by one of the
+/// tracked owners. The detector remembers the accounts it saw owned by a
+/// tracked owner until they are closed, up to a capacity beyond which the
+/// least recently updated account is forgotten.
+///
+/// The tracked accounts are only kept in memory. An account the detector does
+/// not remember, because it was forgotten or because it has not been updated
+/// since the pipeline started, is tracked again from its next update; until
+/// then, its reassignment to the System program is passed to the account pipes
+/// as an ordinary update. Closes to zero lamports are always detected.
+#[derive(Debug)]
+pub struct AccountCloseDetector {
+    tracked_owners: HashSet<Pubkey>,
+    tracked_accounts: Mutex<LruSet<Pubkey>>,
+}
+
+impl Default for AccountCloseDetector {
+    fn default() -> Self {
+        Self::new([])
+    }
+}
+
+impl AccountCloseDetector {
+    /// The number of accounts a detector created with `new` remembers. Each
+    /// of them takes up to around 150 bytes.
+    pub const DEFAULT_CAPACITY: usize = 1_000_000;
+
+    /// Creates a detector tracking the accounts owned by `tracked_owners`, up
+    /// to `DEFAULT_CAPACITY` of them.
+    pub fn new(tracked_owners: impl IntoIterator<Item = Pubkey>) -> Self {
+        Self::with_capacity(tracked_owners, Self::DEFAULT_CAPACITY)
+    }
+
+    /// Creates a detector tracking up to `capacity` accounts owned by
+    /// `tracked_owners`. The capacity is raised to 1 if zero.
+    pub fn with_capacity(
+        tracked_owners: impl IntoIterator<Item = Pubkey>,
+        capacity: usize,
+    ) -> Self {
+        Self {
+            tracked_owners: tracked_owners.into_iter().collect(),
+            tracked_accounts: Mutex::new(LruSet::new(capacity.max(1))),
+        }
+    }
+
+    /// Returns the deletion reported by `account_update`, if it closes the
+    /// account, and records the account if it is owned by a tracked owner.
+    pub fn detect(&self, account_update: &AccountUpdate) -> Option<AccountDeletion> {
+        let account = &account_update.account;
+        let mut tracked_accounts = self
+            .tracked_accounts
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        let was_tracked = tracked_accounts.remove(&account_update.pubkey);
+        let closed = account.lamports == 0
+            || (was_tracked && account.owner == solana_program::system_program::ID);
+
+        if closed {
+            return Some(AccountDeletion {
+                pubkey: account_update.pubkey,
+                slot: account_update.slot,
+                commitment: account_update.commitment,
+                source: account_update.source.clone(),
+            });
+        }
+
+        if self.tracked_owners.contains(&account.owner) {
+            tracked_accounts.insert(account_update.pubkey);
+        }
+
+        None
+    }
+
+    /// Forgets `pubkey`, after its deletion was reported by the datasource.
+    pub fn forget(&self, pubkey: &Pubkey) {
+        self.tracked_accounts
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(pubkey);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, solana_account::Account};
+
+    fn account_update(pubkey: Pubkey, owner: Pubkey) -> AccountUpdate {
+        AccountUpdate {
+            pubkey,
+            account: Account {
+                lamports: 1_000,
+                data: vec![],
+                owner,
+                executable: false,
+                rent_epoch: 0,
+            },
+            slot: 1,
+            commitment: None,
+            is_snapshot: false,
+            source: None,
+        }
+    }
+
+    #[test]
+    fn test_detector_forgets_least_recently_updated_accounts() {
+        // Arrange
+        let program = Pubkey::new_unique();
+        let system_program = solana_program::system_program::ID;
+        let detector = AccountCloseDetector::with_capacity([program], 2);
+        let [forgotten, updated, tracked] = [(); 3].map(|()| Pubkey::new_unique());
+
+        // Act
+        let tracked_updates = [forgotten, updated, tracked].map(|pubkey| {
+            detector
+                .detect(&account_update(pubkey, program))
+                .map(|deletion| deletion.pubkey)
+        });
+        let forgotten_close = detector.detect(&account_update(forgotten, system_program));
+        let updated_close = detector.detect(&account_update(updated, system_program));
+        let tracked_close = detector.detect(&account_update(tracked, system_program));
+
+        // Assert
+        assert_eq!(tracked_updates, [None, None, None]);
+        assert!(forgotten_close.is_none());
+        assert_eq!(updated_close.map(|deletion| deletion.pubkey), Some(updated));
+        assert_eq!(tracked_close.map(|deletion| deletion.pubkey), Some(tracked));
+    }
 }
@@ -0,0 +1,150 @@
+//! Persists the pipeline's progress so that a restarted indexer can resume
+//! from the last fully processed slot.
+//!
+//! The pipeline calls [`Checkpoint::save`] once every update of a slot has
+//! gone through all pipes, and consults [`Checkpoint::load`] on startup to tell
+//! the datasources where to begin (see [`Datasource::resume_from_slot`]).
+//!
+//! [`FileCheckpoint`] is the default implementation. It stores the slot as
+//! plain text and replaces the file atomically, so a crash during a save leaves
+//! either the previous or the new slot on disk, never a partial write.
+//!
+//! [`Datasource::resume_from_slot`]: crate::datasource::Datasource::resume_from_slot
+
+use {
+    crate::error::{CarbonResult, Error},
+    std::{
+        fs, io,
+        path::{Path, PathBuf},
+    },
+};
+
+/// Stores and retrieves the last slot the pipeline has fully processed.
+///
+/// Implementations must be cheap enough to be called from the pipeline's
+/// processing loop; use `PipelineBuilder::checkpoint_interval` to bound how
+/// often `save` is called.
+pub trait Checkpoint: Send + Sync {
+    /// Records `slot` as fully processed.
+    fn save(&self, slot: u64) -> CarbonResult<()>;
+
+    /// Returns the last saved slot, or `None` if nothing has been saved yet.
+    fn load(&self) -> Option<u64>;
+}
+
+/// A [`Checkpoint`] backed by a single file.
+///
+/// Saves write to `<path>.tmp` and rename it over `<path>`. A temporary file
+/// left behind by a crash is ignored by `load` and overwritten by the next
+/// `save`.
+#[derive(Debug, Clone)]
+pub struct FileCheckpoint {
+    path: PathBuf,
+}
+
+impl FileCheckpoint {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn temp_path(&self) -> PathBuf {
+        let mut temp_path = self.path.clone().into_os_string();
+        temp_path.push(".tmp");
+        temp_path.into()
+    }
+}
+
+impl Checkpoint for FileCheckpoint {
+    fn save(&self, slot: u64) -> CarbonResult<()> {
+        let temp_path = self.temp_path();
+
+        fs::write(&temp_path, slot.to_string())
+            .and_then(|_| fs::rename(&temp_path, &self.path))
+            .map_err(|err| {
+                Error::Checkpoint(format!(
+                    "failed to save checkpoint {}: {}",
+                    self.path.display(),
+                    err
+                ))
+            })
+    }
+
+    fn load(&self) -> Option<u64> {
+        let content = match fs::read_to_string(&self.path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return None,
+            Err(err) => {
+                log::error!("failed to read checkpoint {}: {}", self.path.display(), err);
+                return None;
+            }
+        };
+
+        match content.trim().parse() {
+            Ok(slot) => Some(slot),
+            Err(err) => {
+                log::error!("invalid checkpoint {}: {}", self.path.display(), err);
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checkpoint_path(name: &str) -> PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("carbon-checkpoint-{}-{}", name, std::process::id()));
+        fs::remove_file(&path).ok();
+        path
+    }
+
+    #[test]
+    fn test_file_checkpoint_roundtrip() {
+        let checkpoint = FileCheckpoint::new(checkpoint_path("roundtrip"));
+
+        assert_eq!(checkpoint.load(), None);
+
+        checkpoint.save(42).unwrap();
+        assert_eq!(checkpoint.load(), Some(42));
+
+        checkpoint.save(43).unwrap();
+        assert_eq!(checkpoint.load(), Some(43));
+        assert!(!checkpoint.temp_path().exists());
+
+        fs::remove_file(checkpoint.path()).ok();
+    }
+
+    #[test]
+    fn test_file_checkpoint_survives_crash_between_write_and_rename() {
+        let checkpoint = FileCheckpoint::new(checkpoint_path("crash"));
+        checkpoint.save(100).unwrap();
+
+        // A crash after writing the temporary file but before renaming it
+        // leaves a partial write next to the checkpoint.
+        fs::write(checkpoint.temp_path(), "10").unwrap();
+
+        assert_eq!(checkpoint.load(), Some(100));
+
+        checkpoint.save(101).unwrap();
+        assert_eq!(checkpoint.load(), Some(101));
+        assert!(!checkpoint.temp_path().exists());
+
+        fs::remove_file(checkpoint.path()).ok();
+    }
+
+    #[test]
+    fn test_file_checkpoint_ignores_invalid_content() {
+        let checkpoint = FileCheckpoint::new(checkpoint_path("invalid"));
+        fs::write(checkpoint.path(), "not a slot").unwrap();
+
+        assert_eq!(checkpoint.load(), None);
+
+        fs::remove_file(checkpoint.path()).ok();
+    }
+}
@@ -92,9 +92,9 @@ use {crate::instruction::DecodedInstruction, serde::Serialize};
 ///   determines how raw instruction data is transformed into a decoded form,
 ///   impacting subsequent processing within the pipeline.
 pub trait InstructionDecoderCollection:
-    Clone + std::fmt::Debug + Send + Sync + Eq + std::hash::Hash + Serialize + 'static
+    Clone + core::fmt::Debug + Send + Sync + Eq + core::hash::Hash + Serialize + 'static
 {
-    type InstructionType: Clone + std::fmt::Debug + PartialEq + Eq + Send + Sync + 'static;
+    type InstructionType: Clone + core::fmt::Debug + PartialEq + Eq + Send + Sync + 'static;
 
     fn parse_instruction(
         instruction: &solana_instruction::Instruction,
@@ -36,9 +36,10 @@
 use {
     crate::{error::CarbonResult, metrics::MetricsCollection},
     async_trait::async_trait,
+    serde::{Deserialize, Serialize},
     solana_account::Account,
     solana_pubkey::Pubkey,
-    solana_sdk::transaction::VersionedTransaction,
+    solana_sdk::{commitment_config::CommitmentLevel, transaction::VersionedTransaction},
     solana_signature::Signature,
     solana_transaction_status::TransactionStatusMeta,
     std::sync::Arc,
@@ -87,6 +88,17 @@ use {
 ///   asynchronous.
 /// - The `consume` method should handle errors and retries to ensure robust
 ///   update delivery.
+/// - Datasources that deliver updates before they are finalized should send an
+///   `Update::Rollback` when a fork they delivered updates for is abandoned.
+///   Datasources that only deliver finalized data never roll back.
+/// - Datasources that know the commitment level of their updates should set
+///   their `commitment` field, so that pipelines configured with
+///   `PipelineBuilder::commitment` can filter them. Untagged updates are never
+///   filtered.
+/// - A datasource signals the end of its stream by dropping the `sender` and
+///   every clone of it, for example once a bounded replay reached its end
+///   slot. The pipeline stops after every datasource has done so. Datasources
+///   that cannot seek to a start slot should skip earlier updates themselves.
 #[async_trait]
 pub trait Datasource: Send + Sync {
     async fn consume(
@@ -97,6 +109,14 @@ pub trait Datasource: Send + Sync {
     ) -> CarbonResult<()>;
 
     fn update_types(&self) -> Vec<UpdateType>;
+
+    /// Called by the pipeline before `consume` when a checkpoint has been
+    /// loaded, with the last slot that was fully processed.
+    ///
+    /// Datasources that can start from an arbitrary slot should begin right
+    /// after `slot`. The default implementation ignores the checkpoint and
+    /// starts wherever the datasource normally would.
+    fn resume_from_slot(&self, _slot: u64) {}
 }
 
 /// Represents a data update in the `carbon-core` pipeline, encompassing
@@ -106,11 +126,48 @@ pub trait Datasource: Send + Sync {
 /// - `Transaction`: Represents a transaction-related update, including
 ///   transaction metadata.
 /// - `AccountDeletion`: Represents an event where an account has been deleted.
+/// - `Rollback`: Represents a fork switch that orphans previously delivered
+///   updates. Only emitted by datasources delivering non-finalized data.
 #[derive(Debug, Clone)]
 pub enum Update {
     Account(AccountUpdate),
     Transaction(Box<TransactionUpdate>),
     AccountDeletion(AccountDeletion),
+    Rollback(Rollback),
+}
+
+impl Update {
+    /// Returns the slot in which the update was recorded.
+    pub fn slot(&self) -> u64 {
+        match self {
+            Update::Account(account_update) => account_update.slot,
+            Update::Transaction(transaction_update) => transaction_update.slot,
+            Update::AccountDeletion(account_deletion) => account_deletion.slot,
+            Update::Rollback(rollback) => rollback.from_slot,
+        }
+    }
+
+    /// Returns the commitment level the update was observed at, if the
+    /// datasource tagged it. Rollbacks carry no commitment level.
+    pub fn commitment(&self) -> Option<Commitment> {
+        match self {
+            Update::Account(account_update) => account_update.commitment,
+            Update::Transaction(transaction_update) => transaction_update.commitment,
+            Update::AccountDeletion(account_deletion) => account_deletion.commitment,
+            Update::Rollback(_) => None,
+        }
+    }
+
+    /// Returns the name of the datasource the update came from, if it was
+    /// tagged by a `MergedDatasource`. Rollbacks carry no source.
+    pub fn source(&self) -> Option<&Arc<str>> {
+        match self {
+            Update::Account(account_update) => account_update.source.as_ref(),
+            Update::Transaction(transaction_update) => transaction_update.source.as_ref(),
+            Update::AccountDeletion(account_deletion) => account_deletion.source.as_ref(),
+            Update::Rollback(_) => None,
+        }
+    }
 }
 
 /// Enumerates the types of updates a datasource can provide.
@@ -127,6 +184,27 @@ pub enum UpdateType {
     AccountDeletion,
 }
 
+/// The commitment level at which a datasource observed an update.
+///
+/// Levels are ordered from the least to the most final, so that
+/// `Commitment::Confirmed < Commitment::Finalized`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Commitment {
+    Processed,
+    Confirmed,
+    Finalized,
+}
+
+impl From<CommitmentLevel> for Commitment {
+    fn from(commitment_level: CommitmentLevel) -> Self {
+        match commitment_level {
+            CommitmentLevel::Processed => Commitment::Processed,
+            CommitmentLevel::Confirmed => Commitment::Confirmed,
+            CommitmentLevel::Finalized => Commitment::Finalized,
+        }
+    }
+}
+
 /// Represents an update to a Solana account, including its public key, data,
 /// and slot information.
 ///
@@ -137,11 +215,21 @@ pub enum UpdateType {
 /// - `pubkey`: The public key of the account being updated.
 /// - `account`: The new state of the account.
 /// - `slot`: The slot number in which this account update was recorded.
+/// - `commitment`: The commitment level the update was observed at, or `None`
+///   if the datasource does not tag its updates.
+/// - `is_snapshot`: Whether the update comes from a snapshot of the account
+///   state loaded before the live updates, rather than from a change of the
+///   account. See the `snapshot` module.
+/// - `source`: The name of the datasource the update came from, set by
+///   `MergedDatasource`, or `None`.
 #[derive(Debug, Clone)]
 pub struct AccountUpdate {
     pub pubkey: Pubkey,
     pub account: Account,
     pub slot: u64,
+    pub commitment: Option<Commitment>,
+    pub is_snapshot: bool,
+    pub source: Option<Arc<str>>,
 }
 
 /// Represents the deletion of a Solana account, containing the account's public
@@ -153,10 +241,28 @@ pub struct AccountUpdate {
 ///
 /// - `pubkey`: The public key of the deleted account.
 /// - `slot`: The slot number in which the account was deleted.
+/// - `commitment`: The commitment level the deletion was observed at, or `None`
+///   if the datasource does not tag its updates.
+/// - `source`: The name of the datasource the deletion came from, set by
+///   `MergedDatasource`, or `None`.
 #[derive(Debug, Clone)]
 pub struct AccountDeletion {
     pub pubkey: Pubkey,
     pub slot: u64,
+    pub commitment: Option<Commitment>,
+    pub source: Option<Arc<str>>,
+}
+
+/// Represents a rollback of the updates delivered for `from_slot` and every
+/// later slot, after the datasource observed that their fork was abandoned.
+///
+/// Datasources that only deliver finalized data never emit a rollback. See the
+/// `rollback` module for the full semantics.
+///
+/// - `from_slot`: The first slot whose updates are no longer valid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rollback {
+    pub from_slot: u64,
 }
 
 /// Represents a transaction update in the Solana network, including transaction
@@ -175,6 +281,10 @@ pub struct AccountDeletion {
 /// - `is_vote`: A boolean indicating whether the transaction is a vote.
 /// - `slot`: The slot number in which the transaction was recorded.
 /// - `block_time`: The Unix timestamp of when the transaction was processed.
+/// - `commitment`: The commitment level the transaction was observed at, or
+///   `None` if the datasource does not tag its updates.
+/// - `source`: The name of the datasource the transaction came from, set by
+///   `MergedDatasource`, or `None`.
 ///
 /// Note: The `block_time` field may not be returned in all scenarios.
 #[derive(Debug, Clone)]
@@ -185,4 +295,24 @@ pub struct TransactionUpdate {
     pub is_vote: bool,
     pub slot: u64,
     pub block_time: Option<i64>,
+    pub commitment: Option<Commitment>,
+    pub source: Option<Arc<str>>,
+}
+
+impl TransactionUpdate {
+    /// Returns the accounts the transaction may write to: the writable static
+    /// account keys, followed by the writable addresses loaded from address
+    /// lookup tables.
+    pub fn writable_accounts(&self) -> Vec<Pubkey> {
+        let message = &self.transaction.message;
+
+        message
+            .static_account_keys()
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| message.is_maybe_writable(*index, None))
+            .map(|(_, pubkey)| *pubkey)
+            .chain(self.meta.loaded_addresses.writable.iter().copied())
+            .collect()
+    }
 }
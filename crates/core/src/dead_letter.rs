@@ -0,0 +1,246 @@
+//! Keeps instructions that could not be decoded or processed, instead of
+//! failing the update they belong to.
+//!
+//! When a [`DeadLetterSink`] is registered with
+//! `PipelineBuilder::dead_letter_sink`, every instruction pipe hands the
+//! instructions it fails on to the sink and carries on with the rest of the
+//! transaction. Without a sink, such errors fail the update as before.
+//!
+//! An instruction is dead-lettered when:
+//! - its decoder recognizes it but fails to deserialize it, or
+//! - its processor returns an error. Processors wrapped in `processor::Retry`
+//!   only report an error once all attempts have failed.
+//!
+//! [`FileDeadLetterSink`] appends the records to a JSON Lines file, and
+//! [`NoopDeadLetterSink`] discards them, for pipelines that only need to skip
+//! failing instructions.
+
+use {
+    crate::{
+        error::{CarbonResult, Error},
+        instruction::InstructionMetadata,
+    },
+    async_trait::async_trait,
+    base64::{engine::general_purpose::STANDARD, Engine},
+    serde::{Deserialize, Serialize},
+    solana_instruction::Instruction,
+    std::{
+        fs::{File, OpenOptions},
+        io::Write,
+        path::{Path, PathBuf},
+        sync::Mutex,
+    },
+};
+
+/// Receives the instructions that failed to decode or process.
+#[async_trait]
+pub trait DeadLetterSink: Send + Sync {
+    /// Records that `instruction` failed with `error`.
+    ///
+    /// If the sink fails, the pipe reports the original error as if no sink
+    /// was registered, so that the instruction is not lost.
+    async fn send(
+        &self,
+        metadata: &InstructionMetadata,
+        instruction: &Instruction,
+        error: &Error,
+    ) -> CarbonResult<()>;
+}
+
+/// A [`DeadLetterSink`] that discards every record.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopDeadLetterSink;
+
+#[async_trait]
+impl DeadLetterSink for NoopDeadLetterSink {
+    async fn send(
+        &self,
+        _metadata: &InstructionMetadata,
+        _instruction: &Instruction,
+        _error: &Error,
+    ) -> CarbonResult<()> {
+        Ok(())
+    }
+}
+
+/// An instruction that failed to decode or process, as written by
+/// [`FileDeadLetterSink`].
+///
+/// Public keys and the signature are base58-encoded, and the instruction data
+/// is base64-encoded.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeadLetter {
+    pub signature: String,
+    pub slot: u64,
+    pub stack_height: u32,
+    pub index: u32,
+    pub program_id: String,
+    pub accounts: Vec<DeadLetterAccount>,
+    pub data: String,
+    pub error: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeadLetterAccount {
+    pub pubkey: String,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+impl DeadLetter {
+    pub fn new(metadata: &InstructionMetadata, instruction: &Instruction, error: &Error) -> Self {
+        Self {
+            signature: metadata.transaction_metadata.signature.to_string(),
+            slot: metadata.transaction_metadata.slot,
+            stack_height: metadata.stack_height,
+            index: metadata.index,
+            program_id: instruction.program_id.to_string(),
+            accounts: instruction
+                .accounts
+                .iter()
+                .map(|account| DeadLetterAccount {
+                    pubkey: account.pubkey.to_string(),
+                    is_signer: account.is_signer,
+                    is_writable: account.is_writable,
+                })
+                .collect(),
+            data: STANDARD.encode(&instruction.data),
+            error: error.to_string(),
+        }
+    }
+}
+
+/// A [`DeadLetterSink`] that appends one JSON-encoded [`DeadLetter`] per line
+/// to a file.
+///
+/// The file is created if it does not exist. Each record is written with a
+/// single `write` call, so a crash leaves at most the last line incomplete.
+#[derive(Debug)]
+pub struct FileDeadLetterSink {
+    path: PathBuf,
+    file: Mutex<File>,
+}
+
+impl FileDeadLetterSink {
+    pub fn new(path: impl Into<PathBuf>) -> CarbonResult<Self> {
+        let path = path.into();
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .map_err(|err| {
+                Error::Custom(format!(
+                    "failed to open dead letter file {}: {}",
+                    path.display(),
+                    err
+                ))
+            })?;
+
+        Ok(Self {
+            path,
+            file: Mutex::new(file),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+#[async_trait]
+impl DeadLetterSink for FileDeadLetterSink {
+    async fn send(
+        &self,
+        metadata: &InstructionMetadata,
+        instruction: &Instruction,
+        error: &Error,
+    ) -> CarbonResult<()> {
+        let dead_letter = DeadLetter::new(metadata, instruction, error);
+        let mut line = serde_json::to_vec(&dead_letter)
+            .map_err(|err| Error::Custom(format!("failed to encode dead letter: {}", err)))?;
+        line.push(b'\n');
+
+        self.file
+            .lock()
+            .map_err(|_| Error::Custom("dead letter file lock poisoned".to_string()))?
+            .write_all(&line)
+            .map_err(|err| {
+                Error::Custom(format!(
+                    "failed to write dead letter to {}: {}",
+                    self.path.display(),
+                    err
+                ))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*, crate::transaction::TransactionMetadata, solana_instruction::AccountMeta,
+        solana_pubkey::Pubkey, std::fs,
+    };
+
+    #[tokio::test]
+    async fn test_file_dead_letter_sink_appends_json_lines() {
+        let path =
+            std::env::temp_dir().join(format!("carbon-dead-letters-{}.jsonl", std::process::id()));
+        fs::remove_file(&path).ok();
+        let sink = FileDeadLetterSink::new(&path).unwrap();
+        let metadata = InstructionMetadata {
+            transaction_metadata: TransactionMetadata {
+                slot: 42,
+                ..Default::default()
+            },
+            stack_height: 2,
+            index: 3,
+            accounts_resolved: true,
+            compute_units: None,
+            absolute_path: vec![],
+            transaction_context: None,
+        };
+        let account = Pubkey::new_unique();
+        let instruction = Instruction {
+            program_id: Pubkey::new_unique(),
+            accounts: vec![AccountMeta::new(account, true)],
+            data: vec![1, 2, 3],
+        };
+
+        sink.send(&metadata, &instruction, &Error::MissingInstructionData)
+            .await
+            .unwrap();
+        sink.send(&metadata, &instruction, &Error::Custom("boom".to_string()))
+            .await
+            .unwrap();
+
+        let dead_letters: Vec<DeadLetter> = fs::read_to_string(sink.path())
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(dead_letters.len(), 2);
+        assert_eq!(dead_letters[0].slot, 42);
+        assert_eq!(dead_letters[0].stack_height, 2);
+        assert_eq!(dead_letters[0].index, 3);
+        assert_eq!(
+            dead_letters[0].program_id,
+            instruction.program_id.to_string()
+        );
+        assert_eq!(
+            dead_letters[0].accounts,
+            vec![DeadLetterAccount {
+                pubkey: account.to_string(),
+                is_signer: true,
+                is_writable: true,
+            }]
+        );
+        assert_eq!(
+            STANDARD.decode(&dead_letters[0].data).unwrap(),
+            vec![1, 2, 3]
+        );
+        assert_eq!(dead_letters[0].error, "Missing instruction data");
+        assert_eq!(dead_letters[1].error, "Custom error: boom");
+
+        fs::remove_file(&path).ok();
+    }
+}
@@ -10,12 +10,28 @@
 //!
 //! - **`CarbonDeserialize`**: A trait for custom deserialization of data
 //!   structures from byte slices.
+//! - **`CarbonSerialize`**: The inverse of `CarbonDeserialize`, producing the
+//!   discriminator-prefixed bytes of a value.
 //! - **`extract_discriminator`**: A function that separates a discriminator
 //!   from the rest of a byte slice, used for parsing data with prefixed
 //!   discriminators.
+//! - **`peek_discriminator`**: Borrows the discriminator of a byte slice
+//!   without looking at the rest, for routing data by type without
+//!   deserializing it.
 //! - **`ArrangeAccounts`**: A trait that allows for defining a specific
 //!   arrangement of accounts, suitable for handling Solana account metadata in
 //!   a customized way.
+//! - **`ArrangeAccountMetas`**: The same arrangement, keeping the signer and
+//!   writable flags of each account instead of only its public key.
+//! - **`ArrangeAccountIndices`**: The same arrangement, giving the index of
+//!   each account in the instruction instead of its public key.
+//! - **`NamedAccounts`**: The names of an instruction's accounts, for labelling
+//!   them without knowing the instruction type.
+//! - **`next_optional_account`**: Reads an optional Anchor account, treating
+//!   the program ID sentinel or a missing trailing account as absent.
+//! - **`split_accounts`**: Splits the accounts of an instruction after a fixed
+//!   prefix, for programs that pass the accounts of downstream programs after
+//!   their own.
 //!
 //! # Notes
 //!
@@ -24,12 +40,18 @@
 //! - Ensure that `extract_discriminator` is used with data slices large enough
 //!   to avoid runtime errors.
 //! - Implement `ArrangeAccounts` when you need to access account metadata for
-//!   Solana instructions.
+//!   Solana instructions, and `ArrangeAccountMetas` when you also need to know
+//!   which accounts signed or were writable.
 
-use std::{
-    io::{Error, ErrorKind, Read, Result},
-    ops::Deref,
+use {
+    crate::{
+        borsh::maybestd::io::{Error, ErrorKind, Read, Result, Write},
+        error::CarbonResult,
+    },
+    alloc::{string::String, vec::Vec},
+    core::ops::Deref,
 };
+
 /// A trait for custom deserialization of types from byte slices.
 ///
 /// The `CarbonDeserialize` trait provides a method for deserializing instances
@@ -44,11 +66,222 @@ use std::{
 ///   which is useful for processing raw blockchain data.
 /// - Ensure the data slice passed to `deserialize` is valid and of appropriate
 ///   length to avoid errors.
+/// - `try_deserialize` distinguishes data that does not belong to the type
+///   (`Ok(None)`) from data whose discriminator matches but whose body is
+///   malformed (`Err`). The default implementation cannot tell the two apart
+///   and reports both as `Ok(None)`.
+/// - `DISCRIMINATOR` holds the prefix that identifies the type, as set with
+///   `#[carbon(discriminator = "0x...")]` when deriving. It is empty for types
+///   without a discriminator.
+/// - `DISCRIMINATORS` lists every prefix that identifies the type, for types
+///   whose discriminator changed in a program upgrade while their layout did
+///   not. It is set with `#[carbon(discriminator(all = ["0x...", "0x..."]))]`
+///   when deriving, and otherwise only holds `DISCRIMINATOR`, which is always
+///   its first entry and the one `CarbonSerialize` writes.
+/// - `DISCRIMINATOR_OFFSET` is the number of bytes that precede the
+///   discriminator, such as a version byte. It is set with `offset = N` in the
+///   `carbon` attribute when deriving, and is 0 for most types. The preceding
+///   bytes are neither compared nor deserialized.
+/// - `matching_discriminator` returns which of `DISCRIMINATORS` the data starts
+///   with after `DISCRIMINATOR_OFFSET` bytes, if any.
+/// - `matches_discriminator` only compares the prefix, so it is much cheaper
+///   than `deserialize` when the value itself is not needed, such as when
+///   counting instructions by type. A match does not guarantee that the rest of
+///   the data deserializes.
 pub trait CarbonDeserialize
 where
     Self: Sized + crate::borsh::BorshDeserialize,
 {
+    const DISCRIMINATOR: &'static [u8] = &[];
+
+    const DISCRIMINATORS: &'static [&'static [u8]] = &[Self::DISCRIMINATOR];
+
+    const DISCRIMINATOR_OFFSET: usize = 0;
+
     fn deserialize(data: &[u8]) -> Option<Self>;
+
+    fn matches_discriminator(data: &[u8]) -> bool {
+        Self::matching_discriminator(data).is_some()
+    }
+
+    fn matching_discriminator(data: &[u8]) -> Option<&'static [u8]> {
+        let data = data.get(Self::DISCRIMINATOR_OFFSET..)?;
+        Self::DISCRIMINATORS.iter().copied().find(|discriminator| {
+            peek_discriminator(data, discriminator.len()) == Some(*discriminator)
+        })
+    }
+
+    fn try_deserialize(data: &[u8]) -> CarbonResult<Option<Self>> {
+        Ok(<Self as CarbonDeserialize>::deserialize(data))
+    }
+}
+
+/// A trait for turning a deserializable type back into its serialized form.
+///
+/// `CarbonSerialize` is the inverse of `CarbonDeserialize`: `to_bytes`
+/// prepends the type's discriminator to its Borsh encoding, so that
+/// `T::deserialize(&value.to_bytes()) == Some(value)`. It is implemented by the
+/// `CarbonDeserialize` derive macro and is useful for building test fixtures
+/// and for forwarding decoded instructions.
+///
+/// The `DISCRIMINATOR_OFFSET` bytes before the discriminator are not part of
+/// the value, so `to_bytes` writes them as zeros.
+pub trait CarbonSerialize: CarbonDeserialize + crate::borsh::BorshSerialize {
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut data = alloc::vec![0; Self::DISCRIMINATOR_OFFSET];
+        data.extend_from_slice(Self::DISCRIMINATOR);
+        self.serialize(&mut data)
+            .expect("serializing into a Vec cannot fail");
+        data
+    }
+}
+
+/// A trait for borrowing a zero-copy view over the serialized form of a type.
+///
+/// `CarbonDeserializeView` is a companion to `CarbonDeserialize` for
+/// fixed-layout types, such as high-volume account states. Instead of
+/// allocating and copying every field, `deserialize_view` validates the
+/// discriminator and the data length and returns a view that borrows the input.
+/// Fields are only read from the underlying bytes when they are accessed.
+///
+/// # Notes
+///
+/// - The `CarbonDeserialize` derive macro implements this trait for structs
+///   marked `#[carbon(view)]`, whose named fields must all be `ViewField`
+///   types, such as integers, `bool`, `Pubkey` and fixed-size arrays of those.
+///   For such a struct `MyAccount`, the generated view type is
+///   `MyAccountView<'a>`, with one accessor method per field.
+/// - Accessors of scalar fields return the field by value. Those of array
+///   fields borrow the input instead, returning `&'a [u8; N]` for byte arrays
+///   and the `&'a [u8]` of the encoded elements for other arrays, which
+///   `ViewField::read` decodes one at a time.
+/// - The view reads fields at their Borsh offsets. Bytes following the
+///   serialized struct are ignored, which accommodates accounts allocated with
+///   trailing space.
+pub trait CarbonDeserializeView<'a> {
+    type View: 'a;
+
+    fn deserialize_view(data: &'a [u8]) -> Option<Self::View>;
+}
+
+/// A fixed-size field that can be read directly from its Borsh encoding.
+///
+/// Implemented for the primitive integers, `bool`, `Pubkey` and fixed-size
+/// arrays of those. Generated views use it to compute field offsets and to
+/// read scalar fields on access.
+pub trait ViewField: Copy {
+    /// The size of the field in its serialized form.
+    const SIZE: usize;
+
+    /// Reads the field from a slice of exactly `SIZE` bytes.
+    fn read(bytes: &[u8]) -> Self;
+}
+
+macro_rules! impl_view_field_for_integers {
+    ($($ty:ty),*) => {
+        $(
+            impl ViewField for $ty {
+                const SIZE: usize = core::mem::size_of::<$ty>();
+
+                #[inline]
+                fn read(bytes: &[u8]) -> Self {
+                    let mut buffer = [0u8; core::mem::size_of::<$ty>()];
+                    buffer.copy_from_slice(&bytes[..Self::SIZE]);
+                    <$ty>::from_le_bytes(buffer)
+                }
+            }
+        )*
+    };
+}
+
+impl_view_field_for_integers!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl ViewField for bool {
+    const SIZE: usize = 1;
+
+    #[inline]
+    fn read(bytes: &[u8]) -> Self {
+        bytes[0] != 0
+    }
+}
+
+impl ViewField for solana_pubkey::Pubkey {
+    const SIZE: usize = 32;
+
+    #[inline]
+    fn read(bytes: &[u8]) -> Self {
+        let mut buffer = [0u8; 32];
+        buffer.copy_from_slice(&bytes[..Self::SIZE]);
+        solana_pubkey::Pubkey::new_from_array(buffer)
+    }
+}
+
+impl<T: ViewField, const N: usize> ViewField for [T; N] {
+    const SIZE: usize = T::SIZE * N;
+
+    #[inline]
+    fn read(bytes: &[u8]) -> Self {
+        core::array::from_fn(|i| T::read(&bytes[i * T::SIZE..(i + 1) * T::SIZE]))
+    }
+}
+
+/// Deserializes a type whose data is prefixed with the given discriminator.
+///
+/// It returns `Ok(None)` when the data is shorter than the discriminator or the
+/// prefix does not match, and an error when the prefix matches but the
+/// remaining bytes cannot be deserialized.
+///
+/// An empty discriminator matches any data, so a failed deserialization is
+/// reported as `Ok(None)` in that case rather than as an error.
+pub fn try_deserialize_with_discriminator<T>(
+    discriminator: &[u8],
+    data: &[u8],
+) -> CarbonResult<Option<T>>
+where
+    T: crate::borsh::BorshDeserialize,
+{
+    let Some((disc, rest)) = extract_discriminator(discriminator.len(), data) else {
+        return Ok(None);
+    };
+
+    if disc != discriminator {
+        return Ok(None);
+    }
+
+    match T::try_from_slice(rest) {
+        Ok(decoded) => Ok(Some(decoded)),
+        Err(_) if discriminator.is_empty() => Ok(None),
+        Err(error) => Err(crate::error::CarbonError::Deserialization {
+            type_name: core::any::type_name::<T>(),
+            discriminator: discriminator.to_vec(),
+            #[cfg(feature = "std")]
+            source: error,
+            #[cfg(not(feature = "std"))]
+            source: crate::error::DeserializationError(error),
+        }),
+    }
+}
+
+/// Deserializes data that starts with any of `discriminators`.
+///
+/// This is the implementation behind the derived
+/// `CarbonDeserialize::try_deserialize`. The first matching discriminator is
+/// handled as by `try_deserialize_with_discriminator`, so the same body is
+/// deserialized whichever one matched. Returns `Ok(None)` if none matches.
+pub fn try_deserialize_with_discriminators<T>(
+    discriminators: &[&[u8]],
+    data: &[u8],
+) -> CarbonResult<Option<T>>
+where
+    T: crate::borsh::BorshDeserialize,
+{
+    match discriminators
+        .iter()
+        .find(|discriminator| data.starts_with(discriminator))
+    {
+        Some(discriminator) => try_deserialize_with_discriminator(discriminator, data),
+        None => Ok(None),
+    }
 }
 
 /// Extracts a discriminator from the beginning of a byte slice and returns the
@@ -78,6 +311,7 @@ where
 /// - This function is particularly useful for decoding prefixed data
 ///   structures, such as those commonly found in Solana transactions.
 pub fn extract_discriminator(length: usize, data: &[u8]) -> Option<(&[u8], &[u8])> {
+    #[cfg(feature = "std")]
     log::trace!(
         "extract_discriminator(length: {:?}, data: {:?})",
         length,
@@ -91,11 +325,25 @@ pub fn extract_discriminator(length: usize, data: &[u8]) -> Option<(&[u8], &[u8]
     Some((&data[..length], &data[length..]))
 }
 
+/// Returns the first `len` bytes of `data`, or `None` if `data` is shorter.
+///
+/// Unlike `extract_discriminator`, this only borrows the discriminator, which
+/// is all a router needs to classify data without deserializing it.
+pub fn peek_discriminator(data: &[u8], len: usize) -> Option<&[u8]> {
+    data.get(..len)
+}
+
 /// A trait for defining a custom arrangement of Solana account metadata.
 ///
 /// The `ArrangeAccounts` trait provides an interface for structuring account
 /// metadata in a custom format.
 ///
+/// Implementations should return `None` when fewer accounts are provided than
+/// the instruction requires. Any accounts beyond the named ones are passed
+/// through on the arranged type (generated decoders expose them as
+/// `remaining_accounts`), so programs that take a variable tail of accounts,
+/// such as oracle or market lists, can still be inspected.
+///
 /// # Associated Types
 ///
 /// - `ArrangedAccounts`: The output type representing the custom arrangement of
@@ -106,6 +354,124 @@ pub trait ArrangeAccounts {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts>;
+
+    /// Builds an instruction of `program_id` with the serialized instruction
+    /// data and the given accounts, in the order `arrange_accounts` expects.
+    fn to_instruction(
+        &self,
+        program_id: solana_pubkey::Pubkey,
+        accounts: Vec<solana_instruction::AccountMeta>,
+    ) -> solana_instruction::Instruction
+    where
+        Self: CarbonSerialize,
+    {
+        solana_instruction::Instruction {
+            program_id,
+            accounts,
+            data: self.to_bytes(),
+        }
+    }
+}
+
+/// A variant of [`ArrangeAccounts`] that keeps the full `AccountMeta` of every
+/// named account.
+///
+/// `ArrangeAccounts` only exposes public keys, which is enough for most
+/// indexers. Use this trait when the `is_signer` and `is_writable` flags
+/// matter, for example to check that an authority actually signed the
+/// instruction. Generated decoders implement both traits with the same
+/// account names, returning `...InstructionAccountMetas` from this one.
+///
+/// As with `ArrangeAccounts`, implementations return `None` when fewer
+/// accounts are provided than the instruction requires.
+pub trait ArrangeAccountMetas {
+    type ArrangedAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas>;
+}
+
+/// A variant of [`ArrangeAccounts`] that gives the index of every named account
+/// in the instruction's `accounts`.
+///
+/// Use this trait to map the accounts of a decoded instruction back to their
+/// positions, for example to rebuild the instruction with some accounts
+/// replaced before simulating it. Generated decoders implement it with the
+/// same account names as `ArrangeAccounts`, returning
+/// `...InstructionAccountIndices`, whose `remaining_accounts` is the range of
+/// indices after the named accounts.
+///
+/// As with `ArrangeAccounts`, implementations return `None` when fewer
+/// accounts are provided than the instruction requires.
+pub trait ArrangeAccountIndices {
+    type ArrangedAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices>;
+}
+
+/// A trait for labelling the accounts of an instruction with their names.
+///
+/// `ArrangeAccounts` gives each account a typed field, which requires knowing
+/// the concrete instruction type. `NamedAccounts` exposes the same names as
+/// data, so that generic code such as a block explorer can render the role of
+/// every account of any decoded instruction. Generated decoders implement it
+/// with the names of the `...InstructionAccounts` fields, in account order.
+///
+/// `named_accounts` pairs the names with the public keys of `accounts`.
+/// Accounts beyond the named ones, the `remaining_accounts` of
+/// `ArrangeAccounts`, are not included, and only the provided accounts are
+/// named if there are fewer than the instruction requires.
+pub trait NamedAccounts {
+    fn account_names() -> &'static [&'static str];
+
+    fn named_accounts(
+        &self,
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Vec<(&'static str, solana_pubkey::Pubkey)> {
+        Self::account_names()
+            .iter()
+            .copied()
+            .zip(accounts.iter().map(|account| account.pubkey))
+            .collect()
+    }
+}
+
+/// Takes the next account of an instruction if it is an optional account that
+/// was provided.
+///
+/// Anchor clients pass the program ID in place of an optional account they
+/// leave out, and may omit trailing optional accounts altogether. Both cases
+/// return `None`, so generated `arrange_accounts` implementations can fill
+/// `Option` fields for the optional accounts of an IDL. The sentinel still
+/// takes up its position, so the accounts after it stay in order.
+pub fn next_optional_account<'a>(
+    accounts: &mut core::slice::Iter<'a, solana_instruction::AccountMeta>,
+    program_id: &solana_pubkey::Pubkey,
+) -> Option<&'a solana_instruction::AccountMeta> {
+    accounts
+        .next()
+        .filter(|account| account.pubkey != *program_id)
+}
+
+/// Splits the accounts of an instruction into its first `prefix_len` accounts
+/// and the ones after them.
+///
+/// Aggregator programs, such as Jupiter, take a fixed prefix of named accounts
+/// followed by the accounts of the downstream programs of a route. Manual
+/// decoders can name the prefix and hand the rest to the decoders of those
+/// programs, like the `remaining_accounts` of generated decoders. Returns
+/// `None` if there are fewer than `prefix_len` accounts.
+pub fn split_accounts(
+    accounts: &[solana_instruction::AccountMeta],
+    prefix_len: usize,
+) -> Option<(
+    &[solana_instruction::AccountMeta],
+    &[solana_instruction::AccountMeta],
+)> {
+    accounts.split_at_checked(prefix_len)
 }
 
 /// A wrapper type for strings that are prefixed with their length.
@@ -127,8 +493,8 @@ impl From<PrefixString> for String {
     }
 }
 
-impl std::fmt::Debug for PrefixString {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Debug for PrefixString {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.write_fmt(format_args!("{:?}", self.0))
     }
 }
@@ -138,10 +504,10 @@ impl crate::borsh::BorshDeserialize for PrefixString {
     #[inline]
     fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
         // read the length of the String
-        let mut buffer = vec![0u8; 4];
+        let mut buffer = alloc::vec![0u8; 4];
         reader.read_exact(&mut buffer)?;
         let length = u32::deserialize(&mut buffer.as_slice())?;
-        let mut buffer = vec![0u8; length as usize];
+        let mut buffer = alloc::vec![0u8; length as usize];
         reader.read_exact(&mut buffer)?;
 
         Ok(Self(String::from_utf8(buffer).map_err(|_| {
@@ -150,6 +516,14 @@ impl crate::borsh::BorshDeserialize for PrefixString {
     }
 }
 
+impl crate::borsh::BorshSerialize for PrefixString {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        (self.0.len() as u32).serialize(writer)?;
+        writer.write_all(self.0.as_bytes())
+    }
+}
+
 /// A wrapper type for strings that are prefixed with their length.
 
 #[derive(serde::Serialize, Default, serde::Deserialize, PartialEq, Eq, Clone)]
@@ -169,8 +543,8 @@ impl From<U64PrefixString> for String {
     }
 }
 
-impl std::fmt::Debug for U64PrefixString {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Debug for U64PrefixString {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.write_fmt(format_args!("{:?}", self.0))
     }
 }
@@ -180,10 +554,10 @@ impl crate::borsh::BorshDeserialize for U64PrefixString {
     #[inline]
     fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
         // read the length of the String
-        let mut buffer = vec![0u8; 8];
+        let mut buffer = alloc::vec![0u8; 8];
         reader.read_exact(&mut buffer)?;
         let length = u64::deserialize(&mut buffer.as_slice())?;
-        let mut buffer = vec![0u8; length as usize];
+        let mut buffer = alloc::vec![0u8; length as usize];
         reader.read_exact(&mut buffer)?;
 
         Ok(Self(String::from_utf8(buffer).map_err(|_| {
@@ -191,3 +565,567 @@ impl crate::borsh::BorshDeserialize for U64PrefixString {
         })?))
     }
 }
+
+impl crate::borsh::BorshSerialize for U64PrefixString {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        (self.0.len() as u64).serialize(writer)?;
+        writer.write_all(self.0.as_bytes())
+    }
+}
+
+#[cfg(all(test, feature = "macros"))]
+mod tests {
+    use {
+        super::*,
+        crate::{borsh, CarbonDeserialize},
+        solana_pubkey::Pubkey,
+    };
+
+    /// A 4 KB fixed-layout account, including its 8-byte discriminator.
+    #[derive(CarbonDeserialize, Debug, PartialEq)]
+    #[carbon(discriminator = "0x0102030405060708", view)]
+    struct LargeAccount {
+        authority: Pubkey,
+        balances: [u64; 500],
+        total: u128,
+        is_active: bool,
+        padding: [u8; 39],
+    }
+
+    /// An account with a variable-size field, which cannot have a view.
+    #[derive(CarbonDeserialize, Debug, PartialEq)]
+    #[carbon(discriminator = "0x0102030405060709")]
+    struct VariableAccount {
+        authority: Pubkey,
+        balances: Vec<u64>,
+    }
+
+    /// An SPL Token style instruction with a single tag byte.
+    #[derive(CarbonDeserialize, Debug, PartialEq)]
+    #[carbon(discriminator = "0x03")]
+    struct OneByteTransfer {
+        amount: u64,
+    }
+
+    /// A System program style instruction with a 4-byte little-endian tag.
+    #[derive(CarbonDeserialize, Debug, PartialEq)]
+    #[carbon(discriminator = "0x02000000")]
+    struct FourByteTransfer {
+        lamports: u64,
+    }
+
+    /// An instruction without data, matched by an empty discriminator.
+    #[derive(CarbonDeserialize, Debug, PartialEq)]
+    #[carbon(discriminator = "0x")]
+    struct EmptyInstruction;
+
+    /// An instruction of a single-instruction program, whose whole data is its
+    /// arguments.
+    #[derive(CarbonDeserialize, Debug, PartialEq)]
+    #[carbon(no_discriminator)]
+    struct RawSwap {
+        amount_in: u64,
+        minimum_amount_out: u64,
+    }
+
+    /// An instruction whose discriminator follows a version byte.
+    #[derive(CarbonDeserialize, Debug, PartialEq)]
+    #[carbon(discriminator = "0x0102030405060708", offset = 1, view)]
+    struct VersionedDeposit {
+        amount: u64,
+    }
+
+    /// Anchor's `initialize` instruction, whose discriminator is derived from
+    /// the type name.
+    #[derive(CarbonDeserialize, Debug, PartialEq)]
+    #[carbon(anchor_instruction)]
+    struct Initialize {
+        amount: u64,
+    }
+
+    #[derive(CarbonDeserialize, Debug, PartialEq)]
+    #[carbon(anchor_instruction)]
+    struct InitializeUser;
+
+    #[derive(CarbonDeserialize, Debug, PartialEq)]
+    #[carbon(anchor_instruction = "initialize")]
+    struct Setup;
+
+    #[derive(CarbonDeserialize, Debug, PartialEq)]
+    #[carbon(anchor_instruction, discriminator = "0x01")]
+    struct OverriddenInitialize;
+
+    /// An instruction whose discriminator changed in a program upgrade.
+    #[derive(CarbonDeserialize, Debug, PartialEq)]
+    #[carbon(discriminator(all = ["0xbbbbbbbbbbbbbbbb", "0xaaaaaaaaaaaaaaaa"]))]
+    struct MigratedDeposit {
+        amount: u64,
+    }
+
+    /// An instruction with length-prefixed string arguments.
+    #[derive(CarbonDeserialize, Debug, PartialEq)]
+    #[carbon(discriminator = "0x0a0b")]
+    struct SetNames {
+        name: PrefixString,
+        long_name: U64PrefixString,
+    }
+
+    /// An instruction with float arguments, which cannot derive `Eq` or
+    /// `Hash`, as the CLI generates it.
+    #[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+    #[carbon(discriminator = "0x0c")]
+    struct UpdateOraclePrice {
+        price: f64,
+        confidence: f32,
+        slot: u64,
+    }
+
+    #[derive(
+        crate::InstructionType, serde::Serialize, serde::Deserialize, PartialEq, Debug, Clone,
+    )]
+    enum OracleInstruction {
+        UpdateOraclePrice(UpdateOraclePrice),
+    }
+
+    fn serialize_large_account(authority: Pubkey) -> Vec<u8> {
+        let mut data = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        data.extend_from_slice(authority.as_ref());
+        for i in 0..500u64 {
+            data.extend_from_slice(&(i * 3).to_le_bytes());
+        }
+        data.extend_from_slice(&u128::MAX.to_le_bytes());
+        data.push(1);
+        data.extend_from_slice(&[9u8; 39]);
+        data
+    }
+
+    #[test]
+    fn test_deserialize_view_matches_borsh() {
+        let authority = Pubkey::new_unique();
+        let data = serialize_large_account(authority);
+        assert_eq!(data.len(), 4096);
+
+        let view = LargeAccount::deserialize_view(&data).expect("view");
+        let owned = <LargeAccount as CarbonDeserialize>::deserialize(&data).expect("deserialize");
+
+        assert_eq!(LargeAccountView::SIZE, 4088);
+        assert_eq!(view.authority(), authority);
+        assert_eq!(view.balances().len(), 4000);
+        assert_eq!(u64::read(&view.balances()[499 * 8..500 * 8]), 1497);
+        assert_eq!(view.total(), u128::MAX);
+        assert!(view.is_active());
+        assert_eq!(view.padding(), &[9u8; 39]);
+        assert_eq!(view.into_owned(), owned);
+    }
+
+    #[test]
+    fn test_deserialize_view_validates_discriminator_and_length() {
+        let mut data = serialize_large_account(Pubkey::new_unique());
+
+        assert!(LargeAccount::deserialize_view(&data[..data.len() - 1]).is_none());
+
+        data[0] = 0;
+        assert!(LargeAccount::deserialize_view(&data).is_none());
+    }
+
+    #[test]
+    fn test_variable_size_struct_still_deserializes() {
+        let authority = Pubkey::new_unique();
+        let mut data = vec![1, 2, 3, 4, 5, 6, 7, 9];
+        data.extend_from_slice(authority.as_ref());
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(&7u64.to_le_bytes());
+
+        assert_eq!(
+            <VariableAccount as CarbonDeserialize>::deserialize(&data),
+            Some(VariableAccount {
+                authority,
+                balances: vec![7],
+            })
+        );
+    }
+
+    #[test]
+    fn test_short_discriminators_match_exact_prefix() {
+        let mut data = vec![3];
+        data.extend_from_slice(&42u64.to_le_bytes());
+
+        assert_eq!(OneByteTransfer::DISCRIMINATOR, &[3]);
+        assert_eq!(
+            <OneByteTransfer as CarbonDeserialize>::deserialize(&data),
+            Some(OneByteTransfer { amount: 42 })
+        );
+        assert_eq!(
+            <FourByteTransfer as CarbonDeserialize>::deserialize(&data),
+            None
+        );
+
+        let mut data = vec![2, 0, 0, 0];
+        data.extend_from_slice(&7u64.to_le_bytes());
+
+        assert_eq!(FourByteTransfer::DISCRIMINATOR, &[2, 0, 0, 0]);
+        assert_eq!(
+            <FourByteTransfer as CarbonDeserialize>::deserialize(&data),
+            Some(FourByteTransfer { lamports: 7 })
+        );
+        assert_eq!(
+            <OneByteTransfer as CarbonDeserialize>::deserialize(&data),
+            None
+        );
+        assert_eq!(
+            <FourByteTransfer as CarbonDeserialize>::deserialize(&data[..3]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_empty_discriminator_matches_empty_data() {
+        assert!(EmptyInstruction::DISCRIMINATOR.is_empty());
+        assert_eq!(
+            <EmptyInstruction as CarbonDeserialize>::deserialize(&[]),
+            Some(EmptyInstruction)
+        );
+        assert_eq!(
+            <EmptyInstruction as CarbonDeserialize>::deserialize(&[1]),
+            None
+        );
+        assert_eq!(
+            <OneByteTransfer as CarbonDeserialize>::deserialize(&[]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_discriminator_at_an_offset() {
+        // Arrange
+        let discriminator = [1, 2, 3, 4, 5, 6, 7, 8];
+        let data = [&[2][..], &discriminator, &42u64.to_le_bytes()].concat();
+
+        // Act
+        let decoded = <VersionedDeposit as CarbonDeserialize>::deserialize(&data);
+        let view = VersionedDeposit::deserialize_view(&data).expect("view");
+
+        // Assert
+        let deposit = VersionedDeposit { amount: 42 };
+        assert_eq!(VersionedDeposit::DISCRIMINATOR_OFFSET, 1);
+        assert_eq!(decoded.as_ref(), Some(&deposit));
+        assert_eq!(view.amount(), 42);
+        assert!(VersionedDeposit::matches_discriminator(&data));
+        assert!(!VersionedDeposit::matches_discriminator(&data[1..]));
+        assert_eq!(
+            <VersionedDeposit as CarbonDeserialize>::deserialize(&data[1..]),
+            None
+        );
+        assert_eq!(deposit.to_bytes()[1..], data[1..]);
+        assert_eq!(deposit.to_bytes()[0], 0);
+    }
+
+    #[test]
+    fn test_no_discriminator_deserializes_the_whole_data() {
+        // Arrange
+        let data = [100u64.to_le_bytes(), 95u64.to_le_bytes()].concat();
+        let instruction = solana_instruction::Instruction {
+            program_id: Pubkey::new_unique(),
+            accounts: vec![],
+            data: data.clone(),
+        };
+
+        // Act
+        let decoded = <RawSwap as CarbonDeserialize>::deserialize(&data);
+        let from_instruction =
+            crate::try_decode_instructions_fallible!(instruction, Some => RawSwap)
+                .unwrap()
+                .expect("decoded");
+
+        // Assert
+        let swap = RawSwap {
+            amount_in: 100,
+            minimum_amount_out: 95,
+        };
+        assert!(RawSwap::DISCRIMINATOR.is_empty());
+        assert_eq!(swap.to_bytes(), data);
+        assert_eq!(decoded.as_ref(), Some(&swap));
+        assert_eq!(from_instruction.data, Some(swap));
+        assert!(from_instruction.discriminator.is_empty());
+        assert_eq!(
+            <RawSwap as CarbonDeserialize>::deserialize(&data[..15]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_to_bytes_round_trips_through_deserialize() {
+        let transfer = OneByteTransfer { amount: 1_000_000 };
+        let names = SetNames {
+            name: PrefixString("carbon".to_string()),
+            long_name: U64PrefixString("carbon indexer".to_string()),
+        };
+
+        let mut expected = vec![0x0a, 0x0b, 6, 0, 0, 0];
+        expected.extend_from_slice(b"carbon");
+        expected.extend_from_slice(&14u64.to_le_bytes());
+        expected.extend_from_slice(b"carbon indexer");
+
+        assert_eq!(transfer.to_bytes(), [3, 64, 66, 15, 0, 0, 0, 0, 0]);
+        assert_eq!(names.to_bytes(), expected);
+        assert_eq!(EmptyInstruction.to_bytes(), Vec::<u8>::new());
+        assert_eq!(
+            <OneByteTransfer as CarbonDeserialize>::deserialize(&transfer.to_bytes()),
+            Some(transfer)
+        );
+        assert_eq!(
+            <SetNames as CarbonDeserialize>::deserialize(&names.to_bytes()),
+            Some(names)
+        );
+    }
+
+    const SWAP_PROGRAM_ID: Pubkey =
+        Pubkey::from_str_const("Swap111111111111111111111111111111111111111");
+
+    struct Swap;
+
+    /// The accounts of `Swap`, arranged the way the CLI generates them for an
+    /// IDL where `referrer` and `fee` are optional.
+    #[derive(Debug, PartialEq)]
+    struct SwapInstructionAccounts {
+        user: Pubkey,
+        referrer: Option<Pubkey>,
+        pool: Pubkey,
+        fee: Option<Pubkey>,
+        remaining_accounts: Vec<solana_instruction::AccountMeta>,
+    }
+
+    impl ArrangeAccounts for Swap {
+        type ArrangedAccounts = SwapInstructionAccounts;
+
+        fn arrange_accounts(
+            accounts: &[solana_instruction::AccountMeta],
+        ) -> Option<Self::ArrangedAccounts> {
+            let mut accounts = accounts.iter();
+            let user = accounts.next()?;
+            let referrer = next_optional_account(&mut accounts, &SWAP_PROGRAM_ID);
+            let pool = accounts.next()?;
+            let fee = next_optional_account(&mut accounts, &SWAP_PROGRAM_ID);
+            let remaining = accounts.as_slice();
+
+            Some(SwapInstructionAccounts {
+                user: user.pubkey,
+                referrer: referrer.map(|account| account.pubkey),
+                pool: pool.pubkey,
+                fee: fee.map(|account| account.pubkey),
+                remaining_accounts: remaining.to_vec(),
+            })
+        }
+    }
+
+    #[test]
+    fn test_optional_accounts_present_and_absent() {
+        // Arrange
+        let user = Pubkey::new_unique();
+        let referrer = Pubkey::new_unique();
+        let pool = Pubkey::new_unique();
+        let fee = Pubkey::new_unique();
+        let meta = |pubkey| solana_instruction::AccountMeta::new_readonly(pubkey, false);
+
+        // Act
+        let with_referrer = Swap::arrange_accounts(&[meta(user), meta(referrer), meta(pool)]);
+        let with_sentinel =
+            Swap::arrange_accounts(&[meta(user), meta(SWAP_PROGRAM_ID), meta(pool), meta(fee)]);
+        let too_short = Swap::arrange_accounts(&[meta(user), meta(referrer)]);
+
+        // Assert
+        assert_eq!(
+            with_referrer,
+            Some(SwapInstructionAccounts {
+                user,
+                referrer: Some(referrer),
+                pool,
+                fee: None,
+                remaining_accounts: vec![],
+            })
+        );
+        assert_eq!(
+            with_sentinel,
+            Some(SwapInstructionAccounts {
+                user,
+                referrer: None,
+                pool,
+                fee: Some(fee),
+                remaining_accounts: vec![],
+            })
+        );
+        assert_eq!(too_short, None);
+    }
+
+    #[test]
+    fn test_split_accounts_at_prefix() {
+        // Arrange
+        let accounts: Vec<_> = (0..10)
+            .map(|_| solana_instruction::AccountMeta::new_readonly(Pubkey::new_unique(), false))
+            .collect();
+
+        // Act
+        let split = split_accounts(&accounts, 4);
+
+        // Assert
+        assert_eq!(split, Some((&accounts[..4], &accounts[4..])));
+        assert_eq!(split.unwrap().1.len(), 6);
+        assert_eq!(
+            split_accounts(&accounts, 10),
+            Some((&accounts[..], &[][..]))
+        );
+        assert_eq!(split_accounts(&accounts, 11), None);
+    }
+
+    #[test]
+    fn test_anchor_instruction_discriminator_is_derived_from_name() {
+        // Arrange
+        let anchor_discriminator = |name: &str| {
+            solana_program::hash::hash(format!("global:{name}").as_bytes()).to_bytes()[..8].to_vec()
+        };
+        let initialize = Initialize { amount: 5 };
+
+        // Act
+        let bytes = initialize.to_bytes();
+
+        // Assert
+        assert_eq!(
+            Initialize::DISCRIMINATOR,
+            &[175, 175, 109, 31, 13, 152, 155, 237]
+        );
+        assert_eq!(
+            InitializeUser::DISCRIMINATOR,
+            anchor_discriminator("initialize_user")
+        );
+        assert_eq!(Setup::DISCRIMINATOR, Initialize::DISCRIMINATOR);
+        assert_eq!(OverriddenInitialize::DISCRIMINATOR, &[1]);
+        assert_eq!(&bytes[..8], Initialize::DISCRIMINATOR);
+        assert_eq!(
+            <Initialize as CarbonDeserialize>::deserialize(&bytes),
+            Some(initialize)
+        );
+    }
+
+    #[test]
+    fn test_matches_discriminator_without_deserializing() {
+        // Arrange
+        let initialize = Initialize { amount: 5 }.to_bytes();
+        let truncated = &initialize[..10];
+        let other = OverriddenInitialize.to_bytes();
+
+        // Act
+        let peeked = peek_discriminator(&initialize, 8);
+        let too_short = peek_discriminator(&initialize[..4], 8);
+
+        // Assert
+        assert_eq!(peeked, Some(Initialize::DISCRIMINATOR));
+        assert_eq!(too_short, None);
+        assert!(Initialize::matches_discriminator(&initialize));
+        assert!(Initialize::matches_discriminator(truncated));
+        assert_eq!(
+            <Initialize as CarbonDeserialize>::deserialize(truncated),
+            None
+        );
+        assert!(!Initialize::matches_discriminator(&other));
+        assert!(crate::matches_discriminators!(
+            &other,
+            Initialize,
+            OverriddenInitialize
+        ));
+        assert!(!crate::matches_discriminators!(
+            &other,
+            Initialize,
+            InitializeUser
+        ));
+    }
+
+    #[test]
+    fn test_one_type_decodes_every_listed_discriminator() {
+        // Arrange
+        let deposit = MigratedDeposit { amount: 7 };
+        let current = deposit.to_bytes();
+        let legacy = [vec![0xaa; 8], 7u64.to_le_bytes().to_vec()].concat();
+        let decode = |data: Vec<u8>| {
+            let instruction = solana_instruction::Instruction {
+                program_id: Pubkey::new_unique(),
+                accounts: vec![],
+                data,
+            };
+            crate::try_decode_instructions_fallible!(instruction, Some => MigratedDeposit)
+        };
+
+        // Act
+        let decoded_current = decode(current.clone()).unwrap().expect("current");
+        let decoded_legacy = decode(legacy.clone()).unwrap().expect("legacy");
+
+        // Assert
+        assert_eq!(MigratedDeposit::DISCRIMINATOR, &[0xbb; 8]);
+        assert_eq!(&current[..8], &[0xbb; 8]);
+        assert_eq!(decoded_current.data, Some(MigratedDeposit { amount: 7 }));
+        assert_eq!(decoded_current.discriminator, vec![0xbb; 8]);
+        assert_eq!(decoded_legacy.data, Some(MigratedDeposit { amount: 7 }));
+        assert_eq!(decoded_legacy.discriminator, vec![0xaa; 8]);
+        assert!(MigratedDeposit::matches_discriminator(&legacy));
+        assert!(decode(vec![0xcc; 16]).unwrap().is_none());
+        assert!(decode(vec![0xaa; 9]).is_err());
+    }
+
+    #[test]
+    fn test_decoded_instruction_records_raw_len_and_num_accounts() {
+        // Arrange
+        let data = MigratedDeposit { amount: 7 }.to_bytes();
+        let instruction = solana_instruction::Instruction {
+            program_id: Pubkey::new_unique(),
+            accounts: vec![
+                solana_instruction::AccountMeta::new(Pubkey::new_unique(), true),
+                solana_instruction::AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            ],
+            data: data.clone(),
+        };
+
+        // Act
+        let decoded =
+            crate::try_decode_instructions!(instruction, Some => MigratedDeposit).expect("decoded");
+
+        // Assert
+        assert_eq!(decoded.num_accounts, decoded.accounts.len());
+        assert_eq!(decoded.num_accounts, 2);
+        assert_eq!(decoded.raw_len, data.len());
+    }
+
+    #[test]
+    fn test_float_instruction_derives_partial_eq_and_serialize_without_eq() {
+        // Arrange
+        let update = UpdateOraclePrice {
+            price: 142.5,
+            confidence: 0.25,
+            slot: 9,
+        };
+        let instruction = solana_instruction::Instruction {
+            program_id: Pubkey::new_unique(),
+            accounts: vec![],
+            data: update.to_bytes(),
+        };
+
+        // Act
+        let decoded = crate::try_decode_instructions!(
+            instruction,
+            OracleInstruction::UpdateOraclePrice => UpdateOraclePrice
+        )
+        .expect("decoded");
+
+        // Assert
+        assert_eq!(decoded.data, OracleInstruction::UpdateOraclePrice(update));
+        assert_eq!(
+            decoded.data.get_instruction_type(),
+            OracleInstructionType::UpdateOraclePrice
+        );
+        assert_eq!(
+            serde_json::to_value(&decoded.data).unwrap(),
+            serde_json::json!({
+                "UpdateOraclePrice": { "price": 142.5, "confidence": 0.25, "slot": 9 }
+            })
+        );
+    }
+}
@@ -1,18 +1,22 @@
-//! Defines the `Error` enum and `CarbonResult` type used for error handling in
-//! the `carbon-core` framework.
+//! Defines the `CarbonError` enum and `CarbonResult` type used for error
+//! handling in the `carbon-core` framework.
 //!
-//! The `Error` enum captures various error types that can occur within the
-//! framework, providing detailed error messages and support for custom error
-//! handling. The `CarbonResult` type alias simplifies function signatures by
-//! unifying the return type for functions that may return an `Error`.
+//! The `CarbonError` enum captures various error types that can occur within
+//! the framework, providing detailed error messages and support for custom
+//! error handling. The `CarbonResult` type alias simplifies function signatures
+//! by unifying the return type for functions that may return a `CarbonError`.
 //!
 //! # Overview
 //!
-//! - **`Error`**: An enum representing specific error cases, from missing data
-//!   in transactions to issues with data sources. Each variant provides a
+//! - **`CarbonError`**: An enum representing specific error cases, from missing
+//!   data in transactions to issues with data sources. Each variant provides a
 //!   descriptive error message.
-//! - **`CarbonResult`**: A type alias for `Result<T, Error>`, where `T` is the
-//!   successful return type.
+//! - **`Error`**: The previous name of `CarbonError`, kept as an alias so that
+//!   existing code keeps compiling.
+//! - **`DeserializationError`**: The error borsh reports for malformed data,
+//!   which is `std::io::Error` with the `std` feature.
+//! - **`CarbonResult`**: A type alias for `Result<T, CarbonError>`, where `T`
+//!   is the successful return type.
 //!
 //! These errors are essential for handling various scenarios that may arise
 //! during data processing in the `carbon-core` pipeline, including missing
@@ -25,11 +29,31 @@
 //!   display messages.
 //! - Each error variant corresponds to a unique error scenario within the
 //!   `carbon-core` framework.
+//! - Errors raised while decoding or processing an instruction are wrapped in
+//!   `Decode` and `Processor`, which record the program and decoder involved.
+//!   The original error is available as the `source`, so callers can match on
+//!   both the stage that failed and its cause:
+//!
+//! ```ignore
+//! match error {
+//!     CarbonError::Decode { source, .. }
+//!         if matches!(*source, CarbonError::Deserialization { .. }) => skip(),
+//!     CarbonError::Processor { .. } => retry(),
+//!     _ => abort(),
+//! }
+//! ```
 
-use {crate::datasource::UpdateType, thiserror::Error};
+#[cfg(feature = "std")]
+use crate::datasource::UpdateType;
+use {
+    alloc::{boxed::Box, string::String, vec::Vec},
+    solana_pubkey::Pubkey,
+    thiserror::Error,
+};
 
 #[derive(Error, Debug)]
-pub enum Error {
+pub enum CarbonError {
+    #[cfg(feature = "std")]
     #[error("Missing update type in datasource")]
     MissingUpdateTypeInDatasource(UpdateType),
     #[error("Failed to receive updates({0})")]
@@ -42,18 +66,109 @@ pub enum Error {
     MissingAccountInTransaction,
     #[error("Missing instruction data")]
     MissingInstructionData,
+    /// `PipelineBuilder::build` was called without any datasource.
+    #[error("Pipeline has no datasource")]
+    MissingDatasource,
+    /// `PipelineBuilder::build` was called without any account, account
+    /// deletion, instruction or transaction pipe.
+    #[error("Pipeline has no pipe")]
+    MissingPipe,
     #[error("Failed to consume datasource ({0})")]
     FailedToConsumeDatasource(String),
+    #[error("Failed to deserialize ({0})")]
+    FailedToDeserialize(String),
+    /// The data matched the discriminator of `type_name`, but its remaining
+    /// bytes could not be deserialized, for example because they are
+    /// truncated.
+    #[error("Failed to deserialize {type_name} (discriminator {discriminator:?}): {source}")]
+    Deserialization {
+        type_name: &'static str,
+        discriminator: Vec<u8>,
+        source: DeserializationError,
+    },
+    /// An instruction has fewer accounts than its type requires, as reported
+    /// by `ArrangeAccounts::arrange_accounts` returning `None`.
+    #[error(
+        "Instruction of program {program_id} (discriminator {discriminator:?}) is missing accounts"
+    )]
+    MissingAccounts {
+        program_id: Pubkey,
+        discriminator: Vec<u8>,
+    },
+    /// A datasource failed to fetch or convert updates.
+    #[error("Datasource {datasource} failed: {source}")]
+    Datasource {
+        datasource: String,
+        source: Box<dyn core::error::Error + Send + Sync>,
+    },
+    /// The processor of an instruction pipe returned an error.
+    #[error("Processor for {decoder} failed on instruction of program {program_id}: {source}")]
+    Processor {
+        program_id: Pubkey,
+        decoder: &'static str,
+        source: Box<CarbonError>,
+    },
+    /// A decoder recognized an instruction but failed to decode it.
+    #[error("Decoder {decoder} failed on instruction of program {program_id}: {source}")]
+    Decode {
+        program_id: Pubkey,
+        decoder: &'static str,
+        source: Box<CarbonError>,
+    },
+    #[error("Fan-out processors failed ({0:?})")]
+    FanOutFailed(Vec<CarbonError>),
+    #[error("Checkpoint error ({0})")]
+    Checkpoint(String),
+    /// A processor wrapped in a `TimeoutProcessor` did not finish within the
+    /// given duration.
+    #[error("Processor timed out after {0:?}")]
+    Timeout(core::time::Duration),
+    #[cfg(feature = "std")]
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[cfg(feature = "std")]
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+    #[cfg(feature = "postgres")]
+    #[error(transparent)]
+    Postgres(#[from] tokio_postgres::Error),
     #[error("Custom error: {0}")]
     Custom(String),
 }
 
-/// A type alias for `Result` with the `Error` type as the error variant.
+/// The error borsh reports for data that cannot be deserialized, the `source`
+/// of [`CarbonError::Deserialization`].
+#[cfg(feature = "std")]
+pub type DeserializationError = std::io::Error;
+
+/// The error borsh reports for data that cannot be deserialized, the `source`
+/// of [`CarbonError::Deserialization`].
+///
+/// Without `std`, borsh's I/O error does not implement `core::error::Error`,
+/// so it is wrapped in this type.
+#[cfg(not(feature = "std"))]
+#[derive(Debug)]
+pub struct DeserializationError(pub crate::borsh::maybestd::io::Error);
+
+#[cfg(not(feature = "std"))]
+impl core::fmt::Display for DeserializationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl core::error::Error for DeserializationError {}
+
+/// The previous name of [`CarbonError`].
+pub type Error = CarbonError;
+
+/// A type alias for `Result` with the `CarbonError` type as the error variant.
 ///
 /// This alias simplifies function signatures in the `carbon-core` framework by
 /// unifying error handling under a common type. Any function that may result in
-/// an `Error` can return a `CarbonResult`, providing clear and consistent error
-/// reporting.
+/// a `CarbonError` can return a `CarbonResult`, providing clear and consistent
+/// error reporting.
 ///
 /// # Example
 ///
@@ -63,7 +178,7 @@ pub enum Error {
 ///     if success {
 ///         Ok(())
 ///     } else {
-///         Err(Error::MissingInstructionData)
+///         Err(CarbonError::MissingInstructionData)
 ///     }
 /// }
 ///
@@ -72,4 +187,4 @@ pub enum Error {
 ///     Err(e) => eprintln!("Error occurred: {}", e),
 /// }
 /// ```
-pub type CarbonResult<T> = Result<T, Error>;
+pub type CarbonResult<T> = Result<T, CarbonError>;
@@ -0,0 +1,891 @@
+//! Provides structures and traits for decoding and processing Anchor events.
+//!
+//! Anchor programs built with `emit_cpi!` publish events by invoking
+//! themselves with a special instruction. The instruction data starts with
+//! [`ANCHOR_EVENT_IX_TAG`], followed by the 8-byte discriminator of the event
+//! and its Borsh-serialized fields. This module recognizes that self-CPI
+//! pattern while walking nested instructions and decodes the payload into
+//! program-specific event types.
+//!
+//! The module includes the following main components:
+//! - **`DecodedEvent`**: An event that has been decoded, with the emitting
+//!   program ID, the event discriminator and the decoded data.
+//! - **`EventDecoder`**: A trait for decoding event payloads into specific
+//!   types.
+//! - **`EventPipe`**: A structure that finds self-CPI events in nested
+//!   instructions and passes the decoded events to a processor.
+//! - **`LogEventRouter`**: A utility that decodes events emitted with `emit!`,
+//!   which are written to the transaction logs as base64-encoded `Program
+//!   data:` lines.
+//! - **`LogDataDecoder`** and **`LogDataRouter`**: Decode the fields of
+//!   arbitrary `sol_log_data` entries, dispatching on their first field.
+
+use {
+    crate::deserialize::CarbonDeserialize,
+    alloc::vec::Vec,
+    serde::{Deserialize, Serialize},
+    solana_pubkey::Pubkey,
+};
+#[cfg(feature = "std")]
+use {
+    crate::{
+        error::CarbonResult,
+        instruction::{InstructionMetadata, InstructionPipes, NestedInstruction},
+        metrics::MetricsCollection,
+        processor::Processor,
+        transaction::TransactionMetadata,
+        transformers::{ProgramLog, ProgramLogs},
+    },
+    async_trait::async_trait,
+    base64::{engine::general_purpose::STANDARD, Engine},
+    std::{collections::HashMap, sync::Arc},
+};
+
+/// The tag prefixed to the instruction data of Anchor self-CPI events.
+///
+/// This is the first 8 bytes of `sha256("anchor:event")`, stored in
+/// little-endian order.
+pub const ANCHOR_EVENT_IX_TAG: [u8; 8] = [0xe4, 0x45, 0xa5, 0x2e, 0x51, 0xcb, 0x9a, 0x1d];
+
+/// A decoded event containing the emitting program ID, discriminator and data.
+///
+/// # Type Parameters
+///
+/// - `T`: The type representing the decoded event data.
+///
+/// # Fields
+///
+/// - `program_id`: The program that emitted the event.
+/// - `discriminator`: The 8-byte discriminator identifying the event type.
+/// - `data`: The decoded event, of type `T`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DecodedEvent<T> {
+    pub program_id: Pubkey,
+    pub discriminator: Vec<u8>,
+    pub data: T,
+}
+
+/// A trait for decoding event payloads into a structured type.
+///
+/// The `data` passed to `decode_event` is the event payload: the 8-byte event
+/// discriminator followed by the Borsh-serialized event, without the
+/// [`ANCHOR_EVENT_IX_TAG`] prefix. Implementations should return `None` for
+/// payloads that do not belong to one of their event types.
+///
+/// # Type Parameters
+///
+/// - `EventType`: The type into which the event data will be decoded.
+pub trait EventDecoder<'a> {
+    type EventType;
+
+    fn decode_event(
+        &self,
+        program_id: &Pubkey,
+        data: &'a [u8],
+    ) -> Option<DecodedEvent<Self::EventType>>;
+}
+
+/// Returns the event payload of an Anchor self-CPI instruction's data, or
+/// `None` if the data does not start with [`ANCHOR_EVENT_IX_TAG`].
+pub fn anchor_event_payload(data: &[u8]) -> Option<&[u8]> {
+    data.strip_prefix(&ANCHOR_EVENT_IX_TAG)
+}
+
+/// Deserializes an event payload into `T`.
+///
+/// Generated event types carry the full self-CPI discriminator, i.e.
+/// [`ANCHOR_EVENT_IX_TAG`] followed by the event discriminator. This function
+/// matches the payload against the event discriminator part only and returns
+/// `None` on a mismatch or if the remaining data cannot be deserialized.
+pub fn try_decode_anchor_event<T: CarbonDeserialize>(data: &[u8]) -> Option<T> {
+    let event_discriminator =
+        <T as CarbonDeserialize>::DISCRIMINATOR.strip_prefix(&ANCHOR_EVENT_IX_TAG)?;
+
+    if event_discriminator.is_empty() {
+        return None;
+    }
+
+    let mut rest = data.strip_prefix(event_discriminator)?;
+    let event = <T as crate::borsh::BorshDeserialize>::deserialize(&mut rest).ok()?;
+
+    #[cfg(feature = "std")]
+    if !rest.is_empty() {
+        log::debug!(
+            "Not all bytes were read when deserializing event {}: {} bytes left",
+            core::any::type_name::<T>(),
+            rest.len()
+        );
+    }
+
+    Some(event)
+}
+
+/// The input type for the event processor.
+///
+/// - `T`: The event type
+#[cfg(feature = "std")]
+pub type EventProcessorInputType<T> = (InstructionMetadata, DecodedEvent<T>);
+
+/// A processing pipeline for Anchor self-CPI events, using a decoder and
+/// processor.
+///
+/// `EventPipe` walks nested instructions and treats an inner instruction as an
+/// event when it invokes the same program as its parent instruction and its
+/// data starts with [`ANCHOR_EVENT_IX_TAG`]. The event payload is decoded with
+/// the `EventDecoder` and, if recognized, passed to the `Processor` together
+/// with the metadata of the self-CPI instruction.
+///
+/// # Fields
+///
+/// - `decoder`: The decoder used for parsing event payloads.
+/// - `processor`: The processor that handles decoded events.
+/// - `max_depth`: The deepest level of inner instructions the pipe visits, as
+///   for `InstructionPipe`. Defaults to `DEFAULT_MAX_INSTRUCTION_DEPTH`.
+/// - `top_level_only`: Whether the pipe only looks for the events of top-level
+///   instructions, without descending into the instructions they invoked.
+///   Defaults to `false`.
+#[cfg(feature = "std")]
+pub struct EventPipe<T: Send> {
+    pub decoder: Arc<dyn for<'a> EventDecoder<'a, EventType = T> + Send + Sync + 'static>,
+    pub processor:
+        Box<dyn Processor<InputType = EventProcessorInputType<T>> + Send + Sync + 'static>,
+    pub max_depth: usize,
+    pub top_level_only: bool,
+}
+
+#[cfg(feature = "std")]
+#[async_trait]
+impl<T: Send + 'static> InstructionPipes<'_> for EventPipe<T> {
+    async fn run(
+        &mut self,
+        nested_instruction: &NestedInstruction,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        log::trace!(
+            "EventPipe::run(nested_instruction: {:?}, metrics)",
+            nested_instruction,
+        );
+
+        // As in `InstructionPipe::run`, the tree is walked with an explicit
+        // stack, in execution order, and never below `max_depth`. Each
+        // instruction is visited with the program ID of the instruction that
+        // invoked it, if any.
+        let mut stack = vec![(nested_instruction, None, 1)];
+        while let Some((nested_instruction, parent_program_id, depth)) = stack.pop() {
+            if depth > self.max_depth {
+                continue;
+            }
+
+            let program_id = nested_instruction.instruction.program_id;
+            let payload = (parent_program_id == Some(program_id))
+                .then(|| anchor_event_payload(&nested_instruction.instruction.data))
+                .flatten();
+
+            if let Some(decoded_event) =
+                payload.and_then(|payload| self.decoder.decode_event(&program_id, payload))
+            {
+                metrics.increment_counter("events_decoded", 1).await?;
+                self.processor
+                    .process(
+                        (nested_instruction.metadata.clone(), decoded_event),
+                        metrics.clone(),
+                    )
+                    .await?;
+            }
+
+            // The events of a top-level instruction are the instructions it
+            // invoked itself, so those are still visited.
+            if self.top_level_only && depth > 1 {
+                continue;
+            }
+
+            stack.extend(
+                nested_instruction
+                    .inner_instructions
+                    .iter()
+                    .rev()
+                    .map(|inner_instruction| (inner_instruction, Some(program_id), depth + 1)),
+            );
+        }
+
+        Ok(())
+    }
+
+    async fn shutdown(&mut self) -> CarbonResult<()> {
+        self.processor.shutdown().await
+    }
+
+    fn fork(&self) -> Option<Box<dyn for<'b> InstructionPipes<'b>>> {
+        Some(Box::new(EventPipe {
+            decoder: self.decoder.clone(),
+            processor: self.processor.fork()?,
+            max_depth: self.max_depth,
+            top_level_only: self.top_level_only,
+        }))
+    }
+
+    fn set_max_depth(&mut self, max_depth: usize) {
+        self.max_depth = max_depth;
+    }
+
+    fn set_top_level_only(&mut self, top_level_only: bool) {
+        self.top_level_only = top_level_only;
+    }
+}
+
+/// The prefix of the log lines written by `sol_log_data`, which Anchor's
+/// `emit!` uses to publish events.
+pub const PROGRAM_DATA_LOG_PREFIX: &str = "Program data: ";
+
+/// An event payload found in the `Program data:` lines of transaction logs.
+///
+/// # Fields
+///
+/// - `program_id`: The program that was executing when the line was logged.
+/// - `data`: The base64-decoded payload: the 8-byte event discriminator
+///   followed by the Borsh-serialized event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogEventPayload {
+    pub program_id: Pubkey,
+    pub data: Vec<u8>,
+}
+
+/// A `sol_log_data` entry found in the `Program data:` lines of transaction
+/// logs.
+///
+/// # Fields
+///
+/// - `program_id`: The program that was executing when the line was logged.
+/// - `fields`: The base64-decoded fields of the line, in the order they were
+///   passed to `sol_log_data`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogDataEntry {
+    pub program_id: Pubkey,
+    pub fields: Vec<Vec<u8>>,
+}
+
+/// Extracts the `Program data:` payloads from transaction log messages.
+///
+/// A `Program data:` line with several base64 fields yields their
+/// concatenation. See [`extract_log_data`] for how lines are attributed to
+/// programs.
+#[cfg(feature = "std")]
+pub fn extract_log_event_payloads(log_messages: &[String]) -> Vec<LogEventPayload> {
+    extract_log_data(log_messages)
+        .into_iter()
+        .map(|entry| LogEventPayload {
+            program_id: entry.program_id,
+            data: entry.fields.concat(),
+        })
+        .collect()
+}
+
+/// Extracts the `sol_log_data` entries from transaction log messages.
+///
+/// The emitting program of each line is tracked with the invocation stack
+/// described by the `Program <id> invoke [n]`, `Program <id> success` and
+/// `Program <id> failed` lines, so data logged by a CPI is attributed to the
+/// invoked program rather than its caller. Lines that cannot be decoded are
+/// skipped, and scanning stops at `Log truncated` since the stack can no
+/// longer be followed reliably.
+#[cfg(feature = "std")]
+pub fn extract_log_data(log_messages: &[String]) -> Vec<LogDataEntry> {
+    ProgramLogs::new(log_messages)
+        .filter_map(|log| {
+            let ProgramLog::Data { program_id, fields } = log else {
+                return None;
+            };
+
+            match fields
+                .split_whitespace()
+                .map(|field| STANDARD.decode(field))
+                .collect::<Result<Vec<_>, _>>()
+            {
+                Ok(fields) => Some(LogDataEntry { program_id, fields }),
+                Err(err) => {
+                    log::debug!("Failed to decode program data {:?}: {}", fields, err);
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+/// Decodes events published in transaction logs with a set of registered
+/// `EventDecoder`s.
+///
+/// Each payload found by [`extract_log_event_payloads`] is passed to the
+/// decoders in registration order, and the first decoder that recognizes its
+/// discriminator produces the event. Payloads that no decoder recognizes are
+/// ignored.
+///
+/// # Example
+///
+/// ```ignore
+/// let router = LogEventRouter::new()
+///     .decoder(MyEventDecoder)
+///     .decoder(MyOtherEventDecoder);
+///
+/// let events = router.decode(&transaction_metadata);
+/// ```
+#[cfg(feature = "std")]
+pub struct LogEventRouter<T> {
+    pub decoders: Vec<Box<dyn for<'a> EventDecoder<'a, EventType = T> + Send + Sync + 'static>>,
+}
+
+#[cfg(feature = "std")]
+impl<T> Default for LogEventRouter<T> {
+    fn default() -> Self {
+        Self {
+            decoders: Vec::new(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> LogEventRouter<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers an `EventDecoder` with the router.
+    pub fn decoder(
+        mut self,
+        decoder: impl for<'a> EventDecoder<'a, EventType = T> + Send + Sync + 'static,
+    ) -> Self {
+        self.decoders.push(Box::new(decoder));
+        self
+    }
+
+    /// Decodes the events in the given log messages, in log order.
+    pub fn decode_logs(&self, log_messages: &[String]) -> Vec<DecodedEvent<T>> {
+        extract_log_event_payloads(log_messages)
+            .iter()
+            .filter_map(|payload| {
+                self.decoders
+                    .iter()
+                    .find_map(|decoder| decoder.decode_event(&payload.program_id, &payload.data))
+            })
+            .collect()
+    }
+
+    /// Decodes the events in the log messages of a transaction, in log order.
+    pub fn decode(&self, transaction_metadata: &TransactionMetadata) -> Vec<DecodedEvent<T>> {
+        transaction_metadata
+            .meta
+            .log_messages
+            .as_deref()
+            .map(|log_messages| self.decode_logs(log_messages))
+            .unwrap_or_default()
+    }
+}
+
+/// A decoded `sol_log_data` entry containing the emitting program ID, the
+/// discriminator and the decoded data.
+///
+/// # Fields
+///
+/// - `program_id`: The program that logged the entry.
+/// - `discriminator`: The first field of the entry, which selected the decoder.
+/// - `data`: The decoded entry, of type `T`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DecodedLogData<T> {
+    pub program_id: Pubkey,
+    pub discriminator: Vec<u8>,
+    pub data: T,
+}
+
+/// A trait for decoding the fields of a `sol_log_data` entry.
+///
+/// Programs that call `sol_log_data` directly log each field separately, and
+/// commonly use the first field to tell entries apart. A `LogDataDecoder` is
+/// registered for one such first field with [`LogDataRouter::decoder`], and
+/// receives the remaining fields. Implementations should return `None` for
+/// entries they cannot decode.
+///
+/// # Type Parameters
+///
+/// - `LogDataType`: The type into which the fields will be decoded.
+pub trait LogDataDecoder<'a> {
+    type LogDataType;
+
+    fn decode_log_data(
+        &self,
+        program_id: &Pubkey,
+        fields: &'a [Vec<u8>],
+    ) -> Option<Self::LogDataType>;
+}
+
+/// Decodes the `sol_log_data` entries of transaction logs with decoders keyed
+/// by the first field of the entry.
+///
+/// Each entry found by [`extract_log_data`] is dispatched on its first field
+/// to the decoder registered for it, which receives the remaining fields.
+/// Entries without fields, or whose first field has no decoder, are ignored.
+///
+/// # Example
+///
+/// ```ignore
+/// let router = LogDataRouter::new()
+///     .decoder(b"swap", SwapLogDecoder)
+///     .decoder(b"deposit", DepositLogDecoder);
+///
+/// let entries = router.decode(&transaction_metadata);
+/// ```
+#[cfg(feature = "std")]
+pub struct LogDataRouter<T> {
+    pub decoders: HashMap<Vec<u8>, BoxedLogDataDecoder<T>>,
+}
+
+#[cfg(feature = "std")]
+type BoxedLogDataDecoder<T> = Box<dyn for<'a> LogDataDecoder<'a, LogDataType = T> + Send + Sync>;
+
+#[cfg(feature = "std")]
+impl<T> Default for LogDataRouter<T> {
+    fn default() -> Self {
+        Self {
+            decoders: HashMap::new(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> LogDataRouter<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a `LogDataDecoder` for the entries whose first field is
+    /// `discriminator`, replacing any decoder registered for it before.
+    pub fn decoder(
+        mut self,
+        discriminator: impl Into<Vec<u8>>,
+        decoder: impl for<'a> LogDataDecoder<'a, LogDataType = T> + Send + Sync + 'static,
+    ) -> Self {
+        self.decoders
+            .insert(discriminator.into(), Box::new(decoder));
+        self
+    }
+
+    /// Decodes the `sol_log_data` entries in the given log messages, in log
+    /// order.
+    pub fn decode_logs(&self, log_messages: &[String]) -> Vec<DecodedLogData<T>> {
+        extract_log_data(log_messages)
+            .iter()
+            .filter_map(|entry| {
+                let (discriminator, fields) = entry.fields.split_first()?;
+                let data = self
+                    .decoders
+                    .get(discriminator)?
+                    .decode_log_data(&entry.program_id, fields)?;
+
+                Some(DecodedLogData {
+                    program_id: entry.program_id,
+                    discriminator: discriminator.clone(),
+                    data,
+                })
+            })
+            .collect()
+    }
+
+    /// Decodes the `sol_log_data` entries in the log messages of a
+    /// transaction, in log order.
+    pub fn decode(&self, transaction_metadata: &TransactionMetadata) -> Vec<DecodedLogData<T>> {
+        transaction_metadata
+            .meta
+            .log_messages
+            .as_deref()
+            .map(|log_messages| self.decode_logs(log_messages))
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            borsh,
+            instruction::{NestedInstructions, DEFAULT_MAX_INSTRUCTION_DEPTH},
+            CarbonDeserialize,
+        },
+        solana_instruction::{AccountMeta, Instruction},
+        std::sync::Mutex,
+    };
+
+    #[derive(CarbonDeserialize, Debug, PartialEq, Clone)]
+    #[carbon(discriminator = "0xe445a52e51cb9a1d0102030405060708")]
+    struct TransferEvent {
+        amount: u64,
+    }
+
+    #[derive(CarbonDeserialize, Debug, PartialEq, Clone)]
+    #[carbon(discriminator = "0xe445a52e51cb9a1d0807060504030201")]
+    struct SwapEvent {
+        amount_in: u64,
+        amount_out: u64,
+    }
+
+    #[derive(Debug, PartialEq, Clone)]
+    enum TestEvent {
+        Transfer(TransferEvent),
+        Swap(SwapEvent),
+    }
+
+    struct TestEventDecoder;
+
+    impl EventDecoder<'_> for TestEventDecoder {
+        type EventType = TestEvent;
+
+        fn decode_event(
+            &self,
+            program_id: &Pubkey,
+            data: &[u8],
+        ) -> Option<DecodedEvent<Self::EventType>> {
+            crate::try_decode_events!(program_id, data, TestEvent::Transfer => TransferEvent)
+        }
+    }
+
+    struct SwapEventDecoder;
+
+    impl EventDecoder<'_> for SwapEventDecoder {
+        type EventType = TestEvent;
+
+        fn decode_event(
+            &self,
+            program_id: &Pubkey,
+            data: &[u8],
+        ) -> Option<DecodedEvent<Self::EventType>> {
+            crate::try_decode_events!(program_id, data, TestEvent::Swap => SwapEvent)
+        }
+    }
+
+    type RecordedEvents = Arc<Mutex<Vec<(u32, DecodedEvent<TestEvent>)>>>;
+
+    struct RecordingProcessor(RecordedEvents);
+
+    #[async_trait]
+    impl Processor for RecordingProcessor {
+        type InputType = EventProcessorInputType<TestEvent>;
+
+        async fn process(
+            &mut self,
+            (metadata, event): Self::InputType,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            self.0.lock().unwrap().push((metadata.stack_height, event));
+            Ok(())
+        }
+    }
+
+    fn event_data(amount: u64) -> Vec<u8> {
+        [
+            ANCHOR_EVENT_IX_TAG.to_vec(),
+            vec![1, 2, 3, 4, 5, 6, 7, 8],
+            amount.to_le_bytes().to_vec(),
+        ]
+        .concat()
+    }
+
+    fn nested(
+        program_id: Pubkey,
+        stack_height: u32,
+        data: Vec<u8>,
+        inner_instructions: Vec<NestedInstruction>,
+    ) -> NestedInstruction {
+        NestedInstruction {
+            metadata: InstructionMetadata {
+                transaction_metadata: TransactionMetadata::default(),
+                stack_height,
+                index: 0,
+                accounts_resolved: true,
+                compute_units: None,
+                absolute_path: vec![],
+                transaction_context: None,
+            },
+            instruction: Instruction {
+                program_id,
+                accounts: vec![AccountMeta::new_readonly(Pubkey::new_unique(), true)],
+                data,
+            },
+            inner_instructions: NestedInstructions(inner_instructions),
+        }
+    }
+
+    #[test]
+    fn test_try_decode_anchor_event() {
+        let data = event_data(42);
+        let payload = anchor_event_payload(&data).expect("anchor event tag");
+
+        assert_eq!(
+            try_decode_anchor_event::<TransferEvent>(payload),
+            Some(TransferEvent { amount: 42 })
+        );
+        assert_eq!(try_decode_anchor_event::<TransferEvent>(&data), None);
+        assert_eq!(anchor_event_payload(&[1, 2, 3]), None);
+    }
+
+    #[test]
+    fn test_event_decoder_exposes_event_discriminator() {
+        let program_id = Pubkey::new_unique();
+        let data = event_data(42);
+
+        let decoded = TestEventDecoder
+            .decode_event(&program_id, anchor_event_payload(&data).unwrap())
+            .expect("decode event");
+
+        assert_eq!(decoded.program_id, program_id);
+        assert_eq!(decoded.discriminator, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(
+            decoded.data,
+            TestEvent::Transfer(TransferEvent { amount: 42 })
+        );
+    }
+
+    #[test]
+    fn test_event_pipe_decodes_self_cpi_events() {
+        let program_id = Pubkey::new_unique();
+        let other_program_id = Pubkey::new_unique();
+        let instruction = nested(
+            program_id,
+            1,
+            vec![0; 8],
+            vec![
+                nested(program_id, 2, event_data(5), vec![]),
+                // Same payload, but not a self-CPI.
+                nested(other_program_id, 2, event_data(6), vec![]),
+                nested(
+                    other_program_id,
+                    2,
+                    vec![0; 8],
+                    vec![nested(other_program_id, 3, event_data(7), vec![])],
+                ),
+            ],
+        );
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let mut pipe = EventPipe {
+            decoder: Arc::new(TestEventDecoder),
+            processor: Box::new(RecordingProcessor(events.clone())),
+            max_depth: DEFAULT_MAX_INSTRUCTION_DEPTH,
+            top_level_only: false,
+        };
+        let metrics = Arc::new(MetricsCollection::new(vec![]));
+
+        futures::executor::block_on(pipe.run(&instruction, metrics)).unwrap();
+
+        let events = events.lock().unwrap();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].0, 2);
+        assert_eq!(events[0].1.program_id, program_id);
+        assert_eq!(
+            events[0].1.data,
+            TestEvent::Transfer(TransferEvent { amount: 5 })
+        );
+        assert_eq!(events[1].0, 3);
+        assert_eq!(events[1].1.program_id, other_program_id);
+        assert_eq!(
+            events[1].1.data,
+            TestEvent::Transfer(TransferEvent { amount: 7 })
+        );
+    }
+
+    #[test]
+    fn test_event_pipe_stops_at_max_depth_and_top_level() {
+        let program_id = Pubkey::new_unique();
+        let other_program_id = Pubkey::new_unique();
+        let instruction = nested(
+            program_id,
+            1,
+            vec![0; 8],
+            vec![
+                nested(program_id, 2, event_data(5), vec![]),
+                nested(
+                    other_program_id,
+                    2,
+                    vec![0; 8],
+                    vec![nested(other_program_id, 3, event_data(7), vec![])],
+                ),
+            ],
+        );
+        let metrics = Arc::new(MetricsCollection::new(vec![]));
+        let run = |max_depth: usize, top_level_only: bool| {
+            let events = Arc::new(Mutex::new(Vec::new()));
+            let mut pipe = EventPipe {
+                decoder: Arc::new(TestEventDecoder),
+                processor: Box::new(RecordingProcessor(events.clone())),
+                max_depth,
+                top_level_only,
+            };
+            futures::executor::block_on(pipe.run(&instruction, metrics.clone())).unwrap();
+            let stack_heights: Vec<u32> =
+                events.lock().unwrap().iter().map(|event| event.0).collect();
+            stack_heights
+        };
+
+        assert_eq!(run(DEFAULT_MAX_INSTRUCTION_DEPTH, false), vec![2, 3]);
+        assert_eq!(run(2, false), vec![2]);
+        assert_eq!(run(DEFAULT_MAX_INSTRUCTION_DEPTH, true), vec![2]);
+        assert_eq!(run(1, false), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn test_extract_log_event_payloads_tracks_invocation_stack() {
+        let outer_program_id = Pubkey::new_unique();
+        let inner_program_id = Pubkey::new_unique();
+        let logs = vec![
+            "Program ComputeBudget111111111111111111111111111111 invoke [1]".to_string(),
+            "Program ComputeBudget111111111111111111111111111111 success".to_string(),
+            format!("Program {outer_program_id} invoke [1]"),
+            "Program log: Instruction: Swap".to_string(),
+            format!("Program {inner_program_id} invoke [2]"),
+            "Program log: Instruction: Transfer".to_string(),
+            "Program data: AQIDBAUGBwgFAAAAAAAAAA==".to_string(),
+            format!("Program {inner_program_id} consumed 4645 of 189614 compute units"),
+            format!("Program {inner_program_id} success"),
+            "Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA invoke [2]".to_string(),
+            "Program log: Instruction: Transfer".to_string(),
+            "Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA success".to_string(),
+            "Program data: CAcGBQQDAgEKAAAAAAAAAAQAAAAAAAAA".to_string(),
+            format!("Program {outer_program_id} consumed 20519 of 199850 compute units"),
+            format!("Program {outer_program_id} success"),
+        ];
+
+        let payloads = extract_log_event_payloads(&logs);
+
+        assert_eq!(
+            payloads,
+            vec![
+                LogEventPayload {
+                    program_id: inner_program_id,
+                    data: [vec![1, 2, 3, 4, 5, 6, 7, 8], 5u64.to_le_bytes().to_vec()].concat(),
+                },
+                LogEventPayload {
+                    program_id: outer_program_id,
+                    data: [
+                        vec![8, 7, 6, 5, 4, 3, 2, 1],
+                        10u64.to_le_bytes().to_vec(),
+                        4u64.to_le_bytes().to_vec(),
+                    ]
+                    .concat(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_log_event_router_decodes_transaction_logs() {
+        let outer_program_id = Pubkey::new_unique();
+        let inner_program_id = Pubkey::new_unique();
+        let mut transaction_metadata = TransactionMetadata::default();
+        transaction_metadata.meta.log_messages = Some(vec![
+            format!("Program {outer_program_id} invoke [1]"),
+            format!("Program {inner_program_id} invoke [2]"),
+            "Program data: AQIDBAUGBwgFAAAAAAAAAA==".to_string(),
+            // Not an event of any registered decoder.
+            "Program data: AAAAAAAAAAA=".to_string(),
+            format!("Program {inner_program_id} success"),
+            "Program data: CAcGBQQDAgEKAAAAAAAAAAQAAAAAAAAA".to_string(),
+            format!("Program {outer_program_id} success"),
+        ]);
+        let router = LogEventRouter::new()
+            .decoder(TestEventDecoder)
+            .decoder(SwapEventDecoder);
+
+        let events = router.decode(&transaction_metadata);
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].program_id, inner_program_id);
+        assert_eq!(events[0].discriminator, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(
+            events[0].data,
+            TestEvent::Transfer(TransferEvent { amount: 5 })
+        );
+        assert_eq!(events[1].program_id, outer_program_id);
+        assert_eq!(events[1].discriminator, vec![8, 7, 6, 5, 4, 3, 2, 1]);
+        assert_eq!(
+            events[1].data,
+            TestEvent::Swap(SwapEvent {
+                amount_in: 10,
+                amount_out: 4,
+            })
+        );
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct SwapLog {
+        amount_in: u64,
+        amount_out: u64,
+    }
+
+    struct SwapLogDecoder;
+
+    impl LogDataDecoder<'_> for SwapLogDecoder {
+        type LogDataType = SwapLog;
+
+        fn decode_log_data(&self, _program_id: &Pubkey, fields: &[Vec<u8>]) -> Option<SwapLog> {
+            let [amount_in, amount_out] = fields else {
+                return None;
+            };
+
+            Some(SwapLog {
+                amount_in: u64::from_le_bytes(amount_in.as_slice().try_into().ok()?),
+                amount_out: u64::from_le_bytes(amount_out.as_slice().try_into().ok()?),
+            })
+        }
+    }
+
+    #[test]
+    fn test_log_data_router_dispatches_on_the_first_field() {
+        // Arrange
+        let outer_program_id = Pubkey::new_unique();
+        let inner_program_id = Pubkey::new_unique();
+        let fields = |fields: &[&[u8]]| {
+            fields
+                .iter()
+                .map(|field| STANDARD.encode(field))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        let mut transaction_metadata = TransactionMetadata::default();
+        transaction_metadata.meta.log_messages = Some(vec![
+            format!("Program {outer_program_id} invoke [1]"),
+            format!("Program {inner_program_id} invoke [2]"),
+            format!(
+                "Program data: {}",
+                fields(&[b"swap", &10u64.to_le_bytes(), &4u64.to_le_bytes()])
+            ),
+            format!("Program {inner_program_id} success"),
+            // No decoder is registered for this discriminator.
+            format!("Program data: {}", fields(&[b"other", &1u64.to_le_bytes()])),
+            format!(
+                "Program data: {}",
+                fields(&[b"swap", &7u64.to_le_bytes(), &3u64.to_le_bytes()])
+            ),
+            format!("Program {outer_program_id} success"),
+        ]);
+        let router = LogDataRouter::new().decoder(*b"swap", SwapLogDecoder);
+
+        // Act
+        let entries = router.decode(&transaction_metadata);
+
+        // Assert
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].program_id, inner_program_id);
+        assert_eq!(entries[0].discriminator, b"swap".to_vec());
+        assert_eq!(
+            entries[0].data,
+            SwapLog {
+                amount_in: 10,
+                amount_out: 4,
+            }
+        );
+        assert_eq!(entries[1].program_id, outer_program_id);
+        assert_eq!(
+            entries[1].data,
+            SwapLog {
+                amount_in: 7,
+                amount_out: 3,
+            }
+        );
+    }
+}
@@ -0,0 +1,559 @@
+//! Decodes the instructions of Anchor programs at runtime, from their IDL.
+//!
+//! [`IdlInstructionDecoder`] is an alternative to generating a decoder crate
+//! with the CLI, for programs that are only looked at occasionally or whose
+//! IDL is only known at runtime. It parses an Anchor IDL, in the current
+//! format or the legacy one, and decodes the instructions of the program into
+//! a `serde_json::Value` keyed by instruction name:
+//!
+//! ```json
+//! { "deposit": { "amount": 1000, "memo": null } }
+//! ```
+//!
+//! Supported argument types are the integers from `u8` to `u128` and `i8` to
+//! `i128`, `f32`, `f64`, `bool`, `pubkey`, `string`, `bytes`, `Option`,
+//! `Vec`, arrays, and the structs and enums defined in the IDL. Enums are
+//! encoded like `serde` does by default: a variant without fields is its name,
+//! any other variant an object with its name as the only key. 128-bit integers
+//! are encoded as strings, since JSON numbers cannot represent them exactly.
+//!
+//! ```ignore
+//! let decoder = IdlInstructionDecoder::from_json(&std::fs::read_to_string("idl.json")?)?;
+//!
+//! if let Some(decoded) = decoder.try_decode_instruction(&instruction)? {
+//!     println!("{}", decoded.data);
+//! }
+//! ```
+
+use {
+    crate::{
+        error::{CarbonError, CarbonResult},
+        instruction::{DecodedInstruction, InstructionDecoder},
+    },
+    serde::Deserialize,
+    serde_json::{Map, Value},
+    solana_instruction::Instruction,
+    solana_pubkey::Pubkey,
+    std::{collections::HashMap, str::FromStr},
+};
+
+/// How deeply defined types may nest, which bounds the recursion on IDLs
+/// whose types refer to themselves.
+const MAX_TYPE_DEPTH: usize = 64;
+
+/// An `InstructionDecoder` for an Anchor program, built from its IDL.
+pub struct IdlInstructionDecoder {
+    program_id: [Pubkey; 1],
+    instructions: Vec<IdlInstruction>,
+    types: HashMap<String, IdlTypeDefinitionTy>,
+}
+
+impl IdlInstructionDecoder {
+    /// Parses `idl`, an Anchor IDL in JSON, for the program at the address it
+    /// declares.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `idl` is not a valid IDL, or declares no valid
+    /// program address. Legacy IDLs only declare one once deployed; use
+    /// `from_json_with_program_id` for the others.
+    pub fn from_json(idl: &str) -> CarbonResult<Self> {
+        let idl: Idl = serde_json::from_str(idl)?;
+        let address = idl
+            .address
+            .as_deref()
+            .or(idl
+                .metadata
+                .as_ref()
+                .and_then(|metadata| metadata.address.as_deref()))
+            .ok_or_else(|| CarbonError::Custom("IDL declares no program address".to_string()))?;
+        let program_id = Pubkey::from_str(address).map_err(|error| {
+            CarbonError::Custom(format!("invalid IDL program address {address}: {error}"))
+        })?;
+
+        Ok(Self::from_idl(idl, program_id))
+    }
+
+    /// Parses `idl`, an Anchor IDL in JSON, for the program at `program_id`,
+    /// regardless of the address the IDL declares.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `idl` is not a valid IDL.
+    pub fn from_json_with_program_id(idl: &str, program_id: Pubkey) -> CarbonResult<Self> {
+        let idl: Idl = serde_json::from_str(idl)?;
+
+        Ok(Self::from_idl(idl, program_id))
+    }
+
+    fn from_idl(idl: Idl, program_id: Pubkey) -> Self {
+        let instructions = idl
+            .instructions
+            .into_iter()
+            .map(|mut instruction| {
+                // Legacy IDLs leave out the discriminators, which Anchor
+                // derives from the instruction name.
+                instruction.discriminator.get_or_insert_with(|| {
+                    let preimage = format!("global:{}", to_snake_case(&instruction.name));
+                    solana_program::hash::hash(preimage.as_bytes()).to_bytes()[..8].to_vec()
+                });
+                instruction
+            })
+            .collect();
+        let types = idl
+            .types
+            .into_iter()
+            .map(|definition| (definition.name, definition.ty))
+            .collect();
+
+        Self {
+            program_id: [program_id],
+            instructions,
+            types,
+        }
+    }
+
+    /// Decodes `data` as a value of type `ty`, advancing it past the value.
+    fn read_value(&self, ty: &IdlType, data: &mut &[u8], depth: usize) -> Result<Value, String> {
+        if depth > MAX_TYPE_DEPTH {
+            return Err(format!("types nest deeper than {MAX_TYPE_DEPTH} levels"));
+        }
+
+        match ty {
+            IdlType::Primitive(name) => read_primitive(name, data),
+            IdlType::Option { option } => match take::<1>(data)? {
+                [0] => Ok(Value::Null),
+                [1] => self.read_value(option, data, depth + 1),
+                [tag] => Err(format!("invalid option tag {tag}")),
+            },
+            IdlType::Vec { vec } => {
+                let len = u32::from_le_bytes(take(data)?) as usize;
+                // Every element takes at least a byte, so this rejects lengths
+                // that could not possibly fit before looping over them.
+                if len > data.len() {
+                    return Err(format!("vector of {len} elements exceeds the data"));
+                }
+                (0..len)
+                    .map(|_| self.read_value(vec, data, depth + 1))
+                    .collect::<Result<_, _>>()
+                    .map(Value::Array)
+            }
+            IdlType::Array { array: (ty, len) } => (0..*len)
+                .map(|_| self.read_value(ty, data, depth + 1))
+                .collect::<Result<_, _>>()
+                .map(Value::Array),
+            IdlType::Defined { defined } => {
+                let name = defined.name();
+                match self.types.get(name) {
+                    Some(IdlTypeDefinitionTy::Struct { fields }) => {
+                        self.read_fields(fields, data, depth + 1)
+                    }
+                    Some(IdlTypeDefinitionTy::Enum { variants }) => {
+                        let [index] = take::<1>(data)?;
+                        let variant = variants.get(index as usize).ok_or_else(|| {
+                            format!("invalid variant index {index} of enum {name}")
+                        })?;
+                        match &variant.fields {
+                            None => Ok(Value::String(variant.name.clone())),
+                            Some(fields) => {
+                                let mut object = Map::new();
+                                object.insert(
+                                    variant.name.clone(),
+                                    self.read_fields(fields, data, depth + 1)?,
+                                );
+                                Ok(Value::Object(object))
+                            }
+                        }
+                    }
+                    Some(IdlTypeDefinitionTy::Unsupported) => {
+                        Err(format!("type {name} is of an unsupported kind"))
+                    }
+                    None => Err(format!("type {name} is not defined in the IDL")),
+                }
+            }
+        }
+    }
+
+    /// Decodes named fields into an object and tuple fields into an array.
+    fn read_fields(
+        &self,
+        fields: &IdlFields,
+        data: &mut &[u8],
+        depth: usize,
+    ) -> Result<Value, String> {
+        match fields {
+            IdlFields::Named(fields) => self.read_named_fields(fields, data, depth),
+            IdlFields::Tuple(types) => types
+                .iter()
+                .map(|ty| self.read_value(ty, data, depth))
+                .collect::<Result<_, _>>()
+                .map(Value::Array),
+        }
+    }
+
+    fn read_named_fields(
+        &self,
+        fields: &[IdlField],
+        data: &mut &[u8],
+        depth: usize,
+    ) -> Result<Value, String> {
+        fields
+            .iter()
+            .map(|field| Ok((field.name.clone(), self.read_value(&field.ty, data, depth)?)))
+            .collect::<Result<_, String>>()
+            .map(Value::Object)
+    }
+}
+
+impl InstructionDecoder<'_> for IdlInstructionDecoder {
+    type InstructionType = Value;
+
+    fn try_decode_instruction(
+        &self,
+        instruction: &Instruction,
+    ) -> CarbonResult<Option<DecodedInstruction<Self::InstructionType>>> {
+        if instruction.program_id != self.program_id[0] {
+            return Ok(None);
+        }
+
+        let Some((idl_instruction, discriminator)) =
+            self.instructions.iter().find_map(|idl_instruction| {
+                let discriminator = idl_instruction.discriminator.as_deref()?;
+                instruction
+                    .data
+                    .starts_with(discriminator)
+                    .then_some((idl_instruction, discriminator))
+            })
+        else {
+            return Ok(None);
+        };
+
+        let mut data = &instruction.data[discriminator.len()..];
+        let args = self
+            .read_named_fields(&idl_instruction.args, &mut data, 0)
+            .map_err(|reason| {
+                CarbonError::FailedToDeserialize(format!(
+                    "instruction {}: {}",
+                    idl_instruction.name, reason
+                ))
+            })?;
+
+        let mut decoded = Map::new();
+        decoded.insert(idl_instruction.name.clone(), args);
+
+        Ok(Some(DecodedInstruction {
+            program_id: instruction.program_id,
+            data: Value::Object(decoded),
+            accounts: instruction.accounts.clone(),
+            discriminator: discriminator.to_vec(),
+            raw_len: instruction.data.len(),
+            num_accounts: instruction.accounts.len(),
+            raw_data: instruction.data.clone(),
+        }))
+    }
+
+    fn program_ids(&self) -> &[Pubkey] {
+        &self.program_id
+    }
+}
+
+/// Takes the next `N` bytes of `data`.
+fn take<const N: usize>(data: &mut &[u8]) -> Result<[u8; N], String> {
+    let Some((bytes, rest)) = data.split_first_chunk::<N>() else {
+        return Err(format!("expected {N} more bytes, found {}", data.len()));
+    };
+    *data = rest;
+    Ok(*bytes)
+}
+
+/// Takes a length-prefixed byte string from `data`.
+fn take_bytes<'a>(data: &mut &'a [u8]) -> Result<&'a [u8], String> {
+    let len = u32::from_le_bytes(take(data)?) as usize;
+    if len > data.len() {
+        return Err(format!("expected {len} more bytes, found {}", data.len()));
+    }
+    let (bytes, rest) = data.split_at(len);
+    *data = rest;
+    Ok(bytes)
+}
+
+fn read_primitive(name: &str, data: &mut &[u8]) -> Result<Value, String> {
+    Ok(match name {
+        "bool" => match take::<1>(data)? {
+            [0] => Value::Bool(false),
+            [1] => Value::Bool(true),
+            [byte] => return Err(format!("invalid bool {byte}")),
+        },
+        "u8" => u8::from_le_bytes(take(data)?).into(),
+        "i8" => i8::from_le_bytes(take(data)?).into(),
+        "u16" => u16::from_le_bytes(take(data)?).into(),
+        "i16" => i16::from_le_bytes(take(data)?).into(),
+        "u32" => u32::from_le_bytes(take(data)?).into(),
+        "i32" => i32::from_le_bytes(take(data)?).into(),
+        "u64" => u64::from_le_bytes(take(data)?).into(),
+        "i64" => i64::from_le_bytes(take(data)?).into(),
+        "u128" => u128::from_le_bytes(take(data)?).to_string().into(),
+        "i128" => i128::from_le_bytes(take(data)?).to_string().into(),
+        "f32" => f32::from_le_bytes(take(data)?).into(),
+        "f64" => f64::from_le_bytes(take(data)?).into(),
+        "pubkey" | "publicKey" => Pubkey::new_from_array(take(data)?).to_string().into(),
+        "string" => std::str::from_utf8(take_bytes(data)?)
+            .map_err(|error| format!("invalid string: {error}"))?
+            .into(),
+        "bytes" => take_bytes(data)?.to_vec().into(),
+        _ => return Err(format!("unsupported type {name}")),
+    })
+}
+
+/// Converts a legacy IDL name, in camel case, to the snake case Anchor hashes
+/// into discriminators.
+fn to_snake_case(name: &str) -> String {
+    let mut snake_case = String::with_capacity(name.len() + 4);
+    for (index, character) in name.chars().enumerate() {
+        if character.is_ascii_uppercase() {
+            if index > 0 {
+                snake_case.push('_');
+            }
+            snake_case.push(character.to_ascii_lowercase());
+        } else {
+            snake_case.push(character);
+        }
+    }
+    snake_case
+}
+
+#[derive(Deserialize)]
+struct Idl {
+    #[serde(default)]
+    address: Option<String>,
+    #[serde(default)]
+    metadata: Option<IdlMetadata>,
+    #[serde(default)]
+    instructions: Vec<IdlInstruction>,
+    #[serde(default)]
+    types: Vec<IdlTypeDefinition>,
+}
+
+#[derive(Deserialize)]
+struct IdlMetadata {
+    #[serde(default)]
+    address: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct IdlInstruction {
+    name: String,
+    #[serde(default)]
+    discriminator: Option<Vec<u8>>,
+    #[serde(default)]
+    args: Vec<IdlField>,
+}
+
+#[derive(Deserialize)]
+struct IdlField {
+    name: String,
+    #[serde(rename = "type")]
+    ty: IdlType,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum IdlType {
+    Primitive(String),
+    Option { option: Box<IdlType> },
+    Vec { vec: Box<IdlType> },
+    Array { array: (Box<IdlType>, usize) },
+    Defined { defined: IdlDefined },
+}
+
+/// A reference to a defined type: its name in legacy IDLs, an object holding
+/// it in the current format.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum IdlDefined {
+    Name(String),
+    Object { name: String },
+}
+
+impl IdlDefined {
+    fn name(&self) -> &str {
+        match self {
+            IdlDefined::Name(name) | IdlDefined::Object { name } => name,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct IdlTypeDefinition {
+    name: String,
+    #[serde(rename = "type")]
+    ty: IdlTypeDefinitionTy,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+enum IdlTypeDefinitionTy {
+    Struct {
+        #[serde(default)]
+        fields: IdlFields,
+    },
+    Enum {
+        variants: Vec<IdlEnumVariant>,
+    },
+    /// Type aliases and other kinds, which only fail to decode when used.
+    #[serde(other)]
+    Unsupported,
+}
+
+#[derive(Deserialize)]
+struct IdlEnumVariant {
+    name: String,
+    #[serde(default)]
+    fields: Option<IdlFields>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum IdlFields {
+    Named(Vec<IdlField>),
+    Tuple(Vec<IdlType>),
+}
+
+impl Default for IdlFields {
+    fn default() -> Self {
+        IdlFields::Named(Vec::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, serde_json::json, solana_instruction::AccountMeta};
+
+    const IDL: &str = r#"{
+        "address": "Vau1t11111111111111111111111111111111111111",
+        "metadata": { "name": "vault", "version": "0.1.0", "spec": "0.1.0" },
+        "instructions": [
+            {
+                "name": "deposit",
+                "discriminator": [242, 35, 198, 137, 82, 225, 242, 182],
+                "accounts": [{ "name": "vault", "writable": true }],
+                "args": [
+                    { "name": "amount", "type": "u64" },
+                    { "name": "shares", "type": "u128" },
+                    { "name": "memo", "type": { "option": "string" } },
+                    { "name": "owner", "type": "pubkey" },
+                    { "name": "splits", "type": { "vec": { "defined": { "name": "Split" } } } },
+                    { "name": "kind", "type": { "defined": { "name": "DepositKind" } } }
+                ]
+            }
+        ],
+        "types": [
+            {
+                "name": "Split",
+                "type": {
+                    "kind": "struct",
+                    "fields": [
+                        { "name": "recipient", "type": "pubkey" },
+                        { "name": "bps", "type": "u16" },
+                        { "name": "locked", "type": "bool" }
+                    ]
+                }
+            },
+            {
+                "name": "DepositKind",
+                "type": {
+                    "kind": "enum",
+                    "variants": [
+                        { "name": "Instant" },
+                        { "name": "Vested", "fields": [{ "name": "until", "type": "i64" }] }
+                    ]
+                }
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn test_idl_decoder_decodes_instruction_to_json() {
+        // Arrange
+        let decoder = IdlInstructionDecoder::from_json(IDL).unwrap();
+        let program_id = Pubkey::from_str("Vau1t11111111111111111111111111111111111111").unwrap();
+        let owner = Pubkey::new_unique();
+        let recipient = Pubkey::new_unique();
+        let mut data = vec![242, 35, 198, 137, 82, 225, 242, 182];
+        data.extend_from_slice(&1_000u64.to_le_bytes());
+        data.extend_from_slice(&(u128::from(u64::MAX) + 1).to_le_bytes());
+        data.extend_from_slice(&[1, 2, 0, 0, 0]);
+        data.extend_from_slice(b"hi");
+        data.extend_from_slice(owner.as_ref());
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(recipient.as_ref());
+        data.extend_from_slice(&2_500u16.to_le_bytes());
+        data.push(1);
+        data.push(1);
+        data.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+        let instruction = Instruction {
+            program_id,
+            accounts: vec![AccountMeta::new(Pubkey::new_unique(), false)],
+            data,
+        };
+
+        // Act
+        let decoded = decoder
+            .try_decode_instruction(&instruction)
+            .unwrap()
+            .expect("deposit instruction");
+
+        // Assert
+        assert_eq!(decoder.program_ids(), &[program_id]);
+        assert_eq!(
+            decoded.discriminator,
+            vec![242, 35, 198, 137, 82, 225, 242, 182]
+        );
+        assert_eq!(
+            decoded.data,
+            json!({
+                "deposit": {
+                    "amount": 1000,
+                    "shares": "18446744073709551616",
+                    "memo": "hi",
+                    "owner": owner.to_string(),
+                    "splits": [
+                        { "recipient": recipient.to_string(), "bps": 2500, "locked": true }
+                    ],
+                    "kind": { "Vested": { "until": 1_700_000_000 } }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_idl_decoder_derives_legacy_discriminators() {
+        // Arrange
+        let legacy_idl = r#"{
+            "version": "0.1.0",
+            "name": "vault",
+            "instructions": [
+                { "name": "closeVault", "accounts": [], "args": [] }
+            ]
+        }"#;
+        let program_id = Pubkey::new_unique();
+        let decoder =
+            IdlInstructionDecoder::from_json_with_program_id(legacy_idl, program_id).unwrap();
+        let discriminator =
+            solana_program::hash::hash(b"global:close_vault").to_bytes()[..8].to_vec();
+        let instruction = |data: Vec<u8>| Instruction {
+            program_id,
+            accounts: vec![],
+            data,
+        };
+
+        // Act
+        let decoded = decoder.try_decode_instruction(&instruction(discriminator.clone()));
+        let unknown = decoder.try_decode_instruction(&instruction(vec![1, 2, 3]));
+
+        // Assert
+        assert_eq!(decoded.unwrap().unwrap().data, json!({ "closeVault": {} }));
+        assert!(unknown.unwrap().is_none());
+        assert!(IdlInstructionDecoder::from_json(legacy_idl).is_err());
+    }
+}
@@ -20,18 +20,29 @@
 //! and facilitating hierarchical processing.
 
 use {
-    crate::{
-        error::CarbonResult, metrics::MetricsCollection, processor::Processor,
-        transaction::TransactionMetadata,
-    },
-    async_trait::async_trait,
+    crate::error::CarbonResult,
+    alloc::vec::Vec,
     serde::{Deserialize, Serialize},
     solana_instruction::AccountMeta,
     solana_pubkey::Pubkey,
+};
+#[cfg(feature = "std")]
+use {
+    crate::{
+        dead_letter::DeadLetterSink,
+        error::CarbonError,
+        metrics::MetricsCollection,
+        processor::Processor,
+        transaction::{TransactionContext, TransactionMetadata},
+    },
+    async_trait::async_trait,
     std::{
-        ops::{Deref, DerefMut},
-        sync::Arc,
+        collections::{HashMap, HashSet},
+        ops::{Bound, Deref, DerefMut, RangeBounds},
+        sync::{Arc, Mutex, PoisonError, RwLock},
+        time::Instant,
     },
+    tracing::Instrument,
 };
 
 /// Metadata associated with a specific instruction, including transaction-level
@@ -47,18 +58,91 @@ use {
 ///   transaction.
 /// - `stack_height`: Represents the instruction's depth within the stack, where
 ///   1 is the root level.
-/// - `index`: The index of the instruction in the transaction. The index is
-///   relative within stack height and is 1-based. Note that the inner instruction indexes are grouped into one vector,
-///   so different inner instructions that have different stack heights may have continuous indexes.
+/// - `index`: The zero-based index of the top-level instruction in the
+///   transaction message. Inner instructions carry the index of the top-level
+///   instruction they were invoked by, so the index does not tell a top-level
+///   instruction from an inner one; only `stack_height` does.
+/// - `accounts_resolved`: Whether every account referenced by the instruction
+///   could be resolved. This is `false` for v0 transactions whose lookup table
+///   addresses were not provided by the datasource; the unresolved accounts
+///   keep their position in the account list but carry a default public key.
+/// - `compute_units`: The compute units consumed by the instruction, including
+///   the instructions it invoked, as reported in the transaction logs. `None`
+///   when the logs are missing or truncated, and for builtin programs, which
+///   do not report their consumption.
+/// - `absolute_path`: The position of the instruction in the transaction's
+///   instruction tree, from the root down: the position of its top-level
+///   instruction, followed by its position among the instructions invoked by
+///   each instruction in its call chain. Every prefix of the path is the path
+///   of an ancestor, which [`NestedInstructions::ancestors`] resolves. The path
+///   is filled in when the instructions are nested, and is empty before.
+/// - `transaction_context`: The `TransactionContext` shared by the instructions
+///   of the transaction, set by the pipeline when it has a
+///   `TransactionContextFinalizer` and `None` otherwise.
 ///
-
+/// Unlike `index`, the path tells instructions invoked by the same top-level
+/// instruction apart; for a tree nested from a whole transaction, its first
+/// element equals `index`. Its length equals `stack_height`, except where
+/// the reported stack height skips a level, in which case the instruction
+/// hangs off its deepest known ancestor and the path is shorter.
+///
+/// Two `InstructionMetadata` are equal only if their `transaction_metadata`
+/// is equal as well, so that instructions of different transactions are never
+/// mistaken for each other. The `transaction_context` is not compared: it
+/// holds what processors stored while the transaction was processed rather
+/// than a property of the instruction.
+#[cfg(feature = "std")]
 #[derive(Debug, Clone)]
 pub struct InstructionMetadata {
     pub transaction_metadata: TransactionMetadata,
     pub stack_height: u32,
     pub index: u32,
+    pub accounts_resolved: bool,
+    pub compute_units: Option<u64>,
+    pub absolute_path: Vec<u32>,
+    pub transaction_context: Option<TransactionContext>,
+}
+
+#[cfg(feature = "std")]
+impl PartialEq for InstructionMetadata {
+    fn eq(&self, other: &Self) -> bool {
+        let Self {
+            transaction_metadata,
+            stack_height,
+            index,
+            accounts_resolved,
+            compute_units,
+            absolute_path,
+            transaction_context: _,
+        } = self;
+
+        *transaction_metadata == other.transaction_metadata
+            && *stack_height == other.stack_height
+            && *index == other.index
+            && *accounts_resolved == other.accounts_resolved
+            && *compute_units == other.compute_units
+            && *absolute_path == other.absolute_path
+    }
 }
 
+#[cfg(feature = "std")]
+impl InstructionMetadata {
+    /// Returns the slot of the transaction containing the instruction.
+    pub fn slot(&self) -> u64 {
+        self.transaction_metadata.slot
+    }
+
+    /// Returns the block time of the transaction containing the instruction,
+    /// as a Unix timestamp in seconds.
+    ///
+    /// This is `None` when the datasource does not know it, which is usually
+    /// the case for transactions streamed before their block is confirmed.
+    pub fn block_time(&self) -> Option<i64> {
+        self.transaction_metadata.block_time
+    }
+}
+
+#[cfg(feature = "std")]
 pub type InstructionsWithMetadata = Vec<(InstructionMetadata, solana_instruction::Instruction)>;
 
 /// A decoded instruction containing program ID, data, and associated accounts.
@@ -77,12 +161,27 @@ pub type InstructionsWithMetadata = Vec<(InstructionMetadata, solana_instruction
 /// - `data`: The decoded data payload for the instruction, of type `T`.
 /// - `accounts`: A vector of `AccountMeta`, representing the accounts involved
 ///   in the instruction.
+/// - `discriminator`: The discriminator bytes that identified the instruction
+///   type. For types with several discriminators, this is the one the data
+///   matched. Empty if the decoder does not use a discriminator.
+/// - `raw_data`: The raw instruction data, including the discriminator.
+/// - `raw_len`: The length in bytes of the raw instruction data, set at decode
+///   time even by decoders that do not keep `raw_data`.
+/// - `num_accounts`: The number of accounts passed to the instruction.
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct DecodedInstruction<T> {
     pub program_id: Pubkey,
     pub data: T,
     pub accounts: Vec<AccountMeta>,
+    #[serde(default)]
+    pub discriminator: Vec<u8>,
+    #[serde(default)]
+    pub raw_data: Vec<u8>,
+    #[serde(default)]
+    pub raw_len: usize,
+    #[serde(default)]
+    pub num_accounts: usize,
 }
 
 /// A trait for decoding Solana instructions into a structured type.
@@ -96,28 +195,294 @@ pub struct DecodedInstruction<T> {
 /// - `InstructionType`: The type into which the instruction data will be
 ///   decoded.
 ///
-/// # Required Methods
+/// # Methods
 ///
+/// - `try_decode_instruction`: Decodes a raw Solana `Instruction`, returning
+///   `Ok(None)` if the instruction does not belong to this decoder and an error
+///   if it does but its data is malformed.
 /// - `decode_instruction`: Decodes a raw Solana `Instruction` into a
-///   `DecodedInstruction`.
+///   `DecodedInstruction`, returning `None` if the instruction cannot be
+///   decoded, whether or not it belongs to this decoder.
+/// - `decoder_name`: A short, stable name for the decoder, used to label
+///   per-decoder metrics. Defaults to the unqualified type name.
+/// - `supports_slot` and `try_decode_instruction_at_slot`: Let decoders whose
+///   data layout changed over the program's history decode instructions with
+///   the layout of the slot they were executed at. See
+///   `VersionedInstructionDecoder`.
+///
+/// Implementors provide `try_decode_instruction`, and `decode_instruction` is
+/// derived from it. Reporting malformed data as an error lets the pipeline
+/// surface corrupt data and schema drift instead of silently skipping the
+/// instruction; a decoder that cannot tell the two apart returns `Ok(None)`.
 pub trait InstructionDecoder<'a> {
     type InstructionType;
 
+    fn try_decode_instruction(
+        &self,
+        instruction: &'a solana_instruction::Instruction,
+    ) -> CarbonResult<Option<DecodedInstruction<Self::InstructionType>>>;
+
     fn decode_instruction(
         &self,
         instruction: &'a solana_instruction::Instruction,
-    ) -> Option<DecodedInstruction<Self::InstructionType>>;
+    ) -> Option<DecodedInstruction<Self::InstructionType>> {
+        self.try_decode_instruction(instruction).ok().flatten()
+    }
+
+    /// Returns the name of the decoder, used in error messages, the `decoder`
+    /// label of per-decoder metrics and `Pipeline::registered_decoders`.
+    ///
+    /// Defaults to the unqualified type name. Decoders generated by the CLI
+    /// return the program name from the IDL.
+    fn decoder_name(&self) -> &'static str {
+        short_type_name(core::any::type_name::<Self>())
+    }
+
+    /// Returns the programs whose instructions the decoder understands.
+    ///
+    /// `InstructionPipe` skips the decoder for instructions of any other
+    /// program without attempting to decode them. An empty slice, the default,
+    /// matches every program.
+    fn program_ids(&self) -> &[Pubkey] {
+        &[]
+    }
+
+    /// Returns the fewest accounts an instruction must have for the decoder
+    /// to recognize it.
+    ///
+    /// `InstructionPipe` skips the decoder for instructions with fewer
+    /// accounts without attempting to decode them. Defaults to 0, which
+    /// accepts every instruction.
+    fn min_accounts(&self) -> usize {
+        0
+    }
+
+    /// Returns whether `data` starts with the discriminator of one of the
+    /// decoder's instruction types, without deserializing it.
+    ///
+    /// This lets a router classify instructions cheaply, but a match does not
+    /// guarantee that `try_decode_instruction` succeeds. Defaults to `true`,
+    /// since a decoder that does not implement it cannot rule any data out.
+    fn matches_discriminator(&self, _data: &[u8]) -> bool {
+        true
+    }
+
+    /// Returns whether the decoder knows the data layout of instructions
+    /// executed at `slot`.
+    ///
+    /// `InstructionPipe` skips the decoder for instructions of other slots
+    /// without attempting to decode them. Defaults to `true`.
+    fn supports_slot(&self, _slot: u64) -> bool {
+        true
+    }
+
+    /// Decodes an instruction executed at `slot`, which `InstructionPipe`
+    /// takes from the instruction's metadata.
+    ///
+    /// Defaults to `try_decode_instruction`, ignoring the slot.
+    fn try_decode_instruction_at_slot(
+        &self,
+        instruction: &'a solana_instruction::Instruction,
+        _slot: u64,
+    ) -> CarbonResult<Option<DecodedInstruction<Self::InstructionType>>> {
+        self.try_decode_instruction(instruction)
+    }
+}
+
+/// An `InstructionDecoder` decoding the data layouts a program used over its
+/// history, each during a range of slots.
+///
+/// When a program upgrade changes the layout of its instructions, historical
+/// transactions still need the old layout. Register a decoder per layout with
+/// the slots it was active in, and `try_decode_instruction_at_slot` picks the
+/// layout whose range contains the instruction's slot. An instruction outside
+/// every range is not decoded, which `InstructionPipe` counts in the
+/// `instructions_outside_slot_ranges` metric. If ranges overlap, the layout
+/// registered first wins.
+///
+/// Without a slot, `try_decode_instruction` uses the layout whose range starts
+/// last, normally the current one.
+///
+/// ```ignore
+/// let decoder = VersionedInstructionDecoder::new()
+///     .layout(..250_000_000, LegacyVaultDecoder)
+///     .layout(250_000_000.., VaultDecoder);
+/// ```
+#[cfg(feature = "std")]
+pub struct VersionedInstructionDecoder<T> {
+    layouts: Vec<SlotLayout<T>>,
+    program_ids: Vec<Pubkey>,
+}
+
+/// A layout of a `VersionedInstructionDecoder` and the slots it is used for.
+#[cfg(feature = "std")]
+type SlotLayout<T> = (
+    (Bound<u64>, Bound<u64>),
+    Box<dyn for<'a> InstructionDecoder<'a, InstructionType = T> + Send + Sync>,
+);
+
+#[cfg(feature = "std")]
+impl<T> Default for VersionedInstructionDecoder<T> {
+    fn default() -> Self {
+        Self {
+            layouts: Vec::new(),
+            program_ids: Vec::new(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> VersionedInstructionDecoder<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `decoder` for the instructions executed in `slots`.
+    pub fn layout(
+        mut self,
+        slots: impl RangeBounds<u64>,
+        decoder: impl for<'a> InstructionDecoder<'a, InstructionType = T> + Send + Sync + 'static,
+    ) -> Self {
+        // The decoder matches every program as soon as one layout does.
+        let program_ids = decoder.program_ids();
+        if self.layouts.is_empty() {
+            self.program_ids = program_ids.to_vec();
+        } else if program_ids.is_empty() {
+            self.program_ids.clear();
+        } else if !self.program_ids.is_empty() {
+            for program_id in program_ids {
+                if !self.program_ids.contains(program_id) {
+                    self.program_ids.push(*program_id);
+                }
+            }
+        }
+
+        self.layouts.push((
+            (slots.start_bound().cloned(), slots.end_bound().cloned()),
+            Box::new(decoder),
+        ));
+        self
+    }
+
+    fn layout_at(
+        &self,
+        slot: u64,
+    ) -> Option<&(dyn for<'a> InstructionDecoder<'a, InstructionType = T> + Send + Sync)> {
+        self.layouts
+            .iter()
+            .find(|(slots, _)| slots.contains(&slot))
+            .map(|(_, decoder)| decoder.as_ref())
+    }
+
+    fn current_layout(
+        &self,
+    ) -> Option<&(dyn for<'a> InstructionDecoder<'a, InstructionType = T> + Send + Sync)> {
+        self.layouts
+            .iter()
+            .rev()
+            .max_by_key(|((start, _), _)| match start {
+                Bound::Included(slot) => Some(*slot),
+                Bound::Excluded(slot) => Some(slot.saturating_add(1)),
+                Bound::Unbounded => None,
+            })
+            .map(|(_, decoder)| decoder.as_ref())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, T> InstructionDecoder<'a> for VersionedInstructionDecoder<T> {
+    type InstructionType = T;
+
+    fn try_decode_instruction(
+        &self,
+        instruction: &'a solana_instruction::Instruction,
+    ) -> CarbonResult<Option<DecodedInstruction<Self::InstructionType>>> {
+        match self.current_layout() {
+            Some(decoder) => decoder.try_decode_instruction(instruction),
+            None => Ok(None),
+        }
+    }
+
+    fn program_ids(&self) -> &[Pubkey] {
+        &self.program_ids
+    }
+
+    fn min_accounts(&self) -> usize {
+        self.layouts
+            .iter()
+            .map(|(_, decoder)| decoder.min_accounts())
+            .min()
+            .unwrap_or_default()
+    }
+
+    fn matches_discriminator(&self, data: &[u8]) -> bool {
+        self.layouts
+            .iter()
+            .any(|(_, decoder)| decoder.matches_discriminator(data))
+    }
+
+    fn supports_slot(&self, slot: u64) -> bool {
+        self.layout_at(slot).is_some()
+    }
+
+    fn try_decode_instruction_at_slot(
+        &self,
+        instruction: &'a solana_instruction::Instruction,
+        slot: u64,
+    ) -> CarbonResult<Option<DecodedInstruction<Self::InstructionType>>> {
+        match self.layout_at(slot) {
+            Some(decoder) => decoder.try_decode_instruction_at_slot(instruction, slot),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Strips the module path and generic arguments from a type name, keeping
+/// metric names derived from it short and bounded.
+fn short_type_name(type_name: &'static str) -> &'static str {
+    let without_generics = type_name.split('<').next().unwrap_or(type_name);
+    without_generics
+        .rsplit("::")
+        .next()
+        .unwrap_or(without_generics)
 }
 
+/// The default for `InstructionPipe::max_depth`.
+///
+/// Solana limits cross-program invocations to a depth of 4, so inner
+/// instructions built from real transactions never come close; the limit only
+/// protects against malformed trees from faulty datasources.
+pub const DEFAULT_MAX_INSTRUCTION_DEPTH: usize = 64;
+
 /// The input type for the instruction processor.
 ///
 /// - `T`: The instruction type
+#[cfg(feature = "std")]
 pub type InstructionProcessorInputType<T> = (
     InstructionMetadata,
     DecodedInstruction<T>,
     NestedInstructions,
 );
 
+/// What an `InstructionPipe` does with an instruction it fails to decode or
+/// process.
+#[cfg(feature = "std")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorPolicy {
+    /// Fail the update, so that the pipe leaves the rest of the transaction
+    /// unprocessed. The pipeline counts the update in `updates_failed` and
+    /// stops advancing its checkpoint, so the update is processed again after
+    /// a restart.
+    #[default]
+    Abort,
+    /// Log the error and continue with the next instruction.
+    Skip,
+    /// Send the instruction to the pipeline's dead letter sink and continue
+    /// with the next instruction. Without a sink, or if the sink fails, the
+    /// update fails as with `Abort`.
+    DeadLetter,
+}
+
 /// A processing pipeline for instructions, using a decoder and processor.
 ///
 /// The `InstructionPipe` structure enables the processing of decoded
@@ -132,11 +497,356 @@ pub type InstructionProcessorInputType<T> = (
 ///
 /// - `decoder`: The decoder used for parsing instructions.
 /// - `processor`: The processor that handles decoded instructions.
+/// - `dead_letter_sink`: Where instructions that fail to decode or process are
+///   sent under `ErrorPolicy::DeadLetter`. The sink only receives the failed
+///   instruction; whether the update fails or the pipe moves on to the next
+///   instruction is decided by `error_policy`.
+/// - `error_policy`: How the pipe handles instructions it fails to decode or
+///   process. `None` follows the pipeline: `ErrorPolicy::DeadLetter` if it has
+///   a dead letter sink, and `ErrorPolicy::Abort` otherwise.
+/// - `decoder_claims`: The program ID and discriminator pairs decoded by the
+///   pipes of the pipeline, shared between them to detect decoder collisions.
+/// - `max_depth`: The deepest level of inner instructions the pipe visits, the
+///   top-level instruction being at depth 1. Deeper inner instructions are
+///   skipped without being walked and left out of the inner instructions passed
+///   to the processor. Defaults to `DEFAULT_MAX_INSTRUCTION_DEPTH`.
+/// - `top_level_only`: Whether the pipe only visits top-level instructions.
+///   Their inner instructions are still passed to the processor, but are not
+///   decoded and processed themselves. Defaults to `false`.
+/// - `decode_rates`: The instructions of each program of the decoder seen and
+///   decoded by the pipe, shared with its forks.
+///
+/// Instructions of programs outside `InstructionDecoder::program_ids`, or with
+/// fewer accounts than `InstructionDecoder::min_accounts`, are skipped without
+/// being decoded, but their inner instructions are still visited.
+///
+/// # Metrics
+///
+/// - `instructions_decoded`: Counter of instructions matched by the decoder.
+/// - `instructions_decode_failed`: Counter of instructions the decoder
+///   recognized but failed to deserialize.
+/// - `instructions_dead_lettered`: Counter of instructions sent to the dead
+///   letter sink.
+/// - `instructions_skipped_on_error`: Counter of instructions that failed and
+///   were skipped under `ErrorPolicy::Skip`.
+/// - `instruction_depth_truncated`: Counter of inner instructions skipped for
+///   being nested one level deeper than `max_depth`. The instructions they
+///   invoked are not walked, and so not counted.
+/// - `decoder_collision`: Counter of instructions decoded by a decoder after a
+///   different decoder already decoded the same program ID and discriminator.
+/// - `instruction_processor_invocations`: Counter of calls to the processor.
+/// - `instructions_outside_slot_ranges`: Counter of instructions skipped
+///   because the decoder does not support their slot.
+/// - `instruction_process_time_milliseconds`: Histogram of the time spent in
+///   the processor, labelled with the `decoder` to find the slowest
+///   decoder/processor pair.
+/// - `decode_rate`: Gauge of the share of the instructions of a program that
+///   the decoder decoded, between 0 and 1, labelled with the `program_id`. Only
+///   reported for the programs listed in `InstructionDecoder::program_ids`, so
+///   that the number of series stays bounded. A drop to 0 usually means that a
+///   program upgrade changed its discriminators.
+///
+/// The `instructions_decoded`, `instructions_decode_failed`,
+/// `instructions_outside_slot_ranges` and `instruction_processor_invocations`
+/// counters carry a `decoder` label holding `InstructionDecoder::decoder_name`,
+/// so that counts can be broken down by program.
+///
+/// The timings and decode rates are only taken when at least one metrics
+/// backend is registered.
+#[cfg(feature = "std")]
 pub struct InstructionPipe<T: Send> {
     pub decoder:
-        Box<dyn for<'a> InstructionDecoder<'a, InstructionType = T> + Send + Sync + 'static>,
+        Arc<dyn for<'a> InstructionDecoder<'a, InstructionType = T> + Send + Sync + 'static>,
     pub processor:
         Box<dyn Processor<InputType = InstructionProcessorInputType<T>> + Send + Sync + 'static>,
+    pub dead_letter_sink: Option<Arc<dyn DeadLetterSink>>,
+    pub error_policy: Option<ErrorPolicy>,
+    pub decoder_claims: Arc<DecoderClaims>,
+    pub max_depth: usize,
+    pub top_level_only: bool,
+    pub decode_rates: Arc<DecodeRates>,
+}
+
+#[cfg(feature = "std")]
+impl<T: Send + 'static> InstructionPipe<T> {
+    /// Handles an instruction that failed to decode or process according to
+    /// the pipe's `error_policy`. Returns an error if the update must fail.
+    async fn handle_error(
+        &self,
+        nested_instruction: &NestedInstruction,
+        error: CarbonError,
+        metrics: &MetricsCollection,
+    ) -> CarbonResult<()> {
+        match self.error_policy {
+            Some(ErrorPolicy::Abort) => Err(error),
+            Some(ErrorPolicy::Skip) => {
+                log::warn!(
+                    "Skipping instruction of transaction {}: {}",
+                    nested_instruction.metadata.transaction_metadata.signature,
+                    error
+                );
+                metrics
+                    .increment_counter("instructions_skipped_on_error", 1)
+                    .await
+            }
+            Some(ErrorPolicy::DeadLetter) | None => {
+                self.dead_letter(nested_instruction, error, metrics).await
+            }
+        }
+    }
+
+    /// Sends a failed instruction to the dead letter sink. Gives `error` back
+    /// if there is no sink or the sink fails, so that the update fails instead
+    /// of losing the instruction.
+    async fn dead_letter(
+        &self,
+        nested_instruction: &NestedInstruction,
+        error: CarbonError,
+        metrics: &MetricsCollection,
+    ) -> CarbonResult<()> {
+        let Some(dead_letter_sink) = &self.dead_letter_sink else {
+            return Err(error);
+        };
+
+        if let Err(sink_error) = dead_letter_sink
+            .send(
+                &nested_instruction.metadata,
+                &nested_instruction.instruction,
+                &error,
+            )
+            .await
+        {
+            log::error!("failed to dead-letter instruction: {:?}", sink_error);
+            return Err(error);
+        }
+
+        metrics
+            .increment_counter("instructions_dead_lettered", 1)
+            .await
+    }
+
+    /// Increments the counter `name`, labelled with the decoder name.
+    async fn increment_decoder_counter(
+        &self,
+        name: &str,
+        metrics: &Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        metrics
+            .increment_counter_with_labels(name, &[("decoder", self.decoder.decoder_name())], 1)
+            .await
+    }
+
+    /// Decodes a single instruction at `depth` and passes it to the processor,
+    /// without visiting its inner instructions.
+    async fn process_instruction(
+        &mut self,
+        nested_instruction: &NestedInstruction,
+        depth: usize,
+        metrics: &Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        let program_ids = self.decoder.program_ids();
+        let program_id = nested_instruction.instruction.program_id;
+        let is_registered_program = program_ids.contains(&program_id);
+        let slot = nested_instruction.metadata.slot();
+        let decoded_instruction = if (!program_ids.is_empty() && !is_registered_program)
+            || nested_instruction.instruction.accounts.len() < self.decoder.min_accounts()
+        {
+            None
+        } else if !self.decoder.supports_slot(slot) {
+            self.increment_decoder_counter("instructions_outside_slot_ranges", metrics)
+                .await?;
+            None
+        } else {
+            match self
+                .decoder
+                .try_decode_instruction_at_slot(&nested_instruction.instruction, slot)
+            {
+                Ok(decoded_instruction) => decoded_instruction,
+                Err(error) => {
+                    self.increment_decoder_counter("instructions_decode_failed", metrics)
+                        .await?;
+                    let error = CarbonError::Decode {
+                        program_id: nested_instruction.instruction.program_id,
+                        decoder: self.decoder.decoder_name(),
+                        source: Box::new(error),
+                    };
+                    self.handle_error(nested_instruction, error, metrics)
+                        .await?;
+                    None
+                }
+            }
+        };
+
+        if is_registered_program && !metrics.metrics.is_empty() {
+            let (program_id, decode_rate) = self
+                .decode_rates
+                .record(program_id, decoded_instruction.is_some());
+            metrics
+                .update_gauge_with_labels(
+                    "decode_rate",
+                    &[("program_id", &program_id)],
+                    decode_rate,
+                )
+                .await?;
+        }
+
+        if let Some(decoded_instruction) = decoded_instruction {
+            self.increment_decoder_counter("instructions_decoded", metrics)
+                .await?;
+            if self.decoder_claims.claim(
+                decoded_instruction.program_id,
+                &decoded_instruction.discriminator,
+                self.decoder.decoder_name(),
+            ) {
+                metrics.increment_counter("decoder_collision", 1).await?;
+            }
+            self.increment_decoder_counter("instruction_processor_invocations", metrics)
+                .await?;
+
+            let start = (!metrics.metrics.is_empty()).then(Instant::now);
+            let process_result = self
+                .processor
+                .process(
+                    (
+                        nested_instruction.metadata.clone(),
+                        decoded_instruction,
+                        nested_instruction
+                            .inner_instructions
+                            .clone_to_depth(self.max_depth.saturating_sub(depth)),
+                    ),
+                    metrics.clone(),
+                )
+                .await;
+
+            if let Some(start) = start {
+                let time_taken_milliseconds = start.elapsed().as_secs_f64() * 1_000.0;
+
+                metrics
+                    .record_histogram_with_labels(
+                        "instruction_process_time_milliseconds",
+                        &[("decoder", self.decoder.decoder_name())],
+                        time_taken_milliseconds,
+                    )
+                    .await?;
+            }
+
+            if let Err(error) = process_result {
+                let error = CarbonError::Processor {
+                    program_id: nested_instruction.instruction.program_id,
+                    decoder: self.decoder.decoder_name(),
+                    source: Box::new(error),
+                };
+                self.handle_error(nested_instruction, error, metrics)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Records which decoder decoded each program ID and discriminator pair.
+///
+/// Decoders are expected to be scoped to their programs, so two decoders that
+/// decode the same program ID and discriminator are a misconfiguration: both
+/// of their processors receive the instruction. The pipes of a pipeline share
+/// one `DecoderClaims` to detect this.
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct DecoderClaims {
+    claims: RwLock<HashMap<(Pubkey, Vec<u8>), &'static str>>,
+    warned: Mutex<HashSet<(Pubkey, Vec<u8>)>>,
+}
+
+#[cfg(feature = "std")]
+impl DecoderClaims {
+    /// Records that `decoder` decoded an instruction of `program_id` with
+    /// `discriminator`.
+    ///
+    /// Returns `true` if a different decoder already claimed the pair, and
+    /// logs a warning the first time this happens for it.
+    pub fn claim(&self, program_id: Pubkey, discriminator: &[u8], decoder: &'static str) -> bool {
+        let key = (program_id, discriminator.to_vec());
+
+        let claimed_by = self
+            .claims
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&key)
+            .copied();
+        let claimed_by = match claimed_by {
+            Some(claimed_by) => claimed_by,
+            None => *self
+                .claims
+                .write()
+                .unwrap_or_else(PoisonError::into_inner)
+                .entry(key.clone())
+                .or_insert(decoder),
+        };
+
+        if claimed_by == decoder {
+            return false;
+        }
+
+        if self
+            .warned
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(key)
+        {
+            log::warn!(
+                "Decoders {} and {} both decode instructions of program {} with discriminator {:?}",
+                claimed_by,
+                decoder,
+                program_id,
+                discriminator
+            );
+        }
+
+        true
+    }
+}
+
+/// Counts, for each program, the instructions an `InstructionPipe` saw and the
+/// ones its decoder decoded, from which the pipe reports the `decode_rate`
+/// gauge of the program.
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct DecodeRates {
+    counts: Mutex<HashMap<Pubkey, DecodeRate>>,
+}
+
+/// The instructions of a program seen and decoded by an `InstructionPipe`,
+/// with the address of the program as it is written in the gauge label.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+struct DecodeRate {
+    label: Arc<str>,
+    seen: u64,
+    decoded: u64,
+}
+
+#[cfg(feature = "std")]
+impl DecodeRates {
+    /// Records an instruction of `program_id`, and whether it was decoded.
+    ///
+    /// Returns the base58 address of `program_id`, which is only encoded the
+    /// first time the program is recorded, and the share of its instructions
+    /// recorded so far that were decoded.
+    pub fn record(&self, program_id: Pubkey, decoded: bool) -> (Arc<str>, f64) {
+        let mut counts = self.counts.lock().unwrap_or_else(PoisonError::into_inner);
+        let rate = counts.entry(program_id).or_insert_with(|| DecodeRate {
+            label: program_id.to_string().into(),
+            seen: 0,
+            decoded: 0,
+        });
+        rate.seen += 1;
+        if decoded {
+            rate.decoded += 1;
+        }
+
+        (rate.label.clone(), rate.decoded as f64 / rate.seen as f64)
+    }
 }
 
 /// An async trait for processing instructions within nested contexts.
@@ -149,6 +859,10 @@ pub struct InstructionPipe<T: Send> {
 ///
 /// - `run`: Processes a `NestedInstruction`, recursively processing any inner
 ///   instructions.
+///
+/// A pipeline runs its instruction pipes one after the other, in the order
+/// they were registered. See `PipelineBuilder::instruction`.
+#[cfg(feature = "std")]
 #[async_trait]
 pub trait InstructionPipes<'a>: Send + Sync {
     async fn run(
@@ -156,8 +870,39 @@ pub trait InstructionPipes<'a>: Send + Sync {
         nested_instruction: &NestedInstruction,
         metrics: Arc<MetricsCollection>,
     ) -> CarbonResult<()>;
+
+    /// Shuts down the pipe's processor when the pipeline shuts down.
+    async fn shutdown(&mut self) -> CarbonResult<()> {
+        Ok(())
+    }
+
+    /// Creates another instance of the pipe for the pipeline to run
+    /// concurrently with this one, or `None` if the pipe must handle one
+    /// update at a time.
+    fn fork(&self) -> Option<Box<dyn for<'b> InstructionPipes<'b>>> {
+        None
+    }
+
+    /// Sends the instructions the pipe fails to decode or process to
+    /// `dead_letter_sink` instead of failing the update.
+    fn set_dead_letter_sink(&mut self, _dead_letter_sink: Arc<dyn DeadLetterSink>) {}
+
+    /// Limits how deeply nested the inner instructions visited by the pipe
+    /// may be.
+    fn set_max_depth(&mut self, _max_depth: usize) {}
+
+    /// Makes the pipe visit top-level instructions only, without descending
+    /// into their inner instructions.
+    fn set_top_level_only(&mut self, _top_level_only: bool) {}
+
+    /// Returns the name of the pipe's instruction decoder and the programs it
+    /// decodes, or `None` for pipes that do not decode instructions.
+    fn decoder(&self) -> Option<(&'static str, &[Pubkey])> {
+        None
+    }
 }
 
+#[cfg(feature = "std")]
 #[async_trait]
 impl<T: Send + 'static> InstructionPipes<'_> for InstructionPipe<T> {
     async fn run(
@@ -170,28 +915,85 @@ impl<T: Send + 'static> InstructionPipes<'_> for InstructionPipe<T> {
             nested_instruction,
         );
 
-        if let Some(decoded_instruction) = self
-            .decoder
-            .decode_instruction(&nested_instruction.instruction)
-        {
-            self.processor
-                .process(
-                    (
-                        nested_instruction.metadata.clone(),
-                        decoded_instruction,
-                        nested_instruction.inner_instructions.clone(),
-                    ),
-                    metrics.clone(),
-                )
+        // The tree is walked with an explicit stack, in execution order, so
+        // that arbitrarily deep nesting cannot overflow the call stack.
+        let mut stack = vec![(nested_instruction, 1)];
+        let mut truncated = 0;
+        while let Some((nested_instruction, depth)) = stack.pop() {
+            if depth > self.max_depth {
+                truncated += 1;
+                continue;
+            }
+
+            let span = tracing::info_span!(
+                "instruction",
+                program_id = %nested_instruction.instruction.program_id,
+                stack_height = nested_instruction.metadata.stack_height,
+            );
+            self.process_instruction(nested_instruction, depth, &metrics)
+                .instrument(span)
                 .await?;
+
+            if self.top_level_only {
+                continue;
+            }
+
+            stack.extend(
+                nested_instruction
+                    .inner_instructions
+                    .iter()
+                    .rev()
+                    .map(|inner_instruction| (inner_instruction, depth + 1)),
+            );
         }
 
-        for nested_inner_instruction in nested_instruction.inner_instructions.iter() {
-            self.run(nested_inner_instruction, metrics.clone()).await?;
+        if truncated > 0 {
+            log::warn!(
+                "Skipped {} inner instructions nested deeper than {} in transaction {}",
+                truncated,
+                self.max_depth,
+                nested_instruction.metadata.transaction_metadata.signature
+            );
+            metrics
+                .increment_counter("instruction_depth_truncated", truncated)
+                .await?;
         }
 
         Ok(())
     }
+
+    async fn shutdown(&mut self) -> CarbonResult<()> {
+        self.processor.shutdown().await
+    }
+
+    fn fork(&self) -> Option<Box<dyn for<'b> InstructionPipes<'b>>> {
+        Some(Box::new(InstructionPipe {
+            decoder: self.decoder.clone(),
+            processor: self.processor.fork()?,
+            dead_letter_sink: self.dead_letter_sink.clone(),
+            error_policy: self.error_policy,
+            decoder_claims: self.decoder_claims.clone(),
+            max_depth: self.max_depth,
+            top_level_only: self.top_level_only,
+            decode_rates: self.decode_rates.clone(),
+        }))
+    }
+
+    fn set_dead_letter_sink(&mut self, dead_letter_sink: Arc<dyn DeadLetterSink>) {
+        self.dead_letter_sink = Some(dead_letter_sink);
+    }
+
+    fn set_max_depth(&mut self, max_depth: usize) {
+        self.max_depth = max_depth;
+    }
+
+    fn set_top_level_only(&mut self, top_level_only: bool) {
+        self.top_level_only = top_level_only;
+    }
+
+    fn decoder(&self) -> Option<(&'static str, &[Pubkey])> {
+        Some((self.decoder.decoder_name(), self.decoder.program_ids()))
+    }
 }
 
 /// Represents a nested instruction with metadata, including potential inner
@@ -207,16 +1009,34 @@ impl<T: Send + 'static> InstructionPipes<'_> for InstructionPipe<T> {
 /// - `instruction`: The Solana instruction being processed.
 /// - `inner_instructions`: A vector of `NestedInstruction`, representing any
 ///   nested instructions.
-#[derive(Debug, Clone)]
+///
+/// Equality is structural: two nested instructions are equal if their
+/// metadata, instruction and whole inner instruction trees are equal.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq)]
 pub struct NestedInstruction {
     pub metadata: InstructionMetadata,
     pub instruction: solana_instruction::Instruction,
     pub inner_instructions: NestedInstructions,
 }
 
-#[derive(Debug, Default)]
+/// Drops the inner instructions with an explicit stack, so that dropping a
+/// deeply nested tree cannot overflow the call stack.
+#[cfg(feature = "std")]
+impl Drop for NestedInstruction {
+    fn drop(&mut self) {
+        let mut stack = std::mem::take(&mut self.inner_instructions.0);
+        while let Some(mut nested_instruction) = stack.pop() {
+            stack.append(&mut nested_instruction.inner_instructions.0);
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default, PartialEq)]
 pub struct NestedInstructions(pub Vec<NestedInstruction>);
 
+#[cfg(feature = "std")]
 impl NestedInstructions {
     pub fn iter(&self) -> std::slice::Iter<NestedInstruction> {
         self.0.iter()
@@ -233,63 +1053,237 @@ impl NestedInstructions {
     pub fn push(&mut self, nested_instruction: NestedInstruction) {
         self.0.push(nested_instruction);
     }
-}
 
-impl Deref for NestedInstructions {
-    type Target = [NestedInstruction];
+    /// Nests `instructions` like `From<InstructionsWithMetadata>`, leaving out
+    /// the instructions that would be nested deeper than `max_depth`, the
+    /// top-level instructions being at depth 1.
+    ///
+    /// Returns the tree and the number of instructions left out.
+    pub fn with_max_depth(instructions: InstructionsWithMetadata, max_depth: usize) -> (Self, u64) {
+        let mut nested_ixs = NestedInstructions::default();
+        let mut left_out = 0;
+        // Positions of the most recent instruction at each depth, from the
+        // root down. `parent_path[d]` is the index of the last instruction
+        // seen at stack height `d + 1` within its parent's children.
+        let mut parent_path: Vec<usize> = Vec::new();
 
-    fn deref(&self) -> &[NestedInstruction] {
-        &self.0[..]
+        // Only the stack height decides where an instruction goes: it is a
+        // root instruction iff its stack height is 1. Sources that do not
+        // report stack heights leave them at 0, which counts as 1.
+        for (mut metadata, instruction) in instructions {
+            let stack_height = metadata.stack_height.max(1) as usize;
+
+            // Keep only the ancestors above this instruction. If the stack
+            // height skips a level, attach to the deepest ancestor we have.
+            parent_path.truncate(stack_height - 1);
+
+            // The instructions invoked by an instruction that was left out
+            // are left out too, since the path never reaches past it.
+            if parent_path.len() >= max_depth {
+                left_out += 1;
+                continue;
+            }
+
+            let mut siblings = &mut nested_ixs;
+            for &position in parent_path.iter() {
+                siblings = &mut siblings.0[position].inner_instructions;
+            }
+
+            parent_path.push(siblings.len());
+            metadata.absolute_path = parent_path
+                .iter()
+                .map(|&position| position as u32)
+                .collect();
+            siblings.push(NestedInstruction {
+                metadata,
+                instruction,
+                inner_instructions: NestedInstructions::default(),
+            });
+        }
+
+        (nested_ixs, left_out)
     }
-}
 
-impl DerefMut for NestedInstructions {
-    fn deref_mut(&mut self) -> &mut [NestedInstruction] {
-        &mut self.0[..]
+    /// Clones the tree down to `depth` levels, leaving out the instructions
+    /// nested deeper.
+    fn clone_to_depth(&self, depth: usize) -> NestedInstructions {
+        if depth == 0 {
+            return NestedInstructions::default();
+        }
+
+        NestedInstructions(
+            self.iter()
+                .map(|nested_instruction| NestedInstruction {
+                    metadata: nested_instruction.metadata.clone(),
+                    instruction: nested_instruction.instruction.clone(),
+                    inner_instructions: nested_instruction
+                        .inner_instructions
+                        .clone_to_depth(depth - 1),
+                })
+                .collect(),
+        )
     }
-}
 
-impl Clone for NestedInstructions {
-    fn clone(&self) -> Self {
-        NestedInstructions(self.0.clone())
+    /// Returns every instruction in the tree in depth-first pre-order, which
+    /// matches the order in which the instructions were executed.
+    ///
+    /// Each node keeps its original `InstructionMetadata`, so `stack_height`
+    /// and `index` remain available to callers.
+    pub fn flatten(&self) -> Vec<&NestedInstruction> {
+        let mut flattened = Vec::new();
+        let mut stack: Vec<&NestedInstruction> = self.0.iter().rev().collect();
+
+        while let Some(nested_instruction) = stack.pop() {
+            flattened.push(nested_instruction);
+            stack.extend(nested_instruction.inner_instructions.iter().rev());
+        }
+
+        flattened
     }
-}
-/// Nests instructions based on stack height, producing a hierarchy of
+
+    /// Consumes the tree and returns every instruction in depth-first
+    /// pre-order, which matches the order in which the instructions were
+    /// executed.
+    ///
+    /// The returned nodes have their `inner_instructions` emptied, since their
+    /// children follow them directly in the returned vector.
+    pub fn into_flattened(self) -> Vec<NestedInstruction> {
+        let mut flattened = Vec::new();
+        let mut stack: Vec<NestedInstruction> = self.0.into_iter().rev().collect();
+
+        while let Some(mut nested_instruction) = stack.pop() {
+            let inner_instructions = std::mem::take(&mut nested_instruction.inner_instructions);
+            flattened.push(nested_instruction);
+            stack.extend(inner_instructions.0.into_iter().rev());
+        }
+
+        flattened
+    }
+
+    /// Returns the instructions in the call chain of the instruction at
+    /// `absolute_path`, from its top-level instruction down to the
+    /// instruction that invoked it, so that an inner instruction can be
+    /// attributed to what triggered it.
+    ///
+    /// The instruction itself is not included. Positions missing from the
+    /// tree end the chain early.
+    pub fn ancestors(&self, absolute_path: &[u32]) -> Vec<&NestedInstruction> {
+        let mut ancestors = Vec::new();
+        let mut siblings = self;
+
+        for &position in absolute_path
+            .iter()
+            .take(absolute_path.len().saturating_sub(1))
+        {
+            let Some(ancestor) = siblings.0.get(position as usize) else {
+                break;
+            };
+            ancestors.push(ancestor);
+            siblings = &ancestor.inner_instructions;
+        }
+
+        ancestors
+    }
+}
+
+#[cfg(feature = "std")]
+impl Deref for NestedInstructions {
+    type Target = [NestedInstruction];
+
+    fn deref(&self) -> &[NestedInstruction] {
+        &self.0[..]
+    }
+}
+
+#[cfg(feature = "std")]
+impl DerefMut for NestedInstructions {
+    fn deref_mut(&mut self) -> &mut [NestedInstruction] {
+        &mut self.0[..]
+    }
+}
+
+#[cfg(feature = "std")]
+impl Clone for NestedInstructions {
+    fn clone(&self) -> Self {
+        NestedInstructions(self.0.clone())
+    }
+}
+
+#[cfg(feature = "std")]
+impl IntoIterator for NestedInstructions {
+    type Item = NestedInstruction;
+    type IntoIter = std::vec::IntoIter<NestedInstruction>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> IntoIterator for &'a NestedInstructions {
+    type Item = &'a NestedInstruction;
+    type IntoIter = std::slice::Iter<'a, NestedInstruction>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> IntoIterator for &'a mut NestedInstructions {
+    type Item = &'a mut NestedInstruction;
+    type IntoIter = std::slice::IterMut<'a, NestedInstruction>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter_mut()
+    }
+}
+
+#[cfg(feature = "std")]
+impl FromIterator<NestedInstruction> for NestedInstructions {
+    fn from_iter<I: IntoIterator<Item = NestedInstruction>>(iter: I) -> Self {
+        NestedInstructions(iter.into_iter().collect())
+    }
+}
+
+/// Nests instructions based on stack height, producing a hierarchy of
 /// `NestedInstruction`.
 ///
 /// This function organizes instructions into a nested structure, enabling
 /// hierarchical transaction analysis. Instructions are nested according to
-/// their stack height, forming a tree-like structure.
+/// their stack height, forming a tree-like structure: each instruction is
+/// attached as a child of the most recent instruction seen at
+/// `stack_height - 1`, so CPI chains of arbitrary depth keep their true
+/// parent.
+///
+/// This follows how Solana reports CPIs: the stack height increases by one
+/// when an instruction invokes another, and drops back once the invoked
+/// instructions return. A run of inner instructions at heights `[2, 2, 3, 2]`
+/// is therefore three CPIs of the same outer instruction, the second of which
+/// made a CPI of its own. The index of an inner instruction is not used.
 ///
 /// # Parameters
 ///
 /// - `instructions`: A list of tuples containing `InstructionMetadata` and
-///   instructions.
+///   instructions, in execution order.
 ///
 /// # Returns
 ///
 /// A vector of `NestedInstruction`, representing the instructions organized by
-/// stack depth.
+/// stack depth. Instructions nested deeper than `DEFAULT_MAX_INSTRUCTION_DEPTH`
+/// are left out; use `NestedInstructions::with_max_depth` for another limit.
+#[cfg(feature = "std")]
 impl From<InstructionsWithMetadata> for NestedInstructions {
     fn from(instructions: InstructionsWithMetadata) -> Self {
         log::trace!("from(instructions: {:?})", instructions);
-        let mut nested_ixs = NestedInstructions::default();
-
-        for (metadata, instruction) in instructions {
-            let nested_instruction = NestedInstruction {
-                metadata: metadata.clone(),
-                instruction,
-                inner_instructions: NestedInstructions::default(),
-            };
-
-            // compose root level of ixs
-            if metadata.stack_height == 1 || metadata.index == 0 {
-                nested_ixs.push(nested_instruction);
-                continue;
-            }
-            nested_ixs[metadata.index as usize]
-                .inner_instructions
-                .push(nested_instruction);
+        let (nested_ixs, left_out) =
+            NestedInstructions::with_max_depth(instructions, DEFAULT_MAX_INSTRUCTION_DEPTH);
+        if left_out > 0 {
+            log::warn!(
+                "Left out {} instructions nested deeper than {}",
+                left_out,
+                DEFAULT_MAX_INSTRUCTION_DEPTH
+            );
         }
 
         nested_ixs
@@ -301,6 +1295,7 @@ mod tests {
     use solana_instruction::Instruction;
 
     use super::*;
+    use crate::testing::RecordingMetrics;
 
     fn create_instruction_with_metadata(
         index: u32,
@@ -310,6 +1305,10 @@ mod tests {
             transaction_metadata: TransactionMetadata::default(),
             stack_height,
             index,
+            accounts_resolved: true,
+            compute_units: None,
+            absolute_path: vec![],
+            transaction_context: None,
         };
         let instruction = Instruction {
             program_id: Pubkey::new_unique(),
@@ -319,6 +1318,47 @@ mod tests {
         (metadata, instruction)
     }
 
+    #[test]
+    fn test_instruction_metadata_slot_and_block_time() {
+        let (mut metadata, _) = create_instruction_with_metadata(0, 1);
+        metadata.transaction_metadata.slot = 42;
+        metadata.transaction_metadata.block_time = Some(1_700_000_000);
+
+        assert_eq!(metadata.slot(), 42);
+        assert_eq!(metadata.block_time(), Some(1_700_000_000));
+
+        metadata.transaction_metadata.block_time = None;
+        assert_eq!(metadata.block_time(), None);
+    }
+
+    #[test]
+    fn test_instruction_metadata_equality_ignores_transaction_context() {
+        let (metadata, _) = create_instruction_with_metadata(0, 1);
+        let with_context = InstructionMetadata {
+            transaction_context: Some(TransactionContext::new()),
+            ..metadata.clone()
+        };
+        let with_other_context = InstructionMetadata {
+            transaction_context: Some(TransactionContext::new()),
+            ..metadata.clone()
+        };
+        with_other_context
+            .transaction_context
+            .as_ref()
+            .unwrap()
+            .insert(42u64);
+
+        assert_eq!(metadata, with_context);
+        assert_eq!(with_context, with_other_context);
+        assert_ne!(
+            metadata,
+            InstructionMetadata {
+                compute_units: Some(1),
+                ..with_context
+            }
+        );
+    }
+
     #[test]
     fn test_nested_instructions_single_level() {
         let instructions = vec![
@@ -351,6 +1391,1029 @@ mod tests {
 
         let nested_instructions: NestedInstructions = instructions.into();
         assert_eq!(nested_instructions.len(), 2);
-        assert_eq!(nested_instructions.0[1].inner_instructions.len(), 4);
+        assert!(nested_instructions[0].inner_instructions.is_empty());
+        assert_eq!(nested_instructions[1].inner_instructions.len(), 1);
+        assert_eq!(
+            nested_instructions[1].inner_instructions[0]
+                .inner_instructions
+                .len(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_nested_instructions_absolute_path_and_ancestors() {
+        // Arrange
+        let instructions = vec![
+            create_instruction_with_metadata(0, 1),
+            create_instruction_with_metadata(1, 1),
+            create_instruction_with_metadata(1, 2),
+            create_instruction_with_metadata(1, 2),
+            create_instruction_with_metadata(1, 3),
+            create_instruction_with_metadata(1, 3),
+        ];
+
+        // Act
+        let nested_instructions: NestedInstructions = instructions.into();
+
+        // Assert
+        let swap = &nested_instructions[1];
+        let route = &swap.inner_instructions[1];
+        let transfer = &route.inner_instructions[1];
+        assert_eq!(nested_instructions[0].metadata.absolute_path, vec![0]);
+        assert_eq!(swap.metadata.absolute_path, vec![1]);
+        assert_eq!(route.metadata.absolute_path, vec![1, 1]);
+        assert_eq!(transfer.metadata.absolute_path, vec![1, 1, 1]);
+
+        let ancestors = nested_instructions.ancestors(&transfer.metadata.absolute_path);
+        assert_eq!(ancestors, vec![swap, route]);
+        assert!(nested_instructions
+            .ancestors(&swap.metadata.absolute_path)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_inner_instruction_with_index_zero_is_nested() {
+        // Inner instructions numbered from 0 within their top-level
+        // instruction, as some sources do.
+        let instructions = vec![
+            create_instruction_with_metadata(0, 1),
+            create_instruction_with_metadata(1, 1),
+            create_instruction_with_metadata(0, 2),
+            create_instruction_with_metadata(0, 3),
+            create_instruction_with_metadata(1, 2),
+        ];
+        let program_ids: Vec<Pubkey> = instructions
+            .iter()
+            .map(|(_, instruction)| instruction.program_id)
+            .collect();
+
+        let nested_instructions: NestedInstructions = instructions.into();
+
+        assert_eq!(nested_instructions.len(), 2);
+        assert!(nested_instructions[0].inner_instructions.is_empty());
+        let parent = &nested_instructions[1];
+        assert_eq!(parent.instruction.program_id, program_ids[1]);
+        assert_eq!(parent.inner_instructions.len(), 2);
+        assert_eq!(
+            parent.inner_instructions[0].instruction.program_id,
+            program_ids[2]
+        );
+        assert_eq!(
+            parent.inner_instructions[0].inner_instructions[0]
+                .instruction
+                .program_id,
+            program_ids[3]
+        );
+        assert_eq!(
+            parent.inner_instructions[1].instruction.program_id,
+            program_ids[4]
+        );
+    }
+
+    #[test]
+    fn test_four_level_cpi_chain() {
+        let instructions = vec![
+            create_instruction_with_metadata(0, 1),
+            create_instruction_with_metadata(0, 2),
+            create_instruction_with_metadata(0, 3),
+            create_instruction_with_metadata(0, 4),
+        ];
+        let expected_program_ids: Vec<Pubkey> = instructions
+            .iter()
+            .map(|(_, instruction)| instruction.program_id)
+            .collect();
+
+        let nested_instructions: NestedInstructions = instructions.into();
+
+        assert_eq!(nested_instructions.len(), 1);
+        let level_1 = &nested_instructions[0];
+        assert_eq!(level_1.instruction.program_id, expected_program_ids[0]);
+        assert_eq!(level_1.inner_instructions.len(), 1);
+        let level_2 = &level_1.inner_instructions[0];
+        assert_eq!(level_2.instruction.program_id, expected_program_ids[1]);
+        assert_eq!(level_2.inner_instructions.len(), 1);
+        let level_3 = &level_2.inner_instructions[0];
+        assert_eq!(level_3.instruction.program_id, expected_program_ids[2]);
+        assert_eq!(level_3.inner_instructions.len(), 1);
+        let level_4 = &level_3.inner_instructions[0];
+        assert_eq!(level_4.instruction.program_id, expected_program_ids[3]);
+        assert!(level_4.inner_instructions.is_empty());
+    }
+
+    #[test]
+    fn test_sibling_subtrees_at_same_height() {
+        // 1
+        // ├── 2
+        // │   └── 3
+        // └── 2
+        //     ├── 3
+        //     └── 3
+        //         └── 4
+        // 1
+        // └── 2
+        let instructions = vec![
+            create_instruction_with_metadata(0, 1),
+            create_instruction_with_metadata(0, 2),
+            create_instruction_with_metadata(0, 3),
+            create_instruction_with_metadata(0, 2),
+            create_instruction_with_metadata(0, 3),
+            create_instruction_with_metadata(0, 3),
+            create_instruction_with_metadata(0, 4),
+            create_instruction_with_metadata(1, 1),
+            create_instruction_with_metadata(1, 2),
+        ];
+
+        let nested_instructions: NestedInstructions = instructions.into();
+
+        assert_eq!(nested_instructions.len(), 2);
+
+        let first_root = &nested_instructions[0];
+        assert_eq!(first_root.inner_instructions.len(), 2);
+        assert_eq!(first_root.inner_instructions[0].inner_instructions.len(), 1);
+
+        let second_subtree = &first_root.inner_instructions[1];
+        assert_eq!(second_subtree.inner_instructions.len(), 2);
+        assert!(second_subtree.inner_instructions[0]
+            .inner_instructions
+            .is_empty());
+        assert_eq!(
+            second_subtree.inner_instructions[1]
+                .inner_instructions
+                .len(),
+            1
+        );
+
+        let second_root = &nested_instructions[1];
+        assert_eq!(second_root.inner_instructions.len(), 1);
+        assert!(second_root.inner_instructions[0]
+            .inner_instructions
+            .is_empty());
+    }
+
+    #[test]
+    fn test_stack_height_sequence_decides_parentage() {
+        // 1
+        // ├── 2
+        // ├── 2
+        // │   └── 3
+        // │       └── 4
+        // └── 2
+        let instructions = vec![
+            create_instruction_with_metadata(0, 1),
+            create_instruction_with_metadata(0, 2),
+            create_instruction_with_metadata(0, 2),
+            create_instruction_with_metadata(0, 3),
+            create_instruction_with_metadata(0, 4),
+            create_instruction_with_metadata(0, 2),
+        ];
+        let program_ids: Vec<Pubkey> = instructions
+            .iter()
+            .map(|(_, instruction)| instruction.program_id)
+            .collect();
+
+        let nested_instructions: NestedInstructions = instructions.into();
+
+        assert_eq!(nested_instructions.len(), 1);
+        let cpis = &nested_instructions[0].inner_instructions;
+        assert_eq!(
+            cpis.iter()
+                .map(|cpi| cpi.instruction.program_id)
+                .collect::<Vec<_>>(),
+            vec![program_ids[1], program_ids[2], program_ids[5]]
+        );
+        assert!(cpis[0].inner_instructions.is_empty());
+        assert_eq!(cpis[1].inner_instructions.len(), 1);
+        let nested_cpi = &cpis[1].inner_instructions[0];
+        assert_eq!(nested_cpi.instruction.program_id, program_ids[3]);
+        assert_eq!(nested_cpi.inner_instructions.len(), 1);
+        assert_eq!(
+            nested_cpi.inner_instructions[0].instruction.program_id,
+            program_ids[4]
+        );
+        assert!(cpis[2].inner_instructions.is_empty());
+    }
+
+    fn create_three_level_tree() -> InstructionsWithMetadata {
+        vec![
+            create_instruction_with_metadata(0, 1),
+            create_instruction_with_metadata(0, 2),
+            create_instruction_with_metadata(0, 3),
+            create_instruction_with_metadata(0, 3),
+            create_instruction_with_metadata(0, 2),
+            create_instruction_with_metadata(1, 1),
+            create_instruction_with_metadata(1, 2),
+            create_instruction_with_metadata(1, 3),
+        ]
+    }
+
+    #[test]
+    fn test_flatten_preserves_execution_order() {
+        let instructions = create_three_level_tree();
+        let expected: Vec<(Pubkey, u32, u32)> = instructions
+            .iter()
+            .map(|(metadata, instruction)| {
+                (
+                    instruction.program_id,
+                    metadata.stack_height,
+                    metadata.index,
+                )
+            })
+            .collect();
+
+        let nested_instructions: NestedInstructions = instructions.into();
+        let flattened: Vec<(Pubkey, u32, u32)> = nested_instructions
+            .flatten()
+            .into_iter()
+            .map(|nested_instruction| {
+                (
+                    nested_instruction.instruction.program_id,
+                    nested_instruction.metadata.stack_height,
+                    nested_instruction.metadata.index,
+                )
+            })
+            .collect();
+
+        assert_eq!(flattened, expected);
+    }
+
+    #[test]
+    fn test_into_flattened_preserves_execution_order() {
+        let instructions = create_three_level_tree();
+        let expected: Vec<(Pubkey, u32, u32)> = instructions
+            .iter()
+            .map(|(metadata, instruction)| {
+                (
+                    instruction.program_id,
+                    metadata.stack_height,
+                    metadata.index,
+                )
+            })
+            .collect();
+
+        let nested_instructions: NestedInstructions = instructions.into();
+        let flattened = nested_instructions.into_flattened();
+
+        assert!(flattened
+            .iter()
+            .all(|nested_instruction| nested_instruction.inner_instructions.is_empty()));
+        assert_eq!(
+            flattened
+                .into_iter()
+                .map(|nested_instruction| (
+                    nested_instruction.instruction.program_id,
+                    nested_instruction.metadata.stack_height,
+                    nested_instruction.metadata.index,
+                ))
+                .collect::<Vec<_>>(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_flatten_empty() {
+        let nested_instructions = NestedInstructions::default();
+        assert!(nested_instructions.flatten().is_empty());
+        assert!(nested_instructions.into_flattened().is_empty());
+    }
+
+    #[test]
+    fn test_nested_instructions_collect_and_iterate() {
+        let instructions = vec![
+            create_instruction_with_metadata(1, 1),
+            create_instruction_with_metadata(2, 2),
+            create_instruction_with_metadata(3, 1),
+        ];
+        let nested_instructions: NestedInstructions = instructions.into();
+
+        let mut collected: NestedInstructions = nested_instructions
+            .iter()
+            .filter(|nested_instruction| !nested_instruction.inner_instructions.is_empty())
+            .cloned()
+            .collect();
+        assert_eq!(collected.len(), 1);
+
+        for nested_instruction in &mut collected {
+            nested_instruction.metadata.index = 10;
+        }
+        assert_eq!(
+            (&collected)
+                .into_iter()
+                .map(|nested_instruction| nested_instruction.metadata.index)
+                .collect::<Vec<_>>(),
+            vec![10]
+        );
+
+        let mut indexes = Vec::new();
+        for nested_instruction in nested_instructions {
+            indexes.push(nested_instruction.metadata.index);
+        }
+        assert_eq!(indexes, vec![1, 3]);
+    }
+
+    #[derive(Debug, crate::borsh::BorshDeserialize)]
+    struct TestInstruction {
+        amount: u64,
+    }
+
+    struct TestDecoder;
+
+    impl InstructionDecoder<'_> for TestDecoder {
+        type InstructionType = TestInstruction;
+
+        fn try_decode_instruction(
+            &self,
+            instruction: &solana_instruction::Instruction,
+        ) -> CarbonResult<Option<DecodedInstruction<Self::InstructionType>>> {
+            let decoded = crate::deserialize::try_deserialize_with_discriminator::<TestInstruction>(
+                &[7],
+                &instruction.data,
+            )?;
+
+            Ok(decoded.map(|data| DecodedInstruction {
+                program_id: instruction.program_id,
+                data,
+                accounts: instruction.accounts.clone(),
+                discriminator: vec![7],
+                raw_len: instruction.data.len(),
+                num_accounts: instruction.accounts.len(),
+                raw_data: instruction.data.clone(),
+            }))
+        }
+    }
+
+    struct CountingProcessor(Arc<std::sync::atomic::AtomicUsize>);
+
+    #[async_trait]
+    impl Processor for CountingProcessor {
+        type InputType = InstructionProcessorInputType<TestInstruction>;
+
+        async fn process(
+            &mut self,
+            _data: Self::InputType,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            self.0.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    fn create_nested_instruction(data: Vec<u8>) -> NestedInstruction {
+        let (metadata, mut instruction) = create_instruction_with_metadata(0, 1);
+        instruction.data = data;
+        NestedInstruction {
+            metadata,
+            instruction,
+            inner_instructions: NestedInstructions::default(),
+        }
+    }
+
+    /// Returns a pipe of `decoder` and `processor` with the settings a pipeline
+    /// builder gives it by default.
+    fn test_pipe<T: Send + 'static>(
+        decoder: Arc<dyn for<'a> InstructionDecoder<'a, InstructionType = T> + Send + Sync>,
+        processor: impl Processor<InputType = InstructionProcessorInputType<T>> + Send + Sync + 'static,
+    ) -> InstructionPipe<T> {
+        InstructionPipe {
+            decoder,
+            processor: Box::new(processor),
+            dead_letter_sink: None,
+            error_policy: None,
+            decoder_claims: Default::default(),
+            max_depth: DEFAULT_MAX_INSTRUCTION_DEPTH,
+            top_level_only: false,
+            decode_rates: Default::default(),
+        }
+    }
+
+    /// Decodes the layout `TestDecoder` replaced, whose amount was a `u32`.
+    struct LegacyTestDecoder;
+
+    impl InstructionDecoder<'_> for LegacyTestDecoder {
+        type InstructionType = TestInstruction;
+
+        fn try_decode_instruction(
+            &self,
+            instruction: &solana_instruction::Instruction,
+        ) -> CarbonResult<Option<DecodedInstruction<Self::InstructionType>>> {
+            let decoded = crate::deserialize::try_deserialize_with_discriminator::<u32>(
+                &[7],
+                &instruction.data,
+            )?;
+
+            Ok(decoded.map(|amount| DecodedInstruction {
+                program_id: instruction.program_id,
+                data: TestInstruction {
+                    amount: amount.into(),
+                },
+                accounts: instruction.accounts.clone(),
+                discriminator: vec![7],
+                raw_len: instruction.data.len(),
+                num_accounts: instruction.accounts.len(),
+                raw_data: instruction.data.clone(),
+            }))
+        }
+    }
+
+    struct AmountRecorder(Arc<std::sync::Mutex<Vec<u64>>>);
+
+    #[async_trait]
+    impl Processor for AmountRecorder {
+        type InputType = InstructionProcessorInputType<TestInstruction>;
+
+        async fn process(
+            &mut self,
+            (_, instruction, _): Self::InputType,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            self.0.lock().unwrap().push(instruction.data.amount);
+            Ok(())
+        }
+    }
+
+    struct StackHeightRecorder(Arc<std::sync::Mutex<Vec<u32>>>);
+
+    #[async_trait]
+    impl Processor for StackHeightRecorder {
+        type InputType = InstructionProcessorInputType<TestInstruction>;
+
+        async fn process(
+            &mut self,
+            (metadata, _, _): Self::InputType,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            self.0.lock().unwrap().push(metadata.stack_height);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_top_level_only_skips_inner_instructions() {
+        // Arrange
+        let data = [vec![7], 42u64.to_le_bytes().to_vec()].concat();
+        let at_height = |stack_height: u32, inner_instructions: Vec<NestedInstruction>| {
+            let mut nested_instruction = create_nested_instruction(data.clone());
+            nested_instruction.metadata.stack_height = stack_height;
+            nested_instruction.inner_instructions = NestedInstructions(inner_instructions);
+            nested_instruction
+        };
+        let tree = at_height(
+            1,
+            vec![
+                at_height(2, vec![at_height(3, vec![])]),
+                at_height(2, vec![]),
+            ],
+        );
+        let metrics = Arc::new(MetricsCollection::new(vec![]));
+        let run = |top_level_only: bool| {
+            let stack_heights = Arc::new(std::sync::Mutex::new(Vec::new()));
+            let mut pipe = InstructionPipe {
+                top_level_only,
+                ..test_pipe(
+                    Arc::new(TestDecoder),
+                    StackHeightRecorder(stack_heights.clone()),
+                )
+            };
+            futures::executor::block_on(pipe.run(&tree, metrics.clone())).unwrap();
+            let stack_heights = stack_heights.lock().unwrap().clone();
+            stack_heights
+        };
+
+        // Act
+        let all = run(false);
+        let top_level = run(true);
+
+        // Assert
+        assert_eq!(all, vec![1, 2, 3, 2]);
+        assert_eq!(top_level, vec![1]);
+    }
+
+    #[test]
+    fn test_versioned_decoder_picks_the_layout_of_the_instruction_slot() {
+        // Arrange
+        let amounts = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let recorder = Arc::new(RecordingMetrics::default());
+        let metrics = Arc::new(MetricsCollection::new(vec![recorder.clone()]));
+        let decoder = VersionedInstructionDecoder::new()
+            .layout(100..200, LegacyTestDecoder)
+            .layout(200.., TestDecoder);
+        let mut pipe = test_pipe(Arc::new(decoder), AmountRecorder(amounts.clone()));
+        let at_slot = |slot: u64, data: Vec<u8>| {
+            let mut nested_instruction = create_nested_instruction(data);
+            nested_instruction.metadata.transaction_metadata.slot = slot;
+            nested_instruction
+        };
+        let legacy = [vec![7], 5u32.to_le_bytes().to_vec()].concat();
+        let current = [vec![7], 42u64.to_le_bytes().to_vec()].concat();
+
+        // Act
+        futures::executor::block_on(async {
+            for nested_instruction in [
+                at_slot(150, legacy.clone()),
+                at_slot(250, current.clone()),
+                at_slot(50, legacy.clone()),
+            ] {
+                pipe.run(&nested_instruction, metrics.clone())
+                    .await
+                    .unwrap();
+            }
+        });
+
+        // Assert
+        assert_eq!(*amounts.lock().unwrap(), vec![5, 42]);
+        assert_eq!(recorder.counter("instructions_outside_slot_ranges"), 1);
+        assert_eq!(
+            pipe.decoder
+                .try_decode_instruction(&at_slot(0, current).instruction)
+                .unwrap()
+                .map(|decoded| decoded.data.amount),
+            Some(42)
+        );
+    }
+
+    #[test]
+    fn test_try_decode_instruction_distinguishes_mismatch_from_malformed() {
+        let valid = create_nested_instruction([vec![7], 42u64.to_le_bytes().to_vec()].concat());
+        let other_discriminator = create_nested_instruction(vec![8, 0, 0]);
+        let malformed = create_nested_instruction(vec![7, 1, 2]);
+
+        let decoded = TestDecoder
+            .try_decode_instruction(&valid.instruction)
+            .expect("valid instruction")
+            .expect("matching discriminator");
+        assert_eq!(decoded.data.amount, 42);
+        assert!(TestDecoder
+            .try_decode_instruction(&other_discriminator.instruction)
+            .expect("mismatched discriminator")
+            .is_none());
+        assert!(TestDecoder
+            .try_decode_instruction(&malformed.instruction)
+            .is_err());
+
+        // The infallible method keeps its previous behavior.
+        assert!(TestDecoder
+            .decode_instruction(&malformed.instruction)
+            .is_none());
+    }
+
+    #[test]
+    fn test_truncated_instruction_data_is_a_deserialization_error() {
+        let truncated = create_nested_instruction(vec![7, 42, 0, 0]);
+        let mut pipe = test_pipe(Arc::new(TestDecoder), CountingProcessor(Arc::default()));
+        let metrics = Arc::new(MetricsCollection::new(vec![]));
+
+        let error = TestDecoder
+            .try_decode_instruction(&truncated.instruction)
+            .unwrap_err();
+        match error {
+            CarbonError::Deserialization {
+                type_name,
+                discriminator,
+                ..
+            } => {
+                assert!(type_name.ends_with("TestInstruction"));
+                assert_eq!(discriminator, vec![7]);
+            }
+            error => panic!("unexpected error: {error:?}"),
+        }
+
+        let error = futures::executor::block_on(pipe.run(&truncated, metrics)).unwrap_err();
+        match error {
+            CarbonError::Decode {
+                program_id,
+                decoder,
+                source,
+            } => {
+                assert_eq!(program_id, truncated.instruction.program_id);
+                assert_eq!(decoder, "TestDecoder");
+                assert!(matches!(*source, CarbonError::Deserialization { .. }));
+            }
+            error => panic!("unexpected error: {error:?}"),
+        }
+    }
+
+    #[test]
+    fn test_instruction_pipe_surfaces_decode_errors() {
+        let processed = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let mut pipe = test_pipe(Arc::new(TestDecoder), CountingProcessor(processed.clone()));
+        let metrics = Arc::new(MetricsCollection::new(vec![]));
+
+        let valid = create_nested_instruction([vec![7], 42u64.to_le_bytes().to_vec()].concat());
+        let other_discriminator = create_nested_instruction(vec![8]);
+        let malformed = create_nested_instruction(vec![7, 1]);
+
+        futures::executor::block_on(async {
+            assert!(pipe.run(&valid, metrics.clone()).await.is_ok());
+            assert!(pipe
+                .run(&other_discriminator, metrics.clone())
+                .await
+                .is_ok());
+            assert!(pipe.run(&malformed, metrics.clone()).await.is_err());
+        });
+
+        assert_eq!(processed.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    struct SlowProcessor;
+
+    #[async_trait]
+    impl Processor for SlowProcessor {
+        type InputType = InstructionProcessorInputType<TestInstruction>;
+
+        async fn process(
+            &mut self,
+            _data: Self::InputType,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            std::thread::sleep(std::time::Duration::from_millis(5));
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_decoder_name_is_unqualified_type_name() {
+        assert_eq!(TestDecoder.decoder_name(), "TestDecoder");
+        assert_eq!(
+            short_type_name("carbon_core::pipe::Decoder<alloc::vec::Vec<u8>>"),
+            "Decoder"
+        );
+    }
+
+    #[test]
+    fn test_instruction_pipe_records_latency_per_decoder() {
+        let recorder = Arc::new(RecordingMetrics::default());
+        let metrics = Arc::new(MetricsCollection::new(vec![recorder.clone()]));
+        let mut pipe = test_pipe(Arc::new(TestDecoder), SlowProcessor);
+        let valid = create_nested_instruction([vec![7], 42u64.to_le_bytes().to_vec()].concat());
+
+        futures::executor::block_on(pipe.run(&valid, metrics)).unwrap();
+
+        let observations =
+            recorder.histogram("instruction_process_time_milliseconds{decoder=TestDecoder}");
+        assert_eq!(observations.len(), 1);
+        assert!(observations[0] >= 5.0);
+    }
+
+    /// Decodes like `TestDecoder`, but only for `program_id`, and counts how
+    /// often it is asked to decode.
+    struct ProgramTestDecoder {
+        program_id: [Pubkey; 1],
+        attempts: std::sync::atomic::AtomicUsize,
+    }
+
+    impl<'a> InstructionDecoder<'a> for ProgramTestDecoder {
+        type InstructionType = TestInstruction;
+
+        fn try_decode_instruction(
+            &self,
+            instruction: &'a Instruction,
+        ) -> CarbonResult<Option<DecodedInstruction<Self::InstructionType>>> {
+            self.attempts
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            TestDecoder.try_decode_instruction(instruction)
+        }
+
+        fn program_ids(&self) -> &[Pubkey] {
+            &self.program_id
+        }
+    }
+
+    #[test]
+    fn test_instruction_pipe_skips_decoding_for_other_programs() {
+        let decoder = Arc::new(ProgramTestDecoder {
+            program_id: [Pubkey::new_unique()],
+            attempts: Default::default(),
+        });
+        let processed = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let mut pipe = test_pipe(decoder.clone(), CountingProcessor(processed.clone()));
+        let data = [vec![7], 42u64.to_le_bytes().to_vec()].concat();
+        let mut inner = create_nested_instruction(data.clone());
+        inner.instruction.program_id = decoder.program_id[0];
+        let mut outer = create_nested_instruction(data);
+        outer.inner_instructions = NestedInstructions(vec![inner]);
+
+        futures::executor::block_on(pipe.run(&outer, Arc::new(MetricsCollection::new(vec![]))))
+            .unwrap();
+
+        // The outer instruction belongs to another program and is never
+        // decoded, but its inner instruction still is.
+        assert_eq!(
+            decoder.attempts.load(std::sync::atomic::Ordering::SeqCst),
+            1
+        );
+        assert_eq!(processed.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    /// Decodes like `TestDecoder` for instructions with at least two accounts,
+    /// and counts how often it is asked to decode.
+    #[derive(Default)]
+    struct MinAccountsTestDecoder {
+        attempts: std::sync::atomic::AtomicUsize,
+    }
+
+    impl<'a> InstructionDecoder<'a> for MinAccountsTestDecoder {
+        type InstructionType = TestInstruction;
+
+        fn try_decode_instruction(
+            &self,
+            instruction: &'a Instruction,
+        ) -> CarbonResult<Option<DecodedInstruction<Self::InstructionType>>> {
+            self.attempts
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            TestDecoder.try_decode_instruction(instruction)
+        }
+
+        fn min_accounts(&self) -> usize {
+            2
+        }
+    }
+
+    #[test]
+    fn test_instruction_pipe_skips_decoding_with_too_few_accounts() {
+        let decoder = Arc::new(MinAccountsTestDecoder::default());
+        let processed = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let mut pipe = test_pipe(decoder.clone(), CountingProcessor(processed.clone()));
+        let data = [vec![7], 42u64.to_le_bytes().to_vec()].concat();
+        let too_short = create_nested_instruction(data.clone());
+        let mut complete = create_nested_instruction(data);
+        complete
+            .instruction
+            .accounts
+            .push(AccountMeta::new(Pubkey::new_unique(), false));
+        let metrics = Arc::new(MetricsCollection::new(vec![]));
+
+        futures::executor::block_on(async {
+            pipe.run(&too_short, metrics.clone()).await.unwrap();
+            pipe.run(&complete, metrics.clone()).await.unwrap();
+        });
+
+        // The instruction with a single account is rejected before the
+        // decoder is invoked.
+        assert_eq!(
+            decoder.attempts.load(std::sync::atomic::Ordering::SeqCst),
+            1
+        );
+        assert_eq!(processed.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    /// Decodes like `TestDecoder` for `program_id`, under its own name.
+    struct NamedTestDecoder {
+        name: &'static str,
+        program_id: [Pubkey; 1],
+    }
+
+    impl<'a> InstructionDecoder<'a> for NamedTestDecoder {
+        type InstructionType = TestInstruction;
+
+        fn try_decode_instruction(
+            &self,
+            instruction: &'a Instruction,
+        ) -> CarbonResult<Option<DecodedInstruction<Self::InstructionType>>> {
+            TestDecoder.try_decode_instruction(instruction)
+        }
+
+        fn decoder_name(&self) -> &'static str {
+            self.name
+        }
+
+        fn program_ids(&self) -> &[Pubkey] {
+            &self.program_id
+        }
+    }
+
+    #[test]
+    fn test_colliding_decoders_only_decode_their_program() {
+        let program_id = Pubkey::new_unique();
+        let other_program_id = Pubkey::new_unique();
+        let recorder = Arc::new(RecordingMetrics::default());
+        let metrics = Arc::new(MetricsCollection::new(vec![recorder.clone()]));
+        let decoder_claims = Arc::new(DecoderClaims::default());
+        let pipe = |name: &'static str, program_id: Pubkey| {
+            let processed = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+            let pipe = InstructionPipe {
+                decoder_claims: decoder_claims.clone(),
+                ..test_pipe(
+                    Arc::new(NamedTestDecoder {
+                        name,
+                        program_id: [program_id],
+                    }),
+                    CountingProcessor(processed.clone()),
+                )
+            };
+            (pipe, processed)
+        };
+        // Both decoders use discriminator 7, but for different programs.
+        let (mut program_pipe, program_processed) = pipe("ProgramDecoder", program_id);
+        let (mut other_pipe, other_processed) = pipe("OtherProgramDecoder", other_program_id);
+        let (mut colliding_pipe, colliding_processed) = pipe("CollidingDecoder", program_id);
+        let mut instruction =
+            create_nested_instruction([vec![7], 42u64.to_le_bytes().to_vec()].concat());
+        instruction.instruction.program_id = program_id;
+        let decoder_collisions = || recorder.counter("decoder_collision");
+
+        futures::executor::block_on(async {
+            program_pipe
+                .run(&instruction, metrics.clone())
+                .await
+                .unwrap();
+            other_pipe.run(&instruction, metrics.clone()).await.unwrap();
+        });
+
+        assert_eq!(
+            program_processed.load(std::sync::atomic::Ordering::SeqCst),
+            1
+        );
+        assert_eq!(other_processed.load(std::sync::atomic::Ordering::SeqCst), 0);
+        assert_eq!(decoder_collisions(), 0);
+
+        // A second decoder for the same program ID and discriminator still
+        // decodes, but every such instruction is counted as a collision.
+        futures::executor::block_on(async {
+            colliding_pipe
+                .run(&instruction, metrics.clone())
+                .await
+                .unwrap();
+            colliding_pipe
+                .run(&instruction, metrics.clone())
+                .await
+                .unwrap();
+            program_pipe
+                .run(&instruction, metrics.clone())
+                .await
+                .unwrap();
+        });
+
+        assert_eq!(
+            colliding_processed.load(std::sync::atomic::Ordering::SeqCst),
+            2
+        );
+        assert_eq!(
+            program_processed.load(std::sync::atomic::Ordering::SeqCst),
+            2
+        );
+        assert_eq!(decoder_collisions(), 2);
+        assert_eq!(
+            recorder.counter("instructions_decoded{decoder=CollidingDecoder}"),
+            2
+        );
+    }
+
+    #[test]
+    fn test_instruction_pipe_reports_decode_rate_per_program() {
+        // Arrange
+        let program_id = Pubkey::new_unique();
+        let recorder = Arc::new(RecordingMetrics::default());
+        let metrics = Arc::new(MetricsCollection::new(vec![recorder.clone()]));
+        let mut pipe = test_pipe(
+            Arc::new(ProgramTestDecoder {
+                program_id: [program_id],
+                attempts: Default::default(),
+            }),
+            CountingProcessor(Arc::default()),
+        );
+        let instruction = |discriminator: u8, program_id: Pubkey| {
+            let mut instruction = create_nested_instruction(
+                [vec![discriminator], 42u64.to_le_bytes().to_vec()].concat(),
+            );
+            instruction.instruction.program_id = program_id;
+            instruction
+        };
+        let matching = instruction(7, program_id);
+        let unknown_discriminator = instruction(8, program_id);
+        let other_program_id = Pubkey::new_unique();
+        let other_program = instruction(7, other_program_id);
+
+        // Act
+        futures::executor::block_on(async {
+            for _ in 0..7 {
+                pipe.run(&matching, metrics.clone()).await.unwrap();
+            }
+            for _ in 0..3 {
+                pipe.run(&unknown_discriminator, metrics.clone())
+                    .await
+                    .unwrap();
+            }
+            pipe.run(&other_program, metrics.clone()).await.unwrap();
+        });
+
+        // Assert
+        assert_eq!(
+            recorder.gauge(&format!("decode_rate{{program_id={program_id}}}")),
+            Some(0.7)
+        );
+        assert_eq!(
+            recorder.gauge(&format!("decode_rate{{program_id={other_program_id}}}")),
+            None
+        );
+    }
+
+    #[test]
+    fn test_instruction_pipe_truncates_deeply_nested_instructions() {
+        let recorder = Arc::new(RecordingMetrics::default());
+        let metrics = Arc::new(MetricsCollection::new(vec![recorder.clone()]));
+        let processed = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let mut pipe = test_pipe(Arc::new(TestDecoder), CountingProcessor(processed.clone()));
+        let data = [vec![7], 42u64.to_le_bytes().to_vec()].concat();
+        let mut nested_instruction = create_nested_instruction(data.clone());
+        for _ in 1..10_000 {
+            let mut outer = create_nested_instruction(data.clone());
+            outer.inner_instructions = NestedInstructions(vec![nested_instruction]);
+            nested_instruction = outer;
+        }
+
+        futures::executor::block_on(pipe.run(&nested_instruction, metrics)).unwrap();
+
+        assert_eq!(
+            processed.load(std::sync::atomic::Ordering::SeqCst),
+            DEFAULT_MAX_INSTRUCTION_DEPTH
+        );
+        // The walk stops at the first skipped instruction, without visiting
+        // the instructions nested below it.
+        assert_eq!(recorder.counter("instruction_depth_truncated"), 1);
+
+        // Dropping the tree must not overflow the test thread's stack either.
+        drop(nested_instruction);
+    }
+
+    #[test]
+    fn test_nested_instructions_leave_out_instructions_deeper_than_max_depth() {
+        let flat: InstructionsWithMetadata = [1, 2, 3, 4, 2, 3, 1]
+            .into_iter()
+            .enumerate()
+            .map(|(index, stack_height)| {
+                create_instruction_with_metadata(index as u32, stack_height)
+            })
+            .collect();
+
+        let (nested_instructions, left_out) = NestedInstructions::with_max_depth(flat, 2);
+
+        assert_eq!(left_out, 3);
+        let stack_heights: Vec<u32> = nested_instructions
+            .flatten()
+            .iter()
+            .map(|nested_instruction| nested_instruction.metadata.stack_height)
+            .collect();
+        assert_eq!(stack_heights, vec![1, 2, 2, 1]);
+        assert!(nested_instructions[0]
+            .inner_instructions
+            .iter()
+            .all(|inner_instruction| inner_instruction.inner_instructions.is_empty()));
+    }
+
+    #[test]
+    fn test_independently_built_trees_are_equal() {
+        let transaction_metadata = TransactionMetadata::default();
+        let program_id = Pubkey::new_unique();
+        let node = |index: u32,
+                    stack_height: u32,
+                    absolute_path: Vec<u32>,
+                    inner_instructions: Vec<NestedInstruction>| {
+            NestedInstruction {
+                metadata: InstructionMetadata {
+                    transaction_metadata: transaction_metadata.clone(),
+                    stack_height,
+                    index,
+                    accounts_resolved: true,
+                    compute_units: None,
+                    absolute_path,
+                    transaction_context: None,
+                },
+                instruction: Instruction {
+                    program_id,
+                    accounts: vec![],
+                    data: vec![index as u8],
+                },
+                inner_instructions: NestedInstructions(inner_instructions),
+            }
+        };
+        let flat: InstructionsWithMetadata = [(0, 1), (1, 2), (2, 3), (3, 2), (4, 1)]
+            .into_iter()
+            .map(|(index, stack_height)| {
+                let nested_instruction = node(index, stack_height, vec![], vec![]);
+                (
+                    nested_instruction.metadata.clone(),
+                    nested_instruction.instruction.clone(),
+                )
+            })
+            .collect();
+
+        let from_flat = NestedInstructions::from(flat);
+        let by_hand = NestedInstructions(vec![
+            node(
+                0,
+                1,
+                vec![0],
+                vec![
+                    node(1, 2, vec![0, 0], vec![node(2, 3, vec![0, 0, 0], vec![])]),
+                    node(3, 2, vec![0, 1], vec![]),
+                ],
+            ),
+            node(4, 1, vec![1], vec![]),
+        ]);
+
+        assert_eq!(from_flat, by_hand);
+        let mut different = by_hand.clone();
+        different[0].inner_instructions[1].instruction.data = vec![42];
+        assert_ne!(from_flat, different);
     }
 }
@@ -0,0 +1,239 @@
+//! Writes decoded instructions as JSON Lines, for inspecting a decoder's output
+//! without setting up a database.
+//!
+//! [`JsonlSinkProcessor`] is an instruction processor that serializes each
+//! decoded instruction together with its metadata to any `Write` sink, one JSON
+//! object per line. It is only compiled with the `jsonl` feature.
+//!
+//! ```ignore
+//! let pipeline = Pipeline::builder()
+//!     .datasource(datasource)
+//!     .instruction(DriftDecoder, JsonlSinkProcessor::stdout())
+//!     .build()?;
+//! ```
+//!
+//! Each line has the following shape, with public keys and the signature
+//! base58-encoded and `data` serialized with the `Serialize` implementation of
+//! the instruction type:
+//!
+//! ```json
+//! {"signature":"…","slot":42,"block_time":null,"stack_height":1,"index":0,
+//!  "program_id":"…","accounts":[{"pubkey":"…","is_signer":true,"is_writable":true}],
+//!  "data":{…}}
+//! ```
+
+use {
+    crate::{
+        error::CarbonResult,
+        instruction::{DecodedInstruction, InstructionMetadata, InstructionProcessorInputType},
+        metrics::MetricsCollection,
+        processor::Processor,
+    },
+    async_trait::async_trait,
+    serde::Serialize,
+    std::{
+        io::{BufWriter, Stdout, Write},
+        marker::PhantomData,
+        sync::Arc,
+        time::{Duration, Instant},
+    },
+};
+
+const DEFAULT_FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// A decoded instruction as written by [`JsonlSinkProcessor`].
+#[derive(Debug, Serialize)]
+pub struct JsonlInstruction<'a, T> {
+    pub signature: String,
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    pub stack_height: u32,
+    pub index: u32,
+    pub program_id: String,
+    pub accounts: Vec<JsonlAccountMeta>,
+    pub data: &'a T,
+}
+
+#[derive(Debug, Serialize)]
+pub struct JsonlAccountMeta {
+    pub pubkey: String,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+impl<'a, T> JsonlInstruction<'a, T> {
+    pub fn new(metadata: &InstructionMetadata, instruction: &'a DecodedInstruction<T>) -> Self {
+        Self {
+            signature: metadata.transaction_metadata.signature.to_string(),
+            slot: metadata.slot(),
+            block_time: metadata.block_time(),
+            stack_height: metadata.stack_height,
+            index: metadata.index,
+            program_id: instruction.program_id.to_string(),
+            accounts: instruction
+                .accounts
+                .iter()
+                .map(|account| JsonlAccountMeta {
+                    pubkey: account.pubkey.to_string(),
+                    is_signer: account.is_signer,
+                    is_writable: account.is_writable,
+                })
+                .collect(),
+            data: &instruction.data,
+        }
+    }
+}
+
+/// An instruction processor that writes every decoded instruction as a line of
+/// JSON to `W`.
+///
+/// Output is buffered and flushed at most every `flush_interval`, one second
+/// by default, as well as on shutdown.
+pub struct JsonlSinkProcessor<T, W: Write> {
+    writer: BufWriter<W>,
+    flush_interval: Duration,
+    last_flush: Instant,
+    instruction: PhantomData<fn(T)>,
+}
+
+impl<T> JsonlSinkProcessor<T, Stdout> {
+    /// Writes the instructions to standard output.
+    pub fn stdout() -> Self {
+        Self::new(std::io::stdout())
+    }
+}
+
+impl<T, W: Write> JsonlSinkProcessor<T, W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer: BufWriter::new(writer),
+            flush_interval: DEFAULT_FLUSH_INTERVAL,
+            last_flush: Instant::now(),
+            instruction: PhantomData,
+        }
+    }
+
+    /// Sets how long written lines may stay buffered. `Duration::ZERO` flushes
+    /// after every instruction.
+    pub fn with_flush_interval(mut self, flush_interval: Duration) -> Self {
+        self.flush_interval = flush_interval;
+        self
+    }
+
+    /// Flushes the buffered lines and returns the underlying writer.
+    pub fn into_inner(self) -> CarbonResult<W> {
+        self.writer
+            .into_inner()
+            .map_err(|err| err.into_error().into())
+    }
+
+    fn flush(&mut self) -> CarbonResult<()> {
+        self.writer.flush()?;
+        self.last_flush = Instant::now();
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<T, W> Processor for JsonlSinkProcessor<T, W>
+where
+    T: Serialize + Send + Sync + 'static,
+    W: Write + Send + Sync + 'static,
+{
+    type InputType = InstructionProcessorInputType<T>;
+
+    async fn process(
+        &mut self,
+        (metadata, instruction, _): Self::InputType,
+        _metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        serde_json::to_writer(
+            &mut self.writer,
+            &JsonlInstruction::new(&metadata, &instruction),
+        )?;
+        self.writer.write_all(b"\n")?;
+
+        if self.last_flush.elapsed() >= self.flush_interval {
+            self.flush()?;
+        }
+
+        Ok(())
+    }
+
+    async fn shutdown(&mut self) -> CarbonResult<()> {
+        self.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{instruction::NestedInstructions, transaction::TransactionMetadata},
+        solana_instruction::AccountMeta,
+        solana_pubkey::Pubkey,
+    };
+
+    #[derive(Debug, Serialize)]
+    struct Transfer {
+        amount: u64,
+    }
+
+    #[tokio::test]
+    async fn test_jsonl_sink_writes_one_json_object_per_line() {
+        // Arrange
+        let program_id = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let mut processor = JsonlSinkProcessor::new(Vec::new());
+        let metrics = Arc::new(MetricsCollection::new(vec![]));
+        let input = |index: u32, amount: u64| {
+            (
+                InstructionMetadata {
+                    transaction_metadata: TransactionMetadata {
+                        slot: 42,
+                        ..Default::default()
+                    },
+                    stack_height: 1,
+                    index,
+                    accounts_resolved: true,
+                    compute_units: None,
+                    absolute_path: vec![],
+                    transaction_context: None,
+                },
+                DecodedInstruction {
+                    program_id,
+                    data: Transfer { amount },
+                    accounts: vec![AccountMeta::new(authority, true)],
+                    discriminator: vec![],
+                    raw_len: 0,
+                    num_accounts: 1,
+                    raw_data: vec![],
+                },
+                NestedInstructions::default(),
+            )
+        };
+
+        // Act
+        processor
+            .process(input(0, 100), metrics.clone())
+            .await
+            .unwrap();
+        processor.process(input(1, 200), metrics).await.unwrap();
+        let output = String::from_utf8(processor.into_inner().unwrap()).unwrap();
+
+        // Assert
+        let lines: Vec<serde_json::Value> = output
+            .lines()
+            .map(|line| serde_json::from_str(line).expect("valid JSON line"))
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["slot"], 42);
+        assert_eq!(lines[0]["index"], 0);
+        assert_eq!(lines[0]["program_id"], program_id.to_string());
+        assert_eq!(lines[0]["accounts"][0]["pubkey"], authority.to_string());
+        assert_eq!(lines[0]["accounts"][0]["is_signer"], true);
+        assert_eq!(lines[0]["data"]["amount"], 100);
+        assert_eq!(lines[1]["index"], 1);
+        assert_eq!(lines[1]["data"]["amount"], 200);
+    }
+}
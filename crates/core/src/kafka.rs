@@ -0,0 +1,300 @@
+//! Publishes decoded data to Kafka. Only available with the `kafka` feature.
+//!
+//! [`KafkaProcessor`] sends one record per input to a topic. The value of the
+//! record is a [`KafkaMessage`] holding the decoded instruction or event and
+//! the [`KafkaMetadata`] of the instruction it was found in, encoded by a
+//! [`KafkaCodec`] (JSON by default). The signature, slot and path of the
+//! instruction let consumers order the messages and drop duplicates, for
+//! example after a restart. The key of the record is the base58 address of the
+//! program the input belongs to. Kafka assigns records with the same key to the
+//! same partition, so the records of a program keep their order.
+//!
+//! Inputs are buffered by a [`BatchingProcessor`], and the records of a batch
+//! are sent concurrently, with the batch completing once every record has been
+//! acknowledged. Records are sent through a [`KafkaProducer`], which is
+//! implemented for `rdkafka`'s `FutureProducer`. A batch with a record that
+//! could not be delivered is reported by the next call to `process`, or by
+//! `shutdown`, as described in [`BatchingProcessor`].
+//!
+//! The processor records the `kafka_records_sent` and `kafka_records_failed`
+//! counters.
+//!
+//! ```ignore
+//! let producer: FutureProducer = ClientConfig::new()
+//!     .set("bootstrap.servers", "localhost:9092")
+//!     .set("message.timeout.ms", "5000")
+//!     .create()?;
+//!
+//! let pipeline = Pipeline::builder()
+//!     .instruction(SwapDecoder, KafkaProcessor::new(producer, "swaps"))
+//!     .build()?;
+//! ```
+
+pub use rdkafka::{
+    producer::{FutureProducer, FutureRecord},
+    util::Timeout,
+    ClientConfig,
+};
+use {
+    crate::{
+        error::{CarbonResult, Error},
+        event::{DecodedEvent, EventProcessorInputType},
+        instruction::{DecodedInstruction, InstructionMetadata, InstructionProcessorInputType},
+        metrics::MetricsCollection,
+        processor::{BatchProcessor, BatchingProcessor, Processor},
+    },
+    async_trait::async_trait,
+    futures::future::join_all,
+    serde::Serialize,
+    solana_pubkey::Pubkey,
+    std::{sync::Arc, time::Duration},
+};
+
+/// An input of a [`KafkaProcessor`].
+///
+/// Implemented for the inputs of instruction and event processors, where the
+/// payload is the `DecodedInstruction` or `DecodedEvent`.
+pub trait KafkaRecord: Send + 'static {
+    /// The value that is encoded as the body of the record.
+    type Payload;
+
+    /// The program the input belongs to, used as the key of the record.
+    fn program_id(&self) -> Pubkey;
+
+    /// Returns the metadata of the instruction the input was found in.
+    fn metadata(&self) -> &InstructionMetadata;
+
+    /// Returns the value that is encoded as the body of the record.
+    fn payload(&self) -> &Self::Payload;
+}
+
+impl<T: Send + 'static> KafkaRecord for InstructionProcessorInputType<T> {
+    type Payload = DecodedInstruction<T>;
+
+    fn program_id(&self) -> Pubkey {
+        self.1.program_id
+    }
+
+    fn metadata(&self) -> &InstructionMetadata {
+        &self.0
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        &self.1
+    }
+}
+
+impl<T: Send + 'static> KafkaRecord for EventProcessorInputType<T> {
+    type Payload = DecodedEvent<T>;
+
+    fn program_id(&self) -> Pubkey {
+        self.1.program_id
+    }
+
+    fn metadata(&self) -> &InstructionMetadata {
+        &self.0
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        &self.1
+    }
+}
+
+/// The parts of the `InstructionMetadata` of an input that are sent with its
+/// record.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct KafkaMetadata {
+    /// The base58 signature of the transaction.
+    pub signature: String,
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    /// The index of the instruction among its siblings.
+    pub index: u32,
+    pub stack_height: u32,
+    /// The position of the instruction in the transaction's instruction tree,
+    /// which together with `signature` identifies it.
+    pub absolute_path: Vec<u32>,
+}
+
+impl From<&InstructionMetadata> for KafkaMetadata {
+    fn from(metadata: &InstructionMetadata) -> Self {
+        Self {
+            signature: metadata.transaction_metadata.signature.to_string(),
+            slot: metadata.slot(),
+            block_time: metadata.block_time(),
+            index: metadata.index,
+            stack_height: metadata.stack_height,
+            absolute_path: metadata.absolute_path.clone(),
+        }
+    }
+}
+
+/// The body of a record, before it is encoded.
+#[derive(Debug, Serialize)]
+pub struct KafkaMessage<'a, P> {
+    pub metadata: KafkaMetadata,
+    pub payload: &'a P,
+}
+
+/// Encodes the messages of a [`KafkaProcessor`] into the bodies of records.
+pub trait KafkaCodec<P>: Send + Sync {
+    fn encode(&self, message: &KafkaMessage<'_, P>) -> CarbonResult<Vec<u8>>;
+}
+
+/// Encodes messages as JSON. The default codec of a [`KafkaProcessor`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct JsonCodec;
+
+impl<P: Serialize> KafkaCodec<P> for JsonCodec {
+    fn encode(&self, message: &KafkaMessage<'_, P>) -> CarbonResult<Vec<u8>> {
+        Ok(serde_json::to_vec(message)?)
+    }
+}
+
+/// Sends records to Kafka.
+///
+/// `send` completes once the broker has acknowledged the record, and fails if
+/// it could not be delivered.
+#[async_trait]
+pub trait KafkaProducer: Send + Sync {
+    async fn send(&self, topic: &str, key: &[u8], payload: &[u8]) -> CarbonResult<()>;
+}
+
+#[async_trait]
+impl KafkaProducer for FutureProducer {
+    async fn send(&self, topic: &str, key: &[u8], payload: &[u8]) -> CarbonResult<()> {
+        let record = FutureRecord::to(topic).key(key).payload(payload);
+
+        // Waiting while the producer queue is full applies backpressure to the
+        // batches; `message.timeout.ms` still bounds the delivery.
+        FutureProducer::send(self, record, Timeout::Never)
+            .await
+            .map(|_| ())
+            .map_err(|(error, _)| {
+                Error::Custom(format!(
+                    "Failed to deliver Kafka record to {topic}: {error}"
+                ))
+            })
+    }
+}
+
+/// A processor that publishes a record per input to a Kafka topic, in
+/// batches.
+///
+/// See the [module documentation](self) for details.
+///
+/// # Defaults
+///
+/// - `batch_size`: 100
+/// - `flush_interval`: 1 second
+/// - `codec`: `JsonCodec`
+pub struct KafkaProcessor<T: KafkaRecord> {
+    batching: BatchingProcessor<KafkaWriter<T>>,
+}
+
+impl<T> KafkaProcessor<T>
+where
+    T: KafkaRecord,
+    T::Payload: Serialize,
+{
+    /// Creates a processor publishing JSON records to `topic` with
+    /// `producer`.
+    pub fn new(producer: impl KafkaProducer + 'static, topic: impl Into<String>) -> Self {
+        Self {
+            batching: BatchingProcessor::new(KafkaWriter {
+                producer: Box::new(producer),
+                topic: topic.into(),
+                codec: Box::new(JsonCodec),
+            }),
+        }
+    }
+}
+
+impl<T: KafkaRecord> KafkaProcessor<T> {
+    /// Sets the codec that encodes the bodies of the records.
+    pub fn codec(mut self, codec: impl KafkaCodec<T::Payload> + 'static) -> Self {
+        if let Some(writer) = self.batching.processor.as_mut() {
+            writer.codec = Box::new(codec);
+        }
+        self
+    }
+
+    /// Sets the number of records sent per batch. Values below `1` are treated
+    /// as `1`.
+    pub fn batch_size(mut self, batch_size: usize) -> Self {
+        self.batching = self.batching.batch_size(batch_size);
+        self
+    }
+
+    /// Sets the longest time an input waits before its record is sent.
+    pub fn flush_interval(mut self, flush_interval: Duration) -> Self {
+        self.batching = self.batching.flush_interval(flush_interval);
+        self
+    }
+}
+
+#[async_trait]
+impl<T: KafkaRecord> Processor for KafkaProcessor<T> {
+    type InputType = T;
+
+    async fn process(
+        &mut self,
+        data: Self::InputType,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        self.batching.process(data, metrics).await
+    }
+
+    async fn shutdown(&mut self) -> CarbonResult<()> {
+        self.batching.shutdown().await
+    }
+}
+
+/// Sends each batch of a `KafkaProcessor`.
+struct KafkaWriter<T: KafkaRecord> {
+    producer: Box<dyn KafkaProducer>,
+    topic: String,
+    codec: Box<dyn KafkaCodec<T::Payload>>,
+}
+
+#[async_trait]
+impl<T: KafkaRecord> BatchProcessor for KafkaWriter<T> {
+    type InputType = T;
+
+    /// Encodes and sends the records of `items`, returning the first error.
+    async fn process_batch(
+        &self,
+        items: Vec<Self::InputType>,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        let records = items
+            .iter()
+            .map(|item| {
+                Ok((
+                    item.program_id().to_string(),
+                    self.codec.encode(&KafkaMessage {
+                        metadata: item.metadata().into(),
+                        payload: item.payload(),
+                    })?,
+                ))
+            })
+            .collect::<CarbonResult<Vec<_>>>()?;
+
+        let results = join_all(
+            records
+                .iter()
+                .map(|(key, payload)| self.producer.send(&self.topic, key.as_bytes(), payload)),
+        )
+        .await;
+        let failed = results.iter().filter(|result| result.is_err()).count();
+
+        metrics
+            .increment_counter("kafka_records_sent", (results.len() - failed) as u64)
+            .await?;
+        metrics
+            .increment_counter("kafka_records_failed", failed as u64)
+            .await?;
+
+        results.into_iter().collect()
+    }
+}
@@ -19,6 +19,9 @@
 //! - **[`account_deletion`]**: Handles the deletion of accounts and processes
 //!   these events in the pipeline.
 //!
+//! - **[`checkpoint`]**: Persists the last fully processed slot so that a
+//!   restarted pipeline can resume where it stopped.
+//!
 //! - **[`collection`]**: Defines collections for instruction decoding, allowing
 //!   for customized instruction parsers that handle specific instruction sets.
 //!
@@ -33,28 +36,73 @@
 //! - **[`error`]**: Defines error types used throughout the crate, providing
 //!   consistent error handling for the framework.
 //!
+//! - **[`event`]**: Decodes Anchor events emitted through self-CPI instructions
+//!   and routes them to event processors, and decodes data logged with
+//!   `sol_log_data`.
+//!
+//! - **[`idl`]**: Decodes the instructions of Anchor programs to JSON from
+//!   their IDL at runtime, without generating a decoder crate.
+//!
 //! - **[`instruction`]**: Supports instruction parsing and processing within
 //!   transactions. This module includes structures and traits for decoding and
 //!   handling transaction instructions.
 //!
+//! - **[`jsonl`]**: Writes decoded instructions as JSON Lines to stdout or a
+//!   file, for debugging decoders. Only available with the `jsonl` feature.
+//!
+//! - **[`kafka`]**: Publishes decoded instructions and events to a Kafka topic
+//!   in batches. Only available with the `kafka` feature.
+//!
+//! - **[`merge`]**: Runs several datasources as one, optionally dropping
+//!   transactions that more than one of them delivered.
+//!
 //! - **[`metrics`]**: Facilitates performance monitoring and metric recording
 //!   within the pipeline. Metrics can be customized and are recorded at each
 //!   processing stage for monitoring and debugging purposes.
 //!
+//! - **[`ordering`]**: Runs work in submission order per account while work
+//!   on different accounts runs in parallel.
+//!
 //! - **[`pipeline`]**: Represents the core of the framework, defining the main
 //!   pipeline structure that manages data flow and processing. The pipeline
 //!   integrates data sources, processing pipes, and metrics to provide a
 //!   complete data processing solution.
 //!
+//! - **[`postgres`]**: Inserts decoded data into Postgres tables in batches.
+//!   Only available with the `postgres` feature.
+//!
 //! - **[`processor`]**: Contains traits and implementations for processing data
 //!   in the pipeline. This module allows for the creation of custom data
 //!   processors that can be integrated into various stages of the pipeline.
 //!
+//! - **[`rate_limit`]**: Limits the rate at which a datasource delivers
+//!   updates, to stay within the request quota of an RPC provider.
+//!
+//! - **[`registry`]**: Decodes single instructions, or all instructions of a
+//!   `VersionedTransaction`, of any registered program to JSON, without
+//!   running a pipeline.
+//!
+//! - **[`rollback`]**: Notifies processors when a datasource observes a fork
+//!   switch, so that data stored for the abandoned slots can be invalidated.
+//!
 //! - **[`schema`]**: Defines transaction schemas, allowing for structured
 //!   parsing and validation of transaction data based on specified rules.
 //!   Supports complex nested instruction matching for comprehensive transaction
 //!   analysis.
 //!
+//! - **[`serde`]**: Serializes `Pubkey` and byte-array fields of decoded types
+//!   as base58 and base64 strings, for readable JSON output.
+//!
+//! - **[`snapshot`]**: Loads a snapshot of accounts, for example all accounts
+//!   of a program, to process before the live updates.
+//!
+//! - **[`testing`]**: Builds consistent transaction fixtures for decoder and
+//!   processor tests, and runs pipelines over them. Only available with the
+//!   `testing` feature.
+//!
+//! - **[`spill`]**: Spills updates to disk while the pipes fall behind, and
+//!   replays them in order once they have caught up.
+//!
 //! - **[`transaction`]**: Manages transaction data, including metadata
 //!   extraction and parsing. This module supports transaction validation and
 //!   processing, enabling detailed transaction insights.
@@ -100,6 +148,27 @@
 //! - **Solana-Specific**: Tailored to handle Solana blockchain data structures,
 //!   making it ideal for blockchain data analysis and transaction processing.
 //!
+//! ## `no_std` Support
+//!
+//! Everything that runs a pipeline, such as datasources, pipes, processors and
+//! metrics, requires the `std` feature, which is enabled by default. Without
+//! it, the crate is `no_std` and only depends on `alloc`, keeping the pieces
+//! that decoders are built from:
+//!
+//! - `CarbonDeserialize`, `CarbonSerialize`, `ArrangeAccounts` and the other
+//!   traits and helpers of [`deserialize`], including discriminator matching.
+//! - `AccountDecoder`, `InstructionDecoder`, `EventDecoder` and
+//!   `InstructionDecoderCollection`, along with the decoded types they return.
+//! - [`error::CarbonError`], without the variants that wrap `std` errors.
+//! - The [`serde`] helpers used by the `carbon_serde` attribute.
+//!
+//! This lets decoders be reused in on-chain programs and other constrained
+//! environments:
+//!
+//! ```toml
+//! carbon-core = { version = "0.8", default-features = false, features = ["macros"] }
+//! ```
+//!
 //! ## Notes
 //!
 //! - `carbon-core` integrates with Solana's SDK, leveraging types and data
@@ -111,21 +180,70 @@
 //! to learn how to customize and extend `carbon-core` to suit your specific
 //! data processing requirements.
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
 pub mod account;
+#[cfg(feature = "std")]
 pub mod account_deletion;
+#[cfg(feature = "std")]
+pub mod checkpoint;
 pub mod collection;
+#[cfg(feature = "std")]
 pub mod datasource;
+#[cfg(feature = "std")]
+pub mod dead_letter;
 pub mod deserialize;
 pub mod error;
+pub mod event;
+#[cfg(feature = "std")]
+pub mod idl;
 pub mod instruction;
+#[cfg(any(test, feature = "jsonl"))]
+pub mod jsonl;
+#[cfg(feature = "kafka")]
+pub mod kafka;
+#[cfg(feature = "std")]
+pub mod merge;
+#[cfg(feature = "std")]
 pub mod metrics;
+#[cfg(feature = "std")]
+pub mod ordering;
+#[cfg(feature = "std")]
 pub mod pipeline;
+#[cfg(feature = "postgres")]
+pub mod postgres;
+#[cfg(feature = "std")]
 pub mod processor;
+#[cfg(feature = "std")]
+pub mod rate_limit;
+#[cfg(feature = "std")]
+pub mod registry;
+#[cfg(feature = "std")]
+pub mod rollback;
+#[cfg(feature = "std")]
 pub mod schema;
+pub mod serde;
+#[cfg(feature = "std")]
+pub mod snapshot;
+#[cfg(feature = "std")]
+pub mod spill;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+#[cfg(feature = "std")]
 pub mod transaction;
+#[cfg(feature = "std")]
 pub mod transformers;
 pub use borsh;
+#[cfg(feature = "std")]
+pub use registry::decode_versioned_transaction;
 #[cfg(feature = "macros")]
 pub use carbon_macros::*;
 #[cfg(feature = "macros")]
 pub use carbon_proc_macros::*;
+
+// Lets code generated by the derive macros, which refers to `carbon_core`,
+// compile inside this crate's own tests.
+#[cfg(all(test, feature = "macros"))]
+extern crate self as carbon_core;
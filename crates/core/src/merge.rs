@@ -0,0 +1,379 @@
+//! Runs several datasources as a single one, for example to index from a
+//! Geyser stream and an RPC crawler at once, or from two providers for
+//! redundancy.
+//!
+//! [`MergedDatasource`] consumes every source concurrently and forwards their
+//! updates as they arrive. Each source is registered under a name that is set
+//! as the `source` of its updates, unless the source already set one, so
+//! processors can tell where data came from through
+//! `TransactionMetadata::source` and `AccountMetadata::source`. The name also
+//! tags the metrics: `merged_updates_{name}` counts the updates a source
+//! delivered and `merged_duplicates_dropped_{name}` the duplicates that were
+//! dropped from it.
+//!
+//! Sources that overlap deliver the same transaction more than once. With
+//! [`MergedDatasource::dedupe_signatures`], transactions are deduplicated by
+//! signature, keeping whichever copy arrives first. The signatures are held in
+//! a bounded LRU cache, so a duplicate is only detected while its signature is
+//! among the most recently seen ones; the capacity should cover the delay
+//! between the sources. Account updates, account deletions and rollbacks are
+//! always forwarded.
+//!
+//! ```ignore
+//! let pipeline = Pipeline::builder()
+//!     .datasource(
+//!         MergedDatasource::new()
+//!             .source("geyser", geyser_datasource)
+//!             .source("rpc", rpc_datasource)
+//!             .dedupe_signatures(100_000),
+//!     )
+//!     .instruction(DriftDecoder, DriftInstructionProcessor)
+//!     .build()?;
+//! ```
+
+use {
+    crate::{
+        datasource::{Datasource, Update, UpdateType},
+        error::CarbonResult,
+        metrics::MetricsCollection,
+    },
+    async_trait::async_trait,
+    solana_signature::Signature,
+    std::{
+        collections::{HashMap, VecDeque},
+        hash::Hash,
+        sync::Arc,
+    },
+    tokio::sync::mpsc,
+    tokio_util::sync::CancellationToken,
+};
+
+/// A [`Datasource`] that fans in the updates of several named datasources,
+/// optionally dropping transactions already delivered by another source.
+#[derive(Default)]
+pub struct MergedDatasource {
+    sources: Vec<(Arc<str>, Box<dyn Datasource>)>,
+    dedupe_capacity: Option<usize>,
+}
+
+impl MergedDatasource {
+    /// Creates a merged datasource without sources.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `datasource` under `name`, which tags its updates and their
+    /// metrics.
+    pub fn source(
+        mut self,
+        name: impl Into<Arc<str>>,
+        datasource: impl Datasource + 'static,
+    ) -> Self {
+        self.sources.push((name.into(), Box::new(datasource)));
+        self
+    }
+
+    /// Drops transactions whose signature is among the last `capacity`
+    /// signatures seen from any source. The capacity is raised to 1 if zero.
+    pub fn dedupe_signatures(mut self, capacity: usize) -> Self {
+        self.dedupe_capacity = Some(capacity.max(1));
+        self
+    }
+}
+
+#[async_trait]
+impl Datasource for MergedDatasource {
+    async fn consume(
+        &self,
+        sender: &mpsc::Sender<Update>,
+        cancellation_token: CancellationToken,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        let (merged_sender, mut merged_receiver) = mpsc::channel(self.sources.len().max(1));
+        let mut signatures = self.dedupe_capacity.map(SignatureCache::new);
+
+        let consumes: Vec<_> = self
+            .sources
+            .iter()
+            .enumerate()
+            .map(|(index, (_, datasource))| {
+                let merged_sender = merged_sender.clone();
+                let cancellation_token = cancellation_token.clone();
+                let metrics = metrics.clone();
+                async move {
+                    let (source_sender, mut source_receiver) = mpsc::channel(1);
+
+                    let consume = async move {
+                        datasource
+                            .consume(&source_sender, cancellation_token, metrics)
+                            .await
+                    };
+
+                    // Keeps forwarding after `consume` returns, since
+                    // datasources may keep sending from tasks they spawned
+                    // until they drop their senders.
+                    let forward = async {
+                        while let Some(update) = source_receiver.recv().await {
+                            if merged_sender.send((index, update)).await.is_err() {
+                                break;
+                            }
+                        }
+                    };
+
+                    let (result, ()) = tokio::join!(consume, forward);
+                    result
+                }
+            })
+            .collect();
+        drop(merged_sender);
+
+        let forward = async {
+            loop {
+                let (index, mut update) = tokio::select! {
+                    _ = cancellation_token.cancelled() => break,
+                    update = merged_receiver.recv() => match update {
+                        Some(update) => update,
+                        None => break,
+                    },
+                };
+                let name = &self.sources[index].0;
+                let source = match &mut update {
+                    Update::Account(account_update) => Some(&mut account_update.source),
+                    Update::Transaction(transaction_update) => Some(&mut transaction_update.source),
+                    Update::AccountDeletion(account_deletion) => Some(&mut account_deletion.source),
+                    Update::Rollback(_) => None,
+                };
+                if let Some(source @ None) = source {
+                    *source = Some(name.clone());
+                }
+
+                if let (Some(signatures), Update::Transaction(transaction)) =
+                    (signatures.as_mut(), &update)
+                {
+                    if !signatures.insert(transaction.signature) {
+                        log::debug!(
+                            "Dropping transaction {} already delivered before {} did",
+                            transaction.signature,
+                            name
+                        );
+                        metrics
+                            .increment_counter(&format!("merged_duplicates_dropped_{name}"), 1)
+                            .await
+                            .unwrap_or_else(|value| {
+                                log::error!("Error recording metric: {}", value)
+                            });
+                        continue;
+                    }
+                }
+
+                metrics
+                    .increment_counter(&format!("merged_updates_{name}"), 1)
+                    .await
+                    .unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
+
+                if sender.send(update).await.is_err() {
+                    break;
+                }
+            }
+        };
+
+        let (results, ()) = tokio::join!(futures::future::join_all(consumes), forward);
+        results.into_iter().collect()
+    }
+
+    fn update_types(&self) -> Vec<UpdateType> {
+        let mut update_types = Vec::new();
+        for update_type in self
+            .sources
+            .iter()
+            .flat_map(|(_, datasource)| datasource.update_types())
+        {
+            if !update_types.contains(&update_type) {
+                update_types.push(update_type);
+            }
+        }
+        update_types
+    }
+
+    fn resume_from_slot(&self, slot: u64) {
+        for (_, datasource) in &self.sources {
+            datasource.resume_from_slot(slot);
+        }
+    }
+}
+
+/// Remembers the `capacity` most recently seen signatures.
+pub(crate) type SignatureCache = LruSet<Signature>;
+
+/// Remembers the `capacity` most recently seen keys, evicting the least
+/// recently seen one when full.
+///
+/// Seeing a key again moves it to the back of `order` by pushing a new entry;
+/// the entries it leaves behind, like those of removed keys, are recognised as
+/// stale by their `tick` and skipped on eviction. They are dropped once they
+/// outnumber the keys, so that `order` stays proportional to the set rather
+/// than to its capacity.
+#[derive(Debug)]
+pub(crate) struct LruSet<K> {
+    capacity: usize,
+    last_seen: HashMap<K, u64>,
+    order: VecDeque<(K, u64)>,
+    tick: u64,
+}
+
+impl<K: Copy + Eq + Hash> LruSet<K> {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            last_seen: HashMap::new(),
+            order: VecDeque::new(),
+            tick: 0,
+        }
+    }
+
+    /// Records `key` as the most recently seen one, returning whether it was
+    /// not already in the set.
+    pub(crate) fn insert(&mut self, key: K) -> bool {
+        self.tick += 1;
+        let is_new = self.last_seen.insert(key, self.tick).is_none();
+        self.order.push_back((key, self.tick));
+
+        while self.last_seen.len() > self.capacity {
+            let Some((oldest, tick)) = self.order.pop_front() else {
+                break;
+            };
+            if self.last_seen.get(&oldest) == Some(&tick) {
+                self.last_seen.remove(&oldest);
+            }
+        }
+
+        if self.order.len() > 2 * self.last_seen.len().max(1) {
+            let last_seen = &self.last_seen;
+            self.order
+                .retain(|(key, tick)| last_seen.get(key) == Some(tick));
+        }
+
+        is_new
+    }
+
+    /// Removes `key`, returning whether it was in the set.
+    pub(crate) fn remove(&mut self, key: &K) -> bool {
+        self.last_seen.remove(key).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*, crate::datasource::TransactionUpdate,
+        solana_sdk::transaction::VersionedTransaction,
+        solana_transaction_status::TransactionStatusMeta,
+    };
+
+    struct TransactionDatasource {
+        signatures: Vec<Signature>,
+    }
+
+    #[async_trait]
+    impl Datasource for TransactionDatasource {
+        async fn consume(
+            &self,
+            sender: &mpsc::Sender<Update>,
+            _cancellation_token: CancellationToken,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            for signature in &self.signatures {
+                let update = Update::Transaction(Box::new(TransactionUpdate {
+                    signature: *signature,
+                    transaction: VersionedTransaction::default(),
+                    meta: TransactionStatusMeta::default(),
+                    is_vote: false,
+                    slot: 1,
+                    block_time: None,
+                    commitment: None,
+                    source: None,
+                }));
+                if sender.send(update).await.is_err() {
+                    break;
+                }
+            }
+
+            Ok(())
+        }
+
+        fn update_types(&self) -> Vec<UpdateType> {
+            vec![UpdateType::Transaction]
+        }
+    }
+
+    #[tokio::test]
+    async fn test_merged_datasource_delivers_overlapping_transaction_once() {
+        // Arrange
+        let [first, shared, second] = [(); 3].map(|()| Signature::new_unique());
+        let datasource = MergedDatasource::new()
+            .source(
+                "geyser",
+                TransactionDatasource {
+                    signatures: vec![first, shared],
+                },
+            )
+            .source(
+                "rpc",
+                TransactionDatasource {
+                    signatures: vec![shared, second],
+                },
+            )
+            .dedupe_signatures(16);
+        let (sender, mut receiver) = mpsc::channel(16);
+        let metrics = Arc::new(MetricsCollection::new(vec![]));
+
+        // Act
+        let result = datasource
+            .consume(&sender, CancellationToken::new(), metrics)
+            .await;
+        drop(sender);
+        let mut delivered = HashMap::new();
+        while let Some(update) = receiver.recv().await {
+            match update {
+                Update::Transaction(transaction) => {
+                    let source = transaction.source.expect("tagged with its source");
+                    let previous = delivered.insert(transaction.signature, source);
+                    assert!(
+                        previous.is_none(),
+                        "{} delivered twice",
+                        transaction.signature
+                    );
+                }
+                update => panic!("unexpected update {update:?}"),
+            }
+        }
+
+        // Assert
+        result.unwrap();
+        assert_eq!(delivered.len(), 3);
+        assert_eq!(delivered[&first].as_ref(), "geyser");
+        assert_eq!(delivered[&second].as_ref(), "rpc");
+        assert!(["geyser", "rpc"].contains(&delivered[&shared].as_ref()));
+        assert_eq!(datasource.update_types(), vec![UpdateType::Transaction]);
+    }
+
+    #[test]
+    fn test_signature_cache_evicts_least_recently_seen() {
+        // Arrange
+        let mut cache = SignatureCache::new(2);
+        let [a, b, c] = [(); 3].map(|()| Signature::new_unique());
+
+        // Act
+        let inserted = [
+            cache.insert(a),
+            cache.insert(b),
+            cache.insert(a),
+            cache.insert(c),
+            cache.insert(a),
+            cache.insert(b),
+        ];
+
+        // Assert
+        assert_eq!(inserted, [true, true, false, true, false, true]);
+    }
+}
@@ -23,6 +23,15 @@
 //! visualization and alerting. The trait requires `async` functions, allowing
 //! implementations to perform non-blocking I/O operations, such as network
 //! requests or database writes.
+//!
+//! ## Labels
+//!
+//! The `_with_labels` methods record a metric for the series identified by a
+//! list of label names and values, such as the `decoder` label of the
+//! instruction pipe counters, so that the name of a metric stays fixed while
+//! its series can be broken down. Backends that do not support labels can
+//! leave their default implementations, which record the metric without its
+//! labels, merging its series.
 
 use {crate::error::CarbonResult, async_trait::async_trait, std::sync::Arc};
 
@@ -61,6 +70,45 @@ pub trait Metrics: Send + Sync {
     /// - `value`: The value to add to the histogram, typically representing
     ///   time or size.
     async fn record_histogram(&self, name: &str, value: f64) -> CarbonResult<()>;
+
+    /// Updates the series of a gauge metric identified by `labels`, given as
+    /// name and value pairs.
+    ///
+    /// Defaults to `update_gauge`, ignoring the labels.
+    async fn update_gauge_with_labels(
+        &self,
+        name: &str,
+        _labels: &[(&str, &str)],
+        value: f64,
+    ) -> CarbonResult<()> {
+        self.update_gauge(name, value).await
+    }
+
+    /// Increments the series of a counter metric identified by `labels`,
+    /// given as name and value pairs.
+    ///
+    /// Defaults to `increment_counter`, ignoring the labels.
+    async fn increment_counter_with_labels(
+        &self,
+        name: &str,
+        _labels: &[(&str, &str)],
+        value: u64,
+    ) -> CarbonResult<()> {
+        self.increment_counter(name, value).await
+    }
+
+    /// Records a value in the series of a histogram metric identified by
+    /// `labels`, given as name and value pairs.
+    ///
+    /// Defaults to `record_histogram`, ignoring the labels.
+    async fn record_histogram_with_labels(
+        &self,
+        name: &str,
+        _labels: &[(&str, &str)],
+        value: f64,
+    ) -> CarbonResult<()> {
+        self.record_histogram(name, value).await
+    }
 }
 
 #[derive(Default)]
@@ -114,4 +162,44 @@ impl MetricsCollection {
         }
         Ok(())
     }
+
+    pub async fn update_gauge_with_labels(
+        &self,
+        name: &str,
+        labels: &[(&str, &str)],
+        value: f64,
+    ) -> CarbonResult<()> {
+        for metric in &self.metrics {
+            metric.update_gauge_with_labels(name, labels, value).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn increment_counter_with_labels(
+        &self,
+        name: &str,
+        labels: &[(&str, &str)],
+        value: u64,
+    ) -> CarbonResult<()> {
+        for metric in &self.metrics {
+            metric
+                .increment_counter_with_labels(name, labels, value)
+                .await?;
+        }
+        Ok(())
+    }
+
+    pub async fn record_histogram_with_labels(
+        &self,
+        name: &str,
+        labels: &[(&str, &str)],
+        value: f64,
+    ) -> CarbonResult<()> {
+        for metric in &self.metrics {
+            metric
+                .record_histogram_with_labels(name, labels, value)
+                .await?;
+        }
+        Ok(())
+    }
 }
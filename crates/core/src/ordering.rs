@@ -0,0 +1,203 @@
+//! Runs work in submission order per account while letting work on different
+//! accounts run in parallel.
+//!
+//! [`AccountOrderedExecutor`] extracts a key, usually an account address, from
+//! each input with a user supplied function. Work submitted for the same key
+//! waits for all work submitted before it for that key, while work for other
+//! keys is not held back. This is the ordering the pipeline keeps when
+//! transactions are processed concurrently, exposed for processors that fan
+//! out work of their own.
+//!
+//! The order is fixed when [`AccountOrderedExecutor::run`] is called, not when
+//! the returned future is first polled, so the futures can be spawned onto
+//! separate tasks without losing it:
+//!
+//! ```ignore
+//! let executor = AccountOrderedExecutor::new(|update: &AccountUpdate| update.pubkey);
+//!
+//! for update in updates {
+//!     let turn = executor.run(update, |update| store.upsert(update));
+//!     tokio::spawn(turn);
+//! }
+//! ```
+//!
+//! Keys are spread over a fixed number of shards, each guarding the keys that
+//! hash to it, so submissions for different keys rarely contend on the same
+//! lock. A key's entry is removed once its last submitted work finishes.
+
+use {
+    core::{
+        future::Future,
+        hash::{BuildHasher, Hash},
+        sync::atomic::{AtomicU64, Ordering},
+    },
+    std::{
+        collections::{hash_map::RandomState, HashMap},
+        sync::{Arc, Mutex, PoisonError},
+    },
+    tokio::sync::oneshot,
+};
+
+const DEFAULT_SHARDS: usize = 16;
+
+/// The most recent submission for a key, which the next submission for that
+/// key waits on.
+struct Tail {
+    id: u64,
+    done: oneshot::Receiver<()>,
+}
+
+type Shard<K> = Mutex<HashMap<K, Tail>>;
+
+/// Serializes work per key and parallelizes it across keys.
+///
+/// The futures returned by [`run`](Self::run) do not borrow the executor, so
+/// they can be spawned as tasks. See the [module documentation](self) for
+/// details.
+pub struct AccountOrderedExecutor<T, K, F> {
+    key: F,
+    shards: Arc<[Shard<K>]>,
+    hasher: RandomState,
+    next_id: AtomicU64,
+    _input: core::marker::PhantomData<fn(T)>,
+}
+
+impl<T, K, F> AccountOrderedExecutor<T, K, F>
+where
+    K: Hash + Eq + Clone,
+    F: Fn(&T) -> K,
+{
+    /// Creates an executor that orders inputs by the key `key` returns.
+    pub fn new(key: F) -> Self {
+        Self::with_shards(key, DEFAULT_SHARDS)
+    }
+
+    /// Creates an executor spreading keys over `shards` locks, raised to 1 if
+    /// zero.
+    pub fn with_shards(key: F, shards: usize) -> Self {
+        Self {
+            key,
+            shards: (0..shards.max(1))
+                .map(|_| Mutex::new(HashMap::new()))
+                .collect(),
+            hasher: RandomState::new(),
+            next_id: AtomicU64::new(0),
+            _input: core::marker::PhantomData,
+        }
+    }
+
+    /// Returns the number of keys with work submitted that has not finished.
+    pub fn pending_keys(&self) -> usize {
+        self.shards
+            .iter()
+            .map(|shard| shard.lock().unwrap_or_else(PoisonError::into_inner).len())
+            .sum()
+    }
+
+    /// Submits `input` to be handled by `work` once all work submitted before
+    /// it for the same key has finished, and returns a future resolving to
+    /// the output of `work`.
+    ///
+    /// The input takes its place in line immediately. Dropping the returned
+    /// future gives up that place without holding back later submissions.
+    pub fn run<W, Fut>(&self, input: T, work: W) -> impl Future<Output = Fut::Output>
+    where
+        W: FnOnce(T) -> Fut,
+        Fut: Future,
+    {
+        let key = (self.key)(&input);
+        let shard = self.hasher.hash_one(&key) as usize % self.shards.len();
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let (done_sender, done) = oneshot::channel();
+
+        let previous = self.shards[shard]
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(key.clone(), Tail { id, done });
+
+        let turn = Turn {
+            shards: self.shards.clone(),
+            shard,
+            key,
+            id,
+            _done: done_sender,
+        };
+
+        async move {
+            if let Some(previous) = previous {
+                // The sender is dropped rather than used once the previous
+                // work finishes or is cancelled, so either result means go.
+                let _ = previous.done.await;
+            }
+
+            let output = work(input).await;
+            drop(turn);
+            output
+        }
+    }
+}
+
+/// A submission's place in line. Dropping it lets the next submission for the
+/// key start, and removes the key's entry if no later submission exists.
+struct Turn<K: Hash + Eq> {
+    shards: Arc<[Shard<K>]>,
+    shard: usize,
+    key: K,
+    id: u64,
+    _done: oneshot::Sender<()>,
+}
+
+impl<K: Hash + Eq> Drop for Turn<K> {
+    fn drop(&mut self) {
+        let mut tails = self.shards[self.shard]
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if tails.get(&self.key).is_some_and(|tail| tail.id == self.id) {
+            tails.remove(&self.key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, solana_pubkey::Pubkey, std::time::Duration};
+
+    #[tokio::test]
+    async fn test_work_runs_in_submission_order_per_key() {
+        // Arrange
+        let executor = AccountOrderedExecutor::new(|(account, _): &(Pubkey, u64)| *account);
+        let accounts: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+        let recorded = Arc::new(Mutex::new(HashMap::<Pubkey, Vec<u64>>::new()));
+
+        // Act
+        let mut handles = Vec::new();
+        for sequence in 0..200u64 {
+            let account = accounts[sequence as usize % accounts.len()];
+            let recorded = recorded.clone();
+            let turn = executor.run((account, sequence), move |(account, sequence)| async move {
+                // Later submissions sleep less, so without ordering they
+                // would finish first.
+                tokio::time::sleep(Duration::from_micros(200 - sequence)).await;
+                recorded
+                    .lock()
+                    .unwrap()
+                    .entry(account)
+                    .or_default()
+                    .push(sequence);
+            });
+            handles.push(tokio::spawn(turn));
+        }
+        for handle in handles {
+            handle.await.unwrap();
+        }
+
+        // Assert
+        let recorded = recorded.lock().unwrap();
+        assert_eq!(recorded.len(), accounts.len());
+        for sequences in recorded.values() {
+            assert_eq!(sequences.len(), 50);
+            assert!(sequences.windows(2).all(|pair| pair[0] < pair[1]));
+        }
+        assert_eq!(executor.pending_keys(), 0);
+    }
+}
@@ -36,6 +36,8 @@
 //!   deletion events.
 //! - **instruction_pipes**: Used to process instructions within transactions.
 //! - **transaction_pipes**: For handling full transactions.
+//! - **rollback_pipes**: Notified when a datasource rolls back slots after a
+//!   fork switch.
 //! - **metrics**: A vector of `Metrics` implementations that gather and report
 //!   on performance data.
 //! - **metrics_flush_interval**: Specifies how frequently metrics are flushed.
@@ -55,24 +57,51 @@ use {
         account::{
             AccountDecoder, AccountMetadata, AccountPipe, AccountPipes, AccountProcessorInputType,
         },
-        account_deletion::{AccountDeletionPipe, AccountDeletionPipes},
+        account_deletion::{AccountCloseDetector, AccountDeletionPipe, AccountDeletionPipes},
+        checkpoint::Checkpoint,
         collection::InstructionDecoderCollection,
-        datasource::{AccountDeletion, Datasource, Update},
-        error::CarbonResult,
+        datasource::{
+            AccountDeletion, AccountUpdate, Commitment, Datasource, Rollback, TransactionUpdate,
+            Update,
+        },
+        dead_letter::DeadLetterSink,
+        error::{CarbonError, CarbonResult},
+        event::{EventDecoder, EventPipe, EventProcessorInputType},
         instruction::{
-            InstructionDecoder, InstructionPipe, InstructionPipes, InstructionProcessorInputType,
-            InstructionsWithMetadata, NestedInstructions,
+            DecoderClaims, ErrorPolicy, InstructionDecoder, InstructionPipe, InstructionPipes,
+            InstructionProcessorInputType, InstructionsWithMetadata, NestedInstructions,
+            DEFAULT_MAX_INSTRUCTION_DEPTH,
         },
+        merge::SignatureCache,
         metrics::{Metrics, MetricsCollection},
-        processor::Processor,
+        processor::{Processor, SendProcessor, StaticProcessor, SyncProcessor},
+        rollback::{RollbackPipe, RollbackPipes},
         schema::TransactionSchema,
-        transaction::{TransactionPipe, TransactionPipes, TransactionProcessorInputType},
+        snapshot::AccountSnapshot,
+        spill::{spill_when_full, SpillToDisk},
+        transaction::{
+            TransactionContext, TransactionContextFinalizer, TransactionDecoder,
+            TransactionDecoderPipe, TransactionDecoderProcessorInputType, TransactionMetadata,
+            TransactionPipe, TransactionPipes, TransactionProcessorInputType,
+        },
         transformers,
     },
     core::time,
+    futures::{
+        future::select_all,
+        stream::{FuturesOrdered, StreamExt},
+    },
     serde::de::DeserializeOwned,
-    std::{convert::TryInto, sync::Arc, time::Instant},
+    solana_pubkey::Pubkey,
+    std::{
+        collections::{BTreeMap, VecDeque},
+        convert::TryInto,
+        sync::Arc,
+        time::{Duration, Instant},
+    },
+    tokio::sync::{Mutex, MutexGuard, Notify},
     tokio_util::sync::CancellationToken,
+    tracing::Instrument,
 };
 
 /// Defines the shutdown behavior for the pipeline.
@@ -106,15 +135,55 @@ pub enum ShutdownStrategy {
     ProcessPending,
 }
 
+/// Defines what happens to an update sent by a datasource while the
+/// pipeline's channel is full.
+///
+/// The channel between the datasources and the pipes holds at most
+/// `channel_buffer_size` updates, so a pipeline whose processors fall behind
+/// does not buffer an unbounded number of updates in memory.
+///
+/// - `Block`: The datasource waits until there is room in the channel. Pull
+///   datasources, such as RPC crawlers, are thereby slowed down to the pace of
+///   the pipeline and no update is lost. This is the default behavior.
+/// - `DropNewest`: The update is dropped and counted in the `updates_dropped`
+///   metric. Suited to push datasources, such as gRPC subscriptions, that must
+///   keep reading their stream to stay connected.
+///
+/// Under either policy, the `updates_queued` gauge reports how many updates
+/// wait in the channel and the `updates_lag_slots` gauge how many slots the
+/// processed updates trail behind the newest received one.
+#[derive(Default, PartialEq, Eq, Debug, Clone, Copy)]
+pub enum BackpressurePolicy {
+    /// Wait for room in the channel.
+    #[default]
+    Block,
+    /// Drop the updates that do not fit in the channel.
+    DropNewest,
+}
+
 /// The default size of the channel buffer for the pipeline.
 ///
 /// This constant defines the default number of updates that can be queued in
 /// the pipeline's channel buffer. It is used as a fallback value if the
 /// `channel_buffer_size` is not explicitly set during pipeline construction.
 ///
-/// The default size is 10,000 updates, which provides a reasonable balance
+/// The default size is 1,000 updates, which provides a reasonable balance
+/// between memory use and throughput.
 pub const DEFAULT_CHANNEL_BUFFER_SIZE: usize = 1_000;
 
+/// The default number of slots between two checkpoint saves.
+///
+/// With the default of 1, the checkpoint is saved after every fully processed
+/// slot. Use `PipelineBuilder::checkpoint_interval` to save less often.
+pub const DEFAULT_CHECKPOINT_INTERVAL: u64 = 1;
+
+/// The default number of updates processed at the same time.
+///
+/// With the default of 1, updates are processed one after the other. Use
+/// `PipelineBuilder::max_concurrent_transactions` to process updates touching
+/// different accounts concurrently.
+pub const DEFAULT_MAX_CONCURRENT_TRANSACTIONS: usize = 1;
+
 /// Represents the primary data processing pipeline in the `carbon-core`
 /// framework.
 ///
@@ -159,14 +228,63 @@ pub const DEFAULT_CHANNEL_BUFFER_SIZE: usize = 1_000;
 ///   types.
 /// - `transaction_pipes`: A vector of `TransactionPipes` responsible for
 ///   processing complete transaction payloads.
+/// - `rollback_pipes`: A vector of `RollbackPipes` notified when a datasource
+///   rolls back slots after a fork switch.
 /// - `metrics`: A vector of `Metrics` implementations to record and track
 ///   performance data. Each metrics instance is managed within an `Arc` to
 ///   ensure thread safety.
 /// - `metrics_flush_interval`: An optional interval, in seconds, defining how
 ///   frequently metrics should be flushed. If `None`, the default interval is
 ///   used.
+/// - `shutdown_token`: An optional `CancellationToken` that triggers a shutdown
+///   of the pipeline when cancelled, in the same way as `SIGINT`.
+/// - `shutdown_drain_timeout`: An optional upper bound on how long the pipeline
+///   keeps processing pending updates after a shutdown was requested. If
+///   `None`, pending updates are drained without a time limit.
 /// - `channel_buffer_size`: The size of the channel buffer for the pipeline.
-///   If not set, a default size of 10_000 will be used.
+///   If not set, `DEFAULT_CHANNEL_BUFFER_SIZE` is used.
+/// - `backpressure_policy`: What happens to updates sent while the channel
+///   buffer is full. See `BackpressurePolicy`.
+/// - `spill_to_disk`: If set, updates that do not fit in the channel are
+///   spilled to disk instead. See `PipelineBuilder::spill_to_disk`.
+/// - `slot_reorder_window`: If set, how many slots updates are held back to
+///   be processed in slot order. See `PipelineBuilder::slot_reorder_window`.
+/// - `max_concurrent_transactions`: The maximum number of updates processed at
+///   the same time. See `PipelineBuilder::max_concurrent_transactions` for the
+///   ordering guarantees.
+/// - `deterministic`: Whether updates are processed one after the other
+///   regardless of `max_concurrent_transactions`. See
+///   `PipelineBuilder::deterministic`.
+/// - `skip_failed_transactions`: Whether transactions that failed on-chain are
+///   skipped. Enabled by default.
+/// - `max_instruction_depth`: The deepest level of inner instructions kept in
+///   the instruction tree of a transaction. See
+///   `PipelineBuilder::max_instruction_depth`.
+/// - `account_close_detector`: If set, recognises account updates that report a
+///   closed account, which are then routed to the account deletion pipes.
+/// - `signature_dedupe_window`: If set, how many recent transaction signatures
+///   are remembered to skip transactions delivered more than once. See
+///   `PipelineBuilder::dedupe_signatures`.
+/// - `snapshots`: The `AccountSnapshot`s whose accounts are processed before
+///   the live updates. See `PipelineBuilder::snapshot`.
+/// - `transaction_context_finalizer`: If set, every transaction gets a
+///   `TransactionContext` shared by its instruction processors, which is handed
+///   to the finalizer once the transaction is processed. See
+///   `PipelineBuilder::transaction_context`.
+///
+/// ## Slot Gaps
+///
+/// The pipeline remembers the newest slot it has processed. When an update is
+/// more than one slot newer, the slots in between are counted in the
+/// `slot_gap_total` counter and a warning is logged, which reveals a
+/// datasource that silently skipped slots. With several datasources, gaps are
+/// computed on the merged stream in the order its updates are processed, so a
+/// slot delivered by any of them closes the gap.
+///
+/// Slots in which no update was delivered at all, such as slots skipped by
+/// their leader or slots without a transaction matching the datasource's
+/// filters, are counted as well. Alert on an unusual rate of the counter
+/// rather than on any increase.
 ///
 /// ## Example
 ///
@@ -207,11 +325,28 @@ pub struct Pipeline {
     pub account_deletion_pipes: Vec<Box<dyn AccountDeletionPipes>>,
     pub instruction_pipes: Vec<Box<dyn for<'a> InstructionPipes<'a>>>,
     pub transaction_pipes: Vec<Box<dyn for<'a> TransactionPipes<'a>>>,
+    pub rollback_pipes: Vec<Box<dyn RollbackPipes>>,
     pub metrics: Arc<MetricsCollection>,
     pub metrics_flush_interval: Option<u64>,
     pub datasource_cancellation_token: Option<CancellationToken>,
     pub shutdown_strategy: ShutdownStrategy,
+    pub shutdown_token: Option<CancellationToken>,
+    pub shutdown_drain_timeout: Option<Duration>,
     pub channel_buffer_size: usize,
+    pub backpressure_policy: BackpressurePolicy,
+    pub spill_to_disk: Option<SpillToDisk>,
+    pub slot_reorder_window: Option<u64>,
+    pub checkpoint: Option<Arc<dyn Checkpoint>>,
+    pub checkpoint_interval: u64,
+    pub commitment: Option<Commitment>,
+    pub max_concurrent_transactions: usize,
+    pub deterministic: bool,
+    pub skip_failed_transactions: bool,
+    pub max_instruction_depth: usize,
+    pub account_close_detector: Option<Arc<AccountCloseDetector>>,
+    pub signature_dedupe_window: Option<usize>,
+    pub snapshots: Vec<Arc<dyn AccountSnapshot>>,
+    pub transaction_context_finalizer: Option<Arc<dyn TransactionContextFinalizer>>,
 }
 
 impl Pipeline {
@@ -244,18 +379,31 @@ impl Pipeline {
     /// using the builder pattern.
     pub fn builder() -> PipelineBuilder {
         log::trace!("Pipeline::builder()");
-        PipelineBuilder {
-            datasources: Vec::new(),
-            account_pipes: Vec::new(),
-            account_deletion_pipes: Vec::new(),
-            instruction_pipes: Vec::new(),
-            transaction_pipes: Vec::new(),
-            metrics: MetricsCollection::default(),
-            metrics_flush_interval: None,
-            datasource_cancellation_token: None,
-            shutdown_strategy: ShutdownStrategy::default(),
-            channel_buffer_size: DEFAULT_CHANNEL_BUFFER_SIZE,
-        }
+        PipelineBuilder::default()
+    }
+
+    /// Lists the instruction decoders of the pipeline, by name, with each
+    /// program they decode.
+    ///
+    /// A decoder is listed once per program ID. Decoders that do not restrict
+    /// themselves to any program are listed once, with `Pubkey::default()`.
+    /// The names are those returned by `InstructionDecoder::decoder_name`,
+    /// which also label the per-decoder metrics of the instruction pipes.
+    pub fn registered_decoders(&self) -> Vec<(String, Pubkey)> {
+        self.instruction_pipes
+            .iter()
+            .filter_map(|pipe| pipe.decoder())
+            .flat_map(|(decoder_name, program_ids)| {
+                let program_ids = if program_ids.is_empty() {
+                    vec![Pubkey::default()]
+                } else {
+                    program_ids.to_vec()
+                };
+                program_ids
+                    .into_iter()
+                    .map(move |program_id| (decoder_name.to_string(), program_id))
+            })
+            .collect()
     }
 
     /// Runs the `Pipeline`, processing updates from data sources and handling
@@ -274,10 +422,30 @@ impl Pipeline {
     /// - Initializes metrics and sets up an interval for periodic metric
     ///   flushing.
     /// - Spawns tasks for each data source to continuously consume updates.
+    ///   Updates are queued in a channel bounded by `channel_buffer_size`; once
+    ///   it is full, the `backpressure_policy` decides whether data sources
+    ///   wait or their updates are dropped.
     /// - Processes updates according to their type (e.g., Account, Transaction,
     ///   or AccountDeletion).
     /// - Records performance metrics such as update processing times, and
     ///   tracks success and failure counts.
+    /// - Processes each transaction inside a `tracing` span named
+    ///   `transaction`, with `signature` and `slot` fields, and each decoded
+    ///   instruction inside a child span named `instruction`, with
+    ///   `program_id` and `stack_height` fields. Events emitted by processors
+    ///   are thus correlated with their transaction. Records of the `log`
+    ///   crate are too when forwarded to `tracing`, for example with
+    ///   `tracing_log::LogTracer`; without a `tracing` subscriber, the
+    ///   pipeline logs through `log` as before.
+    /// - Shuts down on `SIGINT` or when the configured `shutdown_token` is
+    ///   cancelled. The update being processed at that moment always runs to
+    ///   completion; with `ShutdownStrategy::ProcessPending`, the queued
+    ///   updates are then drained, bounded by `shutdown_drain_timeout` if set.
+    ///   Before returning, every processor's `shutdown` is called so that
+    ///   buffered data is written out.
+    /// - Also shuts down once every data source has finished, for example a
+    ///   replay that reached its end slot: the updates they sent are processed
+    ///   and `run` returns.
     ///
     /// # Errors
     ///
@@ -328,17 +496,45 @@ impl Pipeline {
         log::trace!("run(self)");
 
         self.metrics.initialize_metrics().await?;
-        let (update_sender, mut update_receiver) =
+        let (update_sender, update_receiver) =
             tokio::sync::mpsc::channel::<Update>(self.channel_buffer_size);
 
         let datasource_cancellation_token = self
             .datasource_cancellation_token
             .clone()
             .unwrap_or_default();
+        let shutdown_token = self.shutdown_token.clone().unwrap_or_default();
+
+        let slot_checkpointer = self.checkpoint.clone().map(|checkpoint| {
+            let last_saved_slot = checkpoint.load();
+            if let Some(slot) = last_saved_slot {
+                log::info!("resuming datasources after checkpointed slot {}.", slot);
+                for datasource in &self.datasources {
+                    datasource.resume_from_slot(slot);
+                }
+            }
+            SlotCheckpointer::new(checkpoint, self.checkpoint_interval, last_saved_slot)
+        });
+
+        // With spilling, the datasources share a sender whose updates never
+        // wait on the channel, so the backpressure policy does not apply.
+        let (spill_sender, spill_replay) = self
+            .spill_to_disk
+            .clone()
+            .map(|spill_to_disk| {
+                spill_when_full(update_sender.clone(), spill_to_disk, self.metrics.clone())
+            })
+            .unzip();
 
         for datasource in &self.datasources {
             let datasource_cancellation_token_clone = datasource_cancellation_token.clone();
-            let sender_clone = update_sender.clone();
+            let sender_clone = match (&spill_sender, self.backpressure_policy) {
+                (Some(spill_sender), _) => spill_sender.clone(),
+                (None, BackpressurePolicy::Block) => update_sender.clone(),
+                (None, BackpressurePolicy::DropNewest) => {
+                    drop_when_full(update_sender.clone(), self.metrics.clone())
+                }
+            };
             let datasource_clone = Arc::clone(datasource);
             let metrics_collection = self.metrics.clone();
 
@@ -356,88 +552,443 @@ impl Pipeline {
             });
         }
 
+        // Only the datasources hold senders, so that the receiver closes once
+        // they have all finished or, on shutdown, stopped after cancellation.
+        drop(update_sender);
+        drop(spill_sender);
+
+        let pipes = RunningPipes::take(self);
+        let result = match self.process_snapshots(&pipes).await {
+            Ok(()) => {
+                self.process_updates(
+                    &pipes,
+                    update_receiver,
+                    spill_replay,
+                    datasource_cancellation_token,
+                    shutdown_token,
+                    slot_checkpointer,
+                )
+                .await
+            }
+            Err(error) => {
+                datasource_cancellation_token.cancel();
+                Err(error)
+            }
+        };
+        pipes.restore(self);
+        result?;
+
+        log::info!("pipeline shutdown complete.");
+
+        Ok(())
+    }
+
+    /// Loads the snapshots and runs their accounts through the pipes, one
+    /// after the other.
+    ///
+    /// This happens once the datasources are started, so that no change
+    /// made while a snapshot loads is missed: the live updates wait in the
+    /// channel and are processed afterwards, on top of the snapshot state.
+    /// A snapshot that fails to load stops the pipeline, while an account
+    /// that fails to process is counted like a failed live update.
+    async fn process_snapshots(&self, pipes: &RunningPipes) -> CarbonResult<()> {
+        for snapshot in &self.snapshots {
+            let accounts = snapshot.load().await?;
+            log::info!("processing snapshot of {} accounts.", accounts.len());
+
+            for account_update in accounts {
+                let update = Update::Account(AccountUpdate {
+                    is_snapshot: true,
+                    ..account_update
+                });
+
+                match pipes.process(update).await {
+                    Ok(()) => {
+                        self.metrics
+                            .increment_counter("snapshot_accounts_processed", 1)
+                            .await?;
+                    }
+                    Err(error) => {
+                        log::error!("error processing snapshot account: {:?}", error);
+                        self.metrics.increment_counter("updates_failed", 1).await?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Receives updates and processes them until the pipeline shuts down.
+    ///
+    /// Up to `max_concurrent_transactions` updates are processed at the same
+    /// time. An update is only started once no update in flight locks any of
+    /// the accounts it locks (see `UpdateLocks`), and updates that cannot
+    /// start yet hold back the ones received after them. Results are handled
+    /// in the order the updates were received, so metrics and checkpoints see
+    /// the same sequence as with sequential processing.
+    ///
+    /// `spill_replay` is notified of every received update, so that spilled
+    /// updates are replayed once there is room in the channel.
+    async fn process_updates(
+        &self,
+        pipes: &RunningPipes,
+        mut update_receiver: tokio::sync::mpsc::Receiver<Update>,
+        spill_replay: Option<Arc<Notify>>,
+        datasource_cancellation_token: CancellationToken,
+        shutdown_token: CancellationToken,
+        mut slot_checkpointer: Option<SlotCheckpointer>,
+    ) -> CarbonResult<()> {
         let mut interval = tokio::time::interval(time::Duration::from_secs(
             self.metrics_flush_interval.unwrap_or(5),
         ));
+        let immediate_shutdown = self.shutdown_strategy == ShutdownStrategy::Immediate;
+        let shutdown_drain_timeout = self.shutdown_drain_timeout;
+        let mut shutdown_requested = false;
+        let mut drain_deadline: Option<tokio::time::Instant> = None;
+        let mut receiver_closed = false;
+
+        let mut in_flight = FuturesOrdered::new();
+        let mut in_flight_locks: VecDeque<UpdateLocks> = VecDeque::new();
+        let mut pending: Option<(Update, UpdateLocks)> = None;
+        let mut newest_received_slot: u64 = 0;
+        let mut slot_gaps = SlotGapDetector::default();
+        let mut reorder_buffer = self.slot_reorder_window.map(SlotReorderBuffer::new);
+        let mut seen_signatures = self.signature_dedupe_window.map(SignatureCache::new);
 
         loop {
+            if let Some(reorder_buffer) = reorder_buffer.as_mut().filter(|_| pending.is_none()) {
+                if receiver_closed {
+                    reorder_buffer.release_all();
+                }
+                pending = reorder_buffer.pop().map(|update| {
+                    let locks = UpdateLocks::of(&update);
+                    (update, locks)
+                });
+            }
+
+            if let Some((update, locks)) = pending.take() {
+                let can_start = in_flight.len() < self.max_concurrent_transactions
+                    && !in_flight_locks
+                        .iter()
+                        .any(|in_flight| in_flight.conflicts_with(&locks));
+
+                if can_start {
+                    in_flight_locks.push_back(locks);
+                    in_flight.push_back(async move {
+                        let start = Instant::now();
+                        let process_result = pipes.process(update.clone()).await;
+                        (update, start, process_result)
+                    });
+                } else {
+                    pending = Some((update, locks));
+                }
+            }
+
+            if receiver_closed
+                && pending.is_none()
+                && in_flight.is_empty()
+                && reorder_buffer
+                    .as_ref()
+                    .is_none_or(SlotReorderBuffer::is_empty)
+            {
+                log::info!("update_receiver closed, shutting down.");
+                break;
+            }
+
             tokio::select! {
-                _ = tokio::signal::ctrl_c() => {
-                    log::trace!("received SIGINT, shutting down.");
+                _ = shutdown_signal(&shutdown_token), if !shutdown_requested => {
+                    shutdown_requested = true;
                     datasource_cancellation_token.cancel();
 
-                    if self.shutdown_strategy == ShutdownStrategy::Immediate {
+                    if immediate_shutdown {
                         log::info!("shutting down the pipeline immediately.");
-                        self.metrics.flush_metrics().await?;
-                        self.metrics.shutdown_metrics().await?;
                         break;
                     } else {
                         log::info!("shutting down the pipeline after processing pending updates.");
+                        drain_deadline = shutdown_drain_timeout
+                            .map(|timeout| tokio::time::Instant::now() + timeout);
                     }
                 }
+                _ = sleep_until_deadline(drain_deadline), if drain_deadline.is_some() => {
+                    log::error!(
+                        "shutdown drain timeout elapsed, aborted processing of {} updates and dropping {} pending updates.",
+                        in_flight.len(),
+                        update_receiver.len() + usize::from(pending.is_some())
+                    );
+                    break;
+                }
                 _ = interval.tick() => {
                     self.metrics.flush_metrics().await?;
                 }
-                update = update_receiver.recv() => {
-                    match update {
-                        Some(update) => {
-                            self
-                                .metrics.increment_counter("updates_received", 1)
-                                .await?;
+                Some((update, start, process_result)) = in_flight.next(), if !in_flight.is_empty() => {
+                    in_flight_locks.pop_front();
+
+                    if let Some(slot_checkpointer) = slot_checkpointer.as_mut() {
+                        match &update {
+                            Update::Rollback(rollback) => slot_checkpointer
+                                .rollback(rollback.from_slot, process_result.is_ok()),
+                            _ => slot_checkpointer
+                                .record(update.slot(), process_result.is_ok()),
+                        }
+                    }
+
+                    if let Some((last_slot, gap)) = slot_gaps.record(&update) {
+                        log::warn!("{} slots missing between slot {} and slot {}.", gap, last_slot, update.slot());
+                        self.metrics.increment_counter("slot_gap_total", gap).await?;
+                    }
+
+                    let time_taken_nanoseconds = start.elapsed().as_nanos();
+                    let time_taken_milliseconds = time_taken_nanoseconds / 1_000_000;
 
-                            let start = Instant::now();
-                            let process_result = self.process(update.clone()).await;
-                            let time_taken_nanoseconds = start.elapsed().as_nanos();
-                            let time_taken_milliseconds = time_taken_nanoseconds / 1_000_000;
+                    self
+                        .metrics
+                        .record_histogram("updates_process_time_nanoseconds", time_taken_nanoseconds as f64)
+                        .await?;
+
+                    self
+                        .metrics
+                        .record_histogram("updates_process_time_milliseconds", time_taken_milliseconds as f64)
+                        .await?;
 
+                    match process_result {
+                        Ok(_) => {
                             self
-                                .metrics
-                                .record_histogram("updates_process_time_nanoseconds", time_taken_nanoseconds as f64)
+                                .metrics.increment_counter("updates_successful", 1)
                                 .await?;
 
+                            log::trace!("processed update")
+                        }
+                        Err(error) => {
+                            log::error!("error processing update ({:?}): {:?}", update, error);
+                            self.metrics.increment_counter("updates_failed", 1).await?;
+                        }
+                    };
+
+                    self
+                        .metrics.increment_counter("updates_processed", 1)
+                        .await?;
+
+                    self
+                        .metrics.update_gauge("updates_queued", update_receiver.len() as f64)
+                        .await?;
+
+                    self
+                        .metrics.update_gauge("updates_lag_slots", newest_received_slot.saturating_sub(update.slot()) as f64)
+                        .await?;
+                }
+                update = update_receiver.recv(), if pending.is_none() && !receiver_closed => {
+                    match update {
+                        Some(update) => {
+                            if let Some(spill_replay) = &spill_replay {
+                                spill_replay.notify_one();
+                            }
                             self
-                                .metrics
-                                .record_histogram("updates_process_time_milliseconds", time_taken_milliseconds as f64)
+                                .metrics.increment_counter("updates_received", 1)
                                 .await?;
+                            newest_received_slot = newest_received_slot.max(update.slot());
+
+                            if !self.accepts_commitment(&update) {
+                                log::trace!("skipping update below the configured commitment: {:?}", update);
+                                self
+                                    .metrics.increment_counter("updates_filtered_by_commitment", 1)
+                                    .await?;
+                                continue;
+                            }
 
-                            match process_result {
-                                Ok(_) => {
+                            if self.skips_failed_transaction(&update) {
+                                log::trace!("skipping failed transaction: {:?}", update);
+                                self
+                                    .metrics.increment_counter("failed_transactions_skipped", 1)
+                                    .await?;
+                                continue;
+                            }
+
+                            if let (Some(seen_signatures), Update::Transaction(transaction_update)) =
+                                (seen_signatures.as_mut(), &update)
+                            {
+                                if !seen_signatures.insert(transaction_update.signature) {
+                                    log::trace!("skipping duplicate transaction: {:?}", transaction_update.signature);
                                     self
-                                        .metrics.increment_counter("updates_successful", 1)
+                                        .metrics.increment_counter("duplicate_skipped", 1)
                                         .await?;
+                                    continue;
+                                }
+                            }
 
-                                    log::trace!("processed update")
+                            match reorder_buffer.as_mut() {
+                                Some(reorder_buffer) => {
+                                    if let Some(update) = reorder_buffer.push(update) {
+                                        log::debug!("dropping update older than the reordering window: {:?}", update);
+                                        self
+                                            .metrics.increment_counter("out_of_order_dropped", 1)
+                                            .await?;
+                                    }
                                 }
-                                Err(error) => {
-                                    log::error!("error processing update ({:?}): {:?}", update, error);
-                                    self.metrics.increment_counter("updates_failed", 1).await?;
+                                None => {
+                                    let locks = UpdateLocks::of(&update);
+                                    pending = Some((update, locks));
                                 }
-                            };
-
-                            self
-                                .metrics.increment_counter("updates_processed", 1)
-                                .await?;
-
-                            self
-                                .metrics.update_gauge("updates_queued", update_receiver.len() as f64)
-                                .await?;
-                        }
-                        None => {
-                            log::info!("update_receiver closed, shutting down.");
-                            self.metrics.flush_metrics().await?;
-                            self.metrics.shutdown_metrics().await?;
-                            break;
+                            }
                         }
+                        None => receiver_closed = true,
                     }
                 }
             }
         }
 
-        log::info!("pipeline shutdown complete.");
+        // Aborts the updates still in flight, which release their pipes.
+        drop(in_flight);
+
+        pipes.shutdown().await;
+        self.metrics.flush_metrics().await?;
+        self.metrics.shutdown_metrics().await?;
 
         Ok(())
     }
 
+    /// Returns whether `update` is at or above the configured commitment
+    /// level. Updates without a commitment tag are always accepted.
+    fn accepts_commitment(&self, update: &Update) -> bool {
+        match (self.commitment, update.commitment()) {
+            (Some(required), Some(commitment)) => commitment >= required,
+            _ => true,
+        }
+    }
+
+    /// Returns whether `update` is a transaction that failed on-chain and
+    /// should be skipped because of `skip_failed_transactions`.
+    fn skips_failed_transaction(&self, update: &Update) -> bool {
+        match update {
+            Update::Transaction(transaction_update) => {
+                self.skip_failed_transactions && transaction_update.meta.status.is_err()
+            }
+            _ => false,
+        }
+    }
+}
+
+/// The pipes of a running pipeline.
+///
+/// `Pipeline::run` moves its pipes here for the duration of the run, so that
+/// updates processed concurrently can share them. With
+/// `max_concurrent_transactions` above one, every pipe whose processor can be
+/// forked gets additional instances, up to one per update in flight; the
+/// other pipes handle one update at a time.
+pub(crate) struct RunningPipes {
+    account_pipes: Vec<RunningPipe<dyn AccountPipes>>,
+    account_deletion_pipes: Vec<RunningPipe<dyn AccountDeletionPipes>>,
+    instruction_pipes: Vec<RunningPipe<dyn for<'a> InstructionPipes<'a>>>,
+    transaction_pipes: Vec<RunningPipe<dyn for<'a> TransactionPipes<'a>>>,
+    rollback_pipes: Vec<RunningPipe<dyn RollbackPipes>>,
+    account_close_detector: Option<Arc<AccountCloseDetector>>,
+    transaction_context_finalizer: Option<Arc<dyn TransactionContextFinalizer>>,
+    max_instruction_depth: usize,
+    metrics: Arc<MetricsCollection>,
+}
+
+impl RunningPipes {
+    pub(crate) fn take(pipeline: &mut Pipeline) -> Self {
+        let instances = pipeline.max_concurrent_transactions;
+
+        Self {
+            account_pipes: std::mem::take(&mut pipeline.account_pipes)
+                .into_iter()
+                .map(|pipe| RunningPipe::new(pipe, |pipe| pipe.fork(), instances))
+                .collect(),
+            account_deletion_pipes: std::mem::take(&mut pipeline.account_deletion_pipes)
+                .into_iter()
+                .map(|pipe| RunningPipe::new(pipe, |pipe| pipe.fork(), instances))
+                .collect(),
+            instruction_pipes: std::mem::take(&mut pipeline.instruction_pipes)
+                .into_iter()
+                .map(|pipe| RunningPipe::new(pipe, |pipe| pipe.fork(), instances))
+                .collect(),
+            transaction_pipes: std::mem::take(&mut pipeline.transaction_pipes)
+                .into_iter()
+                .map(|pipe| RunningPipe::new(pipe, |pipe| pipe.fork(), instances))
+                .collect(),
+            rollback_pipes: std::mem::take(&mut pipeline.rollback_pipes)
+                .into_iter()
+                .map(|pipe| RunningPipe::new(pipe, |pipe| pipe.fork(), instances))
+                .collect(),
+            account_close_detector: pipeline.account_close_detector.clone(),
+            transaction_context_finalizer: pipeline.transaction_context_finalizer.clone(),
+            max_instruction_depth: pipeline.max_instruction_depth,
+            metrics: pipeline.metrics.clone(),
+        }
+    }
+
+    pub(crate) fn restore(self, pipeline: &mut Pipeline) {
+        pipeline.account_pipes = self
+            .account_pipes
+            .into_iter()
+            .map(RunningPipe::into_pipe)
+            .collect();
+        pipeline.account_deletion_pipes = self
+            .account_deletion_pipes
+            .into_iter()
+            .map(RunningPipe::into_pipe)
+            .collect();
+        pipeline.instruction_pipes = self
+            .instruction_pipes
+            .into_iter()
+            .map(RunningPipe::into_pipe)
+            .collect();
+        pipeline.transaction_pipes = self
+            .transaction_pipes
+            .into_iter()
+            .map(RunningPipe::into_pipe)
+            .collect();
+        pipeline.rollback_pipes = self
+            .rollback_pipes
+            .into_iter()
+            .map(RunningPipe::into_pipe)
+            .collect();
+    }
+
+    /// Calls `shutdown` on every instance of every pipe. Failures are logged
+    /// and do not keep the remaining pipes from shutting down.
+    pub(crate) async fn shutdown(&self) {
+        for instance in self.account_pipes.iter().flat_map(RunningPipe::instances) {
+            if let Err(error) = instance.lock().await.shutdown().await {
+                log::error!("error shutting down account pipe: {:?}", error);
+            }
+        }
+        for instance in self
+            .account_deletion_pipes
+            .iter()
+            .flat_map(RunningPipe::instances)
+        {
+            if let Err(error) = instance.lock().await.shutdown().await {
+                log::error!("error shutting down account deletion pipe: {:?}", error);
+            }
+        }
+        for instance in self
+            .instruction_pipes
+            .iter()
+            .flat_map(RunningPipe::instances)
+        {
+            if let Err(error) = instance.lock().await.shutdown().await {
+                log::error!("error shutting down instruction pipe: {:?}", error);
+            }
+        }
+        for instance in self
+            .transaction_pipes
+            .iter()
+            .flat_map(RunningPipe::instances)
+        {
+            if let Err(error) = instance.lock().await.shutdown().await {
+                log::error!("error shutting down transaction pipe: {:?}", error);
+            }
+        }
+        for instance in self.rollback_pipes.iter().flat_map(RunningPipe::instances) {
+            if let Err(error) = instance.lock().await.shutdown().await {
+                log::error!("error shutting down rollback pipe: {:?}", error);
+            }
+        }
+    }
+
     /// Processes a single update and routes it through the appropriate pipeline
     /// stages.
     ///
@@ -451,12 +1002,14 @@ impl Pipeline {
     ///
     /// - **Account Updates**: Passes account updates through the
     ///   `account_pipes`. Each pipe processes the account metadata and the
-    ///   updated account state.
+    ///   updated account state. Updates recognised as closes by the
+    ///   `account_close_detector` are handled as account deletions instead.
     /// - **Transaction Updates**: Extracts transaction metadata and
     ///   instructions, nests them if needed, and routes them through
     ///   `instruction_pipes` and `transaction_pipes`.
     /// - **Account Deletions**: Sends account deletion events through the
     ///   `account_deletion_pipes`.
+    /// - **Rollbacks**: Sends rollback events through the `rollback_pipes`.
     ///
     /// The method also updates metrics counters for each type of update,
     /// tracking how many updates have been processed in each category.
@@ -487,16 +1040,35 @@ impl Pipeline {
     /// Returns an error if any of the pipes fail during processing, or if an
     /// issue arises while incrementing counters or updating metrics. Handle
     /// errors gracefully to ensure continuous pipeline operation.
-    async fn process(&mut self, update: Update) -> CarbonResult<()> {
-        log::trace!("process(self, update: {:?})", update);
+    async fn process(&self, update: Update) -> CarbonResult<()> {
+        log::trace!("RunningPipes::process(self, update: {:?})", update);
         match update {
             Update::Account(account_update) => {
+                if let Some(account_deletion) = self
+                    .account_close_detector
+                    .as_ref()
+                    .and_then(|detector| detector.detect(&account_update))
+                {
+                    log::debug!(
+                        "account {} closed at slot {}",
+                        account_deletion.pubkey,
+                        account_deletion.slot
+                    );
+                    self.metrics
+                        .increment_counter("account_closes_detected", 1)
+                        .await?;
+                    return self.process_account_deletion(account_deletion).await;
+                }
+
                 let account_metadata = AccountMetadata {
                     slot: account_update.slot,
                     pubkey: account_update.pubkey,
+                    is_snapshot: account_update.is_snapshot,
+                    source: account_update.source.clone(),
                 };
 
-                for pipe in self.account_pipes.iter_mut() {
+                for pipe in self.account_pipes.iter() {
+                    let mut pipe = pipe.acquire().await;
                     pipe.run(
                         (account_metadata.clone(), account_update.account.clone()),
                         self.metrics.clone(),
@@ -509,84 +1081,525 @@ impl Pipeline {
                     .await?;
             }
             Update::Transaction(transaction_update) => {
-                let transaction_metadata = &(*transaction_update).clone().try_into()?;
-
-                let instructions_with_metadata: InstructionsWithMetadata =
-                    transformers::extract_instructions_with_metadata(
-                        transaction_metadata,
-                        &transaction_update,
-                    )?;
-
-                let nested_instructions: NestedInstructions = instructions_with_metadata.into();
-
-                for pipe in self.instruction_pipes.iter_mut() {
-                    for nested_instruction in nested_instructions.iter() {
-                        pipe.run(nested_instruction, self.metrics.clone()).await?;
-                    }
-                }
-
-                for pipe in self.transaction_pipes.iter_mut() {
-                    pipe.run(
-                        transaction_metadata.clone(),
-                        &nested_instructions,
-                        self.metrics.clone(),
-                    )
-                    .await?;
-                }
-
-                self.metrics
-                    .increment_counter("transaction_updates_processed", 1)
+                let span = tracing::info_span!(
+                    "transaction",
+                    signature = %transaction_update.signature,
+                    slot = transaction_update.slot,
+                );
+                self.process_transaction(transaction_update)
+                    .instrument(span)
                     .await?;
             }
             Update::AccountDeletion(account_deletion) => {
-                for pipe in self.account_deletion_pipes.iter_mut() {
-                    pipe.run(account_deletion.clone(), self.metrics.clone())
-                        .await?;
+                if let Some(detector) = &self.account_close_detector {
+                    detector.forget(&account_deletion.pubkey);
+                }
+                self.process_account_deletion(account_deletion).await?;
+            }
+            Update::Rollback(rollback) => {
+                log::warn!("rolling back updates from slot {}.", rollback.from_slot);
+
+                for pipe in self.rollback_pipes.iter() {
+                    let mut pipe = pipe.acquire().await;
+                    pipe.run(rollback, self.metrics.clone()).await?;
                 }
 
                 self.metrics
-                    .increment_counter("account_deletions_processed", 1)
+                    .increment_counter("rollbacks_processed", 1)
                     .await?;
             }
         };
 
         Ok(())
     }
+
+    /// Runs the account deletion pipes on an account deletion.
+    async fn process_account_deletion(
+        &self,
+        account_deletion: AccountDeletion,
+    ) -> CarbonResult<()> {
+        for pipe in self.account_deletion_pipes.iter() {
+            let mut pipe = pipe.acquire().await;
+            pipe.run(account_deletion.clone(), self.metrics.clone())
+                .await?;
+        }
+
+        self.metrics
+            .increment_counter("account_deletions_processed", 1)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Runs the instruction and transaction pipes on a transaction update.
+    ///
+    /// Called inside the update's `transaction` span, which the instruction
+    /// pipes open their `instruction` spans in.
+    async fn process_transaction(
+        &self,
+        transaction_update: Box<TransactionUpdate>,
+    ) -> CarbonResult<()> {
+        let transaction_metadata = (*transaction_update).clone().try_into()?;
+
+        let instructions_with_metadata: InstructionsWithMetadata =
+            transformers::extract_instructions_with_metadata(
+                &transaction_metadata,
+                &transaction_update,
+            )?;
+
+        self.process_instructions(transaction_metadata, instructions_with_metadata)
+            .await
+    }
+
+    /// Runs the instruction and transaction pipes on the instructions of a
+    /// transaction, once they have been extracted from its update, followed by
+    /// the `TransactionContextFinalizer`, if any.
+    pub(crate) async fn process_instructions(
+        &self,
+        transaction_metadata: TransactionMetadata,
+        mut instructions_with_metadata: InstructionsWithMetadata,
+    ) -> CarbonResult<()> {
+        let transaction_context = self
+            .transaction_context_finalizer
+            .as_ref()
+            .map(|_| TransactionContext::new());
+        if let Some(context) = &transaction_context {
+            for (metadata, _) in instructions_with_metadata.iter_mut() {
+                metadata.transaction_context = Some(context.clone());
+            }
+        }
+
+        let (nested_instructions, truncated) = NestedInstructions::with_max_depth(
+            instructions_with_metadata,
+            self.max_instruction_depth,
+        );
+        if truncated > 0 {
+            log::warn!(
+                "Skipped {} inner instructions nested deeper than {} in transaction {}",
+                truncated,
+                self.max_instruction_depth,
+                transaction_metadata.signature
+            );
+            self.metrics
+                .increment_counter("instruction_depth_truncated", truncated)
+                .await?;
+        }
+
+        // Pipes run sequentially in registration order, which processors and
+        // tests rely on; see `PipelineBuilder::instruction`.
+        for pipe in self.instruction_pipes.iter() {
+            let mut pipe = pipe.acquire().await;
+            for nested_instruction in nested_instructions.iter() {
+                pipe.run(nested_instruction, self.metrics.clone()).await?;
+            }
+        }
+
+        for pipe in self.transaction_pipes.iter() {
+            let mut pipe = pipe.acquire().await;
+            pipe.run(
+                transaction_metadata.clone(),
+                &nested_instructions,
+                self.metrics.clone(),
+            )
+            .await?;
+        }
+
+        if let (Some(finalizer), Some(context)) =
+            (&self.transaction_context_finalizer, transaction_context)
+        {
+            finalizer
+                .finalize(&transaction_metadata, context, self.metrics.clone())
+                .await?;
+        }
+
+        self.metrics
+            .increment_counter("transaction_updates_processed", 1)
+            .await?;
+
+        Ok(())
+    }
 }
 
-/// A builder for constructing a `Pipeline` instance with customized data
-/// sources, processing pipes, and metrics.
-///
-/// The `PipelineBuilder` struct offers a flexible way to assemble a `Pipeline`
-/// by allowing configuration of its components, such as data sources, account
-/// and transaction pipes, deletion handling, and metrics. Using the builder
-/// pattern, you can add the desired elements incrementally and then finalize
-/// with a call to `build`.
-///
-/// ## Overview
-///
-/// The `PipelineBuilder` supports the following components:
-/// - **Datasources**: Sources of data updates, such as account information and
-///   transactions.
-/// - **Account Pipes**: For processing account updates from data sources.
-/// - **Account Deletion Pipes**: For handling account deletion updates.
-/// - **Instruction Pipes**: For handling instructions associated with
-///   transactions.
-/// - **Transaction Pipes**: For handling full transaction data.
-/// - **Metrics**: Collects and reports performance data, such as update
-///   processing times.
-/// - **Metrics Flush Interval**: Optional interval defining how often to flush
-///   metrics data.
+/// The instances of a pipe while the pipeline runs: the pipe registered on the
+/// pipeline, followed by the forks created for concurrent processing.
+struct RunningPipe<T: ?Sized> {
+    instances: Vec<Mutex<Box<T>>>,
+}
+
+impl<T: ?Sized> RunningPipe<T> {
+    /// Creates up to `max_instances` instances of `pipe`, stopping at the
+    /// first fork that fails.
+    fn new(pipe: Box<T>, fork: impl Fn(&T) -> Option<Box<T>>, max_instances: usize) -> Self {
+        let forks: Vec<Box<T>> = (1..max_instances).map_while(|_| fork(&pipe)).collect();
+
+        Self {
+            instances: std::iter::once(pipe).chain(forks).map(Mutex::new).collect(),
+        }
+    }
+
+    fn instances(&self) -> &[Mutex<Box<T>>] {
+        &self.instances
+    }
+
+    /// Waits until an instance is idle and locks it.
+    async fn acquire(&self) -> MutexGuard<'_, Box<T>> {
+        if let [instance] = self.instances.as_slice() {
+            return instance.lock().await;
+        }
+
+        if let Some(guard) = self
+            .instances
+            .iter()
+            .find_map(|instance| instance.try_lock().ok())
+        {
+            return guard;
+        }
+
+        select_all(
+            self.instances
+                .iter()
+                .map(|instance| Box::pin(instance.lock())),
+        )
+        .await
+        .0
+    }
+
+    /// Returns the pipe registered on the pipeline, dropping the forks.
+    fn into_pipe(self) -> Box<T> {
+        self.instances
+            .into_iter()
+            .next()
+            .expect("a running pipe has at least one instance")
+            .into_inner()
+    }
+}
+
+/// The accounts an update may modify, which decide whether it can be processed
+/// concurrently with other updates.
 ///
-/// Each component can be added through method chaining, enhancing code
-/// readability and maintainability.
+/// - An account update or deletion locks its account.
+/// - A transaction locks every account it may write to, as returned by
+///   `TransactionUpdate::writable_accounts`. Accounts it only reads are not
+///   locked.
+/// - A rollback is exclusive: it waits for every update in flight, and no other
+///   update starts until it has been processed.
 ///
-/// # Example
+/// Two updates conflict when one of them is exclusive or when they lock a
+/// common account.
+#[derive(Debug)]
+enum UpdateLocks {
+    Accounts(Vec<Pubkey>),
+    Exclusive,
+}
+
+impl UpdateLocks {
+    fn of(update: &Update) -> Self {
+        match update {
+            Update::Account(account_update) => Self::Accounts(vec![account_update.pubkey]),
+            Update::AccountDeletion(account_deletion) => {
+                Self::Accounts(vec![account_deletion.pubkey])
+            }
+            Update::Transaction(transaction_update) => {
+                Self::Accounts(transaction_update.writable_accounts())
+            }
+            Update::Rollback(_) => Self::Exclusive,
+        }
+    }
+
+    fn conflicts_with(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Accounts(accounts), Self::Accounts(other_accounts)) => accounts
+                .iter()
+                .any(|account| other_accounts.contains(account)),
+            _ => true,
+        }
+    }
+}
+
+/// Resolves once the process receives `SIGINT` or `shutdown_token` is
+/// cancelled.
+async fn shutdown_signal(shutdown_token: &CancellationToken) {
+    tokio::select! {
+        _ = tokio::signal::ctrl_c() => log::trace!("received SIGINT, shutting down."),
+        _ = shutdown_token.cancelled() => log::trace!("shutdown requested, shutting down."),
+    }
+}
+
+/// Holds updates back to release them in non-decreasing slot order, for
+/// `PipelineBuilder::slot_reorder_window`.
 ///
-/// ```rust
-/// carbon_core::pipeline::Pipeline::builder()
-/// .datasource(transaction_crawler)
-/// .metrics(Arc::new(LogMetrics::new()))
+/// Updates are held until an update at least `window` slots newer arrives.
+/// Updates for a slot older than the last released one are rejected.
+struct SlotReorderBuffer {
+    window: u64,
+    held: BTreeMap<u64, VecDeque<Update>>,
+    released: VecDeque<Update>,
+    newest_slot: u64,
+    released_slot: Option<u64>,
+}
+
+impl SlotReorderBuffer {
+    fn new(window: u64) -> Self {
+        Self {
+            window,
+            held: BTreeMap::new(),
+            released: VecDeque::new(),
+            newest_slot: 0,
+            released_slot: None,
+        }
+    }
+
+    /// Adds an update to the buffer. Returns the update instead if its slot is
+    /// older than the last released slot.
+    fn push(&mut self, update: Update) -> Option<Update> {
+        if let Update::Rollback(rollback) = &update {
+            let from_slot = rollback.from_slot;
+            self.release_all();
+            self.released.push_back(update);
+            self.newest_slot = from_slot;
+            self.released_slot = self.released_slot.map(|slot| slot.min(from_slot));
+            return None;
+        }
+
+        let slot = update.slot();
+        if self
+            .released_slot
+            .is_some_and(|released_slot| slot < released_slot)
+        {
+            return Some(update);
+        }
+
+        self.newest_slot = self.newest_slot.max(slot);
+        self.held.entry(slot).or_default().push_back(update);
+        while let Some(entry) = self.held.first_entry() {
+            if self.newest_slot - entry.key() < self.window {
+                break;
+            }
+            self.released_slot = Some(*entry.key());
+            self.released.extend(entry.remove());
+        }
+
+        None
+    }
+
+    /// Releases every held update, for when no more updates will arrive.
+    fn release_all(&mut self) {
+        while let Some((slot, updates)) = self.held.pop_first() {
+            self.released_slot = Some(slot);
+            self.released.extend(updates);
+        }
+    }
+
+    /// Returns the next released update, if any.
+    fn pop(&mut self) -> Option<Update> {
+        self.released.pop_front()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.held.is_empty() && self.released.is_empty()
+    }
+}
+
+/// Tracks the newest processed slot to detect slots that were never delivered.
+/// See the "Slot Gaps" section of the `Pipeline` documentation.
+#[derive(Default)]
+struct SlotGapDetector {
+    last_slot: Option<u64>,
+}
+
+impl SlotGapDetector {
+    /// Records a processed update. Returns the previous newest slot and the
+    /// number of slots between it and the update's slot, if there are any.
+    fn record(&mut self, update: &Update) -> Option<(u64, u64)> {
+        if let Update::Rollback(rollback) = update {
+            // The rolled back slots are delivered again.
+            let slot = rollback.from_slot.saturating_sub(1);
+            self.last_slot = self.last_slot.map(|last_slot| last_slot.min(slot));
+            return None;
+        }
+
+        let slot = update.slot();
+        let last_slot = self.last_slot.replace(slot)?;
+        if slot <= last_slot {
+            self.last_slot = Some(last_slot);
+            return None;
+        }
+
+        let gap = slot - last_slot - 1;
+        (gap > 0).then_some((last_slot, gap))
+    }
+}
+
+/// Saves the pipeline's progress to a `Checkpoint` as slots complete.
+///
+/// A slot is considered complete once an update for a later slot has been
+/// processed, so the slot that is in progress at shutdown is never saved and is
+/// processed again after a restart. Once an update fails, no later slot is
+/// saved either, so that a restart does not skip the failed update.
+struct SlotCheckpointer {
+    checkpoint: Arc<dyn Checkpoint>,
+    interval: u64,
+    last_saved_slot: Option<u64>,
+    current_slot: Option<u64>,
+    current_slot_failed: bool,
+    halted: bool,
+}
+
+impl SlotCheckpointer {
+    fn new(checkpoint: Arc<dyn Checkpoint>, interval: u64, last_saved_slot: Option<u64>) -> Self {
+        Self {
+            checkpoint,
+            interval: interval.max(1),
+            last_saved_slot,
+            current_slot: None,
+            current_slot_failed: false,
+            halted: false,
+        }
+    }
+
+    /// Records the outcome of processing an update recorded in `slot`.
+    fn record(&mut self, slot: u64, success: bool) {
+        let Some(current_slot) = self.current_slot else {
+            self.current_slot = Some(slot);
+            self.current_slot_failed = !success;
+            return;
+        };
+
+        if slot <= current_slot {
+            if !success {
+                // An update of an earlier slot may arrive late when several
+                // datasources are used; its slot may already be saved.
+                if slot < current_slot {
+                    self.halt(slot);
+                }
+                self.current_slot_failed = true;
+            }
+            return;
+        }
+
+        if self.current_slot_failed {
+            self.halt(current_slot);
+        } else if !self.halted
+            && self
+                .last_saved_slot
+                .is_none_or(|last_saved_slot| current_slot >= last_saved_slot + self.interval)
+        {
+            match self.checkpoint.save(current_slot) {
+                Ok(()) => self.last_saved_slot = Some(current_slot),
+                Err(error) => log::error!("error saving checkpoint: {:?}", error),
+            }
+        }
+
+        self.current_slot = Some(slot);
+        self.current_slot_failed = !success;
+    }
+
+    /// Forgets the progress made in `from_slot` and later slots, moving the
+    /// checkpoint back if it was already saved past the rollback.
+    fn rollback(&mut self, from_slot: u64, success: bool) {
+        self.current_slot = None;
+        self.current_slot_failed = false;
+
+        if !success {
+            self.halt(from_slot);
+            return;
+        }
+
+        if self
+            .last_saved_slot
+            .is_some_and(|last_saved_slot| last_saved_slot >= from_slot)
+        {
+            let slot = from_slot.saturating_sub(1);
+            match self.checkpoint.save(slot) {
+                Ok(()) => self.last_saved_slot = Some(slot),
+                Err(error) => {
+                    log::error!("error saving checkpoint: {:?}", error);
+                    self.halt(from_slot);
+                }
+            }
+        }
+    }
+
+    fn halt(&mut self, slot: u64) {
+        if !self.halted {
+            log::warn!(
+                "an update of slot {} failed, the checkpoint is no longer advanced.",
+                slot
+            );
+            self.halted = true;
+        }
+    }
+}
+
+/// Resolves at `deadline`, or never if there is no deadline.
+async fn sleep_until_deadline(deadline: Option<tokio::time::Instant>) {
+    match deadline {
+        Some(deadline) => tokio::time::sleep_until(deadline).await,
+        None => std::future::pending().await,
+    }
+}
+
+/// Returns a sender for a datasource that never waits on the pipeline: a
+/// spawned task forwards its updates to `update_sender`, dropping and counting
+/// those that do not fit in the channel, until the datasource drops its
+/// senders.
+fn drop_when_full(
+    update_sender: tokio::sync::mpsc::Sender<Update>,
+    metrics: Arc<MetricsCollection>,
+) -> tokio::sync::mpsc::Sender<Update> {
+    let (datasource_sender, mut datasource_receiver) = tokio::sync::mpsc::channel::<Update>(1);
+
+    tokio::spawn(async move {
+        while let Some(update) = datasource_receiver.recv().await {
+            match update_sender.try_send(update) {
+                Ok(()) => {}
+                Err(tokio::sync::mpsc::error::TrySendError::Full(update)) => {
+                    log::debug!("channel full, dropping update of slot {}.", update.slot());
+                    if let Err(error) = metrics.increment_counter("updates_dropped", 1).await {
+                        log::error!("failed to count dropped update: {:?}", error);
+                    }
+                }
+                Err(tokio::sync::mpsc::error::TrySendError::Closed(_)) => break,
+            }
+        }
+    });
+
+    datasource_sender
+}
+
+/// A builder for constructing a `Pipeline` instance with customized data
+/// sources, processing pipes, and metrics.
+///
+/// The `PipelineBuilder` struct offers a flexible way to assemble a `Pipeline`
+/// by allowing configuration of its components, such as data sources, account
+/// and transaction pipes, deletion handling, and metrics. Using the builder
+/// pattern, you can add the desired elements incrementally and then finalize
+/// with a call to `build`.
+///
+/// ## Overview
+///
+/// The `PipelineBuilder` supports the following components:
+/// - **Datasources**: Sources of data updates, such as account information and
+///   transactions.
+/// - **Account Pipes**: For processing account updates from data sources.
+/// - **Account Deletion Pipes**: For handling account deletion updates.
+/// - **Instruction Pipes**: For handling instructions associated with
+///   transactions.
+/// - **Transaction Pipes**: For handling full transaction data.
+/// - **Metrics**: Collects and reports performance data, such as update
+///   processing times.
+/// - **Metrics Flush Interval**: Optional interval defining how often to flush
+///   metrics data.
+///
+/// Each component can be added through method chaining, enhancing code
+/// readability and maintainability.
+///
+/// # Example
+///
+/// ```rust
+/// carbon_core::pipeline::Pipeline::builder()
+/// .datasource(transaction_crawler)
+/// .metrics(Arc::new(LogMetrics::new()))
 /// .metrics(Arc::new(PrometheusMetrics::new()))
 /// .instruction(
 ///    TestProgramDecoder,
@@ -607,14 +1620,48 @@ impl Pipeline {
 ///   instructions in transactions.
 /// - `transaction_pipes`: A collection of `TransactionPipes` to process full
 ///   transaction data.
+/// - `rollback_pipes`: A collection of `RollbackPipes` for processing
+///   rollbacks.
 /// - `metrics`: A vector of `Metrics` implementations for tracking pipeline
 ///   performance.
 /// - `metrics_flush_interval`: An optional interval (in seconds) for flushing
 ///   metrics data. If not set, a default flush interval will be used.
 /// - `datasource_cancellation_token`: An optional `CancellationToken` for
 ///   canceling datasource. If not set, a default `CancellationToken` will be used.
+/// - `shutdown_token`: An optional `CancellationToken` for shutting down the
+///   pipeline on demand.
+/// - `shutdown_drain_timeout`: An optional limit on how long pending updates
+///   are drained after a shutdown was requested.
 /// - `channel_buffer_size`: The size of the channel buffer for the pipeline.
-///   If not set, a default size of 10_000 will be used.
+///   If not set, `DEFAULT_CHANNEL_BUFFER_SIZE` is used.
+/// - `backpressure_policy`: What happens to updates sent while the channel
+///   buffer is full. See `BackpressurePolicy`.
+/// - `spill_to_disk`: An optional `SpillToDisk` configuration, spilling the
+///   updates that do not fit in the channel to disk.
+/// - `slot_reorder_window`: If set, how many slots updates are held back to
+///   be processed in slot order.
+/// - `deterministic`: Whether updates are processed one after the other, so
+///   that processors observe them in a reproducible order. Defaults to `false`.
+/// - `dead_letter_sink`: An optional `DeadLetterSink` receiving the
+///   instructions that fail to decode or process.
+/// - `skip_failed_transactions`: Whether transactions that failed on-chain are
+///   skipped before reaching the instruction and transaction pipes. Defaults to
+///   `true`.
+/// - `decoder_claims`: The `DecoderClaims` shared by the instruction pipes to
+///   detect decoders that decode the same program ID and discriminator.
+/// - `max_instruction_depth`: The deepest level of inner instructions visited
+///   by the instruction pipes.
+/// - `top_level_only`: Whether the instruction pipes only visit top-level
+///   instructions. Defaults to `false`.
+/// - `account_close_detector`: An optional `AccountCloseDetector` routing
+///   account updates that report a closed account to the account deletion
+///   pipes.
+/// - `signature_dedupe_window`: An optional number of recent transaction
+///   signatures remembered to skip duplicate transactions.
+/// - `snapshots`: A collection of `AccountSnapshot`s loaded and processed
+///   before the live updates.
+/// - `transaction_context_finalizer`: An optional `TransactionContextFinalizer`
+///   receiving the `TransactionContext` of every transaction.
 ///
 /// # Returns
 ///
@@ -628,18 +1675,75 @@ impl Pipeline {
 ///   incrementally add components to the `Pipeline`.
 /// - Ensure that each component matches the data and update types expected by
 ///   your application.
-#[derive(Default)]
+#[must_use = "a PipelineBuilder does nothing until `build` is called"]
 pub struct PipelineBuilder {
     pub datasources: Vec<Arc<dyn Datasource + Send + Sync>>,
     pub account_pipes: Vec<Box<dyn AccountPipes>>,
     pub account_deletion_pipes: Vec<Box<dyn AccountDeletionPipes>>,
     pub instruction_pipes: Vec<Box<dyn for<'a> InstructionPipes<'a>>>,
     pub transaction_pipes: Vec<Box<dyn for<'a> TransactionPipes<'a>>>,
+    pub rollback_pipes: Vec<Box<dyn RollbackPipes>>,
     pub metrics: MetricsCollection,
     pub metrics_flush_interval: Option<u64>,
     pub datasource_cancellation_token: Option<CancellationToken>,
     pub shutdown_strategy: ShutdownStrategy,
+    pub shutdown_token: Option<CancellationToken>,
+    pub shutdown_drain_timeout: Option<Duration>,
     pub channel_buffer_size: usize,
+    pub backpressure_policy: BackpressurePolicy,
+    pub spill_to_disk: Option<SpillToDisk>,
+    pub slot_reorder_window: Option<u64>,
+    pub checkpoint: Option<Arc<dyn Checkpoint>>,
+    pub checkpoint_interval: u64,
+    pub commitment: Option<Commitment>,
+    pub max_concurrent_transactions: usize,
+    pub deterministic: bool,
+    pub dead_letter_sink: Option<Arc<dyn DeadLetterSink>>,
+    pub skip_failed_transactions: bool,
+    pub decoder_claims: Arc<DecoderClaims>,
+    pub max_instruction_depth: usize,
+    pub top_level_only: bool,
+    pub account_close_detector: Option<Arc<AccountCloseDetector>>,
+    pub signature_dedupe_window: Option<usize>,
+    pub snapshots: Vec<Arc<dyn AccountSnapshot>>,
+    pub transaction_context_finalizer: Option<Arc<dyn TransactionContextFinalizer>>,
+}
+
+impl Default for PipelineBuilder {
+    fn default() -> Self {
+        Self {
+            datasources: Vec::new(),
+            account_pipes: Vec::new(),
+            account_deletion_pipes: Vec::new(),
+            instruction_pipes: Vec::new(),
+            transaction_pipes: Vec::new(),
+            rollback_pipes: Vec::new(),
+            metrics: MetricsCollection::default(),
+            metrics_flush_interval: None,
+            datasource_cancellation_token: None,
+            shutdown_strategy: ShutdownStrategy::default(),
+            shutdown_token: None,
+            shutdown_drain_timeout: None,
+            channel_buffer_size: DEFAULT_CHANNEL_BUFFER_SIZE,
+            backpressure_policy: BackpressurePolicy::default(),
+            spill_to_disk: None,
+            slot_reorder_window: None,
+            checkpoint: None,
+            checkpoint_interval: DEFAULT_CHECKPOINT_INTERVAL,
+            commitment: None,
+            max_concurrent_transactions: DEFAULT_MAX_CONCURRENT_TRANSACTIONS,
+            deterministic: false,
+            dead_letter_sink: None,
+            skip_failed_transactions: true,
+            decoder_claims: Arc::new(DecoderClaims::default()),
+            max_instruction_depth: DEFAULT_MAX_INSTRUCTION_DEPTH,
+            top_level_only: false,
+            account_close_detector: None,
+            signature_dedupe_window: None,
+            snapshots: Vec::new(),
+            transaction_context_finalizer: None,
+        }
+    }
 }
 
 impl PipelineBuilder {
@@ -737,7 +1841,10 @@ impl PipelineBuilder {
     pub fn account<T: Send + Sync + 'static>(
         mut self,
         decoder: impl for<'a> AccountDecoder<'a, AccountType = T> + Send + Sync + 'static,
-        processor: impl Processor<InputType = AccountProcessorInputType<T>> + Send + Sync + 'static,
+        processor: impl Processor<InputType = AccountProcessorInputType<T>>
+            + SendProcessor
+            + SyncProcessor
+            + StaticProcessor,
     ) -> Self {
         log::trace!(
             "account(self, decoder: {:?}, processor: {:?})",
@@ -745,7 +1852,7 @@ impl PipelineBuilder {
             stringify!(processor)
         );
         self.account_pipes.push(Box::new(AccountPipe {
-            decoder: Box::new(decoder),
+            decoder: Arc::new(decoder),
             processor: Box::new(processor),
         }));
         self
@@ -768,7 +1875,10 @@ impl PipelineBuilder {
     /// ```
     pub fn account_deletions(
         mut self,
-        processor: impl Processor<InputType = AccountDeletion> + Send + Sync + 'static,
+        processor: impl Processor<InputType = AccountDeletion>
+            + SendProcessor
+            + SyncProcessor
+            + StaticProcessor,
     ) -> Self {
         log::trace!(
             "account_deletions(self, processor: {:?})",
@@ -781,11 +1891,47 @@ impl PipelineBuilder {
         self
     }
 
+    /// Adds a rollback pipe to handle rollbacks emitted after fork switches.
+    ///
+    /// The processor receives every `Rollback` emitted by the datasources and
+    /// should delete or invalidate the data it stored for `from_slot` and
+    /// later slots. Datasources that only deliver finalized data never emit
+    /// rollbacks.
+    ///
+    /// # Parameters
+    ///
+    /// - `processor`: A `Processor` that processes rollback events.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .rollbacks(MyRollbackProcessor);
+    /// ```
+    pub fn rollbacks(
+        mut self,
+        processor: impl Processor<InputType = Rollback>
+            + SendProcessor
+            + SyncProcessor
+            + StaticProcessor,
+    ) -> Self {
+        log::trace!("rollbacks(self, processor: {:?})", stringify!(processor));
+        self.rollback_pipes.push(Box::new(RollbackPipe {
+            processor: Box::new(processor),
+        }));
+        self
+    }
+
     /// Adds an instruction pipe to process instructions within transactions.
     ///
     /// Instruction pipes decode and process individual instructions,
     /// enabling specialized handling of various instruction types.
     ///
+    /// For every transaction, the instruction pipes run one after the other
+    /// in the order they were added, each going through the transaction's
+    /// instructions in order before the next pipe starts. The transaction
+    /// pipes run afterwards, also in the order they were added.
+    ///
     /// # Parameters
     ///
     /// - `decoder`: An `InstructionDecoder` for decoding instructions from
@@ -799,18 +1945,124 @@ impl PipelineBuilder {
     ///     .instruction(MyDecoder, MyInstructionProcessor);
     /// ```
     pub fn instruction<T: Send + Sync + 'static>(
-        mut self,
+        self,
         decoder: impl for<'a> InstructionDecoder<'a, InstructionType = T> + Send + Sync + 'static,
-        processor: impl Processor<InputType = InstructionProcessorInputType<T>> + Send + Sync + 'static,
+        processor: impl Processor<InputType = InstructionProcessorInputType<T>>
+            + SendProcessor
+            + SyncProcessor
+            + StaticProcessor,
     ) -> Self {
         log::trace!(
             "instruction(self, decoder: {:?}, processor: {:?})",
             stringify!(decoder),
             stringify!(processor)
         );
+        self.instruction_pipe(decoder, processor, None)
+    }
+
+    /// Adds an instruction pipe that handles the instructions it fails to
+    /// decode or process according to `error_policy`.
+    ///
+    /// Pipes added with `instruction` fail the update on such errors, or send
+    /// the instruction to the dead letter sink if the pipeline has one. This
+    /// lets a critical decoder fail the update while an experimental one
+    /// skips what it cannot handle.
+    ///
+    /// # Parameters
+    ///
+    /// - `decoder`: An `InstructionDecoder` for decoding instructions from
+    ///   transaction data.
+    /// - `processor`: A `Processor` that processes decoded instruction data.
+    /// - `error_policy`: What to do with the instructions that fail. See
+    ///   `ErrorPolicy`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .instruction_with_error_policy(
+    ///         ExperimentalDecoder,
+    ///         ExperimentalProcessor,
+    ///         ErrorPolicy::Skip,
+    ///     );
+    /// ```
+    pub fn instruction_with_error_policy<T: Send + Sync + 'static>(
+        self,
+        decoder: impl for<'a> InstructionDecoder<'a, InstructionType = T> + Send + Sync + 'static,
+        processor: impl Processor<InputType = InstructionProcessorInputType<T>>
+            + SendProcessor
+            + SyncProcessor
+            + StaticProcessor,
+        error_policy: ErrorPolicy,
+    ) -> Self {
+        log::trace!(
+            "instruction_with_error_policy(self, decoder: {:?}, processor: {:?}, error_policy: {:?})",
+            stringify!(decoder),
+            stringify!(processor),
+            error_policy
+        );
+        self.instruction_pipe(decoder, processor, Some(error_policy))
+    }
+
+    /// Adds an instruction pipe with `error_policy`, where `None` follows the
+    /// pipeline's dead letter sink as described in `InstructionPipe`.
+    fn instruction_pipe<T: Send + Sync + 'static>(
+        mut self,
+        decoder: impl for<'a> InstructionDecoder<'a, InstructionType = T> + Send + Sync + 'static,
+        processor: impl Processor<InputType = InstructionProcessorInputType<T>>
+            + SendProcessor
+            + SyncProcessor
+            + StaticProcessor,
+        error_policy: Option<ErrorPolicy>,
+    ) -> Self {
         self.instruction_pipes.push(Box::new(InstructionPipe {
-            decoder: Box::new(decoder),
+            decoder: Arc::new(decoder),
+            processor: Box::new(processor),
+            dead_letter_sink: None,
+            error_policy,
+            decoder_claims: self.decoder_claims.clone(),
+            max_depth: self.max_instruction_depth,
+            top_level_only: self.top_level_only,
+            decode_rates: Default::default(),
+        }));
+        self
+    }
+
+    /// Adds an event pipe to process Anchor events emitted through self-CPI.
+    ///
+    /// Event pipes inspect the inner instructions of every transaction and
+    /// decode those that follow the Anchor `emit_cpi!` pattern with the given
+    /// `EventDecoder`, passing the decoded events to the `Processor`.
+    ///
+    /// # Parameters
+    ///
+    /// - `decoder`: An `EventDecoder` that decodes event payloads.
+    /// - `processor`: A `Processor` that processes the decoded events.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .event(MyEventDecoder, MyEventProcessor);
+    /// ```
+    pub fn event<T: Send + Sync + 'static>(
+        mut self,
+        decoder: impl for<'a> EventDecoder<'a, EventType = T> + Send + Sync + 'static,
+        processor: impl Processor<InputType = EventProcessorInputType<T>>
+            + SendProcessor
+            + SyncProcessor
+            + StaticProcessor,
+    ) -> Self {
+        log::trace!(
+            "event(self, decoder: {:?}, processor: {:?})",
+            stringify!(decoder),
+            stringify!(processor)
+        );
+        self.instruction_pipes.push(Box::new(EventPipe {
+            decoder: Arc::new(decoder),
             processor: Box::new(processor),
+            max_depth: self.max_instruction_depth,
+            top_level_only: self.top_level_only,
         }));
         self
     }
@@ -836,9 +2088,9 @@ impl PipelineBuilder {
     pub fn transaction<T, U>(
         mut self,
         processor: impl Processor<InputType = TransactionProcessorInputType<T, U>>
-            + Send
-            + Sync
-            + 'static,
+            + SendProcessor
+            + SyncProcessor
+            + StaticProcessor,
         schema: Option<TransactionSchema<T>>,
     ) -> Self
     where
@@ -855,6 +2107,45 @@ impl PipelineBuilder {
         self
     }
 
+    /// Adds a transaction decoder pipe for recognizing whole transactions.
+    ///
+    /// Transaction decoder pipes look at all of a transaction's instructions at
+    /// once, enabling matching on composite patterns that span several
+    /// instructions or programs. They run after the instruction pipes for each
+    /// transaction.
+    ///
+    /// # Parameters
+    ///
+    /// - `decoder`: A `TransactionDecoder` that recognizes transactions.
+    /// - `processor`: A `Processor` that processes decoded transactions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .transaction_decoder(MyTransactionDecoder, MyTransactionProcessor);
+    /// ```
+    pub fn transaction_decoder<T: Send + Sync + 'static>(
+        mut self,
+        decoder: impl for<'a> TransactionDecoder<'a, TransactionType = T> + Send + Sync + 'static,
+        processor: impl Processor<InputType = TransactionDecoderProcessorInputType<T>>
+            + SendProcessor
+            + SyncProcessor
+            + StaticProcessor,
+    ) -> Self {
+        log::trace!(
+            "transaction_decoder(self, decoder: {:?}, processor: {:?})",
+            stringify!(decoder),
+            stringify!(processor)
+        );
+        self.transaction_pipes
+            .push(Box::new(TransactionDecoderPipe {
+                decoder: Arc::new(decoder),
+                processor: Box::new(processor),
+            }));
+        self
+    }
+
     /// Adds a metrics component to the pipeline for performance tracking.
     ///
     /// This component collects and reports on pipeline metrics, providing
@@ -922,11 +2213,64 @@ impl PipelineBuilder {
         self
     }
 
+    /// Sets the token used to shut down the pipeline on demand.
+    ///
+    /// Cancelling this token has the same effect as sending `SIGINT` to the
+    /// process: the datasources are cancelled and the pipeline stops according
+    /// to its [`ShutdownStrategy`]. This makes it possible to shut down on
+    /// other signals, such as `SIGTERM`, or from application code.
+    ///
+    /// # Parameters
+    ///
+    /// - `shutdown_token`: An instance of `CancellationToken`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let shutdown_token = CancellationToken::new();
+    /// let builder = PipelineBuilder::new()
+    ///     .shutdown_token(shutdown_token.clone());
+    ///
+    /// // Later, e.g. on SIGTERM:
+    /// shutdown_token.cancel();
+    /// ```
+    pub fn shutdown_token(mut self, shutdown_token: CancellationToken) -> Self {
+        log::trace!("shutdown_token(self, shutdown_token: {:?})", shutdown_token);
+        self.shutdown_token = Some(shutdown_token);
+        self
+    }
+
+    /// Sets the maximum time spent draining pending updates on shutdown.
+    ///
+    /// With `ShutdownStrategy::ProcessPending`, the pipeline keeps processing
+    /// the updates already received from the datasources after a shutdown was
+    /// requested. Once this timeout elapses, the update being processed is
+    /// aborted, the remaining updates are dropped and an error is logged. If
+    /// not set, the pipeline drains all pending updates without a time limit.
+    ///
+    /// # Parameters
+    ///
+    /// - `timeout`: The maximum duration of the drain phase.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .shutdown_drain_timeout(Duration::from_secs(30));
+    /// ```
+    pub fn shutdown_drain_timeout(mut self, timeout: Duration) -> Self {
+        log::trace!("shutdown_drain_timeout(self, timeout: {:?})", timeout);
+        self.shutdown_drain_timeout = Some(timeout);
+        self
+    }
+
     /// Sets the size of the channel buffer for the pipeline.
     ///
     /// This value defines the maximum number of updates that can be queued in
-    /// the pipeline's channel buffer. If not set, a default size of 10_000
-    /// will be used.
+    /// the pipeline's channel buffer. If not set,
+    /// `DEFAULT_CHANNEL_BUFFER_SIZE` is used. Once the buffer is full,
+    /// datasources are held back or their updates dropped according to the
+    /// `backpressure_policy`.
     ///
     /// # Parameters
     ///
@@ -944,51 +2288,2313 @@ impl PipelineBuilder {
         self
     }
 
-    /// Builds and returns a `Pipeline` configured with the specified
-    /// components.
+    /// Sets what happens to updates sent while the channel buffer is full.
     ///
-    /// After configuring the `PipelineBuilder` with data sources, pipes, and
-    /// metrics, call this method to create the final `Pipeline` instance
-    /// ready for operation.
+    /// With the default `BackpressurePolicy::Block`, datasources wait for the
+    /// pipes to catch up. With `BackpressurePolicy::DropNewest`, the updates
+    /// that do not fit are dropped and counted in the `updates_dropped`
+    /// metric, so that push datasources never stall on a slow pipeline.
     ///
-    /// # Returns
+    /// # Parameters
     ///
-    /// Returns a `CarbonResult<Pipeline>` containing the configured `Pipeline`,
-    /// or an error if any part of the configuration is invalid.
+    /// - `policy`: The [`BackpressurePolicy`] applied to every datasource.
     ///
     /// # Example
     ///
     /// ```rust
-    /// carbon_core::pipeline::Pipeline::builder()
-    /// .datasource(transaction_crawler)
-    /// .metrics(Arc::new(LogMetrics::new()))
-    /// .metrics(Arc::new(PrometheusMetrics::new()))
-    /// .instruction(
-    ///    TestProgramDecoder,
-    ///    TestProgramProcessor
-    /// )
-    /// .account(
-    ///     TestProgramDecoder,
-    ///     TestProgramAccountProcessor
-    /// )
-    /// .transaction(TEST_SCHEMA.clone(), TestProgramTransactionProcessor)
+    /// let builder = PipelineBuilder::new()
+    ///     .channel_buffer_size(1000)
+    ///     .backpressure_policy(BackpressurePolicy::DropNewest);
+    /// ```
+    pub fn backpressure_policy(mut self, policy: BackpressurePolicy) -> Self {
+        log::trace!("backpressure_policy(self, policy: {:?})", policy);
+        self.backpressure_policy = policy;
+        self
+    }
+
+    /// Spills the updates that do not fit in the channel to disk, and
+    /// replays them once the pipes have caught up.
+    ///
+    /// Once `high_watermark` updates wait in the channel, incoming updates are
+    /// written to segment files in the configured directory, and they are fed
+    /// back into the channel whenever fewer than `low_watermark` updates wait
+    /// in it. Updates keep the order they were received in, and datasources
+    /// never wait on the pipes, so the `BackpressurePolicy` no longer applies.
+    /// See the `spill` module for details.
+    ///
+    /// # Parameters
+    ///
+    /// - `spill_to_disk`: The [`SpillToDisk`] configuration.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .channel_buffer_size(1000)
+    ///     .spill_to_disk(SpillToDisk::new("/tmp/carbon-spill", 900, 100));
+    /// ```
+    pub fn spill_to_disk(mut self, spill_to_disk: SpillToDisk) -> Self {
+        log::trace!("spill_to_disk(self, spill_to_disk: {:?})", spill_to_disk);
+        self.spill_to_disk = Some(spill_to_disk);
+        self
+    }
+
+    /// Processes updates in non-decreasing slot order, for datasources that
+    /// may deliver slots out of order, such as merged or parallel streams.
+    ///
+    /// Updates are held back until the datasources deliver an update at least
+    /// `slots` slots newer, then released lowest slot first. An update older
+    /// than the last released slot arrives too late to be reordered: it is
+    /// dropped and counted in the `out_of_order_dropped` metric. A larger
+    /// window tolerates more disorder, but delays every update by about
+    /// `slots` slots, roughly 400ms each. Updates still held when the
+    /// datasources finish are released in order before the pipeline stops.
+    ///
+    /// A rollback releases the updates held before it, and lets the slots it
+    /// rolls back be delivered again.
+    ///
+    /// # Parameters
+    ///
+    /// - `slots`: The size of the reordering window, in slots. With 0, updates
+    ///   are not held back, but those older than an already processed slot are
+    ///   still dropped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .slot_reorder_window(4);
+    /// ```
+    pub fn slot_reorder_window(mut self, slots: u64) -> Self {
+        log::trace!("slot_reorder_window(self, slots: {:?})", slots);
+        self.slot_reorder_window = Some(slots);
+        self
+    }
+
+    /// Sets the checkpoint used to persist the pipeline's progress.
+    ///
+    /// On startup, the pipeline loads the last saved slot and passes it to
+    /// every datasource through `Datasource::resume_from_slot`. While running,
+    /// it saves each slot once all of its updates have been processed by all
+    /// pipes without error.
+    ///
+    /// # Parameters
+    ///
+    /// - `checkpoint`: The checkpoint implementation, such as `FileCheckpoint`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .checkpoint(FileCheckpoint::new("indexer.checkpoint"));
+    /// ```
+    pub fn checkpoint(mut self, checkpoint: impl Checkpoint + 'static) -> Self {
+        log::trace!("checkpoint(self, checkpoint: {:?})", stringify!(checkpoint));
+        self.checkpoint = Some(Arc::new(checkpoint));
+        self
+    }
+
+    /// Sets the number of slots between two checkpoint saves.
+    ///
+    /// Saving less often bounds the checkpoint I/O, at the cost of
+    /// reprocessing up to `slots` slots after a restart. If not set, a
+    /// checkpoint is saved after every slot.
+    ///
+    /// # Parameters
+    ///
+    /// - `slots`: The minimum number of slots between two saves.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .checkpoint(FileCheckpoint::new("indexer.checkpoint"))
+    ///     .checkpoint_interval(100);
+    /// ```
+    pub fn checkpoint_interval(mut self, slots: u64) -> Self {
+        log::trace!("checkpoint_interval(self, slots: {:?})", slots);
+        self.checkpoint_interval = slots;
+        self
+    }
+
+    /// Sets the minimum commitment level of the updates to process.
+    ///
+    /// Updates tagged with a lower commitment level by their datasource are
+    /// skipped before reaching any pipe, so that, for example, a pipeline set
+    /// to `Commitment::Finalized` never sees confirmed-only data. The setting
+    /// is ignored for updates whose datasource does not tag them with a
+    /// commitment level.
+    ///
+    /// # Parameters
+    ///
+    /// - `commitment`: The lowest commitment level to process.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .commitment(Commitment::Finalized);
+    /// ```
+    pub fn commitment(mut self, commitment: Commitment) -> Self {
+        log::trace!("commitment(self, commitment: {:?})", commitment);
+        self.commitment = Some(commitment);
+        self
+    }
+
+    /// Sets the maximum number of updates processed at the same time.
+    ///
+    /// By default, updates are processed one after the other. With a higher
+    /// limit, updates that modify different accounts are processed
+    /// concurrently, which helps pipelines whose processors spend most of
+    /// their time waiting on I/O.
+    ///
+    /// # Ordering Guarantees
+    ///
+    /// - Updates that may modify a common account are processed in the order
+    ///   they were received: the later one starts only after the earlier one
+    ///   has finished. A transaction may modify every account it marks as
+    ///   writable, including writable accounts loaded from lookup tables; an
+    ///   account update or deletion modifies its account.
+    /// - Updates that do not share such an account may run concurrently and
+    ///   finish in any order, even if they were received in a different order.
+    ///   Accounts a transaction only reads do not order it.
+    /// - Rollbacks are processed on their own, after all earlier updates have
+    ///   finished and before any later update starts.
+    /// - An update that has to wait also holds back the updates received after
+    ///   it, so an update never starts before an update received earlier.
+    /// - A pipe runs concurrent updates on separate instances when its
+    ///   processor implements `Processor::fork`, creating up to one instance
+    ///   per update in flight. Pipes whose processor cannot be forked handle
+    ///   one update at a time, so concurrent updates only overlap in decoding
+    ///   and across different pipes.
+    /// - Results are recorded in the order the updates were received, so
+    ///   checkpoints never move past an update that is still in flight.
+    ///
+    /// # Parameters
+    ///
+    /// - `max_concurrent_transactions`: The maximum number of updates in
+    ///   flight. Values below `1` are treated as `1`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .max_concurrent_transactions(16);
+    /// ```
+    pub fn max_concurrent_transactions(mut self, max_concurrent_transactions: usize) -> Self {
+        log::trace!(
+            "max_concurrent_transactions(self, max_concurrent_transactions: {:?})",
+            max_concurrent_transactions
+        );
+        self.max_concurrent_transactions = max_concurrent_transactions;
+        self
+    }
+
+    /// Makes the pipeline process updates in a reproducible order.
+    ///
+    /// Within an update, pipes always run in the order they were registered
+    /// (see `PipelineBuilder::instruction`). In deterministic mode, updates
+    /// are also processed one after the other in the order they were
+    /// received, overriding `max_concurrent_transactions`, so processors
+    /// observe the same sequence on every run. This is mostly useful in
+    /// tests comparing decoded output.
+    ///
+    /// # Parameters
+    ///
+    /// - `deterministic`: Whether updates are processed one after the other.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .max_concurrent_transactions(16)
+    ///     .deterministic(true);
+    /// ```
+    pub fn deterministic(mut self, deterministic: bool) -> Self {
+        log::trace!("deterministic(self, deterministic: {:?})", deterministic);
+        self.deterministic = deterministic;
+        self
+    }
+
+    /// Sets the sink for instructions that fail to decode or process.
+    ///
+    /// With a sink, an instruction pipe without an explicit error policy, or
+    /// with `ErrorPolicy::DeadLetter`, sends the instructions it fails on to
+    /// the sink and continues with the rest of the transaction, instead of
+    /// failing the whole update. Pipes added with `ErrorPolicy::Abort` or
+    /// `ErrorPolicy::Skip` keep that behavior and do not use the sink. The
+    /// sink applies to every instruction pipe, including those added after
+    /// this call.
+    ///
+    /// # Parameters
+    ///
+    /// - `dead_letter_sink`: The sink implementation, such as
+    ///   `FileDeadLetterSink`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .dead_letter_sink(FileDeadLetterSink::new("dead-letters.jsonl")?);
+    /// ```
+    pub fn dead_letter_sink(mut self, dead_letter_sink: impl DeadLetterSink + 'static) -> Self {
+        log::trace!(
+            "dead_letter_sink(self, dead_letter_sink: {:?})",
+            stringify!(dead_letter_sink)
+        );
+        self.dead_letter_sink = Some(Arc::new(dead_letter_sink));
+        self
+    }
+
+    /// Sets whether transactions that failed on-chain are skipped.
+    ///
+    /// A failed transaction has no effect on chain: the runtime reverts all of
+    /// its instructions, including the inner instructions that executed before
+    /// the failure. By default such transactions are therefore dropped before
+    /// reaching the instruction and transaction pipes, based on the `err`
+    /// field of their status meta. Disable this to index failed transactions
+    /// as well, for example to track failed swaps.
+    ///
+    /// # Parameters
+    ///
+    /// - `skip_failed_transactions`: Whether to skip failed transactions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .skip_failed_transactions(false);
+    /// ```
+    pub fn skip_failed_transactions(mut self, skip_failed_transactions: bool) -> Self {
+        log::trace!(
+            "skip_failed_transactions(self, skip_failed_transactions: {:?})",
+            skip_failed_transactions
+        );
+        self.skip_failed_transactions = skip_failed_transactions;
+        self
+    }
+
+    /// Adds a snapshot of accounts to process before the live updates, for
+    /// example all accounts of a program, so that processors start with
+    /// complete state.
+    ///
+    /// The snapshots are loaded in the order they were added, once the
+    /// datasources are started. Their accounts go through the account pipes
+    /// with `AccountMetadata::is_snapshot` set, and the updates the
+    /// datasources deliver in the meantime are processed afterwards. With
+    /// `BackpressurePolicy::DropNewest`, live updates that do not fit in the
+    /// channel while a snapshot is processed are dropped, so size
+    /// `channel_buffer_size` accordingly.
+    ///
+    /// # Parameters
+    ///
+    /// - `snapshot`: The snapshot to load, such as `FileAccountSnapshot`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .snapshot(FileAccountSnapshot::new("drift-users.snapshot"))
+    ///     .datasource(program_subscribe);
+    /// ```
+    pub fn snapshot(mut self, snapshot: impl AccountSnapshot + 'static) -> Self {
+        log::trace!("snapshot(self, snapshot: {:?})", stringify!(snapshot));
+        self.snapshots.push(Arc::new(snapshot));
+        self
+    }
+
+    /// Gives every transaction a `TransactionContext` shared by the processors
+    /// of its instructions, and sets the finalizer that receives it once the
+    /// instruction and transaction pipes have run.
+    ///
+    /// The processors find the context in
+    /// `InstructionMetadata::transaction_context`, which is `None` when no
+    /// finalizer is set.
+    ///
+    /// # Parameters
+    ///
+    /// - `finalizer`: The `TransactionContextFinalizer` called once per
+    ///   transaction.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .instruction(RaydiumAmmV4Decoder, SwapLegProcessor)
+    ///     .transaction_context(SwapCorrelator::new());
+    /// ```
+    pub fn transaction_context(
+        mut self,
+        finalizer: impl TransactionContextFinalizer + 'static,
+    ) -> Self {
+        log::trace!(
+            "transaction_context(self, finalizer: {:?})",
+            stringify!(finalizer)
+        );
+        self.transaction_context_finalizer = Some(Arc::new(finalizer));
+        self
+    }
+
+    /// Skips transactions whose signature was seen among the last `window`
+    /// transactions, for example when a backfill overlaps a live stream.
+    ///
+    /// Skipped transactions are counted in the `duplicate_skipped` metric.
+    /// Deduplication is best-effort: only the `window` most recently seen
+    /// signatures are remembered, in memory, so a duplicate arriving after
+    /// `window` other transactions, or after a restart, is processed again.
+    /// Processors that must not double count still need to be idempotent,
+    /// for example by upserting on the signature.
+    ///
+    /// # Parameters
+    ///
+    /// - `window`: The number of recent signatures remembered, raised to 1 if
+    ///   zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .dedupe_signatures(100_000);
+    /// ```
+    pub fn dedupe_signatures(mut self, window: usize) -> Self {
+        log::trace!("dedupe_signatures(self, window: {:?})", window);
+        self.signature_dedupe_window = Some(window.max(1));
+        self
+    }
+
+    /// Sets the deepest level of inner instructions visited by the
+    /// instruction pipes.
+    ///
+    /// Top-level instructions are at depth 1. Inner instructions nested deeper
+    /// than `max_instruction_depth` are left out of the instruction tree of
+    /// their transaction, so no pipe sees them, and counted in the
+    /// `instruction_depth_truncated` metric. Real transactions never nest more
+    /// than 5 levels, so the default of `DEFAULT_MAX_INSTRUCTION_DEPTH` only
+    /// guards against malformed trees.
+    ///
+    /// # Parameters
+    ///
+    /// - `max_instruction_depth`: The maximum nesting depth.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .max_instruction_depth(8);
+    /// ```
+    pub fn max_instruction_depth(mut self, max_instruction_depth: usize) -> Self {
+        log::trace!(
+            "max_instruction_depth(self, max_instruction_depth: {:?})",
+            max_instruction_depth
+        );
+        self.max_instruction_depth = max_instruction_depth;
+        self
+    }
+
+    /// Makes the instruction pipes process top-level instructions only.
+    ///
+    /// By default, the instruction pipes descend into the inner instructions
+    /// of every instruction, so that instructions invoked through CPI are
+    /// decoded and processed as well. When only the instructions submitted by
+    /// users matter, skipping that descent saves the work of decoding the inner
+    /// instructions. Processors still receive the inner instructions of each
+    /// top-level instruction. Event pipes, which find events among inner
+    /// instructions, and transaction pipes are not affected.
+    ///
+    /// # Parameters
+    ///
+    /// - `top_level_only`: Whether to skip inner instructions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .top_level_only(true);
+    /// ```
+    pub fn top_level_only(mut self, top_level_only: bool) -> Self {
+        log::trace!("top_level_only(self, top_level_only: {:?})", top_level_only);
+        self.top_level_only = top_level_only;
+        self
+    }
+
+    /// Routes account updates that report a closed account to the account
+    /// deletion pipes, for datasources that do not emit
+    /// `Update::AccountDeletion` themselves.
+    ///
+    /// An account update is treated as a close if the account has zero
+    /// lamports, or if its owner changed to the System program from one of
+    /// `tracked_owners`. Such updates are passed to the account deletion
+    /// pipes as an `AccountDeletion` instead of to the account pipes, and
+    /// counted in the `account_closes_detected` metric.
+    ///
+    /// Reassignments are only detected for accounts updated since the pipeline
+    /// started, among the `AccountCloseDetector::DEFAULT_CAPACITY` most
+    /// recently updated ones. To remember a different number of accounts, set
+    /// `account_close_detector` to a detector created with
+    /// `AccountCloseDetector::with_capacity` instead.
+    ///
+    /// # Parameters
+    ///
+    /// - `tracked_owners`: The programs whose accounts are reported as closed
+    ///   when reassigned to the System program.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let builder = PipelineBuilder::new()
+    ///     .detect_account_closes([DRIFT_PROGRAM_ID]);
+    /// ```
+    pub fn detect_account_closes(
+        mut self,
+        tracked_owners: impl IntoIterator<Item = Pubkey>,
+    ) -> Self {
+        log::trace!("detect_account_closes(self, tracked_owners)");
+        self.account_close_detector = Some(Arc::new(AccountCloseDetector::new(tracked_owners)));
+        self
+    }
+
+    /// Builds and returns a `Pipeline` configured with the specified
+    /// components.
+    ///
+    /// After configuring the `PipelineBuilder` with data sources, pipes, and
+    /// metrics, call this method to create the final `Pipeline` instance
+    /// ready for operation.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `CarbonResult<Pipeline>` containing the configured `Pipeline`,
+    /// or an error if any part of the configuration is invalid.
+    ///
+    /// # Errors
+    ///
+    /// - `CarbonError::MissingDatasource` if no datasource was added.
+    /// - `CarbonError::MissingPipe` if no account, account deletion,
+    ///   instruction or transaction pipe was added. Rollback pipes alone do not
+    ///   process any update.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// carbon_core::pipeline::Pipeline::builder()
+    /// .datasource(transaction_crawler)
+    /// .metrics(Arc::new(LogMetrics::new()))
+    /// .metrics(Arc::new(PrometheusMetrics::new()))
+    /// .instruction(
+    ///    TestProgramDecoder,
+    ///    TestProgramProcessor
+    /// )
+    /// .account(
+    ///     TestProgramDecoder,
+    ///     TestProgramAccountProcessor
+    /// )
+    /// .transaction(TEST_SCHEMA.clone(), TestProgramTransactionProcessor)
     /// .account_deletions(TestProgramAccountDeletionProcessor)
     /// .channel_buffer_size(1000)
     /// .build()?
     /// ```
     pub fn build(self) -> CarbonResult<Pipeline> {
         log::trace!("build(self)");
-        Ok(Pipeline {
-            datasources: self.datasources,
-            account_pipes: self.account_pipes,
-            account_deletion_pipes: self.account_deletion_pipes,
-            instruction_pipes: self.instruction_pipes,
-            transaction_pipes: self.transaction_pipes,
-            shutdown_strategy: self.shutdown_strategy,
+
+        if self.datasources.is_empty() {
+            return Err(CarbonError::MissingDatasource);
+        }
+
+        self.build_without_datasources()
+    }
+
+    /// Builds the pipeline without requiring a datasource, for running its
+    /// pipes over updates that do not come from one, as `TestPipeline` does.
+    pub(crate) fn build_without_datasources(mut self) -> CarbonResult<Pipeline> {
+        if self.account_pipes.is_empty()
+            && self.account_deletion_pipes.is_empty()
+            && self.instruction_pipes.is_empty()
+            && self.transaction_pipes.is_empty()
+        {
+            return Err(CarbonError::MissingPipe);
+        }
+
+        for pipe in self.instruction_pipes.iter_mut() {
+            if let Some(dead_letter_sink) = &self.dead_letter_sink {
+                pipe.set_dead_letter_sink(dead_letter_sink.clone());
+            }
+            pipe.set_max_depth(self.max_instruction_depth);
+            pipe.set_top_level_only(self.top_level_only);
+        }
+
+        Ok(Pipeline {
+            datasources: self.datasources,
+            account_pipes: self.account_pipes,
+            account_deletion_pipes: self.account_deletion_pipes,
+            instruction_pipes: self.instruction_pipes,
+            transaction_pipes: self.transaction_pipes,
+            rollback_pipes: self.rollback_pipes,
+            shutdown_strategy: self.shutdown_strategy,
             metrics: Arc::new(self.metrics),
             metrics_flush_interval: self.metrics_flush_interval,
             datasource_cancellation_token: self.datasource_cancellation_token,
-            channel_buffer_size: self.channel_buffer_size,
+            shutdown_token: self.shutdown_token,
+            shutdown_drain_timeout: self.shutdown_drain_timeout,
+            channel_buffer_size: self.channel_buffer_size.max(1),
+            backpressure_policy: self.backpressure_policy,
+            spill_to_disk: self.spill_to_disk,
+            slot_reorder_window: self.slot_reorder_window,
+            checkpoint: self.checkpoint,
+            checkpoint_interval: self.checkpoint_interval,
+            commitment: self.commitment,
+            max_concurrent_transactions: if self.deterministic {
+                1
+            } else {
+                self.max_concurrent_transactions.max(1)
+            },
+            deterministic: self.deterministic,
+            skip_failed_transactions: self.skip_failed_transactions,
+            max_instruction_depth: self.max_instruction_depth,
+            account_close_detector: self.account_close_detector,
+            signature_dedupe_window: self.signature_dedupe_window,
+            snapshots: self.snapshots,
+            transaction_context_finalizer: self.transaction_context_finalizer,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            account::DecodedAccount,
+            datasource::{AccountDeletion, AccountUpdate, TransactionUpdate, UpdateType},
+            error::Error,
+            instruction::{DecodedInstruction, InstructionMetadata, NestedInstructions},
+            processor::{BatchProcessor, BatchingProcessor},
+            transaction::{TransactionDecoder, TransactionDecoderProcessorInputType},
+        },
+        async_trait::async_trait,
+        solana_instruction::{AccountMeta, Instruction},
+        solana_pubkey::Pubkey,
+        solana_sdk::{
+            instruction::InstructionError,
+            message::{Message, VersionedMessage},
+            transaction::{TransactionError, VersionedTransaction},
+        },
+        solana_signature::Signature,
+        solana_transaction_status::TransactionStatusMeta,
+        std::{
+            collections::HashMap,
+            sync::{
+                atomic::{AtomicUsize, Ordering},
+                Mutex,
+            },
+        },
+    };
+
+    struct EndlessDeletionDatasource;
+
+    #[async_trait]
+    impl Datasource for EndlessDeletionDatasource {
+        async fn consume(
+            &self,
+            sender: &tokio::sync::mpsc::Sender<Update>,
+            cancellation_token: CancellationToken,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            for slot in 0.. {
+                let update = Update::AccountDeletion(AccountDeletion {
+                    pubkey: Pubkey::new_unique(),
+                    slot,
+                    commitment: None,
+                    source: None,
+                });
+
+                tokio::select! {
+                    _ = cancellation_token.cancelled() => break,
+                    result = sender.send(update) => {
+                        if result.is_err() {
+                            break;
+                        }
+                    }
+                }
+            }
+
+            Ok(())
+        }
+
+        fn update_types(&self) -> Vec<UpdateType> {
+            vec![UpdateType::AccountDeletion]
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct ProcessingCounts {
+        started: Arc<AtomicUsize>,
+        finished: Arc<AtomicUsize>,
+    }
+
+    struct SlowDeletionProcessor {
+        counts: ProcessingCounts,
+        duration: Duration,
+    }
+
+    #[async_trait]
+    impl Processor for SlowDeletionProcessor {
+        type InputType = AccountDeletion;
+
+        async fn process(
+            &mut self,
+            _data: Self::InputType,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            self.counts.started.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(self.duration).await;
+            self.counts.finished.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    fn build_pipeline(
+        counts: &ProcessingCounts,
+        duration: Duration,
+        shutdown_token: CancellationToken,
+    ) -> PipelineBuilder {
+        Pipeline::builder()
+            .datasource(EndlessDeletionDatasource)
+            .account_deletions(SlowDeletionProcessor {
+                counts: counts.clone(),
+                duration,
+            })
+            .channel_buffer_size(4)
+            .shutdown_token(shutdown_token)
+    }
+
+    async fn shutdown_once_started(counts: &ProcessingCounts, shutdown_token: CancellationToken) {
+        while counts.started.load(Ordering::SeqCst) == 0 {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        }
+        shutdown_token.cancel();
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_drains_in_flight_and_pending_updates() {
+        let counts = ProcessingCounts::default();
+        let shutdown_token = CancellationToken::new();
+        let mut pipeline =
+            build_pipeline(&counts, Duration::from_millis(20), shutdown_token.clone())
+                .build()
+                .unwrap();
+
+        let (result, _) = tokio::join!(
+            pipeline.run(),
+            shutdown_once_started(&counts, shutdown_token)
+        );
+
+        assert!(result.is_ok());
+        let started = counts.started.load(Ordering::SeqCst);
+        assert!(started >= 1);
+        assert_eq!(counts.finished.load(Ordering::SeqCst), started);
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_aborts_processing_after_drain_timeout() {
+        let counts = ProcessingCounts::default();
+        let shutdown_token = CancellationToken::new();
+        let mut pipeline = build_pipeline(&counts, Duration::from_secs(60), shutdown_token.clone())
+            .shutdown_drain_timeout(Duration::from_millis(50))
+            .build()
+            .unwrap();
+
+        let (result, _) = tokio::time::timeout(Duration::from_secs(5), async {
+            tokio::join!(
+                pipeline.run(),
+                shutdown_once_started(&counts, shutdown_token)
+            )
         })
+        .await
+        .expect("pipeline shuts down after the drain timeout");
+
+        assert!(result.is_ok());
+        assert_eq!(counts.started.load(Ordering::SeqCst), 1);
+        assert_eq!(counts.finished.load(Ordering::SeqCst), 0);
+    }
+
+    #[derive(Default)]
+    struct QueueMetrics {
+        max_queued: Mutex<f64>,
+        dropped: AtomicUsize,
+        out_of_order_dropped: AtomicUsize,
+        spilled: AtomicUsize,
+        slot_gaps: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl Metrics for QueueMetrics {
+        async fn initialize(&self) -> CarbonResult<()> {
+            Ok(())
+        }
+
+        async fn flush(&self) -> CarbonResult<()> {
+            Ok(())
+        }
+
+        async fn shutdown(&self) -> CarbonResult<()> {
+            Ok(())
+        }
+
+        async fn update_gauge(&self, name: &str, value: f64) -> CarbonResult<()> {
+            if name == "updates_queued" {
+                let mut max_queued = self.max_queued.lock().unwrap();
+                *max_queued = max_queued.max(value);
+            }
+            Ok(())
+        }
+
+        async fn increment_counter(&self, name: &str, value: u64) -> CarbonResult<()> {
+            match name {
+                "updates_dropped" => self.dropped.fetch_add(value as usize, Ordering::SeqCst),
+                "updates_spilled" => self.spilled.fetch_add(value as usize, Ordering::SeqCst),
+                "slot_gap_total" => self.slot_gaps.fetch_add(value as usize, Ordering::SeqCst),
+                "out_of_order_dropped" => self
+                    .out_of_order_dropped
+                    .fetch_add(value as usize, Ordering::SeqCst),
+                _ => 0,
+            };
+            Ok(())
+        }
+
+        async fn record_histogram(&self, _name: &str, _value: f64) -> CarbonResult<()> {
+            Ok(())
+        }
+    }
+
+    async fn run_slow_pipeline(
+        policy: BackpressurePolicy,
+        updates: u64,
+    ) -> (ProcessingCounts, Arc<QueueMetrics>) {
+        let counts = ProcessingCounts::default();
+        let metrics = Arc::new(QueueMetrics::default());
+        let slots: Vec<u64> = (0..updates).collect();
+        let mut pipeline = Pipeline::builder()
+            .datasource(UpdatesDatasource {
+                updates: deletions(&slots),
+                resumed_from: Arc::default(),
+            })
+            .account_deletions(SlowDeletionProcessor {
+                counts: counts.clone(),
+                duration: Duration::from_millis(5),
+            })
+            .metrics(metrics.clone())
+            .channel_buffer_size(4)
+            .backpressure_policy(policy)
+            .build()
+            .unwrap();
+
+        pipeline.run().await.unwrap();
+
+        (counts, metrics)
+    }
+
+    #[tokio::test]
+    async fn test_block_policy_queues_updates_without_losing_any() {
+        // Arrange
+        let updates = 20;
+
+        // Act
+        let (counts, metrics) = run_slow_pipeline(BackpressurePolicy::Block, updates).await;
+
+        // Assert
+        let max_queued = *metrics.max_queued.lock().unwrap();
+        assert!(max_queued > 0.0 && max_queued <= 4.0, "{max_queued}");
+        assert_eq!(counts.finished.load(Ordering::SeqCst), updates as usize);
+        assert_eq!(metrics.dropped.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn test_drop_newest_policy_counts_dropped_updates() {
+        // Arrange
+        let updates = 20;
+
+        // Act
+        let (counts, metrics) = run_slow_pipeline(BackpressurePolicy::DropNewest, updates).await;
+
+        // Assert
+        let processed = counts.finished.load(Ordering::SeqCst);
+        let dropped = metrics.dropped.load(Ordering::SeqCst);
+        assert!(dropped > 0);
+        assert_eq!(processed + dropped, updates as usize);
+    }
+
+    struct SlowRecordingDeletionProcessor {
+        slots: Arc<Mutex<Vec<u64>>>,
+    }
+
+    #[async_trait]
+    impl Processor for SlowRecordingDeletionProcessor {
+        type InputType = AccountDeletion;
+
+        async fn process(
+            &mut self,
+            data: Self::InputType,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            tokio::time::sleep(Duration::from_millis(2)).await;
+            self.slots.lock().unwrap().push(data.slot);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_spill_to_disk_processes_every_update_in_order() {
+        // Arrange
+        let directory =
+            std::env::temp_dir().join(format!("carbon-spill-pipeline-{}", std::process::id()));
+        let slots: Vec<u64> = (0..40).collect();
+        let processed_slots = Arc::new(Mutex::new(Vec::new()));
+        let metrics = Arc::new(QueueMetrics::default());
+        let mut pipeline = Pipeline::builder()
+            .datasource(UpdatesDatasource {
+                updates: deletions(&slots),
+                resumed_from: Arc::default(),
+            })
+            .account_deletions(SlowRecordingDeletionProcessor {
+                slots: processed_slots.clone(),
+            })
+            .metrics(metrics.clone())
+            .channel_buffer_size(4)
+            .spill_to_disk(SpillToDisk::new(&directory, 4, 2))
+            .build()
+            .unwrap();
+
+        // Act
+        pipeline.run().await.unwrap();
+
+        // Assert
+        assert!(metrics.spilled.load(Ordering::SeqCst) > 0);
+        assert_eq!(metrics.dropped.load(Ordering::SeqCst), 0);
+        assert_eq!(*processed_slots.lock().unwrap(), slots);
+        assert_eq!(std::fs::read_dir(&directory).unwrap().count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_slot_gaps_are_counted() {
+        // Arrange
+        let metrics = Arc::new(QueueMetrics::default());
+        let mut pipeline = Pipeline::builder()
+            .datasource(UpdatesDatasource {
+                updates: deletions(&[10, 11, 15]),
+                resumed_from: Arc::default(),
+            })
+            .account_deletions(RecordingDeletionProcessor {
+                slots: Arc::default(),
+            })
+            .metrics(metrics.clone())
+            .build()
+            .unwrap();
+
+        // Act
+        pipeline.run().await.unwrap();
+
+        // Assert
+        assert_eq!(metrics.slot_gaps.load(Ordering::SeqCst), 3);
+    }
+
+    async fn run_reordering_pipeline(window: u64, slots: &[u64]) -> (Vec<u64>, Arc<QueueMetrics>) {
+        let processed_slots = Arc::new(Mutex::new(Vec::new()));
+        let metrics = Arc::new(QueueMetrics::default());
+        let mut pipeline = Pipeline::builder()
+            .datasource(UpdatesDatasource {
+                updates: deletions(slots),
+                resumed_from: Arc::default(),
+            })
+            .account_deletions(RecordingDeletionProcessor {
+                slots: processed_slots.clone(),
+            })
+            .metrics(metrics.clone())
+            .slot_reorder_window(window)
+            .build()
+            .unwrap();
+
+        pipeline.run().await.unwrap();
+
+        let processed_slots = processed_slots.lock().unwrap().clone();
+        (processed_slots, metrics)
+    }
+
+    #[tokio::test]
+    async fn test_reorder_window_delivers_updates_in_slot_order() {
+        // Act
+        let (processed_slots, metrics) = run_reordering_pipeline(4, &[3, 1, 2]).await;
+
+        // Assert
+        assert_eq!(processed_slots, vec![1, 2, 3]);
+        assert_eq!(metrics.out_of_order_dropped.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn test_reorder_window_drops_updates_older_than_released_slot() {
+        // Act
+        let (processed_slots, metrics) = run_reordering_pipeline(1, &[5, 7, 6, 8, 4]).await;
+
+        // Assert
+        assert_eq!(processed_slots, vec![5, 6, 7, 8]);
+        assert_eq!(metrics.out_of_order_dropped.load(Ordering::SeqCst), 1);
+    }
+
+    #[derive(Clone, Default)]
+    struct MemoryCheckpoint {
+        loaded_slot: Option<u64>,
+        saved_slots: Arc<Mutex<Vec<u64>>>,
+    }
+
+    impl Checkpoint for MemoryCheckpoint {
+        fn save(&self, slot: u64) -> CarbonResult<()> {
+            self.saved_slots.lock().unwrap().push(slot);
+            Ok(())
+        }
+
+        fn load(&self) -> Option<u64> {
+            self.loaded_slot
+        }
+    }
+
+    struct UpdatesDatasource {
+        updates: Vec<Update>,
+        resumed_from: Arc<Mutex<Option<u64>>>,
+    }
+
+    #[async_trait]
+    impl Datasource for UpdatesDatasource {
+        async fn consume(
+            &self,
+            sender: &tokio::sync::mpsc::Sender<Update>,
+            _cancellation_token: CancellationToken,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            for update in &self.updates {
+                if sender.send(update.clone()).await.is_err() {
+                    break;
+                }
+            }
+
+            Ok(())
+        }
+
+        fn update_types(&self) -> Vec<UpdateType> {
+            vec![UpdateType::AccountDeletion]
+        }
+
+        fn resume_from_slot(&self, slot: u64) {
+            *self.resumed_from.lock().unwrap() = Some(slot);
+        }
+    }
+
+    fn deletion(slot: u64) -> Update {
+        Update::AccountDeletion(AccountDeletion {
+            pubkey: Pubkey::new_unique(),
+            slot,
+            commitment: None,
+            source: None,
+        })
+    }
+
+    fn deletions(slots: &[u64]) -> Vec<Update> {
+        slots.iter().copied().map(deletion).collect()
+    }
+
+    struct FailingSlotProcessor {
+        failing_slot: Option<u64>,
+        processed: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl Processor for FailingSlotProcessor {
+        type InputType = AccountDeletion;
+
+        async fn process(
+            &mut self,
+            data: Self::InputType,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            self.processed.fetch_add(1, Ordering::SeqCst);
+            if Some(data.slot) == self.failing_slot {
+                return Err(Error::Custom(format!("failed slot {}", data.slot)));
+            }
+            Ok(())
+        }
+    }
+
+    struct RecordingRollbackProcessor {
+        rollbacks: Arc<Mutex<Vec<u64>>>,
+        processed: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl Processor for RecordingRollbackProcessor {
+        type InputType = Rollback;
+
+        async fn process(
+            &mut self,
+            data: Self::InputType,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            self.rollbacks.lock().unwrap().push(data.from_slot);
+            self.processed.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    struct RunOutcome {
+        resumed_from: Option<u64>,
+        rollbacks: Vec<u64>,
+    }
+
+    async fn run_checkpointed(
+        checkpoint: MemoryCheckpoint,
+        checkpoint_interval: u64,
+        updates: Vec<Update>,
+        failing_slot: Option<u64>,
+    ) -> RunOutcome {
+        let resumed_from = Arc::new(Mutex::new(None));
+        let rollbacks = Arc::new(Mutex::new(Vec::new()));
+        let processed = Arc::new(AtomicUsize::new(0));
+        let shutdown_token = CancellationToken::new();
+        let num_updates = updates.len();
+
+        let mut pipeline = Pipeline::builder()
+            .datasource(UpdatesDatasource {
+                updates,
+                resumed_from: resumed_from.clone(),
+            })
+            .account_deletions(FailingSlotProcessor {
+                failing_slot,
+                processed: processed.clone(),
+            })
+            .rollbacks(RecordingRollbackProcessor {
+                rollbacks: rollbacks.clone(),
+                processed: processed.clone(),
+            })
+            .checkpoint(checkpoint)
+            .checkpoint_interval(checkpoint_interval)
+            .shutdown_token(shutdown_token.clone())
+            .build()
+            .unwrap();
+
+        let (result, _) = tokio::join!(pipeline.run(), async {
+            while processed.load(Ordering::SeqCst) < num_updates {
+                tokio::time::sleep(Duration::from_millis(1)).await;
+            }
+            shutdown_token.cancel();
+        });
+        assert!(result.is_ok());
+
+        let resumed_from = *resumed_from.lock().unwrap();
+        let rollbacks = rollbacks.lock().unwrap().clone();
+        RunOutcome {
+            resumed_from,
+            rollbacks,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_checkpoint_resumes_and_saves_completed_slots() {
+        let checkpoint = MemoryCheckpoint {
+            loaded_slot: Some(10),
+            ..Default::default()
+        };
+
+        let outcome = run_checkpointed(
+            checkpoint.clone(),
+            2,
+            deletions(&[11, 11, 12, 13, 14, 15]),
+            None,
+        )
+        .await;
+
+        assert_eq!(outcome.resumed_from, Some(10));
+        // Slot 15 is still in progress at shutdown and is not saved.
+        assert_eq!(*checkpoint.saved_slots.lock().unwrap(), vec![12, 14]);
+    }
+
+    #[tokio::test]
+    async fn test_checkpoint_stops_advancing_after_failed_update() {
+        let checkpoint = MemoryCheckpoint::default();
+
+        let outcome = run_checkpointed(
+            checkpoint.clone(),
+            1,
+            deletions(&[11, 12, 13, 14, 15]),
+            Some(13),
+        )
+        .await;
+
+        assert_eq!(outcome.resumed_from, None);
+        assert_eq!(*checkpoint.saved_slots.lock().unwrap(), vec![11, 12]);
+    }
+
+    #[tokio::test]
+    async fn test_rollback_reaches_processor_and_rewinds_checkpoint() {
+        let checkpoint = MemoryCheckpoint::default();
+        let mut updates = deletions(&[11, 12, 13]);
+        updates.push(Update::Rollback(Rollback { from_slot: 12 }));
+        updates.extend(deletions(&[12, 13, 14]));
+
+        let outcome = run_checkpointed(checkpoint.clone(), 1, updates, None).await;
+
+        assert_eq!(outcome.rollbacks, vec![12]);
+        // Slot 12 was saved before the rollback, so the checkpoint moves back
+        // to slot 11 before the new fork is saved.
+        assert_eq!(
+            *checkpoint.saved_slots.lock().unwrap(),
+            vec![11, 12, 11, 12, 13]
+        );
+    }
+
+    struct RecordingDeletionProcessor {
+        slots: Arc<Mutex<Vec<u64>>>,
+    }
+
+    #[async_trait]
+    impl Processor for RecordingDeletionProcessor {
+        type InputType = AccountDeletion;
+
+        async fn process(
+            &mut self,
+            data: Self::InputType,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            self.slots.lock().unwrap().push(data.slot);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_commitment_filters_updates_below_configured_level() {
+        let updates = [
+            (1, Some(Commitment::Processed)),
+            (2, Some(Commitment::Confirmed)),
+            (3, Some(Commitment::Finalized)),
+            (4, None),
+            (5, Some(Commitment::Processed)),
+            (6, Some(Commitment::Finalized)),
+        ]
+        .into_iter()
+        .map(|(slot, commitment)| {
+            Update::AccountDeletion(AccountDeletion {
+                pubkey: Pubkey::new_unique(),
+                slot,
+                commitment,
+                source: None,
+            })
+        })
+        .collect();
+        let slots = Arc::new(Mutex::new(Vec::new()));
+        let shutdown_token = CancellationToken::new();
+
+        let mut pipeline = Pipeline::builder()
+            .datasource(UpdatesDatasource {
+                updates,
+                resumed_from: Arc::new(Mutex::new(None)),
+            })
+            .account_deletions(RecordingDeletionProcessor {
+                slots: slots.clone(),
+            })
+            .commitment(Commitment::Confirmed)
+            .shutdown_token(shutdown_token.clone())
+            .build()
+            .unwrap();
+
+        let (result, _) = tokio::join!(pipeline.run(), async {
+            while slots.lock().unwrap().last() != Some(&6) {
+                tokio::time::sleep(Duration::from_millis(1)).await;
+            }
+            shutdown_token.cancel();
+        });
+
+        assert!(result.is_ok());
+        assert_eq!(*slots.lock().unwrap(), vec![2, 3, 4, 6]);
+    }
+
+    struct RecordingClosedAccountsProcessor {
+        closed: Arc<Mutex<Vec<(Pubkey, u64)>>>,
+    }
+
+    #[async_trait]
+    impl Processor for RecordingClosedAccountsProcessor {
+        type InputType = AccountDeletion;
+
+        async fn process(
+            &mut self,
+            data: Self::InputType,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            self.closed.lock().unwrap().push((data.pubkey, data.slot));
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_account_closes_reach_deletion_processor() {
+        // Arrange
+        let program = Pubkey::new_unique();
+        let [reassigned, drained, system_owned] = [(); 3].map(|()| Pubkey::new_unique());
+        let account_update = |pubkey, slot, lamports, owner| {
+            Update::Account(AccountUpdate {
+                pubkey,
+                account: solana_account::Account {
+                    lamports,
+                    data: vec![],
+                    owner,
+                    executable: false,
+                    rent_epoch: 0,
+                },
+                slot,
+                commitment: None,
+                is_snapshot: false,
+                source: None,
+            })
+        };
+        let system_program = solana_program::system_program::ID;
+        let updates = vec![
+            account_update(reassigned, 1, 1_000, program),
+            account_update(system_owned, 2, 1_000, system_program),
+            account_update(reassigned, 3, 1_000, system_program),
+            account_update(drained, 4, 0, program),
+        ];
+        let closed = Arc::new(Mutex::new(Vec::new()));
+        let shutdown_token = CancellationToken::new();
+
+        let mut pipeline = Pipeline::builder()
+            .datasource(UpdatesDatasource {
+                updates,
+                resumed_from: Arc::new(Mutex::new(None)),
+            })
+            .account_deletions(RecordingClosedAccountsProcessor {
+                closed: closed.clone(),
+            })
+            .detect_account_closes([program])
+            .shutdown_token(shutdown_token.clone())
+            .build()
+            .unwrap();
+
+        // Act
+        let (result, _) = tokio::join!(pipeline.run(), async {
+            while closed.lock().unwrap().len() < 2 {
+                tokio::time::sleep(Duration::from_millis(1)).await;
+            }
+            shutdown_token.cancel();
+        });
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(*closed.lock().unwrap(), vec![(reassigned, 3), (drained, 4)]);
+    }
+
+    struct MemorySnapshot(Vec<AccountUpdate>);
+
+    #[async_trait]
+    impl AccountSnapshot for MemorySnapshot {
+        async fn load(&self) -> CarbonResult<Vec<AccountUpdate>> {
+            Ok(self.0.clone())
+        }
+    }
+
+    struct RawAccountDecoder;
+
+    impl AccountDecoder<'_> for RawAccountDecoder {
+        type AccountType = Vec<u8>;
+
+        fn decode_account(
+            &self,
+            account: &solana_account::Account,
+        ) -> Option<DecodedAccount<Self::AccountType>> {
+            Some(DecodedAccount {
+                lamports: account.lamports,
+                data: account.data.clone(),
+                owner: account.owner,
+                executable: account.executable,
+                rent_epoch: account.rent_epoch,
+            })
+        }
+    }
+
+    struct RecordingAccountProcessor {
+        accounts: Arc<Mutex<Vec<(Pubkey, u64, bool)>>>,
+    }
+
+    #[async_trait]
+    impl Processor for RecordingAccountProcessor {
+        type InputType = AccountProcessorInputType<Vec<u8>>;
+
+        async fn process(
+            &mut self,
+            (metadata, _account): Self::InputType,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            self.accounts.lock().unwrap().push((
+                metadata.pubkey,
+                metadata.slot,
+                metadata.is_snapshot,
+            ));
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_accounts_are_processed_before_live_updates() {
+        // Arrange
+        let [user, other_user] = [(); 2].map(|()| Pubkey::new_unique());
+        let account_update = |pubkey, slot, data| AccountUpdate {
+            pubkey,
+            account: solana_account::Account {
+                lamports: 1_000,
+                data,
+                owner: Pubkey::new_unique(),
+                executable: false,
+                rent_epoch: 0,
+            },
+            slot,
+            commitment: None,
+            is_snapshot: false,
+            source: None,
+        };
+        let accounts = Arc::new(Mutex::new(Vec::new()));
+        let shutdown_token = CancellationToken::new();
+
+        let mut pipeline = Pipeline::builder()
+            .snapshot(MemorySnapshot(vec![
+                account_update(user, 5, vec![1]),
+                account_update(other_user, 5, vec![1]),
+            ]))
+            .datasource(UpdatesDatasource {
+                updates: vec![Update::Account(account_update(user, 10, vec![2]))],
+                resumed_from: Arc::new(Mutex::new(None)),
+            })
+            .account(
+                RawAccountDecoder,
+                RecordingAccountProcessor {
+                    accounts: accounts.clone(),
+                },
+            )
+            .shutdown_token(shutdown_token.clone())
+            .build()
+            .unwrap();
+
+        // Act
+        let (result, _) = tokio::join!(pipeline.run(), async {
+            while accounts.lock().unwrap().len() < 3 {
+                tokio::time::sleep(Duration::from_millis(1)).await;
+            }
+            shutdown_token.cancel();
+        });
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(
+            *accounts.lock().unwrap(),
+            vec![(user, 5, true), (other_user, 5, true), (user, 10, false),]
+        );
+    }
+
+    struct RecordingDeletionBatchProcessor {
+        batches: Arc<Mutex<Vec<Vec<u64>>>>,
+    }
+
+    #[async_trait]
+    impl BatchProcessor for RecordingDeletionBatchProcessor {
+        type InputType = AccountDeletion;
+
+        async fn process_batch(
+            &self,
+            items: Vec<Self::InputType>,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            self.batches
+                .lock()
+                .unwrap()
+                .push(items.iter().map(|deletion| deletion.slot).collect());
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_flushes_batching_processors() {
+        let batches = Arc::new(Mutex::new(Vec::new()));
+        let shutdown_token = CancellationToken::new();
+
+        let mut pipeline = Pipeline::builder()
+            .datasource(UpdatesDatasource {
+                updates: deletions(&[1, 2, 3, 4, 5]),
+                resumed_from: Arc::new(Mutex::new(None)),
+            })
+            .account_deletions(
+                BatchingProcessor::new(RecordingDeletionBatchProcessor {
+                    batches: batches.clone(),
+                })
+                .batch_size(2)
+                .flush_interval(Duration::from_secs(3600)),
+            )
+            .shutdown_token(shutdown_token.clone())
+            .build()
+            .unwrap();
+
+        shutdown_token.cancel();
+        let result = pipeline.run().await;
+
+        assert!(result.is_ok());
+        assert_eq!(
+            *batches.lock().unwrap(),
+            vec![vec![1, 2], vec![3, 4], vec![5]]
+        );
+    }
+
+    /// Decodes every transaction into its slot, which the tests below use to
+    /// identify transactions.
+    struct SlotDecoder;
+
+    impl TransactionDecoder<'_> for SlotDecoder {
+        type TransactionType = u64;
+
+        fn decode_transaction(
+            &self,
+            transaction_metadata: &TransactionMetadata,
+            _instructions: &NestedInstructions,
+        ) -> Option<Self::TransactionType> {
+            Some(transaction_metadata.slot)
+        }
+    }
+
+    /// Records when each transaction starts and finishes processing. The
+    /// transaction in `slow_slot` takes a while, so that later transactions
+    /// can overtake it.
+    #[derive(Clone)]
+    struct RecordingTransactionProcessor {
+        slow_slot: u64,
+        events: Arc<Mutex<Vec<(u64, &'static str)>>>,
+    }
+
+    #[async_trait]
+    impl Processor for RecordingTransactionProcessor {
+        type InputType = TransactionDecoderProcessorInputType<u64>;
+
+        async fn process(
+            &mut self,
+            (_, slot): Self::InputType,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            self.events.lock().unwrap().push((slot, "start"));
+            if slot == self.slow_slot {
+                tokio::time::sleep(Duration::from_millis(100)).await;
+            }
+            self.events.lock().unwrap().push((slot, "end"));
+            Ok(())
+        }
+
+        fn fork(&self) -> Option<Box<dyn Processor<InputType = Self::InputType> + Send + Sync>> {
+            Some(Box::new(self.clone()))
+        }
+    }
+
+    /// A transaction in `slot`, paid for by its own fee payer, that writes to
+    /// `account`.
+    fn transaction(slot: u64, account: Pubkey) -> Update {
+        let instruction = Instruction {
+            program_id: Pubkey::new_unique(),
+            accounts: vec![AccountMeta::new(account, false)],
+            data: vec![],
+        };
+        transaction_with_instructions(slot, &[instruction])
+    }
+
+    /// A transaction in `slot`, paid for by its own fee payer, that executes
+    /// `instructions`.
+    fn transaction_with_instructions(slot: u64, instructions: &[Instruction]) -> Update {
+        let message = Message::new(instructions, Some(&Pubkey::new_unique()));
+
+        Update::Transaction(Box::new(TransactionUpdate {
+            signature: Signature::default(),
+            transaction: VersionedTransaction {
+                signatures: vec![Signature::default()],
+                message: VersionedMessage::Legacy(message),
+            },
+            meta: TransactionStatusMeta::default(),
+            is_vote: false,
+            slot,
+            block_time: None,
+            commitment: None,
+            source: None,
+        }))
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_transactions_keep_per_account_order() {
+        let first_account = Pubkey::new_unique();
+        let second_account = Pubkey::new_unique();
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let shutdown_token = CancellationToken::new();
+
+        let mut pipeline = Pipeline::builder()
+            .datasource(UpdatesDatasource {
+                updates: vec![
+                    transaction(1, first_account),
+                    transaction(2, second_account),
+                    transaction(3, first_account),
+                ],
+                resumed_from: Arc::new(Mutex::new(None)),
+            })
+            .transaction_decoder(
+                SlotDecoder,
+                RecordingTransactionProcessor {
+                    slow_slot: 1,
+                    events: events.clone(),
+                },
+            )
+            .max_concurrent_transactions(4)
+            .shutdown_token(shutdown_token.clone())
+            .build()
+            .unwrap();
+
+        let finished = |events: &[(u64, &str)]| events.iter().filter(|(_, e)| *e == "end").count();
+        let (result, _) = tokio::join!(pipeline.run(), async {
+            while finished(&events.lock().unwrap()) < 3 {
+                tokio::time::sleep(Duration::from_millis(1)).await;
+            }
+            shutdown_token.cancel();
+        });
+        assert!(result.is_ok());
+
+        let events = events.lock().unwrap().clone();
+        let position = |event: (u64, &str)| events.iter().position(|e| *e == event).unwrap();
+
+        // The transaction on the other account overtakes the slow one...
+        assert!(position((2, "end")) < position((1, "end")));
+        // ...while the transaction on the same account waits for it.
+        assert!(position((1, "end")) < position((3, "start")));
+    }
+
+    /// Decodes every instruction of `program_id` into its first data byte.
+    struct FirstByteDecoder {
+        program_id: Pubkey,
+    }
+
+    impl InstructionDecoder<'_> for FirstByteDecoder {
+        type InstructionType = u8;
+
+        fn try_decode_instruction(
+            &self,
+            instruction: &Instruction,
+        ) -> CarbonResult<Option<DecodedInstruction<Self::InstructionType>>> {
+            Ok(
+                (instruction.program_id == self.program_id).then(|| DecodedInstruction {
+                    program_id: instruction.program_id,
+                    data: instruction.data[0],
+                    accounts: instruction.accounts.clone(),
+                    discriminator: vec![],
+                    raw_len: instruction.data.len(),
+                    num_accounts: instruction.accounts.len(),
+                    raw_data: instruction.data.clone(),
+                }),
+            )
+        }
+    }
+
+    struct FailingInstructionProcessor;
+
+    #[async_trait]
+    impl Processor for FailingInstructionProcessor {
+        type InputType = InstructionProcessorInputType<u8>;
+
+        async fn process(
+            &mut self,
+            (_, instruction, _): Self::InputType,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            Err(Error::Custom(format!("instruction {}", instruction.data)))
+        }
+    }
+
+    /// The slot, data and error of a dead-lettered instruction.
+    type DeadLetterRecord = (u64, Vec<u8>, String);
+
+    #[derive(Clone, Default)]
+    struct MemoryDeadLetterSink {
+        records: Arc<Mutex<Vec<DeadLetterRecord>>>,
+    }
+
+    #[async_trait]
+    impl DeadLetterSink for MemoryDeadLetterSink {
+        async fn send(
+            &self,
+            metadata: &InstructionMetadata,
+            instruction: &Instruction,
+            error: &Error,
+        ) -> CarbonResult<()> {
+            self.records.lock().unwrap().push((
+                metadata.slot(),
+                instruction.data.clone(),
+                error.to_string(),
+            ));
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_failed_instructions_are_dead_lettered() {
+        let program_id = Pubkey::new_unique();
+        let instruction = |data: u8| Instruction {
+            program_id,
+            accounts: vec![],
+            data: vec![data],
+        };
+        let sink = MemoryDeadLetterSink::default();
+        let shutdown_token = CancellationToken::new();
+
+        let mut pipeline = Pipeline::builder()
+            .datasource(UpdatesDatasource {
+                updates: vec![
+                    transaction_with_instructions(1, &[instruction(1), instruction(2)]),
+                    transaction_with_instructions(2, &[instruction(3)]),
+                ],
+                resumed_from: Arc::new(Mutex::new(None)),
+            })
+            .instruction(FirstByteDecoder { program_id }, FailingInstructionProcessor)
+            .dead_letter_sink(sink.clone())
+            .shutdown_token(shutdown_token.clone())
+            .build()
+            .unwrap();
+
+        let (result, _) = tokio::join!(pipeline.run(), async {
+            while sink.records.lock().unwrap().len() < 3 {
+                tokio::time::sleep(Duration::from_millis(1)).await;
+            }
+            shutdown_token.cancel();
+        });
+        assert!(result.is_ok());
+
+        let record = |slot: u64, data: u8| {
+            (
+                slot,
+                vec![data],
+                format!(
+                    "Processor for FirstByteDecoder failed on instruction of program {}: Custom \
+                     error: instruction {}",
+                    program_id, data
+                ),
+            )
+        };
+        assert_eq!(
+            *sink.records.lock().unwrap(),
+            vec![record(1, 1), record(1, 2), record(2, 3)]
+        );
+    }
+
+    struct RecordingInstructionProcessor {
+        data: Arc<Mutex<Vec<u8>>>,
+    }
+
+    #[async_trait]
+    impl Processor for RecordingInstructionProcessor {
+        type InputType = InstructionProcessorInputType<u8>;
+
+        async fn process(
+            &mut self,
+            (_, instruction, _): Self::InputType,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            self.data.lock().unwrap().push(instruction.data);
+            Ok(())
+        }
+    }
+
+    /// Records the data of the instructions it processes, failing on those
+    /// whose data is 1.
+    struct FailingOnOneProcessor {
+        data: Arc<Mutex<Vec<u8>>>,
+    }
+
+    #[async_trait]
+    impl Processor for FailingOnOneProcessor {
+        type InputType = InstructionProcessorInputType<u8>;
+
+        async fn process(
+            &mut self,
+            (_, instruction, _): Self::InputType,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            if instruction.data == 1 {
+                return Err(Error::Custom("instruction 1".to_string()));
+            }
+            self.data.lock().unwrap().push(instruction.data);
+            Ok(())
+        }
+    }
+
+    /// Runs a pipe with `error_policy` over a transaction with instructions 1
+    /// and 2, then one with instruction 3, and returns the data processed and
+    /// dead-lettered.
+    async fn run_with_error_policy(error_policy: ErrorPolicy) -> (Vec<u8>, Vec<Vec<u8>>) {
+        let program_id = Pubkey::new_unique();
+        let instruction = |data: u8| Instruction {
+            program_id,
+            accounts: vec![],
+            data: vec![data],
+        };
+        let data = Arc::new(Mutex::new(Vec::new()));
+        let sink = MemoryDeadLetterSink::default();
+        let shutdown_token = CancellationToken::new();
+
+        let mut pipeline = Pipeline::builder()
+            .datasource(UpdatesDatasource {
+                updates: vec![
+                    transaction_with_instructions(1, &[instruction(1), instruction(2)]),
+                    transaction_with_instructions(2, &[instruction(3)]),
+                ],
+                resumed_from: Arc::new(Mutex::new(None)),
+            })
+            .instruction_with_error_policy(
+                FirstByteDecoder { program_id },
+                FailingOnOneProcessor { data: data.clone() },
+                error_policy,
+            )
+            .dead_letter_sink(sink.clone())
+            .shutdown_token(shutdown_token.clone())
+            .build()
+            .unwrap();
+
+        let (result, _) = tokio::join!(pipeline.run(), async {
+            while data.lock().unwrap().last() != Some(&3) {
+                tokio::time::sleep(Duration::from_millis(1)).await;
+            }
+            shutdown_token.cancel();
+        });
+        assert!(result.is_ok());
+
+        let processed = data.lock().unwrap().clone();
+        let dead_lettered = sink
+            .records
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(_, data, _)| data.clone())
+            .collect();
+        (processed, dead_lettered)
+    }
+
+    #[tokio::test]
+    async fn test_abort_policy_fails_the_rest_of_the_transaction() {
+        let (processed, dead_lettered) = run_with_error_policy(ErrorPolicy::Abort).await;
+
+        // The sink is ignored, and instruction 2 is never reached, but the
+        // pipeline goes on with the next transaction.
+        assert_eq!(processed, vec![3]);
+        assert!(dead_lettered.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_skip_policy_continues_with_the_next_instruction() {
+        let (processed, dead_lettered) = run_with_error_policy(ErrorPolicy::Skip).await;
+
+        assert_eq!(processed, vec![2, 3]);
+        assert!(dead_lettered.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_dead_letter_policy_sends_failures_to_the_sink() {
+        let (processed, dead_lettered) = run_with_error_policy(ErrorPolicy::DeadLetter).await;
+
+        assert_eq!(processed, vec![2, 3]);
+        assert_eq!(dead_lettered, vec![vec![1]]);
+    }
+
+    /// The number of instructions of a transaction seen so far.
+    #[derive(Debug, Default, Clone, Copy, PartialEq)]
+    struct InstructionCount(u32);
+
+    struct CountingProcessor;
+
+    #[async_trait]
+    impl Processor for CountingProcessor {
+        type InputType = InstructionProcessorInputType<u8>;
+
+        async fn process(
+            &mut self,
+            (metadata, _, _): Self::InputType,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            let context = metadata
+                .transaction_context
+                .ok_or_else(|| Error::Custom("missing transaction context".to_string()))?;
+            context.with(|count: &mut InstructionCount| count.0 += 1);
+            Ok(())
+        }
+    }
+
+    struct RecordingFinalizer {
+        counts: Arc<Mutex<Vec<(u64, u32)>>>,
+    }
+
+    #[async_trait]
+    impl TransactionContextFinalizer for RecordingFinalizer {
+        async fn finalize(
+            &self,
+            transaction_metadata: &TransactionMetadata,
+            context: TransactionContext,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            let count = context.get::<InstructionCount>().unwrap_or_default();
+            self.counts
+                .lock()
+                .unwrap()
+                .push((transaction_metadata.slot, count.0));
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_transaction_context_is_shared_and_finalized() {
+        // Arrange
+        let program_id = Pubkey::new_unique();
+        let instruction = |data: u8| Instruction {
+            program_id,
+            accounts: vec![],
+            data: vec![data],
+        };
+        let counts = Arc::new(Mutex::new(Vec::new()));
+        let shutdown_token = CancellationToken::new();
+
+        let mut pipeline = Pipeline::builder()
+            .datasource(UpdatesDatasource {
+                updates: vec![
+                    transaction_with_instructions(1, &[instruction(1), instruction(2)]),
+                    transaction_with_instructions(2, &[instruction(3)]),
+                ],
+                resumed_from: Arc::new(Mutex::new(None)),
+            })
+            .instruction(FirstByteDecoder { program_id }, CountingProcessor)
+            .transaction_context(RecordingFinalizer {
+                counts: counts.clone(),
+            })
+            .shutdown_token(shutdown_token.clone())
+            .build()
+            .unwrap();
+
+        // Act
+        let (result, _) = tokio::join!(pipeline.run(), async {
+            while counts.lock().unwrap().len() < 2 {
+                tokio::time::sleep(Duration::from_millis(1)).await;
+            }
+            shutdown_token.cancel();
+        });
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(*counts.lock().unwrap(), vec![(1, 2), (2, 1)]);
+    }
+
+    /// Appends its pipe number and the data of the instructions it processes
+    /// to a log shared with other pipes.
+    struct PipeLogProcessor {
+        pipe: u8,
+        log: Arc<Mutex<Vec<(u8, u8)>>>,
+    }
+
+    #[async_trait]
+    impl Processor for PipeLogProcessor {
+        type InputType = InstructionProcessorInputType<u8>;
+
+        async fn process(
+            &mut self,
+            (_, instruction, _): Self::InputType,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            self.log.lock().unwrap().push((self.pipe, instruction.data));
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_instruction_pipes_run_in_registration_order() {
+        // Arrange
+        let program_id = Pubkey::new_unique();
+        let instruction = |data: u8| Instruction {
+            program_id,
+            accounts: vec![],
+            data: vec![data],
+        };
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let shutdown_token = CancellationToken::new();
+
+        let mut builder = Pipeline::builder().datasource(UpdatesDatasource {
+            updates: vec![
+                transaction_with_instructions(1, &[instruction(1), instruction(2)]),
+                transaction_with_instructions(2, &[instruction(3)]),
+            ],
+            resumed_from: Arc::new(Mutex::new(None)),
+        });
+        for pipe in 1..=3 {
+            builder = builder.instruction(
+                FirstByteDecoder { program_id },
+                PipeLogProcessor {
+                    pipe,
+                    log: log.clone(),
+                },
+            );
+        }
+        let mut pipeline = builder
+            .max_concurrent_transactions(4)
+            .deterministic(true)
+            .shutdown_token(shutdown_token.clone())
+            .build()
+            .unwrap();
+
+        // Act
+        let (result, _) = tokio::join!(pipeline.run(), async {
+            while log.lock().unwrap().len() < 9 {
+                tokio::time::sleep(Duration::from_millis(1)).await;
+            }
+            shutdown_token.cancel();
+        });
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(pipeline.max_concurrent_transactions, 1);
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec![
+                (1, 1),
+                (1, 2),
+                (2, 1),
+                (2, 2),
+                (3, 1),
+                (3, 2),
+                (1, 3),
+                (2, 3),
+                (3, 3),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_failed_transactions_are_skipped() {
+        let program_id = Pubkey::new_unique();
+        let instruction = |data: u8| Instruction {
+            program_id,
+            accounts: vec![],
+            data: vec![data],
+        };
+        let mut failed = transaction_with_instructions(2, &[instruction(2), instruction(3)]);
+        if let Update::Transaction(transaction_update) = &mut failed {
+            transaction_update.meta.status = Err(TransactionError::InstructionError(
+                1,
+                InstructionError::Custom(0),
+            ));
+        }
+        let data = Arc::new(Mutex::new(Vec::new()));
+        let shutdown_token = CancellationToken::new();
+
+        let mut pipeline = Pipeline::builder()
+            .datasource(UpdatesDatasource {
+                updates: vec![
+                    transaction_with_instructions(1, &[instruction(1)]),
+                    failed,
+                    transaction_with_instructions(3, &[instruction(4)]),
+                ],
+                resumed_from: Arc::new(Mutex::new(None)),
+            })
+            .instruction(
+                FirstByteDecoder { program_id },
+                RecordingInstructionProcessor { data: data.clone() },
+            )
+            .shutdown_token(shutdown_token.clone())
+            .build()
+            .unwrap();
+
+        let (result, _) = tokio::join!(pipeline.run(), async {
+            while data.lock().unwrap().last() != Some(&4) {
+                tokio::time::sleep(Duration::from_millis(1)).await;
+            }
+            shutdown_token.cancel();
+        });
+
+        assert!(result.is_ok());
+        assert_eq!(*data.lock().unwrap(), vec![1, 4]);
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_signatures_are_processed_once() {
+        let program_id = Pubkey::new_unique();
+        let instruction = |data: u8| Instruction {
+            program_id,
+            accounts: vec![],
+            data: vec![data],
+        };
+        let mut other = transaction_with_instructions(3, &[instruction(3)]);
+        if let Update::Transaction(transaction_update) = &mut other {
+            transaction_update.signature = Signature::from([1; 64]);
+        }
+        let data = Arc::new(Mutex::new(Vec::new()));
+        let shutdown_token = CancellationToken::new();
+
+        let mut pipeline = Pipeline::builder()
+            .datasource(UpdatesDatasource {
+                updates: vec![
+                    transaction_with_instructions(1, &[instruction(1)]),
+                    transaction_with_instructions(2, &[instruction(2)]),
+                    other,
+                ],
+                resumed_from: Arc::new(Mutex::new(None)),
+            })
+            .instruction(
+                FirstByteDecoder { program_id },
+                RecordingInstructionProcessor { data: data.clone() },
+            )
+            .dedupe_signatures(16)
+            .shutdown_token(shutdown_token.clone())
+            .build()
+            .unwrap();
+
+        let (result, _) = tokio::join!(pipeline.run(), async {
+            while data.lock().unwrap().last() != Some(&3) {
+                tokio::time::sleep(Duration::from_millis(1)).await;
+            }
+            shutdown_token.cancel();
+        });
+
+        assert!(result.is_ok());
+        assert_eq!(*data.lock().unwrap(), vec![1, 3]);
+    }
+
+    /// Decodes nothing, for programs given by `program_ids`.
+    struct ProgramsDecoder {
+        program_ids: Vec<Pubkey>,
+    }
+
+    impl InstructionDecoder<'_> for ProgramsDecoder {
+        type InstructionType = u8;
+
+        fn try_decode_instruction(
+            &self,
+            _instruction: &Instruction,
+        ) -> CarbonResult<Option<DecodedInstruction<Self::InstructionType>>> {
+            Ok(None)
+        }
+
+        fn program_ids(&self) -> &[Pubkey] {
+            &self.program_ids
+        }
+    }
+
+    #[test]
+    fn test_registered_decoders_lists_instruction_decoders() {
+        let program_id = Pubkey::new_unique();
+        let first_program_id = Pubkey::new_unique();
+        let second_program_id = Pubkey::new_unique();
+        let recording = || RecordingInstructionProcessor {
+            data: Arc::default(),
+        };
+
+        let pipeline = Pipeline::builder()
+            .datasource(EndlessDeletionDatasource)
+            .instruction(FirstByteDecoder { program_id }, recording())
+            .instruction(
+                ProgramsDecoder {
+                    program_ids: vec![first_program_id, second_program_id],
+                },
+                recording(),
+            )
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            pipeline.registered_decoders(),
+            vec![
+                ("FirstByteDecoder".to_string(), Pubkey::default()),
+                ("ProgramsDecoder".to_string(), first_program_id),
+                ("ProgramsDecoder".to_string(), second_program_id),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_build_requires_a_datasource_and_a_pipe() {
+        let deletions = || SlowDeletionProcessor {
+            counts: ProcessingCounts::default(),
+            duration: Duration::ZERO,
+        };
+
+        let without_datasource = Pipeline::builder().account_deletions(deletions()).build();
+        let without_pipe = Pipeline::builder()
+            .datasource(EndlessDeletionDatasource)
+            .build();
+        let with_only_rollbacks = Pipeline::builder()
+            .datasource(EndlessDeletionDatasource)
+            .rollbacks(RecordingRollbackProcessor {
+                rollbacks: Arc::default(),
+                processed: Arc::default(),
+            })
+            .build();
+        let complete = Pipeline::builder()
+            .datasource(EndlessDeletionDatasource)
+            .account_deletions(deletions())
+            .build();
+
+        assert!(matches!(
+            without_datasource,
+            Err(CarbonError::MissingDatasource)
+        ));
+        assert!(matches!(without_pipe, Err(CarbonError::MissingPipe)));
+        assert!(matches!(with_only_rollbacks, Err(CarbonError::MissingPipe)));
+        let pipeline = complete.expect("valid pipeline");
+        assert_eq!(pipeline.datasources.len(), 1);
+        assert_eq!(pipeline.account_deletion_pipes.len(), 1);
+    }
+
+    /// A span captured by `SpanCapture`, with its fields formatted with
+    /// `Debug`.
+    struct CapturedSpan {
+        name: &'static str,
+        parent: Option<u64>,
+        fields: HashMap<&'static str, String>,
+    }
+
+    /// An event captured by `SpanCapture`, with the span it was emitted in.
+    #[derive(PartialEq)]
+    struct CapturedEvent {
+        message: String,
+        span: Option<u64>,
+    }
+
+    #[derive(Default)]
+    struct CapturedFields(HashMap<&'static str, String>);
+
+    impl tracing::field::Visit for CapturedFields {
+        fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+            self.0.insert(field.name(), format!("{:?}", value));
+        }
+    }
+
+    /// A subscriber that records every span and the span every event was
+    /// emitted in. It keeps a single stack of entered spans, so it only works
+    /// on a single-threaded runtime. Span IDs are indices into `spans` plus 1.
+    #[derive(Clone, Default)]
+    struct SpanCapture {
+        spans: Arc<Mutex<Vec<CapturedSpan>>>,
+        events: Arc<Mutex<Vec<CapturedEvent>>>,
+        entered: Arc<Mutex<Vec<u64>>>,
+    }
+
+    impl tracing::Subscriber for SpanCapture {
+        fn enabled(&self, _metadata: &tracing::Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, attributes: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+            let mut fields = CapturedFields::default();
+            attributes.record(&mut fields);
+            let parent = if attributes.is_contextual() {
+                self.entered.lock().unwrap().last().copied()
+            } else {
+                attributes.parent().map(tracing::span::Id::into_u64)
+            };
+
+            let mut spans = self.spans.lock().unwrap();
+            spans.push(CapturedSpan {
+                name: attributes.metadata().name(),
+                parent,
+                fields: fields.0,
+            });
+            tracing::span::Id::from_u64(spans.len() as u64)
+        }
+
+        fn record(&self, _span: &tracing::span::Id, _values: &tracing::span::Record<'_>) {}
+
+        fn record_follows_from(&self, _span: &tracing::span::Id, _follows: &tracing::span::Id) {}
+
+        fn event(&self, event: &tracing::Event<'_>) {
+            let mut fields = CapturedFields::default();
+            event.record(&mut fields);
+            self.events.lock().unwrap().push(CapturedEvent {
+                message: fields.0.remove("message").unwrap_or_default(),
+                span: self.entered.lock().unwrap().last().copied(),
+            });
+        }
+
+        fn enter(&self, span: &tracing::span::Id) {
+            self.entered.lock().unwrap().push(span.into_u64());
+        }
+
+        fn exit(&self, _span: &tracing::span::Id) {
+            self.entered.lock().unwrap().pop();
+        }
+    }
+
+    struct TracingInstructionProcessor;
+
+    #[async_trait]
+    impl Processor for TracingInstructionProcessor {
+        type InputType = InstructionProcessorInputType<u8>;
+
+        async fn process(
+            &mut self,
+            (_, instruction, _): Self::InputType,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            tracing::info!("processing instruction {}", instruction.data);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_processor_events_are_in_transaction_and_instruction_spans() {
+        let capture = SpanCapture::default();
+        let _dispatcher =
+            tracing::dispatcher::set_default(&tracing::Dispatch::new(capture.clone()));
+        let program_id = Pubkey::new_unique();
+        let signature = Signature::new_unique();
+        let mut update = transaction_with_instructions(
+            7,
+            &[Instruction {
+                program_id,
+                accounts: vec![],
+                data: vec![1],
+            }],
+        );
+        if let Update::Transaction(transaction_update) = &mut update {
+            transaction_update.signature = signature;
+        }
+
+        let mut pipeline = Pipeline::builder()
+            .datasource(UpdatesDatasource {
+                updates: vec![update],
+                resumed_from: Arc::new(Mutex::new(None)),
+            })
+            .instruction(FirstByteDecoder { program_id }, TracingInstructionProcessor)
+            .build()
+            .unwrap();
+        tokio::time::timeout(Duration::from_secs(5), pipeline.run())
+            .await
+            .expect("pipeline stops at the end of the stream")
+            .unwrap();
+
+        let spans = capture.spans.lock().unwrap();
+        let span_id = |name: &str| {
+            spans
+                .iter()
+                .position(|span| span.name == name)
+                .map(|index| index as u64 + 1)
+                .unwrap()
+        };
+        let transaction_span = &spans[span_id("transaction") as usize - 1];
+        let instruction_span = &spans[span_id("instruction") as usize - 1];
+        assert_eq!(transaction_span.parent, None);
+        assert_eq!(transaction_span.fields["signature"], signature.to_string());
+        assert_eq!(transaction_span.fields["slot"], "7");
+        assert_eq!(instruction_span.parent, Some(span_id("transaction")));
+        assert_eq!(
+            instruction_span.fields["program_id"],
+            program_id.to_string()
+        );
+        assert_eq!(instruction_span.fields["stack_height"], "1");
+        assert!(capture.events.lock().unwrap().contains(&CapturedEvent {
+            message: "processing instruction 1".to_string(),
+            span: Some(span_id("instruction")),
+        }));
     }
 }
@@ -0,0 +1,267 @@
+//! Writes decoded data to Postgres. Only available with the `postgres`
+//! feature.
+//!
+//! [`PostgresProcessor`] inserts one row per input into a table, using a
+//! [`PgRow`] implementation to map each input to the values of its columns.
+//! Inputs are buffered by a [`BatchingProcessor`], and every batch is written
+//! in a single transaction through a [`PgClient`]. `PgClient` is implemented
+//! for `deadpool-postgres` pools, which prepare the INSERT statement once per
+//! connection and pipeline the rows of a batch over it, so a batch costs about
+//! one round trip.
+//!
+//! A batch whose transaction fails is reported by the next call to `process`,
+//! or by `shutdown`, as described in [`BatchingProcessor`], so that the
+//! pipeline does not move its checkpoint past rows that were not written.
+//!
+//! After a restart or a rollback, a pipeline may process updates it already
+//! wrote. With [`PostgresProcessor::on_conflict_do_nothing`], rows that
+//! violate a unique constraint of the table are skipped, which makes such
+//! replays idempotent.
+//!
+//! The processor records the `postgres_rows_inserted` and
+//! `postgres_rows_skipped` counters.
+//!
+//! ```ignore
+//! struct SwapRows;
+//!
+//! impl PgRow<InstructionProcessorInputType<SwapInstruction>> for SwapRows {
+//!     fn table(&self) -> &str {
+//!         "swaps"
+//!     }
+//!
+//!     fn columns(&self) -> &[&str] {
+//!         &["signature", "instruction_index", "amount_in"]
+//!     }
+//!
+//!     fn values(
+//!         &self,
+//!         (metadata, instruction, _): &InstructionProcessorInputType<SwapInstruction>,
+//!     ) -> Vec<Box<dyn ToSql + Send + Sync>> {
+//!         vec![
+//!             Box::new(metadata.transaction_metadata.signature.to_string()),
+//!             Box::new(metadata.index as i32),
+//!             Box::new(instruction.data.amount_in as i64),
+//!         ]
+//!     }
+//! }
+//!
+//! let pipeline = Pipeline::builder()
+//!     .instruction(
+//!         SwapDecoder,
+//!         PostgresProcessor::new(pool, SwapRows).on_conflict_do_nothing(),
+//!     )
+//!     .build()?;
+//! ```
+
+use {
+    crate::{
+        error::{CarbonResult, Error},
+        metrics::MetricsCollection,
+        processor::{BatchProcessor, BatchingProcessor, Processor},
+    },
+    async_trait::async_trait,
+    futures::future::try_join_all,
+    std::{marker::PhantomData, sync::Arc, time::Duration},
+};
+pub use {deadpool_postgres::Pool, tokio_postgres::types::ToSql};
+
+/// Maps the inputs of a [`PostgresProcessor`] to rows of a table.
+///
+/// `table` and `columns` are inserted into the statement as they are, so they
+/// must be valid, and if needed quoted, SQL identifiers. The values returned by
+/// `values` are passed as parameters, one per column and in the same order.
+pub trait PgRow<T>: Send + Sync {
+    /// The table rows are inserted into.
+    fn table(&self) -> &str;
+
+    /// The columns each row provides a value for.
+    fn columns(&self) -> &[&str];
+
+    /// Returns the value of each column for `input`.
+    fn values(&self, input: &T) -> Vec<Box<dyn ToSql + Send + Sync>>;
+}
+
+/// Writes the rows of a [`PostgresProcessor`].
+#[async_trait]
+pub trait PgClient: Send + Sync {
+    /// Executes `statement` with the parameters of each row, in a single
+    /// transaction, and returns the number of rows inserted.
+    async fn insert_rows(
+        &self,
+        statement: &str,
+        rows: &[Vec<Box<dyn ToSql + Send + Sync>>],
+    ) -> CarbonResult<u64>;
+}
+
+#[async_trait]
+impl PgClient for Pool {
+    async fn insert_rows(
+        &self,
+        statement: &str,
+        rows: &[Vec<Box<dyn ToSql + Send + Sync>>],
+    ) -> CarbonResult<u64> {
+        let mut client = self.get().await.map_err(|error| {
+            Error::Custom(format!("Failed to get Postgres connection: {error}"))
+        })?;
+        let transaction = client.transaction().await?;
+        let statement = transaction.prepare_cached(statement).await?;
+
+        let inserted = try_join_all(rows.iter().map(|values| {
+            transaction.execute_raw(
+                &statement,
+                values
+                    .iter()
+                    .map(|value| value.as_ref() as &(dyn ToSql + Sync)),
+            )
+        }))
+        .await?
+        .into_iter()
+        .sum();
+
+        transaction.commit().await?;
+
+        Ok(inserted)
+    }
+}
+
+/// A processor that inserts a row per input into Postgres, in batches.
+///
+/// See the [module documentation](self) for details.
+///
+/// # Defaults
+///
+/// - `batch_size`: 100
+/// - `flush_interval`: 1 second
+/// - `on_conflict_do_nothing`: disabled
+pub struct PostgresProcessor<T: Send, M: PgRow<T>> {
+    batching: BatchingProcessor<PostgresWriter<T, M>>,
+}
+
+impl<T: Send, M: PgRow<T>> PostgresProcessor<T, M> {
+    /// Creates a processor inserting the rows mapped by `mapping` with
+    /// `client`, usually a `Pool`.
+    pub fn new(client: impl PgClient + 'static, mapping: M) -> Self {
+        let statement = insert_statement(mapping.table(), mapping.columns(), false);
+
+        Self {
+            batching: BatchingProcessor::new(PostgresWriter {
+                client: Box::new(client),
+                mapping,
+                statement,
+                input: PhantomData,
+            }),
+        }
+    }
+
+    /// Skips rows that violate a unique constraint of the table, by adding
+    /// `ON CONFLICT DO NOTHING` to the statement.
+    pub fn on_conflict_do_nothing(mut self) -> Self {
+        if let Some(writer) = self.batching.processor.as_mut() {
+            writer.statement =
+                insert_statement(writer.mapping.table(), writer.mapping.columns(), true);
+        }
+        self
+    }
+
+    /// Sets the number of rows written per transaction. Values below `1` are
+    /// treated as `1`.
+    pub fn batch_size(mut self, batch_size: usize) -> Self {
+        self.batching = self.batching.batch_size(batch_size);
+        self
+    }
+
+    /// Sets the longest time an input waits before its row is written.
+    pub fn flush_interval(mut self, flush_interval: Duration) -> Self {
+        self.batching = self.batching.flush_interval(flush_interval);
+        self
+    }
+}
+
+#[async_trait]
+impl<T, M> Processor for PostgresProcessor<T, M>
+where
+    T: Send + 'static,
+    M: PgRow<T> + 'static,
+{
+    type InputType = T;
+
+    async fn process(
+        &mut self,
+        data: Self::InputType,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        self.batching.process(data, metrics).await
+    }
+
+    async fn shutdown(&mut self) -> CarbonResult<()> {
+        self.batching.shutdown().await
+    }
+}
+
+/// Writes each batch of a `PostgresProcessor` in a transaction.
+struct PostgresWriter<T, M> {
+    client: Box<dyn PgClient>,
+    mapping: M,
+    statement: String,
+    input: PhantomData<fn(T)>,
+}
+
+#[async_trait]
+impl<T, M> BatchProcessor for PostgresWriter<T, M>
+where
+    T: Send,
+    M: PgRow<T>,
+{
+    type InputType = T;
+
+    async fn process_batch(
+        &self,
+        items: Vec<Self::InputType>,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        let rows: Vec<_> = items.iter().map(|item| self.mapping.values(item)).collect();
+        let inserted = self.client.insert_rows(&self.statement, &rows).await?;
+
+        metrics
+            .increment_counter("postgres_rows_inserted", inserted)
+            .await?;
+        metrics
+            .increment_counter("postgres_rows_skipped", rows.len() as u64 - inserted)
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// Builds the parameterized INSERT of a row into `table`.
+fn insert_statement(table: &str, columns: &[&str], on_conflict_do_nothing: bool) -> String {
+    let placeholders: Vec<String> = (1..=columns.len())
+        .map(|index| format!("${index}"))
+        .collect();
+    let mut statement = format!(
+        "INSERT INTO {table} ({}) VALUES ({})",
+        columns.join(", "),
+        placeholders.join(", ")
+    );
+    if on_conflict_do_nothing {
+        statement.push_str(" ON CONFLICT DO NOTHING");
+    }
+    statement
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_statement() {
+        assert_eq!(
+            insert_statement("swaps", &["signature", "amount"], false),
+            "INSERT INTO swaps (signature, amount) VALUES ($1, $2)"
+        );
+        assert_eq!(
+            insert_statement("swaps", &["signature"], true),
+            "INSERT INTO swaps (signature) VALUES ($1) ON CONFLICT DO NOTHING"
+        );
+    }
+}
@@ -30,6 +30,13 @@
 //!   asynchronous and should be implemented to define how data should be
 //!   processed in your specific use case.
 //!
+//! ### Provided Methods
+//!
+//! - `shutdown`: Called once when the pipeline shuts down, so that processors
+//!   holding buffered data can write it out. Does nothing by default.
+//! - `fork`: Creates another instance of the processor, so that the pipeline
+//!   can process several updates concurrently. Returns `None` by default.
+//!
 //! ## Parameters
 //!
 //! - `data`: An instance of the type specified by `InputType`. This represents
@@ -42,6 +49,49 @@
 //! The `process` method returns a `CarbonResult<()>`, which indicates either
 //! successful processing (`Ok(())`) or an error.
 //!
+//! ## Combinators
+//!
+//! - `FanOutProcessor`: Forwards a clone of each input to several child
+//!   processors, so independent sinks can share a single decoded value instead
+//!   of registering one pipe (and one decode) per sink.
+//! - `RetryProcessor`: Retries a wrapped processor with exponential backoff and
+//!   jitter when it fails with an error the caller considers transient.
+//! - `BatchingProcessor`: Buffers inputs and hands them to a `BatchProcessor`
+//!   in batches, so that sinks can write many rows in a single round trip.
+//! - `MapProcessor`: Converts each input with a function before passing it to a
+//!   wrapped processor, so that one processor can consume the outputs of
+//!   several decoders.
+//! - `FilterProcessor`: Passes only the inputs accepted by a predicate to a
+//!   wrapped processor, so that a broadly registered decoder can feed a
+//!   processor interested in a subset of its outputs.
+//! - `TimeoutProcessor`: Fails a wrapped processor that does not finish within
+//!   a deadline, so that a hanging processor surfaces as an error instead of
+//!   stalling the pipeline.
+//!
+//! ## Registration Bounds
+//!
+//! The pipeline runs processors on a multi-threaded runtime, so the
+//! `PipelineBuilder` methods that register them require them to be `Send`,
+//! `Sync` and `'static`, through `SendProcessor`, `SyncProcessor` and
+//! `StaticProcessor`. These are implemented by every `Send`, `Sync` and
+//! `'static` type respectively, so that registering a processor holding an
+//! `Rc` or a `RefCell` fails with an error naming the field, the processor
+//! and the missing bound, rather than one pointing deep into the pipeline's
+//! generics.
+//!
+//! A processor borrowing data that does not live for the whole program is
+//! reported as a borrow error, which cannot carry a custom message. Its note
+//! points at the `StaticProcessor` bound instead, whose documentation
+//! describes the fix.
+//!
+//! ## Type-Erased Processors
+//!
+//! `ErasedProcessor` is implemented by every processor and takes its input as
+//! a `Box<dyn Any + Send>`, so that processors of different input types can be
+//! stored together. `ProcessorRegistry` keys them by input type and routes
+//! each value to every processor expecting its type, for plugin-style
+//! applications that load processors at runtime.
+//!
 //! ## Notes
 //!
 //! - This trait uses `async_trait` to enable asynchronous processing. Ensure
@@ -52,9 +102,26 @@
 //!   enable monitoring and alerting on key performance indicators.
 
 use {
-    crate::{error::CarbonResult, metrics::MetricsCollection},
+    crate::{
+        error::{CarbonResult, Error},
+        metrics::MetricsCollection,
+    },
     async_trait::async_trait,
-    std::sync::Arc,
+    futures::future::join_all,
+    std::{
+        any::{Any, TypeId},
+        collections::{hash_map::RandomState, HashMap},
+        future::Future,
+        hash::{BuildHasher, Hasher},
+        marker::PhantomData,
+        sync::Arc,
+        time::Duration,
+    },
+    tokio::{
+        sync::mpsc,
+        task::JoinHandle,
+        time::{Instant, MissedTickBehavior},
+    },
 };
 
 /// A trait for defining asynchronous data processing within the pipeline.
@@ -76,6 +143,15 @@ use {
 /// - `process`: Processes the specified `InputType` data asynchronously,
 ///   optionally updating associated metrics.
 ///
+/// # Provided Methods
+///
+/// - `shutdown`: Called by the pipeline once, after the last `process` call,
+///   when it shuts down. Processors that buffer data should write it out here.
+/// - `fork`: Called when the pipeline is configured with
+///   `max_concurrent_transactions` above one. Each returned instance handles
+///   its own share of the updates, concurrently with the others. Processors
+///   that return `None`, the default, handle one update at a time.
+///
 /// # Example
 ///
 /// ```rust
@@ -110,4 +186,1543 @@ pub trait Processor {
         data: Self::InputType,
         metrics: Arc<MetricsCollection>,
     ) -> CarbonResult<()>;
+
+    async fn shutdown(&mut self) -> CarbonResult<()> {
+        Ok(())
+    }
+
+    fn fork(&self) -> Option<Box<dyn Processor<InputType = Self::InputType> + Send + Sync>> {
+        None
+    }
+}
+
+/// A processor that can be sent to the pipeline's tasks.
+///
+/// Implemented by every `Send` type. The `PipelineBuilder` methods require it
+/// instead of `Send`, so that registering a processor that is not `Send` fails
+/// with an error naming the offending field and noting that the processor
+/// holding it must implement this trait.
+#[diagnostic::on_unimplemented(
+    message = "processor `{Self}` cannot be sent between threads safely",
+    label = "`{Self}` is not `Send`",
+    note = "the pipeline runs processors on a multi-threaded runtime, so a processor and all of its \
+            fields must be `Send`",
+    note = "replace `Rc` with `Arc` and `RefCell` or `Cell` with `Mutex` in the fields of the \
+            processor, and do not hold a `MutexGuard` across an `.await`"
+)]
+pub trait SendProcessor: Send {}
+
+impl<P: Send> SendProcessor for P {}
+
+/// A processor that can be shared between the pipeline's tasks.
+///
+/// Implemented by every `Sync` type. The `PipelineBuilder` methods require it
+/// instead of `Sync`, so that registering a processor that is not `Sync` fails
+/// with an error naming the offending field and noting that the processor
+/// holding it must implement this trait.
+#[diagnostic::on_unimplemented(
+    message = "processor `{Self}` cannot be shared between threads safely",
+    label = "`{Self}` is not `Sync`",
+    note = "the pipeline runs processors on a multi-threaded runtime, so a processor and all of its \
+            fields must be `Sync`",
+    note = "`process` takes `&mut self`, so fields can be mutated without a `RefCell` or `Cell`; \
+            use a `Mutex` or an atomic for state shared with other code"
+)]
+pub trait SyncProcessor: Sync {}
+
+impl<P: Sync> SyncProcessor for P {}
+
+/// A processor that owns its data, so that it can outlive the builder it is
+/// registered with.
+///
+/// Implemented by every `'static` type. The `PipelineBuilder` methods require
+/// it instead of `'static`, so that registering a processor that borrows a
+/// local variable fails with a note pointing at this bound. Move the borrowed
+/// data into the processor, or share it through an `Arc`.
+pub trait StaticProcessor: 'static {}
+
+impl<P: 'static> StaticProcessor for P {}
+
+#[async_trait]
+impl<P> Processor for Box<P>
+where
+    P: Processor + Send + Sync + ?Sized,
+    P::InputType: Send,
+{
+    type InputType = P::InputType;
+
+    async fn process(
+        &mut self,
+        data: Self::InputType,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        (**self).process(data, metrics).await
+    }
+
+    async fn shutdown(&mut self) -> CarbonResult<()> {
+        (**self).shutdown().await
+    }
+
+    fn fork(&self) -> Option<Box<dyn Processor<InputType = Self::InputType> + Send + Sync>> {
+        (**self).fork()
+    }
+}
+
+/// A processor that forwards each input to multiple child processors.
+///
+/// `FanOutProcessor` clones the incoming data once per child and runs all
+/// children concurrently. This lets several independent sinks (for example a
+/// database writer, a metrics emitter and a webhook notifier) consume the same
+/// decoded value from a single pipe.
+///
+/// # Error Handling
+///
+/// A failing child does not abort the others: every child runs to completion
+/// for every input. If one or more children return an error, the errors are
+/// collected in child order and returned together as
+/// `Error::FanOutFailed`. When all children succeed, `process` returns
+/// `Ok(())`.
+///
+/// # Example
+///
+/// ```ignore
+/// let processor = FanOutProcessor::new(vec![
+///     Box::new(DatabaseWriter::new(pool)),
+///     Box::new(WebhookNotifier::new(url)),
+/// ]);
+///
+/// let pipeline = Pipeline::builder()
+///     .instruction(MyDecoder, processor)
+///     .build()?;
+/// ```
+pub struct FanOutProcessor<T> {
+    processors: Vec<Box<dyn Processor<InputType = T> + Send + Sync>>,
+}
+
+impl<T> FanOutProcessor<T> {
+    /// Creates a new `FanOutProcessor` from the given child processors.
+    pub fn new(processors: Vec<Box<dyn Processor<InputType = T> + Send + Sync>>) -> Self {
+        Self { processors }
+    }
+}
+
+#[async_trait]
+impl<T> Processor for FanOutProcessor<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    type InputType = T;
+
+    async fn process(
+        &mut self,
+        data: Self::InputType,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        join_fan_out(
+            self.processors
+                .iter_mut()
+                .map(|processor| processor.process(data.clone(), metrics.clone())),
+        )
+        .await
+    }
+
+    async fn shutdown(&mut self) -> CarbonResult<()> {
+        join_fan_out(
+            self.processors
+                .iter_mut()
+                .map(|processor| processor.shutdown()),
+        )
+        .await
+    }
+
+    fn fork(&self) -> Option<Box<dyn Processor<InputType = Self::InputType> + Send + Sync>> {
+        let processors = self
+            .processors
+            .iter()
+            .map(|processor| processor.fork())
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(Box::new(FanOutProcessor::new(processors)))
+    }
+}
+
+/// A processor that retries a wrapped processor on transient failures.
+///
+/// `RetryProcessor` calls the inner processor and, if it returns an error for
+/// which the retry predicate returns `true`, waits and calls it again with a
+/// clone of the same input. The wait doubles after every attempt, starting at
+/// the initial backoff and capped at the maximum backoff, and is randomly
+/// shortened by up to the configured jitter fraction so that many retrying
+/// processors do not hit a recovering backend at the same moment.
+///
+/// Once `max_retries` retries have been used up, or the predicate rejects the
+/// error, the last error is returned unchanged. Every retry increments the
+/// `processor_retries` counter on the `MetricsCollection`.
+///
+/// # Defaults
+///
+/// - `max_retries`: 3
+/// - `backoff`: 100 milliseconds initially, capped at 10 seconds
+/// - `jitter`: 0.5
+/// - `retry_if`: every error is retried
+///
+/// # Example
+///
+/// ```ignore
+/// let processor = RetryProcessor::new(PostgresWriter::new(pool))
+///     .max_retries(5)
+///     .backoff(Duration::from_millis(50), Duration::from_secs(5))
+///     .retry_if(|error| matches!(error, Error::Custom(_)));
+/// ```
+pub struct RetryProcessor<P> {
+    inner: P,
+    max_retries: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    jitter: f64,
+    retry_if: fn(&Error) -> bool,
+}
+
+impl<P> RetryProcessor<P> {
+    /// Wraps `inner` with the default retry policy.
+    pub fn new(inner: P) -> Self {
+        Self {
+            inner,
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(10),
+            jitter: 0.5,
+            retry_if: |_| true,
+        }
+    }
+
+    /// Sets the maximum number of retries after the first attempt.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the delay before the first retry and the upper bound for the
+    /// exponentially growing delay.
+    pub fn backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_backoff = initial;
+        self.max_backoff = max;
+        self
+    }
+
+    /// Sets the fraction, between `0.0` and `1.0`, by which each delay may be
+    /// randomly shortened.
+    pub fn jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the predicate deciding whether an error is worth retrying.
+    pub fn retry_if(mut self, retry_if: fn(&Error) -> bool) -> Self {
+        self.retry_if = retry_if;
+        self
+    }
+
+    /// Returns the delay to wait before the given retry, counting from zero.
+    fn backoff_delay(&self, retry: u32) -> Duration {
+        let delay = self
+            .initial_backoff
+            .saturating_mul(2u32.saturating_pow(retry))
+            .min(self.max_backoff);
+
+        if self.jitter == 0.0 {
+            return delay;
+        }
+
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_u32(retry);
+        let random = hasher.finish() as f64 / u64::MAX as f64;
+
+        delay.mul_f64(1.0 - self.jitter * random)
+    }
+}
+
+#[async_trait]
+impl<P> Processor for RetryProcessor<P>
+where
+    P: Processor + Send + Sync,
+    P::InputType: Clone + Send + Sync + 'static,
+{
+    type InputType = P::InputType;
+
+    async fn process(
+        &mut self,
+        data: Self::InputType,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        let mut retry = 0;
+
+        loop {
+            let error = match self.inner.process(data.clone(), metrics.clone()).await {
+                Ok(()) => return Ok(()),
+                Err(error) => error,
+            };
+
+            if retry >= self.max_retries || !(self.retry_if)(&error) {
+                return Err(error);
+            }
+
+            let delay = self.backoff_delay(retry);
+            log::warn!(
+                "Processor failed ({}), retrying in {:?} ({}/{})",
+                error,
+                delay,
+                retry + 1,
+                self.max_retries
+            );
+            metrics.increment_counter("processor_retries", 1).await?;
+
+            tokio::time::sleep(delay).await;
+            retry += 1;
+        }
+    }
+
+    async fn shutdown(&mut self) -> CarbonResult<()> {
+        self.inner.shutdown().await
+    }
+
+    fn fork(&self) -> Option<Box<dyn Processor<InputType = Self::InputType> + Send + Sync>> {
+        Some(Box::new(RetryProcessor {
+            inner: self.inner.fork()?,
+            max_retries: self.max_retries,
+            initial_backoff: self.initial_backoff,
+            max_backoff: self.max_backoff,
+            jitter: self.jitter,
+            retry_if: self.retry_if,
+        }))
+    }
+}
+
+/// A processor that converts its input before passing it to a wrapped
+/// processor.
+///
+/// Every decoder produces its own input type, such as
+/// `InstructionProcessorInputType<DriftInstruction>`. `MapProcessor` applies
+/// `map` to each input and forwards the result, along with the
+/// `MetricsCollection`, to `inner`. This lets a single processor working on a
+/// domain type be registered for several decoders, with one small conversion
+/// function per decoder.
+///
+/// `I` is the input type of the `MapProcessor` itself. It is usually inferred
+/// from the parameter type of `map`.
+///
+/// # Example
+///
+/// ```ignore
+/// let processor = MapProcessor::new(
+///     SwapWriter::new(pool),
+///     |(metadata, instruction, _): InstructionProcessorInputType<RaydiumAmmV4Instruction>| {
+///         Swap::from_raydium(metadata, instruction)
+///     },
+/// );
+/// ```
+pub struct MapProcessor<P, F, I> {
+    inner: P,
+    map: F,
+    input: PhantomData<fn(I)>,
+}
+
+impl<P, F, I> MapProcessor<P, F, I>
+where
+    P: Processor,
+    F: Fn(I) -> P::InputType,
+{
+    /// Wraps `inner`, converting every input with `map`.
+    pub fn new(inner: P, map: F) -> Self {
+        Self {
+            inner,
+            map,
+            input: PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+impl<P, F, I> Processor for MapProcessor<P, F, I>
+where
+    P: Processor + Send + Sync,
+    P::InputType: Send + 'static,
+    F: Fn(I) -> P::InputType + Clone + Send + Sync + 'static,
+    I: Send + 'static,
+{
+    type InputType = I;
+
+    async fn process(
+        &mut self,
+        data: Self::InputType,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        self.inner.process((self.map)(data), metrics).await
+    }
+
+    async fn shutdown(&mut self) -> CarbonResult<()> {
+        self.inner.shutdown().await
+    }
+
+    fn fork(&self) -> Option<Box<dyn Processor<InputType = Self::InputType> + Send + Sync>> {
+        Some(Box::new(MapProcessor {
+            inner: self.inner.fork()?,
+            map: self.map.clone(),
+            input: PhantomData,
+        }))
+    }
+}
+
+/// A processor that passes only the inputs accepted by a predicate to a
+/// wrapped processor.
+///
+/// A decoder is registered for a whole program, while a processor is often
+/// interested in a subset of its outputs, such as the deposits above a size
+/// threshold. `FilterProcessor` calls `predicate` on each input and forwards
+/// it, along with the `MetricsCollection`, to `inner` only when the predicate
+/// returns `true`. Every rejected input increments the `filtered_out` counter.
+///
+/// # Example
+///
+/// ```ignore
+/// let processor = FilterProcessor::new(
+///     DepositWriter::new(pool),
+///     |(_, instruction, _): &InstructionProcessorInputType<DriftInstruction>| {
+///         matches!(&instruction.data, DriftInstruction::Deposit(deposit) if deposit.amount >= 1_000_000)
+///     },
+/// );
+/// ```
+pub struct FilterProcessor<P, F> {
+    inner: P,
+    predicate: F,
+}
+
+impl<P, F> FilterProcessor<P, F>
+where
+    P: Processor,
+    F: Fn(&P::InputType) -> bool,
+{
+    /// Wraps `inner`, skipping every input for which `predicate` returns
+    /// `false`.
+    pub fn new(inner: P, predicate: F) -> Self {
+        Self { inner, predicate }
+    }
+}
+
+#[async_trait]
+impl<P, F> Processor for FilterProcessor<P, F>
+where
+    P: Processor + Send + Sync,
+    P::InputType: Send + 'static,
+    F: Fn(&P::InputType) -> bool + Clone + Send + Sync + 'static,
+{
+    type InputType = P::InputType;
+
+    async fn process(
+        &mut self,
+        data: Self::InputType,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        if !(self.predicate)(&data) {
+            metrics.increment_counter("filtered_out", 1).await?;
+            return Ok(());
+        }
+
+        self.inner.process(data, metrics).await
+    }
+
+    async fn shutdown(&mut self) -> CarbonResult<()> {
+        self.inner.shutdown().await
+    }
+
+    fn fork(&self) -> Option<Box<dyn Processor<InputType = Self::InputType> + Send + Sync>> {
+        Some(Box::new(FilterProcessor {
+            inner: self.inner.fork()?,
+            predicate: self.predicate.clone(),
+        }))
+    }
+}
+
+/// A processor that fails when a wrapped processor takes too long.
+///
+/// A processor that hangs, for example on a deadlocked database connection,
+/// would otherwise stall its pipe forever without any diagnostic.
+/// `TimeoutProcessor` races the inner `process` call against `timeout`. If the
+/// deadline passes first, the inner future is dropped, which cancels it at the
+/// point where it was waiting, and `CarbonError::Timeout` is returned so that
+/// the pipeline's error handling can react. Every timeout increments the
+/// `processor_timeouts` counter on the `MetricsCollection`.
+///
+/// As with any cancellation, a processor interrupted mid-way may have applied
+/// part of its work, so it should be safe to process the same input again.
+/// `shutdown` is not subject to the timeout.
+///
+/// # Example
+///
+/// ```ignore
+/// let processor = TimeoutProcessor::new(PostgresWriter::new(pool), Duration::from_secs(30));
+/// ```
+pub struct TimeoutProcessor<P> {
+    inner: P,
+    timeout: Duration,
+}
+
+impl<P> TimeoutProcessor<P> {
+    /// Wraps `inner`, failing every `process` call that takes longer than
+    /// `timeout`.
+    pub fn new(inner: P, timeout: Duration) -> Self {
+        Self { inner, timeout }
+    }
+}
+
+#[async_trait]
+impl<P> Processor for TimeoutProcessor<P>
+where
+    P: Processor + Send + Sync,
+    P::InputType: Send + 'static,
+{
+    type InputType = P::InputType;
+
+    async fn process(
+        &mut self,
+        data: Self::InputType,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        let process = self.inner.process(data, metrics.clone());
+        match tokio::time::timeout(self.timeout, process).await {
+            Ok(result) => result,
+            Err(_) => {
+                log::warn!("Processor timed out after {:?}", self.timeout);
+                metrics.increment_counter("processor_timeouts", 1).await?;
+                Err(Error::Timeout(self.timeout))
+            }
+        }
+    }
+
+    async fn shutdown(&mut self) -> CarbonResult<()> {
+        self.inner.shutdown().await
+    }
+
+    fn fork(&self) -> Option<Box<dyn Processor<InputType = Self::InputType> + Send + Sync>> {
+        Some(Box::new(TimeoutProcessor {
+            inner: self.inner.fork()?,
+            timeout: self.timeout,
+        }))
+    }
+}
+
+/// A trait for processing inputs in batches.
+///
+/// Writing every decoded value on its own means one database round trip per
+/// value. A `BatchProcessor` instead receives a `Vec` of accumulated inputs,
+/// which it can write with a single bulk statement. Wrap it in a
+/// `BatchingProcessor` to register it on a pipeline like any other processor.
+///
+/// # Example
+///
+/// ```ignore
+/// struct SwapWriter {
+///     pool: PgPool,
+/// }
+///
+/// #[async_trait]
+/// impl BatchProcessor for SwapWriter {
+///     type InputType = InstructionProcessorInputType<SwapInstruction>;
+///
+///     async fn process_batch(
+///         &self,
+///         items: Vec<Self::InputType>,
+///         metrics: Arc<MetricsCollection>,
+///     ) -> CarbonResult<()> {
+///         // INSERT all items in one statement
+///         Ok(())
+///     }
+/// }
+///
+/// let pipeline = Pipeline::builder()
+///     .instruction(SwapDecoder, BatchingProcessor::new(SwapWriter { pool }))
+///     .build()?;
+/// ```
+#[async_trait]
+pub trait BatchProcessor {
+    type InputType;
+
+    async fn process_batch(
+        &self,
+        items: Vec<Self::InputType>,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()>;
+}
+
+/// A processor that buffers inputs and passes them to a `BatchProcessor`.
+///
+/// Inputs are sent to a background task, which calls `process_batch` as soon
+/// as `batch_size` inputs have accumulated, or when `flush_interval` has
+/// elapsed since the last batch and at least one input is pending, whichever
+/// comes first. Inputs are delivered in the order they were processed.
+///
+/// The background task is started on the first call to `process`. When the
+/// pipeline shuts down, `shutdown` flushes the inputs still buffered and waits
+/// for the task to finish, so that no accepted input is lost.
+///
+/// # Error Handling
+///
+/// `process` only enqueues the input, so a failing batch cannot be reported to
+/// the update that contributed to it. Instead, the error of a failed batch is
+/// returned from the next call to `process`, or from `shutdown`, so that the
+/// pipe handles it according to its `ErrorPolicy`. With the default
+/// `ErrorPolicy::Abort`, the update that receives the error fails: the
+/// pipeline counts it in `updates_failed` and stops advancing its checkpoint,
+/// but keeps processing the updates that follow. Since batches are processed
+/// in the background, the update that fails is a later one than the updates
+/// that contributed to the batch, and the checkpoint may already have moved
+/// past them, so a restart does not necessarily process them again.
+///
+/// Failures are also logged and counted in the `processor_batches_failed`
+/// counter. The items of a failed batch are dropped; wrap the
+/// `BatchProcessor` in retry logic to retry them.
+///
+/// # Backpressure
+///
+/// At most `batch_size` inputs are queued for the background task; `process`
+/// waits while the queue is full.
+///
+/// # Defaults
+///
+/// - `batch_size`: 100
+/// - `flush_interval`: 1 second
+pub struct BatchingProcessor<P: BatchProcessor> {
+    pub(crate) processor: Option<P>,
+    batch_size: usize,
+    flush_interval: Duration,
+    worker: Option<BatchWorker<P::InputType>>,
+}
+
+struct BatchWorker<T> {
+    sender: mpsc::Sender<(T, Arc<MetricsCollection>)>,
+    failures: mpsc::UnboundedReceiver<Error>,
+    handle: JoinHandle<CarbonResult<()>>,
+}
+
+impl<P: BatchProcessor> BatchingProcessor<P> {
+    /// Wraps `processor` with the default batch size and flush interval.
+    pub fn new(processor: P) -> Self {
+        Self {
+            processor: Some(processor),
+            batch_size: 100,
+            flush_interval: Duration::from_secs(1),
+            worker: None,
+        }
+    }
+
+    /// Sets the number of inputs that triggers a flush. Values below `1` are
+    /// treated as `1`.
+    pub fn batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Sets the longest time an input waits in the buffer before it is
+    /// flushed.
+    pub fn flush_interval(mut self, flush_interval: Duration) -> Self {
+        self.flush_interval = flush_interval;
+        self
+    }
+}
+
+impl<P> BatchingProcessor<P>
+where
+    P: BatchProcessor + Send + Sync + 'static,
+    P::InputType: Send + 'static,
+{
+    /// Returns the background task, starting it if needed.
+    fn worker(&mut self) -> CarbonResult<&mut BatchWorker<P::InputType>> {
+        if self.worker.is_none() {
+            let processor = self.processor.take().ok_or_else(|| {
+                Error::Custom("batching processor has already been shut down".to_string())
+            })?;
+            let (sender, receiver) = mpsc::channel(self.batch_size);
+            let (failure_sender, failures) = mpsc::unbounded_channel();
+            let handle = tokio::spawn(run_batches(
+                processor,
+                receiver,
+                failure_sender,
+                self.batch_size,
+                self.flush_interval,
+            ));
+
+            self.worker = Some(BatchWorker {
+                sender,
+                failures,
+                handle,
+            });
+        }
+
+        Ok(self.worker.as_mut().expect("worker was started"))
+    }
+}
+
+#[async_trait]
+impl<P> Processor for BatchingProcessor<P>
+where
+    P: BatchProcessor + Send + Sync + 'static,
+    P::InputType: Send + 'static,
+{
+    type InputType = P::InputType;
+
+    async fn process(
+        &mut self,
+        data: Self::InputType,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        let worker = self.worker()?;
+
+        if let Ok(error) = worker.failures.try_recv() {
+            return Err(error);
+        }
+
+        worker
+            .sender
+            .send((data, metrics))
+            .await
+            .map_err(|_| Error::Custom("batching processor task has stopped".to_string()))
+    }
+
+    async fn shutdown(&mut self) -> CarbonResult<()> {
+        let Some(BatchWorker {
+            sender,
+            mut failures,
+            handle,
+        }) = self.worker.take()
+        else {
+            return Ok(());
+        };
+
+        // Closing the channel makes the task flush what is left and return.
+        drop(sender);
+
+        let result = handle
+            .await
+            .map_err(|error| Error::Custom(format!("batching processor task failed: {error}")))?;
+
+        // A failure not yet reported by `process` takes precedence over the
+        // result of the final flush, which happened after it.
+        match failures.try_recv() {
+            Ok(error) => Err(error),
+            Err(_) => result,
+        }
+    }
+}
+
+/// Receives inputs and flushes them in batches until the channel closes.
+///
+/// The errors of the batches flushed before the channel closes are sent to
+/// `failures`, while the result of the final flush is returned.
+async fn run_batches<P: BatchProcessor>(
+    processor: P,
+    mut receiver: mpsc::Receiver<(P::InputType, Arc<MetricsCollection>)>,
+    failures: mpsc::UnboundedSender<Error>,
+    batch_size: usize,
+    flush_interval: Duration,
+) -> CarbonResult<()> {
+    let mut batch = Vec::with_capacity(batch_size);
+    let mut batch_metrics = None;
+    let mut interval = tokio::time::interval_at(Instant::now() + flush_interval, flush_interval);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        tokio::select! {
+            item = receiver.recv() => match item {
+                Some((item, metrics)) => {
+                    batch.push(item);
+                    batch_metrics = Some(metrics);
+
+                    if batch.len() >= batch_size {
+                        if let Err(error) = flush_batch(&processor, &mut batch, &batch_metrics).await {
+                            failures.send(error).ok();
+                        }
+                        interval.reset();
+                    }
+                }
+                None => return flush_batch(&processor, &mut batch, &batch_metrics).await,
+            },
+            _ = interval.tick() => {
+                if let Err(error) = flush_batch(&processor, &mut batch, &batch_metrics).await {
+                    failures.send(error).ok();
+                }
+            }
+        }
+    }
+}
+
+/// Passes the buffered inputs to the processor, logging and counting failures.
+async fn flush_batch<P: BatchProcessor>(
+    processor: &P,
+    batch: &mut Vec<P::InputType>,
+    metrics: &Option<Arc<MetricsCollection>>,
+) -> CarbonResult<()> {
+    let Some(metrics) = metrics.as_ref().filter(|_| !batch.is_empty()) else {
+        return Ok(());
+    };
+
+    let items = std::mem::replace(batch, Vec::with_capacity(batch.capacity()));
+    let len = items.len();
+
+    match processor.process_batch(items, metrics.clone()).await {
+        Ok(()) => Ok(()),
+        Err(error) => {
+            log::error!("error processing batch of {} items: {:?}", len, error);
+            metrics
+                .increment_counter("processor_batches_failed", 1)
+                .await?;
+            Err(error)
+        }
+    }
+}
+
+/// A `Processor` whose input type is erased, so that processors of different
+/// input types can be stored together.
+///
+/// Every `Processor` with a `Send + 'static` input type implements this trait.
+/// `process_any` downcasts the input back to the processor's input type and
+/// fails without calling the processor if the input has another type. Use
+/// `input_type_id` to find out what to pass, or let a `ProcessorRegistry`
+/// route the inputs.
+///
+/// # Example
+///
+/// ```ignore
+/// let mut processor: Box<dyn ErasedProcessor> = Box::new(SwapWriter::new(pool));
+///
+/// if processor.input_type_id() == TypeId::of::<Swap>() {
+///     processor.process_any(Box::new(swap), metrics).await?;
+/// }
+/// ```
+#[async_trait]
+pub trait ErasedProcessor: Send + Sync {
+    /// Returns the `TypeId` of the processor's input type.
+    fn input_type_id(&self) -> TypeId;
+
+    /// Returns the name of the processor's input type, for diagnostics.
+    fn input_type_name(&self) -> &'static str;
+
+    /// Processes `data` if it is of the processor's input type, and returns an
+    /// error otherwise.
+    async fn process_any(
+        &mut self,
+        data: Box<dyn Any + Send>,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()>;
+
+    /// Shuts the processor down, as `Processor::shutdown` does.
+    async fn shutdown_any(&mut self) -> CarbonResult<()>;
+}
+
+#[async_trait]
+impl<P> ErasedProcessor for P
+where
+    P: Processor + Send + Sync,
+    P::InputType: Send + 'static,
+{
+    fn input_type_id(&self) -> TypeId {
+        TypeId::of::<P::InputType>()
+    }
+
+    fn input_type_name(&self) -> &'static str {
+        std::any::type_name::<P::InputType>()
+    }
+
+    async fn process_any(
+        &mut self,
+        data: Box<dyn Any + Send>,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        let data = data.downcast::<P::InputType>().map_err(|_| {
+            Error::Custom(format!(
+                "processor expects input of type {}",
+                self.input_type_name()
+            ))
+        })?;
+
+        self.process(*data, metrics).await
+    }
+
+    async fn shutdown_any(&mut self) -> CarbonResult<()> {
+        self.shutdown().await
+    }
+}
+
+/// Processors of different input types, keyed by the `TypeId` of their input.
+///
+/// `dispatch` hands a value to every processor registered for its type, in
+/// the same way as a `FanOutProcessor`, and ignores values no processor
+/// expects. This lets a generic dispatcher route decoded values of any type
+/// without knowing which processors were registered.
+///
+/// # Example
+///
+/// ```ignore
+/// let mut registry = ProcessorRegistry::new()
+///     .register(SwapWriter::new(pool))
+///     .register(TransferNotifier::new(url));
+///
+/// registry.dispatch(swap, metrics.clone()).await?;
+/// registry.dispatch(transfer, metrics).await?;
+/// ```
+#[derive(Default)]
+pub struct ProcessorRegistry {
+    processors: HashMap<TypeId, Vec<Box<dyn ErasedProcessor>>>,
+}
+
+impl ProcessorRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `processor` for the values of its input type.
+    pub fn register(self, processor: impl ErasedProcessor + 'static) -> Self {
+        self.register_boxed(Box::new(processor))
+    }
+
+    /// Registers an already boxed `processor` for the values of its input
+    /// type.
+    pub fn register_boxed(mut self, processor: Box<dyn ErasedProcessor>) -> Self {
+        self.processors
+            .entry(processor.input_type_id())
+            .or_default()
+            .push(processor);
+        self
+    }
+
+    /// Returns whether at least one processor expects values of type `T`.
+    pub fn handles<T: 'static>(&self) -> bool {
+        self.processors.contains_key(&TypeId::of::<T>())
+    }
+
+    /// Passes a clone of `data` to every processor registered for `T` and
+    /// returns how many there were.
+    ///
+    /// The processors run concurrently and all of them run even if some fail.
+    /// Their errors are returned together as `Error::FanOutFailed`.
+    pub async fn dispatch<T>(
+        &mut self,
+        data: T,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<usize>
+    where
+        T: Clone + Send + 'static,
+    {
+        let Some(processors) = self.processors.get_mut(&TypeId::of::<T>()) else {
+            return Ok(0);
+        };
+
+        join_fan_out(
+            processors
+                .iter_mut()
+                .map(|processor| processor.process_any(Box::new(data.clone()), metrics.clone())),
+        )
+        .await?;
+
+        Ok(processors.len())
+    }
+
+    /// Shuts down every registered processor.
+    pub async fn shutdown(&mut self) -> CarbonResult<()> {
+        join_fan_out(
+            self.processors
+                .values_mut()
+                .flatten()
+                .map(|processor| processor.shutdown_any()),
+        )
+        .await
+    }
+}
+
+/// Runs `futures` concurrently until all of them complete, and returns their
+/// errors in order as `Error::FanOutFailed` if any of them failed.
+async fn join_fan_out(
+    futures: impl IntoIterator<Item = impl Future<Output = CarbonResult<()>>>,
+) -> CarbonResult<()> {
+    let errors: Vec<Error> = join_all(futures)
+        .await
+        .into_iter()
+        .filter_map(Result::err)
+        .collect();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::FanOutFailed(errors))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            instruction::{
+                DecodedInstruction, InstructionMetadata, InstructionProcessorInputType,
+                NestedInstructions,
+            },
+            testing::RecordingMetrics,
+            transaction::TransactionMetadata,
+        },
+        solana_pubkey::Pubkey,
+        std::sync::{
+            atomic::{AtomicUsize, Ordering},
+            Mutex, MutexGuard,
+        },
+    };
+
+    struct RecordingProcessor(Arc<Mutex<Vec<u64>>>);
+
+    #[async_trait]
+    impl Processor for RecordingProcessor {
+        type InputType = u64;
+
+        async fn process(
+            &mut self,
+            data: Self::InputType,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            self.0.lock().unwrap().push(data);
+            Ok(())
+        }
+    }
+
+    struct FailingProcessor;
+
+    #[async_trait]
+    impl Processor for FailingProcessor {
+        type InputType = u64;
+
+        async fn process(
+            &mut self,
+            data: Self::InputType,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            Err(Error::Custom(format!("failed to process {data}")))
+        }
+    }
+
+    fn recorded(received: &Arc<Mutex<Vec<u64>>>) -> MutexGuard<'_, Vec<u64>> {
+        received.lock().unwrap()
+    }
+
+    #[test]
+    fn test_fan_out_forwards_input_to_every_child() {
+        let first = Arc::new(Mutex::new(Vec::new()));
+        let second = Arc::new(Mutex::new(Vec::new()));
+        let mut processor = FanOutProcessor::new(vec![
+            Box::new(RecordingProcessor(first.clone())),
+            Box::new(RecordingProcessor(second.clone())),
+        ]);
+        let metrics = Arc::new(MetricsCollection::new(vec![]));
+
+        futures::executor::block_on(async {
+            processor.process(1, metrics.clone()).await.unwrap();
+            processor.process(2, metrics.clone()).await.unwrap();
+        });
+
+        assert_eq!(*recorded(&first), vec![1, 2]);
+        assert_eq!(*recorded(&second), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_fan_out_runs_all_children_and_aggregates_errors() {
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let mut processor = FanOutProcessor::new(vec![
+            Box::new(FailingProcessor),
+            Box::new(RecordingProcessor(received.clone())),
+        ]);
+        let metrics = Arc::new(MetricsCollection::new(vec![]));
+
+        let result = futures::executor::block_on(processor.process(7, metrics));
+
+        match result {
+            Err(Error::FanOutFailed(errors)) => {
+                assert_eq!(errors.len(), 1);
+                assert!(
+                    matches!(&errors[0], Error::Custom(message) if message == "failed to process 7")
+                );
+            }
+            other => panic!("expected FanOutFailed, got {other:?}"),
+        }
+        assert_eq!(*recorded(&received), vec![7]);
+    }
+
+    struct FlakyProcessor {
+        failures: usize,
+        attempts: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl Processor for FlakyProcessor {
+        type InputType = u64;
+
+        async fn process(
+            &mut self,
+            _data: Self::InputType,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            let attempt = self.attempts.fetch_add(1, Ordering::SeqCst);
+            if attempt < self.failures {
+                Err(Error::Custom("connection reset".to_string()))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    fn flaky_retry_processor(
+        failures: usize,
+    ) -> (RetryProcessor<FlakyProcessor>, Arc<AtomicUsize>) {
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let processor = RetryProcessor::new(FlakyProcessor {
+            failures,
+            attempts: attempts.clone(),
+        })
+        .backoff(Duration::from_millis(1), Duration::from_millis(5));
+
+        (processor, attempts)
+    }
+
+    #[tokio::test]
+    async fn test_retry_succeeds_after_transient_failures() {
+        let (mut processor, attempts) = flaky_retry_processor(2);
+        let recorder = Arc::new(RecordingMetrics::default());
+        let metrics = Arc::new(MetricsCollection::new(vec![recorder.clone()]));
+
+        let result = processor.process(1, metrics).await;
+
+        assert!(result.is_ok());
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+        assert_eq!(recorder.counter("processor_retries"), 2);
+    }
+
+    #[tokio::test]
+    async fn test_retry_gives_up_after_max_retries() {
+        let (processor, attempts) = flaky_retry_processor(usize::MAX);
+        let mut processor = processor.max_retries(2);
+        let metrics = Arc::new(MetricsCollection::new(vec![]));
+
+        let result = processor.process(1, metrics).await;
+
+        assert!(matches!(result, Err(Error::Custom(_))));
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_retry_skips_permanent_errors() {
+        let (processor, attempts) = flaky_retry_processor(2);
+        let mut processor = processor.retry_if(|error| !matches!(error, Error::Custom(_)));
+        let recorder = Arc::new(RecordingMetrics::default());
+        let metrics = Arc::new(MetricsCollection::new(vec![recorder.clone()]));
+
+        let result = processor.process(1, metrics).await;
+
+        assert!(result.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+        assert_eq!(recorder.counter("processor_retries"), 0);
+    }
+
+    #[test]
+    fn test_retry_backoff_is_exponential_and_capped() {
+        let processor = RetryProcessor::new(FailingProcessor)
+            .backoff(Duration::from_millis(100), Duration::from_millis(500))
+            .jitter(0.0);
+
+        assert_eq!(processor.backoff_delay(0), Duration::from_millis(100));
+        assert_eq!(processor.backoff_delay(1), Duration::from_millis(200));
+        assert_eq!(processor.backoff_delay(2), Duration::from_millis(400));
+        assert_eq!(processor.backoff_delay(3), Duration::from_millis(500));
+        assert_eq!(processor.backoff_delay(40), Duration::from_millis(500));
+
+        let processor = processor.jitter(0.5);
+        for retry in 0..8 {
+            let delay = processor.backoff_delay(retry);
+            assert!(delay >= Duration::from_millis(50) && delay <= Duration::from_millis(500));
+        }
+    }
+
+    struct RecordingBatchProcessor(Arc<Mutex<Vec<Vec<u64>>>>);
+
+    #[async_trait]
+    impl BatchProcessor for RecordingBatchProcessor {
+        type InputType = u64;
+
+        async fn process_batch(
+            &self,
+            items: Vec<Self::InputType>,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            self.0.lock().unwrap().push(items);
+            Ok(())
+        }
+    }
+
+    fn batches(received: &Arc<Mutex<Vec<Vec<u64>>>>) -> Vec<Vec<u64>> {
+        received.lock().unwrap().clone()
+    }
+
+    /// Waits until `count` batches have been recorded, failing after a second.
+    async fn wait_for_batches(received: &Arc<Mutex<Vec<Vec<u64>>>>, count: usize) {
+        tokio::time::timeout(Duration::from_secs(1), async {
+            while batches(received).len() < count {
+                tokio::time::sleep(Duration::from_millis(1)).await;
+            }
+        })
+        .await
+        .expect("batches were not flushed in time");
+    }
+
+    #[tokio::test]
+    async fn test_batching_flushes_when_batch_size_is_reached() {
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let mut processor = BatchingProcessor::new(RecordingBatchProcessor(received.clone()))
+            .batch_size(3)
+            .flush_interval(Duration::from_secs(3600));
+        let metrics = Arc::new(MetricsCollection::new(vec![]));
+
+        for item in 0..7 {
+            processor.process(item, metrics.clone()).await.unwrap();
+        }
+        wait_for_batches(&received, 2).await;
+
+        assert_eq!(batches(&received), vec![vec![0, 1, 2], vec![3, 4, 5]]);
+
+        processor.shutdown().await.unwrap();
+
+        assert_eq!(
+            batches(&received),
+            vec![vec![0, 1, 2], vec![3, 4, 5], vec![6]]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_batching_flushes_when_flush_interval_elapses() {
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let mut processor = BatchingProcessor::new(RecordingBatchProcessor(received.clone()))
+            .batch_size(100)
+            .flush_interval(Duration::from_millis(20));
+        let metrics = Arc::new(MetricsCollection::new(vec![]));
+
+        processor.process(1, metrics.clone()).await.unwrap();
+        processor.process(2, metrics.clone()).await.unwrap();
+        wait_for_batches(&received, 1).await;
+
+        processor.process(3, metrics.clone()).await.unwrap();
+        wait_for_batches(&received, 2).await;
+
+        assert_eq!(batches(&received), vec![vec![1, 2], vec![3]]);
+
+        processor.shutdown().await.unwrap();
+
+        assert_eq!(batches(&received), vec![vec![1, 2], vec![3]]);
+    }
+
+    struct FailingBatchProcessor;
+
+    #[async_trait]
+    impl BatchProcessor for FailingBatchProcessor {
+        type InputType = u64;
+
+        async fn process_batch(
+            &self,
+            _items: Vec<Self::InputType>,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            Err(Error::Custom("insert failed".to_string()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_batching_reports_failed_batches_to_the_caller() {
+        let mut processor = BatchingProcessor::new(FailingBatchProcessor)
+            .batch_size(1)
+            .flush_interval(Duration::from_secs(3600));
+        let metrics = Arc::new(MetricsCollection::new(vec![]));
+
+        processor.process(1, metrics.clone()).await.unwrap();
+        let result = tokio::time::timeout(Duration::from_secs(1), async {
+            loop {
+                if let Err(error) = processor.process(2, metrics.clone()).await {
+                    break error;
+                }
+                tokio::time::sleep(Duration::from_millis(1)).await;
+            }
+        })
+        .await
+        .expect("the failed batch was not reported in time");
+
+        assert!(matches!(result, Error::Custom(message) if message == "insert failed"));
+    }
+
+    #[tokio::test]
+    async fn test_batching_shutdown_reports_a_failed_final_flush() {
+        let mut processor = BatchingProcessor::new(FailingBatchProcessor);
+        let metrics = Arc::new(MetricsCollection::new(vec![]));
+
+        processor.process(1, metrics).await.unwrap();
+
+        assert!(processor.shutdown().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_batching_shutdown_without_input_does_nothing() {
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let mut processor = BatchingProcessor::new(RecordingBatchProcessor(received.clone()));
+
+        processor.shutdown().await.unwrap();
+
+        assert!(batches(&received).is_empty());
+    }
+
+    /// An instruction of a perpetuals program, as its decoder produces it.
+    enum PerpInstruction {
+        PlacePerpOrder,
+        CancelOrder,
+    }
+
+    /// An instruction of a swap program, produced by another decoder.
+    enum SwapInstruction {
+        Swap,
+    }
+
+    /// The domain event a single processor handles for both programs.
+    #[derive(Debug, PartialEq)]
+    struct InstructionSummary {
+        program: Pubkey,
+        kind: &'static str,
+    }
+
+    struct SummaryProcessor(Arc<Mutex<Vec<InstructionSummary>>>);
+
+    #[async_trait]
+    impl Processor for SummaryProcessor {
+        type InputType = InstructionSummary;
+
+        async fn process(
+            &mut self,
+            data: Self::InputType,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            self.0.lock().unwrap().push(data);
+            Ok(())
+        }
+    }
+
+    fn instruction_input<T>(program_id: Pubkey, data: T) -> InstructionProcessorInputType<T> {
+        (
+            InstructionMetadata {
+                transaction_metadata: TransactionMetadata::default(),
+                stack_height: 1,
+                index: 0,
+                accounts_resolved: true,
+                compute_units: None,
+                absolute_path: vec![0],
+                transaction_context: None,
+            },
+            DecodedInstruction {
+                program_id,
+                data,
+                accounts: vec![],
+                discriminator: vec![],
+                raw_data: vec![],
+                raw_len: 0,
+                num_accounts: 0,
+            },
+            NestedInstructions::default(),
+        )
+    }
+
+    #[test]
+    fn test_map_converts_input_before_processing() {
+        // Arrange
+        let summaries = Arc::new(Mutex::new(Vec::new()));
+        let mut perp_processor = MapProcessor::new(
+            SummaryProcessor(summaries.clone()),
+            |(_, instruction, _): InstructionProcessorInputType<PerpInstruction>| {
+                InstructionSummary {
+                    program: instruction.program_id,
+                    kind: match instruction.data {
+                        PerpInstruction::PlacePerpOrder => "place_perp_order",
+                        PerpInstruction::CancelOrder => "cancel_order",
+                    },
+                }
+            },
+        );
+        let mut swap_processor = MapProcessor::new(
+            SummaryProcessor(summaries.clone()),
+            |(_, instruction, _): InstructionProcessorInputType<SwapInstruction>| {
+                InstructionSummary {
+                    program: instruction.program_id,
+                    kind: match instruction.data {
+                        SwapInstruction::Swap => "swap",
+                    },
+                }
+            },
+        );
+        let (perp_program, swap_program) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let place_order = instruction_input(perp_program, PerpInstruction::PlacePerpOrder);
+        let swap = instruction_input(swap_program, SwapInstruction::Swap);
+        let cancel_order = instruction_input(perp_program, PerpInstruction::CancelOrder);
+        let metrics = Arc::new(MetricsCollection::new(vec![]));
+
+        // Act
+        futures::executor::block_on(async {
+            perp_processor
+                .process(place_order, metrics.clone())
+                .await
+                .unwrap();
+            swap_processor.process(swap, metrics.clone()).await.unwrap();
+            perp_processor
+                .process(cancel_order, metrics.clone())
+                .await
+                .unwrap();
+        });
+
+        // Assert
+        assert_eq!(
+            *summaries.lock().unwrap(),
+            vec![
+                InstructionSummary {
+                    program: perp_program,
+                    kind: "place_perp_order",
+                },
+                InstructionSummary {
+                    program: swap_program,
+                    kind: "swap",
+                },
+                InstructionSummary {
+                    program: perp_program,
+                    kind: "cancel_order",
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_filter_processes_only_accepted_inputs() {
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let mut processor =
+            FilterProcessor::new(RecordingProcessor(received.clone()), |value: &u64| {
+                value % 2 == 0
+            });
+        let recorder = Arc::new(RecordingMetrics::default());
+        let metrics = Arc::new(MetricsCollection::new(vec![recorder.clone()]));
+
+        for value in 1..=5 {
+            processor.process(value, metrics.clone()).await.unwrap();
+        }
+
+        assert_eq!(*recorded(&received), vec![2, 4]);
+        assert_eq!(recorder.counter("filtered_out"), 3);
+    }
+
+    struct SleepingProcessor {
+        delay: Duration,
+        completed: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl Processor for SleepingProcessor {
+        type InputType = u64;
+
+        async fn process(
+            &mut self,
+            _data: Self::InputType,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            tokio::time::sleep(self.delay).await;
+            self.completed.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    struct RecordingStringProcessor(Arc<Mutex<Vec<String>>>);
+
+    #[async_trait]
+    impl Processor for RecordingStringProcessor {
+        type InputType = String;
+
+        async fn process(
+            &mut self,
+            data: Self::InputType,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            self.0.lock().unwrap().push(data);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_registry_routes_values_by_input_type() {
+        // Arrange
+        let numbers = Arc::new(Mutex::new(Vec::new()));
+        let strings = Arc::new(Mutex::new(Vec::new()));
+        let mut registry = ProcessorRegistry::new()
+            .register(RecordingProcessor(numbers.clone()))
+            .register(RecordingStringProcessor(strings.clone()));
+        let metrics = Arc::new(MetricsCollection::new(vec![]));
+
+        // Act
+        let number_processors = registry.dispatch(7u64, metrics.clone()).await.unwrap();
+        let string_processors = registry
+            .dispatch("swap".to_string(), metrics.clone())
+            .await
+            .unwrap();
+        let unhandled = registry.dispatch(true, metrics.clone()).await.unwrap();
+        let mismatched = RecordingProcessor(numbers.clone())
+            .process_any(Box::new("swap"), metrics)
+            .await;
+
+        // Assert
+        assert_eq!((number_processors, string_processors, unhandled), (1, 1, 0));
+        assert_eq!(*recorded(&numbers), vec![7]);
+        assert_eq!(*strings.lock().unwrap(), vec!["swap".to_string()]);
+        assert!(registry.handles::<u64>() && !registry.handles::<bool>());
+        assert!(matches!(mismatched, Err(Error::Custom(_))));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_timeout_fails_and_cancels_slow_processor() {
+        let completed = Arc::new(AtomicUsize::new(0));
+        let mut processor = TimeoutProcessor::new(
+            SleepingProcessor {
+                delay: Duration::from_secs(3600),
+                completed: completed.clone(),
+            },
+            Duration::from_secs(1),
+        );
+        let metrics = Arc::new(MetricsCollection::new(vec![]));
+
+        let result = processor.process(1, metrics).await;
+        tokio::time::sleep(Duration::from_secs(7200)).await;
+
+        assert!(
+            matches!(result, Err(Error::Timeout(timeout)) if timeout == Duration::from_secs(1))
+        );
+        // The timed out call was dropped rather than left running.
+        assert_eq!(completed.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_timeout_passes_through_processor_within_deadline() {
+        let completed = Arc::new(AtomicUsize::new(0));
+        let mut processor = TimeoutProcessor::new(
+            SleepingProcessor {
+                delay: Duration::from_millis(10),
+                completed: completed.clone(),
+            },
+            Duration::from_secs(1),
+        );
+        let metrics = Arc::new(MetricsCollection::new(vec![]));
+
+        let result = processor.process(1, metrics).await;
+
+        assert!(result.is_ok());
+        assert_eq!(completed.load(Ordering::SeqCst), 1);
+    }
 }
@@ -0,0 +1,215 @@
+//! Limits the rate at which a datasource delivers updates, to stay within the
+//! request quota of an RPC provider.
+//!
+//! [`RateLimitedDatasource`] wraps any [`Datasource`] and lets its updates
+//! through a token bucket: up to `burst` updates pass immediately, after which
+//! updates are released at `rate` per second. The wrapped datasource sends to a
+//! channel with room for a single update, so once the bucket is empty it blocks
+//! on its next send. Datasources that fetch each update with a request, such as
+//! RPC crawlers and backfills, therefore issue requests no faster than `rate`.
+//! Datasources that fetch many updates per request, or that are pushed updates
+//! over a subscription, are slowed down but their request rate is not bounded
+//! one-to-one.
+//!
+//! ```ignore
+//! let pipeline = Pipeline::builder()
+//!     .datasource(RateLimitedDatasource::new(crawler, 50, 100))
+//!     .instruction(DriftDecoder, DriftInstructionProcessor)
+//!     .build()?;
+//! ```
+
+use {
+    crate::{
+        datasource::{Datasource, Update, UpdateType},
+        error::CarbonResult,
+        metrics::MetricsCollection,
+    },
+    async_trait::async_trait,
+    std::{sync::Arc, time::Duration},
+    tokio::{sync::mpsc, time::Instant},
+    tokio_util::sync::CancellationToken,
+};
+
+/// A [`Datasource`] that delivers the updates of `D` at no more than `rate`
+/// per second, with bursts of up to `burst` updates.
+pub struct RateLimitedDatasource<D> {
+    datasource: D,
+    rate: u32,
+    burst: u32,
+}
+
+impl<D: Datasource> RateLimitedDatasource<D> {
+    /// Wraps `datasource`, limiting it to `rate` updates per second after an
+    /// initial burst of `burst` updates. Both are raised to 1 if zero.
+    pub fn new(datasource: D, rate: u32, burst: u32) -> Self {
+        Self {
+            datasource,
+            rate: rate.max(1),
+            burst: burst.max(1),
+        }
+    }
+}
+
+#[async_trait]
+impl<D: Datasource> Datasource for RateLimitedDatasource<D> {
+    async fn consume(
+        &self,
+        sender: &mpsc::Sender<Update>,
+        cancellation_token: CancellationToken,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        let (limited_sender, mut limited_receiver) = mpsc::channel(1);
+        let mut bucket = TokenBucket::new(self.rate, self.burst);
+
+        let consume = {
+            let cancellation_token = cancellation_token.clone();
+            async move {
+                self.datasource
+                    .consume(&limited_sender, cancellation_token, metrics)
+                    .await
+            }
+        };
+
+        // Keeps forwarding after `consume` returns, since datasources may
+        // keep sending from tasks they spawned until they drop their senders.
+        let forward = async {
+            loop {
+                let update = tokio::select! {
+                    _ = cancellation_token.cancelled() => break,
+                    update = limited_receiver.recv() => match update {
+                        Some(update) => update,
+                        None => break,
+                    },
+                };
+
+                tokio::select! {
+                    _ = cancellation_token.cancelled() => break,
+                    _ = bucket.acquire() => {}
+                }
+
+                if sender.send(update).await.is_err() {
+                    break;
+                }
+            }
+        };
+
+        let (result, ()) = tokio::join!(consume, forward);
+        result
+    }
+
+    fn update_types(&self) -> Vec<UpdateType> {
+        self.datasource.update_types()
+    }
+
+    fn resume_from_slot(&self, slot: u64) {
+        self.datasource.resume_from_slot(slot);
+    }
+}
+
+/// Holds up to `burst` tokens, refilled continuously at `rate` tokens per
+/// second. Uses Tokio's clock so that tests can pause and advance time.
+struct TokenBucket {
+    rate: f64,
+    burst: f64,
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn new(rate: u32, burst: u32) -> Self {
+        Self {
+            rate: f64::from(rate),
+            burst: f64::from(burst),
+            tokens: f64::from(burst),
+            last_refill: Instant::now(),
+        }
+    }
+
+    /// Takes a token, waiting for one to be refilled if the bucket is empty.
+    async fn acquire(&mut self) {
+        self.refill();
+        if self.tokens < 1.0 {
+            let wait = Duration::from_secs_f64((1.0 - self.tokens) / self.rate);
+            tokio::time::sleep(wait).await;
+            self.refill();
+        }
+        self.tokens -= 1.0;
+    }
+
+    fn refill(&mut self) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.burst);
+        self.last_refill = now;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::datasource::AccountDeletion, solana_pubkey::Pubkey};
+
+    struct DeletionDatasource {
+        updates: u64,
+    }
+
+    #[async_trait]
+    impl Datasource for DeletionDatasource {
+        async fn consume(
+            &self,
+            sender: &mpsc::Sender<Update>,
+            _cancellation_token: CancellationToken,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            for slot in 0..self.updates {
+                let update = Update::AccountDeletion(AccountDeletion {
+                    pubkey: Pubkey::new_unique(),
+                    slot,
+                    commitment: None,
+                    source: None,
+                });
+                if sender.send(update).await.is_err() {
+                    break;
+                }
+            }
+
+            Ok(())
+        }
+
+        fn update_types(&self) -> Vec<UpdateType> {
+            vec![UpdateType::AccountDeletion]
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_rate_limited_datasource_spreads_updates_over_time() {
+        // Arrange
+        let datasource = RateLimitedDatasource::new(DeletionDatasource { updates: 25 }, 10, 5);
+        let (sender, mut receiver) = mpsc::channel(100);
+        let metrics = Arc::new(MetricsCollection::new(vec![]));
+        let start = Instant::now();
+
+        // Act
+        let consume = datasource.consume(&sender, CancellationToken::new(), metrics);
+        let receive = async {
+            let mut elapsed = Vec::new();
+            while elapsed.len() < 25 {
+                receiver.recv().await.expect("update");
+                elapsed.push(start.elapsed());
+            }
+            elapsed
+        };
+        let (result, elapsed) = tokio::join!(consume, receive);
+
+        // Assert
+        result.unwrap();
+        assert!(elapsed[..5].iter().all(|elapsed| elapsed.is_zero()));
+        for (i, elapsed) in elapsed.iter().enumerate().skip(5) {
+            let expected = Duration::from_millis(100 * (i as u64 - 4));
+            assert!(
+                elapsed.abs_diff(expected) < Duration::from_millis(1),
+                "update {i} delivered after {elapsed:?}, expected {expected:?}"
+            );
+        }
+        assert_eq!(datasource.update_types(), vec![UpdateType::AccountDeletion]);
+    }
+}
@@ -0,0 +1,628 @@
+//! Decodes single instructions with any of a set of registered decoders,
+//! without running a pipeline.
+//!
+//! A [`DecoderRegistry`] holds decoders of different instruction types, keyed
+//! by the program they decode. Since the decoded types differ, the registry
+//! returns the decoded data as a `serde_json::Value` produced by the
+//! instruction type's `Serialize` implementation. This suits services that
+//! explain arbitrary instructions, such as a REST endpoint taking a program ID,
+//! accounts and data.
+//!
+//! ```ignore
+//! let registry = DecoderRegistry::new()
+//!     .register(carbon_drift_v2_decoder::PROGRAM_ID, DriftDecoder)
+//!     .register(carbon_jupiter_swap_decoder::PROGRAM_ID, JupiterSwapDecoder);
+//!
+//! if let Some(decoded) = registry.try_decode(&instruction)? {
+//!     println!("{}", decoded.data);
+//! }
+//! ```
+//!
+//! The registry also converts the inner instructions of transactions fetched
+//! with the `jsonParsed` encoding. The RPC parses the instructions of the
+//! programs it knows, such as the System and Token programs, and leaves the
+//! rest as raw data. [`DecoderRegistry::decode_json_parsed_inner_instructions`]
+//! decodes the raw instructions with the registered decoders and keeps the
+//! RPC's parsed form for the others.
+//!
+//! For offline analysis, [`decode_versioned_transaction`] decodes every
+//! instruction of a `VersionedTransaction`, legacy or v0, without assembling a
+//! pipeline:
+//!
+//! ```ignore
+//! for decoded in decode_versioned_transaction(&transaction, Some(loaded_addresses), &registry)? {
+//!     println!("{:?} {}", decoded.metadata.absolute_path, decoded.instruction.data);
+//! }
+//! ```
+
+use {
+    crate::{
+        datasource::TransactionUpdate,
+        error::{CarbonResult, Error},
+        instruction::{
+            DecodedInstruction, InstructionDecoder, InstructionMetadata, NestedInstructions,
+        },
+        transaction::TransactionMetadata,
+        transformers::{decode_instruction_data, extract_instructions_with_metadata},
+    },
+    serde::Serialize,
+    solana_instruction::{AccountMeta, Instruction},
+    solana_program::message::v0::LoadedAddresses,
+    solana_pubkey::Pubkey,
+    solana_sdk::transaction::VersionedTransaction,
+    solana_transaction_status::{
+        TransactionStatusMeta, UiInnerInstructions, UiInstruction, UiParsedInstruction,
+        UiTransactionEncoding,
+    },
+    std::{collections::HashMap, str::FromStr},
+};
+
+/// An `InstructionDecoder` whose instruction type is erased to JSON.
+trait JsonInstructionDecoder: Send + Sync {
+    fn try_decode_json(
+        &self,
+        instruction: &Instruction,
+    ) -> CarbonResult<Option<DecodedInstruction<serde_json::Value>>>;
+}
+
+impl<D> JsonInstructionDecoder for D
+where
+    D: for<'a> InstructionDecoder<'a> + Send + Sync,
+    for<'a> <D as InstructionDecoder<'a>>::InstructionType: Serialize,
+{
+    fn try_decode_json(
+        &self,
+        instruction: &Instruction,
+    ) -> CarbonResult<Option<DecodedInstruction<serde_json::Value>>> {
+        let Some(decoded_instruction) = self.try_decode_instruction(instruction)? else {
+            return Ok(None);
+        };
+
+        Ok(Some(DecodedInstruction {
+            program_id: decoded_instruction.program_id,
+            data: serde_json::to_value(&decoded_instruction.data)?,
+            accounts: decoded_instruction.accounts,
+            discriminator: decoded_instruction.discriminator,
+            raw_len: decoded_instruction.raw_len,
+            num_accounts: decoded_instruction.num_accounts,
+            raw_data: decoded_instruction.raw_data,
+        }))
+    }
+}
+
+/// Decoders of different instruction types, keyed by program ID.
+///
+/// Several decoders may be registered for the same program, for example for
+/// different versions of it. They are tried in registration order and the
+/// first match is returned.
+#[derive(Default)]
+pub struct DecoderRegistry {
+    decoders: HashMap<Pubkey, Vec<Box<dyn JsonInstructionDecoder>>>,
+}
+
+impl DecoderRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `decoder` for the instructions of `program_id`.
+    pub fn register<D>(mut self, program_id: Pubkey, decoder: D) -> Self
+    where
+        D: for<'a> InstructionDecoder<'a> + Send + Sync + 'static,
+        for<'a> <D as InstructionDecoder<'a>>::InstructionType: Serialize,
+    {
+        self.decoders
+            .entry(program_id)
+            .or_default()
+            .push(Box::new(decoder));
+        self
+    }
+
+    /// Returns the program IDs with at least one registered decoder.
+    pub fn program_ids(&self) -> impl Iterator<Item = &Pubkey> {
+        self.decoders.keys()
+    }
+
+    /// Decodes `instruction` with the decoders registered for its program.
+    ///
+    /// Returns `Ok(None)` if no decoder recognizes the instruction, and an
+    /// error if the matching decoder fails to decode it or its instruction
+    /// type fails to serialize.
+    pub fn try_decode(
+        &self,
+        instruction: &Instruction,
+    ) -> CarbonResult<Option<DecodedInstruction<serde_json::Value>>> {
+        let Some(decoders) = self.decoders.get(&instruction.program_id) else {
+            return Ok(None);
+        };
+
+        for decoder in decoders {
+            if let Some(decoded_instruction) = decoder.try_decode_json(instruction)? {
+                return Ok(Some(decoded_instruction));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Like `try_decode`, but treats errors as no match.
+    pub fn decode(
+        &self,
+        instruction: &Instruction,
+    ) -> Option<DecodedInstruction<serde_json::Value>> {
+        self.try_decode(instruction).ok().flatten()
+    }
+
+    /// Converts the inner instructions of a transaction fetched with the
+    /// `jsonParsed` encoding, keeping the stack heights reported by the RPC.
+    ///
+    /// Instructions the RPC left as raw data are decoded with the registered
+    /// decoders. Instructions the RPC parsed carry no raw data, so their parsed
+    /// form is kept even if a decoder is registered for their program.
+    ///
+    /// The RPC does not report whether the accounts of a raw inner instruction
+    /// are signers or writable, so their account metas have both flags unset.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an instruction is compiled rather than parsed, if
+    /// its program ID, accounts or data are malformed, or if a matching
+    /// decoder fails.
+    pub fn decode_json_parsed_inner_instructions(
+        &self,
+        inner_instructions: &[UiInnerInstructions],
+    ) -> CarbonResult<Vec<JsonParsedInnerInstructions>> {
+        inner_instructions
+            .iter()
+            .map(|inner_instruction_group| {
+                Ok(JsonParsedInnerInstructions {
+                    index: inner_instruction_group.index,
+                    instructions: inner_instruction_group
+                        .instructions
+                        .iter()
+                        .map(|ui_instruction| self.decode_json_parsed_instruction(ui_instruction))
+                        .collect::<CarbonResult<_>>()?,
+                })
+            })
+            .collect()
+    }
+
+    fn decode_json_parsed_instruction(
+        &self,
+        ui_instruction: &UiInstruction,
+    ) -> CarbonResult<JsonParsedInnerInstruction> {
+        match ui_instruction {
+            UiInstruction::Parsed(UiParsedInstruction::Parsed(parsed_instruction)) => {
+                Ok(JsonParsedInnerInstruction {
+                    program_id: parse_pubkey(&parsed_instruction.program_id)?,
+                    stack_height: parsed_instruction.stack_height,
+                    data: JsonParsedData::Rpc {
+                        program: parsed_instruction.program.clone(),
+                        parsed: parsed_instruction.parsed.clone(),
+                    },
+                })
+            }
+            UiInstruction::Parsed(UiParsedInstruction::PartiallyDecoded(raw_instruction)) => {
+                let instruction = Instruction {
+                    program_id: parse_pubkey(&raw_instruction.program_id)?,
+                    accounts: raw_instruction
+                        .accounts
+                        .iter()
+                        .map(|account| Ok(AccountMeta::new_readonly(parse_pubkey(account)?, false)))
+                        .collect::<CarbonResult<_>>()?,
+                    data: decode_instruction_data(
+                        UiTransactionEncoding::JsonParsed,
+                        &raw_instruction.data,
+                    )?,
+                };
+
+                Ok(JsonParsedInnerInstruction {
+                    program_id: instruction.program_id,
+                    stack_height: raw_instruction.stack_height,
+                    data: match self.try_decode(&instruction)? {
+                        Some(decoded_instruction) => JsonParsedData::Decoded(decoded_instruction),
+                        None => JsonParsedData::Undecoded(instruction),
+                    },
+                })
+            }
+            UiInstruction::Compiled(_) => Err(Error::FailedToDeserialize(
+                "Compiled inner instruction in a jsonParsed transaction".to_string(),
+            )),
+        }
+    }
+}
+
+fn parse_pubkey(pubkey: &str) -> CarbonResult<Pubkey> {
+    Pubkey::from_str(pubkey)
+        .map_err(|err| Error::FailedToDeserialize(format!("Invalid pubkey {}: {}", pubkey, err)))
+}
+
+/// The inner instructions invoked by one top-level instruction of a
+/// `jsonParsed` transaction.
+#[derive(Debug, Clone)]
+pub struct JsonParsedInnerInstructions {
+    /// The index of the top-level instruction.
+    pub index: u8,
+    pub instructions: Vec<JsonParsedInnerInstruction>,
+}
+
+/// An inner instruction of a `jsonParsed` transaction.
+#[derive(Debug, Clone)]
+pub struct JsonParsedInnerInstruction {
+    pub program_id: Pubkey,
+    /// The stack height reported by the RPC, if any.
+    pub stack_height: Option<u32>,
+    pub data: JsonParsedData,
+}
+
+/// The data of an inner instruction of a `jsonParsed` transaction.
+#[derive(Debug, Clone)]
+pub enum JsonParsedData {
+    /// Raw data decoded by a registered decoder.
+    Decoded(DecodedInstruction<serde_json::Value>),
+    /// Data parsed by the RPC, which does not return the raw data.
+    Rpc {
+        program: String,
+        parsed: serde_json::Value,
+    },
+    /// Raw data that no registered decoder recognizes.
+    Undecoded(Instruction),
+}
+
+/// An instruction of a transaction decoded by a `DecoderRegistry`, with its
+/// position in the transaction.
+#[derive(Debug, Clone)]
+pub struct DecodedAny {
+    pub metadata: InstructionMetadata,
+    pub instruction: DecodedInstruction<serde_json::Value>,
+}
+
+/// Decodes the instructions of `transaction` with the decoders of `registry`,
+/// without running a pipeline.
+///
+/// The instructions are nested as in a pipeline and returned in execution
+/// order, skipping those no registered decoder recognizes. A transaction on its
+/// own carries no status metadata, so only its top-level instructions are
+/// decoded, and the metadata of the returned instructions has a default
+/// `TransactionStatusMeta` and a slot of 0.
+///
+/// The accounts of a v0 transaction loaded from address lookup tables are
+/// only known if `loaded_addresses` is given, writable addresses first, as
+/// the lookup tables are not read here. Otherwise, they are left as default
+/// public keys and `accounts_resolved` is `false`.
+///
+/// # Errors
+///
+/// Returns an error if the transaction has no fee payer or if a matching
+/// decoder fails.
+pub fn decode_versioned_transaction(
+    transaction: &VersionedTransaction,
+    loaded_addresses: Option<LoadedAddresses>,
+    registry: &DecoderRegistry,
+) -> CarbonResult<Vec<DecodedAny>> {
+    let transaction_update = TransactionUpdate {
+        signature: transaction.signatures.first().copied().unwrap_or_default(),
+        transaction: transaction.clone(),
+        meta: TransactionStatusMeta {
+            loaded_addresses: loaded_addresses.unwrap_or_default(),
+            ..Default::default()
+        },
+        is_vote: false,
+        slot: 0,
+        block_time: None,
+        commitment: None,
+        source: None,
+    };
+    let transaction_metadata: TransactionMetadata = transaction_update.clone().try_into()?;
+    let nested_instructions: NestedInstructions =
+        extract_instructions_with_metadata(&transaction_metadata, &transaction_update)?.into();
+
+    nested_instructions
+        .into_flattened()
+        .into_iter()
+        .filter_map(|nested_instruction| {
+            registry
+                .try_decode(&nested_instruction.instruction)
+                .map(|decoded_instruction| {
+                    decoded_instruction.map(|instruction| DecodedAny {
+                        metadata: nested_instruction.metadata.clone(),
+                        instruction,
+                    })
+                })
+                .transpose()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        serde_json::json,
+        solana_instruction::AccountMeta,
+        solana_program::{
+            hash::Hash,
+            message::{v0, Message, VersionedMessage},
+        },
+        solana_signature::Signature,
+    };
+
+    #[derive(Serialize)]
+    struct Deposit {
+        amount: u64,
+    }
+
+    #[derive(Serialize)]
+    enum VaultInstruction {
+        Deposit(Deposit),
+    }
+
+    struct VaultDecoder;
+
+    impl InstructionDecoder<'_> for VaultDecoder {
+        type InstructionType = VaultInstruction;
+
+        fn try_decode_instruction(
+            &self,
+            instruction: &Instruction,
+        ) -> CarbonResult<Option<DecodedInstruction<Self::InstructionType>>> {
+            let Some((&discriminator, amount)) = instruction.data.split_first() else {
+                return Ok(None);
+            };
+            let (1, Ok(amount)) = (discriminator, amount.try_into()) else {
+                return Ok(None);
+            };
+
+            Ok(Some(DecodedInstruction {
+                program_id: instruction.program_id,
+                data: VaultInstruction::Deposit(Deposit {
+                    amount: u64::from_le_bytes(amount),
+                }),
+                accounts: instruction.accounts.clone(),
+                discriminator: vec![discriminator],
+                raw_len: instruction.data.len(),
+                num_accounts: instruction.accounts.len(),
+                raw_data: instruction.data.clone(),
+            }))
+        }
+    }
+
+    #[derive(Serialize)]
+    struct Memo {
+        text: String,
+    }
+
+    struct MemoDecoder;
+
+    impl InstructionDecoder<'_> for MemoDecoder {
+        type InstructionType = Memo;
+
+        fn try_decode_instruction(
+            &self,
+            instruction: &Instruction,
+        ) -> CarbonResult<Option<DecodedInstruction<Self::InstructionType>>> {
+            let Ok(text) = String::from_utf8(instruction.data.clone()) else {
+                return Ok(None);
+            };
+
+            Ok(Some(DecodedInstruction {
+                program_id: instruction.program_id,
+                data: Memo { text },
+                accounts: instruction.accounts.clone(),
+                discriminator: vec![],
+                raw_len: instruction.data.len(),
+                num_accounts: instruction.accounts.len(),
+                raw_data: instruction.data.clone(),
+            }))
+        }
+    }
+
+    #[test]
+    fn test_registry_decodes_instructions_of_each_program() {
+        // Arrange
+        let vault_program_id = Pubkey::new_unique();
+        let memo_program_id = Pubkey::new_unique();
+        let registry = DecoderRegistry::new()
+            .register(vault_program_id, VaultDecoder)
+            .register(memo_program_id, MemoDecoder);
+        let depositor = AccountMeta::new(Pubkey::new_unique(), true);
+        let deposit = Instruction {
+            program_id: vault_program_id,
+            accounts: vec![depositor.clone()],
+            data: [vec![1], 500u64.to_le_bytes().to_vec()].concat(),
+        };
+        let memo = Instruction {
+            program_id: memo_program_id,
+            accounts: vec![],
+            data: b"gm".to_vec(),
+        };
+        let unknown_program = Instruction {
+            program_id: Pubkey::new_unique(),
+            ..memo.clone()
+        };
+
+        // Act
+        let decoded_deposit = registry.decode(&deposit).expect("vault instruction");
+        let decoded_memo = registry.decode(&memo).expect("memo instruction");
+
+        // Assert
+        assert_eq!(decoded_deposit.program_id, vault_program_id);
+        assert_eq!(
+            decoded_deposit.data,
+            json!({ "Deposit": { "amount": 500 } })
+        );
+        assert_eq!(decoded_deposit.accounts, vec![depositor]);
+        assert_eq!(decoded_memo.program_id, memo_program_id);
+        assert_eq!(decoded_memo.data, json!({ "text": "gm" }));
+        assert!(registry.decode(&unknown_program).is_none());
+    }
+
+    #[test]
+    fn test_decode_versioned_transaction_decodes_legacy_and_v0_instructions() {
+        // Arrange
+        let vault_program_id = Pubkey::new_unique();
+        let memo_program_id = Pubkey::new_unique();
+        let registry = DecoderRegistry::new()
+            .register(vault_program_id, VaultDecoder)
+            .register(memo_program_id, MemoDecoder);
+        let fee_payer = Pubkey::new_unique();
+        let vault = Pubkey::new_unique();
+        let instructions = [
+            Instruction {
+                program_id: vault_program_id,
+                accounts: vec![
+                    AccountMeta::new(fee_payer, true),
+                    AccountMeta::new(vault, false),
+                ],
+                data: [vec![1], 500u64.to_le_bytes().to_vec()].concat(),
+            },
+            Instruction {
+                program_id: Pubkey::new_unique(),
+                accounts: vec![],
+                data: vec![9],
+            },
+            Instruction {
+                program_id: memo_program_id,
+                accounts: vec![],
+                data: b"gm".to_vec(),
+            },
+        ];
+        let lookup_table = solana_sdk::address_lookup_table::AddressLookupTableAccount {
+            key: Pubkey::new_unique(),
+            addresses: vec![vault],
+        };
+        let transaction = |message: VersionedMessage| VersionedTransaction {
+            signatures: vec![Signature::new_unique()],
+            message,
+        };
+        let legacy = transaction(VersionedMessage::Legacy(Message::new(
+            &instructions,
+            Some(&fee_payer),
+        )));
+        let v0 = transaction(VersionedMessage::V0(
+            v0::Message::try_compile(&fee_payer, &instructions, &[lookup_table], Hash::default())
+                .expect("compile v0 message"),
+        ));
+
+        // Act
+        let decoded_legacy = decode_versioned_transaction(&legacy, None, &registry).unwrap();
+        let decoded_v0 = decode_versioned_transaction(
+            &v0,
+            Some(LoadedAddresses {
+                writable: vec![vault],
+                readonly: vec![],
+            }),
+            &registry,
+        )
+        .unwrap();
+        let decoded_v0_without_addresses =
+            decode_versioned_transaction(&v0, None, &registry).unwrap();
+
+        // Assert
+        for decoded in [&decoded_legacy, &decoded_v0] {
+            assert_eq!(decoded.len(), 2);
+            assert_eq!(decoded[0].metadata.absolute_path, vec![0]);
+            assert!(decoded[0].metadata.accounts_resolved);
+            assert_eq!(
+                decoded[0].instruction.data,
+                json!({ "Deposit": { "amount": 500 } })
+            );
+            assert_eq!(decoded[0].instruction.accounts, instructions[0].accounts);
+            assert_eq!(decoded[1].metadata.absolute_path, vec![2]);
+            assert_eq!(decoded[1].instruction.data, json!({ "text": "gm" }));
+        }
+        assert_eq!(
+            decoded_legacy[0].metadata.transaction_metadata.signature,
+            legacy.signatures[0]
+        );
+        assert!(!decoded_v0_without_addresses[0].metadata.accounts_resolved);
+        assert_eq!(
+            decoded_v0_without_addresses[0].instruction.accounts[1].pubkey,
+            Pubkey::default()
+        );
+    }
+
+    #[test]
+    fn test_json_parsed_inner_instructions_keep_rpc_parsed_form_and_stack_heights() {
+        // Arrange
+        let vault_program_id = Pubkey::new_unique();
+        let unknown_program_id = Pubkey::new_unique();
+        let depositor = Pubkey::new_unique();
+        let registry = DecoderRegistry::new().register(vault_program_id, VaultDecoder);
+        let deposit_data = bs58::encode([vec![1], 500u64.to_le_bytes().to_vec()].concat());
+        let inner_instructions: Vec<UiInnerInstructions> = serde_json::from_value(json!([{
+            "index": 0,
+            "instructions": [
+                {
+                    "accounts": [depositor.to_string()],
+                    "data": deposit_data.into_string(),
+                    "programId": vault_program_id.to_string(),
+                    "stackHeight": 2
+                },
+                {
+                    "parsed": {
+                        "info": {
+                            "destination": depositor.to_string(),
+                            "lamports": 5000,
+                            "source": Pubkey::new_unique().to_string()
+                        },
+                        "type": "transfer"
+                    },
+                    "program": "system",
+                    "programId": "11111111111111111111111111111111",
+                    "stackHeight": 3
+                },
+                {
+                    "accounts": [],
+                    "data": "3Bxs4h24hBtQy9rw",
+                    "programId": unknown_program_id.to_string(),
+                    "stackHeight": 2
+                }
+            ]
+        }]))
+        .expect("jsonParsed inner instructions");
+
+        // Act
+        let converted = registry
+            .decode_json_parsed_inner_instructions(&inner_instructions)
+            .expect("converted inner instructions");
+
+        // Assert
+        assert_eq!(converted.len(), 1);
+        assert_eq!(converted[0].index, 0);
+        let instructions = &converted[0].instructions;
+        assert_eq!(
+            instructions
+                .iter()
+                .map(|instruction| instruction.stack_height)
+                .collect::<Vec<_>>(),
+            vec![Some(2), Some(3), Some(2)]
+        );
+
+        let JsonParsedData::Decoded(deposit) = &instructions[0].data else {
+            panic!("expected the vault instruction to be decoded");
+        };
+        assert_eq!(deposit.data, json!({ "Deposit": { "amount": 500 } }));
+        assert_eq!(
+            deposit.accounts,
+            vec![AccountMeta::new_readonly(depositor, false)]
+        );
+
+        let JsonParsedData::Rpc { program, parsed } = &instructions[1].data else {
+            panic!("expected the system instruction to keep the RPC's parsed form");
+        };
+        assert_eq!(instructions[1].program_id, Pubkey::default());
+        assert_eq!(program, "system");
+        assert_eq!(parsed["type"], "transfer");
+        assert_eq!(parsed["info"]["lamports"], 5000);
+
+        let JsonParsedData::Undecoded(unknown) = &instructions[2].data else {
+            panic!("expected the unknown instruction to stay undecoded");
+        };
+        assert_eq!(unknown.program_id, unknown_program_id);
+        assert_eq!(
+            unknown.data,
+            bs58::decode("3Bxs4h24hBtQy9rw").into_vec().unwrap()
+        );
+    }
+}
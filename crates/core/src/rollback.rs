@@ -0,0 +1,124 @@
+//! Provides structures and traits for handling chain reorganizations within
+//! the pipeline.
+//!
+//! Datasources that deliver updates before they are finalized (at `processed`
+//! or `confirmed` commitment) can observe a fork being abandoned, in which case
+//! the updates they already sent for the abandoned slots are orphaned. Such
+//! datasources emit an `Update::Rollback` carrying the first slot that is no
+//! longer valid, and the pipeline passes it to every `RollbackPipe` so that
+//! downstream stores can delete or invalidate their data for those slots.
+//!
+//! # Semantics
+//!
+//! - A `Rollback { from_slot }` invalidates every update previously delivered
+//!   for `from_slot` and any later slot. Updates for earlier slots are
+//!   unaffected.
+//! - After a rollback, the datasource continues with updates from the new fork,
+//!   which may include the same slots again.
+//! - Datasources that only deliver finalized data never emit a rollback, since
+//!   finalized slots cannot be replaced. Rollback processors can still be
+//!   registered on pipelines using them; they are simply never called.
+
+use {
+    crate::{
+        datasource::Rollback, error::CarbonResult, metrics::MetricsCollection, processor::Processor,
+    },
+    async_trait::async_trait,
+    std::sync::Arc,
+};
+
+/// A processing pipe for handling rollbacks.
+///
+/// The `RollbackPipe` passes each `Rollback` event to a user-defined
+/// `Processor`, which is responsible for removing or invalidating the data it
+/// stored for the rolled back slots.
+///
+/// # Example
+///
+/// ```rust
+/// struct MyRollbackProcessor;
+///
+/// #[async_trait]
+/// impl Processor for MyRollbackProcessor {
+///     type InputType = Rollback;
+///
+///     async fn process(
+///         &mut self,
+///         rollback: Rollback,
+///         metrics: Arc<MetricsCollection>,
+///     ) -> CarbonResult<()> {
+///         // Delete everything stored for slots >= rollback.from_slot
+///         Ok(())
+///     }
+/// }
+/// ```
+///
+/// ## Fields
+///
+/// - `processor`: A boxed `Processor` that handles the rollback.
+pub struct RollbackPipe {
+    pub processor: Box<dyn Processor<InputType = Rollback> + Send + Sync>,
+}
+
+/// A trait for handling rollback events in the pipeline.
+///
+/// The `RollbackPipes` trait defines an asynchronous `run` method, which is
+/// responsible for processing a `Rollback` event. It is implemented by
+/// `RollbackPipe` and can be implemented for custom rollback handling.
+#[async_trait]
+pub trait RollbackPipes: Send + Sync {
+    /// Processes a rollback event and tracks the operation with metrics.
+    ///
+    /// # Parameters
+    ///
+    /// - `rollback`: The rollback event to process.
+    /// - `metrics`: A list of `Metrics` implementations for tracking and
+    ///   reporting metrics.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `CarbonResult<()>`, which is `Ok` on success, or an error if
+    /// processing fails.
+    async fn run(
+        &mut self,
+        rollback: Rollback,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()>;
+
+    /// Shuts down the pipe's processor when the pipeline shuts down.
+    async fn shutdown(&mut self) -> CarbonResult<()> {
+        Ok(())
+    }
+
+    /// Creates another instance of the pipe for the pipeline to run
+    /// concurrently with this one, or `None` if the pipe must handle one
+    /// update at a time.
+    fn fork(&self) -> Option<Box<dyn RollbackPipes>> {
+        None
+    }
+}
+
+#[async_trait]
+impl RollbackPipes for RollbackPipe {
+    async fn run(
+        &mut self,
+        rollback: Rollback,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        log::trace!("RollbackPipe::run(rollback: {:?}, metrics)", rollback);
+
+        self.processor.process(rollback, metrics).await?;
+
+        Ok(())
+    }
+
+    async fn shutdown(&mut self) -> CarbonResult<()> {
+        self.processor.shutdown().await
+    }
+
+    fn fork(&self) -> Option<Box<dyn RollbackPipes>> {
+        Some(Box::new(RollbackPipe {
+            processor: self.processor.fork()?,
+        }))
+    }
+}
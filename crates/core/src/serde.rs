@@ -0,0 +1,137 @@
+//! Serde helpers that make decoded types readable as JSON.
+//!
+//! By default, `Pubkey` and `[u8; N]` fields serialize as arrays of numbers.
+//! The modules of this file are meant for `#[serde(with = "...")]` and
+//! serialize them as strings instead:
+//!
+//! - [`pubkey_as_base58`]: a `Pubkey` as its base58 address.
+//! - [`bytes_as_base64`]: a `[u8; N]` as standard base64.
+//!
+//! Both deserialize the strings they produce, so decoded types keep
+//! round-tripping through JSON.
+//!
+//! Rather than annotating every field, put the `carbon_serde` attribute above
+//! the `derive` of a decoded type: it applies these modules to all of its
+//! `Pubkey` and `[u8; N]` fields. A field marked `#[carbon_serde(raw)]`, or
+//! one that already has a `#[serde(with = "...")]` attribute, is left as is.
+//! The decoders generated by the CLI put the attribute on their types,
+//! accounts, instructions and events, unless `--raw-serde` is passed.
+//!
+//! ```ignore
+//! #[carbon_serde]
+//! #[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
+//! #[carbon(discriminator = "0x01")]
+//! pub struct Initialize {
+//!     pub authority: Pubkey,
+//!     pub seed: [u8; 32],
+//!     #[carbon_serde(raw)]
+//!     pub raw_seed: [u8; 32],
+//! }
+//! ```
+
+/// Serializes a `Pubkey` as its base58 address.
+pub mod pubkey_as_base58 {
+    use {
+        ::serde::{de::Error, Deserialize, Deserializer, Serializer},
+        alloc::{format, string::String},
+        core::str::FromStr,
+        solana_pubkey::Pubkey,
+    };
+
+    pub fn serialize<S: Serializer>(pubkey: &Pubkey, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(pubkey)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Pubkey, D::Error> {
+        let address = String::deserialize(deserializer)?;
+        Pubkey::from_str(&address).map_err(|err| D::Error::custom(format!("{address}: {err}")))
+    }
+}
+
+/// Serializes a `[u8; N]` as standard base64.
+pub mod bytes_as_base64 {
+    use {
+        ::serde::{de::Error, Deserialize, Deserializer, Serializer},
+        alloc::{format, string::String},
+        base64::{engine::general_purpose::STANDARD, Engine},
+    };
+
+    pub fn serialize<S: Serializer, const N: usize>(
+        bytes: &[u8; N],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&STANDARD.encode(bytes))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, const N: usize>(
+        deserializer: D,
+    ) -> Result<[u8; N], D::Error> {
+        let encoded = String::deserialize(deserializer)?;
+        let bytes = STANDARD.decode(&encoded).map_err(D::Error::custom)?;
+        let len = bytes.len();
+
+        bytes
+            .try_into()
+            .map_err(|_| D::Error::invalid_length(len, &format!("{N} bytes").as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        crate::carbon_serde,
+        ::serde::{Deserialize, Serialize},
+        solana_pubkey::Pubkey,
+    };
+
+    #[carbon_serde]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Initialize {
+        authority: Pubkey,
+        seed: [u8; 4],
+        #[carbon_serde(raw)]
+        raw_seed: [u8; 2],
+        amount: u64,
+    }
+
+    #[test]
+    fn test_pubkey_serializes_as_base58() {
+        // Arrange
+        let initialize = Initialize {
+            authority: Pubkey::new_unique(),
+            seed: [1, 2, 3, 4],
+            raw_seed: [5, 6],
+            amount: 7,
+        };
+
+        // Act
+        let json = serde_json::to_value(&initialize).unwrap();
+
+        // Assert
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "authority": initialize.authority.to_string(),
+                "seed": "AQIDBA==",
+                "raw_seed": [5, 6],
+                "amount": 7,
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<Initialize>(json).unwrap(),
+            initialize
+        );
+    }
+
+    #[test]
+    fn test_bytes_of_the_wrong_length_are_rejected() {
+        let json = serde_json::json!({
+            "authority": Pubkey::new_unique().to_string(),
+            "seed": "AQID",
+            "raw_seed": [5, 6],
+            "amount": 7,
+        });
+
+        assert!(serde_json::from_value::<Initialize>(json).is_err());
+    }
+}
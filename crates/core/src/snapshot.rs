@@ -0,0 +1,198 @@
+//! Bootstraps account state from a snapshot before live updates are
+//! processed.
+//!
+//! Datasources streaming account changes only deliver the accounts that
+//! change after they subscribe. To start processors with complete state, add
+//! an [`AccountSnapshot`] with `PipelineBuilder::snapshot`: once the
+//! datasources are subscribed, the pipeline loads every snapshot and runs its
+//! accounts through the account pipes, so they are decoded by the registered
+//! `AccountDecoder`s, before it processes the first live update. The
+//! processors see these accounts with `AccountMetadata::is_snapshot` set.
+//!
+//! [`FileAccountSnapshot`] loads a dump written by [`write_account_snapshot`].
+//! Snapshots fetched over RPC with `getProgramAccounts` are provided by the
+//! `carbon-rpc-program-subscribe-datasource` crate.
+//!
+//! ```ignore
+//! let pipeline = Pipeline::builder()
+//!     .snapshot(FileAccountSnapshot::new("drift-users.snapshot"))
+//!     .datasource(program_subscribe)
+//!     .account(DriftDecoder, UserProcessor)
+//!     .build()?;
+//! ```
+
+use {
+    crate::{
+        datasource::AccountUpdate,
+        error::{CarbonResult, Error},
+    },
+    async_trait::async_trait,
+    borsh::{BorshDeserialize, BorshSerialize},
+    solana_account::Account,
+    solana_pubkey::Pubkey,
+    std::{
+        fs,
+        io::{BufWriter, Write},
+        path::{Path, PathBuf},
+    },
+};
+
+/// Loads the accounts a pipeline processes before its live updates.
+#[async_trait]
+pub trait AccountSnapshot: Send + Sync {
+    /// Returns the accounts of the snapshot. The pipeline marks them as
+    /// snapshot updates, whatever `is_snapshot` is set to here.
+    async fn load(&self) -> CarbonResult<Vec<AccountUpdate>>;
+}
+
+/// An account as stored in a snapshot dump.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+struct SnapshotAccount {
+    pubkey: [u8; 32],
+    slot: u64,
+    lamports: u64,
+    data: Vec<u8>,
+    owner: [u8; 32],
+    executable: bool,
+    rent_epoch: u64,
+}
+
+impl From<&AccountUpdate> for SnapshotAccount {
+    fn from(update: &AccountUpdate) -> Self {
+        Self {
+            pubkey: update.pubkey.to_bytes(),
+            slot: update.slot,
+            lamports: update.account.lamports,
+            data: update.account.data.clone(),
+            owner: update.account.owner.to_bytes(),
+            executable: update.account.executable,
+            rent_epoch: update.account.rent_epoch,
+        }
+    }
+}
+
+impl From<SnapshotAccount> for AccountUpdate {
+    fn from(account: SnapshotAccount) -> Self {
+        Self {
+            pubkey: Pubkey::new_from_array(account.pubkey),
+            account: Account {
+                lamports: account.lamports,
+                data: account.data,
+                owner: Pubkey::new_from_array(account.owner),
+                executable: account.executable,
+                rent_epoch: account.rent_epoch,
+            },
+            slot: account.slot,
+            commitment: None,
+            is_snapshot: true,
+            source: None,
+        }
+    }
+}
+
+/// Writes `accounts` to `path` in the format read by [`FileAccountSnapshot`],
+/// a Borsh-encoded list of accounts with the slot they were read at.
+pub fn write_account_snapshot(
+    path: impl AsRef<Path>,
+    accounts: &[AccountUpdate],
+) -> CarbonResult<()> {
+    let accounts: Vec<SnapshotAccount> = accounts.iter().map(SnapshotAccount::from).collect();
+
+    let mut writer = BufWriter::new(fs::File::create(path)?);
+    accounts.serialize(&mut writer)?;
+    writer.flush()?;
+
+    Ok(())
+}
+
+/// An [`AccountSnapshot`] read from a dump written by
+/// [`write_account_snapshot`].
+#[derive(Debug, Clone)]
+pub struct FileAccountSnapshot {
+    path: PathBuf,
+}
+
+impl FileAccountSnapshot {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+#[async_trait]
+impl AccountSnapshot for FileAccountSnapshot {
+    async fn load(&self) -> CarbonResult<Vec<AccountUpdate>> {
+        let bytes = tokio::fs::read(&self.path).await?;
+        let accounts = Vec::<SnapshotAccount>::try_from_slice(&bytes).map_err(|err| {
+            Error::FailedToDeserialize(format!(
+                "invalid account snapshot {}: {}",
+                self.path.display(),
+                err
+            ))
+        })?;
+
+        Ok(accounts.into_iter().map(AccountUpdate::from).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_file_snapshot_round_trip() {
+        // Arrange
+        let path = std::env::temp_dir().join(format!(
+            "carbon-account-snapshot-{}-{}",
+            std::process::id(),
+            Pubkey::new_unique()
+        ));
+        let accounts = vec![
+            AccountUpdate {
+                pubkey: Pubkey::new_unique(),
+                account: Account {
+                    lamports: 1_000,
+                    data: vec![1, 2, 3],
+                    owner: Pubkey::new_unique(),
+                    executable: false,
+                    rent_epoch: u64::MAX,
+                },
+                slot: 42,
+                commitment: None,
+                is_snapshot: false,
+                source: None,
+            },
+            AccountUpdate {
+                pubkey: Pubkey::new_unique(),
+                account: Account::default(),
+                slot: 43,
+                commitment: None,
+                is_snapshot: false,
+                source: None,
+            },
+        ];
+        write_account_snapshot(&path, &accounts).expect("write snapshot");
+
+        // Act
+        let loaded = FileAccountSnapshot::new(&path)
+            .load()
+            .await
+            .expect("load snapshot");
+        fs::write(&path, [1, 2, 3]).expect("corrupt snapshot");
+        let corrupted = FileAccountSnapshot::new(&path).load().await;
+        fs::remove_file(&path).ok();
+
+        // Assert
+        assert_eq!(loaded.len(), 2);
+        for (loaded, written) in loaded.iter().zip(&accounts) {
+            assert_eq!(loaded.pubkey, written.pubkey);
+            assert_eq!(loaded.account, written.account);
+            assert_eq!(loaded.slot, written.slot);
+            assert!(loaded.is_snapshot);
+        }
+        assert!(matches!(corrupted, Err(Error::FailedToDeserialize(_))));
+    }
+}
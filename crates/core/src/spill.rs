@@ -0,0 +1,787 @@
+//! Spills updates to disk while the pipes fall behind, instead of blocking
+//! the datasources or dropping updates.
+//!
+//! With `PipelineBuilder::spill_to_disk`, the datasources send their updates
+//! to a forwarding task rather than to the pipeline's channel. While fewer
+//! than `high_watermark` updates wait in the channel, the task forwards
+//! updates as they arrive. From then on, it appends incoming updates to
+//! segment files in the spill directory, and replays them into the channel
+//! once fewer than `low_watermark` updates wait in it, which the pipeline
+//! signals each time it receives an update. Updates received while spilled
+//! updates are waiting are spilled as well, so the pipes see every update in
+//! the order it was received.
+//!
+//! Segments are JSON Lines files, with transactions stored in their wire
+//! format, base64-encoded. A segment holds up to 10,000 updates, after which
+//! the next one is started, and it is deleted once it has been replayed, or
+//! when the pipeline stops. The disk space used is therefore bounded by the
+//! updates waiting to be replayed. Segments are written and read with
+//! `tokio::fs`, so that spilling does not block the runtime's workers.
+//!
+//! The `updates_spilled` counter counts the updates written to disk, and the
+//! `updates_spilled_pending` gauge reports how many of them are waiting to be
+//! replayed.
+//!
+//! ```ignore
+//! let pipeline = Pipeline::builder()
+//!     .datasource(yellowstone_grpc)
+//!     .instruction(DriftDecoder, DriftInstructionProcessor)
+//!     .channel_buffer_size(10_000)
+//!     .spill_to_disk(SpillToDisk::new("/var/lib/indexer/spill", 9_000, 1_000))
+//!     .build()?;
+//! ```
+
+use {
+    crate::{
+        datasource::{
+            AccountDeletion, AccountUpdate, Commitment, Rollback, TransactionUpdate, Update,
+        },
+        error::{CarbonResult, Error},
+        metrics::MetricsCollection,
+    },
+    base64::{engine::general_purpose::STANDARD, Engine},
+    serde::{Deserialize, Serialize},
+    solana_account::Account,
+    solana_pubkey::Pubkey,
+    solana_sdk::{
+        message::v0::LoadedAddresses,
+        transaction::{Result as TransactionResult, VersionedTransaction},
+        transaction_context::TransactionReturnData,
+    },
+    solana_transaction_status::{
+        InnerInstructions, Rewards, TransactionStatusMeta, TransactionTokenBalance,
+    },
+    std::{
+        collections::VecDeque,
+        path::{Path, PathBuf},
+        sync::{
+            atomic::{AtomicU64, Ordering},
+            Arc,
+        },
+    },
+    tokio::{
+        fs::{self, File},
+        io::{AsyncBufReadExt, AsyncWriteExt, BufReader, BufWriter},
+        sync::{mpsc, Notify},
+    },
+};
+
+/// The number of updates written to a segment before the next one is
+/// started, so that replayed segments can be deleted while updates are still
+/// being spilled.
+const SEGMENT_CAPACITY: usize = 10_000;
+
+/// Numbers the segment files, so that pipelines running in the same process
+/// never share one.
+static NEXT_SEGMENT: AtomicU64 = AtomicU64::new(0);
+
+/// Configures spilling updates to disk. See the module documentation.
+///
+/// - `directory`: The directory the segment files are written to. It is created
+///   if it does not exist.
+/// - `high_watermark`: The number of updates waiting in the channel from which
+///   incoming updates are spilled. Values above the channel buffer size are
+///   treated as the buffer size.
+/// - `low_watermark`: Spilled updates are replayed once fewer updates than this
+///   wait in the channel. Values below `1` are treated as `1`, and values above
+///   `high_watermark` as `high_watermark`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpillToDisk {
+    pub directory: PathBuf,
+    pub high_watermark: usize,
+    pub low_watermark: usize,
+}
+
+impl SpillToDisk {
+    pub fn new(directory: impl Into<PathBuf>, high_watermark: usize, low_watermark: usize) -> Self {
+        Self {
+            directory: directory.into(),
+            high_watermark,
+            low_watermark,
+        }
+    }
+}
+
+/// Returns a sender for the datasources that forwards their updates to
+/// `update_sender`, spilling them to disk while the channel is above the high
+/// watermark, until the datasources drop their senders and every spilled
+/// update has been replayed.
+///
+/// The returned `Notify` must be notified whenever an update is received from
+/// the channel, so that spilled updates are replayed as soon as there is room.
+pub(crate) fn spill_when_full(
+    update_sender: mpsc::Sender<Update>,
+    config: SpillToDisk,
+    metrics: Arc<MetricsCollection>,
+) -> (mpsc::Sender<Update>, Arc<Notify>) {
+    let (datasource_sender, mut datasource_receiver) = mpsc::channel::<Update>(1);
+    let high_watermark = config.high_watermark.clamp(1, update_sender.max_capacity());
+    let low_watermark = config.low_watermark.clamp(1, high_watermark);
+    let queued = |sender: &mpsc::Sender<Update>| sender.max_capacity() - sender.capacity();
+    let update_received = Arc::new(Notify::new());
+    let replay = update_received.clone();
+
+    tokio::spawn(async move {
+        let mut spill = SpillQueue::new(config.directory, SEGMENT_CAPACITY, metrics);
+
+        loop {
+            tokio::select! {
+                update = datasource_receiver.recv() => {
+                    let Some(update) = update else {
+                        break;
+                    };
+
+                    if spill.is_empty() && queued(&update_sender) < high_watermark {
+                        if update_sender.send(update).await.is_err() {
+                            return;
+                        }
+                    } else {
+                        spill.push(&update).await;
+                    }
+                }
+                _ = replay.notified(), if !spill.is_empty() => {
+                    if queued(&update_sender) >= low_watermark {
+                        continue;
+                    }
+
+                    while queued(&update_sender) < high_watermark {
+                        let Some(update) = spill.pop().await else {
+                            break;
+                        };
+                        if update_sender.send(update).await.is_err() {
+                            return;
+                        }
+                    }
+                }
+            }
+        }
+
+        // The datasources have finished, so the remaining updates are
+        // replayed as soon as there is room in the channel.
+        while let Some(update) = spill.pop().await {
+            if update_sender.send(update).await.is_err() {
+                return;
+            }
+        }
+    });
+
+    (datasource_sender, update_received)
+}
+
+/// The spilled updates, in the order they were received.
+struct SpillQueue {
+    directory: PathBuf,
+    segment_capacity: usize,
+    /// The segments, oldest first. Updates are appended to the last one and
+    /// replayed from the first one.
+    segments: VecDeque<Segment>,
+    metrics: Arc<MetricsCollection>,
+}
+
+impl SpillQueue {
+    fn new(directory: PathBuf, segment_capacity: usize, metrics: Arc<MetricsCollection>) -> Self {
+        Self {
+            directory,
+            segment_capacity,
+            segments: VecDeque::new(),
+            metrics,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    /// Appends `update` to the last segment, starting a new one if there is
+    /// none or if it is full. Updates that cannot be written are dropped and
+    /// counted in the `updates_dropped` metric.
+    async fn push(&mut self, update: &Update) {
+        match self.append(update).await {
+            Ok(()) => {
+                self.record("updates_spilled").await;
+            }
+            Err(error) => {
+                log::error!(
+                    "failed to spill update of slot {}, dropping it: {:?}",
+                    update.slot(),
+                    error
+                );
+                self.record("updates_dropped").await;
+            }
+        }
+    }
+
+    async fn append(&mut self, update: &Update) -> CarbonResult<()> {
+        let segment = match self.segments.back_mut() {
+            Some(segment) if segment.written < self.segment_capacity => segment,
+            last => {
+                if let Some(segment) = last {
+                    segment.close().await?;
+                }
+                self.segments
+                    .push_back(Segment::create(&self.directory).await?);
+                self.segments.back_mut().expect("segment was pushed")
+            }
+        };
+
+        segment.push(update).await
+    }
+
+    /// Removes the oldest spilled update, deleting its segment once it is
+    /// fully replayed. Updates that cannot be read back are skipped and
+    /// counted in the `updates_dropped` metric.
+    async fn pop(&mut self) -> Option<Update> {
+        loop {
+            let segment = self.segments.front_mut()?;
+            let result = segment.pop().await;
+            if segment.pending == 0 {
+                let segment = self.segments.pop_front().expect("segment is first");
+                segment.delete().await;
+            }
+
+            match result {
+                Ok(update) => {
+                    self.update_pending_gauge().await;
+                    return Some(update);
+                }
+                Err(error) => {
+                    log::error!("failed to replay spilled update, dropping it: {:?}", error);
+                    self.record("updates_dropped").await;
+                }
+            }
+        }
+    }
+
+    async fn record(&self, counter: &str) {
+        if let Err(error) = self.metrics.increment_counter(counter, 1).await {
+            log::error!("failed to increment {}: {:?}", counter, error);
+        }
+        self.update_pending_gauge().await;
+    }
+
+    async fn update_pending_gauge(&self) {
+        let pending: usize = self.segments.iter().map(|segment| segment.pending).sum();
+        if let Err(error) = self
+            .metrics
+            .update_gauge("updates_spilled_pending", pending as f64)
+            .await
+        {
+            log::error!("failed to update updates_spilled_pending: {:?}", error);
+        }
+    }
+}
+
+/// A segment file, written at its end and replayed from its start.
+struct Segment {
+    path: PathBuf,
+    /// `None` once the segment is full and its writes have been flushed.
+    writer: Option<BufWriter<File>>,
+    reader: BufReader<File>,
+    written: usize,
+    pending: usize,
+    deleted: bool,
+}
+
+impl Segment {
+    async fn create(directory: &Path) -> CarbonResult<Self> {
+        fs::create_dir_all(directory).await?;
+        let path = directory.join(format!(
+            "carbon-spill-{}-{}.jsonl",
+            std::process::id(),
+            NEXT_SEGMENT.fetch_add(1, Ordering::Relaxed)
+        ));
+        let writer = BufWriter::new(File::create(&path).await?);
+        let reader = BufReader::new(File::open(&path).await?);
+        log::debug!("spilling updates to {}", path.display());
+
+        Ok(Self {
+            path,
+            writer: Some(writer),
+            reader,
+            written: 0,
+            pending: 0,
+            deleted: false,
+        })
+    }
+
+    async fn push(&mut self, update: &Update) -> CarbonResult<()> {
+        let writer = self
+            .writer
+            .as_mut()
+            .ok_or_else(|| Error::Custom("spill segment is closed".to_string()))?;
+        let mut line = serde_json::to_vec(&SpilledUpdate::try_from(update)?)?;
+        line.push(b'\n');
+        writer.write_all(&line).await?;
+        self.written += 1;
+        self.pending += 1;
+
+        Ok(())
+    }
+
+    /// Flushes the buffered writes and stops accepting updates.
+    async fn close(&mut self) -> CarbonResult<()> {
+        if let Some(mut writer) = self.writer.take() {
+            writer.flush().await?;
+        }
+
+        Ok(())
+    }
+
+    async fn pop(&mut self) -> CarbonResult<Update> {
+        // The updates still buffered by the writer are not visible to the
+        // reader yet.
+        if let Some(writer) = self.writer.as_mut() {
+            writer.flush().await?;
+        }
+
+        let mut line = String::new();
+        self.reader.read_line(&mut line).await?;
+        self.pending -= 1;
+
+        serde_json::from_str::<SpilledUpdate>(&line)?.try_into()
+    }
+
+    /// Deletes the segment file.
+    async fn delete(mut self) {
+        self.deleted = true;
+        if let Err(error) = fs::remove_file(&self.path).await {
+            log::error!(
+                "failed to delete spill segment {}: {:?}",
+                self.path.display(),
+                error
+            );
+        }
+    }
+}
+
+impl Drop for Segment {
+    /// Deletes the files of the segments left when the forwarding task stops
+    /// before replaying them.
+    fn drop(&mut self) {
+        if self.deleted {
+            return;
+        }
+
+        if let Err(error) = std::fs::remove_file(&self.path) {
+            log::error!(
+                "failed to delete spill segment {}: {:?}",
+                self.path.display(),
+                error
+            );
+        }
+    }
+}
+
+/// An `Update` as written to a segment file.
+#[derive(Debug, Serialize, Deserialize)]
+enum SpilledUpdate {
+    Account {
+        pubkey: Pubkey,
+        lamports: u64,
+        data: String,
+        owner: Pubkey,
+        executable: bool,
+        rent_epoch: u64,
+        slot: u64,
+        commitment: Option<Commitment>,
+        is_snapshot: bool,
+        source: Option<String>,
+    },
+    Transaction {
+        signature: String,
+        transaction: String,
+        meta: Box<SpilledTransactionStatusMeta>,
+        is_vote: bool,
+        slot: u64,
+        block_time: Option<i64>,
+        commitment: Option<Commitment>,
+        source: Option<String>,
+    },
+    AccountDeletion {
+        pubkey: Pubkey,
+        slot: u64,
+        commitment: Option<Commitment>,
+        source: Option<String>,
+    },
+    Rollback {
+        from_slot: u64,
+    },
+}
+
+/// A `TransactionStatusMeta`, which does not implement serde, as written to a
+/// segment file.
+#[derive(Debug, Serialize, Deserialize)]
+struct SpilledTransactionStatusMeta {
+    status: TransactionResult<()>,
+    fee: u64,
+    pre_balances: Vec<u64>,
+    post_balances: Vec<u64>,
+    inner_instructions: Option<Vec<InnerInstructions>>,
+    log_messages: Option<Vec<String>>,
+    pre_token_balances: Option<Vec<SpilledTokenBalance>>,
+    post_token_balances: Option<Vec<SpilledTokenBalance>>,
+    rewards: Option<Rewards>,
+    loaded_addresses: LoadedAddresses,
+    return_data: Option<TransactionReturnData>,
+    compute_units_consumed: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SpilledTokenBalance {
+    account_index: u8,
+    mint: String,
+    ui_token_amount: serde_json::Value,
+    owner: String,
+    program_id: String,
+}
+
+impl TryFrom<&Update> for SpilledUpdate {
+    type Error = Error;
+
+    fn try_from(update: &Update) -> CarbonResult<Self> {
+        Ok(match update {
+            Update::Account(account_update) => SpilledUpdate::Account {
+                pubkey: account_update.pubkey,
+                lamports: account_update.account.lamports,
+                data: STANDARD.encode(&account_update.account.data),
+                owner: account_update.account.owner,
+                executable: account_update.account.executable,
+                rent_epoch: account_update.account.rent_epoch,
+                slot: account_update.slot,
+                commitment: account_update.commitment,
+                is_snapshot: account_update.is_snapshot,
+                source: account_update.source.as_deref().map(str::to_string),
+            },
+            Update::Transaction(transaction_update) => {
+                let transaction = bincode::serialize(&transaction_update.transaction)
+                    .map_err(|err| Error::Custom(format!("failed to encode transaction: {err}")))?;
+                let meta = &transaction_update.meta;
+                let token_balances = |balances: &Option<Vec<TransactionTokenBalance>>| {
+                    balances
+                        .as_ref()
+                        .map(|balances| {
+                            balances
+                                .iter()
+                                .map(|balance| {
+                                    Ok(SpilledTokenBalance {
+                                        account_index: balance.account_index,
+                                        mint: balance.mint.clone(),
+                                        ui_token_amount: serde_json::to_value(
+                                            &balance.ui_token_amount,
+                                        )?,
+                                        owner: balance.owner.clone(),
+                                        program_id: balance.program_id.clone(),
+                                    })
+                                })
+                                .collect::<CarbonResult<Vec<_>>>()
+                        })
+                        .transpose()
+                };
+
+                SpilledUpdate::Transaction {
+                    signature: transaction_update.signature.to_string(),
+                    transaction: STANDARD.encode(transaction),
+                    meta: Box::new(SpilledTransactionStatusMeta {
+                        status: meta.status.clone(),
+                        fee: meta.fee,
+                        pre_balances: meta.pre_balances.clone(),
+                        post_balances: meta.post_balances.clone(),
+                        inner_instructions: meta.inner_instructions.clone(),
+                        log_messages: meta.log_messages.clone(),
+                        pre_token_balances: token_balances(&meta.pre_token_balances)?,
+                        post_token_balances: token_balances(&meta.post_token_balances)?,
+                        rewards: meta.rewards.clone(),
+                        loaded_addresses: meta.loaded_addresses.clone(),
+                        return_data: meta.return_data.clone(),
+                        compute_units_consumed: meta.compute_units_consumed,
+                    }),
+                    is_vote: transaction_update.is_vote,
+                    slot: transaction_update.slot,
+                    block_time: transaction_update.block_time,
+                    commitment: transaction_update.commitment,
+                    source: transaction_update.source.as_deref().map(str::to_string),
+                }
+            }
+            Update::AccountDeletion(account_deletion) => SpilledUpdate::AccountDeletion {
+                pubkey: account_deletion.pubkey,
+                slot: account_deletion.slot,
+                commitment: account_deletion.commitment,
+                source: account_deletion.source.as_deref().map(str::to_string),
+            },
+            Update::Rollback(rollback) => SpilledUpdate::Rollback {
+                from_slot: rollback.from_slot,
+            },
+        })
+    }
+}
+
+impl TryFrom<SpilledUpdate> for Update {
+    type Error = Error;
+
+    fn try_from(update: SpilledUpdate) -> CarbonResult<Self> {
+        Ok(match update {
+            SpilledUpdate::Account {
+                pubkey,
+                lamports,
+                data,
+                owner,
+                executable,
+                rent_epoch,
+                slot,
+                commitment,
+                is_snapshot,
+                source,
+            } => Update::Account(AccountUpdate {
+                pubkey,
+                account: Account {
+                    lamports,
+                    data: decode_base64(&data)?,
+                    owner,
+                    executable,
+                    rent_epoch,
+                },
+                slot,
+                commitment,
+                is_snapshot,
+                source: source.map(Into::into),
+            }),
+            SpilledUpdate::Transaction {
+                signature,
+                transaction,
+                meta,
+                is_vote,
+                slot,
+                block_time,
+                commitment,
+                source,
+            } => {
+                let transaction: VersionedTransaction =
+                    bincode::deserialize(&decode_base64(&transaction)?).map_err(|err| {
+                        Error::FailedToDeserialize(format!("spilled transaction: {err}"))
+                    })?;
+                let token_balances = |balances: Option<Vec<SpilledTokenBalance>>| {
+                    balances
+                        .map(|balances| {
+                            balances
+                                .into_iter()
+                                .map(|balance| {
+                                    Ok(TransactionTokenBalance {
+                                        account_index: balance.account_index,
+                                        mint: balance.mint,
+                                        ui_token_amount: serde_json::from_value(
+                                            balance.ui_token_amount,
+                                        )?,
+                                        owner: balance.owner,
+                                        program_id: balance.program_id,
+                                    })
+                                })
+                                .collect::<CarbonResult<Vec<_>>>()
+                        })
+                        .transpose()
+                };
+
+                Update::Transaction(Box::new(TransactionUpdate {
+                    signature: signature.parse().map_err(|err| {
+                        Error::FailedToDeserialize(format!("spilled signature: {err}"))
+                    })?,
+                    transaction,
+                    meta: TransactionStatusMeta {
+                        status: meta.status,
+                        fee: meta.fee,
+                        pre_balances: meta.pre_balances,
+                        post_balances: meta.post_balances,
+                        inner_instructions: meta.inner_instructions,
+                        log_messages: meta.log_messages,
+                        pre_token_balances: token_balances(meta.pre_token_balances)?,
+                        post_token_balances: token_balances(meta.post_token_balances)?,
+                        rewards: meta.rewards,
+                        loaded_addresses: meta.loaded_addresses,
+                        return_data: meta.return_data,
+                        compute_units_consumed: meta.compute_units_consumed,
+                    },
+                    is_vote,
+                    slot,
+                    block_time,
+                    commitment,
+                    source: source.map(Into::into),
+                }))
+            }
+            SpilledUpdate::AccountDeletion {
+                pubkey,
+                slot,
+                commitment,
+                source,
+            } => Update::AccountDeletion(AccountDeletion {
+                pubkey,
+                slot,
+                commitment,
+                source: source.map(Into::into),
+            }),
+            SpilledUpdate::Rollback { from_slot } => Update::Rollback(Rollback { from_slot }),
+        })
+    }
+}
+
+fn decode_base64(data: &str) -> CarbonResult<Vec<u8>> {
+    STANDARD
+        .decode(data)
+        .map_err(|err| Error::FailedToDeserialize(format!("spilled data: {err}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        solana_account_decoder_client_types::token::UiTokenAmount,
+        solana_instruction::Instruction,
+        solana_sdk::{
+            instruction::CompiledInstruction,
+            message::{Message, VersionedMessage},
+            transaction::TransactionError,
+        },
+        solana_signature::Signature,
+        solana_transaction_status::InnerInstruction,
+    };
+
+    #[tokio::test]
+    async fn test_spilled_updates_are_replayed_unchanged() {
+        // Arrange
+        let directory =
+            std::env::temp_dir().join(format!("carbon-spill-test-{}", std::process::id()));
+        let program_id = Pubkey::new_unique();
+        let message = Message::new(
+            &[Instruction {
+                program_id,
+                accounts: vec![],
+                data: vec![1, 2, 3],
+            }],
+            Some(&Pubkey::new_unique()),
+        );
+        let transaction = Update::Transaction(Box::new(TransactionUpdate {
+            signature: Signature::from([3; 64]),
+            transaction: VersionedTransaction {
+                signatures: vec![Signature::from([3; 64])],
+                message: VersionedMessage::Legacy(message),
+            },
+            meta: TransactionStatusMeta {
+                status: Err(TransactionError::AccountNotFound),
+                fee: 5000,
+                pre_balances: vec![10, 20],
+                post_balances: vec![5, 20],
+                inner_instructions: Some(vec![InnerInstructions {
+                    index: 0,
+                    instructions: vec![InnerInstruction {
+                        instruction: CompiledInstruction {
+                            program_id_index: 1,
+                            accounts: vec![0],
+                            data: vec![9],
+                        },
+                        stack_height: Some(2),
+                    }],
+                }]),
+                log_messages: Some(vec!["Program log: Instruction: Swap".to_string()]),
+                pre_token_balances: Some(vec![TransactionTokenBalance {
+                    account_index: 0,
+                    mint: Pubkey::new_unique().to_string(),
+                    ui_token_amount: UiTokenAmount {
+                        ui_amount: Some(1.5),
+                        decimals: 6,
+                        amount: "1500000".to_string(),
+                        ui_amount_string: "1.5".to_string(),
+                    },
+                    owner: Pubkey::new_unique().to_string(),
+                    program_id: Pubkey::new_unique().to_string(),
+                }]),
+                post_token_balances: None,
+                rewards: Some(vec![]),
+                loaded_addresses: LoadedAddresses {
+                    writable: vec![Pubkey::new_unique()],
+                    readonly: vec![],
+                },
+                return_data: Some(TransactionReturnData {
+                    program_id,
+                    data: vec![4],
+                }),
+                compute_units_consumed: Some(1000),
+            },
+            is_vote: false,
+            slot: 7,
+            block_time: Some(1_700_000_000),
+            commitment: None,
+            source: None,
+        }));
+        let account = Update::Account(AccountUpdate {
+            pubkey: Pubkey::new_unique(),
+            account: Account {
+                lamports: 42,
+                data: vec![4, 5, 6],
+                owner: program_id,
+                executable: false,
+                rent_epoch: 7,
+            },
+            slot: 8,
+            commitment: Some(Commitment::Confirmed),
+            is_snapshot: true,
+            source: Some("geyser".into()),
+        });
+        let mut segment = Segment::create(&directory).await.unwrap();
+        let path = segment.path.clone();
+
+        // Act
+        segment.push(&transaction).await.unwrap();
+        segment.push(&account).await.unwrap();
+        let replayed_transaction = segment.pop().await.unwrap();
+        let replayed_account = segment.pop().await.unwrap();
+        segment.delete().await;
+
+        // Assert
+        assert_eq!(
+            format!("{replayed_transaction:?}"),
+            format!("{transaction:?}")
+        );
+        assert_eq!(format!("{replayed_account:?}"), format!("{account:?}"));
+        assert!(!path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_replayed_segments_are_deleted_while_spilling_continues() {
+        // Arrange
+        let directory =
+            std::env::temp_dir().join(format!("carbon-spill-rotation-{}", std::process::id()));
+        let mut spill = SpillQueue::new(
+            directory.clone(),
+            2,
+            Arc::new(MetricsCollection::new(vec![])),
+        );
+        let rollback = |from_slot| Update::Rollback(Rollback { from_slot });
+        let segment_files = || std::fs::read_dir(&directory).unwrap().count();
+
+        // Act
+        for slot in 0..3 {
+            spill.push(&rollback(slot)).await;
+        }
+        let files_while_spilling = segment_files();
+        let first = spill.pop().await;
+        let second = spill.pop().await;
+        let files_after_first_segment = segment_files();
+        spill.push(&rollback(3)).await;
+        let mut rest = Vec::new();
+        while let Some(update) = spill.pop().await {
+            rest.push(update);
+        }
+
+        // Assert
+        assert_eq!(files_while_spilling, 2);
+        assert_eq!(files_after_first_segment, 1);
+        assert_eq!(
+            format!("{:?}", [first.unwrap(), second.unwrap()]),
+            format!("{:?}", [rollback(0), rollback(1)])
+        );
+        assert_eq!(
+            format!("{rest:?}"),
+            format!("{:?}", [rollback(2), rollback(3)])
+        );
+        assert!(spill.is_empty());
+        assert_eq!(segment_files(), 0);
+    }
+}
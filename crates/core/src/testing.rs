@@ -0,0 +1,504 @@
+//! Helpers for building realistic fixtures in decoder and processor tests, and
+//! for running a pipeline over them with `TestPipeline`.
+//!
+//! This module is only compiled with the `testing` feature, which is meant to
+//! be enabled from `[dev-dependencies]`:
+//!
+//! ```toml
+//! [dev-dependencies]
+//! carbon-core = { workspace = true, features = ["testing"] }
+//! ```
+//!
+//! # Example
+//!
+//! ```ignore
+//! use carbon_core::{instruction::InstructionMetadata, transaction::TransactionMetadata};
+//!
+//! let metadata = InstructionMetadata {
+//!     transaction_metadata: TransactionMetadata::builder()
+//!         .slot(42)
+//!         .fee_payer(payer)
+//!         .account_keys(vec![pool, PROGRAM_ID])
+//!         .build(),
+//!     stack_height: 1,
+//!     index: 0,
+//!     accounts_resolved: true,
+//!     compute_units: None,
+//!     absolute_path: vec![0],
+//!     transaction_context: None,
+//! };
+//! ```
+//!
+//! To test processors end-to-end, build the pipeline as usual, without a
+//! datasource, and run it over the fixtures:
+//!
+//! ```ignore
+//! let recorded = Arc::new(Mutex::new(Vec::new()));
+//! let mut pipeline = TestPipeline::new(
+//!     Pipeline::builder().instruction(MyDecoder, RecordingProcessor(recorded.clone())),
+//!     vec![(transaction_metadata, instructions)],
+//! )?;
+//!
+//! pipeline.run().await?;
+//! assert_eq!(recorded.lock().unwrap().len(), 1);
+//! ```
+
+use {
+    crate::{
+        error::CarbonResult,
+        instruction::InstructionsWithMetadata,
+        metrics::Metrics,
+        pipeline::{Pipeline, PipelineBuilder, RunningPipes},
+        transaction::{TransactionMetadata, TransactionVersion},
+    },
+    async_trait::async_trait,
+    solana_program::message::{legacy::Message, MessageHeader, VersionedMessage},
+    solana_pubkey::Pubkey,
+    solana_signature::Signature,
+    solana_transaction_status::TransactionStatusMeta,
+    std::{collections::HashMap, sync::Mutex},
+};
+
+impl TransactionMetadata {
+    /// Returns a `TransactionMetadataBuilder` for test fixtures.
+    pub fn builder() -> TransactionMetadataBuilder {
+        TransactionMetadataBuilder::default()
+    }
+}
+
+/// Builds a `TransactionMetadata` whose fields are consistent with each other.
+///
+/// The fee payer is always the first account key of the message and its only
+/// signer, as it is in a transaction the runtime would accept. Fields that are
+/// not set keep the values of `TransactionMetadata::default()`: slot 0, a
+/// unique signature and fee payer, no block time and no other account keys.
+#[derive(Debug, Clone)]
+pub struct TransactionMetadataBuilder {
+    slot: u64,
+    signature: Signature,
+    block_time: Option<i64>,
+    fee_payer: Pubkey,
+    account_keys: Vec<Pubkey>,
+    meta: TransactionStatusMeta,
+}
+
+impl Default for TransactionMetadataBuilder {
+    fn default() -> Self {
+        Self {
+            slot: 0,
+            signature: Signature::new_unique(),
+            block_time: None,
+            fee_payer: Pubkey::new_unique(),
+            account_keys: Vec::new(),
+            meta: TransactionStatusMeta::default(),
+        }
+    }
+}
+
+impl TransactionMetadataBuilder {
+    pub fn signature(mut self, signature: Signature) -> Self {
+        self.signature = signature;
+        self
+    }
+
+    pub fn slot(mut self, slot: u64) -> Self {
+        self.slot = slot;
+        self
+    }
+
+    pub fn block_time(mut self, block_time: i64) -> Self {
+        self.block_time = Some(block_time);
+        self
+    }
+
+    pub fn fee_payer(mut self, fee_payer: Pubkey) -> Self {
+        self.fee_payer = fee_payer;
+        self
+    }
+
+    /// Sets the account keys of the message following the fee payer.
+    ///
+    /// The fee payer is removed from `account_keys` if it is included, so
+    /// that the message lists every account once.
+    pub fn account_keys(mut self, account_keys: Vec<Pubkey>) -> Self {
+        self.account_keys = account_keys;
+        self
+    }
+
+    pub fn meta(mut self, meta: TransactionStatusMeta) -> Self {
+        self.meta = meta;
+        self
+    }
+
+    pub fn build(self) -> TransactionMetadata {
+        let account_keys = std::iter::once(self.fee_payer)
+            .chain(
+                self.account_keys
+                    .into_iter()
+                    .filter(|account_key| *account_key != self.fee_payer),
+            )
+            .collect();
+
+        TransactionMetadata {
+            slot: self.slot,
+            signature: self.signature,
+            fee_payer: self.fee_payer,
+            meta: self.meta,
+            message: VersionedMessage::Legacy(Message {
+                header: MessageHeader {
+                    num_required_signatures: 1,
+                    num_readonly_signed_accounts: 0,
+                    num_readonly_unsigned_accounts: 0,
+                },
+                account_keys,
+                ..Message::default()
+            }),
+            block_time: self.block_time,
+            version: TransactionVersion::Legacy,
+            source: None,
+        }
+    }
+}
+
+/// Runs the pipes of a pipeline over in-memory transactions, for end-to-end
+/// tests of processors.
+///
+/// Unlike `Pipeline::run`, there is no datasource, channel or shutdown signal:
+/// `run` processes each transaction in order, calls `shutdown` on the pipes so
+/// that buffering processors flush, and returns once all of them are done.
+/// Datasources registered on the builder are ignored.
+///
+/// The transactions are given as the metadata and instructions that the
+/// pipeline would otherwise extract from a `TransactionUpdate`. Instruction
+/// pipes see every instruction, including inner ones, and transaction pipes
+/// see each transaction once, as in a running pipeline.
+pub struct TestPipeline {
+    pipeline: Pipeline,
+    transactions: Vec<(TransactionMetadata, InstructionsWithMetadata)>,
+}
+
+impl TestPipeline {
+    /// Builds the pipeline of `builder`, to be run over `transactions`.
+    ///
+    /// The builder needs no datasource, but returns
+    /// `CarbonError::MissingPipe` if it has no pipes.
+    pub fn new(
+        builder: PipelineBuilder,
+        transactions: Vec<(TransactionMetadata, InstructionsWithMetadata)>,
+    ) -> CarbonResult<Self> {
+        Ok(Self {
+            pipeline: builder.build_without_datasources()?,
+            transactions,
+        })
+    }
+
+    /// Processes every transaction, then shuts the pipes down.
+    ///
+    /// Stops at the first transaction a pipe fails on and returns its error,
+    /// after shutting the pipes down. Transactions are consumed, so running
+    /// again processes nothing.
+    pub async fn run(&mut self) -> CarbonResult<()> {
+        self.pipeline.metrics.initialize_metrics().await?;
+
+        let pipes = RunningPipes::take(&mut self.pipeline);
+        let mut result = Ok(());
+        for (transaction_metadata, instructions_with_metadata) in
+            std::mem::take(&mut self.transactions)
+        {
+            result = pipes
+                .process_instructions(transaction_metadata, instructions_with_metadata)
+                .await;
+            if result.is_err() {
+                break;
+            }
+        }
+        pipes.shutdown().await;
+        pipes.restore(&mut self.pipeline);
+
+        self.pipeline.metrics.shutdown_metrics().await?;
+
+        result
+    }
+}
+
+/// A `Metrics` implementation that keeps everything it is sent in memory, for
+/// assertions on the metrics a pipe or processor reports.
+///
+/// Counters keep their total, gauges their last value and histograms every
+/// recorded value. A value recorded with labels is recorded both under the
+/// plain name and under the name of its series, such as
+/// `instructions_decoded{decoder=MyDecoder}`, with the labels in the order
+/// they were given.
+#[derive(Debug, Default)]
+pub struct RecordingMetrics {
+    counters: Mutex<HashMap<String, u64>>,
+    gauges: Mutex<HashMap<String, f64>>,
+    histograms: Mutex<HashMap<String, Vec<f64>>>,
+}
+
+impl RecordingMetrics {
+    /// Returns the total of the counter `name`, or 0 if it was never
+    /// incremented.
+    pub fn counter(&self, name: &str) -> u64 {
+        self.counters
+            .lock()
+            .unwrap()
+            .get(name)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Returns the last value of the gauge `name`, if it was ever updated.
+    pub fn gauge(&self, name: &str) -> Option<f64> {
+        self.gauges.lock().unwrap().get(name).copied()
+    }
+
+    /// Returns the values recorded in the histogram `name`, oldest first.
+    pub fn histogram(&self, name: &str) -> Vec<f64> {
+        self.histograms
+            .lock()
+            .unwrap()
+            .get(name)
+            .cloned()
+            .unwrap_or_default()
+    }
+}
+
+/// Returns the name of the series of `name` identified by `labels`.
+fn series_name(name: &str, labels: &[(&str, &str)]) -> String {
+    let labels: Vec<_> = labels
+        .iter()
+        .map(|(label, value)| format!("{label}={value}"))
+        .collect();
+    format!("{name}{{{}}}", labels.join(","))
+}
+
+#[async_trait]
+impl Metrics for RecordingMetrics {
+    async fn initialize(&self) -> CarbonResult<()> {
+        Ok(())
+    }
+
+    async fn flush(&self) -> CarbonResult<()> {
+        Ok(())
+    }
+
+    async fn shutdown(&self) -> CarbonResult<()> {
+        Ok(())
+    }
+
+    async fn update_gauge(&self, name: &str, value: f64) -> CarbonResult<()> {
+        self.gauges.lock().unwrap().insert(name.to_string(), value);
+        Ok(())
+    }
+
+    async fn increment_counter(&self, name: &str, value: u64) -> CarbonResult<()> {
+        *self
+            .counters
+            .lock()
+            .unwrap()
+            .entry(name.to_string())
+            .or_default() += value;
+        Ok(())
+    }
+
+    async fn record_histogram(&self, name: &str, value: f64) -> CarbonResult<()> {
+        self.histograms
+            .lock()
+            .unwrap()
+            .entry(name.to_string())
+            .or_default()
+            .push(value);
+        Ok(())
+    }
+
+    async fn update_gauge_with_labels(
+        &self,
+        name: &str,
+        labels: &[(&str, &str)],
+        value: f64,
+    ) -> CarbonResult<()> {
+        self.update_gauge(name, value).await?;
+        self.update_gauge(&series_name(name, labels), value).await
+    }
+
+    async fn increment_counter_with_labels(
+        &self,
+        name: &str,
+        labels: &[(&str, &str)],
+        value: u64,
+    ) -> CarbonResult<()> {
+        self.increment_counter(name, value).await?;
+        self.increment_counter(&series_name(name, labels), value)
+            .await
+    }
+
+    async fn record_histogram_with_labels(
+        &self,
+        name: &str,
+        labels: &[(&str, &str)],
+        value: f64,
+    ) -> CarbonResult<()> {
+        self.record_histogram(name, value).await?;
+        self.record_histogram(&series_name(name, labels), value)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            instruction::{
+                DecodedInstruction, InstructionDecoder, InstructionMetadata,
+                InstructionProcessorInputType,
+            },
+            metrics::MetricsCollection,
+            processor::Processor,
+        },
+        async_trait::async_trait,
+        solana_instruction::Instruction,
+        std::sync::{Arc, Mutex},
+    };
+
+    /// Decodes every instruction of `program_id` into its first data byte.
+    struct FirstByteDecoder {
+        program_id: Pubkey,
+    }
+
+    impl InstructionDecoder<'_> for FirstByteDecoder {
+        type InstructionType = u8;
+
+        fn try_decode_instruction(
+            &self,
+            instruction: &Instruction,
+        ) -> CarbonResult<Option<DecodedInstruction<Self::InstructionType>>> {
+            Ok(
+                (instruction.program_id == self.program_id).then(|| DecodedInstruction {
+                    program_id: instruction.program_id,
+                    data: instruction.data[0],
+                    accounts: instruction.accounts.clone(),
+                    discriminator: vec![],
+                    raw_len: instruction.data.len(),
+                    num_accounts: instruction.accounts.len(),
+                    raw_data: instruction.data.clone(),
+                }),
+            )
+        }
+    }
+
+    /// Records the slot and data of every instruction it processes.
+    struct RecordingProcessor {
+        recorded: Arc<Mutex<Vec<(u64, u8)>>>,
+    }
+
+    #[async_trait]
+    impl Processor for RecordingProcessor {
+        type InputType = InstructionProcessorInputType<u8>;
+
+        async fn process(
+            &mut self,
+            (metadata, instruction, _): Self::InputType,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            self.recorded
+                .lock()
+                .unwrap()
+                .push((metadata.slot(), instruction.data));
+            Ok(())
+        }
+    }
+
+    /// A transaction in `slot` with a single top-level instruction.
+    fn transaction(
+        slot: u64,
+        instruction: Instruction,
+    ) -> (TransactionMetadata, InstructionsWithMetadata) {
+        let transaction_metadata = TransactionMetadata::builder()
+            .slot(slot)
+            .account_keys(vec![instruction.program_id])
+            .build();
+        let metadata = InstructionMetadata {
+            transaction_metadata: transaction_metadata.clone(),
+            stack_height: 1,
+            index: 0,
+            accounts_resolved: true,
+            compute_units: None,
+            absolute_path: vec![],
+            transaction_context: None,
+        };
+
+        (transaction_metadata, vec![(metadata, instruction)])
+    }
+
+    #[test]
+    fn test_builder_produces_consistent_transaction_metadata() {
+        // Arrange
+        let signature = Signature::new_unique();
+        let fee_payer = Pubkey::new_unique();
+        let pool = Pubkey::new_unique();
+        let program_id = Pubkey::new_unique();
+
+        // Act
+        let transaction_metadata = TransactionMetadata::builder()
+            .signature(signature)
+            .slot(42)
+            .block_time(1_700_000_000)
+            .fee_payer(fee_payer)
+            .account_keys(vec![fee_payer, pool, program_id])
+            .build();
+        let metadata = InstructionMetadata {
+            transaction_metadata,
+            stack_height: 1,
+            index: 0,
+            accounts_resolved: true,
+            compute_units: None,
+            absolute_path: vec![],
+            transaction_context: None,
+        };
+
+        // Assert
+        assert_eq!(metadata.slot(), 42);
+        assert_eq!(metadata.block_time(), Some(1_700_000_000));
+        assert_eq!(metadata.transaction_metadata.signature, signature);
+        assert_eq!(
+            metadata.transaction_metadata.message.static_account_keys(),
+            &[fee_payer, pool, program_id]
+        );
+        assert!(metadata.transaction_metadata.message.is_signer(0));
+        assert!(!metadata.transaction_metadata.message.is_signer(1));
+    }
+
+    #[tokio::test]
+    async fn test_pipeline_processes_every_fixture_transaction() {
+        // Arrange
+        let program_id = Pubkey::new_unique();
+        let instruction = |data: u8| Instruction {
+            program_id,
+            accounts: vec![],
+            data: vec![data],
+        };
+        let recorded = Arc::new(Mutex::new(Vec::new()));
+        let mut pipeline = TestPipeline::new(
+            Pipeline::builder().instruction(
+                FirstByteDecoder { program_id },
+                RecordingProcessor {
+                    recorded: recorded.clone(),
+                },
+            ),
+            vec![
+                transaction(1, instruction(7)),
+                transaction(2, instruction(8)),
+            ],
+        )
+        .unwrap();
+
+        // Act
+        let result = pipeline.run().await;
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(*recorded.lock().unwrap(), vec![(1, 7), (2, 8)]);
+    }
+}
@@ -12,6 +12,9 @@
 //!   slot, signature, and fee payer information.
 //! - **ParsedTransaction**: Represents a transaction with its metadata and
 //!   parsed instructions.
+//! - **TransactionContext**: A scratchpad shared by the instruction processors
+//!   of one transaction, handed to a `TransactionContextFinalizer` once the
+//!   transaction has been processed.
 //!
 //! ## Usage
 //!
@@ -33,7 +36,9 @@ use {
     crate::{
         collection::InstructionDecoderCollection,
         error::CarbonResult,
-        instruction::{DecodedInstruction, InstructionMetadata, NestedInstruction},
+        instruction::{
+            DecodedInstruction, InstructionMetadata, NestedInstruction, NestedInstructions,
+        },
         metrics::MetricsCollection,
         processor::Processor,
         schema::{ParsedInstruction, TransactionSchema},
@@ -44,7 +49,11 @@ use {
     serde::de::DeserializeOwned,
     solana_pubkey::Pubkey,
     solana_signature::Signature,
-    std::sync::Arc,
+    std::{
+        any::{Any, TypeId},
+        collections::HashMap,
+        sync::{Arc, Mutex, PoisonError},
+    },
 };
 /// Contains metadata about a transaction, including its slot, signature, fee
 /// payer, transaction status metadata, the version transaction message and its
@@ -57,9 +66,15 @@ use {
 /// - `meta`: Transaction status metadata containing execution status, fees, balances, and other metadata
 /// - `message`: The versioned message containing the transaction instructions and account keys
 /// - `block_time`: The Unix timestamp of when the transaction was processed.
+/// - `version`: Whether the message is a legacy or a v0 message
+/// - `source`: The name of the datasource the transaction came from, set by
+///   `MergedDatasource`, or `None`
 ///
 /// Note: The `block_time` field may not be returned in all scenarios.
-#[derive(Debug, Clone)]
+///
+/// Note: `TransactionMetadata::default()` generates a unique signature and fee
+/// payer, so two default values are not equal.
+#[derive(Debug, Clone, PartialEq)]
 pub struct TransactionMetadata {
     pub slot: u64,
     pub signature: Signature,
@@ -67,6 +82,8 @@ pub struct TransactionMetadata {
     pub meta: solana_transaction_status::TransactionStatusMeta,
     pub message: solana_program::message::VersionedMessage,
     pub block_time: Option<i64>,
+    pub version: TransactionVersion,
+    pub source: Option<Arc<str>>,
 }
 
 impl Default for TransactionMetadata {
@@ -80,9 +97,84 @@ impl Default for TransactionMetadata {
                 solana_sdk::message::Message::default(),
             ),
             block_time: None,
+            version: TransactionVersion::Legacy,
+            source: None,
+        }
+    }
+}
+
+impl TransactionMetadata {
+    /// Returns the fee payer of the transaction.
+    ///
+    /// The fee payer is the first account key of the message, which is always
+    /// a signer. Falls back to the `fee_payer` field if the message has no
+    /// account keys.
+    pub fn fee_payer(&self) -> Pubkey {
+        self.message
+            .static_account_keys()
+            .first()
+            .copied()
+            .unwrap_or(self.fee_payer)
+    }
+
+    /// Returns the signers of the transaction, in message order.
+    ///
+    /// Signers are the first `num_required_signatures` static account keys of
+    /// the message. Addresses loaded from lookup tables can never be signers,
+    /// so for v0 transactions only the static keys are considered.
+    pub fn signers(&self) -> Vec<Pubkey> {
+        let num_required_signatures = self.message.header().num_required_signatures as usize;
+
+        self.message
+            .static_account_keys()
+            .iter()
+            .take(num_required_signatures)
+            .copied()
+            .collect()
+    }
+
+    /// Returns all account keys of the transaction, in the order used by
+    /// compiled instructions to reference them: the static keys, followed by
+    /// the writable and then the readonly addresses loaded from lookup tables.
+    pub fn account_keys(&self) -> Vec<Pubkey> {
+        self.message
+            .static_account_keys()
+            .iter()
+            .chain(self.meta.loaded_addresses.writable.iter())
+            .chain(self.meta.loaded_addresses.readonly.iter())
+            .copied()
+            .collect()
+    }
+
+    /// Returns whether the transaction uses a versioned message, which may
+    /// load accounts from address lookup tables.
+    pub fn is_versioned(&self) -> bool {
+        self.version != TransactionVersion::Legacy
+    }
+}
+
+/// The version of a transaction's message.
+///
+/// Only v0 messages can load accounts from address lookup tables, so analytics
+/// and account resolution can branch on it without matching on the message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TransactionVersion {
+    /// A message without a version prefix.
+    #[default]
+    Legacy,
+    /// A v0 message, which may reference address lookup tables.
+    V0,
+}
+
+impl From<&solana_program::message::VersionedMessage> for TransactionVersion {
+    fn from(message: &solana_program::message::VersionedMessage) -> Self {
+        match message {
+            solana_program::message::VersionedMessage::Legacy(_) => TransactionVersion::Legacy,
+            solana_program::message::VersionedMessage::V0(_) => TransactionVersion::V0,
         }
     }
 }
+
 /// Tries convert transaction update into the metadata.
 ///
 /// This function retrieves core metadata such as the transaction's slot,
@@ -117,8 +209,10 @@ impl TryFrom<crate::datasource::TransactionUpdate> for TransactionMetadata {
                 .first()
                 .ok_or(crate::error::Error::MissingFeePayer)?,
             meta: value.meta.clone(),
+            version: TransactionVersion::from(&value.transaction.message),
             message: value.transaction.message.clone(),
             block_time: value.block_time,
+            source: value.source,
         })
     }
 }
@@ -260,8 +354,8 @@ pub trait TransactionPipes<'a>: Send + Sync {
     ///
     /// # Parameters
     ///
-    /// - `instructions`: A slice of `NestedInstruction` containing the
-    ///   transaction instructions.
+    /// - `instructions`: The `NestedInstructions` containing the transaction
+    ///   instructions.
     /// - `metrics`: A vector of metrics instances for performance tracking.
     ///
     /// # Returns
@@ -270,9 +364,21 @@ pub trait TransactionPipes<'a>: Send + Sync {
     async fn run(
         &mut self,
         transaction_metadata: TransactionMetadata,
-        instructions: &[NestedInstruction],
+        instructions: &NestedInstructions,
         metrics: Arc<MetricsCollection>,
     ) -> CarbonResult<()>;
+
+    /// Shuts down the pipe's processor when the pipeline shuts down.
+    async fn shutdown(&mut self) -> CarbonResult<()> {
+        Ok(())
+    }
+
+    /// Creates another instance of the pipe for the pipeline to run
+    /// concurrently with this one, or `None` if the pipe must handle one
+    /// update at a time.
+    fn fork(&self) -> Option<Box<dyn for<'b> TransactionPipes<'b>>> {
+        None
+    }
 }
 
 #[async_trait]
@@ -284,7 +390,7 @@ where
     async fn run(
         &mut self,
         transaction_metadata: TransactionMetadata,
-        instructions: &[NestedInstruction],
+        instructions: &NestedInstructions,
         metrics: Arc<MetricsCollection>,
     ) -> CarbonResult<()> {
         log::trace!(
@@ -311,4 +417,513 @@ where
 
         Ok(())
     }
+
+    async fn shutdown(&mut self) -> CarbonResult<()> {
+        self.processor.shutdown().await
+    }
+
+    fn fork(&self) -> Option<Box<dyn for<'b> TransactionPipes<'b>>> {
+        Some(Box::new(TransactionPipe {
+            schema: self.schema.clone(),
+            processor: self.processor.fork()?,
+        }))
+    }
+}
+
+/// A trait for recognizing a whole transaction as a single structured type.
+///
+/// Where an `InstructionDecoder` looks at one instruction at a time, a
+/// `TransactionDecoder` sees every instruction of a transaction at once, which
+/// allows it to recognize composite patterns such as a swap wrapped around a
+/// deposit.
+///
+/// # Type Parameters
+///
+/// - `TransactionType`: The type into which a matching transaction is decoded.
+///
+/// # Required Methods
+///
+/// - `decode_transaction`: Returns `Some` if the transaction matches the
+///   pattern this decoder recognizes, or `None` otherwise.
+///
+/// # Example
+///
+/// ```ignore
+/// struct SwapAndDeposit;
+///
+/// struct SwapAndDepositDecoder;
+///
+/// impl TransactionDecoder<'_> for SwapAndDepositDecoder {
+///     type TransactionType = SwapAndDeposit;
+///
+///     fn decode_transaction(
+///         &self,
+///         _transaction_metadata: &TransactionMetadata,
+///         instructions: &NestedInstructions,
+///     ) -> Option<Self::TransactionType> {
+///         let flattened = instructions.flatten();
+///         let has_program = |program_id: &Pubkey| {
+///             flattened
+///                 .iter()
+///                 .any(|ix| ix.instruction.program_id == *program_id)
+///         };
+///
+///         (has_program(&JUPITER_PROGRAM_ID) && has_program(&DRIFT_PROGRAM_ID))
+///             .then_some(SwapAndDeposit)
+///     }
+/// }
+/// ```
+pub trait TransactionDecoder<'a> {
+    type TransactionType;
+
+    fn decode_transaction(
+        &self,
+        transaction_metadata: &'a TransactionMetadata,
+        instructions: &'a NestedInstructions,
+    ) -> Option<Self::TransactionType>;
+}
+
+/// The input type for the transaction decoder processor.
+///
+/// - `T`: The decoded transaction type
+pub type TransactionDecoderProcessorInputType<T> = (TransactionMetadata, T);
+
+/// A processing pipeline for whole transactions, using a decoder and processor.
+///
+/// The `TransactionDecoderPipe` pairs a `TransactionDecoder` with a
+/// `Processor`. The processor is only invoked for transactions the decoder
+/// recognizes. Like every transaction pipe, it runs after the instruction pipes
+/// for a given transaction.
+///
+/// # Type Parameters
+///
+/// - `T`: The type representing the decoded transaction.
+///
+/// # Fields
+///
+/// - `decoder`: The decoder used for recognizing transactions.
+/// - `processor`: The processor that handles decoded transactions.
+pub struct TransactionDecoderPipe<T: Send> {
+    pub decoder:
+        Arc<dyn for<'a> TransactionDecoder<'a, TransactionType = T> + Send + Sync + 'static>,
+    pub processor: Box<
+        dyn Processor<InputType = TransactionDecoderProcessorInputType<T>> + Send + Sync + 'static,
+    >,
+}
+
+#[async_trait]
+impl<T: Send + 'static> TransactionPipes<'_> for TransactionDecoderPipe<T> {
+    async fn run(
+        &mut self,
+        transaction_metadata: TransactionMetadata,
+        instructions: &NestedInstructions,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        log::trace!(
+            "TransactionDecoderPipe::run(instructions: {:?}, metrics)",
+            instructions,
+        );
+
+        if let Some(decoded_transaction) = self
+            .decoder
+            .decode_transaction(&transaction_metadata, instructions)
+        {
+            self.processor
+                .process((transaction_metadata, decoded_transaction), metrics)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn shutdown(&mut self) -> CarbonResult<()> {
+        self.processor.shutdown().await
+    }
+
+    fn fork(&self) -> Option<Box<dyn for<'b> TransactionPipes<'b>>> {
+        Some(Box::new(TransactionDecoderPipe {
+            decoder: self.decoder.clone(),
+            processor: self.processor.fork()?,
+        }))
+    }
+}
+
+/// A scratchpad shared by the instructions of one transaction.
+///
+/// When the pipeline has a `TransactionContextFinalizer`, it creates a context
+/// for every transaction and passes it to the instruction processors in
+/// `InstructionMetadata::transaction_context`, so that a processor can
+/// correlate instructions of the same transaction, such as the transfers of a
+/// swap. Once all instruction and transaction pipes have run, the context is
+/// handed to the finalizer.
+///
+/// The context holds at most one value per type, and clones share the same
+/// values.
+#[derive(Clone, Default)]
+pub struct TransactionContext {
+    values: Arc<Mutex<HashMap<TypeId, Box<dyn Any + Send>>>>,
+}
+
+impl TransactionContext {
+    /// Creates an empty context.
+    ///
+    /// The pipeline creates one per transaction; creating one by hand is
+    /// mostly useful to build an `InstructionMetadata` in tests.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Runs `f` on the value of type `T`, inserting `T::default()` first if
+    /// the context holds none.
+    ///
+    /// The context is locked while `f` runs, so `f` must not access the
+    /// context itself.
+    pub fn with<T: Default + Send + 'static, R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+        let mut values = self.values.lock().unwrap_or_else(PoisonError::into_inner);
+        let value = values
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| Box::new(T::default()))
+            .downcast_mut::<T>()
+            .expect("values are keyed by their type");
+
+        f(value)
+    }
+
+    /// Returns a copy of the value of type `T`, if any.
+    pub fn get<T: Clone + Send + 'static>(&self) -> Option<T> {
+        self.values
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&TypeId::of::<T>())
+            .and_then(|value| value.downcast_ref::<T>())
+            .cloned()
+    }
+
+    /// Stores `value`, returning the previous value of type `T`, if any.
+    pub fn insert<T: Send + 'static>(&self, value: T) -> Option<T> {
+        self.values
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(TypeId::of::<T>(), Box::new(value))
+            .and_then(|previous| previous.downcast::<T>().ok())
+            .map(|previous| *previous)
+    }
+
+    /// Removes and returns the value of type `T`, if any.
+    pub fn remove<T: Send + 'static>(&self) -> Option<T> {
+        self.values
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&TypeId::of::<T>())
+            .and_then(|value| value.downcast::<T>().ok())
+            .map(|value| *value)
+    }
+}
+
+impl std::fmt::Debug for TransactionContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let len = self
+            .values
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len();
+
+        f.debug_struct("TransactionContext")
+            .field("values", &len)
+            .finish()
+    }
+}
+
+/// Receives the `TransactionContext` of every transaction once its
+/// instructions are exhausted.
+///
+/// Setting a finalizer with `PipelineBuilder::transaction_context` is what
+/// makes the pipeline create the contexts. It is called after the instruction
+/// and transaction pipes have run, and an error fails the update like an
+/// error of a pipe does.
+#[async_trait]
+pub trait TransactionContextFinalizer: Send + Sync {
+    async fn finalize(
+        &self,
+        transaction_metadata: &TransactionMetadata,
+        context: TransactionContext,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()>;
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{datasource::TransactionUpdate, instruction::InstructionsWithMetadata},
+        solana_instruction::{AccountMeta, Instruction},
+        std::sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    /// Matches transactions in which both programs are invoked, at any depth.
+    struct ProgramPairDecoder {
+        first_program_id: Pubkey,
+        second_program_id: Pubkey,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct ProgramPair {
+        first_index: usize,
+        second_index: usize,
+    }
+
+    impl TransactionDecoder<'_> for ProgramPairDecoder {
+        type TransactionType = ProgramPair;
+
+        fn decode_transaction(
+            &self,
+            _transaction_metadata: &TransactionMetadata,
+            instructions: &NestedInstructions,
+        ) -> Option<Self::TransactionType> {
+            let flattened = instructions.flatten();
+            let position = |program_id: &Pubkey| {
+                flattened
+                    .iter()
+                    .position(|ix| ix.instruction.program_id == *program_id)
+            };
+
+            Some(ProgramPair {
+                first_index: position(&self.first_program_id)?,
+                second_index: position(&self.second_program_id)?,
+            })
+        }
+    }
+
+    struct CountingProcessor(Arc<AtomicUsize>);
+
+    #[async_trait]
+    impl Processor for CountingProcessor {
+        type InputType = TransactionDecoderProcessorInputType<ProgramPair>;
+
+        async fn process(
+            &mut self,
+            _data: Self::InputType,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    fn create_instructions(program_ids: &[(Pubkey, u32)]) -> NestedInstructions {
+        let instructions: InstructionsWithMetadata = program_ids
+            .iter()
+            .map(|(program_id, stack_height)| {
+                (
+                    InstructionMetadata {
+                        transaction_metadata: TransactionMetadata::default(),
+                        stack_height: *stack_height,
+                        index: 0,
+                        accounts_resolved: true,
+                        compute_units: None,
+                        absolute_path: vec![],
+                        transaction_context: None,
+                    },
+                    Instruction {
+                        program_id: *program_id,
+                        accounts: vec![AccountMeta::new(Pubkey::new_unique(), false)],
+                        data: vec![],
+                    },
+                )
+            })
+            .collect();
+
+        instructions.into()
+    }
+
+    #[test]
+    fn test_transaction_decoder_matches_program_pair() {
+        let first_program_id = Pubkey::new_unique();
+        let second_program_id = Pubkey::new_unique();
+        let decoder = ProgramPairDecoder {
+            first_program_id,
+            second_program_id,
+        };
+
+        let matching = create_instructions(&[
+            (first_program_id, 1),
+            (Pubkey::new_unique(), 2),
+            (second_program_id, 3),
+        ]);
+        let missing_second =
+            create_instructions(&[(first_program_id, 1), (Pubkey::new_unique(), 1)]);
+
+        assert_eq!(
+            decoder.decode_transaction(&TransactionMetadata::default(), &matching),
+            Some(ProgramPair {
+                first_index: 0,
+                second_index: 2,
+            })
+        );
+        assert_eq!(
+            decoder.decode_transaction(&TransactionMetadata::default(), &missing_second),
+            None
+        );
+    }
+
+    #[test]
+    fn test_transaction_decoder_pipe_only_processes_matches() {
+        let first_program_id = Pubkey::new_unique();
+        let second_program_id = Pubkey::new_unique();
+        let processed = Arc::new(AtomicUsize::new(0));
+        let mut pipe = TransactionDecoderPipe {
+            decoder: Arc::new(ProgramPairDecoder {
+                first_program_id,
+                second_program_id,
+            }),
+            processor: Box::new(CountingProcessor(processed.clone())),
+        };
+        let metrics = Arc::new(MetricsCollection::new(vec![]));
+
+        let matching = create_instructions(&[(second_program_id, 1), (first_program_id, 1)]);
+        let not_matching = create_instructions(&[(first_program_id, 1)]);
+
+        futures::executor::block_on(async {
+            pipe.run(TransactionMetadata::default(), &matching, metrics.clone())
+                .await
+                .expect("run matching transaction");
+            pipe.run(TransactionMetadata::default(), &not_matching, metrics)
+                .await
+                .expect("run non-matching transaction");
+        });
+
+        assert_eq!(processed.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_signers_of_legacy_transaction() {
+        let fee_payer = Pubkey::new_unique();
+        let co_signer = Pubkey::new_unique();
+        let readonly_account = Pubkey::new_unique();
+        let instruction = Instruction {
+            program_id: Pubkey::new_unique(),
+            accounts: vec![
+                AccountMeta::new(co_signer, true),
+                AccountMeta::new_readonly(readonly_account, false),
+            ],
+            data: vec![],
+        };
+        let transaction_metadata = TransactionMetadata {
+            message: solana_sdk::message::VersionedMessage::Legacy(
+                solana_sdk::message::Message::new(&[instruction], Some(&fee_payer)),
+            ),
+            ..Default::default()
+        };
+
+        assert_eq!(transaction_metadata.fee_payer(), fee_payer);
+        assert_eq!(transaction_metadata.signers(), vec![fee_payer, co_signer]);
+        assert_eq!(transaction_metadata.account_keys().len(), 4);
+    }
+
+    #[test]
+    fn test_signers_of_v0_transaction_with_lookup_table() {
+        let fee_payer = Pubkey::new_unique();
+        let co_signer = Pubkey::new_unique();
+        let loaded_writable = Pubkey::new_unique();
+        let loaded_readonly = Pubkey::new_unique();
+        let program_id = Pubkey::new_unique();
+        let instruction = Instruction {
+            program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(co_signer, true),
+                AccountMeta::new(loaded_writable, false),
+                AccountMeta::new_readonly(loaded_readonly, false),
+            ],
+            data: vec![],
+        };
+        let lookup_table = solana_sdk::address_lookup_table::AddressLookupTableAccount {
+            key: Pubkey::new_unique(),
+            addresses: vec![loaded_writable, loaded_readonly],
+        };
+        let message = solana_sdk::message::v0::Message::try_compile(
+            &fee_payer,
+            &[instruction],
+            &[lookup_table],
+            solana_sdk::hash::Hash::default(),
+        )
+        .expect("compile v0 message");
+        let mut transaction_metadata = TransactionMetadata {
+            message: solana_sdk::message::VersionedMessage::V0(message),
+            version: TransactionVersion::V0,
+            ..Default::default()
+        };
+        transaction_metadata.meta.loaded_addresses = solana_sdk::message::v0::LoadedAddresses {
+            writable: vec![loaded_writable],
+            readonly: vec![loaded_readonly],
+        };
+
+        assert_eq!(transaction_metadata.fee_payer(), fee_payer);
+        assert_eq!(transaction_metadata.signers(), vec![fee_payer, co_signer]);
+        assert_eq!(
+            transaction_metadata.account_keys(),
+            vec![
+                fee_payer,
+                co_signer,
+                program_id,
+                loaded_writable,
+                loaded_readonly
+            ]
+        );
+    }
+
+    fn transaction_update(message: solana_sdk::message::VersionedMessage) -> TransactionUpdate {
+        TransactionUpdate {
+            signature: Signature::new_unique(),
+            transaction: solana_sdk::transaction::VersionedTransaction {
+                signatures: vec![Signature::new_unique()],
+                message,
+            },
+            meta: solana_transaction_status::TransactionStatusMeta::default(),
+            is_vote: false,
+            slot: 42,
+            block_time: None,
+            commitment: None,
+            source: None,
+        }
+    }
+
+    #[test]
+    fn test_version_of_legacy_transaction() {
+        // Arrange
+        let fee_payer = Pubkey::new_unique();
+        let message = solana_sdk::message::VersionedMessage::Legacy(
+            solana_sdk::message::Message::new(&[], Some(&fee_payer)),
+        );
+
+        // Act
+        let transaction_metadata =
+            TransactionMetadata::try_from(transaction_update(message)).unwrap();
+
+        // Assert
+        assert_eq!(transaction_metadata.version, TransactionVersion::Legacy);
+        assert!(!transaction_metadata.is_versioned());
+    }
+
+    #[test]
+    fn test_version_of_v0_transaction() {
+        // Arrange
+        let fee_payer = Pubkey::new_unique();
+        let message = solana_sdk::message::VersionedMessage::V0(
+            solana_sdk::message::v0::Message::try_compile(
+                &fee_payer,
+                &[],
+                &[],
+                solana_sdk::hash::Hash::default(),
+            )
+            .expect("compile v0 message"),
+        );
+
+        // Act
+        let transaction_metadata =
+            TransactionMetadata::try_from(transaction_update(message)).unwrap();
+
+        // Assert
+        assert_eq!(transaction_metadata.version, TransactionVersion::V0);
+        assert!(transaction_metadata.is_versioned());
+    }
 }
@@ -14,6 +14,10 @@
 //!   from transactions.
 //! - **Account Metadata**: Converts account data into a standardized format for
 //!   transactions.
+//! - **Compiled Instructions**: Resolves the account indexes of compiled
+//!   instructions from raw messages into account metadata.
+//! - **Compute Units**: Attributes the compute units reported in the
+//!   transaction logs to the instructions that consumed them.
 //!
 //! ## Notes
 //!
@@ -25,16 +29,18 @@ use {
         collection::InstructionDecoderCollection,
         datasource::TransactionUpdate,
         error::{CarbonResult, Error},
+        event::PROGRAM_DATA_LOG_PREFIX,
         instruction::{DecodedInstruction, InstructionMetadata},
         schema::ParsedInstruction,
         transaction::TransactionMetadata,
     },
+    base64::{engine::general_purpose::STANDARD, Engine},
     solana_instruction::AccountMeta,
     solana_program::{
         instruction::CompiledInstruction,
         message::{
             v0::{LoadedAddresses, LoadedMessage},
-            VersionedMessage,
+            MessageHeader, VersionedMessage,
         },
     },
     solana_pubkey::Pubkey,
@@ -45,7 +51,7 @@ use {
     solana_transaction_status::{
         option_serializer::OptionSerializer, InnerInstruction, InnerInstructions, Reward,
         TransactionStatusMeta, TransactionTokenBalance, UiInstruction, UiLoadedAddresses,
-        UiTransactionStatusMeta,
+        UiTransactionEncoding, UiTransactionStatusMeta,
     },
     std::{collections::HashSet, str::FromStr},
 };
@@ -56,6 +62,12 @@ use {
 /// with metadata such as stack height and account information. It provides a
 /// detailed breakdown of each instruction, useful for further processing.
 ///
+/// Accounts of v0 transactions are resolved against the static account keys
+/// followed by the addresses loaded from lookup tables, as provided in the
+/// transaction status metadata. Accounts that cannot be resolved keep their
+/// position with a default public key, and the instruction's metadata has
+/// `accounts_resolved` set to `false`.
+///
 /// # Parameters
 ///
 /// - `transaction_metadata`: Metadata about the transaction from which
@@ -84,12 +96,37 @@ pub fn extract_instructions_with_metadata(
     );
     let message = transaction_update.transaction.message.clone();
     let meta = transaction_update.meta.clone();
+    let compute_units = meta
+        .log_messages
+        .as_deref()
+        .map(extract_compute_units)
+        .unwrap_or_default();
+    let top_level_compute_units = |index: usize| {
+        compute_units
+            .get(index)
+            .and_then(|units| units.compute_units)
+    };
+    let inner_compute_units = |index: usize, inner_index: usize| {
+        compute_units.get(index).and_then(|units| {
+            units
+                .inner_compute_units
+                .get(inner_index)
+                .copied()
+                .flatten()
+        })
+    };
 
     let mut instructions_with_metadata =
         Vec::<(InstructionMetadata, solana_instruction::Instruction)>::new();
 
     match message {
         VersionedMessage::Legacy(legacy) => {
+            let is_resolved = |program_id_index: u8, account_indexes: &[u8]| {
+                std::iter::once(&program_id_index)
+                    .chain(account_indexes)
+                    .all(|index| (*index as usize) < legacy.account_keys.len())
+            };
+
             for (i, compiled_instruction) in legacy.instructions.iter().enumerate() {
                 let program_id = *legacy
                     .account_keys
@@ -99,13 +136,14 @@ pub fn extract_instructions_with_metadata(
                 let accounts = compiled_instruction
                     .accounts
                     .iter()
-                    .filter_map(|account_index| {
-                        let account_pubkey = legacy.account_keys.get(*account_index as usize)?;
-                        Some(AccountMeta {
-                            pubkey: *account_pubkey,
-                            is_writable: legacy.is_maybe_writable(*account_index as usize, None),
-                            is_signer: legacy.is_signer(*account_index as usize),
-                        })
+                    .map(|account_index| AccountMeta {
+                        pubkey: legacy
+                            .account_keys
+                            .get(*account_index as usize)
+                            .copied()
+                            .unwrap_or_default(),
+                        is_writable: legacy.is_maybe_writable(*account_index as usize, None),
+                        is_signer: legacy.is_signer(*account_index as usize),
                     })
                     .collect::<Vec<_>>();
 
@@ -114,6 +152,13 @@ pub fn extract_instructions_with_metadata(
                         transaction_metadata: transaction_metadata.clone(),
                         stack_height: 1,
                         index: i as u32,
+                        accounts_resolved: is_resolved(
+                            compiled_instruction.program_id_index,
+                            &compiled_instruction.accounts,
+                        ),
+                        compute_units: top_level_compute_units(i),
+                        absolute_path: vec![],
+                        transaction_context: None,
                     },
                     solana_instruction::Instruction {
                         program_id,
@@ -125,7 +170,9 @@ pub fn extract_instructions_with_metadata(
                 if let Some(inner_instructions) = &meta.inner_instructions {
                     for inner_instructions_per_tx in inner_instructions {
                         if inner_instructions_per_tx.index == i as u8 {
-                            for inner_instruction in inner_instructions_per_tx.instructions.iter() {
+                            for (j, inner_instruction) in
+                                inner_instructions_per_tx.instructions.iter().enumerate()
+                            {
                                 let program_id = *legacy
                                     .account_keys
                                     .get(inner_instruction.instruction.program_id_index as usize)
@@ -135,16 +182,15 @@ pub fn extract_instructions_with_metadata(
                                     .instruction
                                     .accounts
                                     .iter()
-                                    .filter_map(|account_index| {
-                                        let account_pubkey =
-                                            legacy.account_keys.get(*account_index as usize)?;
-
-                                        Some(AccountMeta {
-                                            pubkey: *account_pubkey,
-                                            is_writable: legacy
-                                                .is_maybe_writable(*account_index as usize, None),
-                                            is_signer: legacy.is_signer(*account_index as usize),
-                                        })
+                                    .map(|account_index| AccountMeta {
+                                        pubkey: legacy
+                                            .account_keys
+                                            .get(*account_index as usize)
+                                            .copied()
+                                            .unwrap_or_default(),
+                                        is_writable: legacy
+                                            .is_maybe_writable(*account_index as usize, None),
+                                        is_signer: legacy.is_signer(*account_index as usize),
                                     })
                                     .collect();
 
@@ -153,6 +199,13 @@ pub fn extract_instructions_with_metadata(
                                         transaction_metadata: transaction_metadata.clone(),
                                         stack_height: inner_instruction.stack_height.unwrap_or(1),
                                         index: inner_instructions_per_tx.index as u32,
+                                        accounts_resolved: is_resolved(
+                                            inner_instruction.instruction.program_id_index,
+                                            &inner_instruction.instruction.accounts,
+                                        ),
+                                        compute_units: inner_compute_units(i, j),
+                                        absolute_path: vec![],
+                                        transaction_context: None,
                                     },
                                     solana_instruction::Instruction {
                                         program_id,
@@ -172,11 +225,36 @@ pub fn extract_instructions_with_metadata(
                 readonly: meta.loaded_addresses.readonly.to_vec(),
             };
 
+            // Compiled instructions index into the static keys followed by the
+            // addresses loaded from lookup tables, so the loaded addresses are
+            // needed to resolve any account past the static keys.
+            let expected_loaded_addresses = v0
+                .address_table_lookups
+                .iter()
+                .map(|lookup| lookup.writable_indexes.len() + lookup.readonly_indexes.len())
+                .sum::<usize>();
+            let loaded_addresses_complete = loaded_addresses.len() == expected_loaded_addresses;
+
+            if !loaded_addresses_complete {
+                log::warn!(
+                    "Transaction {} references {} lookup table addresses, but {} were loaded",
+                    transaction_metadata.signature,
+                    expected_loaded_addresses,
+                    loaded_addresses.len()
+                );
+            }
+
             let loaded_message = LoadedMessage::new(
                 v0.clone(),
                 loaded_addresses,
                 &ReservedAccountKeys::empty_key_set(),
             );
+            let is_resolved = |program_id_index: u8, account_indexes: &[u8]| {
+                loaded_addresses_complete
+                    && std::iter::once(&program_id_index)
+                        .chain(account_indexes)
+                        .all(|index| (*index as usize) < loaded_message.account_keys().len())
+            };
 
             for (i, compiled_instruction) in v0.instructions.iter().enumerate() {
                 let program_id = *loaded_message
@@ -204,6 +282,13 @@ pub fn extract_instructions_with_metadata(
                         transaction_metadata: transaction_metadata.clone(),
                         stack_height: 1,
                         index: i as u32,
+                        accounts_resolved: is_resolved(
+                            compiled_instruction.program_id_index,
+                            &compiled_instruction.accounts,
+                        ),
+                        compute_units: top_level_compute_units(i),
+                        absolute_path: vec![],
+                        transaction_context: None,
                     },
                     solana_instruction::Instruction {
                         program_id,
@@ -215,7 +300,9 @@ pub fn extract_instructions_with_metadata(
                 if let Some(inner_instructions) = &meta.inner_instructions {
                     for inner_instructions_per_tx in inner_instructions {
                         if inner_instructions_per_tx.index == i as u8 {
-                            for inner_instruction in inner_instructions_per_tx.instructions.iter() {
+                            for (j, inner_instruction) in
+                                inner_instructions_per_tx.instructions.iter().enumerate()
+                            {
                                 let program_id = *loaded_message
                                     .account_keys()
                                     .get(inner_instruction.instruction.program_id_index as usize)
@@ -247,6 +334,13 @@ pub fn extract_instructions_with_metadata(
                                         transaction_metadata: transaction_metadata.clone(),
                                         stack_height: inner_instruction.stack_height.unwrap_or(1),
                                         index: inner_instructions_per_tx.index as u32,
+                                        accounts_resolved: is_resolved(
+                                            inner_instruction.instruction.program_id_index,
+                                            &inner_instruction.instruction.accounts,
+                                        ),
+                                        compute_units: inner_compute_units(i, j),
+                                        absolute_path: vec![],
+                                        transaction_context: None,
                                     },
                                     solana_instruction::Instruction {
                                         program_id,
@@ -265,6 +359,165 @@ pub fn extract_instructions_with_metadata(
     Ok(instructions_with_metadata)
 }
 
+/// A line written by the runtime to the transaction logs, as read by
+/// [`ProgramLogs`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ProgramLog<'a> {
+    /// `Program <id> invoke [<depth>]`, where `depth` is `1` for top-level
+    /// instructions.
+    Invoke { depth: Option<usize> },
+    /// `Program <id> consumed <n> of <m> compute units`, with `units` set to
+    /// `n`.
+    Consumed { units: u64 },
+    /// `Program data: <fields>`, with the base64 fields still encoded and
+    /// `program_id` set to the program that logged them.
+    Data { program_id: Pubkey, fields: &'a str },
+    /// `Program <id> success` or `Program <id> failed: ...`.
+    Return,
+}
+
+/// Iterates over the runtime lines of transaction logs.
+///
+/// The `invoke`, `success` and `failed` lines are followed as a stack of
+/// invocations, so that data logged by a CPI is attributed to the invoked
+/// program rather than its caller. Program logs (`Program log: ...`) start
+/// with something other than a program id, so they cannot be mistaken for
+/// runtime lines and are skipped, as is data logged outside of an invocation.
+/// Iteration stops at `Log truncated`, since the stack can no longer be
+/// followed reliably.
+pub(crate) struct ProgramLogs<'a> {
+    log_messages: core::slice::Iter<'a, String>,
+    invocation_stack: Vec<Pubkey>,
+}
+
+impl<'a> ProgramLogs<'a> {
+    pub(crate) fn new(log_messages: &'a [String]) -> Self {
+        Self {
+            log_messages: log_messages.iter(),
+            invocation_stack: Vec::new(),
+        }
+    }
+}
+
+impl<'a> Iterator for ProgramLogs<'a> {
+    type Item = ProgramLog<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for log_message in self.log_messages.by_ref() {
+            if log_message == "Log truncated" {
+                log::debug!("Transaction logs are truncated, ignoring the remaining lines");
+                self.log_messages = [].iter();
+                return None;
+            }
+
+            if let Some(fields) = log_message.strip_prefix(PROGRAM_DATA_LOG_PREFIX) {
+                let Some(&program_id) = self.invocation_stack.last() else {
+                    log::debug!("Found program data outside of a program invocation");
+                    continue;
+                };
+                return Some(ProgramLog::Data { program_id, fields });
+            }
+
+            let Some(rest) = log_message.strip_prefix("Program ") else {
+                continue;
+            };
+            let mut tokens = rest.split_whitespace();
+            let (Some(Ok(program_id)), Some(action)) =
+                (tokens.next().map(Pubkey::from_str), tokens.next())
+            else {
+                continue;
+            };
+
+            match action {
+                "invoke" => {
+                    self.invocation_stack.push(program_id);
+                    let depth = tokens
+                        .next()
+                        .and_then(|depth| depth.strip_prefix('[')?.strip_suffix(']')?.parse().ok());
+                    return Some(ProgramLog::Invoke { depth });
+                }
+                "consumed" => {
+                    if let Some(Ok(units)) = tokens.next().map(str::parse) {
+                        return Some(ProgramLog::Consumed { units });
+                    }
+                }
+                "success" | "failed:" => {
+                    self.invocation_stack.pop();
+                    return Some(ProgramLog::Return);
+                }
+                _ => {}
+            }
+        }
+
+        None
+    }
+}
+
+/// The compute units consumed by a top-level instruction and by each of its
+/// inner instructions, as reported in the transaction logs.
+///
+/// `inner_compute_units` follows the order in which the inner instructions
+/// were invoked, which is also their order in the transaction's inner
+/// instructions.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InstructionComputeUnits {
+    pub compute_units: Option<u64>,
+    pub inner_compute_units: Vec<Option<u64>>,
+}
+
+/// Extracts the compute units consumed by every instruction from the log
+/// messages of a transaction, indexed by top-level instruction.
+///
+/// The runtime logs `Program <id> invoke [<depth>]` when an instruction
+/// starts, `Program <id> consumed <n> of <m> compute units` before it
+/// returns, and `Program <id> success` or `Program <id> failed: ...` when it
+/// returns. Following these lines as a stack attributes each consumption line
+/// to the invocation it belongs to, including nested CPIs. The units consumed
+/// by an instruction include those of the instructions it invoked.
+///
+/// Builtin programs, such as the system program, do not log their
+/// consumption, and instructions after a `Log truncated` line cannot be
+/// followed, so their compute units are `None`.
+pub fn extract_compute_units(log_messages: &[String]) -> Vec<InstructionComputeUnits> {
+    let mut instructions = Vec::<InstructionComputeUnits>::new();
+    // The invocations in progress, as (top-level index, inner index).
+    let mut stack = Vec::<(usize, Option<usize>)>::new();
+
+    for log in ProgramLogs::new(log_messages) {
+        match log {
+            ProgramLog::Invoke { depth: Some(1) } => {
+                instructions.push(InstructionComputeUnits::default());
+                stack.push((instructions.len() - 1, None));
+            }
+            ProgramLog::Invoke { .. } => {
+                let index = instructions.len().saturating_sub(1);
+                let Some(instruction) = instructions.last_mut() else {
+                    continue;
+                };
+                instruction.inner_compute_units.push(None);
+                stack.push((index, Some(instruction.inner_compute_units.len() - 1)));
+            }
+            ProgramLog::Consumed { units } => {
+                let Some(&(index, inner_index)) = stack.last() else {
+                    continue;
+                };
+                match inner_index {
+                    Some(inner_index) => {
+                        instructions[index].inner_compute_units[inner_index] = Some(units)
+                    }
+                    None => instructions[index].compute_units = Some(units),
+                }
+            }
+            ProgramLog::Return => {
+                stack.pop();
+            }
+            ProgramLog::Data { .. } => {}
+        }
+    }
+
+    instructions
+}
+
 /// Extracts account metadata from a compiled instruction and transaction
 /// message.
 ///
@@ -320,13 +573,86 @@ pub fn extract_account_metas(
     Ok(accounts)
 }
 
+const BPF_LOADER_UPGRADEABLE_ID: Pubkey =
+    Pubkey::from_str_const("BPFLoaderUpgradeab1e11111111111111111111111");
+
+/// Resolves a compiled instruction, whose program and accounts are indexes
+/// into the account keys of its message, into an `Instruction`.
+///
+/// This lets consumers of raw messages feed carbon decoders. The indexes
+/// refer to `message_keys`, the static account keys of the message, followed
+/// by the writable and then the readonly addresses in `loaded_addresses`,
+/// which are empty for legacy messages. The flags are derived as the runtime
+/// does:
+///
+/// - The first `num_required_signatures` static keys are signers, of which the
+///   last `num_readonly_signed_accounts` are readonly.
+/// - The last `num_readonly_unsigned_accounts` static keys are readonly, the
+///   other unsigned static keys are writable.
+/// - Addresses loaded from lookup tables are never signers, and are writable if
+///   they were loaded as writable.
+/// - The program of the instruction is demoted to readonly, unless the
+///   upgradeable BPF loader is among the static keys.
+///
+/// Indexes past the available keys resolve to `Pubkey::default()`.
+pub fn resolve_compiled_instruction(
+    message_keys: &[Pubkey],
+    header: &MessageHeader,
+    loaded_addresses: &LoadedAddresses,
+    compiled_instruction: &CompiledInstruction,
+) -> solana_instruction::Instruction {
+    let num_static_keys = message_keys.len();
+    let num_signers = header.num_required_signatures as usize;
+    let program_index = compiled_instruction.program_id_index as usize;
+    let demote_program_id = !message_keys.contains(&BPF_LOADER_UPGRADEABLE_ID);
+
+    let pubkey = |index: usize| {
+        message_keys
+            .iter()
+            .chain(&loaded_addresses.writable)
+            .chain(&loaded_addresses.readonly)
+            .nth(index)
+            .copied()
+            .unwrap_or_default()
+    };
+    let is_writable = |index: usize| {
+        let is_writable_index = if index < num_signers {
+            index < num_signers.saturating_sub(header.num_readonly_signed_accounts as usize)
+        } else if index < num_static_keys {
+            index < num_static_keys.saturating_sub(header.num_readonly_unsigned_accounts as usize)
+        } else {
+            index - num_static_keys < loaded_addresses.writable.len()
+        };
+
+        is_writable_index && !(demote_program_id && index == program_index)
+    };
+
+    solana_instruction::Instruction {
+        program_id: pubkey(program_index),
+        accounts: compiled_instruction
+            .accounts
+            .iter()
+            .map(|account_index| {
+                let index = *account_index as usize;
+                AccountMeta {
+                    pubkey: pubkey(index),
+                    is_signer: index < num_signers && index < num_static_keys,
+                    is_writable: is_writable(index),
+                }
+            })
+            .collect(),
+        data: compiled_instruction.data.clone(),
+    }
+}
+
 /// Unnests parsed instructions, producing an array of `(InstructionMetadata,
 /// DecodedInstruction<T>)` tuple
 ///
 /// This function takes a vector of `ParsedInstruction` and unnests them into a
 /// vector of `(InstructionMetadata, DecodedInstruction<T>)` tuples.
 /// It recursively processes nested instructions, increasing the stack height
-/// for each level of nesting.
+/// for each level of nesting, and sets each instruction's `absolute_path`
+/// relative to `instructions`.
 ///
 /// # Parameters
 ///
@@ -357,14 +683,25 @@ pub fn unnest_parsed_instructions<T: InstructionDecoderCollection>(
                 transaction_metadata: transaction_metadata.clone(),
                 stack_height,
                 index: ix_idx as u32 + 1,
+                accounts_resolved: true,
+                compute_units: None,
+                absolute_path: vec![ix_idx as u32],
+                transaction_context: None,
             },
             parsed_instruction.instruction,
         ));
-        result.extend(unnest_parsed_instructions(
-            transaction_metadata.clone(),
-            parsed_instruction.inner_instructions,
-            stack_height + 1,
-        ));
+        result.extend(
+            unnest_parsed_instructions(
+                transaction_metadata.clone(),
+                parsed_instruction.inner_instructions,
+                stack_height + 1,
+            )
+            .into_iter()
+            .map(|(mut metadata, instruction)| {
+                metadata.absolute_path.insert(0, ix_idx as u32);
+                (metadata, instruction)
+            }),
+        );
     }
 
     result
@@ -397,6 +734,32 @@ pub fn transaction_metadata_from_original_meta(
         "transaction_metadata_from_original_meta(meta_original: {:?})",
         meta_original
     );
+    transaction_metadata_from_original_meta_with_encoding(
+        meta_original,
+        UiTransactionEncoding::Base58,
+    )
+}
+
+/// Converts UI transaction metadata whose compiled inner instruction data is
+/// encoded with `encoding`.
+///
+/// The RPC specification encodes inner instruction data in base58, which is
+/// what `transaction_metadata_from_original_meta` assumes. Some providers send
+/// base64 instead; datasources that know the encoding of their source use this
+/// function so that decoders always see the raw instruction bytes.
+///
+/// Inner instruction data that is not valid for `encoding` is logged and
+/// replaced with empty data, which no decoder matches, so that the rest of the
+/// transaction is still processed.
+pub fn transaction_metadata_from_original_meta_with_encoding(
+    meta_original: UiTransactionStatusMeta,
+    encoding: UiTransactionEncoding,
+) -> CarbonResult<TransactionStatusMeta> {
+    log::trace!(
+        "transaction_metadata_from_original_meta_with_encoding(meta_original: {:?}, encoding: {:?})",
+        meta_original,
+        encoding
+    );
     Ok(TransactionStatusMeta {
         status: meta_original.status,
         fee: meta_original.fee,
@@ -415,9 +778,15 @@ pub fn transaction_metadata_from_original_meta(
                         .map(|ui_instruction| match ui_instruction {
                             UiInstruction::Compiled(compiled_ui_instruction) => {
                                 let decoded_data =
-                                    bs58::decode(compiled_ui_instruction.data.clone())
-                                        .into_vec()
-                                        .unwrap_or_else(|_| vec![]);
+                                    decode_instruction_data(encoding, &compiled_ui_instruction.data)
+                                        .unwrap_or_else(|err| {
+                                            log::warn!(
+                                                "Ignoring the data of an inner instruction of instruction {}: {}",
+                                                inner_instruction_group.index,
+                                                err
+                                            );
+                                            vec![]
+                                        });
                                 InnerInstruction {
                                     instruction: CompiledInstruction {
                                         program_id_index: compiled_ui_instruction.program_id_index,
@@ -541,6 +910,32 @@ pub fn transaction_metadata_from_original_meta(
     })
 }
 
+/// Decodes the data of a compiled instruction encoded with `encoding`.
+///
+/// Base64 data is decoded as base64. Every other encoding is decoded as base58,
+/// which is how the RPC encodes compiled instruction data for the binary and
+/// JSON transaction encodings.
+///
+/// # Errors
+///
+/// Returns `Error::FailedToDeserialize` if `data` is not valid for `encoding`.
+pub fn decode_instruction_data(
+    encoding: UiTransactionEncoding,
+    data: &str,
+) -> CarbonResult<Vec<u8>> {
+    match encoding {
+        UiTransactionEncoding::Base64 => STANDARD.decode(data).map_err(|err| {
+            Error::FailedToDeserialize(format!("Invalid base64 instruction data: {}", err))
+        }),
+        UiTransactionEncoding::Base58
+        | UiTransactionEncoding::Binary
+        | UiTransactionEncoding::Json
+        | UiTransactionEncoding::JsonParsed => bs58::decode(data).into_vec().map_err(|err| {
+            Error::FailedToDeserialize(format!("Invalid base58 instruction data: {}", err))
+        }),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::vec;
@@ -790,6 +1185,8 @@ mod tests {
             is_vote: false,
             slot: 123,
             block_time: Some(123),
+            commitment: None,
+            source: None,
         };
         let transaction_metadata = transaction_update
             .clone()
@@ -1214,6 +1611,8 @@ mod tests {
             is_vote: false,
             slot: 123,
             block_time: Some(123),
+            commitment: None,
+            source: None,
         };
         let transaction_metadata = transaction_update
             .clone()
@@ -1230,6 +1629,379 @@ mod tests {
         assert_eq!(nested_instructions[0].inner_instructions.len(), 0);
         assert_eq!(nested_instructions[1].inner_instructions.len(), 0);
         assert_eq!(nested_instructions[2].inner_instructions.len(), 0);
-        assert_eq!(nested_instructions[3].inner_instructions.len(), 5);
+        assert_eq!(nested_instructions[3].inner_instructions.len(), 2);
+    }
+
+    #[test]
+    fn test_extract_instructions_with_metadata_resolves_lookup_table_accounts() {
+        // Arrange
+        let fee_payer = Pubkey::new_unique();
+        let program_id = Pubkey::new_unique();
+        let loaded_writable = Pubkey::new_unique();
+        let loaded_readonly = Pubkey::new_unique();
+        let instruction = solana_instruction::Instruction {
+            program_id,
+            accounts: vec![
+                AccountMeta::new(fee_payer, true),
+                AccountMeta::new(loaded_writable, false),
+                AccountMeta::new_readonly(loaded_readonly, false),
+            ],
+            data: vec![1, 2, 3],
+        };
+        let lookup_table = solana_sdk::address_lookup_table::AddressLookupTableAccount {
+            key: Pubkey::new_unique(),
+            addresses: vec![loaded_readonly, Pubkey::new_unique(), loaded_writable],
+        };
+        let message =
+            v0::Message::try_compile(&fee_payer, &[instruction], &[lookup_table], Hash::default())
+                .expect("compile v0 message");
+        let transaction_update = |loaded_addresses: LoadedAddresses| TransactionUpdate {
+            signature: Signature::default(),
+            transaction: VersionedTransaction {
+                signatures: vec![Signature::default()],
+                message: VersionedMessage::V0(message.clone()),
+            },
+            meta: TransactionStatusMeta {
+                loaded_addresses,
+                ..Default::default()
+            },
+            is_vote: false,
+            slot: 123,
+            block_time: Some(123),
+            commitment: None,
+            source: None,
+        };
+        let resolved_update = transaction_update(LoadedAddresses {
+            writable: vec![loaded_writable],
+            readonly: vec![loaded_readonly],
+        });
+        let unresolved_update = transaction_update(LoadedAddresses::default());
+
+        // Act
+        let extract = |transaction_update: TransactionUpdate| {
+            let transaction_metadata = transaction_update
+                .clone()
+                .try_into()
+                .expect("transaction metadata");
+            extract_instructions_with_metadata(&transaction_metadata, &transaction_update)
+                .expect("extract instructions with metadata")
+        };
+        let resolved = extract(resolved_update);
+        let unresolved = extract(unresolved_update);
+
+        // Assert
+        assert_eq!(resolved.len(), 1);
+        assert!(resolved[0].0.accounts_resolved);
+        assert_eq!(resolved[0].1.program_id, program_id);
+        assert_eq!(
+            resolved[0].1.accounts,
+            vec![
+                AccountMeta::new(fee_payer, true),
+                AccountMeta::new(loaded_writable, false),
+                AccountMeta::new_readonly(loaded_readonly, false),
+            ]
+        );
+
+        assert_eq!(unresolved.len(), 1);
+        assert!(!unresolved[0].0.accounts_resolved);
+        assert_eq!(unresolved[0].1.accounts.len(), 3);
+        assert_eq!(unresolved[0].1.accounts[0].pubkey, fee_payer);
+        assert_eq!(unresolved[0].1.accounts[1].pubkey, Pubkey::default());
+    }
+
+    #[test]
+    fn test_extract_compute_units_from_logs() {
+        // Arrange
+        let swap_program = Pubkey::new_unique();
+        let token_program = Pubkey::new_unique();
+        let memo_program = Pubkey::new_unique();
+        let log_messages: Vec<String> = [
+            format!("Program {swap_program} invoke [1]"),
+            "Program log: Instruction: Swap".to_string(),
+            format!("Program {token_program} invoke [2]"),
+            "Program log: Instruction: Transfer".to_string(),
+            format!("Program {token_program} consumed 4645 of 180000 compute units"),
+            format!("Program {token_program} success"),
+            format!("Program {token_program} invoke [2]"),
+            "Program log: Instruction: Transfer".to_string(),
+            format!("Program {token_program} consumed 4736 of 170000 compute units"),
+            format!("Program {token_program} success"),
+            format!("Program {swap_program} consumed 31250 of 200000 compute units"),
+            format!("Program {swap_program} success"),
+            format!("Program {memo_program} invoke [1]"),
+            "Program log: Memo (len 5): \"hello\"".to_string(),
+            format!("Program {memo_program} consumed 6310 of 168750 compute units"),
+            format!("Program {memo_program} success"),
+        ]
+        .into();
+        let transaction_update = TransactionUpdate {
+            signature: Signature::default(),
+            transaction: VersionedTransaction {
+                signatures: vec![Signature::default()],
+                message: VersionedMessage::Legacy(Message::new(
+                    &[
+                        solana_instruction::Instruction::new_with_bytes(swap_program, &[], vec![]),
+                        solana_instruction::Instruction::new_with_bytes(memo_program, &[], vec![]),
+                    ],
+                    Some(&Pubkey::new_unique()),
+                )),
+            },
+            meta: TransactionStatusMeta {
+                inner_instructions: Some(vec![InnerInstructions {
+                    index: 0,
+                    instructions: vec![
+                        InnerInstruction {
+                            instruction: CompiledInstruction::new_from_raw_parts(3, vec![], vec![]),
+                            stack_height: Some(2),
+                        };
+                        2
+                    ],
+                }]),
+                log_messages: Some(log_messages.clone()),
+                ..Default::default()
+            },
+            is_vote: false,
+            slot: 123,
+            block_time: None,
+            commitment: None,
+            source: None,
+        };
+        let transaction_metadata = transaction_update
+            .clone()
+            .try_into()
+            .expect("transaction metadata");
+
+        // Act
+        let compute_units = extract_compute_units(&log_messages);
+        let instructions_with_metadata =
+            extract_instructions_with_metadata(&transaction_metadata, &transaction_update)
+                .expect("extract instructions with metadata");
+
+        // Assert
+        assert_eq!(
+            compute_units,
+            vec![
+                InstructionComputeUnits {
+                    compute_units: Some(31250),
+                    inner_compute_units: vec![Some(4645), Some(4736)],
+                },
+                InstructionComputeUnits {
+                    compute_units: Some(6310),
+                    inner_compute_units: vec![],
+                },
+            ]
+        );
+        assert_eq!(
+            instructions_with_metadata
+                .iter()
+                .map(|(metadata, _)| (metadata.stack_height, metadata.compute_units))
+                .collect::<Vec<_>>(),
+            vec![
+                (1, Some(31250)),
+                (2, Some(4645)),
+                (2, Some(4736)),
+                (1, Some(6310)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_compute_units_stops_at_truncated_logs() {
+        // Arrange
+        let program = Pubkey::new_unique();
+        let log_messages = vec![
+            format!("Program {program} invoke [1]"),
+            format!("Program {program} consumed 1000 of 200000 compute units"),
+            format!("Program {program} success"),
+            format!("Program {program} invoke [1]"),
+            "Log truncated".to_string(),
+        ];
+
+        // Act
+        let compute_units = extract_compute_units(&log_messages);
+
+        // Assert
+        assert_eq!(compute_units.len(), 2);
+        assert_eq!(compute_units[0].compute_units, Some(1000));
+        assert_eq!(compute_units[1].compute_units, None);
+    }
+
+    #[test]
+    fn test_decode_instruction_data_base58_and_base64_give_identical_bytes() {
+        // Arrange
+        let data = vec![3, 0, 255, 17, 42, 128, 9, 64];
+        let base58_data = bs58::encode(&data).into_string();
+        let base64_data = STANDARD.encode(&data);
+
+        // Act
+        let from_base58 = decode_instruction_data(UiTransactionEncoding::Base58, &base58_data)
+            .expect("valid base58");
+        let from_base64 = decode_instruction_data(UiTransactionEncoding::Base64, &base64_data)
+            .expect("valid base64");
+        let invalid = decode_instruction_data(UiTransactionEncoding::Base58, &base64_data);
+
+        // Assert
+        assert_eq!(from_base58, data);
+        assert_eq!(from_base64, data);
+        assert!(matches!(invalid, Err(Error::FailedToDeserialize(_))));
+    }
+
+    #[test]
+    fn test_transaction_metadata_from_original_meta_with_base64_inner_instructions() {
+        // Arrange
+        let base58_meta = carbon_test_utils::read_transaction_meta("tests/fixtures/cpi_tx.json")
+            .expect("read fixture");
+        let mut base64_meta = base58_meta.clone();
+        if let OptionSerializer::Some(inner_instructions) = &mut base64_meta.inner_instructions {
+            for ui_instruction in inner_instructions
+                .iter_mut()
+                .flat_map(|inner_instruction_group| inner_instruction_group.instructions.iter_mut())
+            {
+                if let UiInstruction::Compiled(compiled_ui_instruction) = ui_instruction {
+                    let data = bs58::decode(&compiled_ui_instruction.data)
+                        .into_vec()
+                        .expect("base58 fixture data");
+                    compiled_ui_instruction.data = STANDARD.encode(data);
+                }
+            }
+        }
+
+        // Act
+        let from_base58 = transaction_metadata_from_original_meta(base58_meta)
+            .expect("transaction metadata from base58 meta");
+        let from_base64 = transaction_metadata_from_original_meta_with_encoding(
+            base64_meta,
+            UiTransactionEncoding::Base64,
+        )
+        .expect("transaction metadata from base64 meta");
+
+        // Assert
+        assert!(!from_base58.inner_instructions.as_ref().unwrap().is_empty());
+        assert_eq!(from_base58, from_base64);
+    }
+
+    #[test]
+    fn test_transaction_metadata_from_original_meta_keeps_undecodable_inner_instructions() {
+        // Arrange
+        let valid_meta = carbon_test_utils::read_transaction_meta("tests/fixtures/cpi_tx.json")
+            .expect("read fixture");
+        let mut invalid_meta = valid_meta.clone();
+        if let OptionSerializer::Some(inner_instructions) = &mut invalid_meta.inner_instructions {
+            if let Some(UiInstruction::Compiled(compiled_ui_instruction)) = inner_instructions
+                .first_mut()
+                .and_then(|inner_instruction_group| {
+                    inner_instruction_group.instructions.first_mut()
+                })
+            {
+                // `0`, `O`, `I` and `l` are not part of the base58 alphabet.
+                compiled_ui_instruction.data = "0OIl".to_string();
+            }
+        }
+
+        // Act
+        let valid = transaction_metadata_from_original_meta(valid_meta)
+            .expect("transaction metadata from valid meta");
+        let invalid = transaction_metadata_from_original_meta(invalid_meta)
+            .expect("transaction metadata from meta with invalid data");
+
+        // Assert
+        let valid_groups = valid.inner_instructions.unwrap();
+        let invalid_groups = invalid.inner_instructions.unwrap();
+        assert_eq!(valid_groups.len(), invalid_groups.len());
+        let invalid_instruction = &invalid_groups[0].instructions[0];
+        assert!(invalid_instruction.instruction.data.is_empty());
+        assert_eq!(
+            invalid_instruction.instruction.accounts,
+            valid_groups[0].instructions[0].instruction.accounts
+        );
+        assert_eq!(
+            invalid_groups[0].instructions[1..],
+            valid_groups[0].instructions[1..]
+        );
+        assert_eq!(invalid_groups[1..], valid_groups[1..]);
+    }
+
+    #[test]
+    fn test_resolve_compiled_instruction_derives_signer_and_writable_flags() {
+        // Arrange
+        // Two writable signers, one readonly signer, one writable and two
+        // readonly unsigned accounts.
+        let message_keys: Vec<Pubkey> = (0..6).map(|_| Pubkey::new_unique()).collect();
+        let header = MessageHeader {
+            num_required_signatures: 3,
+            num_readonly_signed_accounts: 1,
+            num_readonly_unsigned_accounts: 2,
+        };
+        let compiled_instruction = CompiledInstruction {
+            program_id_index: 5,
+            accounts: vec![0, 1, 2, 3, 4],
+            data: vec![1, 2, 3],
+        };
+
+        // Act
+        let instruction = resolve_compiled_instruction(
+            &message_keys,
+            &header,
+            &LoadedAddresses::default(),
+            &compiled_instruction,
+        );
+
+        // Assert
+        assert_eq!(instruction.program_id, message_keys[5]);
+        assert_eq!(instruction.data, vec![1, 2, 3]);
+        assert_eq!(
+            instruction.accounts,
+            vec![
+                AccountMeta::new(message_keys[0], true),
+                AccountMeta::new(message_keys[1], true),
+                AccountMeta::new_readonly(message_keys[2], true),
+                AccountMeta::new(message_keys[3], false),
+                AccountMeta::new_readonly(message_keys[4], false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_resolve_compiled_instruction_with_lookup_table_addresses() {
+        // Arrange
+        let payer = Pubkey::new_unique();
+        let program_id = Pubkey::new_unique();
+        let loaded_addresses = LoadedAddresses {
+            writable: vec![Pubkey::new_unique()],
+            readonly: vec![Pubkey::new_unique()],
+        };
+        // The program is writable according to the header, but a program
+        // called by the instruction is demoted to readonly.
+        let header = MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 0,
+        };
+        let compiled_instruction = CompiledInstruction {
+            program_id_index: 1,
+            accounts: vec![0, 1, 2, 3, 4],
+            data: vec![],
+        };
+
+        // Act
+        let instruction = resolve_compiled_instruction(
+            &[payer, program_id],
+            &header,
+            &loaded_addresses,
+            &compiled_instruction,
+        );
+
+        // Assert
+        assert_eq!(instruction.program_id, program_id);
+        assert_eq!(
+            instruction.accounts,
+            vec![
+                AccountMeta::new(payer, true),
+                AccountMeta::new_readonly(program_id, false),
+                AccountMeta::new(loaded_addresses.writable[0], false),
+                AccountMeta::new_readonly(loaded_addresses.readonly[0], false),
+                AccountMeta::new_readonly(Pubkey::default(), false),
+            ]
+        );
     }
 }
@@ -0,0 +1,142 @@
+#![cfg(feature = "kafka")]
+
+use {
+    async_trait::async_trait,
+    carbon_core::{
+        error::{CarbonResult, Error},
+        event::{DecodedEvent, EventProcessorInputType},
+        instruction::InstructionMetadata,
+        kafka::{KafkaProcessor, KafkaProducer},
+        metrics::MetricsCollection,
+        processor::Processor,
+        transaction::TransactionMetadata,
+    },
+    solana_pubkey::Pubkey,
+    std::sync::{Arc, Mutex},
+};
+
+/// The records sent by a `MockProducer`, as `(topic, key, payload)`.
+type SentRecords = Arc<Mutex<Vec<(String, Vec<u8>, Vec<u8>)>>>;
+
+#[derive(Default)]
+struct MockProducer {
+    sent: SentRecords,
+    fail: bool,
+}
+
+#[async_trait]
+impl KafkaProducer for MockProducer {
+    async fn send(&self, topic: &str, key: &[u8], payload: &[u8]) -> CarbonResult<()> {
+        if self.fail {
+            return Err(Error::Custom("broker unavailable".to_string()));
+        }
+
+        self.sent
+            .lock()
+            .unwrap()
+            .push((topic.to_string(), key.to_vec(), payload.to_vec()));
+        Ok(())
+    }
+}
+
+fn transfer(program_id: Pubkey, amount: u64) -> EventProcessorInputType<u64> {
+    (
+        InstructionMetadata {
+            transaction_metadata: TransactionMetadata::default(),
+            stack_height: 1,
+            index: 0,
+            accounts_resolved: true,
+            compute_units: None,
+            absolute_path: vec![0],
+            transaction_context: None,
+        },
+        DecodedEvent {
+            program_id,
+            discriminator: vec![1],
+            data: amount,
+        },
+    )
+}
+
+#[tokio::test]
+async fn test_kafka_processor_keys_records_by_program_id() {
+    // Arrange
+    let producer = MockProducer::default();
+    let sent = producer.sent.clone();
+    let mut processor = KafkaProcessor::new(producer, "transfers").batch_size(2);
+    let metrics = Arc::new(MetricsCollection::new(vec![]));
+    let (first, second) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let events = [transfer(first, 1), transfer(second, 2), transfer(first, 3)];
+    let signatures: Vec<_> = events
+        .iter()
+        .map(|(metadata, _)| metadata.transaction_metadata.signature.to_string())
+        .collect();
+
+    // Act
+    for event in events {
+        processor
+            .process(event, metrics.clone())
+            .await
+            .expect("record queued");
+    }
+    processor.shutdown().await.expect("records sent");
+
+    // Assert
+    let sent = sent.lock().unwrap();
+    let records: Vec<_> = sent
+        .iter()
+        .map(|(topic, key, payload)| {
+            let payload: serde_json::Value = serde_json::from_slice(payload).unwrap();
+            (
+                topic.as_str(),
+                String::from_utf8(key.clone()).unwrap(),
+                payload["metadata"]["signature"].clone(),
+                payload["payload"]["data"].clone(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        records,
+        vec![
+            (
+                "transfers",
+                first.to_string(),
+                serde_json::json!(signatures[0]),
+                serde_json::json!(1)
+            ),
+            (
+                "transfers",
+                second.to_string(),
+                serde_json::json!(signatures[1]),
+                serde_json::json!(2)
+            ),
+            (
+                "transfers",
+                first.to_string(),
+                serde_json::json!(signatures[2]),
+                serde_json::json!(3)
+            ),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_kafka_processor_reports_delivery_failures() {
+    // Arrange
+    let producer = MockProducer {
+        fail: true,
+        ..Default::default()
+    };
+    let mut processor = KafkaProcessor::new(producer, "transfers").batch_size(1);
+    let metrics = Arc::new(MetricsCollection::new(vec![]));
+
+    // Act
+    processor
+        .process(transfer(Pubkey::new_unique(), 1), metrics.clone())
+        .await
+        .expect("record queued");
+    let result = processor.shutdown().await;
+
+    // Assert
+    assert!(result.is_err());
+}
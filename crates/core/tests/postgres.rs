@@ -0,0 +1,171 @@
+//! The tests using a real database are ignored. Run them against a disposable
+//! database with
+//!
+//! ```sh
+//! DATABASE_URL=postgres://... cargo test -p carbon-core --features postgres -- --ignored
+//! ```
+
+#![cfg(feature = "postgres")]
+
+use {
+    async_trait::async_trait,
+    carbon_core::{
+        error::{CarbonResult, Error},
+        metrics::MetricsCollection,
+        postgres::{PgClient, PgRow, PostgresProcessor, ToSql},
+        processor::Processor,
+    },
+    deadpool_postgres::{Config, PoolConfig, Runtime},
+    std::sync::{Arc, Mutex},
+    tokio_postgres::NoTls,
+};
+
+/// The batches written by a `MockClient`, as the statement and the `Debug`
+/// output of the parameters of each row.
+type WrittenBatches = Arc<Mutex<Vec<(String, Vec<Vec<String>>)>>>;
+
+#[derive(Default)]
+struct MockClient {
+    written: WrittenBatches,
+    fail: bool,
+}
+
+#[async_trait]
+impl PgClient for MockClient {
+    async fn insert_rows(
+        &self,
+        statement: &str,
+        rows: &[Vec<Box<dyn ToSql + Send + Sync>>],
+    ) -> CarbonResult<u64> {
+        if self.fail {
+            return Err(Error::Custom("connection refused".to_string()));
+        }
+
+        let rows = rows
+            .iter()
+            .map(|values| values.iter().map(|value| format!("{value:?}")).collect())
+            .collect();
+        self.written
+            .lock()
+            .unwrap()
+            .push((statement.to_string(), rows));
+        Ok(1)
+    }
+}
+
+struct TransferRows;
+
+impl PgRow<(String, i64)> for TransferRows {
+    fn table(&self) -> &str {
+        "transfers"
+    }
+
+    fn columns(&self) -> &[&str] {
+        &["signature", "amount"]
+    }
+
+    fn values(&self, (signature, amount): &(String, i64)) -> Vec<Box<dyn ToSql + Send + Sync>> {
+        vec![Box::new(signature.clone()), Box::new(*amount)]
+    }
+}
+
+#[tokio::test]
+async fn test_postgres_processor_writes_batches_of_mapped_rows() {
+    // Arrange
+    let client = MockClient::default();
+    let written = client.written.clone();
+    let mut processor = PostgresProcessor::new(client, TransferRows)
+        .on_conflict_do_nothing()
+        .batch_size(2);
+    let metrics = Arc::new(MetricsCollection::new(vec![]));
+
+    // Act
+    for transfer in [("a", 1), ("b", 2), ("c", 3)] {
+        processor
+            .process((transfer.0.to_string(), transfer.1), metrics.clone())
+            .await
+            .expect("row queued");
+    }
+    processor.shutdown().await.expect("rows written");
+
+    // Assert
+    let statement =
+        "INSERT INTO transfers (signature, amount) VALUES ($1, $2) ON CONFLICT DO NOTHING";
+    let row = |signature: &str, amount: i64| vec![format!("{signature:?}"), amount.to_string()];
+    assert_eq!(
+        *written.lock().unwrap(),
+        vec![
+            (statement.to_string(), vec![row("a", 1), row("b", 2)]),
+            (statement.to_string(), vec![row("c", 3)]),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_postgres_processor_reports_failed_transactions() {
+    // Arrange
+    let client = MockClient {
+        fail: true,
+        ..Default::default()
+    };
+    let mut processor = PostgresProcessor::new(client, TransferRows);
+    let metrics = Arc::new(MetricsCollection::new(vec![]));
+
+    // Act
+    processor
+        .process(("a".to_string(), 1), metrics.clone())
+        .await
+        .expect("row queued");
+    let result = processor.shutdown().await;
+
+    // Assert
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+#[ignore = "requires a Postgres server at DATABASE_URL"]
+async fn test_postgres_processor_skips_replayed_rows() {
+    // Arrange
+    let url = std::env::var("DATABASE_URL").expect("DATABASE_URL is set");
+    // A single connection, so that the temporary table is visible to the
+    // processor.
+    let pool = Config {
+        url: Some(url),
+        pool: Some(PoolConfig::new(1)),
+        ..Default::default()
+    }
+    .create_pool(Some(Runtime::Tokio1), NoTls)
+    .expect("valid config");
+    pool.get()
+        .await
+        .expect("connection")
+        .batch_execute(
+            "CREATE TEMPORARY TABLE transfers (signature TEXT PRIMARY KEY, amount BIGINT)",
+        )
+        .await
+        .expect("table created");
+    let mut processor = PostgresProcessor::new(pool.clone(), TransferRows)
+        .on_conflict_do_nothing()
+        .batch_size(2);
+    let metrics = Arc::new(MetricsCollection::new(vec![]));
+
+    // Act
+    for transfer in [("a", 1), ("b", 2), ("a", 1)] {
+        processor
+            .process((transfer.0.to_string(), transfer.1), metrics.clone())
+            .await
+            .expect("row queued");
+    }
+    processor.shutdown().await.expect("rows written");
+
+    // Assert
+    let rows: i64 = pool
+        .get()
+        .await
+        .expect("connection")
+        .query_one("SELECT COUNT(*) FROM transfers", &[])
+        .await
+        .expect("rows counted")
+        .get(0);
+    assert_eq!(rows, 2);
+}
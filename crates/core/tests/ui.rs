@@ -0,0 +1,15 @@
+//! Checks the errors reported when the pipeline API or the derive macros are
+//! misused.
+//!
+//! The expected errors in `tests/ui/*.stderr` depend on the compiler version.
+//! Regenerate them with the toolchain of `rust-toolchain.toml`:
+//!
+//! ```sh
+//! TRYBUILD=overwrite cargo test -p carbon-core --test ui
+//! ```
+
+#[test]
+fn test_ui() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,32 @@
+use {
+    async_trait::async_trait,
+    carbon_core::{
+        datasource::AccountDeletion, error::CarbonResult, metrics::MetricsCollection,
+        pipeline::Pipeline, processor::Processor,
+    },
+    std::{rc::Rc, sync::Arc},
+};
+
+struct DeletionLogger {
+    prefix: Rc<str>,
+}
+
+#[async_trait]
+impl Processor for DeletionLogger {
+    type InputType = AccountDeletion;
+
+    async fn process(
+        &mut self,
+        data: Self::InputType,
+        _metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        println!("{}: {}", self.prefix, data.pubkey);
+        Ok(())
+    }
+}
+
+fn main() {
+    let _ = Pipeline::builder().account_deletions(DeletionLogger {
+        prefix: Rc::from("deleted"),
+    });
+}
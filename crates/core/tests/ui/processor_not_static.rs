@@ -0,0 +1,31 @@
+use {
+    async_trait::async_trait,
+    carbon_core::{
+        datasource::AccountDeletion, error::CarbonResult, metrics::MetricsCollection,
+        pipeline::Pipeline, processor::Processor,
+    },
+    std::sync::Arc,
+};
+
+struct DeletionLogger<'a> {
+    prefix: &'a str,
+}
+
+#[async_trait]
+impl Processor for DeletionLogger<'_> {
+    type InputType = AccountDeletion;
+
+    async fn process(
+        &mut self,
+        data: Self::InputType,
+        _metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        println!("{}: {}", self.prefix, data.pubkey);
+        Ok(())
+    }
+}
+
+fn main() {
+    let prefix = String::from("deleted");
+    let _ = Pipeline::builder().account_deletions(DeletionLogger { prefix: &prefix });
+}
@@ -0,0 +1,32 @@
+use {
+    async_trait::async_trait,
+    carbon_core::{
+        datasource::AccountDeletion, error::CarbonResult, metrics::MetricsCollection,
+        pipeline::Pipeline, processor::Processor,
+    },
+    std::{cell::RefCell, sync::Arc},
+};
+
+struct DeletionCounter {
+    deletions: RefCell<u64>,
+}
+
+#[async_trait]
+impl Processor for DeletionCounter {
+    type InputType = AccountDeletion;
+
+    async fn process(
+        &mut self,
+        _data: Self::InputType,
+        _metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        *self.deletions.borrow_mut() += 1;
+        Ok(())
+    }
+}
+
+fn main() {
+    let _ = Pipeline::builder().account_deletions(DeletionCounter {
+        deletions: RefCell::new(0),
+    });
+}
@@ -0,0 +1,10 @@
+use {carbon_core::CarbonDeserialize, solana_pubkey::Pubkey};
+
+#[derive(CarbonDeserialize)]
+#[carbon(discriminator = "0x01", view)]
+struct VariableAccount {
+    authority: Pubkey,
+    balances: Vec<u64>,
+}
+
+fn main() {}
@@ -1,7 +1,8 @@
 //! # Carbon Macros
 //!
 //! This crate provides powerful macros for building and processing transaction
-//! schemas and decoding instructions dynamically. It includes two main modules:
+//! schemas and decoding instructions and events dynamically. It includes the
+//! following modules:
 //!
 //! - **`schema`**: Offers the `schema!` macro to construct hierarchical
 //!   transaction schemas with flexible node types, ideal for organizing and
@@ -9,6 +10,8 @@
 //! - **`try_decode_ix`**: Includes the `try_decode_instructions!` macro,
 //!   enabling dynamic decoding of instructions into various types based on
 //!   specified patterns.
+//! - **`try_decode_events`**: Includes the `try_decode_events!` macro, which
+//!   decodes Anchor event payloads into various event types.
 //!
 //! ## Overview
 //!
@@ -22,7 +25,9 @@
 //!
 //! - **`schema`**: For building transaction schemas.
 //! - **`try_decode_ix`**: For decoding instructions dynamically.
+//! - **`try_decode_events`**: For decoding Anchor events dynamically.
 #![no_std]
 
 pub mod schemas;
+pub mod try_decode_events;
 pub mod try_decode_ixs;
@@ -0,0 +1,56 @@
+//! # Event Decoding Module
+//!
+//! The `try_decode_events` module provides the `try_decode_events!` macro,
+//! which decodes an Anchor event payload into one of several event types.
+//!
+//! ## Usage
+//!
+//! Specify the emitting program ID and the event payload (the event
+//! discriminator followed by the serialized event) along with a series of
+//! variant-type pairs. The macro matches the payload against each type's event
+//! discriminator sequentially and returns the first successful match. If no
+//! match is found, `None` is returned.
+
+/// Attempts to decode an event payload into a specific variant type.
+///
+/// The `try_decode_events!` macro takes a program ID and an event payload and
+/// tries to decode the payload into one of the provided variant types. If
+/// decoding is successful, it returns a `DecodedEvent` object with the decoded
+/// data wrapped in the specified variant. If none of the variant types match,
+/// it returns `None`.
+///
+/// # Syntax
+///
+/// ```ignore
+/// try_decode_events!(program_id, data, VariantA => TypeA, VariantB => TypeB, ...);
+/// ```
+///
+/// - `$program_id`: A reference to the program that emitted the event.
+/// - `$data`: The event payload, without the Anchor event instruction tag.
+/// - `$variant`: The enum variant to wrap the decoded event data.
+/// - `$ty`: The event type, whose `CarbonDeserialize` discriminator is the
+///   Anchor event instruction tag followed by the event discriminator.
+///
+/// # Returns
+///
+/// Returns an `Option<DecodedEvent>` that contains the decoded event wrapped
+/// in the specified variant type if decoding is successful.
+#[macro_export]
+macro_rules! try_decode_events {
+    ($program_id:expr, $data:expr, $($variant:path => $ty:ty),* $(,)?) => {{
+        use carbon_core::deserialize::CarbonDeserialize;
+        let event_data: &[u8] = $data;
+        $(
+            if let Some(decoded_event) = carbon_core::event::try_decode_anchor_event::<$ty>(event_data) {
+                Some(carbon_core::event::DecodedEvent {
+                    program_id: *$program_id,
+                    discriminator: <$ty>::DISCRIMINATOR[carbon_core::event::ANCHOR_EVENT_IX_TAG.len()..].to_vec(),
+                    data: $variant(decoded_event),
+                })
+            } else
+        )*
+        {
+            None
+        }
+    }};
+}
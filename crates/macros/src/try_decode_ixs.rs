@@ -10,6 +10,16 @@
 //! decode along with a series of variant-type pairs. The macro attempts to
 //! decode the instruction into each type sequentially, returning the first
 //! successful match. If no match is found, `None` is returned.
+//! Types marked `#[carbon(no_discriminator)]` match any data that deserializes
+//! as them, so list them after every type that has a discriminator.
+//!
+//! The `try_decode_instructions_fallible!` macro works the same way but also
+//! reports instructions whose discriminator matches while their data fails to
+//! deserialize, so callers can tell malformed data apart from instructions
+//! that belong to another type.
+//!
+//! The `matches_discriminators!` macro only checks whether instruction data
+//! starts with the discriminator of one of several types, without decoding it.
 
 /// Attempts to decode an instruction into a specific variant type.
 ///
@@ -82,6 +92,12 @@ macro_rules! try_decode_instructions {
                     program_id: $instruction.program_id,
                     accounts: $instruction.accounts.clone(),
                     data: $variant(decoded_instruction),
+                    discriminator: <$ty>::matching_discriminator($instruction.data.as_slice())
+                        .unwrap_or(<$ty>::DISCRIMINATOR)
+                        .to_vec(),
+                    raw_len: $instruction.data.len(),
+                    num_accounts: $instruction.accounts.len(),
+                    raw_data: $instruction.data.clone(),
                 })
             } else
         )*
@@ -90,3 +106,83 @@ macro_rules! try_decode_instructions {
         }
     }};
 }
+
+/// Attempts to decode an instruction into a specific variant type, reporting
+/// malformed data as an error.
+///
+/// The `try_decode_instructions_fallible!` macro behaves like
+/// `try_decode_instructions!`, but evaluates to a
+/// `CarbonResult<Option<DecodedInstruction>>`. It returns `Ok(None)` if no
+/// variant's discriminator matches the instruction data, and an error if a
+/// discriminator matches but the remaining data cannot be deserialized. This is
+/// intended for implementing `InstructionDecoder::try_decode_instruction`.
+///
+/// # Syntax
+///
+/// ```ignore
+/// try_decode_instructions_fallible!(instruction, VariantA => TypeA, VariantB => TypeB, ...);
+/// ```
+///
+/// - `$instruction`: The instruction to decode.
+/// - `$variant`: The enum variant to wrap the decoded instruction data.
+/// - `$ty`: The type to which the instruction data should be deserialized.
+///
+/// # Notes
+///
+/// - Each `$ty` type must implement `CarbonDeserialize`. Types deriving it
+///   distinguish a mismatched discriminator from malformed data; types with a
+///   hand-written implementation report both as a mismatch unless they
+///   override `try_deserialize`.
+/// - The first variant whose discriminator matches determines the result, so
+///   later variants are not tried after a deserialization error.
+#[macro_export]
+macro_rules! try_decode_instructions_fallible {
+    ($instruction:expr, $($variant:path => $ty:ty),* $(,)?) => {{
+        use carbon_core::deserialize::CarbonDeserialize;
+        'decode: {
+            $(
+                match <$ty>::try_deserialize($instruction.data.as_slice()) {
+                    Ok(Some(decoded_instruction)) => {
+                        break 'decode Ok(Some(carbon_core::instruction::DecodedInstruction {
+                            program_id: $instruction.program_id,
+                            accounts: $instruction.accounts.clone(),
+                            data: $variant(decoded_instruction),
+                            discriminator: <$ty>::matching_discriminator($instruction.data.as_slice())
+                                .unwrap_or(<$ty>::DISCRIMINATOR)
+                                .to_vec(),
+                            raw_len: $instruction.data.len(),
+                            num_accounts: $instruction.accounts.len(),
+                            raw_data: $instruction.data.clone(),
+                        }));
+                    }
+                    Ok(None) => {}
+                    Err(error) => break 'decode Err(error),
+                }
+            )*
+            Ok(None)
+        }
+    }};
+}
+
+/// Checks whether data starts with the discriminator of one of the given
+/// types, without deserializing it.
+///
+/// This is intended for implementing
+/// `InstructionDecoder::matches_discriminator`.
+///
+/// # Syntax
+///
+/// ```ignore
+/// matches_discriminators!(data, TypeA, TypeB, ...);
+/// ```
+///
+/// - `$data`: The instruction data, as a byte slice.
+/// - `$ty`: A type implementing `CarbonDeserialize`.
+#[macro_export]
+macro_rules! matches_discriminators {
+    ($data:expr, $($ty:ty),* $(,)?) => {{
+        use carbon_core::deserialize::CarbonDeserialize;
+        let data: &[u8] = $data;
+        false $(|| <$ty>::matches_discriminator(data))*
+    }};
+}
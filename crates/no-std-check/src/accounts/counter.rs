@@ -0,0 +1,9 @@
+use carbon_core::{borsh, CarbonDeserialize};
+
+#[carbon_core::carbon_serde]
+#[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
+#[carbon(discriminator = "0xffb004f5bcfd7c19")]
+pub struct Counter {
+    pub authority: solana_pubkey::Pubkey,
+    pub count: u64,
+}
@@ -0,0 +1,29 @@
+use carbon_core::account::AccountDecoder;
+use carbon_core::deserialize::CarbonDeserialize;
+
+use super::CounterDecoder;
+pub mod counter;
+
+pub enum CounterAccount {
+    Counter(counter::Counter),
+}
+
+impl<'a> AccountDecoder<'a> for CounterDecoder {
+    type AccountType = CounterAccount;
+    fn decode_account(
+        &self,
+        account: &solana_account::Account,
+    ) -> Option<carbon_core::account::DecodedAccount<Self::AccountType>> {
+        if let Some(decoded_account) = counter::Counter::deserialize(account.data.as_slice()) {
+            return Some(carbon_core::account::DecodedAccount {
+                lamports: account.lamports,
+                data: CounterAccount::Counter(decoded_account),
+                owner: account.owner,
+                executable: account.executable,
+                rent_epoch: account.rent_epoch,
+            });
+        }
+
+        None
+    }
+}
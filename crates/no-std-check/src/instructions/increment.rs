@@ -0,0 +1,93 @@
+use alloc::vec::Vec;
+
+use carbon_core::{borsh, CarbonDeserialize};
+
+#[carbon_core::carbon_serde]
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x0b12680968ae3b21")]
+pub struct Increment {
+    pub amount: u64,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
+pub struct IncrementInstructionAccounts {
+    pub counter: solana_pubkey::Pubkey,
+    pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccounts for Increment {
+    type ArrangedAccounts = IncrementInstructionAccounts;
+
+    fn arrange_accounts(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccounts> {
+        let [counter, authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(IncrementInstructionAccounts {
+            counter: counter.pubkey,
+            authority: authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
+pub struct IncrementInstructionAccountMetas {
+    pub counter: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Increment {
+    type ArrangedAccountMetas = IncrementInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [counter, authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(IncrementInstructionAccountMetas {
+            counter: counter.clone(),
+            authority: authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Hash, serde::Serialize, serde::Deserialize)]
+pub struct IncrementInstructionAccountIndices {
+    pub counter: usize,
+    pub authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Increment {
+    type ArrangedAccountIndices = IncrementInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(IncrementInstructionAccountIndices {
+            counter: 0,
+            authority: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Increment {
+    fn account_names() -> &'static [&'static str] {
+        &["counter", "authority"]
+    }
+}
@@ -0,0 +1,78 @@
+use super::CounterDecoder;
+pub mod increment;
+pub mod incremented_event;
+
+#[derive(
+    carbon_core::InstructionType,
+    serde::Serialize,
+    serde::Deserialize,
+    PartialEq,
+    Eq,
+    Debug,
+    Clone,
+    Hash,
+)]
+pub enum CounterInstruction {
+    Increment(increment::Increment),
+    IncrementedEvent(incremented_event::IncrementedEvent),
+}
+
+impl<'a> carbon_core::instruction::InstructionDecoder<'a> for CounterDecoder {
+    type InstructionType = CounterInstruction;
+
+    fn decoder_name(&self) -> &'static str {
+        "counter"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[super::PROGRAM_ID]
+    }
+
+    fn min_accounts(&self) -> usize {
+        1
+    }
+
+    fn try_decode_instruction(
+        &self,
+        instruction: &solana_instruction::Instruction,
+    ) -> carbon_core::error::CarbonResult<
+        Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>>,
+    > {
+        carbon_core::try_decode_instructions_fallible!(instruction,
+            CounterInstruction::Increment => increment::Increment,
+            CounterInstruction::IncrementedEvent => incremented_event::IncrementedEvent,
+        )
+    }
+
+    fn matches_discriminator(&self, data: &[u8]) -> bool {
+        carbon_core::matches_discriminators!(
+            data,
+            increment::Increment,
+            incremented_event::IncrementedEvent,
+        )
+    }
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone, Hash)]
+pub enum CounterEvent {
+    IncrementedEvent(incremented_event::IncrementedEvent),
+}
+
+impl<'a> carbon_core::event::EventDecoder<'a> for CounterDecoder {
+    type EventType = CounterEvent;
+
+    fn decode_event(
+        &self,
+        program_id: &solana_pubkey::Pubkey,
+        data: &'a [u8],
+    ) -> Option<carbon_core::event::DecodedEvent<Self::EventType>> {
+        if !program_id.eq(&super::PROGRAM_ID) {
+            return None;
+        }
+
+        carbon_core::try_decode_events!(program_id, data,
+            CounterEvent::IncrementedEvent => incremented_event::IncrementedEvent,
+        )
+    }
+}
@@ -0,0 +1,90 @@
+//! A decoder generated by the CLI, that checks the decoding part of
+//! `carbon-core` compiles without `std`.
+//!
+//! The modules are generated from `idl.json`, with `src` taken from the
+//! `counter-decoder` crate written by
+//!
+//! ```sh
+//! carbon-cli parse --idl idl.json --output . --as-crate
+//! ```
+//!
+//! with the default flags, so the generated types carry the `carbon_serde`
+//! attribute. As in the other `no_std` decoders, the crate root is then marked
+//! `#![no_std]` and the files using `Vec` import it from `alloc`.
+//!
+//! The crate only enables `carbon-core/std` through its default `std` feature,
+//! so CI builds it with
+//!
+//! ```sh
+//! cargo build -p carbon-no-std-check --no-default-features
+//! ```
+//!
+//! to catch `std`, `tokio` or `log` dependencies creeping into
+//! `CarbonDeserialize`, `ArrangeAccounts`, the decoder traits or the code
+//! generated by the CLI and the derive macros. The build targets the host:
+//! `solana-instruction` 2.x only defines `Instruction` and `AccountMeta` with
+//! its `std` feature, and `solana-account` requires `std`, so no decoder can be
+//! built for a target without `std` yet.
+
+#![no_std]
+
+extern crate alloc;
+use solana_pubkey::Pubkey;
+
+pub struct CounterDecoder;
+pub mod accounts;
+pub mod instructions;
+pub mod types;
+
+pub const PROGRAM_ID: Pubkey =
+    Pubkey::from_str_const("Counter111111111111111111111111111111111111");
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{
+            instructions::{increment::Increment, CounterInstruction, CounterInstructionType},
+            *,
+        },
+        alloc::vec,
+        carbon_core::{
+            deserialize::{ArrangeAccounts, CarbonSerialize},
+            instruction::InstructionDecoder,
+        },
+        solana_instruction::{AccountMeta, Instruction},
+    };
+
+    #[test]
+    fn test_decode_increment() {
+        // Arrange
+        let counter = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(counter, false),
+                AccountMeta::new_readonly(authority, true),
+            ],
+            data: Increment { amount: 3 }.to_bytes(),
+        };
+
+        // Act
+        let decoded = CounterDecoder
+            .try_decode_instruction(&instruction)
+            .expect("valid instruction")
+            .expect("counter instruction");
+        let accounts = Increment::arrange_accounts(&decoded.accounts).expect("accounts");
+
+        // Assert
+        assert_eq!(
+            decoded.data,
+            CounterInstruction::Increment(Increment { amount: 3 })
+        );
+        assert_eq!(
+            decoded.data.get_instruction_type(),
+            CounterInstructionType::Increment
+        );
+        assert_eq!(accounts.counter, counter);
+        assert_eq!(accounts.authority, authority);
+    }
+}
@@ -0,0 +1,10 @@
+use carbon_core::{borsh, CarbonDeserialize};
+
+#[carbon_core::carbon_serde]
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct Counter {
+    pub authority: solana_pubkey::Pubkey,
+    pub count: u64,
+}
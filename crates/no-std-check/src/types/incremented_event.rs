@@ -0,0 +1,9 @@
+use carbon_core::{borsh, CarbonDeserialize};
+
+#[carbon_core::carbon_serde]
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct IncrementedEvent {
+    pub count: u64,
+}
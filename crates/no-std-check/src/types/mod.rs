@@ -0,0 +1,4 @@
+pub mod counter;
+pub use counter::*;
+pub mod incremented_event;
+pub use incremented_event::*;
@@ -23,7 +23,10 @@
 //!   instructions are parsed and categorized.
 //! - **`InstructionType` Derivation**: Derive `InstructionType` enums that
 //!   mirror existing enum structures, providing a simplified, data-free version
-//!   of each variant.
+//!   of each variant, along with conversions between the enum and the types its
+//!   variants wrap.
+//! - **`carbon_serde`**: Serialize the `Pubkey` and byte-array fields of a
+//!   decoded type as base58 and base64 strings, for readable JSON output.
 //!
 //! ## Usage
 //!
@@ -44,12 +47,17 @@
 //! or opening an issue on the project’s GitHub repository.
 use {
     borsh_derive_internal::*,
+    heck::ToSnakeCase,
     proc_macro::TokenStream,
     proc_macro2::{Span, TokenStream as TokenStream2},
     quote::{format_ident, quote},
+    sha2::{Digest, Sha256},
     syn::{
+        bracketed, parenthesized,
         parse::{Parse, ParseStream},
-        parse_macro_input, DeriveInput, Ident, Item, ItemEnum, Lit, Meta, NestedMeta, Token,
+        parse_macro_input,
+        punctuated::Punctuated,
+        token, DeriveInput, Ident, Item, ItemEnum, LitInt, LitStr, Meta, NestedMeta, Token, Type,
         TypePath,
     },
 };
@@ -70,6 +78,35 @@ use {
 /// discriminator is validated at the start of the byte slice before proceeding
 /// with full deserialization.
 ///
+/// Discriminators can have any length: 8-byte Anchor discriminators, a single
+/// tag byte as used by SPL Token (`"0x03"`), or a 4-byte little-endian tag as
+/// used by the System program (`"0x02000000"`). Exactly that many leading
+/// bytes are compared, and the remaining bytes are deserialized. An empty
+/// discriminator (`"0x"`) matches any data, including empty data.
+///
+/// When a program upgrade gives an instruction a new discriminator without
+/// changing its arguments, list every discriminator with
+/// `#[carbon(discriminator(all = ["0x...", "0x..."]))]`. Data starting with
+/// any of them is deserialized into the same type. The first one is the type's
+/// `DISCRIMINATOR`, which `to_bytes` writes.
+///
+/// Some programs put a header, such as a version byte, before the
+/// discriminator. Give its length with `offset`, as in
+/// `#[carbon(discriminator = "0x...", offset = 1)]`: the discriminator is then
+/// compared `offset` bytes into the data, and the bytes after it are
+/// deserialized. The header bytes are skipped without being checked, so types
+/// that only differ in their header cannot be told apart, and they are not
+/// part of the value: `to_bytes` writes them as zeros. The offset applies to
+/// every discriminator listed with `discriminator(all = [...])`.
+///
+/// Some programs take a single instruction whose data is the argument struct
+/// itself, with no tag in front of it. Mark such types with
+/// `#[carbon(no_discriminator)]` so the entire data is deserialized as the
+/// struct body. Nothing in the data identifies the type, so any bytes that
+/// happen to deserialize as the struct match it: when a decoder tries several
+/// types, try a discriminator-less one last and only for programs that have a
+/// single instruction layout.
+///
 /// ```ignore
 /// #[derive(CarbonDeserialize)]
 /// #[carbon(discriminator = "0x1234")]
@@ -110,10 +147,26 @@ use {
 ///
 /// - The `#[carbon(discriminator = "0x...")]` attribute is optional. If not
 ///   provided, the deserialization proceeds without a discriminator check.
+/// - `#[carbon(anchor_instruction)]` uses the discriminator Anchor gives the
+///   instruction, the first 8 bytes of `sha256("global:<name>")`, where the
+///   name is the type name in snake case. Use `#[carbon(anchor_instruction =
+///   "name")]` when the instruction is named differently. The hash is computed
+///   when the macro expands, and an explicit `discriminator` takes precedence.
+/// - `#[carbon(no_discriminator)]` gives the type an empty discriminator. It
+///   cannot be combined with `discriminator` or `anchor_instruction`.
+/// - `offset = N` sets `DISCRIMINATOR_OFFSET`. It requires a non-empty
+///   discriminator.
 /// - Ensure the discriminator matches the data's format exactly, as the
 ///   deserialization will return `None` if there is a mismatch.
-/// - The macro will panic if the discriminator is invalid or not provided
-///   correctly as a hex string when expected.
+/// - Compilation fails if the discriminator is not a valid hex string.
+/// - `#[carbon(view)]` also generates a zero-copy `<Name>View<'a>` type and
+///   implements `CarbonDeserializeView` for it. The struct must have named
+///   fields that are all fixed-size: integers, `bool`, `Pubkey` and arrays of
+///   those.
+/// - The macro also derives `BorshSerialize` and implements
+///   `CarbonSerialize`, whose `to_bytes` prepends the discriminator so that
+///   `deserialize(&value.to_bytes())` returns the original value. All fields
+///   must therefore implement `BorshSerialize` as well.
 ///
 /// # Errors
 ///
@@ -126,29 +179,90 @@ pub fn carbon_deserialize_derive(input_token_stream: TokenStream) -> TokenStream
     let input = parse_macro_input!(derive_input as DeriveInput);
     let name = &input.ident;
 
-    let discriminator = get_discriminator(&input.attrs).unwrap_or(quote! { &[] });
-    let deser = gen_borsh_deserialize(input_token_stream);
+    let args = match parse_carbon_args(&input.attrs) {
+        Ok(args) => args,
+        Err(error) => return error.to_compile_error().into(),
+    };
+    let discriminators = match get_discriminators(&args, name) {
+        Ok(discriminators) => discriminators,
+        Err(error) => return error.to_compile_error().into(),
+    };
+    let discriminator = discriminators.first().cloned().unwrap_or_default();
+    let discriminator_offset = match get_discriminator_offset(&args) {
+        Ok(Some(offset)) if discriminator.is_empty() => {
+            return syn::Error::new(offset.span(), "`offset` requires a non-empty discriminator")
+                .to_compile_error()
+                .into();
+        }
+        Ok(offset) => offset.map(|offset| {
+            let offset = offset
+                .base10_parse::<usize>()
+                .expect("offset is validated when parsed");
+            quote! {
+                const DISCRIMINATOR_OFFSET: usize = #offset;
+            }
+        }),
+        Err(error) => return error.to_compile_error().into(),
+    };
+    // Types with a single discriminator rely on the default
+    // `DISCRIMINATORS`, which only holds `DISCRIMINATOR`.
+    let all_discriminators = (discriminators.len() > 1).then(|| {
+        let discriminators = discriminators.iter().map(|discriminator| {
+            quote! { &[#(#discriminator),*] }
+        });
+        quote! {
+            const DISCRIMINATORS: &'static [&'static [u8]] = &[#(#discriminators),*];
+        }
+    });
+    let deser = gen_borsh_deserialize(input_token_stream.clone());
+    let ser = gen_borsh_serialize(input_token_stream);
+    let view = match get_view(&args) {
+        Some(key) => match gen_deserialize_view(&input, key) {
+            Ok(view) => view,
+            Err(error) => return error.to_compile_error().into(),
+        },
+        None => quote! {},
+    };
 
     let expanded = quote! {
         #deser
 
+        #ser
+
+        #view
+
         #[automatically_derived]
         impl carbon_core::deserialize::CarbonDeserialize for #name {
+            const DISCRIMINATOR: &'static [u8] = &[#(#discriminator),*];
+
+            #all_discriminators
+
+            #discriminator_offset
+
             fn deserialize(data: &[u8]) -> Option<Self> {
-                let discriminator: &[u8] = #discriminator;
-                if data.len() < discriminator.len() {
-                    return None;
-                }
+                Self::try_deserialize(data).ok().flatten()
+            }
 
+            fn matches_discriminator(data: &[u8]) -> bool {
+                <Self as carbon_core::deserialize::CarbonDeserialize>::matching_discriminator(data).is_some()
+            }
 
-                let (disc, rest) = data.split_at(discriminator.len());
-                if disc != discriminator {
-                    return None;
-                }
+            fn try_deserialize(data: &[u8]) -> carbon_core::error::CarbonResult<Option<Self>> {
+                let Some(data) = data.get(
+                    <Self as carbon_core::deserialize::CarbonDeserialize>::DISCRIMINATOR_OFFSET..,
+                ) else {
+                    return Ok(None);
+                };
 
-                 carbon_core::borsh::BorshDeserialize::try_from_slice(rest).ok()
+                carbon_core::deserialize::try_deserialize_with_discriminators(
+                    <Self as carbon_core::deserialize::CarbonDeserialize>::DISCRIMINATORS,
+                    data,
+                )
             }
         }
+
+        #[automatically_derived]
+        impl carbon_core::deserialize::CarbonSerialize for #name {}
     };
 
     TokenStream::from(expanded)
@@ -229,21 +343,240 @@ fn gen_borsh_deserialize(input: TokenStream) -> TokenStream2 {
     }
 }
 
-/// Extracts the discriminator value from a set of attributes.
+/// Generates a `BorshSerialize` implementation for the type, mirroring
+/// `gen_borsh_deserialize`, so that derived types also implement
+/// `CarbonSerialize`.
+fn gen_borsh_serialize(input: TokenStream) -> TokenStream2 {
+    let cratename = Ident::new("borsh", Span::call_site());
+
+    let item: Item = syn::parse(input).unwrap();
+    let res = match item {
+        Item::Struct(item) => struct_ser(&item, cratename),
+        Item::Enum(item) => enum_ser(&item, cratename),
+        Item::Union(item) => union_ser(&item, cratename),
+        // Derive macros can only be defined on structs, enums, and unions.
+        _ => unreachable!(),
+    };
+
+    match res {
+        Ok(res) => res,
+        Err(err) => err.to_compile_error(),
+    }
+}
+
+/// Generates a zero-copy view type and its `CarbonDeserializeView`
+/// implementation for a struct marked `#[carbon(view)]`.
+///
+/// For a struct `MyAccount`, the generated `MyAccountView<'a>` borrows the
+/// serialized bytes following the discriminator and exposes one accessor per
+/// field at its Borsh offset, plus an `into_owned` method that reads every
+/// field into a `MyAccount`. Accessors of scalar fields read them on access,
+/// while those of array fields borrow their bytes: `&'a [u8; N]` for byte
+/// arrays and the encoded elements as `&'a [u8]` for other arrays.
 ///
-/// This function searches through a list of attributes for a `carbon` attribute
-/// containing a `discriminator` key in the format `carbon(discriminator =
-/// "0x...")`. If found, it parses the discriminator as a hexadecimal string and
-/// returns it as a byte slice within a `TokenStream`. If the
-/// `carbon(discriminator = "...")` attribute is not present, the function
-/// returns `None`.
+/// # Errors
+///
+/// Returns an error spanning `key` if the struct is generic or does not have
+/// named fields, and one spanning the field type if a field is not fixed-size
+/// (see `is_fixed_size_type`).
+fn gen_deserialize_view(input: &DeriveInput, key: &Ident) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Named(fields),
+            ..
+        }) if input.generics.params.is_empty() => fields,
+        _ => {
+            return Err(syn::Error::new(
+                key.span(),
+                "`view` requires a non-generic struct with named fields",
+            ))
+        }
+    };
+
+    if let Some(field) = fields
+        .named
+        .iter()
+        .find(|field| !is_fixed_size_type(&field.ty))
+    {
+        return Err(syn::Error::new_spanned(
+            &field.ty,
+            "`view` requires fixed-size fields: integers, `bool`, `Pubkey` and arrays of those",
+        ));
+    }
+
+    let name = &input.ident;
+    let vis = &input.vis;
+    let view_name = format_ident!("{}View", name);
+    let field_names: Vec<&Ident> = fields
+        .named
+        .iter()
+        .filter_map(|field| field.ident.as_ref())
+        .collect();
+    let field_types: Vec<&syn::Type> = fields.named.iter().map(|field| &field.ty).collect();
+    let field_bytes: Vec<TokenStream2> = field_types
+        .iter()
+        .enumerate()
+        .map(|(i, ty)| {
+            let preceding_types = &field_types[..i];
+            quote! {
+                {
+                    const OFFSET: usize =
+                        0 #(+ <#preceding_types as carbon_core::deserialize::ViewField>::SIZE)*;
+                    &self.data[OFFSET..OFFSET + <#ty as carbon_core::deserialize::ViewField>::SIZE]
+                }
+            }
+        })
+        .collect();
+
+    let accessors = fields.named.iter().zip(&field_bytes).map(|(field, bytes)| {
+        let field_name = &field.ident;
+        let ty = &field.ty;
+        match ty {
+            syn::Type::Array(array) if is_u8(&array.elem) => quote! {
+                #[inline]
+                pub fn #field_name(&self) -> &'a #ty {
+                    let bytes: &'a [u8] = #bytes;
+                    bytes.try_into().expect("the slice has the size of the array")
+                }
+            },
+            syn::Type::Array(_) => quote! {
+                #[inline]
+                pub fn #field_name(&self) -> &'a [u8] {
+                    #bytes
+                }
+            },
+            _ => quote! {
+                #[inline]
+                pub fn #field_name(&self) -> #ty {
+                    <#ty as carbon_core::deserialize::ViewField>::read(#bytes)
+                }
+            },
+        }
+    });
+
+    Ok(quote! {
+        #[derive(Debug, Clone, Copy)]
+        #vis struct #view_name<'a> {
+            data: &'a [u8],
+        }
+
+        // Accessors are named after the struct's fields, which may clash with
+        // naming conventions clippy expects from hand-written methods.
+        #[automatically_derived]
+        #[allow(
+            clippy::len_without_is_empty,
+            clippy::new_ret_no_self,
+            clippy::wrong_self_convention
+        )]
+        impl<'a> #view_name<'a> {
+            /// The size of the serialized struct, excluding its discriminator.
+            pub const SIZE: usize =
+                0 #(+ <#field_types as carbon_core::deserialize::ViewField>::SIZE)*;
+
+            #(#accessors)*
+
+            pub fn into_owned(self) -> #name {
+                #name {
+                    #(#field_names: <#field_types as carbon_core::deserialize::ViewField>::read(#field_bytes),)*
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl<'a> carbon_core::deserialize::CarbonDeserializeView<'a> for #name {
+            type View = #view_name<'a>;
+
+            fn deserialize_view(data: &'a [u8]) -> Option<Self::View> {
+                let discriminator =
+                    <Self as carbon_core::deserialize::CarbonDeserialize>::matching_discriminator(data)?;
+                let rest = &data[<Self as carbon_core::deserialize::CarbonDeserialize>::DISCRIMINATOR_OFFSET
+                    + discriminator.len()..];
+                if rest.len() < #view_name::SIZE {
+                    return None;
+                }
+
+                Some(#view_name {
+                    data: &rest[..#view_name::SIZE],
+                })
+            }
+        }
+    })
+}
+
+/// Returns whether a type is `u8`.
+fn is_u8(ty: &syn::Type) -> bool {
+    matches!(ty, syn::Type::Path(type_path) if type_path.path.is_ident("u8"))
+}
+
+/// Returns whether a type has a fixed-size Borsh encoding that a generated
+/// view can read in place.
+///
+/// Only primitive integers, `bool`, `Pubkey` and fixed-size arrays of those
+/// are recognized, since other named types cannot be inspected from within the
+/// macro.
+fn is_fixed_size_type(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Array(array) => is_fixed_size_type(&array.elem),
+        syn::Type::Path(type_path) if type_path.qself.is_none() => {
+            type_path.path.segments.last().is_some_and(|segment| {
+                segment.arguments.is_empty()
+                    && matches!(
+                        segment.ident.to_string().as_str(),
+                        "u8" | "u16"
+                            | "u32"
+                            | "u64"
+                            | "u128"
+                            | "i8"
+                            | "i16"
+                            | "i32"
+                            | "i64"
+                            | "i128"
+                            | "bool"
+                            | "Pubkey"
+                    )
+            })
+        }
+        _ => false,
+    }
+}
+
+/// Parses the arguments of every `#[carbon(...)]` attribute in `attrs`, in the
+/// order they are written.
+///
+/// # Errors
+///
+/// Returns an error if an attribute is malformed.
+fn parse_carbon_args(attrs: &[syn::Attribute]) -> syn::Result<Vec<CarbonArg>> {
+    let mut args = Vec::new();
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("carbon")) {
+        args.extend(attr.parse_args_with(Punctuated::<CarbonArg, Token![,]>::parse_terminated)?);
+    }
+
+    Ok(args)
+}
+
+/// Extracts the discriminators of a type from its `carbon` arguments.
+///
+/// This function searches through the arguments of the `carbon` attributes
+/// for a `discriminator` key, either in the format
+/// `carbon(discriminator = "0x...")` or, for types that are identified by
+/// several discriminators, `carbon(discriminator(all = ["0x...", "0x..."]))`.
+/// If found, it parses each discriminator as a hexadecimal string. Otherwise,
+/// if the attribute contains `anchor_instruction`, the discriminator is the one
+/// Anchor derives from the instruction name. `no_discriminator` yields a
+/// single empty discriminator. If none is present, the function returns an
+/// empty list.
 ///
 /// # Syntax
 ///
-/// The attribute should be specified in the format:
+/// The attribute should be specified in one of the formats:
 ///
 /// ```ignore
 /// #[carbon(discriminator = "0x...")]
+/// #[carbon(discriminator(all = ["0x...", "0x..."]))]
+/// #[carbon(anchor_instruction)]
+/// #[carbon(anchor_instruction = "instruction_name")]
+/// #[carbon(no_discriminator)]
 /// ```
 ///
 /// # Example
@@ -252,65 +585,193 @@ fn gen_borsh_deserialize(input: TokenStream) -> TokenStream2 {
 /// ///
 /// // Example attribute with a discriminator
 /// let attrs: Vec<Attribute> = vec![parse_quote!(#[carbon(discriminator = "0x1234")])];
-/// let discriminator = get_discriminator(&attrs);
+/// let args = parse_carbon_args(&attrs).unwrap();
+/// let discriminators = get_discriminators(&args, &parse_quote!(Transfer)).unwrap();
 ///
-/// assert!(discriminator.is_some());
+/// assert_eq!(discriminators, vec![vec![0x12, 0x34]]);
 /// ```
 ///
 /// # Parameters
 ///
-/// - `attrs`: A reference to a slice of `syn::Attribute` items. These represent
-///   the attributes attached to a Rust item, from which the function will
-///   attempt to extract the discriminator.
+/// - `args`: The arguments of the `carbon` attributes attached to a Rust item,
+///   as returned by `parse_carbon_args`.
+/// - `name`: The name of the type, which in snake case is the default
+///   instruction name for `anchor_instruction`.
 ///
 /// # Return
 ///
-/// Returns an `Option<TokenStream>` containing the parsed byte slice if a
-/// valid `carbon(discriminator = "...")` attribute is found. If the attribute
-/// is not present, or if the value is not a valid hexadecimal string, the
-/// function returns `None`.
+/// Returns the discriminators in the order they are listed, the first of which
+/// is the one `CarbonSerialize` writes.
 ///
 /// # Errors
 ///
-/// If the `carbon(discriminator = "...")` attribute contains an invalid hex
-/// string, this function will panic with an error message indicating
-/// "Invalid hex string". To avoid runtime panics, ensure that the hex string
-/// provided is correctly formatted.
+/// Returns an error spanning the attribute value if it is not a valid
+/// hexadecimal string, such as an odd number of digits, or if a list of
+/// discriminators is malformed or empty. `no_discriminator` combined with
+/// another discriminator is an error as well.
 ///
 /// # Notes
 ///
-/// - The `discriminator` value must be a hexadecimal string prefixed with "0x".
-/// - If the hex string is invalid, an error will be raised; consider adding
-///   further error handling if required for your application.
-fn get_discriminator(attrs: &[syn::Attribute]) -> Option<quote::__private::TokenStream> {
-    attrs.iter().find_map(|attr| {
-        if attr.path.is_ident("carbon") {
-            attr.parse_meta().ok().and_then(|meta| {
-                if let Meta::List(list) = meta {
-                    list.nested.iter().find_map(|nested| {
-                        if let NestedMeta::Meta(Meta::NameValue(nv)) = nested {
-                            if nv.path.is_ident("discriminator") {
-                                if let Lit::Str(lit_str) = &nv.lit {
-                                    let disc_str = lit_str.value();
-                                    let disc_bytes = hex::decode(disc_str.trim_start_matches("0x"))
-                                        .expect("Invalid hex string");
-                                    let disc_array = disc_bytes.as_slice();
-                                    return Some(quote! { &[#(#disc_array),*] });
-                                }
-                            }
-                        }
-                        None
-                    })
-                } else {
-                    None
-                }
+/// - The `discriminator` values must be hexadecimal strings prefixed with "0x".
+/// - A value can encode any number of bytes, including none.
+fn get_discriminators(args: &[CarbonArg], name: &Ident) -> syn::Result<Vec<Vec<u8>>> {
+    let mut discriminators = None;
+    let mut anchor_instruction = None;
+    let mut no_discriminator = None;
+    for arg in args {
+        match arg {
+            CarbonArg::Discriminators(lit_strs) => {
+                discriminators.get_or_insert(lit_strs);
+            }
+            CarbonArg::AnchorInstruction(instruction_name) => {
+                anchor_instruction.get_or_insert_with(|| {
+                    instruction_name.as_ref().map_or_else(
+                        || name.to_string().to_snake_case(),
+                        |lit_str| lit_str.value(),
+                    )
+                });
+            }
+            CarbonArg::NoDiscriminator(key) => {
+                no_discriminator.get_or_insert(key);
+            }
+            CarbonArg::Offset(_) | CarbonArg::View(_) | CarbonArg::Other => {}
+        }
+    }
+
+    if let Some(key) = no_discriminator {
+        if discriminators.is_some() || anchor_instruction.is_some() {
+            return Err(syn::Error::new(
+                key.span(),
+                "`no_discriminator` cannot be combined with `discriminator` or \
+                 `anchor_instruction`",
+            ));
+        }
+        return Ok(vec![vec![]]);
+    }
+
+    match (discriminators, anchor_instruction) {
+        (Some(lit_strs), _) => lit_strs
+            .iter()
+            .map(|lit_str| {
+                let disc_str = lit_str.value();
+                hex::decode(disc_str.trim_start_matches("0x")).map_err(|error| {
+                    syn::Error::new(
+                        lit_str.span(),
+                        format!("invalid discriminator \"{}\": {}", disc_str, error),
+                    )
+                })
             })
-        } else {
-            None
+            .collect(),
+        (None, Some(instruction_name)) => Ok(vec![Sha256::digest(
+            format!("global:{}", instruction_name).as_bytes(),
+        )[..8]
+            .to_vec()]),
+        (None, None) => Ok(vec![]),
+    }
+}
+
+/// Extracts the `offset = N` of the `carbon` arguments, the number of bytes
+/// that precede the discriminator.
+///
+/// # Errors
+///
+/// Returns an error if the offset is not a `usize` literal.
+fn get_discriminator_offset(args: &[CarbonArg]) -> syn::Result<Option<LitInt>> {
+    for arg in args {
+        if let CarbonArg::Offset(offset) = arg {
+            offset.base10_parse::<usize>()?;
+            return Ok(Some(offset.clone()));
         }
+    }
+
+    Ok(None)
+}
+
+/// Extracts the `view` flag of the `carbon` arguments, which requests a
+/// zero-copy view of the type.
+fn get_view(args: &[CarbonArg]) -> Option<&Ident> {
+    args.iter().find_map(|arg| match arg {
+        CarbonArg::View(key) => Some(key),
+        _ => None,
     })
 }
 
+/// A single argument of a `#[carbon(...)]` attribute on a type.
+enum CarbonArg {
+    /// `discriminator = "0x..."` or `discriminator(all = ["0x...", ...])`.
+    Discriminators(Vec<LitStr>),
+    /// `anchor_instruction`, optionally naming the instruction.
+    AnchorInstruction(Option<LitStr>),
+    /// `no_discriminator`, for types whose data has no tag at all.
+    NoDiscriminator(Ident),
+    /// `offset = N`, the number of bytes before the discriminator.
+    Offset(LitInt),
+    /// `view`, to generate a zero-copy view of the type.
+    View(Ident),
+    /// Any other argument, which `get_discriminators` ignores.
+    Other,
+}
+
+impl Parse for CarbonArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let key: Ident = input.parse()?;
+
+        if key == "discriminator" && input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            return Ok(CarbonArg::Discriminators(vec![input.parse()?]));
+        }
+
+        if key == "discriminator" && input.peek(token::Paren) {
+            let content;
+            parenthesized!(content in input);
+            let all: Ident = content.parse()?;
+            if all != "all" {
+                return Err(syn::Error::new(
+                    all.span(),
+                    "expected `discriminator(all = [\"0x...\", ...])`",
+                ));
+            }
+            content.parse::<Token![=]>()?;
+            let list;
+            bracketed!(list in content);
+            let lit_strs = Punctuated::<LitStr, Token![,]>::parse_terminated(&list)?;
+            if lit_strs.is_empty() {
+                return Err(syn::Error::new(
+                    all.span(),
+                    "expected at least one discriminator",
+                ));
+            }
+            return Ok(CarbonArg::Discriminators(lit_strs.into_iter().collect()));
+        }
+
+        if key == "anchor_instruction" {
+            if input.peek(Token![=]) {
+                input.parse::<Token![=]>()?;
+                return Ok(CarbonArg::AnchorInstruction(Some(input.parse()?)));
+            }
+            return Ok(CarbonArg::AnchorInstruction(None));
+        }
+
+        if key == "no_discriminator" {
+            return Ok(CarbonArg::NoDiscriminator(key));
+        }
+
+        if key == "offset" {
+            input.parse::<Token![=]>()?;
+            return Ok(CarbonArg::Offset(input.parse()?));
+        }
+
+        if key == "view" {
+            return Ok(CarbonArg::View(key));
+        }
+
+        while !input.is_empty() && !input.peek(Token![,]) {
+            input.parse::<proc_macro2::TokenTree>()?;
+        }
+        Ok(CarbonArg::Other)
+    }
+}
+
 /// Represents the parsed input for the `instruction_decoder_collection!` macro.
 ///
 /// The `InstructionMacroInput` struct holds the essential elements required
@@ -645,6 +1106,10 @@ pub fn instruction_decoder_collection(input: TokenStream) -> TokenStream {
                     program_id: instruction.program_id,
                     accounts: instruction.accounts.clone(),
                     data: #instructions_enum_name::#program_variant(decoded_instruction.data),
+                    discriminator: decoded_instruction.discriminator,
+                    raw_len: decoded_instruction.raw_len,
+                    num_accounts: decoded_instruction.num_accounts,
+                    raw_data: decoded_instruction.raw_data,
                 });
             }
         });
@@ -657,7 +1122,7 @@ pub fn instruction_decoder_collection(input: TokenStream) -> TokenStream {
     }
 
     let expanded = quote! {
-        #[derive(Debug, Clone, std::hash::Hash, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
+        #[derive(Debug, Clone, core::hash::Hash, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
         pub enum #instructions_enum_name {
             #(#instruction_variants),*
         }
@@ -765,6 +1230,11 @@ pub fn instruction_decoder_collection(input: TokenStream) -> TokenStream {
 /// - The generated `InstructionType` enum derives `Debug`, `Clone`,
 ///   `PartialEq`, `Eq`, and `serde::Serialize`, making it suitable for use in
 ///   serialization contexts as well as comparison and debugging.
+/// - For every variant wrapping a single value of type `T`, such as
+///   `WithData(u64)` above, `From<T>` is implemented for the enum and
+///   `TryFrom<Enum>` for `T`, returning the enum back as the error if it holds
+///   another variant. Types wrapped by several variants, and generic enums, get
+///   no conversions.
 #[proc_macro_derive(InstructionType)]
 pub fn instruction_type_derive(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as ItemEnum);
@@ -815,11 +1285,206 @@ pub fn instruction_type_derive(input: TokenStream) -> TokenStream {
         }
     };
 
+    let conversions = gen_variant_conversions(&input);
+
     let expanded = quote! {
         #instruction_type_enum
 
         #impl_get_instruction_type
+
+        #conversions
     };
 
     TokenStream::from(expanded)
 }
+
+/// Generates `From<T> for Enum` and `TryFrom<Enum> for T` for every variant
+/// wrapping a single `T`.
+///
+/// Types wrapped by more than one variant are skipped, since a conversion
+/// from them would be ambiguous, and so are generic enums, whose conversions
+/// could conflict with the blanket implementations of the standard library.
+fn gen_variant_conversions(input: &ItemEnum) -> TokenStream2 {
+    if !input.generics.params.is_empty() {
+        return quote! {};
+    }
+
+    let enum_name = &input.ident;
+    let single_field_variants: Vec<(&Ident, &syn::Type)> = input
+        .variants
+        .iter()
+        .filter_map(|variant| match &variant.fields {
+            syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                Some((&variant.ident, &fields.unnamed[0].ty))
+            }
+            _ => None,
+        })
+        .collect();
+    let type_key = |ty: &syn::Type| quote!(#ty).to_string();
+
+    let wraps = |ty: &syn::Type| {
+        single_field_variants
+            .iter()
+            .filter(|(_, other)| type_key(other) == type_key(ty))
+            .count()
+    };
+
+    let conversions = single_field_variants
+        .iter()
+        .filter(|(_, ty)| wraps(ty) == 1)
+        .map(|(variant_ident, ty)| {
+            quote! {
+                impl From<#ty> for #enum_name {
+                    fn from(value: #ty) -> Self {
+                        Self::#variant_ident(value)
+                    }
+                }
+
+                impl TryFrom<#enum_name> for #ty {
+                    type Error = #enum_name;
+
+                    #[allow(unreachable_patterns)]
+                    fn try_from(value: #enum_name) -> core::result::Result<Self, Self::Error> {
+                        match value {
+                            #enum_name::#variant_ident(value) => Ok(value),
+                            other => Err(other),
+                        }
+                    }
+                }
+            }
+        });
+
+    quote! {
+        #(#conversions)*
+    }
+}
+
+/// Serializes the `Pubkey` and byte-array fields of a struct or enum as
+/// strings.
+///
+/// Serde serializes `Pubkey` and `[u8; N]` fields as arrays of numbers, which
+/// are hard to read in JSON output. This attribute adds
+/// `#[serde(with = "carbon_core::serde::pubkey_as_base58")]` to every field of
+/// type `Pubkey`, and `#[serde(with = "carbon_core::serde::bytes_as_base64")]`
+/// to every field of type `[u8; N]`, so that they serialize as base58 and
+/// base64 strings. Derive macros cannot change the attributes seen by other
+/// derives, so this is an attribute rather than part of `CarbonDeserialize`,
+/// and it has to be placed above the `derive` list.
+///
+/// # Syntax
+///
+/// ```ignore
+/// #[carbon_serde]
+/// #[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize)]
+/// #[carbon(discriminator = "0x01")]
+/// pub struct Initialize {
+///     pub authority: Pubkey,
+///     pub seed: [u8; 32],
+///     #[carbon_serde(raw)]
+///     pub raw_seed: [u8; 32],
+/// }
+/// ```
+///
+/// # Notes
+///
+/// - Fields marked `#[carbon_serde(raw)]` keep serde's default format.
+/// - Fields that already have a `with`, `serialize_with` or `deserialize_with`
+///   serde attribute, such as `serde_big_array::BigArray`, are left untouched.
+/// - Only fields whose type is exactly `Pubkey` or `[u8; N]` are changed;
+///   `Option<Pubkey>`, `Vec<Pubkey>` and other wrappers keep the default
+///   format.
+#[proc_macro_attribute]
+pub fn carbon_serde(args: TokenStream, input: TokenStream) -> TokenStream {
+    if !args.is_empty() {
+        return syn::Error::new(
+            TokenStream2::from(args)
+                .into_iter()
+                .next()
+                .map_or_else(Span::call_site, |token| token.span()),
+            "`carbon_serde` takes no arguments",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    let mut input = parse_macro_input!(input as DeriveInput);
+    let result = match &mut input.data {
+        syn::Data::Struct(data) => apply_carbon_serde(&mut data.fields),
+        syn::Data::Enum(data) => data
+            .variants
+            .iter_mut()
+            .try_for_each(|variant| apply_carbon_serde(&mut variant.fields)),
+        syn::Data::Union(_) => Err(syn::Error::new_spanned(
+            &input.ident,
+            "`carbon_serde` does not support unions",
+        )),
+    };
+
+    match result {
+        Ok(()) => quote! { #input }.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Adds the `carbon_core::serde` helpers to the `Pubkey` and byte-array
+/// `fields`, removing the `#[carbon_serde(raw)]` markers.
+fn apply_carbon_serde(fields: &mut syn::Fields) -> syn::Result<()> {
+    for field in fields.iter_mut() {
+        let mut raw = false;
+        let mut attrs = Vec::with_capacity(field.attrs.len());
+        for attr in field.attrs.drain(..) {
+            if attr.path.is_ident("carbon_serde") {
+                let arg: Ident = attr.parse_args()?;
+                if arg != "raw" {
+                    return Err(syn::Error::new_spanned(arg, "expected `raw`"));
+                }
+                raw = true;
+            } else {
+                attrs.push(attr);
+            }
+        }
+        field.attrs = attrs;
+
+        if raw || field.attrs.iter().any(has_serde_with) {
+            continue;
+        }
+
+        let with = match &field.ty {
+            Type::Path(type_path)
+                if type_path.qself.is_none()
+                    && type_path.path.segments.last().is_some_and(|segment| {
+                        segment.ident == "Pubkey" && segment.arguments.is_empty()
+                    }) =>
+            {
+                "carbon_core::serde::pubkey_as_base58"
+            }
+            Type::Array(array) if matches!(&*array.elem, Type::Path(elem) if elem.path.is_ident("u8")) => {
+                "carbon_core::serde::bytes_as_base64"
+            }
+            _ => continue,
+        };
+        field.attrs.push(syn::parse_quote!(#[serde(with = #with)]));
+    }
+
+    Ok(())
+}
+
+/// Whether `attr` is a serde attribute choosing how the field is serialized.
+fn has_serde_with(attr: &syn::Attribute) -> bool {
+    if !attr.path.is_ident("serde") {
+        return false;
+    }
+
+    match attr.parse_meta() {
+        Ok(Meta::List(list)) => list.nested.iter().any(|nested| {
+            matches!(
+                nested,
+                NestedMeta::Meta(Meta::NameValue(name_value))
+                    if ["with", "serialize_with", "deserialize_with"]
+                        .iter()
+                        .any(|name| name_value.path.is_ident(name))
+            )
+        }),
+        _ => false,
+    }
+}
@@ -0,0 +1,253 @@
+//! A datasource that replays transactions recorded in a file.
+//!
+//! The file contains one JSON record per line, in the format returned by the
+//! `getTransaction` RPC method: the slot, the block time, the encoded
+//! transaction and its status metadata. Files with a `.gz` extension are
+//! decompressed on the fly.
+//!
+//! Records are emitted as `Update::Transaction`, exactly like the live RPC
+//! datasources, so the same pipes and processors can be used for replays and
+//! backfills. By default records are replayed as fast as the pipeline consumes
+//! them; a rate limit can be set with `FileDatasource::with_rate`.
+//!
+//! For targeted backfills, the replay can be bounded to a slot range with
+//! `FileDatasource::with_start_slot` and `FileDatasource::with_end_slot`. A
+//! file cannot be searched by slot, so records before the start slot are still
+//! read and filtered out here. The replay ends at the first record after the
+//! end slot, or at the end of the file, after which the pipeline processes the
+//! pending updates and stops.
+
+use {
+    async_trait::async_trait,
+    carbon_core::{
+        datasource::{Datasource, TransactionUpdate, Update, UpdateType},
+        error::{CarbonResult, Error},
+        metrics::MetricsCollection,
+        transformers::transaction_metadata_from_original_meta_with_encoding,
+    },
+    flate2::read::GzDecoder,
+    solana_transaction_status::{EncodedConfirmedTransactionWithStatusMeta, UiTransactionEncoding},
+    std::{
+        fs::File,
+        io::{BufRead, BufReader},
+        path::PathBuf,
+        sync::Arc,
+        time::Duration,
+    },
+    tokio::sync::mpsc::{self, Sender},
+    tokio_util::sync::CancellationToken,
+};
+
+const LINE_CHANNEL_SIZE: usize = 1_000;
+
+pub struct FileDatasource {
+    pub path: PathBuf,
+    pub transactions_per_second: Option<u32>,
+    pub instruction_data_encoding: UiTransactionEncoding,
+    pub start_slot: Option<u64>,
+    pub end_slot: Option<u64>,
+}
+
+impl FileDatasource {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            transactions_per_second: None,
+            instruction_data_encoding: UiTransactionEncoding::Base58,
+            start_slot: None,
+            end_slot: None,
+        }
+    }
+
+    /// Limits the replay to the given number of transactions per second.
+    pub fn with_rate(mut self, transactions_per_second: u32) -> Self {
+        self.transactions_per_second = Some(transactions_per_second.max(1));
+        self
+    }
+
+    /// Sets the encoding of the recorded inner instruction data.
+    ///
+    /// Records fetched through the standard RPC encode it in base58, which is
+    /// the default. Use `UiTransactionEncoding::Base64` for records from
+    /// providers that encode it in base64.
+    pub fn with_instruction_data_encoding(mut self, encoding: UiTransactionEncoding) -> Self {
+        self.instruction_data_encoding = encoding;
+        self
+    }
+
+    /// Skips the records of slots before `start_slot`.
+    pub fn with_start_slot(mut self, start_slot: u64) -> Self {
+        self.start_slot = Some(start_slot);
+        self
+    }
+
+    /// Ends the replay at the first record of a slot after `end_slot`.
+    ///
+    /// Records are expected in slot order, as they are when recorded from a
+    /// crawler.
+    pub fn with_end_slot(mut self, end_slot: u64) -> Self {
+        self.end_slot = Some(end_slot);
+        self
+    }
+
+    fn open(&self) -> CarbonResult<Box<dyn BufRead + Send>> {
+        let file = File::open(&self.path).map_err(|err| {
+            Error::Custom(format!("Failed to open {}: {}", self.path.display(), err))
+        })?;
+
+        let gzip = self
+            .path
+            .extension()
+            .is_some_and(|extension| extension == "gz");
+
+        if gzip {
+            Ok(Box::new(BufReader::new(GzDecoder::new(file))))
+        } else {
+            Ok(Box::new(BufReader::new(file)))
+        }
+    }
+}
+
+#[async_trait]
+impl Datasource for FileDatasource {
+    async fn consume(
+        &self,
+        sender: &Sender<Update>,
+        cancellation_token: CancellationToken,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        let reader = self.open()?;
+        let path = self.path.display().to_string();
+        let instruction_data_encoding = self.instruction_data_encoding;
+
+        // Reading and decompressing is blocking, so it happens on a dedicated
+        // thread that hands the lines over through a bounded channel.
+        let (line_sender, mut line_receiver) = mpsc::channel(LINE_CHANNEL_SIZE);
+        tokio::task::spawn_blocking(move || {
+            for line in reader.lines() {
+                if line_sender.blocking_send(line).is_err() {
+                    break;
+                }
+            }
+        });
+
+        let mut interval = self.transactions_per_second.map(|transactions_per_second| {
+            tokio::time::interval(Duration::from_secs(1) / transactions_per_second)
+        });
+
+        loop {
+            let line = tokio::select! {
+                _ = cancellation_token.cancelled() => {
+                    log::info!("Cancellation requested, stopping replay of {}", path);
+                    return Ok(());
+                }
+                line = line_receiver.recv() => line,
+            };
+
+            let line = match line {
+                Some(Ok(line)) => line,
+                Some(Err(err)) => {
+                    return Err(Error::Custom(format!("Failed to read {}: {}", path, err)));
+                }
+                None => break,
+            };
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let update = match transaction_update_from_record(&line, instruction_data_encoding) {
+                Ok(Some(update)) => update,
+                Ok(None) => continue,
+                Err(err) => {
+                    log::error!("Failed to parse record from {}: {:?}", path, err);
+                    metrics
+                        .increment_counter("file_datasource_records_failed", 1)
+                        .await
+                        .unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
+                    continue;
+                }
+            };
+
+            if self.end_slot.is_some_and(|end_slot| update.slot > end_slot) {
+                log::info!("Reached end slot of the replay of {}", path);
+                break;
+            }
+
+            if self
+                .start_slot
+                .is_some_and(|start_slot| update.slot < start_slot)
+            {
+                continue;
+            }
+
+            if let Some(interval) = interval.as_mut() {
+                interval.tick().await;
+            }
+
+            if let Err(err) = sender.send(Update::Transaction(update)).await {
+                log::error!("Error sending transaction update: {:?}", err);
+                break;
+            }
+
+            metrics
+                .increment_counter("file_datasource_transactions_processed", 1)
+                .await
+                .unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
+        }
+
+        log::info!("Finished replaying {}", path);
+
+        Ok(())
+    }
+
+    fn update_types(&self) -> Vec<UpdateType> {
+        vec![UpdateType::Transaction]
+    }
+}
+
+/// Converts a recorded `getTransaction` response into a transaction update.
+///
+/// Returns `Ok(None)` for failed transactions, which the live datasources do
+/// not emit either.
+fn transaction_update_from_record(
+    line: &str,
+    instruction_data_encoding: UiTransactionEncoding,
+) -> CarbonResult<Option<Box<TransactionUpdate>>> {
+    let record: EncodedConfirmedTransactionWithStatusMeta = serde_json::from_str(line)
+        .map_err(|err| Error::Custom(format!("Invalid transaction record: {}", err)))?;
+
+    let meta_original = record
+        .transaction
+        .meta
+        .ok_or_else(|| Error::Custom("Transaction record has no metadata".to_string()))?;
+
+    if meta_original.status.is_err() {
+        return Ok(None);
+    }
+
+    let transaction = record
+        .transaction
+        .transaction
+        .decode()
+        .ok_or_else(|| Error::Custom("Failed to decode transaction".to_string()))?;
+
+    let signature = *transaction
+        .signatures
+        .first()
+        .ok_or_else(|| Error::Custom("Transaction has no signatures".to_string()))?;
+
+    Ok(Some(Box::new(TransactionUpdate {
+        signature,
+        transaction,
+        meta: transaction_metadata_from_original_meta_with_encoding(
+            meta_original,
+            instruction_data_encoding,
+        )?,
+        is_vote: false,
+        slot: record.slot,
+        block_time: record.block_time,
+        commitment: None,
+        source: None,
+    })))
+}
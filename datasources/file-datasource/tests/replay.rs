@@ -0,0 +1,146 @@
+use {
+    async_trait::async_trait,
+    carbon_core::{
+        error::CarbonResult,
+        instruction::{DecodedInstruction, InstructionDecoder, InstructionProcessorInputType},
+        metrics::MetricsCollection,
+        pipeline::Pipeline,
+        processor::Processor,
+    },
+    carbon_file_datasource::FileDatasource,
+    flate2::{write::GzEncoder, Compression},
+    std::{
+        io::Write,
+        path::{Path, PathBuf},
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        time::Duration,
+    },
+    tokio_util::sync::CancellationToken,
+};
+
+const FIXTURE: &str = "tests/fixtures/transactions.jsonl";
+
+/// Two top-level instructions with one inner instruction, and one top-level
+/// instruction. The third record is a failed transaction and is skipped.
+const EXPECTED_INSTRUCTIONS: usize = 4;
+
+struct AnyInstructionDecoder;
+
+impl InstructionDecoder<'_> for AnyInstructionDecoder {
+    type InstructionType = ();
+
+    fn try_decode_instruction(
+        &self,
+        instruction: &solana_instruction::Instruction,
+    ) -> CarbonResult<Option<DecodedInstruction<Self::InstructionType>>> {
+        Ok(Some(DecodedInstruction {
+            program_id: instruction.program_id,
+            data: (),
+            accounts: instruction.accounts.clone(),
+            discriminator: vec![],
+            raw_len: instruction.data.len(),
+            num_accounts: instruction.accounts.len(),
+            raw_data: instruction.data.clone(),
+        }))
+    }
+}
+
+struct CountingProcessor(Arc<AtomicUsize>);
+
+#[async_trait]
+impl Processor for CountingProcessor {
+    type InputType = InstructionProcessorInputType<()>;
+
+    async fn process(
+        &mut self,
+        _data: Self::InputType,
+        _metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        self.0.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    }
+}
+
+async fn replay(datasource: FileDatasource) -> usize {
+    let processed = Arc::new(AtomicUsize::new(0));
+    let shutdown_token = CancellationToken::new();
+    let mut pipeline = Pipeline::builder()
+        .datasource(datasource)
+        .instruction(AnyInstructionDecoder, CountingProcessor(processed.clone()))
+        .shutdown_token(shutdown_token.clone())
+        .build()
+        .expect("build pipeline");
+
+    let pipeline = tokio::spawn(async move { pipeline.run().await });
+
+    tokio::time::timeout(Duration::from_secs(10), async {
+        while processed.load(Ordering::SeqCst) < EXPECTED_INSTRUCTIONS {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("replay timed out");
+
+    shutdown_token.cancel();
+    pipeline
+        .await
+        .expect("pipeline task")
+        .expect("pipeline run");
+
+    processed.load(Ordering::SeqCst)
+}
+
+fn gzip_fixture(path: &Path) -> PathBuf {
+    let gzip_path = std::env::temp_dir().join(format!(
+        "carbon-file-datasource-{}.jsonl.gz",
+        std::process::id()
+    ));
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder
+        .write_all(&std::fs::read(path).expect("read fixture"))
+        .expect("compress fixture");
+    std::fs::write(&gzip_path, encoder.finish().expect("finish gzip")).expect("write gzip");
+    gzip_path
+}
+
+#[tokio::test]
+async fn test_replays_jsonl_file() {
+    let processed = replay(FileDatasource::new(FIXTURE)).await;
+
+    assert_eq!(processed, EXPECTED_INSTRUCTIONS);
+}
+
+#[tokio::test]
+async fn test_replays_gzip_file_with_rate_limit() {
+    let gzip_path = gzip_fixture(Path::new(FIXTURE));
+
+    let processed = replay(FileDatasource::new(&gzip_path).with_rate(100)).await;
+    std::fs::remove_file(&gzip_path).ok();
+
+    assert_eq!(processed, EXPECTED_INSTRUCTIONS);
+}
+
+#[tokio::test]
+async fn test_replays_slot_window_and_stops() {
+    let processed = Arc::new(AtomicUsize::new(0));
+    let mut pipeline = Pipeline::builder()
+        .datasource(
+            FileDatasource::new(FIXTURE)
+                .with_start_slot(320_000_001)
+                .with_end_slot(320_000_001),
+        )
+        .instruction(AnyInstructionDecoder, CountingProcessor(processed.clone()))
+        .build()
+        .expect("build pipeline");
+
+    tokio::time::timeout(Duration::from_secs(10), pipeline.run())
+        .await
+        .expect("pipeline did not stop at the end slot")
+        .expect("pipeline run");
+
+    // Only the single instruction of the record in slot 320000001.
+    assert_eq!(processed.load(Ordering::SeqCst), 1);
+}
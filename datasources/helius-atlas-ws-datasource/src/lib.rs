@@ -312,6 +312,8 @@ impl Datasource for HeliusWebsocket {
                                                         let account_deletion = AccountDeletion {
                                                             pubkey: account,
                                                             slot: acc_event.context.slot,
+                                                            commitment: None,
+                                                            source: None,
                                                         };
 
                                                         metrics.record_histogram("helius_atlas_ws_account_deletion_process_time_nanoseconds", start_time.elapsed().as_nanos() as f64).await.unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
@@ -331,6 +333,9 @@ impl Datasource for HeliusWebsocket {
                                                         pubkey: account,
                                                         account: decoded_account,
                                                         slot: acc_event.context.slot,
+                                                        commitment: None,
+                                                        is_snapshot: false,
+                                                        source: None,
                                                     });
 
                                                     metrics.record_histogram("helius_atlas_ws_account_process_time_nanoseconds", start_time.elapsed().as_nanos() as f64).await.unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
@@ -583,6 +588,8 @@ impl Datasource for HeliusWebsocket {
                                                 is_vote: config.filter.vote.is_some_and(|is_vote| is_vote),
                                                 slot: tx_event.slot,
                                                 block_time: None,
+                                                commitment: None,
+                                                source: None,
                                             }));
 
                                             metrics
@@ -119,6 +119,8 @@ impl Datasource for JitoShredstreamGrpcClient {
                                         start_time.duration_since(UNIX_EPOCH).unwrap().as_secs()
                                             as i64,
                                     ),
+                                    commitment: None,
+                                    source: None,
                                 }));
 
                                 if let Err(e) = sender.try_send(update) {
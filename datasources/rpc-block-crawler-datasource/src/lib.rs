@@ -299,6 +299,8 @@ fn task_processor(
                                 is_vote: false,
                                 slot,
                                 block_time: block.block_time,
+                                commitment: None,
+                                source: None,
                             }));
 
                             metrics
@@ -153,6 +153,8 @@ impl Datasource for RpcBlockSubscribe {
                                                 is_vote: false,
                                                 slot,
                                                 block_time: block.block_time,
+                                                commitment: None,
+                                                source: None,
                                             }));
 
                                             metrics
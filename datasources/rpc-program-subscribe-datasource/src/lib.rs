@@ -16,6 +16,10 @@ use {
     tokio_util::sync::CancellationToken,
 };
 
+pub mod snapshot;
+
+pub use snapshot::RpcProgramAccountsSnapshot;
+
 const MAX_RECONNECTION_ATTEMPTS: u32 = 10;
 const RECONNECTION_DELAY_MS: u64 = 3000;
 
@@ -134,6 +138,9 @@ impl Datasource for RpcProgramSubscribe {
                                     pubkey: account_pubkey,
                                     account: decoded_account,
                                     slot: acc_event.context.slot,
+                                    commitment: None,
+                                    is_snapshot: false,
+                                    source: None,
                                 });
 
                                 metrics
@@ -0,0 +1,184 @@
+//! Loads the accounts of a program with `getProgramAccounts`, to bootstrap the
+//! state that `RpcProgramSubscribe` then keeps up to date.
+
+use {
+    async_trait::async_trait,
+    carbon_core::{
+        datasource::AccountUpdate,
+        error::{CarbonResult, Error},
+        snapshot::AccountSnapshot,
+    },
+    solana_account_decoder_client_types::UiAccountEncoding,
+    solana_client::{
+        nonblocking::rpc_client::RpcClient,
+        rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+        rpc_filter::{Memcmp, RpcFilterType},
+    },
+    solana_pubkey::Pubkey,
+};
+
+/// An `AccountSnapshot` of the accounts owned by a program, fetched over RPC
+/// with `getProgramAccounts`.
+///
+/// `filters` apply to every request, for example a `dataSize` filter selecting
+/// a single account type. Programs with more accounts than an RPC node returns
+/// at once can be fetched in pages: each filter set of `pages` is sent as a
+/// separate request, combined with `filters`. The pages must not overlap, for
+/// example by matching distinct values of one byte with `memcmp`.
+///
+/// The accounts are tagged with the slot the node reported before the first
+/// request, which may be older than the state returned.
+pub struct RpcProgramAccountsSnapshot {
+    pub rpc_client: RpcClient,
+    pub program_id: Pubkey,
+    pub filters: Vec<RpcFilterType>,
+    pub pages: Vec<Vec<RpcFilterType>>,
+}
+
+impl RpcProgramAccountsSnapshot {
+    pub fn new(rpc_url: impl Into<String>, program_id: Pubkey) -> Self {
+        Self {
+            rpc_client: RpcClient::new(rpc_url.into()),
+            program_id,
+            filters: Vec::new(),
+            pages: Vec::new(),
+        }
+    }
+
+    /// Only loads the accounts whose data is `data_size` bytes long.
+    pub fn with_data_size(mut self, data_size: u64) -> Self {
+        self.filters.push(RpcFilterType::DataSize(data_size));
+        self
+    }
+
+    /// Only loads the accounts whose data contains `bytes` at `offset`, such as
+    /// an Anchor account discriminator at offset 0.
+    pub fn with_memcmp(mut self, offset: usize, bytes: &[u8]) -> Self {
+        self.filters
+            .push(RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+                offset, bytes,
+            )));
+        self
+    }
+
+    /// Fetches the accounts with one request per filter set instead of a
+    /// single request.
+    pub fn with_pages(mut self, pages: Vec<Vec<RpcFilterType>>) -> Self {
+        self.pages = pages;
+        self
+    }
+}
+
+#[async_trait]
+impl AccountSnapshot for RpcProgramAccountsSnapshot {
+    async fn load(&self) -> CarbonResult<Vec<AccountUpdate>> {
+        let slot = self.rpc_client.get_slot().await.map_err(|err| {
+            Error::FailedToConsumeDatasource(format!("failed to fetch slot: {}", err))
+        })?;
+
+        let pages = if self.pages.is_empty() {
+            vec![Vec::new()]
+        } else {
+            self.pages.clone()
+        };
+
+        let mut accounts = Vec::new();
+        for (page_index, page) in pages.into_iter().enumerate() {
+            let filters: Vec<RpcFilterType> = self.filters.iter().cloned().chain(page).collect();
+            let config = RpcProgramAccountsConfig {
+                filters: (!filters.is_empty()).then_some(filters),
+                account_config: RpcAccountInfoConfig {
+                    encoding: Some(UiAccountEncoding::Base64),
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+
+            let page_accounts = self
+                .rpc_client
+                .get_program_accounts_with_config(&self.program_id, config)
+                .await
+                .map_err(|err| {
+                    Error::FailedToConsumeDatasource(format!(
+                        "failed to fetch page {} of the accounts of program {}: {}",
+                        page_index, self.program_id, err
+                    ))
+                })?;
+            log::debug!(
+                "fetched {} accounts of program {} in page {}",
+                page_accounts.len(),
+                self.program_id,
+                page_index
+            );
+
+            accounts.extend(
+                page_accounts
+                    .into_iter()
+                    .map(|(pubkey, account)| AccountUpdate {
+                        pubkey,
+                        account,
+                        slot,
+                        commitment: None,
+                        is_snapshot: true,
+                        source: None,
+                    }),
+            );
+        }
+
+        Ok(accounts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*, serde_json::json, solana_client::rpc_request::RpcRequest,
+        std::collections::HashMap,
+    };
+
+    #[tokio::test]
+    async fn test_load_program_accounts_from_rpc() {
+        // Arrange
+        let program_id = Pubkey::new_unique();
+        let [first, second] = [(); 2].map(|()| Pubkey::new_unique());
+        let keyed_account = |pubkey: Pubkey, lamports: u64, data: &str| {
+            json!({
+                "pubkey": pubkey.to_string(),
+                "account": {
+                    "lamports": lamports,
+                    "data": [data, "base64"],
+                    "owner": program_id.to_string(),
+                    "executable": false,
+                    "rentEpoch": 0,
+                    "space": 3,
+                },
+            })
+        };
+        let mocks = HashMap::from([(
+            RpcRequest::GetProgramAccounts,
+            json!([
+                keyed_account(first, 1_000, "AQID"),
+                keyed_account(second, 2_000, "BAUG"),
+            ]),
+        )]);
+        let snapshot = RpcProgramAccountsSnapshot {
+            rpc_client: RpcClient::new_mock_with_mocks("succeeds".to_string(), mocks),
+            ..RpcProgramAccountsSnapshot::new("http://localhost:8899", program_id)
+        }
+        .with_data_size(3);
+
+        // Act
+        let accounts = snapshot.load().await.expect("load snapshot");
+
+        // Assert
+        assert_eq!(accounts.len(), 2);
+        assert_eq!(accounts[0].pubkey, first);
+        assert_eq!(accounts[0].account.lamports, 1_000);
+        assert_eq!(accounts[0].account.data, vec![1, 2, 3]);
+        assert_eq!(accounts[1].pubkey, second);
+        assert_eq!(accounts[1].account.data, vec![4, 5, 6]);
+        assert!(accounts
+            .iter()
+            .all(|account| account.is_snapshot && account.account.owner == program_id));
+    }
+}
@@ -0,0 +1,62 @@
+//! Persistence of the crawler's position, so that a restarted crawler resumes
+//! where the previous one stopped instead of re-crawling the whole history.
+
+use {
+    solana_signature::Signature,
+    std::{
+        fs, io,
+        path::{Path, PathBuf},
+        str::FromStr,
+    },
+};
+
+/// A file-backed cursor holding the most recent signature the crawler has
+/// finished a pass up to.
+///
+/// The signature is stored as base58 text. Writes go to a temporary file that
+/// is renamed over the cursor file, so a crash during `save` leaves either the
+/// previous or the new signature, never a partial one.
+#[derive(Debug, Clone)]
+pub struct SignatureCursor {
+    path: PathBuf,
+}
+
+impl SignatureCursor {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Loads the stored signature, or `None` if there is no cursor yet or it
+    /// cannot be read.
+    pub fn load(&self) -> Option<Signature> {
+        let content = match fs::read_to_string(&self.path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return None,
+            Err(err) => {
+                log::error!("Failed to read cursor {}: {}", self.path.display(), err);
+                return None;
+            }
+        };
+
+        match Signature::from_str(content.trim()) {
+            Ok(signature) => Some(signature),
+            Err(err) => {
+                log::error!("Invalid cursor {}: {:?}", self.path.display(), err);
+                None
+            }
+        }
+    }
+
+    /// Atomically replaces the stored signature.
+    pub fn save(&self, signature: &Signature) -> io::Result<()> {
+        let mut temp_path = self.path.clone().into_os_string();
+        temp_path.push(".tmp");
+
+        fs::write(&temp_path, signature.to_string())?;
+        fs::rename(&temp_path, &self.path)
+    }
+}
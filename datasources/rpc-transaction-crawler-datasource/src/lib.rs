@@ -1,4 +1,7 @@
+pub mod cursor;
+
 use {
+    crate::cursor::SignatureCursor,
     async_trait::async_trait,
     carbon_core::{
         datasource::{Datasource, TransactionUpdate, Update, UpdateType},
@@ -8,8 +11,11 @@ use {
     },
     futures::StreamExt,
     solana_client::{
-        nonblocking::rpc_client::RpcClient, rpc_client::GetConfirmedSignaturesForAddress2Config,
+        client_error::{ClientError, ClientErrorKind},
+        nonblocking::rpc_client::RpcClient,
+        rpc_client::GetConfirmedSignaturesForAddress2Config,
         rpc_config::RpcTransactionConfig,
+        rpc_request::RpcError,
     },
     solana_pubkey::Pubkey,
     solana_sdk::commitment_config::CommitmentConfig,
@@ -17,7 +23,7 @@ use {
     solana_transaction_status::{
         EncodedConfirmedTransactionWithStatusMeta, UiLoadedAddresses, UiTransactionEncoding,
     },
-    std::{collections::HashSet, str::FromStr, sync::Arc, time::Duration},
+    std::{collections::HashSet, path::PathBuf, str::FromStr, sync::Arc, time::Duration},
     tokio::{
         sync::mpsc::{self, Receiver, Sender},
         task::JoinHandle,
@@ -26,6 +32,9 @@ use {
     tokio_util::sync::CancellationToken,
 };
 
+const RATE_LIMIT_INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+const RATE_LIMIT_MAX_BACKOFF: Duration = Duration::from_secs(30);
+
 #[derive(Debug, Clone)]
 pub struct Filters {
     pub accounts: Option<Vec<Pubkey>>,
@@ -55,6 +64,7 @@ pub struct RpcTransactionCrawler {
     pub filters: Filters,
     pub commitment: Option<CommitmentConfig>,
     pub max_concurrent_requests: usize,
+    pub cursor: Option<SignatureCursor>,
 }
 
 impl RpcTransactionCrawler {
@@ -75,8 +85,20 @@ impl RpcTransactionCrawler {
             filters,
             commitment,
             max_concurrent_requests,
+            cursor: None,
         }
     }
+
+    /// Persists the crawler's position to the given file, so that a restarted
+    /// crawler only fetches transactions newer than the last completed pass.
+    ///
+    /// The cursor is saved each time the crawler has walked all signatures
+    /// down to the previous cursor. If it exists on startup, it takes the place
+    /// of `Filters::until_signature`.
+    pub fn with_cursor(mut self, path: impl Into<PathBuf>) -> Self {
+        self.cursor = Some(SignatureCursor::new(path));
+        self
+    }
 }
 
 #[async_trait]
@@ -98,6 +120,7 @@ impl Datasource for RpcTransactionCrawler {
         let sender = sender.clone();
         let commitment = self.commitment;
         let max_concurrent_requests = self.max_concurrent_requests;
+        let cursor = self.cursor.clone();
 
         let (signature_sender, signature_receiver) = mpsc::channel(1000);
         let (transaction_sender, transaction_receiver) = mpsc::channel(1000);
@@ -110,6 +133,7 @@ impl Datasource for RpcTransactionCrawler {
             signature_sender,
             filters.clone(),
             commitment,
+            cursor,
             cancellation_token.clone(),
             metrics.clone(),
         );
@@ -157,6 +181,7 @@ fn signature_fetcher(
     signature_sender: Sender<Signature>,
     filters: Filters,
     commitment: Option<CommitmentConfig>,
+    cursor: Option<SignatureCursor>,
     cancellation_token: CancellationToken,
     metrics: Arc<MetricsCollection>,
 ) -> JoinHandle<()> {
@@ -166,8 +191,12 @@ fn signature_fetcher(
 
     tokio::spawn(async move {
         let mut last_fetched_signature = filters.before_signature;
-        let mut until_signature = filters.until_signature;
+        let mut until_signature = cursor
+            .as_ref()
+            .and_then(SignatureCursor::load)
+            .or(filters.until_signature);
         let mut most_recent_signature: Option<Signature> = None;
+        let mut backoff = RateLimitBackoff::default();
         loop {
             tokio::select! {
                 _ = cancellation_token.cancelled() => {
@@ -186,6 +215,7 @@ fn signature_fetcher(
                     match result {
                         Ok(signatures) => {
                             let start = Instant::now();
+                            backoff.reset();
 
                             if signatures.is_empty() {
                                 // no more signatures to fetch, so we've gone through
@@ -200,6 +230,12 @@ fn signature_fetcher(
                                         // this will prevent reindexing old transactions
                                         // after we run out of new
                                         most_recent_signature = None;
+
+                                        if let (Some(cursor), Some(signature)) = (&cursor, &until_signature) {
+                                            if let Err(e) = cursor.save(signature) {
+                                                log::error!("Failed to save cursor {}: {:?}", cursor.path().display(), e);
+                                            }
+                                        }
                                 }
 
                                 tokio::time::sleep(polling_interval).await;
@@ -244,6 +280,12 @@ fn signature_fetcher(
                             metrics.increment_counter("transaction_crawler_signatures_fetched", signatures.len() as u64).await.unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
 
                         }
+                        Err(e) if is_rate_limited(&e) => {
+                            let delay = backoff.next_delay();
+                            log::warn!("Rate limited while fetching signatures, retrying in {:?}", delay);
+                            metrics.increment_counter("transaction_crawler_rate_limited", 1).await.unwrap_or_else(|value| log::error!("Error recording metric: {}", value));
+                            tokio::time::sleep(delay).await;
+                        }
                         Err(e) => {
                             log::error!("Error fetching signatures: {:?}", e);
                             tokio::time::sleep(Duration::from_secs(1)).await;
@@ -282,36 +324,58 @@ fn transaction_fetcher(
                     let metrics = metrics.clone();
                     async move {
                         let start = Instant::now();
-
-                        match rpc_client
-                            .get_transaction_with_config(
-                                &signature,
-                                RpcTransactionConfig {
-                                    encoding: Some(UiTransactionEncoding::Base64),
-                                    commitment: Some(
-                                        commitment.unwrap_or(CommitmentConfig::confirmed()),
-                                    ),
-                                    max_supported_transaction_version: Some(0),
-                                },
-                            )
-                            .await
-                        {
-                            Ok(tx) => {
-                                let time_taken = start.elapsed().as_millis();
-
-                                metrics
-                                    .record_histogram(
-                                        "transaction_crawler_transaction_fetch_times_milliseconds",
-                                        time_taken as f64,
-                                    )
-                                    .await
-                                    .unwrap();
-
-                                Some((signature, tx))
-                            }
-                            Err(e) => {
-                                log::error!("Error fetching transaction {}: {:?}", signature, e);
-                                None
+                        let mut backoff = RateLimitBackoff::default();
+
+                        loop {
+                            match rpc_client
+                                .get_transaction_with_config(
+                                    &signature,
+                                    RpcTransactionConfig {
+                                        encoding: Some(UiTransactionEncoding::Base64),
+                                        commitment: Some(
+                                            commitment.unwrap_or(CommitmentConfig::confirmed()),
+                                        ),
+                                        max_supported_transaction_version: Some(0),
+                                    },
+                                )
+                                .await
+                            {
+                                Ok(tx) => {
+                                    let time_taken = start.elapsed().as_millis();
+
+                                    metrics
+                                        .record_histogram(
+                                            "transaction_crawler_transaction_fetch_times_milliseconds",
+                                            time_taken as f64,
+                                        )
+                                        .await
+                                        .unwrap();
+
+                                    break Some((signature, tx));
+                                }
+                                Err(e) if is_rate_limited(&e) => {
+                                    let delay = backoff.next_delay();
+                                    log::warn!(
+                                        "Rate limited while fetching transaction {}, retrying in {:?}",
+                                        signature,
+                                        delay
+                                    );
+                                    metrics
+                                        .increment_counter("transaction_crawler_rate_limited", 1)
+                                        .await
+                                        .unwrap_or_else(|value| {
+                                            log::error!("Error recording metric: {}", value)
+                                        });
+                                    tokio::time::sleep(delay).await;
+                                }
+                                Err(e) => {
+                                    log::error!(
+                                        "Error fetching transaction {}: {:?}",
+                                        signature,
+                                        e
+                                    );
+                                    break None;
+                                }
                             }
                         }
                     }
@@ -432,6 +496,8 @@ fn task_processor(
                         is_vote: false,
                         slot: fetched_transaction.slot,
                         block_time: fetched_transaction.block_time,
+                        commitment: None,
+                        source: None,
                     }));
 
 
@@ -453,3 +519,60 @@ fn task_processor(
         }
     })
 }
+
+/// Returns whether the RPC node rejected a request because of rate limiting,
+/// either with an HTTP 429 status or a JSON-RPC error with code 429.
+fn is_rate_limited(error: &ClientError) -> bool {
+    match error.kind() {
+        ClientErrorKind::Reqwest(error) => {
+            error.status().map(|status| status.as_u16()) == Some(429)
+        }
+        ClientErrorKind::RpcError(RpcError::RpcResponseError { code, .. }) => *code == 429,
+        _ => false,
+    }
+}
+
+/// Exponential backoff for rate-limited requests, doubling from
+/// `RATE_LIMIT_INITIAL_BACKOFF` up to `RATE_LIMIT_MAX_BACKOFF`.
+#[derive(Debug)]
+struct RateLimitBackoff {
+    delay: Duration,
+}
+
+impl Default for RateLimitBackoff {
+    fn default() -> Self {
+        Self {
+            delay: RATE_LIMIT_INITIAL_BACKOFF,
+        }
+    }
+}
+
+impl RateLimitBackoff {
+    fn next_delay(&mut self) -> Duration {
+        let delay = self.delay;
+        self.delay = (self.delay * 2).min(RATE_LIMIT_MAX_BACKOFF);
+        delay
+    }
+
+    fn reset(&mut self) {
+        self.delay = RATE_LIMIT_INITIAL_BACKOFF;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rate_limit_backoff_doubles_up_to_max() {
+        let mut backoff = RateLimitBackoff::default();
+
+        let delays: Vec<_> = (0..8).map(|_| backoff.next_delay()).collect();
+        assert_eq!(delays[0], RATE_LIMIT_INITIAL_BACKOFF);
+        assert_eq!(delays[1], RATE_LIMIT_INITIAL_BACKOFF * 2);
+        assert_eq!(delays[7], RATE_LIMIT_MAX_BACKOFF);
+
+        backoff.reset();
+        assert_eq!(backoff.next_delay(), RATE_LIMIT_INITIAL_BACKOFF);
+    }
+}
@@ -0,0 +1,257 @@
+use {
+    carbon_core::{
+        datasource::{Datasource, Update},
+        metrics::MetricsCollection,
+    },
+    carbon_rpc_transaction_crawler_datasource::{Filters, RpcTransactionCrawler},
+    serde_json::{json, Value},
+    solana_pubkey::Pubkey,
+    solana_signature::Signature,
+    std::{
+        collections::HashSet,
+        sync::{Arc, Mutex},
+        time::Duration,
+    },
+    tokio::{
+        io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+        net::{TcpListener, TcpStream},
+        sync::mpsc,
+    },
+    tokio_util::sync::CancellationToken,
+};
+
+/// A legacy transaction with two instructions, base64-encoded.
+const TRANSACTION: &str = "AQcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcBAAIEAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQICAgEDBAEAAAACAgEDBAIAAAA=";
+
+/// A minimal JSON-RPC server serving `getSignaturesForAddress` in pages of
+/// `signatures`, newest first, and `getTransaction` for any signature.
+#[derive(Clone)]
+struct MockRpc {
+    signatures: Vec<Signature>,
+    page_size: usize,
+    signature_requests: Arc<Mutex<Vec<Value>>>,
+}
+
+impl MockRpc {
+    async fn start(self) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+        let url = format!("http://{}", listener.local_addr().expect("local address"));
+
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                tokio::spawn(self.clone().serve(stream));
+            }
+        });
+
+        url
+    }
+
+    async fn serve(self, stream: TcpStream) {
+        let mut reader = BufReader::new(stream);
+
+        loop {
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                if reader.read_line(&mut line).await.unwrap_or(0) == 0 {
+                    return;
+                }
+                let line = line.trim_end();
+                if line.is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        content_length = value.trim().parse().unwrap_or(0);
+                    }
+                }
+            }
+
+            let mut body = vec![0; content_length];
+            if reader.read_exact(&mut body).await.is_err() {
+                return;
+            }
+            let request: Value = serde_json::from_slice(&body).expect("JSON-RPC request");
+            let response = serde_json::to_vec(&json!({
+                "jsonrpc": "2.0",
+                "id": request["id"],
+                "result": self.handle(&request),
+            }))
+            .expect("JSON-RPC response");
+
+            let head = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n",
+                response.len()
+            );
+            let stream = reader.get_mut();
+            if stream.write_all(head.as_bytes()).await.is_err()
+                || stream.write_all(&response).await.is_err()
+            {
+                return;
+            }
+        }
+    }
+
+    fn handle(&self, request: &Value) -> Value {
+        match request["method"].as_str() {
+            Some("getSignaturesForAddress") => {
+                let config = request["params"][1].clone();
+                self.signature_requests.lock().unwrap().push(config.clone());
+
+                let signatures = self.signatures.iter().map(ToString::to_string);
+                let until = config["until"].as_str();
+                let newer: Vec<String> = signatures
+                    .take_while(|signature| Some(signature.as_str()) != until)
+                    .collect();
+                let start = config["before"]
+                    .as_str()
+                    .and_then(|before| newer.iter().position(|signature| signature == before))
+                    .map_or(0, |position| position + 1);
+
+                newer
+                    .iter()
+                    .skip(start)
+                    .take(self.page_size)
+                    .map(|signature| {
+                        json!({
+                            "signature": signature,
+                            "slot": 320_000_000,
+                            "err": null,
+                            "memo": null,
+                            "blockTime": 1_738_000_000,
+                            "confirmationStatus": "finalized",
+                        })
+                    })
+                    .collect()
+            }
+            Some("getTransaction") => json!({
+                "slot": 320_000_000,
+                "blockTime": 1_738_000_000,
+                "version": "legacy",
+                "transaction": [TRANSACTION, "base64"],
+                "meta": {
+                    "err": null,
+                    "status": { "Ok": null },
+                    "fee": 5000,
+                    "preBalances": [1_000_000_000u64, 2_039_280, 1_141_440, 1_141_440],
+                    "postBalances": [999_995_000u64, 2_039_280, 1_141_440, 1_141_440],
+                    "innerInstructions": [],
+                    "logMessages": [],
+                    "preTokenBalances": [],
+                    "postTokenBalances": [],
+                    "rewards": [],
+                    "loadedAddresses": { "writable": [], "readonly": [] },
+                    "computeUnitsConsumed": 12_345,
+                },
+            }),
+            Some("getVersion") => json!({ "solana-core": "2.1.16", "feature-set": 0 }),
+            _ => Value::Null,
+        }
+    }
+}
+
+fn crawler(rpc_url: String) -> RpcTransactionCrawler {
+    RpcTransactionCrawler::new(
+        rpc_url,
+        Pubkey::new_unique(),
+        2,
+        Duration::from_millis(50),
+        Filters::new(None, None, None),
+        None,
+        2,
+    )
+}
+
+async fn receive_signatures(receiver: &mut mpsc::Receiver<Update>, count: usize) -> Vec<Signature> {
+    let mut signatures = Vec::new();
+    while signatures.len() < count {
+        match tokio::time::timeout(Duration::from_secs(10), receiver.recv()).await {
+            Ok(Some(Update::Transaction(transaction))) => signatures.push(transaction.signature),
+            Ok(Some(_)) => {}
+            Ok(None) | Err(_) => break,
+        }
+    }
+    signatures
+}
+
+#[tokio::test]
+async fn test_crawls_two_pages_and_resumes_from_cursor() {
+    let signatures: Vec<Signature> = (1..=3).map(|n| Signature::from([n; 64])).collect();
+    let signature_requests = Arc::new(Mutex::new(Vec::new()));
+    let rpc_url = MockRpc {
+        signatures: signatures.clone(),
+        page_size: 2,
+        signature_requests: signature_requests.clone(),
+    }
+    .start()
+    .await;
+    let cursor_path = std::env::temp_dir().join(format!(
+        "carbon-rpc-transaction-crawler-{}.cursor",
+        std::process::id()
+    ));
+    std::fs::remove_file(&cursor_path).ok();
+
+    // First run: two pages of signatures, then an empty page completes the
+    // pass and saves the most recent signature as the cursor.
+    let (sender, mut receiver) = mpsc::channel(100);
+    let cancellation_token = CancellationToken::new();
+    crawler(rpc_url.clone())
+        .with_cursor(&cursor_path)
+        .consume(
+            &sender,
+            cancellation_token.clone(),
+            Arc::new(MetricsCollection::new(vec![])),
+        )
+        .await
+        .expect("consume");
+
+    let received = receive_signatures(&mut receiver, signatures.len()).await;
+    assert_eq!(
+        received.into_iter().collect::<HashSet<_>>(),
+        signatures.iter().copied().collect::<HashSet<_>>()
+    );
+
+    tokio::time::timeout(Duration::from_secs(10), async {
+        while !cursor_path.exists() {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("cursor saved");
+    cancellation_token.cancel();
+
+    let first_run_requests = signature_requests.lock().unwrap().clone();
+    assert_eq!(first_run_requests[0]["before"], Value::Null);
+    assert_eq!(
+        first_run_requests[1]["before"],
+        json!(signatures[1].to_string())
+    );
+
+    // Second run: the crawler resumes from the cursor and only asks for
+    // signatures newer than the last pass.
+    signature_requests.lock().unwrap().clear();
+    let (sender, mut receiver) = mpsc::channel(100);
+    let cancellation_token = CancellationToken::new();
+    crawler(rpc_url)
+        .with_cursor(&cursor_path)
+        .consume(
+            &sender,
+            cancellation_token.clone(),
+            Arc::new(MetricsCollection::new(vec![])),
+        )
+        .await
+        .expect("consume");
+
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    cancellation_token.cancel();
+    drop(sender);
+
+    assert!(receive_signatures(&mut receiver, 1).await.is_empty());
+    let second_run_requests = signature_requests.lock().unwrap().clone();
+    assert_eq!(
+        second_run_requests[0]["until"],
+        json!(signatures[0].to_string())
+    );
+
+    std::fs::remove_file(&cursor_path).ok();
+}
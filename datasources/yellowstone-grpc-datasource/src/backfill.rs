@@ -0,0 +1,139 @@
+//! Fills the slots a subscription missed when the server could not replay
+//! them.
+
+use {
+    async_trait::async_trait,
+    carbon_core::{
+        datasource::{Commitment, TransactionUpdate, Update},
+        error::{CarbonResult, Error},
+        transformers::transaction_metadata_from_original_meta,
+    },
+    solana_client::{nonblocking::rpc_client::RpcClient, rpc_config::RpcBlockConfig},
+    solana_pubkey::Pubkey,
+    solana_sdk::commitment_config::CommitmentConfig,
+    solana_transaction_status::{TransactionDetails, UiTransactionEncoding},
+    std::ops::Range,
+    tokio::sync::mpsc::Sender,
+};
+
+/// Delivers the updates of slots that a `YellowstoneGrpcDatasource`
+/// subscription skipped over after a reconnect.
+#[async_trait]
+pub trait SlotBackfill: Send + Sync {
+    /// Sends the updates of `slots` that match the datasource filters, in
+    /// slot order. `programs` are the programs whose transactions the
+    /// datasource subscribes to.
+    async fn backfill(
+        &self,
+        slots: Range<u64>,
+        programs: &[Pubkey],
+        commitment: Option<Commitment>,
+        sender: &Sender<Update>,
+    ) -> CarbonResult<()>;
+}
+
+/// A `SlotBackfill` that fetches the missed blocks over RPC and sends the
+/// transactions invoking one of the subscribed programs.
+///
+/// Account updates cannot be fetched by slot, so an account changed during
+/// the gap is only delivered with its next change.
+pub struct RpcBlockBackfill {
+    pub rpc_client: RpcClient,
+}
+
+impl RpcBlockBackfill {
+    pub fn new(rpc_url: impl Into<String>) -> Self {
+        Self {
+            rpc_client: RpcClient::new_with_commitment(
+                rpc_url.into(),
+                CommitmentConfig::confirmed(),
+            ),
+        }
+    }
+}
+
+#[async_trait]
+impl SlotBackfill for RpcBlockBackfill {
+    async fn backfill(
+        &self,
+        slots: Range<u64>,
+        programs: &[Pubkey],
+        commitment: Option<Commitment>,
+        sender: &Sender<Update>,
+    ) -> CarbonResult<()> {
+        let block_config = RpcBlockConfig {
+            encoding: Some(UiTransactionEncoding::Base64),
+            transaction_details: Some(TransactionDetails::Full),
+            rewards: Some(false),
+            commitment: Some(CommitmentConfig::confirmed()),
+            max_supported_transaction_version: Some(0),
+        };
+
+        for slot in slots {
+            let block = match self
+                .rpc_client
+                .get_block_with_config(slot, block_config)
+                .await
+            {
+                Ok(block) => block,
+                // Skipped slots have no block:
+                // -32004, // Block not available for slot x
+                // -32007, // Slot {} was skipped, or missing due to ledger jump to recent snapshot
+                // -32009, // Slot {} was skipped, or missing in long-term storage
+                Err(err)
+                    if ["-32004", "-32007", "-32009"]
+                        .iter()
+                        .any(|code| err.to_string().contains(code)) =>
+                {
+                    continue;
+                }
+                Err(err) => {
+                    return Err(Error::FailedToConsumeDatasource(format!(
+                        "failed to backfill slot {}: {}",
+                        slot, err
+                    )));
+                }
+            };
+
+            for encoded_transaction in block.transactions.unwrap_or_default() {
+                let (Some(transaction), Some(meta)) = (
+                    encoded_transaction.transaction.decode(),
+                    encoded_transaction.meta,
+                ) else {
+                    continue;
+                };
+                let Some(signature) = transaction.signatures.first().copied() else {
+                    continue;
+                };
+                let meta = transaction_metadata_from_original_meta(meta)?;
+
+                let invokes_program = transaction
+                    .message
+                    .static_account_keys()
+                    .iter()
+                    .chain(&meta.loaded_addresses.writable)
+                    .chain(&meta.loaded_addresses.readonly)
+                    .any(|key| programs.contains(key));
+                if !invokes_program {
+                    continue;
+                }
+
+                let update = Update::Transaction(Box::new(TransactionUpdate {
+                    signature,
+                    transaction,
+                    meta,
+                    is_vote: false,
+                    slot,
+                    block_time: block.block_time,
+                    commitment,
+                    source: None,
+                }));
+                if sender.send(update).await.is_err() {
+                    return Ok(());
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -1,10 +1,12 @@
+pub use backfill::{RpcBlockBackfill, SlotBackfill};
 use {
     async_trait::async_trait,
     carbon_core::{
         datasource::{
-            AccountDeletion, AccountUpdate, Datasource, TransactionUpdate, Update, UpdateType,
+            AccountDeletion, AccountUpdate, Commitment, Datasource, TransactionUpdate, Update,
+            UpdateType,
         },
-        error::CarbonResult,
+        error::{CarbonResult, Error},
         metrics::MetricsCollection,
     },
     futures::{sink::SinkExt, StreamExt},
@@ -14,23 +16,25 @@ use {
     std::{
         collections::{HashMap, HashSet},
         convert::TryFrom,
-        sync::Arc,
-        time::Duration,
+        sync::{Arc, Mutex},
+        time::{Duration, Instant},
     },
     tokio::sync::{mpsc::Sender, RwLock},
     tokio_util::sync::CancellationToken,
-    yellowstone_grpc_client::GeyserGrpcClient,
+    yellowstone_grpc_client::{GeyserGrpcClient, GeyserGrpcClientError},
     yellowstone_grpc_proto::{
         convert_from::{create_tx_meta, create_tx_versioned},
         geyser::{
             subscribe_update::UpdateOneof, CommitmentLevel, SubscribeRequest,
             SubscribeRequestFilterAccounts, SubscribeRequestFilterTransactions,
-            SubscribeRequestPing,
+            SubscribeRequestPing, SubscribeUpdateAccount, SubscribeUpdateTransaction,
         },
-        tonic::transport::ClientTlsConfig,
+        tonic::{transport::ClientTlsConfig, Code, Status},
     },
 };
 
+mod backfill;
+
 #[derive(Debug)]
 pub struct YellowstoneGrpcGeyserClient {
     pub endpoint: String,
@@ -73,6 +77,9 @@ impl Datasource for YellowstoneGrpcGeyserClient {
         let endpoint = self.endpoint.clone();
         let x_token = self.x_token.clone();
         let commitment = self.commitment;
+        // Updates are delivered at the subscription's commitment level, which
+        // is unknown when the server default is used.
+        let update_commitment = commitment.map(commitment_of);
         let account_filters = self.account_filters.clone();
         let transaction_filters = self.transaction_filters.clone();
         let account_deletions_tracked = self.account_deletions_tracked.clone();
@@ -154,6 +161,8 @@ impl Datasource for YellowstoneGrpcGeyserClient {
                                                             let account_deletion = AccountDeletion {
                                                                 pubkey: account_pubkey,
                                                                 slot: account_update.slot,
+                                                                commitment: update_commitment,
+                                                                source: None,
                                                             };
                                                             if let Err(e) = sender.try_send(
                                                                 Update::AccountDeletion(account_deletion),
@@ -166,6 +175,9 @@ impl Datasource for YellowstoneGrpcGeyserClient {
                                                             pubkey: account_pubkey,
                                                             account,
                                                             slot: account_update.slot,
+                                                            commitment: update_commitment,
+                                                            is_snapshot: false,
+                                                            source: None,
                                                         });
 
                                                         if let Err(e) = sender.try_send(update) {
@@ -232,6 +244,8 @@ impl Datasource for YellowstoneGrpcGeyserClient {
                                                         is_vote: transaction_info.is_vote,
                                                         slot: transaction_update.slot,
                                                         block_time: None,
+                                                        commitment: update_commitment,
+                                                        source: None,
                                                     }));
                                                     if let Err(e) = sender.try_send(update) {
                                                         log::error!("Failed to send transaction update with signature {:?} at slot {}: {:?}", signature, transaction_update.slot, e);
@@ -291,3 +305,840 @@ impl Datasource for YellowstoneGrpcGeyserClient {
         ]
     }
 }
+
+const MAX_RECONNECTION_ATTEMPTS: u32 = 10;
+const DEFAULT_RECONNECTION_DELAY: Duration = Duration::from_secs(3);
+const DEFAULT_PING_INTERVAL: Duration = Duration::from_secs(10);
+const DEFAULT_PONG_TIMEOUT: Duration = Duration::from_secs(30);
+const DEFAULT_MAX_MESSAGE_SIZE: usize = 64 * 1024 * 1024;
+
+/// The name of the filters in the `SubscribeRequest` sent by
+/// `YellowstoneGrpcDatasource`.
+const FILTER_NAME: &str = "carbon";
+
+/// A Yellowstone gRPC datasource that only pulls the accounts and programs it
+/// is configured with, using server-side filters.
+///
+/// The subscription requests:
+/// - account updates for the configured `accounts`, and for every account owned
+///   by one of the configured `owners`;
+/// - non-vote transactions that invoke one of the configured `programs`.
+///
+/// # Reconnection
+///
+/// The datasource reconnects whenever the stream fails, is closed by the
+/// server, or stays silent for longer than the pong timeout. Each new
+/// subscription asks the server to replay from the slot of the last update
+/// received, by setting `from_slot` in the `SubscribeRequest`, so updates of
+/// that slot may be delivered again. After `MAX_RECONNECTION_ATTEMPTS`
+/// consecutive failures, `consume` returns an error.
+///
+/// The `datasource_connected` gauge is set to 1 once a subscription is
+/// established and to 0 while reconnecting, which `HealthServer` of
+/// `carbon-prometheus-metrics` reports on its readiness endpoint.
+///
+/// When the pipeline resumes from a checkpoint, the first subscription starts
+/// right after the checkpointed slot. Set a `Checkpoint` on the pipeline to
+/// persist the last processed slot across restarts.
+///
+/// # Server-side replay
+///
+/// Replaying from a slot requires Yellowstone gRPC 5.0 or later, with replay
+/// enabled through the `replay_stored_slots` setting of the plugin, and only
+/// covers the slots the server still stores:
+///
+/// - Self-hosted Yellowstone gRPC 5.0+ nodes replay as many slots as
+///   `replay_stored_slots` is set to. It is disabled by default.
+/// - Triton One's Dragon's Mouth and Helius' LaserStream replay a window of
+///   recent slots whose size depends on the plan.
+/// - Providers running an older Yellowstone version, or with replay disabled,
+///   do not support it.
+///
+/// A server that cannot replay a slot rejects the subscription with
+/// `InvalidArgument`, after which the datasource subscribes from the tip
+/// instead. Some servers ignore `from_slot` and start from the tip right
+/// away. Either way, slots between the last one received and the first one
+/// streamed are missed unless a `SlotBackfill` is set with `with_backfill`;
+/// `RpcBlockBackfill` fetches their transactions over RPC before the stream
+/// is resumed.
+///
+/// # Keepalive
+///
+/// A ping is sent every ping interval so that idle connections are not
+/// dropped by load balancers. Pings from the server are answered as well.
+pub struct YellowstoneGrpcDatasource {
+    pub endpoint: String,
+    pub x_token: Option<String>,
+    pub commitment: Option<CommitmentLevel>,
+    pub programs: Vec<Pubkey>,
+    pub owners: Vec<Pubkey>,
+    pub accounts: Vec<Pubkey>,
+    pub ping_interval: Duration,
+    pub pong_timeout: Duration,
+    pub max_message_size: usize,
+    pub reconnection_delay: Duration,
+    pub backfill: Option<Arc<dyn SlotBackfill>>,
+    resume_from_slot: Mutex<Option<u64>>,
+}
+
+impl YellowstoneGrpcDatasource {
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            x_token: None,
+            commitment: None,
+            programs: Vec::new(),
+            owners: Vec::new(),
+            accounts: Vec::new(),
+            ping_interval: DEFAULT_PING_INTERVAL,
+            pong_timeout: DEFAULT_PONG_TIMEOUT,
+            max_message_size: DEFAULT_MAX_MESSAGE_SIZE,
+            reconnection_delay: DEFAULT_RECONNECTION_DELAY,
+            backfill: None,
+            resume_from_slot: Mutex::new(None),
+        }
+    }
+
+    pub fn with_x_token(mut self, x_token: impl Into<String>) -> Self {
+        self.x_token = Some(x_token.into());
+        self
+    }
+
+    pub fn with_commitment(mut self, commitment: CommitmentLevel) -> Self {
+        self.commitment = Some(commitment);
+        self
+    }
+
+    /// Subscribes to the transactions invoking any of `programs`.
+    pub fn with_programs(mut self, programs: impl IntoIterator<Item = Pubkey>) -> Self {
+        self.programs.extend(programs);
+        self
+    }
+
+    /// Subscribes to the accounts owned by any of `owners`.
+    pub fn with_owners(mut self, owners: impl IntoIterator<Item = Pubkey>) -> Self {
+        self.owners.extend(owners);
+        self
+    }
+
+    /// Subscribes to the updates of `accounts`.
+    pub fn with_accounts(mut self, accounts: impl IntoIterator<Item = Pubkey>) -> Self {
+        self.accounts.extend(accounts);
+        self
+    }
+
+    /// Sends a ping every `ping_interval`, and reconnects when nothing was
+    /// received from the server for `pong_timeout`. Defaults to 10 and 30
+    /// seconds.
+    pub fn with_keepalive(mut self, ping_interval: Duration, pong_timeout: Duration) -> Self {
+        self.ping_interval = ping_interval;
+        self.pong_timeout = pong_timeout;
+        self
+    }
+
+    /// Sets the largest message accepted from the server, in bytes. Defaults
+    /// to 64 MiB.
+    pub fn with_max_message_size(mut self, max_message_size: usize) -> Self {
+        self.max_message_size = max_message_size;
+        self
+    }
+
+    /// Sets how long to wait before reconnecting. Defaults to 3 seconds.
+    pub fn with_reconnection_delay(mut self, reconnection_delay: Duration) -> Self {
+        self.reconnection_delay = reconnection_delay;
+        self
+    }
+
+    /// Fills the slots missed after a reconnect with `backfill` when the
+    /// server does not replay them. See the type documentation.
+    pub fn with_backfill(mut self, backfill: impl SlotBackfill + 'static) -> Self {
+        self.backfill = Some(Arc::new(backfill));
+        self
+    }
+
+    /// Builds the subscription for the configured filters, replaying from
+    /// `from_slot` when given.
+    pub fn subscribe_request(&self, from_slot: Option<u64>) -> SubscribeRequest {
+        let mut accounts = HashMap::new();
+        if !self.accounts.is_empty() || !self.owners.is_empty() {
+            accounts.insert(
+                FILTER_NAME.to_string(),
+                SubscribeRequestFilterAccounts {
+                    account: self.accounts.iter().map(ToString::to_string).collect(),
+                    owner: self.owners.iter().map(ToString::to_string).collect(),
+                    ..Default::default()
+                },
+            );
+        }
+
+        let mut transactions = HashMap::new();
+        if !self.programs.is_empty() {
+            transactions.insert(
+                FILTER_NAME.to_string(),
+                SubscribeRequestFilterTransactions {
+                    vote: Some(false),
+                    account_include: self.programs.iter().map(ToString::to_string).collect(),
+                    ..Default::default()
+                },
+            );
+        }
+
+        SubscribeRequest {
+            accounts,
+            transactions,
+            commitment: self.commitment.map(|commitment| commitment as i32),
+            from_slot,
+            ..Default::default()
+        }
+    }
+
+    /// Streams updates until the subscription ends. Returns `Ok(true)` once
+    /// consumption should stop, and `Ok(false)` or an error when the
+    /// datasource should reconnect.
+    async fn subscribe(
+        &self,
+        sender: &Sender<Update>,
+        cancellation_token: &CancellationToken,
+        metrics: &MetricsCollection,
+        cursor: &mut Cursor,
+        received_updates: &mut bool,
+    ) -> CarbonResult<bool> {
+        let mut builder = GeyserGrpcClient::build_from_shared(self.endpoint.clone())
+            .map_err(|err| Error::FailedToConsumeDatasource(err.to_string()))?
+            .x_token(self.x_token.clone())
+            .map_err(|err| Error::FailedToConsumeDatasource(err.to_string()))?
+            .connect_timeout(Duration::from_secs(15))
+            .max_decoding_message_size(self.max_message_size);
+        if self.endpoint.starts_with("https://") {
+            builder = builder
+                .tls_config(ClientTlsConfig::new().with_enabled_roots())
+                .map_err(|err| Error::FailedToConsumeDatasource(err.to_string()))?;
+        }
+        let mut client = builder
+            .connect()
+            .await
+            .map_err(|err| Error::FailedToConsumeDatasource(err.to_string()))?;
+
+        let from_slot = cursor.last_slot.or(*self.resume_from_slot.lock().unwrap());
+        let requested_slot = from_slot.filter(|_| cursor.replay_supported);
+        let (mut subscribe_tx, mut stream) = match client
+            .subscribe_with_request(Some(self.subscribe_request(requested_slot)))
+            .await
+        {
+            Ok(subscription) => subscription,
+            Err(GeyserGrpcClientError::TonicStatus(status))
+                if rejects_replay(&status, requested_slot) =>
+            {
+                log::warn!(
+                    "Yellowstone gRPC server cannot replay from slot {:?}: {}",
+                    requested_slot,
+                    status.message()
+                );
+                cursor.replay_supported = false;
+                return Ok(false);
+            }
+            Err(err) => return Err(Error::FailedToConsumeDatasource(err.to_string())),
+        };
+        metrics.update_gauge("datasource_connected", 1.0).await?;
+        // The first slot whose updates may be missing, until the first update
+        // of the stream shows whether it picked up from there.
+        let mut gap_start = from_slot.filter(|_| self.backfill.is_some());
+
+        let update_commitment = self.commitment.map(commitment_of);
+        let mut ping = tokio::time::interval_at(
+            tokio::time::Instant::now() + self.ping_interval,
+            self.ping_interval,
+        );
+        let mut ping_id = 0;
+        let mut last_message = Instant::now();
+
+        loop {
+            let message = tokio::select! {
+                _ = cancellation_token.cancelled() => {
+                    log::info!("Cancelling Yellowstone gRPC subscription.");
+                    return Ok(true);
+                }
+                _ = ping.tick() => {
+                    if last_message.elapsed() >= self.pong_timeout {
+                        return Err(Error::FailedToConsumeDatasource(format!(
+                            "no message received for {:?}",
+                            last_message.elapsed()
+                        )));
+                    }
+
+                    ping_id += 1;
+                    subscribe_tx
+                        .send(SubscribeRequest {
+                            ping: Some(SubscribeRequestPing { id: ping_id }),
+                            ..Default::default()
+                        })
+                        .await
+                        .map_err(|err| Error::FailedToConsumeDatasource(err.to_string()))?;
+                    continue;
+                }
+                message = stream.next() => message,
+            };
+
+            let Some(message) = message else {
+                log::warn!("Yellowstone gRPC stream closed by the server.");
+                return Ok(false);
+            };
+            let message = match message {
+                Ok(message) => message,
+                Err(status) if !*received_updates && rejects_replay(&status, requested_slot) => {
+                    log::warn!(
+                        "Yellowstone gRPC server cannot replay from slot {:?}: {}",
+                        requested_slot,
+                        status.message()
+                    );
+                    cursor.replay_supported = false;
+                    return Ok(false);
+                }
+                Err(status) => return Err(Error::FailedToConsumeDatasource(status.to_string())),
+            };
+            last_message = Instant::now();
+
+            let update = match message.update_oneof {
+                Some(UpdateOneof::Account(account_update)) => {
+                    metrics
+                        .increment_counter("yellowstone_grpc_account_updates_received", 1)
+                        .await?;
+                    account_update_from(account_update, update_commitment).map(Update::Account)
+                }
+                Some(UpdateOneof::Transaction(transaction_update)) => {
+                    metrics
+                        .increment_counter("yellowstone_grpc_transaction_updates_received", 1)
+                        .await?;
+                    transaction_update_from(transaction_update, update_commitment)
+                        .map(|transaction_update| Update::Transaction(Box::new(transaction_update)))
+                }
+                Some(UpdateOneof::Ping(_)) => {
+                    subscribe_tx
+                        .send(SubscribeRequest {
+                            ping: Some(SubscribeRequestPing { id: 1 }),
+                            ..Default::default()
+                        })
+                        .await
+                        .map_err(|err| Error::FailedToConsumeDatasource(err.to_string()))?;
+                    None
+                }
+                _ => None,
+            };
+
+            if let Some(update) = update {
+                let slot = update.slot();
+                if let (Some(start), Some(backfill)) = (gap_start.take(), &self.backfill) {
+                    if slot > start {
+                        log::info!(
+                            "Backfilling slots {}..{} missed by the Yellowstone gRPC subscription",
+                            start,
+                            slot
+                        );
+                        backfill
+                            .backfill(start..slot, &self.programs, update_commitment, sender)
+                            .await?;
+                        metrics
+                            .increment_counter("yellowstone_grpc_backfilled_slots", slot - start)
+                            .await?;
+                    }
+                }
+
+                cursor.last_slot = Some(slot);
+                *received_updates = true;
+                if sender.send(update).await.is_err() {
+                    log::info!("Update receiver dropped, stopping Yellowstone gRPC subscription.");
+                    return Ok(true);
+                }
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Datasource for YellowstoneGrpcDatasource {
+    async fn consume(
+        &self,
+        sender: &Sender<Update>,
+        cancellation_token: CancellationToken,
+        metrics: Arc<MetricsCollection>,
+    ) -> CarbonResult<()> {
+        let mut cursor = Cursor {
+            last_slot: None,
+            replay_supported: true,
+        };
+        let mut reconnection_attempts = 0;
+
+        loop {
+            let mut received_updates = false;
+            let result = self
+                .subscribe(
+                    sender,
+                    &cancellation_token,
+                    &metrics,
+                    &mut cursor,
+                    &mut received_updates,
+                )
+                .await;
+
+            if received_updates {
+                reconnection_attempts = 0;
+            }
+
+            if !matches!(result, Ok(true)) {
+                metrics.update_gauge("datasource_connected", 0.0).await?;
+            }
+
+            match result {
+                Ok(true) => return Ok(()),
+                Ok(false) => {}
+                Err(err) => {
+                    log::error!("Yellowstone gRPC subscription failed: {}", err);
+                    reconnection_attempts += 1;
+                    if reconnection_attempts >= MAX_RECONNECTION_ATTEMPTS {
+                        return Err(Error::FailedToConsumeDatasource(format!(
+                            "Yellowstone gRPC subscription failed {} times in a row: {}",
+                            MAX_RECONNECTION_ATTEMPTS, err
+                        )));
+                    }
+                }
+            }
+
+            metrics
+                .increment_counter("yellowstone_grpc_reconnections", 1)
+                .await?;
+
+            tokio::select! {
+                _ = cancellation_token.cancelled() => return Ok(()),
+                _ = tokio::time::sleep(self.reconnection_delay) => {}
+            }
+        }
+    }
+
+    fn update_types(&self) -> Vec<UpdateType> {
+        vec![UpdateType::AccountUpdate, UpdateType::Transaction]
+    }
+
+    fn resume_from_slot(&self, slot: u64) {
+        *self.resume_from_slot.lock().unwrap() = Some(slot + 1);
+    }
+}
+
+/// Where a `YellowstoneGrpcDatasource` resumes streaming after a reconnect.
+struct Cursor {
+    /// The slot of the last update received.
+    last_slot: Option<u64>,
+    /// Whether the server accepts `from_slot`. Cleared once it rejected it.
+    replay_supported: bool,
+}
+
+/// Returns whether `status` rejects the replay from `requested_slot`.
+fn rejects_replay(status: &Status, requested_slot: Option<u64>) -> bool {
+    requested_slot.is_some() && status.code() == Code::InvalidArgument
+}
+
+const fn commitment_of(commitment: CommitmentLevel) -> Commitment {
+    match commitment {
+        CommitmentLevel::Processed => Commitment::Processed,
+        CommitmentLevel::Confirmed => Commitment::Confirmed,
+        CommitmentLevel::Finalized => Commitment::Finalized,
+    }
+}
+
+/// Converts an account update from the stream, or returns `None` if it is
+/// malformed.
+fn account_update_from(
+    account_update: SubscribeUpdateAccount,
+    commitment: Option<Commitment>,
+) -> Option<AccountUpdate> {
+    let Some(account_info) = account_update.account else {
+        log::error!(
+            "No account info in UpdateOneof::Account at slot {}",
+            account_update.slot
+        );
+        return None;
+    };
+    let pubkey = Pubkey::try_from(account_info.pubkey).ok()?;
+    let owner = Pubkey::try_from(account_info.owner).ok()?;
+
+    Some(AccountUpdate {
+        pubkey,
+        account: Account {
+            lamports: account_info.lamports,
+            data: account_info.data,
+            owner,
+            executable: account_info.executable,
+            rent_epoch: account_info.rent_epoch,
+        },
+        slot: account_update.slot,
+        commitment,
+        is_snapshot: false,
+        source: None,
+    })
+}
+
+/// Converts a transaction update from the stream, or returns `None` if it is
+/// malformed.
+fn transaction_update_from(
+    transaction_update: SubscribeUpdateTransaction,
+    commitment: Option<Commitment>,
+) -> Option<TransactionUpdate> {
+    let Some(transaction_info) = transaction_update.transaction else {
+        log::error!(
+            "No transaction info in `UpdateOneof::Transaction` at slot {}",
+            transaction_update.slot
+        );
+        return None;
+    };
+    let signature = Signature::try_from(transaction_info.signature).ok()?;
+    let transaction = create_tx_versioned(transaction_info.transaction?).ok()?;
+    let meta = match create_tx_meta(transaction_info.meta?) {
+        Ok(meta) => meta,
+        Err(err) => {
+            log::error!("Failed to create transaction meta: {:?}", err);
+            return None;
+        }
+    };
+
+    Some(TransactionUpdate {
+        signature,
+        transaction,
+        meta,
+        is_vote: transaction_info.is_vote,
+        slot: transaction_update.slot,
+        block_time: None,
+        commitment,
+        source: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        futures::stream::{self, BoxStream},
+        std::{collections::VecDeque, ops::Range},
+        tokio::{net::TcpListener, sync::mpsc},
+        yellowstone_grpc_proto::{
+            geyser::{
+                geyser_server::{Geyser, GeyserServer},
+                GetBlockHeightRequest, GetBlockHeightResponse, GetLatestBlockhashRequest,
+                GetLatestBlockhashResponse, GetSlotRequest, GetSlotResponse, GetVersionRequest,
+                GetVersionResponse, IsBlockhashValidRequest, IsBlockhashValidResponse, PingRequest,
+                PongResponse, SubscribeReplayInfoRequest, SubscribeReplayInfoResponse,
+                SubscribeUpdate, SubscribeUpdateAccountInfo,
+            },
+            tonic::{self, transport::Server, Request, Response, Status, Streaming},
+        },
+    };
+
+    /// A Geyser server that records the subscriptions it receives. Each
+    /// subscription is sent the next entry of `streams` and then closed; once
+    /// they are used up, subscriptions stay open without updates. Without
+    /// `replay`, subscriptions with `from_slot` are rejected as by servers
+    /// that do not store past slots.
+    struct MockGeyser {
+        streams: Mutex<VecDeque<Vec<SubscribeUpdate>>>,
+        replay: bool,
+        requests: mpsc::UnboundedSender<SubscribeRequest>,
+    }
+
+    impl MockGeyser {
+        fn new(
+            streams: Vec<Vec<SubscribeUpdate>>,
+            replay: bool,
+            requests: mpsc::UnboundedSender<SubscribeRequest>,
+        ) -> Self {
+            Self {
+                streams: Mutex::new(streams.into()),
+                replay,
+                requests,
+            }
+        }
+    }
+
+    #[tonic::async_trait]
+    impl Geyser for MockGeyser {
+        type SubscribeStream = BoxStream<'static, Result<SubscribeUpdate, Status>>;
+
+        async fn subscribe(
+            &self,
+            request: Request<Streaming<SubscribeRequest>>,
+        ) -> Result<Response<Self::SubscribeStream>, Status> {
+            let subscribe_request = request
+                .into_inner()
+                .message()
+                .await?
+                .ok_or_else(|| Status::invalid_argument("missing subscribe request"))?;
+            let from_slot = subscribe_request.from_slot;
+            self.requests.send(subscribe_request).unwrap();
+
+            if let (Some(from_slot), false) = (from_slot, self.replay) {
+                let rejection = Status::invalid_argument(format!(
+                    "broadcast from {from_slot} is not available"
+                ));
+                return Ok(Response::new(stream::iter([Err(rejection)]).boxed()));
+            }
+
+            match self.streams.lock().unwrap().pop_front() {
+                Some(updates) => Ok(Response::new(
+                    stream::iter(updates.into_iter().map(Ok)).boxed(),
+                )),
+                None => Ok(Response::new(stream::pending().boxed())),
+            }
+        }
+
+        async fn subscribe_replay_info(
+            &self,
+            _request: Request<SubscribeReplayInfoRequest>,
+        ) -> Result<Response<SubscribeReplayInfoResponse>, Status> {
+            Err(Status::unimplemented("subscribe_replay_info"))
+        }
+
+        async fn ping(
+            &self,
+            _request: Request<PingRequest>,
+        ) -> Result<Response<PongResponse>, Status> {
+            Err(Status::unimplemented("ping"))
+        }
+
+        async fn get_latest_blockhash(
+            &self,
+            _request: Request<GetLatestBlockhashRequest>,
+        ) -> Result<Response<GetLatestBlockhashResponse>, Status> {
+            Err(Status::unimplemented("get_latest_blockhash"))
+        }
+
+        async fn get_block_height(
+            &self,
+            _request: Request<GetBlockHeightRequest>,
+        ) -> Result<Response<GetBlockHeightResponse>, Status> {
+            Err(Status::unimplemented("get_block_height"))
+        }
+
+        async fn get_slot(
+            &self,
+            _request: Request<GetSlotRequest>,
+        ) -> Result<Response<GetSlotResponse>, Status> {
+            Err(Status::unimplemented("get_slot"))
+        }
+
+        async fn is_blockhash_valid(
+            &self,
+            _request: Request<IsBlockhashValidRequest>,
+        ) -> Result<Response<IsBlockhashValidResponse>, Status> {
+            Err(Status::unimplemented("is_blockhash_valid"))
+        }
+
+        async fn get_version(
+            &self,
+            _request: Request<GetVersionRequest>,
+        ) -> Result<Response<GetVersionResponse>, Status> {
+            Err(Status::unimplemented("get_version"))
+        }
+    }
+
+    /// Starts `geyser` on a local port and returns its endpoint.
+    async fn serve(geyser: MockGeyser) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+        let incoming = stream::unfold(listener, |listener| async move {
+            let connection = listener.accept().await.map(|(stream, _)| stream);
+            Some((connection, listener))
+        });
+
+        tokio::spawn(
+            Server::builder()
+                .add_service(GeyserServer::new(geyser))
+                .serve_with_incoming(incoming),
+        );
+
+        endpoint
+    }
+
+    fn account_update(pubkey: Pubkey, owner: Pubkey, slot: u64) -> SubscribeUpdate {
+        SubscribeUpdate {
+            filters: vec![FILTER_NAME.to_string()],
+            update_oneof: Some(UpdateOneof::Account(SubscribeUpdateAccount {
+                account: Some(SubscribeUpdateAccountInfo {
+                    pubkey: pubkey.to_bytes().to_vec(),
+                    lamports: 1_000,
+                    owner: owner.to_bytes().to_vec(),
+                    data: vec![1, 2, 3],
+                    ..Default::default()
+                }),
+                slot,
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_streams_updates_and_resumes_after_reconnect() {
+        let program = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let tracked_account = Pubkey::new_unique();
+        let owned_account = Pubkey::new_unique();
+        let (request_sender, mut request_receiver) = mpsc::unbounded_channel();
+        let endpoint = serve(MockGeyser::new(
+            vec![vec![
+                account_update(tracked_account, Pubkey::new_unique(), 10),
+                account_update(owned_account, owner, 11),
+            ]],
+            true,
+            request_sender,
+        ))
+        .await;
+
+        let datasource = YellowstoneGrpcDatasource::new(endpoint)
+            .with_commitment(CommitmentLevel::Confirmed)
+            .with_programs([program])
+            .with_owners([owner])
+            .with_accounts([tracked_account])
+            .with_reconnection_delay(Duration::from_millis(10));
+        let (sender, mut receiver) = mpsc::channel(10);
+        let cancellation_token = CancellationToken::new();
+        let consume = {
+            let cancellation_token = cancellation_token.clone();
+            async move {
+                datasource
+                    .consume(
+                        &sender,
+                        cancellation_token,
+                        Arc::new(MetricsCollection::new(vec![])),
+                    )
+                    .await
+            }
+        };
+        let consume = tokio::spawn(consume);
+
+        let mut received = Vec::new();
+        for _ in 0..2 {
+            match receiver.recv().await {
+                Some(Update::Account(account_update)) => received.push(account_update),
+                update => panic!("unexpected update: {:?}", update),
+            }
+        }
+        let first_request = request_receiver.recv().await.unwrap();
+        let second_request = request_receiver.recv().await.unwrap();
+        cancellation_token.cancel();
+
+        assert!(consume.await.unwrap().is_ok());
+
+        assert_eq!(received[0].pubkey, tracked_account);
+        assert_eq!(received[0].slot, 10);
+        assert_eq!(received[0].account.data, vec![1, 2, 3]);
+        assert_eq!(received[0].commitment, Some(Commitment::Confirmed));
+        assert_eq!(received[1].pubkey, owned_account);
+        assert_eq!(received[1].account.owner, owner);
+        assert_eq!(received[1].slot, 11);
+
+        let accounts_filter = &first_request.accounts[FILTER_NAME];
+        assert_eq!(accounts_filter.account, vec![tracked_account.to_string()]);
+        assert_eq!(accounts_filter.owner, vec![owner.to_string()]);
+        let transactions_filter = &first_request.transactions[FILTER_NAME];
+        assert_eq!(
+            transactions_filter.account_include,
+            vec![program.to_string()]
+        );
+        assert_eq!(transactions_filter.vote, Some(false));
+        assert_eq!(
+            first_request.commitment,
+            Some(CommitmentLevel::Confirmed as i32)
+        );
+        assert_eq!(first_request.from_slot, None);
+
+        // The server closed the first stream, so the datasource reconnected
+        // and asked to replay from the last slot it received.
+        assert_eq!(second_request.from_slot, Some(11));
+    }
+
+    /// A `SlotBackfill` that sends an account update per slot, recording the
+    /// ranges it was asked for.
+    struct MockBackfill {
+        ranges: Arc<Mutex<Vec<Range<u64>>>>,
+    }
+
+    #[async_trait]
+    impl SlotBackfill for MockBackfill {
+        async fn backfill(
+            &self,
+            slots: Range<u64>,
+            _programs: &[Pubkey],
+            _commitment: Option<Commitment>,
+            sender: &Sender<Update>,
+        ) -> CarbonResult<()> {
+            self.ranges.lock().unwrap().push(slots.clone());
+            for slot in slots {
+                let update = Update::Account(AccountUpdate {
+                    pubkey: Pubkey::new_unique(),
+                    account: Account::default(),
+                    slot,
+                    commitment: None,
+                    is_snapshot: false,
+                    source: None,
+                });
+                sender.send(update).await.unwrap();
+            }
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_backfills_slots_missed_while_disconnected() {
+        // Arrange
+        let owner = Pubkey::new_unique();
+        let (request_sender, mut request_receiver) = mpsc::unbounded_channel();
+        let endpoint = serve(MockGeyser::new(
+            vec![
+                vec![
+                    account_update(Pubkey::new_unique(), owner, 10),
+                    account_update(Pubkey::new_unique(), owner, 11),
+                ],
+                vec![account_update(Pubkey::new_unique(), owner, 15)],
+            ],
+            false,
+            request_sender,
+        ))
+        .await;
+        let ranges = Arc::new(Mutex::new(Vec::new()));
+        let datasource = YellowstoneGrpcDatasource::new(endpoint)
+            .with_owners([owner])
+            .with_reconnection_delay(Duration::from_millis(10))
+            .with_backfill(MockBackfill {
+                ranges: ranges.clone(),
+            });
+        let (sender, mut receiver) = mpsc::channel(10);
+        let cancellation_token = CancellationToken::new();
+        let consume = tokio::spawn({
+            let cancellation_token = cancellation_token.clone();
+            async move {
+                datasource
+                    .consume(
+                        &sender,
+                        cancellation_token,
+                        Arc::new(MetricsCollection::new(vec![])),
+                    )
+                    .await
+            }
+        });
+
+        // Act
+        let mut slots = Vec::new();
+        while slots.last() != Some(&15) {
+            slots.push(receiver.recv().await.unwrap().slot());
+        }
+        let requests: Vec<_> = (0..3)
+            .map(|_| request_receiver.try_recv().unwrap().from_slot)
+            .collect();
+        cancellation_token.cancel();
+
+        // Assert
+        assert!(consume.await.unwrap().is_ok());
+        // The server rejected the replay from slot 11, so the datasource
+        // subscribed from the tip and backfilled the slots before 15.
+        assert_eq!(requests, vec![None, Some(11), None]);
+        assert_eq!(*ranges.lock().unwrap(), vec![11..15]);
+        assert_eq!(slots, vec![10, 11, 11, 12, 13, 14, 15]);
+    }
+}
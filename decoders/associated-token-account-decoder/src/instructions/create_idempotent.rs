@@ -1,12 +1,14 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x01")]
 pub struct CreateIdempotent {}
 
-#[derive(Debug, PartialEq, Eq, Clone, Hash, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
 pub struct CreateIdempotentInstructionAccounts {
     pub funding_address: solana_pubkey::Pubkey,
     pub associated_account_address: solana_pubkey::Pubkey,
@@ -14,6 +16,7 @@ pub struct CreateIdempotentInstructionAccounts {
     pub token_mint_address: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CreateIdempotent {
@@ -22,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateIdempotent {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [funding_address, associated_account_address, wallet_address, token_mint_address, system_program, token_program, _remaining @ ..] =
+        let [funding_address, associated_account_address, wallet_address, token_mint_address, system_program, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -35,6 +38,88 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateIdempotent {
             token_mint_address: token_mint_address.pubkey,
             system_program: system_program.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CreateIdempotentInstructionAccountMetas {
+    pub funding_address: solana_instruction::AccountMeta,
+    pub associated_account_address: solana_instruction::AccountMeta,
+    pub wallet_address: solana_instruction::AccountMeta,
+    pub token_mint_address: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CreateIdempotent {
+    type ArrangedAccountMetas = CreateIdempotentInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [funding_address, associated_account_address, wallet_address, token_mint_address, system_program, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CreateIdempotentInstructionAccountMetas {
+            funding_address: funding_address.clone(),
+            associated_account_address: associated_account_address.clone(),
+            wallet_address: wallet_address.clone(),
+            token_mint_address: token_mint_address.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CreateIdempotentInstructionAccountIndices {
+    pub funding_address: usize,
+    pub associated_account_address: usize,
+    pub wallet_address: usize,
+    pub token_mint_address: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CreateIdempotent {
+    type ArrangedAccountIndices = CreateIdempotentInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(CreateIdempotentInstructionAccountIndices {
+            funding_address: 0,
+            associated_account_address: 1,
+            wallet_address: 2,
+            token_mint_address: 3,
+            system_program: 4,
+            token_program: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CreateIdempotent {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "funding_address",
+            "associated_account_address",
+            "wallet_address",
+            "token_mint_address",
+            "system_program",
+            "token_program",
+        ]
+    }
+}
@@ -24,18 +24,230 @@ pub enum SplAssociatedTokenAccountInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for SplAssociatedTokenAccountDecoder {
     type InstructionType = SplAssociatedTokenAccountInstruction;
 
-    fn decode_instruction(
+    fn decoder_name(&self) -> &'static str {
+        "spl_associated_token_account"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[super::PROGRAM_ID]
+    }
+
+    fn try_decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
-    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+    ) -> carbon_core::error::CarbonResult<
+        Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>>,
+    > {
         if !instruction.program_id.eq(&PROGRAM_ID) {
-            return None;
+            return Ok(None);
         }
 
-        carbon_core::try_decode_instructions!(instruction,
+        // `Create` predates the instruction discriminators: the program still
+        // accepts it with empty instruction data, as older clients send it.
+        if instruction.data.is_empty() {
+            return Ok(Some(carbon_core::instruction::DecodedInstruction {
+                program_id: instruction.program_id,
+                data: SplAssociatedTokenAccountInstruction::Create(create::Create {}),
+                accounts: instruction.accounts.clone(),
+                discriminator: alloc::vec::Vec::new(),
+                raw_len: 0,
+                num_accounts: instruction.accounts.len(),
+                raw_data: alloc::vec::Vec::new(),
+            }));
+        }
+
+        Ok(carbon_core::try_decode_instructions!(instruction,
             SplAssociatedTokenAccountInstruction::Create => create::Create,
             SplAssociatedTokenAccountInstruction::CreateIdempotent => create_idempotent::CreateIdempotent,
             SplAssociatedTokenAccountInstruction::RecoverNested => recover_nested::RecoverNested,
-        )
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use carbon_core::{
+        datasource::TransactionUpdate,
+        deserialize::ArrangeAccounts,
+        instruction::{InstructionDecoder, NestedInstructions},
+        transaction::TransactionMetadata,
+        transformers::{
+            extract_instructions_with_metadata, transaction_metadata_from_original_meta,
+        },
+    };
+    use carbon_system_program_decoder::{
+        instructions::{create_account::CreateAccount, SystemProgramInstruction},
+        SystemProgramDecoder,
+    };
+    use carbon_token_program_decoder::{
+        instructions::{
+            get_account_data_size::GetAccountDataSize, initialize_account3::InitializeAccount3,
+            initialize_immutable_owner::InitializeImmutableOwner, TokenProgramInstruction,
+        },
+        TokenProgramDecoder,
+    };
+    use solana_instruction::{AccountMeta, Instruction};
+    use solana_pubkey::Pubkey;
+    use solana_sdk::{
+        hash::Hash,
+        instruction::CompiledInstruction,
+        message::{legacy::Message, MessageHeader, VersionedMessage},
+        transaction::VersionedTransaction,
+    };
+    use solana_signature::Signature;
+
+    use super::*;
+
+    const SYSTEM_PROGRAM_ID: Pubkey = Pubkey::from_str_const("11111111111111111111111111111111");
+    const TOKEN_PROGRAM_ID: Pubkey =
+        Pubkey::from_str_const("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+
+    #[test]
+    fn test_decode_create_idempotent_with_nested_instructions() {
+        // Arrange
+        let wallet = Pubkey::from_str_const("7Z2QzVa3q7r7m84nuez9eRn2u3oCUeg9D1bzdRvNFdxN");
+        let associated_account =
+            Pubkey::from_str_const("EP9izgVge5AmuVbXsDUiTokWptNxbukhhnGVS3CKHQoC");
+        let mint = Pubkey::from_str_const("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v");
+        let tx_meta =
+            carbon_test_utils::read_transaction_meta("tests/fixtures/create_idempotent_tx.json")
+                .expect("read fixture");
+        let transaction_update = TransactionUpdate {
+            signature: Signature::default(),
+            transaction: VersionedTransaction {
+                signatures: vec![Signature::default()],
+                message: VersionedMessage::Legacy(Message {
+                    header: MessageHeader {
+                        num_required_signatures: 1,
+                        num_readonly_signed_accounts: 0,
+                        num_readonly_unsigned_accounts: 4,
+                    },
+                    account_keys: vec![
+                        wallet,
+                        associated_account,
+                        mint,
+                        SYSTEM_PROGRAM_ID,
+                        TOKEN_PROGRAM_ID,
+                        PROGRAM_ID,
+                    ],
+                    recent_blockhash: Hash::default(),
+                    instructions: vec![CompiledInstruction {
+                        program_id_index: 5,
+                        accounts: vec![0, 1, 0, 2, 3, 4],
+                        data: vec![1],
+                    }],
+                }),
+            },
+            meta: transaction_metadata_from_original_meta(tx_meta)
+                .expect("transaction metadata from original meta"),
+            is_vote: false,
+            slot: 123,
+            block_time: Some(123),
+            commitment: None,
+            source: None,
+        };
+        let transaction_metadata: TransactionMetadata = transaction_update
+            .clone()
+            .try_into()
+            .expect("transaction metadata");
+
+        // Act
+        let nested_instructions: NestedInstructions =
+            extract_instructions_with_metadata(&transaction_metadata, &transaction_update)
+                .expect("extract instructions with metadata")
+                .into();
+        let create = &nested_instructions[0];
+        let decoded = SplAssociatedTokenAccountDecoder
+            .decode_instruction(&create.instruction)
+            .expect("decode instruction");
+        let arranged_accounts =
+            create_idempotent::CreateIdempotent::arrange_accounts(&decoded.accounts)
+                .expect("arrange accounts");
+        let system_instructions: vec::Vec<_> = create
+            .inner_instructions
+            .iter()
+            .filter_map(|inner| SystemProgramDecoder.decode_instruction(&inner.instruction))
+            .map(|decoded| decoded.data)
+            .collect();
+        let token_instructions: vec::Vec<_> = create
+            .inner_instructions
+            .iter()
+            .filter_map(|inner| TokenProgramDecoder.decode_instruction(&inner.instruction))
+            .map(|decoded| decoded.data)
+            .collect();
+        let initialize_account = &create.inner_instructions[3].instruction;
+        let initialize_accounts =
+            InitializeAccount3::arrange_accounts(&initialize_account.accounts)
+                .expect("arrange initialize account accounts");
+
+        // Assert
+        assert_eq!(nested_instructions.len(), 1);
+        assert_eq!(
+            decoded.data,
+            SplAssociatedTokenAccountInstruction::CreateIdempotent(
+                create_idempotent::CreateIdempotent {}
+            )
+        );
+        assert_eq!(arranged_accounts.funding_address, wallet);
+        assert_eq!(
+            arranged_accounts.associated_account_address,
+            associated_account
+        );
+        assert_eq!(arranged_accounts.wallet_address, wallet);
+        assert_eq!(arranged_accounts.token_mint_address, mint);
+        assert_eq!(create.inner_instructions.len(), 4);
+        assert!(create.inner_instructions.iter().all(|inner| {
+            inner.metadata.stack_height == 2 && inner.inner_instructions.is_empty()
+        }));
+        assert_eq!(
+            system_instructions,
+            vec![SystemProgramInstruction::CreateAccount(CreateAccount {
+                lamports: 2039280,
+                space: 165,
+                program_address: TOKEN_PROGRAM_ID,
+            })]
+        );
+        assert_eq!(
+            token_instructions,
+            vec![
+                TokenProgramInstruction::GetAccountDataSize(GetAccountDataSize {}),
+                TokenProgramInstruction::InitializeImmutableOwner(InitializeImmutableOwner {}),
+                TokenProgramInstruction::InitializeAccount3(InitializeAccount3 { owner: wallet }),
+            ]
+        );
+        assert_eq!(initialize_accounts.account, associated_account);
+        assert_eq!(initialize_accounts.mint, mint);
+    }
+
+    #[test]
+    fn test_decode_create_without_instruction_data() {
+        // Arrange
+        let accounts = vec![
+            AccountMeta::new(Pubkey::new_unique(), true),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ];
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: accounts.clone(),
+            data: vec![],
+        };
+
+        // Act
+        let decoded = SplAssociatedTokenAccountDecoder
+            .decode_instruction(&instruction)
+            .expect("decode instruction");
+
+        // Assert
+        assert_eq!(
+            decoded.data,
+            SplAssociatedTokenAccountInstruction::Create(create::Create {})
+        );
+        assert_eq!(decoded.accounts, accounts);
+        assert!(decoded.discriminator.is_empty());
     }
 }
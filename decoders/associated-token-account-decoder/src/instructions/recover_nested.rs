@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +16,7 @@ pub struct RecoverNestedInstructionAccounts {
     pub owner_token_mint_address: solana_pubkey::Pubkey,
     pub wallet_address: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for RecoverNested {
@@ -22,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for RecoverNested {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [nested_associated_account_address, nested_token_mint_address, destination_associated_account_address, owner_associated_account_address, owner_token_mint_address, wallet_address, token_program, _remaining @ ..] =
+        let [nested_associated_account_address, nested_token_mint_address, destination_associated_account_address, owner_associated_account_address, owner_token_mint_address, wallet_address, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -36,6 +39,91 @@ impl carbon_core::deserialize::ArrangeAccounts for RecoverNested {
             owner_token_mint_address: owner_token_mint_address.pubkey,
             wallet_address: wallet_address.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct RecoverNestedInstructionAccountMetas {
+    pub nested_associated_account_address: solana_instruction::AccountMeta,
+    pub nested_token_mint_address: solana_instruction::AccountMeta,
+    pub destination_associated_account_address: solana_instruction::AccountMeta,
+    pub owner_associated_account_address: solana_instruction::AccountMeta,
+    pub owner_token_mint_address: solana_instruction::AccountMeta,
+    pub wallet_address: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for RecoverNested {
+    type ArrangedAccountMetas = RecoverNestedInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [nested_associated_account_address, nested_token_mint_address, destination_associated_account_address, owner_associated_account_address, owner_token_mint_address, wallet_address, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(RecoverNestedInstructionAccountMetas {
+            nested_associated_account_address: nested_associated_account_address.clone(),
+            nested_token_mint_address: nested_token_mint_address.clone(),
+            destination_associated_account_address: destination_associated_account_address.clone(),
+            owner_associated_account_address: owner_associated_account_address.clone(),
+            owner_token_mint_address: owner_token_mint_address.clone(),
+            wallet_address: wallet_address.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct RecoverNestedInstructionAccountIndices {
+    pub nested_associated_account_address: usize,
+    pub nested_token_mint_address: usize,
+    pub destination_associated_account_address: usize,
+    pub owner_associated_account_address: usize,
+    pub owner_token_mint_address: usize,
+    pub wallet_address: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for RecoverNested {
+    type ArrangedAccountIndices = RecoverNestedInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 7 {
+            return None;
+        }
+
+        Some(RecoverNestedInstructionAccountIndices {
+            nested_associated_account_address: 0,
+            nested_token_mint_address: 1,
+            destination_associated_account_address: 2,
+            owner_associated_account_address: 3,
+            owner_token_mint_address: 4,
+            wallet_address: 5,
+            token_program: 6,
+            remaining_accounts: 7..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for RecoverNested {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "nested_associated_account_address",
+            "nested_token_mint_address",
+            "destination_associated_account_address",
+            "owner_associated_account_address",
+            "owner_token_mint_address",
+            "wallet_address",
+            "token_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -20,6 +21,7 @@ pub struct AddInsuranceFundStakeInstructionAccounts {
     pub drift_signer: solana_pubkey::Pubkey,
     pub user_token_account: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for AddInsuranceFundStake {
@@ -28,7 +30,7 @@ impl carbon_core::deserialize::ArrangeAccounts for AddInsuranceFundStake {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, spot_market, insurance_fund_stake, user_stats, authority, spot_market_vault, insurance_fund_vault, drift_signer, user_token_account, token_program, _remaining @ ..] =
+        let [state, spot_market, insurance_fund_stake, user_stats, authority, spot_market_vault, insurance_fund_vault, drift_signer, user_token_account, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -45,6 +47,106 @@ impl carbon_core::deserialize::ArrangeAccounts for AddInsuranceFundStake {
             drift_signer: drift_signer.pubkey,
             user_token_account: user_token_account.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct AddInsuranceFundStakeInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub spot_market: solana_instruction::AccountMeta,
+    pub insurance_fund_stake: solana_instruction::AccountMeta,
+    pub user_stats: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub spot_market_vault: solana_instruction::AccountMeta,
+    pub insurance_fund_vault: solana_instruction::AccountMeta,
+    pub drift_signer: solana_instruction::AccountMeta,
+    pub user_token_account: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for AddInsuranceFundStake {
+    type ArrangedAccountMetas = AddInsuranceFundStakeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, spot_market, insurance_fund_stake, user_stats, authority, spot_market_vault, insurance_fund_vault, drift_signer, user_token_account, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(AddInsuranceFundStakeInstructionAccountMetas {
+            state: state.clone(),
+            spot_market: spot_market.clone(),
+            insurance_fund_stake: insurance_fund_stake.clone(),
+            user_stats: user_stats.clone(),
+            authority: authority.clone(),
+            spot_market_vault: spot_market_vault.clone(),
+            insurance_fund_vault: insurance_fund_vault.clone(),
+            drift_signer: drift_signer.clone(),
+            user_token_account: user_token_account.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct AddInsuranceFundStakeInstructionAccountIndices {
+    pub state: usize,
+    pub spot_market: usize,
+    pub insurance_fund_stake: usize,
+    pub user_stats: usize,
+    pub authority: usize,
+    pub spot_market_vault: usize,
+    pub insurance_fund_vault: usize,
+    pub drift_signer: usize,
+    pub user_token_account: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for AddInsuranceFundStake {
+    type ArrangedAccountIndices = AddInsuranceFundStakeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 10 {
+            return None;
+        }
+
+        Some(AddInsuranceFundStakeInstructionAccountIndices {
+            state: 0,
+            spot_market: 1,
+            insurance_fund_stake: 2,
+            user_stats: 3,
+            authority: 4,
+            spot_market_vault: 5,
+            insurance_fund_vault: 6,
+            drift_signer: 7,
+            user_token_account: 8,
+            token_program: 9,
+            remaining_accounts: 10..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for AddInsuranceFundStake {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "spot_market",
+            "insurance_fund_stake",
+            "user_stats",
+            "authority",
+            "spot_market_vault",
+            "insurance_fund_vault",
+            "drift_signer",
+            "user_token_account",
+            "token_program",
+        ]
+    }
+}
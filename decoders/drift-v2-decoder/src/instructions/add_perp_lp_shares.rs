@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +14,7 @@ pub struct AddPerpLpSharesInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub user: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for AddPerpLpShares {
@@ -21,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for AddPerpLpShares {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, user, authority, _remaining @ ..] = accounts else {
+        let [state, user, authority, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -29,6 +31,65 @@ impl carbon_core::deserialize::ArrangeAccounts for AddPerpLpShares {
             state: state.pubkey,
             user: user.pubkey,
             authority: authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct AddPerpLpSharesInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for AddPerpLpShares {
+    type ArrangedAccountMetas = AddPerpLpSharesInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, user, authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(AddPerpLpSharesInstructionAccountMetas {
+            state: state.clone(),
+            user: user.clone(),
+            authority: authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct AddPerpLpSharesInstructionAccountIndices {
+    pub state: usize,
+    pub user: usize,
+    pub authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for AddPerpLpShares {
+    type ArrangedAccountIndices = AddPerpLpSharesInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(AddPerpLpSharesInstructionAccountIndices {
+            state: 0,
+            user: 1,
+            authority: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for AddPerpLpShares {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "user", "authority"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +13,7 @@ pub struct AdminDisableUpdatePerpBidAskTwapInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub state: solana_pubkey::Pubkey,
     pub user_stats: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for AdminDisableUpdatePerpBidAskTwap {
@@ -20,7 +22,7 @@ impl carbon_core::deserialize::ArrangeAccounts for AdminDisableUpdatePerpBidAskT
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin, state, user_stats, _remaining @ ..] = accounts else {
+        let [admin, state, user_stats, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +30,65 @@ impl carbon_core::deserialize::ArrangeAccounts for AdminDisableUpdatePerpBidAskT
             admin: admin.pubkey,
             state: state.pubkey,
             user_stats: user_stats.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct AdminDisableUpdatePerpBidAskTwapInstructionAccountMetas {
+    pub admin: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub user_stats: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for AdminDisableUpdatePerpBidAskTwap {
+    type ArrangedAccountMetas = AdminDisableUpdatePerpBidAskTwapInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin, state, user_stats, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(AdminDisableUpdatePerpBidAskTwapInstructionAccountMetas {
+            admin: admin.clone(),
+            state: state.clone(),
+            user_stats: user_stats.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct AdminDisableUpdatePerpBidAskTwapInstructionAccountIndices {
+    pub admin: usize,
+    pub state: usize,
+    pub user_stats: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for AdminDisableUpdatePerpBidAskTwap {
+    type ArrangedAccountIndices = AdminDisableUpdatePerpBidAskTwapInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(AdminDisableUpdatePerpBidAskTwapInstructionAccountIndices {
+            admin: 0,
+            state: 1,
+            user_stats: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for AdminDisableUpdatePerpBidAskTwap {
+    fn account_names() -> &'static [&'static str] {
+        &["admin", "state", "user_stats"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -22,6 +23,7 @@ pub struct BeginSwapInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub drift_signer: solana_pubkey::Pubkey,
     pub instructions: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for BeginSwap {
@@ -30,7 +32,7 @@ impl carbon_core::deserialize::ArrangeAccounts for BeginSwap {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, user, user_stats, authority, out_spot_market_vault, in_spot_market_vault, out_token_account, in_token_account, token_program, drift_signer, instructions, _remaining @ ..] =
+        let [state, user, user_stats, authority, out_spot_market_vault, in_spot_market_vault, out_token_account, in_token_account, token_program, drift_signer, instructions, remaining @ ..] =
             accounts
         else {
             return None;
@@ -48,6 +50,111 @@ impl carbon_core::deserialize::ArrangeAccounts for BeginSwap {
             token_program: token_program.pubkey,
             drift_signer: drift_signer.pubkey,
             instructions: instructions.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct BeginSwapInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub user_stats: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub out_spot_market_vault: solana_instruction::AccountMeta,
+    pub in_spot_market_vault: solana_instruction::AccountMeta,
+    pub out_token_account: solana_instruction::AccountMeta,
+    pub in_token_account: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub drift_signer: solana_instruction::AccountMeta,
+    pub instructions: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for BeginSwap {
+    type ArrangedAccountMetas = BeginSwapInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, user, user_stats, authority, out_spot_market_vault, in_spot_market_vault, out_token_account, in_token_account, token_program, drift_signer, instructions, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(BeginSwapInstructionAccountMetas {
+            state: state.clone(),
+            user: user.clone(),
+            user_stats: user_stats.clone(),
+            authority: authority.clone(),
+            out_spot_market_vault: out_spot_market_vault.clone(),
+            in_spot_market_vault: in_spot_market_vault.clone(),
+            out_token_account: out_token_account.clone(),
+            in_token_account: in_token_account.clone(),
+            token_program: token_program.clone(),
+            drift_signer: drift_signer.clone(),
+            instructions: instructions.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct BeginSwapInstructionAccountIndices {
+    pub state: usize,
+    pub user: usize,
+    pub user_stats: usize,
+    pub authority: usize,
+    pub out_spot_market_vault: usize,
+    pub in_spot_market_vault: usize,
+    pub out_token_account: usize,
+    pub in_token_account: usize,
+    pub token_program: usize,
+    pub drift_signer: usize,
+    pub instructions: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for BeginSwap {
+    type ArrangedAccountIndices = BeginSwapInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 11 {
+            return None;
+        }
+
+        Some(BeginSwapInstructionAccountIndices {
+            state: 0,
+            user: 1,
+            user_stats: 2,
+            authority: 3,
+            out_spot_market_vault: 4,
+            in_spot_market_vault: 5,
+            out_token_account: 6,
+            in_token_account: 7,
+            token_program: 8,
+            drift_signer: 9,
+            instructions: 10,
+            remaining_accounts: 11..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for BeginSwap {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "user",
+            "user_stats",
+            "authority",
+            "out_spot_market_vault",
+            "in_spot_market_vault",
+            "out_token_account",
+            "in_token_account",
+            "token_program",
+            "drift_signer",
+            "instructions",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +13,7 @@ pub struct CancelOrderByUserIdInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub user: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CancelOrderByUserId {
@@ -20,7 +22,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CancelOrderByUserId {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, user, authority, _remaining @ ..] = accounts else {
+        let [state, user, authority, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +30,65 @@ impl carbon_core::deserialize::ArrangeAccounts for CancelOrderByUserId {
             state: state.pubkey,
             user: user.pubkey,
             authority: authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CancelOrderByUserIdInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CancelOrderByUserId {
+    type ArrangedAccountMetas = CancelOrderByUserIdInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, user, authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(CancelOrderByUserIdInstructionAccountMetas {
+            state: state.clone(),
+            user: user.clone(),
+            authority: authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CancelOrderByUserIdInstructionAccountIndices {
+    pub state: usize,
+    pub user: usize,
+    pub authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CancelOrderByUserId {
+    type ArrangedAccountIndices = CancelOrderByUserIdInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(CancelOrderByUserIdInstructionAccountIndices {
+            state: 0,
+            user: 1,
+            authority: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CancelOrderByUserId {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "user", "authority"]
+    }
+}
@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -16,6 +17,7 @@ pub struct CancelOrdersInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub user: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CancelOrders {
@@ -24,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CancelOrders {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, user, authority, _remaining @ ..] = accounts else {
+        let [state, user, authority, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -32,6 +34,65 @@ impl carbon_core::deserialize::ArrangeAccounts for CancelOrders {
             state: state.pubkey,
             user: user.pubkey,
             authority: authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CancelOrdersInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CancelOrders {
+    type ArrangedAccountMetas = CancelOrdersInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, user, authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(CancelOrdersInstructionAccountMetas {
+            state: state.clone(),
+            user: user.clone(),
+            authority: authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CancelOrdersInstructionAccountIndices {
+    pub state: usize,
+    pub user: usize,
+    pub authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CancelOrders {
+    type ArrangedAccountIndices = CancelOrdersInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(CancelOrdersInstructionAccountIndices {
+            state: 0,
+            user: 1,
+            authority: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CancelOrders {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "user", "authority"]
+    }
+}
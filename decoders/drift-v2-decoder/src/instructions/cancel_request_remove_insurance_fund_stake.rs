@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct CancelRequestRemoveInsuranceFundStakeInstructionAccounts {
     pub user_stats: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
     pub insurance_fund_vault: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CancelRequestRemoveInsuranceFundStake {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CancelRequestRemoveInsuranceF
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [spot_market, insurance_fund_stake, user_stats, authority, insurance_fund_vault, _remaining @ ..] =
+        let [spot_market, insurance_fund_stake, user_stats, authority, insurance_fund_vault, remaining @ ..] =
             accounts
         else {
             return None;
@@ -34,6 +36,85 @@ impl carbon_core::deserialize::ArrangeAccounts for CancelRequestRemoveInsuranceF
             user_stats: user_stats.pubkey,
             authority: authority.pubkey,
             insurance_fund_vault: insurance_fund_vault.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CancelRequestRemoveInsuranceFundStakeInstructionAccountMetas {
+    pub spot_market: solana_instruction::AccountMeta,
+    pub insurance_fund_stake: solana_instruction::AccountMeta,
+    pub user_stats: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub insurance_fund_vault: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CancelRequestRemoveInsuranceFundStake {
+    type ArrangedAccountMetas = CancelRequestRemoveInsuranceFundStakeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [spot_market, insurance_fund_stake, user_stats, authority, insurance_fund_vault, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(
+            CancelRequestRemoveInsuranceFundStakeInstructionAccountMetas {
+                spot_market: spot_market.clone(),
+                insurance_fund_stake: insurance_fund_stake.clone(),
+                user_stats: user_stats.clone(),
+                authority: authority.clone(),
+                insurance_fund_vault: insurance_fund_vault.clone(),
+                remaining_accounts: remaining.to_vec(),
+            },
+        )
+    }
+}
+
+pub struct CancelRequestRemoveInsuranceFundStakeInstructionAccountIndices {
+    pub spot_market: usize,
+    pub insurance_fund_stake: usize,
+    pub user_stats: usize,
+    pub authority: usize,
+    pub insurance_fund_vault: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CancelRequestRemoveInsuranceFundStake {
+    type ArrangedAccountIndices = CancelRequestRemoveInsuranceFundStakeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(
+            CancelRequestRemoveInsuranceFundStakeInstructionAccountIndices {
+                spot_market: 0,
+                insurance_fund_stake: 1,
+                user_stats: 2,
+                authority: 3,
+                insurance_fund_vault: 4,
+                remaining_accounts: 5..accounts.len(),
+            },
+        )
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CancelRequestRemoveInsuranceFundStake {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "spot_market",
+            "insurance_fund_stake",
+            "user_stats",
+            "authority",
+            "insurance_fund_vault",
+        ]
+    }
+}
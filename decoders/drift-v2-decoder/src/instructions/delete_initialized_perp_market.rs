@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +13,7 @@ pub struct DeleteInitializedPerpMarketInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub state: solana_pubkey::Pubkey,
     pub perp_market: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for DeleteInitializedPerpMarket {
@@ -20,7 +22,7 @@ impl carbon_core::deserialize::ArrangeAccounts for DeleteInitializedPerpMarket {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin, state, perp_market, _remaining @ ..] = accounts else {
+        let [admin, state, perp_market, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +30,65 @@ impl carbon_core::deserialize::ArrangeAccounts for DeleteInitializedPerpMarket {
             admin: admin.pubkey,
             state: state.pubkey,
             perp_market: perp_market.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DeleteInitializedPerpMarketInstructionAccountMetas {
+    pub admin: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub perp_market: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for DeleteInitializedPerpMarket {
+    type ArrangedAccountMetas = DeleteInitializedPerpMarketInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin, state, perp_market, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(DeleteInitializedPerpMarketInstructionAccountMetas {
+            admin: admin.clone(),
+            state: state.clone(),
+            perp_market: perp_market.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DeleteInitializedPerpMarketInstructionAccountIndices {
+    pub admin: usize,
+    pub state: usize,
+    pub perp_market: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for DeleteInitializedPerpMarket {
+    type ArrangedAccountIndices = DeleteInitializedPerpMarketInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(DeleteInitializedPerpMarketInstructionAccountIndices {
+            admin: 0,
+            state: 1,
+            perp_market: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for DeleteInitializedPerpMarket {
+    fn account_names() -> &'static [&'static str] {
+        &["admin", "state", "perp_market"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -16,6 +17,7 @@ pub struct DeleteInitializedSpotMarketInstructionAccounts {
     pub insurance_fund_vault: solana_pubkey::Pubkey,
     pub drift_signer: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for DeleteInitializedSpotMarket {
@@ -24,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for DeleteInitializedSpotMarket {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin, state, spot_market, spot_market_vault, insurance_fund_vault, drift_signer, token_program, _remaining @ ..] =
+        let [admin, state, spot_market, spot_market_vault, insurance_fund_vault, drift_signer, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -38,6 +40,91 @@ impl carbon_core::deserialize::ArrangeAccounts for DeleteInitializedSpotMarket {
             insurance_fund_vault: insurance_fund_vault.pubkey,
             drift_signer: drift_signer.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DeleteInitializedSpotMarketInstructionAccountMetas {
+    pub admin: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub spot_market: solana_instruction::AccountMeta,
+    pub spot_market_vault: solana_instruction::AccountMeta,
+    pub insurance_fund_vault: solana_instruction::AccountMeta,
+    pub drift_signer: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for DeleteInitializedSpotMarket {
+    type ArrangedAccountMetas = DeleteInitializedSpotMarketInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin, state, spot_market, spot_market_vault, insurance_fund_vault, drift_signer, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(DeleteInitializedSpotMarketInstructionAccountMetas {
+            admin: admin.clone(),
+            state: state.clone(),
+            spot_market: spot_market.clone(),
+            spot_market_vault: spot_market_vault.clone(),
+            insurance_fund_vault: insurance_fund_vault.clone(),
+            drift_signer: drift_signer.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DeleteInitializedSpotMarketInstructionAccountIndices {
+    pub admin: usize,
+    pub state: usize,
+    pub spot_market: usize,
+    pub spot_market_vault: usize,
+    pub insurance_fund_vault: usize,
+    pub drift_signer: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for DeleteInitializedSpotMarket {
+    type ArrangedAccountIndices = DeleteInitializedSpotMarketInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 7 {
+            return None;
+        }
+
+        Some(DeleteInitializedSpotMarketInstructionAccountIndices {
+            admin: 0,
+            state: 1,
+            spot_market: 2,
+            spot_market_vault: 3,
+            insurance_fund_vault: 4,
+            drift_signer: 5,
+            token_program: 6,
+            remaining_accounts: 7..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for DeleteInitializedSpotMarket {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "admin",
+            "state",
+            "spot_market",
+            "spot_market_vault",
+            "insurance_fund_vault",
+            "drift_signer",
+            "token_program",
+        ]
+    }
+}
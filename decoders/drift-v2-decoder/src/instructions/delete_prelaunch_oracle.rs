@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +14,7 @@ pub struct DeletePrelaunchOracleInstructionAccounts {
     pub prelaunch_oracle: solana_pubkey::Pubkey,
     pub perp_market: solana_pubkey::Pubkey,
     pub state: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for DeletePrelaunchOracle {
@@ -21,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for DeletePrelaunchOracle {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin, prelaunch_oracle, perp_market, state, _remaining @ ..] = accounts else {
+        let [admin, prelaunch_oracle, perp_market, state, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -30,6 +32,69 @@ impl carbon_core::deserialize::ArrangeAccounts for DeletePrelaunchOracle {
             prelaunch_oracle: prelaunch_oracle.pubkey,
             perp_market: perp_market.pubkey,
             state: state.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DeletePrelaunchOracleInstructionAccountMetas {
+    pub admin: solana_instruction::AccountMeta,
+    pub prelaunch_oracle: solana_instruction::AccountMeta,
+    pub perp_market: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for DeletePrelaunchOracle {
+    type ArrangedAccountMetas = DeletePrelaunchOracleInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin, prelaunch_oracle, perp_market, state, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(DeletePrelaunchOracleInstructionAccountMetas {
+            admin: admin.clone(),
+            prelaunch_oracle: prelaunch_oracle.clone(),
+            perp_market: perp_market.clone(),
+            state: state.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DeletePrelaunchOracleInstructionAccountIndices {
+    pub admin: usize,
+    pub prelaunch_oracle: usize,
+    pub perp_market: usize,
+    pub state: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for DeletePrelaunchOracle {
+    type ArrangedAccountIndices = DeletePrelaunchOracleInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(DeletePrelaunchOracleInstructionAccountIndices {
+            admin: 0,
+            prelaunch_oracle: 1,
+            perp_market: 2,
+            state: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for DeletePrelaunchOracle {
+    fn account_names() -> &'static [&'static str] {
+        &["admin", "prelaunch_oracle", "perp_market", "state"]
+    }
+}
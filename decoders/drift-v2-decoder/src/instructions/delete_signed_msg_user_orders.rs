@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -10,6 +11,7 @@ pub struct DeleteSignedMsgUserOrdersInstructionAccounts {
     pub signed_msg_user_orders: solana_pubkey::Pubkey,
     pub state: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for DeleteSignedMsgUserOrders {
@@ -18,7 +20,7 @@ impl carbon_core::deserialize::ArrangeAccounts for DeleteSignedMsgUserOrders {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [signed_msg_user_orders, state, authority, _remaining @ ..] = accounts else {
+        let [signed_msg_user_orders, state, authority, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -26,6 +28,65 @@ impl carbon_core::deserialize::ArrangeAccounts for DeleteSignedMsgUserOrders {
             signed_msg_user_orders: signed_msg_user_orders.pubkey,
             state: state.pubkey,
             authority: authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DeleteSignedMsgUserOrdersInstructionAccountMetas {
+    pub signed_msg_user_orders: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for DeleteSignedMsgUserOrders {
+    type ArrangedAccountMetas = DeleteSignedMsgUserOrdersInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [signed_msg_user_orders, state, authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(DeleteSignedMsgUserOrdersInstructionAccountMetas {
+            signed_msg_user_orders: signed_msg_user_orders.clone(),
+            state: state.clone(),
+            authority: authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DeleteSignedMsgUserOrdersInstructionAccountIndices {
+    pub signed_msg_user_orders: usize,
+    pub state: usize,
+    pub authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for DeleteSignedMsgUserOrders {
+    type ArrangedAccountIndices = DeleteSignedMsgUserOrdersInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(DeleteSignedMsgUserOrdersInstructionAccountIndices {
+            signed_msg_user_orders: 0,
+            state: 1,
+            authority: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for DeleteSignedMsgUserOrders {
+    fn account_names() -> &'static [&'static str] {
+        &["signed_msg_user_orders", "state", "authority"]
+    }
+}
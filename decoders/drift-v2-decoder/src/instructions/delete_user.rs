@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +12,7 @@ pub struct DeleteUserInstructionAccounts {
     pub user_stats: solana_pubkey::Pubkey,
     pub state: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for DeleteUser {
@@ -19,7 +21,7 @@ impl carbon_core::deserialize::ArrangeAccounts for DeleteUser {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [user, user_stats, state, authority, _remaining @ ..] = accounts else {
+        let [user, user_stats, state, authority, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +30,69 @@ impl carbon_core::deserialize::ArrangeAccounts for DeleteUser {
             user_stats: user_stats.pubkey,
             state: state.pubkey,
             authority: authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DeleteUserInstructionAccountMetas {
+    pub user: solana_instruction::AccountMeta,
+    pub user_stats: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for DeleteUser {
+    type ArrangedAccountMetas = DeleteUserInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [user, user_stats, state, authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(DeleteUserInstructionAccountMetas {
+            user: user.clone(),
+            user_stats: user_stats.clone(),
+            state: state.clone(),
+            authority: authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DeleteUserInstructionAccountIndices {
+    pub user: usize,
+    pub user_stats: usize,
+    pub state: usize,
+    pub authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for DeleteUser {
+    type ArrangedAccountIndices = DeleteUserInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(DeleteUserInstructionAccountIndices {
+            user: 0,
+            user_stats: 1,
+            state: 2,
+            authority: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for DeleteUser {
+    fn account_names() -> &'static [&'static str] {
+        &["user", "user_stats", "state", "authority"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -18,6 +19,7 @@ pub struct DepositInstructionAccounts {
     pub spot_market_vault: solana_pubkey::Pubkey,
     pub user_token_account: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Deposit {
@@ -26,7 +28,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Deposit {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, user, user_stats, authority, spot_market_vault, user_token_account, token_program, _remaining @ ..] =
+        let [state, user, user_stats, authority, spot_market_vault, user_token_account, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -40,6 +42,91 @@ impl carbon_core::deserialize::ArrangeAccounts for Deposit {
             spot_market_vault: spot_market_vault.pubkey,
             user_token_account: user_token_account.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DepositInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub user_stats: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub spot_market_vault: solana_instruction::AccountMeta,
+    pub user_token_account: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Deposit {
+    type ArrangedAccountMetas = DepositInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, user, user_stats, authority, spot_market_vault, user_token_account, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(DepositInstructionAccountMetas {
+            state: state.clone(),
+            user: user.clone(),
+            user_stats: user_stats.clone(),
+            authority: authority.clone(),
+            spot_market_vault: spot_market_vault.clone(),
+            user_token_account: user_token_account.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DepositInstructionAccountIndices {
+    pub state: usize,
+    pub user: usize,
+    pub user_stats: usize,
+    pub authority: usize,
+    pub spot_market_vault: usize,
+    pub user_token_account: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Deposit {
+    type ArrangedAccountIndices = DepositInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 7 {
+            return None;
+        }
+
+        Some(DepositInstructionAccountIndices {
+            state: 0,
+            user: 1,
+            user_stats: 2,
+            authority: 3,
+            spot_market_vault: 4,
+            user_token_account: 5,
+            token_program: 6,
+            remaining_accounts: 7..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Deposit {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "user",
+            "user_stats",
+            "authority",
+            "spot_market_vault",
+            "user_token_account",
+            "token_program",
+        ]
+    }
+}
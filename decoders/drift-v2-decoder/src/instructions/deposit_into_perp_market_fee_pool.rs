@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -17,6 +18,7 @@ pub struct DepositIntoPerpMarketFeePoolInstructionAccounts {
     pub quote_spot_market: solana_pubkey::Pubkey,
     pub spot_market_vault: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for DepositIntoPerpMarketFeePool {
@@ -25,7 +27,7 @@ impl carbon_core::deserialize::ArrangeAccounts for DepositIntoPerpMarketFeePool
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, perp_market, admin, source_vault, drift_signer, quote_spot_market, spot_market_vault, token_program, _remaining @ ..] =
+        let [state, perp_market, admin, source_vault, drift_signer, quote_spot_market, spot_market_vault, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -40,6 +42,96 @@ impl carbon_core::deserialize::ArrangeAccounts for DepositIntoPerpMarketFeePool
             quote_spot_market: quote_spot_market.pubkey,
             spot_market_vault: spot_market_vault.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DepositIntoPerpMarketFeePoolInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub perp_market: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub source_vault: solana_instruction::AccountMeta,
+    pub drift_signer: solana_instruction::AccountMeta,
+    pub quote_spot_market: solana_instruction::AccountMeta,
+    pub spot_market_vault: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for DepositIntoPerpMarketFeePool {
+    type ArrangedAccountMetas = DepositIntoPerpMarketFeePoolInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, perp_market, admin, source_vault, drift_signer, quote_spot_market, spot_market_vault, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(DepositIntoPerpMarketFeePoolInstructionAccountMetas {
+            state: state.clone(),
+            perp_market: perp_market.clone(),
+            admin: admin.clone(),
+            source_vault: source_vault.clone(),
+            drift_signer: drift_signer.clone(),
+            quote_spot_market: quote_spot_market.clone(),
+            spot_market_vault: spot_market_vault.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DepositIntoPerpMarketFeePoolInstructionAccountIndices {
+    pub state: usize,
+    pub perp_market: usize,
+    pub admin: usize,
+    pub source_vault: usize,
+    pub drift_signer: usize,
+    pub quote_spot_market: usize,
+    pub spot_market_vault: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for DepositIntoPerpMarketFeePool {
+    type ArrangedAccountIndices = DepositIntoPerpMarketFeePoolInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(DepositIntoPerpMarketFeePoolInstructionAccountIndices {
+            state: 0,
+            perp_market: 1,
+            admin: 2,
+            source_vault: 3,
+            drift_signer: 4,
+            quote_spot_market: 5,
+            spot_market_vault: 6,
+            token_program: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for DepositIntoPerpMarketFeePool {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "perp_market",
+            "admin",
+            "source_vault",
+            "drift_signer",
+            "quote_spot_market",
+            "spot_market_vault",
+            "token_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -15,6 +16,7 @@ pub struct DepositIntoSpotMarketRevenuePoolInstructionAccounts {
     pub spot_market_vault: solana_pubkey::Pubkey,
     pub user_token_account: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for DepositIntoSpotMarketRevenuePool {
@@ -23,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for DepositIntoSpotMarketRevenueP
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, spot_market, authority, spot_market_vault, user_token_account, token_program, _remaining @ ..] =
+        let [state, spot_market, authority, spot_market_vault, user_token_account, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -36,6 +38,86 @@ impl carbon_core::deserialize::ArrangeAccounts for DepositIntoSpotMarketRevenueP
             spot_market_vault: spot_market_vault.pubkey,
             user_token_account: user_token_account.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DepositIntoSpotMarketRevenuePoolInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub spot_market: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub spot_market_vault: solana_instruction::AccountMeta,
+    pub user_token_account: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for DepositIntoSpotMarketRevenuePool {
+    type ArrangedAccountMetas = DepositIntoSpotMarketRevenuePoolInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, spot_market, authority, spot_market_vault, user_token_account, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(DepositIntoSpotMarketRevenuePoolInstructionAccountMetas {
+            state: state.clone(),
+            spot_market: spot_market.clone(),
+            authority: authority.clone(),
+            spot_market_vault: spot_market_vault.clone(),
+            user_token_account: user_token_account.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DepositIntoSpotMarketRevenuePoolInstructionAccountIndices {
+    pub state: usize,
+    pub spot_market: usize,
+    pub authority: usize,
+    pub spot_market_vault: usize,
+    pub user_token_account: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for DepositIntoSpotMarketRevenuePool {
+    type ArrangedAccountIndices = DepositIntoSpotMarketRevenuePoolInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(DepositIntoSpotMarketRevenuePoolInstructionAccountIndices {
+            state: 0,
+            spot_market: 1,
+            authority: 2,
+            spot_market_vault: 3,
+            user_token_account: 4,
+            token_program: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for DepositIntoSpotMarketRevenuePool {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "spot_market",
+            "authority",
+            "spot_market_vault",
+            "user_token_account",
+            "token_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -15,6 +16,7 @@ pub struct DepositIntoSpotMarketVaultInstructionAccounts {
     pub source_vault: solana_pubkey::Pubkey,
     pub spot_market_vault: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for DepositIntoSpotMarketVault {
@@ -23,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for DepositIntoSpotMarketVault {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, spot_market, admin, source_vault, spot_market_vault, token_program, _remaining @ ..] =
+        let [state, spot_market, admin, source_vault, spot_market_vault, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -36,6 +38,86 @@ impl carbon_core::deserialize::ArrangeAccounts for DepositIntoSpotMarketVault {
             source_vault: source_vault.pubkey,
             spot_market_vault: spot_market_vault.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DepositIntoSpotMarketVaultInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub spot_market: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub source_vault: solana_instruction::AccountMeta,
+    pub spot_market_vault: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for DepositIntoSpotMarketVault {
+    type ArrangedAccountMetas = DepositIntoSpotMarketVaultInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, spot_market, admin, source_vault, spot_market_vault, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(DepositIntoSpotMarketVaultInstructionAccountMetas {
+            state: state.clone(),
+            spot_market: spot_market.clone(),
+            admin: admin.clone(),
+            source_vault: source_vault.clone(),
+            spot_market_vault: spot_market_vault.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DepositIntoSpotMarketVaultInstructionAccountIndices {
+    pub state: usize,
+    pub spot_market: usize,
+    pub admin: usize,
+    pub source_vault: usize,
+    pub spot_market_vault: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for DepositIntoSpotMarketVault {
+    type ArrangedAccountIndices = DepositIntoSpotMarketVaultInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(DepositIntoSpotMarketVaultInstructionAccountIndices {
+            state: 0,
+            spot_market: 1,
+            admin: 2,
+            source_vault: 3,
+            spot_market_vault: 4,
+            token_program: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for DepositIntoSpotMarketVault {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "spot_market",
+            "admin",
+            "source_vault",
+            "spot_market_vault",
+            "token_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +12,7 @@ pub struct DisableUserHighLeverageModeInstructionAccounts {
     pub authority: solana_pubkey::Pubkey,
     pub user: solana_pubkey::Pubkey,
     pub high_leverage_mode_config: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for DisableUserHighLeverageMode {
@@ -19,7 +21,7 @@ impl carbon_core::deserialize::ArrangeAccounts for DisableUserHighLeverageMode {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, authority, user, high_leverage_mode_config, _remaining @ ..] = accounts else {
+        let [state, authority, user, high_leverage_mode_config, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +30,69 @@ impl carbon_core::deserialize::ArrangeAccounts for DisableUserHighLeverageMode {
             authority: authority.pubkey,
             user: user.pubkey,
             high_leverage_mode_config: high_leverage_mode_config.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DisableUserHighLeverageModeInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub high_leverage_mode_config: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for DisableUserHighLeverageMode {
+    type ArrangedAccountMetas = DisableUserHighLeverageModeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, authority, user, high_leverage_mode_config, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(DisableUserHighLeverageModeInstructionAccountMetas {
+            state: state.clone(),
+            authority: authority.clone(),
+            user: user.clone(),
+            high_leverage_mode_config: high_leverage_mode_config.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DisableUserHighLeverageModeInstructionAccountIndices {
+    pub state: usize,
+    pub authority: usize,
+    pub user: usize,
+    pub high_leverage_mode_config: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for DisableUserHighLeverageMode {
+    type ArrangedAccountIndices = DisableUserHighLeverageModeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(DisableUserHighLeverageModeInstructionAccountIndices {
+            state: 0,
+            authority: 1,
+            user: 2,
+            high_leverage_mode_config: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for DisableUserHighLeverageMode {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "authority", "user", "high_leverage_mode_config"]
+    }
+}
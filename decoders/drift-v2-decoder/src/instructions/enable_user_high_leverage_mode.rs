@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +14,7 @@ pub struct EnableUserHighLeverageModeInstructionAccounts {
     pub user: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
     pub high_leverage_mode_config: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for EnableUserHighLeverageMode {
@@ -21,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for EnableUserHighLeverageMode {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, user, authority, high_leverage_mode_config, _remaining @ ..] = accounts else {
+        let [state, user, authority, high_leverage_mode_config, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -30,6 +32,69 @@ impl carbon_core::deserialize::ArrangeAccounts for EnableUserHighLeverageMode {
             user: user.pubkey,
             authority: authority.pubkey,
             high_leverage_mode_config: high_leverage_mode_config.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct EnableUserHighLeverageModeInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub high_leverage_mode_config: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for EnableUserHighLeverageMode {
+    type ArrangedAccountMetas = EnableUserHighLeverageModeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, user, authority, high_leverage_mode_config, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(EnableUserHighLeverageModeInstructionAccountMetas {
+            state: state.clone(),
+            user: user.clone(),
+            authority: authority.clone(),
+            high_leverage_mode_config: high_leverage_mode_config.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct EnableUserHighLeverageModeInstructionAccountIndices {
+    pub state: usize,
+    pub user: usize,
+    pub authority: usize,
+    pub high_leverage_mode_config: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for EnableUserHighLeverageMode {
+    type ArrangedAccountIndices = EnableUserHighLeverageModeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(EnableUserHighLeverageModeInstructionAccountIndices {
+            state: 0,
+            user: 1,
+            authority: 2,
+            high_leverage_mode_config: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for EnableUserHighLeverageMode {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "user", "authority", "high_leverage_mode_config"]
+    }
+}
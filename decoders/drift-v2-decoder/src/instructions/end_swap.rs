@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -25,6 +26,7 @@ pub struct EndSwapInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub drift_signer: solana_pubkey::Pubkey,
     pub instructions: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for EndSwap {
@@ -33,7 +35,7 @@ impl carbon_core::deserialize::ArrangeAccounts for EndSwap {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, user, user_stats, authority, out_spot_market_vault, in_spot_market_vault, out_token_account, in_token_account, token_program, drift_signer, instructions, _remaining @ ..] =
+        let [state, user, user_stats, authority, out_spot_market_vault, in_spot_market_vault, out_token_account, in_token_account, token_program, drift_signer, instructions, remaining @ ..] =
             accounts
         else {
             return None;
@@ -51,6 +53,111 @@ impl carbon_core::deserialize::ArrangeAccounts for EndSwap {
             token_program: token_program.pubkey,
             drift_signer: drift_signer.pubkey,
             instructions: instructions.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct EndSwapInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub user_stats: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub out_spot_market_vault: solana_instruction::AccountMeta,
+    pub in_spot_market_vault: solana_instruction::AccountMeta,
+    pub out_token_account: solana_instruction::AccountMeta,
+    pub in_token_account: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub drift_signer: solana_instruction::AccountMeta,
+    pub instructions: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for EndSwap {
+    type ArrangedAccountMetas = EndSwapInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, user, user_stats, authority, out_spot_market_vault, in_spot_market_vault, out_token_account, in_token_account, token_program, drift_signer, instructions, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(EndSwapInstructionAccountMetas {
+            state: state.clone(),
+            user: user.clone(),
+            user_stats: user_stats.clone(),
+            authority: authority.clone(),
+            out_spot_market_vault: out_spot_market_vault.clone(),
+            in_spot_market_vault: in_spot_market_vault.clone(),
+            out_token_account: out_token_account.clone(),
+            in_token_account: in_token_account.clone(),
+            token_program: token_program.clone(),
+            drift_signer: drift_signer.clone(),
+            instructions: instructions.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct EndSwapInstructionAccountIndices {
+    pub state: usize,
+    pub user: usize,
+    pub user_stats: usize,
+    pub authority: usize,
+    pub out_spot_market_vault: usize,
+    pub in_spot_market_vault: usize,
+    pub out_token_account: usize,
+    pub in_token_account: usize,
+    pub token_program: usize,
+    pub drift_signer: usize,
+    pub instructions: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for EndSwap {
+    type ArrangedAccountIndices = EndSwapInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 11 {
+            return None;
+        }
+
+        Some(EndSwapInstructionAccountIndices {
+            state: 0,
+            user: 1,
+            user_stats: 2,
+            authority: 3,
+            out_spot_market_vault: 4,
+            in_spot_market_vault: 5,
+            out_token_account: 6,
+            in_token_account: 7,
+            token_program: 8,
+            drift_signer: 9,
+            instructions: 10,
+            remaining_accounts: 11..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for EndSwap {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "user",
+            "user_stats",
+            "authority",
+            "out_spot_market_vault",
+            "in_spot_market_vault",
+            "out_token_account",
+            "in_token_account",
+            "token_program",
+            "drift_signer",
+            "instructions",
+        ]
+    }
+}
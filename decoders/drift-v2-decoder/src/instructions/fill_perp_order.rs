@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -16,6 +17,7 @@ pub struct FillPerpOrderInstructionAccounts {
     pub filler_stats: solana_pubkey::Pubkey,
     pub user: solana_pubkey::Pubkey,
     pub user_stats: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for FillPerpOrder {
@@ -24,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for FillPerpOrder {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, authority, filler, filler_stats, user, user_stats, _remaining @ ..] = accounts
+        let [state, authority, filler, filler_stats, user, user_stats, remaining @ ..] = accounts
         else {
             return None;
         };
@@ -36,6 +38,85 @@ impl carbon_core::deserialize::ArrangeAccounts for FillPerpOrder {
             filler_stats: filler_stats.pubkey,
             user: user.pubkey,
             user_stats: user_stats.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct FillPerpOrderInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub filler: solana_instruction::AccountMeta,
+    pub filler_stats: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub user_stats: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for FillPerpOrder {
+    type ArrangedAccountMetas = FillPerpOrderInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, authority, filler, filler_stats, user, user_stats, remaining @ ..] = accounts
+        else {
+            return None;
+        };
+
+        Some(FillPerpOrderInstructionAccountMetas {
+            state: state.clone(),
+            authority: authority.clone(),
+            filler: filler.clone(),
+            filler_stats: filler_stats.clone(),
+            user: user.clone(),
+            user_stats: user_stats.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct FillPerpOrderInstructionAccountIndices {
+    pub state: usize,
+    pub authority: usize,
+    pub filler: usize,
+    pub filler_stats: usize,
+    pub user: usize,
+    pub user_stats: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for FillPerpOrder {
+    type ArrangedAccountIndices = FillPerpOrderInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(FillPerpOrderInstructionAccountIndices {
+            state: 0,
+            authority: 1,
+            filler: 2,
+            filler_stats: 3,
+            user: 4,
+            user_stats: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for FillPerpOrder {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "authority",
+            "filler",
+            "filler_stats",
+            "user",
+            "user_stats",
+        ]
+    }
+}
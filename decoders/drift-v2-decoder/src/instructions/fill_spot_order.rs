@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -19,6 +20,7 @@ pub struct FillSpotOrderInstructionAccounts {
     pub filler_stats: solana_pubkey::Pubkey,
     pub user: solana_pubkey::Pubkey,
     pub user_stats: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for FillSpotOrder {
@@ -27,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for FillSpotOrder {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, authority, filler, filler_stats, user, user_stats, _remaining @ ..] = accounts
+        let [state, authority, filler, filler_stats, user, user_stats, remaining @ ..] = accounts
         else {
             return None;
         };
@@ -39,6 +41,85 @@ impl carbon_core::deserialize::ArrangeAccounts for FillSpotOrder {
             filler_stats: filler_stats.pubkey,
             user: user.pubkey,
             user_stats: user_stats.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct FillSpotOrderInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub filler: solana_instruction::AccountMeta,
+    pub filler_stats: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub user_stats: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for FillSpotOrder {
+    type ArrangedAccountMetas = FillSpotOrderInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, authority, filler, filler_stats, user, user_stats, remaining @ ..] = accounts
+        else {
+            return None;
+        };
+
+        Some(FillSpotOrderInstructionAccountMetas {
+            state: state.clone(),
+            authority: authority.clone(),
+            filler: filler.clone(),
+            filler_stats: filler_stats.clone(),
+            user: user.clone(),
+            user_stats: user_stats.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct FillSpotOrderInstructionAccountIndices {
+    pub state: usize,
+    pub authority: usize,
+    pub filler: usize,
+    pub filler_stats: usize,
+    pub user: usize,
+    pub user_stats: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for FillSpotOrder {
+    type ArrangedAccountIndices = FillSpotOrderInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(FillSpotOrderInstructionAccountIndices {
+            state: 0,
+            authority: 1,
+            filler: 2,
+            filler_stats: 3,
+            user: 4,
+            user_stats: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for FillSpotOrder {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "authority",
+            "filler",
+            "filler_stats",
+            "user",
+            "user_stats",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +12,7 @@ pub struct ForceCancelOrdersInstructionAccounts {
     pub authority: solana_pubkey::Pubkey,
     pub filler: solana_pubkey::Pubkey,
     pub user: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ForceCancelOrders {
@@ -19,7 +21,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ForceCancelOrders {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, authority, filler, user, _remaining @ ..] = accounts else {
+        let [state, authority, filler, user, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +30,69 @@ impl carbon_core::deserialize::ArrangeAccounts for ForceCancelOrders {
             authority: authority.pubkey,
             filler: filler.pubkey,
             user: user.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ForceCancelOrdersInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub filler: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ForceCancelOrders {
+    type ArrangedAccountMetas = ForceCancelOrdersInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, authority, filler, user, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(ForceCancelOrdersInstructionAccountMetas {
+            state: state.clone(),
+            authority: authority.clone(),
+            filler: filler.clone(),
+            user: user.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ForceCancelOrdersInstructionAccountIndices {
+    pub state: usize,
+    pub authority: usize,
+    pub filler: usize,
+    pub user: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ForceCancelOrders {
+    type ArrangedAccountIndices = ForceCancelOrdersInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(ForceCancelOrdersInstructionAccountIndices {
+            state: 0,
+            authority: 1,
+            filler: 2,
+            user: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ForceCancelOrders {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "authority", "filler", "user"]
+    }
+}
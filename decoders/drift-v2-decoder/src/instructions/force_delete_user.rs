@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +14,7 @@ pub struct ForceDeleteUserInstructionAccounts {
     pub authority: solana_pubkey::Pubkey,
     pub keeper: solana_pubkey::Pubkey,
     pub drift_signer: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ForceDeleteUser {
@@ -21,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ForceDeleteUser {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [user, user_stats, state, authority, keeper, drift_signer, _remaining @ ..] = accounts
+        let [user, user_stats, state, authority, keeper, drift_signer, remaining @ ..] = accounts
         else {
             return None;
         };
@@ -33,6 +35,85 @@ impl carbon_core::deserialize::ArrangeAccounts for ForceDeleteUser {
             authority: authority.pubkey,
             keeper: keeper.pubkey,
             drift_signer: drift_signer.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ForceDeleteUserInstructionAccountMetas {
+    pub user: solana_instruction::AccountMeta,
+    pub user_stats: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub keeper: solana_instruction::AccountMeta,
+    pub drift_signer: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ForceDeleteUser {
+    type ArrangedAccountMetas = ForceDeleteUserInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [user, user_stats, state, authority, keeper, drift_signer, remaining @ ..] = accounts
+        else {
+            return None;
+        };
+
+        Some(ForceDeleteUserInstructionAccountMetas {
+            user: user.clone(),
+            user_stats: user_stats.clone(),
+            state: state.clone(),
+            authority: authority.clone(),
+            keeper: keeper.clone(),
+            drift_signer: drift_signer.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ForceDeleteUserInstructionAccountIndices {
+    pub user: usize,
+    pub user_stats: usize,
+    pub state: usize,
+    pub authority: usize,
+    pub keeper: usize,
+    pub drift_signer: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ForceDeleteUser {
+    type ArrangedAccountIndices = ForceDeleteUserInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(ForceDeleteUserInstructionAccountIndices {
+            user: 0,
+            user_stats: 1,
+            state: 2,
+            authority: 3,
+            keeper: 4,
+            drift_signer: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ForceDeleteUser {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "user",
+            "user_stats",
+            "state",
+            "authority",
+            "keeper",
+            "drift_signer",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -17,6 +18,7 @@ pub struct InitUserFuelInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub user: solana_pubkey::Pubkey,
     pub user_stats: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitUserFuel {
@@ -25,7 +27,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitUserFuel {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin, state, user, user_stats, _remaining @ ..] = accounts else {
+        let [admin, state, user, user_stats, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -34,6 +36,69 @@ impl carbon_core::deserialize::ArrangeAccounts for InitUserFuel {
             state: state.pubkey,
             user: user.pubkey,
             user_stats: user_stats.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitUserFuelInstructionAccountMetas {
+    pub admin: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub user_stats: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitUserFuel {
+    type ArrangedAccountMetas = InitUserFuelInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin, state, user, user_stats, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(InitUserFuelInstructionAccountMetas {
+            admin: admin.clone(),
+            state: state.clone(),
+            user: user.clone(),
+            user_stats: user_stats.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitUserFuelInstructionAccountIndices {
+    pub admin: usize,
+    pub state: usize,
+    pub user: usize,
+    pub user_stats: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitUserFuel {
+    type ArrangedAccountIndices = InitUserFuelInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(InitUserFuelInstructionAccountIndices {
+            admin: 0,
+            state: 1,
+            user: 2,
+            user_stats: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitUserFuel {
+    fn account_names() -> &'static [&'static str] {
+        &["admin", "state", "user", "user_stats"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct InitializeInstructionAccounts {
     pub rent: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Initialize {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Initialize {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin, state, quote_asset_mint, drift_signer, rent, system_program, token_program, _remaining @ ..] =
+        let [admin, state, quote_asset_mint, drift_signer, rent, system_program, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -36,6 +38,91 @@ impl carbon_core::deserialize::ArrangeAccounts for Initialize {
             rent: rent.pubkey,
             system_program: system_program.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeInstructionAccountMetas {
+    pub admin: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub quote_asset_mint: solana_instruction::AccountMeta,
+    pub drift_signer: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Initialize {
+    type ArrangedAccountMetas = InitializeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin, state, quote_asset_mint, drift_signer, rent, system_program, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeInstructionAccountMetas {
+            admin: admin.clone(),
+            state: state.clone(),
+            quote_asset_mint: quote_asset_mint.clone(),
+            drift_signer: drift_signer.clone(),
+            rent: rent.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeInstructionAccountIndices {
+    pub admin: usize,
+    pub state: usize,
+    pub quote_asset_mint: usize,
+    pub drift_signer: usize,
+    pub rent: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Initialize {
+    type ArrangedAccountIndices = InitializeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 7 {
+            return None;
+        }
+
+        Some(InitializeInstructionAccountIndices {
+            admin: 0,
+            state: 1,
+            quote_asset_mint: 2,
+            drift_signer: 3,
+            rent: 4,
+            system_program: 5,
+            token_program: 6,
+            remaining_accounts: 7..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Initialize {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "admin",
+            "state",
+            "quote_asset_mint",
+            "drift_signer",
+            "rent",
+            "system_program",
+            "token_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +14,7 @@ pub struct InitializeFuelOverflowInstructionAccounts {
     pub payer: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeFuelOverflow {
@@ -21,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeFuelOverflow {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [fuel_overflow, user_stats, authority, payer, rent, system_program, _remaining @ ..] =
+        let [fuel_overflow, user_stats, authority, payer, rent, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -34,6 +36,86 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeFuelOverflow {
             payer: payer.pubkey,
             rent: rent.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeFuelOverflowInstructionAccountMetas {
+    pub fuel_overflow: solana_instruction::AccountMeta,
+    pub user_stats: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeFuelOverflow {
+    type ArrangedAccountMetas = InitializeFuelOverflowInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [fuel_overflow, user_stats, authority, payer, rent, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeFuelOverflowInstructionAccountMetas {
+            fuel_overflow: fuel_overflow.clone(),
+            user_stats: user_stats.clone(),
+            authority: authority.clone(),
+            payer: payer.clone(),
+            rent: rent.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeFuelOverflowInstructionAccountIndices {
+    pub fuel_overflow: usize,
+    pub user_stats: usize,
+    pub authority: usize,
+    pub payer: usize,
+    pub rent: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeFuelOverflow {
+    type ArrangedAccountIndices = InitializeFuelOverflowInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(InitializeFuelOverflowInstructionAccountIndices {
+            fuel_overflow: 0,
+            user_stats: 1,
+            authority: 2,
+            payer: 3,
+            rent: 4,
+            system_program: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeFuelOverflow {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "fuel_overflow",
+            "user_stats",
+            "authority",
+            "payer",
+            "rent",
+            "system_program",
+        ]
+    }
+}
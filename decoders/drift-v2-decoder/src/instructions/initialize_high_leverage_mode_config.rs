@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct InitializeHighLeverageModeConfigInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeHighLeverageModeConfig {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeHighLeverageModeCon
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin, high_leverage_mode_config, state, rent, system_program, _remaining @ ..] =
+        let [admin, high_leverage_mode_config, state, rent, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -34,6 +36,81 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeHighLeverageModeCon
             state: state.pubkey,
             rent: rent.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeHighLeverageModeConfigInstructionAccountMetas {
+    pub admin: solana_instruction::AccountMeta,
+    pub high_leverage_mode_config: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeHighLeverageModeConfig {
+    type ArrangedAccountMetas = InitializeHighLeverageModeConfigInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin, high_leverage_mode_config, state, rent, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeHighLeverageModeConfigInstructionAccountMetas {
+            admin: admin.clone(),
+            high_leverage_mode_config: high_leverage_mode_config.clone(),
+            state: state.clone(),
+            rent: rent.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeHighLeverageModeConfigInstructionAccountIndices {
+    pub admin: usize,
+    pub high_leverage_mode_config: usize,
+    pub state: usize,
+    pub rent: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeHighLeverageModeConfig {
+    type ArrangedAccountIndices = InitializeHighLeverageModeConfigInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(InitializeHighLeverageModeConfigInstructionAccountIndices {
+            admin: 0,
+            high_leverage_mode_config: 1,
+            state: 2,
+            rent: 3,
+            system_program: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeHighLeverageModeConfig {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "admin",
+            "high_leverage_mode_config",
+            "state",
+            "rent",
+            "system_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -17,6 +18,7 @@ pub struct InitializeInsuranceFundStakeInstructionAccounts {
     pub payer: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeInsuranceFundStake {
@@ -25,7 +27,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeInsuranceFundStake
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [spot_market, insurance_fund_stake, user_stats, state, authority, payer, rent, system_program, _remaining @ ..] =
+        let [spot_market, insurance_fund_stake, user_stats, state, authority, payer, rent, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -40,6 +42,96 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeInsuranceFundStake
             payer: payer.pubkey,
             rent: rent.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeInsuranceFundStakeInstructionAccountMetas {
+    pub spot_market: solana_instruction::AccountMeta,
+    pub insurance_fund_stake: solana_instruction::AccountMeta,
+    pub user_stats: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeInsuranceFundStake {
+    type ArrangedAccountMetas = InitializeInsuranceFundStakeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [spot_market, insurance_fund_stake, user_stats, state, authority, payer, rent, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeInsuranceFundStakeInstructionAccountMetas {
+            spot_market: spot_market.clone(),
+            insurance_fund_stake: insurance_fund_stake.clone(),
+            user_stats: user_stats.clone(),
+            state: state.clone(),
+            authority: authority.clone(),
+            payer: payer.clone(),
+            rent: rent.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeInsuranceFundStakeInstructionAccountIndices {
+    pub spot_market: usize,
+    pub insurance_fund_stake: usize,
+    pub user_stats: usize,
+    pub state: usize,
+    pub authority: usize,
+    pub payer: usize,
+    pub rent: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeInsuranceFundStake {
+    type ArrangedAccountIndices = InitializeInsuranceFundStakeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(InitializeInsuranceFundStakeInstructionAccountIndices {
+            spot_market: 0,
+            insurance_fund_stake: 1,
+            user_stats: 2,
+            state: 3,
+            authority: 4,
+            payer: 5,
+            rent: 6,
+            system_program: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeInsuranceFundStake {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "spot_market",
+            "insurance_fund_stake",
+            "user_stats",
+            "state",
+            "authority",
+            "payer",
+            "rent",
+            "system_program",
+        ]
+    }
+}
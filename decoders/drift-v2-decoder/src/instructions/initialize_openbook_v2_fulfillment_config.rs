@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -19,6 +20,7 @@ pub struct InitializeOpenbookV2FulfillmentConfigInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeOpenbookV2FulfillmentConfig {
@@ -27,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeOpenbookV2Fulfillme
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [base_spot_market, quote_spot_market, state, openbook_v2_program, openbook_v2_market, drift_signer, openbook_v2_fulfillment_config, admin, rent, system_program, _remaining @ ..] =
+        let [base_spot_market, quote_spot_market, state, openbook_v2_program, openbook_v2_market, drift_signer, openbook_v2_fulfillment_config, admin, rent, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -44,6 +46,110 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeOpenbookV2Fulfillme
             admin: admin.pubkey,
             rent: rent.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeOpenbookV2FulfillmentConfigInstructionAccountMetas {
+    pub base_spot_market: solana_instruction::AccountMeta,
+    pub quote_spot_market: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub openbook_v2_program: solana_instruction::AccountMeta,
+    pub openbook_v2_market: solana_instruction::AccountMeta,
+    pub drift_signer: solana_instruction::AccountMeta,
+    pub openbook_v2_fulfillment_config: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeOpenbookV2FulfillmentConfig {
+    type ArrangedAccountMetas = InitializeOpenbookV2FulfillmentConfigInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [base_spot_market, quote_spot_market, state, openbook_v2_program, openbook_v2_market, drift_signer, openbook_v2_fulfillment_config, admin, rent, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(
+            InitializeOpenbookV2FulfillmentConfigInstructionAccountMetas {
+                base_spot_market: base_spot_market.clone(),
+                quote_spot_market: quote_spot_market.clone(),
+                state: state.clone(),
+                openbook_v2_program: openbook_v2_program.clone(),
+                openbook_v2_market: openbook_v2_market.clone(),
+                drift_signer: drift_signer.clone(),
+                openbook_v2_fulfillment_config: openbook_v2_fulfillment_config.clone(),
+                admin: admin.clone(),
+                rent: rent.clone(),
+                system_program: system_program.clone(),
+                remaining_accounts: remaining.to_vec(),
+            },
+        )
+    }
+}
+
+pub struct InitializeOpenbookV2FulfillmentConfigInstructionAccountIndices {
+    pub base_spot_market: usize,
+    pub quote_spot_market: usize,
+    pub state: usize,
+    pub openbook_v2_program: usize,
+    pub openbook_v2_market: usize,
+    pub drift_signer: usize,
+    pub openbook_v2_fulfillment_config: usize,
+    pub admin: usize,
+    pub rent: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeOpenbookV2FulfillmentConfig {
+    type ArrangedAccountIndices = InitializeOpenbookV2FulfillmentConfigInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 10 {
+            return None;
+        }
+
+        Some(
+            InitializeOpenbookV2FulfillmentConfigInstructionAccountIndices {
+                base_spot_market: 0,
+                quote_spot_market: 1,
+                state: 2,
+                openbook_v2_program: 3,
+                openbook_v2_market: 4,
+                drift_signer: 5,
+                openbook_v2_fulfillment_config: 6,
+                admin: 7,
+                rent: 8,
+                system_program: 9,
+                remaining_accounts: 10..accounts.len(),
+            },
+        )
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeOpenbookV2FulfillmentConfig {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "base_spot_market",
+            "quote_spot_market",
+            "state",
+            "openbook_v2_program",
+            "openbook_v2_market",
+            "drift_signer",
+            "openbook_v2_fulfillment_config",
+            "admin",
+            "rent",
+            "system_program",
+        ]
+    }
+}
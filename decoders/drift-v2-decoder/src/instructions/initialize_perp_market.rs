@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -41,6 +42,7 @@ pub struct InitializePerpMarketInstructionAccounts {
     pub oracle: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializePerpMarket {
@@ -49,7 +51,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializePerpMarket {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin, state, perp_market, oracle, rent, system_program, _remaining @ ..] = accounts
+        let [admin, state, perp_market, oracle, rent, system_program, remaining @ ..] = accounts
         else {
             return None;
         };
@@ -61,6 +63,85 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializePerpMarket {
             oracle: oracle.pubkey,
             rent: rent.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializePerpMarketInstructionAccountMetas {
+    pub admin: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub perp_market: solana_instruction::AccountMeta,
+    pub oracle: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializePerpMarket {
+    type ArrangedAccountMetas = InitializePerpMarketInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin, state, perp_market, oracle, rent, system_program, remaining @ ..] = accounts
+        else {
+            return None;
+        };
+
+        Some(InitializePerpMarketInstructionAccountMetas {
+            admin: admin.clone(),
+            state: state.clone(),
+            perp_market: perp_market.clone(),
+            oracle: oracle.clone(),
+            rent: rent.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializePerpMarketInstructionAccountIndices {
+    pub admin: usize,
+    pub state: usize,
+    pub perp_market: usize,
+    pub oracle: usize,
+    pub rent: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializePerpMarket {
+    type ArrangedAccountIndices = InitializePerpMarketInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(InitializePerpMarketInstructionAccountIndices {
+            admin: 0,
+            state: 1,
+            perp_market: 2,
+            oracle: 3,
+            rent: 4,
+            system_program: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializePerpMarket {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "admin",
+            "state",
+            "perp_market",
+            "oracle",
+            "rent",
+            "system_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -19,6 +20,7 @@ pub struct InitializePhoenixFulfillmentConfigInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializePhoenixFulfillmentConfig {
@@ -27,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializePhoenixFulfillmentC
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [base_spot_market, quote_spot_market, state, phoenix_program, phoenix_market, drift_signer, phoenix_fulfillment_config, admin, rent, system_program, _remaining @ ..] =
+        let [base_spot_market, quote_spot_market, state, phoenix_program, phoenix_market, drift_signer, phoenix_fulfillment_config, admin, rent, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -44,6 +46,108 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializePhoenixFulfillmentC
             admin: admin.pubkey,
             rent: rent.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializePhoenixFulfillmentConfigInstructionAccountMetas {
+    pub base_spot_market: solana_instruction::AccountMeta,
+    pub quote_spot_market: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub phoenix_program: solana_instruction::AccountMeta,
+    pub phoenix_market: solana_instruction::AccountMeta,
+    pub drift_signer: solana_instruction::AccountMeta,
+    pub phoenix_fulfillment_config: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializePhoenixFulfillmentConfig {
+    type ArrangedAccountMetas = InitializePhoenixFulfillmentConfigInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [base_spot_market, quote_spot_market, state, phoenix_program, phoenix_market, drift_signer, phoenix_fulfillment_config, admin, rent, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializePhoenixFulfillmentConfigInstructionAccountMetas {
+            base_spot_market: base_spot_market.clone(),
+            quote_spot_market: quote_spot_market.clone(),
+            state: state.clone(),
+            phoenix_program: phoenix_program.clone(),
+            phoenix_market: phoenix_market.clone(),
+            drift_signer: drift_signer.clone(),
+            phoenix_fulfillment_config: phoenix_fulfillment_config.clone(),
+            admin: admin.clone(),
+            rent: rent.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializePhoenixFulfillmentConfigInstructionAccountIndices {
+    pub base_spot_market: usize,
+    pub quote_spot_market: usize,
+    pub state: usize,
+    pub phoenix_program: usize,
+    pub phoenix_market: usize,
+    pub drift_signer: usize,
+    pub phoenix_fulfillment_config: usize,
+    pub admin: usize,
+    pub rent: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializePhoenixFulfillmentConfig {
+    type ArrangedAccountIndices = InitializePhoenixFulfillmentConfigInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 10 {
+            return None;
+        }
+
+        Some(
+            InitializePhoenixFulfillmentConfigInstructionAccountIndices {
+                base_spot_market: 0,
+                quote_spot_market: 1,
+                state: 2,
+                phoenix_program: 3,
+                phoenix_market: 4,
+                drift_signer: 5,
+                phoenix_fulfillment_config: 6,
+                admin: 7,
+                rent: 8,
+                system_program: 9,
+                remaining_accounts: 10..accounts.len(),
+            },
+        )
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializePhoenixFulfillmentConfig {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "base_spot_market",
+            "quote_spot_market",
+            "state",
+            "phoenix_program",
+            "phoenix_market",
+            "drift_signer",
+            "phoenix_fulfillment_config",
+            "admin",
+            "rent",
+            "system_program",
+        ]
+    }
+}
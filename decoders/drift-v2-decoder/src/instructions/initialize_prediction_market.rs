@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -10,6 +11,7 @@ pub struct InitializePredictionMarketInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub state: solana_pubkey::Pubkey,
     pub perp_market: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializePredictionMarket {
@@ -18,7 +20,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializePredictionMarket {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin, state, perp_market, _remaining @ ..] = accounts else {
+        let [admin, state, perp_market, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -26,6 +28,65 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializePredictionMarket {
             admin: admin.pubkey,
             state: state.pubkey,
             perp_market: perp_market.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializePredictionMarketInstructionAccountMetas {
+    pub admin: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub perp_market: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializePredictionMarket {
+    type ArrangedAccountMetas = InitializePredictionMarketInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin, state, perp_market, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(InitializePredictionMarketInstructionAccountMetas {
+            admin: admin.clone(),
+            state: state.clone(),
+            perp_market: perp_market.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializePredictionMarketInstructionAccountIndices {
+    pub admin: usize,
+    pub state: usize,
+    pub perp_market: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializePredictionMarket {
+    type ArrangedAccountIndices = InitializePredictionMarketInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(InitializePredictionMarketInstructionAccountIndices {
+            admin: 0,
+            state: 1,
+            perp_market: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializePredictionMarket {
+    fn account_names() -> &'static [&'static str] {
+        &["admin", "state", "perp_market"]
+    }
+}
@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -16,6 +17,7 @@ pub struct InitializePrelaunchOracleInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializePrelaunchOracle {
@@ -24,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializePrelaunchOracle {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin, prelaunch_oracle, state, rent, system_program, _remaining @ ..] = accounts
+        let [admin, prelaunch_oracle, state, rent, system_program, remaining @ ..] = accounts
         else {
             return None;
         };
@@ -35,6 +37,80 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializePrelaunchOracle {
             state: state.pubkey,
             rent: rent.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializePrelaunchOracleInstructionAccountMetas {
+    pub admin: solana_instruction::AccountMeta,
+    pub prelaunch_oracle: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializePrelaunchOracle {
+    type ArrangedAccountMetas = InitializePrelaunchOracleInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin, prelaunch_oracle, state, rent, system_program, remaining @ ..] = accounts
+        else {
+            return None;
+        };
+
+        Some(InitializePrelaunchOracleInstructionAccountMetas {
+            admin: admin.clone(),
+            prelaunch_oracle: prelaunch_oracle.clone(),
+            state: state.clone(),
+            rent: rent.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializePrelaunchOracleInstructionAccountIndices {
+    pub admin: usize,
+    pub prelaunch_oracle: usize,
+    pub state: usize,
+    pub rent: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializePrelaunchOracle {
+    type ArrangedAccountIndices = InitializePrelaunchOracleInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(InitializePrelaunchOracleInstructionAccountIndices {
+            admin: 0,
+            prelaunch_oracle: 1,
+            state: 2,
+            rent: 3,
+            system_program: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializePrelaunchOracle {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "admin",
+            "prelaunch_oracle",
+            "state",
+            "rent",
+            "system_program",
+        ]
+    }
+}
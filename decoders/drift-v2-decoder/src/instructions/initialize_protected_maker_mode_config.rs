@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct InitializeProtectedMakerModeConfigInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeProtectedMakerModeConfig {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeProtectedMakerModeC
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin, protected_maker_mode_config, state, rent, system_program, _remaining @ ..] =
+        let [admin, protected_maker_mode_config, state, rent, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -34,6 +36,83 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeProtectedMakerModeC
             state: state.pubkey,
             rent: rent.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeProtectedMakerModeConfigInstructionAccountMetas {
+    pub admin: solana_instruction::AccountMeta,
+    pub protected_maker_mode_config: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeProtectedMakerModeConfig {
+    type ArrangedAccountMetas = InitializeProtectedMakerModeConfigInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin, protected_maker_mode_config, state, rent, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeProtectedMakerModeConfigInstructionAccountMetas {
+            admin: admin.clone(),
+            protected_maker_mode_config: protected_maker_mode_config.clone(),
+            state: state.clone(),
+            rent: rent.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeProtectedMakerModeConfigInstructionAccountIndices {
+    pub admin: usize,
+    pub protected_maker_mode_config: usize,
+    pub state: usize,
+    pub rent: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeProtectedMakerModeConfig {
+    type ArrangedAccountIndices = InitializeProtectedMakerModeConfigInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(
+            InitializeProtectedMakerModeConfigInstructionAccountIndices {
+                admin: 0,
+                protected_maker_mode_config: 1,
+                state: 2,
+                rent: 3,
+                system_program: 4,
+                remaining_accounts: 5..accounts.len(),
+            },
+        )
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeProtectedMakerModeConfig {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "admin",
+            "protected_maker_mode_config",
+            "state",
+            "rent",
+            "system_program",
+        ]
+    }
+}
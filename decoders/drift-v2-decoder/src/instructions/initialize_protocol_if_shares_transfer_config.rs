@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +13,7 @@ pub struct InitializeProtocolIfSharesTransferConfigInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeProtocolIfSharesTransferConfig {
@@ -20,7 +22,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeProtocolIfSharesTra
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin, protocol_if_shares_transfer_config, state, rent, system_program, _remaining @ ..] =
+        let [admin, protocol_if_shares_transfer_config, state, rent, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -33,7 +35,86 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeProtocolIfSharesTra
                 state: state.pubkey,
                 rent: rent.pubkey,
                 system_program: system_program.pubkey,
+                remaining_accounts: remaining.to_vec(),
             },
         )
     }
 }
+
+pub struct InitializeProtocolIfSharesTransferConfigInstructionAccountMetas {
+    pub admin: solana_instruction::AccountMeta,
+    pub protocol_if_shares_transfer_config: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeProtocolIfSharesTransferConfig {
+    type ArrangedAccountMetas = InitializeProtocolIfSharesTransferConfigInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin, protocol_if_shares_transfer_config, state, rent, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(
+            InitializeProtocolIfSharesTransferConfigInstructionAccountMetas {
+                admin: admin.clone(),
+                protocol_if_shares_transfer_config: protocol_if_shares_transfer_config.clone(),
+                state: state.clone(),
+                rent: rent.clone(),
+                system_program: system_program.clone(),
+                remaining_accounts: remaining.to_vec(),
+            },
+        )
+    }
+}
+
+pub struct InitializeProtocolIfSharesTransferConfigInstructionAccountIndices {
+    pub admin: usize,
+    pub protocol_if_shares_transfer_config: usize,
+    pub state: usize,
+    pub rent: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeProtocolIfSharesTransferConfig {
+    type ArrangedAccountIndices = InitializeProtocolIfSharesTransferConfigInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(
+            InitializeProtocolIfSharesTransferConfigInstructionAccountIndices {
+                admin: 0,
+                protocol_if_shares_transfer_config: 1,
+                state: 2,
+                rent: 3,
+                system_program: 4,
+                remaining_accounts: 5..accounts.len(),
+            },
+        )
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeProtocolIfSharesTransferConfig {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "admin",
+            "protocol_if_shares_transfer_config",
+            "state",
+            "rent",
+            "system_program",
+        ]
+    }
+}
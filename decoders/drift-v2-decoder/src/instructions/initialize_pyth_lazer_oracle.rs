@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct InitializePythLazerOracleInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializePythLazerOracle {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializePythLazerOracle {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin, lazer_oracle, state, rent, system_program, _remaining @ ..] = accounts else {
+        let [admin, lazer_oracle, state, rent, system_program, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -32,6 +34,73 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializePythLazerOracle {
             state: state.pubkey,
             rent: rent.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializePythLazerOracleInstructionAccountMetas {
+    pub admin: solana_instruction::AccountMeta,
+    pub lazer_oracle: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializePythLazerOracle {
+    type ArrangedAccountMetas = InitializePythLazerOracleInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin, lazer_oracle, state, rent, system_program, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(InitializePythLazerOracleInstructionAccountMetas {
+            admin: admin.clone(),
+            lazer_oracle: lazer_oracle.clone(),
+            state: state.clone(),
+            rent: rent.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializePythLazerOracleInstructionAccountIndices {
+    pub admin: usize,
+    pub lazer_oracle: usize,
+    pub state: usize,
+    pub rent: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializePythLazerOracle {
+    type ArrangedAccountIndices = InitializePythLazerOracleInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(InitializePythLazerOracleInstructionAccountIndices {
+            admin: 0,
+            lazer_oracle: 1,
+            state: 2,
+            rent: 3,
+            system_program: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializePythLazerOracle {
+    fn account_names() -> &'static [&'static str] {
+        &["admin", "lazer_oracle", "state", "rent", "system_program"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct InitializePythPullOracleInstructionAccounts {
     pub price_feed: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub state: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializePythPullOracle {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializePythPullOracle {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin, pyth_solana_receiver, price_feed, system_program, state, _remaining @ ..] =
+        let [admin, pyth_solana_receiver, price_feed, system_program, state, remaining @ ..] =
             accounts
         else {
             return None;
@@ -34,6 +36,81 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializePythPullOracle {
             price_feed: price_feed.pubkey,
             system_program: system_program.pubkey,
             state: state.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializePythPullOracleInstructionAccountMetas {
+    pub admin: solana_instruction::AccountMeta,
+    pub pyth_solana_receiver: solana_instruction::AccountMeta,
+    pub price_feed: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializePythPullOracle {
+    type ArrangedAccountMetas = InitializePythPullOracleInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin, pyth_solana_receiver, price_feed, system_program, state, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializePythPullOracleInstructionAccountMetas {
+            admin: admin.clone(),
+            pyth_solana_receiver: pyth_solana_receiver.clone(),
+            price_feed: price_feed.clone(),
+            system_program: system_program.clone(),
+            state: state.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializePythPullOracleInstructionAccountIndices {
+    pub admin: usize,
+    pub pyth_solana_receiver: usize,
+    pub price_feed: usize,
+    pub system_program: usize,
+    pub state: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializePythPullOracle {
+    type ArrangedAccountIndices = InitializePythPullOracleInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(InitializePythPullOracleInstructionAccountIndices {
+            admin: 0,
+            pyth_solana_receiver: 1,
+            price_feed: 2,
+            system_program: 3,
+            state: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializePythPullOracle {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "admin",
+            "pyth_solana_receiver",
+            "price_feed",
+            "system_program",
+            "state",
+        ]
+    }
+}
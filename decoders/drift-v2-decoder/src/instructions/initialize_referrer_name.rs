@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -16,6 +17,7 @@ pub struct InitializeReferrerNameInstructionAccounts {
     pub payer: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeReferrerName {
@@ -24,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeReferrerName {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [referrer_name, user, user_stats, authority, payer, rent, system_program, _remaining @ ..] =
+        let [referrer_name, user, user_stats, authority, payer, rent, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -38,6 +40,91 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeReferrerName {
             payer: payer.pubkey,
             rent: rent.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeReferrerNameInstructionAccountMetas {
+    pub referrer_name: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub user_stats: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeReferrerName {
+    type ArrangedAccountMetas = InitializeReferrerNameInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [referrer_name, user, user_stats, authority, payer, rent, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeReferrerNameInstructionAccountMetas {
+            referrer_name: referrer_name.clone(),
+            user: user.clone(),
+            user_stats: user_stats.clone(),
+            authority: authority.clone(),
+            payer: payer.clone(),
+            rent: rent.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeReferrerNameInstructionAccountIndices {
+    pub referrer_name: usize,
+    pub user: usize,
+    pub user_stats: usize,
+    pub authority: usize,
+    pub payer: usize,
+    pub rent: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeReferrerName {
+    type ArrangedAccountIndices = InitializeReferrerNameInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 7 {
+            return None;
+        }
+
+        Some(InitializeReferrerNameInstructionAccountIndices {
+            referrer_name: 0,
+            user: 1,
+            user_stats: 2,
+            authority: 3,
+            payer: 4,
+            rent: 5,
+            system_program: 6,
+            remaining_accounts: 7..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeReferrerName {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "referrer_name",
+            "user",
+            "user_stats",
+            "authority",
+            "payer",
+            "rent",
+            "system_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -20,6 +21,7 @@ pub struct InitializeSerumFulfillmentConfigInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeSerumFulfillmentConfig {
@@ -28,7 +30,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeSerumFulfillmentCon
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [base_spot_market, quote_spot_market, state, serum_program, serum_market, serum_open_orders, drift_signer, serum_fulfillment_config, admin, rent, system_program, _remaining @ ..] =
+        let [base_spot_market, quote_spot_market, state, serum_program, serum_market, serum_open_orders, drift_signer, serum_fulfillment_config, admin, rent, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -46,6 +48,111 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeSerumFulfillmentCon
             admin: admin.pubkey,
             rent: rent.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeSerumFulfillmentConfigInstructionAccountMetas {
+    pub base_spot_market: solana_instruction::AccountMeta,
+    pub quote_spot_market: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub serum_program: solana_instruction::AccountMeta,
+    pub serum_market: solana_instruction::AccountMeta,
+    pub serum_open_orders: solana_instruction::AccountMeta,
+    pub drift_signer: solana_instruction::AccountMeta,
+    pub serum_fulfillment_config: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeSerumFulfillmentConfig {
+    type ArrangedAccountMetas = InitializeSerumFulfillmentConfigInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [base_spot_market, quote_spot_market, state, serum_program, serum_market, serum_open_orders, drift_signer, serum_fulfillment_config, admin, rent, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeSerumFulfillmentConfigInstructionAccountMetas {
+            base_spot_market: base_spot_market.clone(),
+            quote_spot_market: quote_spot_market.clone(),
+            state: state.clone(),
+            serum_program: serum_program.clone(),
+            serum_market: serum_market.clone(),
+            serum_open_orders: serum_open_orders.clone(),
+            drift_signer: drift_signer.clone(),
+            serum_fulfillment_config: serum_fulfillment_config.clone(),
+            admin: admin.clone(),
+            rent: rent.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeSerumFulfillmentConfigInstructionAccountIndices {
+    pub base_spot_market: usize,
+    pub quote_spot_market: usize,
+    pub state: usize,
+    pub serum_program: usize,
+    pub serum_market: usize,
+    pub serum_open_orders: usize,
+    pub drift_signer: usize,
+    pub serum_fulfillment_config: usize,
+    pub admin: usize,
+    pub rent: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeSerumFulfillmentConfig {
+    type ArrangedAccountIndices = InitializeSerumFulfillmentConfigInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 11 {
+            return None;
+        }
+
+        Some(InitializeSerumFulfillmentConfigInstructionAccountIndices {
+            base_spot_market: 0,
+            quote_spot_market: 1,
+            state: 2,
+            serum_program: 3,
+            serum_market: 4,
+            serum_open_orders: 5,
+            drift_signer: 6,
+            serum_fulfillment_config: 7,
+            admin: 8,
+            rent: 9,
+            system_program: 10,
+            remaining_accounts: 11..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeSerumFulfillmentConfig {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "base_spot_market",
+            "quote_spot_market",
+            "state",
+            "serum_program",
+            "serum_market",
+            "serum_open_orders",
+            "drift_signer",
+            "serum_fulfillment_config",
+            "admin",
+            "rent",
+            "system_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct InitializeSignedMsgUserOrdersInstructionAccounts {
     pub payer: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeSignedMsgUserOrders {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeSignedMsgUserOrders
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [signed_msg_user_orders, authority, payer, rent, system_program, _remaining @ ..] =
+        let [signed_msg_user_orders, authority, payer, rent, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -34,6 +36,81 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeSignedMsgUserOrders
             payer: payer.pubkey,
             rent: rent.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeSignedMsgUserOrdersInstructionAccountMetas {
+    pub signed_msg_user_orders: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeSignedMsgUserOrders {
+    type ArrangedAccountMetas = InitializeSignedMsgUserOrdersInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [signed_msg_user_orders, authority, payer, rent, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeSignedMsgUserOrdersInstructionAccountMetas {
+            signed_msg_user_orders: signed_msg_user_orders.clone(),
+            authority: authority.clone(),
+            payer: payer.clone(),
+            rent: rent.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeSignedMsgUserOrdersInstructionAccountIndices {
+    pub signed_msg_user_orders: usize,
+    pub authority: usize,
+    pub payer: usize,
+    pub rent: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeSignedMsgUserOrders {
+    type ArrangedAccountIndices = InitializeSignedMsgUserOrdersInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(InitializeSignedMsgUserOrdersInstructionAccountIndices {
+            signed_msg_user_orders: 0,
+            authority: 1,
+            payer: 2,
+            rent: 3,
+            system_program: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeSignedMsgUserOrders {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "signed_msg_user_orders",
+            "authority",
+            "payer",
+            "rent",
+            "system_program",
+        ]
+    }
+}
@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -40,6 +41,7 @@ pub struct InitializeSpotMarketInstructionAccounts {
     pub rent: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeSpotMarket {
@@ -48,7 +50,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeSpotMarket {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [spot_market, spot_market_mint, spot_market_vault, insurance_fund_vault, drift_signer, state, oracle, admin, rent, system_program, token_program, _remaining @ ..] =
+        let [spot_market, spot_market_mint, spot_market_vault, insurance_fund_vault, drift_signer, state, oracle, admin, rent, system_program, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -66,6 +68,111 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeSpotMarket {
             rent: rent.pubkey,
             system_program: system_program.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeSpotMarketInstructionAccountMetas {
+    pub spot_market: solana_instruction::AccountMeta,
+    pub spot_market_mint: solana_instruction::AccountMeta,
+    pub spot_market_vault: solana_instruction::AccountMeta,
+    pub insurance_fund_vault: solana_instruction::AccountMeta,
+    pub drift_signer: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub oracle: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeSpotMarket {
+    type ArrangedAccountMetas = InitializeSpotMarketInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [spot_market, spot_market_mint, spot_market_vault, insurance_fund_vault, drift_signer, state, oracle, admin, rent, system_program, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeSpotMarketInstructionAccountMetas {
+            spot_market: spot_market.clone(),
+            spot_market_mint: spot_market_mint.clone(),
+            spot_market_vault: spot_market_vault.clone(),
+            insurance_fund_vault: insurance_fund_vault.clone(),
+            drift_signer: drift_signer.clone(),
+            state: state.clone(),
+            oracle: oracle.clone(),
+            admin: admin.clone(),
+            rent: rent.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeSpotMarketInstructionAccountIndices {
+    pub spot_market: usize,
+    pub spot_market_mint: usize,
+    pub spot_market_vault: usize,
+    pub insurance_fund_vault: usize,
+    pub drift_signer: usize,
+    pub state: usize,
+    pub oracle: usize,
+    pub admin: usize,
+    pub rent: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeSpotMarket {
+    type ArrangedAccountIndices = InitializeSpotMarketInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 11 {
+            return None;
+        }
+
+        Some(InitializeSpotMarketInstructionAccountIndices {
+            spot_market: 0,
+            spot_market_mint: 1,
+            spot_market_vault: 2,
+            insurance_fund_vault: 3,
+            drift_signer: 4,
+            state: 5,
+            oracle: 6,
+            admin: 7,
+            rent: 8,
+            system_program: 9,
+            token_program: 10,
+            remaining_accounts: 11..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeSpotMarket {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "spot_market",
+            "spot_market_mint",
+            "spot_market_vault",
+            "insurance_fund_vault",
+            "drift_signer",
+            "state",
+            "oracle",
+            "admin",
+            "rent",
+            "system_program",
+            "token_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -17,6 +18,7 @@ pub struct InitializeUserInstructionAccounts {
     pub payer: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeUser {
@@ -25,7 +27,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeUser {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [user, user_stats, state, authority, payer, rent, system_program, _remaining @ ..] =
+        let [user, user_stats, state, authority, payer, rent, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -39,6 +41,91 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeUser {
             payer: payer.pubkey,
             rent: rent.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeUserInstructionAccountMetas {
+    pub user: solana_instruction::AccountMeta,
+    pub user_stats: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeUser {
+    type ArrangedAccountMetas = InitializeUserInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [user, user_stats, state, authority, payer, rent, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeUserInstructionAccountMetas {
+            user: user.clone(),
+            user_stats: user_stats.clone(),
+            state: state.clone(),
+            authority: authority.clone(),
+            payer: payer.clone(),
+            rent: rent.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeUserInstructionAccountIndices {
+    pub user: usize,
+    pub user_stats: usize,
+    pub state: usize,
+    pub authority: usize,
+    pub payer: usize,
+    pub rent: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeUser {
+    type ArrangedAccountIndices = InitializeUserInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 7 {
+            return None;
+        }
+
+        Some(InitializeUserInstructionAccountIndices {
+            user: 0,
+            user_stats: 1,
+            state: 2,
+            authority: 3,
+            payer: 4,
+            rent: 5,
+            system_program: 6,
+            remaining_accounts: 7..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeUser {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "user",
+            "user_stats",
+            "state",
+            "authority",
+            "payer",
+            "rent",
+            "system_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +14,7 @@ pub struct InitializeUserStatsInstructionAccounts {
     pub payer: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeUserStats {
@@ -21,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeUserStats {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [user_stats, state, authority, payer, rent, system_program, _remaining @ ..] = accounts
+        let [user_stats, state, authority, payer, rent, system_program, remaining @ ..] = accounts
         else {
             return None;
         };
@@ -33,6 +35,85 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeUserStats {
             payer: payer.pubkey,
             rent: rent.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeUserStatsInstructionAccountMetas {
+    pub user_stats: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeUserStats {
+    type ArrangedAccountMetas = InitializeUserStatsInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [user_stats, state, authority, payer, rent, system_program, remaining @ ..] = accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeUserStatsInstructionAccountMetas {
+            user_stats: user_stats.clone(),
+            state: state.clone(),
+            authority: authority.clone(),
+            payer: payer.clone(),
+            rent: rent.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeUserStatsInstructionAccountIndices {
+    pub user_stats: usize,
+    pub state: usize,
+    pub authority: usize,
+    pub payer: usize,
+    pub rent: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeUserStats {
+    type ArrangedAccountIndices = InitializeUserStatsInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(InitializeUserStatsInstructionAccountIndices {
+            user_stats: 0,
+            state: 1,
+            authority: 2,
+            payer: 3,
+            rent: 4,
+            system_program: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeUserStats {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "user_stats",
+            "state",
+            "authority",
+            "payer",
+            "rent",
+            "system_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -18,6 +19,7 @@ pub struct LiquidateSpotInstructionAccounts {
     pub liquidator_stats: solana_pubkey::Pubkey,
     pub user: solana_pubkey::Pubkey,
     pub user_stats: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for LiquidateSpot {
@@ -26,7 +28,7 @@ impl carbon_core::deserialize::ArrangeAccounts for LiquidateSpot {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, authority, liquidator, liquidator_stats, user, user_stats, _remaining @ ..] =
+        let [state, authority, liquidator, liquidator_stats, user, user_stats, remaining @ ..] =
             accounts
         else {
             return None;
@@ -39,6 +41,86 @@ impl carbon_core::deserialize::ArrangeAccounts for LiquidateSpot {
             liquidator_stats: liquidator_stats.pubkey,
             user: user.pubkey,
             user_stats: user_stats.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct LiquidateSpotInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub liquidator: solana_instruction::AccountMeta,
+    pub liquidator_stats: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub user_stats: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for LiquidateSpot {
+    type ArrangedAccountMetas = LiquidateSpotInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, authority, liquidator, liquidator_stats, user, user_stats, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(LiquidateSpotInstructionAccountMetas {
+            state: state.clone(),
+            authority: authority.clone(),
+            liquidator: liquidator.clone(),
+            liquidator_stats: liquidator_stats.clone(),
+            user: user.clone(),
+            user_stats: user_stats.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct LiquidateSpotInstructionAccountIndices {
+    pub state: usize,
+    pub authority: usize,
+    pub liquidator: usize,
+    pub liquidator_stats: usize,
+    pub user: usize,
+    pub user_stats: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for LiquidateSpot {
+    type ArrangedAccountIndices = LiquidateSpotInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(LiquidateSpotInstructionAccountIndices {
+            state: 0,
+            authority: 1,
+            liquidator: 2,
+            liquidator_stats: 3,
+            user: 4,
+            user_stats: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for LiquidateSpot {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "authority",
+            "liquidator",
+            "liquidator_stats",
+            "user",
+            "user_stats",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -23,6 +24,7 @@ pub struct LiquidateSpotWithSwapEndInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub drift_signer: solana_pubkey::Pubkey,
     pub instructions: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for LiquidateSpotWithSwapEnd {
@@ -31,7 +33,7 @@ impl carbon_core::deserialize::ArrangeAccounts for LiquidateSpotWithSwapEnd {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, authority, liquidator, liquidator_stats, user, user_stats, liability_spot_market_vault, asset_spot_market_vault, liability_token_account, asset_token_account, token_program, drift_signer, instructions, _remaining @ ..] =
+        let [state, authority, liquidator, liquidator_stats, user, user_stats, liability_spot_market_vault, asset_spot_market_vault, liability_token_account, asset_token_account, token_program, drift_signer, instructions, remaining @ ..] =
             accounts
         else {
             return None;
@@ -51,6 +53,121 @@ impl carbon_core::deserialize::ArrangeAccounts for LiquidateSpotWithSwapEnd {
             token_program: token_program.pubkey,
             drift_signer: drift_signer.pubkey,
             instructions: instructions.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct LiquidateSpotWithSwapEndInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub liquidator: solana_instruction::AccountMeta,
+    pub liquidator_stats: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub user_stats: solana_instruction::AccountMeta,
+    pub liability_spot_market_vault: solana_instruction::AccountMeta,
+    pub asset_spot_market_vault: solana_instruction::AccountMeta,
+    pub liability_token_account: solana_instruction::AccountMeta,
+    pub asset_token_account: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub drift_signer: solana_instruction::AccountMeta,
+    pub instructions: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for LiquidateSpotWithSwapEnd {
+    type ArrangedAccountMetas = LiquidateSpotWithSwapEndInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, authority, liquidator, liquidator_stats, user, user_stats, liability_spot_market_vault, asset_spot_market_vault, liability_token_account, asset_token_account, token_program, drift_signer, instructions, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(LiquidateSpotWithSwapEndInstructionAccountMetas {
+            state: state.clone(),
+            authority: authority.clone(),
+            liquidator: liquidator.clone(),
+            liquidator_stats: liquidator_stats.clone(),
+            user: user.clone(),
+            user_stats: user_stats.clone(),
+            liability_spot_market_vault: liability_spot_market_vault.clone(),
+            asset_spot_market_vault: asset_spot_market_vault.clone(),
+            liability_token_account: liability_token_account.clone(),
+            asset_token_account: asset_token_account.clone(),
+            token_program: token_program.clone(),
+            drift_signer: drift_signer.clone(),
+            instructions: instructions.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct LiquidateSpotWithSwapEndInstructionAccountIndices {
+    pub state: usize,
+    pub authority: usize,
+    pub liquidator: usize,
+    pub liquidator_stats: usize,
+    pub user: usize,
+    pub user_stats: usize,
+    pub liability_spot_market_vault: usize,
+    pub asset_spot_market_vault: usize,
+    pub liability_token_account: usize,
+    pub asset_token_account: usize,
+    pub token_program: usize,
+    pub drift_signer: usize,
+    pub instructions: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for LiquidateSpotWithSwapEnd {
+    type ArrangedAccountIndices = LiquidateSpotWithSwapEndInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 13 {
+            return None;
+        }
+
+        Some(LiquidateSpotWithSwapEndInstructionAccountIndices {
+            state: 0,
+            authority: 1,
+            liquidator: 2,
+            liquidator_stats: 3,
+            user: 4,
+            user_stats: 5,
+            liability_spot_market_vault: 6,
+            asset_spot_market_vault: 7,
+            liability_token_account: 8,
+            asset_token_account: 9,
+            token_program: 10,
+            drift_signer: 11,
+            instructions: 12,
+            remaining_accounts: 13..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for LiquidateSpotWithSwapEnd {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "authority",
+            "liquidator",
+            "liquidator_stats",
+            "user",
+            "user_stats",
+            "liability_spot_market_vault",
+            "asset_spot_market_vault",
+            "liability_token_account",
+            "asset_token_account",
+            "token_program",
+            "drift_signer",
+            "instructions",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -10,6 +11,7 @@ pub struct LogUserBalancesInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
     pub user: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for LogUserBalances {
@@ -18,7 +20,7 @@ impl carbon_core::deserialize::ArrangeAccounts for LogUserBalances {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, authority, user, _remaining @ ..] = accounts else {
+        let [state, authority, user, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -26,6 +28,65 @@ impl carbon_core::deserialize::ArrangeAccounts for LogUserBalances {
             state: state.pubkey,
             authority: authority.pubkey,
             user: user.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct LogUserBalancesInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for LogUserBalances {
+    type ArrangedAccountMetas = LogUserBalancesInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, authority, user, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(LogUserBalancesInstructionAccountMetas {
+            state: state.clone(),
+            authority: authority.clone(),
+            user: user.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct LogUserBalancesInstructionAccountIndices {
+    pub state: usize,
+    pub authority: usize,
+    pub user: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for LogUserBalances {
+    type ArrangedAccountIndices = LogUserBalancesInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(LogUserBalancesInstructionAccountIndices {
+            state: 0,
+            authority: 1,
+            user: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for LogUserBalances {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "authority", "user"]
+    }
+}
@@ -449,15 +449,29 @@ pub enum DriftInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for DriftDecoder {
     type InstructionType = DriftInstruction;
 
-    fn decode_instruction(
+    fn decoder_name(&self) -> &'static str {
+        "drift"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[super::PROGRAM_ID]
+    }
+
+    fn min_accounts(&self) -> usize {
+        1
+    }
+
+    fn try_decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
-    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+    ) -> carbon_core::error::CarbonResult<
+        Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>>,
+    > {
         if !instruction.program_id.eq(&PROGRAM_ID) {
-            return None;
+            return Ok(None);
         }
 
-        carbon_core::try_decode_instructions!(instruction,
+        Ok(carbon_core::try_decode_instructions!(instruction,
             DriftInstruction::InitializeUser => initialize_user::InitializeUser,
             DriftInstruction::InitializeUserStats => initialize_user_stats::InitializeUserStats,
             DriftInstruction::InitializeSignedMsgUserOrders => initialize_signed_msg_user_orders::InitializeSignedMsgUserOrders,
@@ -673,6 +687,830 @@ impl carbon_core::instruction::InstructionDecoder<'_> for DriftDecoder {
             DriftInstruction::DeleteUserRecordEvent => delete_user_record_event::DeleteUserRecordEvent,
             DriftInstruction::FuelSweepRecordEvent => fuel_sweep_record_event::FuelSweepRecordEvent,
             DriftInstruction::FuelSeasonRecordEvent => fuel_season_record_event::FuelSeasonRecordEvent,
+        ))
+    }
+
+    fn matches_discriminator(&self, data: &[u8]) -> bool {
+        carbon_core::matches_discriminators!(data,
+            initialize_user::InitializeUser,
+            initialize_user_stats::InitializeUserStats,
+            initialize_signed_msg_user_orders::InitializeSignedMsgUserOrders,
+            resize_signed_msg_user_orders::ResizeSignedMsgUserOrders,
+            initialize_fuel_overflow::InitializeFuelOverflow,
+            sweep_fuel::SweepFuel,
+            reset_fuel_season::ResetFuelSeason,
+            initialize_referrer_name::InitializeReferrerName,
+            deposit::Deposit,
+            withdraw::Withdraw,
+            transfer_deposit::TransferDeposit,
+            transfer_pools::TransferPools,
+            place_perp_order::PlacePerpOrder,
+            cancel_order::CancelOrder,
+            cancel_order_by_user_id::CancelOrderByUserId,
+            cancel_orders::CancelOrders,
+            cancel_orders_by_ids::CancelOrdersByIds,
+            modify_order::ModifyOrder,
+            modify_order_by_user_id::ModifyOrderByUserId,
+            place_and_take_perp_order::PlaceAndTakePerpOrder,
+            place_and_make_perp_order::PlaceAndMakePerpOrder,
+            place_and_make_signed_msg_perp_order::PlaceAndMakeSignedMsgPerpOrder,
+            place_signed_msg_taker_order::PlaceSignedMsgTakerOrder,
+            place_spot_order::PlaceSpotOrder,
+            place_and_take_spot_order::PlaceAndTakeSpotOrder,
+            place_and_make_spot_order::PlaceAndMakeSpotOrder,
+            place_orders::PlaceOrders,
+            begin_swap::BeginSwap,
+            end_swap::EndSwap,
+            add_perp_lp_shares::AddPerpLpShares,
+            remove_perp_lp_shares::RemovePerpLpShares,
+            remove_perp_lp_shares_in_expiring_market::RemovePerpLpSharesInExpiringMarket,
+            update_user_name::UpdateUserName,
+            update_user_custom_margin_ratio::UpdateUserCustomMarginRatio,
+            update_user_margin_trading_enabled::UpdateUserMarginTradingEnabled,
+            update_user_pool_id::UpdateUserPoolId,
+            update_user_delegate::UpdateUserDelegate,
+            update_user_reduce_only::UpdateUserReduceOnly,
+            update_user_advanced_lp::UpdateUserAdvancedLp,
+            update_user_protected_maker_orders::UpdateUserProtectedMakerOrders,
+            delete_user::DeleteUser,
+            force_delete_user::ForceDeleteUser,
+            delete_signed_msg_user_orders::DeleteSignedMsgUserOrders,
+            reclaim_rent::ReclaimRent,
+            enable_user_high_leverage_mode::EnableUserHighLeverageMode,
+            fill_perp_order::FillPerpOrder,
+            revert_fill::RevertFill,
+            fill_spot_order::FillSpotOrder,
+            trigger_order::TriggerOrder,
+            force_cancel_orders::ForceCancelOrders,
+            update_user_idle::UpdateUserIdle,
+            log_user_balances::LogUserBalances,
+            disable_user_high_leverage_mode::DisableUserHighLeverageMode,
+            update_user_fuel_bonus::UpdateUserFuelBonus,
+            update_user_stats_referrer_status::UpdateUserStatsReferrerStatus,
+            update_user_open_orders_count::UpdateUserOpenOrdersCount,
+            admin_disable_update_perp_bid_ask_twap::AdminDisableUpdatePerpBidAskTwap,
+            settle_pnl::SettlePnl,
+            settle_multiple_pnls::SettleMultiplePnls,
+            settle_funding_payment::SettleFundingPayment,
+            settle_lp::SettleLp,
+            settle_expired_market::SettleExpiredMarket,
+            liquidate_perp::LiquidatePerp,
+            liquidate_perp_with_fill::LiquidatePerpWithFill,
+            liquidate_spot::LiquidateSpot,
+            liquidate_spot_with_swap_begin::LiquidateSpotWithSwapBegin,
+            liquidate_spot_with_swap_end::LiquidateSpotWithSwapEnd,
+            liquidate_borrow_for_perp_pnl::LiquidateBorrowForPerpPnl,
+            liquidate_perp_pnl_for_deposit::LiquidatePerpPnlForDeposit,
+            set_user_status_to_being_liquidated::SetUserStatusToBeingLiquidated,
+            resolve_perp_pnl_deficit::ResolvePerpPnlDeficit,
+            resolve_perp_bankruptcy::ResolvePerpBankruptcy,
+            resolve_spot_bankruptcy::ResolveSpotBankruptcy,
+            settle_revenue_to_insurance_fund::SettleRevenueToInsuranceFund,
+            update_funding_rate::UpdateFundingRate,
+            update_prelaunch_oracle::UpdatePrelaunchOracle,
+            update_perp_bid_ask_twap::UpdatePerpBidAskTwap,
+            update_spot_market_cumulative_interest::UpdateSpotMarketCumulativeInterest,
+            update_amms::UpdateAmms,
+            update_spot_market_expiry::UpdateSpotMarketExpiry,
+            update_user_quote_asset_insurance_stake::UpdateUserQuoteAssetInsuranceStake,
+            update_user_gov_token_insurance_stake::UpdateUserGovTokenInsuranceStake,
+            update_user_gov_token_insurance_stake_devnet::UpdateUserGovTokenInsuranceStakeDevnet,
+            initialize_insurance_fund_stake::InitializeInsuranceFundStake,
+            add_insurance_fund_stake::AddInsuranceFundStake,
+            request_remove_insurance_fund_stake::RequestRemoveInsuranceFundStake,
+            cancel_request_remove_insurance_fund_stake::CancelRequestRemoveInsuranceFundStake,
+            remove_insurance_fund_stake::RemoveInsuranceFundStake,
+            transfer_protocol_if_shares::TransferProtocolIfShares,
+            update_pyth_pull_oracle::UpdatePythPullOracle,
+            post_pyth_pull_oracle_update_atomic::PostPythPullOracleUpdateAtomic,
+            post_multi_pyth_pull_oracle_updates_atomic::PostMultiPythPullOracleUpdatesAtomic,
+            pause_spot_market_deposit_withdraw::PauseSpotMarketDepositWithdraw,
+            initialize::Initialize,
+            initialize_spot_market::InitializeSpotMarket,
+            delete_initialized_spot_market::DeleteInitializedSpotMarket,
+            initialize_serum_fulfillment_config::InitializeSerumFulfillmentConfig,
+            update_serum_fulfillment_config_status::UpdateSerumFulfillmentConfigStatus,
+            initialize_openbook_v2_fulfillment_config::InitializeOpenbookV2FulfillmentConfig,
+            openbook_v2_fulfillment_config_status::OpenbookV2FulfillmentConfigStatus,
+            initialize_phoenix_fulfillment_config::InitializePhoenixFulfillmentConfig,
+            phoenix_fulfillment_config_status::PhoenixFulfillmentConfigStatus,
+            update_serum_vault::UpdateSerumVault,
+            initialize_perp_market::InitializePerpMarket,
+            initialize_prediction_market::InitializePredictionMarket,
+            delete_initialized_perp_market::DeleteInitializedPerpMarket,
+            move_amm_price::MoveAmmPrice,
+            recenter_perp_market_amm::RecenterPerpMarketAmm,
+            update_perp_market_amm_summary_stats::UpdatePerpMarketAmmSummaryStats,
+            update_perp_market_expiry::UpdatePerpMarketExpiry,
+            settle_expired_market_pools_to_revenue_pool::SettleExpiredMarketPoolsToRevenuePool,
+            deposit_into_perp_market_fee_pool::DepositIntoPerpMarketFeePool,
+            deposit_into_spot_market_vault::DepositIntoSpotMarketVault,
+            deposit_into_spot_market_revenue_pool::DepositIntoSpotMarketRevenuePool,
+            repeg_amm_curve::RepegAmmCurve,
+            update_perp_market_amm_oracle_twap::UpdatePerpMarketAmmOracleTwap,
+            reset_perp_market_amm_oracle_twap::ResetPerpMarketAmmOracleTwap,
+            update_k::UpdateK,
+            update_perp_market_margin_ratio::UpdatePerpMarketMarginRatio,
+            update_perp_market_high_leverage_margin_ratio::UpdatePerpMarketHighLeverageMarginRatio,
+            update_perp_market_funding_period::UpdatePerpMarketFundingPeriod,
+            update_perp_market_max_imbalances::UpdatePerpMarketMaxImbalances,
+            update_perp_market_liquidation_fee::UpdatePerpMarketLiquidationFee,
+            update_insurance_fund_unstaking_period::UpdateInsuranceFundUnstakingPeriod,
+            update_spot_market_pool_id::UpdateSpotMarketPoolId,
+            update_spot_market_liquidation_fee::UpdateSpotMarketLiquidationFee,
+            update_withdraw_guard_threshold::UpdateWithdrawGuardThreshold,
+            update_spot_market_if_factor::UpdateSpotMarketIfFactor,
+            update_spot_market_revenue_settle_period::UpdateSpotMarketRevenueSettlePeriod,
+            update_spot_market_status::UpdateSpotMarketStatus,
+            update_spot_market_paused_operations::UpdateSpotMarketPausedOperations,
+            update_spot_market_asset_tier::UpdateSpotMarketAssetTier,
+            update_spot_market_margin_weights::UpdateSpotMarketMarginWeights,
+            update_spot_market_borrow_rate::UpdateSpotMarketBorrowRate,
+            update_spot_market_max_token_deposits::UpdateSpotMarketMaxTokenDeposits,
+            update_spot_market_max_token_borrows::UpdateSpotMarketMaxTokenBorrows,
+            update_spot_market_scale_initial_asset_weight_start::UpdateSpotMarketScaleInitialAssetWeightStart,
+            update_spot_market_oracle::UpdateSpotMarketOracle,
+            update_spot_market_step_size_and_tick_size::UpdateSpotMarketStepSizeAndTickSize,
+            update_spot_market_min_order_size::UpdateSpotMarketMinOrderSize,
+            update_spot_market_orders_enabled::UpdateSpotMarketOrdersEnabled,
+            update_spot_market_if_paused_operations::UpdateSpotMarketIfPausedOperations,
+            update_spot_market_name::UpdateSpotMarketName,
+            update_perp_market_status::UpdatePerpMarketStatus,
+            update_perp_market_paused_operations::UpdatePerpMarketPausedOperations,
+            update_perp_market_contract_tier::UpdatePerpMarketContractTier,
+            update_perp_market_imf_factor::UpdatePerpMarketImfFactor,
+            update_perp_market_unrealized_asset_weight::UpdatePerpMarketUnrealizedAssetWeight,
+            update_perp_market_concentration_coef::UpdatePerpMarketConcentrationCoef,
+            update_perp_market_curve_update_intensity::UpdatePerpMarketCurveUpdateIntensity,
+            update_perp_market_target_base_asset_amount_per_lp::UpdatePerpMarketTargetBaseAssetAmountPerLp,
+            update_perp_market_per_lp_base::UpdatePerpMarketPerLpBase,
+            update_lp_cooldown_time::UpdateLpCooldownTime,
+            update_perp_fee_structure::UpdatePerpFeeStructure,
+            update_spot_fee_structure::UpdateSpotFeeStructure,
+            update_initial_pct_to_liquidate::UpdateInitialPctToLiquidate,
+            update_liquidation_duration::UpdateLiquidationDuration,
+            update_liquidation_margin_buffer_ratio::UpdateLiquidationMarginBufferRatio,
+            update_oracle_guard_rails::UpdateOracleGuardRails,
+            update_state_settlement_duration::UpdateStateSettlementDuration,
+            update_state_max_number_of_sub_accounts::UpdateStateMaxNumberOfSubAccounts,
+            update_state_max_initialize_user_fee::UpdateStateMaxInitializeUserFee,
+            update_perp_market_oracle::UpdatePerpMarketOracle,
+            update_perp_market_base_spread::UpdatePerpMarketBaseSpread,
+            update_amm_jit_intensity::UpdateAmmJitIntensity,
+            update_perp_market_max_spread::UpdatePerpMarketMaxSpread,
+            update_perp_market_step_size_and_tick_size::UpdatePerpMarketStepSizeAndTickSize,
+            update_perp_market_name::UpdatePerpMarketName,
+            update_perp_market_min_order_size::UpdatePerpMarketMinOrderSize,
+            update_perp_market_max_slippage_ratio::UpdatePerpMarketMaxSlippageRatio,
+            update_perp_market_max_fill_reserve_fraction::UpdatePerpMarketMaxFillReserveFraction,
+            update_perp_market_max_open_interest::UpdatePerpMarketMaxOpenInterest,
+            update_perp_market_number_of_users::UpdatePerpMarketNumberOfUsers,
+            update_perp_market_fee_adjustment::UpdatePerpMarketFeeAdjustment,
+            update_spot_market_fee_adjustment::UpdateSpotMarketFeeAdjustment,
+            update_perp_market_fuel::UpdatePerpMarketFuel,
+            update_spot_market_fuel::UpdateSpotMarketFuel,
+            init_user_fuel::InitUserFuel,
+            update_admin::UpdateAdmin,
+            update_whitelist_mint::UpdateWhitelistMint,
+            update_discount_mint::UpdateDiscountMint,
+            update_exchange_status::UpdateExchangeStatus,
+            update_perp_auction_duration::UpdatePerpAuctionDuration,
+            update_spot_auction_duration::UpdateSpotAuctionDuration,
+            initialize_protocol_if_shares_transfer_config::InitializeProtocolIfSharesTransferConfig,
+            update_protocol_if_shares_transfer_config::UpdateProtocolIfSharesTransferConfig,
+            initialize_prelaunch_oracle::InitializePrelaunchOracle,
+            update_prelaunch_oracle_params::UpdatePrelaunchOracleParams,
+            delete_prelaunch_oracle::DeletePrelaunchOracle,
+            initialize_pyth_pull_oracle::InitializePythPullOracle,
+            initialize_pyth_lazer_oracle::InitializePythLazerOracle,
+            post_pyth_lazer_oracle_update::PostPythLazerOracleUpdate,
+            initialize_high_leverage_mode_config::InitializeHighLeverageModeConfig,
+            update_high_leverage_mode_config::UpdateHighLeverageModeConfig,
+            initialize_protected_maker_mode_config::InitializeProtectedMakerModeConfig,
+            update_protected_maker_mode_config::UpdateProtectedMakerModeConfig,
+            new_user_record_event::NewUserRecordEvent,
+            deposit_record_event::DepositRecordEvent,
+            spot_interest_record_event::SpotInterestRecordEvent,
+            funding_payment_record_event::FundingPaymentRecordEvent,
+            funding_rate_record_event::FundingRateRecordEvent,
+            curve_record_event::CurveRecordEvent,
+            signed_msg_order_record_event::SignedMsgOrderRecordEvent,
+            order_record_event::OrderRecordEvent,
+            order_action_record_event::OrderActionRecordEvent,
+            lp_record_event::LpRecordEvent,
+            liquidation_record_event::LiquidationRecordEvent,
+            settle_pnl_record_event::SettlePnlRecordEvent,
+            insurance_fund_record_event::InsuranceFundRecordEvent,
+            insurance_fund_stake_record_event::InsuranceFundStakeRecordEvent,
+            swap_record_event::SwapRecordEvent,
+            spot_market_vault_deposit_record_event::SpotMarketVaultDepositRecordEvent,
+            delete_user_record_event::DeleteUserRecordEvent,
+            fuel_sweep_record_event::FuelSweepRecordEvent,
+            fuel_season_record_event::FuelSeasonRecordEvent,
+        )
+    }
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone, Hash)]
+pub enum DriftEvent {
+    NewUserRecordEvent(new_user_record_event::NewUserRecordEvent),
+    DepositRecordEvent(deposit_record_event::DepositRecordEvent),
+    SpotInterestRecordEvent(spot_interest_record_event::SpotInterestRecordEvent),
+    FundingPaymentRecordEvent(funding_payment_record_event::FundingPaymentRecordEvent),
+    FundingRateRecordEvent(funding_rate_record_event::FundingRateRecordEvent),
+    CurveRecordEvent(curve_record_event::CurveRecordEvent),
+    SignedMsgOrderRecordEvent(signed_msg_order_record_event::SignedMsgOrderRecordEvent),
+    OrderRecordEvent(order_record_event::OrderRecordEvent),
+    OrderActionRecordEvent(order_action_record_event::OrderActionRecordEvent),
+    LpRecordEvent(lp_record_event::LpRecordEvent),
+    LiquidationRecordEvent(liquidation_record_event::LiquidationRecordEvent),
+    SettlePnlRecordEvent(settle_pnl_record_event::SettlePnlRecordEvent),
+    InsuranceFundRecordEvent(insurance_fund_record_event::InsuranceFundRecordEvent),
+    InsuranceFundStakeRecordEvent(insurance_fund_stake_record_event::InsuranceFundStakeRecordEvent),
+    SwapRecordEvent(swap_record_event::SwapRecordEvent),
+    SpotMarketVaultDepositRecordEvent(
+        spot_market_vault_deposit_record_event::SpotMarketVaultDepositRecordEvent,
+    ),
+    DeleteUserRecordEvent(delete_user_record_event::DeleteUserRecordEvent),
+    FuelSweepRecordEvent(fuel_sweep_record_event::FuelSweepRecordEvent),
+    FuelSeasonRecordEvent(fuel_season_record_event::FuelSeasonRecordEvent),
+}
+
+impl<'a> carbon_core::event::EventDecoder<'a> for DriftDecoder {
+    type EventType = DriftEvent;
+
+    fn decode_event(
+        &self,
+        program_id: &solana_pubkey::Pubkey,
+        data: &'a [u8],
+    ) -> Option<carbon_core::event::DecodedEvent<Self::EventType>> {
+        if !program_id.eq(&super::PROGRAM_ID) {
+            return None;
+        }
+
+        carbon_core::try_decode_events!(program_id, data,
+            DriftEvent::NewUserRecordEvent => new_user_record_event::NewUserRecordEvent,
+            DriftEvent::DepositRecordEvent => deposit_record_event::DepositRecordEvent,
+            DriftEvent::SpotInterestRecordEvent => spot_interest_record_event::SpotInterestRecordEvent,
+            DriftEvent::FundingPaymentRecordEvent => funding_payment_record_event::FundingPaymentRecordEvent,
+            DriftEvent::FundingRateRecordEvent => funding_rate_record_event::FundingRateRecordEvent,
+            DriftEvent::CurveRecordEvent => curve_record_event::CurveRecordEvent,
+            DriftEvent::SignedMsgOrderRecordEvent => signed_msg_order_record_event::SignedMsgOrderRecordEvent,
+            DriftEvent::OrderRecordEvent => order_record_event::OrderRecordEvent,
+            DriftEvent::OrderActionRecordEvent => order_action_record_event::OrderActionRecordEvent,
+            DriftEvent::LpRecordEvent => lp_record_event::LpRecordEvent,
+            DriftEvent::LiquidationRecordEvent => liquidation_record_event::LiquidationRecordEvent,
+            DriftEvent::SettlePnlRecordEvent => settle_pnl_record_event::SettlePnlRecordEvent,
+            DriftEvent::InsuranceFundRecordEvent => insurance_fund_record_event::InsuranceFundRecordEvent,
+            DriftEvent::InsuranceFundStakeRecordEvent => insurance_fund_stake_record_event::InsuranceFundStakeRecordEvent,
+            DriftEvent::SwapRecordEvent => swap_record_event::SwapRecordEvent,
+            DriftEvent::SpotMarketVaultDepositRecordEvent => spot_market_vault_deposit_record_event::SpotMarketVaultDepositRecordEvent,
+            DriftEvent::DeleteUserRecordEvent => delete_user_record_event::DeleteUserRecordEvent,
+            DriftEvent::FuelSweepRecordEvent => fuel_sweep_record_event::FuelSweepRecordEvent,
+            DriftEvent::FuelSeasonRecordEvent => fuel_season_record_event::FuelSeasonRecordEvent,
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use carbon_core::deserialize::{
+        ArrangeAccountIndices, ArrangeAccountMetas, ArrangeAccounts, CarbonDeserialize,
+        CarbonSerialize, NamedAccounts,
+    };
+    use carbon_core::error::CarbonResult;
+    use carbon_core::event::EventDecoder;
+    use carbon_core::instruction::{
+        InstructionDecoder, InstructionMetadata, InstructionProcessorInputType, NestedInstructions,
+    };
+    use carbon_core::metrics::MetricsCollection;
+    use carbon_core::processor::{MapProcessor, Processor};
+    use solana_instruction::AccountMeta;
+    use solana_pubkey::Pubkey;
+    use std::sync::{Arc, Mutex};
+
+    use super::super::types::*;
+    use super::*;
+
+    /// A small xorshift generator, so the round-trip test covers many
+    /// deterministic inputs without pulling in a property testing crate.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next_u64(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn next_bool(&mut self) -> bool {
+            self.next_u64() & 1 == 1
+        }
+
+        fn option<T>(&mut self, value: impl FnOnce(&mut Self) -> T) -> Option<T> {
+            if self.next_bool() {
+                Some(value(self))
+            } else {
+                None
+            }
+        }
+
+        fn order_params(&mut self) -> OrderParams {
+            OrderParams {
+                order_type: match self.next_u64() % 5 {
+                    0 => OrderType::Market,
+                    1 => OrderType::Limit,
+                    2 => OrderType::TriggerMarket,
+                    3 => OrderType::TriggerLimit,
+                    _ => OrderType::Oracle,
+                },
+                market_type: if self.next_bool() {
+                    MarketType::Spot
+                } else {
+                    MarketType::Perp
+                },
+                direction: self.direction(),
+                user_order_id: self.next_u64() as u8,
+                base_asset_amount: self.next_u64(),
+                price: self.next_u64(),
+                market_index: self.next_u64() as u16,
+                reduce_only: self.next_bool(),
+                post_only: self.post_only(),
+                immediate_or_cancel: self.next_bool(),
+                max_ts: self.option(|rng| rng.next_u64() as i64),
+                trigger_price: self.option(Self::next_u64),
+                trigger_condition: self.trigger_condition(),
+                oracle_price_offset: self.option(|rng| rng.next_u64() as i32),
+                auction_duration: self.option(|rng| rng.next_u64() as u8),
+                auction_start_price: self.option(|rng| rng.next_u64() as i64),
+                auction_end_price: self.option(|rng| rng.next_u64() as i64),
+            }
+        }
+
+        fn modify_order_params(&mut self) -> ModifyOrderParams {
+            ModifyOrderParams {
+                direction: self.option(Self::direction),
+                base_asset_amount: self.option(Self::next_u64),
+                price: self.option(Self::next_u64),
+                reduce_only: self.option(Self::next_bool),
+                post_only: self.option(Self::post_only),
+                immediate_or_cancel: self.option(Self::next_bool),
+                max_ts: self.option(|rng| rng.next_u64() as i64),
+                trigger_price: self.option(Self::next_u64),
+                trigger_condition: self.option(Self::trigger_condition),
+                oracle_price_offset: self.option(|rng| rng.next_u64() as i32),
+                auction_duration: self.option(|rng| rng.next_u64() as u8),
+                auction_start_price: self.option(|rng| rng.next_u64() as i64),
+                auction_end_price: self.option(|rng| rng.next_u64() as i64),
+                policy: self.option(|rng| rng.next_u64() as u8),
+            }
+        }
+
+        fn direction(&mut self) -> PositionDirection {
+            if self.next_bool() {
+                PositionDirection::Long
+            } else {
+                PositionDirection::Short
+            }
+        }
+
+        fn post_only(&mut self) -> PostOnlyParam {
+            match self.next_u64() % 4 {
+                0 => PostOnlyParam::None,
+                1 => PostOnlyParam::MustPostOnly,
+                2 => PostOnlyParam::TryPostOnly,
+                _ => PostOnlyParam::Slide,
+            }
+        }
+
+        fn trigger_condition(&mut self) -> OrderTriggerCondition {
+            match self.next_u64() % 4 {
+                0 => OrderTriggerCondition::Above,
+                1 => OrderTriggerCondition::Below,
+                2 => OrderTriggerCondition::TriggeredAbove,
+                _ => OrderTriggerCondition::TriggeredBelow,
+            }
+        }
+    }
+
+    fn assert_round_trip<T>(data: T, expected: DriftInstruction)
+    where
+        T: CarbonSerialize + ArrangeAccounts + Clone + PartialEq + std::fmt::Debug,
+    {
+        let accounts = vec![AccountMeta::new(Pubkey::new_unique(), false); 4];
+        let instruction = data.to_instruction(PROGRAM_ID, accounts.clone());
+
+        assert_eq!(
+            <T as CarbonDeserialize>::deserialize(&data.to_bytes()),
+            Some(data.clone())
+        );
+        assert_eq!(instruction.accounts, accounts);
+
+        let decoded = DriftDecoder
+            .decode_instruction(&instruction)
+            .expect("decode instruction");
+        assert_eq!(decoded.data, expected);
+        assert_eq!(decoded.raw_data, data.to_bytes());
+    }
+
+    #[test]
+    fn test_instruction_round_trip() {
+        // Arrange
+        let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+
+        for _ in 0..64 {
+            let paused_operations =
+                update_perp_market_paused_operations::UpdatePerpMarketPausedOperations {
+                    paused_operations: rng.next_u64() as u8,
+                };
+            let settle_pnl = settle_pnl::SettlePnl {
+                market_index: rng.next_u64() as u16,
+            };
+            let mut name = [0u8; 32];
+            name.iter_mut()
+                .for_each(|byte| *byte = rng.next_u64() as u8);
+            let initialize_user = initialize_user::InitializeUser {
+                sub_account_id: rng.next_u64() as u16,
+                name,
+            };
+            let place_perp_order = place_perp_order::PlacePerpOrder {
+                params: rng.order_params(),
+            };
+            let place_orders = place_orders::PlaceOrders {
+                params: (0..rng.next_u64() % 4)
+                    .map(|_| rng.order_params())
+                    .collect(),
+            };
+            let modify_order = modify_order::ModifyOrder {
+                order_id: rng.option(|rng| rng.next_u64() as u32),
+                modify_order_params: rng.modify_order_params(),
+            };
+
+            // Act & Assert
+            assert_round_trip(
+                paused_operations.clone(),
+                DriftInstruction::UpdatePerpMarketPausedOperations(paused_operations),
+            );
+            assert_round_trip(settle_pnl.clone(), DriftInstruction::SettlePnl(settle_pnl));
+            assert_round_trip(
+                initialize_user.clone(),
+                DriftInstruction::InitializeUser(initialize_user),
+            );
+            assert_round_trip(
+                place_perp_order.clone(),
+                DriftInstruction::PlacePerpOrder(place_perp_order),
+            );
+            assert_round_trip(
+                place_orders.clone(),
+                DriftInstruction::PlaceOrders(place_orders),
+            );
+            assert_round_trip(
+                modify_order.clone(),
+                DriftInstruction::ModifyOrder(modify_order),
+            );
+        }
+    }
+
+    #[test]
+    fn test_matches_discriminator_without_decoding() {
+        // Arrange
+        let settle_pnl = settle_pnl::SettlePnl { market_index: 3 }.to_bytes();
+        let truncated = &settle_pnl[..8];
+        let unknown = [0xffu8; 16];
+
+        // Act
+        let matches = [
+            DriftDecoder.matches_discriminator(&settle_pnl),
+            DriftDecoder.matches_discriminator(truncated),
+            DriftDecoder.matches_discriminator(&unknown),
+        ];
+
+        // Assert
+        assert_eq!(matches, [true, true, false]);
+        assert!(settle_pnl::SettlePnl::deserialize(truncated).is_none());
+    }
+
+    #[test]
+    fn test_arrange_accounts_keeps_remaining_accounts() {
+        // Arrange
+        let state = Pubkey::new_unique();
+        let user = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let spot_market_vault = Pubkey::new_unique();
+        let remaining_accounts = vec![
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+        ];
+        let mut accounts = vec![
+            AccountMeta::new_readonly(state, false),
+            AccountMeta::new(user, false),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new_readonly(spot_market_vault, false),
+        ];
+        accounts.extend(remaining_accounts.clone());
+
+        // Act
+        let arranged =
+            settle_pnl::SettlePnl::arrange_accounts(&accounts).expect("arrange accounts");
+
+        // Assert
+        assert_eq!(arranged.state, state);
+        assert_eq!(arranged.user, user);
+        assert_eq!(arranged.authority, authority);
+        assert_eq!(arranged.spot_market_vault, spot_market_vault);
+        assert_eq!(arranged.remaining_accounts, remaining_accounts);
+    }
+
+    #[test]
+    fn test_arrange_accounts_without_remaining_accounts() {
+        // Arrange
+        let accounts = vec![
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), true),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+        ];
+
+        // Act
+        let arranged =
+            settle_pnl::SettlePnl::arrange_accounts(&accounts).expect("arrange accounts");
+
+        // Assert
+        assert!(arranged.remaining_accounts.is_empty());
+    }
+
+    #[test]
+    fn test_arrange_accounts_missing_required_accounts() {
+        // Arrange
+        let accounts = vec![
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), true),
+        ];
+
+        // Act
+        let arranged = settle_pnl::SettlePnl::arrange_accounts(&accounts);
+
+        // Assert
+        assert!(arranged.is_none());
+    }
+
+    #[test]
+    fn test_arrange_account_metas_keeps_signer_flags() {
+        // Arrange
+        let admin = AccountMeta::new(Pubkey::new_unique(), true);
+        let mut accounts = vec![admin.clone()];
+        accounts.extend((0..6).map(|_| AccountMeta::new_readonly(Pubkey::new_unique(), false)));
+
+        // Act
+        let arranged = initialize::Initialize::arrange_account_metas(&accounts)
+            .expect("arrange account metas");
+
+        // Assert
+        assert_eq!(arranged.admin, admin);
+        assert!(arranged.admin.is_signer);
+        assert!(arranged.admin.is_writable);
+        assert!(!arranged.state.is_signer);
+        assert_eq!(arranged.token_program, accounts[6]);
+        assert!(arranged.remaining_accounts.is_empty());
+        assert_eq!(
+            initialize::Initialize::arrange_accounts(&accounts)
+                .expect("arrange accounts")
+                .admin,
+            admin.pubkey
+        );
+    }
+
+    #[test]
+    fn test_arrange_account_indices_map_back_to_accounts() {
+        // Arrange
+        let accounts: Vec<AccountMeta> = (0..6)
+            .map(|_| AccountMeta::new_readonly(Pubkey::new_unique(), false))
+            .collect();
+
+        // Act
+        let indices =
+            update_k::UpdateK::arrange_account_indices(&accounts).expect("arrange account indices");
+        let arranged = update_k::UpdateK::arrange_accounts(&accounts).expect("arrange accounts");
+
+        // Assert
+        assert_eq!(indices.admin, 0);
+        assert_eq!(indices.state, 1);
+        assert_eq!(indices.perp_market, 2);
+        assert_eq!(indices.oracle, 3);
+        assert_eq!(indices.remaining_accounts, 4..6);
+        assert_eq!(accounts[indices.perp_market].pubkey, arranged.perp_market);
+        assert!(update_k::UpdateK::arrange_account_indices(&accounts[..3]).is_none());
+    }
+
+    #[test]
+    fn test_named_accounts_label_account_pubkeys() {
+        // Arrange
+        let instruction = update_perp_market_paused_operations::UpdatePerpMarketPausedOperations {
+            paused_operations: 1,
+        };
+        let accounts = vec![
+            AccountMeta::new_readonly(Pubkey::new_unique(), true),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+        ];
+
+        // Act
+        let named_accounts = instruction.named_accounts(&accounts);
+
+        // Assert
+        assert_eq!(
+            update_perp_market_paused_operations::UpdatePerpMarketPausedOperations::account_names(),
+            ["admin", "state", "perp_market"]
+        );
+        assert_eq!(
+            named_accounts,
+            vec![
+                ("admin", accounts[0].pubkey),
+                ("state", accounts[1].pubkey),
+                ("perp_market", accounts[2].pubkey),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decode_instruction_exposes_discriminator_and_raw_data() {
+        // Arrange
+        let discriminator = [0x35, 0x10, 0x88, 0x84, 0x1e, 0xdc, 0x79, 0x55];
+        let mut data = discriminator.to_vec();
+        data.push(3);
+        let instruction = solana_instruction::Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![],
+            data: data.clone(),
+        };
+
+        // Act
+        let decoded = DriftDecoder
+            .decode_instruction(&instruction)
+            .expect("decode instruction");
+
+        // Assert
+        assert_eq!(decoded.discriminator, discriminator);
+        assert_eq!(decoded.raw_data, data);
+        assert_eq!(
+            decoded.data,
+            DriftInstruction::UpdatePerpMarketPausedOperations(
+                update_perp_market_paused_operations::UpdatePerpMarketPausedOperations {
+                    paused_operations: 3,
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn test_unified_decoder_converts_between_enum_and_instructions() {
+        // Arrange
+        let deposit = deposit::Deposit {
+            market_index: 1,
+            amount: 500,
+            reduce_only: false,
+        };
+        let paused = update_perp_market_paused_operations::UpdatePerpMarketPausedOperations {
+            paused_operations: 3,
+        };
+        let decode = |data: Vec<u8>| {
+            DriftDecoder
+                .decode_instruction(&solana_instruction::Instruction {
+                    program_id: PROGRAM_ID,
+                    accounts: vec![],
+                    data,
+                })
+                .expect("decode instruction")
+                .data
+        };
+
+        // Act
+        let decoded_deposit = decode(deposit.to_bytes());
+        let decoded_paused = decode(paused.to_bytes());
+
+        // Assert
+        assert_eq!(decoded_deposit, DriftInstruction::from(deposit.clone()));
+        assert_eq!(decoded_paused, DriftInstruction::from(paused.clone()));
+        assert_eq!(deposit::Deposit::try_from(decoded_deposit), Ok(deposit));
+        assert_eq!(
+            deposit::Deposit::try_from(decoded_paused.clone()),
+            Err(decoded_paused)
+        );
+    }
+
+    #[test]
+    fn test_decode_self_cpi_event() {
+        // Arrange
+        let user_authority = Pubkey::new_unique();
+        let user = Pubkey::new_unique();
+        let mut data = vec![
+            0xe4, 0x45, 0xa5, 0x2e, 0x51, 0xcb, 0x9a, 0x1d, 0x47, 0x6f, 0xbe, 0x76, 0x07, 0x03,
+            0x84, 0xde,
+        ];
+        data.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+        data.extend_from_slice(user_authority.as_ref());
+        data.extend_from_slice(user.as_ref());
+        data.extend_from_slice(&3u16.to_le_bytes());
+        data.push(0);
+        let payload =
+            carbon_core::event::anchor_event_payload(&data).expect("anchor event payload");
+
+        // Act
+        let decoded = DriftDecoder
+            .decode_event(&PROGRAM_ID, payload)
+            .expect("decode event");
+
+        // Assert
+        assert_eq!(
+            decoded.discriminator,
+            vec![0x47, 0x6f, 0xbe, 0x76, 0x07, 0x03, 0x84, 0xde]
+        );
+        assert_eq!(
+            decoded.data,
+            DriftEvent::DeleteUserRecordEvent(delete_user_record_event::DeleteUserRecordEvent {
+                ts: 1_700_000_000,
+                user_authority,
+                user,
+                sub_account_id: 3,
+                keeper: None,
+            })
+        );
+        assert!(DriftDecoder
+            .decode_event(&Pubkey::new_unique(), payload)
+            .is_none());
+    }
+    #[derive(Debug, PartialEq, Eq)]
+    struct ProgramEvent {
+        program: Pubkey,
+        kind: &'static str,
+    }
+
+    struct ProgramEventProcessor(Arc<Mutex<Vec<ProgramEvent>>>);
+
+    #[async_trait::async_trait]
+    impl Processor for ProgramEventProcessor {
+        type InputType = ProgramEvent;
+
+        async fn process(
+            &mut self,
+            event: Self::InputType,
+            _metrics: Arc<MetricsCollection>,
+        ) -> CarbonResult<()> {
+            self.0.lock().unwrap().push(event);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_map_processor_adapts_instructions_to_shared_processor() {
+        // Arrange
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let mut processor = MapProcessor::new(
+            ProgramEventProcessor(events.clone()),
+            |(_, instruction, _): InstructionProcessorInputType<DriftInstruction>| ProgramEvent {
+                program: instruction.program_id,
+                kind: match instruction.data {
+                    DriftInstruction::SettlePnl(_) => "settle_pnl",
+                    _ => "other",
+                },
+            },
+        );
+        let instruction = settle_pnl::SettlePnl { market_index: 3 }.to_instruction(
+            PROGRAM_ID,
+            vec![AccountMeta::new(Pubkey::new_unique(), false); 4],
+        );
+        let decoded_instruction = DriftDecoder
+            .decode_instruction(&instruction)
+            .expect("decode instruction");
+        let metadata = InstructionMetadata {
+            transaction_metadata: Default::default(),
+            stack_height: 1,
+            index: 0,
+            accounts_resolved: true,
+            compute_units: None,
+            absolute_path: vec![],
+            transaction_context: None,
+        };
+
+        // Act
+        futures::executor::block_on(processor.process(
+            (metadata, decoded_instruction, NestedInstructions::default()),
+            Arc::new(MetricsCollection::new(vec![])),
+        ))
+        .expect("process instruction");
+
+        // Assert
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![ProgramEvent {
+                program: PROGRAM_ID,
+                kind: "settle_pnl",
+            }]
+        );
+    }
+}
@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct OpenbookV2FulfillmentConfigStatusInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub openbook_v2_fulfillment_config: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for OpenbookV2FulfillmentConfigStatus {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for OpenbookV2FulfillmentConfigSt
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, openbook_v2_fulfillment_config, admin, _remaining @ ..] = accounts else {
+        let [state, openbook_v2_fulfillment_config, admin, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -30,6 +32,65 @@ impl carbon_core::deserialize::ArrangeAccounts for OpenbookV2FulfillmentConfigSt
             state: state.pubkey,
             openbook_v2_fulfillment_config: openbook_v2_fulfillment_config.pubkey,
             admin: admin.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct OpenbookV2FulfillmentConfigStatusInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub openbook_v2_fulfillment_config: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for OpenbookV2FulfillmentConfigStatus {
+    type ArrangedAccountMetas = OpenbookV2FulfillmentConfigStatusInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, openbook_v2_fulfillment_config, admin, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(OpenbookV2FulfillmentConfigStatusInstructionAccountMetas {
+            state: state.clone(),
+            openbook_v2_fulfillment_config: openbook_v2_fulfillment_config.clone(),
+            admin: admin.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct OpenbookV2FulfillmentConfigStatusInstructionAccountIndices {
+    pub state: usize,
+    pub openbook_v2_fulfillment_config: usize,
+    pub admin: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for OpenbookV2FulfillmentConfigStatus {
+    type ArrangedAccountIndices = OpenbookV2FulfillmentConfigStatusInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(OpenbookV2FulfillmentConfigStatusInstructionAccountIndices {
+            state: 0,
+            openbook_v2_fulfillment_config: 1,
+            admin: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for OpenbookV2FulfillmentConfigStatus {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "openbook_v2_fulfillment_config", "admin"]
+    }
+}
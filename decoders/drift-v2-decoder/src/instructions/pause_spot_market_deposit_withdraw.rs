@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +12,7 @@ pub struct PauseSpotMarketDepositWithdrawInstructionAccounts {
     pub keeper: solana_pubkey::Pubkey,
     pub spot_market: solana_pubkey::Pubkey,
     pub spot_market_vault: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for PauseSpotMarketDepositWithdraw {
@@ -19,7 +21,7 @@ impl carbon_core::deserialize::ArrangeAccounts for PauseSpotMarketDepositWithdra
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, keeper, spot_market, spot_market_vault, _remaining @ ..] = accounts else {
+        let [state, keeper, spot_market, spot_market_vault, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +30,69 @@ impl carbon_core::deserialize::ArrangeAccounts for PauseSpotMarketDepositWithdra
             keeper: keeper.pubkey,
             spot_market: spot_market.pubkey,
             spot_market_vault: spot_market_vault.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct PauseSpotMarketDepositWithdrawInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub keeper: solana_instruction::AccountMeta,
+    pub spot_market: solana_instruction::AccountMeta,
+    pub spot_market_vault: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for PauseSpotMarketDepositWithdraw {
+    type ArrangedAccountMetas = PauseSpotMarketDepositWithdrawInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, keeper, spot_market, spot_market_vault, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(PauseSpotMarketDepositWithdrawInstructionAccountMetas {
+            state: state.clone(),
+            keeper: keeper.clone(),
+            spot_market: spot_market.clone(),
+            spot_market_vault: spot_market_vault.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct PauseSpotMarketDepositWithdrawInstructionAccountIndices {
+    pub state: usize,
+    pub keeper: usize,
+    pub spot_market: usize,
+    pub spot_market_vault: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for PauseSpotMarketDepositWithdraw {
+    type ArrangedAccountIndices = PauseSpotMarketDepositWithdrawInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(PauseSpotMarketDepositWithdrawInstructionAccountIndices {
+            state: 0,
+            keeper: 1,
+            spot_market: 2,
+            spot_market_vault: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for PauseSpotMarketDepositWithdraw {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "keeper", "spot_market", "spot_market_vault"]
+    }
+}
@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct PhoenixFulfillmentConfigStatusInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub phoenix_fulfillment_config: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for PhoenixFulfillmentConfigStatus {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for PhoenixFulfillmentConfigStatu
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, phoenix_fulfillment_config, admin, _remaining @ ..] = accounts else {
+        let [state, phoenix_fulfillment_config, admin, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -30,6 +32,65 @@ impl carbon_core::deserialize::ArrangeAccounts for PhoenixFulfillmentConfigStatu
             state: state.pubkey,
             phoenix_fulfillment_config: phoenix_fulfillment_config.pubkey,
             admin: admin.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct PhoenixFulfillmentConfigStatusInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub phoenix_fulfillment_config: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for PhoenixFulfillmentConfigStatus {
+    type ArrangedAccountMetas = PhoenixFulfillmentConfigStatusInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, phoenix_fulfillment_config, admin, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(PhoenixFulfillmentConfigStatusInstructionAccountMetas {
+            state: state.clone(),
+            phoenix_fulfillment_config: phoenix_fulfillment_config.clone(),
+            admin: admin.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct PhoenixFulfillmentConfigStatusInstructionAccountIndices {
+    pub state: usize,
+    pub phoenix_fulfillment_config: usize,
+    pub admin: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for PhoenixFulfillmentConfigStatus {
+    type ArrangedAccountIndices = PhoenixFulfillmentConfigStatusInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(PhoenixFulfillmentConfigStatusInstructionAccountIndices {
+            state: 0,
+            phoenix_fulfillment_config: 1,
+            admin: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for PhoenixFulfillmentConfigStatus {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "phoenix_fulfillment_config", "admin"]
+    }
+}
@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -18,6 +19,7 @@ pub struct PlaceAndMakePerpOrderInstructionAccounts {
     pub taker: solana_pubkey::Pubkey,
     pub taker_stats: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for PlaceAndMakePerpOrder {
@@ -26,7 +28,7 @@ impl carbon_core::deserialize::ArrangeAccounts for PlaceAndMakePerpOrder {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, user, user_stats, taker, taker_stats, authority, _remaining @ ..] = accounts
+        let [state, user, user_stats, taker, taker_stats, authority, remaining @ ..] = accounts
         else {
             return None;
         };
@@ -38,6 +40,85 @@ impl carbon_core::deserialize::ArrangeAccounts for PlaceAndMakePerpOrder {
             taker: taker.pubkey,
             taker_stats: taker_stats.pubkey,
             authority: authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct PlaceAndMakePerpOrderInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub user_stats: solana_instruction::AccountMeta,
+    pub taker: solana_instruction::AccountMeta,
+    pub taker_stats: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for PlaceAndMakePerpOrder {
+    type ArrangedAccountMetas = PlaceAndMakePerpOrderInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, user, user_stats, taker, taker_stats, authority, remaining @ ..] = accounts
+        else {
+            return None;
+        };
+
+        Some(PlaceAndMakePerpOrderInstructionAccountMetas {
+            state: state.clone(),
+            user: user.clone(),
+            user_stats: user_stats.clone(),
+            taker: taker.clone(),
+            taker_stats: taker_stats.clone(),
+            authority: authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct PlaceAndMakePerpOrderInstructionAccountIndices {
+    pub state: usize,
+    pub user: usize,
+    pub user_stats: usize,
+    pub taker: usize,
+    pub taker_stats: usize,
+    pub authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for PlaceAndMakePerpOrder {
+    type ArrangedAccountIndices = PlaceAndMakePerpOrderInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(PlaceAndMakePerpOrderInstructionAccountIndices {
+            state: 0,
+            user: 1,
+            user_stats: 2,
+            taker: 3,
+            taker_stats: 4,
+            authority: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for PlaceAndMakePerpOrder {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "user",
+            "user_stats",
+            "taker",
+            "taker_stats",
+            "authority",
+        ]
+    }
+}
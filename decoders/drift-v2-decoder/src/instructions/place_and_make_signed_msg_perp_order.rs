@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -19,6 +20,7 @@ pub struct PlaceAndMakeSignedMsgPerpOrderInstructionAccounts {
     pub taker_stats: solana_pubkey::Pubkey,
     pub taker_signed_msg_user_orders: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for PlaceAndMakeSignedMsgPerpOrder {
@@ -27,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for PlaceAndMakeSignedMsgPerpOrde
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, user, user_stats, taker, taker_stats, taker_signed_msg_user_orders, authority, _remaining @ ..] =
+        let [state, user, user_stats, taker, taker_stats, taker_signed_msg_user_orders, authority, remaining @ ..] =
             accounts
         else {
             return None;
@@ -41,6 +43,91 @@ impl carbon_core::deserialize::ArrangeAccounts for PlaceAndMakeSignedMsgPerpOrde
             taker_stats: taker_stats.pubkey,
             taker_signed_msg_user_orders: taker_signed_msg_user_orders.pubkey,
             authority: authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct PlaceAndMakeSignedMsgPerpOrderInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub user_stats: solana_instruction::AccountMeta,
+    pub taker: solana_instruction::AccountMeta,
+    pub taker_stats: solana_instruction::AccountMeta,
+    pub taker_signed_msg_user_orders: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for PlaceAndMakeSignedMsgPerpOrder {
+    type ArrangedAccountMetas = PlaceAndMakeSignedMsgPerpOrderInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, user, user_stats, taker, taker_stats, taker_signed_msg_user_orders, authority, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(PlaceAndMakeSignedMsgPerpOrderInstructionAccountMetas {
+            state: state.clone(),
+            user: user.clone(),
+            user_stats: user_stats.clone(),
+            taker: taker.clone(),
+            taker_stats: taker_stats.clone(),
+            taker_signed_msg_user_orders: taker_signed_msg_user_orders.clone(),
+            authority: authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct PlaceAndMakeSignedMsgPerpOrderInstructionAccountIndices {
+    pub state: usize,
+    pub user: usize,
+    pub user_stats: usize,
+    pub taker: usize,
+    pub taker_stats: usize,
+    pub taker_signed_msg_user_orders: usize,
+    pub authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for PlaceAndMakeSignedMsgPerpOrder {
+    type ArrangedAccountIndices = PlaceAndMakeSignedMsgPerpOrderInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 7 {
+            return None;
+        }
+
+        Some(PlaceAndMakeSignedMsgPerpOrderInstructionAccountIndices {
+            state: 0,
+            user: 1,
+            user_stats: 2,
+            taker: 3,
+            taker_stats: 4,
+            taker_signed_msg_user_orders: 5,
+            authority: 6,
+            remaining_accounts: 7..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for PlaceAndMakeSignedMsgPerpOrder {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "user",
+            "user_stats",
+            "taker",
+            "taker_stats",
+            "taker_signed_msg_user_orders",
+            "authority",
+        ]
+    }
+}
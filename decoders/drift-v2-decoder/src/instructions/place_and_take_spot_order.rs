@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -17,6 +18,7 @@ pub struct PlaceAndTakeSpotOrderInstructionAccounts {
     pub user: solana_pubkey::Pubkey,
     pub user_stats: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for PlaceAndTakeSpotOrder {
@@ -25,7 +27,7 @@ impl carbon_core::deserialize::ArrangeAccounts for PlaceAndTakeSpotOrder {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, user, user_stats, authority, _remaining @ ..] = accounts else {
+        let [state, user, user_stats, authority, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -34,6 +36,69 @@ impl carbon_core::deserialize::ArrangeAccounts for PlaceAndTakeSpotOrder {
             user: user.pubkey,
             user_stats: user_stats.pubkey,
             authority: authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct PlaceAndTakeSpotOrderInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub user_stats: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for PlaceAndTakeSpotOrder {
+    type ArrangedAccountMetas = PlaceAndTakeSpotOrderInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, user, user_stats, authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(PlaceAndTakeSpotOrderInstructionAccountMetas {
+            state: state.clone(),
+            user: user.clone(),
+            user_stats: user_stats.clone(),
+            authority: authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct PlaceAndTakeSpotOrderInstructionAccountIndices {
+    pub state: usize,
+    pub user: usize,
+    pub user_stats: usize,
+    pub authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for PlaceAndTakeSpotOrder {
+    type ArrangedAccountIndices = PlaceAndTakeSpotOrderInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(PlaceAndTakeSpotOrderInstructionAccountIndices {
+            state: 0,
+            user: 1,
+            user_stats: 2,
+            authority: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for PlaceAndTakeSpotOrder {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "user", "user_stats", "authority"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -16,6 +17,7 @@ pub struct PlaceSignedMsgTakerOrderInstructionAccounts {
     pub signed_msg_user_orders: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
     pub ix_sysvar: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for PlaceSignedMsgTakerOrder {
@@ -24,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for PlaceSignedMsgTakerOrder {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, user, user_stats, signed_msg_user_orders, authority, ix_sysvar, _remaining @ ..] =
+        let [state, user, user_stats, signed_msg_user_orders, authority, ix_sysvar, remaining @ ..] =
             accounts
         else {
             return None;
@@ -37,6 +39,86 @@ impl carbon_core::deserialize::ArrangeAccounts for PlaceSignedMsgTakerOrder {
             signed_msg_user_orders: signed_msg_user_orders.pubkey,
             authority: authority.pubkey,
             ix_sysvar: ix_sysvar.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct PlaceSignedMsgTakerOrderInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub user_stats: solana_instruction::AccountMeta,
+    pub signed_msg_user_orders: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub ix_sysvar: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for PlaceSignedMsgTakerOrder {
+    type ArrangedAccountMetas = PlaceSignedMsgTakerOrderInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, user, user_stats, signed_msg_user_orders, authority, ix_sysvar, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(PlaceSignedMsgTakerOrderInstructionAccountMetas {
+            state: state.clone(),
+            user: user.clone(),
+            user_stats: user_stats.clone(),
+            signed_msg_user_orders: signed_msg_user_orders.clone(),
+            authority: authority.clone(),
+            ix_sysvar: ix_sysvar.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct PlaceSignedMsgTakerOrderInstructionAccountIndices {
+    pub state: usize,
+    pub user: usize,
+    pub user_stats: usize,
+    pub signed_msg_user_orders: usize,
+    pub authority: usize,
+    pub ix_sysvar: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for PlaceSignedMsgTakerOrder {
+    type ArrangedAccountIndices = PlaceSignedMsgTakerOrderInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(PlaceSignedMsgTakerOrderInstructionAccountIndices {
+            state: 0,
+            user: 1,
+            user_stats: 2,
+            signed_msg_user_orders: 3,
+            authority: 4,
+            ix_sysvar: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for PlaceSignedMsgTakerOrder {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "user",
+            "user_stats",
+            "signed_msg_user_orders",
+            "authority",
+            "ix_sysvar",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +13,7 @@ pub struct PostMultiPythPullOracleUpdatesAtomicInstructionAccounts {
     pub keeper: solana_pubkey::Pubkey,
     pub pyth_solana_receiver: solana_pubkey::Pubkey,
     pub guardian_set: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for PostMultiPythPullOracleUpdatesAtomic {
@@ -20,7 +22,7 @@ impl carbon_core::deserialize::ArrangeAccounts for PostMultiPythPullOracleUpdate
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [keeper, pyth_solana_receiver, guardian_set, _remaining @ ..] = accounts else {
+        let [keeper, pyth_solana_receiver, guardian_set, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +30,69 @@ impl carbon_core::deserialize::ArrangeAccounts for PostMultiPythPullOracleUpdate
             keeper: keeper.pubkey,
             pyth_solana_receiver: pyth_solana_receiver.pubkey,
             guardian_set: guardian_set.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct PostMultiPythPullOracleUpdatesAtomicInstructionAccountMetas {
+    pub keeper: solana_instruction::AccountMeta,
+    pub pyth_solana_receiver: solana_instruction::AccountMeta,
+    pub guardian_set: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for PostMultiPythPullOracleUpdatesAtomic {
+    type ArrangedAccountMetas = PostMultiPythPullOracleUpdatesAtomicInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [keeper, pyth_solana_receiver, guardian_set, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(
+            PostMultiPythPullOracleUpdatesAtomicInstructionAccountMetas {
+                keeper: keeper.clone(),
+                pyth_solana_receiver: pyth_solana_receiver.clone(),
+                guardian_set: guardian_set.clone(),
+                remaining_accounts: remaining.to_vec(),
+            },
+        )
+    }
+}
+
+pub struct PostMultiPythPullOracleUpdatesAtomicInstructionAccountIndices {
+    pub keeper: usize,
+    pub pyth_solana_receiver: usize,
+    pub guardian_set: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for PostMultiPythPullOracleUpdatesAtomic {
+    type ArrangedAccountIndices = PostMultiPythPullOracleUpdatesAtomicInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(
+            PostMultiPythPullOracleUpdatesAtomicInstructionAccountIndices {
+                keeper: 0,
+                pyth_solana_receiver: 1,
+                guardian_set: 2,
+                remaining_accounts: 3..accounts.len(),
+            },
+        )
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for PostMultiPythPullOracleUpdatesAtomic {
+    fn account_names() -> &'static [&'static str] {
+        &["keeper", "pyth_solana_receiver", "guardian_set"]
+    }
+}
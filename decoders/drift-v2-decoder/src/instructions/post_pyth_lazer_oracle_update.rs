@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +13,7 @@ pub struct PostPythLazerOracleUpdateInstructionAccounts {
     pub keeper: solana_pubkey::Pubkey,
     pub pyth_lazer_storage: solana_pubkey::Pubkey,
     pub ix_sysvar: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for PostPythLazerOracleUpdate {
@@ -20,7 +22,7 @@ impl carbon_core::deserialize::ArrangeAccounts for PostPythLazerOracleUpdate {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [keeper, pyth_lazer_storage, ix_sysvar, _remaining @ ..] = accounts else {
+        let [keeper, pyth_lazer_storage, ix_sysvar, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +30,65 @@ impl carbon_core::deserialize::ArrangeAccounts for PostPythLazerOracleUpdate {
             keeper: keeper.pubkey,
             pyth_lazer_storage: pyth_lazer_storage.pubkey,
             ix_sysvar: ix_sysvar.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct PostPythLazerOracleUpdateInstructionAccountMetas {
+    pub keeper: solana_instruction::AccountMeta,
+    pub pyth_lazer_storage: solana_instruction::AccountMeta,
+    pub ix_sysvar: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for PostPythLazerOracleUpdate {
+    type ArrangedAccountMetas = PostPythLazerOracleUpdateInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [keeper, pyth_lazer_storage, ix_sysvar, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(PostPythLazerOracleUpdateInstructionAccountMetas {
+            keeper: keeper.clone(),
+            pyth_lazer_storage: pyth_lazer_storage.clone(),
+            ix_sysvar: ix_sysvar.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct PostPythLazerOracleUpdateInstructionAccountIndices {
+    pub keeper: usize,
+    pub pyth_lazer_storage: usize,
+    pub ix_sysvar: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for PostPythLazerOracleUpdate {
+    type ArrangedAccountIndices = PostPythLazerOracleUpdateInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(PostPythLazerOracleUpdateInstructionAccountIndices {
+            keeper: 0,
+            pyth_lazer_storage: 1,
+            ix_sysvar: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for PostPythLazerOracleUpdate {
+    fn account_names() -> &'static [&'static str] {
+        &["keeper", "pyth_lazer_storage", "ix_sysvar"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct PostPythPullOracleUpdateAtomicInstructionAccounts {
     pub pyth_solana_receiver: solana_pubkey::Pubkey,
     pub guardian_set: solana_pubkey::Pubkey,
     pub price_feed: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for PostPythPullOracleUpdateAtomic {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for PostPythPullOracleUpdateAtomi
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [keeper, pyth_solana_receiver, guardian_set, price_feed, _remaining @ ..] = accounts
+        let [keeper, pyth_solana_receiver, guardian_set, price_feed, remaining @ ..] = accounts
         else {
             return None;
         };
@@ -32,6 +34,75 @@ impl carbon_core::deserialize::ArrangeAccounts for PostPythPullOracleUpdateAtomi
             pyth_solana_receiver: pyth_solana_receiver.pubkey,
             guardian_set: guardian_set.pubkey,
             price_feed: price_feed.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct PostPythPullOracleUpdateAtomicInstructionAccountMetas {
+    pub keeper: solana_instruction::AccountMeta,
+    pub pyth_solana_receiver: solana_instruction::AccountMeta,
+    pub guardian_set: solana_instruction::AccountMeta,
+    pub price_feed: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for PostPythPullOracleUpdateAtomic {
+    type ArrangedAccountMetas = PostPythPullOracleUpdateAtomicInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [keeper, pyth_solana_receiver, guardian_set, price_feed, remaining @ ..] = accounts
+        else {
+            return None;
+        };
+
+        Some(PostPythPullOracleUpdateAtomicInstructionAccountMetas {
+            keeper: keeper.clone(),
+            pyth_solana_receiver: pyth_solana_receiver.clone(),
+            guardian_set: guardian_set.clone(),
+            price_feed: price_feed.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct PostPythPullOracleUpdateAtomicInstructionAccountIndices {
+    pub keeper: usize,
+    pub pyth_solana_receiver: usize,
+    pub guardian_set: usize,
+    pub price_feed: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for PostPythPullOracleUpdateAtomic {
+    type ArrangedAccountIndices = PostPythPullOracleUpdateAtomicInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(PostPythPullOracleUpdateAtomicInstructionAccountIndices {
+            keeper: 0,
+            pyth_solana_receiver: 1,
+            guardian_set: 2,
+            price_feed: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for PostPythPullOracleUpdateAtomic {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "keeper",
+            "pyth_solana_receiver",
+            "guardian_set",
+            "price_feed",
+        ]
+    }
+}
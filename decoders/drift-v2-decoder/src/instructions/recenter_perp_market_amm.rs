@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +14,7 @@ pub struct RecenterPerpMarketAmmInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub state: solana_pubkey::Pubkey,
     pub perp_market: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for RecenterPerpMarketAmm {
@@ -21,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for RecenterPerpMarketAmm {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin, state, perp_market, _remaining @ ..] = accounts else {
+        let [admin, state, perp_market, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -29,6 +31,65 @@ impl carbon_core::deserialize::ArrangeAccounts for RecenterPerpMarketAmm {
             admin: admin.pubkey,
             state: state.pubkey,
             perp_market: perp_market.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct RecenterPerpMarketAmmInstructionAccountMetas {
+    pub admin: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub perp_market: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for RecenterPerpMarketAmm {
+    type ArrangedAccountMetas = RecenterPerpMarketAmmInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin, state, perp_market, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(RecenterPerpMarketAmmInstructionAccountMetas {
+            admin: admin.clone(),
+            state: state.clone(),
+            perp_market: perp_market.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct RecenterPerpMarketAmmInstructionAccountIndices {
+    pub admin: usize,
+    pub state: usize,
+    pub perp_market: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for RecenterPerpMarketAmm {
+    type ArrangedAccountIndices = RecenterPerpMarketAmmInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(RecenterPerpMarketAmmInstructionAccountIndices {
+            admin: 0,
+            state: 1,
+            perp_market: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for RecenterPerpMarketAmm {
+    fn account_names() -> &'static [&'static str] {
+        &["admin", "state", "perp_market"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +13,7 @@ pub struct ReclaimRentInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ReclaimRent {
@@ -20,7 +22,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ReclaimRent {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [user, user_stats, state, authority, rent, _remaining @ ..] = accounts else {
+        let [user, user_stats, state, authority, rent, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -30,6 +32,73 @@ impl carbon_core::deserialize::ArrangeAccounts for ReclaimRent {
             state: state.pubkey,
             authority: authority.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ReclaimRentInstructionAccountMetas {
+    pub user: solana_instruction::AccountMeta,
+    pub user_stats: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ReclaimRent {
+    type ArrangedAccountMetas = ReclaimRentInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [user, user_stats, state, authority, rent, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(ReclaimRentInstructionAccountMetas {
+            user: user.clone(),
+            user_stats: user_stats.clone(),
+            state: state.clone(),
+            authority: authority.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ReclaimRentInstructionAccountIndices {
+    pub user: usize,
+    pub user_stats: usize,
+    pub state: usize,
+    pub authority: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ReclaimRent {
+    type ArrangedAccountIndices = ReclaimRentInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(ReclaimRentInstructionAccountIndices {
+            user: 0,
+            user_stats: 1,
+            state: 2,
+            authority: 3,
+            rent: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ReclaimRent {
+    fn account_names() -> &'static [&'static str] {
+        &["user", "user_stats", "state", "authority", "rent"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +14,7 @@ pub struct RepegAmmCurveInstructionAccounts {
     pub perp_market: solana_pubkey::Pubkey,
     pub oracle: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for RepegAmmCurve {
@@ -21,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for RepegAmmCurve {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, perp_market, oracle, admin, _remaining @ ..] = accounts else {
+        let [state, perp_market, oracle, admin, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -30,6 +32,69 @@ impl carbon_core::deserialize::ArrangeAccounts for RepegAmmCurve {
             perp_market: perp_market.pubkey,
             oracle: oracle.pubkey,
             admin: admin.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct RepegAmmCurveInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub perp_market: solana_instruction::AccountMeta,
+    pub oracle: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for RepegAmmCurve {
+    type ArrangedAccountMetas = RepegAmmCurveInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, perp_market, oracle, admin, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(RepegAmmCurveInstructionAccountMetas {
+            state: state.clone(),
+            perp_market: perp_market.clone(),
+            oracle: oracle.clone(),
+            admin: admin.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct RepegAmmCurveInstructionAccountIndices {
+    pub state: usize,
+    pub perp_market: usize,
+    pub oracle: usize,
+    pub admin: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for RepegAmmCurve {
+    type ArrangedAccountIndices = RepegAmmCurveInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(RepegAmmCurveInstructionAccountIndices {
+            state: 0,
+            perp_market: 1,
+            oracle: 2,
+            admin: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for RepegAmmCurve {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "perp_market", "oracle", "admin"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -15,6 +16,7 @@ pub struct RequestRemoveInsuranceFundStakeInstructionAccounts {
     pub user_stats: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
     pub insurance_fund_vault: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for RequestRemoveInsuranceFundStake {
@@ -23,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for RequestRemoveInsuranceFundSta
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [spot_market, insurance_fund_stake, user_stats, authority, insurance_fund_vault, _remaining @ ..] =
+        let [spot_market, insurance_fund_stake, user_stats, authority, insurance_fund_vault, remaining @ ..] =
             accounts
         else {
             return None;
@@ -35,6 +37,81 @@ impl carbon_core::deserialize::ArrangeAccounts for RequestRemoveInsuranceFundSta
             user_stats: user_stats.pubkey,
             authority: authority.pubkey,
             insurance_fund_vault: insurance_fund_vault.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct RequestRemoveInsuranceFundStakeInstructionAccountMetas {
+    pub spot_market: solana_instruction::AccountMeta,
+    pub insurance_fund_stake: solana_instruction::AccountMeta,
+    pub user_stats: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub insurance_fund_vault: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for RequestRemoveInsuranceFundStake {
+    type ArrangedAccountMetas = RequestRemoveInsuranceFundStakeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [spot_market, insurance_fund_stake, user_stats, authority, insurance_fund_vault, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(RequestRemoveInsuranceFundStakeInstructionAccountMetas {
+            spot_market: spot_market.clone(),
+            insurance_fund_stake: insurance_fund_stake.clone(),
+            user_stats: user_stats.clone(),
+            authority: authority.clone(),
+            insurance_fund_vault: insurance_fund_vault.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct RequestRemoveInsuranceFundStakeInstructionAccountIndices {
+    pub spot_market: usize,
+    pub insurance_fund_stake: usize,
+    pub user_stats: usize,
+    pub authority: usize,
+    pub insurance_fund_vault: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for RequestRemoveInsuranceFundStake {
+    type ArrangedAccountIndices = RequestRemoveInsuranceFundStakeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(RequestRemoveInsuranceFundStakeInstructionAccountIndices {
+            spot_market: 0,
+            insurance_fund_stake: 1,
+            user_stats: 2,
+            authority: 3,
+            insurance_fund_vault: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for RequestRemoveInsuranceFundStake {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "spot_market",
+            "insurance_fund_stake",
+            "user_stats",
+            "authority",
+            "insurance_fund_vault",
+        ]
+    }
+}
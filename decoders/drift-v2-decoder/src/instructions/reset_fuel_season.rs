@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +12,7 @@ pub struct ResetFuelSeasonInstructionAccounts {
     pub authority: solana_pubkey::Pubkey,
     pub state: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ResetFuelSeason {
@@ -19,7 +21,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ResetFuelSeason {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [user_stats, authority, state, admin, _remaining @ ..] = accounts else {
+        let [user_stats, authority, state, admin, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +30,69 @@ impl carbon_core::deserialize::ArrangeAccounts for ResetFuelSeason {
             authority: authority.pubkey,
             state: state.pubkey,
             admin: admin.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ResetFuelSeasonInstructionAccountMetas {
+    pub user_stats: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ResetFuelSeason {
+    type ArrangedAccountMetas = ResetFuelSeasonInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [user_stats, authority, state, admin, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(ResetFuelSeasonInstructionAccountMetas {
+            user_stats: user_stats.clone(),
+            authority: authority.clone(),
+            state: state.clone(),
+            admin: admin.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ResetFuelSeasonInstructionAccountIndices {
+    pub user_stats: usize,
+    pub authority: usize,
+    pub state: usize,
+    pub admin: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ResetFuelSeason {
+    type ArrangedAccountIndices = ResetFuelSeasonInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(ResetFuelSeasonInstructionAccountIndices {
+            user_stats: 0,
+            authority: 1,
+            state: 2,
+            admin: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ResetFuelSeason {
+    fn account_names() -> &'static [&'static str] {
+        &["user_stats", "authority", "state", "admin"]
+    }
+}
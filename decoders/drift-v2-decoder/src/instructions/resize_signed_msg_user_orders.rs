@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +13,7 @@ pub struct ResizeSignedMsgUserOrdersInstructionAccounts {
     pub signed_msg_user_orders: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ResizeSignedMsgUserOrders {
@@ -20,7 +22,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ResizeSignedMsgUserOrders {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [signed_msg_user_orders, authority, system_program, _remaining @ ..] = accounts else {
+        let [signed_msg_user_orders, authority, system_program, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +30,65 @@ impl carbon_core::deserialize::ArrangeAccounts for ResizeSignedMsgUserOrders {
             signed_msg_user_orders: signed_msg_user_orders.pubkey,
             authority: authority.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ResizeSignedMsgUserOrdersInstructionAccountMetas {
+    pub signed_msg_user_orders: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ResizeSignedMsgUserOrders {
+    type ArrangedAccountMetas = ResizeSignedMsgUserOrdersInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [signed_msg_user_orders, authority, system_program, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(ResizeSignedMsgUserOrdersInstructionAccountMetas {
+            signed_msg_user_orders: signed_msg_user_orders.clone(),
+            authority: authority.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ResizeSignedMsgUserOrdersInstructionAccountIndices {
+    pub signed_msg_user_orders: usize,
+    pub authority: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ResizeSignedMsgUserOrders {
+    type ArrangedAccountIndices = ResizeSignedMsgUserOrdersInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(ResizeSignedMsgUserOrdersInstructionAccountIndices {
+            signed_msg_user_orders: 0,
+            authority: 1,
+            system_program: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ResizeSignedMsgUserOrders {
+    fn account_names() -> &'static [&'static str] {
+        &["signed_msg_user_orders", "authority", "system_program"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -16,6 +17,7 @@ pub struct ResolvePerpPnlDeficitInstructionAccounts {
     pub insurance_fund_vault: solana_pubkey::Pubkey,
     pub drift_signer: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ResolvePerpPnlDeficit {
@@ -24,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ResolvePerpPnlDeficit {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, authority, spot_market_vault, insurance_fund_vault, drift_signer, token_program, _remaining @ ..] =
+        let [state, authority, spot_market_vault, insurance_fund_vault, drift_signer, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -37,6 +39,86 @@ impl carbon_core::deserialize::ArrangeAccounts for ResolvePerpPnlDeficit {
             insurance_fund_vault: insurance_fund_vault.pubkey,
             drift_signer: drift_signer.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ResolvePerpPnlDeficitInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub spot_market_vault: solana_instruction::AccountMeta,
+    pub insurance_fund_vault: solana_instruction::AccountMeta,
+    pub drift_signer: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ResolvePerpPnlDeficit {
+    type ArrangedAccountMetas = ResolvePerpPnlDeficitInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, authority, spot_market_vault, insurance_fund_vault, drift_signer, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(ResolvePerpPnlDeficitInstructionAccountMetas {
+            state: state.clone(),
+            authority: authority.clone(),
+            spot_market_vault: spot_market_vault.clone(),
+            insurance_fund_vault: insurance_fund_vault.clone(),
+            drift_signer: drift_signer.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ResolvePerpPnlDeficitInstructionAccountIndices {
+    pub state: usize,
+    pub authority: usize,
+    pub spot_market_vault: usize,
+    pub insurance_fund_vault: usize,
+    pub drift_signer: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ResolvePerpPnlDeficit {
+    type ArrangedAccountIndices = ResolvePerpPnlDeficitInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(ResolvePerpPnlDeficitInstructionAccountIndices {
+            state: 0,
+            authority: 1,
+            spot_market_vault: 2,
+            insurance_fund_vault: 3,
+            drift_signer: 4,
+            token_program: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ResolvePerpPnlDeficit {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "authority",
+            "spot_market_vault",
+            "insurance_fund_vault",
+            "drift_signer",
+            "token_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -19,6 +20,7 @@ pub struct ResolveSpotBankruptcyInstructionAccounts {
     pub insurance_fund_vault: solana_pubkey::Pubkey,
     pub drift_signer: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ResolveSpotBankruptcy {
@@ -27,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ResolveSpotBankruptcy {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, authority, liquidator, liquidator_stats, user, user_stats, spot_market_vault, insurance_fund_vault, drift_signer, token_program, _remaining @ ..] =
+        let [state, authority, liquidator, liquidator_stats, user, user_stats, spot_market_vault, insurance_fund_vault, drift_signer, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -44,6 +46,106 @@ impl carbon_core::deserialize::ArrangeAccounts for ResolveSpotBankruptcy {
             insurance_fund_vault: insurance_fund_vault.pubkey,
             drift_signer: drift_signer.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ResolveSpotBankruptcyInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub liquidator: solana_instruction::AccountMeta,
+    pub liquidator_stats: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub user_stats: solana_instruction::AccountMeta,
+    pub spot_market_vault: solana_instruction::AccountMeta,
+    pub insurance_fund_vault: solana_instruction::AccountMeta,
+    pub drift_signer: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ResolveSpotBankruptcy {
+    type ArrangedAccountMetas = ResolveSpotBankruptcyInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, authority, liquidator, liquidator_stats, user, user_stats, spot_market_vault, insurance_fund_vault, drift_signer, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(ResolveSpotBankruptcyInstructionAccountMetas {
+            state: state.clone(),
+            authority: authority.clone(),
+            liquidator: liquidator.clone(),
+            liquidator_stats: liquidator_stats.clone(),
+            user: user.clone(),
+            user_stats: user_stats.clone(),
+            spot_market_vault: spot_market_vault.clone(),
+            insurance_fund_vault: insurance_fund_vault.clone(),
+            drift_signer: drift_signer.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ResolveSpotBankruptcyInstructionAccountIndices {
+    pub state: usize,
+    pub authority: usize,
+    pub liquidator: usize,
+    pub liquidator_stats: usize,
+    pub user: usize,
+    pub user_stats: usize,
+    pub spot_market_vault: usize,
+    pub insurance_fund_vault: usize,
+    pub drift_signer: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ResolveSpotBankruptcy {
+    type ArrangedAccountIndices = ResolveSpotBankruptcyInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 10 {
+            return None;
+        }
+
+        Some(ResolveSpotBankruptcyInstructionAccountIndices {
+            state: 0,
+            authority: 1,
+            liquidator: 2,
+            liquidator_stats: 3,
+            user: 4,
+            user_stats: 5,
+            spot_market_vault: 6,
+            insurance_fund_vault: 7,
+            drift_signer: 8,
+            token_program: 9,
+            remaining_accounts: 10..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ResolveSpotBankruptcy {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "authority",
+            "liquidator",
+            "liquidator_stats",
+            "user",
+            "user_stats",
+            "spot_market_vault",
+            "insurance_fund_vault",
+            "drift_signer",
+            "token_program",
+        ]
+    }
+}
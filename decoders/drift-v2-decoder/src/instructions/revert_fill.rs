@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +12,7 @@ pub struct RevertFillInstructionAccounts {
     pub authority: solana_pubkey::Pubkey,
     pub filler: solana_pubkey::Pubkey,
     pub filler_stats: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for RevertFill {
@@ -19,7 +21,7 @@ impl carbon_core::deserialize::ArrangeAccounts for RevertFill {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, authority, filler, filler_stats, _remaining @ ..] = accounts else {
+        let [state, authority, filler, filler_stats, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +30,69 @@ impl carbon_core::deserialize::ArrangeAccounts for RevertFill {
             authority: authority.pubkey,
             filler: filler.pubkey,
             filler_stats: filler_stats.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct RevertFillInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub filler: solana_instruction::AccountMeta,
+    pub filler_stats: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for RevertFill {
+    type ArrangedAccountMetas = RevertFillInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, authority, filler, filler_stats, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(RevertFillInstructionAccountMetas {
+            state: state.clone(),
+            authority: authority.clone(),
+            filler: filler.clone(),
+            filler_stats: filler_stats.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct RevertFillInstructionAccountIndices {
+    pub state: usize,
+    pub authority: usize,
+    pub filler: usize,
+    pub filler_stats: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for RevertFill {
+    type ArrangedAccountIndices = RevertFillInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(RevertFillInstructionAccountIndices {
+            state: 0,
+            authority: 1,
+            filler: 2,
+            filler_stats: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for RevertFill {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "authority", "filler", "filler_stats"]
+    }
+}
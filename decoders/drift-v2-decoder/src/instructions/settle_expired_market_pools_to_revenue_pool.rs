@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +12,7 @@ pub struct SettleExpiredMarketPoolsToRevenuePoolInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub spot_market: solana_pubkey::Pubkey,
     pub perp_market: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SettleExpiredMarketPoolsToRevenuePool {
@@ -19,7 +21,7 @@ impl carbon_core::deserialize::ArrangeAccounts for SettleExpiredMarketPoolsToRev
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, admin, spot_market, perp_market, _remaining @ ..] = accounts else {
+        let [state, admin, spot_market, perp_market, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +30,73 @@ impl carbon_core::deserialize::ArrangeAccounts for SettleExpiredMarketPoolsToRev
             admin: admin.pubkey,
             spot_market: spot_market.pubkey,
             perp_market: perp_market.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SettleExpiredMarketPoolsToRevenuePoolInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub spot_market: solana_instruction::AccountMeta,
+    pub perp_market: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SettleExpiredMarketPoolsToRevenuePool {
+    type ArrangedAccountMetas = SettleExpiredMarketPoolsToRevenuePoolInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, admin, spot_market, perp_market, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(
+            SettleExpiredMarketPoolsToRevenuePoolInstructionAccountMetas {
+                state: state.clone(),
+                admin: admin.clone(),
+                spot_market: spot_market.clone(),
+                perp_market: perp_market.clone(),
+                remaining_accounts: remaining.to_vec(),
+            },
+        )
+    }
+}
+
+pub struct SettleExpiredMarketPoolsToRevenuePoolInstructionAccountIndices {
+    pub state: usize,
+    pub admin: usize,
+    pub spot_market: usize,
+    pub perp_market: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SettleExpiredMarketPoolsToRevenuePool {
+    type ArrangedAccountIndices = SettleExpiredMarketPoolsToRevenuePoolInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(
+            SettleExpiredMarketPoolsToRevenuePoolInstructionAccountIndices {
+                state: 0,
+                admin: 1,
+                spot_market: 2,
+                perp_market: 3,
+                remaining_accounts: 4..accounts.len(),
+            },
+        )
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SettleExpiredMarketPoolsToRevenuePool {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "admin", "spot_market", "perp_market"]
+    }
+}
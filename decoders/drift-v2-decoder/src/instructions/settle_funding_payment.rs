@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -9,6 +10,7 @@ pub struct SettleFundingPayment {}
 pub struct SettleFundingPaymentInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub user: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SettleFundingPayment {
@@ -17,13 +19,68 @@ impl carbon_core::deserialize::ArrangeAccounts for SettleFundingPayment {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, user, _remaining @ ..] = accounts else {
+        let [state, user, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(SettleFundingPaymentInstructionAccounts {
             state: state.pubkey,
             user: user.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SettleFundingPaymentInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SettleFundingPayment {
+    type ArrangedAccountMetas = SettleFundingPaymentInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, user, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(SettleFundingPaymentInstructionAccountMetas {
+            state: state.clone(),
+            user: user.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SettleFundingPaymentInstructionAccountIndices {
+    pub state: usize,
+    pub user: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SettleFundingPayment {
+    type ArrangedAccountIndices = SettleFundingPaymentInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(SettleFundingPaymentInstructionAccountIndices {
+            state: 0,
+            user: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SettleFundingPayment {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "user"]
+    }
+}
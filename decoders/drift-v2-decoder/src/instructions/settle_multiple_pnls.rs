@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -16,6 +17,7 @@ pub struct SettleMultiplePnlsInstructionAccounts {
     pub user: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
     pub spot_market_vault: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SettleMultiplePnls {
@@ -24,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for SettleMultiplePnls {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, user, authority, spot_market_vault, _remaining @ ..] = accounts else {
+        let [state, user, authority, spot_market_vault, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -33,6 +35,69 @@ impl carbon_core::deserialize::ArrangeAccounts for SettleMultiplePnls {
             user: user.pubkey,
             authority: authority.pubkey,
             spot_market_vault: spot_market_vault.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SettleMultiplePnlsInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub spot_market_vault: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SettleMultiplePnls {
+    type ArrangedAccountMetas = SettleMultiplePnlsInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, user, authority, spot_market_vault, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(SettleMultiplePnlsInstructionAccountMetas {
+            state: state.clone(),
+            user: user.clone(),
+            authority: authority.clone(),
+            spot_market_vault: spot_market_vault.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SettleMultiplePnlsInstructionAccountIndices {
+    pub state: usize,
+    pub user: usize,
+    pub authority: usize,
+    pub spot_market_vault: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SettleMultiplePnls {
+    type ArrangedAccountIndices = SettleMultiplePnlsInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(SettleMultiplePnlsInstructionAccountIndices {
+            state: 0,
+            user: 1,
+            authority: 2,
+            spot_market_vault: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SettleMultiplePnls {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "user", "authority", "spot_market_vault"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +14,7 @@ pub struct SettlePnlInstructionAccounts {
     pub user: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
     pub spot_market_vault: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SettlePnl {
@@ -21,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for SettlePnl {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, user, authority, spot_market_vault, _remaining @ ..] = accounts else {
+        let [state, user, authority, spot_market_vault, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -30,6 +32,69 @@ impl carbon_core::deserialize::ArrangeAccounts for SettlePnl {
             user: user.pubkey,
             authority: authority.pubkey,
             spot_market_vault: spot_market_vault.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SettlePnlInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub spot_market_vault: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SettlePnl {
+    type ArrangedAccountMetas = SettlePnlInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, user, authority, spot_market_vault, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(SettlePnlInstructionAccountMetas {
+            state: state.clone(),
+            user: user.clone(),
+            authority: authority.clone(),
+            spot_market_vault: spot_market_vault.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SettlePnlInstructionAccountIndices {
+    pub state: usize,
+    pub user: usize,
+    pub authority: usize,
+    pub spot_market_vault: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SettlePnl {
+    type ArrangedAccountIndices = SettlePnlInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(SettlePnlInstructionAccountIndices {
+            state: 0,
+            user: 1,
+            authority: 2,
+            spot_market_vault: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SettlePnl {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "user", "authority", "spot_market_vault"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -15,6 +16,7 @@ pub struct SettleRevenueToInsuranceFundInstructionAccounts {
     pub drift_signer: solana_pubkey::Pubkey,
     pub insurance_fund_vault: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SettleRevenueToInsuranceFund {
@@ -23,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for SettleRevenueToInsuranceFund
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, spot_market, spot_market_vault, drift_signer, insurance_fund_vault, token_program, _remaining @ ..] =
+        let [state, spot_market, spot_market_vault, drift_signer, insurance_fund_vault, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -36,6 +38,86 @@ impl carbon_core::deserialize::ArrangeAccounts for SettleRevenueToInsuranceFund
             drift_signer: drift_signer.pubkey,
             insurance_fund_vault: insurance_fund_vault.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SettleRevenueToInsuranceFundInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub spot_market: solana_instruction::AccountMeta,
+    pub spot_market_vault: solana_instruction::AccountMeta,
+    pub drift_signer: solana_instruction::AccountMeta,
+    pub insurance_fund_vault: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SettleRevenueToInsuranceFund {
+    type ArrangedAccountMetas = SettleRevenueToInsuranceFundInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, spot_market, spot_market_vault, drift_signer, insurance_fund_vault, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(SettleRevenueToInsuranceFundInstructionAccountMetas {
+            state: state.clone(),
+            spot_market: spot_market.clone(),
+            spot_market_vault: spot_market_vault.clone(),
+            drift_signer: drift_signer.clone(),
+            insurance_fund_vault: insurance_fund_vault.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SettleRevenueToInsuranceFundInstructionAccountIndices {
+    pub state: usize,
+    pub spot_market: usize,
+    pub spot_market_vault: usize,
+    pub drift_signer: usize,
+    pub insurance_fund_vault: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SettleRevenueToInsuranceFund {
+    type ArrangedAccountIndices = SettleRevenueToInsuranceFundInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(SettleRevenueToInsuranceFundInstructionAccountIndices {
+            state: 0,
+            spot_market: 1,
+            spot_market_vault: 2,
+            drift_signer: 3,
+            insurance_fund_vault: 4,
+            token_program: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SettleRevenueToInsuranceFund {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "spot_market",
+            "spot_market_vault",
+            "drift_signer",
+            "insurance_fund_vault",
+            "token_program",
+        ]
+    }
+}
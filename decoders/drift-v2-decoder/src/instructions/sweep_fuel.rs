@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +12,7 @@ pub struct SweepFuelInstructionAccounts {
     pub user_stats: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
     pub signer: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SweepFuel {
@@ -19,7 +21,7 @@ impl carbon_core::deserialize::ArrangeAccounts for SweepFuel {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [fuel_overflow, user_stats, authority, signer, _remaining @ ..] = accounts else {
+        let [fuel_overflow, user_stats, authority, signer, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +30,69 @@ impl carbon_core::deserialize::ArrangeAccounts for SweepFuel {
             user_stats: user_stats.pubkey,
             authority: authority.pubkey,
             signer: signer.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SweepFuelInstructionAccountMetas {
+    pub fuel_overflow: solana_instruction::AccountMeta,
+    pub user_stats: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub signer: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SweepFuel {
+    type ArrangedAccountMetas = SweepFuelInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [fuel_overflow, user_stats, authority, signer, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(SweepFuelInstructionAccountMetas {
+            fuel_overflow: fuel_overflow.clone(),
+            user_stats: user_stats.clone(),
+            authority: authority.clone(),
+            signer: signer.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SweepFuelInstructionAccountIndices {
+    pub fuel_overflow: usize,
+    pub user_stats: usize,
+    pub authority: usize,
+    pub signer: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SweepFuel {
+    type ArrangedAccountIndices = SweepFuelInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(SweepFuelInstructionAccountIndices {
+            fuel_overflow: 0,
+            user_stats: 1,
+            authority: 2,
+            signer: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SweepFuel {
+    fn account_names() -> &'static [&'static str] {
+        &["fuel_overflow", "user_stats", "authority", "signer"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -16,6 +17,7 @@ pub struct TransferDepositInstructionAccounts {
     pub authority: solana_pubkey::Pubkey,
     pub state: solana_pubkey::Pubkey,
     pub spot_market_vault: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for TransferDeposit {
@@ -24,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for TransferDeposit {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [from_user, to_user, user_stats, authority, state, spot_market_vault, _remaining @ ..] =
+        let [from_user, to_user, user_stats, authority, state, spot_market_vault, remaining @ ..] =
             accounts
         else {
             return None;
@@ -37,6 +39,86 @@ impl carbon_core::deserialize::ArrangeAccounts for TransferDeposit {
             authority: authority.pubkey,
             state: state.pubkey,
             spot_market_vault: spot_market_vault.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct TransferDepositInstructionAccountMetas {
+    pub from_user: solana_instruction::AccountMeta,
+    pub to_user: solana_instruction::AccountMeta,
+    pub user_stats: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub spot_market_vault: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for TransferDeposit {
+    type ArrangedAccountMetas = TransferDepositInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [from_user, to_user, user_stats, authority, state, spot_market_vault, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(TransferDepositInstructionAccountMetas {
+            from_user: from_user.clone(),
+            to_user: to_user.clone(),
+            user_stats: user_stats.clone(),
+            authority: authority.clone(),
+            state: state.clone(),
+            spot_market_vault: spot_market_vault.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct TransferDepositInstructionAccountIndices {
+    pub from_user: usize,
+    pub to_user: usize,
+    pub user_stats: usize,
+    pub authority: usize,
+    pub state: usize,
+    pub spot_market_vault: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for TransferDeposit {
+    type ArrangedAccountIndices = TransferDepositInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(TransferDepositInstructionAccountIndices {
+            from_user: 0,
+            to_user: 1,
+            user_stats: 2,
+            authority: 3,
+            state: 4,
+            spot_market_vault: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for TransferDeposit {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "from_user",
+            "to_user",
+            "user_stats",
+            "authority",
+            "state",
+            "spot_market_vault",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -24,6 +25,7 @@ pub struct TransferPoolsInstructionAccounts {
     pub borrow_from_spot_market_vault: solana_pubkey::Pubkey,
     pub borrow_to_spot_market_vault: solana_pubkey::Pubkey,
     pub drift_signer: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for TransferPools {
@@ -32,7 +34,7 @@ impl carbon_core::deserialize::ArrangeAccounts for TransferPools {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [from_user, to_user, user_stats, authority, state, deposit_from_spot_market_vault, deposit_to_spot_market_vault, borrow_from_spot_market_vault, borrow_to_spot_market_vault, drift_signer, _remaining @ ..] =
+        let [from_user, to_user, user_stats, authority, state, deposit_from_spot_market_vault, deposit_to_spot_market_vault, borrow_from_spot_market_vault, borrow_to_spot_market_vault, drift_signer, remaining @ ..] =
             accounts
         else {
             return None;
@@ -49,6 +51,106 @@ impl carbon_core::deserialize::ArrangeAccounts for TransferPools {
             borrow_from_spot_market_vault: borrow_from_spot_market_vault.pubkey,
             borrow_to_spot_market_vault: borrow_to_spot_market_vault.pubkey,
             drift_signer: drift_signer.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct TransferPoolsInstructionAccountMetas {
+    pub from_user: solana_instruction::AccountMeta,
+    pub to_user: solana_instruction::AccountMeta,
+    pub user_stats: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub deposit_from_spot_market_vault: solana_instruction::AccountMeta,
+    pub deposit_to_spot_market_vault: solana_instruction::AccountMeta,
+    pub borrow_from_spot_market_vault: solana_instruction::AccountMeta,
+    pub borrow_to_spot_market_vault: solana_instruction::AccountMeta,
+    pub drift_signer: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for TransferPools {
+    type ArrangedAccountMetas = TransferPoolsInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [from_user, to_user, user_stats, authority, state, deposit_from_spot_market_vault, deposit_to_spot_market_vault, borrow_from_spot_market_vault, borrow_to_spot_market_vault, drift_signer, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(TransferPoolsInstructionAccountMetas {
+            from_user: from_user.clone(),
+            to_user: to_user.clone(),
+            user_stats: user_stats.clone(),
+            authority: authority.clone(),
+            state: state.clone(),
+            deposit_from_spot_market_vault: deposit_from_spot_market_vault.clone(),
+            deposit_to_spot_market_vault: deposit_to_spot_market_vault.clone(),
+            borrow_from_spot_market_vault: borrow_from_spot_market_vault.clone(),
+            borrow_to_spot_market_vault: borrow_to_spot_market_vault.clone(),
+            drift_signer: drift_signer.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct TransferPoolsInstructionAccountIndices {
+    pub from_user: usize,
+    pub to_user: usize,
+    pub user_stats: usize,
+    pub authority: usize,
+    pub state: usize,
+    pub deposit_from_spot_market_vault: usize,
+    pub deposit_to_spot_market_vault: usize,
+    pub borrow_from_spot_market_vault: usize,
+    pub borrow_to_spot_market_vault: usize,
+    pub drift_signer: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for TransferPools {
+    type ArrangedAccountIndices = TransferPoolsInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 10 {
+            return None;
+        }
+
+        Some(TransferPoolsInstructionAccountIndices {
+            from_user: 0,
+            to_user: 1,
+            user_stats: 2,
+            authority: 3,
+            state: 4,
+            deposit_from_spot_market_vault: 5,
+            deposit_to_spot_market_vault: 6,
+            borrow_from_spot_market_vault: 7,
+            borrow_to_spot_market_vault: 8,
+            drift_signer: 9,
+            remaining_accounts: 10..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for TransferPools {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "from_user",
+            "to_user",
+            "user_stats",
+            "authority",
+            "state",
+            "deposit_from_spot_market_vault",
+            "deposit_to_spot_market_vault",
+            "borrow_from_spot_market_vault",
+            "borrow_to_spot_market_vault",
+            "drift_signer",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -18,6 +19,7 @@ pub struct TransferProtocolIfSharesInstructionAccounts {
     pub user_stats: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
     pub insurance_fund_vault: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for TransferProtocolIfShares {
@@ -26,7 +28,7 @@ impl carbon_core::deserialize::ArrangeAccounts for TransferProtocolIfShares {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [signer, transfer_config, state, spot_market, insurance_fund_stake, user_stats, authority, insurance_fund_vault, _remaining @ ..] =
+        let [signer, transfer_config, state, spot_market, insurance_fund_stake, user_stats, authority, insurance_fund_vault, remaining @ ..] =
             accounts
         else {
             return None;
@@ -41,6 +43,96 @@ impl carbon_core::deserialize::ArrangeAccounts for TransferProtocolIfShares {
             user_stats: user_stats.pubkey,
             authority: authority.pubkey,
             insurance_fund_vault: insurance_fund_vault.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct TransferProtocolIfSharesInstructionAccountMetas {
+    pub signer: solana_instruction::AccountMeta,
+    pub transfer_config: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub spot_market: solana_instruction::AccountMeta,
+    pub insurance_fund_stake: solana_instruction::AccountMeta,
+    pub user_stats: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub insurance_fund_vault: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for TransferProtocolIfShares {
+    type ArrangedAccountMetas = TransferProtocolIfSharesInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [signer, transfer_config, state, spot_market, insurance_fund_stake, user_stats, authority, insurance_fund_vault, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(TransferProtocolIfSharesInstructionAccountMetas {
+            signer: signer.clone(),
+            transfer_config: transfer_config.clone(),
+            state: state.clone(),
+            spot_market: spot_market.clone(),
+            insurance_fund_stake: insurance_fund_stake.clone(),
+            user_stats: user_stats.clone(),
+            authority: authority.clone(),
+            insurance_fund_vault: insurance_fund_vault.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct TransferProtocolIfSharesInstructionAccountIndices {
+    pub signer: usize,
+    pub transfer_config: usize,
+    pub state: usize,
+    pub spot_market: usize,
+    pub insurance_fund_stake: usize,
+    pub user_stats: usize,
+    pub authority: usize,
+    pub insurance_fund_vault: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for TransferProtocolIfShares {
+    type ArrangedAccountIndices = TransferProtocolIfSharesInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(TransferProtocolIfSharesInstructionAccountIndices {
+            signer: 0,
+            transfer_config: 1,
+            state: 2,
+            spot_market: 3,
+            insurance_fund_stake: 4,
+            user_stats: 5,
+            authority: 6,
+            insurance_fund_vault: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for TransferProtocolIfShares {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "signer",
+            "transfer_config",
+            "state",
+            "spot_market",
+            "insurance_fund_stake",
+            "user_stats",
+            "authority",
+            "insurance_fund_vault",
+        ]
+    }
+}
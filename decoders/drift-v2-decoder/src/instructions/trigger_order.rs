@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +14,7 @@ pub struct TriggerOrderInstructionAccounts {
     pub authority: solana_pubkey::Pubkey,
     pub filler: solana_pubkey::Pubkey,
     pub user: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for TriggerOrder {
@@ -21,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for TriggerOrder {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, authority, filler, user, _remaining @ ..] = accounts else {
+        let [state, authority, filler, user, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -30,6 +32,69 @@ impl carbon_core::deserialize::ArrangeAccounts for TriggerOrder {
             authority: authority.pubkey,
             filler: filler.pubkey,
             user: user.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct TriggerOrderInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub filler: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for TriggerOrder {
+    type ArrangedAccountMetas = TriggerOrderInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, authority, filler, user, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(TriggerOrderInstructionAccountMetas {
+            state: state.clone(),
+            authority: authority.clone(),
+            filler: filler.clone(),
+            user: user.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct TriggerOrderInstructionAccountIndices {
+    pub state: usize,
+    pub authority: usize,
+    pub filler: usize,
+    pub user: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for TriggerOrder {
+    type ArrangedAccountIndices = TriggerOrderInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(TriggerOrderInstructionAccountIndices {
+            state: 0,
+            authority: 1,
+            filler: 2,
+            user: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for TriggerOrder {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "authority", "filler", "user"]
+    }
+}
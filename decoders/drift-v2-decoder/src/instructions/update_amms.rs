@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +12,7 @@ pub struct UpdateAmms {
 pub struct UpdateAmmsInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateAmms {
@@ -19,13 +21,68 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateAmms {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, authority, _remaining @ ..] = accounts else {
+        let [state, authority, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(UpdateAmmsInstructionAccounts {
             state: state.pubkey,
             authority: authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateAmmsInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateAmms {
+    type ArrangedAccountMetas = UpdateAmmsInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateAmmsInstructionAccountMetas {
+            state: state.clone(),
+            authority: authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateAmmsInstructionAccountIndices {
+    pub state: usize,
+    pub authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateAmms {
+    type ArrangedAccountIndices = UpdateAmmsInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(UpdateAmmsInstructionAccountIndices {
+            state: 0,
+            authority: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateAmms {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "authority"]
+    }
+}
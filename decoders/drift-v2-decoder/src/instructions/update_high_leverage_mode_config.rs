@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +14,7 @@ pub struct UpdateHighLeverageModeConfigInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub high_leverage_mode_config: solana_pubkey::Pubkey,
     pub state: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateHighLeverageModeConfig {
@@ -21,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateHighLeverageModeConfig
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin, high_leverage_mode_config, state, _remaining @ ..] = accounts else {
+        let [admin, high_leverage_mode_config, state, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -29,6 +31,65 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateHighLeverageModeConfig
             admin: admin.pubkey,
             high_leverage_mode_config: high_leverage_mode_config.pubkey,
             state: state.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateHighLeverageModeConfigInstructionAccountMetas {
+    pub admin: solana_instruction::AccountMeta,
+    pub high_leverage_mode_config: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateHighLeverageModeConfig {
+    type ArrangedAccountMetas = UpdateHighLeverageModeConfigInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin, high_leverage_mode_config, state, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateHighLeverageModeConfigInstructionAccountMetas {
+            admin: admin.clone(),
+            high_leverage_mode_config: high_leverage_mode_config.clone(),
+            state: state.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateHighLeverageModeConfigInstructionAccountIndices {
+    pub admin: usize,
+    pub high_leverage_mode_config: usize,
+    pub state: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateHighLeverageModeConfig {
+    type ArrangedAccountIndices = UpdateHighLeverageModeConfigInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(UpdateHighLeverageModeConfigInstructionAccountIndices {
+            admin: 0,
+            high_leverage_mode_config: 1,
+            state: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateHighLeverageModeConfig {
+    fn account_names() -> &'static [&'static str] {
+        &["admin", "high_leverage_mode_config", "state"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +12,7 @@ pub struct UpdateInitialPctToLiquidate {
 pub struct UpdateInitialPctToLiquidateInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub state: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateInitialPctToLiquidate {
@@ -19,13 +21,68 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateInitialPctToLiquidate {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin, state, _remaining @ ..] = accounts else {
+        let [admin, state, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(UpdateInitialPctToLiquidateInstructionAccounts {
             admin: admin.pubkey,
             state: state.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateInitialPctToLiquidateInstructionAccountMetas {
+    pub admin: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateInitialPctToLiquidate {
+    type ArrangedAccountMetas = UpdateInitialPctToLiquidateInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin, state, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateInitialPctToLiquidateInstructionAccountMetas {
+            admin: admin.clone(),
+            state: state.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateInitialPctToLiquidateInstructionAccountIndices {
+    pub admin: usize,
+    pub state: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateInitialPctToLiquidate {
+    type ArrangedAccountIndices = UpdateInitialPctToLiquidateInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(UpdateInitialPctToLiquidateInstructionAccountIndices {
+            admin: 0,
+            state: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateInitialPctToLiquidate {
+    fn account_names() -> &'static [&'static str] {
+        &["admin", "state"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +13,7 @@ pub struct UpdatePerpBidAskTwapInstructionAccounts {
     pub oracle: solana_pubkey::Pubkey,
     pub keeper_stats: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdatePerpBidAskTwap {
@@ -20,8 +22,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdatePerpBidAskTwap {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, perp_market, oracle, keeper_stats, authority, _remaining @ ..] = accounts
-        else {
+        let [state, perp_market, oracle, keeper_stats, authority, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -31,6 +32,79 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdatePerpBidAskTwap {
             oracle: oracle.pubkey,
             keeper_stats: keeper_stats.pubkey,
             authority: authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdatePerpBidAskTwapInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub perp_market: solana_instruction::AccountMeta,
+    pub oracle: solana_instruction::AccountMeta,
+    pub keeper_stats: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdatePerpBidAskTwap {
+    type ArrangedAccountMetas = UpdatePerpBidAskTwapInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, perp_market, oracle, keeper_stats, authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdatePerpBidAskTwapInstructionAccountMetas {
+            state: state.clone(),
+            perp_market: perp_market.clone(),
+            oracle: oracle.clone(),
+            keeper_stats: keeper_stats.clone(),
+            authority: authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdatePerpBidAskTwapInstructionAccountIndices {
+    pub state: usize,
+    pub perp_market: usize,
+    pub oracle: usize,
+    pub keeper_stats: usize,
+    pub authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdatePerpBidAskTwap {
+    type ArrangedAccountIndices = UpdatePerpBidAskTwapInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(UpdatePerpBidAskTwapInstructionAccountIndices {
+            state: 0,
+            perp_market: 1,
+            oracle: 2,
+            keeper_stats: 3,
+            authority: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdatePerpBidAskTwap {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "perp_market",
+            "oracle",
+            "keeper_stats",
+            "authority",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +12,7 @@ pub struct UpdatePerpMarketAmmOracleTwapInstructionAccounts {
     pub perp_market: solana_pubkey::Pubkey,
     pub oracle: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdatePerpMarketAmmOracleTwap {
@@ -19,7 +21,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdatePerpMarketAmmOracleTwap
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, perp_market, oracle, admin, _remaining @ ..] = accounts else {
+        let [state, perp_market, oracle, admin, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +30,69 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdatePerpMarketAmmOracleTwap
             perp_market: perp_market.pubkey,
             oracle: oracle.pubkey,
             admin: admin.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdatePerpMarketAmmOracleTwapInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub perp_market: solana_instruction::AccountMeta,
+    pub oracle: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdatePerpMarketAmmOracleTwap {
+    type ArrangedAccountMetas = UpdatePerpMarketAmmOracleTwapInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, perp_market, oracle, admin, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdatePerpMarketAmmOracleTwapInstructionAccountMetas {
+            state: state.clone(),
+            perp_market: perp_market.clone(),
+            oracle: oracle.clone(),
+            admin: admin.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdatePerpMarketAmmOracleTwapInstructionAccountIndices {
+    pub state: usize,
+    pub perp_market: usize,
+    pub oracle: usize,
+    pub admin: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdatePerpMarketAmmOracleTwap {
+    type ArrangedAccountIndices = UpdatePerpMarketAmmOracleTwapInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(UpdatePerpMarketAmmOracleTwapInstructionAccountIndices {
+            state: 0,
+            perp_market: 1,
+            oracle: 2,
+            admin: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdatePerpMarketAmmOracleTwap {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "perp_market", "oracle", "admin"]
+    }
+}
@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -16,6 +17,7 @@ pub struct UpdatePerpMarketAmmSummaryStatsInstructionAccounts {
     pub perp_market: solana_pubkey::Pubkey,
     pub spot_market: solana_pubkey::Pubkey,
     pub oracle: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdatePerpMarketAmmSummaryStats {
@@ -24,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdatePerpMarketAmmSummarySta
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin, state, perp_market, spot_market, oracle, _remaining @ ..] = accounts else {
+        let [admin, state, perp_market, spot_market, oracle, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -34,6 +36,73 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdatePerpMarketAmmSummarySta
             perp_market: perp_market.pubkey,
             spot_market: spot_market.pubkey,
             oracle: oracle.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdatePerpMarketAmmSummaryStatsInstructionAccountMetas {
+    pub admin: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub perp_market: solana_instruction::AccountMeta,
+    pub spot_market: solana_instruction::AccountMeta,
+    pub oracle: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdatePerpMarketAmmSummaryStats {
+    type ArrangedAccountMetas = UpdatePerpMarketAmmSummaryStatsInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin, state, perp_market, spot_market, oracle, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdatePerpMarketAmmSummaryStatsInstructionAccountMetas {
+            admin: admin.clone(),
+            state: state.clone(),
+            perp_market: perp_market.clone(),
+            spot_market: spot_market.clone(),
+            oracle: oracle.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdatePerpMarketAmmSummaryStatsInstructionAccountIndices {
+    pub admin: usize,
+    pub state: usize,
+    pub perp_market: usize,
+    pub spot_market: usize,
+    pub oracle: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdatePerpMarketAmmSummaryStats {
+    type ArrangedAccountIndices = UpdatePerpMarketAmmSummaryStatsInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(UpdatePerpMarketAmmSummaryStatsInstructionAccountIndices {
+            admin: 0,
+            state: 1,
+            perp_market: 2,
+            spot_market: 3,
+            oracle: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdatePerpMarketAmmSummaryStats {
+    fn account_names() -> &'static [&'static str] {
+        &["admin", "state", "perp_market", "spot_market", "oracle"]
+    }
+}
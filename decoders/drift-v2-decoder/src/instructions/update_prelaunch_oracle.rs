@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -10,6 +11,7 @@ pub struct UpdatePrelaunchOracleInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub perp_market: solana_pubkey::Pubkey,
     pub oracle: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdatePrelaunchOracle {
@@ -18,7 +20,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdatePrelaunchOracle {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, perp_market, oracle, _remaining @ ..] = accounts else {
+        let [state, perp_market, oracle, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -26,6 +28,65 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdatePrelaunchOracle {
             state: state.pubkey,
             perp_market: perp_market.pubkey,
             oracle: oracle.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdatePrelaunchOracleInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub perp_market: solana_instruction::AccountMeta,
+    pub oracle: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdatePrelaunchOracle {
+    type ArrangedAccountMetas = UpdatePrelaunchOracleInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, perp_market, oracle, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdatePrelaunchOracleInstructionAccountMetas {
+            state: state.clone(),
+            perp_market: perp_market.clone(),
+            oracle: oracle.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdatePrelaunchOracleInstructionAccountIndices {
+    pub state: usize,
+    pub perp_market: usize,
+    pub oracle: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdatePrelaunchOracle {
+    type ArrangedAccountIndices = UpdatePrelaunchOracleInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(UpdatePrelaunchOracleInstructionAccountIndices {
+            state: 0,
+            perp_market: 1,
+            oracle: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdatePrelaunchOracle {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "perp_market", "oracle"]
+    }
+}
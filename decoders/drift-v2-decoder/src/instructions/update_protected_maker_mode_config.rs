@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +14,7 @@ pub struct UpdateProtectedMakerModeConfigInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub protected_maker_mode_config: solana_pubkey::Pubkey,
     pub state: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateProtectedMakerModeConfig {
@@ -21,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateProtectedMakerModeConfi
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin, protected_maker_mode_config, state, _remaining @ ..] = accounts else {
+        let [admin, protected_maker_mode_config, state, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -29,6 +31,65 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateProtectedMakerModeConfi
             admin: admin.pubkey,
             protected_maker_mode_config: protected_maker_mode_config.pubkey,
             state: state.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateProtectedMakerModeConfigInstructionAccountMetas {
+    pub admin: solana_instruction::AccountMeta,
+    pub protected_maker_mode_config: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateProtectedMakerModeConfig {
+    type ArrangedAccountMetas = UpdateProtectedMakerModeConfigInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin, protected_maker_mode_config, state, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateProtectedMakerModeConfigInstructionAccountMetas {
+            admin: admin.clone(),
+            protected_maker_mode_config: protected_maker_mode_config.clone(),
+            state: state.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateProtectedMakerModeConfigInstructionAccountIndices {
+    pub admin: usize,
+    pub protected_maker_mode_config: usize,
+    pub state: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateProtectedMakerModeConfig {
+    type ArrangedAccountIndices = UpdateProtectedMakerModeConfigInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(UpdateProtectedMakerModeConfigInstructionAccountIndices {
+            admin: 0,
+            protected_maker_mode_config: 1,
+            state: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateProtectedMakerModeConfig {
+    fn account_names() -> &'static [&'static str] {
+        &["admin", "protected_maker_mode_config", "state"]
+    }
+}
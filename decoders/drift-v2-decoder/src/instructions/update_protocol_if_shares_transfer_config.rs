@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +14,7 @@ pub struct UpdateProtocolIfSharesTransferConfigInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub protocol_if_shares_transfer_config: solana_pubkey::Pubkey,
     pub state: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateProtocolIfSharesTransferConfig {
@@ -21,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateProtocolIfSharesTransfe
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin, protocol_if_shares_transfer_config, state, _remaining @ ..] = accounts else {
+        let [admin, protocol_if_shares_transfer_config, state, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -29,6 +31,69 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateProtocolIfSharesTransfe
             admin: admin.pubkey,
             protocol_if_shares_transfer_config: protocol_if_shares_transfer_config.pubkey,
             state: state.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateProtocolIfSharesTransferConfigInstructionAccountMetas {
+    pub admin: solana_instruction::AccountMeta,
+    pub protocol_if_shares_transfer_config: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateProtocolIfSharesTransferConfig {
+    type ArrangedAccountMetas = UpdateProtocolIfSharesTransferConfigInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin, protocol_if_shares_transfer_config, state, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(
+            UpdateProtocolIfSharesTransferConfigInstructionAccountMetas {
+                admin: admin.clone(),
+                protocol_if_shares_transfer_config: protocol_if_shares_transfer_config.clone(),
+                state: state.clone(),
+                remaining_accounts: remaining.to_vec(),
+            },
+        )
+    }
+}
+
+pub struct UpdateProtocolIfSharesTransferConfigInstructionAccountIndices {
+    pub admin: usize,
+    pub protocol_if_shares_transfer_config: usize,
+    pub state: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateProtocolIfSharesTransferConfig {
+    type ArrangedAccountIndices = UpdateProtocolIfSharesTransferConfigInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(
+            UpdateProtocolIfSharesTransferConfigInstructionAccountIndices {
+                admin: 0,
+                protocol_if_shares_transfer_config: 1,
+                state: 2,
+                remaining_accounts: 3..accounts.len(),
+            },
+        )
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateProtocolIfSharesTransferConfig {
+    fn account_names() -> &'static [&'static str] {
+        &["admin", "protocol_if_shares_transfer_config", "state"]
+    }
+}
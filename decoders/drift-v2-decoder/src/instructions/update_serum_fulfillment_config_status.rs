@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct UpdateSerumFulfillmentConfigStatusInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub serum_fulfillment_config: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateSerumFulfillmentConfigStatus {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateSerumFulfillmentConfigS
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, serum_fulfillment_config, admin, _remaining @ ..] = accounts else {
+        let [state, serum_fulfillment_config, admin, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -30,6 +32,67 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateSerumFulfillmentConfigS
             state: state.pubkey,
             serum_fulfillment_config: serum_fulfillment_config.pubkey,
             admin: admin.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateSerumFulfillmentConfigStatusInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub serum_fulfillment_config: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateSerumFulfillmentConfigStatus {
+    type ArrangedAccountMetas = UpdateSerumFulfillmentConfigStatusInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, serum_fulfillment_config, admin, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateSerumFulfillmentConfigStatusInstructionAccountMetas {
+            state: state.clone(),
+            serum_fulfillment_config: serum_fulfillment_config.clone(),
+            admin: admin.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateSerumFulfillmentConfigStatusInstructionAccountIndices {
+    pub state: usize,
+    pub serum_fulfillment_config: usize,
+    pub admin: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateSerumFulfillmentConfigStatus {
+    type ArrangedAccountIndices = UpdateSerumFulfillmentConfigStatusInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(
+            UpdateSerumFulfillmentConfigStatusInstructionAccountIndices {
+                state: 0,
+                serum_fulfillment_config: 1,
+                admin: 2,
+                remaining_accounts: 3..accounts.len(),
+            },
+        )
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateSerumFulfillmentConfigStatus {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "serum_fulfillment_config", "admin"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -10,6 +11,7 @@ pub struct UpdateSerumVaultInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
     pub srm_vault: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateSerumVault {
@@ -18,7 +20,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateSerumVault {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, admin, srm_vault, _remaining @ ..] = accounts else {
+        let [state, admin, srm_vault, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -26,6 +28,65 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateSerumVault {
             state: state.pubkey,
             admin: admin.pubkey,
             srm_vault: srm_vault.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateSerumVaultInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub srm_vault: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateSerumVault {
+    type ArrangedAccountMetas = UpdateSerumVaultInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, admin, srm_vault, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateSerumVaultInstructionAccountMetas {
+            state: state.clone(),
+            admin: admin.clone(),
+            srm_vault: srm_vault.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateSerumVaultInstructionAccountIndices {
+    pub state: usize,
+    pub admin: usize,
+    pub srm_vault: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateSerumVault {
+    type ArrangedAccountIndices = UpdateSerumVaultInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(UpdateSerumVaultInstructionAccountIndices {
+            state: 0,
+            admin: 1,
+            srm_vault: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateSerumVault {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "admin", "srm_vault"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -15,6 +16,7 @@ pub struct UpdateSpotMarketBorrowRateInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub state: solana_pubkey::Pubkey,
     pub spot_market: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateSpotMarketBorrowRate {
@@ -23,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateSpotMarketBorrowRate {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin, state, spot_market, _remaining @ ..] = accounts else {
+        let [admin, state, spot_market, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -31,6 +33,65 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateSpotMarketBorrowRate {
             admin: admin.pubkey,
             state: state.pubkey,
             spot_market: spot_market.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateSpotMarketBorrowRateInstructionAccountMetas {
+    pub admin: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub spot_market: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateSpotMarketBorrowRate {
+    type ArrangedAccountMetas = UpdateSpotMarketBorrowRateInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin, state, spot_market, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateSpotMarketBorrowRateInstructionAccountMetas {
+            admin: admin.clone(),
+            state: state.clone(),
+            spot_market: spot_market.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateSpotMarketBorrowRateInstructionAccountIndices {
+    pub admin: usize,
+    pub state: usize,
+    pub spot_market: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateSpotMarketBorrowRate {
+    type ArrangedAccountIndices = UpdateSpotMarketBorrowRateInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(UpdateSpotMarketBorrowRateInstructionAccountIndices {
+            admin: 0,
+            state: 1,
+            spot_market: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateSpotMarketBorrowRate {
+    fn account_names() -> &'static [&'static str] {
+        &["admin", "state", "spot_market"]
+    }
+}
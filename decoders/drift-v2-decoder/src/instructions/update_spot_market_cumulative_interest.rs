@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +12,7 @@ pub struct UpdateSpotMarketCumulativeInterestInstructionAccounts {
     pub spot_market: solana_pubkey::Pubkey,
     pub oracle: solana_pubkey::Pubkey,
     pub spot_market_vault: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateSpotMarketCumulativeInterest {
@@ -19,7 +21,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateSpotMarketCumulativeInt
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, spot_market, oracle, spot_market_vault, _remaining @ ..] = accounts else {
+        let [state, spot_market, oracle, spot_market_vault, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +30,71 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateSpotMarketCumulativeInt
             spot_market: spot_market.pubkey,
             oracle: oracle.pubkey,
             spot_market_vault: spot_market_vault.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateSpotMarketCumulativeInterestInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub spot_market: solana_instruction::AccountMeta,
+    pub oracle: solana_instruction::AccountMeta,
+    pub spot_market_vault: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateSpotMarketCumulativeInterest {
+    type ArrangedAccountMetas = UpdateSpotMarketCumulativeInterestInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, spot_market, oracle, spot_market_vault, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateSpotMarketCumulativeInterestInstructionAccountMetas {
+            state: state.clone(),
+            spot_market: spot_market.clone(),
+            oracle: oracle.clone(),
+            spot_market_vault: spot_market_vault.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateSpotMarketCumulativeInterestInstructionAccountIndices {
+    pub state: usize,
+    pub spot_market: usize,
+    pub oracle: usize,
+    pub spot_market_vault: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateSpotMarketCumulativeInterest {
+    type ArrangedAccountIndices = UpdateSpotMarketCumulativeInterestInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(
+            UpdateSpotMarketCumulativeInterestInstructionAccountIndices {
+                state: 0,
+                spot_market: 1,
+                oracle: 2,
+                spot_market_vault: 3,
+                remaining_accounts: 4..accounts.len(),
+            },
+        )
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateSpotMarketCumulativeInterest {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "spot_market", "oracle", "spot_market_vault"]
+    }
+}
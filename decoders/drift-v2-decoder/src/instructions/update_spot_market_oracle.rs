@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -18,6 +19,7 @@ pub struct UpdateSpotMarketOracleInstructionAccounts {
     pub spot_market: solana_pubkey::Pubkey,
     pub oracle: solana_pubkey::Pubkey,
     pub old_oracle: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateSpotMarketOracle {
@@ -26,7 +28,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateSpotMarketOracle {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin, state, spot_market, oracle, old_oracle, _remaining @ ..] = accounts else {
+        let [admin, state, spot_market, oracle, old_oracle, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -36,6 +38,73 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateSpotMarketOracle {
             spot_market: spot_market.pubkey,
             oracle: oracle.pubkey,
             old_oracle: old_oracle.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateSpotMarketOracleInstructionAccountMetas {
+    pub admin: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub spot_market: solana_instruction::AccountMeta,
+    pub oracle: solana_instruction::AccountMeta,
+    pub old_oracle: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateSpotMarketOracle {
+    type ArrangedAccountMetas = UpdateSpotMarketOracleInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin, state, spot_market, oracle, old_oracle, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateSpotMarketOracleInstructionAccountMetas {
+            admin: admin.clone(),
+            state: state.clone(),
+            spot_market: spot_market.clone(),
+            oracle: oracle.clone(),
+            old_oracle: old_oracle.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateSpotMarketOracleInstructionAccountIndices {
+    pub admin: usize,
+    pub state: usize,
+    pub spot_market: usize,
+    pub oracle: usize,
+    pub old_oracle: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateSpotMarketOracle {
+    type ArrangedAccountIndices = UpdateSpotMarketOracleInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(UpdateSpotMarketOracleInstructionAccountIndices {
+            admin: 0,
+            state: 1,
+            spot_market: 2,
+            oracle: 3,
+            old_oracle: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateSpotMarketOracle {
+    fn account_names() -> &'static [&'static str] {
+        &["admin", "state", "spot_market", "oracle", "old_oracle"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +13,7 @@ pub struct UpdateUserAdvancedLp {
 pub struct UpdateUserAdvancedLpInstructionAccounts {
     pub user: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateUserAdvancedLp {
@@ -20,13 +22,68 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateUserAdvancedLp {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [user, authority, _remaining @ ..] = accounts else {
+        let [user, authority, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(UpdateUserAdvancedLpInstructionAccounts {
             user: user.pubkey,
             authority: authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateUserAdvancedLpInstructionAccountMetas {
+    pub user: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateUserAdvancedLp {
+    type ArrangedAccountMetas = UpdateUserAdvancedLpInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [user, authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateUserAdvancedLpInstructionAccountMetas {
+            user: user.clone(),
+            authority: authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateUserAdvancedLpInstructionAccountIndices {
+    pub user: usize,
+    pub authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateUserAdvancedLp {
+    type ArrangedAccountIndices = UpdateUserAdvancedLpInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(UpdateUserAdvancedLpInstructionAccountIndices {
+            user: 0,
+            authority: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateUserAdvancedLp {
+    fn account_names() -> &'static [&'static str] {
+        &["user", "authority"]
+    }
+}
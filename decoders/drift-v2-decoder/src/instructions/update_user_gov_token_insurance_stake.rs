@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +14,7 @@ pub struct UpdateUserGovTokenInsuranceStakeInstructionAccounts {
     pub user_stats: solana_pubkey::Pubkey,
     pub signer: solana_pubkey::Pubkey,
     pub insurance_fund_vault: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateUserGovTokenInsuranceStake {
@@ -21,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateUserGovTokenInsuranceSt
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, spot_market, insurance_fund_stake, user_stats, signer, insurance_fund_vault, _remaining @ ..] =
+        let [state, spot_market, insurance_fund_stake, user_stats, signer, insurance_fund_vault, remaining @ ..] =
             accounts
         else {
             return None;
@@ -34,6 +36,86 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateUserGovTokenInsuranceSt
             user_stats: user_stats.pubkey,
             signer: signer.pubkey,
             insurance_fund_vault: insurance_fund_vault.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateUserGovTokenInsuranceStakeInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub spot_market: solana_instruction::AccountMeta,
+    pub insurance_fund_stake: solana_instruction::AccountMeta,
+    pub user_stats: solana_instruction::AccountMeta,
+    pub signer: solana_instruction::AccountMeta,
+    pub insurance_fund_vault: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateUserGovTokenInsuranceStake {
+    type ArrangedAccountMetas = UpdateUserGovTokenInsuranceStakeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, spot_market, insurance_fund_stake, user_stats, signer, insurance_fund_vault, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(UpdateUserGovTokenInsuranceStakeInstructionAccountMetas {
+            state: state.clone(),
+            spot_market: spot_market.clone(),
+            insurance_fund_stake: insurance_fund_stake.clone(),
+            user_stats: user_stats.clone(),
+            signer: signer.clone(),
+            insurance_fund_vault: insurance_fund_vault.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateUserGovTokenInsuranceStakeInstructionAccountIndices {
+    pub state: usize,
+    pub spot_market: usize,
+    pub insurance_fund_stake: usize,
+    pub user_stats: usize,
+    pub signer: usize,
+    pub insurance_fund_vault: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateUserGovTokenInsuranceStake {
+    type ArrangedAccountIndices = UpdateUserGovTokenInsuranceStakeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(UpdateUserGovTokenInsuranceStakeInstructionAccountIndices {
+            state: 0,
+            spot_market: 1,
+            insurance_fund_stake: 2,
+            user_stats: 3,
+            signer: 4,
+            insurance_fund_vault: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateUserGovTokenInsuranceStake {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "spot_market",
+            "insurance_fund_stake",
+            "user_stats",
+            "signer",
+            "insurance_fund_vault",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +12,7 @@ pub struct UpdateUserGovTokenInsuranceStakeDevnet {
 pub struct UpdateUserGovTokenInsuranceStakeDevnetInstructionAccounts {
     pub user_stats: solana_pubkey::Pubkey,
     pub signer: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateUserGovTokenInsuranceStakeDevnet {
@@ -19,13 +21,72 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateUserGovTokenInsuranceSt
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [user_stats, signer, _remaining @ ..] = accounts else {
+        let [user_stats, signer, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(UpdateUserGovTokenInsuranceStakeDevnetInstructionAccounts {
             user_stats: user_stats.pubkey,
             signer: signer.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateUserGovTokenInsuranceStakeDevnetInstructionAccountMetas {
+    pub user_stats: solana_instruction::AccountMeta,
+    pub signer: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateUserGovTokenInsuranceStakeDevnet {
+    type ArrangedAccountMetas = UpdateUserGovTokenInsuranceStakeDevnetInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [user_stats, signer, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(
+            UpdateUserGovTokenInsuranceStakeDevnetInstructionAccountMetas {
+                user_stats: user_stats.clone(),
+                signer: signer.clone(),
+                remaining_accounts: remaining.to_vec(),
+            },
+        )
+    }
+}
+
+pub struct UpdateUserGovTokenInsuranceStakeDevnetInstructionAccountIndices {
+    pub user_stats: usize,
+    pub signer: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateUserGovTokenInsuranceStakeDevnet {
+    type ArrangedAccountIndices = UpdateUserGovTokenInsuranceStakeDevnetInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(
+            UpdateUserGovTokenInsuranceStakeDevnetInstructionAccountIndices {
+                user_stats: 0,
+                signer: 1,
+                remaining_accounts: 2..accounts.len(),
+            },
+        )
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateUserGovTokenInsuranceStakeDevnet {
+    fn account_names() -> &'static [&'static str] {
+        &["user_stats", "signer"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct UpdateUserProtectedMakerOrdersInstructionAccounts {
     pub user: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
     pub protected_maker_mode_config: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateUserProtectedMakerOrders {
@@ -22,8 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateUserProtectedMakerOrder
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, user, authority, protected_maker_mode_config, _remaining @ ..] = accounts
-        else {
+        let [state, user, authority, protected_maker_mode_config, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -32,6 +33,69 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateUserProtectedMakerOrder
             user: user.pubkey,
             authority: authority.pubkey,
             protected_maker_mode_config: protected_maker_mode_config.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateUserProtectedMakerOrdersInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub protected_maker_mode_config: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateUserProtectedMakerOrders {
+    type ArrangedAccountMetas = UpdateUserProtectedMakerOrdersInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, user, authority, protected_maker_mode_config, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateUserProtectedMakerOrdersInstructionAccountMetas {
+            state: state.clone(),
+            user: user.clone(),
+            authority: authority.clone(),
+            protected_maker_mode_config: protected_maker_mode_config.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateUserProtectedMakerOrdersInstructionAccountIndices {
+    pub state: usize,
+    pub user: usize,
+    pub authority: usize,
+    pub protected_maker_mode_config: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateUserProtectedMakerOrders {
+    type ArrangedAccountIndices = UpdateUserProtectedMakerOrdersInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(UpdateUserProtectedMakerOrdersInstructionAccountIndices {
+            state: 0,
+            user: 1,
+            authority: 2,
+            protected_maker_mode_config: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateUserProtectedMakerOrders {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "user", "authority", "protected_maker_mode_config"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -10,6 +11,7 @@ pub struct UpdateUserStatsReferrerStatusInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
     pub user_stats: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateUserStatsReferrerStatus {
@@ -18,7 +20,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateUserStatsReferrerStatus
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, authority, user_stats, _remaining @ ..] = accounts else {
+        let [state, authority, user_stats, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -26,6 +28,65 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateUserStatsReferrerStatus
             state: state.pubkey,
             authority: authority.pubkey,
             user_stats: user_stats.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateUserStatsReferrerStatusInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub user_stats: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateUserStatsReferrerStatus {
+    type ArrangedAccountMetas = UpdateUserStatsReferrerStatusInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, authority, user_stats, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateUserStatsReferrerStatusInstructionAccountMetas {
+            state: state.clone(),
+            authority: authority.clone(),
+            user_stats: user_stats.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateUserStatsReferrerStatusInstructionAccountIndices {
+    pub state: usize,
+    pub authority: usize,
+    pub user_stats: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateUserStatsReferrerStatus {
+    type ArrangedAccountIndices = UpdateUserStatsReferrerStatusInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(UpdateUserStatsReferrerStatusInstructionAccountIndices {
+            state: 0,
+            authority: 1,
+            user_stats: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateUserStatsReferrerStatus {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "authority", "user_stats"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -19,6 +20,7 @@ pub struct WithdrawInstructionAccounts {
     pub drift_signer: solana_pubkey::Pubkey,
     pub user_token_account: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Withdraw {
@@ -27,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Withdraw {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, user, user_stats, authority, spot_market_vault, drift_signer, user_token_account, token_program, _remaining @ ..] =
+        let [state, user, user_stats, authority, spot_market_vault, drift_signer, user_token_account, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -42,6 +44,96 @@ impl carbon_core::deserialize::ArrangeAccounts for Withdraw {
             drift_signer: drift_signer.pubkey,
             user_token_account: user_token_account.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct WithdrawInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub user_stats: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub spot_market_vault: solana_instruction::AccountMeta,
+    pub drift_signer: solana_instruction::AccountMeta,
+    pub user_token_account: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Withdraw {
+    type ArrangedAccountMetas = WithdrawInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, user, user_stats, authority, spot_market_vault, drift_signer, user_token_account, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(WithdrawInstructionAccountMetas {
+            state: state.clone(),
+            user: user.clone(),
+            user_stats: user_stats.clone(),
+            authority: authority.clone(),
+            spot_market_vault: spot_market_vault.clone(),
+            drift_signer: drift_signer.clone(),
+            user_token_account: user_token_account.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct WithdrawInstructionAccountIndices {
+    pub state: usize,
+    pub user: usize,
+    pub user_stats: usize,
+    pub authority: usize,
+    pub spot_market_vault: usize,
+    pub drift_signer: usize,
+    pub user_token_account: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Withdraw {
+    type ArrangedAccountIndices = WithdrawInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(WithdrawInstructionAccountIndices {
+            state: 0,
+            user: 1,
+            user_stats: 2,
+            authority: 3,
+            spot_market_vault: 4,
+            drift_signer: 5,
+            user_token_account: 6,
+            token_program: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Withdraw {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "user",
+            "user_stats",
+            "authority",
+            "spot_market_vault",
+            "drift_signer",
+            "user_token_account",
+            "token_program",
+        ]
+    }
+}
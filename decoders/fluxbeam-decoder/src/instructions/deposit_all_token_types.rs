@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -25,6 +26,7 @@ pub struct DepositAllTokenTypesInstructionAccounts {
     pub token_a_program: solana_pubkey::Pubkey,
     pub token_b_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for DepositAllTokenTypes {
@@ -33,7 +35,7 @@ impl carbon_core::deserialize::ArrangeAccounts for DepositAllTokenTypes {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [swap, authority, user_transfer_authority, deposit_token_a, deposit_token_b, swap_token_a, swap_token_b, pool_mint, destination, token_a_mint, token_b_mint, token_a_program, token_b_program, token_program, _remaining @ ..] =
+        let [swap, authority, user_transfer_authority, deposit_token_a, deposit_token_b, swap_token_a, swap_token_b, pool_mint, destination, token_a_mint, token_b_mint, token_a_program, token_b_program, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -54,6 +56,126 @@ impl carbon_core::deserialize::ArrangeAccounts for DepositAllTokenTypes {
             token_a_program: token_a_program.pubkey,
             token_b_program: token_b_program.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DepositAllTokenTypesInstructionAccountMetas {
+    pub swap: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub user_transfer_authority: solana_instruction::AccountMeta,
+    pub deposit_token_a: solana_instruction::AccountMeta,
+    pub deposit_token_b: solana_instruction::AccountMeta,
+    pub swap_token_a: solana_instruction::AccountMeta,
+    pub swap_token_b: solana_instruction::AccountMeta,
+    pub pool_mint: solana_instruction::AccountMeta,
+    pub destination: solana_instruction::AccountMeta,
+    pub token_a_mint: solana_instruction::AccountMeta,
+    pub token_b_mint: solana_instruction::AccountMeta,
+    pub token_a_program: solana_instruction::AccountMeta,
+    pub token_b_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for DepositAllTokenTypes {
+    type ArrangedAccountMetas = DepositAllTokenTypesInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [swap, authority, user_transfer_authority, deposit_token_a, deposit_token_b, swap_token_a, swap_token_b, pool_mint, destination, token_a_mint, token_b_mint, token_a_program, token_b_program, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(DepositAllTokenTypesInstructionAccountMetas {
+            swap: swap.clone(),
+            authority: authority.clone(),
+            user_transfer_authority: user_transfer_authority.clone(),
+            deposit_token_a: deposit_token_a.clone(),
+            deposit_token_b: deposit_token_b.clone(),
+            swap_token_a: swap_token_a.clone(),
+            swap_token_b: swap_token_b.clone(),
+            pool_mint: pool_mint.clone(),
+            destination: destination.clone(),
+            token_a_mint: token_a_mint.clone(),
+            token_b_mint: token_b_mint.clone(),
+            token_a_program: token_a_program.clone(),
+            token_b_program: token_b_program.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DepositAllTokenTypesInstructionAccountIndices {
+    pub swap: usize,
+    pub authority: usize,
+    pub user_transfer_authority: usize,
+    pub deposit_token_a: usize,
+    pub deposit_token_b: usize,
+    pub swap_token_a: usize,
+    pub swap_token_b: usize,
+    pub pool_mint: usize,
+    pub destination: usize,
+    pub token_a_mint: usize,
+    pub token_b_mint: usize,
+    pub token_a_program: usize,
+    pub token_b_program: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for DepositAllTokenTypes {
+    type ArrangedAccountIndices = DepositAllTokenTypesInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 14 {
+            return None;
+        }
+
+        Some(DepositAllTokenTypesInstructionAccountIndices {
+            swap: 0,
+            authority: 1,
+            user_transfer_authority: 2,
+            deposit_token_a: 3,
+            deposit_token_b: 4,
+            swap_token_a: 5,
+            swap_token_b: 6,
+            pool_mint: 7,
+            destination: 8,
+            token_a_mint: 9,
+            token_b_mint: 10,
+            token_a_program: 11,
+            token_b_program: 12,
+            token_program: 13,
+            remaining_accounts: 14..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for DepositAllTokenTypes {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "swap",
+            "authority",
+            "user_transfer_authority",
+            "deposit_token_a",
+            "deposit_token_b",
+            "swap_token_a",
+            "swap_token_b",
+            "pool_mint",
+            "destination",
+            "token_a_mint",
+            "token_b_mint",
+            "token_a_program",
+            "token_b_program",
+            "token_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -21,6 +22,7 @@ pub struct DepositSingleTokenTypeExactAmountInInstructionAccounts {
     pub source_mint: solana_pubkey::Pubkey,
     pub token_a_program: solana_pubkey::Pubkey,
     pub token_b_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for DepositSingleTokenTypeExactAmountIn {
@@ -29,7 +31,7 @@ impl carbon_core::deserialize::ArrangeAccounts for DepositSingleTokenTypeExactAm
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [swap, authority, user_transfer_authority, source_token, swap_token_a, swap_token_b, pool_mint, destination, source_mint, token_a_program, token_b_program, _remaining @ ..] =
+        let [swap, authority, user_transfer_authority, source_token, swap_token_a, swap_token_b, pool_mint, destination, source_mint, token_a_program, token_b_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -47,6 +49,113 @@ impl carbon_core::deserialize::ArrangeAccounts for DepositSingleTokenTypeExactAm
             source_mint: source_mint.pubkey,
             token_a_program: token_a_program.pubkey,
             token_b_program: token_b_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DepositSingleTokenTypeExactAmountInInstructionAccountMetas {
+    pub swap: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub user_transfer_authority: solana_instruction::AccountMeta,
+    pub source_token: solana_instruction::AccountMeta,
+    pub swap_token_a: solana_instruction::AccountMeta,
+    pub swap_token_b: solana_instruction::AccountMeta,
+    pub pool_mint: solana_instruction::AccountMeta,
+    pub destination: solana_instruction::AccountMeta,
+    pub source_mint: solana_instruction::AccountMeta,
+    pub token_a_program: solana_instruction::AccountMeta,
+    pub token_b_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for DepositSingleTokenTypeExactAmountIn {
+    type ArrangedAccountMetas = DepositSingleTokenTypeExactAmountInInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [swap, authority, user_transfer_authority, source_token, swap_token_a, swap_token_b, pool_mint, destination, source_mint, token_a_program, token_b_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(DepositSingleTokenTypeExactAmountInInstructionAccountMetas {
+            swap: swap.clone(),
+            authority: authority.clone(),
+            user_transfer_authority: user_transfer_authority.clone(),
+            source_token: source_token.clone(),
+            swap_token_a: swap_token_a.clone(),
+            swap_token_b: swap_token_b.clone(),
+            pool_mint: pool_mint.clone(),
+            destination: destination.clone(),
+            source_mint: source_mint.clone(),
+            token_a_program: token_a_program.clone(),
+            token_b_program: token_b_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DepositSingleTokenTypeExactAmountInInstructionAccountIndices {
+    pub swap: usize,
+    pub authority: usize,
+    pub user_transfer_authority: usize,
+    pub source_token: usize,
+    pub swap_token_a: usize,
+    pub swap_token_b: usize,
+    pub pool_mint: usize,
+    pub destination: usize,
+    pub source_mint: usize,
+    pub token_a_program: usize,
+    pub token_b_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for DepositSingleTokenTypeExactAmountIn {
+    type ArrangedAccountIndices = DepositSingleTokenTypeExactAmountInInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 11 {
+            return None;
+        }
+
+        Some(
+            DepositSingleTokenTypeExactAmountInInstructionAccountIndices {
+                swap: 0,
+                authority: 1,
+                user_transfer_authority: 2,
+                source_token: 3,
+                swap_token_a: 4,
+                swap_token_b: 5,
+                pool_mint: 6,
+                destination: 7,
+                source_mint: 8,
+                token_a_program: 9,
+                token_b_program: 10,
+                remaining_accounts: 11..accounts.len(),
+            },
+        )
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for DepositSingleTokenTypeExactAmountIn {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "swap",
+            "authority",
+            "user_transfer_authority",
+            "source_token",
+            "swap_token_a",
+            "swap_token_b",
+            "pool_mint",
+            "destination",
+            "source_mint",
+            "token_a_program",
+            "token_b_program",
+        ]
+    }
+}
@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -21,6 +22,7 @@ pub struct InitializeInstructionAccounts {
     pub fee: solana_pubkey::Pubkey,
     pub destination: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Initialize {
@@ -29,7 +31,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Initialize {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [swap, authority, token_a, token_b, pool, fee, destination, token_program, _remaining @ ..] =
+        let [swap, authority, token_a, token_b, pool, fee, destination, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -44,6 +46,98 @@ impl carbon_core::deserialize::ArrangeAccounts for Initialize {
             fee: fee.pubkey,
             destination: destination.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+#[derive(Debug, PartialEq)]
+pub struct InitializeInstructionAccountMetas {
+    pub swap: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub token_a: solana_instruction::AccountMeta,
+    pub token_b: solana_instruction::AccountMeta,
+    pub pool: solana_instruction::AccountMeta,
+    pub fee: solana_instruction::AccountMeta,
+    pub destination: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Initialize {
+    type ArrangedAccountMetas = InitializeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [swap, authority, token_a, token_b, pool, fee, destination, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeInstructionAccountMetas {
+            swap: swap.clone(),
+            authority: authority.clone(),
+            token_a: token_a.clone(),
+            token_b: token_b.clone(),
+            pool: pool.clone(),
+            fee: fee.clone(),
+            destination: destination.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct InitializeInstructionAccountIndices {
+    pub swap: usize,
+    pub authority: usize,
+    pub token_a: usize,
+    pub token_b: usize,
+    pub pool: usize,
+    pub fee: usize,
+    pub destination: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Initialize {
+    type ArrangedAccountIndices = InitializeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(InitializeInstructionAccountIndices {
+            swap: 0,
+            authority: 1,
+            token_a: 2,
+            token_b: 3,
+            pool: 4,
+            fee: 5,
+            destination: 6,
+            token_program: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Initialize {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "swap",
+            "authority",
+            "token_a",
+            "token_b",
+            "pool",
+            "fee",
+            "destination",
+            "token_program",
+        ]
+    }
+}
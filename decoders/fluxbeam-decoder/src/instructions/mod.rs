@@ -34,22 +34,32 @@ pub enum FluxbeamInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for FluxbeamDecoder {
     type InstructionType = FluxbeamInstruction;
 
-    fn decode_instruction(
+    fn decoder_name(&self) -> &'static str {
+        "fluxbeam"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[super::PROGRAM_ID]
+    }
+
+    fn try_decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
-    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+    ) -> carbon_core::error::CarbonResult<
+        Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>>,
+    > {
         if !instruction.program_id.eq(&PROGRAM_ID) {
-            return None;
+            return Ok(None);
         }
 
-        carbon_core::try_decode_instructions!(instruction,
+        Ok(carbon_core::try_decode_instructions!(instruction,
             FluxbeamInstruction::Initialize => initialize::Initialize,
             FluxbeamInstruction::Swap => swap::Swap,
             FluxbeamInstruction::DepositAllTokenTypes => deposit_all_token_types::DepositAllTokenTypes,
             FluxbeamInstruction::WithdrawAllTokenTypes => withdraw_all_token_types::WithdrawAllTokenTypes,
             FluxbeamInstruction::DepositSingleTokenTypeExactAmountIn => deposit_single_token_type_exact_amount_in::DepositSingleTokenTypeExactAmountIn,
             FluxbeamInstruction::WithdrawSingleTokenTypeExactAmountOut => withdraw_single_token_type_exact_amount_out::WithdrawSingleTokenTypeExactAmountOut,
-        )
+        ))
     }
 }
 
@@ -156,6 +166,7 @@ mod tests {
             token_program: solana_pubkey::Pubkey::from_str_const(
                 "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb",
             ),
+            remaining_accounts: vec![],
         };
 
         // Act
@@ -321,6 +332,7 @@ mod tests {
             swap_program: solana_pubkey::Pubkey::from_str_const(
                 "396TeW1MeyQvFGgxjaxJxRFkuiir4Ye4imuxVDcqfE88",
             ),
+            remaining_accounts: vec![],
         };
 
         // Act
@@ -489,6 +501,7 @@ mod tests {
                 token_b_program: solana_pubkey::Pubkey::from_str_const(
                     "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb",
                 ),
+                remaining_accounts: vec![],
             };
 
         // Act
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -26,6 +27,7 @@ pub struct SwapInstructionAccounts {
     pub destination_token_program: solana_pubkey::Pubkey,
     pub pool_token_program: solana_pubkey::Pubkey,
     pub swap_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Swap {
@@ -34,7 +36,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Swap {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [swap, authority, user_transfer_authority, source, swap_source, swap_destination, destination, pool_mint, pool_fee, source_mint, destination_mint, source_token_program, destination_token_program, pool_token_program, swap_program, _remaining @ ..] =
+        let [swap, authority, user_transfer_authority, source, swap_source, swap_destination, destination, pool_mint, pool_fee, source_mint, destination_mint, source_token_program, destination_token_program, pool_token_program, swap_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -56,6 +58,133 @@ impl carbon_core::deserialize::ArrangeAccounts for Swap {
             destination_token_program: destination_token_program.pubkey,
             pool_token_program: pool_token_program.pubkey,
             swap_program: swap_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+#[derive(Debug, PartialEq)]
+pub struct SwapInstructionAccountMetas {
+    pub swap: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub user_transfer_authority: solana_instruction::AccountMeta,
+    pub source: solana_instruction::AccountMeta,
+    pub swap_source: solana_instruction::AccountMeta,
+    pub swap_destination: solana_instruction::AccountMeta,
+    pub destination: solana_instruction::AccountMeta,
+    pub pool_mint: solana_instruction::AccountMeta,
+    pub pool_fee: solana_instruction::AccountMeta,
+    pub source_mint: solana_instruction::AccountMeta,
+    pub destination_mint: solana_instruction::AccountMeta,
+    pub source_token_program: solana_instruction::AccountMeta,
+    pub destination_token_program: solana_instruction::AccountMeta,
+    pub pool_token_program: solana_instruction::AccountMeta,
+    pub swap_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Swap {
+    type ArrangedAccountMetas = SwapInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [swap, authority, user_transfer_authority, source, swap_source, swap_destination, destination, pool_mint, pool_fee, source_mint, destination_mint, source_token_program, destination_token_program, pool_token_program, swap_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(SwapInstructionAccountMetas {
+            swap: swap.clone(),
+            authority: authority.clone(),
+            user_transfer_authority: user_transfer_authority.clone(),
+            source: source.clone(),
+            swap_source: swap_source.clone(),
+            swap_destination: swap_destination.clone(),
+            destination: destination.clone(),
+            pool_mint: pool_mint.clone(),
+            pool_fee: pool_fee.clone(),
+            source_mint: source_mint.clone(),
+            destination_mint: destination_mint.clone(),
+            source_token_program: source_token_program.clone(),
+            destination_token_program: destination_token_program.clone(),
+            pool_token_program: pool_token_program.clone(),
+            swap_program: swap_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct SwapInstructionAccountIndices {
+    pub swap: usize,
+    pub authority: usize,
+    pub user_transfer_authority: usize,
+    pub source: usize,
+    pub swap_source: usize,
+    pub swap_destination: usize,
+    pub destination: usize,
+    pub pool_mint: usize,
+    pub pool_fee: usize,
+    pub source_mint: usize,
+    pub destination_mint: usize,
+    pub source_token_program: usize,
+    pub destination_token_program: usize,
+    pub pool_token_program: usize,
+    pub swap_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Swap {
+    type ArrangedAccountIndices = SwapInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 15 {
+            return None;
+        }
+
+        Some(SwapInstructionAccountIndices {
+            swap: 0,
+            authority: 1,
+            user_transfer_authority: 2,
+            source: 3,
+            swap_source: 4,
+            swap_destination: 5,
+            destination: 6,
+            pool_mint: 7,
+            pool_fee: 8,
+            source_mint: 9,
+            destination_mint: 10,
+            source_token_program: 11,
+            destination_token_program: 12,
+            pool_token_program: 13,
+            swap_program: 14,
+            remaining_accounts: 15..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Swap {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "swap",
+            "authority",
+            "user_transfer_authority",
+            "source",
+            "swap_source",
+            "swap_destination",
+            "destination",
+            "pool_mint",
+            "pool_fee",
+            "source_mint",
+            "destination_mint",
+            "source_token_program",
+            "destination_token_program",
+            "pool_token_program",
+            "swap_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -27,6 +28,7 @@ pub struct WithdrawAllTokenTypesInstructionAccounts {
     pub pool_token_program: solana_pubkey::Pubkey,
     pub token_a_program: solana_pubkey::Pubkey,
     pub token_b_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for WithdrawAllTokenTypes {
@@ -35,7 +37,7 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawAllTokenTypes {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [swap, authority, user_transfer_authority, pool_mint, source, swap_token_a, swap_token_b, destination_token_a, destination_token_b, fee_account, token_a_mint, token_b_mint, pool_token_program, token_a_program, token_b_program, _remaining @ ..] =
+        let [swap, authority, user_transfer_authority, pool_mint, source, swap_token_a, swap_token_b, destination_token_a, destination_token_b, fee_account, token_a_mint, token_b_mint, pool_token_program, token_a_program, token_b_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -57,6 +59,133 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawAllTokenTypes {
             pool_token_program: pool_token_program.pubkey,
             token_a_program: token_a_program.pubkey,
             token_b_program: token_b_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+#[derive(Debug, PartialEq)]
+pub struct WithdrawAllTokenTypesInstructionAccountMetas {
+    pub swap: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub user_transfer_authority: solana_instruction::AccountMeta,
+    pub pool_mint: solana_instruction::AccountMeta,
+    pub source: solana_instruction::AccountMeta,
+    pub swap_token_a: solana_instruction::AccountMeta,
+    pub swap_token_b: solana_instruction::AccountMeta,
+    pub destination_token_a: solana_instruction::AccountMeta,
+    pub destination_token_b: solana_instruction::AccountMeta,
+    pub fee_account: solana_instruction::AccountMeta,
+    pub token_a_mint: solana_instruction::AccountMeta,
+    pub token_b_mint: solana_instruction::AccountMeta,
+    pub pool_token_program: solana_instruction::AccountMeta,
+    pub token_a_program: solana_instruction::AccountMeta,
+    pub token_b_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for WithdrawAllTokenTypes {
+    type ArrangedAccountMetas = WithdrawAllTokenTypesInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [swap, authority, user_transfer_authority, pool_mint, source, swap_token_a, swap_token_b, destination_token_a, destination_token_b, fee_account, token_a_mint, token_b_mint, pool_token_program, token_a_program, token_b_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(WithdrawAllTokenTypesInstructionAccountMetas {
+            swap: swap.clone(),
+            authority: authority.clone(),
+            user_transfer_authority: user_transfer_authority.clone(),
+            pool_mint: pool_mint.clone(),
+            source: source.clone(),
+            swap_token_a: swap_token_a.clone(),
+            swap_token_b: swap_token_b.clone(),
+            destination_token_a: destination_token_a.clone(),
+            destination_token_b: destination_token_b.clone(),
+            fee_account: fee_account.clone(),
+            token_a_mint: token_a_mint.clone(),
+            token_b_mint: token_b_mint.clone(),
+            pool_token_program: pool_token_program.clone(),
+            token_a_program: token_a_program.clone(),
+            token_b_program: token_b_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct WithdrawAllTokenTypesInstructionAccountIndices {
+    pub swap: usize,
+    pub authority: usize,
+    pub user_transfer_authority: usize,
+    pub pool_mint: usize,
+    pub source: usize,
+    pub swap_token_a: usize,
+    pub swap_token_b: usize,
+    pub destination_token_a: usize,
+    pub destination_token_b: usize,
+    pub fee_account: usize,
+    pub token_a_mint: usize,
+    pub token_b_mint: usize,
+    pub pool_token_program: usize,
+    pub token_a_program: usize,
+    pub token_b_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for WithdrawAllTokenTypes {
+    type ArrangedAccountIndices = WithdrawAllTokenTypesInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 15 {
+            return None;
+        }
+
+        Some(WithdrawAllTokenTypesInstructionAccountIndices {
+            swap: 0,
+            authority: 1,
+            user_transfer_authority: 2,
+            pool_mint: 3,
+            source: 4,
+            swap_token_a: 5,
+            swap_token_b: 6,
+            destination_token_a: 7,
+            destination_token_b: 8,
+            fee_account: 9,
+            token_a_mint: 10,
+            token_b_mint: 11,
+            pool_token_program: 12,
+            token_a_program: 13,
+            token_b_program: 14,
+            remaining_accounts: 15..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for WithdrawAllTokenTypes {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "swap",
+            "authority",
+            "user_transfer_authority",
+            "pool_mint",
+            "source",
+            "swap_token_a",
+            "swap_token_b",
+            "destination_token_a",
+            "destination_token_b",
+            "fee_account",
+            "token_a_mint",
+            "token_b_mint",
+            "pool_token_program",
+            "token_a_program",
+            "token_b_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -22,6 +23,7 @@ pub struct WithdrawSingleTokenTypeExactAmountOutInstructionAccounts {
     pub destination_mint: solana_pubkey::Pubkey,
     pub token_a_program: solana_pubkey::Pubkey,
     pub token_b_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for WithdrawSingleTokenTypeExactAmountOut {
@@ -30,7 +32,7 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawSingleTokenTypeExactA
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [swap, authority, user_transfer_authority, pool_mint, pool_token_source, swap_token_a, swap_token_b, destination, fee_account, destination_mint, token_a_program, token_b_program, _remaining @ ..] =
+        let [swap, authority, user_transfer_authority, pool_mint, pool_token_source, swap_token_a, swap_token_b, destination, fee_account, destination_mint, token_a_program, token_b_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -49,6 +51,120 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawSingleTokenTypeExactA
             destination_mint: destination_mint.pubkey,
             token_a_program: token_a_program.pubkey,
             token_b_program: token_b_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct WithdrawSingleTokenTypeExactAmountOutInstructionAccountMetas {
+    pub swap: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub user_transfer_authority: solana_instruction::AccountMeta,
+    pub pool_mint: solana_instruction::AccountMeta,
+    pub pool_token_source: solana_instruction::AccountMeta,
+    pub swap_token_a: solana_instruction::AccountMeta,
+    pub swap_token_b: solana_instruction::AccountMeta,
+    pub destination: solana_instruction::AccountMeta,
+    pub fee_account: solana_instruction::AccountMeta,
+    pub destination_mint: solana_instruction::AccountMeta,
+    pub token_a_program: solana_instruction::AccountMeta,
+    pub token_b_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for WithdrawSingleTokenTypeExactAmountOut {
+    type ArrangedAccountMetas = WithdrawSingleTokenTypeExactAmountOutInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [swap, authority, user_transfer_authority, pool_mint, pool_token_source, swap_token_a, swap_token_b, destination, fee_account, destination_mint, token_a_program, token_b_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(
+            WithdrawSingleTokenTypeExactAmountOutInstructionAccountMetas {
+                swap: swap.clone(),
+                authority: authority.clone(),
+                user_transfer_authority: user_transfer_authority.clone(),
+                pool_mint: pool_mint.clone(),
+                pool_token_source: pool_token_source.clone(),
+                swap_token_a: swap_token_a.clone(),
+                swap_token_b: swap_token_b.clone(),
+                destination: destination.clone(),
+                fee_account: fee_account.clone(),
+                destination_mint: destination_mint.clone(),
+                token_a_program: token_a_program.clone(),
+                token_b_program: token_b_program.clone(),
+                remaining_accounts: remaining.to_vec(),
+            },
+        )
+    }
+}
+
+pub struct WithdrawSingleTokenTypeExactAmountOutInstructionAccountIndices {
+    pub swap: usize,
+    pub authority: usize,
+    pub user_transfer_authority: usize,
+    pub pool_mint: usize,
+    pub pool_token_source: usize,
+    pub swap_token_a: usize,
+    pub swap_token_b: usize,
+    pub destination: usize,
+    pub fee_account: usize,
+    pub destination_mint: usize,
+    pub token_a_program: usize,
+    pub token_b_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for WithdrawSingleTokenTypeExactAmountOut {
+    type ArrangedAccountIndices = WithdrawSingleTokenTypeExactAmountOutInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 12 {
+            return None;
+        }
+
+        Some(
+            WithdrawSingleTokenTypeExactAmountOutInstructionAccountIndices {
+                swap: 0,
+                authority: 1,
+                user_transfer_authority: 2,
+                pool_mint: 3,
+                pool_token_source: 4,
+                swap_token_a: 5,
+                swap_token_b: 6,
+                destination: 7,
+                fee_account: 8,
+                destination_mint: 9,
+                token_a_program: 10,
+                token_b_program: 11,
+                remaining_accounts: 12..accounts.len(),
+            },
+        )
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for WithdrawSingleTokenTypeExactAmountOut {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "swap",
+            "authority",
+            "user_transfer_authority",
+            "pool_mint",
+            "pool_token_source",
+            "swap_token_a",
+            "swap_token_b",
+            "destination",
+            "fee_account",
+            "destination_mint",
+            "token_a_program",
+            "token_b_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -21,6 +22,7 @@ pub struct CloseDcaInstructionAccounts {
     pub associated_token_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CloseDca {
@@ -29,7 +31,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CloseDca {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [user, dca, input_mint, output_mint, in_ata, out_ata, user_in_ata, user_out_ata, system_program, token_program, associated_token_program, event_authority, program, _remaining @ ..] =
+        let [user, dca, input_mint, output_mint, in_ata, out_ata, user_in_ata, user_out_ata, system_program, token_program, associated_token_program, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -49,6 +51,123 @@ impl carbon_core::deserialize::ArrangeAccounts for CloseDca {
             associated_token_program: associated_token_program.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+#[derive(Debug, PartialEq)]
+pub struct CloseDcaInstructionAccountMetas {
+    pub user: solana_instruction::AccountMeta,
+    pub dca: solana_instruction::AccountMeta,
+    pub input_mint: solana_instruction::AccountMeta,
+    pub output_mint: solana_instruction::AccountMeta,
+    pub in_ata: solana_instruction::AccountMeta,
+    pub out_ata: solana_instruction::AccountMeta,
+    pub user_in_ata: solana_instruction::AccountMeta,
+    pub user_out_ata: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CloseDca {
+    type ArrangedAccountMetas = CloseDcaInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [user, dca, input_mint, output_mint, in_ata, out_ata, user_in_ata, user_out_ata, system_program, token_program, associated_token_program, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CloseDcaInstructionAccountMetas {
+            user: user.clone(),
+            dca: dca.clone(),
+            input_mint: input_mint.clone(),
+            output_mint: output_mint.clone(),
+            in_ata: in_ata.clone(),
+            out_ata: out_ata.clone(),
+            user_in_ata: user_in_ata.clone(),
+            user_out_ata: user_out_ata.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            associated_token_program: associated_token_program.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct CloseDcaInstructionAccountIndices {
+    pub user: usize,
+    pub dca: usize,
+    pub input_mint: usize,
+    pub output_mint: usize,
+    pub in_ata: usize,
+    pub out_ata: usize,
+    pub user_in_ata: usize,
+    pub user_out_ata: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub associated_token_program: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CloseDca {
+    type ArrangedAccountIndices = CloseDcaInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 13 {
+            return None;
+        }
+
+        Some(CloseDcaInstructionAccountIndices {
+            user: 0,
+            dca: 1,
+            input_mint: 2,
+            output_mint: 3,
+            in_ata: 4,
+            out_ata: 5,
+            user_in_ata: 6,
+            user_out_ata: 7,
+            system_program: 8,
+            token_program: 9,
+            associated_token_program: 10,
+            event_authority: 11,
+            program: 12,
+            remaining_accounts: 13..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CloseDca {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "user",
+            "dca",
+            "input_mint",
+            "output_mint",
+            "in_ata",
+            "out_ata",
+            "user_in_ata",
+            "user_out_ata",
+            "system_program",
+            "token_program",
+            "associated_token_program",
+            "event_authority",
+            "program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -16,6 +17,7 @@ pub struct DepositInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Deposit {
@@ -24,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Deposit {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [user, dca, in_ata, user_in_ata, token_program, event_authority, program, _remaining @ ..] =
+        let [user, dca, in_ata, user_in_ata, token_program, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -38,6 +40,91 @@ impl carbon_core::deserialize::ArrangeAccounts for Deposit {
             token_program: token_program.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DepositInstructionAccountMetas {
+    pub user: solana_instruction::AccountMeta,
+    pub dca: solana_instruction::AccountMeta,
+    pub in_ata: solana_instruction::AccountMeta,
+    pub user_in_ata: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Deposit {
+    type ArrangedAccountMetas = DepositInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [user, dca, in_ata, user_in_ata, token_program, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(DepositInstructionAccountMetas {
+            user: user.clone(),
+            dca: dca.clone(),
+            in_ata: in_ata.clone(),
+            user_in_ata: user_in_ata.clone(),
+            token_program: token_program.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DepositInstructionAccountIndices {
+    pub user: usize,
+    pub dca: usize,
+    pub in_ata: usize,
+    pub user_in_ata: usize,
+    pub token_program: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Deposit {
+    type ArrangedAccountIndices = DepositInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 7 {
+            return None;
+        }
+
+        Some(DepositInstructionAccountIndices {
+            user: 0,
+            dca: 1,
+            in_ata: 2,
+            user_in_ata: 3,
+            token_program: 4,
+            event_authority: 5,
+            program: 6,
+            remaining_accounts: 7..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Deposit {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "user",
+            "dca",
+            "in_ata",
+            "user_in_ata",
+            "token_program",
+            "event_authority",
+            "program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -23,6 +24,7 @@ pub struct EndAndCloseInstructionAccounts {
     pub associated_token_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for EndAndClose {
@@ -31,7 +33,7 @@ impl carbon_core::deserialize::ArrangeAccounts for EndAndClose {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [keeper, dca, input_mint, output_mint, in_ata, out_ata, user, user_out_ata, init_user_out_ata, intermediate_account, system_program, token_program, associated_token_program, event_authority, program, _remaining @ ..] =
+        let [keeper, dca, input_mint, output_mint, in_ata, out_ata, user, user_out_ata, init_user_out_ata, intermediate_account, system_program, token_program, associated_token_program, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -53,6 +55,133 @@ impl carbon_core::deserialize::ArrangeAccounts for EndAndClose {
             associated_token_program: associated_token_program.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+#[derive(Debug, PartialEq)]
+pub struct EndAndCloseInstructionAccountMetas {
+    pub keeper: solana_instruction::AccountMeta,
+    pub dca: solana_instruction::AccountMeta,
+    pub input_mint: solana_instruction::AccountMeta,
+    pub output_mint: solana_instruction::AccountMeta,
+    pub in_ata: solana_instruction::AccountMeta,
+    pub out_ata: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub user_out_ata: solana_instruction::AccountMeta,
+    pub init_user_out_ata: solana_instruction::AccountMeta,
+    pub intermediate_account: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for EndAndClose {
+    type ArrangedAccountMetas = EndAndCloseInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [keeper, dca, input_mint, output_mint, in_ata, out_ata, user, user_out_ata, init_user_out_ata, intermediate_account, system_program, token_program, associated_token_program, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(EndAndCloseInstructionAccountMetas {
+            keeper: keeper.clone(),
+            dca: dca.clone(),
+            input_mint: input_mint.clone(),
+            output_mint: output_mint.clone(),
+            in_ata: in_ata.clone(),
+            out_ata: out_ata.clone(),
+            user: user.clone(),
+            user_out_ata: user_out_ata.clone(),
+            init_user_out_ata: init_user_out_ata.clone(),
+            intermediate_account: intermediate_account.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            associated_token_program: associated_token_program.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct EndAndCloseInstructionAccountIndices {
+    pub keeper: usize,
+    pub dca: usize,
+    pub input_mint: usize,
+    pub output_mint: usize,
+    pub in_ata: usize,
+    pub out_ata: usize,
+    pub user: usize,
+    pub user_out_ata: usize,
+    pub init_user_out_ata: usize,
+    pub intermediate_account: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub associated_token_program: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for EndAndClose {
+    type ArrangedAccountIndices = EndAndCloseInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 15 {
+            return None;
+        }
+
+        Some(EndAndCloseInstructionAccountIndices {
+            keeper: 0,
+            dca: 1,
+            input_mint: 2,
+            output_mint: 3,
+            in_ata: 4,
+            out_ata: 5,
+            user: 6,
+            user_out_ata: 7,
+            init_user_out_ata: 8,
+            intermediate_account: 9,
+            system_program: 10,
+            token_program: 11,
+            associated_token_program: 12,
+            event_authority: 13,
+            program: 14,
+            remaining_accounts: 15..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for EndAndClose {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "keeper",
+            "dca",
+            "input_mint",
+            "output_mint",
+            "in_ata",
+            "out_ata",
+            "user",
+            "user_out_ata",
+            "init_user_out_ata",
+            "intermediate_account",
+            "system_program",
+            "token_program",
+            "associated_token_program",
+            "event_authority",
+            "program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -24,6 +25,7 @@ pub struct FulfillDlmmFillInstructionAccounts {
     pub associated_token_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for FulfillDlmmFill {
@@ -32,7 +34,7 @@ impl carbon_core::deserialize::ArrangeAccounts for FulfillDlmmFill {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [keeper, dca, input_mint, output_mint, keeper_in_ata, in_ata, out_ata, fee_authority, fee_ata, instructions_sysvar, system_program, token_program, associated_token_program, event_authority, program, _remaining @ ..] =
+        let [keeper, dca, input_mint, output_mint, keeper_in_ata, in_ata, out_ata, fee_authority, fee_ata, instructions_sysvar, system_program, token_program, associated_token_program, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -54,6 +56,131 @@ impl carbon_core::deserialize::ArrangeAccounts for FulfillDlmmFill {
             associated_token_program: associated_token_program.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct FulfillDlmmFillInstructionAccountMetas {
+    pub keeper: solana_instruction::AccountMeta,
+    pub dca: solana_instruction::AccountMeta,
+    pub input_mint: solana_instruction::AccountMeta,
+    pub output_mint: solana_instruction::AccountMeta,
+    pub keeper_in_ata: solana_instruction::AccountMeta,
+    pub in_ata: solana_instruction::AccountMeta,
+    pub out_ata: solana_instruction::AccountMeta,
+    pub fee_authority: solana_instruction::AccountMeta,
+    pub fee_ata: solana_instruction::AccountMeta,
+    pub instructions_sysvar: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for FulfillDlmmFill {
+    type ArrangedAccountMetas = FulfillDlmmFillInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [keeper, dca, input_mint, output_mint, keeper_in_ata, in_ata, out_ata, fee_authority, fee_ata, instructions_sysvar, system_program, token_program, associated_token_program, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(FulfillDlmmFillInstructionAccountMetas {
+            keeper: keeper.clone(),
+            dca: dca.clone(),
+            input_mint: input_mint.clone(),
+            output_mint: output_mint.clone(),
+            keeper_in_ata: keeper_in_ata.clone(),
+            in_ata: in_ata.clone(),
+            out_ata: out_ata.clone(),
+            fee_authority: fee_authority.clone(),
+            fee_ata: fee_ata.clone(),
+            instructions_sysvar: instructions_sysvar.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            associated_token_program: associated_token_program.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct FulfillDlmmFillInstructionAccountIndices {
+    pub keeper: usize,
+    pub dca: usize,
+    pub input_mint: usize,
+    pub output_mint: usize,
+    pub keeper_in_ata: usize,
+    pub in_ata: usize,
+    pub out_ata: usize,
+    pub fee_authority: usize,
+    pub fee_ata: usize,
+    pub instructions_sysvar: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub associated_token_program: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for FulfillDlmmFill {
+    type ArrangedAccountIndices = FulfillDlmmFillInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 15 {
+            return None;
+        }
+
+        Some(FulfillDlmmFillInstructionAccountIndices {
+            keeper: 0,
+            dca: 1,
+            input_mint: 2,
+            output_mint: 3,
+            keeper_in_ata: 4,
+            in_ata: 5,
+            out_ata: 6,
+            fee_authority: 7,
+            fee_ata: 8,
+            instructions_sysvar: 9,
+            system_program: 10,
+            token_program: 11,
+            associated_token_program: 12,
+            event_authority: 13,
+            program: 14,
+            remaining_accounts: 15..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for FulfillDlmmFill {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "keeper",
+            "dca",
+            "input_mint",
+            "output_mint",
+            "keeper_in_ata",
+            "in_ata",
+            "out_ata",
+            "fee_authority",
+            "fee_ata",
+            "instructions_sysvar",
+            "system_program",
+            "token_program",
+            "associated_token_program",
+            "event_authority",
+            "program",
+        ]
+    }
+}
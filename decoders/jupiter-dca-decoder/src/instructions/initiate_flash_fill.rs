@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -18,6 +19,7 @@ pub struct InitiateFlashFillInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub associated_token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitiateFlashFill {
@@ -26,7 +28,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitiateFlashFill {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [keeper, dca, input_mint, keeper_in_ata, in_ata, out_ata, instructions_sysvar, system_program, token_program, associated_token_program, _remaining @ ..] =
+        let [keeper, dca, input_mint, keeper_in_ata, in_ata, out_ata, instructions_sysvar, system_program, token_program, associated_token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -43,6 +45,108 @@ impl carbon_core::deserialize::ArrangeAccounts for InitiateFlashFill {
             system_program: system_program.pubkey,
             token_program: token_program.pubkey,
             associated_token_program: associated_token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+#[derive(Debug, PartialEq)]
+pub struct InitiateFlashFillInstructionAccountMetas {
+    pub keeper: solana_instruction::AccountMeta,
+    pub dca: solana_instruction::AccountMeta,
+    pub input_mint: solana_instruction::AccountMeta,
+    pub keeper_in_ata: solana_instruction::AccountMeta,
+    pub in_ata: solana_instruction::AccountMeta,
+    pub out_ata: solana_instruction::AccountMeta,
+    pub instructions_sysvar: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitiateFlashFill {
+    type ArrangedAccountMetas = InitiateFlashFillInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [keeper, dca, input_mint, keeper_in_ata, in_ata, out_ata, instructions_sysvar, system_program, token_program, associated_token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitiateFlashFillInstructionAccountMetas {
+            keeper: keeper.clone(),
+            dca: dca.clone(),
+            input_mint: input_mint.clone(),
+            keeper_in_ata: keeper_in_ata.clone(),
+            in_ata: in_ata.clone(),
+            out_ata: out_ata.clone(),
+            instructions_sysvar: instructions_sysvar.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            associated_token_program: associated_token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct InitiateFlashFillInstructionAccountIndices {
+    pub keeper: usize,
+    pub dca: usize,
+    pub input_mint: usize,
+    pub keeper_in_ata: usize,
+    pub in_ata: usize,
+    pub out_ata: usize,
+    pub instructions_sysvar: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub associated_token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitiateFlashFill {
+    type ArrangedAccountIndices = InitiateFlashFillInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 10 {
+            return None;
+        }
+
+        Some(InitiateFlashFillInstructionAccountIndices {
+            keeper: 0,
+            dca: 1,
+            input_mint: 2,
+            keeper_in_ata: 3,
+            in_ata: 4,
+            out_ata: 5,
+            instructions_sysvar: 6,
+            system_program: 7,
+            token_program: 8,
+            associated_token_program: 9,
+            remaining_accounts: 10..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitiateFlashFill {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "keeper",
+            "dca",
+            "input_mint",
+            "keeper_in_ata",
+            "in_ata",
+            "out_ata",
+            "instructions_sysvar",
+            "system_program",
+            "token_program",
+            "associated_token_program",
+        ]
+    }
+}
@@ -54,15 +54,25 @@ pub enum JupiterDcaInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for JupiterDcaDecoder {
     type InstructionType = JupiterDcaInstruction;
 
-    fn decode_instruction(
+    fn decoder_name(&self) -> &'static str {
+        "jupiter_dca"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[super::PROGRAM_ID]
+    }
+
+    fn try_decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
-    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+    ) -> carbon_core::error::CarbonResult<
+        Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>>,
+    > {
         if !instruction.program_id.eq(&PROGRAM_ID) {
-            return None;
+            return Ok(None);
         }
 
-        carbon_core::try_decode_instructions!(instruction,
+        Ok(carbon_core::try_decode_instructions!(instruction,
             JupiterDcaInstruction::OpenDca => open_dca::OpenDca,
             JupiterDcaInstruction::OpenDcaV2 => open_dca_v2::OpenDcaV2,
             JupiterDcaInstruction::CloseDca => close_dca::CloseDca,
@@ -81,7 +91,7 @@ impl carbon_core::instruction::InstructionDecoder<'_> for JupiterDcaDecoder {
             JupiterDcaInstruction::ClosedEvent => closed_event::ClosedEvent,
             JupiterDcaInstruction::WithdrawEvent => withdraw_event::WithdrawEvent,
             JupiterDcaInstruction::DepositEvent => deposit_event::DepositEvent,
-        )
+        ))
     }
 }
 
@@ -213,6 +223,7 @@ mod tests {
             program: solana_pubkey::Pubkey::from_str_const(
                 "DCA265Vj8a9CEuX1eb1LWRnDT7uK6q1xMipnNyatn23M",
             ),
+            remaining_accounts: vec![],
         };
 
         let decoder = JupiterDcaDecoder;
@@ -359,6 +370,7 @@ mod tests {
             program: solana_pubkey::Pubkey::from_str_const(
                 "DCA265Vj8a9CEuX1eb1LWRnDT7uK6q1xMipnNyatn23M",
             ),
+            remaining_accounts: vec![],
         };
 
         let decoder = JupiterDcaDecoder;
@@ -498,6 +510,7 @@ mod tests {
             program: solana_pubkey::Pubkey::from_str_const(
                 "DCA265Vj8a9CEuX1eb1LWRnDT7uK6q1xMipnNyatn23M",
             ),
+            remaining_accounts: vec![],
         };
 
         let decoder = JupiterDcaDecoder;
@@ -592,6 +605,7 @@ mod tests {
             associated_token_program: solana_pubkey::Pubkey::from_str_const(
                 "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
             ),
+            remaining_accounts: vec![],
         };
 
         let decoder = JupiterDcaDecoder;
@@ -707,6 +721,7 @@ mod tests {
                 associated_token_program: solana_pubkey::Pubkey::from_str_const(
                     "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
                 ),
+                remaining_accounts: vec![],
             };
 
         let decoder = JupiterDcaDecoder;
@@ -868,6 +883,7 @@ mod tests {
             program: solana_pubkey::Pubkey::from_str_const(
                 "DCA265Vj8a9CEuX1eb1LWRnDT7uK6q1xMipnNyatn23M",
             ),
+            remaining_accounts: vec![],
         };
 
         let decoder = JupiterDcaDecoder;
@@ -1026,6 +1042,7 @@ mod tests {
             program: solana_pubkey::Pubkey::from_str_const(
                 "DCA265Vj8a9CEuX1eb1LWRnDT7uK6q1xMipnNyatn23M",
             ),
+            remaining_accounts: vec![],
         };
 
         let decoder = JupiterDcaDecoder;
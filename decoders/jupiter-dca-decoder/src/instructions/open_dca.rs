@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -29,6 +30,7 @@ pub struct OpenDcaInstructionAccounts {
     pub associated_token_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for OpenDca {
@@ -37,7 +39,7 @@ impl carbon_core::deserialize::ArrangeAccounts for OpenDca {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [dca, user, input_mint, output_mint, user_ata, in_ata, out_ata, system_program, token_program, associated_token_program, event_authority, program, _remaining @ ..] =
+        let [dca, user, input_mint, output_mint, user_ata, in_ata, out_ata, system_program, token_program, associated_token_program, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -56,6 +58,118 @@ impl carbon_core::deserialize::ArrangeAccounts for OpenDca {
             associated_token_program: associated_token_program.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+#[derive(Debug, PartialEq)]
+pub struct OpenDcaInstructionAccountMetas {
+    pub dca: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub input_mint: solana_instruction::AccountMeta,
+    pub output_mint: solana_instruction::AccountMeta,
+    pub user_ata: solana_instruction::AccountMeta,
+    pub in_ata: solana_instruction::AccountMeta,
+    pub out_ata: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for OpenDca {
+    type ArrangedAccountMetas = OpenDcaInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [dca, user, input_mint, output_mint, user_ata, in_ata, out_ata, system_program, token_program, associated_token_program, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(OpenDcaInstructionAccountMetas {
+            dca: dca.clone(),
+            user: user.clone(),
+            input_mint: input_mint.clone(),
+            output_mint: output_mint.clone(),
+            user_ata: user_ata.clone(),
+            in_ata: in_ata.clone(),
+            out_ata: out_ata.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            associated_token_program: associated_token_program.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct OpenDcaInstructionAccountIndices {
+    pub dca: usize,
+    pub user: usize,
+    pub input_mint: usize,
+    pub output_mint: usize,
+    pub user_ata: usize,
+    pub in_ata: usize,
+    pub out_ata: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub associated_token_program: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for OpenDca {
+    type ArrangedAccountIndices = OpenDcaInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 12 {
+            return None;
+        }
+
+        Some(OpenDcaInstructionAccountIndices {
+            dca: 0,
+            user: 1,
+            input_mint: 2,
+            output_mint: 3,
+            user_ata: 4,
+            in_ata: 5,
+            out_ata: 6,
+            system_program: 7,
+            token_program: 8,
+            associated_token_program: 9,
+            event_authority: 10,
+            program: 11,
+            remaining_accounts: 12..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for OpenDca {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "dca",
+            "user",
+            "input_mint",
+            "output_mint",
+            "user_ata",
+            "in_ata",
+            "out_ata",
+            "system_program",
+            "token_program",
+            "associated_token_program",
+            "event_authority",
+            "program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -29,6 +30,7 @@ pub struct OpenDcaV2InstructionAccounts {
     pub associated_token_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for OpenDcaV2 {
@@ -37,7 +39,7 @@ impl carbon_core::deserialize::ArrangeAccounts for OpenDcaV2 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [dca, user, payer, input_mint, output_mint, user_ata, in_ata, out_ata, system_program, token_program, associated_token_program, event_authority, program, _remaining @ ..] =
+        let [dca, user, payer, input_mint, output_mint, user_ata, in_ata, out_ata, system_program, token_program, associated_token_program, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -57,6 +59,123 @@ impl carbon_core::deserialize::ArrangeAccounts for OpenDcaV2 {
             associated_token_program: associated_token_program.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+#[derive(Debug, PartialEq)]
+pub struct OpenDcaV2InstructionAccountMetas {
+    pub dca: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub input_mint: solana_instruction::AccountMeta,
+    pub output_mint: solana_instruction::AccountMeta,
+    pub user_ata: solana_instruction::AccountMeta,
+    pub in_ata: solana_instruction::AccountMeta,
+    pub out_ata: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for OpenDcaV2 {
+    type ArrangedAccountMetas = OpenDcaV2InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [dca, user, payer, input_mint, output_mint, user_ata, in_ata, out_ata, system_program, token_program, associated_token_program, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(OpenDcaV2InstructionAccountMetas {
+            dca: dca.clone(),
+            user: user.clone(),
+            payer: payer.clone(),
+            input_mint: input_mint.clone(),
+            output_mint: output_mint.clone(),
+            user_ata: user_ata.clone(),
+            in_ata: in_ata.clone(),
+            out_ata: out_ata.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            associated_token_program: associated_token_program.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct OpenDcaV2InstructionAccountIndices {
+    pub dca: usize,
+    pub user: usize,
+    pub payer: usize,
+    pub input_mint: usize,
+    pub output_mint: usize,
+    pub user_ata: usize,
+    pub in_ata: usize,
+    pub out_ata: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub associated_token_program: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for OpenDcaV2 {
+    type ArrangedAccountIndices = OpenDcaV2InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 13 {
+            return None;
+        }
+
+        Some(OpenDcaV2InstructionAccountIndices {
+            dca: 0,
+            user: 1,
+            payer: 2,
+            input_mint: 3,
+            output_mint: 4,
+            user_ata: 5,
+            in_ata: 6,
+            out_ata: 7,
+            system_program: 8,
+            token_program: 9,
+            associated_token_program: 10,
+            event_authority: 11,
+            program: 12,
+            remaining_accounts: 13..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for OpenDcaV2 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "dca",
+            "user",
+            "payer",
+            "input_mint",
+            "output_mint",
+            "user_ata",
+            "in_ata",
+            "out_ata",
+            "system_program",
+            "token_program",
+            "associated_token_program",
+            "event_authority",
+            "program",
+        ]
+    }
+}
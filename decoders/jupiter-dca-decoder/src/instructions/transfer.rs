@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -19,6 +20,7 @@ pub struct TransferInstructionAccounts {
     pub associated_token_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Transfer {
@@ -27,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Transfer {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [keeper, dca, user, output_mint, dca_out_ata, user_out_ata, intermediate_account, system_program, token_program, associated_token_program, event_authority, program, _remaining @ ..] =
+        let [keeper, dca, user, output_mint, dca_out_ata, user_out_ata, intermediate_account, system_program, token_program, associated_token_program, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -46,6 +48,116 @@ impl carbon_core::deserialize::ArrangeAccounts for Transfer {
             associated_token_program: associated_token_program.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct TransferInstructionAccountMetas {
+    pub keeper: solana_instruction::AccountMeta,
+    pub dca: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub output_mint: solana_instruction::AccountMeta,
+    pub dca_out_ata: solana_instruction::AccountMeta,
+    pub user_out_ata: solana_instruction::AccountMeta,
+    pub intermediate_account: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Transfer {
+    type ArrangedAccountMetas = TransferInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [keeper, dca, user, output_mint, dca_out_ata, user_out_ata, intermediate_account, system_program, token_program, associated_token_program, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(TransferInstructionAccountMetas {
+            keeper: keeper.clone(),
+            dca: dca.clone(),
+            user: user.clone(),
+            output_mint: output_mint.clone(),
+            dca_out_ata: dca_out_ata.clone(),
+            user_out_ata: user_out_ata.clone(),
+            intermediate_account: intermediate_account.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            associated_token_program: associated_token_program.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct TransferInstructionAccountIndices {
+    pub keeper: usize,
+    pub dca: usize,
+    pub user: usize,
+    pub output_mint: usize,
+    pub dca_out_ata: usize,
+    pub user_out_ata: usize,
+    pub intermediate_account: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub associated_token_program: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Transfer {
+    type ArrangedAccountIndices = TransferInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 12 {
+            return None;
+        }
+
+        Some(TransferInstructionAccountIndices {
+            keeper: 0,
+            dca: 1,
+            user: 2,
+            output_mint: 3,
+            dca_out_ata: 4,
+            user_out_ata: 5,
+            intermediate_account: 6,
+            system_program: 7,
+            token_program: 8,
+            associated_token_program: 9,
+            event_authority: 10,
+            program: 11,
+            remaining_accounts: 12..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Transfer {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "keeper",
+            "dca",
+            "user",
+            "output_mint",
+            "dca_out_ata",
+            "user_out_ata",
+            "intermediate_account",
+            "system_program",
+            "token_program",
+            "associated_token_program",
+            "event_authority",
+            "program",
+        ]
+    }
+}
@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -24,6 +25,7 @@ pub struct WithdrawInstructionAccounts {
     pub associated_token_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Withdraw {
@@ -32,7 +34,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Withdraw {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [user, dca, input_mint, output_mint, dca_ata, user_in_ata, user_out_ata, system_program, token_program, associated_token_program, event_authority, program, _remaining @ ..] =
+        let [user, dca, input_mint, output_mint, dca_ata, user_in_ata, user_out_ata, system_program, token_program, associated_token_program, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -51,6 +53,116 @@ impl carbon_core::deserialize::ArrangeAccounts for Withdraw {
             associated_token_program: associated_token_program.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct WithdrawInstructionAccountMetas {
+    pub user: solana_instruction::AccountMeta,
+    pub dca: solana_instruction::AccountMeta,
+    pub input_mint: solana_instruction::AccountMeta,
+    pub output_mint: solana_instruction::AccountMeta,
+    pub dca_ata: solana_instruction::AccountMeta,
+    pub user_in_ata: solana_instruction::AccountMeta,
+    pub user_out_ata: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Withdraw {
+    type ArrangedAccountMetas = WithdrawInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [user, dca, input_mint, output_mint, dca_ata, user_in_ata, user_out_ata, system_program, token_program, associated_token_program, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(WithdrawInstructionAccountMetas {
+            user: user.clone(),
+            dca: dca.clone(),
+            input_mint: input_mint.clone(),
+            output_mint: output_mint.clone(),
+            dca_ata: dca_ata.clone(),
+            user_in_ata: user_in_ata.clone(),
+            user_out_ata: user_out_ata.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            associated_token_program: associated_token_program.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct WithdrawInstructionAccountIndices {
+    pub user: usize,
+    pub dca: usize,
+    pub input_mint: usize,
+    pub output_mint: usize,
+    pub dca_ata: usize,
+    pub user_in_ata: usize,
+    pub user_out_ata: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub associated_token_program: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Withdraw {
+    type ArrangedAccountIndices = WithdrawInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 12 {
+            return None;
+        }
+
+        Some(WithdrawInstructionAccountIndices {
+            user: 0,
+            dca: 1,
+            input_mint: 2,
+            output_mint: 3,
+            dca_ata: 4,
+            user_in_ata: 5,
+            user_out_ata: 6,
+            system_program: 7,
+            token_program: 8,
+            associated_token_program: 9,
+            event_authority: 10,
+            program: 11,
+            remaining_accounts: 12..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Withdraw {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "user",
+            "dca",
+            "input_mint",
+            "output_mint",
+            "dca_ata",
+            "user_in_ata",
+            "user_out_ata",
+            "system_program",
+            "token_program",
+            "associated_token_program",
+            "event_authority",
+            "program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -18,6 +19,7 @@ pub struct WithdrawFeesInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub associated_token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for WithdrawFees {
@@ -26,7 +28,7 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawFees {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin, mint, fee_authority, program_fee_ata, admin_fee_ata, system_program, token_program, associated_token_program, _remaining @ ..] =
+        let [admin, mint, fee_authority, program_fee_ata, admin_fee_ata, system_program, token_program, associated_token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -41,6 +43,98 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawFees {
             system_program: system_program.pubkey,
             token_program: token_program.pubkey,
             associated_token_program: associated_token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+#[derive(Debug, PartialEq)]
+pub struct WithdrawFeesInstructionAccountMetas {
+    pub admin: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub fee_authority: solana_instruction::AccountMeta,
+    pub program_fee_ata: solana_instruction::AccountMeta,
+    pub admin_fee_ata: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for WithdrawFees {
+    type ArrangedAccountMetas = WithdrawFeesInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin, mint, fee_authority, program_fee_ata, admin_fee_ata, system_program, token_program, associated_token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(WithdrawFeesInstructionAccountMetas {
+            admin: admin.clone(),
+            mint: mint.clone(),
+            fee_authority: fee_authority.clone(),
+            program_fee_ata: program_fee_ata.clone(),
+            admin_fee_ata: admin_fee_ata.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            associated_token_program: associated_token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct WithdrawFeesInstructionAccountIndices {
+    pub admin: usize,
+    pub mint: usize,
+    pub fee_authority: usize,
+    pub program_fee_ata: usize,
+    pub admin_fee_ata: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub associated_token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for WithdrawFees {
+    type ArrangedAccountIndices = WithdrawFeesInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(WithdrawFeesInstructionAccountIndices {
+            admin: 0,
+            mint: 1,
+            fee_authority: 2,
+            program_fee_ata: 3,
+            admin_fee_ata: 4,
+            system_program: 5,
+            token_program: 6,
+            associated_token_program: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for WithdrawFees {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "admin",
+            "mint",
+            "fee_authority",
+            "program_fee_ata",
+            "admin_fee_ata",
+            "system_program",
+            "token_program",
+            "associated_token_program",
+        ]
+    }
+}
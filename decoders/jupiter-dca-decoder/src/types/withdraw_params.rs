@@ -2,6 +2,7 @@ use {
     super::*,
     carbon_core::{borsh, CarbonDeserialize},
 };
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -16,6 +17,7 @@ pub struct CancelOrderInstructionAccounts {
     pub input_token_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CancelOrder {
@@ -24,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CancelOrder {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [signer, maker, order, input_mint_reserve, maker_input_mint_account, input_mint, input_token_program, event_authority, program, _remaining @ ..] =
+        let [signer, maker, order, input_mint_reserve, maker_input_mint_account, input_mint, input_token_program, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -40,6 +42,101 @@ impl carbon_core::deserialize::ArrangeAccounts for CancelOrder {
             input_token_program: input_token_program.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CancelOrderInstructionAccountMetas {
+    pub signer: solana_instruction::AccountMeta,
+    pub maker: solana_instruction::AccountMeta,
+    pub order: solana_instruction::AccountMeta,
+    pub input_mint_reserve: solana_instruction::AccountMeta,
+    pub maker_input_mint_account: solana_instruction::AccountMeta,
+    pub input_mint: solana_instruction::AccountMeta,
+    pub input_token_program: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CancelOrder {
+    type ArrangedAccountMetas = CancelOrderInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [signer, maker, order, input_mint_reserve, maker_input_mint_account, input_mint, input_token_program, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CancelOrderInstructionAccountMetas {
+            signer: signer.clone(),
+            maker: maker.clone(),
+            order: order.clone(),
+            input_mint_reserve: input_mint_reserve.clone(),
+            maker_input_mint_account: maker_input_mint_account.clone(),
+            input_mint: input_mint.clone(),
+            input_token_program: input_token_program.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CancelOrderInstructionAccountIndices {
+    pub signer: usize,
+    pub maker: usize,
+    pub order: usize,
+    pub input_mint_reserve: usize,
+    pub maker_input_mint_account: usize,
+    pub input_mint: usize,
+    pub input_token_program: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CancelOrder {
+    type ArrangedAccountIndices = CancelOrderInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 9 {
+            return None;
+        }
+
+        Some(CancelOrderInstructionAccountIndices {
+            signer: 0,
+            maker: 1,
+            order: 2,
+            input_mint_reserve: 3,
+            maker_input_mint_account: 4,
+            input_mint: 5,
+            input_token_program: 6,
+            event_authority: 7,
+            program: 8,
+            remaining_accounts: 9..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CancelOrder {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "signer",
+            "maker",
+            "order",
+            "input_mint_reserve",
+            "maker_input_mint_account",
+            "input_mint",
+            "input_token_program",
+            "event_authority",
+            "program",
+        ]
+    }
+}
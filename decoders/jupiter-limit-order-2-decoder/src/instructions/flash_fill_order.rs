@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -25,6 +26,7 @@ pub struct FlashFillOrderInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for FlashFillOrder {
@@ -33,7 +35,7 @@ impl carbon_core::deserialize::ArrangeAccounts for FlashFillOrder {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [taker, maker, order, input_mint_reserve, maker_output_mint_account, taker_output_mint_account, fee_account, input_token_program, output_mint, output_token_program, system_program, event_authority, program, _remaining @ ..] =
+        let [taker, maker, order, input_mint_reserve, maker_output_mint_account, taker_output_mint_account, fee_account, input_token_program, output_mint, output_token_program, system_program, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -53,6 +55,121 @@ impl carbon_core::deserialize::ArrangeAccounts for FlashFillOrder {
             system_program: system_program.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct FlashFillOrderInstructionAccountMetas {
+    pub taker: solana_instruction::AccountMeta,
+    pub maker: solana_instruction::AccountMeta,
+    pub order: solana_instruction::AccountMeta,
+    pub input_mint_reserve: solana_instruction::AccountMeta,
+    pub maker_output_mint_account: solana_instruction::AccountMeta,
+    pub taker_output_mint_account: solana_instruction::AccountMeta,
+    pub fee_account: solana_instruction::AccountMeta,
+    pub input_token_program: solana_instruction::AccountMeta,
+    pub output_mint: solana_instruction::AccountMeta,
+    pub output_token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for FlashFillOrder {
+    type ArrangedAccountMetas = FlashFillOrderInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [taker, maker, order, input_mint_reserve, maker_output_mint_account, taker_output_mint_account, fee_account, input_token_program, output_mint, output_token_program, system_program, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(FlashFillOrderInstructionAccountMetas {
+            taker: taker.clone(),
+            maker: maker.clone(),
+            order: order.clone(),
+            input_mint_reserve: input_mint_reserve.clone(),
+            maker_output_mint_account: maker_output_mint_account.clone(),
+            taker_output_mint_account: taker_output_mint_account.clone(),
+            fee_account: fee_account.clone(),
+            input_token_program: input_token_program.clone(),
+            output_mint: output_mint.clone(),
+            output_token_program: output_token_program.clone(),
+            system_program: system_program.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct FlashFillOrderInstructionAccountIndices {
+    pub taker: usize,
+    pub maker: usize,
+    pub order: usize,
+    pub input_mint_reserve: usize,
+    pub maker_output_mint_account: usize,
+    pub taker_output_mint_account: usize,
+    pub fee_account: usize,
+    pub input_token_program: usize,
+    pub output_mint: usize,
+    pub output_token_program: usize,
+    pub system_program: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for FlashFillOrder {
+    type ArrangedAccountIndices = FlashFillOrderInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 13 {
+            return None;
+        }
+
+        Some(FlashFillOrderInstructionAccountIndices {
+            taker: 0,
+            maker: 1,
+            order: 2,
+            input_mint_reserve: 3,
+            maker_output_mint_account: 4,
+            taker_output_mint_account: 5,
+            fee_account: 6,
+            input_token_program: 7,
+            output_mint: 8,
+            output_token_program: 9,
+            system_program: 10,
+            event_authority: 11,
+            program: 12,
+            remaining_accounts: 13..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for FlashFillOrder {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "taker",
+            "maker",
+            "order",
+            "input_mint_reserve",
+            "maker_output_mint_account",
+            "taker_output_mint_account",
+            "fee_account",
+            "input_token_program",
+            "output_mint",
+            "output_token_program",
+            "system_program",
+            "event_authority",
+            "program",
+        ]
+    }
+}
@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -27,6 +28,7 @@ pub struct InitializeOrderInstructionAccounts {
     pub associated_token_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeOrder {
@@ -35,7 +37,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeOrder {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [payer, maker, order, input_mint_reserve, maker_input_mint_account, fee, referral, input_mint, output_mint, input_token_program, output_token_program, system_program, associated_token_program, event_authority, program, _remaining @ ..] =
+        let [payer, maker, order, input_mint_reserve, maker_input_mint_account, fee, referral, input_mint, output_mint, input_token_program, output_token_program, system_program, associated_token_program, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -57,6 +59,131 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeOrder {
             associated_token_program: associated_token_program.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeOrderInstructionAccountMetas {
+    pub payer: solana_instruction::AccountMeta,
+    pub maker: solana_instruction::AccountMeta,
+    pub order: solana_instruction::AccountMeta,
+    pub input_mint_reserve: solana_instruction::AccountMeta,
+    pub maker_input_mint_account: solana_instruction::AccountMeta,
+    pub fee: solana_instruction::AccountMeta,
+    pub referral: solana_instruction::AccountMeta,
+    pub input_mint: solana_instruction::AccountMeta,
+    pub output_mint: solana_instruction::AccountMeta,
+    pub input_token_program: solana_instruction::AccountMeta,
+    pub output_token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeOrder {
+    type ArrangedAccountMetas = InitializeOrderInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [payer, maker, order, input_mint_reserve, maker_input_mint_account, fee, referral, input_mint, output_mint, input_token_program, output_token_program, system_program, associated_token_program, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeOrderInstructionAccountMetas {
+            payer: payer.clone(),
+            maker: maker.clone(),
+            order: order.clone(),
+            input_mint_reserve: input_mint_reserve.clone(),
+            maker_input_mint_account: maker_input_mint_account.clone(),
+            fee: fee.clone(),
+            referral: referral.clone(),
+            input_mint: input_mint.clone(),
+            output_mint: output_mint.clone(),
+            input_token_program: input_token_program.clone(),
+            output_token_program: output_token_program.clone(),
+            system_program: system_program.clone(),
+            associated_token_program: associated_token_program.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeOrderInstructionAccountIndices {
+    pub payer: usize,
+    pub maker: usize,
+    pub order: usize,
+    pub input_mint_reserve: usize,
+    pub maker_input_mint_account: usize,
+    pub fee: usize,
+    pub referral: usize,
+    pub input_mint: usize,
+    pub output_mint: usize,
+    pub input_token_program: usize,
+    pub output_token_program: usize,
+    pub system_program: usize,
+    pub associated_token_program: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeOrder {
+    type ArrangedAccountIndices = InitializeOrderInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 15 {
+            return None;
+        }
+
+        Some(InitializeOrderInstructionAccountIndices {
+            payer: 0,
+            maker: 1,
+            order: 2,
+            input_mint_reserve: 3,
+            maker_input_mint_account: 4,
+            fee: 5,
+            referral: 6,
+            input_mint: 7,
+            output_mint: 8,
+            input_token_program: 9,
+            output_token_program: 10,
+            system_program: 11,
+            associated_token_program: 12,
+            event_authority: 13,
+            program: 14,
+            remaining_accounts: 15..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeOrder {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "payer",
+            "maker",
+            "order",
+            "input_mint_reserve",
+            "maker_input_mint_account",
+            "fee",
+            "referral",
+            "input_mint",
+            "output_mint",
+            "input_token_program",
+            "output_token_program",
+            "system_program",
+            "associated_token_program",
+            "event_authority",
+            "program",
+        ]
+    }
+}
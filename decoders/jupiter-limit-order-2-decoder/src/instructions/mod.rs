@@ -36,15 +36,25 @@ pub enum JupiterLimitOrder2Instruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for JupiterLimitOrder2Decoder {
     type InstructionType = JupiterLimitOrder2Instruction;
 
-    fn decode_instruction(
+    fn decoder_name(&self) -> &'static str {
+        "jupiter_limit_order2"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[super::PROGRAM_ID]
+    }
+
+    fn try_decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
-    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+    ) -> carbon_core::error::CarbonResult<
+        Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>>,
+    > {
         if !instruction.program_id.eq(&PROGRAM_ID) {
-            return None;
+            return Ok(None);
         }
 
-        carbon_core::try_decode_instructions!(instruction,
+        Ok(carbon_core::try_decode_instructions!(instruction,
             JupiterLimitOrder2Instruction::UpdateFee => update_fee::UpdateFee,
             JupiterLimitOrder2Instruction::WithdrawFee => withdraw_fee::WithdrawFee,
             JupiterLimitOrder2Instruction::InitializeOrder => initialize_order::InitializeOrder,
@@ -54,6 +64,6 @@ impl carbon_core::instruction::InstructionDecoder<'_> for JupiterLimitOrder2Deco
             JupiterLimitOrder2Instruction::TradeEvent => trade_event::TradeEvent,
             JupiterLimitOrder2Instruction::CancelOrderEvent => cancel_order_event::CancelOrderEvent,
             JupiterLimitOrder2Instruction::CreateOrderEvent => create_order_event::CreateOrderEvent,
-        )
+        ))
     }
 }
@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -19,6 +20,7 @@ pub struct PreFlashFillOrderInstructionAccounts {
     pub input_mint: solana_pubkey::Pubkey,
     pub input_token_program: solana_pubkey::Pubkey,
     pub instruction: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for PreFlashFillOrder {
@@ -27,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for PreFlashFillOrder {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [taker, order, input_mint_reserve, taker_input_mint_account, input_mint, input_token_program, instruction, _remaining @ ..] =
+        let [taker, order, input_mint_reserve, taker_input_mint_account, input_mint, input_token_program, instruction, remaining @ ..] =
             accounts
         else {
             return None;
@@ -41,6 +43,91 @@ impl carbon_core::deserialize::ArrangeAccounts for PreFlashFillOrder {
             input_mint: input_mint.pubkey,
             input_token_program: input_token_program.pubkey,
             instruction: instruction.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct PreFlashFillOrderInstructionAccountMetas {
+    pub taker: solana_instruction::AccountMeta,
+    pub order: solana_instruction::AccountMeta,
+    pub input_mint_reserve: solana_instruction::AccountMeta,
+    pub taker_input_mint_account: solana_instruction::AccountMeta,
+    pub input_mint: solana_instruction::AccountMeta,
+    pub input_token_program: solana_instruction::AccountMeta,
+    pub instruction: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for PreFlashFillOrder {
+    type ArrangedAccountMetas = PreFlashFillOrderInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [taker, order, input_mint_reserve, taker_input_mint_account, input_mint, input_token_program, instruction, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(PreFlashFillOrderInstructionAccountMetas {
+            taker: taker.clone(),
+            order: order.clone(),
+            input_mint_reserve: input_mint_reserve.clone(),
+            taker_input_mint_account: taker_input_mint_account.clone(),
+            input_mint: input_mint.clone(),
+            input_token_program: input_token_program.clone(),
+            instruction: instruction.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct PreFlashFillOrderInstructionAccountIndices {
+    pub taker: usize,
+    pub order: usize,
+    pub input_mint_reserve: usize,
+    pub taker_input_mint_account: usize,
+    pub input_mint: usize,
+    pub input_token_program: usize,
+    pub instruction: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for PreFlashFillOrder {
+    type ArrangedAccountIndices = PreFlashFillOrderInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 7 {
+            return None;
+        }
+
+        Some(PreFlashFillOrderInstructionAccountIndices {
+            taker: 0,
+            order: 1,
+            input_mint_reserve: 2,
+            taker_input_mint_account: 3,
+            input_mint: 4,
+            input_token_program: 5,
+            instruction: 6,
+            remaining_accounts: 7..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for PreFlashFillOrder {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "taker",
+            "order",
+            "input_mint_reserve",
+            "taker_input_mint_account",
+            "input_mint",
+            "input_token_program",
+            "instruction",
+        ]
+    }
+}
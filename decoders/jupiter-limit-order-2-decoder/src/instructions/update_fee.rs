@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -15,6 +16,7 @@ pub struct UpdateFeeInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub fee_authority: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateFee {
@@ -23,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateFee {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin, fee_authority, system_program, _remaining @ ..] = accounts else {
+        let [admin, fee_authority, system_program, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -31,6 +33,65 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateFee {
             admin: admin.pubkey,
             fee_authority: fee_authority.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateFeeInstructionAccountMetas {
+    pub admin: solana_instruction::AccountMeta,
+    pub fee_authority: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateFee {
+    type ArrangedAccountMetas = UpdateFeeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin, fee_authority, system_program, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateFeeInstructionAccountMetas {
+            admin: admin.clone(),
+            fee_authority: fee_authority.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateFeeInstructionAccountIndices {
+    pub admin: usize,
+    pub fee_authority: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateFee {
+    type ArrangedAccountIndices = UpdateFeeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(UpdateFeeInstructionAccountIndices {
+            admin: 0,
+            fee_authority: 1,
+            system_program: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateFee {
+    fn account_names() -> &'static [&'static str] {
+        &["admin", "fee_authority", "system_program"]
+    }
+}
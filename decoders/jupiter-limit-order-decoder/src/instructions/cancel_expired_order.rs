@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct CancelExpiredOrderInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub input_mint: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CancelExpiredOrder {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CancelExpiredOrder {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [order, reserve, maker, maker_input_account, system_program, token_program, input_mint, _remaining @ ..] =
+        let [order, reserve, maker, maker_input_account, system_program, token_program, input_mint, remaining @ ..] =
             accounts
         else {
             return None;
@@ -36,6 +38,91 @@ impl carbon_core::deserialize::ArrangeAccounts for CancelExpiredOrder {
             system_program: system_program.pubkey,
             token_program: token_program.pubkey,
             input_mint: input_mint.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CancelExpiredOrderInstructionAccountMetas {
+    pub order: solana_instruction::AccountMeta,
+    pub reserve: solana_instruction::AccountMeta,
+    pub maker: solana_instruction::AccountMeta,
+    pub maker_input_account: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub input_mint: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CancelExpiredOrder {
+    type ArrangedAccountMetas = CancelExpiredOrderInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [order, reserve, maker, maker_input_account, system_program, token_program, input_mint, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CancelExpiredOrderInstructionAccountMetas {
+            order: order.clone(),
+            reserve: reserve.clone(),
+            maker: maker.clone(),
+            maker_input_account: maker_input_account.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            input_mint: input_mint.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CancelExpiredOrderInstructionAccountIndices {
+    pub order: usize,
+    pub reserve: usize,
+    pub maker: usize,
+    pub maker_input_account: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub input_mint: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CancelExpiredOrder {
+    type ArrangedAccountIndices = CancelExpiredOrderInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 7 {
+            return None;
+        }
+
+        Some(CancelExpiredOrderInstructionAccountIndices {
+            order: 0,
+            reserve: 1,
+            maker: 2,
+            maker_input_account: 3,
+            system_program: 4,
+            token_program: 5,
+            input_mint: 6,
+            remaining_accounts: 7..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CancelExpiredOrder {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "order",
+            "reserve",
+            "maker",
+            "maker_input_account",
+            "system_program",
+            "token_program",
+            "input_mint",
+        ]
+    }
+}
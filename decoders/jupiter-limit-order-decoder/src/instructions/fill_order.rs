@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -22,6 +23,7 @@ pub struct FillOrderInstructionAccounts {
     pub referral: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for FillOrder {
@@ -30,7 +32,7 @@ impl carbon_core::deserialize::ArrangeAccounts for FillOrder {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [order, reserve, maker, taker, taker_output_account, maker_output_account, taker_input_account, fee_authority, program_fee_account, referral, token_program, system_program, _remaining @ ..] =
+        let [order, reserve, maker, taker, taker_output_account, maker_output_account, taker_input_account, fee_authority, program_fee_account, referral, token_program, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -49,6 +51,116 @@ impl carbon_core::deserialize::ArrangeAccounts for FillOrder {
             referral: referral.pubkey,
             token_program: token_program.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct FillOrderInstructionAccountMetas {
+    pub order: solana_instruction::AccountMeta,
+    pub reserve: solana_instruction::AccountMeta,
+    pub maker: solana_instruction::AccountMeta,
+    pub taker: solana_instruction::AccountMeta,
+    pub taker_output_account: solana_instruction::AccountMeta,
+    pub maker_output_account: solana_instruction::AccountMeta,
+    pub taker_input_account: solana_instruction::AccountMeta,
+    pub fee_authority: solana_instruction::AccountMeta,
+    pub program_fee_account: solana_instruction::AccountMeta,
+    pub referral: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for FillOrder {
+    type ArrangedAccountMetas = FillOrderInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [order, reserve, maker, taker, taker_output_account, maker_output_account, taker_input_account, fee_authority, program_fee_account, referral, token_program, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(FillOrderInstructionAccountMetas {
+            order: order.clone(),
+            reserve: reserve.clone(),
+            maker: maker.clone(),
+            taker: taker.clone(),
+            taker_output_account: taker_output_account.clone(),
+            maker_output_account: maker_output_account.clone(),
+            taker_input_account: taker_input_account.clone(),
+            fee_authority: fee_authority.clone(),
+            program_fee_account: program_fee_account.clone(),
+            referral: referral.clone(),
+            token_program: token_program.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct FillOrderInstructionAccountIndices {
+    pub order: usize,
+    pub reserve: usize,
+    pub maker: usize,
+    pub taker: usize,
+    pub taker_output_account: usize,
+    pub maker_output_account: usize,
+    pub taker_input_account: usize,
+    pub fee_authority: usize,
+    pub program_fee_account: usize,
+    pub referral: usize,
+    pub token_program: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for FillOrder {
+    type ArrangedAccountIndices = FillOrderInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 12 {
+            return None;
+        }
+
+        Some(FillOrderInstructionAccountIndices {
+            order: 0,
+            reserve: 1,
+            maker: 2,
+            taker: 3,
+            taker_output_account: 4,
+            maker_output_account: 5,
+            taker_input_account: 6,
+            fee_authority: 7,
+            program_fee_account: 8,
+            referral: 9,
+            token_program: 10,
+            system_program: 11,
+            remaining_accounts: 12..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for FillOrder {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "order",
+            "reserve",
+            "maker",
+            "taker",
+            "taker_output_account",
+            "maker_output_account",
+            "taker_input_account",
+            "fee_authority",
+            "program_fee_account",
+            "referral",
+            "token_program",
+            "system_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -23,6 +24,7 @@ pub struct FlashFillOrderInstructionAccounts {
     pub output_mint: solana_pubkey::Pubkey,
     pub output_mint_token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for FlashFillOrder {
@@ -31,7 +33,7 @@ impl carbon_core::deserialize::ArrangeAccounts for FlashFillOrder {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [order, reserve, maker, taker, maker_output_account, taker_input_account, fee_authority, program_fee_account, referral, input_mint, input_mint_token_program, output_mint, output_mint_token_program, system_program, _remaining @ ..] =
+        let [order, reserve, maker, taker, maker_output_account, taker_input_account, fee_authority, program_fee_account, referral, input_mint, input_mint_token_program, output_mint, output_mint_token_program, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -52,6 +54,126 @@ impl carbon_core::deserialize::ArrangeAccounts for FlashFillOrder {
             output_mint: output_mint.pubkey,
             output_mint_token_program: output_mint_token_program.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct FlashFillOrderInstructionAccountMetas {
+    pub order: solana_instruction::AccountMeta,
+    pub reserve: solana_instruction::AccountMeta,
+    pub maker: solana_instruction::AccountMeta,
+    pub taker: solana_instruction::AccountMeta,
+    pub maker_output_account: solana_instruction::AccountMeta,
+    pub taker_input_account: solana_instruction::AccountMeta,
+    pub fee_authority: solana_instruction::AccountMeta,
+    pub program_fee_account: solana_instruction::AccountMeta,
+    pub referral: solana_instruction::AccountMeta,
+    pub input_mint: solana_instruction::AccountMeta,
+    pub input_mint_token_program: solana_instruction::AccountMeta,
+    pub output_mint: solana_instruction::AccountMeta,
+    pub output_mint_token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for FlashFillOrder {
+    type ArrangedAccountMetas = FlashFillOrderInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [order, reserve, maker, taker, maker_output_account, taker_input_account, fee_authority, program_fee_account, referral, input_mint, input_mint_token_program, output_mint, output_mint_token_program, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(FlashFillOrderInstructionAccountMetas {
+            order: order.clone(),
+            reserve: reserve.clone(),
+            maker: maker.clone(),
+            taker: taker.clone(),
+            maker_output_account: maker_output_account.clone(),
+            taker_input_account: taker_input_account.clone(),
+            fee_authority: fee_authority.clone(),
+            program_fee_account: program_fee_account.clone(),
+            referral: referral.clone(),
+            input_mint: input_mint.clone(),
+            input_mint_token_program: input_mint_token_program.clone(),
+            output_mint: output_mint.clone(),
+            output_mint_token_program: output_mint_token_program.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct FlashFillOrderInstructionAccountIndices {
+    pub order: usize,
+    pub reserve: usize,
+    pub maker: usize,
+    pub taker: usize,
+    pub maker_output_account: usize,
+    pub taker_input_account: usize,
+    pub fee_authority: usize,
+    pub program_fee_account: usize,
+    pub referral: usize,
+    pub input_mint: usize,
+    pub input_mint_token_program: usize,
+    pub output_mint: usize,
+    pub output_mint_token_program: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for FlashFillOrder {
+    type ArrangedAccountIndices = FlashFillOrderInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 14 {
+            return None;
+        }
+
+        Some(FlashFillOrderInstructionAccountIndices {
+            order: 0,
+            reserve: 1,
+            maker: 2,
+            taker: 3,
+            maker_output_account: 4,
+            taker_input_account: 5,
+            fee_authority: 6,
+            program_fee_account: 7,
+            referral: 8,
+            input_mint: 9,
+            input_mint_token_program: 10,
+            output_mint: 11,
+            output_mint_token_program: 12,
+            system_program: 13,
+            remaining_accounts: 14..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for FlashFillOrder {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "order",
+            "reserve",
+            "maker",
+            "taker",
+            "maker_output_account",
+            "taker_input_account",
+            "fee_authority",
+            "program_fee_account",
+            "referral",
+            "input_mint",
+            "input_mint_token_program",
+            "output_mint",
+            "output_mint_token_program",
+            "system_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -15,6 +16,7 @@ pub struct InitFeeInstructionAccounts {
     pub keeper: solana_pubkey::Pubkey,
     pub fee_authority: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitFee {
@@ -23,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitFee {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [keeper, fee_authority, system_program, _remaining @ ..] = accounts else {
+        let [keeper, fee_authority, system_program, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -31,6 +33,65 @@ impl carbon_core::deserialize::ArrangeAccounts for InitFee {
             keeper: keeper.pubkey,
             fee_authority: fee_authority.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitFeeInstructionAccountMetas {
+    pub keeper: solana_instruction::AccountMeta,
+    pub fee_authority: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitFee {
+    type ArrangedAccountMetas = InitFeeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [keeper, fee_authority, system_program, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(InitFeeInstructionAccountMetas {
+            keeper: keeper.clone(),
+            fee_authority: fee_authority.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitFeeInstructionAccountIndices {
+    pub keeper: usize,
+    pub fee_authority: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitFee {
+    type ArrangedAccountIndices = InitFeeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(InitFeeInstructionAccountIndices {
+            keeper: 0,
+            fee_authority: 1,
+            system_program: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitFee {
+    fn account_names() -> &'static [&'static str] {
+        &["keeper", "fee_authority", "system_program"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -23,6 +24,7 @@ pub struct InitializeOrderInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeOrder {
@@ -31,7 +33,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeOrder {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [base, maker, order, reserve, maker_input_account, input_mint, maker_output_account, referral, output_mint, system_program, token_program, rent, _remaining @ ..] =
+        let [base, maker, order, reserve, maker_input_account, input_mint, maker_output_account, referral, output_mint, system_program, token_program, rent, remaining @ ..] =
             accounts
         else {
             return None;
@@ -50,6 +52,116 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeOrder {
             system_program: system_program.pubkey,
             token_program: token_program.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeOrderInstructionAccountMetas {
+    pub base: solana_instruction::AccountMeta,
+    pub maker: solana_instruction::AccountMeta,
+    pub order: solana_instruction::AccountMeta,
+    pub reserve: solana_instruction::AccountMeta,
+    pub maker_input_account: solana_instruction::AccountMeta,
+    pub input_mint: solana_instruction::AccountMeta,
+    pub maker_output_account: solana_instruction::AccountMeta,
+    pub referral: solana_instruction::AccountMeta,
+    pub output_mint: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeOrder {
+    type ArrangedAccountMetas = InitializeOrderInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [base, maker, order, reserve, maker_input_account, input_mint, maker_output_account, referral, output_mint, system_program, token_program, rent, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeOrderInstructionAccountMetas {
+            base: base.clone(),
+            maker: maker.clone(),
+            order: order.clone(),
+            reserve: reserve.clone(),
+            maker_input_account: maker_input_account.clone(),
+            input_mint: input_mint.clone(),
+            maker_output_account: maker_output_account.clone(),
+            referral: referral.clone(),
+            output_mint: output_mint.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeOrderInstructionAccountIndices {
+    pub base: usize,
+    pub maker: usize,
+    pub order: usize,
+    pub reserve: usize,
+    pub maker_input_account: usize,
+    pub input_mint: usize,
+    pub maker_output_account: usize,
+    pub referral: usize,
+    pub output_mint: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeOrder {
+    type ArrangedAccountIndices = InitializeOrderInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 12 {
+            return None;
+        }
+
+        Some(InitializeOrderInstructionAccountIndices {
+            base: 0,
+            maker: 1,
+            order: 2,
+            reserve: 3,
+            maker_input_account: 4,
+            input_mint: 5,
+            maker_output_account: 6,
+            referral: 7,
+            output_mint: 8,
+            system_program: 9,
+            token_program: 10,
+            rent: 11,
+            remaining_accounts: 12..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeOrder {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "base",
+            "maker",
+            "order",
+            "reserve",
+            "maker_input_account",
+            "input_mint",
+            "maker_output_account",
+            "referral",
+            "output_mint",
+            "system_program",
+            "token_program",
+            "rent",
+        ]
+    }
+}
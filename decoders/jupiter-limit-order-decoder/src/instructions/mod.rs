@@ -42,15 +42,25 @@ pub enum JupiterLimitOrderInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for JupiterLimitOrderDecoder {
     type InstructionType = JupiterLimitOrderInstruction;
 
-    fn decode_instruction(
+    fn decoder_name(&self) -> &'static str {
+        "jupiter_limit_order"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[super::PROGRAM_ID]
+    }
+
+    fn try_decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
-    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+    ) -> carbon_core::error::CarbonResult<
+        Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>>,
+    > {
         if !instruction.program_id.eq(&PROGRAM_ID) {
-            return None;
+            return Ok(None);
         }
 
-        carbon_core::try_decode_instructions!(instruction,
+        Ok(carbon_core::try_decode_instructions!(instruction,
             JupiterLimitOrderInstruction::InitializeOrder => initialize_order::InitializeOrder,
             JupiterLimitOrderInstruction::FillOrder => fill_order::FillOrder,
             JupiterLimitOrderInstruction::PreFlashFillOrder => pre_flash_fill_order::PreFlashFillOrder,
@@ -63,6 +73,6 @@ impl carbon_core::instruction::InstructionDecoder<'_> for JupiterLimitOrderDecod
             JupiterLimitOrderInstruction::TradeEvent => trade_event::TradeEvent,
             JupiterLimitOrderInstruction::CancelOrderEvent => cancel_order_event::CancelOrderEvent,
             JupiterLimitOrderInstruction::CreateOrderEvent => create_order_event::CreateOrderEvent,
-        )
+        ))
     }
 }
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -17,6 +18,7 @@ pub struct PreFlashFillOrderInstructionAccounts {
     pub input_mint_token_program: solana_pubkey::Pubkey,
     pub instruction: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for PreFlashFillOrder {
@@ -25,7 +27,7 @@ impl carbon_core::deserialize::ArrangeAccounts for PreFlashFillOrder {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [order, reserve, taker, taker_output_account, input_mint, input_mint_token_program, instruction, system_program, _remaining @ ..] =
+        let [order, reserve, taker, taker_output_account, input_mint, input_mint_token_program, instruction, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -40,6 +42,96 @@ impl carbon_core::deserialize::ArrangeAccounts for PreFlashFillOrder {
             input_mint_token_program: input_mint_token_program.pubkey,
             instruction: instruction.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct PreFlashFillOrderInstructionAccountMetas {
+    pub order: solana_instruction::AccountMeta,
+    pub reserve: solana_instruction::AccountMeta,
+    pub taker: solana_instruction::AccountMeta,
+    pub taker_output_account: solana_instruction::AccountMeta,
+    pub input_mint: solana_instruction::AccountMeta,
+    pub input_mint_token_program: solana_instruction::AccountMeta,
+    pub instruction: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for PreFlashFillOrder {
+    type ArrangedAccountMetas = PreFlashFillOrderInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [order, reserve, taker, taker_output_account, input_mint, input_mint_token_program, instruction, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(PreFlashFillOrderInstructionAccountMetas {
+            order: order.clone(),
+            reserve: reserve.clone(),
+            taker: taker.clone(),
+            taker_output_account: taker_output_account.clone(),
+            input_mint: input_mint.clone(),
+            input_mint_token_program: input_mint_token_program.clone(),
+            instruction: instruction.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct PreFlashFillOrderInstructionAccountIndices {
+    pub order: usize,
+    pub reserve: usize,
+    pub taker: usize,
+    pub taker_output_account: usize,
+    pub input_mint: usize,
+    pub input_mint_token_program: usize,
+    pub instruction: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for PreFlashFillOrder {
+    type ArrangedAccountIndices = PreFlashFillOrderInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(PreFlashFillOrderInstructionAccountIndices {
+            order: 0,
+            reserve: 1,
+            taker: 2,
+            taker_output_account: 3,
+            input_mint: 4,
+            input_mint_token_program: 5,
+            instruction: 6,
+            system_program: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for PreFlashFillOrder {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "order",
+            "reserve",
+            "taker",
+            "taker_output_account",
+            "input_mint",
+            "input_mint_token_program",
+            "instruction",
+            "system_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct UpdateFee {
 pub struct UpdateFeeInstructionAccounts {
     pub keeper: solana_pubkey::Pubkey,
     pub fee_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateFee {
@@ -22,13 +24,68 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateFee {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [keeper, fee_authority, _remaining @ ..] = accounts else {
+        let [keeper, fee_authority, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(UpdateFeeInstructionAccounts {
             keeper: keeper.pubkey,
             fee_authority: fee_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateFeeInstructionAccountMetas {
+    pub keeper: solana_instruction::AccountMeta,
+    pub fee_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateFee {
+    type ArrangedAccountMetas = UpdateFeeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [keeper, fee_authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateFeeInstructionAccountMetas {
+            keeper: keeper.clone(),
+            fee_authority: fee_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateFeeInstructionAccountIndices {
+    pub keeper: usize,
+    pub fee_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateFee {
+    type ArrangedAccountIndices = UpdateFeeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(UpdateFeeInstructionAccountIndices {
+            keeper: 0,
+            fee_authority: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateFee {
+    fn account_names() -> &'static [&'static str] {
+        &["keeper", "fee_authority"]
+    }
+}
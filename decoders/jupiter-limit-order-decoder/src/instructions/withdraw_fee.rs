@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -15,6 +16,7 @@ pub struct WithdrawFeeInstructionAccounts {
     pub admin_token_acocunt: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub mint: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for WithdrawFee {
@@ -23,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawFee {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin, fee_authority, program_fee_account, admin_token_acocunt, token_program, mint, _remaining @ ..] =
+        let [admin, fee_authority, program_fee_account, admin_token_acocunt, token_program, mint, remaining @ ..] =
             accounts
         else {
             return None;
@@ -36,6 +38,86 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawFee {
             admin_token_acocunt: admin_token_acocunt.pubkey,
             token_program: token_program.pubkey,
             mint: mint.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct WithdrawFeeInstructionAccountMetas {
+    pub admin: solana_instruction::AccountMeta,
+    pub fee_authority: solana_instruction::AccountMeta,
+    pub program_fee_account: solana_instruction::AccountMeta,
+    pub admin_token_acocunt: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for WithdrawFee {
+    type ArrangedAccountMetas = WithdrawFeeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin, fee_authority, program_fee_account, admin_token_acocunt, token_program, mint, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(WithdrawFeeInstructionAccountMetas {
+            admin: admin.clone(),
+            fee_authority: fee_authority.clone(),
+            program_fee_account: program_fee_account.clone(),
+            admin_token_acocunt: admin_token_acocunt.clone(),
+            token_program: token_program.clone(),
+            mint: mint.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct WithdrawFeeInstructionAccountIndices {
+    pub admin: usize,
+    pub fee_authority: usize,
+    pub program_fee_account: usize,
+    pub admin_token_acocunt: usize,
+    pub token_program: usize,
+    pub mint: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for WithdrawFee {
+    type ArrangedAccountIndices = WithdrawFeeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(WithdrawFeeInstructionAccountIndices {
+            admin: 0,
+            fee_authority: 1,
+            program_fee_account: 2,
+            admin_token_acocunt: 3,
+            token_program: 4,
+            mint: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for WithdrawFee {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "admin",
+            "fee_authority",
+            "program_fee_account",
+            "admin_token_acocunt",
+            "token_program",
+            "mint",
+        ]
+    }
+}
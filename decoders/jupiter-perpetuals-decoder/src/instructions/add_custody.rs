@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -21,6 +22,7 @@ pub struct AddCustodyInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for AddCustody {
@@ -29,7 +31,7 @@ impl carbon_core::deserialize::ArrangeAccounts for AddCustody {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin, transfer_authority, perpetuals, pool, custody, custody_token_account, custody_token_mint, system_program, token_program, rent, _remaining @ ..] =
+        let [admin, transfer_authority, perpetuals, pool, custody, custody_token_account, custody_token_mint, system_program, token_program, rent, remaining @ ..] =
             accounts
         else {
             return None;
@@ -46,6 +48,106 @@ impl carbon_core::deserialize::ArrangeAccounts for AddCustody {
             system_program: system_program.pubkey,
             token_program: token_program.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct AddCustodyInstructionAccountMetas {
+    pub admin: solana_instruction::AccountMeta,
+    pub transfer_authority: solana_instruction::AccountMeta,
+    pub perpetuals: solana_instruction::AccountMeta,
+    pub pool: solana_instruction::AccountMeta,
+    pub custody: solana_instruction::AccountMeta,
+    pub custody_token_account: solana_instruction::AccountMeta,
+    pub custody_token_mint: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for AddCustody {
+    type ArrangedAccountMetas = AddCustodyInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin, transfer_authority, perpetuals, pool, custody, custody_token_account, custody_token_mint, system_program, token_program, rent, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(AddCustodyInstructionAccountMetas {
+            admin: admin.clone(),
+            transfer_authority: transfer_authority.clone(),
+            perpetuals: perpetuals.clone(),
+            pool: pool.clone(),
+            custody: custody.clone(),
+            custody_token_account: custody_token_account.clone(),
+            custody_token_mint: custody_token_mint.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct AddCustodyInstructionAccountIndices {
+    pub admin: usize,
+    pub transfer_authority: usize,
+    pub perpetuals: usize,
+    pub pool: usize,
+    pub custody: usize,
+    pub custody_token_account: usize,
+    pub custody_token_mint: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for AddCustody {
+    type ArrangedAccountIndices = AddCustodyInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 10 {
+            return None;
+        }
+
+        Some(AddCustodyInstructionAccountIndices {
+            admin: 0,
+            transfer_authority: 1,
+            perpetuals: 2,
+            pool: 3,
+            custody: 4,
+            custody_token_account: 5,
+            custody_token_mint: 6,
+            system_program: 7,
+            token_program: 8,
+            rent: 9,
+            remaining_accounts: 10..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for AddCustody {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "admin",
+            "transfer_authority",
+            "perpetuals",
+            "pool",
+            "custody",
+            "custody_token_account",
+            "custody_token_mint",
+            "system_program",
+            "token_program",
+            "rent",
+        ]
+    }
+}
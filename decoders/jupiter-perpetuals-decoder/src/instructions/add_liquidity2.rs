@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -25,6 +26,7 @@ pub struct AddLiquidity2InstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for AddLiquidity2 {
@@ -33,7 +35,7 @@ impl carbon_core::deserialize::ArrangeAccounts for AddLiquidity2 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [owner, funding_account, lp_token_account, transfer_authority, perpetuals, pool, custody, custody_doves_price_account, custody_pythnet_price_account, custody_token_account, lp_token_mint, token_program, event_authority, program, _remaining @ ..] =
+        let [owner, funding_account, lp_token_account, transfer_authority, perpetuals, pool, custody, custody_doves_price_account, custody_pythnet_price_account, custody_token_account, lp_token_mint, token_program, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -54,6 +56,126 @@ impl carbon_core::deserialize::ArrangeAccounts for AddLiquidity2 {
             token_program: token_program.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct AddLiquidity2InstructionAccountMetas {
+    pub owner: solana_instruction::AccountMeta,
+    pub funding_account: solana_instruction::AccountMeta,
+    pub lp_token_account: solana_instruction::AccountMeta,
+    pub transfer_authority: solana_instruction::AccountMeta,
+    pub perpetuals: solana_instruction::AccountMeta,
+    pub pool: solana_instruction::AccountMeta,
+    pub custody: solana_instruction::AccountMeta,
+    pub custody_doves_price_account: solana_instruction::AccountMeta,
+    pub custody_pythnet_price_account: solana_instruction::AccountMeta,
+    pub custody_token_account: solana_instruction::AccountMeta,
+    pub lp_token_mint: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for AddLiquidity2 {
+    type ArrangedAccountMetas = AddLiquidity2InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [owner, funding_account, lp_token_account, transfer_authority, perpetuals, pool, custody, custody_doves_price_account, custody_pythnet_price_account, custody_token_account, lp_token_mint, token_program, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(AddLiquidity2InstructionAccountMetas {
+            owner: owner.clone(),
+            funding_account: funding_account.clone(),
+            lp_token_account: lp_token_account.clone(),
+            transfer_authority: transfer_authority.clone(),
+            perpetuals: perpetuals.clone(),
+            pool: pool.clone(),
+            custody: custody.clone(),
+            custody_doves_price_account: custody_doves_price_account.clone(),
+            custody_pythnet_price_account: custody_pythnet_price_account.clone(),
+            custody_token_account: custody_token_account.clone(),
+            lp_token_mint: lp_token_mint.clone(),
+            token_program: token_program.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct AddLiquidity2InstructionAccountIndices {
+    pub owner: usize,
+    pub funding_account: usize,
+    pub lp_token_account: usize,
+    pub transfer_authority: usize,
+    pub perpetuals: usize,
+    pub pool: usize,
+    pub custody: usize,
+    pub custody_doves_price_account: usize,
+    pub custody_pythnet_price_account: usize,
+    pub custody_token_account: usize,
+    pub lp_token_mint: usize,
+    pub token_program: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for AddLiquidity2 {
+    type ArrangedAccountIndices = AddLiquidity2InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 14 {
+            return None;
+        }
+
+        Some(AddLiquidity2InstructionAccountIndices {
+            owner: 0,
+            funding_account: 1,
+            lp_token_account: 2,
+            transfer_authority: 3,
+            perpetuals: 4,
+            pool: 5,
+            custody: 6,
+            custody_doves_price_account: 7,
+            custody_pythnet_price_account: 8,
+            custody_token_account: 9,
+            lp_token_mint: 10,
+            token_program: 11,
+            event_authority: 12,
+            program: 13,
+            remaining_accounts: 14..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for AddLiquidity2 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "owner",
+            "funding_account",
+            "lp_token_account",
+            "transfer_authority",
+            "perpetuals",
+            "pool",
+            "custody",
+            "custody_doves_price_account",
+            "custody_pythnet_price_account",
+            "custody_token_account",
+            "lp_token_mint",
+            "token_program",
+            "event_authority",
+            "program",
+        ]
+    }
+}
@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -19,6 +20,7 @@ pub struct AddPoolInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for AddPool {
@@ -27,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for AddPool {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin, transfer_authority, perpetuals, pool, lp_token_mint, system_program, token_program, rent, _remaining @ ..] =
+        let [admin, transfer_authority, perpetuals, pool, lp_token_mint, system_program, token_program, rent, remaining @ ..] =
             accounts
         else {
             return None;
@@ -42,6 +44,96 @@ impl carbon_core::deserialize::ArrangeAccounts for AddPool {
             system_program: system_program.pubkey,
             token_program: token_program.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct AddPoolInstructionAccountMetas {
+    pub admin: solana_instruction::AccountMeta,
+    pub transfer_authority: solana_instruction::AccountMeta,
+    pub perpetuals: solana_instruction::AccountMeta,
+    pub pool: solana_instruction::AccountMeta,
+    pub lp_token_mint: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for AddPool {
+    type ArrangedAccountMetas = AddPoolInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin, transfer_authority, perpetuals, pool, lp_token_mint, system_program, token_program, rent, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(AddPoolInstructionAccountMetas {
+            admin: admin.clone(),
+            transfer_authority: transfer_authority.clone(),
+            perpetuals: perpetuals.clone(),
+            pool: pool.clone(),
+            lp_token_mint: lp_token_mint.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct AddPoolInstructionAccountIndices {
+    pub admin: usize,
+    pub transfer_authority: usize,
+    pub perpetuals: usize,
+    pub pool: usize,
+    pub lp_token_mint: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for AddPool {
+    type ArrangedAccountIndices = AddPoolInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(AddPoolInstructionAccountIndices {
+            admin: 0,
+            transfer_authority: 1,
+            perpetuals: 2,
+            pool: 3,
+            lp_token_mint: 4,
+            system_program: 5,
+            token_program: 6,
+            rent: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for AddPool {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "admin",
+            "transfer_authority",
+            "perpetuals",
+            "pool",
+            "lp_token_mint",
+            "system_program",
+            "token_program",
+            "rent",
+        ]
+    }
+}
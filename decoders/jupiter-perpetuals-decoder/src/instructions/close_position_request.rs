@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -21,6 +22,7 @@ pub struct ClosePositionRequestInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ClosePositionRequest {
@@ -29,7 +31,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ClosePositionRequest {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [keeper, owner, owner_ata, pool, position_request, position_request_ata, position, token_program, event_authority, program, _remaining @ ..] =
+        let [keeper, owner, owner_ata, pool, position_request, position_request_ata, position, token_program, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -46,6 +48,106 @@ impl carbon_core::deserialize::ArrangeAccounts for ClosePositionRequest {
             token_program: token_program.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ClosePositionRequestInstructionAccountMetas {
+    pub keeper: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub owner_ata: solana_instruction::AccountMeta,
+    pub pool: solana_instruction::AccountMeta,
+    pub position_request: solana_instruction::AccountMeta,
+    pub position_request_ata: solana_instruction::AccountMeta,
+    pub position: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ClosePositionRequest {
+    type ArrangedAccountMetas = ClosePositionRequestInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [keeper, owner, owner_ata, pool, position_request, position_request_ata, position, token_program, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(ClosePositionRequestInstructionAccountMetas {
+            keeper: keeper.clone(),
+            owner: owner.clone(),
+            owner_ata: owner_ata.clone(),
+            pool: pool.clone(),
+            position_request: position_request.clone(),
+            position_request_ata: position_request_ata.clone(),
+            position: position.clone(),
+            token_program: token_program.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ClosePositionRequestInstructionAccountIndices {
+    pub keeper: usize,
+    pub owner: usize,
+    pub owner_ata: usize,
+    pub pool: usize,
+    pub position_request: usize,
+    pub position_request_ata: usize,
+    pub position: usize,
+    pub token_program: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ClosePositionRequest {
+    type ArrangedAccountIndices = ClosePositionRequestInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 10 {
+            return None;
+        }
+
+        Some(ClosePositionRequestInstructionAccountIndices {
+            keeper: 0,
+            owner: 1,
+            owner_ata: 2,
+            pool: 3,
+            position_request: 4,
+            position_request_ata: 5,
+            position: 6,
+            token_program: 7,
+            event_authority: 8,
+            program: 9,
+            remaining_accounts: 10..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ClosePositionRequest {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "keeper",
+            "owner",
+            "owner_ata",
+            "pool",
+            "position_request",
+            "position_request_ata",
+            "position",
+            "token_program",
+            "event_authority",
+            "program",
+        ]
+    }
+}
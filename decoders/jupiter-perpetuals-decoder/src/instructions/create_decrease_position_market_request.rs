@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -27,6 +28,7 @@ pub struct CreateDecreasePositionMarketRequestInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CreateDecreasePositionMarketRequest {
@@ -35,7 +37,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateDecreasePositionMarketR
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [owner, receiving_account, perpetuals, pool, position, position_request, position_request_ata, custody, collateral_custody, desired_mint, referral, token_program, associated_token_program, system_program, event_authority, program, _remaining @ ..] =
+        let [owner, receiving_account, perpetuals, pool, position, position_request, position_request_ata, custody, collateral_custody, desired_mint, referral, token_program, associated_token_program, system_program, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -58,6 +60,138 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateDecreasePositionMarketR
             system_program: system_program.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CreateDecreasePositionMarketRequestInstructionAccountMetas {
+    pub owner: solana_instruction::AccountMeta,
+    pub receiving_account: solana_instruction::AccountMeta,
+    pub perpetuals: solana_instruction::AccountMeta,
+    pub pool: solana_instruction::AccountMeta,
+    pub position: solana_instruction::AccountMeta,
+    pub position_request: solana_instruction::AccountMeta,
+    pub position_request_ata: solana_instruction::AccountMeta,
+    pub custody: solana_instruction::AccountMeta,
+    pub collateral_custody: solana_instruction::AccountMeta,
+    pub desired_mint: solana_instruction::AccountMeta,
+    pub referral: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CreateDecreasePositionMarketRequest {
+    type ArrangedAccountMetas = CreateDecreasePositionMarketRequestInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [owner, receiving_account, perpetuals, pool, position, position_request, position_request_ata, custody, collateral_custody, desired_mint, referral, token_program, associated_token_program, system_program, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CreateDecreasePositionMarketRequestInstructionAccountMetas {
+            owner: owner.clone(),
+            receiving_account: receiving_account.clone(),
+            perpetuals: perpetuals.clone(),
+            pool: pool.clone(),
+            position: position.clone(),
+            position_request: position_request.clone(),
+            position_request_ata: position_request_ata.clone(),
+            custody: custody.clone(),
+            collateral_custody: collateral_custody.clone(),
+            desired_mint: desired_mint.clone(),
+            referral: referral.clone(),
+            token_program: token_program.clone(),
+            associated_token_program: associated_token_program.clone(),
+            system_program: system_program.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CreateDecreasePositionMarketRequestInstructionAccountIndices {
+    pub owner: usize,
+    pub receiving_account: usize,
+    pub perpetuals: usize,
+    pub pool: usize,
+    pub position: usize,
+    pub position_request: usize,
+    pub position_request_ata: usize,
+    pub custody: usize,
+    pub collateral_custody: usize,
+    pub desired_mint: usize,
+    pub referral: usize,
+    pub token_program: usize,
+    pub associated_token_program: usize,
+    pub system_program: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CreateDecreasePositionMarketRequest {
+    type ArrangedAccountIndices = CreateDecreasePositionMarketRequestInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 16 {
+            return None;
+        }
+
+        Some(
+            CreateDecreasePositionMarketRequestInstructionAccountIndices {
+                owner: 0,
+                receiving_account: 1,
+                perpetuals: 2,
+                pool: 3,
+                position: 4,
+                position_request: 5,
+                position_request_ata: 6,
+                custody: 7,
+                collateral_custody: 8,
+                desired_mint: 9,
+                referral: 10,
+                token_program: 11,
+                associated_token_program: 12,
+                system_program: 13,
+                event_authority: 14,
+                program: 15,
+                remaining_accounts: 16..accounts.len(),
+            },
+        )
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CreateDecreasePositionMarketRequest {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "owner",
+            "receiving_account",
+            "perpetuals",
+            "pool",
+            "position",
+            "position_request",
+            "position_request_ata",
+            "custody",
+            "collateral_custody",
+            "desired_mint",
+            "referral",
+            "token_program",
+            "associated_token_program",
+            "system_program",
+            "event_authority",
+            "program",
+        ]
+    }
+}
@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -29,6 +30,7 @@ pub struct CreateDecreasePositionRequest2InstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CreateDecreasePositionRequest2 {
@@ -37,7 +39,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateDecreasePositionRequest
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [owner, receiving_account, perpetuals, pool, position, position_request, position_request_ata, custody, custody_doves_price_account, custody_pythnet_price_account, collateral_custody, desired_mint, referral, token_program, associated_token_program, system_program, event_authority, program, _remaining @ ..] =
+        let [owner, receiving_account, perpetuals, pool, position, position_request, position_request_ata, custody, custody_doves_price_account, custody_pythnet_price_account, collateral_custody, desired_mint, referral, token_program, associated_token_program, system_program, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -62,6 +64,146 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateDecreasePositionRequest
             system_program: system_program.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CreateDecreasePositionRequest2InstructionAccountMetas {
+    pub owner: solana_instruction::AccountMeta,
+    pub receiving_account: solana_instruction::AccountMeta,
+    pub perpetuals: solana_instruction::AccountMeta,
+    pub pool: solana_instruction::AccountMeta,
+    pub position: solana_instruction::AccountMeta,
+    pub position_request: solana_instruction::AccountMeta,
+    pub position_request_ata: solana_instruction::AccountMeta,
+    pub custody: solana_instruction::AccountMeta,
+    pub custody_doves_price_account: solana_instruction::AccountMeta,
+    pub custody_pythnet_price_account: solana_instruction::AccountMeta,
+    pub collateral_custody: solana_instruction::AccountMeta,
+    pub desired_mint: solana_instruction::AccountMeta,
+    pub referral: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CreateDecreasePositionRequest2 {
+    type ArrangedAccountMetas = CreateDecreasePositionRequest2InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [owner, receiving_account, perpetuals, pool, position, position_request, position_request_ata, custody, custody_doves_price_account, custody_pythnet_price_account, collateral_custody, desired_mint, referral, token_program, associated_token_program, system_program, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CreateDecreasePositionRequest2InstructionAccountMetas {
+            owner: owner.clone(),
+            receiving_account: receiving_account.clone(),
+            perpetuals: perpetuals.clone(),
+            pool: pool.clone(),
+            position: position.clone(),
+            position_request: position_request.clone(),
+            position_request_ata: position_request_ata.clone(),
+            custody: custody.clone(),
+            custody_doves_price_account: custody_doves_price_account.clone(),
+            custody_pythnet_price_account: custody_pythnet_price_account.clone(),
+            collateral_custody: collateral_custody.clone(),
+            desired_mint: desired_mint.clone(),
+            referral: referral.clone(),
+            token_program: token_program.clone(),
+            associated_token_program: associated_token_program.clone(),
+            system_program: system_program.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CreateDecreasePositionRequest2InstructionAccountIndices {
+    pub owner: usize,
+    pub receiving_account: usize,
+    pub perpetuals: usize,
+    pub pool: usize,
+    pub position: usize,
+    pub position_request: usize,
+    pub position_request_ata: usize,
+    pub custody: usize,
+    pub custody_doves_price_account: usize,
+    pub custody_pythnet_price_account: usize,
+    pub collateral_custody: usize,
+    pub desired_mint: usize,
+    pub referral: usize,
+    pub token_program: usize,
+    pub associated_token_program: usize,
+    pub system_program: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CreateDecreasePositionRequest2 {
+    type ArrangedAccountIndices = CreateDecreasePositionRequest2InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 18 {
+            return None;
+        }
+
+        Some(CreateDecreasePositionRequest2InstructionAccountIndices {
+            owner: 0,
+            receiving_account: 1,
+            perpetuals: 2,
+            pool: 3,
+            position: 4,
+            position_request: 5,
+            position_request_ata: 6,
+            custody: 7,
+            custody_doves_price_account: 8,
+            custody_pythnet_price_account: 9,
+            collateral_custody: 10,
+            desired_mint: 11,
+            referral: 12,
+            token_program: 13,
+            associated_token_program: 14,
+            system_program: 15,
+            event_authority: 16,
+            program: 17,
+            remaining_accounts: 18..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CreateDecreasePositionRequest2 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "owner",
+            "receiving_account",
+            "perpetuals",
+            "pool",
+            "position",
+            "position_request",
+            "position_request_ata",
+            "custody",
+            "custody_doves_price_account",
+            "custody_pythnet_price_account",
+            "collateral_custody",
+            "desired_mint",
+            "referral",
+            "token_program",
+            "associated_token_program",
+            "system_program",
+            "event_authority",
+            "program",
+        ]
+    }
+}
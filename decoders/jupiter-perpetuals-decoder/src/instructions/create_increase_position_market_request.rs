@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -27,6 +28,7 @@ pub struct CreateIncreasePositionMarketRequestInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CreateIncreasePositionMarketRequest {
@@ -35,7 +37,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateIncreasePositionMarketR
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [owner, funding_account, perpetuals, pool, position, position_request, position_request_ata, custody, collateral_custody, input_mint, referral, token_program, associated_token_program, system_program, event_authority, program, _remaining @ ..] =
+        let [owner, funding_account, perpetuals, pool, position, position_request, position_request_ata, custody, collateral_custody, input_mint, referral, token_program, associated_token_program, system_program, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -58,6 +60,138 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateIncreasePositionMarketR
             system_program: system_program.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CreateIncreasePositionMarketRequestInstructionAccountMetas {
+    pub owner: solana_instruction::AccountMeta,
+    pub funding_account: solana_instruction::AccountMeta,
+    pub perpetuals: solana_instruction::AccountMeta,
+    pub pool: solana_instruction::AccountMeta,
+    pub position: solana_instruction::AccountMeta,
+    pub position_request: solana_instruction::AccountMeta,
+    pub position_request_ata: solana_instruction::AccountMeta,
+    pub custody: solana_instruction::AccountMeta,
+    pub collateral_custody: solana_instruction::AccountMeta,
+    pub input_mint: solana_instruction::AccountMeta,
+    pub referral: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CreateIncreasePositionMarketRequest {
+    type ArrangedAccountMetas = CreateIncreasePositionMarketRequestInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [owner, funding_account, perpetuals, pool, position, position_request, position_request_ata, custody, collateral_custody, input_mint, referral, token_program, associated_token_program, system_program, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CreateIncreasePositionMarketRequestInstructionAccountMetas {
+            owner: owner.clone(),
+            funding_account: funding_account.clone(),
+            perpetuals: perpetuals.clone(),
+            pool: pool.clone(),
+            position: position.clone(),
+            position_request: position_request.clone(),
+            position_request_ata: position_request_ata.clone(),
+            custody: custody.clone(),
+            collateral_custody: collateral_custody.clone(),
+            input_mint: input_mint.clone(),
+            referral: referral.clone(),
+            token_program: token_program.clone(),
+            associated_token_program: associated_token_program.clone(),
+            system_program: system_program.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CreateIncreasePositionMarketRequestInstructionAccountIndices {
+    pub owner: usize,
+    pub funding_account: usize,
+    pub perpetuals: usize,
+    pub pool: usize,
+    pub position: usize,
+    pub position_request: usize,
+    pub position_request_ata: usize,
+    pub custody: usize,
+    pub collateral_custody: usize,
+    pub input_mint: usize,
+    pub referral: usize,
+    pub token_program: usize,
+    pub associated_token_program: usize,
+    pub system_program: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CreateIncreasePositionMarketRequest {
+    type ArrangedAccountIndices = CreateIncreasePositionMarketRequestInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 16 {
+            return None;
+        }
+
+        Some(
+            CreateIncreasePositionMarketRequestInstructionAccountIndices {
+                owner: 0,
+                funding_account: 1,
+                perpetuals: 2,
+                pool: 3,
+                position: 4,
+                position_request: 5,
+                position_request_ata: 6,
+                custody: 7,
+                collateral_custody: 8,
+                input_mint: 9,
+                referral: 10,
+                token_program: 11,
+                associated_token_program: 12,
+                system_program: 13,
+                event_authority: 14,
+                program: 15,
+                remaining_accounts: 16..accounts.len(),
+            },
+        )
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CreateIncreasePositionMarketRequest {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "owner",
+            "funding_account",
+            "perpetuals",
+            "pool",
+            "position",
+            "position_request",
+            "position_request_ata",
+            "custody",
+            "collateral_custody",
+            "input_mint",
+            "referral",
+            "token_program",
+            "associated_token_program",
+            "system_program",
+            "event_authority",
+            "program",
+        ]
+    }
+}
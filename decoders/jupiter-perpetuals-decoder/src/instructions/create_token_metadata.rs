@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -20,6 +21,7 @@ pub struct CreateTokenMetadataInstructionAccounts {
     pub token_metadata_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CreateTokenMetadata {
@@ -28,7 +30,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateTokenMetadata {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin, perpetuals, pool, transfer_authority, metadata, lp_token_mint, token_metadata_program, system_program, rent, _remaining @ ..] =
+        let [admin, perpetuals, pool, transfer_authority, metadata, lp_token_mint, token_metadata_program, system_program, rent, remaining @ ..] =
             accounts
         else {
             return None;
@@ -44,6 +46,101 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateTokenMetadata {
             token_metadata_program: token_metadata_program.pubkey,
             system_program: system_program.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CreateTokenMetadataInstructionAccountMetas {
+    pub admin: solana_instruction::AccountMeta,
+    pub perpetuals: solana_instruction::AccountMeta,
+    pub pool: solana_instruction::AccountMeta,
+    pub transfer_authority: solana_instruction::AccountMeta,
+    pub metadata: solana_instruction::AccountMeta,
+    pub lp_token_mint: solana_instruction::AccountMeta,
+    pub token_metadata_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CreateTokenMetadata {
+    type ArrangedAccountMetas = CreateTokenMetadataInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin, perpetuals, pool, transfer_authority, metadata, lp_token_mint, token_metadata_program, system_program, rent, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CreateTokenMetadataInstructionAccountMetas {
+            admin: admin.clone(),
+            perpetuals: perpetuals.clone(),
+            pool: pool.clone(),
+            transfer_authority: transfer_authority.clone(),
+            metadata: metadata.clone(),
+            lp_token_mint: lp_token_mint.clone(),
+            token_metadata_program: token_metadata_program.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CreateTokenMetadataInstructionAccountIndices {
+    pub admin: usize,
+    pub perpetuals: usize,
+    pub pool: usize,
+    pub transfer_authority: usize,
+    pub metadata: usize,
+    pub lp_token_mint: usize,
+    pub token_metadata_program: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CreateTokenMetadata {
+    type ArrangedAccountIndices = CreateTokenMetadataInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 9 {
+            return None;
+        }
+
+        Some(CreateTokenMetadataInstructionAccountIndices {
+            admin: 0,
+            perpetuals: 1,
+            pool: 2,
+            transfer_authority: 3,
+            metadata: 4,
+            lp_token_mint: 5,
+            token_metadata_program: 6,
+            system_program: 7,
+            rent: 8,
+            remaining_accounts: 9..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CreateTokenMetadata {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "admin",
+            "perpetuals",
+            "pool",
+            "transfer_authority",
+            "metadata",
+            "lp_token_mint",
+            "token_metadata_program",
+            "system_program",
+            "rent",
+        ]
+    }
+}
@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -29,6 +30,7 @@ pub struct DecreasePosition4InstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for DecreasePosition4 {
@@ -37,7 +39,7 @@ impl carbon_core::deserialize::ArrangeAccounts for DecreasePosition4 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [keeper, owner, transfer_authority, perpetuals, pool, position_request, position_request_ata, position, custody, custody_doves_price_account, custody_pythnet_price_account, collateral_custody, collateral_custody_doves_price_account, collateral_custody_pythnet_price_account, collateral_custody_token_account, token_program, event_authority, program, _remaining @ ..] =
+        let [keeper, owner, transfer_authority, perpetuals, pool, position_request, position_request_ata, position, custody, custody_doves_price_account, custody_pythnet_price_account, collateral_custody, collateral_custody_doves_price_account, collateral_custody_pythnet_price_account, collateral_custody_token_account, token_program, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -63,6 +65,147 @@ impl carbon_core::deserialize::ArrangeAccounts for DecreasePosition4 {
             token_program: token_program.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DecreasePosition4InstructionAccountMetas {
+    pub keeper: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub transfer_authority: solana_instruction::AccountMeta,
+    pub perpetuals: solana_instruction::AccountMeta,
+    pub pool: solana_instruction::AccountMeta,
+    pub position_request: solana_instruction::AccountMeta,
+    pub position_request_ata: solana_instruction::AccountMeta,
+    pub position: solana_instruction::AccountMeta,
+    pub custody: solana_instruction::AccountMeta,
+    pub custody_doves_price_account: solana_instruction::AccountMeta,
+    pub custody_pythnet_price_account: solana_instruction::AccountMeta,
+    pub collateral_custody: solana_instruction::AccountMeta,
+    pub collateral_custody_doves_price_account: solana_instruction::AccountMeta,
+    pub collateral_custody_pythnet_price_account: solana_instruction::AccountMeta,
+    pub collateral_custody_token_account: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for DecreasePosition4 {
+    type ArrangedAccountMetas = DecreasePosition4InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [keeper, owner, transfer_authority, perpetuals, pool, position_request, position_request_ata, position, custody, custody_doves_price_account, custody_pythnet_price_account, collateral_custody, collateral_custody_doves_price_account, collateral_custody_pythnet_price_account, collateral_custody_token_account, token_program, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(DecreasePosition4InstructionAccountMetas {
+            keeper: keeper.clone(),
+            owner: owner.clone(),
+            transfer_authority: transfer_authority.clone(),
+            perpetuals: perpetuals.clone(),
+            pool: pool.clone(),
+            position_request: position_request.clone(),
+            position_request_ata: position_request_ata.clone(),
+            position: position.clone(),
+            custody: custody.clone(),
+            custody_doves_price_account: custody_doves_price_account.clone(),
+            custody_pythnet_price_account: custody_pythnet_price_account.clone(),
+            collateral_custody: collateral_custody.clone(),
+            collateral_custody_doves_price_account: collateral_custody_doves_price_account.clone(),
+            collateral_custody_pythnet_price_account: collateral_custody_pythnet_price_account
+                .clone(),
+            collateral_custody_token_account: collateral_custody_token_account.clone(),
+            token_program: token_program.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DecreasePosition4InstructionAccountIndices {
+    pub keeper: usize,
+    pub owner: usize,
+    pub transfer_authority: usize,
+    pub perpetuals: usize,
+    pub pool: usize,
+    pub position_request: usize,
+    pub position_request_ata: usize,
+    pub position: usize,
+    pub custody: usize,
+    pub custody_doves_price_account: usize,
+    pub custody_pythnet_price_account: usize,
+    pub collateral_custody: usize,
+    pub collateral_custody_doves_price_account: usize,
+    pub collateral_custody_pythnet_price_account: usize,
+    pub collateral_custody_token_account: usize,
+    pub token_program: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for DecreasePosition4 {
+    type ArrangedAccountIndices = DecreasePosition4InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 18 {
+            return None;
+        }
+
+        Some(DecreasePosition4InstructionAccountIndices {
+            keeper: 0,
+            owner: 1,
+            transfer_authority: 2,
+            perpetuals: 3,
+            pool: 4,
+            position_request: 5,
+            position_request_ata: 6,
+            position: 7,
+            custody: 8,
+            custody_doves_price_account: 9,
+            custody_pythnet_price_account: 10,
+            collateral_custody: 11,
+            collateral_custody_doves_price_account: 12,
+            collateral_custody_pythnet_price_account: 13,
+            collateral_custody_token_account: 14,
+            token_program: 15,
+            event_authority: 16,
+            program: 17,
+            remaining_accounts: 18..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for DecreasePosition4 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "keeper",
+            "owner",
+            "transfer_authority",
+            "perpetuals",
+            "pool",
+            "position_request",
+            "position_request_ata",
+            "position",
+            "custody",
+            "custody_doves_price_account",
+            "custody_pythnet_price_account",
+            "collateral_custody",
+            "collateral_custody_doves_price_account",
+            "collateral_custody_pythnet_price_account",
+            "collateral_custody_token_account",
+            "token_program",
+            "event_authority",
+            "program",
+        ]
+    }
+}
@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -33,6 +34,7 @@ pub struct DecreasePositionWithInternalSwapInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for DecreasePositionWithInternalSwap {
@@ -41,7 +43,7 @@ impl carbon_core::deserialize::ArrangeAccounts for DecreasePositionWithInternalS
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [keeper, owner, transfer_authority, perpetuals, pool, position_request, position_request_ata, position, custody, custody_doves_price_account, custody_pythnet_price_account, collateral_custody, collateral_custody_doves_price_account, collateral_custody_pythnet_price_account, collateral_custody_token_account, dispensing_custody, dispensing_custody_doves_price_account, dispensing_custody_pythnet_price_account, dispensing_custody_token_account, token_program, event_authority, program, _remaining @ ..] =
+        let [keeper, owner, transfer_authority, perpetuals, pool, position_request, position_request_ata, position, custody, custody_doves_price_account, custody_pythnet_price_account, collateral_custody, collateral_custody_doves_price_account, collateral_custody_pythnet_price_account, collateral_custody_token_account, dispensing_custody, dispensing_custody_doves_price_account, dispensing_custody_pythnet_price_account, dispensing_custody_token_account, token_program, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -72,6 +74,168 @@ impl carbon_core::deserialize::ArrangeAccounts for DecreasePositionWithInternalS
             token_program: token_program.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DecreasePositionWithInternalSwapInstructionAccountMetas {
+    pub keeper: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub transfer_authority: solana_instruction::AccountMeta,
+    pub perpetuals: solana_instruction::AccountMeta,
+    pub pool: solana_instruction::AccountMeta,
+    pub position_request: solana_instruction::AccountMeta,
+    pub position_request_ata: solana_instruction::AccountMeta,
+    pub position: solana_instruction::AccountMeta,
+    pub custody: solana_instruction::AccountMeta,
+    pub custody_doves_price_account: solana_instruction::AccountMeta,
+    pub custody_pythnet_price_account: solana_instruction::AccountMeta,
+    pub collateral_custody: solana_instruction::AccountMeta,
+    pub collateral_custody_doves_price_account: solana_instruction::AccountMeta,
+    pub collateral_custody_pythnet_price_account: solana_instruction::AccountMeta,
+    pub collateral_custody_token_account: solana_instruction::AccountMeta,
+    pub dispensing_custody: solana_instruction::AccountMeta,
+    pub dispensing_custody_doves_price_account: solana_instruction::AccountMeta,
+    pub dispensing_custody_pythnet_price_account: solana_instruction::AccountMeta,
+    pub dispensing_custody_token_account: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for DecreasePositionWithInternalSwap {
+    type ArrangedAccountMetas = DecreasePositionWithInternalSwapInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [keeper, owner, transfer_authority, perpetuals, pool, position_request, position_request_ata, position, custody, custody_doves_price_account, custody_pythnet_price_account, collateral_custody, collateral_custody_doves_price_account, collateral_custody_pythnet_price_account, collateral_custody_token_account, dispensing_custody, dispensing_custody_doves_price_account, dispensing_custody_pythnet_price_account, dispensing_custody_token_account, token_program, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(DecreasePositionWithInternalSwapInstructionAccountMetas {
+            keeper: keeper.clone(),
+            owner: owner.clone(),
+            transfer_authority: transfer_authority.clone(),
+            perpetuals: perpetuals.clone(),
+            pool: pool.clone(),
+            position_request: position_request.clone(),
+            position_request_ata: position_request_ata.clone(),
+            position: position.clone(),
+            custody: custody.clone(),
+            custody_doves_price_account: custody_doves_price_account.clone(),
+            custody_pythnet_price_account: custody_pythnet_price_account.clone(),
+            collateral_custody: collateral_custody.clone(),
+            collateral_custody_doves_price_account: collateral_custody_doves_price_account.clone(),
+            collateral_custody_pythnet_price_account: collateral_custody_pythnet_price_account
+                .clone(),
+            collateral_custody_token_account: collateral_custody_token_account.clone(),
+            dispensing_custody: dispensing_custody.clone(),
+            dispensing_custody_doves_price_account: dispensing_custody_doves_price_account.clone(),
+            dispensing_custody_pythnet_price_account: dispensing_custody_pythnet_price_account
+                .clone(),
+            dispensing_custody_token_account: dispensing_custody_token_account.clone(),
+            token_program: token_program.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DecreasePositionWithInternalSwapInstructionAccountIndices {
+    pub keeper: usize,
+    pub owner: usize,
+    pub transfer_authority: usize,
+    pub perpetuals: usize,
+    pub pool: usize,
+    pub position_request: usize,
+    pub position_request_ata: usize,
+    pub position: usize,
+    pub custody: usize,
+    pub custody_doves_price_account: usize,
+    pub custody_pythnet_price_account: usize,
+    pub collateral_custody: usize,
+    pub collateral_custody_doves_price_account: usize,
+    pub collateral_custody_pythnet_price_account: usize,
+    pub collateral_custody_token_account: usize,
+    pub dispensing_custody: usize,
+    pub dispensing_custody_doves_price_account: usize,
+    pub dispensing_custody_pythnet_price_account: usize,
+    pub dispensing_custody_token_account: usize,
+    pub token_program: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for DecreasePositionWithInternalSwap {
+    type ArrangedAccountIndices = DecreasePositionWithInternalSwapInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 22 {
+            return None;
+        }
+
+        Some(DecreasePositionWithInternalSwapInstructionAccountIndices {
+            keeper: 0,
+            owner: 1,
+            transfer_authority: 2,
+            perpetuals: 3,
+            pool: 4,
+            position_request: 5,
+            position_request_ata: 6,
+            position: 7,
+            custody: 8,
+            custody_doves_price_account: 9,
+            custody_pythnet_price_account: 10,
+            collateral_custody: 11,
+            collateral_custody_doves_price_account: 12,
+            collateral_custody_pythnet_price_account: 13,
+            collateral_custody_token_account: 14,
+            dispensing_custody: 15,
+            dispensing_custody_doves_price_account: 16,
+            dispensing_custody_pythnet_price_account: 17,
+            dispensing_custody_token_account: 18,
+            token_program: 19,
+            event_authority: 20,
+            program: 21,
+            remaining_accounts: 22..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for DecreasePositionWithInternalSwap {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "keeper",
+            "owner",
+            "transfer_authority",
+            "perpetuals",
+            "pool",
+            "position_request",
+            "position_request_ata",
+            "position",
+            "custody",
+            "custody_doves_price_account",
+            "custody_pythnet_price_account",
+            "collateral_custody",
+            "collateral_custody_doves_price_account",
+            "collateral_custody_pythnet_price_account",
+            "collateral_custody_token_account",
+            "dispensing_custody",
+            "dispensing_custody_doves_price_account",
+            "dispensing_custody_pythnet_price_account",
+            "dispensing_custody_token_account",
+            "token_program",
+            "event_authority",
+            "program",
+        ]
+    }
+}
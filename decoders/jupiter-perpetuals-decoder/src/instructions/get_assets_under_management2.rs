@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +14,7 @@ pub struct GetAssetsUnderManagement2 {
 pub struct GetAssetsUnderManagement2InstructionAccounts {
     pub perpetuals: solana_pubkey::Pubkey,
     pub pool: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for GetAssetsUnderManagement2 {
@@ -21,13 +23,68 @@ impl carbon_core::deserialize::ArrangeAccounts for GetAssetsUnderManagement2 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [perpetuals, pool, _remaining @ ..] = accounts else {
+        let [perpetuals, pool, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(GetAssetsUnderManagement2InstructionAccounts {
             perpetuals: perpetuals.pubkey,
             pool: pool.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct GetAssetsUnderManagement2InstructionAccountMetas {
+    pub perpetuals: solana_instruction::AccountMeta,
+    pub pool: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for GetAssetsUnderManagement2 {
+    type ArrangedAccountMetas = GetAssetsUnderManagement2InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [perpetuals, pool, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(GetAssetsUnderManagement2InstructionAccountMetas {
+            perpetuals: perpetuals.clone(),
+            pool: pool.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct GetAssetsUnderManagement2InstructionAccountIndices {
+    pub perpetuals: usize,
+    pub pool: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for GetAssetsUnderManagement2 {
+    type ArrangedAccountIndices = GetAssetsUnderManagement2InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(GetAssetsUnderManagement2InstructionAccountIndices {
+            perpetuals: 0,
+            pool: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for GetAssetsUnderManagement2 {
+    fn account_names() -> &'static [&'static str] {
+        &["perpetuals", "pool"]
+    }
+}
@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -17,6 +18,7 @@ pub struct GetRemoveLiquidityAmountAndFee2InstructionAccounts {
     pub custody_doves_price_account: solana_pubkey::Pubkey,
     pub custody_pythnet_price_account: solana_pubkey::Pubkey,
     pub lp_token_mint: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for GetRemoveLiquidityAmountAndFee2 {
@@ -25,7 +27,7 @@ impl carbon_core::deserialize::ArrangeAccounts for GetRemoveLiquidityAmountAndFe
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [perpetuals, pool, custody, custody_doves_price_account, custody_pythnet_price_account, lp_token_mint, _remaining @ ..] =
+        let [perpetuals, pool, custody, custody_doves_price_account, custody_pythnet_price_account, lp_token_mint, remaining @ ..] =
             accounts
         else {
             return None;
@@ -38,6 +40,86 @@ impl carbon_core::deserialize::ArrangeAccounts for GetRemoveLiquidityAmountAndFe
             custody_doves_price_account: custody_doves_price_account.pubkey,
             custody_pythnet_price_account: custody_pythnet_price_account.pubkey,
             lp_token_mint: lp_token_mint.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct GetRemoveLiquidityAmountAndFee2InstructionAccountMetas {
+    pub perpetuals: solana_instruction::AccountMeta,
+    pub pool: solana_instruction::AccountMeta,
+    pub custody: solana_instruction::AccountMeta,
+    pub custody_doves_price_account: solana_instruction::AccountMeta,
+    pub custody_pythnet_price_account: solana_instruction::AccountMeta,
+    pub lp_token_mint: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for GetRemoveLiquidityAmountAndFee2 {
+    type ArrangedAccountMetas = GetRemoveLiquidityAmountAndFee2InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [perpetuals, pool, custody, custody_doves_price_account, custody_pythnet_price_account, lp_token_mint, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(GetRemoveLiquidityAmountAndFee2InstructionAccountMetas {
+            perpetuals: perpetuals.clone(),
+            pool: pool.clone(),
+            custody: custody.clone(),
+            custody_doves_price_account: custody_doves_price_account.clone(),
+            custody_pythnet_price_account: custody_pythnet_price_account.clone(),
+            lp_token_mint: lp_token_mint.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct GetRemoveLiquidityAmountAndFee2InstructionAccountIndices {
+    pub perpetuals: usize,
+    pub pool: usize,
+    pub custody: usize,
+    pub custody_doves_price_account: usize,
+    pub custody_pythnet_price_account: usize,
+    pub lp_token_mint: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for GetRemoveLiquidityAmountAndFee2 {
+    type ArrangedAccountIndices = GetRemoveLiquidityAmountAndFee2InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(GetRemoveLiquidityAmountAndFee2InstructionAccountIndices {
+            perpetuals: 0,
+            pool: 1,
+            custody: 2,
+            custody_doves_price_account: 3,
+            custody_pythnet_price_account: 4,
+            lp_token_mint: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for GetRemoveLiquidityAmountAndFee2 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "perpetuals",
+            "pool",
+            "custody",
+            "custody_doves_price_account",
+            "custody_pythnet_price_account",
+            "lp_token_mint",
+        ]
+    }
+}
@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -22,6 +23,7 @@ pub struct IncreasePositionPreSwapInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for IncreasePositionPreSwap {
@@ -30,7 +32,7 @@ impl carbon_core::deserialize::ArrangeAccounts for IncreasePositionPreSwap {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [keeper, keeper_ata, position_request, position_request_ata, position, collateral_custody, collateral_custody_token_account, instruction, token_program, event_authority, program, _remaining @ ..] =
+        let [keeper, keeper_ata, position_request, position_request_ata, position, collateral_custody, collateral_custody_token_account, instruction, token_program, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -48,6 +50,111 @@ impl carbon_core::deserialize::ArrangeAccounts for IncreasePositionPreSwap {
             token_program: token_program.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct IncreasePositionPreSwapInstructionAccountMetas {
+    pub keeper: solana_instruction::AccountMeta,
+    pub keeper_ata: solana_instruction::AccountMeta,
+    pub position_request: solana_instruction::AccountMeta,
+    pub position_request_ata: solana_instruction::AccountMeta,
+    pub position: solana_instruction::AccountMeta,
+    pub collateral_custody: solana_instruction::AccountMeta,
+    pub collateral_custody_token_account: solana_instruction::AccountMeta,
+    pub instruction: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for IncreasePositionPreSwap {
+    type ArrangedAccountMetas = IncreasePositionPreSwapInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [keeper, keeper_ata, position_request, position_request_ata, position, collateral_custody, collateral_custody_token_account, instruction, token_program, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(IncreasePositionPreSwapInstructionAccountMetas {
+            keeper: keeper.clone(),
+            keeper_ata: keeper_ata.clone(),
+            position_request: position_request.clone(),
+            position_request_ata: position_request_ata.clone(),
+            position: position.clone(),
+            collateral_custody: collateral_custody.clone(),
+            collateral_custody_token_account: collateral_custody_token_account.clone(),
+            instruction: instruction.clone(),
+            token_program: token_program.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct IncreasePositionPreSwapInstructionAccountIndices {
+    pub keeper: usize,
+    pub keeper_ata: usize,
+    pub position_request: usize,
+    pub position_request_ata: usize,
+    pub position: usize,
+    pub collateral_custody: usize,
+    pub collateral_custody_token_account: usize,
+    pub instruction: usize,
+    pub token_program: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for IncreasePositionPreSwap {
+    type ArrangedAccountIndices = IncreasePositionPreSwapInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 11 {
+            return None;
+        }
+
+        Some(IncreasePositionPreSwapInstructionAccountIndices {
+            keeper: 0,
+            keeper_ata: 1,
+            position_request: 2,
+            position_request_ata: 3,
+            position: 4,
+            collateral_custody: 5,
+            collateral_custody_token_account: 6,
+            instruction: 7,
+            token_program: 8,
+            event_authority: 9,
+            program: 10,
+            remaining_accounts: 11..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for IncreasePositionPreSwap {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "keeper",
+            "keeper_ata",
+            "position_request",
+            "position_request_ata",
+            "position",
+            "collateral_custody",
+            "collateral_custody_token_account",
+            "instruction",
+            "token_program",
+            "event_authority",
+            "program",
+        ]
+    }
+}
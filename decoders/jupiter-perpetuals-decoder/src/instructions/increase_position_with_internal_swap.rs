@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -31,6 +32,7 @@ pub struct IncreasePositionWithInternalSwapInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for IncreasePositionWithInternalSwap {
@@ -39,7 +41,7 @@ impl carbon_core::deserialize::ArrangeAccounts for IncreasePositionWithInternalS
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [keeper, perpetuals, pool, position_request, position_request_ata, position, custody, custody_doves_price_account, custody_pythnet_price_account, collateral_custody, collateral_custody_doves_price_account, collateral_custody_pythnet_price_account, collateral_custody_token_account, receiving_custody, receiving_custody_doves_price_account, receiving_custody_pythnet_price_account, receiving_custody_token_account, token_program, event_authority, program, _remaining @ ..] =
+        let [keeper, perpetuals, pool, position_request, position_request_ata, position, custody, custody_doves_price_account, custody_pythnet_price_account, collateral_custody, collateral_custody_doves_price_account, collateral_custody_pythnet_price_account, collateral_custody_token_account, receiving_custody, receiving_custody_doves_price_account, receiving_custody_pythnet_price_account, receiving_custody_token_account, token_program, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -67,6 +69,158 @@ impl carbon_core::deserialize::ArrangeAccounts for IncreasePositionWithInternalS
             token_program: token_program.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct IncreasePositionWithInternalSwapInstructionAccountMetas {
+    pub keeper: solana_instruction::AccountMeta,
+    pub perpetuals: solana_instruction::AccountMeta,
+    pub pool: solana_instruction::AccountMeta,
+    pub position_request: solana_instruction::AccountMeta,
+    pub position_request_ata: solana_instruction::AccountMeta,
+    pub position: solana_instruction::AccountMeta,
+    pub custody: solana_instruction::AccountMeta,
+    pub custody_doves_price_account: solana_instruction::AccountMeta,
+    pub custody_pythnet_price_account: solana_instruction::AccountMeta,
+    pub collateral_custody: solana_instruction::AccountMeta,
+    pub collateral_custody_doves_price_account: solana_instruction::AccountMeta,
+    pub collateral_custody_pythnet_price_account: solana_instruction::AccountMeta,
+    pub collateral_custody_token_account: solana_instruction::AccountMeta,
+    pub receiving_custody: solana_instruction::AccountMeta,
+    pub receiving_custody_doves_price_account: solana_instruction::AccountMeta,
+    pub receiving_custody_pythnet_price_account: solana_instruction::AccountMeta,
+    pub receiving_custody_token_account: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for IncreasePositionWithInternalSwap {
+    type ArrangedAccountMetas = IncreasePositionWithInternalSwapInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [keeper, perpetuals, pool, position_request, position_request_ata, position, custody, custody_doves_price_account, custody_pythnet_price_account, collateral_custody, collateral_custody_doves_price_account, collateral_custody_pythnet_price_account, collateral_custody_token_account, receiving_custody, receiving_custody_doves_price_account, receiving_custody_pythnet_price_account, receiving_custody_token_account, token_program, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(IncreasePositionWithInternalSwapInstructionAccountMetas {
+            keeper: keeper.clone(),
+            perpetuals: perpetuals.clone(),
+            pool: pool.clone(),
+            position_request: position_request.clone(),
+            position_request_ata: position_request_ata.clone(),
+            position: position.clone(),
+            custody: custody.clone(),
+            custody_doves_price_account: custody_doves_price_account.clone(),
+            custody_pythnet_price_account: custody_pythnet_price_account.clone(),
+            collateral_custody: collateral_custody.clone(),
+            collateral_custody_doves_price_account: collateral_custody_doves_price_account.clone(),
+            collateral_custody_pythnet_price_account: collateral_custody_pythnet_price_account
+                .clone(),
+            collateral_custody_token_account: collateral_custody_token_account.clone(),
+            receiving_custody: receiving_custody.clone(),
+            receiving_custody_doves_price_account: receiving_custody_doves_price_account.clone(),
+            receiving_custody_pythnet_price_account: receiving_custody_pythnet_price_account
+                .clone(),
+            receiving_custody_token_account: receiving_custody_token_account.clone(),
+            token_program: token_program.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct IncreasePositionWithInternalSwapInstructionAccountIndices {
+    pub keeper: usize,
+    pub perpetuals: usize,
+    pub pool: usize,
+    pub position_request: usize,
+    pub position_request_ata: usize,
+    pub position: usize,
+    pub custody: usize,
+    pub custody_doves_price_account: usize,
+    pub custody_pythnet_price_account: usize,
+    pub collateral_custody: usize,
+    pub collateral_custody_doves_price_account: usize,
+    pub collateral_custody_pythnet_price_account: usize,
+    pub collateral_custody_token_account: usize,
+    pub receiving_custody: usize,
+    pub receiving_custody_doves_price_account: usize,
+    pub receiving_custody_pythnet_price_account: usize,
+    pub receiving_custody_token_account: usize,
+    pub token_program: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for IncreasePositionWithInternalSwap {
+    type ArrangedAccountIndices = IncreasePositionWithInternalSwapInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 20 {
+            return None;
+        }
+
+        Some(IncreasePositionWithInternalSwapInstructionAccountIndices {
+            keeper: 0,
+            perpetuals: 1,
+            pool: 2,
+            position_request: 3,
+            position_request_ata: 4,
+            position: 5,
+            custody: 6,
+            custody_doves_price_account: 7,
+            custody_pythnet_price_account: 8,
+            collateral_custody: 9,
+            collateral_custody_doves_price_account: 10,
+            collateral_custody_pythnet_price_account: 11,
+            collateral_custody_token_account: 12,
+            receiving_custody: 13,
+            receiving_custody_doves_price_account: 14,
+            receiving_custody_pythnet_price_account: 15,
+            receiving_custody_token_account: 16,
+            token_program: 17,
+            event_authority: 18,
+            program: 19,
+            remaining_accounts: 20..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for IncreasePositionWithInternalSwap {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "keeper",
+            "perpetuals",
+            "pool",
+            "position_request",
+            "position_request_ata",
+            "position",
+            "custody",
+            "custody_doves_price_account",
+            "custody_pythnet_price_account",
+            "collateral_custody",
+            "collateral_custody_doves_price_account",
+            "collateral_custody_pythnet_price_account",
+            "collateral_custody_token_account",
+            "receiving_custody",
+            "receiving_custody_doves_price_account",
+            "receiving_custody_pythnet_price_account",
+            "receiving_custody_token_account",
+            "token_program",
+            "event_authority",
+            "program",
+        ]
+    }
+}
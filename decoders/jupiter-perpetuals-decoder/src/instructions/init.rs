@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -19,6 +20,7 @@ pub struct InitInstructionAccounts {
     pub perpetuals_program_data: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Init {
@@ -27,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Init {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [upgrade_authority, admin, transfer_authority, perpetuals, perpetuals_program, perpetuals_program_data, system_program, token_program, _remaining @ ..] =
+        let [upgrade_authority, admin, transfer_authority, perpetuals, perpetuals_program, perpetuals_program_data, system_program, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -42,6 +44,96 @@ impl carbon_core::deserialize::ArrangeAccounts for Init {
             perpetuals_program_data: perpetuals_program_data.pubkey,
             system_program: system_program.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitInstructionAccountMetas {
+    pub upgrade_authority: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub transfer_authority: solana_instruction::AccountMeta,
+    pub perpetuals: solana_instruction::AccountMeta,
+    pub perpetuals_program: solana_instruction::AccountMeta,
+    pub perpetuals_program_data: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Init {
+    type ArrangedAccountMetas = InitInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [upgrade_authority, admin, transfer_authority, perpetuals, perpetuals_program, perpetuals_program_data, system_program, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitInstructionAccountMetas {
+            upgrade_authority: upgrade_authority.clone(),
+            admin: admin.clone(),
+            transfer_authority: transfer_authority.clone(),
+            perpetuals: perpetuals.clone(),
+            perpetuals_program: perpetuals_program.clone(),
+            perpetuals_program_data: perpetuals_program_data.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitInstructionAccountIndices {
+    pub upgrade_authority: usize,
+    pub admin: usize,
+    pub transfer_authority: usize,
+    pub perpetuals: usize,
+    pub perpetuals_program: usize,
+    pub perpetuals_program_data: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Init {
+    type ArrangedAccountIndices = InitInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(InitInstructionAccountIndices {
+            upgrade_authority: 0,
+            admin: 1,
+            transfer_authority: 2,
+            perpetuals: 3,
+            perpetuals_program: 4,
+            perpetuals_program_data: 5,
+            system_program: 6,
+            token_program: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Init {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "upgrade_authority",
+            "admin",
+            "transfer_authority",
+            "perpetuals",
+            "perpetuals_program",
+            "perpetuals_program_data",
+            "system_program",
+            "token_program",
+        ]
+    }
+}
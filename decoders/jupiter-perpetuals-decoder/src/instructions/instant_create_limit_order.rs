@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -31,6 +32,7 @@ pub struct InstantCreateLimitOrderInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InstantCreateLimitOrder {
@@ -39,7 +41,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InstantCreateLimitOrder {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [keeper, api_keeper, owner, funding_account, perpetuals, pool, position, position_request, position_request_ata, custody, custody_doves_price_account, custody_pythnet_price_account, collateral_custody, input_mint, referral, token_program, associated_token_program, system_program, event_authority, program, _remaining @ ..] =
+        let [keeper, api_keeper, owner, funding_account, perpetuals, pool, position, position_request, position_request_ata, custody, custody_doves_price_account, custody_pythnet_price_account, collateral_custody, input_mint, referral, token_program, associated_token_program, system_program, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -66,6 +68,156 @@ impl carbon_core::deserialize::ArrangeAccounts for InstantCreateLimitOrder {
             system_program: system_program.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InstantCreateLimitOrderInstructionAccountMetas {
+    pub keeper: solana_instruction::AccountMeta,
+    pub api_keeper: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub funding_account: solana_instruction::AccountMeta,
+    pub perpetuals: solana_instruction::AccountMeta,
+    pub pool: solana_instruction::AccountMeta,
+    pub position: solana_instruction::AccountMeta,
+    pub position_request: solana_instruction::AccountMeta,
+    pub position_request_ata: solana_instruction::AccountMeta,
+    pub custody: solana_instruction::AccountMeta,
+    pub custody_doves_price_account: solana_instruction::AccountMeta,
+    pub custody_pythnet_price_account: solana_instruction::AccountMeta,
+    pub collateral_custody: solana_instruction::AccountMeta,
+    pub input_mint: solana_instruction::AccountMeta,
+    pub referral: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InstantCreateLimitOrder {
+    type ArrangedAccountMetas = InstantCreateLimitOrderInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [keeper, api_keeper, owner, funding_account, perpetuals, pool, position, position_request, position_request_ata, custody, custody_doves_price_account, custody_pythnet_price_account, collateral_custody, input_mint, referral, token_program, associated_token_program, system_program, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InstantCreateLimitOrderInstructionAccountMetas {
+            keeper: keeper.clone(),
+            api_keeper: api_keeper.clone(),
+            owner: owner.clone(),
+            funding_account: funding_account.clone(),
+            perpetuals: perpetuals.clone(),
+            pool: pool.clone(),
+            position: position.clone(),
+            position_request: position_request.clone(),
+            position_request_ata: position_request_ata.clone(),
+            custody: custody.clone(),
+            custody_doves_price_account: custody_doves_price_account.clone(),
+            custody_pythnet_price_account: custody_pythnet_price_account.clone(),
+            collateral_custody: collateral_custody.clone(),
+            input_mint: input_mint.clone(),
+            referral: referral.clone(),
+            token_program: token_program.clone(),
+            associated_token_program: associated_token_program.clone(),
+            system_program: system_program.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InstantCreateLimitOrderInstructionAccountIndices {
+    pub keeper: usize,
+    pub api_keeper: usize,
+    pub owner: usize,
+    pub funding_account: usize,
+    pub perpetuals: usize,
+    pub pool: usize,
+    pub position: usize,
+    pub position_request: usize,
+    pub position_request_ata: usize,
+    pub custody: usize,
+    pub custody_doves_price_account: usize,
+    pub custody_pythnet_price_account: usize,
+    pub collateral_custody: usize,
+    pub input_mint: usize,
+    pub referral: usize,
+    pub token_program: usize,
+    pub associated_token_program: usize,
+    pub system_program: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InstantCreateLimitOrder {
+    type ArrangedAccountIndices = InstantCreateLimitOrderInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 20 {
+            return None;
+        }
+
+        Some(InstantCreateLimitOrderInstructionAccountIndices {
+            keeper: 0,
+            api_keeper: 1,
+            owner: 2,
+            funding_account: 3,
+            perpetuals: 4,
+            pool: 5,
+            position: 6,
+            position_request: 7,
+            position_request_ata: 8,
+            custody: 9,
+            custody_doves_price_account: 10,
+            custody_pythnet_price_account: 11,
+            collateral_custody: 12,
+            input_mint: 13,
+            referral: 14,
+            token_program: 15,
+            associated_token_program: 16,
+            system_program: 17,
+            event_authority: 18,
+            program: 19,
+            remaining_accounts: 20..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InstantCreateLimitOrder {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "keeper",
+            "api_keeper",
+            "owner",
+            "funding_account",
+            "perpetuals",
+            "pool",
+            "position",
+            "position_request",
+            "position_request_ata",
+            "custody",
+            "custody_doves_price_account",
+            "custody_pythnet_price_account",
+            "collateral_custody",
+            "input_mint",
+            "referral",
+            "token_program",
+            "associated_token_program",
+            "system_program",
+            "event_authority",
+            "program",
+        ]
+    }
+}
@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -33,6 +34,7 @@ pub struct InstantDecreasePositionInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InstantDecreasePosition {
@@ -41,7 +43,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InstantDecreasePosition {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [keeper, api_keeper, owner, receiving_account, transfer_authority, perpetuals, pool, position, custody, custody_doves_price_account, custody_pythnet_price_account, collateral_custody, collateral_custody_doves_price_account, collateral_custody_pythnet_price_account, collateral_custody_token_account, desired_mint, referral, token_program, associated_token_program, system_program, event_authority, program, _remaining @ ..] =
+        let [keeper, api_keeper, owner, receiving_account, transfer_authority, perpetuals, pool, position, custody, custody_doves_price_account, custody_pythnet_price_account, collateral_custody, collateral_custody_doves_price_account, collateral_custody_pythnet_price_account, collateral_custody_token_account, desired_mint, referral, token_program, associated_token_program, system_program, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -71,6 +73,167 @@ impl carbon_core::deserialize::ArrangeAccounts for InstantDecreasePosition {
             system_program: system_program.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InstantDecreasePositionInstructionAccountMetas {
+    pub keeper: solana_instruction::AccountMeta,
+    pub api_keeper: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub receiving_account: solana_instruction::AccountMeta,
+    pub transfer_authority: solana_instruction::AccountMeta,
+    pub perpetuals: solana_instruction::AccountMeta,
+    pub pool: solana_instruction::AccountMeta,
+    pub position: solana_instruction::AccountMeta,
+    pub custody: solana_instruction::AccountMeta,
+    pub custody_doves_price_account: solana_instruction::AccountMeta,
+    pub custody_pythnet_price_account: solana_instruction::AccountMeta,
+    pub collateral_custody: solana_instruction::AccountMeta,
+    pub collateral_custody_doves_price_account: solana_instruction::AccountMeta,
+    pub collateral_custody_pythnet_price_account: solana_instruction::AccountMeta,
+    pub collateral_custody_token_account: solana_instruction::AccountMeta,
+    pub desired_mint: solana_instruction::AccountMeta,
+    pub referral: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InstantDecreasePosition {
+    type ArrangedAccountMetas = InstantDecreasePositionInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [keeper, api_keeper, owner, receiving_account, transfer_authority, perpetuals, pool, position, custody, custody_doves_price_account, custody_pythnet_price_account, collateral_custody, collateral_custody_doves_price_account, collateral_custody_pythnet_price_account, collateral_custody_token_account, desired_mint, referral, token_program, associated_token_program, system_program, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InstantDecreasePositionInstructionAccountMetas {
+            keeper: keeper.clone(),
+            api_keeper: api_keeper.clone(),
+            owner: owner.clone(),
+            receiving_account: receiving_account.clone(),
+            transfer_authority: transfer_authority.clone(),
+            perpetuals: perpetuals.clone(),
+            pool: pool.clone(),
+            position: position.clone(),
+            custody: custody.clone(),
+            custody_doves_price_account: custody_doves_price_account.clone(),
+            custody_pythnet_price_account: custody_pythnet_price_account.clone(),
+            collateral_custody: collateral_custody.clone(),
+            collateral_custody_doves_price_account: collateral_custody_doves_price_account.clone(),
+            collateral_custody_pythnet_price_account: collateral_custody_pythnet_price_account
+                .clone(),
+            collateral_custody_token_account: collateral_custody_token_account.clone(),
+            desired_mint: desired_mint.clone(),
+            referral: referral.clone(),
+            token_program: token_program.clone(),
+            associated_token_program: associated_token_program.clone(),
+            system_program: system_program.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InstantDecreasePositionInstructionAccountIndices {
+    pub keeper: usize,
+    pub api_keeper: usize,
+    pub owner: usize,
+    pub receiving_account: usize,
+    pub transfer_authority: usize,
+    pub perpetuals: usize,
+    pub pool: usize,
+    pub position: usize,
+    pub custody: usize,
+    pub custody_doves_price_account: usize,
+    pub custody_pythnet_price_account: usize,
+    pub collateral_custody: usize,
+    pub collateral_custody_doves_price_account: usize,
+    pub collateral_custody_pythnet_price_account: usize,
+    pub collateral_custody_token_account: usize,
+    pub desired_mint: usize,
+    pub referral: usize,
+    pub token_program: usize,
+    pub associated_token_program: usize,
+    pub system_program: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InstantDecreasePosition {
+    type ArrangedAccountIndices = InstantDecreasePositionInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 22 {
+            return None;
+        }
+
+        Some(InstantDecreasePositionInstructionAccountIndices {
+            keeper: 0,
+            api_keeper: 1,
+            owner: 2,
+            receiving_account: 3,
+            transfer_authority: 4,
+            perpetuals: 5,
+            pool: 6,
+            position: 7,
+            custody: 8,
+            custody_doves_price_account: 9,
+            custody_pythnet_price_account: 10,
+            collateral_custody: 11,
+            collateral_custody_doves_price_account: 12,
+            collateral_custody_pythnet_price_account: 13,
+            collateral_custody_token_account: 14,
+            desired_mint: 15,
+            referral: 16,
+            token_program: 17,
+            associated_token_program: 18,
+            system_program: 19,
+            event_authority: 20,
+            program: 21,
+            remaining_accounts: 22..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InstantDecreasePosition {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "keeper",
+            "api_keeper",
+            "owner",
+            "receiving_account",
+            "transfer_authority",
+            "perpetuals",
+            "pool",
+            "position",
+            "custody",
+            "custody_doves_price_account",
+            "custody_pythnet_price_account",
+            "collateral_custody",
+            "collateral_custody_doves_price_account",
+            "collateral_custody_pythnet_price_account",
+            "collateral_custody_token_account",
+            "desired_mint",
+            "referral",
+            "token_program",
+            "associated_token_program",
+            "system_program",
+            "event_authority",
+            "program",
+        ]
+    }
+}
@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -21,6 +22,7 @@ pub struct InstantUpdateLimitOrderInstructionAccounts {
     pub custody: solana_pubkey::Pubkey,
     pub custody_doves_price_account: solana_pubkey::Pubkey,
     pub custody_pythnet_price_account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InstantUpdateLimitOrder {
@@ -29,7 +31,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InstantUpdateLimitOrder {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [keeper, api_keeper, owner, perpetuals, pool, position, position_request, custody, custody_doves_price_account, custody_pythnet_price_account, _remaining @ ..] =
+        let [keeper, api_keeper, owner, perpetuals, pool, position, position_request, custody, custody_doves_price_account, custody_pythnet_price_account, remaining @ ..] =
             accounts
         else {
             return None;
@@ -46,6 +48,106 @@ impl carbon_core::deserialize::ArrangeAccounts for InstantUpdateLimitOrder {
             custody: custody.pubkey,
             custody_doves_price_account: custody_doves_price_account.pubkey,
             custody_pythnet_price_account: custody_pythnet_price_account.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InstantUpdateLimitOrderInstructionAccountMetas {
+    pub keeper: solana_instruction::AccountMeta,
+    pub api_keeper: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub perpetuals: solana_instruction::AccountMeta,
+    pub pool: solana_instruction::AccountMeta,
+    pub position: solana_instruction::AccountMeta,
+    pub position_request: solana_instruction::AccountMeta,
+    pub custody: solana_instruction::AccountMeta,
+    pub custody_doves_price_account: solana_instruction::AccountMeta,
+    pub custody_pythnet_price_account: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InstantUpdateLimitOrder {
+    type ArrangedAccountMetas = InstantUpdateLimitOrderInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [keeper, api_keeper, owner, perpetuals, pool, position, position_request, custody, custody_doves_price_account, custody_pythnet_price_account, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InstantUpdateLimitOrderInstructionAccountMetas {
+            keeper: keeper.clone(),
+            api_keeper: api_keeper.clone(),
+            owner: owner.clone(),
+            perpetuals: perpetuals.clone(),
+            pool: pool.clone(),
+            position: position.clone(),
+            position_request: position_request.clone(),
+            custody: custody.clone(),
+            custody_doves_price_account: custody_doves_price_account.clone(),
+            custody_pythnet_price_account: custody_pythnet_price_account.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InstantUpdateLimitOrderInstructionAccountIndices {
+    pub keeper: usize,
+    pub api_keeper: usize,
+    pub owner: usize,
+    pub perpetuals: usize,
+    pub pool: usize,
+    pub position: usize,
+    pub position_request: usize,
+    pub custody: usize,
+    pub custody_doves_price_account: usize,
+    pub custody_pythnet_price_account: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InstantUpdateLimitOrder {
+    type ArrangedAccountIndices = InstantUpdateLimitOrderInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 10 {
+            return None;
+        }
+
+        Some(InstantUpdateLimitOrderInstructionAccountIndices {
+            keeper: 0,
+            api_keeper: 1,
+            owner: 2,
+            perpetuals: 3,
+            pool: 4,
+            position: 5,
+            position_request: 6,
+            custody: 7,
+            custody_doves_price_account: 8,
+            custody_pythnet_price_account: 9,
+            remaining_accounts: 10..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InstantUpdateLimitOrder {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "keeper",
+            "api_keeper",
+            "owner",
+            "perpetuals",
+            "pool",
+            "position",
+            "position_request",
+            "custody",
+            "custody_doves_price_account",
+            "custody_pythnet_price_account",
+        ]
+    }
+}
@@ -142,15 +142,25 @@ pub enum PerpetualsInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for PerpetualsDecoder {
     type InstructionType = PerpetualsInstruction;
 
-    fn decode_instruction(
+    fn decoder_name(&self) -> &'static str {
+        "perpetuals"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[super::PROGRAM_ID]
+    }
+
+    fn try_decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
-    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+    ) -> carbon_core::error::CarbonResult<
+        Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>>,
+    > {
         if !instruction.program_id.eq(&PROGRAM_ID) {
-            return None;
+            return Ok(None);
         }
 
-        carbon_core::try_decode_instructions!(instruction,
+        Ok(carbon_core::try_decode_instructions!(instruction,
             PerpetualsInstruction::Init => init::Init,
             PerpetualsInstruction::AddPool => add_pool::AddPool,
             PerpetualsInstruction::AddCustody => add_custody::AddCustody,
@@ -206,6 +216,6 @@ impl carbon_core::instruction::InstructionDecoder<'_> for PerpetualsDecoder {
             PerpetualsInstruction::InstantCreateLimitOrderEvent => instant_create_limit_order_event::InstantCreateLimitOrderEvent,
             PerpetualsInstruction::InstantIncreasePositionEvent => instant_increase_position_event::InstantIncreasePositionEvent,
             PerpetualsInstruction::InstantDecreasePositionEvent => instant_decrease_position_event::InstantDecreasePositionEvent,
-        )
+        ))
     }
 }
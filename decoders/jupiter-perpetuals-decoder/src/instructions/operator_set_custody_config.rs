@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +14,7 @@ pub struct OperatorSetCustodyConfig {
 pub struct OperatorSetCustodyConfigInstructionAccounts {
     pub operator: solana_pubkey::Pubkey,
     pub custody: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for OperatorSetCustodyConfig {
@@ -21,13 +23,68 @@ impl carbon_core::deserialize::ArrangeAccounts for OperatorSetCustodyConfig {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [operator, custody, _remaining @ ..] = accounts else {
+        let [operator, custody, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(OperatorSetCustodyConfigInstructionAccounts {
             operator: operator.pubkey,
             custody: custody.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct OperatorSetCustodyConfigInstructionAccountMetas {
+    pub operator: solana_instruction::AccountMeta,
+    pub custody: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for OperatorSetCustodyConfig {
+    type ArrangedAccountMetas = OperatorSetCustodyConfigInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [operator, custody, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(OperatorSetCustodyConfigInstructionAccountMetas {
+            operator: operator.clone(),
+            custody: custody.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct OperatorSetCustodyConfigInstructionAccountIndices {
+    pub operator: usize,
+    pub custody: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for OperatorSetCustodyConfig {
+    type ArrangedAccountIndices = OperatorSetCustodyConfigInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(OperatorSetCustodyConfigInstructionAccountIndices {
+            operator: 0,
+            custody: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for OperatorSetCustodyConfig {
+    fn account_names() -> &'static [&'static str] {
+        &["operator", "custody"]
+    }
+}
@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +14,7 @@ pub struct OperatorSetPoolConfig {
 pub struct OperatorSetPoolConfigInstructionAccounts {
     pub operator: solana_pubkey::Pubkey,
     pub pool: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for OperatorSetPoolConfig {
@@ -21,13 +23,68 @@ impl carbon_core::deserialize::ArrangeAccounts for OperatorSetPoolConfig {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [operator, pool, _remaining @ ..] = accounts else {
+        let [operator, pool, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(OperatorSetPoolConfigInstructionAccounts {
             operator: operator.pubkey,
             pool: pool.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct OperatorSetPoolConfigInstructionAccountMetas {
+    pub operator: solana_instruction::AccountMeta,
+    pub pool: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for OperatorSetPoolConfig {
+    type ArrangedAccountMetas = OperatorSetPoolConfigInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [operator, pool, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(OperatorSetPoolConfigInstructionAccountMetas {
+            operator: operator.clone(),
+            pool: pool.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct OperatorSetPoolConfigInstructionAccountIndices {
+    pub operator: usize,
+    pub pool: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for OperatorSetPoolConfig {
+    type ArrangedAccountIndices = OperatorSetPoolConfigInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(OperatorSetPoolConfigInstructionAccountIndices {
+            operator: 0,
+            pool: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for OperatorSetPoolConfig {
+    fn account_names() -> &'static [&'static str] {
+        &["operator", "pool"]
+    }
+}
@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct RefreshAssetsUnderManagementInstructionAccounts {
     pub keeper: solana_pubkey::Pubkey,
     pub perpetuals: solana_pubkey::Pubkey,
     pub pool: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for RefreshAssetsUnderManagement {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for RefreshAssetsUnderManagement
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [keeper, perpetuals, pool, _remaining @ ..] = accounts else {
+        let [keeper, perpetuals, pool, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -30,6 +32,65 @@ impl carbon_core::deserialize::ArrangeAccounts for RefreshAssetsUnderManagement
             keeper: keeper.pubkey,
             perpetuals: perpetuals.pubkey,
             pool: pool.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct RefreshAssetsUnderManagementInstructionAccountMetas {
+    pub keeper: solana_instruction::AccountMeta,
+    pub perpetuals: solana_instruction::AccountMeta,
+    pub pool: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for RefreshAssetsUnderManagement {
+    type ArrangedAccountMetas = RefreshAssetsUnderManagementInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [keeper, perpetuals, pool, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(RefreshAssetsUnderManagementInstructionAccountMetas {
+            keeper: keeper.clone(),
+            perpetuals: perpetuals.clone(),
+            pool: pool.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct RefreshAssetsUnderManagementInstructionAccountIndices {
+    pub keeper: usize,
+    pub perpetuals: usize,
+    pub pool: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for RefreshAssetsUnderManagement {
+    type ArrangedAccountIndices = RefreshAssetsUnderManagementInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(RefreshAssetsUnderManagementInstructionAccountIndices {
+            keeper: 0,
+            perpetuals: 1,
+            pool: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for RefreshAssetsUnderManagement {
+    fn account_names() -> &'static [&'static str] {
+        &["keeper", "perpetuals", "pool"]
+    }
+}
@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct SetCustodyConfigInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub perpetuals: solana_pubkey::Pubkey,
     pub custody: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SetCustodyConfig {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for SetCustodyConfig {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin, perpetuals, custody, _remaining @ ..] = accounts else {
+        let [admin, perpetuals, custody, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -30,6 +32,65 @@ impl carbon_core::deserialize::ArrangeAccounts for SetCustodyConfig {
             admin: admin.pubkey,
             perpetuals: perpetuals.pubkey,
             custody: custody.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SetCustodyConfigInstructionAccountMetas {
+    pub admin: solana_instruction::AccountMeta,
+    pub perpetuals: solana_instruction::AccountMeta,
+    pub custody: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SetCustodyConfig {
+    type ArrangedAccountMetas = SetCustodyConfigInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin, perpetuals, custody, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(SetCustodyConfigInstructionAccountMetas {
+            admin: admin.clone(),
+            perpetuals: perpetuals.clone(),
+            custody: custody.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SetCustodyConfigInstructionAccountIndices {
+    pub admin: usize,
+    pub perpetuals: usize,
+    pub custody: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SetCustodyConfig {
+    type ArrangedAccountIndices = SetCustodyConfigInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(SetCustodyConfigInstructionAccountIndices {
+            admin: 0,
+            perpetuals: 1,
+            custody: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SetCustodyConfig {
+    fn account_names() -> &'static [&'static str] {
+        &["admin", "perpetuals", "custody"]
+    }
+}
@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct SetPoolConfigInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub perpetuals: solana_pubkey::Pubkey,
     pub pool: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SetPoolConfig {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for SetPoolConfig {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin, perpetuals, pool, _remaining @ ..] = accounts else {
+        let [admin, perpetuals, pool, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -30,6 +32,65 @@ impl carbon_core::deserialize::ArrangeAccounts for SetPoolConfig {
             admin: admin.pubkey,
             perpetuals: perpetuals.pubkey,
             pool: pool.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SetPoolConfigInstructionAccountMetas {
+    pub admin: solana_instruction::AccountMeta,
+    pub perpetuals: solana_instruction::AccountMeta,
+    pub pool: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SetPoolConfig {
+    type ArrangedAccountMetas = SetPoolConfigInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin, perpetuals, pool, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(SetPoolConfigInstructionAccountMetas {
+            admin: admin.clone(),
+            perpetuals: perpetuals.clone(),
+            pool: pool.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SetPoolConfigInstructionAccountIndices {
+    pub admin: usize,
+    pub perpetuals: usize,
+    pub pool: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SetPoolConfig {
+    type ArrangedAccountIndices = SetPoolConfigInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(SetPoolConfigInstructionAccountIndices {
+            admin: 0,
+            perpetuals: 1,
+            pool: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SetPoolConfig {
+    fn account_names() -> &'static [&'static str] {
+        &["admin", "perpetuals", "pool"]
+    }
+}
@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +14,7 @@ pub struct SetTestTime {
 pub struct SetTestTimeInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub perpetuals: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SetTestTime {
@@ -21,13 +23,68 @@ impl carbon_core::deserialize::ArrangeAccounts for SetTestTime {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin, perpetuals, _remaining @ ..] = accounts else {
+        let [admin, perpetuals, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(SetTestTimeInstructionAccounts {
             admin: admin.pubkey,
             perpetuals: perpetuals.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SetTestTimeInstructionAccountMetas {
+    pub admin: solana_instruction::AccountMeta,
+    pub perpetuals: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SetTestTime {
+    type ArrangedAccountMetas = SetTestTimeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin, perpetuals, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(SetTestTimeInstructionAccountMetas {
+            admin: admin.clone(),
+            perpetuals: perpetuals.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SetTestTimeInstructionAccountIndices {
+    pub admin: usize,
+    pub perpetuals: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SetTestTime {
+    type ArrangedAccountIndices = SetTestTimeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(SetTestTimeInstructionAccountIndices {
+            admin: 0,
+            perpetuals: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SetTestTime {
+    fn account_names() -> &'static [&'static str] {
+        &["admin", "perpetuals"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -10,6 +11,7 @@ pub struct SetTokenLedgerInstructionAccounts {
     pub token_ledger: solana_pubkey::Pubkey,
     pub token_account: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SetTokenLedger {
@@ -18,7 +20,7 @@ impl carbon_core::deserialize::ArrangeAccounts for SetTokenLedger {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [token_ledger, token_account, token_program, _remaining @ ..] = accounts else {
+        let [token_ledger, token_account, token_program, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -26,6 +28,65 @@ impl carbon_core::deserialize::ArrangeAccounts for SetTokenLedger {
             token_ledger: token_ledger.pubkey,
             token_account: token_account.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SetTokenLedgerInstructionAccountMetas {
+    pub token_ledger: solana_instruction::AccountMeta,
+    pub token_account: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SetTokenLedger {
+    type ArrangedAccountMetas = SetTokenLedgerInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [token_ledger, token_account, token_program, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(SetTokenLedgerInstructionAccountMetas {
+            token_ledger: token_ledger.clone(),
+            token_account: token_account.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SetTokenLedgerInstructionAccountIndices {
+    pub token_ledger: usize,
+    pub token_account: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SetTokenLedger {
+    type ArrangedAccountIndices = SetTokenLedgerInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(SetTokenLedgerInstructionAccountIndices {
+            token_ledger: 0,
+            token_account: 1,
+            token_program: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SetTokenLedger {
+    fn account_names() -> &'static [&'static str] {
+        &["token_ledger", "token_account", "token_program"]
+    }
+}
@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -28,6 +29,7 @@ pub struct Swap2InstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Swap2 {
@@ -36,7 +38,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Swap2 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [owner, funding_account, receiving_account, transfer_authority, perpetuals, pool, receiving_custody, receiving_custody_doves_price_account, receiving_custody_pythnet_price_account, receiving_custody_token_account, dispensing_custody, dispensing_custody_doves_price_account, dispensing_custody_pythnet_price_account, dispensing_custody_token_account, token_program, event_authority, program, _remaining @ ..] =
+        let [owner, funding_account, receiving_account, transfer_authority, perpetuals, pool, receiving_custody, receiving_custody_doves_price_account, receiving_custody_pythnet_price_account, receiving_custody_token_account, dispensing_custody, dispensing_custody_doves_price_account, dispensing_custody_pythnet_price_account, dispensing_custody_token_account, token_program, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -61,6 +63,143 @@ impl carbon_core::deserialize::ArrangeAccounts for Swap2 {
             token_program: token_program.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct Swap2InstructionAccountMetas {
+    pub owner: solana_instruction::AccountMeta,
+    pub funding_account: solana_instruction::AccountMeta,
+    pub receiving_account: solana_instruction::AccountMeta,
+    pub transfer_authority: solana_instruction::AccountMeta,
+    pub perpetuals: solana_instruction::AccountMeta,
+    pub pool: solana_instruction::AccountMeta,
+    pub receiving_custody: solana_instruction::AccountMeta,
+    pub receiving_custody_doves_price_account: solana_instruction::AccountMeta,
+    pub receiving_custody_pythnet_price_account: solana_instruction::AccountMeta,
+    pub receiving_custody_token_account: solana_instruction::AccountMeta,
+    pub dispensing_custody: solana_instruction::AccountMeta,
+    pub dispensing_custody_doves_price_account: solana_instruction::AccountMeta,
+    pub dispensing_custody_pythnet_price_account: solana_instruction::AccountMeta,
+    pub dispensing_custody_token_account: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Swap2 {
+    type ArrangedAccountMetas = Swap2InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [owner, funding_account, receiving_account, transfer_authority, perpetuals, pool, receiving_custody, receiving_custody_doves_price_account, receiving_custody_pythnet_price_account, receiving_custody_token_account, dispensing_custody, dispensing_custody_doves_price_account, dispensing_custody_pythnet_price_account, dispensing_custody_token_account, token_program, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(Swap2InstructionAccountMetas {
+            owner: owner.clone(),
+            funding_account: funding_account.clone(),
+            receiving_account: receiving_account.clone(),
+            transfer_authority: transfer_authority.clone(),
+            perpetuals: perpetuals.clone(),
+            pool: pool.clone(),
+            receiving_custody: receiving_custody.clone(),
+            receiving_custody_doves_price_account: receiving_custody_doves_price_account.clone(),
+            receiving_custody_pythnet_price_account: receiving_custody_pythnet_price_account
+                .clone(),
+            receiving_custody_token_account: receiving_custody_token_account.clone(),
+            dispensing_custody: dispensing_custody.clone(),
+            dispensing_custody_doves_price_account: dispensing_custody_doves_price_account.clone(),
+            dispensing_custody_pythnet_price_account: dispensing_custody_pythnet_price_account
+                .clone(),
+            dispensing_custody_token_account: dispensing_custody_token_account.clone(),
+            token_program: token_program.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct Swap2InstructionAccountIndices {
+    pub owner: usize,
+    pub funding_account: usize,
+    pub receiving_account: usize,
+    pub transfer_authority: usize,
+    pub perpetuals: usize,
+    pub pool: usize,
+    pub receiving_custody: usize,
+    pub receiving_custody_doves_price_account: usize,
+    pub receiving_custody_pythnet_price_account: usize,
+    pub receiving_custody_token_account: usize,
+    pub dispensing_custody: usize,
+    pub dispensing_custody_doves_price_account: usize,
+    pub dispensing_custody_pythnet_price_account: usize,
+    pub dispensing_custody_token_account: usize,
+    pub token_program: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Swap2 {
+    type ArrangedAccountIndices = Swap2InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 17 {
+            return None;
+        }
+
+        Some(Swap2InstructionAccountIndices {
+            owner: 0,
+            funding_account: 1,
+            receiving_account: 2,
+            transfer_authority: 3,
+            perpetuals: 4,
+            pool: 5,
+            receiving_custody: 6,
+            receiving_custody_doves_price_account: 7,
+            receiving_custody_pythnet_price_account: 8,
+            receiving_custody_token_account: 9,
+            dispensing_custody: 10,
+            dispensing_custody_doves_price_account: 11,
+            dispensing_custody_pythnet_price_account: 12,
+            dispensing_custody_token_account: 13,
+            token_program: 14,
+            event_authority: 15,
+            program: 16,
+            remaining_accounts: 17..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Swap2 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "owner",
+            "funding_account",
+            "receiving_account",
+            "transfer_authority",
+            "perpetuals",
+            "pool",
+            "receiving_custody",
+            "receiving_custody_doves_price_account",
+            "receiving_custody_pythnet_price_account",
+            "receiving_custody_token_account",
+            "dispensing_custody",
+            "dispensing_custody_doves_price_account",
+            "dispensing_custody_pythnet_price_account",
+            "dispensing_custody_token_account",
+            "token_program",
+            "event_authority",
+            "program",
+        ]
+    }
+}
@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -17,6 +18,7 @@ pub struct TestInitInstructionAccounts {
     pub perpetuals: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for TestInit {
@@ -25,7 +27,7 @@ impl carbon_core::deserialize::ArrangeAccounts for TestInit {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [upgrade_authority, admin, transfer_authority, perpetuals, system_program, token_program, _remaining @ ..] =
+        let [upgrade_authority, admin, transfer_authority, perpetuals, system_program, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -38,6 +40,86 @@ impl carbon_core::deserialize::ArrangeAccounts for TestInit {
             perpetuals: perpetuals.pubkey,
             system_program: system_program.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct TestInitInstructionAccountMetas {
+    pub upgrade_authority: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub transfer_authority: solana_instruction::AccountMeta,
+    pub perpetuals: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for TestInit {
+    type ArrangedAccountMetas = TestInitInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [upgrade_authority, admin, transfer_authority, perpetuals, system_program, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(TestInitInstructionAccountMetas {
+            upgrade_authority: upgrade_authority.clone(),
+            admin: admin.clone(),
+            transfer_authority: transfer_authority.clone(),
+            perpetuals: perpetuals.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct TestInitInstructionAccountIndices {
+    pub upgrade_authority: usize,
+    pub admin: usize,
+    pub transfer_authority: usize,
+    pub perpetuals: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for TestInit {
+    type ArrangedAccountIndices = TestInitInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(TestInitInstructionAccountIndices {
+            upgrade_authority: 0,
+            admin: 1,
+            transfer_authority: 2,
+            perpetuals: 3,
+            system_program: 4,
+            token_program: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for TestInit {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "upgrade_authority",
+            "admin",
+            "transfer_authority",
+            "perpetuals",
+            "system_program",
+            "token_program",
+        ]
+    }
+}
@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct TransferAdminInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub new_admin: solana_pubkey::Pubkey,
     pub perpetuals: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for TransferAdmin {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for TransferAdmin {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin, new_admin, perpetuals, _remaining @ ..] = accounts else {
+        let [admin, new_admin, perpetuals, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -30,6 +32,65 @@ impl carbon_core::deserialize::ArrangeAccounts for TransferAdmin {
             admin: admin.pubkey,
             new_admin: new_admin.pubkey,
             perpetuals: perpetuals.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct TransferAdminInstructionAccountMetas {
+    pub admin: solana_instruction::AccountMeta,
+    pub new_admin: solana_instruction::AccountMeta,
+    pub perpetuals: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for TransferAdmin {
+    type ArrangedAccountMetas = TransferAdminInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin, new_admin, perpetuals, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(TransferAdminInstructionAccountMetas {
+            admin: admin.clone(),
+            new_admin: new_admin.clone(),
+            perpetuals: perpetuals.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct TransferAdminInstructionAccountIndices {
+    pub admin: usize,
+    pub new_admin: usize,
+    pub perpetuals: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for TransferAdmin {
+    type ArrangedAccountIndices = TransferAdminInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(TransferAdminInstructionAccountIndices {
+            admin: 0,
+            new_admin: 1,
+            perpetuals: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for TransferAdmin {
+    fn account_names() -> &'static [&'static str] {
+        &["admin", "new_admin", "perpetuals"]
+    }
+}
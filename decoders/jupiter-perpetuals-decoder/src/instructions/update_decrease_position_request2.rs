@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -19,6 +20,7 @@ pub struct UpdateDecreasePositionRequest2InstructionAccounts {
     pub custody: solana_pubkey::Pubkey,
     pub custody_doves_price_account: solana_pubkey::Pubkey,
     pub custody_pythnet_price_account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateDecreasePositionRequest2 {
@@ -27,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateDecreasePositionRequest
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [owner, perpetuals, pool, position, position_request, custody, custody_doves_price_account, custody_pythnet_price_account, _remaining @ ..] =
+        let [owner, perpetuals, pool, position, position_request, custody, custody_doves_price_account, custody_pythnet_price_account, remaining @ ..] =
             accounts
         else {
             return None;
@@ -42,6 +44,96 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateDecreasePositionRequest
             custody: custody.pubkey,
             custody_doves_price_account: custody_doves_price_account.pubkey,
             custody_pythnet_price_account: custody_pythnet_price_account.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateDecreasePositionRequest2InstructionAccountMetas {
+    pub owner: solana_instruction::AccountMeta,
+    pub perpetuals: solana_instruction::AccountMeta,
+    pub pool: solana_instruction::AccountMeta,
+    pub position: solana_instruction::AccountMeta,
+    pub position_request: solana_instruction::AccountMeta,
+    pub custody: solana_instruction::AccountMeta,
+    pub custody_doves_price_account: solana_instruction::AccountMeta,
+    pub custody_pythnet_price_account: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateDecreasePositionRequest2 {
+    type ArrangedAccountMetas = UpdateDecreasePositionRequest2InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [owner, perpetuals, pool, position, position_request, custody, custody_doves_price_account, custody_pythnet_price_account, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(UpdateDecreasePositionRequest2InstructionAccountMetas {
+            owner: owner.clone(),
+            perpetuals: perpetuals.clone(),
+            pool: pool.clone(),
+            position: position.clone(),
+            position_request: position_request.clone(),
+            custody: custody.clone(),
+            custody_doves_price_account: custody_doves_price_account.clone(),
+            custody_pythnet_price_account: custody_pythnet_price_account.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateDecreasePositionRequest2InstructionAccountIndices {
+    pub owner: usize,
+    pub perpetuals: usize,
+    pub pool: usize,
+    pub position: usize,
+    pub position_request: usize,
+    pub custody: usize,
+    pub custody_doves_price_account: usize,
+    pub custody_pythnet_price_account: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateDecreasePositionRequest2 {
+    type ArrangedAccountIndices = UpdateDecreasePositionRequest2InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(UpdateDecreasePositionRequest2InstructionAccountIndices {
+            owner: 0,
+            perpetuals: 1,
+            pool: 2,
+            position: 3,
+            position_request: 4,
+            custody: 5,
+            custody_doves_price_account: 6,
+            custody_pythnet_price_account: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateDecreasePositionRequest2 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "owner",
+            "perpetuals",
+            "pool",
+            "position",
+            "position_request",
+            "custody",
+            "custody_doves_price_account",
+            "custody_pythnet_price_account",
+        ]
+    }
+}
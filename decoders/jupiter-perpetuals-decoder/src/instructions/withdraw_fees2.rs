@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -21,6 +22,7 @@ pub struct WithdrawFees2InstructionAccounts {
     pub custody_pythnet_price_account: solana_pubkey::Pubkey,
     pub receiving_token_account: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for WithdrawFees2 {
@@ -29,7 +31,7 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawFees2 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [keeper, transfer_authority, perpetuals, pool, custody, custody_token_account, custody_doves_price_account, custody_pythnet_price_account, receiving_token_account, token_program, _remaining @ ..] =
+        let [keeper, transfer_authority, perpetuals, pool, custody, custody_token_account, custody_doves_price_account, custody_pythnet_price_account, receiving_token_account, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -46,6 +48,106 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawFees2 {
             custody_pythnet_price_account: custody_pythnet_price_account.pubkey,
             receiving_token_account: receiving_token_account.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct WithdrawFees2InstructionAccountMetas {
+    pub keeper: solana_instruction::AccountMeta,
+    pub transfer_authority: solana_instruction::AccountMeta,
+    pub perpetuals: solana_instruction::AccountMeta,
+    pub pool: solana_instruction::AccountMeta,
+    pub custody: solana_instruction::AccountMeta,
+    pub custody_token_account: solana_instruction::AccountMeta,
+    pub custody_doves_price_account: solana_instruction::AccountMeta,
+    pub custody_pythnet_price_account: solana_instruction::AccountMeta,
+    pub receiving_token_account: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for WithdrawFees2 {
+    type ArrangedAccountMetas = WithdrawFees2InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [keeper, transfer_authority, perpetuals, pool, custody, custody_token_account, custody_doves_price_account, custody_pythnet_price_account, receiving_token_account, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(WithdrawFees2InstructionAccountMetas {
+            keeper: keeper.clone(),
+            transfer_authority: transfer_authority.clone(),
+            perpetuals: perpetuals.clone(),
+            pool: pool.clone(),
+            custody: custody.clone(),
+            custody_token_account: custody_token_account.clone(),
+            custody_doves_price_account: custody_doves_price_account.clone(),
+            custody_pythnet_price_account: custody_pythnet_price_account.clone(),
+            receiving_token_account: receiving_token_account.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct WithdrawFees2InstructionAccountIndices {
+    pub keeper: usize,
+    pub transfer_authority: usize,
+    pub perpetuals: usize,
+    pub pool: usize,
+    pub custody: usize,
+    pub custody_token_account: usize,
+    pub custody_doves_price_account: usize,
+    pub custody_pythnet_price_account: usize,
+    pub receiving_token_account: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for WithdrawFees2 {
+    type ArrangedAccountIndices = WithdrawFees2InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 10 {
+            return None;
+        }
+
+        Some(WithdrawFees2InstructionAccountIndices {
+            keeper: 0,
+            transfer_authority: 1,
+            perpetuals: 2,
+            pool: 3,
+            custody: 4,
+            custody_token_account: 5,
+            custody_doves_price_account: 6,
+            custody_pythnet_price_account: 7,
+            receiving_token_account: 8,
+            token_program: 9,
+            remaining_accounts: 10..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for WithdrawFees2 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "keeper",
+            "transfer_authority",
+            "perpetuals",
+            "pool",
+            "custody",
+            "custody_token_account",
+            "custody_doves_price_account",
+            "custody_pythnet_price_account",
+            "receiving_token_account",
+            "token_program",
+        ]
+    }
+}
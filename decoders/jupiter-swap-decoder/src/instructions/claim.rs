@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +13,7 @@ pub struct ClaimInstructionAccounts {
     pub wallet: solana_pubkey::Pubkey,
     pub program_authority: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Claim {
@@ -20,7 +22,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Claim {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [wallet, program_authority, system_program, _remaining @ ..] = accounts else {
+        let [wallet, program_authority, system_program, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +30,65 @@ impl carbon_core::deserialize::ArrangeAccounts for Claim {
             wallet: wallet.pubkey,
             program_authority: program_authority.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ClaimInstructionAccountMetas {
+    pub wallet: solana_instruction::AccountMeta,
+    pub program_authority: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Claim {
+    type ArrangedAccountMetas = ClaimInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [wallet, program_authority, system_program, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(ClaimInstructionAccountMetas {
+            wallet: wallet.clone(),
+            program_authority: program_authority.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ClaimInstructionAccountIndices {
+    pub wallet: usize,
+    pub program_authority: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Claim {
+    type ArrangedAccountIndices = ClaimInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(ClaimInstructionAccountIndices {
+            wallet: 0,
+            program_authority: 1,
+            system_program: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Claim {
+    fn account_names() -> &'static [&'static str] {
+        &["wallet", "program_authority", "system_program"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -18,6 +19,7 @@ pub struct ClaimTokenInstructionAccounts {
     pub associated_token_token_program: solana_pubkey::Pubkey,
     pub associated_token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ClaimToken {
@@ -26,7 +28,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ClaimToken {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [payer, wallet, program_authority, program_token_account, destination_token_account, mint, associated_token_token_program, associated_token_program, system_program, _remaining @ ..] =
+        let [payer, wallet, program_authority, program_token_account, destination_token_account, mint, associated_token_token_program, associated_token_program, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -42,6 +44,101 @@ impl carbon_core::deserialize::ArrangeAccounts for ClaimToken {
             associated_token_token_program: associated_token_token_program.pubkey,
             associated_token_program: associated_token_program.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ClaimTokenInstructionAccountMetas {
+    pub payer: solana_instruction::AccountMeta,
+    pub wallet: solana_instruction::AccountMeta,
+    pub program_authority: solana_instruction::AccountMeta,
+    pub program_token_account: solana_instruction::AccountMeta,
+    pub destination_token_account: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub associated_token_token_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ClaimToken {
+    type ArrangedAccountMetas = ClaimTokenInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [payer, wallet, program_authority, program_token_account, destination_token_account, mint, associated_token_token_program, associated_token_program, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(ClaimTokenInstructionAccountMetas {
+            payer: payer.clone(),
+            wallet: wallet.clone(),
+            program_authority: program_authority.clone(),
+            program_token_account: program_token_account.clone(),
+            destination_token_account: destination_token_account.clone(),
+            mint: mint.clone(),
+            associated_token_token_program: associated_token_token_program.clone(),
+            associated_token_program: associated_token_program.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ClaimTokenInstructionAccountIndices {
+    pub payer: usize,
+    pub wallet: usize,
+    pub program_authority: usize,
+    pub program_token_account: usize,
+    pub destination_token_account: usize,
+    pub mint: usize,
+    pub associated_token_token_program: usize,
+    pub associated_token_program: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ClaimToken {
+    type ArrangedAccountIndices = ClaimTokenInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 9 {
+            return None;
+        }
+
+        Some(ClaimTokenInstructionAccountIndices {
+            payer: 0,
+            wallet: 1,
+            program_authority: 2,
+            program_token_account: 3,
+            destination_token_account: 4,
+            mint: 5,
+            associated_token_token_program: 6,
+            associated_token_program: 7,
+            system_program: 8,
+            remaining_accounts: 9..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ClaimToken {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "payer",
+            "wallet",
+            "program_authority",
+            "program_token_account",
+            "destination_token_account",
+            "mint",
+            "associated_token_token_program",
+            "associated_token_program",
+            "system_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +14,7 @@ pub struct CreateOpenOrdersInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub market: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CreateOpenOrders {
@@ -21,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateOpenOrders {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [open_orders, payer, dex_program, system_program, rent, market, _remaining @ ..] =
+        let [open_orders, payer, dex_program, system_program, rent, market, remaining @ ..] =
             accounts
         else {
             return None;
@@ -34,6 +36,86 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateOpenOrders {
             system_program: system_program.pubkey,
             rent: rent.pubkey,
             market: market.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CreateOpenOrdersInstructionAccountMetas {
+    pub open_orders: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub dex_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub market: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CreateOpenOrders {
+    type ArrangedAccountMetas = CreateOpenOrdersInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [open_orders, payer, dex_program, system_program, rent, market, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CreateOpenOrdersInstructionAccountMetas {
+            open_orders: open_orders.clone(),
+            payer: payer.clone(),
+            dex_program: dex_program.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            market: market.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CreateOpenOrdersInstructionAccountIndices {
+    pub open_orders: usize,
+    pub payer: usize,
+    pub dex_program: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub market: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CreateOpenOrders {
+    type ArrangedAccountIndices = CreateOpenOrdersInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(CreateOpenOrdersInstructionAccountIndices {
+            open_orders: 0,
+            payer: 1,
+            dex_program: 2,
+            system_program: 3,
+            rent: 4,
+            market: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CreateOpenOrders {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "open_orders",
+            "payer",
+            "dex_program",
+            "system_program",
+            "rent",
+            "market",
+        ]
+    }
+}
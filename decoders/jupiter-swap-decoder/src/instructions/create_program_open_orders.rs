@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -16,6 +17,7 @@ pub struct CreateProgramOpenOrdersInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub market: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CreateProgramOpenOrders {
@@ -24,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateProgramOpenOrders {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [open_orders, payer, program_authority, dex_program, system_program, rent, market, _remaining @ ..] =
+        let [open_orders, payer, program_authority, dex_program, system_program, rent, market, remaining @ ..] =
             accounts
         else {
             return None;
@@ -38,6 +40,91 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateProgramOpenOrders {
             system_program: system_program.pubkey,
             rent: rent.pubkey,
             market: market.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CreateProgramOpenOrdersInstructionAccountMetas {
+    pub open_orders: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub program_authority: solana_instruction::AccountMeta,
+    pub dex_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub market: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CreateProgramOpenOrders {
+    type ArrangedAccountMetas = CreateProgramOpenOrdersInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [open_orders, payer, program_authority, dex_program, system_program, rent, market, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CreateProgramOpenOrdersInstructionAccountMetas {
+            open_orders: open_orders.clone(),
+            payer: payer.clone(),
+            program_authority: program_authority.clone(),
+            dex_program: dex_program.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            market: market.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CreateProgramOpenOrdersInstructionAccountIndices {
+    pub open_orders: usize,
+    pub payer: usize,
+    pub program_authority: usize,
+    pub dex_program: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub market: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CreateProgramOpenOrders {
+    type ArrangedAccountIndices = CreateProgramOpenOrdersInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 7 {
+            return None;
+        }
+
+        Some(CreateProgramOpenOrdersInstructionAccountIndices {
+            open_orders: 0,
+            payer: 1,
+            program_authority: 2,
+            dex_program: 3,
+            system_program: 4,
+            rent: 5,
+            market: 6,
+            remaining_accounts: 7..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CreateProgramOpenOrders {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "open_orders",
+            "payer",
+            "program_authority",
+            "dex_program",
+            "system_program",
+            "rent",
+            "market",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -10,6 +11,7 @@ pub struct CreateTokenLedgerInstructionAccounts {
     pub token_ledger: solana_pubkey::Pubkey,
     pub payer: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CreateTokenLedger {
@@ -18,7 +20,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateTokenLedger {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [token_ledger, payer, system_program, _remaining @ ..] = accounts else {
+        let [token_ledger, payer, system_program, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -26,6 +28,65 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateTokenLedger {
             token_ledger: token_ledger.pubkey,
             payer: payer.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CreateTokenLedgerInstructionAccountMetas {
+    pub token_ledger: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CreateTokenLedger {
+    type ArrangedAccountMetas = CreateTokenLedgerInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [token_ledger, payer, system_program, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(CreateTokenLedgerInstructionAccountMetas {
+            token_ledger: token_ledger.clone(),
+            payer: payer.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CreateTokenLedgerInstructionAccountIndices {
+    pub token_ledger: usize,
+    pub payer: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CreateTokenLedger {
+    type ArrangedAccountIndices = CreateTokenLedgerInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(CreateTokenLedgerInstructionAccountIndices {
+            token_ledger: 0,
+            payer: 1,
+            system_program: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CreateTokenLedger {
+    fn account_names() -> &'static [&'static str] {
+        &["token_ledger", "payer", "system_program"]
+    }
+}
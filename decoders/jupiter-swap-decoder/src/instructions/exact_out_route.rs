@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -27,6 +28,7 @@ pub struct ExactOutRouteInstructionAccounts {
     pub token_2022_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ExactOutRoute {
@@ -35,7 +37,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ExactOutRoute {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [token_program, user_transfer_authority, user_source_token_account, user_destination_token_account, destination_token_account, source_mint, destination_mint, platform_fee_account, token_2022_program, event_authority, program, _remaining @ ..] =
+        let [token_program, user_transfer_authority, user_source_token_account, user_destination_token_account, destination_token_account, source_mint, destination_mint, platform_fee_account, token_2022_program, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -53,6 +55,111 @@ impl carbon_core::deserialize::ArrangeAccounts for ExactOutRoute {
             token_2022_program: token_2022_program.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ExactOutRouteInstructionAccountMetas {
+    pub token_program: solana_instruction::AccountMeta,
+    pub user_transfer_authority: solana_instruction::AccountMeta,
+    pub user_source_token_account: solana_instruction::AccountMeta,
+    pub user_destination_token_account: solana_instruction::AccountMeta,
+    pub destination_token_account: solana_instruction::AccountMeta,
+    pub source_mint: solana_instruction::AccountMeta,
+    pub destination_mint: solana_instruction::AccountMeta,
+    pub platform_fee_account: solana_instruction::AccountMeta,
+    pub token_2022_program: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ExactOutRoute {
+    type ArrangedAccountMetas = ExactOutRouteInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [token_program, user_transfer_authority, user_source_token_account, user_destination_token_account, destination_token_account, source_mint, destination_mint, platform_fee_account, token_2022_program, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(ExactOutRouteInstructionAccountMetas {
+            token_program: token_program.clone(),
+            user_transfer_authority: user_transfer_authority.clone(),
+            user_source_token_account: user_source_token_account.clone(),
+            user_destination_token_account: user_destination_token_account.clone(),
+            destination_token_account: destination_token_account.clone(),
+            source_mint: source_mint.clone(),
+            destination_mint: destination_mint.clone(),
+            platform_fee_account: platform_fee_account.clone(),
+            token_2022_program: token_2022_program.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ExactOutRouteInstructionAccountIndices {
+    pub token_program: usize,
+    pub user_transfer_authority: usize,
+    pub user_source_token_account: usize,
+    pub user_destination_token_account: usize,
+    pub destination_token_account: usize,
+    pub source_mint: usize,
+    pub destination_mint: usize,
+    pub platform_fee_account: usize,
+    pub token_2022_program: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ExactOutRoute {
+    type ArrangedAccountIndices = ExactOutRouteInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 11 {
+            return None;
+        }
+
+        Some(ExactOutRouteInstructionAccountIndices {
+            token_program: 0,
+            user_transfer_authority: 1,
+            user_source_token_account: 2,
+            user_destination_token_account: 3,
+            destination_token_account: 4,
+            source_mint: 5,
+            destination_mint: 6,
+            platform_fee_account: 7,
+            token_2022_program: 8,
+            event_authority: 9,
+            program: 10,
+            remaining_accounts: 11..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ExactOutRoute {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "token_program",
+            "user_transfer_authority",
+            "user_source_token_account",
+            "user_destination_token_account",
+            "destination_token_account",
+            "source_mint",
+            "destination_mint",
+            "platform_fee_account",
+            "token_2022_program",
+            "event_authority",
+            "program",
+        ]
+    }
+}
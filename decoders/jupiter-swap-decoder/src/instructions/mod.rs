@@ -48,15 +48,25 @@ pub enum JupiterSwapInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for JupiterSwapDecoder {
     type InstructionType = JupiterSwapInstruction;
 
-    fn decode_instruction(
+    fn decoder_name(&self) -> &'static str {
+        "jupiter_swap"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[super::PROGRAM_ID]
+    }
+
+    fn try_decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
-    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+    ) -> carbon_core::error::CarbonResult<
+        Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>>,
+    > {
         if !instruction.program_id.eq(&PROGRAM_ID) {
-            return None;
+            return Ok(None);
         }
 
-        carbon_core::try_decode_instructions!(instruction,
+        Ok(carbon_core::try_decode_instructions!(instruction,
             JupiterSwapInstruction::Claim => claim::Claim,
             JupiterSwapInstruction::ClaimToken => claim_token::ClaimToken,
             JupiterSwapInstruction::CreateOpenOrders => create_open_orders::CreateOpenOrders,
@@ -71,6 +81,6 @@ impl carbon_core::instruction::InstructionDecoder<'_> for JupiterSwapDecoder {
             JupiterSwapInstruction::SharedAccountsRouteWithTokenLedger => shared_accounts_route_with_token_ledger::SharedAccountsRouteWithTokenLedger,
             JupiterSwapInstruction::FeeEvent => fee_event::FeeEvent,
             JupiterSwapInstruction::SwapEvent => swap_event::SwapEvent,
-        )
+        ))
     }
 }
@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -25,6 +26,7 @@ pub struct RouteWithTokenLedgerInstructionAccounts {
     pub token_ledger: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for RouteWithTokenLedger {
@@ -33,7 +35,7 @@ impl carbon_core::deserialize::ArrangeAccounts for RouteWithTokenLedger {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [token_program, user_transfer_authority, user_source_token_account, user_destination_token_account, destination_token_account, destination_mint, platform_fee_account, token_ledger, event_authority, program, _remaining @ ..] =
+        let [token_program, user_transfer_authority, user_source_token_account, user_destination_token_account, destination_token_account, destination_mint, platform_fee_account, token_ledger, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -50,6 +52,106 @@ impl carbon_core::deserialize::ArrangeAccounts for RouteWithTokenLedger {
             token_ledger: token_ledger.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct RouteWithTokenLedgerInstructionAccountMetas {
+    pub token_program: solana_instruction::AccountMeta,
+    pub user_transfer_authority: solana_instruction::AccountMeta,
+    pub user_source_token_account: solana_instruction::AccountMeta,
+    pub user_destination_token_account: solana_instruction::AccountMeta,
+    pub destination_token_account: solana_instruction::AccountMeta,
+    pub destination_mint: solana_instruction::AccountMeta,
+    pub platform_fee_account: solana_instruction::AccountMeta,
+    pub token_ledger: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for RouteWithTokenLedger {
+    type ArrangedAccountMetas = RouteWithTokenLedgerInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [token_program, user_transfer_authority, user_source_token_account, user_destination_token_account, destination_token_account, destination_mint, platform_fee_account, token_ledger, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(RouteWithTokenLedgerInstructionAccountMetas {
+            token_program: token_program.clone(),
+            user_transfer_authority: user_transfer_authority.clone(),
+            user_source_token_account: user_source_token_account.clone(),
+            user_destination_token_account: user_destination_token_account.clone(),
+            destination_token_account: destination_token_account.clone(),
+            destination_mint: destination_mint.clone(),
+            platform_fee_account: platform_fee_account.clone(),
+            token_ledger: token_ledger.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct RouteWithTokenLedgerInstructionAccountIndices {
+    pub token_program: usize,
+    pub user_transfer_authority: usize,
+    pub user_source_token_account: usize,
+    pub user_destination_token_account: usize,
+    pub destination_token_account: usize,
+    pub destination_mint: usize,
+    pub platform_fee_account: usize,
+    pub token_ledger: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for RouteWithTokenLedger {
+    type ArrangedAccountIndices = RouteWithTokenLedgerInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 10 {
+            return None;
+        }
+
+        Some(RouteWithTokenLedgerInstructionAccountIndices {
+            token_program: 0,
+            user_transfer_authority: 1,
+            user_source_token_account: 2,
+            user_destination_token_account: 3,
+            destination_token_account: 4,
+            destination_mint: 5,
+            platform_fee_account: 6,
+            token_ledger: 7,
+            event_authority: 8,
+            program: 9,
+            remaining_accounts: 10..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for RouteWithTokenLedger {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "token_program",
+            "user_transfer_authority",
+            "user_source_token_account",
+            "user_destination_token_account",
+            "destination_token_account",
+            "destination_mint",
+            "platform_fee_account",
+            "token_ledger",
+            "event_authority",
+            "program",
+        ]
+    }
+}
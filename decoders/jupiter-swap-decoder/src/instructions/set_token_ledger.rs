@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -9,6 +10,7 @@ pub struct SetTokenLedger {}
 pub struct SetTokenLedgerInstructionAccounts {
     pub token_ledger: solana_pubkey::Pubkey,
     pub token_account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SetTokenLedger {
@@ -17,13 +19,68 @@ impl carbon_core::deserialize::ArrangeAccounts for SetTokenLedger {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [token_ledger, token_account, _remaining @ ..] = accounts else {
+        let [token_ledger, token_account, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(SetTokenLedgerInstructionAccounts {
             token_ledger: token_ledger.pubkey,
             token_account: token_account.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SetTokenLedgerInstructionAccountMetas {
+    pub token_ledger: solana_instruction::AccountMeta,
+    pub token_account: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SetTokenLedger {
+    type ArrangedAccountMetas = SetTokenLedgerInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [token_ledger, token_account, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(SetTokenLedgerInstructionAccountMetas {
+            token_ledger: token_ledger.clone(),
+            token_account: token_account.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SetTokenLedgerInstructionAccountIndices {
+    pub token_ledger: usize,
+    pub token_account: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SetTokenLedger {
+    type ArrangedAccountIndices = SetTokenLedgerInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(SetTokenLedgerInstructionAccountIndices {
+            token_ledger: 0,
+            token_account: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SetTokenLedger {
+    fn account_names() -> &'static [&'static str] {
+        &["token_ledger", "token_account"]
+    }
+}
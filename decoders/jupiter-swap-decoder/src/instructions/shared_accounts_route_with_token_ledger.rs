@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -30,6 +31,7 @@ pub struct SharedAccountsRouteWithTokenLedgerInstructionAccounts {
     pub token_ledger: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SharedAccountsRouteWithTokenLedger {
@@ -38,7 +40,7 @@ impl carbon_core::deserialize::ArrangeAccounts for SharedAccountsRouteWithTokenL
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [token_program, program_authority, user_transfer_authority, source_token_account, program_source_token_account, program_destination_token_account, destination_token_account, source_mint, destination_mint, platform_fee_account, token_2022_program, token_ledger, event_authority, program, _remaining @ ..] =
+        let [token_program, program_authority, user_transfer_authority, source_token_account, program_source_token_account, program_destination_token_account, destination_token_account, source_mint, destination_mint, platform_fee_account, token_2022_program, token_ledger, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -59,6 +61,128 @@ impl carbon_core::deserialize::ArrangeAccounts for SharedAccountsRouteWithTokenL
             token_ledger: token_ledger.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SharedAccountsRouteWithTokenLedgerInstructionAccountMetas {
+    pub token_program: solana_instruction::AccountMeta,
+    pub program_authority: solana_instruction::AccountMeta,
+    pub user_transfer_authority: solana_instruction::AccountMeta,
+    pub source_token_account: solana_instruction::AccountMeta,
+    pub program_source_token_account: solana_instruction::AccountMeta,
+    pub program_destination_token_account: solana_instruction::AccountMeta,
+    pub destination_token_account: solana_instruction::AccountMeta,
+    pub source_mint: solana_instruction::AccountMeta,
+    pub destination_mint: solana_instruction::AccountMeta,
+    pub platform_fee_account: solana_instruction::AccountMeta,
+    pub token_2022_program: solana_instruction::AccountMeta,
+    pub token_ledger: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SharedAccountsRouteWithTokenLedger {
+    type ArrangedAccountMetas = SharedAccountsRouteWithTokenLedgerInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [token_program, program_authority, user_transfer_authority, source_token_account, program_source_token_account, program_destination_token_account, destination_token_account, source_mint, destination_mint, platform_fee_account, token_2022_program, token_ledger, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(SharedAccountsRouteWithTokenLedgerInstructionAccountMetas {
+            token_program: token_program.clone(),
+            program_authority: program_authority.clone(),
+            user_transfer_authority: user_transfer_authority.clone(),
+            source_token_account: source_token_account.clone(),
+            program_source_token_account: program_source_token_account.clone(),
+            program_destination_token_account: program_destination_token_account.clone(),
+            destination_token_account: destination_token_account.clone(),
+            source_mint: source_mint.clone(),
+            destination_mint: destination_mint.clone(),
+            platform_fee_account: platform_fee_account.clone(),
+            token_2022_program: token_2022_program.clone(),
+            token_ledger: token_ledger.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SharedAccountsRouteWithTokenLedgerInstructionAccountIndices {
+    pub token_program: usize,
+    pub program_authority: usize,
+    pub user_transfer_authority: usize,
+    pub source_token_account: usize,
+    pub program_source_token_account: usize,
+    pub program_destination_token_account: usize,
+    pub destination_token_account: usize,
+    pub source_mint: usize,
+    pub destination_mint: usize,
+    pub platform_fee_account: usize,
+    pub token_2022_program: usize,
+    pub token_ledger: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SharedAccountsRouteWithTokenLedger {
+    type ArrangedAccountIndices = SharedAccountsRouteWithTokenLedgerInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 14 {
+            return None;
+        }
+
+        Some(
+            SharedAccountsRouteWithTokenLedgerInstructionAccountIndices {
+                token_program: 0,
+                program_authority: 1,
+                user_transfer_authority: 2,
+                source_token_account: 3,
+                program_source_token_account: 4,
+                program_destination_token_account: 5,
+                destination_token_account: 6,
+                source_mint: 7,
+                destination_mint: 8,
+                platform_fee_account: 9,
+                token_2022_program: 10,
+                token_ledger: 11,
+                event_authority: 12,
+                program: 13,
+                remaining_accounts: 14..accounts.len(),
+            },
+        )
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SharedAccountsRouteWithTokenLedger {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "token_program",
+            "program_authority",
+            "user_transfer_authority",
+            "source_token_account",
+            "program_source_token_account",
+            "program_destination_token_account",
+            "destination_token_account",
+            "source_mint",
+            "destination_mint",
+            "platform_fee_account",
+            "token_2022_program",
+            "token_ledger",
+            "event_authority",
+            "program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct DepositToFarmVaultInstructionAccounts {
     pub farm_vault: solana_pubkey::Pubkey,
     pub depositor_ata: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for DepositToFarmVault {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for DepositToFarmVault {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [depositor, farm_state, farm_vault, depositor_ata, token_program, _remaining @ ..] =
+        let [depositor, farm_state, farm_vault, depositor_ata, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -34,6 +36,81 @@ impl carbon_core::deserialize::ArrangeAccounts for DepositToFarmVault {
             farm_vault: farm_vault.pubkey,
             depositor_ata: depositor_ata.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DepositToFarmVaultInstructionAccountMetas {
+    pub depositor: solana_instruction::AccountMeta,
+    pub farm_state: solana_instruction::AccountMeta,
+    pub farm_vault: solana_instruction::AccountMeta,
+    pub depositor_ata: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for DepositToFarmVault {
+    type ArrangedAccountMetas = DepositToFarmVaultInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [depositor, farm_state, farm_vault, depositor_ata, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(DepositToFarmVaultInstructionAccountMetas {
+            depositor: depositor.clone(),
+            farm_state: farm_state.clone(),
+            farm_vault: farm_vault.clone(),
+            depositor_ata: depositor_ata.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DepositToFarmVaultInstructionAccountIndices {
+    pub depositor: usize,
+    pub farm_state: usize,
+    pub farm_vault: usize,
+    pub depositor_ata: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for DepositToFarmVault {
+    type ArrangedAccountIndices = DepositToFarmVaultInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(DepositToFarmVaultInstructionAccountIndices {
+            depositor: 0,
+            farm_state: 1,
+            farm_vault: 2,
+            depositor_ata: 3,
+            token_program: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for DepositToFarmVault {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "depositor",
+            "farm_state",
+            "farm_vault",
+            "depositor_ata",
+            "token_program",
+        ]
+    }
+}
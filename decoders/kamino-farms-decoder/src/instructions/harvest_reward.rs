@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -20,6 +21,7 @@ pub struct HarvestRewardInstructionAccounts {
     pub farm_vaults_authority: solana_pubkey::Pubkey,
     pub scope_prices: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for HarvestReward {
@@ -28,7 +30,7 @@ impl carbon_core::deserialize::ArrangeAccounts for HarvestReward {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [owner, user_state, farm_state, global_config, reward_mint, user_reward_ata, rewards_vault, rewards_treasury_vault, farm_vaults_authority, scope_prices, token_program, _remaining @ ..] =
+        let [owner, user_state, farm_state, global_config, reward_mint, user_reward_ata, rewards_vault, rewards_treasury_vault, farm_vaults_authority, scope_prices, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -46,6 +48,111 @@ impl carbon_core::deserialize::ArrangeAccounts for HarvestReward {
             farm_vaults_authority: farm_vaults_authority.pubkey,
             scope_prices: scope_prices.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct HarvestRewardInstructionAccountMetas {
+    pub owner: solana_instruction::AccountMeta,
+    pub user_state: solana_instruction::AccountMeta,
+    pub farm_state: solana_instruction::AccountMeta,
+    pub global_config: solana_instruction::AccountMeta,
+    pub reward_mint: solana_instruction::AccountMeta,
+    pub user_reward_ata: solana_instruction::AccountMeta,
+    pub rewards_vault: solana_instruction::AccountMeta,
+    pub rewards_treasury_vault: solana_instruction::AccountMeta,
+    pub farm_vaults_authority: solana_instruction::AccountMeta,
+    pub scope_prices: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for HarvestReward {
+    type ArrangedAccountMetas = HarvestRewardInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [owner, user_state, farm_state, global_config, reward_mint, user_reward_ata, rewards_vault, rewards_treasury_vault, farm_vaults_authority, scope_prices, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(HarvestRewardInstructionAccountMetas {
+            owner: owner.clone(),
+            user_state: user_state.clone(),
+            farm_state: farm_state.clone(),
+            global_config: global_config.clone(),
+            reward_mint: reward_mint.clone(),
+            user_reward_ata: user_reward_ata.clone(),
+            rewards_vault: rewards_vault.clone(),
+            rewards_treasury_vault: rewards_treasury_vault.clone(),
+            farm_vaults_authority: farm_vaults_authority.clone(),
+            scope_prices: scope_prices.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct HarvestRewardInstructionAccountIndices {
+    pub owner: usize,
+    pub user_state: usize,
+    pub farm_state: usize,
+    pub global_config: usize,
+    pub reward_mint: usize,
+    pub user_reward_ata: usize,
+    pub rewards_vault: usize,
+    pub rewards_treasury_vault: usize,
+    pub farm_vaults_authority: usize,
+    pub scope_prices: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for HarvestReward {
+    type ArrangedAccountIndices = HarvestRewardInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 11 {
+            return None;
+        }
+
+        Some(HarvestRewardInstructionAccountIndices {
+            owner: 0,
+            user_state: 1,
+            farm_state: 2,
+            global_config: 3,
+            reward_mint: 4,
+            user_reward_ata: 5,
+            rewards_vault: 6,
+            rewards_treasury_vault: 7,
+            farm_vaults_authority: 8,
+            scope_prices: 9,
+            token_program: 10,
+            remaining_accounts: 11..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for HarvestReward {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "owner",
+            "user_state",
+            "farm_state",
+            "global_config",
+            "reward_mint",
+            "user_reward_ata",
+            "rewards_vault",
+            "rewards_treasury_vault",
+            "farm_vaults_authority",
+            "scope_prices",
+            "token_program",
+        ]
+    }
+}
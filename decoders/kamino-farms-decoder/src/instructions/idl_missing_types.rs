@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -18,6 +19,7 @@ pub struct IdlMissingTypes {
 pub struct IdlMissingTypesInstructionAccounts {
     pub global_admin: solana_pubkey::Pubkey,
     pub global_config: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for IdlMissingTypes {
@@ -26,13 +28,68 @@ impl carbon_core::deserialize::ArrangeAccounts for IdlMissingTypes {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [global_admin, global_config, _remaining @ ..] = accounts else {
+        let [global_admin, global_config, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(IdlMissingTypesInstructionAccounts {
             global_admin: global_admin.pubkey,
             global_config: global_config.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct IdlMissingTypesInstructionAccountMetas {
+    pub global_admin: solana_instruction::AccountMeta,
+    pub global_config: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for IdlMissingTypes {
+    type ArrangedAccountMetas = IdlMissingTypesInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [global_admin, global_config, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(IdlMissingTypesInstructionAccountMetas {
+            global_admin: global_admin.clone(),
+            global_config: global_config.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct IdlMissingTypesInstructionAccountIndices {
+    pub global_admin: usize,
+    pub global_config: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for IdlMissingTypes {
+    type ArrangedAccountIndices = IdlMissingTypesInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(IdlMissingTypesInstructionAccountIndices {
+            global_admin: 0,
+            global_config: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for IdlMissingTypes {
+    fn account_names() -> &'static [&'static str] {
+        &["global_admin", "global_config"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -16,6 +17,7 @@ pub struct InitializeFarmInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeFarm {
@@ -24,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeFarm {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [farm_admin, farm_state, global_config, farm_vault, farm_vaults_authority, token_mint, token_program, system_program, rent, _remaining @ ..] =
+        let [farm_admin, farm_state, global_config, farm_vault, farm_vaults_authority, token_mint, token_program, system_program, rent, remaining @ ..] =
             accounts
         else {
             return None;
@@ -40,6 +42,101 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeFarm {
             token_program: token_program.pubkey,
             system_program: system_program.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeFarmInstructionAccountMetas {
+    pub farm_admin: solana_instruction::AccountMeta,
+    pub farm_state: solana_instruction::AccountMeta,
+    pub global_config: solana_instruction::AccountMeta,
+    pub farm_vault: solana_instruction::AccountMeta,
+    pub farm_vaults_authority: solana_instruction::AccountMeta,
+    pub token_mint: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeFarm {
+    type ArrangedAccountMetas = InitializeFarmInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [farm_admin, farm_state, global_config, farm_vault, farm_vaults_authority, token_mint, token_program, system_program, rent, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeFarmInstructionAccountMetas {
+            farm_admin: farm_admin.clone(),
+            farm_state: farm_state.clone(),
+            global_config: global_config.clone(),
+            farm_vault: farm_vault.clone(),
+            farm_vaults_authority: farm_vaults_authority.clone(),
+            token_mint: token_mint.clone(),
+            token_program: token_program.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeFarmInstructionAccountIndices {
+    pub farm_admin: usize,
+    pub farm_state: usize,
+    pub global_config: usize,
+    pub farm_vault: usize,
+    pub farm_vaults_authority: usize,
+    pub token_mint: usize,
+    pub token_program: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeFarm {
+    type ArrangedAccountIndices = InitializeFarmInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 9 {
+            return None;
+        }
+
+        Some(InitializeFarmInstructionAccountIndices {
+            farm_admin: 0,
+            farm_state: 1,
+            global_config: 2,
+            farm_vault: 3,
+            farm_vaults_authority: 4,
+            token_mint: 5,
+            token_program: 6,
+            system_program: 7,
+            rent: 8,
+            remaining_accounts: 9..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeFarm {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "farm_admin",
+            "farm_state",
+            "global_config",
+            "farm_vault",
+            "farm_vaults_authority",
+            "token_mint",
+            "token_program",
+            "system_program",
+            "rent",
+        ]
+    }
+}
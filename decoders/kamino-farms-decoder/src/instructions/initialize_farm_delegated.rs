@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct InitializeFarmDelegatedInstructionAccounts {
     pub farm_vaults_authority: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeFarmDelegated {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeFarmDelegated {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [farm_admin, farm_delegate, farm_state, global_config, farm_vaults_authority, system_program, rent, _remaining @ ..] =
+        let [farm_admin, farm_delegate, farm_state, global_config, farm_vaults_authority, system_program, rent, remaining @ ..] =
             accounts
         else {
             return None;
@@ -36,6 +38,91 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeFarmDelegated {
             farm_vaults_authority: farm_vaults_authority.pubkey,
             system_program: system_program.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeFarmDelegatedInstructionAccountMetas {
+    pub farm_admin: solana_instruction::AccountMeta,
+    pub farm_delegate: solana_instruction::AccountMeta,
+    pub farm_state: solana_instruction::AccountMeta,
+    pub global_config: solana_instruction::AccountMeta,
+    pub farm_vaults_authority: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeFarmDelegated {
+    type ArrangedAccountMetas = InitializeFarmDelegatedInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [farm_admin, farm_delegate, farm_state, global_config, farm_vaults_authority, system_program, rent, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeFarmDelegatedInstructionAccountMetas {
+            farm_admin: farm_admin.clone(),
+            farm_delegate: farm_delegate.clone(),
+            farm_state: farm_state.clone(),
+            global_config: global_config.clone(),
+            farm_vaults_authority: farm_vaults_authority.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeFarmDelegatedInstructionAccountIndices {
+    pub farm_admin: usize,
+    pub farm_delegate: usize,
+    pub farm_state: usize,
+    pub global_config: usize,
+    pub farm_vaults_authority: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeFarmDelegated {
+    type ArrangedAccountIndices = InitializeFarmDelegatedInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 7 {
+            return None;
+        }
+
+        Some(InitializeFarmDelegatedInstructionAccountIndices {
+            farm_admin: 0,
+            farm_delegate: 1,
+            farm_state: 2,
+            global_config: 3,
+            farm_vaults_authority: 4,
+            system_program: 5,
+            rent: 6,
+            remaining_accounts: 7..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeFarmDelegated {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "farm_admin",
+            "farm_delegate",
+            "farm_state",
+            "global_config",
+            "farm_vaults_authority",
+            "system_program",
+            "rent",
+        ]
+    }
+}
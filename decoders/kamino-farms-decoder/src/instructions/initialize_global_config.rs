@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +12,7 @@ pub struct InitializeGlobalConfigInstructionAccounts {
     pub global_config: solana_pubkey::Pubkey,
     pub treasury_vaults_authority: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeGlobalConfig {
@@ -19,7 +21,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeGlobalConfig {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [global_admin, global_config, treasury_vaults_authority, system_program, _remaining @ ..] =
+        let [global_admin, global_config, treasury_vaults_authority, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -30,6 +32,76 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeGlobalConfig {
             global_config: global_config.pubkey,
             treasury_vaults_authority: treasury_vaults_authority.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeGlobalConfigInstructionAccountMetas {
+    pub global_admin: solana_instruction::AccountMeta,
+    pub global_config: solana_instruction::AccountMeta,
+    pub treasury_vaults_authority: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeGlobalConfig {
+    type ArrangedAccountMetas = InitializeGlobalConfigInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [global_admin, global_config, treasury_vaults_authority, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeGlobalConfigInstructionAccountMetas {
+            global_admin: global_admin.clone(),
+            global_config: global_config.clone(),
+            treasury_vaults_authority: treasury_vaults_authority.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeGlobalConfigInstructionAccountIndices {
+    pub global_admin: usize,
+    pub global_config: usize,
+    pub treasury_vaults_authority: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeGlobalConfig {
+    type ArrangedAccountIndices = InitializeGlobalConfigInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(InitializeGlobalConfigInstructionAccountIndices {
+            global_admin: 0,
+            global_config: 1,
+            treasury_vaults_authority: 2,
+            system_program: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeGlobalConfig {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "global_admin",
+            "global_config",
+            "treasury_vaults_authority",
+            "system_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -18,6 +19,7 @@ pub struct InitializeRewardInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeReward {
@@ -26,7 +28,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeReward {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [farm_admin, farm_state, global_config, reward_mint, reward_vault, reward_treasury_vault, farm_vaults_authority, treasury_vaults_authority, token_program, system_program, rent, _remaining @ ..] =
+        let [farm_admin, farm_state, global_config, reward_mint, reward_vault, reward_treasury_vault, farm_vaults_authority, treasury_vaults_authority, token_program, system_program, rent, remaining @ ..] =
             accounts
         else {
             return None;
@@ -44,6 +46,111 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeReward {
             token_program: token_program.pubkey,
             system_program: system_program.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeRewardInstructionAccountMetas {
+    pub farm_admin: solana_instruction::AccountMeta,
+    pub farm_state: solana_instruction::AccountMeta,
+    pub global_config: solana_instruction::AccountMeta,
+    pub reward_mint: solana_instruction::AccountMeta,
+    pub reward_vault: solana_instruction::AccountMeta,
+    pub reward_treasury_vault: solana_instruction::AccountMeta,
+    pub farm_vaults_authority: solana_instruction::AccountMeta,
+    pub treasury_vaults_authority: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeReward {
+    type ArrangedAccountMetas = InitializeRewardInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [farm_admin, farm_state, global_config, reward_mint, reward_vault, reward_treasury_vault, farm_vaults_authority, treasury_vaults_authority, token_program, system_program, rent, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeRewardInstructionAccountMetas {
+            farm_admin: farm_admin.clone(),
+            farm_state: farm_state.clone(),
+            global_config: global_config.clone(),
+            reward_mint: reward_mint.clone(),
+            reward_vault: reward_vault.clone(),
+            reward_treasury_vault: reward_treasury_vault.clone(),
+            farm_vaults_authority: farm_vaults_authority.clone(),
+            treasury_vaults_authority: treasury_vaults_authority.clone(),
+            token_program: token_program.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeRewardInstructionAccountIndices {
+    pub farm_admin: usize,
+    pub farm_state: usize,
+    pub global_config: usize,
+    pub reward_mint: usize,
+    pub reward_vault: usize,
+    pub reward_treasury_vault: usize,
+    pub farm_vaults_authority: usize,
+    pub treasury_vaults_authority: usize,
+    pub token_program: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeReward {
+    type ArrangedAccountIndices = InitializeRewardInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 11 {
+            return None;
+        }
+
+        Some(InitializeRewardInstructionAccountIndices {
+            farm_admin: 0,
+            farm_state: 1,
+            global_config: 2,
+            reward_mint: 3,
+            reward_vault: 4,
+            reward_treasury_vault: 5,
+            farm_vaults_authority: 6,
+            treasury_vaults_authority: 7,
+            token_program: 8,
+            system_program: 9,
+            rent: 10,
+            remaining_accounts: 11..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeReward {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "farm_admin",
+            "farm_state",
+            "global_config",
+            "reward_mint",
+            "reward_vault",
+            "reward_treasury_vault",
+            "farm_vaults_authority",
+            "treasury_vaults_authority",
+            "token_program",
+            "system_program",
+            "rent",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -15,6 +16,7 @@ pub struct InitializeUserInstructionAccounts {
     pub farm_state: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeUser {
@@ -23,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeUser {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [authority, payer, owner, delegatee, user_state, farm_state, system_program, rent, _remaining @ ..] =
+        let [authority, payer, owner, delegatee, user_state, farm_state, system_program, rent, remaining @ ..] =
             accounts
         else {
             return None;
@@ -38,6 +40,96 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeUser {
             farm_state: farm_state.pubkey,
             system_program: system_program.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeUserInstructionAccountMetas {
+    pub authority: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub delegatee: solana_instruction::AccountMeta,
+    pub user_state: solana_instruction::AccountMeta,
+    pub farm_state: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeUser {
+    type ArrangedAccountMetas = InitializeUserInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [authority, payer, owner, delegatee, user_state, farm_state, system_program, rent, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeUserInstructionAccountMetas {
+            authority: authority.clone(),
+            payer: payer.clone(),
+            owner: owner.clone(),
+            delegatee: delegatee.clone(),
+            user_state: user_state.clone(),
+            farm_state: farm_state.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeUserInstructionAccountIndices {
+    pub authority: usize,
+    pub payer: usize,
+    pub owner: usize,
+    pub delegatee: usize,
+    pub user_state: usize,
+    pub farm_state: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeUser {
+    type ArrangedAccountIndices = InitializeUserInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(InitializeUserInstructionAccountIndices {
+            authority: 0,
+            payer: 1,
+            owner: 2,
+            delegatee: 3,
+            user_state: 4,
+            farm_state: 5,
+            system_program: 6,
+            rent: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeUser {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "authority",
+            "payer",
+            "owner",
+            "delegatee",
+            "user_state",
+            "farm_state",
+            "system_program",
+            "rent",
+        ]
+    }
+}
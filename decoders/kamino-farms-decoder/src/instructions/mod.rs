@@ -66,14 +66,24 @@ pub enum KaminoFarmsInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for KaminoFarmsDecoder {
     type InstructionType = KaminoFarmsInstruction;
 
-    fn decode_instruction(
+    fn decoder_name(&self) -> &'static str {
+        "kamino_farms"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[super::PROGRAM_ID]
+    }
+
+    fn try_decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
-    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+    ) -> carbon_core::error::CarbonResult<
+        Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>>,
+    > {
         if !instruction.program_id.eq(&PROGRAM_ID) {
-            return None;
+            return Ok(None);
         }
-        carbon_core::try_decode_instructions!(instruction,
+        Ok(carbon_core::try_decode_instructions!(instruction,
             KaminoFarmsInstruction::InitializeGlobalConfig => initialize_global_config::InitializeGlobalConfig,
             KaminoFarmsInstruction::UpdateGlobalConfig => update_global_config::UpdateGlobalConfig,
             KaminoFarmsInstruction::InitializeFarm => initialize_farm::InitializeFarm,
@@ -99,6 +109,6 @@ impl carbon_core::instruction::InstructionDecoder<'_> for KaminoFarmsDecoder {
             KaminoFarmsInstruction::UpdateGlobalConfigAdmin => update_global_config_admin::UpdateGlobalConfigAdmin,
             KaminoFarmsInstruction::WithdrawReward => withdraw_reward::WithdrawReward,
             KaminoFarmsInstruction::IdlMissingTypes => idl_missing_types::IdlMissingTypes,
-        )
+        ))
     }
 }
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -9,6 +10,7 @@ pub struct RefreshFarm {}
 pub struct RefreshFarmInstructionAccounts {
     pub farm_state: solana_pubkey::Pubkey,
     pub scope_prices: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for RefreshFarm {
@@ -17,13 +19,68 @@ impl carbon_core::deserialize::ArrangeAccounts for RefreshFarm {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [farm_state, scope_prices, _remaining @ ..] = accounts else {
+        let [farm_state, scope_prices, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(RefreshFarmInstructionAccounts {
             farm_state: farm_state.pubkey,
             scope_prices: scope_prices.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct RefreshFarmInstructionAccountMetas {
+    pub farm_state: solana_instruction::AccountMeta,
+    pub scope_prices: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for RefreshFarm {
+    type ArrangedAccountMetas = RefreshFarmInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [farm_state, scope_prices, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(RefreshFarmInstructionAccountMetas {
+            farm_state: farm_state.clone(),
+            scope_prices: scope_prices.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct RefreshFarmInstructionAccountIndices {
+    pub farm_state: usize,
+    pub scope_prices: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for RefreshFarm {
+    type ArrangedAccountIndices = RefreshFarmInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(RefreshFarmInstructionAccountIndices {
+            farm_state: 0,
+            scope_prices: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for RefreshFarm {
+    fn account_names() -> &'static [&'static str] {
+        &["farm_state", "scope_prices"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -10,6 +11,7 @@ pub struct RefreshUserStateInstructionAccounts {
     pub user_state: solana_pubkey::Pubkey,
     pub farm_state: solana_pubkey::Pubkey,
     pub scope_prices: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for RefreshUserState {
@@ -18,7 +20,7 @@ impl carbon_core::deserialize::ArrangeAccounts for RefreshUserState {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [user_state, farm_state, scope_prices, _remaining @ ..] = accounts else {
+        let [user_state, farm_state, scope_prices, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -26,6 +28,65 @@ impl carbon_core::deserialize::ArrangeAccounts for RefreshUserState {
             user_state: user_state.pubkey,
             farm_state: farm_state.pubkey,
             scope_prices: scope_prices.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct RefreshUserStateInstructionAccountMetas {
+    pub user_state: solana_instruction::AccountMeta,
+    pub farm_state: solana_instruction::AccountMeta,
+    pub scope_prices: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for RefreshUserState {
+    type ArrangedAccountMetas = RefreshUserStateInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [user_state, farm_state, scope_prices, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(RefreshUserStateInstructionAccountMetas {
+            user_state: user_state.clone(),
+            farm_state: farm_state.clone(),
+            scope_prices: scope_prices.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct RefreshUserStateInstructionAccountIndices {
+    pub user_state: usize,
+    pub farm_state: usize,
+    pub scope_prices: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for RefreshUserState {
+    type ArrangedAccountIndices = RefreshUserStateInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(RefreshUserStateInstructionAccountIndices {
+            user_state: 0,
+            farm_state: 1,
+            scope_prices: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for RefreshUserState {
+    fn account_names() -> &'static [&'static str] {
+        &["user_state", "farm_state", "scope_prices"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +14,7 @@ pub struct RewardUserOnceInstructionAccounts {
     pub farm_admin: solana_pubkey::Pubkey,
     pub farm_state: solana_pubkey::Pubkey,
     pub user_state: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for RewardUserOnce {
@@ -21,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for RewardUserOnce {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [farm_admin, farm_state, user_state, _remaining @ ..] = accounts else {
+        let [farm_admin, farm_state, user_state, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -29,6 +31,65 @@ impl carbon_core::deserialize::ArrangeAccounts for RewardUserOnce {
             farm_admin: farm_admin.pubkey,
             farm_state: farm_state.pubkey,
             user_state: user_state.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct RewardUserOnceInstructionAccountMetas {
+    pub farm_admin: solana_instruction::AccountMeta,
+    pub farm_state: solana_instruction::AccountMeta,
+    pub user_state: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for RewardUserOnce {
+    type ArrangedAccountMetas = RewardUserOnceInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [farm_admin, farm_state, user_state, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(RewardUserOnceInstructionAccountMetas {
+            farm_admin: farm_admin.clone(),
+            farm_state: farm_state.clone(),
+            user_state: user_state.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct RewardUserOnceInstructionAccountIndices {
+    pub farm_admin: usize,
+    pub farm_state: usize,
+    pub user_state: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for RewardUserOnce {
+    type ArrangedAccountIndices = RewardUserOnceInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(RewardUserOnceInstructionAccountIndices {
+            farm_admin: 0,
+            farm_state: 1,
+            user_state: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for RewardUserOnce {
+    fn account_names() -> &'static [&'static str] {
+        &["farm_admin", "farm_state", "user_state"]
+    }
+}
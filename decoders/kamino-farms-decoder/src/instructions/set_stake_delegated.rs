@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +13,7 @@ pub struct SetStakeDelegatedInstructionAccounts {
     pub delegate_authority: solana_pubkey::Pubkey,
     pub user_state: solana_pubkey::Pubkey,
     pub farm_state: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SetStakeDelegated {
@@ -20,7 +22,7 @@ impl carbon_core::deserialize::ArrangeAccounts for SetStakeDelegated {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [delegate_authority, user_state, farm_state, _remaining @ ..] = accounts else {
+        let [delegate_authority, user_state, farm_state, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +30,65 @@ impl carbon_core::deserialize::ArrangeAccounts for SetStakeDelegated {
             delegate_authority: delegate_authority.pubkey,
             user_state: user_state.pubkey,
             farm_state: farm_state.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SetStakeDelegatedInstructionAccountMetas {
+    pub delegate_authority: solana_instruction::AccountMeta,
+    pub user_state: solana_instruction::AccountMeta,
+    pub farm_state: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SetStakeDelegated {
+    type ArrangedAccountMetas = SetStakeDelegatedInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [delegate_authority, user_state, farm_state, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(SetStakeDelegatedInstructionAccountMetas {
+            delegate_authority: delegate_authority.clone(),
+            user_state: user_state.clone(),
+            farm_state: farm_state.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SetStakeDelegatedInstructionAccountIndices {
+    pub delegate_authority: usize,
+    pub user_state: usize,
+    pub farm_state: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SetStakeDelegated {
+    type ArrangedAccountIndices = SetStakeDelegatedInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(SetStakeDelegatedInstructionAccountIndices {
+            delegate_authority: 0,
+            user_state: 1,
+            farm_state: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SetStakeDelegated {
+    fn account_names() -> &'static [&'static str] {
+        &["delegate_authority", "user_state", "farm_state"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -17,6 +18,7 @@ pub struct StakeInstructionAccounts {
     pub token_mint: solana_pubkey::Pubkey,
     pub scope_prices: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Stake {
@@ -25,7 +27,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Stake {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [owner, user_state, farm_state, farm_vault, user_ata, token_mint, scope_prices, token_program, _remaining @ ..] =
+        let [owner, user_state, farm_state, farm_vault, user_ata, token_mint, scope_prices, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -40,6 +42,96 @@ impl carbon_core::deserialize::ArrangeAccounts for Stake {
             token_mint: token_mint.pubkey,
             scope_prices: scope_prices.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct StakeInstructionAccountMetas {
+    pub owner: solana_instruction::AccountMeta,
+    pub user_state: solana_instruction::AccountMeta,
+    pub farm_state: solana_instruction::AccountMeta,
+    pub farm_vault: solana_instruction::AccountMeta,
+    pub user_ata: solana_instruction::AccountMeta,
+    pub token_mint: solana_instruction::AccountMeta,
+    pub scope_prices: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Stake {
+    type ArrangedAccountMetas = StakeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [owner, user_state, farm_state, farm_vault, user_ata, token_mint, scope_prices, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(StakeInstructionAccountMetas {
+            owner: owner.clone(),
+            user_state: user_state.clone(),
+            farm_state: farm_state.clone(),
+            farm_vault: farm_vault.clone(),
+            user_ata: user_ata.clone(),
+            token_mint: token_mint.clone(),
+            scope_prices: scope_prices.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct StakeInstructionAccountIndices {
+    pub owner: usize,
+    pub user_state: usize,
+    pub farm_state: usize,
+    pub farm_vault: usize,
+    pub user_ata: usize,
+    pub token_mint: usize,
+    pub scope_prices: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Stake {
+    type ArrangedAccountIndices = StakeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(StakeInstructionAccountIndices {
+            owner: 0,
+            user_state: 1,
+            farm_state: 2,
+            farm_vault: 3,
+            user_ata: 4,
+            token_mint: 5,
+            scope_prices: 6,
+            token_program: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Stake {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "owner",
+            "user_state",
+            "farm_state",
+            "farm_vault",
+            "user_ata",
+            "token_mint",
+            "scope_prices",
+            "token_program",
+        ]
+    }
+}
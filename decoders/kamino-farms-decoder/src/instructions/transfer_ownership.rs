@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +12,7 @@ pub struct TransferOwnership {
 pub struct TransferOwnershipInstructionAccounts {
     pub owner: solana_pubkey::Pubkey,
     pub user_state: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for TransferOwnership {
@@ -19,13 +21,68 @@ impl carbon_core::deserialize::ArrangeAccounts for TransferOwnership {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [owner, user_state, _remaining @ ..] = accounts else {
+        let [owner, user_state, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(TransferOwnershipInstructionAccounts {
             owner: owner.pubkey,
             user_state: user_state.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct TransferOwnershipInstructionAccountMetas {
+    pub owner: solana_instruction::AccountMeta,
+    pub user_state: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for TransferOwnership {
+    type ArrangedAccountMetas = TransferOwnershipInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [owner, user_state, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(TransferOwnershipInstructionAccountMetas {
+            owner: owner.clone(),
+            user_state: user_state.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct TransferOwnershipInstructionAccountIndices {
+    pub owner: usize,
+    pub user_state: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for TransferOwnership {
+    type ArrangedAccountIndices = TransferOwnershipInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(TransferOwnershipInstructionAccountIndices {
+            owner: 0,
+            user_state: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for TransferOwnership {
+    fn account_names() -> &'static [&'static str] {
+        &["owner", "user_state"]
+    }
+}
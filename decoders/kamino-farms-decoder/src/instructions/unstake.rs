@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +14,7 @@ pub struct UnstakeInstructionAccounts {
     pub user_state: solana_pubkey::Pubkey,
     pub farm_state: solana_pubkey::Pubkey,
     pub scope_prices: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Unstake {
@@ -21,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Unstake {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [owner, user_state, farm_state, scope_prices, _remaining @ ..] = accounts else {
+        let [owner, user_state, farm_state, scope_prices, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -30,6 +32,69 @@ impl carbon_core::deserialize::ArrangeAccounts for Unstake {
             user_state: user_state.pubkey,
             farm_state: farm_state.pubkey,
             scope_prices: scope_prices.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UnstakeInstructionAccountMetas {
+    pub owner: solana_instruction::AccountMeta,
+    pub user_state: solana_instruction::AccountMeta,
+    pub farm_state: solana_instruction::AccountMeta,
+    pub scope_prices: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Unstake {
+    type ArrangedAccountMetas = UnstakeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [owner, user_state, farm_state, scope_prices, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UnstakeInstructionAccountMetas {
+            owner: owner.clone(),
+            user_state: user_state.clone(),
+            farm_state: farm_state.clone(),
+            scope_prices: scope_prices.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UnstakeInstructionAccountIndices {
+    pub owner: usize,
+    pub user_state: usize,
+    pub farm_state: usize,
+    pub scope_prices: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Unstake {
+    type ArrangedAccountIndices = UnstakeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(UnstakeInstructionAccountIndices {
+            owner: 0,
+            user_state: 1,
+            farm_state: 2,
+            scope_prices: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Unstake {
+    fn account_names() -> &'static [&'static str] {
+        &["owner", "user_state", "farm_state", "scope_prices"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -9,6 +10,7 @@ pub struct UpdateFarmAdmin {}
 pub struct UpdateFarmAdminInstructionAccounts {
     pub pending_farm_admin: solana_pubkey::Pubkey,
     pub farm_state: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateFarmAdmin {
@@ -17,13 +19,68 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateFarmAdmin {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [pending_farm_admin, farm_state, _remaining @ ..] = accounts else {
+        let [pending_farm_admin, farm_state, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(UpdateFarmAdminInstructionAccounts {
             pending_farm_admin: pending_farm_admin.pubkey,
             farm_state: farm_state.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateFarmAdminInstructionAccountMetas {
+    pub pending_farm_admin: solana_instruction::AccountMeta,
+    pub farm_state: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateFarmAdmin {
+    type ArrangedAccountMetas = UpdateFarmAdminInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [pending_farm_admin, farm_state, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateFarmAdminInstructionAccountMetas {
+            pending_farm_admin: pending_farm_admin.clone(),
+            farm_state: farm_state.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateFarmAdminInstructionAccountIndices {
+    pub pending_farm_admin: usize,
+    pub farm_state: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateFarmAdmin {
+    type ArrangedAccountIndices = UpdateFarmAdminInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(UpdateFarmAdminInstructionAccountIndices {
+            pending_farm_admin: 0,
+            farm_state: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateFarmAdmin {
+    fn account_names() -> &'static [&'static str] {
+        &["pending_farm_admin", "farm_state"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +14,7 @@ pub struct UpdateFarmConfigInstructionAccounts {
     pub signer: solana_pubkey::Pubkey,
     pub farm_state: solana_pubkey::Pubkey,
     pub scope_prices: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateFarmConfig {
@@ -21,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateFarmConfig {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [signer, farm_state, scope_prices, _remaining @ ..] = accounts else {
+        let [signer, farm_state, scope_prices, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -29,6 +31,65 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateFarmConfig {
             signer: signer.pubkey,
             farm_state: farm_state.pubkey,
             scope_prices: scope_prices.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateFarmConfigInstructionAccountMetas {
+    pub signer: solana_instruction::AccountMeta,
+    pub farm_state: solana_instruction::AccountMeta,
+    pub scope_prices: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateFarmConfig {
+    type ArrangedAccountMetas = UpdateFarmConfigInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [signer, farm_state, scope_prices, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateFarmConfigInstructionAccountMetas {
+            signer: signer.clone(),
+            farm_state: farm_state.clone(),
+            scope_prices: scope_prices.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateFarmConfigInstructionAccountIndices {
+    pub signer: usize,
+    pub farm_state: usize,
+    pub scope_prices: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateFarmConfig {
+    type ArrangedAccountIndices = UpdateFarmConfigInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(UpdateFarmConfigInstructionAccountIndices {
+            signer: 0,
+            farm_state: 1,
+            scope_prices: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateFarmConfig {
+    fn account_names() -> &'static [&'static str] {
+        &["signer", "farm_state", "scope_prices"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +13,7 @@ pub struct UpdateGlobalConfig {
 pub struct UpdateGlobalConfigInstructionAccounts {
     pub global_admin: solana_pubkey::Pubkey,
     pub global_config: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateGlobalConfig {
@@ -20,13 +22,68 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateGlobalConfig {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [global_admin, global_config, _remaining @ ..] = accounts else {
+        let [global_admin, global_config, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(UpdateGlobalConfigInstructionAccounts {
             global_admin: global_admin.pubkey,
             global_config: global_config.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateGlobalConfigInstructionAccountMetas {
+    pub global_admin: solana_instruction::AccountMeta,
+    pub global_config: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateGlobalConfig {
+    type ArrangedAccountMetas = UpdateGlobalConfigInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [global_admin, global_config, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateGlobalConfigInstructionAccountMetas {
+            global_admin: global_admin.clone(),
+            global_config: global_config.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateGlobalConfigInstructionAccountIndices {
+    pub global_admin: usize,
+    pub global_config: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateGlobalConfig {
+    type ArrangedAccountIndices = UpdateGlobalConfigInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(UpdateGlobalConfigInstructionAccountIndices {
+            global_admin: 0,
+            global_config: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateGlobalConfig {
+    fn account_names() -> &'static [&'static str] {
+        &["global_admin", "global_config"]
+    }
+}
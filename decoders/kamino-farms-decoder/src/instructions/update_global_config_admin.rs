@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -9,6 +10,7 @@ pub struct UpdateGlobalConfigAdmin {}
 pub struct UpdateGlobalConfigAdminInstructionAccounts {
     pub pending_global_admin: solana_pubkey::Pubkey,
     pub global_config: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateGlobalConfigAdmin {
@@ -17,13 +19,68 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateGlobalConfigAdmin {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [pending_global_admin, global_config, _remaining @ ..] = accounts else {
+        let [pending_global_admin, global_config, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(UpdateGlobalConfigAdminInstructionAccounts {
             pending_global_admin: pending_global_admin.pubkey,
             global_config: global_config.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateGlobalConfigAdminInstructionAccountMetas {
+    pub pending_global_admin: solana_instruction::AccountMeta,
+    pub global_config: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateGlobalConfigAdmin {
+    type ArrangedAccountMetas = UpdateGlobalConfigAdminInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [pending_global_admin, global_config, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateGlobalConfigAdminInstructionAccountMetas {
+            pending_global_admin: pending_global_admin.clone(),
+            global_config: global_config.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateGlobalConfigAdminInstructionAccountIndices {
+    pub pending_global_admin: usize,
+    pub global_config: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateGlobalConfigAdmin {
+    type ArrangedAccountIndices = UpdateGlobalConfigAdminInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(UpdateGlobalConfigAdminInstructionAccountIndices {
+            pending_global_admin: 0,
+            global_config: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateGlobalConfigAdmin {
+    fn account_names() -> &'static [&'static str] {
+        &["pending_global_admin", "global_config"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -15,6 +16,7 @@ pub struct WithdrawFromFarmVaultInstructionAccounts {
     pub farm_vault: solana_pubkey::Pubkey,
     pub farm_vaults_authority: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for WithdrawFromFarmVault {
@@ -23,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawFromFarmVault {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [withdraw_authority, farm_state, withdrawer_token_account, farm_vault, farm_vaults_authority, token_program, _remaining @ ..] =
+        let [withdraw_authority, farm_state, withdrawer_token_account, farm_vault, farm_vaults_authority, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -36,6 +38,86 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawFromFarmVault {
             farm_vault: farm_vault.pubkey,
             farm_vaults_authority: farm_vaults_authority.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct WithdrawFromFarmVaultInstructionAccountMetas {
+    pub withdraw_authority: solana_instruction::AccountMeta,
+    pub farm_state: solana_instruction::AccountMeta,
+    pub withdrawer_token_account: solana_instruction::AccountMeta,
+    pub farm_vault: solana_instruction::AccountMeta,
+    pub farm_vaults_authority: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for WithdrawFromFarmVault {
+    type ArrangedAccountMetas = WithdrawFromFarmVaultInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [withdraw_authority, farm_state, withdrawer_token_account, farm_vault, farm_vaults_authority, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(WithdrawFromFarmVaultInstructionAccountMetas {
+            withdraw_authority: withdraw_authority.clone(),
+            farm_state: farm_state.clone(),
+            withdrawer_token_account: withdrawer_token_account.clone(),
+            farm_vault: farm_vault.clone(),
+            farm_vaults_authority: farm_vaults_authority.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct WithdrawFromFarmVaultInstructionAccountIndices {
+    pub withdraw_authority: usize,
+    pub farm_state: usize,
+    pub withdrawer_token_account: usize,
+    pub farm_vault: usize,
+    pub farm_vaults_authority: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for WithdrawFromFarmVault {
+    type ArrangedAccountIndices = WithdrawFromFarmVaultInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(WithdrawFromFarmVaultInstructionAccountIndices {
+            withdraw_authority: 0,
+            farm_state: 1,
+            withdrawer_token_account: 2,
+            farm_vault: 3,
+            farm_vaults_authority: 4,
+            token_program: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for WithdrawFromFarmVault {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "withdraw_authority",
+            "farm_state",
+            "withdrawer_token_account",
+            "farm_vault",
+            "farm_vaults_authority",
+            "token_program",
+        ]
+    }
+}
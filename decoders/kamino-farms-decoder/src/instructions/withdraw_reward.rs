@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -18,6 +19,7 @@ pub struct WithdrawRewardInstructionAccounts {
     pub admin_reward_token_ata: solana_pubkey::Pubkey,
     pub scope_prices: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for WithdrawReward {
@@ -26,7 +28,7 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawReward {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [farm_admin, farm_state, reward_mint, reward_vault, farm_vaults_authority, admin_reward_token_ata, scope_prices, token_program, _remaining @ ..] =
+        let [farm_admin, farm_state, reward_mint, reward_vault, farm_vaults_authority, admin_reward_token_ata, scope_prices, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -41,6 +43,96 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawReward {
             admin_reward_token_ata: admin_reward_token_ata.pubkey,
             scope_prices: scope_prices.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct WithdrawRewardInstructionAccountMetas {
+    pub farm_admin: solana_instruction::AccountMeta,
+    pub farm_state: solana_instruction::AccountMeta,
+    pub reward_mint: solana_instruction::AccountMeta,
+    pub reward_vault: solana_instruction::AccountMeta,
+    pub farm_vaults_authority: solana_instruction::AccountMeta,
+    pub admin_reward_token_ata: solana_instruction::AccountMeta,
+    pub scope_prices: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for WithdrawReward {
+    type ArrangedAccountMetas = WithdrawRewardInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [farm_admin, farm_state, reward_mint, reward_vault, farm_vaults_authority, admin_reward_token_ata, scope_prices, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(WithdrawRewardInstructionAccountMetas {
+            farm_admin: farm_admin.clone(),
+            farm_state: farm_state.clone(),
+            reward_mint: reward_mint.clone(),
+            reward_vault: reward_vault.clone(),
+            farm_vaults_authority: farm_vaults_authority.clone(),
+            admin_reward_token_ata: admin_reward_token_ata.clone(),
+            scope_prices: scope_prices.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct WithdrawRewardInstructionAccountIndices {
+    pub farm_admin: usize,
+    pub farm_state: usize,
+    pub reward_mint: usize,
+    pub reward_vault: usize,
+    pub farm_vaults_authority: usize,
+    pub admin_reward_token_ata: usize,
+    pub scope_prices: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for WithdrawReward {
+    type ArrangedAccountIndices = WithdrawRewardInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(WithdrawRewardInstructionAccountIndices {
+            farm_admin: 0,
+            farm_state: 1,
+            reward_mint: 2,
+            reward_vault: 3,
+            farm_vaults_authority: 4,
+            admin_reward_token_ata: 5,
+            scope_prices: 6,
+            token_program: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for WithdrawReward {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "farm_admin",
+            "farm_state",
+            "reward_mint",
+            "reward_vault",
+            "farm_vaults_authority",
+            "admin_reward_token_ata",
+            "scope_prices",
+            "token_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +14,7 @@ pub struct WithdrawSlashedAmountInstructionAccounts {
     pub farm_vault: solana_pubkey::Pubkey,
     pub farm_vaults_authority: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for WithdrawSlashedAmount {
@@ -21,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawSlashedAmount {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [crank, farm_state, slashed_amount_spill_address, farm_vault, farm_vaults_authority, token_program, _remaining @ ..] =
+        let [crank, farm_state, slashed_amount_spill_address, farm_vault, farm_vaults_authority, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -34,6 +36,86 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawSlashedAmount {
             farm_vault: farm_vault.pubkey,
             farm_vaults_authority: farm_vaults_authority.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct WithdrawSlashedAmountInstructionAccountMetas {
+    pub crank: solana_instruction::AccountMeta,
+    pub farm_state: solana_instruction::AccountMeta,
+    pub slashed_amount_spill_address: solana_instruction::AccountMeta,
+    pub farm_vault: solana_instruction::AccountMeta,
+    pub farm_vaults_authority: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for WithdrawSlashedAmount {
+    type ArrangedAccountMetas = WithdrawSlashedAmountInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [crank, farm_state, slashed_amount_spill_address, farm_vault, farm_vaults_authority, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(WithdrawSlashedAmountInstructionAccountMetas {
+            crank: crank.clone(),
+            farm_state: farm_state.clone(),
+            slashed_amount_spill_address: slashed_amount_spill_address.clone(),
+            farm_vault: farm_vault.clone(),
+            farm_vaults_authority: farm_vaults_authority.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct WithdrawSlashedAmountInstructionAccountIndices {
+    pub crank: usize,
+    pub farm_state: usize,
+    pub slashed_amount_spill_address: usize,
+    pub farm_vault: usize,
+    pub farm_vaults_authority: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for WithdrawSlashedAmount {
+    type ArrangedAccountIndices = WithdrawSlashedAmountInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(WithdrawSlashedAmountInstructionAccountIndices {
+            crank: 0,
+            farm_state: 1,
+            slashed_amount_spill_address: 2,
+            farm_vault: 3,
+            farm_vaults_authority: 4,
+            token_program: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for WithdrawSlashedAmount {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "crank",
+            "farm_state",
+            "slashed_amount_spill_address",
+            "farm_vault",
+            "farm_vaults_authority",
+            "token_program",
+        ]
+    }
+}
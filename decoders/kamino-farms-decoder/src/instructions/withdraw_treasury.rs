@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -16,6 +17,7 @@ pub struct WithdrawTreasuryInstructionAccounts {
     pub treasury_vault_authority: solana_pubkey::Pubkey,
     pub withdraw_destination_token_account: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for WithdrawTreasury {
@@ -24,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawTreasury {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [global_admin, global_config, reward_mint, reward_treasury_vault, treasury_vault_authority, withdraw_destination_token_account, token_program, _remaining @ ..] =
+        let [global_admin, global_config, reward_mint, reward_treasury_vault, treasury_vault_authority, withdraw_destination_token_account, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -38,6 +40,91 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawTreasury {
             treasury_vault_authority: treasury_vault_authority.pubkey,
             withdraw_destination_token_account: withdraw_destination_token_account.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct WithdrawTreasuryInstructionAccountMetas {
+    pub global_admin: solana_instruction::AccountMeta,
+    pub global_config: solana_instruction::AccountMeta,
+    pub reward_mint: solana_instruction::AccountMeta,
+    pub reward_treasury_vault: solana_instruction::AccountMeta,
+    pub treasury_vault_authority: solana_instruction::AccountMeta,
+    pub withdraw_destination_token_account: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for WithdrawTreasury {
+    type ArrangedAccountMetas = WithdrawTreasuryInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [global_admin, global_config, reward_mint, reward_treasury_vault, treasury_vault_authority, withdraw_destination_token_account, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(WithdrawTreasuryInstructionAccountMetas {
+            global_admin: global_admin.clone(),
+            global_config: global_config.clone(),
+            reward_mint: reward_mint.clone(),
+            reward_treasury_vault: reward_treasury_vault.clone(),
+            treasury_vault_authority: treasury_vault_authority.clone(),
+            withdraw_destination_token_account: withdraw_destination_token_account.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct WithdrawTreasuryInstructionAccountIndices {
+    pub global_admin: usize,
+    pub global_config: usize,
+    pub reward_mint: usize,
+    pub reward_treasury_vault: usize,
+    pub treasury_vault_authority: usize,
+    pub withdraw_destination_token_account: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for WithdrawTreasury {
+    type ArrangedAccountIndices = WithdrawTreasuryInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 7 {
+            return None;
+        }
+
+        Some(WithdrawTreasuryInstructionAccountIndices {
+            global_admin: 0,
+            global_config: 1,
+            reward_mint: 2,
+            reward_treasury_vault: 3,
+            treasury_vault_authority: 4,
+            withdraw_destination_token_account: 5,
+            token_program: 6,
+            remaining_accounts: 7..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for WithdrawTreasury {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "global_admin",
+            "global_config",
+            "reward_mint",
+            "reward_treasury_vault",
+            "treasury_vault_authority",
+            "withdraw_destination_token_account",
+            "token_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct WithdrawUnstakedDepositsInstructionAccounts {
     pub farm_vault: solana_pubkey::Pubkey,
     pub farm_vaults_authority: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for WithdrawUnstakedDeposits {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawUnstakedDeposits {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [owner, user_state, farm_state, user_ata, farm_vault, farm_vaults_authority, token_program, _remaining @ ..] =
+        let [owner, user_state, farm_state, user_ata, farm_vault, farm_vaults_authority, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -36,6 +38,91 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawUnstakedDeposits {
             farm_vault: farm_vault.pubkey,
             farm_vaults_authority: farm_vaults_authority.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct WithdrawUnstakedDepositsInstructionAccountMetas {
+    pub owner: solana_instruction::AccountMeta,
+    pub user_state: solana_instruction::AccountMeta,
+    pub farm_state: solana_instruction::AccountMeta,
+    pub user_ata: solana_instruction::AccountMeta,
+    pub farm_vault: solana_instruction::AccountMeta,
+    pub farm_vaults_authority: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for WithdrawUnstakedDeposits {
+    type ArrangedAccountMetas = WithdrawUnstakedDepositsInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [owner, user_state, farm_state, user_ata, farm_vault, farm_vaults_authority, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(WithdrawUnstakedDepositsInstructionAccountMetas {
+            owner: owner.clone(),
+            user_state: user_state.clone(),
+            farm_state: farm_state.clone(),
+            user_ata: user_ata.clone(),
+            farm_vault: farm_vault.clone(),
+            farm_vaults_authority: farm_vaults_authority.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct WithdrawUnstakedDepositsInstructionAccountIndices {
+    pub owner: usize,
+    pub user_state: usize,
+    pub farm_state: usize,
+    pub user_ata: usize,
+    pub farm_vault: usize,
+    pub farm_vaults_authority: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for WithdrawUnstakedDeposits {
+    type ArrangedAccountIndices = WithdrawUnstakedDepositsInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 7 {
+            return None;
+        }
+
+        Some(WithdrawUnstakedDepositsInstructionAccountIndices {
+            owner: 0,
+            user_state: 1,
+            farm_state: 2,
+            user_ata: 3,
+            farm_vault: 4,
+            farm_vaults_authority: 5,
+            token_program: 6,
+            remaining_accounts: 7..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for WithdrawUnstakedDeposits {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "owner",
+            "user_state",
+            "farm_state",
+            "user_ata",
+            "farm_vault",
+            "farm_vaults_authority",
+            "token_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -21,6 +22,7 @@ pub struct BorrowObligationLiquidityInstructionAccounts {
     pub referrer_token_state: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub instruction_sysvar_account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for BorrowObligationLiquidity {
@@ -29,7 +31,7 @@ impl carbon_core::deserialize::ArrangeAccounts for BorrowObligationLiquidity {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [owner, obligation, lending_market, lending_market_authority, borrow_reserve, borrow_reserve_liquidity_mint, reserve_source_liquidity, borrow_reserve_liquidity_fee_receiver, user_destination_liquidity, referrer_token_state, token_program, instruction_sysvar_account, _remaining @ ..] =
+        let [owner, obligation, lending_market, lending_market_authority, borrow_reserve, borrow_reserve_liquidity_mint, reserve_source_liquidity, borrow_reserve_liquidity_fee_receiver, user_destination_liquidity, referrer_token_state, token_program, instruction_sysvar_account, remaining @ ..] =
             accounts
         else {
             return None;
@@ -48,6 +50,116 @@ impl carbon_core::deserialize::ArrangeAccounts for BorrowObligationLiquidity {
             referrer_token_state: referrer_token_state.pubkey,
             token_program: token_program.pubkey,
             instruction_sysvar_account: instruction_sysvar_account.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct BorrowObligationLiquidityInstructionAccountMetas {
+    pub owner: solana_instruction::AccountMeta,
+    pub obligation: solana_instruction::AccountMeta,
+    pub lending_market: solana_instruction::AccountMeta,
+    pub lending_market_authority: solana_instruction::AccountMeta,
+    pub borrow_reserve: solana_instruction::AccountMeta,
+    pub borrow_reserve_liquidity_mint: solana_instruction::AccountMeta,
+    pub reserve_source_liquidity: solana_instruction::AccountMeta,
+    pub borrow_reserve_liquidity_fee_receiver: solana_instruction::AccountMeta,
+    pub user_destination_liquidity: solana_instruction::AccountMeta,
+    pub referrer_token_state: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub instruction_sysvar_account: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for BorrowObligationLiquidity {
+    type ArrangedAccountMetas = BorrowObligationLiquidityInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [owner, obligation, lending_market, lending_market_authority, borrow_reserve, borrow_reserve_liquidity_mint, reserve_source_liquidity, borrow_reserve_liquidity_fee_receiver, user_destination_liquidity, referrer_token_state, token_program, instruction_sysvar_account, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(BorrowObligationLiquidityInstructionAccountMetas {
+            owner: owner.clone(),
+            obligation: obligation.clone(),
+            lending_market: lending_market.clone(),
+            lending_market_authority: lending_market_authority.clone(),
+            borrow_reserve: borrow_reserve.clone(),
+            borrow_reserve_liquidity_mint: borrow_reserve_liquidity_mint.clone(),
+            reserve_source_liquidity: reserve_source_liquidity.clone(),
+            borrow_reserve_liquidity_fee_receiver: borrow_reserve_liquidity_fee_receiver.clone(),
+            user_destination_liquidity: user_destination_liquidity.clone(),
+            referrer_token_state: referrer_token_state.clone(),
+            token_program: token_program.clone(),
+            instruction_sysvar_account: instruction_sysvar_account.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct BorrowObligationLiquidityInstructionAccountIndices {
+    pub owner: usize,
+    pub obligation: usize,
+    pub lending_market: usize,
+    pub lending_market_authority: usize,
+    pub borrow_reserve: usize,
+    pub borrow_reserve_liquidity_mint: usize,
+    pub reserve_source_liquidity: usize,
+    pub borrow_reserve_liquidity_fee_receiver: usize,
+    pub user_destination_liquidity: usize,
+    pub referrer_token_state: usize,
+    pub token_program: usize,
+    pub instruction_sysvar_account: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for BorrowObligationLiquidity {
+    type ArrangedAccountIndices = BorrowObligationLiquidityInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 12 {
+            return None;
+        }
+
+        Some(BorrowObligationLiquidityInstructionAccountIndices {
+            owner: 0,
+            obligation: 1,
+            lending_market: 2,
+            lending_market_authority: 3,
+            borrow_reserve: 4,
+            borrow_reserve_liquidity_mint: 5,
+            reserve_source_liquidity: 6,
+            borrow_reserve_liquidity_fee_receiver: 7,
+            user_destination_liquidity: 8,
+            referrer_token_state: 9,
+            token_program: 10,
+            instruction_sysvar_account: 11,
+            remaining_accounts: 12..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for BorrowObligationLiquidity {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "owner",
+            "obligation",
+            "lending_market",
+            "lending_market_authority",
+            "borrow_reserve",
+            "borrow_reserve_liquidity_mint",
+            "reserve_source_liquidity",
+            "borrow_reserve_liquidity_fee_receiver",
+            "user_destination_liquidity",
+            "referrer_token_state",
+            "token_program",
+            "instruction_sysvar_account",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +13,7 @@ pub struct DeleteReferrerStateAndShortUrlInstructionAccounts {
     pub short_url: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for DeleteReferrerStateAndShortUrl {
@@ -20,7 +22,7 @@ impl carbon_core::deserialize::ArrangeAccounts for DeleteReferrerStateAndShortUr
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [referrer, referrer_state, short_url, rent, system_program, _remaining @ ..] = accounts
+        let [referrer, referrer_state, short_url, rent, system_program, remaining @ ..] = accounts
         else {
             return None;
         };
@@ -31,6 +33,80 @@ impl carbon_core::deserialize::ArrangeAccounts for DeleteReferrerStateAndShortUr
             short_url: short_url.pubkey,
             rent: rent.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DeleteReferrerStateAndShortUrlInstructionAccountMetas {
+    pub referrer: solana_instruction::AccountMeta,
+    pub referrer_state: solana_instruction::AccountMeta,
+    pub short_url: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for DeleteReferrerStateAndShortUrl {
+    type ArrangedAccountMetas = DeleteReferrerStateAndShortUrlInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [referrer, referrer_state, short_url, rent, system_program, remaining @ ..] = accounts
+        else {
+            return None;
+        };
+
+        Some(DeleteReferrerStateAndShortUrlInstructionAccountMetas {
+            referrer: referrer.clone(),
+            referrer_state: referrer_state.clone(),
+            short_url: short_url.clone(),
+            rent: rent.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DeleteReferrerStateAndShortUrlInstructionAccountIndices {
+    pub referrer: usize,
+    pub referrer_state: usize,
+    pub short_url: usize,
+    pub rent: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for DeleteReferrerStateAndShortUrl {
+    type ArrangedAccountIndices = DeleteReferrerStateAndShortUrlInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(DeleteReferrerStateAndShortUrlInstructionAccountIndices {
+            referrer: 0,
+            referrer_state: 1,
+            short_url: 2,
+            rent: 3,
+            system_program: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for DeleteReferrerStateAndShortUrl {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "referrer",
+            "referrer_state",
+            "short_url",
+            "rent",
+            "system_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -17,6 +18,7 @@ pub struct DepositObligationCollateralInstructionAccounts {
     pub user_source_collateral: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub instruction_sysvar_account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for DepositObligationCollateral {
@@ -25,7 +27,7 @@ impl carbon_core::deserialize::ArrangeAccounts for DepositObligationCollateral {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [owner, obligation, lending_market, deposit_reserve, reserve_destination_collateral, user_source_collateral, token_program, instruction_sysvar_account, _remaining @ ..] =
+        let [owner, obligation, lending_market, deposit_reserve, reserve_destination_collateral, user_source_collateral, token_program, instruction_sysvar_account, remaining @ ..] =
             accounts
         else {
             return None;
@@ -40,6 +42,96 @@ impl carbon_core::deserialize::ArrangeAccounts for DepositObligationCollateral {
             user_source_collateral: user_source_collateral.pubkey,
             token_program: token_program.pubkey,
             instruction_sysvar_account: instruction_sysvar_account.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DepositObligationCollateralInstructionAccountMetas {
+    pub owner: solana_instruction::AccountMeta,
+    pub obligation: solana_instruction::AccountMeta,
+    pub lending_market: solana_instruction::AccountMeta,
+    pub deposit_reserve: solana_instruction::AccountMeta,
+    pub reserve_destination_collateral: solana_instruction::AccountMeta,
+    pub user_source_collateral: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub instruction_sysvar_account: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for DepositObligationCollateral {
+    type ArrangedAccountMetas = DepositObligationCollateralInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [owner, obligation, lending_market, deposit_reserve, reserve_destination_collateral, user_source_collateral, token_program, instruction_sysvar_account, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(DepositObligationCollateralInstructionAccountMetas {
+            owner: owner.clone(),
+            obligation: obligation.clone(),
+            lending_market: lending_market.clone(),
+            deposit_reserve: deposit_reserve.clone(),
+            reserve_destination_collateral: reserve_destination_collateral.clone(),
+            user_source_collateral: user_source_collateral.clone(),
+            token_program: token_program.clone(),
+            instruction_sysvar_account: instruction_sysvar_account.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DepositObligationCollateralInstructionAccountIndices {
+    pub owner: usize,
+    pub obligation: usize,
+    pub lending_market: usize,
+    pub deposit_reserve: usize,
+    pub reserve_destination_collateral: usize,
+    pub user_source_collateral: usize,
+    pub token_program: usize,
+    pub instruction_sysvar_account: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for DepositObligationCollateral {
+    type ArrangedAccountIndices = DepositObligationCollateralInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(DepositObligationCollateralInstructionAccountIndices {
+            owner: 0,
+            obligation: 1,
+            lending_market: 2,
+            deposit_reserve: 3,
+            reserve_destination_collateral: 4,
+            user_source_collateral: 5,
+            token_program: 6,
+            instruction_sysvar_account: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for DepositObligationCollateral {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "owner",
+            "obligation",
+            "lending_market",
+            "deposit_reserve",
+            "reserve_destination_collateral",
+            "user_source_collateral",
+            "token_program",
+            "instruction_sysvar_account",
+        ]
+    }
+}
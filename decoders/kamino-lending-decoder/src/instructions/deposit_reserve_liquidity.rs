@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -21,6 +22,7 @@ pub struct DepositReserveLiquidityInstructionAccounts {
     pub collateral_token_program: solana_pubkey::Pubkey,
     pub liquidity_token_program: solana_pubkey::Pubkey,
     pub instruction_sysvar_account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for DepositReserveLiquidity {
@@ -29,7 +31,7 @@ impl carbon_core::deserialize::ArrangeAccounts for DepositReserveLiquidity {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [owner, reserve, lending_market, lending_market_authority, reserve_liquidity_mint, reserve_liquidity_supply, reserve_collateral_mint, user_source_liquidity, user_destination_collateral, collateral_token_program, liquidity_token_program, instruction_sysvar_account, _remaining @ ..] =
+        let [owner, reserve, lending_market, lending_market_authority, reserve_liquidity_mint, reserve_liquidity_supply, reserve_collateral_mint, user_source_liquidity, user_destination_collateral, collateral_token_program, liquidity_token_program, instruction_sysvar_account, remaining @ ..] =
             accounts
         else {
             return None;
@@ -48,6 +50,116 @@ impl carbon_core::deserialize::ArrangeAccounts for DepositReserveLiquidity {
             collateral_token_program: collateral_token_program.pubkey,
             liquidity_token_program: liquidity_token_program.pubkey,
             instruction_sysvar_account: instruction_sysvar_account.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DepositReserveLiquidityInstructionAccountMetas {
+    pub owner: solana_instruction::AccountMeta,
+    pub reserve: solana_instruction::AccountMeta,
+    pub lending_market: solana_instruction::AccountMeta,
+    pub lending_market_authority: solana_instruction::AccountMeta,
+    pub reserve_liquidity_mint: solana_instruction::AccountMeta,
+    pub reserve_liquidity_supply: solana_instruction::AccountMeta,
+    pub reserve_collateral_mint: solana_instruction::AccountMeta,
+    pub user_source_liquidity: solana_instruction::AccountMeta,
+    pub user_destination_collateral: solana_instruction::AccountMeta,
+    pub collateral_token_program: solana_instruction::AccountMeta,
+    pub liquidity_token_program: solana_instruction::AccountMeta,
+    pub instruction_sysvar_account: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for DepositReserveLiquidity {
+    type ArrangedAccountMetas = DepositReserveLiquidityInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [owner, reserve, lending_market, lending_market_authority, reserve_liquidity_mint, reserve_liquidity_supply, reserve_collateral_mint, user_source_liquidity, user_destination_collateral, collateral_token_program, liquidity_token_program, instruction_sysvar_account, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(DepositReserveLiquidityInstructionAccountMetas {
+            owner: owner.clone(),
+            reserve: reserve.clone(),
+            lending_market: lending_market.clone(),
+            lending_market_authority: lending_market_authority.clone(),
+            reserve_liquidity_mint: reserve_liquidity_mint.clone(),
+            reserve_liquidity_supply: reserve_liquidity_supply.clone(),
+            reserve_collateral_mint: reserve_collateral_mint.clone(),
+            user_source_liquidity: user_source_liquidity.clone(),
+            user_destination_collateral: user_destination_collateral.clone(),
+            collateral_token_program: collateral_token_program.clone(),
+            liquidity_token_program: liquidity_token_program.clone(),
+            instruction_sysvar_account: instruction_sysvar_account.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DepositReserveLiquidityInstructionAccountIndices {
+    pub owner: usize,
+    pub reserve: usize,
+    pub lending_market: usize,
+    pub lending_market_authority: usize,
+    pub reserve_liquidity_mint: usize,
+    pub reserve_liquidity_supply: usize,
+    pub reserve_collateral_mint: usize,
+    pub user_source_liquidity: usize,
+    pub user_destination_collateral: usize,
+    pub collateral_token_program: usize,
+    pub liquidity_token_program: usize,
+    pub instruction_sysvar_account: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for DepositReserveLiquidity {
+    type ArrangedAccountIndices = DepositReserveLiquidityInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 12 {
+            return None;
+        }
+
+        Some(DepositReserveLiquidityInstructionAccountIndices {
+            owner: 0,
+            reserve: 1,
+            lending_market: 2,
+            lending_market_authority: 3,
+            reserve_liquidity_mint: 4,
+            reserve_liquidity_supply: 5,
+            reserve_collateral_mint: 6,
+            user_source_liquidity: 7,
+            user_destination_collateral: 8,
+            collateral_token_program: 9,
+            liquidity_token_program: 10,
+            instruction_sysvar_account: 11,
+            remaining_accounts: 12..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for DepositReserveLiquidity {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "owner",
+            "reserve",
+            "lending_market",
+            "lending_market_authority",
+            "reserve_liquidity_mint",
+            "reserve_liquidity_supply",
+            "reserve_collateral_mint",
+            "user_source_liquidity",
+            "user_destination_collateral",
+            "collateral_token_program",
+            "liquidity_token_program",
+            "instruction_sysvar_account",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -23,6 +24,7 @@ pub struct DepositReserveLiquidityAndObligationCollateralInstructionAccounts {
     pub collateral_token_program: solana_pubkey::Pubkey,
     pub liquidity_token_program: solana_pubkey::Pubkey,
     pub instruction_sysvar_account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for DepositReserveLiquidityAndObligationCollateral {
@@ -31,7 +33,7 @@ impl carbon_core::deserialize::ArrangeAccounts for DepositReserveLiquidityAndObl
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [owner, obligation, lending_market, lending_market_authority, reserve, reserve_liquidity_mint, reserve_liquidity_supply, reserve_collateral_mint, reserve_destination_deposit_collateral, user_source_liquidity, placeholder_user_destination_collateral, collateral_token_program, liquidity_token_program, instruction_sysvar_account, _remaining @ ..] =
+        let [owner, obligation, lending_market, lending_market_authority, reserve, reserve_liquidity_mint, reserve_liquidity_supply, reserve_collateral_mint, reserve_destination_deposit_collateral, user_source_liquidity, placeholder_user_destination_collateral, collateral_token_program, liquidity_token_program, instruction_sysvar_account, remaining @ ..] =
             accounts
         else {
             return None;
@@ -55,7 +57,139 @@ impl carbon_core::deserialize::ArrangeAccounts for DepositReserveLiquidityAndObl
                 collateral_token_program: collateral_token_program.pubkey,
                 liquidity_token_program: liquidity_token_program.pubkey,
                 instruction_sysvar_account: instruction_sysvar_account.pubkey,
+                remaining_accounts: remaining.to_vec(),
             },
         )
     }
 }
+
+pub struct DepositReserveLiquidityAndObligationCollateralInstructionAccountMetas {
+    pub owner: solana_instruction::AccountMeta,
+    pub obligation: solana_instruction::AccountMeta,
+    pub lending_market: solana_instruction::AccountMeta,
+    pub lending_market_authority: solana_instruction::AccountMeta,
+    pub reserve: solana_instruction::AccountMeta,
+    pub reserve_liquidity_mint: solana_instruction::AccountMeta,
+    pub reserve_liquidity_supply: solana_instruction::AccountMeta,
+    pub reserve_collateral_mint: solana_instruction::AccountMeta,
+    pub reserve_destination_deposit_collateral: solana_instruction::AccountMeta,
+    pub user_source_liquidity: solana_instruction::AccountMeta,
+    pub placeholder_user_destination_collateral: solana_instruction::AccountMeta,
+    pub collateral_token_program: solana_instruction::AccountMeta,
+    pub liquidity_token_program: solana_instruction::AccountMeta,
+    pub instruction_sysvar_account: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas
+    for DepositReserveLiquidityAndObligationCollateral
+{
+    type ArrangedAccountMetas =
+        DepositReserveLiquidityAndObligationCollateralInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [owner, obligation, lending_market, lending_market_authority, reserve, reserve_liquidity_mint, reserve_liquidity_supply, reserve_collateral_mint, reserve_destination_deposit_collateral, user_source_liquidity, placeholder_user_destination_collateral, collateral_token_program, liquidity_token_program, instruction_sysvar_account, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(
+            DepositReserveLiquidityAndObligationCollateralInstructionAccountMetas {
+                owner: owner.clone(),
+                obligation: obligation.clone(),
+                lending_market: lending_market.clone(),
+                lending_market_authority: lending_market_authority.clone(),
+                reserve: reserve.clone(),
+                reserve_liquidity_mint: reserve_liquidity_mint.clone(),
+                reserve_liquidity_supply: reserve_liquidity_supply.clone(),
+                reserve_collateral_mint: reserve_collateral_mint.clone(),
+                reserve_destination_deposit_collateral: reserve_destination_deposit_collateral
+                    .clone(),
+                user_source_liquidity: user_source_liquidity.clone(),
+                placeholder_user_destination_collateral: placeholder_user_destination_collateral
+                    .clone(),
+                collateral_token_program: collateral_token_program.clone(),
+                liquidity_token_program: liquidity_token_program.clone(),
+                instruction_sysvar_account: instruction_sysvar_account.clone(),
+                remaining_accounts: remaining.to_vec(),
+            },
+        )
+    }
+}
+
+pub struct DepositReserveLiquidityAndObligationCollateralInstructionAccountIndices {
+    pub owner: usize,
+    pub obligation: usize,
+    pub lending_market: usize,
+    pub lending_market_authority: usize,
+    pub reserve: usize,
+    pub reserve_liquidity_mint: usize,
+    pub reserve_liquidity_supply: usize,
+    pub reserve_collateral_mint: usize,
+    pub reserve_destination_deposit_collateral: usize,
+    pub user_source_liquidity: usize,
+    pub placeholder_user_destination_collateral: usize,
+    pub collateral_token_program: usize,
+    pub liquidity_token_program: usize,
+    pub instruction_sysvar_account: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices
+    for DepositReserveLiquidityAndObligationCollateral
+{
+    type ArrangedAccountIndices =
+        DepositReserveLiquidityAndObligationCollateralInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 14 {
+            return None;
+        }
+
+        Some(
+            DepositReserveLiquidityAndObligationCollateralInstructionAccountIndices {
+                owner: 0,
+                obligation: 1,
+                lending_market: 2,
+                lending_market_authority: 3,
+                reserve: 4,
+                reserve_liquidity_mint: 5,
+                reserve_liquidity_supply: 6,
+                reserve_collateral_mint: 7,
+                reserve_destination_deposit_collateral: 8,
+                user_source_liquidity: 9,
+                placeholder_user_destination_collateral: 10,
+                collateral_token_program: 11,
+                liquidity_token_program: 12,
+                instruction_sysvar_account: 13,
+                remaining_accounts: 14..accounts.len(),
+            },
+        )
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for DepositReserveLiquidityAndObligationCollateral {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "owner",
+            "obligation",
+            "lending_market",
+            "lending_market_authority",
+            "reserve",
+            "reserve_liquidity_mint",
+            "reserve_liquidity_supply",
+            "reserve_collateral_mint",
+            "reserve_destination_deposit_collateral",
+            "user_source_liquidity",
+            "placeholder_user_destination_collateral",
+            "collateral_token_program",
+            "liquidity_token_program",
+            "instruction_sysvar_account",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -22,6 +23,7 @@ pub struct FlashRepayReserveLiquidityInstructionAccounts {
     pub referrer_account: solana_pubkey::Pubkey,
     pub sysvar_info: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for FlashRepayReserveLiquidity {
@@ -30,7 +32,7 @@ impl carbon_core::deserialize::ArrangeAccounts for FlashRepayReserveLiquidity {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [user_transfer_authority, lending_market_authority, lending_market, reserve, reserve_liquidity_mint, reserve_destination_liquidity, user_source_liquidity, reserve_liquidity_fee_receiver, referrer_token_state, referrer_account, sysvar_info, token_program, _remaining @ ..] =
+        let [user_transfer_authority, lending_market_authority, lending_market, reserve, reserve_liquidity_mint, reserve_destination_liquidity, user_source_liquidity, reserve_liquidity_fee_receiver, referrer_token_state, referrer_account, sysvar_info, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -49,6 +51,116 @@ impl carbon_core::deserialize::ArrangeAccounts for FlashRepayReserveLiquidity {
             referrer_account: referrer_account.pubkey,
             sysvar_info: sysvar_info.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct FlashRepayReserveLiquidityInstructionAccountMetas {
+    pub user_transfer_authority: solana_instruction::AccountMeta,
+    pub lending_market_authority: solana_instruction::AccountMeta,
+    pub lending_market: solana_instruction::AccountMeta,
+    pub reserve: solana_instruction::AccountMeta,
+    pub reserve_liquidity_mint: solana_instruction::AccountMeta,
+    pub reserve_destination_liquidity: solana_instruction::AccountMeta,
+    pub user_source_liquidity: solana_instruction::AccountMeta,
+    pub reserve_liquidity_fee_receiver: solana_instruction::AccountMeta,
+    pub referrer_token_state: solana_instruction::AccountMeta,
+    pub referrer_account: solana_instruction::AccountMeta,
+    pub sysvar_info: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for FlashRepayReserveLiquidity {
+    type ArrangedAccountMetas = FlashRepayReserveLiquidityInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [user_transfer_authority, lending_market_authority, lending_market, reserve, reserve_liquidity_mint, reserve_destination_liquidity, user_source_liquidity, reserve_liquidity_fee_receiver, referrer_token_state, referrer_account, sysvar_info, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(FlashRepayReserveLiquidityInstructionAccountMetas {
+            user_transfer_authority: user_transfer_authority.clone(),
+            lending_market_authority: lending_market_authority.clone(),
+            lending_market: lending_market.clone(),
+            reserve: reserve.clone(),
+            reserve_liquidity_mint: reserve_liquidity_mint.clone(),
+            reserve_destination_liquidity: reserve_destination_liquidity.clone(),
+            user_source_liquidity: user_source_liquidity.clone(),
+            reserve_liquidity_fee_receiver: reserve_liquidity_fee_receiver.clone(),
+            referrer_token_state: referrer_token_state.clone(),
+            referrer_account: referrer_account.clone(),
+            sysvar_info: sysvar_info.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct FlashRepayReserveLiquidityInstructionAccountIndices {
+    pub user_transfer_authority: usize,
+    pub lending_market_authority: usize,
+    pub lending_market: usize,
+    pub reserve: usize,
+    pub reserve_liquidity_mint: usize,
+    pub reserve_destination_liquidity: usize,
+    pub user_source_liquidity: usize,
+    pub reserve_liquidity_fee_receiver: usize,
+    pub referrer_token_state: usize,
+    pub referrer_account: usize,
+    pub sysvar_info: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for FlashRepayReserveLiquidity {
+    type ArrangedAccountIndices = FlashRepayReserveLiquidityInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 12 {
+            return None;
+        }
+
+        Some(FlashRepayReserveLiquidityInstructionAccountIndices {
+            user_transfer_authority: 0,
+            lending_market_authority: 1,
+            lending_market: 2,
+            reserve: 3,
+            reserve_liquidity_mint: 4,
+            reserve_destination_liquidity: 5,
+            user_source_liquidity: 6,
+            reserve_liquidity_fee_receiver: 7,
+            referrer_token_state: 8,
+            referrer_account: 9,
+            sysvar_info: 10,
+            token_program: 11,
+            remaining_accounts: 12..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for FlashRepayReserveLiquidity {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "user_transfer_authority",
+            "lending_market_authority",
+            "lending_market",
+            "reserve",
+            "reserve_liquidity_mint",
+            "reserve_destination_liquidity",
+            "user_source_liquidity",
+            "reserve_liquidity_fee_receiver",
+            "referrer_token_state",
+            "referrer_account",
+            "sysvar_info",
+            "token_program",
+        ]
+    }
+}
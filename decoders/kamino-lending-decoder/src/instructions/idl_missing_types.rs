@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -21,6 +22,7 @@ pub struct IdlMissingTypesInstructionAccounts {
     pub lending_market_owner: solana_pubkey::Pubkey,
     pub lending_market: solana_pubkey::Pubkey,
     pub reserve: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for IdlMissingTypes {
@@ -29,7 +31,7 @@ impl carbon_core::deserialize::ArrangeAccounts for IdlMissingTypes {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [lending_market_owner, lending_market, reserve, _remaining @ ..] = accounts else {
+        let [lending_market_owner, lending_market, reserve, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -37,6 +39,65 @@ impl carbon_core::deserialize::ArrangeAccounts for IdlMissingTypes {
             lending_market_owner: lending_market_owner.pubkey,
             lending_market: lending_market.pubkey,
             reserve: reserve.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct IdlMissingTypesInstructionAccountMetas {
+    pub lending_market_owner: solana_instruction::AccountMeta,
+    pub lending_market: solana_instruction::AccountMeta,
+    pub reserve: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for IdlMissingTypes {
+    type ArrangedAccountMetas = IdlMissingTypesInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [lending_market_owner, lending_market, reserve, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(IdlMissingTypesInstructionAccountMetas {
+            lending_market_owner: lending_market_owner.clone(),
+            lending_market: lending_market.clone(),
+            reserve: reserve.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct IdlMissingTypesInstructionAccountIndices {
+    pub lending_market_owner: usize,
+    pub lending_market: usize,
+    pub reserve: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for IdlMissingTypes {
+    type ArrangedAccountIndices = IdlMissingTypesInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(IdlMissingTypesInstructionAccountIndices {
+            lending_market_owner: 0,
+            lending_market: 1,
+            reserve: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for IdlMissingTypes {
+    fn account_names() -> &'static [&'static str] {
+        &["lending_market_owner", "lending_market", "reserve"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -19,6 +20,7 @@ pub struct InitFarmsForReserveInstructionAccounts {
     pub farms_vault_authority: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitFarmsForReserve {
@@ -27,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitFarmsForReserve {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [lending_market_owner, lending_market, lending_market_authority, reserve, farms_program, farms_global_config, farm_state, farms_vault_authority, rent, system_program, _remaining @ ..] =
+        let [lending_market_owner, lending_market, lending_market_authority, reserve, farms_program, farms_global_config, farm_state, farms_vault_authority, rent, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -44,6 +46,106 @@ impl carbon_core::deserialize::ArrangeAccounts for InitFarmsForReserve {
             farms_vault_authority: farms_vault_authority.pubkey,
             rent: rent.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitFarmsForReserveInstructionAccountMetas {
+    pub lending_market_owner: solana_instruction::AccountMeta,
+    pub lending_market: solana_instruction::AccountMeta,
+    pub lending_market_authority: solana_instruction::AccountMeta,
+    pub reserve: solana_instruction::AccountMeta,
+    pub farms_program: solana_instruction::AccountMeta,
+    pub farms_global_config: solana_instruction::AccountMeta,
+    pub farm_state: solana_instruction::AccountMeta,
+    pub farms_vault_authority: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitFarmsForReserve {
+    type ArrangedAccountMetas = InitFarmsForReserveInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [lending_market_owner, lending_market, lending_market_authority, reserve, farms_program, farms_global_config, farm_state, farms_vault_authority, rent, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitFarmsForReserveInstructionAccountMetas {
+            lending_market_owner: lending_market_owner.clone(),
+            lending_market: lending_market.clone(),
+            lending_market_authority: lending_market_authority.clone(),
+            reserve: reserve.clone(),
+            farms_program: farms_program.clone(),
+            farms_global_config: farms_global_config.clone(),
+            farm_state: farm_state.clone(),
+            farms_vault_authority: farms_vault_authority.clone(),
+            rent: rent.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitFarmsForReserveInstructionAccountIndices {
+    pub lending_market_owner: usize,
+    pub lending_market: usize,
+    pub lending_market_authority: usize,
+    pub reserve: usize,
+    pub farms_program: usize,
+    pub farms_global_config: usize,
+    pub farm_state: usize,
+    pub farms_vault_authority: usize,
+    pub rent: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitFarmsForReserve {
+    type ArrangedAccountIndices = InitFarmsForReserveInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 10 {
+            return None;
+        }
+
+        Some(InitFarmsForReserveInstructionAccountIndices {
+            lending_market_owner: 0,
+            lending_market: 1,
+            lending_market_authority: 2,
+            reserve: 3,
+            farms_program: 4,
+            farms_global_config: 5,
+            farm_state: 6,
+            farms_vault_authority: 7,
+            rent: 8,
+            system_program: 9,
+            remaining_accounts: 10..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitFarmsForReserve {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "lending_market_owner",
+            "lending_market",
+            "lending_market_authority",
+            "reserve",
+            "farms_program",
+            "farms_global_config",
+            "farm_state",
+            "farms_vault_authority",
+            "rent",
+            "system_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct InitLendingMarketInstructionAccounts {
     pub lending_market_authority: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitLendingMarket {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitLendingMarket {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [lending_market_owner, lending_market, lending_market_authority, system_program, rent, _remaining @ ..] =
+        let [lending_market_owner, lending_market, lending_market_authority, system_program, rent, remaining @ ..] =
             accounts
         else {
             return None;
@@ -34,6 +36,81 @@ impl carbon_core::deserialize::ArrangeAccounts for InitLendingMarket {
             lending_market_authority: lending_market_authority.pubkey,
             system_program: system_program.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitLendingMarketInstructionAccountMetas {
+    pub lending_market_owner: solana_instruction::AccountMeta,
+    pub lending_market: solana_instruction::AccountMeta,
+    pub lending_market_authority: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitLendingMarket {
+    type ArrangedAccountMetas = InitLendingMarketInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [lending_market_owner, lending_market, lending_market_authority, system_program, rent, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitLendingMarketInstructionAccountMetas {
+            lending_market_owner: lending_market_owner.clone(),
+            lending_market: lending_market.clone(),
+            lending_market_authority: lending_market_authority.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitLendingMarketInstructionAccountIndices {
+    pub lending_market_owner: usize,
+    pub lending_market: usize,
+    pub lending_market_authority: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitLendingMarket {
+    type ArrangedAccountIndices = InitLendingMarketInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(InitLendingMarketInstructionAccountIndices {
+            lending_market_owner: 0,
+            lending_market: 1,
+            lending_market_authority: 2,
+            system_program: 3,
+            rent: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitLendingMarket {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "lending_market_owner",
+            "lending_market",
+            "lending_market_authority",
+            "system_program",
+            "rent",
+        ]
+    }
+}
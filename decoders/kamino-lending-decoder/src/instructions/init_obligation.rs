@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -21,6 +22,7 @@ pub struct InitObligationInstructionAccounts {
     pub owner_user_metadata: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitObligation {
@@ -29,7 +31,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitObligation {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [obligation_owner, fee_payer, obligation, lending_market, seed1_account, seed2_account, owner_user_metadata, rent, system_program, _remaining @ ..] =
+        let [obligation_owner, fee_payer, obligation, lending_market, seed1_account, seed2_account, owner_user_metadata, rent, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -45,6 +47,101 @@ impl carbon_core::deserialize::ArrangeAccounts for InitObligation {
             owner_user_metadata: owner_user_metadata.pubkey,
             rent: rent.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitObligationInstructionAccountMetas {
+    pub obligation_owner: solana_instruction::AccountMeta,
+    pub fee_payer: solana_instruction::AccountMeta,
+    pub obligation: solana_instruction::AccountMeta,
+    pub lending_market: solana_instruction::AccountMeta,
+    pub seed1_account: solana_instruction::AccountMeta,
+    pub seed2_account: solana_instruction::AccountMeta,
+    pub owner_user_metadata: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitObligation {
+    type ArrangedAccountMetas = InitObligationInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [obligation_owner, fee_payer, obligation, lending_market, seed1_account, seed2_account, owner_user_metadata, rent, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitObligationInstructionAccountMetas {
+            obligation_owner: obligation_owner.clone(),
+            fee_payer: fee_payer.clone(),
+            obligation: obligation.clone(),
+            lending_market: lending_market.clone(),
+            seed1_account: seed1_account.clone(),
+            seed2_account: seed2_account.clone(),
+            owner_user_metadata: owner_user_metadata.clone(),
+            rent: rent.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitObligationInstructionAccountIndices {
+    pub obligation_owner: usize,
+    pub fee_payer: usize,
+    pub obligation: usize,
+    pub lending_market: usize,
+    pub seed1_account: usize,
+    pub seed2_account: usize,
+    pub owner_user_metadata: usize,
+    pub rent: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitObligation {
+    type ArrangedAccountIndices = InitObligationInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 9 {
+            return None;
+        }
+
+        Some(InitObligationInstructionAccountIndices {
+            obligation_owner: 0,
+            fee_payer: 1,
+            obligation: 2,
+            lending_market: 3,
+            seed1_account: 4,
+            seed2_account: 5,
+            owner_user_metadata: 6,
+            rent: 7,
+            system_program: 8,
+            remaining_accounts: 9..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitObligation {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "obligation_owner",
+            "fee_payer",
+            "obligation",
+            "lending_market",
+            "seed1_account",
+            "seed2_account",
+            "owner_user_metadata",
+            "rent",
+            "system_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -20,6 +21,7 @@ pub struct InitObligationFarmsForReserveInstructionAccounts {
     pub farms_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitObligationFarmsForReserve {
@@ -28,7 +30,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitObligationFarmsForReserve
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [payer, owner, obligation, lending_market_authority, reserve, reserve_farm_state, obligation_farm, lending_market, farms_program, rent, system_program, _remaining @ ..] =
+        let [payer, owner, obligation, lending_market_authority, reserve, reserve_farm_state, obligation_farm, lending_market, farms_program, rent, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -46,6 +48,111 @@ impl carbon_core::deserialize::ArrangeAccounts for InitObligationFarmsForReserve
             farms_program: farms_program.pubkey,
             rent: rent.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitObligationFarmsForReserveInstructionAccountMetas {
+    pub payer: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub obligation: solana_instruction::AccountMeta,
+    pub lending_market_authority: solana_instruction::AccountMeta,
+    pub reserve: solana_instruction::AccountMeta,
+    pub reserve_farm_state: solana_instruction::AccountMeta,
+    pub obligation_farm: solana_instruction::AccountMeta,
+    pub lending_market: solana_instruction::AccountMeta,
+    pub farms_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitObligationFarmsForReserve {
+    type ArrangedAccountMetas = InitObligationFarmsForReserveInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [payer, owner, obligation, lending_market_authority, reserve, reserve_farm_state, obligation_farm, lending_market, farms_program, rent, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitObligationFarmsForReserveInstructionAccountMetas {
+            payer: payer.clone(),
+            owner: owner.clone(),
+            obligation: obligation.clone(),
+            lending_market_authority: lending_market_authority.clone(),
+            reserve: reserve.clone(),
+            reserve_farm_state: reserve_farm_state.clone(),
+            obligation_farm: obligation_farm.clone(),
+            lending_market: lending_market.clone(),
+            farms_program: farms_program.clone(),
+            rent: rent.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitObligationFarmsForReserveInstructionAccountIndices {
+    pub payer: usize,
+    pub owner: usize,
+    pub obligation: usize,
+    pub lending_market_authority: usize,
+    pub reserve: usize,
+    pub reserve_farm_state: usize,
+    pub obligation_farm: usize,
+    pub lending_market: usize,
+    pub farms_program: usize,
+    pub rent: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitObligationFarmsForReserve {
+    type ArrangedAccountIndices = InitObligationFarmsForReserveInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 11 {
+            return None;
+        }
+
+        Some(InitObligationFarmsForReserveInstructionAccountIndices {
+            payer: 0,
+            owner: 1,
+            obligation: 2,
+            lending_market_authority: 3,
+            reserve: 4,
+            reserve_farm_state: 5,
+            obligation_farm: 6,
+            lending_market: 7,
+            farms_program: 8,
+            rent: 9,
+            system_program: 10,
+            remaining_accounts: 11..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitObligationFarmsForReserve {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "payer",
+            "owner",
+            "obligation",
+            "lending_market_authority",
+            "reserve",
+            "reserve_farm_state",
+            "obligation_farm",
+            "lending_market",
+            "farms_program",
+            "rent",
+            "system_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -15,6 +16,7 @@ pub struct InitReferrerStateAndShortUrlInstructionAccounts {
     pub referrer_user_metadata: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitReferrerStateAndShortUrl {
@@ -23,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitReferrerStateAndShortUrl
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [referrer, referrer_state, referrer_short_url, referrer_user_metadata, rent, system_program, _remaining @ ..] =
+        let [referrer, referrer_state, referrer_short_url, referrer_user_metadata, rent, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -36,6 +38,86 @@ impl carbon_core::deserialize::ArrangeAccounts for InitReferrerStateAndShortUrl
             referrer_user_metadata: referrer_user_metadata.pubkey,
             rent: rent.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitReferrerStateAndShortUrlInstructionAccountMetas {
+    pub referrer: solana_instruction::AccountMeta,
+    pub referrer_state: solana_instruction::AccountMeta,
+    pub referrer_short_url: solana_instruction::AccountMeta,
+    pub referrer_user_metadata: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitReferrerStateAndShortUrl {
+    type ArrangedAccountMetas = InitReferrerStateAndShortUrlInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [referrer, referrer_state, referrer_short_url, referrer_user_metadata, rent, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitReferrerStateAndShortUrlInstructionAccountMetas {
+            referrer: referrer.clone(),
+            referrer_state: referrer_state.clone(),
+            referrer_short_url: referrer_short_url.clone(),
+            referrer_user_metadata: referrer_user_metadata.clone(),
+            rent: rent.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitReferrerStateAndShortUrlInstructionAccountIndices {
+    pub referrer: usize,
+    pub referrer_state: usize,
+    pub referrer_short_url: usize,
+    pub referrer_user_metadata: usize,
+    pub rent: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitReferrerStateAndShortUrl {
+    type ArrangedAccountIndices = InitReferrerStateAndShortUrlInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(InitReferrerStateAndShortUrlInstructionAccountIndices {
+            referrer: 0,
+            referrer_state: 1,
+            referrer_short_url: 2,
+            referrer_user_metadata: 3,
+            rent: 4,
+            system_program: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitReferrerStateAndShortUrl {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "referrer",
+            "referrer_state",
+            "referrer_short_url",
+            "referrer_user_metadata",
+            "rent",
+            "system_program",
+        ]
+    }
+}
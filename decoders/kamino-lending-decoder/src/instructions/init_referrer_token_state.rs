@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct InitReferrerTokenStateInstructionAccounts {
     pub referrer_token_state: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitReferrerTokenState {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitReferrerTokenState {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [payer, lending_market, reserve, referrer, referrer_token_state, rent, system_program, _remaining @ ..] =
+        let [payer, lending_market, reserve, referrer, referrer_token_state, rent, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -36,6 +38,91 @@ impl carbon_core::deserialize::ArrangeAccounts for InitReferrerTokenState {
             referrer_token_state: referrer_token_state.pubkey,
             rent: rent.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitReferrerTokenStateInstructionAccountMetas {
+    pub payer: solana_instruction::AccountMeta,
+    pub lending_market: solana_instruction::AccountMeta,
+    pub reserve: solana_instruction::AccountMeta,
+    pub referrer: solana_instruction::AccountMeta,
+    pub referrer_token_state: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitReferrerTokenState {
+    type ArrangedAccountMetas = InitReferrerTokenStateInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [payer, lending_market, reserve, referrer, referrer_token_state, rent, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitReferrerTokenStateInstructionAccountMetas {
+            payer: payer.clone(),
+            lending_market: lending_market.clone(),
+            reserve: reserve.clone(),
+            referrer: referrer.clone(),
+            referrer_token_state: referrer_token_state.clone(),
+            rent: rent.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitReferrerTokenStateInstructionAccountIndices {
+    pub payer: usize,
+    pub lending_market: usize,
+    pub reserve: usize,
+    pub referrer: usize,
+    pub referrer_token_state: usize,
+    pub rent: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitReferrerTokenState {
+    type ArrangedAccountIndices = InitReferrerTokenStateInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 7 {
+            return None;
+        }
+
+        Some(InitReferrerTokenStateInstructionAccountIndices {
+            payer: 0,
+            lending_market: 1,
+            reserve: 2,
+            referrer: 3,
+            referrer_token_state: 4,
+            rent: 5,
+            system_program: 6,
+            remaining_accounts: 7..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitReferrerTokenState {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "payer",
+            "lending_market",
+            "reserve",
+            "referrer",
+            "referrer_token_state",
+            "rent",
+            "system_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -20,6 +21,7 @@ pub struct InitReserveInstructionAccounts {
     pub liquidity_token_program: solana_pubkey::Pubkey,
     pub collateral_token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitReserve {
@@ -28,7 +30,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitReserve {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [lending_market_owner, lending_market, lending_market_authority, reserve, reserve_liquidity_mint, reserve_liquidity_supply, fee_receiver, reserve_collateral_mint, reserve_collateral_supply, rent, liquidity_token_program, collateral_token_program, system_program, _remaining @ ..] =
+        let [lending_market_owner, lending_market, lending_market_authority, reserve, reserve_liquidity_mint, reserve_liquidity_supply, fee_receiver, reserve_collateral_mint, reserve_collateral_supply, rent, liquidity_token_program, collateral_token_program, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -48,6 +50,121 @@ impl carbon_core::deserialize::ArrangeAccounts for InitReserve {
             liquidity_token_program: liquidity_token_program.pubkey,
             collateral_token_program: collateral_token_program.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitReserveInstructionAccountMetas {
+    pub lending_market_owner: solana_instruction::AccountMeta,
+    pub lending_market: solana_instruction::AccountMeta,
+    pub lending_market_authority: solana_instruction::AccountMeta,
+    pub reserve: solana_instruction::AccountMeta,
+    pub reserve_liquidity_mint: solana_instruction::AccountMeta,
+    pub reserve_liquidity_supply: solana_instruction::AccountMeta,
+    pub fee_receiver: solana_instruction::AccountMeta,
+    pub reserve_collateral_mint: solana_instruction::AccountMeta,
+    pub reserve_collateral_supply: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub liquidity_token_program: solana_instruction::AccountMeta,
+    pub collateral_token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitReserve {
+    type ArrangedAccountMetas = InitReserveInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [lending_market_owner, lending_market, lending_market_authority, reserve, reserve_liquidity_mint, reserve_liquidity_supply, fee_receiver, reserve_collateral_mint, reserve_collateral_supply, rent, liquidity_token_program, collateral_token_program, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitReserveInstructionAccountMetas {
+            lending_market_owner: lending_market_owner.clone(),
+            lending_market: lending_market.clone(),
+            lending_market_authority: lending_market_authority.clone(),
+            reserve: reserve.clone(),
+            reserve_liquidity_mint: reserve_liquidity_mint.clone(),
+            reserve_liquidity_supply: reserve_liquidity_supply.clone(),
+            fee_receiver: fee_receiver.clone(),
+            reserve_collateral_mint: reserve_collateral_mint.clone(),
+            reserve_collateral_supply: reserve_collateral_supply.clone(),
+            rent: rent.clone(),
+            liquidity_token_program: liquidity_token_program.clone(),
+            collateral_token_program: collateral_token_program.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitReserveInstructionAccountIndices {
+    pub lending_market_owner: usize,
+    pub lending_market: usize,
+    pub lending_market_authority: usize,
+    pub reserve: usize,
+    pub reserve_liquidity_mint: usize,
+    pub reserve_liquidity_supply: usize,
+    pub fee_receiver: usize,
+    pub reserve_collateral_mint: usize,
+    pub reserve_collateral_supply: usize,
+    pub rent: usize,
+    pub liquidity_token_program: usize,
+    pub collateral_token_program: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitReserve {
+    type ArrangedAccountIndices = InitReserveInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 13 {
+            return None;
+        }
+
+        Some(InitReserveInstructionAccountIndices {
+            lending_market_owner: 0,
+            lending_market: 1,
+            lending_market_authority: 2,
+            reserve: 3,
+            reserve_liquidity_mint: 4,
+            reserve_liquidity_supply: 5,
+            fee_receiver: 6,
+            reserve_collateral_mint: 7,
+            reserve_collateral_supply: 8,
+            rent: 9,
+            liquidity_token_program: 10,
+            collateral_token_program: 11,
+            system_program: 12,
+            remaining_accounts: 13..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitReserve {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "lending_market_owner",
+            "lending_market",
+            "lending_market_authority",
+            "reserve",
+            "reserve_liquidity_mint",
+            "reserve_liquidity_supply",
+            "fee_receiver",
+            "reserve_collateral_mint",
+            "reserve_collateral_supply",
+            "rent",
+            "liquidity_token_program",
+            "collateral_token_program",
+            "system_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -15,6 +16,7 @@ pub struct InitUserMetadataInstructionAccounts {
     pub referrer_user_metadata: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitUserMetadata {
@@ -23,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitUserMetadata {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [owner, fee_payer, user_metadata, referrer_user_metadata, rent, system_program, _remaining @ ..] =
+        let [owner, fee_payer, user_metadata, referrer_user_metadata, rent, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -36,6 +38,86 @@ impl carbon_core::deserialize::ArrangeAccounts for InitUserMetadata {
             referrer_user_metadata: referrer_user_metadata.pubkey,
             rent: rent.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitUserMetadataInstructionAccountMetas {
+    pub owner: solana_instruction::AccountMeta,
+    pub fee_payer: solana_instruction::AccountMeta,
+    pub user_metadata: solana_instruction::AccountMeta,
+    pub referrer_user_metadata: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitUserMetadata {
+    type ArrangedAccountMetas = InitUserMetadataInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [owner, fee_payer, user_metadata, referrer_user_metadata, rent, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitUserMetadataInstructionAccountMetas {
+            owner: owner.clone(),
+            fee_payer: fee_payer.clone(),
+            user_metadata: user_metadata.clone(),
+            referrer_user_metadata: referrer_user_metadata.clone(),
+            rent: rent.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitUserMetadataInstructionAccountIndices {
+    pub owner: usize,
+    pub fee_payer: usize,
+    pub user_metadata: usize,
+    pub referrer_user_metadata: usize,
+    pub rent: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitUserMetadata {
+    type ArrangedAccountIndices = InitUserMetadataInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(InitUserMetadataInstructionAccountIndices {
+            owner: 0,
+            fee_payer: 1,
+            user_metadata: 2,
+            referrer_user_metadata: 3,
+            rent: 4,
+            system_program: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitUserMetadata {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "owner",
+            "fee_payer",
+            "user_metadata",
+            "referrer_user_metadata",
+            "rent",
+            "system_program",
+        ]
+    }
+}
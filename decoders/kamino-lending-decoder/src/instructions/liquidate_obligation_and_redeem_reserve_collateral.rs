@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -31,6 +32,7 @@ pub struct LiquidateObligationAndRedeemReserveCollateralInstructionAccounts {
     pub repay_liquidity_token_program: solana_pubkey::Pubkey,
     pub withdraw_liquidity_token_program: solana_pubkey::Pubkey,
     pub instruction_sysvar_account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for LiquidateObligationAndRedeemReserveCollateral {
@@ -39,7 +41,7 @@ impl carbon_core::deserialize::ArrangeAccounts for LiquidateObligationAndRedeemR
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [liquidator, obligation, lending_market, lending_market_authority, repay_reserve, repay_reserve_liquidity_mint, repay_reserve_liquidity_supply, withdraw_reserve, withdraw_reserve_liquidity_mint, withdraw_reserve_collateral_mint, withdraw_reserve_collateral_supply, withdraw_reserve_liquidity_supply, withdraw_reserve_liquidity_fee_receiver, user_source_liquidity, user_destination_collateral, user_destination_liquidity, collateral_token_program, repay_liquidity_token_program, withdraw_liquidity_token_program, instruction_sysvar_account, _remaining @ ..] =
+        let [liquidator, obligation, lending_market, lending_market_authority, repay_reserve, repay_reserve_liquidity_mint, repay_reserve_liquidity_supply, withdraw_reserve, withdraw_reserve_liquidity_mint, withdraw_reserve_collateral_mint, withdraw_reserve_collateral_supply, withdraw_reserve_liquidity_supply, withdraw_reserve_liquidity_fee_receiver, user_source_liquidity, user_destination_collateral, user_destination_liquidity, collateral_token_program, repay_liquidity_token_program, withdraw_liquidity_token_program, instruction_sysvar_account, remaining @ ..] =
             accounts
         else {
             return None;
@@ -68,7 +70,168 @@ impl carbon_core::deserialize::ArrangeAccounts for LiquidateObligationAndRedeemR
                 repay_liquidity_token_program: repay_liquidity_token_program.pubkey,
                 withdraw_liquidity_token_program: withdraw_liquidity_token_program.pubkey,
                 instruction_sysvar_account: instruction_sysvar_account.pubkey,
+                remaining_accounts: remaining.to_vec(),
             },
         )
     }
 }
+
+pub struct LiquidateObligationAndRedeemReserveCollateralInstructionAccountMetas {
+    pub liquidator: solana_instruction::AccountMeta,
+    pub obligation: solana_instruction::AccountMeta,
+    pub lending_market: solana_instruction::AccountMeta,
+    pub lending_market_authority: solana_instruction::AccountMeta,
+    pub repay_reserve: solana_instruction::AccountMeta,
+    pub repay_reserve_liquidity_mint: solana_instruction::AccountMeta,
+    pub repay_reserve_liquidity_supply: solana_instruction::AccountMeta,
+    pub withdraw_reserve: solana_instruction::AccountMeta,
+    pub withdraw_reserve_liquidity_mint: solana_instruction::AccountMeta,
+    pub withdraw_reserve_collateral_mint: solana_instruction::AccountMeta,
+    pub withdraw_reserve_collateral_supply: solana_instruction::AccountMeta,
+    pub withdraw_reserve_liquidity_supply: solana_instruction::AccountMeta,
+    pub withdraw_reserve_liquidity_fee_receiver: solana_instruction::AccountMeta,
+    pub user_source_liquidity: solana_instruction::AccountMeta,
+    pub user_destination_collateral: solana_instruction::AccountMeta,
+    pub user_destination_liquidity: solana_instruction::AccountMeta,
+    pub collateral_token_program: solana_instruction::AccountMeta,
+    pub repay_liquidity_token_program: solana_instruction::AccountMeta,
+    pub withdraw_liquidity_token_program: solana_instruction::AccountMeta,
+    pub instruction_sysvar_account: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas
+    for LiquidateObligationAndRedeemReserveCollateral
+{
+    type ArrangedAccountMetas =
+        LiquidateObligationAndRedeemReserveCollateralInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [liquidator, obligation, lending_market, lending_market_authority, repay_reserve, repay_reserve_liquidity_mint, repay_reserve_liquidity_supply, withdraw_reserve, withdraw_reserve_liquidity_mint, withdraw_reserve_collateral_mint, withdraw_reserve_collateral_supply, withdraw_reserve_liquidity_supply, withdraw_reserve_liquidity_fee_receiver, user_source_liquidity, user_destination_collateral, user_destination_liquidity, collateral_token_program, repay_liquidity_token_program, withdraw_liquidity_token_program, instruction_sysvar_account, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(
+            LiquidateObligationAndRedeemReserveCollateralInstructionAccountMetas {
+                liquidator: liquidator.clone(),
+                obligation: obligation.clone(),
+                lending_market: lending_market.clone(),
+                lending_market_authority: lending_market_authority.clone(),
+                repay_reserve: repay_reserve.clone(),
+                repay_reserve_liquidity_mint: repay_reserve_liquidity_mint.clone(),
+                repay_reserve_liquidity_supply: repay_reserve_liquidity_supply.clone(),
+                withdraw_reserve: withdraw_reserve.clone(),
+                withdraw_reserve_liquidity_mint: withdraw_reserve_liquidity_mint.clone(),
+                withdraw_reserve_collateral_mint: withdraw_reserve_collateral_mint.clone(),
+                withdraw_reserve_collateral_supply: withdraw_reserve_collateral_supply.clone(),
+                withdraw_reserve_liquidity_supply: withdraw_reserve_liquidity_supply.clone(),
+                withdraw_reserve_liquidity_fee_receiver: withdraw_reserve_liquidity_fee_receiver
+                    .clone(),
+                user_source_liquidity: user_source_liquidity.clone(),
+                user_destination_collateral: user_destination_collateral.clone(),
+                user_destination_liquidity: user_destination_liquidity.clone(),
+                collateral_token_program: collateral_token_program.clone(),
+                repay_liquidity_token_program: repay_liquidity_token_program.clone(),
+                withdraw_liquidity_token_program: withdraw_liquidity_token_program.clone(),
+                instruction_sysvar_account: instruction_sysvar_account.clone(),
+                remaining_accounts: remaining.to_vec(),
+            },
+        )
+    }
+}
+
+pub struct LiquidateObligationAndRedeemReserveCollateralInstructionAccountIndices {
+    pub liquidator: usize,
+    pub obligation: usize,
+    pub lending_market: usize,
+    pub lending_market_authority: usize,
+    pub repay_reserve: usize,
+    pub repay_reserve_liquidity_mint: usize,
+    pub repay_reserve_liquidity_supply: usize,
+    pub withdraw_reserve: usize,
+    pub withdraw_reserve_liquidity_mint: usize,
+    pub withdraw_reserve_collateral_mint: usize,
+    pub withdraw_reserve_collateral_supply: usize,
+    pub withdraw_reserve_liquidity_supply: usize,
+    pub withdraw_reserve_liquidity_fee_receiver: usize,
+    pub user_source_liquidity: usize,
+    pub user_destination_collateral: usize,
+    pub user_destination_liquidity: usize,
+    pub collateral_token_program: usize,
+    pub repay_liquidity_token_program: usize,
+    pub withdraw_liquidity_token_program: usize,
+    pub instruction_sysvar_account: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices
+    for LiquidateObligationAndRedeemReserveCollateral
+{
+    type ArrangedAccountIndices =
+        LiquidateObligationAndRedeemReserveCollateralInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 20 {
+            return None;
+        }
+
+        Some(
+            LiquidateObligationAndRedeemReserveCollateralInstructionAccountIndices {
+                liquidator: 0,
+                obligation: 1,
+                lending_market: 2,
+                lending_market_authority: 3,
+                repay_reserve: 4,
+                repay_reserve_liquidity_mint: 5,
+                repay_reserve_liquidity_supply: 6,
+                withdraw_reserve: 7,
+                withdraw_reserve_liquidity_mint: 8,
+                withdraw_reserve_collateral_mint: 9,
+                withdraw_reserve_collateral_supply: 10,
+                withdraw_reserve_liquidity_supply: 11,
+                withdraw_reserve_liquidity_fee_receiver: 12,
+                user_source_liquidity: 13,
+                user_destination_collateral: 14,
+                user_destination_liquidity: 15,
+                collateral_token_program: 16,
+                repay_liquidity_token_program: 17,
+                withdraw_liquidity_token_program: 18,
+                instruction_sysvar_account: 19,
+                remaining_accounts: 20..accounts.len(),
+            },
+        )
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for LiquidateObligationAndRedeemReserveCollateral {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "liquidator",
+            "obligation",
+            "lending_market",
+            "lending_market_authority",
+            "repay_reserve",
+            "repay_reserve_liquidity_mint",
+            "repay_reserve_liquidity_supply",
+            "withdraw_reserve",
+            "withdraw_reserve_liquidity_mint",
+            "withdraw_reserve_collateral_mint",
+            "withdraw_reserve_collateral_supply",
+            "withdraw_reserve_liquidity_supply",
+            "withdraw_reserve_liquidity_fee_receiver",
+            "user_source_liquidity",
+            "user_destination_collateral",
+            "user_destination_liquidity",
+            "collateral_token_program",
+            "repay_liquidity_token_program",
+            "withdraw_liquidity_token_program",
+            "instruction_sysvar_account",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +13,7 @@ pub struct MarkObligationForDeleveragingInstructionAccounts {
     pub risk_council: solana_pubkey::Pubkey,
     pub obligation: solana_pubkey::Pubkey,
     pub lending_market: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for MarkObligationForDeleveraging {
@@ -20,7 +22,7 @@ impl carbon_core::deserialize::ArrangeAccounts for MarkObligationForDeleveraging
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [risk_council, obligation, lending_market, _remaining @ ..] = accounts else {
+        let [risk_council, obligation, lending_market, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +30,65 @@ impl carbon_core::deserialize::ArrangeAccounts for MarkObligationForDeleveraging
             risk_council: risk_council.pubkey,
             obligation: obligation.pubkey,
             lending_market: lending_market.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct MarkObligationForDeleveragingInstructionAccountMetas {
+    pub risk_council: solana_instruction::AccountMeta,
+    pub obligation: solana_instruction::AccountMeta,
+    pub lending_market: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for MarkObligationForDeleveraging {
+    type ArrangedAccountMetas = MarkObligationForDeleveragingInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [risk_council, obligation, lending_market, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(MarkObligationForDeleveragingInstructionAccountMetas {
+            risk_council: risk_council.clone(),
+            obligation: obligation.clone(),
+            lending_market: lending_market.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct MarkObligationForDeleveragingInstructionAccountIndices {
+    pub risk_council: usize,
+    pub obligation: usize,
+    pub lending_market: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for MarkObligationForDeleveraging {
+    type ArrangedAccountIndices = MarkObligationForDeleveragingInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(MarkObligationForDeleveragingInstructionAccountIndices {
+            risk_council: 0,
+            obligation: 1,
+            lending_market: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for MarkObligationForDeleveraging {
+    fn account_names() -> &'static [&'static str] {
+        &["risk_council", "obligation", "lending_market"]
+    }
+}
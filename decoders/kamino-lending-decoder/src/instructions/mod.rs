@@ -88,15 +88,25 @@ pub enum KaminoLendingInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for KaminoLendingDecoder {
     type InstructionType = KaminoLendingInstruction;
 
-    fn decode_instruction(
+    fn decoder_name(&self) -> &'static str {
+        "kamino_lending"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[super::PROGRAM_ID]
+    }
+
+    fn try_decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
-    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+    ) -> carbon_core::error::CarbonResult<
+        Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>>,
+    > {
         if !instruction.program_id.eq(&PROGRAM_ID) {
-            return None;
+            return Ok(None);
         }
 
-        carbon_core::try_decode_instructions!(instruction,
+        Ok(carbon_core::try_decode_instructions!(instruction,
             KaminoLendingInstruction::InitLendingMarket => init_lending_market::InitLendingMarket,
             KaminoLendingInstruction::UpdateLendingMarket => update_lending_market::UpdateLendingMarket,
             KaminoLendingInstruction::UpdateLendingMarketOwner => update_lending_market_owner::UpdateLendingMarketOwner,
@@ -132,6 +142,6 @@ impl carbon_core::instruction::InstructionDecoder<'_> for KaminoLendingDecoder {
             KaminoLendingInstruction::InitReferrerStateAndShortUrl => init_referrer_state_and_short_url::InitReferrerStateAndShortUrl,
             KaminoLendingInstruction::DeleteReferrerStateAndShortUrl => delete_referrer_state_and_short_url::DeleteReferrerStateAndShortUrl,
             KaminoLendingInstruction::IdlMissingTypes => idl_missing_types::IdlMissingTypes,
-        )
+        ))
     }
 }
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct RedeemFeesInstructionAccounts {
     pub lending_market: solana_pubkey::Pubkey,
     pub lending_market_authority: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for RedeemFees {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for RedeemFees {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [reserve, reserve_liquidity_mint, reserve_liquidity_fee_receiver, reserve_supply_liquidity, lending_market, lending_market_authority, token_program, _remaining @ ..] =
+        let [reserve, reserve_liquidity_mint, reserve_liquidity_fee_receiver, reserve_supply_liquidity, lending_market, lending_market_authority, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -36,6 +38,91 @@ impl carbon_core::deserialize::ArrangeAccounts for RedeemFees {
             lending_market: lending_market.pubkey,
             lending_market_authority: lending_market_authority.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct RedeemFeesInstructionAccountMetas {
+    pub reserve: solana_instruction::AccountMeta,
+    pub reserve_liquidity_mint: solana_instruction::AccountMeta,
+    pub reserve_liquidity_fee_receiver: solana_instruction::AccountMeta,
+    pub reserve_supply_liquidity: solana_instruction::AccountMeta,
+    pub lending_market: solana_instruction::AccountMeta,
+    pub lending_market_authority: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for RedeemFees {
+    type ArrangedAccountMetas = RedeemFeesInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [reserve, reserve_liquidity_mint, reserve_liquidity_fee_receiver, reserve_supply_liquidity, lending_market, lending_market_authority, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(RedeemFeesInstructionAccountMetas {
+            reserve: reserve.clone(),
+            reserve_liquidity_mint: reserve_liquidity_mint.clone(),
+            reserve_liquidity_fee_receiver: reserve_liquidity_fee_receiver.clone(),
+            reserve_supply_liquidity: reserve_supply_liquidity.clone(),
+            lending_market: lending_market.clone(),
+            lending_market_authority: lending_market_authority.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct RedeemFeesInstructionAccountIndices {
+    pub reserve: usize,
+    pub reserve_liquidity_mint: usize,
+    pub reserve_liquidity_fee_receiver: usize,
+    pub reserve_supply_liquidity: usize,
+    pub lending_market: usize,
+    pub lending_market_authority: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for RedeemFees {
+    type ArrangedAccountIndices = RedeemFeesInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 7 {
+            return None;
+        }
+
+        Some(RedeemFeesInstructionAccountIndices {
+            reserve: 0,
+            reserve_liquidity_mint: 1,
+            reserve_liquidity_fee_receiver: 2,
+            reserve_supply_liquidity: 3,
+            lending_market: 4,
+            lending_market_authority: 5,
+            token_program: 6,
+            remaining_accounts: 7..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for RedeemFees {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "reserve",
+            "reserve_liquidity_mint",
+            "reserve_liquidity_fee_receiver",
+            "reserve_supply_liquidity",
+            "lending_market",
+            "lending_market_authority",
+            "token_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -21,6 +22,7 @@ pub struct RedeemReserveCollateralInstructionAccounts {
     pub collateral_token_program: solana_pubkey::Pubkey,
     pub liquidity_token_program: solana_pubkey::Pubkey,
     pub instruction_sysvar_account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for RedeemReserveCollateral {
@@ -29,7 +31,7 @@ impl carbon_core::deserialize::ArrangeAccounts for RedeemReserveCollateral {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [owner, lending_market, reserve, lending_market_authority, reserve_liquidity_mint, reserve_collateral_mint, reserve_liquidity_supply, user_source_collateral, user_destination_liquidity, collateral_token_program, liquidity_token_program, instruction_sysvar_account, _remaining @ ..] =
+        let [owner, lending_market, reserve, lending_market_authority, reserve_liquidity_mint, reserve_collateral_mint, reserve_liquidity_supply, user_source_collateral, user_destination_liquidity, collateral_token_program, liquidity_token_program, instruction_sysvar_account, remaining @ ..] =
             accounts
         else {
             return None;
@@ -48,6 +50,116 @@ impl carbon_core::deserialize::ArrangeAccounts for RedeemReserveCollateral {
             collateral_token_program: collateral_token_program.pubkey,
             liquidity_token_program: liquidity_token_program.pubkey,
             instruction_sysvar_account: instruction_sysvar_account.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct RedeemReserveCollateralInstructionAccountMetas {
+    pub owner: solana_instruction::AccountMeta,
+    pub lending_market: solana_instruction::AccountMeta,
+    pub reserve: solana_instruction::AccountMeta,
+    pub lending_market_authority: solana_instruction::AccountMeta,
+    pub reserve_liquidity_mint: solana_instruction::AccountMeta,
+    pub reserve_collateral_mint: solana_instruction::AccountMeta,
+    pub reserve_liquidity_supply: solana_instruction::AccountMeta,
+    pub user_source_collateral: solana_instruction::AccountMeta,
+    pub user_destination_liquidity: solana_instruction::AccountMeta,
+    pub collateral_token_program: solana_instruction::AccountMeta,
+    pub liquidity_token_program: solana_instruction::AccountMeta,
+    pub instruction_sysvar_account: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for RedeemReserveCollateral {
+    type ArrangedAccountMetas = RedeemReserveCollateralInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [owner, lending_market, reserve, lending_market_authority, reserve_liquidity_mint, reserve_collateral_mint, reserve_liquidity_supply, user_source_collateral, user_destination_liquidity, collateral_token_program, liquidity_token_program, instruction_sysvar_account, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(RedeemReserveCollateralInstructionAccountMetas {
+            owner: owner.clone(),
+            lending_market: lending_market.clone(),
+            reserve: reserve.clone(),
+            lending_market_authority: lending_market_authority.clone(),
+            reserve_liquidity_mint: reserve_liquidity_mint.clone(),
+            reserve_collateral_mint: reserve_collateral_mint.clone(),
+            reserve_liquidity_supply: reserve_liquidity_supply.clone(),
+            user_source_collateral: user_source_collateral.clone(),
+            user_destination_liquidity: user_destination_liquidity.clone(),
+            collateral_token_program: collateral_token_program.clone(),
+            liquidity_token_program: liquidity_token_program.clone(),
+            instruction_sysvar_account: instruction_sysvar_account.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct RedeemReserveCollateralInstructionAccountIndices {
+    pub owner: usize,
+    pub lending_market: usize,
+    pub reserve: usize,
+    pub lending_market_authority: usize,
+    pub reserve_liquidity_mint: usize,
+    pub reserve_collateral_mint: usize,
+    pub reserve_liquidity_supply: usize,
+    pub user_source_collateral: usize,
+    pub user_destination_liquidity: usize,
+    pub collateral_token_program: usize,
+    pub liquidity_token_program: usize,
+    pub instruction_sysvar_account: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for RedeemReserveCollateral {
+    type ArrangedAccountIndices = RedeemReserveCollateralInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 12 {
+            return None;
+        }
+
+        Some(RedeemReserveCollateralInstructionAccountIndices {
+            owner: 0,
+            lending_market: 1,
+            reserve: 2,
+            lending_market_authority: 3,
+            reserve_liquidity_mint: 4,
+            reserve_collateral_mint: 5,
+            reserve_liquidity_supply: 6,
+            user_source_collateral: 7,
+            user_destination_liquidity: 8,
+            collateral_token_program: 9,
+            liquidity_token_program: 10,
+            instruction_sysvar_account: 11,
+            remaining_accounts: 12..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for RedeemReserveCollateral {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "owner",
+            "lending_market",
+            "reserve",
+            "lending_market_authority",
+            "reserve_liquidity_mint",
+            "reserve_collateral_mint",
+            "reserve_liquidity_supply",
+            "user_source_collateral",
+            "user_destination_liquidity",
+            "collateral_token_program",
+            "liquidity_token_program",
+            "instruction_sysvar_account",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -9,6 +10,7 @@ pub struct RefreshObligation {}
 pub struct RefreshObligationInstructionAccounts {
     pub lending_market: solana_pubkey::Pubkey,
     pub obligation: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for RefreshObligation {
@@ -17,13 +19,68 @@ impl carbon_core::deserialize::ArrangeAccounts for RefreshObligation {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [lending_market, obligation, _remaining @ ..] = accounts else {
+        let [lending_market, obligation, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(RefreshObligationInstructionAccounts {
             lending_market: lending_market.pubkey,
             obligation: obligation.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct RefreshObligationInstructionAccountMetas {
+    pub lending_market: solana_instruction::AccountMeta,
+    pub obligation: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for RefreshObligation {
+    type ArrangedAccountMetas = RefreshObligationInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [lending_market, obligation, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(RefreshObligationInstructionAccountMetas {
+            lending_market: lending_market.clone(),
+            obligation: obligation.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct RefreshObligationInstructionAccountIndices {
+    pub lending_market: usize,
+    pub obligation: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for RefreshObligation {
+    type ArrangedAccountIndices = RefreshObligationInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(RefreshObligationInstructionAccountIndices {
+            lending_market: 0,
+            obligation: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for RefreshObligation {
+    fn account_names() -> &'static [&'static str] {
+        &["lending_market", "obligation"]
+    }
+}
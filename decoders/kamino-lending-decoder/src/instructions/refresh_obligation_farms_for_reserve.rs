@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -19,6 +20,7 @@ pub struct RefreshObligationFarmsForReserveInstructionAccounts {
     pub farms_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for RefreshObligationFarmsForReserve {
@@ -27,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for RefreshObligationFarmsForRese
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [crank, obligation, lending_market_authority, reserve, reserve_farm_state, obligation_farm_user_state, lending_market, farms_program, rent, system_program, _remaining @ ..] =
+        let [crank, obligation, lending_market_authority, reserve, reserve_farm_state, obligation_farm_user_state, lending_market, farms_program, rent, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -44,6 +46,106 @@ impl carbon_core::deserialize::ArrangeAccounts for RefreshObligationFarmsForRese
             farms_program: farms_program.pubkey,
             rent: rent.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct RefreshObligationFarmsForReserveInstructionAccountMetas {
+    pub crank: solana_instruction::AccountMeta,
+    pub obligation: solana_instruction::AccountMeta,
+    pub lending_market_authority: solana_instruction::AccountMeta,
+    pub reserve: solana_instruction::AccountMeta,
+    pub reserve_farm_state: solana_instruction::AccountMeta,
+    pub obligation_farm_user_state: solana_instruction::AccountMeta,
+    pub lending_market: solana_instruction::AccountMeta,
+    pub farms_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for RefreshObligationFarmsForReserve {
+    type ArrangedAccountMetas = RefreshObligationFarmsForReserveInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [crank, obligation, lending_market_authority, reserve, reserve_farm_state, obligation_farm_user_state, lending_market, farms_program, rent, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(RefreshObligationFarmsForReserveInstructionAccountMetas {
+            crank: crank.clone(),
+            obligation: obligation.clone(),
+            lending_market_authority: lending_market_authority.clone(),
+            reserve: reserve.clone(),
+            reserve_farm_state: reserve_farm_state.clone(),
+            obligation_farm_user_state: obligation_farm_user_state.clone(),
+            lending_market: lending_market.clone(),
+            farms_program: farms_program.clone(),
+            rent: rent.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct RefreshObligationFarmsForReserveInstructionAccountIndices {
+    pub crank: usize,
+    pub obligation: usize,
+    pub lending_market_authority: usize,
+    pub reserve: usize,
+    pub reserve_farm_state: usize,
+    pub obligation_farm_user_state: usize,
+    pub lending_market: usize,
+    pub farms_program: usize,
+    pub rent: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for RefreshObligationFarmsForReserve {
+    type ArrangedAccountIndices = RefreshObligationFarmsForReserveInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 10 {
+            return None;
+        }
+
+        Some(RefreshObligationFarmsForReserveInstructionAccountIndices {
+            crank: 0,
+            obligation: 1,
+            lending_market_authority: 2,
+            reserve: 3,
+            reserve_farm_state: 4,
+            obligation_farm_user_state: 5,
+            lending_market: 6,
+            farms_program: 7,
+            rent: 8,
+            system_program: 9,
+            remaining_accounts: 10..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for RefreshObligationFarmsForReserve {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "crank",
+            "obligation",
+            "lending_market_authority",
+            "reserve",
+            "reserve_farm_state",
+            "obligation_farm_user_state",
+            "lending_market",
+            "farms_program",
+            "rent",
+            "system_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +14,7 @@ pub struct RefreshReserveInstructionAccounts {
     pub switchboard_price_oracle: solana_pubkey::Pubkey,
     pub switchboard_twap_oracle: solana_pubkey::Pubkey,
     pub scope_prices: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for RefreshReserve {
@@ -21,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for RefreshReserve {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [reserve, lending_market, pyth_oracle, switchboard_price_oracle, switchboard_twap_oracle, scope_prices, _remaining @ ..] =
+        let [reserve, lending_market, pyth_oracle, switchboard_price_oracle, switchboard_twap_oracle, scope_prices, remaining @ ..] =
             accounts
         else {
             return None;
@@ -34,6 +36,86 @@ impl carbon_core::deserialize::ArrangeAccounts for RefreshReserve {
             switchboard_price_oracle: switchboard_price_oracle.pubkey,
             switchboard_twap_oracle: switchboard_twap_oracle.pubkey,
             scope_prices: scope_prices.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct RefreshReserveInstructionAccountMetas {
+    pub reserve: solana_instruction::AccountMeta,
+    pub lending_market: solana_instruction::AccountMeta,
+    pub pyth_oracle: solana_instruction::AccountMeta,
+    pub switchboard_price_oracle: solana_instruction::AccountMeta,
+    pub switchboard_twap_oracle: solana_instruction::AccountMeta,
+    pub scope_prices: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for RefreshReserve {
+    type ArrangedAccountMetas = RefreshReserveInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [reserve, lending_market, pyth_oracle, switchboard_price_oracle, switchboard_twap_oracle, scope_prices, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(RefreshReserveInstructionAccountMetas {
+            reserve: reserve.clone(),
+            lending_market: lending_market.clone(),
+            pyth_oracle: pyth_oracle.clone(),
+            switchboard_price_oracle: switchboard_price_oracle.clone(),
+            switchboard_twap_oracle: switchboard_twap_oracle.clone(),
+            scope_prices: scope_prices.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct RefreshReserveInstructionAccountIndices {
+    pub reserve: usize,
+    pub lending_market: usize,
+    pub pyth_oracle: usize,
+    pub switchboard_price_oracle: usize,
+    pub switchboard_twap_oracle: usize,
+    pub scope_prices: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for RefreshReserve {
+    type ArrangedAccountIndices = RefreshReserveInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(RefreshReserveInstructionAccountIndices {
+            reserve: 0,
+            lending_market: 1,
+            pyth_oracle: 2,
+            switchboard_price_oracle: 3,
+            switchboard_twap_oracle: 4,
+            scope_prices: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for RefreshReserve {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "reserve",
+            "lending_market",
+            "pyth_oracle",
+            "switchboard_price_oracle",
+            "switchboard_twap_oracle",
+            "scope_prices",
+        ]
+    }
+}
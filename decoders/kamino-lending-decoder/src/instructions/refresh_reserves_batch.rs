@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -8,14 +9,56 @@ pub struct RefreshReservesBatch {
     pub skip_price_updates: bool,
 }
 
-pub struct RefreshReservesBatchInstructionAccounts {}
+pub struct RefreshReservesBatchInstructionAccounts {
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
 
 impl carbon_core::deserialize::ArrangeAccounts for RefreshReservesBatch {
     type ArrangedAccounts = RefreshReservesBatchInstructionAccounts;
 
     fn arrange_accounts(
-        _accounts: &[solana_instruction::AccountMeta],
+        accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        Some(RefreshReservesBatchInstructionAccounts {})
+        Some(RefreshReservesBatchInstructionAccounts {
+            remaining_accounts: accounts.to_vec(),
+        })
+    }
+}
+
+pub struct RefreshReservesBatchInstructionAccountMetas {
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for RefreshReservesBatch {
+    type ArrangedAccountMetas = RefreshReservesBatchInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        Some(RefreshReservesBatchInstructionAccountMetas {
+            remaining_accounts: accounts.to_vec(),
+        })
+    }
+}
+
+pub struct RefreshReservesBatchInstructionAccountIndices {
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for RefreshReservesBatch {
+    type ArrangedAccountIndices = RefreshReservesBatchInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        Some(RefreshReservesBatchInstructionAccountIndices {
+            remaining_accounts: 0..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for RefreshReservesBatch {
+    fn account_names() -> &'static [&'static str] {
+        &[]
     }
 }
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +13,7 @@ pub struct RepayAndWithdrawAndRedeem {
 pub struct RepayAndWithdrawAndRedeemInstructionAccounts {
     pub repay_accounts: solana_pubkey::Pubkey,
     pub withdraw_accounts: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for RepayAndWithdrawAndRedeem {
@@ -20,13 +22,68 @@ impl carbon_core::deserialize::ArrangeAccounts for RepayAndWithdrawAndRedeem {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [repay_accounts, withdraw_accounts, _remaining @ ..] = accounts else {
+        let [repay_accounts, withdraw_accounts, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(RepayAndWithdrawAndRedeemInstructionAccounts {
             repay_accounts: repay_accounts.pubkey,
             withdraw_accounts: withdraw_accounts.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct RepayAndWithdrawAndRedeemInstructionAccountMetas {
+    pub repay_accounts: solana_instruction::AccountMeta,
+    pub withdraw_accounts: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for RepayAndWithdrawAndRedeem {
+    type ArrangedAccountMetas = RepayAndWithdrawAndRedeemInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [repay_accounts, withdraw_accounts, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(RepayAndWithdrawAndRedeemInstructionAccountMetas {
+            repay_accounts: repay_accounts.clone(),
+            withdraw_accounts: withdraw_accounts.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct RepayAndWithdrawAndRedeemInstructionAccountIndices {
+    pub repay_accounts: usize,
+    pub withdraw_accounts: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for RepayAndWithdrawAndRedeem {
+    type ArrangedAccountIndices = RepayAndWithdrawAndRedeemInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(RepayAndWithdrawAndRedeemInstructionAccountIndices {
+            repay_accounts: 0,
+            withdraw_accounts: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for RepayAndWithdrawAndRedeem {
+    fn account_names() -> &'static [&'static str] {
+        &["repay_accounts", "withdraw_accounts"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -18,6 +19,7 @@ pub struct RepayObligationLiquidityInstructionAccounts {
     pub user_source_liquidity: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub instruction_sysvar_account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for RepayObligationLiquidity {
@@ -26,7 +28,7 @@ impl carbon_core::deserialize::ArrangeAccounts for RepayObligationLiquidity {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [owner, obligation, lending_market, repay_reserve, reserve_liquidity_mint, reserve_destination_liquidity, user_source_liquidity, token_program, instruction_sysvar_account, _remaining @ ..] =
+        let [owner, obligation, lending_market, repay_reserve, reserve_liquidity_mint, reserve_destination_liquidity, user_source_liquidity, token_program, instruction_sysvar_account, remaining @ ..] =
             accounts
         else {
             return None;
@@ -42,6 +44,101 @@ impl carbon_core::deserialize::ArrangeAccounts for RepayObligationLiquidity {
             user_source_liquidity: user_source_liquidity.pubkey,
             token_program: token_program.pubkey,
             instruction_sysvar_account: instruction_sysvar_account.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct RepayObligationLiquidityInstructionAccountMetas {
+    pub owner: solana_instruction::AccountMeta,
+    pub obligation: solana_instruction::AccountMeta,
+    pub lending_market: solana_instruction::AccountMeta,
+    pub repay_reserve: solana_instruction::AccountMeta,
+    pub reserve_liquidity_mint: solana_instruction::AccountMeta,
+    pub reserve_destination_liquidity: solana_instruction::AccountMeta,
+    pub user_source_liquidity: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub instruction_sysvar_account: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for RepayObligationLiquidity {
+    type ArrangedAccountMetas = RepayObligationLiquidityInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [owner, obligation, lending_market, repay_reserve, reserve_liquidity_mint, reserve_destination_liquidity, user_source_liquidity, token_program, instruction_sysvar_account, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(RepayObligationLiquidityInstructionAccountMetas {
+            owner: owner.clone(),
+            obligation: obligation.clone(),
+            lending_market: lending_market.clone(),
+            repay_reserve: repay_reserve.clone(),
+            reserve_liquidity_mint: reserve_liquidity_mint.clone(),
+            reserve_destination_liquidity: reserve_destination_liquidity.clone(),
+            user_source_liquidity: user_source_liquidity.clone(),
+            token_program: token_program.clone(),
+            instruction_sysvar_account: instruction_sysvar_account.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct RepayObligationLiquidityInstructionAccountIndices {
+    pub owner: usize,
+    pub obligation: usize,
+    pub lending_market: usize,
+    pub repay_reserve: usize,
+    pub reserve_liquidity_mint: usize,
+    pub reserve_destination_liquidity: usize,
+    pub user_source_liquidity: usize,
+    pub token_program: usize,
+    pub instruction_sysvar_account: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for RepayObligationLiquidity {
+    type ArrangedAccountIndices = RepayObligationLiquidityInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 9 {
+            return None;
+        }
+
+        Some(RepayObligationLiquidityInstructionAccountIndices {
+            owner: 0,
+            obligation: 1,
+            lending_market: 2,
+            repay_reserve: 3,
+            reserve_liquidity_mint: 4,
+            reserve_destination_liquidity: 5,
+            user_source_liquidity: 6,
+            token_program: 7,
+            instruction_sysvar_account: 8,
+            remaining_accounts: 9..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for RepayObligationLiquidity {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "owner",
+            "obligation",
+            "lending_market",
+            "repay_reserve",
+            "reserve_liquidity_mint",
+            "reserve_destination_liquidity",
+            "user_source_liquidity",
+            "token_program",
+            "instruction_sysvar_account",
+        ]
+    }
+}
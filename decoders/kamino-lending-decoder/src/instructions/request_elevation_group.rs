@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +13,7 @@ pub struct RequestElevationGroupInstructionAccounts {
     pub owner: solana_pubkey::Pubkey,
     pub obligation: solana_pubkey::Pubkey,
     pub lending_market: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for RequestElevationGroup {
@@ -20,7 +22,7 @@ impl carbon_core::deserialize::ArrangeAccounts for RequestElevationGroup {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [owner, obligation, lending_market, _remaining @ ..] = accounts else {
+        let [owner, obligation, lending_market, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +30,65 @@ impl carbon_core::deserialize::ArrangeAccounts for RequestElevationGroup {
             owner: owner.pubkey,
             obligation: obligation.pubkey,
             lending_market: lending_market.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct RequestElevationGroupInstructionAccountMetas {
+    pub owner: solana_instruction::AccountMeta,
+    pub obligation: solana_instruction::AccountMeta,
+    pub lending_market: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for RequestElevationGroup {
+    type ArrangedAccountMetas = RequestElevationGroupInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [owner, obligation, lending_market, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(RequestElevationGroupInstructionAccountMetas {
+            owner: owner.clone(),
+            obligation: obligation.clone(),
+            lending_market: lending_market.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct RequestElevationGroupInstructionAccountIndices {
+    pub owner: usize,
+    pub obligation: usize,
+    pub lending_market: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for RequestElevationGroup {
+    type ArrangedAccountIndices = RequestElevationGroupInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(RequestElevationGroupInstructionAccountIndices {
+            owner: 0,
+            obligation: 1,
+            lending_market: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for RequestElevationGroup {
+    fn account_names() -> &'static [&'static str] {
+        &["owner", "obligation", "lending_market"]
+    }
+}
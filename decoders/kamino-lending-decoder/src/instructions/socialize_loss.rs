@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct SocializeLossInstructionAccounts {
     pub lending_market: solana_pubkey::Pubkey,
     pub reserve: solana_pubkey::Pubkey,
     pub instruction_sysvar_account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SocializeLoss {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for SocializeLoss {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [risk_council, obligation, lending_market, reserve, instruction_sysvar_account, _remaining @ ..] =
+        let [risk_council, obligation, lending_market, reserve, instruction_sysvar_account, remaining @ ..] =
             accounts
         else {
             return None;
@@ -34,6 +36,81 @@ impl carbon_core::deserialize::ArrangeAccounts for SocializeLoss {
             lending_market: lending_market.pubkey,
             reserve: reserve.pubkey,
             instruction_sysvar_account: instruction_sysvar_account.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SocializeLossInstructionAccountMetas {
+    pub risk_council: solana_instruction::AccountMeta,
+    pub obligation: solana_instruction::AccountMeta,
+    pub lending_market: solana_instruction::AccountMeta,
+    pub reserve: solana_instruction::AccountMeta,
+    pub instruction_sysvar_account: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SocializeLoss {
+    type ArrangedAccountMetas = SocializeLossInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [risk_council, obligation, lending_market, reserve, instruction_sysvar_account, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(SocializeLossInstructionAccountMetas {
+            risk_council: risk_council.clone(),
+            obligation: obligation.clone(),
+            lending_market: lending_market.clone(),
+            reserve: reserve.clone(),
+            instruction_sysvar_account: instruction_sysvar_account.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SocializeLossInstructionAccountIndices {
+    pub risk_council: usize,
+    pub obligation: usize,
+    pub lending_market: usize,
+    pub reserve: usize,
+    pub instruction_sysvar_account: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SocializeLoss {
+    type ArrangedAccountIndices = SocializeLossInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(SocializeLossInstructionAccountIndices {
+            risk_council: 0,
+            obligation: 1,
+            lending_market: 2,
+            reserve: 3,
+            instruction_sysvar_account: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SocializeLoss {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "risk_council",
+            "obligation",
+            "lending_market",
+            "reserve",
+            "instruction_sysvar_account",
+        ]
+    }
+}
@@ -3,6 +3,7 @@ use {
     serde_big_array::BigArray,
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -16,6 +17,7 @@ pub struct UpdateLendingMarket {
 pub struct UpdateLendingMarketInstructionAccounts {
     pub lending_market_owner: solana_pubkey::Pubkey,
     pub lending_market: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateLendingMarket {
@@ -24,13 +26,68 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateLendingMarket {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [lending_market_owner, lending_market, _remaining @ ..] = accounts else {
+        let [lending_market_owner, lending_market, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(UpdateLendingMarketInstructionAccounts {
             lending_market_owner: lending_market_owner.pubkey,
             lending_market: lending_market.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateLendingMarketInstructionAccountMetas {
+    pub lending_market_owner: solana_instruction::AccountMeta,
+    pub lending_market: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateLendingMarket {
+    type ArrangedAccountMetas = UpdateLendingMarketInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [lending_market_owner, lending_market, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateLendingMarketInstructionAccountMetas {
+            lending_market_owner: lending_market_owner.clone(),
+            lending_market: lending_market.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateLendingMarketInstructionAccountIndices {
+    pub lending_market_owner: usize,
+    pub lending_market: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateLendingMarket {
+    type ArrangedAccountIndices = UpdateLendingMarketInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(UpdateLendingMarketInstructionAccountIndices {
+            lending_market_owner: 0,
+            lending_market: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateLendingMarket {
+    fn account_names() -> &'static [&'static str] {
+        &["lending_market_owner", "lending_market"]
+    }
+}
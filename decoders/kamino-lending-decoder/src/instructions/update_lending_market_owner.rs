@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -9,6 +10,7 @@ pub struct UpdateLendingMarketOwner {}
 pub struct UpdateLendingMarketOwnerInstructionAccounts {
     pub lending_market_owner_cached: solana_pubkey::Pubkey,
     pub lending_market: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateLendingMarketOwner {
@@ -17,13 +19,68 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateLendingMarketOwner {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [lending_market_owner_cached, lending_market, _remaining @ ..] = accounts else {
+        let [lending_market_owner_cached, lending_market, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(UpdateLendingMarketOwnerInstructionAccounts {
             lending_market_owner_cached: lending_market_owner_cached.pubkey,
             lending_market: lending_market.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateLendingMarketOwnerInstructionAccountMetas {
+    pub lending_market_owner_cached: solana_instruction::AccountMeta,
+    pub lending_market: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateLendingMarketOwner {
+    type ArrangedAccountMetas = UpdateLendingMarketOwnerInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [lending_market_owner_cached, lending_market, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateLendingMarketOwnerInstructionAccountMetas {
+            lending_market_owner_cached: lending_market_owner_cached.clone(),
+            lending_market: lending_market.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateLendingMarketOwnerInstructionAccountIndices {
+    pub lending_market_owner_cached: usize,
+    pub lending_market: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateLendingMarketOwner {
+    type ArrangedAccountIndices = UpdateLendingMarketOwnerInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(UpdateLendingMarketOwnerInstructionAccountIndices {
+            lending_market_owner_cached: 0,
+            lending_market: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateLendingMarketOwner {
+    fn account_names() -> &'static [&'static str] {
+        &["lending_market_owner_cached", "lending_market"]
+    }
+}
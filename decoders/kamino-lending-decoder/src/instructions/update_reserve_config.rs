@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct UpdateReserveConfigInstructionAccounts {
     pub lending_market_owner: solana_pubkey::Pubkey,
     pub lending_market: solana_pubkey::Pubkey,
     pub reserve: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateReserveConfig {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateReserveConfig {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [lending_market_owner, lending_market, reserve, _remaining @ ..] = accounts else {
+        let [lending_market_owner, lending_market, reserve, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -30,6 +32,65 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateReserveConfig {
             lending_market_owner: lending_market_owner.pubkey,
             lending_market: lending_market.pubkey,
             reserve: reserve.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateReserveConfigInstructionAccountMetas {
+    pub lending_market_owner: solana_instruction::AccountMeta,
+    pub lending_market: solana_instruction::AccountMeta,
+    pub reserve: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateReserveConfig {
+    type ArrangedAccountMetas = UpdateReserveConfigInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [lending_market_owner, lending_market, reserve, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateReserveConfigInstructionAccountMetas {
+            lending_market_owner: lending_market_owner.clone(),
+            lending_market: lending_market.clone(),
+            reserve: reserve.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateReserveConfigInstructionAccountIndices {
+    pub lending_market_owner: usize,
+    pub lending_market: usize,
+    pub reserve: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateReserveConfig {
+    type ArrangedAccountIndices = UpdateReserveConfigInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(UpdateReserveConfigInstructionAccountIndices {
+            lending_market_owner: 0,
+            lending_market: 1,
+            reserve: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateReserveConfig {
+    fn account_names() -> &'static [&'static str] {
+        &["lending_market_owner", "lending_market", "reserve"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -18,6 +19,7 @@ pub struct WithdrawObligationCollateralInstructionAccounts {
     pub user_destination_collateral: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub instruction_sysvar_account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for WithdrawObligationCollateral {
@@ -26,7 +28,7 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawObligationCollateral
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [owner, obligation, lending_market, lending_market_authority, withdraw_reserve, reserve_source_collateral, user_destination_collateral, token_program, instruction_sysvar_account, _remaining @ ..] =
+        let [owner, obligation, lending_market, lending_market_authority, withdraw_reserve, reserve_source_collateral, user_destination_collateral, token_program, instruction_sysvar_account, remaining @ ..] =
             accounts
         else {
             return None;
@@ -42,6 +44,101 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawObligationCollateral
             user_destination_collateral: user_destination_collateral.pubkey,
             token_program: token_program.pubkey,
             instruction_sysvar_account: instruction_sysvar_account.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct WithdrawObligationCollateralInstructionAccountMetas {
+    pub owner: solana_instruction::AccountMeta,
+    pub obligation: solana_instruction::AccountMeta,
+    pub lending_market: solana_instruction::AccountMeta,
+    pub lending_market_authority: solana_instruction::AccountMeta,
+    pub withdraw_reserve: solana_instruction::AccountMeta,
+    pub reserve_source_collateral: solana_instruction::AccountMeta,
+    pub user_destination_collateral: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub instruction_sysvar_account: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for WithdrawObligationCollateral {
+    type ArrangedAccountMetas = WithdrawObligationCollateralInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [owner, obligation, lending_market, lending_market_authority, withdraw_reserve, reserve_source_collateral, user_destination_collateral, token_program, instruction_sysvar_account, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(WithdrawObligationCollateralInstructionAccountMetas {
+            owner: owner.clone(),
+            obligation: obligation.clone(),
+            lending_market: lending_market.clone(),
+            lending_market_authority: lending_market_authority.clone(),
+            withdraw_reserve: withdraw_reserve.clone(),
+            reserve_source_collateral: reserve_source_collateral.clone(),
+            user_destination_collateral: user_destination_collateral.clone(),
+            token_program: token_program.clone(),
+            instruction_sysvar_account: instruction_sysvar_account.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct WithdrawObligationCollateralInstructionAccountIndices {
+    pub owner: usize,
+    pub obligation: usize,
+    pub lending_market: usize,
+    pub lending_market_authority: usize,
+    pub withdraw_reserve: usize,
+    pub reserve_source_collateral: usize,
+    pub user_destination_collateral: usize,
+    pub token_program: usize,
+    pub instruction_sysvar_account: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for WithdrawObligationCollateral {
+    type ArrangedAccountIndices = WithdrawObligationCollateralInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 9 {
+            return None;
+        }
+
+        Some(WithdrawObligationCollateralInstructionAccountIndices {
+            owner: 0,
+            obligation: 1,
+            lending_market: 2,
+            lending_market_authority: 3,
+            withdraw_reserve: 4,
+            reserve_source_collateral: 5,
+            user_destination_collateral: 6,
+            token_program: 7,
+            instruction_sysvar_account: 8,
+            remaining_accounts: 9..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for WithdrawObligationCollateral {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "owner",
+            "obligation",
+            "lending_market",
+            "lending_market_authority",
+            "withdraw_reserve",
+            "reserve_source_collateral",
+            "user_destination_collateral",
+            "token_program",
+            "instruction_sysvar_account",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -17,6 +18,7 @@ pub struct WithdrawProtocolFeeInstructionAccounts {
     pub fee_vault: solana_pubkey::Pubkey,
     pub lending_market_owner_ata: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for WithdrawProtocolFee {
@@ -25,7 +27,7 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawProtocolFee {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [lending_market_owner, lending_market, reserve, reserve_liquidity_mint, lending_market_authority, fee_vault, lending_market_owner_ata, token_program, _remaining @ ..] =
+        let [lending_market_owner, lending_market, reserve, reserve_liquidity_mint, lending_market_authority, fee_vault, lending_market_owner_ata, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -40,6 +42,96 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawProtocolFee {
             fee_vault: fee_vault.pubkey,
             lending_market_owner_ata: lending_market_owner_ata.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct WithdrawProtocolFeeInstructionAccountMetas {
+    pub lending_market_owner: solana_instruction::AccountMeta,
+    pub lending_market: solana_instruction::AccountMeta,
+    pub reserve: solana_instruction::AccountMeta,
+    pub reserve_liquidity_mint: solana_instruction::AccountMeta,
+    pub lending_market_authority: solana_instruction::AccountMeta,
+    pub fee_vault: solana_instruction::AccountMeta,
+    pub lending_market_owner_ata: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for WithdrawProtocolFee {
+    type ArrangedAccountMetas = WithdrawProtocolFeeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [lending_market_owner, lending_market, reserve, reserve_liquidity_mint, lending_market_authority, fee_vault, lending_market_owner_ata, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(WithdrawProtocolFeeInstructionAccountMetas {
+            lending_market_owner: lending_market_owner.clone(),
+            lending_market: lending_market.clone(),
+            reserve: reserve.clone(),
+            reserve_liquidity_mint: reserve_liquidity_mint.clone(),
+            lending_market_authority: lending_market_authority.clone(),
+            fee_vault: fee_vault.clone(),
+            lending_market_owner_ata: lending_market_owner_ata.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct WithdrawProtocolFeeInstructionAccountIndices {
+    pub lending_market_owner: usize,
+    pub lending_market: usize,
+    pub reserve: usize,
+    pub reserve_liquidity_mint: usize,
+    pub lending_market_authority: usize,
+    pub fee_vault: usize,
+    pub lending_market_owner_ata: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for WithdrawProtocolFee {
+    type ArrangedAccountIndices = WithdrawProtocolFeeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(WithdrawProtocolFeeInstructionAccountIndices {
+            lending_market_owner: 0,
+            lending_market: 1,
+            reserve: 2,
+            reserve_liquidity_mint: 3,
+            lending_market_authority: 4,
+            fee_vault: 5,
+            lending_market_owner_ata: 6,
+            token_program: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for WithdrawProtocolFee {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "lending_market_owner",
+            "lending_market",
+            "reserve",
+            "reserve_liquidity_mint",
+            "lending_market_authority",
+            "fee_vault",
+            "lending_market_owner_ata",
+            "token_program",
+        ]
+    }
+}
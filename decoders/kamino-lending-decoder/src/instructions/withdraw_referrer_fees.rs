@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -16,6 +17,7 @@ pub struct WithdrawReferrerFeesInstructionAccounts {
     pub lending_market: solana_pubkey::Pubkey,
     pub lending_market_authority: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for WithdrawReferrerFees {
@@ -24,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawReferrerFees {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [referrer, referrer_token_state, reserve, reserve_liquidity_mint, reserve_supply_liquidity, referrer_token_account, lending_market, lending_market_authority, token_program, _remaining @ ..] =
+        let [referrer, referrer_token_state, reserve, reserve_liquidity_mint, reserve_supply_liquidity, referrer_token_account, lending_market, lending_market_authority, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -40,6 +42,101 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawReferrerFees {
             lending_market: lending_market.pubkey,
             lending_market_authority: lending_market_authority.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct WithdrawReferrerFeesInstructionAccountMetas {
+    pub referrer: solana_instruction::AccountMeta,
+    pub referrer_token_state: solana_instruction::AccountMeta,
+    pub reserve: solana_instruction::AccountMeta,
+    pub reserve_liquidity_mint: solana_instruction::AccountMeta,
+    pub reserve_supply_liquidity: solana_instruction::AccountMeta,
+    pub referrer_token_account: solana_instruction::AccountMeta,
+    pub lending_market: solana_instruction::AccountMeta,
+    pub lending_market_authority: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for WithdrawReferrerFees {
+    type ArrangedAccountMetas = WithdrawReferrerFeesInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [referrer, referrer_token_state, reserve, reserve_liquidity_mint, reserve_supply_liquidity, referrer_token_account, lending_market, lending_market_authority, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(WithdrawReferrerFeesInstructionAccountMetas {
+            referrer: referrer.clone(),
+            referrer_token_state: referrer_token_state.clone(),
+            reserve: reserve.clone(),
+            reserve_liquidity_mint: reserve_liquidity_mint.clone(),
+            reserve_supply_liquidity: reserve_supply_liquidity.clone(),
+            referrer_token_account: referrer_token_account.clone(),
+            lending_market: lending_market.clone(),
+            lending_market_authority: lending_market_authority.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct WithdrawReferrerFeesInstructionAccountIndices {
+    pub referrer: usize,
+    pub referrer_token_state: usize,
+    pub reserve: usize,
+    pub reserve_liquidity_mint: usize,
+    pub reserve_supply_liquidity: usize,
+    pub referrer_token_account: usize,
+    pub lending_market: usize,
+    pub lending_market_authority: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for WithdrawReferrerFees {
+    type ArrangedAccountIndices = WithdrawReferrerFeesInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 9 {
+            return None;
+        }
+
+        Some(WithdrawReferrerFeesInstructionAccountIndices {
+            referrer: 0,
+            referrer_token_state: 1,
+            reserve: 2,
+            reserve_liquidity_mint: 3,
+            reserve_supply_liquidity: 4,
+            referrer_token_account: 5,
+            lending_market: 6,
+            lending_market_authority: 7,
+            token_program: 8,
+            remaining_accounts: 9..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for WithdrawReferrerFees {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "referrer",
+            "referrer_token_state",
+            "reserve",
+            "reserve_liquidity_mint",
+            "reserve_supply_liquidity",
+            "referrer_token_account",
+            "lending_market",
+            "lending_market_authority",
+            "token_program",
+        ]
+    }
+}
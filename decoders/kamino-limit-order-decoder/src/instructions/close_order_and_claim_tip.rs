@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -19,6 +20,7 @@ pub struct CloseOrderAndClaimTipInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CloseOrderAndClaimTip {
@@ -27,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CloseOrderAndClaimTip {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [maker, order, global_config, pda_authority, input_mint, output_mint, maker_input_ata, input_vault, input_token_program, system_program, event_authority, program, _remaining @ ..] =
+        let [maker, order, global_config, pda_authority, input_mint, output_mint, maker_input_ata, input_vault, input_token_program, system_program, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -46,6 +48,116 @@ impl carbon_core::deserialize::ArrangeAccounts for CloseOrderAndClaimTip {
             system_program: system_program.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CloseOrderAndClaimTipInstructionAccountMetas {
+    pub maker: solana_instruction::AccountMeta,
+    pub order: solana_instruction::AccountMeta,
+    pub global_config: solana_instruction::AccountMeta,
+    pub pda_authority: solana_instruction::AccountMeta,
+    pub input_mint: solana_instruction::AccountMeta,
+    pub output_mint: solana_instruction::AccountMeta,
+    pub maker_input_ata: solana_instruction::AccountMeta,
+    pub input_vault: solana_instruction::AccountMeta,
+    pub input_token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CloseOrderAndClaimTip {
+    type ArrangedAccountMetas = CloseOrderAndClaimTipInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [maker, order, global_config, pda_authority, input_mint, output_mint, maker_input_ata, input_vault, input_token_program, system_program, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CloseOrderAndClaimTipInstructionAccountMetas {
+            maker: maker.clone(),
+            order: order.clone(),
+            global_config: global_config.clone(),
+            pda_authority: pda_authority.clone(),
+            input_mint: input_mint.clone(),
+            output_mint: output_mint.clone(),
+            maker_input_ata: maker_input_ata.clone(),
+            input_vault: input_vault.clone(),
+            input_token_program: input_token_program.clone(),
+            system_program: system_program.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CloseOrderAndClaimTipInstructionAccountIndices {
+    pub maker: usize,
+    pub order: usize,
+    pub global_config: usize,
+    pub pda_authority: usize,
+    pub input_mint: usize,
+    pub output_mint: usize,
+    pub maker_input_ata: usize,
+    pub input_vault: usize,
+    pub input_token_program: usize,
+    pub system_program: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CloseOrderAndClaimTip {
+    type ArrangedAccountIndices = CloseOrderAndClaimTipInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 12 {
+            return None;
+        }
+
+        Some(CloseOrderAndClaimTipInstructionAccountIndices {
+            maker: 0,
+            order: 1,
+            global_config: 2,
+            pda_authority: 3,
+            input_mint: 4,
+            output_mint: 5,
+            maker_input_ata: 6,
+            input_vault: 7,
+            input_token_program: 8,
+            system_program: 9,
+            event_authority: 10,
+            program: 11,
+            remaining_accounts: 12..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CloseOrderAndClaimTip {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "maker",
+            "order",
+            "global_config",
+            "pda_authority",
+            "input_mint",
+            "output_mint",
+            "maker_input_ata",
+            "input_vault",
+            "input_token_program",
+            "system_program",
+            "event_authority",
+            "program",
+        ]
+    }
+}
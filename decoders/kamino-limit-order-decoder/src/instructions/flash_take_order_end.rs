@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -34,6 +35,7 @@ pub struct FlashTakeOrderEndInstructionAccounts {
     pub rent: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for FlashTakeOrderEnd {
@@ -42,7 +44,7 @@ impl carbon_core::deserialize::ArrangeAccounts for FlashTakeOrderEnd {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [taker, maker, global_config, pda_authority, order, input_mint, output_mint, input_vault, taker_input_ata, taker_output_ata, intermediary_output_token_account, maker_output_ata, express_relay, express_relay_metadata, sysvar_instructions, permission, config_router, input_token_program, output_token_program, system_program, rent, event_authority, program, _remaining @ ..] =
+        let [taker, maker, global_config, pda_authority, order, input_mint, output_mint, input_vault, taker_input_ata, taker_output_ata, intermediary_output_token_account, maker_output_ata, express_relay, express_relay_metadata, sysvar_instructions, permission, config_router, input_token_program, output_token_program, system_program, rent, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -72,6 +74,171 @@ impl carbon_core::deserialize::ArrangeAccounts for FlashTakeOrderEnd {
             rent: rent.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct FlashTakeOrderEndInstructionAccountMetas {
+    pub taker: solana_instruction::AccountMeta,
+    pub maker: solana_instruction::AccountMeta,
+    pub global_config: solana_instruction::AccountMeta,
+    pub pda_authority: solana_instruction::AccountMeta,
+    pub order: solana_instruction::AccountMeta,
+    pub input_mint: solana_instruction::AccountMeta,
+    pub output_mint: solana_instruction::AccountMeta,
+    pub input_vault: solana_instruction::AccountMeta,
+    pub taker_input_ata: solana_instruction::AccountMeta,
+    pub taker_output_ata: solana_instruction::AccountMeta,
+    pub intermediary_output_token_account: solana_instruction::AccountMeta,
+    pub maker_output_ata: solana_instruction::AccountMeta,
+    pub express_relay: solana_instruction::AccountMeta,
+    pub express_relay_metadata: solana_instruction::AccountMeta,
+    pub sysvar_instructions: solana_instruction::AccountMeta,
+    pub permission: solana_instruction::AccountMeta,
+    pub config_router: solana_instruction::AccountMeta,
+    pub input_token_program: solana_instruction::AccountMeta,
+    pub output_token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for FlashTakeOrderEnd {
+    type ArrangedAccountMetas = FlashTakeOrderEndInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [taker, maker, global_config, pda_authority, order, input_mint, output_mint, input_vault, taker_input_ata, taker_output_ata, intermediary_output_token_account, maker_output_ata, express_relay, express_relay_metadata, sysvar_instructions, permission, config_router, input_token_program, output_token_program, system_program, rent, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(FlashTakeOrderEndInstructionAccountMetas {
+            taker: taker.clone(),
+            maker: maker.clone(),
+            global_config: global_config.clone(),
+            pda_authority: pda_authority.clone(),
+            order: order.clone(),
+            input_mint: input_mint.clone(),
+            output_mint: output_mint.clone(),
+            input_vault: input_vault.clone(),
+            taker_input_ata: taker_input_ata.clone(),
+            taker_output_ata: taker_output_ata.clone(),
+            intermediary_output_token_account: intermediary_output_token_account.clone(),
+            maker_output_ata: maker_output_ata.clone(),
+            express_relay: express_relay.clone(),
+            express_relay_metadata: express_relay_metadata.clone(),
+            sysvar_instructions: sysvar_instructions.clone(),
+            permission: permission.clone(),
+            config_router: config_router.clone(),
+            input_token_program: input_token_program.clone(),
+            output_token_program: output_token_program.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct FlashTakeOrderEndInstructionAccountIndices {
+    pub taker: usize,
+    pub maker: usize,
+    pub global_config: usize,
+    pub pda_authority: usize,
+    pub order: usize,
+    pub input_mint: usize,
+    pub output_mint: usize,
+    pub input_vault: usize,
+    pub taker_input_ata: usize,
+    pub taker_output_ata: usize,
+    pub intermediary_output_token_account: usize,
+    pub maker_output_ata: usize,
+    pub express_relay: usize,
+    pub express_relay_metadata: usize,
+    pub sysvar_instructions: usize,
+    pub permission: usize,
+    pub config_router: usize,
+    pub input_token_program: usize,
+    pub output_token_program: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for FlashTakeOrderEnd {
+    type ArrangedAccountIndices = FlashTakeOrderEndInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 23 {
+            return None;
+        }
+
+        Some(FlashTakeOrderEndInstructionAccountIndices {
+            taker: 0,
+            maker: 1,
+            global_config: 2,
+            pda_authority: 3,
+            order: 4,
+            input_mint: 5,
+            output_mint: 6,
+            input_vault: 7,
+            taker_input_ata: 8,
+            taker_output_ata: 9,
+            intermediary_output_token_account: 10,
+            maker_output_ata: 11,
+            express_relay: 12,
+            express_relay_metadata: 13,
+            sysvar_instructions: 14,
+            permission: 15,
+            config_router: 16,
+            input_token_program: 17,
+            output_token_program: 18,
+            system_program: 19,
+            rent: 20,
+            event_authority: 21,
+            program: 22,
+            remaining_accounts: 23..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for FlashTakeOrderEnd {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "taker",
+            "maker",
+            "global_config",
+            "pda_authority",
+            "order",
+            "input_mint",
+            "output_mint",
+            "input_vault",
+            "taker_input_ata",
+            "taker_output_ata",
+            "intermediary_output_token_account",
+            "maker_output_ata",
+            "express_relay",
+            "express_relay_metadata",
+            "sysvar_instructions",
+            "permission",
+            "config_router",
+            "input_token_program",
+            "output_token_program",
+            "system_program",
+            "rent",
+            "event_authority",
+            "program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -10,6 +11,7 @@ pub struct InitializeGlobalConfigInstructionAccounts {
     pub admin_authority: solana_pubkey::Pubkey,
     pub pda_authority: solana_pubkey::Pubkey,
     pub global_config: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeGlobalConfig {
@@ -18,7 +20,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeGlobalConfig {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin_authority, pda_authority, global_config, _remaining @ ..] = accounts else {
+        let [admin_authority, pda_authority, global_config, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -26,6 +28,65 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeGlobalConfig {
             admin_authority: admin_authority.pubkey,
             pda_authority: pda_authority.pubkey,
             global_config: global_config.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeGlobalConfigInstructionAccountMetas {
+    pub admin_authority: solana_instruction::AccountMeta,
+    pub pda_authority: solana_instruction::AccountMeta,
+    pub global_config: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeGlobalConfig {
+    type ArrangedAccountMetas = InitializeGlobalConfigInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin_authority, pda_authority, global_config, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(InitializeGlobalConfigInstructionAccountMetas {
+            admin_authority: admin_authority.clone(),
+            pda_authority: pda_authority.clone(),
+            global_config: global_config.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeGlobalConfigInstructionAccountIndices {
+    pub admin_authority: usize,
+    pub pda_authority: usize,
+    pub global_config: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeGlobalConfig {
+    type ArrangedAccountIndices = InitializeGlobalConfigInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(InitializeGlobalConfigInstructionAccountIndices {
+            admin_authority: 0,
+            pda_authority: 1,
+            global_config: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeGlobalConfig {
+    fn account_names() -> &'static [&'static str] {
+        &["admin_authority", "pda_authority", "global_config"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct InitializeVaultInstructionAccounts {
     pub vault: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeVault {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeVault {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [payer, global_config, pda_authority, mint, vault, token_program, system_program, _remaining @ ..] =
+        let [payer, global_config, pda_authority, mint, vault, token_program, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -36,6 +38,91 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeVault {
             vault: vault.pubkey,
             token_program: token_program.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeVaultInstructionAccountMetas {
+    pub payer: solana_instruction::AccountMeta,
+    pub global_config: solana_instruction::AccountMeta,
+    pub pda_authority: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub vault: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeVault {
+    type ArrangedAccountMetas = InitializeVaultInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [payer, global_config, pda_authority, mint, vault, token_program, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeVaultInstructionAccountMetas {
+            payer: payer.clone(),
+            global_config: global_config.clone(),
+            pda_authority: pda_authority.clone(),
+            mint: mint.clone(),
+            vault: vault.clone(),
+            token_program: token_program.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeVaultInstructionAccountIndices {
+    pub payer: usize,
+    pub global_config: usize,
+    pub pda_authority: usize,
+    pub mint: usize,
+    pub vault: usize,
+    pub token_program: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeVault {
+    type ArrangedAccountIndices = InitializeVaultInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 7 {
+            return None;
+        }
+
+        Some(InitializeVaultInstructionAccountIndices {
+            payer: 0,
+            global_config: 1,
+            pda_authority: 2,
+            mint: 3,
+            vault: 4,
+            token_program: 5,
+            system_program: 6,
+            remaining_accounts: 7..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeVault {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "payer",
+            "global_config",
+            "pda_authority",
+            "mint",
+            "vault",
+            "token_program",
+            "system_program",
+        ]
+    }
+}
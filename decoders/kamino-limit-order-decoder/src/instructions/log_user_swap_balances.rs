@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct LogUserSwapBalancesInstructionAccounts {
     pub output_ta: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for LogUserSwapBalances {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for LogUserSwapBalances {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [maker, input_mint, output_mint, input_ta, output_ta, event_authority, program, _remaining @ ..] =
+        let [maker, input_mint, output_mint, input_ta, output_ta, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -36,6 +38,91 @@ impl carbon_core::deserialize::ArrangeAccounts for LogUserSwapBalances {
             output_ta: output_ta.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct LogUserSwapBalancesInstructionAccountMetas {
+    pub maker: solana_instruction::AccountMeta,
+    pub input_mint: solana_instruction::AccountMeta,
+    pub output_mint: solana_instruction::AccountMeta,
+    pub input_ta: solana_instruction::AccountMeta,
+    pub output_ta: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for LogUserSwapBalances {
+    type ArrangedAccountMetas = LogUserSwapBalancesInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [maker, input_mint, output_mint, input_ta, output_ta, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(LogUserSwapBalancesInstructionAccountMetas {
+            maker: maker.clone(),
+            input_mint: input_mint.clone(),
+            output_mint: output_mint.clone(),
+            input_ta: input_ta.clone(),
+            output_ta: output_ta.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct LogUserSwapBalancesInstructionAccountIndices {
+    pub maker: usize,
+    pub input_mint: usize,
+    pub output_mint: usize,
+    pub input_ta: usize,
+    pub output_ta: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for LogUserSwapBalances {
+    type ArrangedAccountIndices = LogUserSwapBalancesInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 7 {
+            return None;
+        }
+
+        Some(LogUserSwapBalancesInstructionAccountIndices {
+            maker: 0,
+            input_mint: 1,
+            output_mint: 2,
+            input_ta: 3,
+            output_ta: 4,
+            event_authority: 5,
+            program: 6,
+            remaining_accounts: 7..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for LogUserSwapBalances {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "maker",
+            "input_mint",
+            "output_mint",
+            "input_ta",
+            "output_ta",
+            "event_authority",
+            "program",
+        ]
+    }
+}
@@ -42,14 +42,24 @@ pub enum KaminoLimitOrderInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for KaminoLimitOrderDecoder {
     type InstructionType = KaminoLimitOrderInstruction;
 
-    fn decode_instruction(
+    fn decoder_name(&self) -> &'static str {
+        "kamino_limit_order"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[super::PROGRAM_ID]
+    }
+
+    fn try_decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
-    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+    ) -> carbon_core::error::CarbonResult<
+        Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>>,
+    > {
         if !instruction.program_id.eq(&PROGRAM_ID) {
-            return None;
+            return Ok(None);
         }
-        carbon_core::try_decode_instructions!(instruction,
+        Ok(carbon_core::try_decode_instructions!(instruction,
             KaminoLimitOrderInstruction::InitializeGlobalConfig => initialize_global_config::InitializeGlobalConfig,
             KaminoLimitOrderInstruction::InitializeVault => initialize_vault::InitializeVault,
             KaminoLimitOrderInstruction::CreateOrder => create_order::CreateOrder,
@@ -63,6 +73,6 @@ impl carbon_core::instruction::InstructionDecoder<'_> for KaminoLimitOrderDecode
             KaminoLimitOrderInstruction::LogUserSwapBalances => log_user_swap_balances::LogUserSwapBalances,
             KaminoLimitOrderInstruction::OrderDisplayEvent => order_display_event::OrderDisplayEvent,
             KaminoLimitOrderInstruction::UserSwapBalancesEvent => user_swap_balances_event::UserSwapBalancesEvent,
-        )
+        ))
     }
 }
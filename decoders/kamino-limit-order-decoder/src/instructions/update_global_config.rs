@@ -1,6 +1,7 @@
 use carbon_core::{borsh, CarbonDeserialize};
 use serde_big_array::BigArray;
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct UpdateGlobalConfig {
 pub struct UpdateGlobalConfigInstructionAccounts {
     pub admin_authority: solana_pubkey::Pubkey,
     pub global_config: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateGlobalConfig {
@@ -22,13 +24,68 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateGlobalConfig {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin_authority, global_config, _remaining @ ..] = accounts else {
+        let [admin_authority, global_config, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(UpdateGlobalConfigInstructionAccounts {
             admin_authority: admin_authority.pubkey,
             global_config: global_config.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateGlobalConfigInstructionAccountMetas {
+    pub admin_authority: solana_instruction::AccountMeta,
+    pub global_config: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateGlobalConfig {
+    type ArrangedAccountMetas = UpdateGlobalConfigInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin_authority, global_config, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateGlobalConfigInstructionAccountMetas {
+            admin_authority: admin_authority.clone(),
+            global_config: global_config.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateGlobalConfigInstructionAccountIndices {
+    pub admin_authority: usize,
+    pub global_config: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateGlobalConfig {
+    type ArrangedAccountIndices = UpdateGlobalConfigInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(UpdateGlobalConfigInstructionAccountIndices {
+            admin_authority: 0,
+            global_config: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateGlobalConfig {
+    fn account_names() -> &'static [&'static str] {
+        &["admin_authority", "global_config"]
+    }
+}
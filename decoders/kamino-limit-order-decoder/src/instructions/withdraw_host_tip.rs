@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +12,7 @@ pub struct WithdrawHostTipInstructionAccounts {
     pub global_config: solana_pubkey::Pubkey,
     pub pda_authority: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for WithdrawHostTip {
@@ -19,7 +21,7 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawHostTip {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin_authority, global_config, pda_authority, system_program, _remaining @ ..] =
+        let [admin_authority, global_config, pda_authority, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -30,6 +32,76 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawHostTip {
             global_config: global_config.pubkey,
             pda_authority: pda_authority.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct WithdrawHostTipInstructionAccountMetas {
+    pub admin_authority: solana_instruction::AccountMeta,
+    pub global_config: solana_instruction::AccountMeta,
+    pub pda_authority: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for WithdrawHostTip {
+    type ArrangedAccountMetas = WithdrawHostTipInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin_authority, global_config, pda_authority, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(WithdrawHostTipInstructionAccountMetas {
+            admin_authority: admin_authority.clone(),
+            global_config: global_config.clone(),
+            pda_authority: pda_authority.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct WithdrawHostTipInstructionAccountIndices {
+    pub admin_authority: usize,
+    pub global_config: usize,
+    pub pda_authority: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for WithdrawHostTip {
+    type ArrangedAccountIndices = WithdrawHostTipInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(WithdrawHostTipInstructionAccountIndices {
+            admin_authority: 0,
+            global_config: 1,
+            pda_authority: 2,
+            system_program: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for WithdrawHostTip {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "admin_authority",
+            "global_config",
+            "pda_authority",
+            "system_program",
+        ]
+    }
+}
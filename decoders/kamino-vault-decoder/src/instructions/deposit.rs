@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -20,6 +21,7 @@ pub struct DepositInstructionAccounts {
     pub klend_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub shares_token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Deposit {
@@ -28,7 +30,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Deposit {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [user, vault_state, token_vault, token_mint, base_vault_authority, shares_mint, user_token_ata, user_shares_ata, klend_program, token_program, shares_token_program, _remaining @ ..] =
+        let [user, vault_state, token_vault, token_mint, base_vault_authority, shares_mint, user_token_ata, user_shares_ata, klend_program, token_program, shares_token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -46,6 +48,111 @@ impl carbon_core::deserialize::ArrangeAccounts for Deposit {
             klend_program: klend_program.pubkey,
             token_program: token_program.pubkey,
             shares_token_program: shares_token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DepositInstructionAccountMetas {
+    pub user: solana_instruction::AccountMeta,
+    pub vault_state: solana_instruction::AccountMeta,
+    pub token_vault: solana_instruction::AccountMeta,
+    pub token_mint: solana_instruction::AccountMeta,
+    pub base_vault_authority: solana_instruction::AccountMeta,
+    pub shares_mint: solana_instruction::AccountMeta,
+    pub user_token_ata: solana_instruction::AccountMeta,
+    pub user_shares_ata: solana_instruction::AccountMeta,
+    pub klend_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub shares_token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Deposit {
+    type ArrangedAccountMetas = DepositInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [user, vault_state, token_vault, token_mint, base_vault_authority, shares_mint, user_token_ata, user_shares_ata, klend_program, token_program, shares_token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(DepositInstructionAccountMetas {
+            user: user.clone(),
+            vault_state: vault_state.clone(),
+            token_vault: token_vault.clone(),
+            token_mint: token_mint.clone(),
+            base_vault_authority: base_vault_authority.clone(),
+            shares_mint: shares_mint.clone(),
+            user_token_ata: user_token_ata.clone(),
+            user_shares_ata: user_shares_ata.clone(),
+            klend_program: klend_program.clone(),
+            token_program: token_program.clone(),
+            shares_token_program: shares_token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DepositInstructionAccountIndices {
+    pub user: usize,
+    pub vault_state: usize,
+    pub token_vault: usize,
+    pub token_mint: usize,
+    pub base_vault_authority: usize,
+    pub shares_mint: usize,
+    pub user_token_ata: usize,
+    pub user_shares_ata: usize,
+    pub klend_program: usize,
+    pub token_program: usize,
+    pub shares_token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Deposit {
+    type ArrangedAccountIndices = DepositInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 11 {
+            return None;
+        }
+
+        Some(DepositInstructionAccountIndices {
+            user: 0,
+            vault_state: 1,
+            token_vault: 2,
+            token_mint: 3,
+            base_vault_authority: 4,
+            shares_mint: 5,
+            user_token_ata: 6,
+            user_shares_ata: 7,
+            klend_program: 8,
+            token_program: 9,
+            shares_token_program: 10,
+            remaining_accounts: 11..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Deposit {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "user",
+            "vault_state",
+            "token_vault",
+            "token_mint",
+            "base_vault_authority",
+            "shares_mint",
+            "user_token_ata",
+            "user_shares_ata",
+            "klend_program",
+            "token_program",
+            "shares_token_program",
+        ]
+    }
+}
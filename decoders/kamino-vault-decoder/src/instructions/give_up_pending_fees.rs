@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +13,7 @@ pub struct GiveUpPendingFeesInstructionAccounts {
     pub admin_authority: solana_pubkey::Pubkey,
     pub vault_state: solana_pubkey::Pubkey,
     pub klend_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for GiveUpPendingFees {
@@ -20,7 +22,7 @@ impl carbon_core::deserialize::ArrangeAccounts for GiveUpPendingFees {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin_authority, vault_state, klend_program, _remaining @ ..] = accounts else {
+        let [admin_authority, vault_state, klend_program, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +30,65 @@ impl carbon_core::deserialize::ArrangeAccounts for GiveUpPendingFees {
             admin_authority: admin_authority.pubkey,
             vault_state: vault_state.pubkey,
             klend_program: klend_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct GiveUpPendingFeesInstructionAccountMetas {
+    pub admin_authority: solana_instruction::AccountMeta,
+    pub vault_state: solana_instruction::AccountMeta,
+    pub klend_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for GiveUpPendingFees {
+    type ArrangedAccountMetas = GiveUpPendingFeesInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin_authority, vault_state, klend_program, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(GiveUpPendingFeesInstructionAccountMetas {
+            admin_authority: admin_authority.clone(),
+            vault_state: vault_state.clone(),
+            klend_program: klend_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct GiveUpPendingFeesInstructionAccountIndices {
+    pub admin_authority: usize,
+    pub vault_state: usize,
+    pub klend_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for GiveUpPendingFees {
+    type ArrangedAccountIndices = GiveUpPendingFeesInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(GiveUpPendingFeesInstructionAccountIndices {
+            admin_authority: 0,
+            vault_state: 1,
+            klend_program: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for GiveUpPendingFees {
+    fn account_names() -> &'static [&'static str] {
+        &["admin_authority", "vault_state", "klend_program"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -17,6 +18,7 @@ pub struct InitVaultInstructionAccounts {
     pub rent: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub shares_token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitVault {
@@ -25,7 +27,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitVault {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin_authority, vault_state, base_vault_authority, token_vault, base_token_mint, shares_mint, system_program, rent, token_program, shares_token_program, _remaining @ ..] =
+        let [admin_authority, vault_state, base_vault_authority, token_vault, base_token_mint, shares_mint, system_program, rent, token_program, shares_token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -42,6 +44,106 @@ impl carbon_core::deserialize::ArrangeAccounts for InitVault {
             rent: rent.pubkey,
             token_program: token_program.pubkey,
             shares_token_program: shares_token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitVaultInstructionAccountMetas {
+    pub admin_authority: solana_instruction::AccountMeta,
+    pub vault_state: solana_instruction::AccountMeta,
+    pub base_vault_authority: solana_instruction::AccountMeta,
+    pub token_vault: solana_instruction::AccountMeta,
+    pub base_token_mint: solana_instruction::AccountMeta,
+    pub shares_mint: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub shares_token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitVault {
+    type ArrangedAccountMetas = InitVaultInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin_authority, vault_state, base_vault_authority, token_vault, base_token_mint, shares_mint, system_program, rent, token_program, shares_token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitVaultInstructionAccountMetas {
+            admin_authority: admin_authority.clone(),
+            vault_state: vault_state.clone(),
+            base_vault_authority: base_vault_authority.clone(),
+            token_vault: token_vault.clone(),
+            base_token_mint: base_token_mint.clone(),
+            shares_mint: shares_mint.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            token_program: token_program.clone(),
+            shares_token_program: shares_token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitVaultInstructionAccountIndices {
+    pub admin_authority: usize,
+    pub vault_state: usize,
+    pub base_vault_authority: usize,
+    pub token_vault: usize,
+    pub base_token_mint: usize,
+    pub shares_mint: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub token_program: usize,
+    pub shares_token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitVault {
+    type ArrangedAccountIndices = InitVaultInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 10 {
+            return None;
+        }
+
+        Some(InitVaultInstructionAccountIndices {
+            admin_authority: 0,
+            vault_state: 1,
+            base_vault_authority: 2,
+            token_vault: 3,
+            base_token_mint: 4,
+            shares_mint: 5,
+            system_program: 6,
+            rent: 7,
+            token_program: 8,
+            shares_token_program: 9,
+            remaining_accounts: 10..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitVault {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "admin_authority",
+            "vault_state",
+            "base_vault_authority",
+            "token_vault",
+            "base_token_mint",
+            "shares_mint",
+            "system_program",
+            "rent",
+            "token_program",
+            "shares_token_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -19,6 +20,7 @@ pub struct InitializeSharesMetadataInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub metadata_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeSharesMetadata {
@@ -27,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeSharesMetadata {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin_authority, vault_state, shares_mint, base_vault_authority, shares_metadata, system_program, rent, metadata_program, _remaining @ ..] =
+        let [admin_authority, vault_state, shares_mint, base_vault_authority, shares_metadata, system_program, rent, metadata_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -42,6 +44,96 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeSharesMetadata {
             system_program: system_program.pubkey,
             rent: rent.pubkey,
             metadata_program: metadata_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeSharesMetadataInstructionAccountMetas {
+    pub admin_authority: solana_instruction::AccountMeta,
+    pub vault_state: solana_instruction::AccountMeta,
+    pub shares_mint: solana_instruction::AccountMeta,
+    pub base_vault_authority: solana_instruction::AccountMeta,
+    pub shares_metadata: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub metadata_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeSharesMetadata {
+    type ArrangedAccountMetas = InitializeSharesMetadataInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin_authority, vault_state, shares_mint, base_vault_authority, shares_metadata, system_program, rent, metadata_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeSharesMetadataInstructionAccountMetas {
+            admin_authority: admin_authority.clone(),
+            vault_state: vault_state.clone(),
+            shares_mint: shares_mint.clone(),
+            base_vault_authority: base_vault_authority.clone(),
+            shares_metadata: shares_metadata.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            metadata_program: metadata_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeSharesMetadataInstructionAccountIndices {
+    pub admin_authority: usize,
+    pub vault_state: usize,
+    pub shares_mint: usize,
+    pub base_vault_authority: usize,
+    pub shares_metadata: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub metadata_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeSharesMetadata {
+    type ArrangedAccountIndices = InitializeSharesMetadataInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(InitializeSharesMetadataInstructionAccountIndices {
+            admin_authority: 0,
+            vault_state: 1,
+            shares_mint: 2,
+            base_vault_authority: 3,
+            shares_metadata: 4,
+            system_program: 5,
+            rent: 6,
+            metadata_program: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeSharesMetadata {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "admin_authority",
+            "vault_state",
+            "shares_mint",
+            "base_vault_authority",
+            "shares_metadata",
+            "system_program",
+            "rent",
+            "metadata_program",
+        ]
+    }
+}
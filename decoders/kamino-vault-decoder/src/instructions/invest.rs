@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -23,6 +24,7 @@ pub struct InvestInstructionAccounts {
     pub reserve_collateral_token_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub instruction_sysvar_account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Invest {
@@ -31,7 +33,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Invest {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [payer, payer_token_account, vault_state, token_vault, token_mint, base_vault_authority, ctoken_vault, reserve, lending_market, lending_market_authority, reserve_liquidity_supply, reserve_collateral_mint, klend_program, reserve_collateral_token_program, token_program, instruction_sysvar_account, _remaining @ ..] =
+        let [payer, payer_token_account, vault_state, token_vault, token_mint, base_vault_authority, ctoken_vault, reserve, lending_market, lending_market_authority, reserve_liquidity_supply, reserve_collateral_mint, klend_program, reserve_collateral_token_program, token_program, instruction_sysvar_account, remaining @ ..] =
             accounts
         else {
             return None;
@@ -54,6 +56,136 @@ impl carbon_core::deserialize::ArrangeAccounts for Invest {
             reserve_collateral_token_program: reserve_collateral_token_program.pubkey,
             token_program: token_program.pubkey,
             instruction_sysvar_account: instruction_sysvar_account.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InvestInstructionAccountMetas {
+    pub payer: solana_instruction::AccountMeta,
+    pub payer_token_account: solana_instruction::AccountMeta,
+    pub vault_state: solana_instruction::AccountMeta,
+    pub token_vault: solana_instruction::AccountMeta,
+    pub token_mint: solana_instruction::AccountMeta,
+    pub base_vault_authority: solana_instruction::AccountMeta,
+    pub ctoken_vault: solana_instruction::AccountMeta,
+    pub reserve: solana_instruction::AccountMeta,
+    pub lending_market: solana_instruction::AccountMeta,
+    pub lending_market_authority: solana_instruction::AccountMeta,
+    pub reserve_liquidity_supply: solana_instruction::AccountMeta,
+    pub reserve_collateral_mint: solana_instruction::AccountMeta,
+    pub klend_program: solana_instruction::AccountMeta,
+    pub reserve_collateral_token_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub instruction_sysvar_account: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Invest {
+    type ArrangedAccountMetas = InvestInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [payer, payer_token_account, vault_state, token_vault, token_mint, base_vault_authority, ctoken_vault, reserve, lending_market, lending_market_authority, reserve_liquidity_supply, reserve_collateral_mint, klend_program, reserve_collateral_token_program, token_program, instruction_sysvar_account, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InvestInstructionAccountMetas {
+            payer: payer.clone(),
+            payer_token_account: payer_token_account.clone(),
+            vault_state: vault_state.clone(),
+            token_vault: token_vault.clone(),
+            token_mint: token_mint.clone(),
+            base_vault_authority: base_vault_authority.clone(),
+            ctoken_vault: ctoken_vault.clone(),
+            reserve: reserve.clone(),
+            lending_market: lending_market.clone(),
+            lending_market_authority: lending_market_authority.clone(),
+            reserve_liquidity_supply: reserve_liquidity_supply.clone(),
+            reserve_collateral_mint: reserve_collateral_mint.clone(),
+            klend_program: klend_program.clone(),
+            reserve_collateral_token_program: reserve_collateral_token_program.clone(),
+            token_program: token_program.clone(),
+            instruction_sysvar_account: instruction_sysvar_account.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InvestInstructionAccountIndices {
+    pub payer: usize,
+    pub payer_token_account: usize,
+    pub vault_state: usize,
+    pub token_vault: usize,
+    pub token_mint: usize,
+    pub base_vault_authority: usize,
+    pub ctoken_vault: usize,
+    pub reserve: usize,
+    pub lending_market: usize,
+    pub lending_market_authority: usize,
+    pub reserve_liquidity_supply: usize,
+    pub reserve_collateral_mint: usize,
+    pub klend_program: usize,
+    pub reserve_collateral_token_program: usize,
+    pub token_program: usize,
+    pub instruction_sysvar_account: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Invest {
+    type ArrangedAccountIndices = InvestInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 16 {
+            return None;
+        }
+
+        Some(InvestInstructionAccountIndices {
+            payer: 0,
+            payer_token_account: 1,
+            vault_state: 2,
+            token_vault: 3,
+            token_mint: 4,
+            base_vault_authority: 5,
+            ctoken_vault: 6,
+            reserve: 7,
+            lending_market: 8,
+            lending_market_authority: 9,
+            reserve_liquidity_supply: 10,
+            reserve_collateral_mint: 11,
+            klend_program: 12,
+            reserve_collateral_token_program: 13,
+            token_program: 14,
+            instruction_sysvar_account: 15,
+            remaining_accounts: 16..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Invest {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "payer",
+            "payer_token_account",
+            "vault_state",
+            "token_vault",
+            "token_mint",
+            "base_vault_authority",
+            "ctoken_vault",
+            "reserve",
+            "lending_market",
+            "lending_market_authority",
+            "reserve_liquidity_supply",
+            "reserve_collateral_mint",
+            "klend_program",
+            "reserve_collateral_token_program",
+            "token_program",
+            "instruction_sysvar_account",
+        ]
+    }
+}
@@ -42,15 +42,25 @@ pub enum KaminoVaultInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for KaminoVaultDecoder {
     type InstructionType = KaminoVaultInstruction;
 
-    fn decode_instruction(
+    fn decoder_name(&self) -> &'static str {
+        "kamino_vault"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[super::PROGRAM_ID]
+    }
+
+    fn try_decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
-    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+    ) -> carbon_core::error::CarbonResult<
+        Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>>,
+    > {
         if !instruction.program_id.eq(&PROGRAM_ID) {
-            return None;
+            return Ok(None);
         }
 
-        carbon_core::try_decode_instructions!(instruction,
+        Ok(carbon_core::try_decode_instructions!(instruction,
             KaminoVaultInstruction::InitVault => init_vault::InitVault,
             KaminoVaultInstruction::UpdateReserveAllocation => update_reserve_allocation::UpdateReserveAllocation,
             KaminoVaultInstruction::Deposit => deposit::Deposit,
@@ -63,6 +73,6 @@ impl carbon_core::instruction::InstructionDecoder<'_> for KaminoVaultDecoder {
             KaminoVaultInstruction::InitializeSharesMetadata => initialize_shares_metadata::InitializeSharesMetadata,
             KaminoVaultInstruction::UpdateSharesMetadata => update_shares_metadata::UpdateSharesMetadata,
             KaminoVaultInstruction::WithdrawFromAvailable => withdraw_from_available::WithdrawFromAvailable,
-        )
+        ))
     }
 }
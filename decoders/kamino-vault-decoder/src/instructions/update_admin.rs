@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -9,6 +10,7 @@ pub struct UpdateAdmin {}
 pub struct UpdateAdminInstructionAccounts {
     pub pending_admin: solana_pubkey::Pubkey,
     pub vault_state: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateAdmin {
@@ -17,13 +19,68 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateAdmin {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [pending_admin, vault_state, _remaining @ ..] = accounts else {
+        let [pending_admin, vault_state, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(UpdateAdminInstructionAccounts {
             pending_admin: pending_admin.pubkey,
             vault_state: vault_state.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateAdminInstructionAccountMetas {
+    pub pending_admin: solana_instruction::AccountMeta,
+    pub vault_state: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateAdmin {
+    type ArrangedAccountMetas = UpdateAdminInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [pending_admin, vault_state, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateAdminInstructionAccountMetas {
+            pending_admin: pending_admin.clone(),
+            vault_state: vault_state.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateAdminInstructionAccountIndices {
+    pub pending_admin: usize,
+    pub vault_state: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateAdmin {
+    type ArrangedAccountIndices = UpdateAdminInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(UpdateAdminInstructionAccountIndices {
+            pending_admin: 0,
+            vault_state: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateAdmin {
+    fn account_names() -> &'static [&'static str] {
+        &["pending_admin", "vault_state"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -19,6 +20,7 @@ pub struct UpdateReserveAllocationInstructionAccounts {
     pub reserve_collateral_token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateReserveAllocation {
@@ -27,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateReserveAllocation {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin_authority, vault_state, base_vault_authority, reserve_collateral_mint, reserve, ctoken_vault, reserve_collateral_token_program, system_program, rent, _remaining @ ..] =
+        let [admin_authority, vault_state, base_vault_authority, reserve_collateral_mint, reserve, ctoken_vault, reserve_collateral_token_program, system_program, rent, remaining @ ..] =
             accounts
         else {
             return None;
@@ -43,6 +45,101 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateReserveAllocation {
             reserve_collateral_token_program: reserve_collateral_token_program.pubkey,
             system_program: system_program.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateReserveAllocationInstructionAccountMetas {
+    pub admin_authority: solana_instruction::AccountMeta,
+    pub vault_state: solana_instruction::AccountMeta,
+    pub base_vault_authority: solana_instruction::AccountMeta,
+    pub reserve_collateral_mint: solana_instruction::AccountMeta,
+    pub reserve: solana_instruction::AccountMeta,
+    pub ctoken_vault: solana_instruction::AccountMeta,
+    pub reserve_collateral_token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateReserveAllocation {
+    type ArrangedAccountMetas = UpdateReserveAllocationInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin_authority, vault_state, base_vault_authority, reserve_collateral_mint, reserve, ctoken_vault, reserve_collateral_token_program, system_program, rent, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(UpdateReserveAllocationInstructionAccountMetas {
+            admin_authority: admin_authority.clone(),
+            vault_state: vault_state.clone(),
+            base_vault_authority: base_vault_authority.clone(),
+            reserve_collateral_mint: reserve_collateral_mint.clone(),
+            reserve: reserve.clone(),
+            ctoken_vault: ctoken_vault.clone(),
+            reserve_collateral_token_program: reserve_collateral_token_program.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateReserveAllocationInstructionAccountIndices {
+    pub admin_authority: usize,
+    pub vault_state: usize,
+    pub base_vault_authority: usize,
+    pub reserve_collateral_mint: usize,
+    pub reserve: usize,
+    pub ctoken_vault: usize,
+    pub reserve_collateral_token_program: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateReserveAllocation {
+    type ArrangedAccountIndices = UpdateReserveAllocationInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 9 {
+            return None;
+        }
+
+        Some(UpdateReserveAllocationInstructionAccountIndices {
+            admin_authority: 0,
+            vault_state: 1,
+            base_vault_authority: 2,
+            reserve_collateral_mint: 3,
+            reserve: 4,
+            ctoken_vault: 5,
+            reserve_collateral_token_program: 6,
+            system_program: 7,
+            rent: 8,
+            remaining_accounts: 9..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateReserveAllocation {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "admin_authority",
+            "vault_state",
+            "base_vault_authority",
+            "reserve_collateral_mint",
+            "reserve",
+            "ctoken_vault",
+            "reserve_collateral_token_program",
+            "system_program",
+            "rent",
+        ]
+    }
+}
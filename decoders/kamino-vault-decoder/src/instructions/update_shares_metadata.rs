@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -16,6 +17,7 @@ pub struct UpdateSharesMetadataInstructionAccounts {
     pub base_vault_authority: solana_pubkey::Pubkey,
     pub shares_metadata: solana_pubkey::Pubkey,
     pub metadata_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateSharesMetadata {
@@ -24,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateSharesMetadata {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin_authority, vault_state, base_vault_authority, shares_metadata, metadata_program, _remaining @ ..] =
+        let [admin_authority, vault_state, base_vault_authority, shares_metadata, metadata_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -36,6 +38,81 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateSharesMetadata {
             base_vault_authority: base_vault_authority.pubkey,
             shares_metadata: shares_metadata.pubkey,
             metadata_program: metadata_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateSharesMetadataInstructionAccountMetas {
+    pub admin_authority: solana_instruction::AccountMeta,
+    pub vault_state: solana_instruction::AccountMeta,
+    pub base_vault_authority: solana_instruction::AccountMeta,
+    pub shares_metadata: solana_instruction::AccountMeta,
+    pub metadata_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateSharesMetadata {
+    type ArrangedAccountMetas = UpdateSharesMetadataInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin_authority, vault_state, base_vault_authority, shares_metadata, metadata_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(UpdateSharesMetadataInstructionAccountMetas {
+            admin_authority: admin_authority.clone(),
+            vault_state: vault_state.clone(),
+            base_vault_authority: base_vault_authority.clone(),
+            shares_metadata: shares_metadata.clone(),
+            metadata_program: metadata_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateSharesMetadataInstructionAccountIndices {
+    pub admin_authority: usize,
+    pub vault_state: usize,
+    pub base_vault_authority: usize,
+    pub shares_metadata: usize,
+    pub metadata_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateSharesMetadata {
+    type ArrangedAccountIndices = UpdateSharesMetadataInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(UpdateSharesMetadataInstructionAccountIndices {
+            admin_authority: 0,
+            vault_state: 1,
+            base_vault_authority: 2,
+            shares_metadata: 3,
+            metadata_program: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateSharesMetadata {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "admin_authority",
+            "vault_state",
+            "base_vault_authority",
+            "shares_metadata",
+            "metadata_program",
+        ]
+    }
+}
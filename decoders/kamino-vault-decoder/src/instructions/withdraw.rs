@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +12,7 @@ pub struct Withdraw {
 pub struct WithdrawInstructionAccounts {
     pub withdraw_from_available: solana_pubkey::Pubkey,
     pub withdraw_from_reserve_accounts: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Withdraw {
@@ -19,7 +21,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Withdraw {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [withdraw_from_available, withdraw_from_reserve_accounts, _remaining @ ..] = accounts
+        let [withdraw_from_available, withdraw_from_reserve_accounts, remaining @ ..] = accounts
         else {
             return None;
         };
@@ -27,6 +29,62 @@ impl carbon_core::deserialize::ArrangeAccounts for Withdraw {
         Some(WithdrawInstructionAccounts {
             withdraw_from_available: withdraw_from_available.pubkey,
             withdraw_from_reserve_accounts: withdraw_from_reserve_accounts.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct WithdrawInstructionAccountMetas {
+    pub withdraw_from_available: solana_instruction::AccountMeta,
+    pub withdraw_from_reserve_accounts: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Withdraw {
+    type ArrangedAccountMetas = WithdrawInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [withdraw_from_available, withdraw_from_reserve_accounts, remaining @ ..] = accounts
+        else {
+            return None;
+        };
+
+        Some(WithdrawInstructionAccountMetas {
+            withdraw_from_available: withdraw_from_available.clone(),
+            withdraw_from_reserve_accounts: withdraw_from_reserve_accounts.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct WithdrawInstructionAccountIndices {
+    pub withdraw_from_available: usize,
+    pub withdraw_from_reserve_accounts: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Withdraw {
+    type ArrangedAccountIndices = WithdrawInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(WithdrawInstructionAccountIndices {
+            withdraw_from_available: 0,
+            withdraw_from_reserve_accounts: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Withdraw {
+    fn account_names() -> &'static [&'static str] {
+        &["withdraw_from_available", "withdraw_from_reserve_accounts"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -20,6 +21,7 @@ pub struct WithdrawFromAvailableInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub shares_token_program: solana_pubkey::Pubkey,
     pub klend_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for WithdrawFromAvailable {
@@ -28,7 +30,7 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawFromAvailable {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [user, vault_state, token_vault, base_vault_authority, user_token_ata, token_mint, user_shares_ata, shares_mint, token_program, shares_token_program, klend_program, _remaining @ ..] =
+        let [user, vault_state, token_vault, base_vault_authority, user_token_ata, token_mint, user_shares_ata, shares_mint, token_program, shares_token_program, klend_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -46,6 +48,111 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawFromAvailable {
             token_program: token_program.pubkey,
             shares_token_program: shares_token_program.pubkey,
             klend_program: klend_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct WithdrawFromAvailableInstructionAccountMetas {
+    pub user: solana_instruction::AccountMeta,
+    pub vault_state: solana_instruction::AccountMeta,
+    pub token_vault: solana_instruction::AccountMeta,
+    pub base_vault_authority: solana_instruction::AccountMeta,
+    pub user_token_ata: solana_instruction::AccountMeta,
+    pub token_mint: solana_instruction::AccountMeta,
+    pub user_shares_ata: solana_instruction::AccountMeta,
+    pub shares_mint: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub shares_token_program: solana_instruction::AccountMeta,
+    pub klend_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for WithdrawFromAvailable {
+    type ArrangedAccountMetas = WithdrawFromAvailableInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [user, vault_state, token_vault, base_vault_authority, user_token_ata, token_mint, user_shares_ata, shares_mint, token_program, shares_token_program, klend_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(WithdrawFromAvailableInstructionAccountMetas {
+            user: user.clone(),
+            vault_state: vault_state.clone(),
+            token_vault: token_vault.clone(),
+            base_vault_authority: base_vault_authority.clone(),
+            user_token_ata: user_token_ata.clone(),
+            token_mint: token_mint.clone(),
+            user_shares_ata: user_shares_ata.clone(),
+            shares_mint: shares_mint.clone(),
+            token_program: token_program.clone(),
+            shares_token_program: shares_token_program.clone(),
+            klend_program: klend_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct WithdrawFromAvailableInstructionAccountIndices {
+    pub user: usize,
+    pub vault_state: usize,
+    pub token_vault: usize,
+    pub base_vault_authority: usize,
+    pub user_token_ata: usize,
+    pub token_mint: usize,
+    pub user_shares_ata: usize,
+    pub shares_mint: usize,
+    pub token_program: usize,
+    pub shares_token_program: usize,
+    pub klend_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for WithdrawFromAvailable {
+    type ArrangedAccountIndices = WithdrawFromAvailableInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 11 {
+            return None;
+        }
+
+        Some(WithdrawFromAvailableInstructionAccountIndices {
+            user: 0,
+            vault_state: 1,
+            token_vault: 2,
+            base_vault_authority: 3,
+            user_token_ata: 4,
+            token_mint: 5,
+            user_shares_ata: 6,
+            shares_mint: 7,
+            token_program: 8,
+            shares_token_program: 9,
+            klend_program: 10,
+            remaining_accounts: 11..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for WithdrawFromAvailable {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "user",
+            "vault_state",
+            "token_vault",
+            "base_vault_authority",
+            "user_token_ata",
+            "token_mint",
+            "user_shares_ata",
+            "shares_mint",
+            "token_program",
+            "shares_token_program",
+            "klend_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -23,6 +24,7 @@ pub struct WithdrawPendingFeesInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub reserve_collateral_token_program: solana_pubkey::Pubkey,
     pub instruction_sysvar_account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for WithdrawPendingFees {
@@ -31,7 +33,7 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawPendingFees {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin_authority, vault_state, reserve, token_vault, ctoken_vault, base_vault_authority, token_ata, token_mint, lending_market, lending_market_authority, reserve_liquidity_supply, reserve_collateral_mint, klend_program, token_program, reserve_collateral_token_program, instruction_sysvar_account, _remaining @ ..] =
+        let [admin_authority, vault_state, reserve, token_vault, ctoken_vault, base_vault_authority, token_ata, token_mint, lending_market, lending_market_authority, reserve_liquidity_supply, reserve_collateral_mint, klend_program, token_program, reserve_collateral_token_program, instruction_sysvar_account, remaining @ ..] =
             accounts
         else {
             return None;
@@ -54,6 +56,136 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawPendingFees {
             token_program: token_program.pubkey,
             reserve_collateral_token_program: reserve_collateral_token_program.pubkey,
             instruction_sysvar_account: instruction_sysvar_account.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct WithdrawPendingFeesInstructionAccountMetas {
+    pub admin_authority: solana_instruction::AccountMeta,
+    pub vault_state: solana_instruction::AccountMeta,
+    pub reserve: solana_instruction::AccountMeta,
+    pub token_vault: solana_instruction::AccountMeta,
+    pub ctoken_vault: solana_instruction::AccountMeta,
+    pub base_vault_authority: solana_instruction::AccountMeta,
+    pub token_ata: solana_instruction::AccountMeta,
+    pub token_mint: solana_instruction::AccountMeta,
+    pub lending_market: solana_instruction::AccountMeta,
+    pub lending_market_authority: solana_instruction::AccountMeta,
+    pub reserve_liquidity_supply: solana_instruction::AccountMeta,
+    pub reserve_collateral_mint: solana_instruction::AccountMeta,
+    pub klend_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub reserve_collateral_token_program: solana_instruction::AccountMeta,
+    pub instruction_sysvar_account: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for WithdrawPendingFees {
+    type ArrangedAccountMetas = WithdrawPendingFeesInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin_authority, vault_state, reserve, token_vault, ctoken_vault, base_vault_authority, token_ata, token_mint, lending_market, lending_market_authority, reserve_liquidity_supply, reserve_collateral_mint, klend_program, token_program, reserve_collateral_token_program, instruction_sysvar_account, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(WithdrawPendingFeesInstructionAccountMetas {
+            admin_authority: admin_authority.clone(),
+            vault_state: vault_state.clone(),
+            reserve: reserve.clone(),
+            token_vault: token_vault.clone(),
+            ctoken_vault: ctoken_vault.clone(),
+            base_vault_authority: base_vault_authority.clone(),
+            token_ata: token_ata.clone(),
+            token_mint: token_mint.clone(),
+            lending_market: lending_market.clone(),
+            lending_market_authority: lending_market_authority.clone(),
+            reserve_liquidity_supply: reserve_liquidity_supply.clone(),
+            reserve_collateral_mint: reserve_collateral_mint.clone(),
+            klend_program: klend_program.clone(),
+            token_program: token_program.clone(),
+            reserve_collateral_token_program: reserve_collateral_token_program.clone(),
+            instruction_sysvar_account: instruction_sysvar_account.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct WithdrawPendingFeesInstructionAccountIndices {
+    pub admin_authority: usize,
+    pub vault_state: usize,
+    pub reserve: usize,
+    pub token_vault: usize,
+    pub ctoken_vault: usize,
+    pub base_vault_authority: usize,
+    pub token_ata: usize,
+    pub token_mint: usize,
+    pub lending_market: usize,
+    pub lending_market_authority: usize,
+    pub reserve_liquidity_supply: usize,
+    pub reserve_collateral_mint: usize,
+    pub klend_program: usize,
+    pub token_program: usize,
+    pub reserve_collateral_token_program: usize,
+    pub instruction_sysvar_account: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for WithdrawPendingFees {
+    type ArrangedAccountIndices = WithdrawPendingFeesInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 16 {
+            return None;
+        }
+
+        Some(WithdrawPendingFeesInstructionAccountIndices {
+            admin_authority: 0,
+            vault_state: 1,
+            reserve: 2,
+            token_vault: 3,
+            ctoken_vault: 4,
+            base_vault_authority: 5,
+            token_ata: 6,
+            token_mint: 7,
+            lending_market: 8,
+            lending_market_authority: 9,
+            reserve_liquidity_supply: 10,
+            reserve_collateral_mint: 11,
+            klend_program: 12,
+            token_program: 13,
+            reserve_collateral_token_program: 14,
+            instruction_sysvar_account: 15,
+            remaining_accounts: 16..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for WithdrawPendingFees {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "admin_authority",
+            "vault_state",
+            "reserve",
+            "token_vault",
+            "ctoken_vault",
+            "base_vault_authority",
+            "token_ata",
+            "token_mint",
+            "lending_market",
+            "lending_market_authority",
+            "reserve_liquidity_supply",
+            "reserve_collateral_mint",
+            "klend_program",
+            "token_program",
+            "reserve_collateral_token_program",
+            "instruction_sysvar_account",
+        ]
+    }
+}
@@ -24,19 +24,29 @@ pub enum LifinityAmmV2Instruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for LifinityAmmV2Decoder {
     type InstructionType = LifinityAmmV2Instruction;
 
-    fn decode_instruction(
+    fn decoder_name(&self) -> &'static str {
+        "lifinity_amm_v2"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[super::PROGRAM_ID]
+    }
+
+    fn try_decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
-    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+    ) -> carbon_core::error::CarbonResult<
+        Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>>,
+    > {
         if !instruction.program_id.eq(&PROGRAM_ID) {
-            return None;
+            return Ok(None);
         }
 
-        carbon_core::try_decode_instructions!(instruction,
+        Ok(carbon_core::try_decode_instructions!(instruction,
             LifinityAmmV2Instruction::Swap => swap::Swap,
             LifinityAmmV2Instruction::DepositAllTokenTypes => deposit_all_token_types::DepositAllTokenTypes,
             LifinityAmmV2Instruction::WithdrawAllTokenTypes => withdraw_all_token_types::WithdrawAllTokenTypes,
-        )
+        ))
     }
 }
 
@@ -173,6 +183,7 @@ mod tests {
             oracle_pc_account: solana_pubkey::Pubkey::from_str_const(
                 "3ZDBff7jeQaksmGvmkRix36rU159EBDjYiPThvV8QVZM",
             ),
+            remaining_accounts: vec![],
         };
 
         let decoder = LifinityAmmV2Decoder;
@@ -293,6 +304,7 @@ mod tests {
                 token_program: solana_pubkey::Pubkey::from_str_const(
                     "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
                 ),
+                remaining_accounts: vec![],
             };
 
         let decoder = LifinityAmmV2Decoder;
@@ -415,6 +427,7 @@ mod tests {
                 token_program: solana_pubkey::Pubkey::from_str_const(
                     "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
                 ),
+                remaining_accounts: vec![],
             };
 
         let decoder = LifinityAmmV2Decoder;
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -24,6 +25,7 @@ pub struct SwapInstructionAccounts {
     pub oracle_main_account: solana_pubkey::Pubkey,
     pub oracle_sub_account: solana_pubkey::Pubkey,
     pub oracle_pc_account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Swap {
@@ -32,7 +34,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Swap {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [authority, amm, user_transfer_authority, source_info, destination_info, swap_source, swap_destination, pool_mint, fee_account, token_program, oracle_main_account, oracle_sub_account, oracle_pc_account, _remaining @ ..] =
+        let [authority, amm, user_transfer_authority, source_info, destination_info, swap_source, swap_destination, pool_mint, fee_account, token_program, oracle_main_account, oracle_sub_account, oracle_pc_account, remaining @ ..] =
             accounts
         else {
             return None;
@@ -52,6 +54,123 @@ impl carbon_core::deserialize::ArrangeAccounts for Swap {
             oracle_main_account: oracle_main_account.pubkey,
             oracle_sub_account: oracle_sub_account.pubkey,
             oracle_pc_account: oracle_pc_account.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+#[derive(Debug, PartialEq)]
+pub struct SwapInstructionAccountMetas {
+    pub authority: solana_instruction::AccountMeta,
+    pub amm: solana_instruction::AccountMeta,
+    pub user_transfer_authority: solana_instruction::AccountMeta,
+    pub source_info: solana_instruction::AccountMeta,
+    pub destination_info: solana_instruction::AccountMeta,
+    pub swap_source: solana_instruction::AccountMeta,
+    pub swap_destination: solana_instruction::AccountMeta,
+    pub pool_mint: solana_instruction::AccountMeta,
+    pub fee_account: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub oracle_main_account: solana_instruction::AccountMeta,
+    pub oracle_sub_account: solana_instruction::AccountMeta,
+    pub oracle_pc_account: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Swap {
+    type ArrangedAccountMetas = SwapInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [authority, amm, user_transfer_authority, source_info, destination_info, swap_source, swap_destination, pool_mint, fee_account, token_program, oracle_main_account, oracle_sub_account, oracle_pc_account, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(SwapInstructionAccountMetas {
+            authority: authority.clone(),
+            amm: amm.clone(),
+            user_transfer_authority: user_transfer_authority.clone(),
+            source_info: source_info.clone(),
+            destination_info: destination_info.clone(),
+            swap_source: swap_source.clone(),
+            swap_destination: swap_destination.clone(),
+            pool_mint: pool_mint.clone(),
+            fee_account: fee_account.clone(),
+            token_program: token_program.clone(),
+            oracle_main_account: oracle_main_account.clone(),
+            oracle_sub_account: oracle_sub_account.clone(),
+            oracle_pc_account: oracle_pc_account.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct SwapInstructionAccountIndices {
+    pub authority: usize,
+    pub amm: usize,
+    pub user_transfer_authority: usize,
+    pub source_info: usize,
+    pub destination_info: usize,
+    pub swap_source: usize,
+    pub swap_destination: usize,
+    pub pool_mint: usize,
+    pub fee_account: usize,
+    pub token_program: usize,
+    pub oracle_main_account: usize,
+    pub oracle_sub_account: usize,
+    pub oracle_pc_account: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Swap {
+    type ArrangedAccountIndices = SwapInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 13 {
+            return None;
+        }
+
+        Some(SwapInstructionAccountIndices {
+            authority: 0,
+            amm: 1,
+            user_transfer_authority: 2,
+            source_info: 3,
+            destination_info: 4,
+            swap_source: 5,
+            swap_destination: 6,
+            pool_mint: 7,
+            fee_account: 8,
+            token_program: 9,
+            oracle_main_account: 10,
+            oracle_sub_account: 11,
+            oracle_pc_account: 12,
+            remaining_accounts: 13..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Swap {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "authority",
+            "amm",
+            "user_transfer_authority",
+            "source_info",
+            "destination_info",
+            "swap_source",
+            "swap_destination",
+            "pool_mint",
+            "fee_account",
+            "token_program",
+            "oracle_main_account",
+            "oracle_sub_account",
+            "oracle_pc_account",
+        ]
+    }
+}
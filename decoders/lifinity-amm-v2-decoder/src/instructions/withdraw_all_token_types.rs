@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -22,6 +23,7 @@ pub struct WithdrawAllTokenTypesInstructionAccounts {
     pub dest_token_a_info: solana_pubkey::Pubkey,
     pub dest_token_b_info: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for WithdrawAllTokenTypes {
@@ -30,7 +32,7 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawAllTokenTypes {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [amm, authority, user_transfer_authority_info, source_info, token_a, token_b, pool_mint, dest_token_a_info, dest_token_b_info, token_program, _remaining @ ..] =
+        let [amm, authority, user_transfer_authority_info, source_info, token_a, token_b, pool_mint, dest_token_a_info, dest_token_b_info, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -47,6 +49,108 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawAllTokenTypes {
             dest_token_a_info: dest_token_a_info.pubkey,
             dest_token_b_info: dest_token_b_info.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+#[derive(Debug, PartialEq)]
+pub struct WithdrawAllTokenTypesInstructionAccountMetas {
+    pub amm: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub user_transfer_authority_info: solana_instruction::AccountMeta,
+    pub source_info: solana_instruction::AccountMeta,
+    pub token_a: solana_instruction::AccountMeta,
+    pub token_b: solana_instruction::AccountMeta,
+    pub pool_mint: solana_instruction::AccountMeta,
+    pub dest_token_a_info: solana_instruction::AccountMeta,
+    pub dest_token_b_info: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for WithdrawAllTokenTypes {
+    type ArrangedAccountMetas = WithdrawAllTokenTypesInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [amm, authority, user_transfer_authority_info, source_info, token_a, token_b, pool_mint, dest_token_a_info, dest_token_b_info, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(WithdrawAllTokenTypesInstructionAccountMetas {
+            amm: amm.clone(),
+            authority: authority.clone(),
+            user_transfer_authority_info: user_transfer_authority_info.clone(),
+            source_info: source_info.clone(),
+            token_a: token_a.clone(),
+            token_b: token_b.clone(),
+            pool_mint: pool_mint.clone(),
+            dest_token_a_info: dest_token_a_info.clone(),
+            dest_token_b_info: dest_token_b_info.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct WithdrawAllTokenTypesInstructionAccountIndices {
+    pub amm: usize,
+    pub authority: usize,
+    pub user_transfer_authority_info: usize,
+    pub source_info: usize,
+    pub token_a: usize,
+    pub token_b: usize,
+    pub pool_mint: usize,
+    pub dest_token_a_info: usize,
+    pub dest_token_b_info: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for WithdrawAllTokenTypes {
+    type ArrangedAccountIndices = WithdrawAllTokenTypesInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 10 {
+            return None;
+        }
+
+        Some(WithdrawAllTokenTypesInstructionAccountIndices {
+            amm: 0,
+            authority: 1,
+            user_transfer_authority_info: 2,
+            source_info: 3,
+            token_a: 4,
+            token_b: 5,
+            pool_mint: 6,
+            dest_token_a_info: 7,
+            dest_token_b_info: 8,
+            token_program: 9,
+            remaining_accounts: 10..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for WithdrawAllTokenTypes {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "amm",
+            "authority",
+            "user_transfer_authority_info",
+            "source_info",
+            "token_a",
+            "token_b",
+            "pool_mint",
+            "dest_token_a_info",
+            "dest_token_b_info",
+            "token_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +12,7 @@ pub struct LendingAccountCloseBalanceInstructionAccounts {
     pub marginfi_account: solana_pubkey::Pubkey,
     pub signer: solana_pubkey::Pubkey,
     pub bank: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for LendingAccountCloseBalance {
@@ -19,7 +21,7 @@ impl carbon_core::deserialize::ArrangeAccounts for LendingAccountCloseBalance {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [marginfi_group, marginfi_account, signer, bank, _remaining @ ..] = accounts else {
+        let [marginfi_group, marginfi_account, signer, bank, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +30,69 @@ impl carbon_core::deserialize::ArrangeAccounts for LendingAccountCloseBalance {
             marginfi_account: marginfi_account.pubkey,
             signer: signer.pubkey,
             bank: bank.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct LendingAccountCloseBalanceInstructionAccountMetas {
+    pub marginfi_group: solana_instruction::AccountMeta,
+    pub marginfi_account: solana_instruction::AccountMeta,
+    pub signer: solana_instruction::AccountMeta,
+    pub bank: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for LendingAccountCloseBalance {
+    type ArrangedAccountMetas = LendingAccountCloseBalanceInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [marginfi_group, marginfi_account, signer, bank, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(LendingAccountCloseBalanceInstructionAccountMetas {
+            marginfi_group: marginfi_group.clone(),
+            marginfi_account: marginfi_account.clone(),
+            signer: signer.clone(),
+            bank: bank.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct LendingAccountCloseBalanceInstructionAccountIndices {
+    pub marginfi_group: usize,
+    pub marginfi_account: usize,
+    pub signer: usize,
+    pub bank: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for LendingAccountCloseBalance {
+    type ArrangedAccountIndices = LendingAccountCloseBalanceInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(LendingAccountCloseBalanceInstructionAccountIndices {
+            marginfi_group: 0,
+            marginfi_account: 1,
+            signer: 2,
+            bank: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for LendingAccountCloseBalance {
+    fn account_names() -> &'static [&'static str] {
+        &["marginfi_group", "marginfi_account", "signer", "bank"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -16,6 +17,7 @@ pub struct LendingAccountDepositInstructionAccounts {
     pub signer_token_account: solana_pubkey::Pubkey,
     pub bank_liquidity_vault: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for LendingAccountDeposit {
@@ -24,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for LendingAccountDeposit {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [marginfi_group, marginfi_account, signer, bank, signer_token_account, bank_liquidity_vault, token_program, _remaining @ ..] =
+        let [marginfi_group, marginfi_account, signer, bank, signer_token_account, bank_liquidity_vault, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -38,6 +40,91 @@ impl carbon_core::deserialize::ArrangeAccounts for LendingAccountDeposit {
             signer_token_account: signer_token_account.pubkey,
             bank_liquidity_vault: bank_liquidity_vault.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct LendingAccountDepositInstructionAccountMetas {
+    pub marginfi_group: solana_instruction::AccountMeta,
+    pub marginfi_account: solana_instruction::AccountMeta,
+    pub signer: solana_instruction::AccountMeta,
+    pub bank: solana_instruction::AccountMeta,
+    pub signer_token_account: solana_instruction::AccountMeta,
+    pub bank_liquidity_vault: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for LendingAccountDeposit {
+    type ArrangedAccountMetas = LendingAccountDepositInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [marginfi_group, marginfi_account, signer, bank, signer_token_account, bank_liquidity_vault, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(LendingAccountDepositInstructionAccountMetas {
+            marginfi_group: marginfi_group.clone(),
+            marginfi_account: marginfi_account.clone(),
+            signer: signer.clone(),
+            bank: bank.clone(),
+            signer_token_account: signer_token_account.clone(),
+            bank_liquidity_vault: bank_liquidity_vault.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct LendingAccountDepositInstructionAccountIndices {
+    pub marginfi_group: usize,
+    pub marginfi_account: usize,
+    pub signer: usize,
+    pub bank: usize,
+    pub signer_token_account: usize,
+    pub bank_liquidity_vault: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for LendingAccountDeposit {
+    type ArrangedAccountIndices = LendingAccountDepositInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 7 {
+            return None;
+        }
+
+        Some(LendingAccountDepositInstructionAccountIndices {
+            marginfi_group: 0,
+            marginfi_account: 1,
+            signer: 2,
+            bank: 3,
+            signer_token_account: 4,
+            bank_liquidity_vault: 5,
+            token_program: 6,
+            remaining_accounts: 7..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for LendingAccountDeposit {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "marginfi_group",
+            "marginfi_account",
+            "signer",
+            "bank",
+            "signer_token_account",
+            "bank_liquidity_vault",
+            "token_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -9,6 +10,7 @@ pub struct LendingAccountEndFlashloan {}
 pub struct LendingAccountEndFlashloanInstructionAccounts {
     pub marginfi_account: solana_pubkey::Pubkey,
     pub signer: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for LendingAccountEndFlashloan {
@@ -17,13 +19,68 @@ impl carbon_core::deserialize::ArrangeAccounts for LendingAccountEndFlashloan {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [marginfi_account, signer, _remaining @ ..] = accounts else {
+        let [marginfi_account, signer, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(LendingAccountEndFlashloanInstructionAccounts {
             marginfi_account: marginfi_account.pubkey,
             signer: signer.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct LendingAccountEndFlashloanInstructionAccountMetas {
+    pub marginfi_account: solana_instruction::AccountMeta,
+    pub signer: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for LendingAccountEndFlashloan {
+    type ArrangedAccountMetas = LendingAccountEndFlashloanInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [marginfi_account, signer, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(LendingAccountEndFlashloanInstructionAccountMetas {
+            marginfi_account: marginfi_account.clone(),
+            signer: signer.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct LendingAccountEndFlashloanInstructionAccountIndices {
+    pub marginfi_account: usize,
+    pub signer: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for LendingAccountEndFlashloan {
+    type ArrangedAccountIndices = LendingAccountEndFlashloanInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(LendingAccountEndFlashloanInstructionAccountIndices {
+            marginfi_account: 0,
+            signer: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for LendingAccountEndFlashloan {
+    fn account_names() -> &'static [&'static str] {
+        &["marginfi_account", "signer"]
+    }
+}
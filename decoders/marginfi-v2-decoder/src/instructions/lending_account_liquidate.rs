@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -19,6 +20,7 @@ pub struct LendingAccountLiquidateInstructionAccounts {
     pub bank_liquidity_vault: solana_pubkey::Pubkey,
     pub bank_insurance_vault: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for LendingAccountLiquidate {
@@ -27,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for LendingAccountLiquidate {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [marginfi_group, asset_bank, liab_bank, liquidator_marginfi_account, signer, liquidatee_marginfi_account, bank_liquidity_vault_authority, bank_liquidity_vault, bank_insurance_vault, token_program, _remaining @ ..] =
+        let [marginfi_group, asset_bank, liab_bank, liquidator_marginfi_account, signer, liquidatee_marginfi_account, bank_liquidity_vault_authority, bank_liquidity_vault, bank_insurance_vault, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -44,6 +46,106 @@ impl carbon_core::deserialize::ArrangeAccounts for LendingAccountLiquidate {
             bank_liquidity_vault: bank_liquidity_vault.pubkey,
             bank_insurance_vault: bank_insurance_vault.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct LendingAccountLiquidateInstructionAccountMetas {
+    pub marginfi_group: solana_instruction::AccountMeta,
+    pub asset_bank: solana_instruction::AccountMeta,
+    pub liab_bank: solana_instruction::AccountMeta,
+    pub liquidator_marginfi_account: solana_instruction::AccountMeta,
+    pub signer: solana_instruction::AccountMeta,
+    pub liquidatee_marginfi_account: solana_instruction::AccountMeta,
+    pub bank_liquidity_vault_authority: solana_instruction::AccountMeta,
+    pub bank_liquidity_vault: solana_instruction::AccountMeta,
+    pub bank_insurance_vault: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for LendingAccountLiquidate {
+    type ArrangedAccountMetas = LendingAccountLiquidateInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [marginfi_group, asset_bank, liab_bank, liquidator_marginfi_account, signer, liquidatee_marginfi_account, bank_liquidity_vault_authority, bank_liquidity_vault, bank_insurance_vault, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(LendingAccountLiquidateInstructionAccountMetas {
+            marginfi_group: marginfi_group.clone(),
+            asset_bank: asset_bank.clone(),
+            liab_bank: liab_bank.clone(),
+            liquidator_marginfi_account: liquidator_marginfi_account.clone(),
+            signer: signer.clone(),
+            liquidatee_marginfi_account: liquidatee_marginfi_account.clone(),
+            bank_liquidity_vault_authority: bank_liquidity_vault_authority.clone(),
+            bank_liquidity_vault: bank_liquidity_vault.clone(),
+            bank_insurance_vault: bank_insurance_vault.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct LendingAccountLiquidateInstructionAccountIndices {
+    pub marginfi_group: usize,
+    pub asset_bank: usize,
+    pub liab_bank: usize,
+    pub liquidator_marginfi_account: usize,
+    pub signer: usize,
+    pub liquidatee_marginfi_account: usize,
+    pub bank_liquidity_vault_authority: usize,
+    pub bank_liquidity_vault: usize,
+    pub bank_insurance_vault: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for LendingAccountLiquidate {
+    type ArrangedAccountIndices = LendingAccountLiquidateInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 10 {
+            return None;
+        }
+
+        Some(LendingAccountLiquidateInstructionAccountIndices {
+            marginfi_group: 0,
+            asset_bank: 1,
+            liab_bank: 2,
+            liquidator_marginfi_account: 3,
+            signer: 4,
+            liquidatee_marginfi_account: 5,
+            bank_liquidity_vault_authority: 6,
+            bank_liquidity_vault: 7,
+            bank_insurance_vault: 8,
+            token_program: 9,
+            remaining_accounts: 10..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for LendingAccountLiquidate {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "marginfi_group",
+            "asset_bank",
+            "liab_bank",
+            "liquidator_marginfi_account",
+            "signer",
+            "liquidatee_marginfi_account",
+            "bank_liquidity_vault_authority",
+            "bank_liquidity_vault",
+            "bank_insurance_vault",
+            "token_program",
+        ]
+    }
+}
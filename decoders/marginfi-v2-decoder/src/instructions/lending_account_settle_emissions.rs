@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -9,6 +10,7 @@ pub struct LendingAccountSettleEmissions {}
 pub struct LendingAccountSettleEmissionsInstructionAccounts {
     pub marginfi_account: solana_pubkey::Pubkey,
     pub bank: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for LendingAccountSettleEmissions {
@@ -17,13 +19,68 @@ impl carbon_core::deserialize::ArrangeAccounts for LendingAccountSettleEmissions
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [marginfi_account, bank, _remaining @ ..] = accounts else {
+        let [marginfi_account, bank, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(LendingAccountSettleEmissionsInstructionAccounts {
             marginfi_account: marginfi_account.pubkey,
             bank: bank.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct LendingAccountSettleEmissionsInstructionAccountMetas {
+    pub marginfi_account: solana_instruction::AccountMeta,
+    pub bank: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for LendingAccountSettleEmissions {
+    type ArrangedAccountMetas = LendingAccountSettleEmissionsInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [marginfi_account, bank, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(LendingAccountSettleEmissionsInstructionAccountMetas {
+            marginfi_account: marginfi_account.clone(),
+            bank: bank.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct LendingAccountSettleEmissionsInstructionAccountIndices {
+    pub marginfi_account: usize,
+    pub bank: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for LendingAccountSettleEmissions {
+    type ArrangedAccountIndices = LendingAccountSettleEmissionsInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(LendingAccountSettleEmissionsInstructionAccountIndices {
+            marginfi_account: 0,
+            bank: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for LendingAccountSettleEmissions {
+    fn account_names() -> &'static [&'static str] {
+        &["marginfi_account", "bank"]
+    }
+}
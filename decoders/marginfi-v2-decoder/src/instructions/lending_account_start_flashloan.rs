@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +13,7 @@ pub struct LendingAccountStartFlashloanInstructionAccounts {
     pub marginfi_account: solana_pubkey::Pubkey,
     pub signer: solana_pubkey::Pubkey,
     pub ixs_sysvar: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for LendingAccountStartFlashloan {
@@ -20,7 +22,7 @@ impl carbon_core::deserialize::ArrangeAccounts for LendingAccountStartFlashloan
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [marginfi_account, signer, ixs_sysvar, _remaining @ ..] = accounts else {
+        let [marginfi_account, signer, ixs_sysvar, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +30,65 @@ impl carbon_core::deserialize::ArrangeAccounts for LendingAccountStartFlashloan
             marginfi_account: marginfi_account.pubkey,
             signer: signer.pubkey,
             ixs_sysvar: ixs_sysvar.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct LendingAccountStartFlashloanInstructionAccountMetas {
+    pub marginfi_account: solana_instruction::AccountMeta,
+    pub signer: solana_instruction::AccountMeta,
+    pub ixs_sysvar: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for LendingAccountStartFlashloan {
+    type ArrangedAccountMetas = LendingAccountStartFlashloanInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [marginfi_account, signer, ixs_sysvar, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(LendingAccountStartFlashloanInstructionAccountMetas {
+            marginfi_account: marginfi_account.clone(),
+            signer: signer.clone(),
+            ixs_sysvar: ixs_sysvar.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct LendingAccountStartFlashloanInstructionAccountIndices {
+    pub marginfi_account: usize,
+    pub signer: usize,
+    pub ixs_sysvar: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for LendingAccountStartFlashloan {
+    type ArrangedAccountIndices = LendingAccountStartFlashloanInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(LendingAccountStartFlashloanInstructionAccountIndices {
+            marginfi_account: 0,
+            signer: 1,
+            ixs_sysvar: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for LendingAccountStartFlashloan {
+    fn account_names() -> &'static [&'static str] {
+        &["marginfi_account", "signer", "ixs_sysvar"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -18,6 +19,7 @@ pub struct LendingAccountWithdrawInstructionAccounts {
     pub bank_liquidity_vault_authority: solana_pubkey::Pubkey,
     pub bank_liquidity_vault: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for LendingAccountWithdraw {
@@ -26,7 +28,7 @@ impl carbon_core::deserialize::ArrangeAccounts for LendingAccountWithdraw {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [marginfi_group, marginfi_account, signer, bank, destination_token_account, bank_liquidity_vault_authority, bank_liquidity_vault, token_program, _remaining @ ..] =
+        let [marginfi_group, marginfi_account, signer, bank, destination_token_account, bank_liquidity_vault_authority, bank_liquidity_vault, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -41,6 +43,96 @@ impl carbon_core::deserialize::ArrangeAccounts for LendingAccountWithdraw {
             bank_liquidity_vault_authority: bank_liquidity_vault_authority.pubkey,
             bank_liquidity_vault: bank_liquidity_vault.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct LendingAccountWithdrawInstructionAccountMetas {
+    pub marginfi_group: solana_instruction::AccountMeta,
+    pub marginfi_account: solana_instruction::AccountMeta,
+    pub signer: solana_instruction::AccountMeta,
+    pub bank: solana_instruction::AccountMeta,
+    pub destination_token_account: solana_instruction::AccountMeta,
+    pub bank_liquidity_vault_authority: solana_instruction::AccountMeta,
+    pub bank_liquidity_vault: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for LendingAccountWithdraw {
+    type ArrangedAccountMetas = LendingAccountWithdrawInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [marginfi_group, marginfi_account, signer, bank, destination_token_account, bank_liquidity_vault_authority, bank_liquidity_vault, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(LendingAccountWithdrawInstructionAccountMetas {
+            marginfi_group: marginfi_group.clone(),
+            marginfi_account: marginfi_account.clone(),
+            signer: signer.clone(),
+            bank: bank.clone(),
+            destination_token_account: destination_token_account.clone(),
+            bank_liquidity_vault_authority: bank_liquidity_vault_authority.clone(),
+            bank_liquidity_vault: bank_liquidity_vault.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct LendingAccountWithdrawInstructionAccountIndices {
+    pub marginfi_group: usize,
+    pub marginfi_account: usize,
+    pub signer: usize,
+    pub bank: usize,
+    pub destination_token_account: usize,
+    pub bank_liquidity_vault_authority: usize,
+    pub bank_liquidity_vault: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for LendingAccountWithdraw {
+    type ArrangedAccountIndices = LendingAccountWithdrawInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(LendingAccountWithdrawInstructionAccountIndices {
+            marginfi_group: 0,
+            marginfi_account: 1,
+            signer: 2,
+            bank: 3,
+            destination_token_account: 4,
+            bank_liquidity_vault_authority: 5,
+            bank_liquidity_vault: 6,
+            token_program: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for LendingAccountWithdraw {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "marginfi_group",
+            "marginfi_account",
+            "signer",
+            "bank",
+            "destination_token_account",
+            "bank_liquidity_vault_authority",
+            "bank_liquidity_vault",
+            "token_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -16,6 +17,7 @@ pub struct LendingAccountWithdrawEmissionsInstructionAccounts {
     pub emissions_vault: solana_pubkey::Pubkey,
     pub destination_account: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for LendingAccountWithdrawEmissions {
@@ -24,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for LendingAccountWithdrawEmissio
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [marginfi_group, marginfi_account, signer, bank, emissions_mint, emissions_auth, emissions_vault, destination_account, token_program, _remaining @ ..] =
+        let [marginfi_group, marginfi_account, signer, bank, emissions_mint, emissions_auth, emissions_vault, destination_account, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -40,6 +42,101 @@ impl carbon_core::deserialize::ArrangeAccounts for LendingAccountWithdrawEmissio
             emissions_vault: emissions_vault.pubkey,
             destination_account: destination_account.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct LendingAccountWithdrawEmissionsInstructionAccountMetas {
+    pub marginfi_group: solana_instruction::AccountMeta,
+    pub marginfi_account: solana_instruction::AccountMeta,
+    pub signer: solana_instruction::AccountMeta,
+    pub bank: solana_instruction::AccountMeta,
+    pub emissions_mint: solana_instruction::AccountMeta,
+    pub emissions_auth: solana_instruction::AccountMeta,
+    pub emissions_vault: solana_instruction::AccountMeta,
+    pub destination_account: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for LendingAccountWithdrawEmissions {
+    type ArrangedAccountMetas = LendingAccountWithdrawEmissionsInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [marginfi_group, marginfi_account, signer, bank, emissions_mint, emissions_auth, emissions_vault, destination_account, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(LendingAccountWithdrawEmissionsInstructionAccountMetas {
+            marginfi_group: marginfi_group.clone(),
+            marginfi_account: marginfi_account.clone(),
+            signer: signer.clone(),
+            bank: bank.clone(),
+            emissions_mint: emissions_mint.clone(),
+            emissions_auth: emissions_auth.clone(),
+            emissions_vault: emissions_vault.clone(),
+            destination_account: destination_account.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct LendingAccountWithdrawEmissionsInstructionAccountIndices {
+    pub marginfi_group: usize,
+    pub marginfi_account: usize,
+    pub signer: usize,
+    pub bank: usize,
+    pub emissions_mint: usize,
+    pub emissions_auth: usize,
+    pub emissions_vault: usize,
+    pub destination_account: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for LendingAccountWithdrawEmissions {
+    type ArrangedAccountIndices = LendingAccountWithdrawEmissionsInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 9 {
+            return None;
+        }
+
+        Some(LendingAccountWithdrawEmissionsInstructionAccountIndices {
+            marginfi_group: 0,
+            marginfi_account: 1,
+            signer: 2,
+            bank: 3,
+            emissions_mint: 4,
+            emissions_auth: 5,
+            emissions_vault: 6,
+            destination_account: 7,
+            token_program: 8,
+            remaining_accounts: 9..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for LendingAccountWithdrawEmissions {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "marginfi_group",
+            "marginfi_account",
+            "signer",
+            "bank",
+            "emissions_mint",
+            "emissions_auth",
+            "emissions_vault",
+            "destination_account",
+            "token_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -9,6 +10,7 @@ pub struct LendingPoolAccrueBankInterest {}
 pub struct LendingPoolAccrueBankInterestInstructionAccounts {
     pub marginfi_group: solana_pubkey::Pubkey,
     pub bank: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for LendingPoolAccrueBankInterest {
@@ -17,13 +19,68 @@ impl carbon_core::deserialize::ArrangeAccounts for LendingPoolAccrueBankInterest
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [marginfi_group, bank, _remaining @ ..] = accounts else {
+        let [marginfi_group, bank, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(LendingPoolAccrueBankInterestInstructionAccounts {
             marginfi_group: marginfi_group.pubkey,
             bank: bank.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct LendingPoolAccrueBankInterestInstructionAccountMetas {
+    pub marginfi_group: solana_instruction::AccountMeta,
+    pub bank: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for LendingPoolAccrueBankInterest {
+    type ArrangedAccountMetas = LendingPoolAccrueBankInterestInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [marginfi_group, bank, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(LendingPoolAccrueBankInterestInstructionAccountMetas {
+            marginfi_group: marginfi_group.clone(),
+            bank: bank.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct LendingPoolAccrueBankInterestInstructionAccountIndices {
+    pub marginfi_group: usize,
+    pub bank: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for LendingPoolAccrueBankInterest {
+    type ArrangedAccountIndices = LendingPoolAccrueBankInterestInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(LendingPoolAccrueBankInterestInstructionAccountIndices {
+            marginfi_group: 0,
+            bank: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for LendingPoolAccrueBankInterest {
+    fn account_names() -> &'static [&'static str] {
+        &["marginfi_group", "bank"]
+    }
+}
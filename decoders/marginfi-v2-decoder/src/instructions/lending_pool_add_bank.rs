@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -26,6 +27,7 @@ pub struct LendingPoolAddBankInstructionAccounts {
     pub rent: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for LendingPoolAddBank {
@@ -34,7 +36,7 @@ impl carbon_core::deserialize::ArrangeAccounts for LendingPoolAddBank {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [marginfi_group, admin, fee_payer, bank_mint, bank, liquidity_vault_authority, liquidity_vault, insurance_vault_authority, insurance_vault, fee_vault_authority, fee_vault, rent, token_program, system_program, _remaining @ ..] =
+        let [marginfi_group, admin, fee_payer, bank_mint, bank, liquidity_vault_authority, liquidity_vault, insurance_vault_authority, insurance_vault, fee_vault_authority, fee_vault, rent, token_program, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -55,6 +57,126 @@ impl carbon_core::deserialize::ArrangeAccounts for LendingPoolAddBank {
             rent: rent.pubkey,
             token_program: token_program.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct LendingPoolAddBankInstructionAccountMetas {
+    pub marginfi_group: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub fee_payer: solana_instruction::AccountMeta,
+    pub bank_mint: solana_instruction::AccountMeta,
+    pub bank: solana_instruction::AccountMeta,
+    pub liquidity_vault_authority: solana_instruction::AccountMeta,
+    pub liquidity_vault: solana_instruction::AccountMeta,
+    pub insurance_vault_authority: solana_instruction::AccountMeta,
+    pub insurance_vault: solana_instruction::AccountMeta,
+    pub fee_vault_authority: solana_instruction::AccountMeta,
+    pub fee_vault: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for LendingPoolAddBank {
+    type ArrangedAccountMetas = LendingPoolAddBankInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [marginfi_group, admin, fee_payer, bank_mint, bank, liquidity_vault_authority, liquidity_vault, insurance_vault_authority, insurance_vault, fee_vault_authority, fee_vault, rent, token_program, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(LendingPoolAddBankInstructionAccountMetas {
+            marginfi_group: marginfi_group.clone(),
+            admin: admin.clone(),
+            fee_payer: fee_payer.clone(),
+            bank_mint: bank_mint.clone(),
+            bank: bank.clone(),
+            liquidity_vault_authority: liquidity_vault_authority.clone(),
+            liquidity_vault: liquidity_vault.clone(),
+            insurance_vault_authority: insurance_vault_authority.clone(),
+            insurance_vault: insurance_vault.clone(),
+            fee_vault_authority: fee_vault_authority.clone(),
+            fee_vault: fee_vault.clone(),
+            rent: rent.clone(),
+            token_program: token_program.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct LendingPoolAddBankInstructionAccountIndices {
+    pub marginfi_group: usize,
+    pub admin: usize,
+    pub fee_payer: usize,
+    pub bank_mint: usize,
+    pub bank: usize,
+    pub liquidity_vault_authority: usize,
+    pub liquidity_vault: usize,
+    pub insurance_vault_authority: usize,
+    pub insurance_vault: usize,
+    pub fee_vault_authority: usize,
+    pub fee_vault: usize,
+    pub rent: usize,
+    pub token_program: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for LendingPoolAddBank {
+    type ArrangedAccountIndices = LendingPoolAddBankInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 14 {
+            return None;
+        }
+
+        Some(LendingPoolAddBankInstructionAccountIndices {
+            marginfi_group: 0,
+            admin: 1,
+            fee_payer: 2,
+            bank_mint: 3,
+            bank: 4,
+            liquidity_vault_authority: 5,
+            liquidity_vault: 6,
+            insurance_vault_authority: 7,
+            insurance_vault: 8,
+            fee_vault_authority: 9,
+            fee_vault: 10,
+            rent: 11,
+            token_program: 12,
+            system_program: 13,
+            remaining_accounts: 14..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for LendingPoolAddBank {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "marginfi_group",
+            "admin",
+            "fee_payer",
+            "bank_mint",
+            "bank",
+            "liquidity_vault_authority",
+            "liquidity_vault",
+            "insurance_vault_authority",
+            "insurance_vault",
+            "fee_vault_authority",
+            "fee_vault",
+            "rent",
+            "token_program",
+            "system_program",
+        ]
+    }
+}
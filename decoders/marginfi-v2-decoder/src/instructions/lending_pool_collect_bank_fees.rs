@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct LendingPoolCollectBankFeesInstructionAccounts {
     pub insurance_vault: solana_pubkey::Pubkey,
     pub fee_vault: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for LendingPoolCollectBankFees {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for LendingPoolCollectBankFees {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [marginfi_group, bank, liquidity_vault_authority, liquidity_vault, insurance_vault, fee_vault, token_program, _remaining @ ..] =
+        let [marginfi_group, bank, liquidity_vault_authority, liquidity_vault, insurance_vault, fee_vault, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -36,6 +38,91 @@ impl carbon_core::deserialize::ArrangeAccounts for LendingPoolCollectBankFees {
             insurance_vault: insurance_vault.pubkey,
             fee_vault: fee_vault.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct LendingPoolCollectBankFeesInstructionAccountMetas {
+    pub marginfi_group: solana_instruction::AccountMeta,
+    pub bank: solana_instruction::AccountMeta,
+    pub liquidity_vault_authority: solana_instruction::AccountMeta,
+    pub liquidity_vault: solana_instruction::AccountMeta,
+    pub insurance_vault: solana_instruction::AccountMeta,
+    pub fee_vault: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for LendingPoolCollectBankFees {
+    type ArrangedAccountMetas = LendingPoolCollectBankFeesInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [marginfi_group, bank, liquidity_vault_authority, liquidity_vault, insurance_vault, fee_vault, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(LendingPoolCollectBankFeesInstructionAccountMetas {
+            marginfi_group: marginfi_group.clone(),
+            bank: bank.clone(),
+            liquidity_vault_authority: liquidity_vault_authority.clone(),
+            liquidity_vault: liquidity_vault.clone(),
+            insurance_vault: insurance_vault.clone(),
+            fee_vault: fee_vault.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct LendingPoolCollectBankFeesInstructionAccountIndices {
+    pub marginfi_group: usize,
+    pub bank: usize,
+    pub liquidity_vault_authority: usize,
+    pub liquidity_vault: usize,
+    pub insurance_vault: usize,
+    pub fee_vault: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for LendingPoolCollectBankFees {
+    type ArrangedAccountIndices = LendingPoolCollectBankFeesInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 7 {
+            return None;
+        }
+
+        Some(LendingPoolCollectBankFeesInstructionAccountIndices {
+            marginfi_group: 0,
+            bank: 1,
+            liquidity_vault_authority: 2,
+            liquidity_vault: 3,
+            insurance_vault: 4,
+            fee_vault: 5,
+            token_program: 6,
+            remaining_accounts: 7..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for LendingPoolCollectBankFees {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "marginfi_group",
+            "bank",
+            "liquidity_vault_authority",
+            "liquidity_vault",
+            "insurance_vault",
+            "fee_vault",
+            "token_program",
+        ]
+    }
+}
@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -15,6 +16,7 @@ pub struct LendingPoolConfigureBankInstructionAccounts {
     pub marginfi_group: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
     pub bank: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for LendingPoolConfigureBank {
@@ -23,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for LendingPoolConfigureBank {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [marginfi_group, admin, bank, _remaining @ ..] = accounts else {
+        let [marginfi_group, admin, bank, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -31,6 +33,65 @@ impl carbon_core::deserialize::ArrangeAccounts for LendingPoolConfigureBank {
             marginfi_group: marginfi_group.pubkey,
             admin: admin.pubkey,
             bank: bank.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct LendingPoolConfigureBankInstructionAccountMetas {
+    pub marginfi_group: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub bank: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for LendingPoolConfigureBank {
+    type ArrangedAccountMetas = LendingPoolConfigureBankInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [marginfi_group, admin, bank, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(LendingPoolConfigureBankInstructionAccountMetas {
+            marginfi_group: marginfi_group.clone(),
+            admin: admin.clone(),
+            bank: bank.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct LendingPoolConfigureBankInstructionAccountIndices {
+    pub marginfi_group: usize,
+    pub admin: usize,
+    pub bank: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for LendingPoolConfigureBank {
+    type ArrangedAccountIndices = LendingPoolConfigureBankInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(LendingPoolConfigureBankInstructionAccountIndices {
+            marginfi_group: 0,
+            admin: 1,
+            bank: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for LendingPoolConfigureBank {
+    fn account_names() -> &'static [&'static str] {
+        &["marginfi_group", "admin", "bank"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -15,6 +16,7 @@ pub struct LendingPoolHandleBankruptcyInstructionAccounts {
     pub insurance_vault: solana_pubkey::Pubkey,
     pub insurance_vault_authority: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for LendingPoolHandleBankruptcy {
@@ -23,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for LendingPoolHandleBankruptcy {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [marginfi_group, admin, bank, marginfi_account, liquidity_vault, insurance_vault, insurance_vault_authority, token_program, _remaining @ ..] =
+        let [marginfi_group, admin, bank, marginfi_account, liquidity_vault, insurance_vault, insurance_vault_authority, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -38,6 +40,96 @@ impl carbon_core::deserialize::ArrangeAccounts for LendingPoolHandleBankruptcy {
             insurance_vault: insurance_vault.pubkey,
             insurance_vault_authority: insurance_vault_authority.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct LendingPoolHandleBankruptcyInstructionAccountMetas {
+    pub marginfi_group: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub bank: solana_instruction::AccountMeta,
+    pub marginfi_account: solana_instruction::AccountMeta,
+    pub liquidity_vault: solana_instruction::AccountMeta,
+    pub insurance_vault: solana_instruction::AccountMeta,
+    pub insurance_vault_authority: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for LendingPoolHandleBankruptcy {
+    type ArrangedAccountMetas = LendingPoolHandleBankruptcyInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [marginfi_group, admin, bank, marginfi_account, liquidity_vault, insurance_vault, insurance_vault_authority, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(LendingPoolHandleBankruptcyInstructionAccountMetas {
+            marginfi_group: marginfi_group.clone(),
+            admin: admin.clone(),
+            bank: bank.clone(),
+            marginfi_account: marginfi_account.clone(),
+            liquidity_vault: liquidity_vault.clone(),
+            insurance_vault: insurance_vault.clone(),
+            insurance_vault_authority: insurance_vault_authority.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct LendingPoolHandleBankruptcyInstructionAccountIndices {
+    pub marginfi_group: usize,
+    pub admin: usize,
+    pub bank: usize,
+    pub marginfi_account: usize,
+    pub liquidity_vault: usize,
+    pub insurance_vault: usize,
+    pub insurance_vault_authority: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for LendingPoolHandleBankruptcy {
+    type ArrangedAccountIndices = LendingPoolHandleBankruptcyInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(LendingPoolHandleBankruptcyInstructionAccountIndices {
+            marginfi_group: 0,
+            admin: 1,
+            bank: 2,
+            marginfi_account: 3,
+            liquidity_vault: 4,
+            insurance_vault: 5,
+            insurance_vault_authority: 6,
+            token_program: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for LendingPoolHandleBankruptcy {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "marginfi_group",
+            "admin",
+            "bank",
+            "marginfi_account",
+            "liquidity_vault",
+            "insurance_vault",
+            "insurance_vault_authority",
+            "token_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -20,6 +21,7 @@ pub struct LendingPoolSetupEmissionsInstructionAccounts {
     pub emissions_funding_account: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for LendingPoolSetupEmissions {
@@ -28,7 +30,7 @@ impl carbon_core::deserialize::ArrangeAccounts for LendingPoolSetupEmissions {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [marginfi_group, admin, bank, emissions_mint, emissions_auth, emissions_token_account, emissions_funding_account, token_program, system_program, _remaining @ ..] =
+        let [marginfi_group, admin, bank, emissions_mint, emissions_auth, emissions_token_account, emissions_funding_account, token_program, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -44,6 +46,101 @@ impl carbon_core::deserialize::ArrangeAccounts for LendingPoolSetupEmissions {
             emissions_funding_account: emissions_funding_account.pubkey,
             token_program: token_program.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct LendingPoolSetupEmissionsInstructionAccountMetas {
+    pub marginfi_group: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub bank: solana_instruction::AccountMeta,
+    pub emissions_mint: solana_instruction::AccountMeta,
+    pub emissions_auth: solana_instruction::AccountMeta,
+    pub emissions_token_account: solana_instruction::AccountMeta,
+    pub emissions_funding_account: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for LendingPoolSetupEmissions {
+    type ArrangedAccountMetas = LendingPoolSetupEmissionsInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [marginfi_group, admin, bank, emissions_mint, emissions_auth, emissions_token_account, emissions_funding_account, token_program, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(LendingPoolSetupEmissionsInstructionAccountMetas {
+            marginfi_group: marginfi_group.clone(),
+            admin: admin.clone(),
+            bank: bank.clone(),
+            emissions_mint: emissions_mint.clone(),
+            emissions_auth: emissions_auth.clone(),
+            emissions_token_account: emissions_token_account.clone(),
+            emissions_funding_account: emissions_funding_account.clone(),
+            token_program: token_program.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct LendingPoolSetupEmissionsInstructionAccountIndices {
+    pub marginfi_group: usize,
+    pub admin: usize,
+    pub bank: usize,
+    pub emissions_mint: usize,
+    pub emissions_auth: usize,
+    pub emissions_token_account: usize,
+    pub emissions_funding_account: usize,
+    pub token_program: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for LendingPoolSetupEmissions {
+    type ArrangedAccountIndices = LendingPoolSetupEmissionsInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 9 {
+            return None;
+        }
+
+        Some(LendingPoolSetupEmissionsInstructionAccountIndices {
+            marginfi_group: 0,
+            admin: 1,
+            bank: 2,
+            emissions_mint: 3,
+            emissions_auth: 4,
+            emissions_token_account: 5,
+            emissions_funding_account: 6,
+            token_program: 7,
+            system_program: 8,
+            remaining_accounts: 9..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for LendingPoolSetupEmissions {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "marginfi_group",
+            "admin",
+            "bank",
+            "emissions_mint",
+            "emissions_auth",
+            "emissions_token_account",
+            "emissions_funding_account",
+            "token_program",
+            "system_program",
+        ]
+    }
+}
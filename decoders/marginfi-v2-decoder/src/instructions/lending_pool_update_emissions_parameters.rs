@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -18,6 +19,7 @@ pub struct LendingPoolUpdateEmissionsParametersInstructionAccounts {
     pub emissions_token_account: solana_pubkey::Pubkey,
     pub emissions_funding_account: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for LendingPoolUpdateEmissionsParameters {
@@ -26,7 +28,7 @@ impl carbon_core::deserialize::ArrangeAccounts for LendingPoolUpdateEmissionsPar
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [marginfi_group, admin, bank, emissions_mint, emissions_token_account, emissions_funding_account, token_program, _remaining @ ..] =
+        let [marginfi_group, admin, bank, emissions_mint, emissions_token_account, emissions_funding_account, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -40,6 +42,95 @@ impl carbon_core::deserialize::ArrangeAccounts for LendingPoolUpdateEmissionsPar
             emissions_token_account: emissions_token_account.pubkey,
             emissions_funding_account: emissions_funding_account.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct LendingPoolUpdateEmissionsParametersInstructionAccountMetas {
+    pub marginfi_group: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub bank: solana_instruction::AccountMeta,
+    pub emissions_mint: solana_instruction::AccountMeta,
+    pub emissions_token_account: solana_instruction::AccountMeta,
+    pub emissions_funding_account: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for LendingPoolUpdateEmissionsParameters {
+    type ArrangedAccountMetas = LendingPoolUpdateEmissionsParametersInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [marginfi_group, admin, bank, emissions_mint, emissions_token_account, emissions_funding_account, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(
+            LendingPoolUpdateEmissionsParametersInstructionAccountMetas {
+                marginfi_group: marginfi_group.clone(),
+                admin: admin.clone(),
+                bank: bank.clone(),
+                emissions_mint: emissions_mint.clone(),
+                emissions_token_account: emissions_token_account.clone(),
+                emissions_funding_account: emissions_funding_account.clone(),
+                token_program: token_program.clone(),
+                remaining_accounts: remaining.to_vec(),
+            },
+        )
+    }
+}
+
+pub struct LendingPoolUpdateEmissionsParametersInstructionAccountIndices {
+    pub marginfi_group: usize,
+    pub admin: usize,
+    pub bank: usize,
+    pub emissions_mint: usize,
+    pub emissions_token_account: usize,
+    pub emissions_funding_account: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for LendingPoolUpdateEmissionsParameters {
+    type ArrangedAccountIndices = LendingPoolUpdateEmissionsParametersInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 7 {
+            return None;
+        }
+
+        Some(
+            LendingPoolUpdateEmissionsParametersInstructionAccountIndices {
+                marginfi_group: 0,
+                admin: 1,
+                bank: 2,
+                emissions_mint: 3,
+                emissions_token_account: 4,
+                emissions_funding_account: 5,
+                token_program: 6,
+                remaining_accounts: 7..accounts.len(),
+            },
+        )
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for LendingPoolUpdateEmissionsParameters {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "marginfi_group",
+            "admin",
+            "bank",
+            "emissions_mint",
+            "emissions_token_account",
+            "emissions_funding_account",
+            "token_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +13,7 @@ pub struct MarginfiAccountInitializeInstructionAccounts {
     pub authority: solana_pubkey::Pubkey,
     pub fee_payer: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for MarginfiAccountInitialize {
@@ -20,7 +22,7 @@ impl carbon_core::deserialize::ArrangeAccounts for MarginfiAccountInitialize {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [marginfi_group, marginfi_account, authority, fee_payer, system_program, _remaining @ ..] =
+        let [marginfi_group, marginfi_account, authority, fee_payer, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -32,6 +34,81 @@ impl carbon_core::deserialize::ArrangeAccounts for MarginfiAccountInitialize {
             authority: authority.pubkey,
             fee_payer: fee_payer.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct MarginfiAccountInitializeInstructionAccountMetas {
+    pub marginfi_group: solana_instruction::AccountMeta,
+    pub marginfi_account: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub fee_payer: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for MarginfiAccountInitialize {
+    type ArrangedAccountMetas = MarginfiAccountInitializeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [marginfi_group, marginfi_account, authority, fee_payer, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(MarginfiAccountInitializeInstructionAccountMetas {
+            marginfi_group: marginfi_group.clone(),
+            marginfi_account: marginfi_account.clone(),
+            authority: authority.clone(),
+            fee_payer: fee_payer.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct MarginfiAccountInitializeInstructionAccountIndices {
+    pub marginfi_group: usize,
+    pub marginfi_account: usize,
+    pub authority: usize,
+    pub fee_payer: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for MarginfiAccountInitialize {
+    type ArrangedAccountIndices = MarginfiAccountInitializeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(MarginfiAccountInitializeInstructionAccountIndices {
+            marginfi_group: 0,
+            marginfi_account: 1,
+            authority: 2,
+            fee_payer: 3,
+            system_program: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for MarginfiAccountInitialize {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "marginfi_group",
+            "marginfi_account",
+            "authority",
+            "fee_payer",
+            "system_program",
+        ]
+    }
+}
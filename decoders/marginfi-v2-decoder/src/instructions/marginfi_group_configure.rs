@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct MarginfiGroupConfigure {
 pub struct MarginfiGroupConfigureInstructionAccounts {
     pub marginfi_group: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for MarginfiGroupConfigure {
@@ -22,13 +24,68 @@ impl carbon_core::deserialize::ArrangeAccounts for MarginfiGroupConfigure {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [marginfi_group, admin, _remaining @ ..] = accounts else {
+        let [marginfi_group, admin, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(MarginfiGroupConfigureInstructionAccounts {
             marginfi_group: marginfi_group.pubkey,
             admin: admin.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct MarginfiGroupConfigureInstructionAccountMetas {
+    pub marginfi_group: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for MarginfiGroupConfigure {
+    type ArrangedAccountMetas = MarginfiGroupConfigureInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [marginfi_group, admin, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(MarginfiGroupConfigureInstructionAccountMetas {
+            marginfi_group: marginfi_group.clone(),
+            admin: admin.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct MarginfiGroupConfigureInstructionAccountIndices {
+    pub marginfi_group: usize,
+    pub admin: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for MarginfiGroupConfigure {
+    type ArrangedAccountIndices = MarginfiGroupConfigureInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(MarginfiGroupConfigureInstructionAccountIndices {
+            marginfi_group: 0,
+            admin: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for MarginfiGroupConfigure {
+    fn account_names() -> &'static [&'static str] {
+        &["marginfi_group", "admin"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -10,6 +11,7 @@ pub struct MarginfiGroupInitializeInstructionAccounts {
     pub marginfi_group: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for MarginfiGroupInitialize {
@@ -18,7 +20,7 @@ impl carbon_core::deserialize::ArrangeAccounts for MarginfiGroupInitialize {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [marginfi_group, admin, system_program, _remaining @ ..] = accounts else {
+        let [marginfi_group, admin, system_program, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -26,6 +28,65 @@ impl carbon_core::deserialize::ArrangeAccounts for MarginfiGroupInitialize {
             marginfi_group: marginfi_group.pubkey,
             admin: admin.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct MarginfiGroupInitializeInstructionAccountMetas {
+    pub marginfi_group: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for MarginfiGroupInitialize {
+    type ArrangedAccountMetas = MarginfiGroupInitializeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [marginfi_group, admin, system_program, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(MarginfiGroupInitializeInstructionAccountMetas {
+            marginfi_group: marginfi_group.clone(),
+            admin: admin.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct MarginfiGroupInitializeInstructionAccountIndices {
+    pub marginfi_group: usize,
+    pub admin: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for MarginfiGroupInitialize {
+    type ArrangedAccountIndices = MarginfiGroupInitializeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(MarginfiGroupInitializeInstructionAccountIndices {
+            marginfi_group: 0,
+            admin: 1,
+            system_program: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for MarginfiGroupInitialize {
+    fn account_names() -> &'static [&'static str] {
+        &["marginfi_group", "admin", "system_program"]
+    }
+}
@@ -85,14 +85,24 @@ pub enum MarginfiV2Instruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for MarginfiV2Decoder {
     type InstructionType = MarginfiV2Instruction;
 
-    fn decode_instruction(
+    fn decoder_name(&self) -> &'static str {
+        "marginfi_v2"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[super::PROGRAM_ID]
+    }
+
+    fn try_decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
-    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+    ) -> carbon_core::error::CarbonResult<
+        Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>>,
+    > {
         if !instruction.program_id.eq(&PROGRAM_ID) {
-            return None;
+            return Ok(None);
         }
-        carbon_core::try_decode_instructions!(instruction,
+        Ok(carbon_core::try_decode_instructions!(instruction,
             MarginfiV2Instruction::MarginfiGroupInitialize => marginfi_group_initialize::MarginfiGroupInitialize,
             MarginfiV2Instruction::MarginfiGroupConfigure => marginfi_group_configure::MarginfiGroupConfigure,
             MarginfiV2Instruction::LendingPoolAddBank => lending_pool_add_bank::LendingPoolAddBank,
@@ -131,6 +141,6 @@ impl carbon_core::instruction::InstructionDecoder<'_> for MarginfiV2Decoder {
             MarginfiV2Instruction::LendingAccountWithdrawEvent => lending_account_withdraw_event::LendingAccountWithdrawEvent,
             MarginfiV2Instruction::LendingAccountLiquidateEvent => lending_account_liquidate_event::LendingAccountLiquidateEvent,
             MarginfiV2Instruction::MarginfiAccountTransferAccountAuthorityEvent => marginfi_account_transfer_account_authority_event::MarginfiAccountTransferAccountAuthorityEvent,
-        )
+        ))
     }
 }
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +13,7 @@ pub struct SetNewAccountAuthorityInstructionAccounts {
     pub signer: solana_pubkey::Pubkey,
     pub new_authority: solana_pubkey::Pubkey,
     pub fee_payer: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SetNewAccountAuthority {
@@ -20,7 +22,7 @@ impl carbon_core::deserialize::ArrangeAccounts for SetNewAccountAuthority {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [marginfi_account, marginfi_group, signer, new_authority, fee_payer, _remaining @ ..] =
+        let [marginfi_account, marginfi_group, signer, new_authority, fee_payer, remaining @ ..] =
             accounts
         else {
             return None;
@@ -32,6 +34,81 @@ impl carbon_core::deserialize::ArrangeAccounts for SetNewAccountAuthority {
             signer: signer.pubkey,
             new_authority: new_authority.pubkey,
             fee_payer: fee_payer.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SetNewAccountAuthorityInstructionAccountMetas {
+    pub marginfi_account: solana_instruction::AccountMeta,
+    pub marginfi_group: solana_instruction::AccountMeta,
+    pub signer: solana_instruction::AccountMeta,
+    pub new_authority: solana_instruction::AccountMeta,
+    pub fee_payer: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SetNewAccountAuthority {
+    type ArrangedAccountMetas = SetNewAccountAuthorityInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [marginfi_account, marginfi_group, signer, new_authority, fee_payer, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(SetNewAccountAuthorityInstructionAccountMetas {
+            marginfi_account: marginfi_account.clone(),
+            marginfi_group: marginfi_group.clone(),
+            signer: signer.clone(),
+            new_authority: new_authority.clone(),
+            fee_payer: fee_payer.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SetNewAccountAuthorityInstructionAccountIndices {
+    pub marginfi_account: usize,
+    pub marginfi_group: usize,
+    pub signer: usize,
+    pub new_authority: usize,
+    pub fee_payer: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SetNewAccountAuthority {
+    type ArrangedAccountIndices = SetNewAccountAuthorityInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(SetNewAccountAuthorityInstructionAccountIndices {
+            marginfi_account: 0,
+            marginfi_group: 1,
+            signer: 2,
+            new_authority: 3,
+            fee_payer: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SetNewAccountAuthority {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "marginfi_account",
+            "marginfi_group",
+            "signer",
+            "new_authority",
+            "fee_payer",
+        ]
+    }
+}
@@ -45,3 +45,70 @@ impl AccountDecoder<'_> for MarinadeFinanceDecoder {
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_ticket_account_data_account() {
+        // Arrange
+        let expected_ticket_account = ticket_account_data::TicketAccountData {
+            state_address: solana_pubkey::Pubkey::from_str_const(
+                "8szGkuLTAux9XMgZ2vtY39jVSowEcpBfFfD8hXSEqdGC",
+            ),
+            beneficiary: solana_pubkey::Pubkey::from_str_const(
+                "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM",
+            ),
+            lamports_amount: 1_500_000_000,
+            created_epoch: 650,
+        };
+
+        // Act
+        let decoder = MarinadeFinanceDecoder;
+        let account =
+            carbon_test_utils::read_account("tests/fixtures/ticket_account_data_account.json")
+                .expect("read fixture");
+        let decoded_account = decoder.decode_account(&account).expect("decode fixture");
+
+        // Assert
+        assert_eq!(decoded_account.lamports, 1503360);
+        assert_eq!(decoded_account.owner, PROGRAM_ID);
+        match decoded_account.data {
+            MarinadeFinanceAccount::TicketAccountData(ticket_account) => {
+                assert_eq!(
+                    expected_ticket_account.state_address,
+                    ticket_account.state_address
+                );
+                assert_eq!(
+                    expected_ticket_account.beneficiary,
+                    ticket_account.beneficiary
+                );
+                assert_eq!(
+                    expected_ticket_account.lamports_amount,
+                    ticket_account.lamports_amount
+                );
+                assert_eq!(
+                    expected_ticket_account.created_epoch,
+                    ticket_account.created_epoch
+                );
+            }
+            _ => panic!("Expected TicketAccountData"),
+        }
+    }
+
+    #[test]
+    fn test_decode_account_with_foreign_owner() {
+        // Arrange
+        let mut account =
+            carbon_test_utils::read_account("tests/fixtures/ticket_account_data_account.json")
+                .expect("read fixture");
+        account.owner = solana_pubkey::Pubkey::new_unique();
+
+        // Act
+        let decoded_account = MarinadeFinanceDecoder.decode_account(&account);
+
+        // Assert
+        assert!(decoded_account.is_none());
+    }
+}
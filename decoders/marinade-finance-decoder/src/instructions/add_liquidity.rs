@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -18,6 +19,7 @@ pub struct AddLiquidityInstructionAccounts {
     pub mint_to: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for AddLiquidity {
@@ -26,7 +28,7 @@ impl carbon_core::deserialize::ArrangeAccounts for AddLiquidity {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, lp_mint, lp_mint_authority, liq_pool_msol_leg, liq_pool_sol_leg_pda, transfer_from, mint_to, system_program, token_program, _remaining @ ..] =
+        let [state, lp_mint, lp_mint_authority, liq_pool_msol_leg, liq_pool_sol_leg_pda, transfer_from, mint_to, system_program, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -42,6 +44,101 @@ impl carbon_core::deserialize::ArrangeAccounts for AddLiquidity {
             mint_to: mint_to.pubkey,
             system_program: system_program.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct AddLiquidityInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub lp_mint: solana_instruction::AccountMeta,
+    pub lp_mint_authority: solana_instruction::AccountMeta,
+    pub liq_pool_msol_leg: solana_instruction::AccountMeta,
+    pub liq_pool_sol_leg_pda: solana_instruction::AccountMeta,
+    pub transfer_from: solana_instruction::AccountMeta,
+    pub mint_to: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for AddLiquidity {
+    type ArrangedAccountMetas = AddLiquidityInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, lp_mint, lp_mint_authority, liq_pool_msol_leg, liq_pool_sol_leg_pda, transfer_from, mint_to, system_program, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(AddLiquidityInstructionAccountMetas {
+            state: state.clone(),
+            lp_mint: lp_mint.clone(),
+            lp_mint_authority: lp_mint_authority.clone(),
+            liq_pool_msol_leg: liq_pool_msol_leg.clone(),
+            liq_pool_sol_leg_pda: liq_pool_sol_leg_pda.clone(),
+            transfer_from: transfer_from.clone(),
+            mint_to: mint_to.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct AddLiquidityInstructionAccountIndices {
+    pub state: usize,
+    pub lp_mint: usize,
+    pub lp_mint_authority: usize,
+    pub liq_pool_msol_leg: usize,
+    pub liq_pool_sol_leg_pda: usize,
+    pub transfer_from: usize,
+    pub mint_to: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for AddLiquidity {
+    type ArrangedAccountIndices = AddLiquidityInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 9 {
+            return None;
+        }
+
+        Some(AddLiquidityInstructionAccountIndices {
+            state: 0,
+            lp_mint: 1,
+            lp_mint_authority: 2,
+            liq_pool_msol_leg: 3,
+            liq_pool_sol_leg_pda: 4,
+            transfer_from: 5,
+            mint_to: 6,
+            system_program: 7,
+            token_program: 8,
+            remaining_accounts: 9..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for AddLiquidity {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "lp_mint",
+            "lp_mint_authority",
+            "liq_pool_msol_leg",
+            "liq_pool_sol_leg_pda",
+            "transfer_from",
+            "mint_to",
+            "system_program",
+            "token_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -18,6 +19,7 @@ pub struct AddValidatorInstructionAccounts {
     pub clock: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for AddValidator {
@@ -26,7 +28,7 @@ impl carbon_core::deserialize::ArrangeAccounts for AddValidator {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, manager_authority, validator_list, validator_vote, duplication_flag, rent_payer, clock, rent, system_program, _remaining @ ..] =
+        let [state, manager_authority, validator_list, validator_vote, duplication_flag, rent_payer, clock, rent, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -42,6 +44,101 @@ impl carbon_core::deserialize::ArrangeAccounts for AddValidator {
             clock: clock.pubkey,
             rent: rent.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct AddValidatorInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub manager_authority: solana_instruction::AccountMeta,
+    pub validator_list: solana_instruction::AccountMeta,
+    pub validator_vote: solana_instruction::AccountMeta,
+    pub duplication_flag: solana_instruction::AccountMeta,
+    pub rent_payer: solana_instruction::AccountMeta,
+    pub clock: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for AddValidator {
+    type ArrangedAccountMetas = AddValidatorInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, manager_authority, validator_list, validator_vote, duplication_flag, rent_payer, clock, rent, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(AddValidatorInstructionAccountMetas {
+            state: state.clone(),
+            manager_authority: manager_authority.clone(),
+            validator_list: validator_list.clone(),
+            validator_vote: validator_vote.clone(),
+            duplication_flag: duplication_flag.clone(),
+            rent_payer: rent_payer.clone(),
+            clock: clock.clone(),
+            rent: rent.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct AddValidatorInstructionAccountIndices {
+    pub state: usize,
+    pub manager_authority: usize,
+    pub validator_list: usize,
+    pub validator_vote: usize,
+    pub duplication_flag: usize,
+    pub rent_payer: usize,
+    pub clock: usize,
+    pub rent: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for AddValidator {
+    type ArrangedAccountIndices = AddValidatorInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 9 {
+            return None;
+        }
+
+        Some(AddValidatorInstructionAccountIndices {
+            state: 0,
+            manager_authority: 1,
+            validator_list: 2,
+            validator_vote: 3,
+            duplication_flag: 4,
+            rent_payer: 5,
+            clock: 6,
+            rent: 7,
+            system_program: 8,
+            remaining_accounts: 9..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for AddValidator {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "manager_authority",
+            "validator_list",
+            "validator_vote",
+            "duplication_flag",
+            "rent_payer",
+            "clock",
+            "rent",
+            "system_program",
+        ]
+    }
+}
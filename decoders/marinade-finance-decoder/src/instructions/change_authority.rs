@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct ChangeAuthority {
 pub struct ChangeAuthorityInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub admin_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ChangeAuthority {
@@ -22,13 +24,68 @@ impl carbon_core::deserialize::ArrangeAccounts for ChangeAuthority {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, admin_authority, _remaining @ ..] = accounts else {
+        let [state, admin_authority, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(ChangeAuthorityInstructionAccounts {
             state: state.pubkey,
             admin_authority: admin_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ChangeAuthorityInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub admin_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ChangeAuthority {
+    type ArrangedAccountMetas = ChangeAuthorityInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, admin_authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(ChangeAuthorityInstructionAccountMetas {
+            state: state.clone(),
+            admin_authority: admin_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ChangeAuthorityInstructionAccountIndices {
+    pub state: usize,
+    pub admin_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ChangeAuthority {
+    type ArrangedAccountIndices = ChangeAuthorityInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(ChangeAuthorityInstructionAccountIndices {
+            state: 0,
+            admin_authority: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ChangeAuthority {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "admin_authority"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +14,7 @@ pub struct ClaimInstructionAccounts {
     pub transfer_sol_to: solana_pubkey::Pubkey,
     pub clock: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Claim {
@@ -21,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Claim {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, reserve_pda, ticket_account, transfer_sol_to, clock, system_program, _remaining @ ..] =
+        let [state, reserve_pda, ticket_account, transfer_sol_to, clock, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -34,6 +36,86 @@ impl carbon_core::deserialize::ArrangeAccounts for Claim {
             transfer_sol_to: transfer_sol_to.pubkey,
             clock: clock.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ClaimInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub reserve_pda: solana_instruction::AccountMeta,
+    pub ticket_account: solana_instruction::AccountMeta,
+    pub transfer_sol_to: solana_instruction::AccountMeta,
+    pub clock: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Claim {
+    type ArrangedAccountMetas = ClaimInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, reserve_pda, ticket_account, transfer_sol_to, clock, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(ClaimInstructionAccountMetas {
+            state: state.clone(),
+            reserve_pda: reserve_pda.clone(),
+            ticket_account: ticket_account.clone(),
+            transfer_sol_to: transfer_sol_to.clone(),
+            clock: clock.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ClaimInstructionAccountIndices {
+    pub state: usize,
+    pub reserve_pda: usize,
+    pub ticket_account: usize,
+    pub transfer_sol_to: usize,
+    pub clock: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Claim {
+    type ArrangedAccountIndices = ClaimInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(ClaimInstructionAccountIndices {
+            state: 0,
+            reserve_pda: 1,
+            ticket_account: 2,
+            transfer_sol_to: 3,
+            clock: 4,
+            system_program: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Claim {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "reserve_pda",
+            "ticket_account",
+            "transfer_sol_to",
+            "clock",
+            "system_program",
+        ]
+    }
+}
@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct ConfigMarinade {
 pub struct ConfigMarinadeInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub admin_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ConfigMarinade {
@@ -22,13 +24,68 @@ impl carbon_core::deserialize::ArrangeAccounts for ConfigMarinade {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, admin_authority, _remaining @ ..] = accounts else {
+        let [state, admin_authority, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(ConfigMarinadeInstructionAccounts {
             state: state.pubkey,
             admin_authority: admin_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ConfigMarinadeInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub admin_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ConfigMarinade {
+    type ArrangedAccountMetas = ConfigMarinadeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, admin_authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(ConfigMarinadeInstructionAccountMetas {
+            state: state.clone(),
+            admin_authority: admin_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ConfigMarinadeInstructionAccountIndices {
+    pub state: usize,
+    pub admin_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ConfigMarinade {
+    type ArrangedAccountIndices = ConfigMarinadeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(ConfigMarinadeInstructionAccountIndices {
+            state: 0,
+            admin_authority: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ConfigMarinade {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "admin_authority"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +12,7 @@ pub struct ConfigValidatorSystem {
 pub struct ConfigValidatorSystemInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub manager_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ConfigValidatorSystem {
@@ -19,13 +21,68 @@ impl carbon_core::deserialize::ArrangeAccounts for ConfigValidatorSystem {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, manager_authority, _remaining @ ..] = accounts else {
+        let [state, manager_authority, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(ConfigValidatorSystemInstructionAccounts {
             state: state.pubkey,
             manager_authority: manager_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ConfigValidatorSystemInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub manager_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ConfigValidatorSystem {
+    type ArrangedAccountMetas = ConfigValidatorSystemInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, manager_authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(ConfigValidatorSystemInstructionAccountMetas {
+            state: state.clone(),
+            manager_authority: manager_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ConfigValidatorSystemInstructionAccountIndices {
+    pub state: usize,
+    pub manager_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ConfigValidatorSystem {
+    type ArrangedAccountIndices = ConfigValidatorSystemInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(ConfigValidatorSystemInstructionAccountIndices {
+            state: 0,
+            manager_authority: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ConfigValidatorSystem {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "manager_authority"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -24,6 +25,7 @@ pub struct DeactivateStakeInstructionAccounts {
     pub stake_history: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub stake_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for DeactivateStake {
@@ -32,7 +34,7 @@ impl carbon_core::deserialize::ArrangeAccounts for DeactivateStake {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, reserve_pda, validator_list, stake_list, stake_account, stake_deposit_authority, split_stake_account, split_stake_rent_payer, clock, rent, epoch_schedule, stake_history, system_program, stake_program, _remaining @ ..] =
+        let [state, reserve_pda, validator_list, stake_list, stake_account, stake_deposit_authority, split_stake_account, split_stake_rent_payer, clock, rent, epoch_schedule, stake_history, system_program, stake_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -53,6 +55,126 @@ impl carbon_core::deserialize::ArrangeAccounts for DeactivateStake {
             stake_history: stake_history.pubkey,
             system_program: system_program.pubkey,
             stake_program: stake_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DeactivateStakeInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub reserve_pda: solana_instruction::AccountMeta,
+    pub validator_list: solana_instruction::AccountMeta,
+    pub stake_list: solana_instruction::AccountMeta,
+    pub stake_account: solana_instruction::AccountMeta,
+    pub stake_deposit_authority: solana_instruction::AccountMeta,
+    pub split_stake_account: solana_instruction::AccountMeta,
+    pub split_stake_rent_payer: solana_instruction::AccountMeta,
+    pub clock: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub epoch_schedule: solana_instruction::AccountMeta,
+    pub stake_history: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub stake_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for DeactivateStake {
+    type ArrangedAccountMetas = DeactivateStakeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, reserve_pda, validator_list, stake_list, stake_account, stake_deposit_authority, split_stake_account, split_stake_rent_payer, clock, rent, epoch_schedule, stake_history, system_program, stake_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(DeactivateStakeInstructionAccountMetas {
+            state: state.clone(),
+            reserve_pda: reserve_pda.clone(),
+            validator_list: validator_list.clone(),
+            stake_list: stake_list.clone(),
+            stake_account: stake_account.clone(),
+            stake_deposit_authority: stake_deposit_authority.clone(),
+            split_stake_account: split_stake_account.clone(),
+            split_stake_rent_payer: split_stake_rent_payer.clone(),
+            clock: clock.clone(),
+            rent: rent.clone(),
+            epoch_schedule: epoch_schedule.clone(),
+            stake_history: stake_history.clone(),
+            system_program: system_program.clone(),
+            stake_program: stake_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DeactivateStakeInstructionAccountIndices {
+    pub state: usize,
+    pub reserve_pda: usize,
+    pub validator_list: usize,
+    pub stake_list: usize,
+    pub stake_account: usize,
+    pub stake_deposit_authority: usize,
+    pub split_stake_account: usize,
+    pub split_stake_rent_payer: usize,
+    pub clock: usize,
+    pub rent: usize,
+    pub epoch_schedule: usize,
+    pub stake_history: usize,
+    pub system_program: usize,
+    pub stake_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for DeactivateStake {
+    type ArrangedAccountIndices = DeactivateStakeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 14 {
+            return None;
+        }
+
+        Some(DeactivateStakeInstructionAccountIndices {
+            state: 0,
+            reserve_pda: 1,
+            validator_list: 2,
+            stake_list: 3,
+            stake_account: 4,
+            stake_deposit_authority: 5,
+            split_stake_account: 6,
+            split_stake_rent_payer: 7,
+            clock: 8,
+            rent: 9,
+            epoch_schedule: 10,
+            stake_history: 11,
+            system_program: 12,
+            stake_program: 13,
+            remaining_accounts: 14..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for DeactivateStake {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "reserve_pda",
+            "validator_list",
+            "stake_list",
+            "stake_account",
+            "stake_deposit_authority",
+            "split_stake_account",
+            "split_stake_rent_payer",
+            "clock",
+            "rent",
+            "epoch_schedule",
+            "stake_history",
+            "system_program",
+            "stake_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -20,6 +21,7 @@ pub struct DepositInstructionAccounts {
     pub msol_mint_authority: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Deposit {
@@ -28,7 +30,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Deposit {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, msol_mint, liq_pool_sol_leg_pda, liq_pool_msol_leg, liq_pool_msol_leg_authority, reserve_pda, transfer_from, mint_to, msol_mint_authority, system_program, token_program, _remaining @ ..] =
+        let [state, msol_mint, liq_pool_sol_leg_pda, liq_pool_msol_leg, liq_pool_msol_leg_authority, reserve_pda, transfer_from, mint_to, msol_mint_authority, system_program, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -46,6 +48,111 @@ impl carbon_core::deserialize::ArrangeAccounts for Deposit {
             msol_mint_authority: msol_mint_authority.pubkey,
             system_program: system_program.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DepositInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub msol_mint: solana_instruction::AccountMeta,
+    pub liq_pool_sol_leg_pda: solana_instruction::AccountMeta,
+    pub liq_pool_msol_leg: solana_instruction::AccountMeta,
+    pub liq_pool_msol_leg_authority: solana_instruction::AccountMeta,
+    pub reserve_pda: solana_instruction::AccountMeta,
+    pub transfer_from: solana_instruction::AccountMeta,
+    pub mint_to: solana_instruction::AccountMeta,
+    pub msol_mint_authority: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Deposit {
+    type ArrangedAccountMetas = DepositInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, msol_mint, liq_pool_sol_leg_pda, liq_pool_msol_leg, liq_pool_msol_leg_authority, reserve_pda, transfer_from, mint_to, msol_mint_authority, system_program, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(DepositInstructionAccountMetas {
+            state: state.clone(),
+            msol_mint: msol_mint.clone(),
+            liq_pool_sol_leg_pda: liq_pool_sol_leg_pda.clone(),
+            liq_pool_msol_leg: liq_pool_msol_leg.clone(),
+            liq_pool_msol_leg_authority: liq_pool_msol_leg_authority.clone(),
+            reserve_pda: reserve_pda.clone(),
+            transfer_from: transfer_from.clone(),
+            mint_to: mint_to.clone(),
+            msol_mint_authority: msol_mint_authority.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DepositInstructionAccountIndices {
+    pub state: usize,
+    pub msol_mint: usize,
+    pub liq_pool_sol_leg_pda: usize,
+    pub liq_pool_msol_leg: usize,
+    pub liq_pool_msol_leg_authority: usize,
+    pub reserve_pda: usize,
+    pub transfer_from: usize,
+    pub mint_to: usize,
+    pub msol_mint_authority: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Deposit {
+    type ArrangedAccountIndices = DepositInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 11 {
+            return None;
+        }
+
+        Some(DepositInstructionAccountIndices {
+            state: 0,
+            msol_mint: 1,
+            liq_pool_sol_leg_pda: 2,
+            liq_pool_msol_leg: 3,
+            liq_pool_msol_leg_authority: 4,
+            reserve_pda: 5,
+            transfer_from: 6,
+            mint_to: 7,
+            msol_mint_authority: 8,
+            system_program: 9,
+            token_program: 10,
+            remaining_accounts: 11..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Deposit {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "msol_mint",
+            "liq_pool_sol_leg_pda",
+            "liq_pool_msol_leg",
+            "liq_pool_msol_leg_authority",
+            "reserve_pda",
+            "transfer_from",
+            "mint_to",
+            "msol_mint_authority",
+            "system_program",
+            "token_program",
+        ]
+    }
+}
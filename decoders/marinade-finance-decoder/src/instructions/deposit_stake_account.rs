@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -24,6 +25,7 @@ pub struct DepositStakeAccountInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub stake_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for DepositStakeAccount {
@@ -32,7 +34,7 @@ impl carbon_core::deserialize::ArrangeAccounts for DepositStakeAccount {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, validator_list, stake_list, stake_account, stake_authority, duplication_flag, rent_payer, msol_mint, mint_to, msol_mint_authority, clock, rent, system_program, token_program, stake_program, _remaining @ ..] =
+        let [state, validator_list, stake_list, stake_account, stake_authority, duplication_flag, rent_payer, msol_mint, mint_to, msol_mint_authority, clock, rent, system_program, token_program, stake_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -54,6 +56,131 @@ impl carbon_core::deserialize::ArrangeAccounts for DepositStakeAccount {
             system_program: system_program.pubkey,
             token_program: token_program.pubkey,
             stake_program: stake_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DepositStakeAccountInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub validator_list: solana_instruction::AccountMeta,
+    pub stake_list: solana_instruction::AccountMeta,
+    pub stake_account: solana_instruction::AccountMeta,
+    pub stake_authority: solana_instruction::AccountMeta,
+    pub duplication_flag: solana_instruction::AccountMeta,
+    pub rent_payer: solana_instruction::AccountMeta,
+    pub msol_mint: solana_instruction::AccountMeta,
+    pub mint_to: solana_instruction::AccountMeta,
+    pub msol_mint_authority: solana_instruction::AccountMeta,
+    pub clock: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub stake_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for DepositStakeAccount {
+    type ArrangedAccountMetas = DepositStakeAccountInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, validator_list, stake_list, stake_account, stake_authority, duplication_flag, rent_payer, msol_mint, mint_to, msol_mint_authority, clock, rent, system_program, token_program, stake_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(DepositStakeAccountInstructionAccountMetas {
+            state: state.clone(),
+            validator_list: validator_list.clone(),
+            stake_list: stake_list.clone(),
+            stake_account: stake_account.clone(),
+            stake_authority: stake_authority.clone(),
+            duplication_flag: duplication_flag.clone(),
+            rent_payer: rent_payer.clone(),
+            msol_mint: msol_mint.clone(),
+            mint_to: mint_to.clone(),
+            msol_mint_authority: msol_mint_authority.clone(),
+            clock: clock.clone(),
+            rent: rent.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            stake_program: stake_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DepositStakeAccountInstructionAccountIndices {
+    pub state: usize,
+    pub validator_list: usize,
+    pub stake_list: usize,
+    pub stake_account: usize,
+    pub stake_authority: usize,
+    pub duplication_flag: usize,
+    pub rent_payer: usize,
+    pub msol_mint: usize,
+    pub mint_to: usize,
+    pub msol_mint_authority: usize,
+    pub clock: usize,
+    pub rent: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub stake_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for DepositStakeAccount {
+    type ArrangedAccountIndices = DepositStakeAccountInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 15 {
+            return None;
+        }
+
+        Some(DepositStakeAccountInstructionAccountIndices {
+            state: 0,
+            validator_list: 1,
+            stake_list: 2,
+            stake_account: 3,
+            stake_authority: 4,
+            duplication_flag: 5,
+            rent_payer: 6,
+            msol_mint: 7,
+            mint_to: 8,
+            msol_mint_authority: 9,
+            clock: 10,
+            rent: 11,
+            system_program: 12,
+            token_program: 13,
+            stake_program: 14,
+            remaining_accounts: 15..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for DepositStakeAccount {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "validator_list",
+            "stake_list",
+            "stake_account",
+            "stake_authority",
+            "duplication_flag",
+            "rent_payer",
+            "msol_mint",
+            "mint_to",
+            "msol_mint_authority",
+            "clock",
+            "rent",
+            "system_program",
+            "token_program",
+            "stake_program",
+        ]
+    }
+}
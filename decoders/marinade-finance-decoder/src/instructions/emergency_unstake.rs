@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -18,6 +19,7 @@ pub struct EmergencyUnstakeInstructionAccounts {
     pub stake_deposit_authority: solana_pubkey::Pubkey,
     pub clock: solana_pubkey::Pubkey,
     pub stake_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for EmergencyUnstake {
@@ -26,7 +28,7 @@ impl carbon_core::deserialize::ArrangeAccounts for EmergencyUnstake {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, validator_manager_authority, validator_list, stake_list, stake_account, stake_deposit_authority, clock, stake_program, _remaining @ ..] =
+        let [state, validator_manager_authority, validator_list, stake_list, stake_account, stake_deposit_authority, clock, stake_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -41,6 +43,96 @@ impl carbon_core::deserialize::ArrangeAccounts for EmergencyUnstake {
             stake_deposit_authority: stake_deposit_authority.pubkey,
             clock: clock.pubkey,
             stake_program: stake_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct EmergencyUnstakeInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub validator_manager_authority: solana_instruction::AccountMeta,
+    pub validator_list: solana_instruction::AccountMeta,
+    pub stake_list: solana_instruction::AccountMeta,
+    pub stake_account: solana_instruction::AccountMeta,
+    pub stake_deposit_authority: solana_instruction::AccountMeta,
+    pub clock: solana_instruction::AccountMeta,
+    pub stake_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for EmergencyUnstake {
+    type ArrangedAccountMetas = EmergencyUnstakeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, validator_manager_authority, validator_list, stake_list, stake_account, stake_deposit_authority, clock, stake_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(EmergencyUnstakeInstructionAccountMetas {
+            state: state.clone(),
+            validator_manager_authority: validator_manager_authority.clone(),
+            validator_list: validator_list.clone(),
+            stake_list: stake_list.clone(),
+            stake_account: stake_account.clone(),
+            stake_deposit_authority: stake_deposit_authority.clone(),
+            clock: clock.clone(),
+            stake_program: stake_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct EmergencyUnstakeInstructionAccountIndices {
+    pub state: usize,
+    pub validator_manager_authority: usize,
+    pub validator_list: usize,
+    pub stake_list: usize,
+    pub stake_account: usize,
+    pub stake_deposit_authority: usize,
+    pub clock: usize,
+    pub stake_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for EmergencyUnstake {
+    type ArrangedAccountIndices = EmergencyUnstakeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(EmergencyUnstakeInstructionAccountIndices {
+            state: 0,
+            validator_manager_authority: 1,
+            validator_list: 2,
+            stake_list: 3,
+            stake_account: 4,
+            stake_deposit_authority: 5,
+            clock: 6,
+            stake_program: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for EmergencyUnstake {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "validator_manager_authority",
+            "validator_list",
+            "stake_list",
+            "stake_account",
+            "stake_deposit_authority",
+            "clock",
+            "stake_program",
+        ]
+    }
+}
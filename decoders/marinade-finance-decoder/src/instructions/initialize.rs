@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -22,6 +23,7 @@ pub struct InitializeInstructionAccounts {
     pub treasury_msol_account: solana_pubkey::Pubkey,
     pub clock: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Initialize {
@@ -30,7 +32,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Initialize {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, reserve_pda, stake_list, validator_list, msol_mint, operational_sol_account, liq_pool, treasury_msol_account, clock, rent, _remaining @ ..] =
+        let [state, reserve_pda, stake_list, validator_list, msol_mint, operational_sol_account, liq_pool, treasury_msol_account, clock, rent, remaining @ ..] =
             accounts
         else {
             return None;
@@ -47,6 +49,106 @@ impl carbon_core::deserialize::ArrangeAccounts for Initialize {
             treasury_msol_account: treasury_msol_account.pubkey,
             clock: clock.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub reserve_pda: solana_instruction::AccountMeta,
+    pub stake_list: solana_instruction::AccountMeta,
+    pub validator_list: solana_instruction::AccountMeta,
+    pub msol_mint: solana_instruction::AccountMeta,
+    pub operational_sol_account: solana_instruction::AccountMeta,
+    pub liq_pool: solana_instruction::AccountMeta,
+    pub treasury_msol_account: solana_instruction::AccountMeta,
+    pub clock: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Initialize {
+    type ArrangedAccountMetas = InitializeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, reserve_pda, stake_list, validator_list, msol_mint, operational_sol_account, liq_pool, treasury_msol_account, clock, rent, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeInstructionAccountMetas {
+            state: state.clone(),
+            reserve_pda: reserve_pda.clone(),
+            stake_list: stake_list.clone(),
+            validator_list: validator_list.clone(),
+            msol_mint: msol_mint.clone(),
+            operational_sol_account: operational_sol_account.clone(),
+            liq_pool: liq_pool.clone(),
+            treasury_msol_account: treasury_msol_account.clone(),
+            clock: clock.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeInstructionAccountIndices {
+    pub state: usize,
+    pub reserve_pda: usize,
+    pub stake_list: usize,
+    pub validator_list: usize,
+    pub msol_mint: usize,
+    pub operational_sol_account: usize,
+    pub liq_pool: usize,
+    pub treasury_msol_account: usize,
+    pub clock: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Initialize {
+    type ArrangedAccountIndices = InitializeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 10 {
+            return None;
+        }
+
+        Some(InitializeInstructionAccountIndices {
+            state: 0,
+            reserve_pda: 1,
+            stake_list: 2,
+            validator_list: 3,
+            msol_mint: 4,
+            operational_sol_account: 5,
+            liq_pool: 6,
+            treasury_msol_account: 7,
+            clock: 8,
+            rent: 9,
+            remaining_accounts: 10..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Initialize {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "reserve_pda",
+            "stake_list",
+            "validator_list",
+            "msol_mint",
+            "operational_sol_account",
+            "liq_pool",
+            "treasury_msol_account",
+            "clock",
+            "rent",
+        ]
+    }
+}
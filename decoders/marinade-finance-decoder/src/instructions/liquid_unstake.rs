@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -19,6 +20,7 @@ pub struct LiquidUnstakeInstructionAccounts {
     pub transfer_sol_to: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for LiquidUnstake {
@@ -27,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for LiquidUnstake {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, msol_mint, liq_pool_sol_leg_pda, liq_pool_msol_leg, treasury_msol_account, get_msol_from, get_msol_from_authority, transfer_sol_to, system_program, token_program, _remaining @ ..] =
+        let [state, msol_mint, liq_pool_sol_leg_pda, liq_pool_msol_leg, treasury_msol_account, get_msol_from, get_msol_from_authority, transfer_sol_to, system_program, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -44,6 +46,106 @@ impl carbon_core::deserialize::ArrangeAccounts for LiquidUnstake {
             transfer_sol_to: transfer_sol_to.pubkey,
             system_program: system_program.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct LiquidUnstakeInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub msol_mint: solana_instruction::AccountMeta,
+    pub liq_pool_sol_leg_pda: solana_instruction::AccountMeta,
+    pub liq_pool_msol_leg: solana_instruction::AccountMeta,
+    pub treasury_msol_account: solana_instruction::AccountMeta,
+    pub get_msol_from: solana_instruction::AccountMeta,
+    pub get_msol_from_authority: solana_instruction::AccountMeta,
+    pub transfer_sol_to: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for LiquidUnstake {
+    type ArrangedAccountMetas = LiquidUnstakeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, msol_mint, liq_pool_sol_leg_pda, liq_pool_msol_leg, treasury_msol_account, get_msol_from, get_msol_from_authority, transfer_sol_to, system_program, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(LiquidUnstakeInstructionAccountMetas {
+            state: state.clone(),
+            msol_mint: msol_mint.clone(),
+            liq_pool_sol_leg_pda: liq_pool_sol_leg_pda.clone(),
+            liq_pool_msol_leg: liq_pool_msol_leg.clone(),
+            treasury_msol_account: treasury_msol_account.clone(),
+            get_msol_from: get_msol_from.clone(),
+            get_msol_from_authority: get_msol_from_authority.clone(),
+            transfer_sol_to: transfer_sol_to.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct LiquidUnstakeInstructionAccountIndices {
+    pub state: usize,
+    pub msol_mint: usize,
+    pub liq_pool_sol_leg_pda: usize,
+    pub liq_pool_msol_leg: usize,
+    pub treasury_msol_account: usize,
+    pub get_msol_from: usize,
+    pub get_msol_from_authority: usize,
+    pub transfer_sol_to: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for LiquidUnstake {
+    type ArrangedAccountIndices = LiquidUnstakeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 10 {
+            return None;
+        }
+
+        Some(LiquidUnstakeInstructionAccountIndices {
+            state: 0,
+            msol_mint: 1,
+            liq_pool_sol_leg_pda: 2,
+            liq_pool_msol_leg: 3,
+            treasury_msol_account: 4,
+            get_msol_from: 5,
+            get_msol_from_authority: 6,
+            transfer_sol_to: 7,
+            system_program: 8,
+            token_program: 9,
+            remaining_accounts: 10..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for LiquidUnstake {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "msol_mint",
+            "liq_pool_sol_leg_pda",
+            "liq_pool_msol_leg",
+            "treasury_msol_account",
+            "get_msol_from",
+            "get_msol_from_authority",
+            "transfer_sol_to",
+            "system_program",
+            "token_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -22,6 +23,7 @@ pub struct MergeStakesInstructionAccounts {
     pub clock: solana_pubkey::Pubkey,
     pub stake_history: solana_pubkey::Pubkey,
     pub stake_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for MergeStakes {
@@ -30,7 +32,7 @@ impl carbon_core::deserialize::ArrangeAccounts for MergeStakes {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, stake_list, validator_list, destination_stake, source_stake, stake_deposit_authority, stake_withdraw_authority, operational_sol_account, clock, stake_history, stake_program, _remaining @ ..] =
+        let [state, stake_list, validator_list, destination_stake, source_stake, stake_deposit_authority, stake_withdraw_authority, operational_sol_account, clock, stake_history, stake_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -48,6 +50,111 @@ impl carbon_core::deserialize::ArrangeAccounts for MergeStakes {
             clock: clock.pubkey,
             stake_history: stake_history.pubkey,
             stake_program: stake_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct MergeStakesInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub stake_list: solana_instruction::AccountMeta,
+    pub validator_list: solana_instruction::AccountMeta,
+    pub destination_stake: solana_instruction::AccountMeta,
+    pub source_stake: solana_instruction::AccountMeta,
+    pub stake_deposit_authority: solana_instruction::AccountMeta,
+    pub stake_withdraw_authority: solana_instruction::AccountMeta,
+    pub operational_sol_account: solana_instruction::AccountMeta,
+    pub clock: solana_instruction::AccountMeta,
+    pub stake_history: solana_instruction::AccountMeta,
+    pub stake_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for MergeStakes {
+    type ArrangedAccountMetas = MergeStakesInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, stake_list, validator_list, destination_stake, source_stake, stake_deposit_authority, stake_withdraw_authority, operational_sol_account, clock, stake_history, stake_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(MergeStakesInstructionAccountMetas {
+            state: state.clone(),
+            stake_list: stake_list.clone(),
+            validator_list: validator_list.clone(),
+            destination_stake: destination_stake.clone(),
+            source_stake: source_stake.clone(),
+            stake_deposit_authority: stake_deposit_authority.clone(),
+            stake_withdraw_authority: stake_withdraw_authority.clone(),
+            operational_sol_account: operational_sol_account.clone(),
+            clock: clock.clone(),
+            stake_history: stake_history.clone(),
+            stake_program: stake_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct MergeStakesInstructionAccountIndices {
+    pub state: usize,
+    pub stake_list: usize,
+    pub validator_list: usize,
+    pub destination_stake: usize,
+    pub source_stake: usize,
+    pub stake_deposit_authority: usize,
+    pub stake_withdraw_authority: usize,
+    pub operational_sol_account: usize,
+    pub clock: usize,
+    pub stake_history: usize,
+    pub stake_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for MergeStakes {
+    type ArrangedAccountIndices = MergeStakesInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 11 {
+            return None;
+        }
+
+        Some(MergeStakesInstructionAccountIndices {
+            state: 0,
+            stake_list: 1,
+            validator_list: 2,
+            destination_stake: 3,
+            source_stake: 4,
+            stake_deposit_authority: 5,
+            stake_withdraw_authority: 6,
+            operational_sol_account: 7,
+            clock: 8,
+            stake_history: 9,
+            stake_program: 10,
+            remaining_accounts: 11..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for MergeStakes {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "stake_list",
+            "validator_list",
+            "destination_stake",
+            "source_stake",
+            "stake_deposit_authority",
+            "stake_withdraw_authority",
+            "operational_sol_account",
+            "clock",
+            "stake_history",
+            "stake_program",
+        ]
+    }
+}
@@ -122,14 +122,24 @@ pub enum MarinadeFinanceInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for MarinadeFinanceDecoder {
     type InstructionType = MarinadeFinanceInstruction;
 
-    fn decode_instruction(
+    fn decoder_name(&self) -> &'static str {
+        "marinade_finance"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[super::PROGRAM_ID]
+    }
+
+    fn try_decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
-    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+    ) -> carbon_core::error::CarbonResult<
+        Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>>,
+    > {
         if !instruction.program_id.eq(&PROGRAM_ID) {
-            return None;
+            return Ok(None);
         }
-        carbon_core::try_decode_instructions!(instruction,
+        Ok(carbon_core::try_decode_instructions!(instruction,
             MarinadeFinanceInstruction::Initialize => initialize::Initialize,
             MarinadeFinanceInstruction::ChangeAuthority => change_authority::ChangeAuthority,
             MarinadeFinanceInstruction::AddValidator => add_validator::AddValidator,
@@ -183,6 +193,6 @@ impl carbon_core::instruction::InstructionDecoder<'_> for MarinadeFinanceDecoder
             MarinadeFinanceInstruction::DepositStakeAccountEvent => deposit_stake_account_event::DepositStakeAccountEvent,
             MarinadeFinanceInstruction::DepositEvent => deposit_event::DepositEvent,
             MarinadeFinanceInstruction::WithdrawStakeAccountEvent => withdraw_stake_account_event::WithdrawStakeAccountEvent,
-        )
+        ))
     }
 }
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -17,6 +18,7 @@ pub struct OrderUnstakeInstructionAccounts {
     pub clock: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for OrderUnstake {
@@ -25,7 +27,7 @@ impl carbon_core::deserialize::ArrangeAccounts for OrderUnstake {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, msol_mint, burn_msol_from, burn_msol_authority, new_ticket_account, clock, rent, token_program, _remaining @ ..] =
+        let [state, msol_mint, burn_msol_from, burn_msol_authority, new_ticket_account, clock, rent, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -40,6 +42,96 @@ impl carbon_core::deserialize::ArrangeAccounts for OrderUnstake {
             clock: clock.pubkey,
             rent: rent.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct OrderUnstakeInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub msol_mint: solana_instruction::AccountMeta,
+    pub burn_msol_from: solana_instruction::AccountMeta,
+    pub burn_msol_authority: solana_instruction::AccountMeta,
+    pub new_ticket_account: solana_instruction::AccountMeta,
+    pub clock: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for OrderUnstake {
+    type ArrangedAccountMetas = OrderUnstakeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, msol_mint, burn_msol_from, burn_msol_authority, new_ticket_account, clock, rent, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(OrderUnstakeInstructionAccountMetas {
+            state: state.clone(),
+            msol_mint: msol_mint.clone(),
+            burn_msol_from: burn_msol_from.clone(),
+            burn_msol_authority: burn_msol_authority.clone(),
+            new_ticket_account: new_ticket_account.clone(),
+            clock: clock.clone(),
+            rent: rent.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct OrderUnstakeInstructionAccountIndices {
+    pub state: usize,
+    pub msol_mint: usize,
+    pub burn_msol_from: usize,
+    pub burn_msol_authority: usize,
+    pub new_ticket_account: usize,
+    pub clock: usize,
+    pub rent: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for OrderUnstake {
+    type ArrangedAccountIndices = OrderUnstakeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(OrderUnstakeInstructionAccountIndices {
+            state: 0,
+            msol_mint: 1,
+            burn_msol_from: 2,
+            burn_msol_authority: 3,
+            new_ticket_account: 4,
+            clock: 5,
+            rent: 6,
+            token_program: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for OrderUnstake {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "msol_mint",
+            "burn_msol_from",
+            "burn_msol_authority",
+            "new_ticket_account",
+            "clock",
+            "rent",
+            "token_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -25,6 +26,7 @@ pub struct PartialUnstakeInstructionAccounts {
     pub stake_history: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub stake_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for PartialUnstake {
@@ -33,7 +35,7 @@ impl carbon_core::deserialize::ArrangeAccounts for PartialUnstake {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, validator_manager_authority, validator_list, stake_list, stake_account, stake_deposit_authority, reserve_pda, split_stake_account, split_stake_rent_payer, clock, rent, stake_history, system_program, stake_program, _remaining @ ..] =
+        let [state, validator_manager_authority, validator_list, stake_list, stake_account, stake_deposit_authority, reserve_pda, split_stake_account, split_stake_rent_payer, clock, rent, stake_history, system_program, stake_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -54,6 +56,126 @@ impl carbon_core::deserialize::ArrangeAccounts for PartialUnstake {
             stake_history: stake_history.pubkey,
             system_program: system_program.pubkey,
             stake_program: stake_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct PartialUnstakeInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub validator_manager_authority: solana_instruction::AccountMeta,
+    pub validator_list: solana_instruction::AccountMeta,
+    pub stake_list: solana_instruction::AccountMeta,
+    pub stake_account: solana_instruction::AccountMeta,
+    pub stake_deposit_authority: solana_instruction::AccountMeta,
+    pub reserve_pda: solana_instruction::AccountMeta,
+    pub split_stake_account: solana_instruction::AccountMeta,
+    pub split_stake_rent_payer: solana_instruction::AccountMeta,
+    pub clock: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub stake_history: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub stake_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for PartialUnstake {
+    type ArrangedAccountMetas = PartialUnstakeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, validator_manager_authority, validator_list, stake_list, stake_account, stake_deposit_authority, reserve_pda, split_stake_account, split_stake_rent_payer, clock, rent, stake_history, system_program, stake_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(PartialUnstakeInstructionAccountMetas {
+            state: state.clone(),
+            validator_manager_authority: validator_manager_authority.clone(),
+            validator_list: validator_list.clone(),
+            stake_list: stake_list.clone(),
+            stake_account: stake_account.clone(),
+            stake_deposit_authority: stake_deposit_authority.clone(),
+            reserve_pda: reserve_pda.clone(),
+            split_stake_account: split_stake_account.clone(),
+            split_stake_rent_payer: split_stake_rent_payer.clone(),
+            clock: clock.clone(),
+            rent: rent.clone(),
+            stake_history: stake_history.clone(),
+            system_program: system_program.clone(),
+            stake_program: stake_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct PartialUnstakeInstructionAccountIndices {
+    pub state: usize,
+    pub validator_manager_authority: usize,
+    pub validator_list: usize,
+    pub stake_list: usize,
+    pub stake_account: usize,
+    pub stake_deposit_authority: usize,
+    pub reserve_pda: usize,
+    pub split_stake_account: usize,
+    pub split_stake_rent_payer: usize,
+    pub clock: usize,
+    pub rent: usize,
+    pub stake_history: usize,
+    pub system_program: usize,
+    pub stake_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for PartialUnstake {
+    type ArrangedAccountIndices = PartialUnstakeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 14 {
+            return None;
+        }
+
+        Some(PartialUnstakeInstructionAccountIndices {
+            state: 0,
+            validator_manager_authority: 1,
+            validator_list: 2,
+            stake_list: 3,
+            stake_account: 4,
+            stake_deposit_authority: 5,
+            reserve_pda: 6,
+            split_stake_account: 7,
+            split_stake_rent_payer: 8,
+            clock: 9,
+            rent: 10,
+            stake_history: 11,
+            system_program: 12,
+            stake_program: 13,
+            remaining_accounts: 14..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for PartialUnstake {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "validator_manager_authority",
+            "validator_list",
+            "stake_list",
+            "stake_account",
+            "stake_deposit_authority",
+            "reserve_pda",
+            "split_stake_account",
+            "split_stake_rent_payer",
+            "clock",
+            "rent",
+            "stake_history",
+            "system_program",
+            "stake_program",
+        ]
+    }
+}
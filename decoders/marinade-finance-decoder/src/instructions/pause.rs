@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -9,6 +10,7 @@ pub struct Pause {}
 pub struct PauseInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub pause_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Pause {
@@ -17,13 +19,68 @@ impl carbon_core::deserialize::ArrangeAccounts for Pause {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, pause_authority, _remaining @ ..] = accounts else {
+        let [state, pause_authority, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(PauseInstructionAccounts {
             state: state.pubkey,
             pause_authority: pause_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct PauseInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub pause_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Pause {
+    type ArrangedAccountMetas = PauseInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, pause_authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(PauseInstructionAccountMetas {
+            state: state.clone(),
+            pause_authority: pause_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct PauseInstructionAccountIndices {
+    pub state: usize,
+    pub pause_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Pause {
+    type ArrangedAccountIndices = PauseInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(PauseInstructionAccountIndices {
+            state: 0,
+            pause_authority: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Pause {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "pause_authority"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct ReallocStakeListInstructionAccounts {
     pub stake_list: solana_pubkey::Pubkey,
     pub rent_funds: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ReallocStakeList {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ReallocStakeList {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, admin_authority, stake_list, rent_funds, system_program, _remaining @ ..] =
+        let [state, admin_authority, stake_list, rent_funds, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -34,6 +36,81 @@ impl carbon_core::deserialize::ArrangeAccounts for ReallocStakeList {
             stake_list: stake_list.pubkey,
             rent_funds: rent_funds.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ReallocStakeListInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub admin_authority: solana_instruction::AccountMeta,
+    pub stake_list: solana_instruction::AccountMeta,
+    pub rent_funds: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ReallocStakeList {
+    type ArrangedAccountMetas = ReallocStakeListInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, admin_authority, stake_list, rent_funds, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(ReallocStakeListInstructionAccountMetas {
+            state: state.clone(),
+            admin_authority: admin_authority.clone(),
+            stake_list: stake_list.clone(),
+            rent_funds: rent_funds.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ReallocStakeListInstructionAccountIndices {
+    pub state: usize,
+    pub admin_authority: usize,
+    pub stake_list: usize,
+    pub rent_funds: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ReallocStakeList {
+    type ArrangedAccountIndices = ReallocStakeListInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(ReallocStakeListInstructionAccountIndices {
+            state: 0,
+            admin_authority: 1,
+            stake_list: 2,
+            rent_funds: 3,
+            system_program: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ReallocStakeList {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "admin_authority",
+            "stake_list",
+            "rent_funds",
+            "system_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct ReallocValidatorListInstructionAccounts {
     pub validator_list: solana_pubkey::Pubkey,
     pub rent_funds: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ReallocValidatorList {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ReallocValidatorList {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, admin_authority, validator_list, rent_funds, system_program, _remaining @ ..] =
+        let [state, admin_authority, validator_list, rent_funds, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -34,6 +36,81 @@ impl carbon_core::deserialize::ArrangeAccounts for ReallocValidatorList {
             validator_list: validator_list.pubkey,
             rent_funds: rent_funds.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ReallocValidatorListInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub admin_authority: solana_instruction::AccountMeta,
+    pub validator_list: solana_instruction::AccountMeta,
+    pub rent_funds: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ReallocValidatorList {
+    type ArrangedAccountMetas = ReallocValidatorListInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, admin_authority, validator_list, rent_funds, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(ReallocValidatorListInstructionAccountMetas {
+            state: state.clone(),
+            admin_authority: admin_authority.clone(),
+            validator_list: validator_list.clone(),
+            rent_funds: rent_funds.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ReallocValidatorListInstructionAccountIndices {
+    pub state: usize,
+    pub admin_authority: usize,
+    pub validator_list: usize,
+    pub rent_funds: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ReallocValidatorList {
+    type ArrangedAccountIndices = ReallocValidatorListInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(ReallocValidatorListInstructionAccountIndices {
+            state: 0,
+            admin_authority: 1,
+            validator_list: 2,
+            rent_funds: 3,
+            system_program: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ReallocValidatorList {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "admin_authority",
+            "validator_list",
+            "rent_funds",
+            "system_program",
+        ]
+    }
+}
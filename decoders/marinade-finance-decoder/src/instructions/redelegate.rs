@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -26,6 +27,7 @@ pub struct RedelegateInstructionAccounts {
     pub stake_config: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub stake_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Redelegate {
@@ -34,7 +36,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Redelegate {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, validator_list, stake_list, stake_account, stake_deposit_authority, reserve_pda, split_stake_account, split_stake_rent_payer, dest_validator_account, redelegate_stake_account, clock, stake_history, stake_config, system_program, stake_program, _remaining @ ..] =
+        let [state, validator_list, stake_list, stake_account, stake_deposit_authority, reserve_pda, split_stake_account, split_stake_rent_payer, dest_validator_account, redelegate_stake_account, clock, stake_history, stake_config, system_program, stake_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -56,6 +58,131 @@ impl carbon_core::deserialize::ArrangeAccounts for Redelegate {
             stake_config: stake_config.pubkey,
             system_program: system_program.pubkey,
             stake_program: stake_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct RedelegateInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub validator_list: solana_instruction::AccountMeta,
+    pub stake_list: solana_instruction::AccountMeta,
+    pub stake_account: solana_instruction::AccountMeta,
+    pub stake_deposit_authority: solana_instruction::AccountMeta,
+    pub reserve_pda: solana_instruction::AccountMeta,
+    pub split_stake_account: solana_instruction::AccountMeta,
+    pub split_stake_rent_payer: solana_instruction::AccountMeta,
+    pub dest_validator_account: solana_instruction::AccountMeta,
+    pub redelegate_stake_account: solana_instruction::AccountMeta,
+    pub clock: solana_instruction::AccountMeta,
+    pub stake_history: solana_instruction::AccountMeta,
+    pub stake_config: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub stake_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Redelegate {
+    type ArrangedAccountMetas = RedelegateInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, validator_list, stake_list, stake_account, stake_deposit_authority, reserve_pda, split_stake_account, split_stake_rent_payer, dest_validator_account, redelegate_stake_account, clock, stake_history, stake_config, system_program, stake_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(RedelegateInstructionAccountMetas {
+            state: state.clone(),
+            validator_list: validator_list.clone(),
+            stake_list: stake_list.clone(),
+            stake_account: stake_account.clone(),
+            stake_deposit_authority: stake_deposit_authority.clone(),
+            reserve_pda: reserve_pda.clone(),
+            split_stake_account: split_stake_account.clone(),
+            split_stake_rent_payer: split_stake_rent_payer.clone(),
+            dest_validator_account: dest_validator_account.clone(),
+            redelegate_stake_account: redelegate_stake_account.clone(),
+            clock: clock.clone(),
+            stake_history: stake_history.clone(),
+            stake_config: stake_config.clone(),
+            system_program: system_program.clone(),
+            stake_program: stake_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct RedelegateInstructionAccountIndices {
+    pub state: usize,
+    pub validator_list: usize,
+    pub stake_list: usize,
+    pub stake_account: usize,
+    pub stake_deposit_authority: usize,
+    pub reserve_pda: usize,
+    pub split_stake_account: usize,
+    pub split_stake_rent_payer: usize,
+    pub dest_validator_account: usize,
+    pub redelegate_stake_account: usize,
+    pub clock: usize,
+    pub stake_history: usize,
+    pub stake_config: usize,
+    pub system_program: usize,
+    pub stake_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Redelegate {
+    type ArrangedAccountIndices = RedelegateInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 15 {
+            return None;
+        }
+
+        Some(RedelegateInstructionAccountIndices {
+            state: 0,
+            validator_list: 1,
+            stake_list: 2,
+            stake_account: 3,
+            stake_deposit_authority: 4,
+            reserve_pda: 5,
+            split_stake_account: 6,
+            split_stake_rent_payer: 7,
+            dest_validator_account: 8,
+            redelegate_stake_account: 9,
+            clock: 10,
+            stake_history: 11,
+            stake_config: 12,
+            system_program: 13,
+            stake_program: 14,
+            remaining_accounts: 15..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Redelegate {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "validator_list",
+            "stake_list",
+            "stake_account",
+            "stake_deposit_authority",
+            "reserve_pda",
+            "split_stake_account",
+            "split_stake_rent_payer",
+            "dest_validator_account",
+            "redelegate_stake_account",
+            "clock",
+            "stake_history",
+            "stake_config",
+            "system_program",
+            "stake_program",
+        ]
+    }
+}
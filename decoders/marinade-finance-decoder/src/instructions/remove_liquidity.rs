@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -20,6 +21,7 @@ pub struct RemoveLiquidityInstructionAccounts {
     pub liq_pool_msol_leg_authority: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for RemoveLiquidity {
@@ -28,7 +30,7 @@ impl carbon_core::deserialize::ArrangeAccounts for RemoveLiquidity {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, lp_mint, burn_from, burn_from_authority, transfer_sol_to, transfer_msol_to, liq_pool_sol_leg_pda, liq_pool_msol_leg, liq_pool_msol_leg_authority, system_program, token_program, _remaining @ ..] =
+        let [state, lp_mint, burn_from, burn_from_authority, transfer_sol_to, transfer_msol_to, liq_pool_sol_leg_pda, liq_pool_msol_leg, liq_pool_msol_leg_authority, system_program, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -46,6 +48,111 @@ impl carbon_core::deserialize::ArrangeAccounts for RemoveLiquidity {
             liq_pool_msol_leg_authority: liq_pool_msol_leg_authority.pubkey,
             system_program: system_program.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct RemoveLiquidityInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub lp_mint: solana_instruction::AccountMeta,
+    pub burn_from: solana_instruction::AccountMeta,
+    pub burn_from_authority: solana_instruction::AccountMeta,
+    pub transfer_sol_to: solana_instruction::AccountMeta,
+    pub transfer_msol_to: solana_instruction::AccountMeta,
+    pub liq_pool_sol_leg_pda: solana_instruction::AccountMeta,
+    pub liq_pool_msol_leg: solana_instruction::AccountMeta,
+    pub liq_pool_msol_leg_authority: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for RemoveLiquidity {
+    type ArrangedAccountMetas = RemoveLiquidityInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, lp_mint, burn_from, burn_from_authority, transfer_sol_to, transfer_msol_to, liq_pool_sol_leg_pda, liq_pool_msol_leg, liq_pool_msol_leg_authority, system_program, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(RemoveLiquidityInstructionAccountMetas {
+            state: state.clone(),
+            lp_mint: lp_mint.clone(),
+            burn_from: burn_from.clone(),
+            burn_from_authority: burn_from_authority.clone(),
+            transfer_sol_to: transfer_sol_to.clone(),
+            transfer_msol_to: transfer_msol_to.clone(),
+            liq_pool_sol_leg_pda: liq_pool_sol_leg_pda.clone(),
+            liq_pool_msol_leg: liq_pool_msol_leg.clone(),
+            liq_pool_msol_leg_authority: liq_pool_msol_leg_authority.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct RemoveLiquidityInstructionAccountIndices {
+    pub state: usize,
+    pub lp_mint: usize,
+    pub burn_from: usize,
+    pub burn_from_authority: usize,
+    pub transfer_sol_to: usize,
+    pub transfer_msol_to: usize,
+    pub liq_pool_sol_leg_pda: usize,
+    pub liq_pool_msol_leg: usize,
+    pub liq_pool_msol_leg_authority: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for RemoveLiquidity {
+    type ArrangedAccountIndices = RemoveLiquidityInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 11 {
+            return None;
+        }
+
+        Some(RemoveLiquidityInstructionAccountIndices {
+            state: 0,
+            lp_mint: 1,
+            burn_from: 2,
+            burn_from_authority: 3,
+            transfer_sol_to: 4,
+            transfer_msol_to: 5,
+            liq_pool_sol_leg_pda: 6,
+            liq_pool_msol_leg: 7,
+            liq_pool_msol_leg_authority: 8,
+            system_program: 9,
+            token_program: 10,
+            remaining_accounts: 11..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for RemoveLiquidity {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "lp_mint",
+            "burn_from",
+            "burn_from_authority",
+            "transfer_sol_to",
+            "transfer_msol_to",
+            "liq_pool_sol_leg_pda",
+            "liq_pool_msol_leg",
+            "liq_pool_msol_leg_authority",
+            "system_program",
+            "token_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -15,6 +16,7 @@ pub struct RemoveValidatorInstructionAccounts {
     pub validator_list: solana_pubkey::Pubkey,
     pub duplication_flag: solana_pubkey::Pubkey,
     pub operational_sol_account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for RemoveValidator {
@@ -23,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for RemoveValidator {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, manager_authority, validator_list, duplication_flag, operational_sol_account, _remaining @ ..] =
+        let [state, manager_authority, validator_list, duplication_flag, operational_sol_account, remaining @ ..] =
             accounts
         else {
             return None;
@@ -35,6 +37,81 @@ impl carbon_core::deserialize::ArrangeAccounts for RemoveValidator {
             validator_list: validator_list.pubkey,
             duplication_flag: duplication_flag.pubkey,
             operational_sol_account: operational_sol_account.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct RemoveValidatorInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub manager_authority: solana_instruction::AccountMeta,
+    pub validator_list: solana_instruction::AccountMeta,
+    pub duplication_flag: solana_instruction::AccountMeta,
+    pub operational_sol_account: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for RemoveValidator {
+    type ArrangedAccountMetas = RemoveValidatorInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, manager_authority, validator_list, duplication_flag, operational_sol_account, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(RemoveValidatorInstructionAccountMetas {
+            state: state.clone(),
+            manager_authority: manager_authority.clone(),
+            validator_list: validator_list.clone(),
+            duplication_flag: duplication_flag.clone(),
+            operational_sol_account: operational_sol_account.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct RemoveValidatorInstructionAccountIndices {
+    pub state: usize,
+    pub manager_authority: usize,
+    pub validator_list: usize,
+    pub duplication_flag: usize,
+    pub operational_sol_account: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for RemoveValidator {
+    type ArrangedAccountIndices = RemoveValidatorInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(RemoveValidatorInstructionAccountIndices {
+            state: 0,
+            manager_authority: 1,
+            validator_list: 2,
+            duplication_flag: 3,
+            operational_sol_account: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for RemoveValidator {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "manager_authority",
+            "validator_list",
+            "duplication_flag",
+            "operational_sol_account",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct SetValidatorScoreInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub manager_authority: solana_pubkey::Pubkey,
     pub validator_list: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SetValidatorScore {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for SetValidatorScore {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, manager_authority, validator_list, _remaining @ ..] = accounts else {
+        let [state, manager_authority, validator_list, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -30,6 +32,65 @@ impl carbon_core::deserialize::ArrangeAccounts for SetValidatorScore {
             state: state.pubkey,
             manager_authority: manager_authority.pubkey,
             validator_list: validator_list.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SetValidatorScoreInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub manager_authority: solana_instruction::AccountMeta,
+    pub validator_list: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SetValidatorScore {
+    type ArrangedAccountMetas = SetValidatorScoreInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, manager_authority, validator_list, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(SetValidatorScoreInstructionAccountMetas {
+            state: state.clone(),
+            manager_authority: manager_authority.clone(),
+            validator_list: validator_list.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SetValidatorScoreInstructionAccountIndices {
+    pub state: usize,
+    pub manager_authority: usize,
+    pub validator_list: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SetValidatorScore {
+    type ArrangedAccountIndices = SetValidatorScoreInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(SetValidatorScoreInstructionAccountIndices {
+            state: 0,
+            manager_authority: 1,
+            validator_list: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SetValidatorScore {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "manager_authority", "validator_list"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -24,6 +25,7 @@ pub struct StakeReserveInstructionAccounts {
     pub stake_config: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub stake_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for StakeReserve {
@@ -32,7 +34,7 @@ impl carbon_core::deserialize::ArrangeAccounts for StakeReserve {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, validator_list, stake_list, validator_vote, reserve_pda, stake_account, stake_deposit_authority, rent_payer, clock, epoch_schedule, rent, stake_history, stake_config, system_program, stake_program, _remaining @ ..] =
+        let [state, validator_list, stake_list, validator_vote, reserve_pda, stake_account, stake_deposit_authority, rent_payer, clock, epoch_schedule, rent, stake_history, stake_config, system_program, stake_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -54,6 +56,131 @@ impl carbon_core::deserialize::ArrangeAccounts for StakeReserve {
             stake_config: stake_config.pubkey,
             system_program: system_program.pubkey,
             stake_program: stake_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct StakeReserveInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub validator_list: solana_instruction::AccountMeta,
+    pub stake_list: solana_instruction::AccountMeta,
+    pub validator_vote: solana_instruction::AccountMeta,
+    pub reserve_pda: solana_instruction::AccountMeta,
+    pub stake_account: solana_instruction::AccountMeta,
+    pub stake_deposit_authority: solana_instruction::AccountMeta,
+    pub rent_payer: solana_instruction::AccountMeta,
+    pub clock: solana_instruction::AccountMeta,
+    pub epoch_schedule: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub stake_history: solana_instruction::AccountMeta,
+    pub stake_config: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub stake_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for StakeReserve {
+    type ArrangedAccountMetas = StakeReserveInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, validator_list, stake_list, validator_vote, reserve_pda, stake_account, stake_deposit_authority, rent_payer, clock, epoch_schedule, rent, stake_history, stake_config, system_program, stake_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(StakeReserveInstructionAccountMetas {
+            state: state.clone(),
+            validator_list: validator_list.clone(),
+            stake_list: stake_list.clone(),
+            validator_vote: validator_vote.clone(),
+            reserve_pda: reserve_pda.clone(),
+            stake_account: stake_account.clone(),
+            stake_deposit_authority: stake_deposit_authority.clone(),
+            rent_payer: rent_payer.clone(),
+            clock: clock.clone(),
+            epoch_schedule: epoch_schedule.clone(),
+            rent: rent.clone(),
+            stake_history: stake_history.clone(),
+            stake_config: stake_config.clone(),
+            system_program: system_program.clone(),
+            stake_program: stake_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct StakeReserveInstructionAccountIndices {
+    pub state: usize,
+    pub validator_list: usize,
+    pub stake_list: usize,
+    pub validator_vote: usize,
+    pub reserve_pda: usize,
+    pub stake_account: usize,
+    pub stake_deposit_authority: usize,
+    pub rent_payer: usize,
+    pub clock: usize,
+    pub epoch_schedule: usize,
+    pub rent: usize,
+    pub stake_history: usize,
+    pub stake_config: usize,
+    pub system_program: usize,
+    pub stake_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for StakeReserve {
+    type ArrangedAccountIndices = StakeReserveInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 15 {
+            return None;
+        }
+
+        Some(StakeReserveInstructionAccountIndices {
+            state: 0,
+            validator_list: 1,
+            stake_list: 2,
+            validator_vote: 3,
+            reserve_pda: 4,
+            stake_account: 5,
+            stake_deposit_authority: 6,
+            rent_payer: 7,
+            clock: 8,
+            epoch_schedule: 9,
+            rent: 10,
+            stake_history: 11,
+            stake_config: 12,
+            system_program: 13,
+            stake_program: 14,
+            remaining_accounts: 15..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for StakeReserve {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "validator_list",
+            "stake_list",
+            "validator_vote",
+            "reserve_pda",
+            "stake_account",
+            "stake_deposit_authority",
+            "rent_payer",
+            "clock",
+            "epoch_schedule",
+            "rent",
+            "stake_history",
+            "stake_config",
+            "system_program",
+            "stake_program",
+        ]
+    }
+}
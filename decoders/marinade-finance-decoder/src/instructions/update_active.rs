@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +13,7 @@ pub struct UpdateActive {
 pub struct UpdateActiveInstructionAccounts {
     pub common: solana_pubkey::Pubkey,
     pub validator_list: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateActive {
@@ -20,13 +22,68 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateActive {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [common, validator_list, _remaining @ ..] = accounts else {
+        let [common, validator_list, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(UpdateActiveInstructionAccounts {
             common: common.pubkey,
             validator_list: validator_list.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateActiveInstructionAccountMetas {
+    pub common: solana_instruction::AccountMeta,
+    pub validator_list: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateActive {
+    type ArrangedAccountMetas = UpdateActiveInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [common, validator_list, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateActiveInstructionAccountMetas {
+            common: common.clone(),
+            validator_list: validator_list.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateActiveInstructionAccountIndices {
+    pub common: usize,
+    pub validator_list: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateActive {
+    type ArrangedAccountIndices = UpdateActiveInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(UpdateActiveInstructionAccountIndices {
+            common: 0,
+            validator_list: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateActive {
+    fn account_names() -> &'static [&'static str] {
+        &["common", "validator_list"]
+    }
+}
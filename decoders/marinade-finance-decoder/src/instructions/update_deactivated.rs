@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +13,7 @@ pub struct UpdateDeactivatedInstructionAccounts {
     pub common: solana_pubkey::Pubkey,
     pub operational_sol_account: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateDeactivated {
@@ -20,7 +22,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateDeactivated {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [common, operational_sol_account, system_program, _remaining @ ..] = accounts else {
+        let [common, operational_sol_account, system_program, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +30,65 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateDeactivated {
             common: common.pubkey,
             operational_sol_account: operational_sol_account.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateDeactivatedInstructionAccountMetas {
+    pub common: solana_instruction::AccountMeta,
+    pub operational_sol_account: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateDeactivated {
+    type ArrangedAccountMetas = UpdateDeactivatedInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [common, operational_sol_account, system_program, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateDeactivatedInstructionAccountMetas {
+            common: common.clone(),
+            operational_sol_account: operational_sol_account.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateDeactivatedInstructionAccountIndices {
+    pub common: usize,
+    pub operational_sol_account: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateDeactivated {
+    type ArrangedAccountIndices = UpdateDeactivatedInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(UpdateDeactivatedInstructionAccountIndices {
+            common: 0,
+            operational_sol_account: 1,
+            system_program: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateDeactivated {
+    fn account_names() -> &'static [&'static str] {
+        &["common", "operational_sol_account", "system_program"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -28,6 +29,7 @@ pub struct WithdrawStakeAccountInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub stake_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for WithdrawStakeAccount {
@@ -36,7 +38,7 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawStakeAccount {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, msol_mint, burn_msol_from, burn_msol_authority, treasury_msol_account, validator_list, stake_list, stake_withdraw_authority, stake_deposit_authority, stake_account, split_stake_account, split_stake_rent_payer, clock, system_program, token_program, stake_program, _remaining @ ..] =
+        let [state, msol_mint, burn_msol_from, burn_msol_authority, treasury_msol_account, validator_list, stake_list, stake_withdraw_authority, stake_deposit_authority, stake_account, split_stake_account, split_stake_rent_payer, clock, system_program, token_program, stake_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -59,6 +61,136 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawStakeAccount {
             system_program: system_program.pubkey,
             token_program: token_program.pubkey,
             stake_program: stake_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct WithdrawStakeAccountInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub msol_mint: solana_instruction::AccountMeta,
+    pub burn_msol_from: solana_instruction::AccountMeta,
+    pub burn_msol_authority: solana_instruction::AccountMeta,
+    pub treasury_msol_account: solana_instruction::AccountMeta,
+    pub validator_list: solana_instruction::AccountMeta,
+    pub stake_list: solana_instruction::AccountMeta,
+    pub stake_withdraw_authority: solana_instruction::AccountMeta,
+    pub stake_deposit_authority: solana_instruction::AccountMeta,
+    pub stake_account: solana_instruction::AccountMeta,
+    pub split_stake_account: solana_instruction::AccountMeta,
+    pub split_stake_rent_payer: solana_instruction::AccountMeta,
+    pub clock: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub stake_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for WithdrawStakeAccount {
+    type ArrangedAccountMetas = WithdrawStakeAccountInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, msol_mint, burn_msol_from, burn_msol_authority, treasury_msol_account, validator_list, stake_list, stake_withdraw_authority, stake_deposit_authority, stake_account, split_stake_account, split_stake_rent_payer, clock, system_program, token_program, stake_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(WithdrawStakeAccountInstructionAccountMetas {
+            state: state.clone(),
+            msol_mint: msol_mint.clone(),
+            burn_msol_from: burn_msol_from.clone(),
+            burn_msol_authority: burn_msol_authority.clone(),
+            treasury_msol_account: treasury_msol_account.clone(),
+            validator_list: validator_list.clone(),
+            stake_list: stake_list.clone(),
+            stake_withdraw_authority: stake_withdraw_authority.clone(),
+            stake_deposit_authority: stake_deposit_authority.clone(),
+            stake_account: stake_account.clone(),
+            split_stake_account: split_stake_account.clone(),
+            split_stake_rent_payer: split_stake_rent_payer.clone(),
+            clock: clock.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            stake_program: stake_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct WithdrawStakeAccountInstructionAccountIndices {
+    pub state: usize,
+    pub msol_mint: usize,
+    pub burn_msol_from: usize,
+    pub burn_msol_authority: usize,
+    pub treasury_msol_account: usize,
+    pub validator_list: usize,
+    pub stake_list: usize,
+    pub stake_withdraw_authority: usize,
+    pub stake_deposit_authority: usize,
+    pub stake_account: usize,
+    pub split_stake_account: usize,
+    pub split_stake_rent_payer: usize,
+    pub clock: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub stake_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for WithdrawStakeAccount {
+    type ArrangedAccountIndices = WithdrawStakeAccountInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 16 {
+            return None;
+        }
+
+        Some(WithdrawStakeAccountInstructionAccountIndices {
+            state: 0,
+            msol_mint: 1,
+            burn_msol_from: 2,
+            burn_msol_authority: 3,
+            treasury_msol_account: 4,
+            validator_list: 5,
+            stake_list: 6,
+            stake_withdraw_authority: 7,
+            stake_deposit_authority: 8,
+            stake_account: 9,
+            split_stake_account: 10,
+            split_stake_rent_payer: 11,
+            clock: 12,
+            system_program: 13,
+            token_program: 14,
+            stake_program: 15,
+            remaining_accounts: 16..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for WithdrawStakeAccount {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "msol_mint",
+            "burn_msol_from",
+            "burn_msol_authority",
+            "treasury_msol_account",
+            "validator_list",
+            "stake_list",
+            "stake_withdraw_authority",
+            "stake_deposit_authority",
+            "stake_account",
+            "split_stake_account",
+            "split_stake_rent_payer",
+            "clock",
+            "system_program",
+            "token_program",
+            "stake_program",
+        ]
+    }
+}
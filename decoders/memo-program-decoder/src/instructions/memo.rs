@@ -0,0 +1,131 @@
+use {
+    crate::MemoDecoder,
+    carbon_core::{
+        error::{CarbonResult, Error},
+        instruction::DecodedInstruction,
+    },
+    solana_pubkey::Pubkey,
+};
+
+/// The program IDs of the Memo program, v1 and v2.
+pub const MEMO_PROGRAM_IDS: [Pubkey; 2] = [spl_memo::v1::ID, spl_memo::ID];
+
+/// A memo, whose instruction data is the UTF-8 text, without discriminator.
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone, Hash)]
+pub struct Memo {
+    pub text: String,
+    /// The accounts that signed the memo.
+    pub signers: Vec<Pubkey>,
+}
+
+impl carbon_core::instruction::InstructionDecoder<'_> for MemoDecoder {
+    type InstructionType = Memo;
+
+    fn program_ids(&self) -> &[Pubkey] {
+        &MEMO_PROGRAM_IDS
+    }
+
+    fn try_decode_instruction(
+        &self,
+        instruction: &solana_instruction::Instruction,
+    ) -> CarbonResult<Option<DecodedInstruction<Self::InstructionType>>> {
+        if !MEMO_PROGRAM_IDS.contains(&instruction.program_id) {
+            return Ok(None);
+        }
+
+        let text = String::from_utf8(instruction.data.clone())
+            .map_err(|err| Error::FailedToDeserialize(format!("Invalid UTF-8 memo: {}", err)))?;
+
+        Ok(Some(DecodedInstruction {
+            data: Memo {
+                text,
+                signers: instruction
+                    .accounts
+                    .iter()
+                    .filter(|account| account.is_signer)
+                    .map(|account| account.pubkey)
+                    .collect(),
+            },
+            program_id: instruction.program_id,
+            accounts: instruction.accounts.clone(),
+            discriminator: vec![],
+            raw_len: instruction.data.len(),
+            num_accounts: instruction.accounts.len(),
+            raw_data: instruction.data.clone(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        carbon_core::instruction::InstructionDecoder,
+        solana_instruction::{AccountMeta, Instruction},
+    };
+
+    #[test]
+    fn test_decode_utf8_memo_with_signers() {
+        // Arrange
+        let signer = Pubkey::new_unique();
+        let instruction = Instruction {
+            program_id: spl_memo::ID,
+            accounts: vec![
+                AccountMeta::new_readonly(signer, true),
+                AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            ],
+            data: "gm ☀️".as_bytes().to_vec(),
+        };
+        let v1_instruction = Instruction {
+            program_id: spl_memo::v1::ID,
+            accounts: vec![],
+            data: b"legacy".to_vec(),
+        };
+
+        // Act
+        let decoded = MemoDecoder
+            .try_decode_instruction(&instruction)
+            .expect("valid memo")
+            .expect("memo program");
+        let decoded_v1 = MemoDecoder
+            .try_decode_instruction(&v1_instruction)
+            .expect("valid memo")
+            .expect("memo program");
+
+        // Assert
+        assert_eq!(
+            decoded.data,
+            Memo {
+                text: "gm ☀️".to_string(),
+                signers: vec![signer],
+            }
+        );
+        assert_eq!(decoded_v1.data.text, "legacy");
+        assert!(decoded_v1.data.signers.is_empty());
+    }
+
+    #[test]
+    fn test_invalid_utf8_memo_is_a_decode_error() {
+        // Arrange
+        let instruction = Instruction {
+            program_id: spl_memo::ID,
+            accounts: vec![],
+            data: vec![0x67, 0x6d, 0xff, 0xfe],
+        };
+        let other_program = Instruction {
+            program_id: Pubkey::new_unique(),
+            ..instruction.clone()
+        };
+
+        // Act
+        let result = MemoDecoder.try_decode_instruction(&instruction);
+
+        // Assert
+        assert!(matches!(result, Err(Error::FailedToDeserialize(_))));
+        assert!(MemoDecoder.decode_instruction(&instruction).is_none());
+        assert!(MemoDecoder
+            .try_decode_instruction(&other_program)
+            .expect("other program")
+            .is_none());
+    }
+}
@@ -1,5 +1,7 @@
 use {crate::MemoProgramDecoder, carbon_core::instruction::DecodedInstruction};
 
+pub mod memo;
+
 #[derive(
     carbon_core::InstructionType,
     serde::Serialize,
@@ -17,18 +19,30 @@ pub enum MemoProgramInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for MemoProgramDecoder {
     type InstructionType = MemoProgramInstruction;
 
-    fn decode_instruction(
+    fn decoder_name(&self) -> &'static str {
+        "memo_program"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[spl_memo::ID]
+    }
+
+    fn try_decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
-    ) -> Option<DecodedInstruction<Self::InstructionType>> {
+    ) -> carbon_core::error::CarbonResult<Option<DecodedInstruction<Self::InstructionType>>> {
         if !instruction.program_id.eq(&spl_memo::ID) {
-            return None;
+            return Ok(None);
         }
 
-        Some(DecodedInstruction {
+        Ok(Some(DecodedInstruction {
             data: MemoProgramInstruction::Memo(instruction.data.clone()),
             program_id: instruction.program_id,
             accounts: instruction.accounts.clone(),
-        })
+            discriminator: vec![],
+            raw_len: instruction.data.len(),
+            num_accounts: instruction.accounts.len(),
+            raw_data: instruction.data.clone(),
+        }))
     }
 }
@@ -1,2 +1,4 @@
 pub struct MemoProgramDecoder;
+/// Decodes instructions of both Memo program versions into their UTF-8 text.
+pub struct MemoDecoder;
 pub mod instructions;
@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -64,3 +65,132 @@ impl carbon_core::deserialize::ArrangeAccounts for AddLiquidity {
         })
     }
 }
+
+pub struct AddLiquidityInstructionAccountMetas {
+    pub position: solana_instruction::AccountMeta,
+    pub lb_pair: solana_instruction::AccountMeta,
+    pub bin_array_bitmap_extension: solana_instruction::AccountMeta,
+    pub user_token_x: solana_instruction::AccountMeta,
+    pub user_token_y: solana_instruction::AccountMeta,
+    pub reserve_x: solana_instruction::AccountMeta,
+    pub reserve_y: solana_instruction::AccountMeta,
+    pub token_x_mint: solana_instruction::AccountMeta,
+    pub token_y_mint: solana_instruction::AccountMeta,
+    pub bin_array_lower: solana_instruction::AccountMeta,
+    pub bin_array_upper: solana_instruction::AccountMeta,
+    pub sender: solana_instruction::AccountMeta,
+    pub token_x_program: solana_instruction::AccountMeta,
+    pub token_y_program: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for AddLiquidity {
+    type ArrangedAccountMetas = AddLiquidityInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [position, lb_pair, bin_array_bitmap_extension, user_token_x, user_token_y, reserve_x, reserve_y, token_x_mint, token_y_mint, bin_array_lower, bin_array_upper, sender, token_x_program, token_y_program, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(AddLiquidityInstructionAccountMetas {
+            position: position.clone(),
+            lb_pair: lb_pair.clone(),
+            bin_array_bitmap_extension: bin_array_bitmap_extension.clone(),
+            user_token_x: user_token_x.clone(),
+            user_token_y: user_token_y.clone(),
+            reserve_x: reserve_x.clone(),
+            reserve_y: reserve_y.clone(),
+            token_x_mint: token_x_mint.clone(),
+            token_y_mint: token_y_mint.clone(),
+            bin_array_lower: bin_array_lower.clone(),
+            bin_array_upper: bin_array_upper.clone(),
+            sender: sender.clone(),
+            token_x_program: token_x_program.clone(),
+            token_y_program: token_y_program.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct AddLiquidityInstructionAccountIndices {
+    pub position: usize,
+    pub lb_pair: usize,
+    pub bin_array_bitmap_extension: usize,
+    pub user_token_x: usize,
+    pub user_token_y: usize,
+    pub reserve_x: usize,
+    pub reserve_y: usize,
+    pub token_x_mint: usize,
+    pub token_y_mint: usize,
+    pub bin_array_lower: usize,
+    pub bin_array_upper: usize,
+    pub sender: usize,
+    pub token_x_program: usize,
+    pub token_y_program: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for AddLiquidity {
+    type ArrangedAccountIndices = AddLiquidityInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 16 {
+            return None;
+        }
+
+        Some(AddLiquidityInstructionAccountIndices {
+            position: 0,
+            lb_pair: 1,
+            bin_array_bitmap_extension: 2,
+            user_token_x: 3,
+            user_token_y: 4,
+            reserve_x: 5,
+            reserve_y: 6,
+            token_x_mint: 7,
+            token_y_mint: 8,
+            bin_array_lower: 9,
+            bin_array_upper: 10,
+            sender: 11,
+            token_x_program: 12,
+            token_y_program: 13,
+            event_authority: 14,
+            program: 15,
+            remaining_accounts: 16..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for AddLiquidity {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "position",
+            "lb_pair",
+            "bin_array_bitmap_extension",
+            "user_token_x",
+            "user_token_y",
+            "reserve_x",
+            "reserve_y",
+            "token_x_mint",
+            "token_y_mint",
+            "bin_array_lower",
+            "bin_array_upper",
+            "sender",
+            "token_x_program",
+            "token_y_program",
+            "event_authority",
+            "program",
+        ]
+    }
+}
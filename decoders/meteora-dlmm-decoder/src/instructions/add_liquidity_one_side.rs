@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -56,3 +57,112 @@ impl carbon_core::deserialize::ArrangeAccounts for AddLiquidityOneSide {
         })
     }
 }
+
+pub struct AddLiquidityOneSideInstructionAccountMetas {
+    pub position: solana_instruction::AccountMeta,
+    pub lb_pair: solana_instruction::AccountMeta,
+    pub bin_array_bitmap_extension: solana_instruction::AccountMeta,
+    pub user_token: solana_instruction::AccountMeta,
+    pub reserve: solana_instruction::AccountMeta,
+    pub token_mint: solana_instruction::AccountMeta,
+    pub bin_array_lower: solana_instruction::AccountMeta,
+    pub bin_array_upper: solana_instruction::AccountMeta,
+    pub sender: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for AddLiquidityOneSide {
+    type ArrangedAccountMetas = AddLiquidityOneSideInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [position, lb_pair, bin_array_bitmap_extension, user_token, reserve, token_mint, bin_array_lower, bin_array_upper, sender, token_program, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(AddLiquidityOneSideInstructionAccountMetas {
+            position: position.clone(),
+            lb_pair: lb_pair.clone(),
+            bin_array_bitmap_extension: bin_array_bitmap_extension.clone(),
+            user_token: user_token.clone(),
+            reserve: reserve.clone(),
+            token_mint: token_mint.clone(),
+            bin_array_lower: bin_array_lower.clone(),
+            bin_array_upper: bin_array_upper.clone(),
+            sender: sender.clone(),
+            token_program: token_program.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct AddLiquidityOneSideInstructionAccountIndices {
+    pub position: usize,
+    pub lb_pair: usize,
+    pub bin_array_bitmap_extension: usize,
+    pub user_token: usize,
+    pub reserve: usize,
+    pub token_mint: usize,
+    pub bin_array_lower: usize,
+    pub bin_array_upper: usize,
+    pub sender: usize,
+    pub token_program: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for AddLiquidityOneSide {
+    type ArrangedAccountIndices = AddLiquidityOneSideInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 12 {
+            return None;
+        }
+
+        Some(AddLiquidityOneSideInstructionAccountIndices {
+            position: 0,
+            lb_pair: 1,
+            bin_array_bitmap_extension: 2,
+            user_token: 3,
+            reserve: 4,
+            token_mint: 5,
+            bin_array_lower: 6,
+            bin_array_upper: 7,
+            sender: 8,
+            token_program: 9,
+            event_authority: 10,
+            program: 11,
+            remaining_accounts: 12..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for AddLiquidityOneSide {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "position",
+            "lb_pair",
+            "bin_array_bitmap_extension",
+            "user_token",
+            "reserve",
+            "token_mint",
+            "bin_array_lower",
+            "bin_array_upper",
+            "sender",
+            "token_program",
+            "event_authority",
+            "program",
+        ]
+    }
+}
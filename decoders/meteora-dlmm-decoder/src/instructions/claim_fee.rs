@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -21,6 +22,7 @@ pub struct ClaimFeeInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ClaimFee {
@@ -29,7 +31,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ClaimFee {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [lb_pair, position, bin_array_lower, bin_array_upper, sender, reserve_x, reserve_y, user_token_x, user_token_y, token_x_mint, token_y_mint, token_program, event_authority, program, _remaining @ ..] =
+        let [lb_pair, position, bin_array_lower, bin_array_upper, sender, reserve_x, reserve_y, user_token_x, user_token_y, token_x_mint, token_y_mint, token_program, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -50,6 +52,126 @@ impl carbon_core::deserialize::ArrangeAccounts for ClaimFee {
             token_program: token_program.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ClaimFeeInstructionAccountMetas {
+    pub lb_pair: solana_instruction::AccountMeta,
+    pub position: solana_instruction::AccountMeta,
+    pub bin_array_lower: solana_instruction::AccountMeta,
+    pub bin_array_upper: solana_instruction::AccountMeta,
+    pub sender: solana_instruction::AccountMeta,
+    pub reserve_x: solana_instruction::AccountMeta,
+    pub reserve_y: solana_instruction::AccountMeta,
+    pub user_token_x: solana_instruction::AccountMeta,
+    pub user_token_y: solana_instruction::AccountMeta,
+    pub token_x_mint: solana_instruction::AccountMeta,
+    pub token_y_mint: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ClaimFee {
+    type ArrangedAccountMetas = ClaimFeeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [lb_pair, position, bin_array_lower, bin_array_upper, sender, reserve_x, reserve_y, user_token_x, user_token_y, token_x_mint, token_y_mint, token_program, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(ClaimFeeInstructionAccountMetas {
+            lb_pair: lb_pair.clone(),
+            position: position.clone(),
+            bin_array_lower: bin_array_lower.clone(),
+            bin_array_upper: bin_array_upper.clone(),
+            sender: sender.clone(),
+            reserve_x: reserve_x.clone(),
+            reserve_y: reserve_y.clone(),
+            user_token_x: user_token_x.clone(),
+            user_token_y: user_token_y.clone(),
+            token_x_mint: token_x_mint.clone(),
+            token_y_mint: token_y_mint.clone(),
+            token_program: token_program.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ClaimFeeInstructionAccountIndices {
+    pub lb_pair: usize,
+    pub position: usize,
+    pub bin_array_lower: usize,
+    pub bin_array_upper: usize,
+    pub sender: usize,
+    pub reserve_x: usize,
+    pub reserve_y: usize,
+    pub user_token_x: usize,
+    pub user_token_y: usize,
+    pub token_x_mint: usize,
+    pub token_y_mint: usize,
+    pub token_program: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ClaimFee {
+    type ArrangedAccountIndices = ClaimFeeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 14 {
+            return None;
+        }
+
+        Some(ClaimFeeInstructionAccountIndices {
+            lb_pair: 0,
+            position: 1,
+            bin_array_lower: 2,
+            bin_array_upper: 3,
+            sender: 4,
+            reserve_x: 5,
+            reserve_y: 6,
+            user_token_x: 7,
+            user_token_y: 8,
+            token_x_mint: 9,
+            token_y_mint: 10,
+            token_program: 11,
+            event_authority: 12,
+            program: 13,
+            remaining_accounts: 14..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ClaimFee {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "lb_pair",
+            "position",
+            "bin_array_lower",
+            "bin_array_upper",
+            "sender",
+            "reserve_x",
+            "reserve_y",
+            "user_token_x",
+            "user_token_y",
+            "token_x_mint",
+            "token_y_mint",
+            "token_program",
+            "event_authority",
+            "program",
+        ]
+    }
+}
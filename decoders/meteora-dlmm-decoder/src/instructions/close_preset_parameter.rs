@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -10,6 +11,7 @@ pub struct ClosePresetParameterInstructionAccounts {
     pub preset_parameter: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
     pub rent_receiver: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ClosePresetParameter {
@@ -18,7 +20,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ClosePresetParameter {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [preset_parameter, admin, rent_receiver, _remaining @ ..] = accounts else {
+        let [preset_parameter, admin, rent_receiver, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -26,6 +28,65 @@ impl carbon_core::deserialize::ArrangeAccounts for ClosePresetParameter {
             preset_parameter: preset_parameter.pubkey,
             admin: admin.pubkey,
             rent_receiver: rent_receiver.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ClosePresetParameterInstructionAccountMetas {
+    pub preset_parameter: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub rent_receiver: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ClosePresetParameter {
+    type ArrangedAccountMetas = ClosePresetParameterInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [preset_parameter, admin, rent_receiver, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(ClosePresetParameterInstructionAccountMetas {
+            preset_parameter: preset_parameter.clone(),
+            admin: admin.clone(),
+            rent_receiver: rent_receiver.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ClosePresetParameterInstructionAccountIndices {
+    pub preset_parameter: usize,
+    pub admin: usize,
+    pub rent_receiver: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ClosePresetParameter {
+    type ArrangedAccountIndices = ClosePresetParameterInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(ClosePresetParameterInstructionAccountIndices {
+            preset_parameter: 0,
+            admin: 1,
+            rent_receiver: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ClosePresetParameter {
+    fn account_names() -> &'static [&'static str] {
+        &["preset_parameter", "admin", "rent_receiver"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -20,6 +21,7 @@ pub struct FundRewardInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for FundReward {
@@ -28,7 +30,7 @@ impl carbon_core::deserialize::ArrangeAccounts for FundReward {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [lb_pair, reward_vault, reward_mint, funder_token_account, funder, bin_array, token_program, event_authority, program, _remaining @ ..] =
+        let [lb_pair, reward_vault, reward_mint, funder_token_account, funder, bin_array, token_program, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -44,6 +46,101 @@ impl carbon_core::deserialize::ArrangeAccounts for FundReward {
             token_program: token_program.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct FundRewardInstructionAccountMetas {
+    pub lb_pair: solana_instruction::AccountMeta,
+    pub reward_vault: solana_instruction::AccountMeta,
+    pub reward_mint: solana_instruction::AccountMeta,
+    pub funder_token_account: solana_instruction::AccountMeta,
+    pub funder: solana_instruction::AccountMeta,
+    pub bin_array: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for FundReward {
+    type ArrangedAccountMetas = FundRewardInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [lb_pair, reward_vault, reward_mint, funder_token_account, funder, bin_array, token_program, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(FundRewardInstructionAccountMetas {
+            lb_pair: lb_pair.clone(),
+            reward_vault: reward_vault.clone(),
+            reward_mint: reward_mint.clone(),
+            funder_token_account: funder_token_account.clone(),
+            funder: funder.clone(),
+            bin_array: bin_array.clone(),
+            token_program: token_program.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct FundRewardInstructionAccountIndices {
+    pub lb_pair: usize,
+    pub reward_vault: usize,
+    pub reward_mint: usize,
+    pub funder_token_account: usize,
+    pub funder: usize,
+    pub bin_array: usize,
+    pub token_program: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for FundReward {
+    type ArrangedAccountIndices = FundRewardInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 9 {
+            return None;
+        }
+
+        Some(FundRewardInstructionAccountIndices {
+            lb_pair: 0,
+            reward_vault: 1,
+            reward_mint: 2,
+            funder_token_account: 3,
+            funder: 4,
+            bin_array: 5,
+            token_program: 6,
+            event_authority: 7,
+            program: 8,
+            remaining_accounts: 9..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for FundReward {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "lb_pair",
+            "reward_vault",
+            "reward_mint",
+            "funder_token_account",
+            "funder",
+            "bin_array",
+            "token_program",
+            "event_authority",
+            "program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -15,6 +16,7 @@ pub struct GoToABinInstructionAccounts {
     pub to_bin_array: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for GoToABin {
@@ -23,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for GoToABin {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [lb_pair, bin_array_bitmap_extension, from_bin_array, to_bin_array, event_authority, program, _remaining @ ..] =
+        let [lb_pair, bin_array_bitmap_extension, from_bin_array, to_bin_array, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -36,6 +38,86 @@ impl carbon_core::deserialize::ArrangeAccounts for GoToABin {
             to_bin_array: to_bin_array.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct GoToABinInstructionAccountMetas {
+    pub lb_pair: solana_instruction::AccountMeta,
+    pub bin_array_bitmap_extension: solana_instruction::AccountMeta,
+    pub from_bin_array: solana_instruction::AccountMeta,
+    pub to_bin_array: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for GoToABin {
+    type ArrangedAccountMetas = GoToABinInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [lb_pair, bin_array_bitmap_extension, from_bin_array, to_bin_array, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(GoToABinInstructionAccountMetas {
+            lb_pair: lb_pair.clone(),
+            bin_array_bitmap_extension: bin_array_bitmap_extension.clone(),
+            from_bin_array: from_bin_array.clone(),
+            to_bin_array: to_bin_array.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct GoToABinInstructionAccountIndices {
+    pub lb_pair: usize,
+    pub bin_array_bitmap_extension: usize,
+    pub from_bin_array: usize,
+    pub to_bin_array: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for GoToABin {
+    type ArrangedAccountIndices = GoToABinInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(GoToABinInstructionAccountIndices {
+            lb_pair: 0,
+            bin_array_bitmap_extension: 1,
+            from_bin_array: 2,
+            to_bin_array: 3,
+            event_authority: 4,
+            program: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for GoToABin {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "lb_pair",
+            "bin_array_bitmap_extension",
+            "from_bin_array",
+            "to_bin_array",
+            "event_authority",
+            "program",
+        ]
+    }
+}
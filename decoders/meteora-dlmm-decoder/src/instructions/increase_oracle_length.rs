@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct IncreaseOracleLengthInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for IncreaseOracleLength {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for IncreaseOracleLength {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [oracle, funder, system_program, event_authority, program, _remaining @ ..] = accounts
+        let [oracle, funder, system_program, event_authority, program, remaining @ ..] = accounts
         else {
             return None;
         };
@@ -33,6 +35,80 @@ impl carbon_core::deserialize::ArrangeAccounts for IncreaseOracleLength {
             system_program: system_program.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct IncreaseOracleLengthInstructionAccountMetas {
+    pub oracle: solana_instruction::AccountMeta,
+    pub funder: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for IncreaseOracleLength {
+    type ArrangedAccountMetas = IncreaseOracleLengthInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [oracle, funder, system_program, event_authority, program, remaining @ ..] = accounts
+        else {
+            return None;
+        };
+
+        Some(IncreaseOracleLengthInstructionAccountMetas {
+            oracle: oracle.clone(),
+            funder: funder.clone(),
+            system_program: system_program.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct IncreaseOracleLengthInstructionAccountIndices {
+    pub oracle: usize,
+    pub funder: usize,
+    pub system_program: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for IncreaseOracleLength {
+    type ArrangedAccountIndices = IncreaseOracleLengthInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(IncreaseOracleLengthInstructionAccountIndices {
+            oracle: 0,
+            funder: 1,
+            system_program: 2,
+            event_authority: 3,
+            program: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for IncreaseOracleLength {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "oracle",
+            "funder",
+            "system_program",
+            "event_authority",
+            "program",
+        ]
+    }
+}
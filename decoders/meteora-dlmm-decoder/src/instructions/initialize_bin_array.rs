@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +14,7 @@ pub struct InitializeBinArrayInstructionAccounts {
     pub bin_array: solana_pubkey::Pubkey,
     pub funder: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeBinArray {
@@ -21,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeBinArray {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [lb_pair, bin_array, funder, system_program, _remaining @ ..] = accounts else {
+        let [lb_pair, bin_array, funder, system_program, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -30,6 +32,69 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeBinArray {
             bin_array: bin_array.pubkey,
             funder: funder.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeBinArrayInstructionAccountMetas {
+    pub lb_pair: solana_instruction::AccountMeta,
+    pub bin_array: solana_instruction::AccountMeta,
+    pub funder: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeBinArray {
+    type ArrangedAccountMetas = InitializeBinArrayInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [lb_pair, bin_array, funder, system_program, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(InitializeBinArrayInstructionAccountMetas {
+            lb_pair: lb_pair.clone(),
+            bin_array: bin_array.clone(),
+            funder: funder.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeBinArrayInstructionAccountIndices {
+    pub lb_pair: usize,
+    pub bin_array: usize,
+    pub funder: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeBinArray {
+    type ArrangedAccountIndices = InitializeBinArrayInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(InitializeBinArrayInstructionAccountIndices {
+            lb_pair: 0,
+            bin_array: 1,
+            funder: 2,
+            system_program: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeBinArray {
+    fn account_names() -> &'static [&'static str] {
+        &["lb_pair", "bin_array", "funder", "system_program"]
+    }
+}
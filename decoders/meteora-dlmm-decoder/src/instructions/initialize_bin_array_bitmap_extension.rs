@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +13,7 @@ pub struct InitializeBinArrayBitmapExtensionInstructionAccounts {
     pub funder: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeBinArrayBitmapExtension {
@@ -20,7 +22,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeBinArrayBitmapExten
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [lb_pair, bin_array_bitmap_extension, funder, system_program, rent, _remaining @ ..] =
+        let [lb_pair, bin_array_bitmap_extension, funder, system_program, rent, remaining @ ..] =
             accounts
         else {
             return None;
@@ -32,6 +34,81 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeBinArrayBitmapExten
             funder: funder.pubkey,
             system_program: system_program.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeBinArrayBitmapExtensionInstructionAccountMetas {
+    pub lb_pair: solana_instruction::AccountMeta,
+    pub bin_array_bitmap_extension: solana_instruction::AccountMeta,
+    pub funder: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeBinArrayBitmapExtension {
+    type ArrangedAccountMetas = InitializeBinArrayBitmapExtensionInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [lb_pair, bin_array_bitmap_extension, funder, system_program, rent, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeBinArrayBitmapExtensionInstructionAccountMetas {
+            lb_pair: lb_pair.clone(),
+            bin_array_bitmap_extension: bin_array_bitmap_extension.clone(),
+            funder: funder.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeBinArrayBitmapExtensionInstructionAccountIndices {
+    pub lb_pair: usize,
+    pub bin_array_bitmap_extension: usize,
+    pub funder: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeBinArrayBitmapExtension {
+    type ArrangedAccountIndices = InitializeBinArrayBitmapExtensionInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(InitializeBinArrayBitmapExtensionInstructionAccountIndices {
+            lb_pair: 0,
+            bin_array_bitmap_extension: 1,
+            funder: 2,
+            system_program: 3,
+            rent: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeBinArrayBitmapExtension {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "lb_pair",
+            "bin_array_bitmap_extension",
+            "funder",
+            "system_program",
+            "rent",
+        ]
+    }
+}
@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -26,6 +27,7 @@ pub struct InitializePermissionLbPairInstructionAccounts {
     pub rent: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializePermissionLbPair {
@@ -34,7 +36,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializePermissionLbPair {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [base, lb_pair, bin_array_bitmap_extension, token_mint_x, token_mint_y, reserve_x, reserve_y, oracle, admin, token_program, system_program, rent, event_authority, program, _remaining @ ..] =
+        let [base, lb_pair, bin_array_bitmap_extension, token_mint_x, token_mint_y, reserve_x, reserve_y, oracle, admin, token_program, system_program, rent, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -55,6 +57,126 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializePermissionLbPair {
             rent: rent.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializePermissionLbPairInstructionAccountMetas {
+    pub base: solana_instruction::AccountMeta,
+    pub lb_pair: solana_instruction::AccountMeta,
+    pub bin_array_bitmap_extension: solana_instruction::AccountMeta,
+    pub token_mint_x: solana_instruction::AccountMeta,
+    pub token_mint_y: solana_instruction::AccountMeta,
+    pub reserve_x: solana_instruction::AccountMeta,
+    pub reserve_y: solana_instruction::AccountMeta,
+    pub oracle: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializePermissionLbPair {
+    type ArrangedAccountMetas = InitializePermissionLbPairInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [base, lb_pair, bin_array_bitmap_extension, token_mint_x, token_mint_y, reserve_x, reserve_y, oracle, admin, token_program, system_program, rent, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializePermissionLbPairInstructionAccountMetas {
+            base: base.clone(),
+            lb_pair: lb_pair.clone(),
+            bin_array_bitmap_extension: bin_array_bitmap_extension.clone(),
+            token_mint_x: token_mint_x.clone(),
+            token_mint_y: token_mint_y.clone(),
+            reserve_x: reserve_x.clone(),
+            reserve_y: reserve_y.clone(),
+            oracle: oracle.clone(),
+            admin: admin.clone(),
+            token_program: token_program.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializePermissionLbPairInstructionAccountIndices {
+    pub base: usize,
+    pub lb_pair: usize,
+    pub bin_array_bitmap_extension: usize,
+    pub token_mint_x: usize,
+    pub token_mint_y: usize,
+    pub reserve_x: usize,
+    pub reserve_y: usize,
+    pub oracle: usize,
+    pub admin: usize,
+    pub token_program: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializePermissionLbPair {
+    type ArrangedAccountIndices = InitializePermissionLbPairInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 14 {
+            return None;
+        }
+
+        Some(InitializePermissionLbPairInstructionAccountIndices {
+            base: 0,
+            lb_pair: 1,
+            bin_array_bitmap_extension: 2,
+            token_mint_x: 3,
+            token_mint_y: 4,
+            reserve_x: 5,
+            reserve_y: 6,
+            oracle: 7,
+            admin: 8,
+            token_program: 9,
+            system_program: 10,
+            rent: 11,
+            event_authority: 12,
+            program: 13,
+            remaining_accounts: 14..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializePermissionLbPair {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "base",
+            "lb_pair",
+            "bin_array_bitmap_extension",
+            "token_mint_x",
+            "token_mint_y",
+            "reserve_x",
+            "reserve_y",
+            "oracle",
+            "admin",
+            "token_program",
+            "system_program",
+            "rent",
+            "event_authority",
+            "program",
+        ]
+    }
+}
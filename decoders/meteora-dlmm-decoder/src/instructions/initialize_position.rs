@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -18,6 +19,7 @@ pub struct InitializePositionInstructionAccounts {
     pub rent: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializePosition {
@@ -26,7 +28,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializePosition {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [payer, position, lb_pair, owner, system_program, rent, event_authority, program, _remaining @ ..] =
+        let [payer, position, lb_pair, owner, system_program, rent, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -41,6 +43,96 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializePosition {
             rent: rent.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializePositionInstructionAccountMetas {
+    pub payer: solana_instruction::AccountMeta,
+    pub position: solana_instruction::AccountMeta,
+    pub lb_pair: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializePosition {
+    type ArrangedAccountMetas = InitializePositionInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [payer, position, lb_pair, owner, system_program, rent, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializePositionInstructionAccountMetas {
+            payer: payer.clone(),
+            position: position.clone(),
+            lb_pair: lb_pair.clone(),
+            owner: owner.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializePositionInstructionAccountIndices {
+    pub payer: usize,
+    pub position: usize,
+    pub lb_pair: usize,
+    pub owner: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializePosition {
+    type ArrangedAccountIndices = InitializePositionInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(InitializePositionInstructionAccountIndices {
+            payer: 0,
+            position: 1,
+            lb_pair: 2,
+            owner: 3,
+            system_program: 4,
+            rent: 5,
+            event_authority: 6,
+            program: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializePosition {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "payer",
+            "position",
+            "lb_pair",
+            "owner",
+            "system_program",
+            "rent",
+            "event_authority",
+            "program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -23,6 +24,7 @@ pub struct InitializePositionByOperatorInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializePositionByOperator {
@@ -31,7 +33,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializePositionByOperator
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [payer, base, position, lb_pair, owner, operator, operator_token_x, owner_token_x, system_program, event_authority, program, _remaining @ ..] =
+        let [payer, base, position, lb_pair, owner, operator, operator_token_x, owner_token_x, system_program, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -49,6 +51,111 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializePositionByOperator
             system_program: system_program.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializePositionByOperatorInstructionAccountMetas {
+    pub payer: solana_instruction::AccountMeta,
+    pub base: solana_instruction::AccountMeta,
+    pub position: solana_instruction::AccountMeta,
+    pub lb_pair: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub operator: solana_instruction::AccountMeta,
+    pub operator_token_x: solana_instruction::AccountMeta,
+    pub owner_token_x: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializePositionByOperator {
+    type ArrangedAccountMetas = InitializePositionByOperatorInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [payer, base, position, lb_pair, owner, operator, operator_token_x, owner_token_x, system_program, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializePositionByOperatorInstructionAccountMetas {
+            payer: payer.clone(),
+            base: base.clone(),
+            position: position.clone(),
+            lb_pair: lb_pair.clone(),
+            owner: owner.clone(),
+            operator: operator.clone(),
+            operator_token_x: operator_token_x.clone(),
+            owner_token_x: owner_token_x.clone(),
+            system_program: system_program.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializePositionByOperatorInstructionAccountIndices {
+    pub payer: usize,
+    pub base: usize,
+    pub position: usize,
+    pub lb_pair: usize,
+    pub owner: usize,
+    pub operator: usize,
+    pub operator_token_x: usize,
+    pub owner_token_x: usize,
+    pub system_program: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializePositionByOperator {
+    type ArrangedAccountIndices = InitializePositionByOperatorInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 11 {
+            return None;
+        }
+
+        Some(InitializePositionByOperatorInstructionAccountIndices {
+            payer: 0,
+            base: 1,
+            position: 2,
+            lb_pair: 3,
+            owner: 4,
+            operator: 5,
+            operator_token_x: 6,
+            owner_token_x: 7,
+            system_program: 8,
+            event_authority: 9,
+            program: 10,
+            remaining_accounts: 11..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializePositionByOperator {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "payer",
+            "base",
+            "position",
+            "lb_pair",
+            "owner",
+            "operator",
+            "operator_token_x",
+            "owner_token_x",
+            "system_program",
+            "event_authority",
+            "program",
+        ]
+    }
+}
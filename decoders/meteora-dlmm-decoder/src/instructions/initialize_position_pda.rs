@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -19,6 +20,7 @@ pub struct InitializePositionPdaInstructionAccounts {
     pub rent: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializePositionPda {
@@ -27,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializePositionPda {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [payer, base, position, lb_pair, owner, system_program, rent, event_authority, program, _remaining @ ..] =
+        let [payer, base, position, lb_pair, owner, system_program, rent, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -43,6 +45,101 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializePositionPda {
             rent: rent.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializePositionPdaInstructionAccountMetas {
+    pub payer: solana_instruction::AccountMeta,
+    pub base: solana_instruction::AccountMeta,
+    pub position: solana_instruction::AccountMeta,
+    pub lb_pair: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializePositionPda {
+    type ArrangedAccountMetas = InitializePositionPdaInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [payer, base, position, lb_pair, owner, system_program, rent, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializePositionPdaInstructionAccountMetas {
+            payer: payer.clone(),
+            base: base.clone(),
+            position: position.clone(),
+            lb_pair: lb_pair.clone(),
+            owner: owner.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializePositionPdaInstructionAccountIndices {
+    pub payer: usize,
+    pub base: usize,
+    pub position: usize,
+    pub lb_pair: usize,
+    pub owner: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializePositionPda {
+    type ArrangedAccountIndices = InitializePositionPdaInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 9 {
+            return None;
+        }
+
+        Some(InitializePositionPdaInstructionAccountIndices {
+            payer: 0,
+            base: 1,
+            position: 2,
+            lb_pair: 3,
+            owner: 4,
+            system_program: 5,
+            rent: 6,
+            event_authority: 7,
+            program: 8,
+            remaining_accounts: 9..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializePositionPda {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "payer",
+            "base",
+            "position",
+            "lb_pair",
+            "owner",
+            "system_program",
+            "rent",
+            "event_authority",
+            "program",
+        ]
+    }
+}
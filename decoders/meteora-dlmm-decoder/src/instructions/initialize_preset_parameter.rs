@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -16,6 +17,7 @@ pub struct InitializePresetParameterInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializePresetParameter {
@@ -24,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializePresetParameter {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [preset_parameter, admin, system_program, rent, _remaining @ ..] = accounts else {
+        let [preset_parameter, admin, system_program, rent, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -33,6 +35,69 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializePresetParameter {
             admin: admin.pubkey,
             system_program: system_program.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializePresetParameterInstructionAccountMetas {
+    pub preset_parameter: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializePresetParameter {
+    type ArrangedAccountMetas = InitializePresetParameterInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [preset_parameter, admin, system_program, rent, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(InitializePresetParameterInstructionAccountMetas {
+            preset_parameter: preset_parameter.clone(),
+            admin: admin.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializePresetParameterInstructionAccountIndices {
+    pub preset_parameter: usize,
+    pub admin: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializePresetParameter {
+    type ArrangedAccountIndices = InitializePresetParameterInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(InitializePresetParameterInstructionAccountIndices {
+            preset_parameter: 0,
+            admin: 1,
+            system_program: 2,
+            rent: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializePresetParameter {
+    fn account_names() -> &'static [&'static str] {
+        &["preset_parameter", "admin", "system_program", "rent"]
+    }
+}
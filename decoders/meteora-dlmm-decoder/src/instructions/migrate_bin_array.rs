@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -8,6 +9,7 @@ pub struct MigrateBinArray {}
 
 pub struct MigrateBinArrayInstructionAccounts {
     pub lb_pair: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for MigrateBinArray {
@@ -16,12 +18,63 @@ impl carbon_core::deserialize::ArrangeAccounts for MigrateBinArray {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [lb_pair, _remaining @ ..] = accounts else {
+        let [lb_pair, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(MigrateBinArrayInstructionAccounts {
             lb_pair: lb_pair.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct MigrateBinArrayInstructionAccountMetas {
+    pub lb_pair: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for MigrateBinArray {
+    type ArrangedAccountMetas = MigrateBinArrayInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [lb_pair, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(MigrateBinArrayInstructionAccountMetas {
+            lb_pair: lb_pair.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct MigrateBinArrayInstructionAccountIndices {
+    pub lb_pair: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for MigrateBinArray {
+    type ArrangedAccountIndices = MigrateBinArrayInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.is_empty() {
+            return None;
+        }
+
+        Some(MigrateBinArrayInstructionAccountIndices {
+            lb_pair: 0,
+            remaining_accounts: 1..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for MigrateBinArray {
+    fn account_names() -> &'static [&'static str] {
+        &["lb_pair"]
+    }
+}
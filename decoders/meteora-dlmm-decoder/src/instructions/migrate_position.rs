@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -17,6 +18,7 @@ pub struct MigratePositionInstructionAccounts {
     pub rent_receiver: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for MigratePosition {
@@ -25,7 +27,7 @@ impl carbon_core::deserialize::ArrangeAccounts for MigratePosition {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [position_v2, position_v1, lb_pair, bin_array_lower, bin_array_upper, owner, system_program, rent_receiver, event_authority, program] =
+        let [position_v2, position_v1, lb_pair, bin_array_lower, bin_array_upper, owner, system_program, rent_receiver, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -42,6 +44,106 @@ impl carbon_core::deserialize::ArrangeAccounts for MigratePosition {
             rent_receiver: rent_receiver.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct MigratePositionInstructionAccountMetas {
+    pub position_v2: solana_instruction::AccountMeta,
+    pub position_v1: solana_instruction::AccountMeta,
+    pub lb_pair: solana_instruction::AccountMeta,
+    pub bin_array_lower: solana_instruction::AccountMeta,
+    pub bin_array_upper: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent_receiver: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for MigratePosition {
+    type ArrangedAccountMetas = MigratePositionInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [position_v2, position_v1, lb_pair, bin_array_lower, bin_array_upper, owner, system_program, rent_receiver, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(MigratePositionInstructionAccountMetas {
+            position_v2: position_v2.clone(),
+            position_v1: position_v1.clone(),
+            lb_pair: lb_pair.clone(),
+            bin_array_lower: bin_array_lower.clone(),
+            bin_array_upper: bin_array_upper.clone(),
+            owner: owner.clone(),
+            system_program: system_program.clone(),
+            rent_receiver: rent_receiver.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct MigratePositionInstructionAccountIndices {
+    pub position_v2: usize,
+    pub position_v1: usize,
+    pub lb_pair: usize,
+    pub bin_array_lower: usize,
+    pub bin_array_upper: usize,
+    pub owner: usize,
+    pub system_program: usize,
+    pub rent_receiver: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for MigratePosition {
+    type ArrangedAccountIndices = MigratePositionInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 10 {
+            return None;
+        }
+
+        Some(MigratePositionInstructionAccountIndices {
+            position_v2: 0,
+            position_v1: 1,
+            lb_pair: 2,
+            bin_array_lower: 3,
+            bin_array_upper: 4,
+            owner: 5,
+            system_program: 6,
+            rent_receiver: 7,
+            event_authority: 8,
+            program: 9,
+            remaining_accounts: 10..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for MigratePosition {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "position_v2",
+            "position_v1",
+            "lb_pair",
+            "bin_array_lower",
+            "bin_array_upper",
+            "owner",
+            "system_program",
+            "rent_receiver",
+            "event_authority",
+            "program",
+        ]
+    }
+}
@@ -148,15 +148,25 @@ pub enum MeteoraDlmmInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for MeteoraDlmmDecoder {
     type InstructionType = MeteoraDlmmInstruction;
 
-    fn decode_instruction(
+    fn decoder_name(&self) -> &'static str {
+        "meteora_dlmm"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[super::PROGRAM_ID]
+    }
+
+    fn try_decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
-    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+    ) -> carbon_core::error::CarbonResult<
+        Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>>,
+    > {
         if !instruction.program_id.eq(&PROGRAM_ID) {
-            return None;
+            return Ok(None);
         }
 
-        carbon_core::try_decode_instructions!(instruction,
+        Ok(carbon_core::try_decode_instructions!(instruction,
             MeteoraDlmmInstruction::InitializeLbPair => initialize_lb_pair::InitializeLbPair,
             MeteoraDlmmInstruction::InitializePermissionLbPair => initialize_permission_lb_pair::InitializePermissionLbPair,
             MeteoraDlmmInstruction::InitializeCustomizablePermissionlessLbPair => initialize_customizable_permissionless_lb_pair::InitializeCustomizablePermissionlessLbPair,
@@ -218,6 +228,6 @@ impl carbon_core::instruction::InstructionDecoder<'_> for MeteoraDlmmDecoder {
             MeteoraDlmmInstruction::UpdatePositionOperatorEvent => update_position_operator_event::UpdatePositionOperatorEvent,
             MeteoraDlmmInstruction::UpdatePositionLockReleasePointEvent => update_position_lock_release_point_event::UpdatePositionLockReleasePointEvent,
             MeteoraDlmmInstruction::GoToABinEvent => go_to_a_bin_event::GoToABinEvent,
-        )
+        ))
     }
 }
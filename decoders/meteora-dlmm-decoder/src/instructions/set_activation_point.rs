@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +12,7 @@ pub struct SetActivationPoint {
 pub struct SetActivationPointInstructionAccounts {
     pub lb_pair: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SetActivationPoint {
@@ -19,13 +21,68 @@ impl carbon_core::deserialize::ArrangeAccounts for SetActivationPoint {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [lb_pair, admin, _remaining @ ..] = accounts else {
+        let [lb_pair, admin, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(SetActivationPointInstructionAccounts {
             lb_pair: lb_pair.pubkey,
             admin: admin.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SetActivationPointInstructionAccountMetas {
+    pub lb_pair: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SetActivationPoint {
+    type ArrangedAccountMetas = SetActivationPointInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [lb_pair, admin, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(SetActivationPointInstructionAccountMetas {
+            lb_pair: lb_pair.clone(),
+            admin: admin.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SetActivationPointInstructionAccountIndices {
+    pub lb_pair: usize,
+    pub admin: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SetActivationPoint {
+    type ArrangedAccountIndices = SetActivationPointInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(SetActivationPointInstructionAccountIndices {
+            lb_pair: 0,
+            admin: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SetActivationPoint {
+    fn account_names() -> &'static [&'static str] {
+        &["lb_pair", "admin"]
+    }
+}
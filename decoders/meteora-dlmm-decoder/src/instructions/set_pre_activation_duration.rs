@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +12,7 @@ pub struct SetPreActivationDuration {
 pub struct SetPreActivationDurationInstructionAccounts {
     pub lb_pair: solana_pubkey::Pubkey,
     pub creator: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SetPreActivationDuration {
@@ -19,13 +21,68 @@ impl carbon_core::deserialize::ArrangeAccounts for SetPreActivationDuration {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [lb_pair, creator, _remaining @ ..] = accounts else {
+        let [lb_pair, creator, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(SetPreActivationDurationInstructionAccounts {
             lb_pair: lb_pair.pubkey,
             creator: creator.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SetPreActivationDurationInstructionAccountMetas {
+    pub lb_pair: solana_instruction::AccountMeta,
+    pub creator: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SetPreActivationDuration {
+    type ArrangedAccountMetas = SetPreActivationDurationInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [lb_pair, creator, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(SetPreActivationDurationInstructionAccountMetas {
+            lb_pair: lb_pair.clone(),
+            creator: creator.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SetPreActivationDurationInstructionAccountIndices {
+    pub lb_pair: usize,
+    pub creator: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SetPreActivationDuration {
+    type ArrangedAccountIndices = SetPreActivationDurationInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(SetPreActivationDurationInstructionAccountIndices {
+            lb_pair: 0,
+            creator: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SetPreActivationDuration {
+    fn account_names() -> &'static [&'static str] {
+        &["lb_pair", "creator"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -61,3 +62,127 @@ impl carbon_core::deserialize::ArrangeAccounts for SwapWithPriceImpact {
         })
     }
 }
+
+pub struct SwapWithPriceImpactInstructionAccountMetas {
+    pub lb_pair: solana_instruction::AccountMeta,
+    pub bin_array_bitmap_extension: solana_instruction::AccountMeta,
+    pub reserve_x: solana_instruction::AccountMeta,
+    pub reserve_y: solana_instruction::AccountMeta,
+    pub user_token_in: solana_instruction::AccountMeta,
+    pub user_token_out: solana_instruction::AccountMeta,
+    pub token_x_mint: solana_instruction::AccountMeta,
+    pub token_y_mint: solana_instruction::AccountMeta,
+    pub oracle: solana_instruction::AccountMeta,
+    pub host_fee_in: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub token_x_program: solana_instruction::AccountMeta,
+    pub token_y_program: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SwapWithPriceImpact {
+    type ArrangedAccountMetas = SwapWithPriceImpactInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [lb_pair, bin_array_bitmap_extension, reserve_x, reserve_y, user_token_in, user_token_out, token_x_mint, token_y_mint, oracle, host_fee_in, user, token_x_program, token_y_program, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(SwapWithPriceImpactInstructionAccountMetas {
+            lb_pair: lb_pair.clone(),
+            bin_array_bitmap_extension: bin_array_bitmap_extension.clone(),
+            reserve_x: reserve_x.clone(),
+            reserve_y: reserve_y.clone(),
+            user_token_in: user_token_in.clone(),
+            user_token_out: user_token_out.clone(),
+            token_x_mint: token_x_mint.clone(),
+            token_y_mint: token_y_mint.clone(),
+            oracle: oracle.clone(),
+            host_fee_in: host_fee_in.clone(),
+            user: user.clone(),
+            token_x_program: token_x_program.clone(),
+            token_y_program: token_y_program.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SwapWithPriceImpactInstructionAccountIndices {
+    pub lb_pair: usize,
+    pub bin_array_bitmap_extension: usize,
+    pub reserve_x: usize,
+    pub reserve_y: usize,
+    pub user_token_in: usize,
+    pub user_token_out: usize,
+    pub token_x_mint: usize,
+    pub token_y_mint: usize,
+    pub oracle: usize,
+    pub host_fee_in: usize,
+    pub user: usize,
+    pub token_x_program: usize,
+    pub token_y_program: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SwapWithPriceImpact {
+    type ArrangedAccountIndices = SwapWithPriceImpactInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 15 {
+            return None;
+        }
+
+        Some(SwapWithPriceImpactInstructionAccountIndices {
+            lb_pair: 0,
+            bin_array_bitmap_extension: 1,
+            reserve_x: 2,
+            reserve_y: 3,
+            user_token_in: 4,
+            user_token_out: 5,
+            token_x_mint: 6,
+            token_y_mint: 7,
+            oracle: 8,
+            host_fee_in: 9,
+            user: 10,
+            token_x_program: 11,
+            token_y_program: 12,
+            event_authority: 13,
+            program: 14,
+            remaining_accounts: 15..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SwapWithPriceImpact {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "lb_pair",
+            "bin_array_bitmap_extension",
+            "reserve_x",
+            "reserve_y",
+            "user_token_in",
+            "user_token_out",
+            "token_x_mint",
+            "token_y_mint",
+            "oracle",
+            "host_fee_in",
+            "user",
+            "token_x_program",
+            "token_y_program",
+            "event_authority",
+            "program",
+        ]
+    }
+}
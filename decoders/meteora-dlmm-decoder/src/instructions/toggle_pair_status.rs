@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -9,6 +10,7 @@ pub struct TogglePairStatus {}
 pub struct TogglePairStatusInstructionAccounts {
     pub lb_pair: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for TogglePairStatus {
@@ -17,13 +19,68 @@ impl carbon_core::deserialize::ArrangeAccounts for TogglePairStatus {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [lb_pair, admin, _remaining @ ..] = accounts else {
+        let [lb_pair, admin, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(TogglePairStatusInstructionAccounts {
             lb_pair: lb_pair.pubkey,
             admin: admin.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct TogglePairStatusInstructionAccountMetas {
+    pub lb_pair: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for TogglePairStatus {
+    type ArrangedAccountMetas = TogglePairStatusInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [lb_pair, admin, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(TogglePairStatusInstructionAccountMetas {
+            lb_pair: lb_pair.clone(),
+            admin: admin.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct TogglePairStatusInstructionAccountIndices {
+    pub lb_pair: usize,
+    pub admin: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for TogglePairStatus {
+    type ArrangedAccountIndices = TogglePairStatusInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(TogglePairStatusInstructionAccountIndices {
+            lb_pair: 0,
+            admin: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for TogglePairStatus {
+    fn account_names() -> &'static [&'static str] {
+        &["lb_pair", "admin"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +13,7 @@ pub struct UpdateFeesAndRewardsInstructionAccounts {
     pub bin_array_lower: solana_pubkey::Pubkey,
     pub bin_array_upper: solana_pubkey::Pubkey,
     pub owner: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateFeesAndRewards {
@@ -20,8 +22,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateFeesAndRewards {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [position, lb_pair, bin_array_lower, bin_array_upper, owner, _remaining @ ..] =
-            accounts
+        let [position, lb_pair, bin_array_lower, bin_array_upper, owner, remaining @ ..] = accounts
         else {
             return None;
         };
@@ -32,6 +33,80 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateFeesAndRewards {
             bin_array_lower: bin_array_lower.pubkey,
             bin_array_upper: bin_array_upper.pubkey,
             owner: owner.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateFeesAndRewardsInstructionAccountMetas {
+    pub position: solana_instruction::AccountMeta,
+    pub lb_pair: solana_instruction::AccountMeta,
+    pub bin_array_lower: solana_instruction::AccountMeta,
+    pub bin_array_upper: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateFeesAndRewards {
+    type ArrangedAccountMetas = UpdateFeesAndRewardsInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [position, lb_pair, bin_array_lower, bin_array_upper, owner, remaining @ ..] = accounts
+        else {
+            return None;
+        };
+
+        Some(UpdateFeesAndRewardsInstructionAccountMetas {
+            position: position.clone(),
+            lb_pair: lb_pair.clone(),
+            bin_array_lower: bin_array_lower.clone(),
+            bin_array_upper: bin_array_upper.clone(),
+            owner: owner.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateFeesAndRewardsInstructionAccountIndices {
+    pub position: usize,
+    pub lb_pair: usize,
+    pub bin_array_lower: usize,
+    pub bin_array_upper: usize,
+    pub owner: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateFeesAndRewards {
+    type ArrangedAccountIndices = UpdateFeesAndRewardsInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(UpdateFeesAndRewardsInstructionAccountIndices {
+            position: 0,
+            lb_pair: 1,
+            bin_array_lower: 2,
+            bin_array_upper: 3,
+            owner: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateFeesAndRewards {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "position",
+            "lb_pair",
+            "bin_array_lower",
+            "bin_array_upper",
+            "owner",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -15,6 +16,7 @@ pub struct UpdateRewardDurationInstructionAccounts {
     pub bin_array: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateRewardDuration {
@@ -23,8 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateRewardDuration {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [lb_pair, admin, bin_array, event_authority, program, _remaining @ ..] = accounts
-        else {
+        let [lb_pair, admin, bin_array, event_authority, program, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -34,6 +35,79 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateRewardDuration {
             bin_array: bin_array.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateRewardDurationInstructionAccountMetas {
+    pub lb_pair: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub bin_array: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateRewardDuration {
+    type ArrangedAccountMetas = UpdateRewardDurationInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [lb_pair, admin, bin_array, event_authority, program, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateRewardDurationInstructionAccountMetas {
+            lb_pair: lb_pair.clone(),
+            admin: admin.clone(),
+            bin_array: bin_array.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateRewardDurationInstructionAccountIndices {
+    pub lb_pair: usize,
+    pub admin: usize,
+    pub bin_array: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateRewardDuration {
+    type ArrangedAccountIndices = UpdateRewardDurationInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(UpdateRewardDurationInstructionAccountIndices {
+            lb_pair: 0,
+            admin: 1,
+            bin_array: 2,
+            event_authority: 3,
+            program: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateRewardDuration {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "lb_pair",
+            "admin",
+            "bin_array",
+            "event_authority",
+            "program",
+        ]
+    }
+}
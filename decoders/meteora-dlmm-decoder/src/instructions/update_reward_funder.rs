@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct UpdateRewardFunderInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateRewardFunder {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateRewardFunder {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [lb_pair, admin, event_authority, program, _remaining @ ..] = accounts else {
+        let [lb_pair, admin, event_authority, program, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -31,6 +33,69 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateRewardFunder {
             admin: admin.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateRewardFunderInstructionAccountMetas {
+    pub lb_pair: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateRewardFunder {
+    type ArrangedAccountMetas = UpdateRewardFunderInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [lb_pair, admin, event_authority, program, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateRewardFunderInstructionAccountMetas {
+            lb_pair: lb_pair.clone(),
+            admin: admin.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateRewardFunderInstructionAccountIndices {
+    pub lb_pair: usize,
+    pub admin: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateRewardFunder {
+    type ArrangedAccountIndices = UpdateRewardFunderInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(UpdateRewardFunderInstructionAccountIndices {
+            lb_pair: 0,
+            admin: 1,
+            event_authority: 2,
+            program: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateRewardFunder {
+    fn account_names() -> &'static [&'static str] {
+        &["lb_pair", "admin", "event_authority", "program"]
+    }
+}
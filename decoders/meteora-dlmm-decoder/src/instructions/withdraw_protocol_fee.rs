@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -19,6 +20,7 @@ pub struct WithdrawProtocolFeeInstructionAccounts {
     pub receiver_token_y: solana_pubkey::Pubkey,
     pub token_x_program: solana_pubkey::Pubkey,
     pub token_y_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for WithdrawProtocolFee {
@@ -27,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawProtocolFee {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [lb_pair, reserve_x, reserve_y, token_x_mint, token_y_mint, receiver_token_x, receiver_token_y, token_x_program, token_y_program, _remaining @ ..] =
+        let [lb_pair, reserve_x, reserve_y, token_x_mint, token_y_mint, receiver_token_x, receiver_token_y, token_x_program, token_y_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -43,6 +45,101 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawProtocolFee {
             receiver_token_y: receiver_token_y.pubkey,
             token_x_program: token_x_program.pubkey,
             token_y_program: token_y_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct WithdrawProtocolFeeInstructionAccountMetas {
+    pub lb_pair: solana_instruction::AccountMeta,
+    pub reserve_x: solana_instruction::AccountMeta,
+    pub reserve_y: solana_instruction::AccountMeta,
+    pub token_x_mint: solana_instruction::AccountMeta,
+    pub token_y_mint: solana_instruction::AccountMeta,
+    pub receiver_token_x: solana_instruction::AccountMeta,
+    pub receiver_token_y: solana_instruction::AccountMeta,
+    pub token_x_program: solana_instruction::AccountMeta,
+    pub token_y_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for WithdrawProtocolFee {
+    type ArrangedAccountMetas = WithdrawProtocolFeeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [lb_pair, reserve_x, reserve_y, token_x_mint, token_y_mint, receiver_token_x, receiver_token_y, token_x_program, token_y_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(WithdrawProtocolFeeInstructionAccountMetas {
+            lb_pair: lb_pair.clone(),
+            reserve_x: reserve_x.clone(),
+            reserve_y: reserve_y.clone(),
+            token_x_mint: token_x_mint.clone(),
+            token_y_mint: token_y_mint.clone(),
+            receiver_token_x: receiver_token_x.clone(),
+            receiver_token_y: receiver_token_y.clone(),
+            token_x_program: token_x_program.clone(),
+            token_y_program: token_y_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct WithdrawProtocolFeeInstructionAccountIndices {
+    pub lb_pair: usize,
+    pub reserve_x: usize,
+    pub reserve_y: usize,
+    pub token_x_mint: usize,
+    pub token_y_mint: usize,
+    pub receiver_token_x: usize,
+    pub receiver_token_y: usize,
+    pub token_x_program: usize,
+    pub token_y_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for WithdrawProtocolFee {
+    type ArrangedAccountIndices = WithdrawProtocolFeeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 9 {
+            return None;
+        }
+
+        Some(WithdrawProtocolFeeInstructionAccountIndices {
+            lb_pair: 0,
+            reserve_x: 1,
+            reserve_y: 2,
+            token_x_mint: 3,
+            token_y_mint: 4,
+            receiver_token_x: 5,
+            receiver_token_y: 6,
+            token_x_program: 7,
+            token_y_program: 8,
+            remaining_accounts: 9..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for WithdrawProtocolFee {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "lb_pair",
+            "reserve_x",
+            "reserve_y",
+            "token_x_mint",
+            "token_y_mint",
+            "receiver_token_x",
+            "receiver_token_y",
+            "token_x_program",
+            "token_y_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -26,6 +27,7 @@ pub struct BootstrapLiquidityInstructionAccounts {
     pub user: solana_pubkey::Pubkey,
     pub vault_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for BootstrapLiquidity {
@@ -34,7 +36,7 @@ impl carbon_core::deserialize::ArrangeAccounts for BootstrapLiquidity {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [pool, lp_mint, user_pool_lp, a_vault_lp, b_vault_lp, a_vault, b_vault, a_vault_lp_mint, b_vault_lp_mint, a_token_vault, b_token_vault, user_a_token, user_b_token, user, vault_program, token_program, _remaining @ ..] =
+        let [pool, lp_mint, user_pool_lp, a_vault_lp, b_vault_lp, a_vault, b_vault, a_vault_lp_mint, b_vault_lp_mint, a_token_vault, b_token_vault, user_a_token, user_b_token, user, vault_program, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -57,6 +59,136 @@ impl carbon_core::deserialize::ArrangeAccounts for BootstrapLiquidity {
             user: user.pubkey,
             vault_program: vault_program.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct BootstrapLiquidityInstructionAccountMetas {
+    pub pool: solana_instruction::AccountMeta,
+    pub lp_mint: solana_instruction::AccountMeta,
+    pub user_pool_lp: solana_instruction::AccountMeta,
+    pub a_vault_lp: solana_instruction::AccountMeta,
+    pub b_vault_lp: solana_instruction::AccountMeta,
+    pub a_vault: solana_instruction::AccountMeta,
+    pub b_vault: solana_instruction::AccountMeta,
+    pub a_vault_lp_mint: solana_instruction::AccountMeta,
+    pub b_vault_lp_mint: solana_instruction::AccountMeta,
+    pub a_token_vault: solana_instruction::AccountMeta,
+    pub b_token_vault: solana_instruction::AccountMeta,
+    pub user_a_token: solana_instruction::AccountMeta,
+    pub user_b_token: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub vault_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for BootstrapLiquidity {
+    type ArrangedAccountMetas = BootstrapLiquidityInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [pool, lp_mint, user_pool_lp, a_vault_lp, b_vault_lp, a_vault, b_vault, a_vault_lp_mint, b_vault_lp_mint, a_token_vault, b_token_vault, user_a_token, user_b_token, user, vault_program, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(BootstrapLiquidityInstructionAccountMetas {
+            pool: pool.clone(),
+            lp_mint: lp_mint.clone(),
+            user_pool_lp: user_pool_lp.clone(),
+            a_vault_lp: a_vault_lp.clone(),
+            b_vault_lp: b_vault_lp.clone(),
+            a_vault: a_vault.clone(),
+            b_vault: b_vault.clone(),
+            a_vault_lp_mint: a_vault_lp_mint.clone(),
+            b_vault_lp_mint: b_vault_lp_mint.clone(),
+            a_token_vault: a_token_vault.clone(),
+            b_token_vault: b_token_vault.clone(),
+            user_a_token: user_a_token.clone(),
+            user_b_token: user_b_token.clone(),
+            user: user.clone(),
+            vault_program: vault_program.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct BootstrapLiquidityInstructionAccountIndices {
+    pub pool: usize,
+    pub lp_mint: usize,
+    pub user_pool_lp: usize,
+    pub a_vault_lp: usize,
+    pub b_vault_lp: usize,
+    pub a_vault: usize,
+    pub b_vault: usize,
+    pub a_vault_lp_mint: usize,
+    pub b_vault_lp_mint: usize,
+    pub a_token_vault: usize,
+    pub b_token_vault: usize,
+    pub user_a_token: usize,
+    pub user_b_token: usize,
+    pub user: usize,
+    pub vault_program: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for BootstrapLiquidity {
+    type ArrangedAccountIndices = BootstrapLiquidityInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 16 {
+            return None;
+        }
+
+        Some(BootstrapLiquidityInstructionAccountIndices {
+            pool: 0,
+            lp_mint: 1,
+            user_pool_lp: 2,
+            a_vault_lp: 3,
+            b_vault_lp: 4,
+            a_vault: 5,
+            b_vault: 6,
+            a_vault_lp_mint: 7,
+            b_vault_lp_mint: 8,
+            a_token_vault: 9,
+            b_token_vault: 10,
+            user_a_token: 11,
+            user_b_token: 12,
+            user: 13,
+            vault_program: 14,
+            token_program: 15,
+            remaining_accounts: 16..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for BootstrapLiquidity {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "pool",
+            "lp_mint",
+            "user_pool_lp",
+            "a_vault_lp",
+            "b_vault_lp",
+            "a_vault",
+            "b_vault",
+            "a_vault_lp_mint",
+            "b_vault_lp_mint",
+            "a_token_vault",
+            "b_token_vault",
+            "user_a_token",
+            "user_b_token",
+            "user",
+            "vault_program",
+            "token_program",
+        ]
+    }
+}
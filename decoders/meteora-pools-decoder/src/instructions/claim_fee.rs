@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -27,6 +28,7 @@ pub struct ClaimFeeInstructionAccounts {
     pub user_a_token: solana_pubkey::Pubkey,
     pub user_b_token: solana_pubkey::Pubkey,
     pub vault_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ClaimFee {
@@ -35,7 +37,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ClaimFee {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [pool, lp_mint, lock_escrow, owner, source_tokens, escrow_vault, token_program, a_token_vault, b_token_vault, a_vault, b_vault, a_vault_lp, b_vault_lp, a_vault_lp_mint, b_vault_lp_mint, user_a_token, user_b_token, vault_program, _remaining @ ..] =
+        let [pool, lp_mint, lock_escrow, owner, source_tokens, escrow_vault, token_program, a_token_vault, b_token_vault, a_vault, b_vault, a_vault_lp, b_vault_lp, a_vault_lp_mint, b_vault_lp_mint, user_a_token, user_b_token, vault_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -60,6 +62,146 @@ impl carbon_core::deserialize::ArrangeAccounts for ClaimFee {
             user_a_token: user_a_token.pubkey,
             user_b_token: user_b_token.pubkey,
             vault_program: vault_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ClaimFeeInstructionAccountMetas {
+    pub pool: solana_instruction::AccountMeta,
+    pub lp_mint: solana_instruction::AccountMeta,
+    pub lock_escrow: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub source_tokens: solana_instruction::AccountMeta,
+    pub escrow_vault: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub a_token_vault: solana_instruction::AccountMeta,
+    pub b_token_vault: solana_instruction::AccountMeta,
+    pub a_vault: solana_instruction::AccountMeta,
+    pub b_vault: solana_instruction::AccountMeta,
+    pub a_vault_lp: solana_instruction::AccountMeta,
+    pub b_vault_lp: solana_instruction::AccountMeta,
+    pub a_vault_lp_mint: solana_instruction::AccountMeta,
+    pub b_vault_lp_mint: solana_instruction::AccountMeta,
+    pub user_a_token: solana_instruction::AccountMeta,
+    pub user_b_token: solana_instruction::AccountMeta,
+    pub vault_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ClaimFee {
+    type ArrangedAccountMetas = ClaimFeeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [pool, lp_mint, lock_escrow, owner, source_tokens, escrow_vault, token_program, a_token_vault, b_token_vault, a_vault, b_vault, a_vault_lp, b_vault_lp, a_vault_lp_mint, b_vault_lp_mint, user_a_token, user_b_token, vault_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(ClaimFeeInstructionAccountMetas {
+            pool: pool.clone(),
+            lp_mint: lp_mint.clone(),
+            lock_escrow: lock_escrow.clone(),
+            owner: owner.clone(),
+            source_tokens: source_tokens.clone(),
+            escrow_vault: escrow_vault.clone(),
+            token_program: token_program.clone(),
+            a_token_vault: a_token_vault.clone(),
+            b_token_vault: b_token_vault.clone(),
+            a_vault: a_vault.clone(),
+            b_vault: b_vault.clone(),
+            a_vault_lp: a_vault_lp.clone(),
+            b_vault_lp: b_vault_lp.clone(),
+            a_vault_lp_mint: a_vault_lp_mint.clone(),
+            b_vault_lp_mint: b_vault_lp_mint.clone(),
+            user_a_token: user_a_token.clone(),
+            user_b_token: user_b_token.clone(),
+            vault_program: vault_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ClaimFeeInstructionAccountIndices {
+    pub pool: usize,
+    pub lp_mint: usize,
+    pub lock_escrow: usize,
+    pub owner: usize,
+    pub source_tokens: usize,
+    pub escrow_vault: usize,
+    pub token_program: usize,
+    pub a_token_vault: usize,
+    pub b_token_vault: usize,
+    pub a_vault: usize,
+    pub b_vault: usize,
+    pub a_vault_lp: usize,
+    pub b_vault_lp: usize,
+    pub a_vault_lp_mint: usize,
+    pub b_vault_lp_mint: usize,
+    pub user_a_token: usize,
+    pub user_b_token: usize,
+    pub vault_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ClaimFee {
+    type ArrangedAccountIndices = ClaimFeeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 18 {
+            return None;
+        }
+
+        Some(ClaimFeeInstructionAccountIndices {
+            pool: 0,
+            lp_mint: 1,
+            lock_escrow: 2,
+            owner: 3,
+            source_tokens: 4,
+            escrow_vault: 5,
+            token_program: 6,
+            a_token_vault: 7,
+            b_token_vault: 8,
+            a_vault: 9,
+            b_vault: 10,
+            a_vault_lp: 11,
+            b_vault_lp: 12,
+            a_vault_lp_mint: 13,
+            b_vault_lp_mint: 14,
+            user_a_token: 15,
+            user_b_token: 16,
+            vault_program: 17,
+            remaining_accounts: 18..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ClaimFee {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "pool",
+            "lp_mint",
+            "lock_escrow",
+            "owner",
+            "source_tokens",
+            "escrow_vault",
+            "token_program",
+            "a_token_vault",
+            "b_token_vault",
+            "a_vault",
+            "b_vault",
+            "a_vault_lp",
+            "b_vault_lp",
+            "a_vault_lp_mint",
+            "b_vault_lp_mint",
+            "user_a_token",
+            "user_b_token",
+            "vault_program",
+        ]
+    }
+}
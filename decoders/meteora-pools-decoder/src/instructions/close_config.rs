@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -10,6 +11,7 @@ pub struct CloseConfigInstructionAccounts {
     pub config: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
     pub rent_receiver: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CloseConfig {
@@ -18,7 +20,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CloseConfig {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [config, admin, rent_receiver, _remaining @ ..] = accounts else {
+        let [config, admin, rent_receiver, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -26,6 +28,65 @@ impl carbon_core::deserialize::ArrangeAccounts for CloseConfig {
             config: config.pubkey,
             admin: admin.pubkey,
             rent_receiver: rent_receiver.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CloseConfigInstructionAccountMetas {
+    pub config: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub rent_receiver: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CloseConfig {
+    type ArrangedAccountMetas = CloseConfigInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [config, admin, rent_receiver, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(CloseConfigInstructionAccountMetas {
+            config: config.clone(),
+            admin: admin.clone(),
+            rent_receiver: rent_receiver.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CloseConfigInstructionAccountIndices {
+    pub config: usize,
+    pub admin: usize,
+    pub rent_receiver: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CloseConfig {
+    type ArrangedAccountIndices = CloseConfigInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(CloseConfigInstructionAccountIndices {
+            config: 0,
+            admin: 1,
+            rent_receiver: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CloseConfig {
+    fn account_names() -> &'static [&'static str] {
+        &["config", "admin", "rent_receiver"]
+    }
+}
@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct CreateConfigInstructionAccounts {
     pub config: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CreateConfig {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateConfig {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [config, admin, system_program, _remaining @ ..] = accounts else {
+        let [config, admin, system_program, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -30,6 +32,65 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateConfig {
             config: config.pubkey,
             admin: admin.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CreateConfigInstructionAccountMetas {
+    pub config: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CreateConfig {
+    type ArrangedAccountMetas = CreateConfigInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [config, admin, system_program, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(CreateConfigInstructionAccountMetas {
+            config: config.clone(),
+            admin: admin.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CreateConfigInstructionAccountIndices {
+    pub config: usize,
+    pub admin: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CreateConfig {
+    type ArrangedAccountIndices = CreateConfigInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(CreateConfigInstructionAccountIndices {
+            config: 0,
+            admin: 1,
+            system_program: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CreateConfig {
+    fn account_names() -> &'static [&'static str] {
+        &["config", "admin", "system_program"]
+    }
+}
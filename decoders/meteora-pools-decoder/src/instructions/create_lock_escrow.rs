@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +14,7 @@ pub struct CreateLockEscrowInstructionAccounts {
     pub lp_mint: solana_pubkey::Pubkey,
     pub payer: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CreateLockEscrow {
@@ -21,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateLockEscrow {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [pool, lock_escrow, owner, lp_mint, payer, system_program, _remaining @ ..] = accounts
+        let [pool, lock_escrow, owner, lp_mint, payer, system_program, remaining @ ..] = accounts
         else {
             return None;
         };
@@ -33,6 +35,85 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateLockEscrow {
             lp_mint: lp_mint.pubkey,
             payer: payer.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CreateLockEscrowInstructionAccountMetas {
+    pub pool: solana_instruction::AccountMeta,
+    pub lock_escrow: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub lp_mint: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CreateLockEscrow {
+    type ArrangedAccountMetas = CreateLockEscrowInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [pool, lock_escrow, owner, lp_mint, payer, system_program, remaining @ ..] = accounts
+        else {
+            return None;
+        };
+
+        Some(CreateLockEscrowInstructionAccountMetas {
+            pool: pool.clone(),
+            lock_escrow: lock_escrow.clone(),
+            owner: owner.clone(),
+            lp_mint: lp_mint.clone(),
+            payer: payer.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CreateLockEscrowInstructionAccountIndices {
+    pub pool: usize,
+    pub lock_escrow: usize,
+    pub owner: usize,
+    pub lp_mint: usize,
+    pub payer: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CreateLockEscrow {
+    type ArrangedAccountIndices = CreateLockEscrowInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(CreateLockEscrowInstructionAccountIndices {
+            pool: 0,
+            lock_escrow: 1,
+            owner: 2,
+            lp_mint: 3,
+            payer: 4,
+            system_program: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CreateLockEscrow {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "pool",
+            "lock_escrow",
+            "owner",
+            "lp_mint",
+            "payer",
+            "system_program",
+        ]
+    }
+}
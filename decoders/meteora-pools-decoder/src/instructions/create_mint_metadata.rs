@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct CreateMintMetadataInstructionAccounts {
     pub metadata_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub payer: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CreateMintMetadata {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateMintMetadata {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [pool, lp_mint, a_vault_lp, mint_metadata, metadata_program, system_program, payer, _remaining @ ..] =
+        let [pool, lp_mint, a_vault_lp, mint_metadata, metadata_program, system_program, payer, remaining @ ..] =
             accounts
         else {
             return None;
@@ -36,6 +38,91 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateMintMetadata {
             metadata_program: metadata_program.pubkey,
             system_program: system_program.pubkey,
             payer: payer.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CreateMintMetadataInstructionAccountMetas {
+    pub pool: solana_instruction::AccountMeta,
+    pub lp_mint: solana_instruction::AccountMeta,
+    pub a_vault_lp: solana_instruction::AccountMeta,
+    pub mint_metadata: solana_instruction::AccountMeta,
+    pub metadata_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CreateMintMetadata {
+    type ArrangedAccountMetas = CreateMintMetadataInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [pool, lp_mint, a_vault_lp, mint_metadata, metadata_program, system_program, payer, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CreateMintMetadataInstructionAccountMetas {
+            pool: pool.clone(),
+            lp_mint: lp_mint.clone(),
+            a_vault_lp: a_vault_lp.clone(),
+            mint_metadata: mint_metadata.clone(),
+            metadata_program: metadata_program.clone(),
+            system_program: system_program.clone(),
+            payer: payer.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CreateMintMetadataInstructionAccountIndices {
+    pub pool: usize,
+    pub lp_mint: usize,
+    pub a_vault_lp: usize,
+    pub mint_metadata: usize,
+    pub metadata_program: usize,
+    pub system_program: usize,
+    pub payer: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CreateMintMetadata {
+    type ArrangedAccountIndices = CreateMintMetadataInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 7 {
+            return None;
+        }
+
+        Some(CreateMintMetadataInstructionAccountIndices {
+            pool: 0,
+            lp_mint: 1,
+            a_vault_lp: 2,
+            mint_metadata: 3,
+            metadata_program: 4,
+            system_program: 5,
+            payer: 6,
+            remaining_accounts: 7..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CreateMintMetadata {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "pool",
+            "lp_mint",
+            "a_vault_lp",
+            "mint_metadata",
+            "metadata_program",
+            "system_program",
+            "payer",
+        ]
+    }
+}
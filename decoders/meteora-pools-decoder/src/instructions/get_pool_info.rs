@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -15,6 +16,7 @@ pub struct GetPoolInfoInstructionAccounts {
     pub b_vault: solana_pubkey::Pubkey,
     pub a_vault_lp_mint: solana_pubkey::Pubkey,
     pub b_vault_lp_mint: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for GetPoolInfo {
@@ -23,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for GetPoolInfo {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [pool, lp_mint, a_vault_lp, b_vault_lp, a_vault, b_vault, a_vault_lp_mint, b_vault_lp_mint, _remaining @ ..] =
+        let [pool, lp_mint, a_vault_lp, b_vault_lp, a_vault, b_vault, a_vault_lp_mint, b_vault_lp_mint, remaining @ ..] =
             accounts
         else {
             return None;
@@ -38,6 +40,96 @@ impl carbon_core::deserialize::ArrangeAccounts for GetPoolInfo {
             b_vault: b_vault.pubkey,
             a_vault_lp_mint: a_vault_lp_mint.pubkey,
             b_vault_lp_mint: b_vault_lp_mint.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct GetPoolInfoInstructionAccountMetas {
+    pub pool: solana_instruction::AccountMeta,
+    pub lp_mint: solana_instruction::AccountMeta,
+    pub a_vault_lp: solana_instruction::AccountMeta,
+    pub b_vault_lp: solana_instruction::AccountMeta,
+    pub a_vault: solana_instruction::AccountMeta,
+    pub b_vault: solana_instruction::AccountMeta,
+    pub a_vault_lp_mint: solana_instruction::AccountMeta,
+    pub b_vault_lp_mint: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for GetPoolInfo {
+    type ArrangedAccountMetas = GetPoolInfoInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [pool, lp_mint, a_vault_lp, b_vault_lp, a_vault, b_vault, a_vault_lp_mint, b_vault_lp_mint, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(GetPoolInfoInstructionAccountMetas {
+            pool: pool.clone(),
+            lp_mint: lp_mint.clone(),
+            a_vault_lp: a_vault_lp.clone(),
+            b_vault_lp: b_vault_lp.clone(),
+            a_vault: a_vault.clone(),
+            b_vault: b_vault.clone(),
+            a_vault_lp_mint: a_vault_lp_mint.clone(),
+            b_vault_lp_mint: b_vault_lp_mint.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct GetPoolInfoInstructionAccountIndices {
+    pub pool: usize,
+    pub lp_mint: usize,
+    pub a_vault_lp: usize,
+    pub b_vault_lp: usize,
+    pub a_vault: usize,
+    pub b_vault: usize,
+    pub a_vault_lp_mint: usize,
+    pub b_vault_lp_mint: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for GetPoolInfo {
+    type ArrangedAccountIndices = GetPoolInfoInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(GetPoolInfoInstructionAccountIndices {
+            pool: 0,
+            lp_mint: 1,
+            a_vault_lp: 2,
+            b_vault_lp: 3,
+            a_vault: 4,
+            b_vault: 5,
+            a_vault_lp_mint: 6,
+            b_vault_lp_mint: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for GetPoolInfo {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "pool",
+            "lp_mint",
+            "a_vault_lp",
+            "b_vault_lp",
+            "a_vault",
+            "b_vault",
+            "a_vault_lp_mint",
+            "b_vault_lp_mint",
+        ]
+    }
+}
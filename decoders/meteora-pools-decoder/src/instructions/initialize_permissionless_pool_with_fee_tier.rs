@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -40,6 +41,7 @@ pub struct InitializePermissionlessPoolWithFeeTierInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub associated_token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializePermissionlessPoolWithFeeTier {
@@ -48,7 +50,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializePermissionlessPoolW
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [pool, lp_mint, token_a_mint, token_b_mint, a_vault, b_vault, a_token_vault, b_token_vault, a_vault_lp_mint, b_vault_lp_mint, a_vault_lp, b_vault_lp, payer_token_a, payer_token_b, payer_pool_lp, protocol_token_a_fee, protocol_token_b_fee, payer, fee_owner, rent, mint_metadata, metadata_program, vault_program, token_program, associated_token_program, system_program, _remaining @ ..] =
+        let [pool, lp_mint, token_a_mint, token_b_mint, a_vault, b_vault, a_token_vault, b_token_vault, a_vault_lp_mint, b_vault_lp_mint, a_vault_lp, b_vault_lp, payer_token_a, payer_token_b, payer_pool_lp, protocol_token_a_fee, protocol_token_b_fee, payer, fee_owner, rent, mint_metadata, metadata_program, vault_program, token_program, associated_token_program, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -81,6 +83,190 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializePermissionlessPoolW
             token_program: token_program.pubkey,
             associated_token_program: associated_token_program.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializePermissionlessPoolWithFeeTierInstructionAccountMetas {
+    pub pool: solana_instruction::AccountMeta,
+    pub lp_mint: solana_instruction::AccountMeta,
+    pub token_a_mint: solana_instruction::AccountMeta,
+    pub token_b_mint: solana_instruction::AccountMeta,
+    pub a_vault: solana_instruction::AccountMeta,
+    pub b_vault: solana_instruction::AccountMeta,
+    pub a_token_vault: solana_instruction::AccountMeta,
+    pub b_token_vault: solana_instruction::AccountMeta,
+    pub a_vault_lp_mint: solana_instruction::AccountMeta,
+    pub b_vault_lp_mint: solana_instruction::AccountMeta,
+    pub a_vault_lp: solana_instruction::AccountMeta,
+    pub b_vault_lp: solana_instruction::AccountMeta,
+    pub payer_token_a: solana_instruction::AccountMeta,
+    pub payer_token_b: solana_instruction::AccountMeta,
+    pub payer_pool_lp: solana_instruction::AccountMeta,
+    pub protocol_token_a_fee: solana_instruction::AccountMeta,
+    pub protocol_token_b_fee: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub fee_owner: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub mint_metadata: solana_instruction::AccountMeta,
+    pub metadata_program: solana_instruction::AccountMeta,
+    pub vault_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializePermissionlessPoolWithFeeTier {
+    type ArrangedAccountMetas = InitializePermissionlessPoolWithFeeTierInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [pool, lp_mint, token_a_mint, token_b_mint, a_vault, b_vault, a_token_vault, b_token_vault, a_vault_lp_mint, b_vault_lp_mint, a_vault_lp, b_vault_lp, payer_token_a, payer_token_b, payer_pool_lp, protocol_token_a_fee, protocol_token_b_fee, payer, fee_owner, rent, mint_metadata, metadata_program, vault_program, token_program, associated_token_program, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(
+            InitializePermissionlessPoolWithFeeTierInstructionAccountMetas {
+                pool: pool.clone(),
+                lp_mint: lp_mint.clone(),
+                token_a_mint: token_a_mint.clone(),
+                token_b_mint: token_b_mint.clone(),
+                a_vault: a_vault.clone(),
+                b_vault: b_vault.clone(),
+                a_token_vault: a_token_vault.clone(),
+                b_token_vault: b_token_vault.clone(),
+                a_vault_lp_mint: a_vault_lp_mint.clone(),
+                b_vault_lp_mint: b_vault_lp_mint.clone(),
+                a_vault_lp: a_vault_lp.clone(),
+                b_vault_lp: b_vault_lp.clone(),
+                payer_token_a: payer_token_a.clone(),
+                payer_token_b: payer_token_b.clone(),
+                payer_pool_lp: payer_pool_lp.clone(),
+                protocol_token_a_fee: protocol_token_a_fee.clone(),
+                protocol_token_b_fee: protocol_token_b_fee.clone(),
+                payer: payer.clone(),
+                fee_owner: fee_owner.clone(),
+                rent: rent.clone(),
+                mint_metadata: mint_metadata.clone(),
+                metadata_program: metadata_program.clone(),
+                vault_program: vault_program.clone(),
+                token_program: token_program.clone(),
+                associated_token_program: associated_token_program.clone(),
+                system_program: system_program.clone(),
+                remaining_accounts: remaining.to_vec(),
+            },
+        )
+    }
+}
+
+pub struct InitializePermissionlessPoolWithFeeTierInstructionAccountIndices {
+    pub pool: usize,
+    pub lp_mint: usize,
+    pub token_a_mint: usize,
+    pub token_b_mint: usize,
+    pub a_vault: usize,
+    pub b_vault: usize,
+    pub a_token_vault: usize,
+    pub b_token_vault: usize,
+    pub a_vault_lp_mint: usize,
+    pub b_vault_lp_mint: usize,
+    pub a_vault_lp: usize,
+    pub b_vault_lp: usize,
+    pub payer_token_a: usize,
+    pub payer_token_b: usize,
+    pub payer_pool_lp: usize,
+    pub protocol_token_a_fee: usize,
+    pub protocol_token_b_fee: usize,
+    pub payer: usize,
+    pub fee_owner: usize,
+    pub rent: usize,
+    pub mint_metadata: usize,
+    pub metadata_program: usize,
+    pub vault_program: usize,
+    pub token_program: usize,
+    pub associated_token_program: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializePermissionlessPoolWithFeeTier {
+    type ArrangedAccountIndices = InitializePermissionlessPoolWithFeeTierInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 26 {
+            return None;
+        }
+
+        Some(
+            InitializePermissionlessPoolWithFeeTierInstructionAccountIndices {
+                pool: 0,
+                lp_mint: 1,
+                token_a_mint: 2,
+                token_b_mint: 3,
+                a_vault: 4,
+                b_vault: 5,
+                a_token_vault: 6,
+                b_token_vault: 7,
+                a_vault_lp_mint: 8,
+                b_vault_lp_mint: 9,
+                a_vault_lp: 10,
+                b_vault_lp: 11,
+                payer_token_a: 12,
+                payer_token_b: 13,
+                payer_pool_lp: 14,
+                protocol_token_a_fee: 15,
+                protocol_token_b_fee: 16,
+                payer: 17,
+                fee_owner: 18,
+                rent: 19,
+                mint_metadata: 20,
+                metadata_program: 21,
+                vault_program: 22,
+                token_program: 23,
+                associated_token_program: 24,
+                system_program: 25,
+                remaining_accounts: 26..accounts.len(),
+            },
+        )
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializePermissionlessPoolWithFeeTier {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "pool",
+            "lp_mint",
+            "token_a_mint",
+            "token_b_mint",
+            "a_vault",
+            "b_vault",
+            "a_token_vault",
+            "b_token_vault",
+            "a_vault_lp_mint",
+            "b_vault_lp_mint",
+            "a_vault_lp",
+            "b_vault_lp",
+            "payer_token_a",
+            "payer_token_b",
+            "payer_pool_lp",
+            "protocol_token_a_fee",
+            "protocol_token_b_fee",
+            "payer",
+            "fee_owner",
+            "rent",
+            "mint_metadata",
+            "metadata_program",
+            "vault_program",
+            "token_program",
+            "associated_token_program",
+            "system_program",
+        ]
+    }
+}
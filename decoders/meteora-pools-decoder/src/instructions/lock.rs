@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -22,6 +23,7 @@ pub struct LockInstructionAccounts {
     pub b_vault_lp: solana_pubkey::Pubkey,
     pub a_vault_lp_mint: solana_pubkey::Pubkey,
     pub b_vault_lp_mint: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Lock {
@@ -30,7 +32,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Lock {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [pool, lp_mint, lock_escrow, owner, source_tokens, escrow_vault, token_program, a_vault, b_vault, a_vault_lp, b_vault_lp, a_vault_lp_mint, b_vault_lp_mint, _remaining @ ..] =
+        let [pool, lp_mint, lock_escrow, owner, source_tokens, escrow_vault, token_program, a_vault, b_vault, a_vault_lp, b_vault_lp, a_vault_lp_mint, b_vault_lp_mint, remaining @ ..] =
             accounts
         else {
             return None;
@@ -50,6 +52,121 @@ impl carbon_core::deserialize::ArrangeAccounts for Lock {
             b_vault_lp: b_vault_lp.pubkey,
             a_vault_lp_mint: a_vault_lp_mint.pubkey,
             b_vault_lp_mint: b_vault_lp_mint.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct LockInstructionAccountMetas {
+    pub pool: solana_instruction::AccountMeta,
+    pub lp_mint: solana_instruction::AccountMeta,
+    pub lock_escrow: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub source_tokens: solana_instruction::AccountMeta,
+    pub escrow_vault: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub a_vault: solana_instruction::AccountMeta,
+    pub b_vault: solana_instruction::AccountMeta,
+    pub a_vault_lp: solana_instruction::AccountMeta,
+    pub b_vault_lp: solana_instruction::AccountMeta,
+    pub a_vault_lp_mint: solana_instruction::AccountMeta,
+    pub b_vault_lp_mint: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Lock {
+    type ArrangedAccountMetas = LockInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [pool, lp_mint, lock_escrow, owner, source_tokens, escrow_vault, token_program, a_vault, b_vault, a_vault_lp, b_vault_lp, a_vault_lp_mint, b_vault_lp_mint, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(LockInstructionAccountMetas {
+            pool: pool.clone(),
+            lp_mint: lp_mint.clone(),
+            lock_escrow: lock_escrow.clone(),
+            owner: owner.clone(),
+            source_tokens: source_tokens.clone(),
+            escrow_vault: escrow_vault.clone(),
+            token_program: token_program.clone(),
+            a_vault: a_vault.clone(),
+            b_vault: b_vault.clone(),
+            a_vault_lp: a_vault_lp.clone(),
+            b_vault_lp: b_vault_lp.clone(),
+            a_vault_lp_mint: a_vault_lp_mint.clone(),
+            b_vault_lp_mint: b_vault_lp_mint.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct LockInstructionAccountIndices {
+    pub pool: usize,
+    pub lp_mint: usize,
+    pub lock_escrow: usize,
+    pub owner: usize,
+    pub source_tokens: usize,
+    pub escrow_vault: usize,
+    pub token_program: usize,
+    pub a_vault: usize,
+    pub b_vault: usize,
+    pub a_vault_lp: usize,
+    pub b_vault_lp: usize,
+    pub a_vault_lp_mint: usize,
+    pub b_vault_lp_mint: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Lock {
+    type ArrangedAccountIndices = LockInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 13 {
+            return None;
+        }
+
+        Some(LockInstructionAccountIndices {
+            pool: 0,
+            lp_mint: 1,
+            lock_escrow: 2,
+            owner: 3,
+            source_tokens: 4,
+            escrow_vault: 5,
+            token_program: 6,
+            a_vault: 7,
+            b_vault: 8,
+            a_vault_lp: 9,
+            b_vault_lp: 10,
+            a_vault_lp_mint: 11,
+            b_vault_lp_mint: 12,
+            remaining_accounts: 13..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Lock {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "pool",
+            "lp_mint",
+            "lock_escrow",
+            "owner",
+            "source_tokens",
+            "escrow_vault",
+            "token_program",
+            "a_vault",
+            "b_vault",
+            "a_vault_lp",
+            "b_vault_lp",
+            "a_vault_lp_mint",
+            "b_vault_lp_mint",
+        ]
+    }
+}
@@ -99,15 +99,25 @@ pub enum MeteoraPoolsProgramInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for MeteoraPoolsDecoder {
     type InstructionType = MeteoraPoolsProgramInstruction;
 
-    fn decode_instruction(
+    fn decoder_name(&self) -> &'static str {
+        "meteora_pools"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[super::PROGRAM_ID]
+    }
+
+    fn try_decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
-    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+    ) -> carbon_core::error::CarbonResult<
+        Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>>,
+    > {
         if !instruction.program_id.eq(&PROGRAM_ID) {
-            return None;
+            return Ok(None);
         }
 
-        carbon_core::try_decode_instructions!(instruction,
+        Ok(carbon_core::try_decode_instructions!(instruction,
             MeteoraPoolsProgramInstruction::InitializePermissionedPool => initialize_permissioned_pool::InitializePermissionedPool,
             MeteoraPoolsProgramInstruction::InitializePermissionlessPool => initialize_permissionless_pool::InitializePermissionlessPool,
             MeteoraPoolsProgramInstruction::InitializePermissionlessPoolWithFeeTier => initialize_permissionless_pool_with_fee_tier::InitializePermissionlessPoolWithFeeTier,
@@ -152,6 +162,6 @@ impl carbon_core::instruction::InstructionDecoder<'_> for MeteoraPoolsDecoder {
             MeteoraPoolsProgramInstruction::CloseConfigEvent => close_config_event::CloseConfigEvent,
             MeteoraPoolsProgramInstruction::WithdrawProtocolFeesEvent => withdraw_protocol_fees_event::WithdrawProtocolFeesEvent,
             MeteoraPoolsProgramInstruction::PartnerClaimFeesEvent => partner_claim_fees_event::PartnerClaimFeesEvent,
-        )
+        ))
     }
 }
@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +14,7 @@ pub struct OverrideCurveParam {
 pub struct OverrideCurveParamInstructionAccounts {
     pub pool: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for OverrideCurveParam {
@@ -21,13 +23,68 @@ impl carbon_core::deserialize::ArrangeAccounts for OverrideCurveParam {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [pool, admin, _remaining @ ..] = accounts else {
+        let [pool, admin, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(OverrideCurveParamInstructionAccounts {
             pool: pool.pubkey,
             admin: admin.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct OverrideCurveParamInstructionAccountMetas {
+    pub pool: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for OverrideCurveParam {
+    type ArrangedAccountMetas = OverrideCurveParamInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [pool, admin, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(OverrideCurveParamInstructionAccountMetas {
+            pool: pool.clone(),
+            admin: admin.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct OverrideCurveParamInstructionAccountIndices {
+    pub pool: usize,
+    pub admin: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for OverrideCurveParam {
+    type ArrangedAccountIndices = OverrideCurveParamInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(OverrideCurveParamInstructionAccountIndices {
+            pool: 0,
+            admin: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for OverrideCurveParam {
+    fn account_names() -> &'static [&'static str] {
+        &["pool", "admin"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -18,6 +19,7 @@ pub struct PartnerClaimFeeInstructionAccounts {
     pub partner_token_b: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub partner_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for PartnerClaimFee {
@@ -26,7 +28,7 @@ impl carbon_core::deserialize::ArrangeAccounts for PartnerClaimFee {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [pool, a_vault_lp, protocol_token_a_fee, protocol_token_b_fee, partner_token_a, partner_token_b, token_program, partner_authority, _remaining @ ..] =
+        let [pool, a_vault_lp, protocol_token_a_fee, protocol_token_b_fee, partner_token_a, partner_token_b, token_program, partner_authority, remaining @ ..] =
             accounts
         else {
             return None;
@@ -41,6 +43,96 @@ impl carbon_core::deserialize::ArrangeAccounts for PartnerClaimFee {
             partner_token_b: partner_token_b.pubkey,
             token_program: token_program.pubkey,
             partner_authority: partner_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct PartnerClaimFeeInstructionAccountMetas {
+    pub pool: solana_instruction::AccountMeta,
+    pub a_vault_lp: solana_instruction::AccountMeta,
+    pub protocol_token_a_fee: solana_instruction::AccountMeta,
+    pub protocol_token_b_fee: solana_instruction::AccountMeta,
+    pub partner_token_a: solana_instruction::AccountMeta,
+    pub partner_token_b: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub partner_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for PartnerClaimFee {
+    type ArrangedAccountMetas = PartnerClaimFeeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [pool, a_vault_lp, protocol_token_a_fee, protocol_token_b_fee, partner_token_a, partner_token_b, token_program, partner_authority, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(PartnerClaimFeeInstructionAccountMetas {
+            pool: pool.clone(),
+            a_vault_lp: a_vault_lp.clone(),
+            protocol_token_a_fee: protocol_token_a_fee.clone(),
+            protocol_token_b_fee: protocol_token_b_fee.clone(),
+            partner_token_a: partner_token_a.clone(),
+            partner_token_b: partner_token_b.clone(),
+            token_program: token_program.clone(),
+            partner_authority: partner_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct PartnerClaimFeeInstructionAccountIndices {
+    pub pool: usize,
+    pub a_vault_lp: usize,
+    pub protocol_token_a_fee: usize,
+    pub protocol_token_b_fee: usize,
+    pub partner_token_a: usize,
+    pub partner_token_b: usize,
+    pub token_program: usize,
+    pub partner_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for PartnerClaimFee {
+    type ArrangedAccountIndices = PartnerClaimFeeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(PartnerClaimFeeInstructionAccountIndices {
+            pool: 0,
+            a_vault_lp: 1,
+            protocol_token_a_fee: 2,
+            protocol_token_b_fee: 3,
+            partner_token_a: 4,
+            partner_token_b: 5,
+            token_program: 6,
+            partner_authority: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for PartnerClaimFee {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "pool",
+            "a_vault_lp",
+            "protocol_token_a_fee",
+            "protocol_token_b_fee",
+            "partner_token_a",
+            "partner_token_b",
+            "token_program",
+            "partner_authority",
+        ]
+    }
+}
@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct SetPoolFees {
 pub struct SetPoolFeesInstructionAccounts {
     pub pool: solana_pubkey::Pubkey,
     pub fee_operator: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SetPoolFees {
@@ -22,13 +24,68 @@ impl carbon_core::deserialize::ArrangeAccounts for SetPoolFees {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [pool, fee_operator, _remaining @ ..] = accounts else {
+        let [pool, fee_operator, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(SetPoolFeesInstructionAccounts {
             pool: pool.pubkey,
             fee_operator: fee_operator.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SetPoolFeesInstructionAccountMetas {
+    pub pool: solana_instruction::AccountMeta,
+    pub fee_operator: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SetPoolFees {
+    type ArrangedAccountMetas = SetPoolFeesInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [pool, fee_operator, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(SetPoolFeesInstructionAccountMetas {
+            pool: pool.clone(),
+            fee_operator: fee_operator.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SetPoolFeesInstructionAccountIndices {
+    pub pool: usize,
+    pub fee_operator: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SetPoolFees {
+    type ArrangedAccountIndices = SetPoolFeesInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(SetPoolFeesInstructionAccountIndices {
+            pool: 0,
+            fee_operator: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SetPoolFees {
+    fn account_names() -> &'static [&'static str] {
+        &["pool", "fee_operator"]
+    }
+}
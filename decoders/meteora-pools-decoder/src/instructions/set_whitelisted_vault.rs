@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +12,7 @@ pub struct SetWhitelistedVault {
 pub struct SetWhitelistedVaultInstructionAccounts {
     pub pool: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SetWhitelistedVault {
@@ -19,13 +21,68 @@ impl carbon_core::deserialize::ArrangeAccounts for SetWhitelistedVault {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [pool, admin, _remaining @ ..] = accounts else {
+        let [pool, admin, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(SetWhitelistedVaultInstructionAccounts {
             pool: pool.pubkey,
             admin: admin.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SetWhitelistedVaultInstructionAccountMetas {
+    pub pool: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SetWhitelistedVault {
+    type ArrangedAccountMetas = SetWhitelistedVaultInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [pool, admin, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(SetWhitelistedVaultInstructionAccountMetas {
+            pool: pool.clone(),
+            admin: admin.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SetWhitelistedVaultInstructionAccountIndices {
+    pub pool: usize,
+    pub admin: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SetWhitelistedVault {
+    type ArrangedAccountIndices = SetWhitelistedVaultInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(SetWhitelistedVaultInstructionAccountIndices {
+            pool: 0,
+            admin: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SetWhitelistedVault {
+    fn account_names() -> &'static [&'static str] {
+        &["pool", "admin"]
+    }
+}
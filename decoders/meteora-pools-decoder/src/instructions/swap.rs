@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -25,6 +26,7 @@ pub struct SwapInstructionAccounts {
     pub user: solana_pubkey::Pubkey,
     pub vault_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Swap {
@@ -33,7 +35,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Swap {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [pool, user_source_token, user_destination_token, a_vault, b_vault, a_token_vault, b_token_vault, a_vault_lp_mint, b_vault_lp_mint, a_vault_lp, b_vault_lp, protocol_token_fee, user, vault_program, token_program, _remaining @ ..] =
+        let [pool, user_source_token, user_destination_token, a_vault, b_vault, a_token_vault, b_token_vault, a_vault_lp_mint, b_vault_lp_mint, a_vault_lp, b_vault_lp, protocol_token_fee, user, vault_program, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -55,6 +57,131 @@ impl carbon_core::deserialize::ArrangeAccounts for Swap {
             user: user.pubkey,
             vault_program: vault_program.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SwapInstructionAccountMetas {
+    pub pool: solana_instruction::AccountMeta,
+    pub user_source_token: solana_instruction::AccountMeta,
+    pub user_destination_token: solana_instruction::AccountMeta,
+    pub a_vault: solana_instruction::AccountMeta,
+    pub b_vault: solana_instruction::AccountMeta,
+    pub a_token_vault: solana_instruction::AccountMeta,
+    pub b_token_vault: solana_instruction::AccountMeta,
+    pub a_vault_lp_mint: solana_instruction::AccountMeta,
+    pub b_vault_lp_mint: solana_instruction::AccountMeta,
+    pub a_vault_lp: solana_instruction::AccountMeta,
+    pub b_vault_lp: solana_instruction::AccountMeta,
+    pub protocol_token_fee: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub vault_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Swap {
+    type ArrangedAccountMetas = SwapInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [pool, user_source_token, user_destination_token, a_vault, b_vault, a_token_vault, b_token_vault, a_vault_lp_mint, b_vault_lp_mint, a_vault_lp, b_vault_lp, protocol_token_fee, user, vault_program, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(SwapInstructionAccountMetas {
+            pool: pool.clone(),
+            user_source_token: user_source_token.clone(),
+            user_destination_token: user_destination_token.clone(),
+            a_vault: a_vault.clone(),
+            b_vault: b_vault.clone(),
+            a_token_vault: a_token_vault.clone(),
+            b_token_vault: b_token_vault.clone(),
+            a_vault_lp_mint: a_vault_lp_mint.clone(),
+            b_vault_lp_mint: b_vault_lp_mint.clone(),
+            a_vault_lp: a_vault_lp.clone(),
+            b_vault_lp: b_vault_lp.clone(),
+            protocol_token_fee: protocol_token_fee.clone(),
+            user: user.clone(),
+            vault_program: vault_program.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SwapInstructionAccountIndices {
+    pub pool: usize,
+    pub user_source_token: usize,
+    pub user_destination_token: usize,
+    pub a_vault: usize,
+    pub b_vault: usize,
+    pub a_token_vault: usize,
+    pub b_token_vault: usize,
+    pub a_vault_lp_mint: usize,
+    pub b_vault_lp_mint: usize,
+    pub a_vault_lp: usize,
+    pub b_vault_lp: usize,
+    pub protocol_token_fee: usize,
+    pub user: usize,
+    pub vault_program: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Swap {
+    type ArrangedAccountIndices = SwapInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 15 {
+            return None;
+        }
+
+        Some(SwapInstructionAccountIndices {
+            pool: 0,
+            user_source_token: 1,
+            user_destination_token: 2,
+            a_vault: 3,
+            b_vault: 4,
+            a_token_vault: 5,
+            b_token_vault: 6,
+            a_vault_lp_mint: 7,
+            b_vault_lp_mint: 8,
+            a_vault_lp: 9,
+            b_vault_lp: 10,
+            protocol_token_fee: 11,
+            user: 12,
+            vault_program: 13,
+            token_program: 14,
+            remaining_accounts: 15..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Swap {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "pool",
+            "user_source_token",
+            "user_destination_token",
+            "a_vault",
+            "b_vault",
+            "a_token_vault",
+            "b_token_vault",
+            "a_vault_lp_mint",
+            "b_vault_lp_mint",
+            "a_vault_lp",
+            "b_vault_lp",
+            "protocol_token_fee",
+            "user",
+            "vault_program",
+            "token_program",
+        ]
+    }
+}
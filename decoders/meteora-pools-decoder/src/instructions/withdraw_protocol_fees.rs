@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct WithdrawProtocolFeesInstructionAccounts {
     pub treasury_token_a: solana_pubkey::Pubkey,
     pub treasury_token_b: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for WithdrawProtocolFees {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawProtocolFees {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [pool, a_vault_lp, protocol_token_a_fee, protocol_token_b_fee, treasury_token_a, treasury_token_b, token_program, _remaining @ ..] =
+        let [pool, a_vault_lp, protocol_token_a_fee, protocol_token_b_fee, treasury_token_a, treasury_token_b, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -36,6 +38,91 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawProtocolFees {
             treasury_token_a: treasury_token_a.pubkey,
             treasury_token_b: treasury_token_b.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct WithdrawProtocolFeesInstructionAccountMetas {
+    pub pool: solana_instruction::AccountMeta,
+    pub a_vault_lp: solana_instruction::AccountMeta,
+    pub protocol_token_a_fee: solana_instruction::AccountMeta,
+    pub protocol_token_b_fee: solana_instruction::AccountMeta,
+    pub treasury_token_a: solana_instruction::AccountMeta,
+    pub treasury_token_b: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for WithdrawProtocolFees {
+    type ArrangedAccountMetas = WithdrawProtocolFeesInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [pool, a_vault_lp, protocol_token_a_fee, protocol_token_b_fee, treasury_token_a, treasury_token_b, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(WithdrawProtocolFeesInstructionAccountMetas {
+            pool: pool.clone(),
+            a_vault_lp: a_vault_lp.clone(),
+            protocol_token_a_fee: protocol_token_a_fee.clone(),
+            protocol_token_b_fee: protocol_token_b_fee.clone(),
+            treasury_token_a: treasury_token_a.clone(),
+            treasury_token_b: treasury_token_b.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct WithdrawProtocolFeesInstructionAccountIndices {
+    pub pool: usize,
+    pub a_vault_lp: usize,
+    pub protocol_token_a_fee: usize,
+    pub protocol_token_b_fee: usize,
+    pub treasury_token_a: usize,
+    pub treasury_token_b: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for WithdrawProtocolFees {
+    type ArrangedAccountIndices = WithdrawProtocolFeesInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 7 {
+            return None;
+        }
+
+        Some(WithdrawProtocolFeesInstructionAccountIndices {
+            pool: 0,
+            a_vault_lp: 1,
+            protocol_token_a_fee: 2,
+            protocol_token_b_fee: 3,
+            treasury_token_a: 4,
+            treasury_token_b: 5,
+            token_program: 6,
+            remaining_accounts: 7..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for WithdrawProtocolFees {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "pool",
+            "a_vault_lp",
+            "protocol_token_a_fee",
+            "protocol_token_b_fee",
+            "treasury_token_a",
+            "treasury_token_b",
+            "token_program",
+        ]
+    }
+}
@@ -1,8 +1,10 @@
+use alloc::vec::Vec;
 use {
     super::super::types::*,
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -24,6 +26,7 @@ pub struct BuyInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub associated_token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Buy {
@@ -32,7 +35,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Buy {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [sender, sender_token_account, curve_account, curve_token_account, dex_fee, helio_fee, mint, config_account, token_program, associated_token_program, system_program, _remaining @ ..] =
+        let [sender, sender_token_account, curve_account, curve_token_account, dex_fee, helio_fee, mint, config_account, token_program, associated_token_program, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -50,6 +53,113 @@ impl carbon_core::deserialize::ArrangeAccounts for Buy {
             token_program: token_program.pubkey,
             associated_token_program: associated_token_program.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+#[derive(Debug, PartialEq)]
+pub struct BuyInstructionAccountMetas {
+    pub sender: solana_instruction::AccountMeta,
+    pub sender_token_account: solana_instruction::AccountMeta,
+    pub curve_account: solana_instruction::AccountMeta,
+    pub curve_token_account: solana_instruction::AccountMeta,
+    pub dex_fee: solana_instruction::AccountMeta,
+    pub helio_fee: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub config_account: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Buy {
+    type ArrangedAccountMetas = BuyInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [sender, sender_token_account, curve_account, curve_token_account, dex_fee, helio_fee, mint, config_account, token_program, associated_token_program, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(BuyInstructionAccountMetas {
+            sender: sender.clone(),
+            sender_token_account: sender_token_account.clone(),
+            curve_account: curve_account.clone(),
+            curve_token_account: curve_token_account.clone(),
+            dex_fee: dex_fee.clone(),
+            helio_fee: helio_fee.clone(),
+            mint: mint.clone(),
+            config_account: config_account.clone(),
+            token_program: token_program.clone(),
+            associated_token_program: associated_token_program.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct BuyInstructionAccountIndices {
+    pub sender: usize,
+    pub sender_token_account: usize,
+    pub curve_account: usize,
+    pub curve_token_account: usize,
+    pub dex_fee: usize,
+    pub helio_fee: usize,
+    pub mint: usize,
+    pub config_account: usize,
+    pub token_program: usize,
+    pub associated_token_program: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Buy {
+    type ArrangedAccountIndices = BuyInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 11 {
+            return None;
+        }
+
+        Some(BuyInstructionAccountIndices {
+            sender: 0,
+            sender_token_account: 1,
+            curve_account: 2,
+            curve_token_account: 3,
+            dex_fee: 4,
+            helio_fee: 5,
+            mint: 6,
+            config_account: 7,
+            token_program: 8,
+            associated_token_program: 9,
+            system_program: 10,
+            remaining_accounts: 11..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Buy {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "sender",
+            "sender_token_account",
+            "curve_account",
+            "curve_token_account",
+            "dex_fee",
+            "helio_fee",
+            "mint",
+            "config_account",
+            "token_program",
+            "associated_token_program",
+            "system_program",
+        ]
+    }
+}
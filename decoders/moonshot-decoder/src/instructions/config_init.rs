@@ -1,8 +1,10 @@
+use alloc::vec::Vec;
 use {
     super::super::types::*,
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -16,6 +18,7 @@ pub struct ConfigInitInstructionAccounts {
     pub config_authority: solana_pubkey::Pubkey,
     pub config_account: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ConfigInit {
@@ -24,7 +27,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ConfigInit {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [config_authority, config_account, system_program, _remaining @ ..] = accounts else {
+        let [config_authority, config_account, system_program, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -32,6 +35,67 @@ impl carbon_core::deserialize::ArrangeAccounts for ConfigInit {
             config_authority: config_authority.pubkey,
             config_account: config_account.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+#[derive(Debug)]
+pub struct ConfigInitInstructionAccountMetas {
+    pub config_authority: solana_instruction::AccountMeta,
+    pub config_account: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ConfigInit {
+    type ArrangedAccountMetas = ConfigInitInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [config_authority, config_account, system_program, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(ConfigInitInstructionAccountMetas {
+            config_authority: config_authority.clone(),
+            config_account: config_account.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct ConfigInitInstructionAccountIndices {
+    pub config_authority: usize,
+    pub config_account: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ConfigInit {
+    type ArrangedAccountIndices = ConfigInitInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(ConfigInitInstructionAccountIndices {
+            config_authority: 0,
+            config_account: 1,
+            system_program: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ConfigInit {
+    fn account_names() -> &'static [&'static str] {
+        &["config_authority", "config_account", "system_program"]
+    }
+}
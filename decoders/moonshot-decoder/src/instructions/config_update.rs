@@ -1,8 +1,10 @@
+use alloc::vec::Vec;
 use {
     super::super::types::*,
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -15,6 +17,7 @@ pub struct ConfigUpdate {
 pub struct ConfigUpdateInstructionAccounts {
     pub config_authority: solana_pubkey::Pubkey,
     pub config_account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ConfigUpdate {
@@ -23,13 +26,70 @@ impl carbon_core::deserialize::ArrangeAccounts for ConfigUpdate {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [config_authority, config_account, _remaining @ ..] = accounts else {
+        let [config_authority, config_account, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(ConfigUpdateInstructionAccounts {
             config_authority: config_authority.pubkey,
             config_account: config_account.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+#[derive(Debug)]
+pub struct ConfigUpdateInstructionAccountMetas {
+    pub config_authority: solana_instruction::AccountMeta,
+    pub config_account: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ConfigUpdate {
+    type ArrangedAccountMetas = ConfigUpdateInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [config_authority, config_account, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(ConfigUpdateInstructionAccountMetas {
+            config_authority: config_authority.clone(),
+            config_account: config_account.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct ConfigUpdateInstructionAccountIndices {
+    pub config_authority: usize,
+    pub config_account: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ConfigUpdate {
+    type ArrangedAccountIndices = ConfigUpdateInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(ConfigUpdateInstructionAccountIndices {
+            config_authority: 0,
+            config_account: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ConfigUpdate {
+    fn account_names() -> &'static [&'static str] {
+        &["config_authority", "config_account"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -20,6 +22,7 @@ pub struct MigrateFundsInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub associated_token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for MigrateFunds {
@@ -28,7 +31,7 @@ impl carbon_core::deserialize::ArrangeAccounts for MigrateFunds {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [backend_authority, migration_authority, curve_account, curve_token_account, migration_authority_token_account, mint, dex_fee_account, helio_fee_account, config_account, system_program, token_program, associated_token_program, _remaining @ ..] =
+        let [backend_authority, migration_authority, curve_account, curve_token_account, migration_authority_token_account, mint, dex_fee_account, helio_fee_account, config_account, system_program, token_program, associated_token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -47,6 +50,118 @@ impl carbon_core::deserialize::ArrangeAccounts for MigrateFunds {
             system_program: system_program.pubkey,
             token_program: token_program.pubkey,
             associated_token_program: associated_token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+#[derive(Debug, PartialEq)]
+pub struct MigrateFundsInstructionAccountMetas {
+    pub backend_authority: solana_instruction::AccountMeta,
+    pub migration_authority: solana_instruction::AccountMeta,
+    pub curve_account: solana_instruction::AccountMeta,
+    pub curve_token_account: solana_instruction::AccountMeta,
+    pub migration_authority_token_account: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub dex_fee_account: solana_instruction::AccountMeta,
+    pub helio_fee_account: solana_instruction::AccountMeta,
+    pub config_account: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for MigrateFunds {
+    type ArrangedAccountMetas = MigrateFundsInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [backend_authority, migration_authority, curve_account, curve_token_account, migration_authority_token_account, mint, dex_fee_account, helio_fee_account, config_account, system_program, token_program, associated_token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(MigrateFundsInstructionAccountMetas {
+            backend_authority: backend_authority.clone(),
+            migration_authority: migration_authority.clone(),
+            curve_account: curve_account.clone(),
+            curve_token_account: curve_token_account.clone(),
+            migration_authority_token_account: migration_authority_token_account.clone(),
+            mint: mint.clone(),
+            dex_fee_account: dex_fee_account.clone(),
+            helio_fee_account: helio_fee_account.clone(),
+            config_account: config_account.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            associated_token_program: associated_token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct MigrateFundsInstructionAccountIndices {
+    pub backend_authority: usize,
+    pub migration_authority: usize,
+    pub curve_account: usize,
+    pub curve_token_account: usize,
+    pub migration_authority_token_account: usize,
+    pub mint: usize,
+    pub dex_fee_account: usize,
+    pub helio_fee_account: usize,
+    pub config_account: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub associated_token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for MigrateFunds {
+    type ArrangedAccountIndices = MigrateFundsInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 12 {
+            return None;
+        }
+
+        Some(MigrateFundsInstructionAccountIndices {
+            backend_authority: 0,
+            migration_authority: 1,
+            curve_account: 2,
+            curve_token_account: 3,
+            migration_authority_token_account: 4,
+            mint: 5,
+            dex_fee_account: 6,
+            helio_fee_account: 7,
+            config_account: 8,
+            system_program: 9,
+            token_program: 10,
+            associated_token_program: 11,
+            remaining_accounts: 12..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for MigrateFunds {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "backend_authority",
+            "migration_authority",
+            "curve_account",
+            "curve_token_account",
+            "migration_authority_token_account",
+            "mint",
+            "dex_fee_account",
+            "helio_fee_account",
+            "config_account",
+            "system_program",
+            "token_program",
+            "associated_token_program",
+        ]
+    }
+}
@@ -27,15 +27,25 @@ pub enum MoonshotInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for MoonshotDecoder {
     type InstructionType = MoonshotInstruction;
 
-    fn decode_instruction(
+    fn decoder_name(&self) -> &'static str {
+        "moonshot"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[super::PROGRAM_ID]
+    }
+
+    fn try_decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
-    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+    ) -> carbon_core::error::CarbonResult<
+        Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>>,
+    > {
         if !instruction.program_id.eq(&PROGRAM_ID) {
-            return None;
+            return Ok(None);
         }
 
-        carbon_core::try_decode_instructions!(instruction,
+        Ok(carbon_core::try_decode_instructions!(instruction,
             MoonshotInstruction::TokenMint => token_mint::TokenMint,
             MoonshotInstruction::Buy => buy::Buy,
             MoonshotInstruction::Sell => sell::Sell,
@@ -44,7 +54,7 @@ impl carbon_core::instruction::InstructionDecoder<'_> for MoonshotDecoder {
             MoonshotInstruction::ConfigUpdate => config_update::ConfigUpdate,
             MoonshotInstruction::TradeEvent => trade_event::TradeEvent,
             MoonshotInstruction::MigrationEvent => migration_event::MigrationEvent,
-        )
+        ))
     }
 }
 
@@ -180,6 +190,7 @@ mod tests {
             system_program: solana_pubkey::Pubkey::from_str_const(
                 "11111111111111111111111111111111",
             ),
+            remaining_accounts: vec![],
         };
 
         // Act
@@ -311,6 +322,7 @@ mod tests {
             system_program: solana_pubkey::Pubkey::from_str_const(
                 "11111111111111111111111111111111",
             ),
+            remaining_accounts: vec![],
         };
 
         // Act
@@ -441,6 +453,7 @@ mod tests {
             system_program: solana_pubkey::Pubkey::from_str_const(
                 "11111111111111111111111111111111",
             ),
+            remaining_accounts: vec![],
         };
 
         // Act
@@ -573,6 +586,7 @@ mod tests {
             associated_token_program: solana_pubkey::Pubkey::from_str_const(
                 "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
             ),
+            remaining_accounts: vec![],
         };
 
         // Act
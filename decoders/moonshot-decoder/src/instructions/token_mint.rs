@@ -1,8 +1,10 @@
+use alloc::vec::Vec;
 use {
     super::super::types::*,
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
 #[carbon(discriminator = "0x032ca4b87b0df5b3")]
 pub struct TokenMint {
@@ -22,6 +24,7 @@ pub struct TokenMintInstructionAccounts {
     pub associated_token_program: solana_pubkey::Pubkey,
     pub mpl_token_metadata: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for TokenMint {
@@ -30,7 +33,7 @@ impl carbon_core::deserialize::ArrangeAccounts for TokenMint {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [sender, backend_authority, curve_account, mint, mint_metadata, curve_token_account, config_account, token_program, associated_token_program, mpl_token_metadata, system_program, _remaining @ ..] =
+        let [sender, backend_authority, curve_account, mint, mint_metadata, curve_token_account, config_account, token_program, associated_token_program, mpl_token_metadata, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -48,6 +51,113 @@ impl carbon_core::deserialize::ArrangeAccounts for TokenMint {
             associated_token_program: associated_token_program.pubkey,
             mpl_token_metadata: mpl_token_metadata.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+#[derive(Debug, PartialEq)]
+pub struct TokenMintInstructionAccountMetas {
+    pub sender: solana_instruction::AccountMeta,
+    pub backend_authority: solana_instruction::AccountMeta,
+    pub curve_account: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub mint_metadata: solana_instruction::AccountMeta,
+    pub curve_token_account: solana_instruction::AccountMeta,
+    pub config_account: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub mpl_token_metadata: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for TokenMint {
+    type ArrangedAccountMetas = TokenMintInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [sender, backend_authority, curve_account, mint, mint_metadata, curve_token_account, config_account, token_program, associated_token_program, mpl_token_metadata, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(TokenMintInstructionAccountMetas {
+            sender: sender.clone(),
+            backend_authority: backend_authority.clone(),
+            curve_account: curve_account.clone(),
+            mint: mint.clone(),
+            mint_metadata: mint_metadata.clone(),
+            curve_token_account: curve_token_account.clone(),
+            config_account: config_account.clone(),
+            token_program: token_program.clone(),
+            associated_token_program: associated_token_program.clone(),
+            mpl_token_metadata: mpl_token_metadata.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct TokenMintInstructionAccountIndices {
+    pub sender: usize,
+    pub backend_authority: usize,
+    pub curve_account: usize,
+    pub mint: usize,
+    pub mint_metadata: usize,
+    pub curve_token_account: usize,
+    pub config_account: usize,
+    pub token_program: usize,
+    pub associated_token_program: usize,
+    pub mpl_token_metadata: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for TokenMint {
+    type ArrangedAccountIndices = TokenMintInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 11 {
+            return None;
+        }
+
+        Some(TokenMintInstructionAccountIndices {
+            sender: 0,
+            backend_authority: 1,
+            curve_account: 2,
+            mint: 3,
+            mint_metadata: 4,
+            curve_token_account: 5,
+            config_account: 6,
+            token_program: 7,
+            associated_token_program: 8,
+            mpl_token_metadata: 9,
+            system_program: 10,
+            remaining_accounts: 11..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for TokenMint {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "sender",
+            "backend_authority",
+            "curve_account",
+            "mint",
+            "mint_metadata",
+            "curve_token_account",
+            "config_account",
+            "token_program",
+            "associated_token_program",
+            "mpl_token_metadata",
+            "system_program",
+        ]
+    }
+}
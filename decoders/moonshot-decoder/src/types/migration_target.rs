@@ -1,6 +1,7 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
 #[repr(u8)]
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize,
     Debug,
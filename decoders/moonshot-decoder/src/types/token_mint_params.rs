@@ -1,5 +1,6 @@
 use carbon_core::{borsh, deserialize::PrefixString, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Default,
 )]
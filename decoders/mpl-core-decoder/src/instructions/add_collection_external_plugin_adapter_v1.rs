@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -17,6 +18,7 @@ pub struct AddCollectionExternalPluginAdapterV1InstructionAccounts {
     pub authority: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub log_wrapper: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for AddCollectionExternalPluginAdapterV1 {
@@ -25,7 +27,7 @@ impl carbon_core::deserialize::ArrangeAccounts for AddCollectionExternalPluginAd
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [collection, payer, authority, system_program, log_wrapper, _remaining @ ..] = accounts
+        let [collection, payer, authority, system_program, log_wrapper, remaining @ ..] = accounts
         else {
             return None;
         };
@@ -36,6 +38,84 @@ impl carbon_core::deserialize::ArrangeAccounts for AddCollectionExternalPluginAd
             authority: authority.pubkey,
             system_program: system_program.pubkey,
             log_wrapper: log_wrapper.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct AddCollectionExternalPluginAdapterV1InstructionAccountMetas {
+    pub collection: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub log_wrapper: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for AddCollectionExternalPluginAdapterV1 {
+    type ArrangedAccountMetas = AddCollectionExternalPluginAdapterV1InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [collection, payer, authority, system_program, log_wrapper, remaining @ ..] = accounts
+        else {
+            return None;
+        };
+
+        Some(
+            AddCollectionExternalPluginAdapterV1InstructionAccountMetas {
+                collection: collection.clone(),
+                payer: payer.clone(),
+                authority: authority.clone(),
+                system_program: system_program.clone(),
+                log_wrapper: log_wrapper.clone(),
+                remaining_accounts: remaining.to_vec(),
+            },
+        )
+    }
+}
+
+pub struct AddCollectionExternalPluginAdapterV1InstructionAccountIndices {
+    pub collection: usize,
+    pub payer: usize,
+    pub authority: usize,
+    pub system_program: usize,
+    pub log_wrapper: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for AddCollectionExternalPluginAdapterV1 {
+    type ArrangedAccountIndices = AddCollectionExternalPluginAdapterV1InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(
+            AddCollectionExternalPluginAdapterV1InstructionAccountIndices {
+                collection: 0,
+                payer: 1,
+                authority: 2,
+                system_program: 3,
+                log_wrapper: 4,
+                remaining_accounts: 5..accounts.len(),
+            },
+        )
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for AddCollectionExternalPluginAdapterV1 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "collection",
+            "payer",
+            "authority",
+            "system_program",
+            "log_wrapper",
+        ]
+    }
+}
@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -18,6 +19,7 @@ pub struct AddExternalPluginAdapterV1InstructionAccounts {
     pub authority: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub log_wrapper: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for AddExternalPluginAdapterV1 {
@@ -26,7 +28,7 @@ impl carbon_core::deserialize::ArrangeAccounts for AddExternalPluginAdapterV1 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [asset, collection, payer, authority, system_program, log_wrapper, _remaining @ ..] =
+        let [asset, collection, payer, authority, system_program, log_wrapper, remaining @ ..] =
             accounts
         else {
             return None;
@@ -39,6 +41,86 @@ impl carbon_core::deserialize::ArrangeAccounts for AddExternalPluginAdapterV1 {
             authority: authority.pubkey,
             system_program: system_program.pubkey,
             log_wrapper: log_wrapper.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct AddExternalPluginAdapterV1InstructionAccountMetas {
+    pub asset: solana_instruction::AccountMeta,
+    pub collection: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub log_wrapper: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for AddExternalPluginAdapterV1 {
+    type ArrangedAccountMetas = AddExternalPluginAdapterV1InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [asset, collection, payer, authority, system_program, log_wrapper, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(AddExternalPluginAdapterV1InstructionAccountMetas {
+            asset: asset.clone(),
+            collection: collection.clone(),
+            payer: payer.clone(),
+            authority: authority.clone(),
+            system_program: system_program.clone(),
+            log_wrapper: log_wrapper.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct AddExternalPluginAdapterV1InstructionAccountIndices {
+    pub asset: usize,
+    pub collection: usize,
+    pub payer: usize,
+    pub authority: usize,
+    pub system_program: usize,
+    pub log_wrapper: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for AddExternalPluginAdapterV1 {
+    type ArrangedAccountIndices = AddExternalPluginAdapterV1InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(AddExternalPluginAdapterV1InstructionAccountIndices {
+            asset: 0,
+            collection: 1,
+            payer: 2,
+            authority: 3,
+            system_program: 4,
+            log_wrapper: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for AddExternalPluginAdapterV1 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "asset",
+            "collection",
+            "payer",
+            "authority",
+            "system_program",
+            "log_wrapper",
+        ]
+    }
+}
@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -16,6 +17,7 @@ pub struct BurnCollectionV1InstructionAccounts {
     pub payer: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
     pub log_wrapper: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for BurnCollectionV1 {
@@ -24,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for BurnCollectionV1 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [collection, payer, authority, log_wrapper, _remaining @ ..] = accounts else {
+        let [collection, payer, authority, log_wrapper, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -33,6 +35,69 @@ impl carbon_core::deserialize::ArrangeAccounts for BurnCollectionV1 {
             payer: payer.pubkey,
             authority: authority.pubkey,
             log_wrapper: log_wrapper.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct BurnCollectionV1InstructionAccountMetas {
+    pub collection: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub log_wrapper: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for BurnCollectionV1 {
+    type ArrangedAccountMetas = BurnCollectionV1InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [collection, payer, authority, log_wrapper, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(BurnCollectionV1InstructionAccountMetas {
+            collection: collection.clone(),
+            payer: payer.clone(),
+            authority: authority.clone(),
+            log_wrapper: log_wrapper.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct BurnCollectionV1InstructionAccountIndices {
+    pub collection: usize,
+    pub payer: usize,
+    pub authority: usize,
+    pub log_wrapper: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for BurnCollectionV1 {
+    type ArrangedAccountIndices = BurnCollectionV1InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(BurnCollectionV1InstructionAccountIndices {
+            collection: 0,
+            payer: 1,
+            authority: 2,
+            log_wrapper: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for BurnCollectionV1 {
+    fn account_names() -> &'static [&'static str] {
+        &["collection", "payer", "authority", "log_wrapper"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -9,6 +10,7 @@ pub struct Collect {}
 pub struct CollectInstructionAccounts {
     pub recipient1: solana_pubkey::Pubkey,
     pub recipient2: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Collect {
@@ -17,13 +19,68 @@ impl carbon_core::deserialize::ArrangeAccounts for Collect {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [recipient1, recipient2, _remaining @ ..] = accounts else {
+        let [recipient1, recipient2, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(CollectInstructionAccounts {
             recipient1: recipient1.pubkey,
             recipient2: recipient2.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CollectInstructionAccountMetas {
+    pub recipient1: solana_instruction::AccountMeta,
+    pub recipient2: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Collect {
+    type ArrangedAccountMetas = CollectInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [recipient1, recipient2, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(CollectInstructionAccountMetas {
+            recipient1: recipient1.clone(),
+            recipient2: recipient2.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CollectInstructionAccountIndices {
+    pub recipient1: usize,
+    pub recipient2: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Collect {
+    type ArrangedAccountIndices = CollectInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(CollectInstructionAccountIndices {
+            recipient1: 0,
+            recipient2: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Collect {
+    fn account_names() -> &'static [&'static str] {
+        &["recipient1", "recipient2"]
+    }
+}
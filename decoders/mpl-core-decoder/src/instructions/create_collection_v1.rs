@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -16,6 +17,7 @@ pub struct CreateCollectionV1InstructionAccounts {
     pub update_authority: solana_pubkey::Pubkey,
     pub payer: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CreateCollectionV1 {
@@ -24,8 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateCollectionV1 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [collection, update_authority, payer, system_program, _remaining @ ..] = accounts
-        else {
+        let [collection, update_authority, payer, system_program, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -34,6 +35,69 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateCollectionV1 {
             update_authority: update_authority.pubkey,
             payer: payer.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CreateCollectionV1InstructionAccountMetas {
+    pub collection: solana_instruction::AccountMeta,
+    pub update_authority: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CreateCollectionV1 {
+    type ArrangedAccountMetas = CreateCollectionV1InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [collection, update_authority, payer, system_program, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(CreateCollectionV1InstructionAccountMetas {
+            collection: collection.clone(),
+            update_authority: update_authority.clone(),
+            payer: payer.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CreateCollectionV1InstructionAccountIndices {
+    pub collection: usize,
+    pub update_authority: usize,
+    pub payer: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CreateCollectionV1 {
+    type ArrangedAccountIndices = CreateCollectionV1InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(CreateCollectionV1InstructionAccountIndices {
+            collection: 0,
+            update_authority: 1,
+            payer: 2,
+            system_program: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CreateCollectionV1 {
+    fn account_names() -> &'static [&'static str] {
+        &["collection", "update_authority", "payer", "system_program"]
+    }
+}
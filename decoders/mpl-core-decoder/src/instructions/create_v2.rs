@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -20,6 +21,7 @@ pub struct CreateV2InstructionAccounts {
     pub update_authority: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub log_wrapper: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CreateV2 {
@@ -28,7 +30,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateV2 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [asset, collection, authority, payer, owner, update_authority, system_program, log_wrapper, _remaining @ ..] =
+        let [asset, collection, authority, payer, owner, update_authority, system_program, log_wrapper, remaining @ ..] =
             accounts
         else {
             return None;
@@ -43,6 +45,96 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateV2 {
             update_authority: update_authority.pubkey,
             system_program: system_program.pubkey,
             log_wrapper: log_wrapper.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CreateV2InstructionAccountMetas {
+    pub asset: solana_instruction::AccountMeta,
+    pub collection: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub update_authority: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub log_wrapper: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CreateV2 {
+    type ArrangedAccountMetas = CreateV2InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [asset, collection, authority, payer, owner, update_authority, system_program, log_wrapper, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CreateV2InstructionAccountMetas {
+            asset: asset.clone(),
+            collection: collection.clone(),
+            authority: authority.clone(),
+            payer: payer.clone(),
+            owner: owner.clone(),
+            update_authority: update_authority.clone(),
+            system_program: system_program.clone(),
+            log_wrapper: log_wrapper.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CreateV2InstructionAccountIndices {
+    pub asset: usize,
+    pub collection: usize,
+    pub authority: usize,
+    pub payer: usize,
+    pub owner: usize,
+    pub update_authority: usize,
+    pub system_program: usize,
+    pub log_wrapper: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CreateV2 {
+    type ArrangedAccountIndices = CreateV2InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(CreateV2InstructionAccountIndices {
+            asset: 0,
+            collection: 1,
+            authority: 2,
+            payer: 3,
+            owner: 4,
+            update_authority: 5,
+            system_program: 6,
+            log_wrapper: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CreateV2 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "asset",
+            "collection",
+            "authority",
+            "payer",
+            "owner",
+            "update_authority",
+            "system_program",
+            "log_wrapper",
+        ]
+    }
+}
@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -19,6 +20,7 @@ pub struct ExecuteV1InstructionAccounts {
     pub authority: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub program_id: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ExecuteV1 {
@@ -27,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ExecuteV1 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [asset, collection, asset_signer, payer, authority, system_program, program_id, _remaining @ ..] =
+        let [asset, collection, asset_signer, payer, authority, system_program, program_id, remaining @ ..] =
             accounts
         else {
             return None;
@@ -41,6 +43,91 @@ impl carbon_core::deserialize::ArrangeAccounts for ExecuteV1 {
             authority: authority.pubkey,
             system_program: system_program.pubkey,
             program_id: program_id.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ExecuteV1InstructionAccountMetas {
+    pub asset: solana_instruction::AccountMeta,
+    pub collection: solana_instruction::AccountMeta,
+    pub asset_signer: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub program_id: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ExecuteV1 {
+    type ArrangedAccountMetas = ExecuteV1InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [asset, collection, asset_signer, payer, authority, system_program, program_id, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(ExecuteV1InstructionAccountMetas {
+            asset: asset.clone(),
+            collection: collection.clone(),
+            asset_signer: asset_signer.clone(),
+            payer: payer.clone(),
+            authority: authority.clone(),
+            system_program: system_program.clone(),
+            program_id: program_id.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ExecuteV1InstructionAccountIndices {
+    pub asset: usize,
+    pub collection: usize,
+    pub asset_signer: usize,
+    pub payer: usize,
+    pub authority: usize,
+    pub system_program: usize,
+    pub program_id: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ExecuteV1 {
+    type ArrangedAccountIndices = ExecuteV1InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 7 {
+            return None;
+        }
+
+        Some(ExecuteV1InstructionAccountIndices {
+            asset: 0,
+            collection: 1,
+            asset_signer: 2,
+            payer: 3,
+            authority: 4,
+            system_program: 5,
+            program_id: 6,
+            remaining_accounts: 7..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ExecuteV1 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "asset",
+            "collection",
+            "asset_signer",
+            "payer",
+            "authority",
+            "system_program",
+            "program_id",
+        ]
+    }
+}
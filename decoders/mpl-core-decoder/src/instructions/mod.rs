@@ -82,15 +82,25 @@ pub enum MplCoreProgramInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for MplCoreProgramDecoder {
     type InstructionType = MplCoreProgramInstruction;
 
-    fn decode_instruction(
+    fn decoder_name(&self) -> &'static str {
+        "mpl_core_program"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[super::PROGRAM_ID]
+    }
+
+    fn try_decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
-    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+    ) -> carbon_core::error::CarbonResult<
+        Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>>,
+    > {
         if !instruction.program_id.eq(&PROGRAM_ID) {
-            return None;
+            return Ok(None);
         }
 
-        carbon_core::try_decode_instructions!(instruction,
+        Ok(carbon_core::try_decode_instructions!(instruction,
             MplCoreProgramInstruction::CreateV1 => create_v1::CreateV1,
             MplCoreProgramInstruction::CreateCollectionV1 => create_collection_v1::CreateCollectionV1,
             MplCoreProgramInstruction::AddPluginV1 => add_plugin_v1::AddPluginV1,
@@ -123,6 +133,6 @@ impl carbon_core::instruction::InstructionDecoder<'_> for MplCoreProgramDecoder
             MplCoreProgramInstruction::WriteCollectionExternalPluginAdapterDataV1 => write_collection_external_plugin_adapter_data_v1::WriteCollectionExternalPluginAdapterDataV1,
             MplCoreProgramInstruction::UpdateV2 => update_v2::UpdateV2,
             MplCoreProgramInstruction::ExecuteV1 => execute_v1::ExecuteV1,
-        )
+        ))
     }
 }
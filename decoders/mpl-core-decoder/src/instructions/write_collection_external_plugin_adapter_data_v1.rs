@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -19,6 +20,7 @@ pub struct WriteCollectionExternalPluginAdapterDataV1InstructionAccounts {
     pub buffer: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub log_wrapper: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for WriteCollectionExternalPluginAdapterDataV1 {
@@ -27,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for WriteCollectionExternalPlugin
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [collection, payer, authority, buffer, system_program, log_wrapper, _remaining @ ..] =
+        let [collection, payer, authority, buffer, system_program, log_wrapper, remaining @ ..] =
             accounts
         else {
             return None;
@@ -41,7 +43,94 @@ impl carbon_core::deserialize::ArrangeAccounts for WriteCollectionExternalPlugin
                 buffer: buffer.pubkey,
                 system_program: system_program.pubkey,
                 log_wrapper: log_wrapper.pubkey,
+                remaining_accounts: remaining.to_vec(),
             },
         )
     }
 }
+
+pub struct WriteCollectionExternalPluginAdapterDataV1InstructionAccountMetas {
+    pub collection: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub buffer: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub log_wrapper: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for WriteCollectionExternalPluginAdapterDataV1 {
+    type ArrangedAccountMetas = WriteCollectionExternalPluginAdapterDataV1InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [collection, payer, authority, buffer, system_program, log_wrapper, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(
+            WriteCollectionExternalPluginAdapterDataV1InstructionAccountMetas {
+                collection: collection.clone(),
+                payer: payer.clone(),
+                authority: authority.clone(),
+                buffer: buffer.clone(),
+                system_program: system_program.clone(),
+                log_wrapper: log_wrapper.clone(),
+                remaining_accounts: remaining.to_vec(),
+            },
+        )
+    }
+}
+
+pub struct WriteCollectionExternalPluginAdapterDataV1InstructionAccountIndices {
+    pub collection: usize,
+    pub payer: usize,
+    pub authority: usize,
+    pub buffer: usize,
+    pub system_program: usize,
+    pub log_wrapper: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices
+    for WriteCollectionExternalPluginAdapterDataV1
+{
+    type ArrangedAccountIndices =
+        WriteCollectionExternalPluginAdapterDataV1InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(
+            WriteCollectionExternalPluginAdapterDataV1InstructionAccountIndices {
+                collection: 0,
+                payer: 1,
+                authority: 2,
+                buffer: 3,
+                system_program: 4,
+                log_wrapper: 5,
+                remaining_accounts: 6..accounts.len(),
+            },
+        )
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for WriteCollectionExternalPluginAdapterDataV1 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "collection",
+            "payer",
+            "authority",
+            "buffer",
+            "system_program",
+            "log_wrapper",
+        ]
+    }
+}
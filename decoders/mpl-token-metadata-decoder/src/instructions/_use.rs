@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -24,6 +25,7 @@ pub struct UseInstructionAccounts {
     pub spl_token_program: solana_pubkey::Pubkey,
     pub authorization_rules_program: solana_pubkey::Pubkey,
     pub authorization_rules: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Use {
@@ -32,7 +34,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Use {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [authority, delegate_record, token, mint, metadata, edition, payer, system_program, sysvar_instructions, spl_token_program, authorization_rules_program, authorization_rules, _remaining @ ..] =
+        let [authority, delegate_record, token, mint, metadata, edition, payer, system_program, sysvar_instructions, spl_token_program, authorization_rules_program, authorization_rules, remaining @ ..] =
             accounts
         else {
             return None;
@@ -51,6 +53,116 @@ impl carbon_core::deserialize::ArrangeAccounts for Use {
             spl_token_program: spl_token_program.pubkey,
             authorization_rules_program: authorization_rules_program.pubkey,
             authorization_rules: authorization_rules.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UseInstructionAccountMetas {
+    pub authority: solana_instruction::AccountMeta,
+    pub delegate_record: solana_instruction::AccountMeta,
+    pub token: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub metadata: solana_instruction::AccountMeta,
+    pub edition: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub sysvar_instructions: solana_instruction::AccountMeta,
+    pub spl_token_program: solana_instruction::AccountMeta,
+    pub authorization_rules_program: solana_instruction::AccountMeta,
+    pub authorization_rules: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Use {
+    type ArrangedAccountMetas = UseInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [authority, delegate_record, token, mint, metadata, edition, payer, system_program, sysvar_instructions, spl_token_program, authorization_rules_program, authorization_rules, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(UseInstructionAccountMetas {
+            authority: authority.clone(),
+            delegate_record: delegate_record.clone(),
+            token: token.clone(),
+            mint: mint.clone(),
+            metadata: metadata.clone(),
+            edition: edition.clone(),
+            payer: payer.clone(),
+            system_program: system_program.clone(),
+            sysvar_instructions: sysvar_instructions.clone(),
+            spl_token_program: spl_token_program.clone(),
+            authorization_rules_program: authorization_rules_program.clone(),
+            authorization_rules: authorization_rules.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UseInstructionAccountIndices {
+    pub authority: usize,
+    pub delegate_record: usize,
+    pub token: usize,
+    pub mint: usize,
+    pub metadata: usize,
+    pub edition: usize,
+    pub payer: usize,
+    pub system_program: usize,
+    pub sysvar_instructions: usize,
+    pub spl_token_program: usize,
+    pub authorization_rules_program: usize,
+    pub authorization_rules: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Use {
+    type ArrangedAccountIndices = UseInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 12 {
+            return None;
+        }
+
+        Some(UseInstructionAccountIndices {
+            authority: 0,
+            delegate_record: 1,
+            token: 2,
+            mint: 3,
+            metadata: 4,
+            edition: 5,
+            payer: 6,
+            system_program: 7,
+            sysvar_instructions: 8,
+            spl_token_program: 9,
+            authorization_rules_program: 10,
+            authorization_rules: 11,
+            remaining_accounts: 12..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Use {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "authority",
+            "delegate_record",
+            "token",
+            "mint",
+            "metadata",
+            "edition",
+            "payer",
+            "system_program",
+            "sysvar_instructions",
+            "spl_token_program",
+            "authorization_rules_program",
+            "authorization_rules",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -15,6 +16,7 @@ pub struct ApproveCollectionAuthorityInstructionAccounts {
     pub mint: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ApproveCollectionAuthority {
@@ -23,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ApproveCollectionAuthority {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [collection_authority_record, new_collection_authority, update_authority, payer, metadata, mint, system_program, rent, _remaining @ ..] =
+        let [collection_authority_record, new_collection_authority, update_authority, payer, metadata, mint, system_program, rent, remaining @ ..] =
             accounts
         else {
             return None;
@@ -38,6 +40,96 @@ impl carbon_core::deserialize::ArrangeAccounts for ApproveCollectionAuthority {
             mint: mint.pubkey,
             system_program: system_program.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ApproveCollectionAuthorityInstructionAccountMetas {
+    pub collection_authority_record: solana_instruction::AccountMeta,
+    pub new_collection_authority: solana_instruction::AccountMeta,
+    pub update_authority: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub metadata: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ApproveCollectionAuthority {
+    type ArrangedAccountMetas = ApproveCollectionAuthorityInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [collection_authority_record, new_collection_authority, update_authority, payer, metadata, mint, system_program, rent, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(ApproveCollectionAuthorityInstructionAccountMetas {
+            collection_authority_record: collection_authority_record.clone(),
+            new_collection_authority: new_collection_authority.clone(),
+            update_authority: update_authority.clone(),
+            payer: payer.clone(),
+            metadata: metadata.clone(),
+            mint: mint.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ApproveCollectionAuthorityInstructionAccountIndices {
+    pub collection_authority_record: usize,
+    pub new_collection_authority: usize,
+    pub update_authority: usize,
+    pub payer: usize,
+    pub metadata: usize,
+    pub mint: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ApproveCollectionAuthority {
+    type ArrangedAccountIndices = ApproveCollectionAuthorityInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(ApproveCollectionAuthorityInstructionAccountIndices {
+            collection_authority_record: 0,
+            new_collection_authority: 1,
+            update_authority: 2,
+            payer: 3,
+            metadata: 4,
+            mint: 5,
+            system_program: 6,
+            rent: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ApproveCollectionAuthority {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "collection_authority_record",
+            "new_collection_authority",
+            "update_authority",
+            "payer",
+            "metadata",
+            "mint",
+            "system_program",
+            "rent",
+        ]
+    }
+}
@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -23,6 +24,7 @@ pub struct ApproveUseAuthorityInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ApproveUseAuthority {
@@ -31,7 +33,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ApproveUseAuthority {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [use_authority_record, owner, payer, user, owner_token_account, metadata, mint, burner, token_program, system_program, rent, _remaining @ ..] =
+        let [use_authority_record, owner, payer, user, owner_token_account, metadata, mint, burner, token_program, system_program, rent, remaining @ ..] =
             accounts
         else {
             return None;
@@ -49,6 +51,111 @@ impl carbon_core::deserialize::ArrangeAccounts for ApproveUseAuthority {
             token_program: token_program.pubkey,
             system_program: system_program.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ApproveUseAuthorityInstructionAccountMetas {
+    pub use_authority_record: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub owner_token_account: solana_instruction::AccountMeta,
+    pub metadata: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub burner: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ApproveUseAuthority {
+    type ArrangedAccountMetas = ApproveUseAuthorityInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [use_authority_record, owner, payer, user, owner_token_account, metadata, mint, burner, token_program, system_program, rent, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(ApproveUseAuthorityInstructionAccountMetas {
+            use_authority_record: use_authority_record.clone(),
+            owner: owner.clone(),
+            payer: payer.clone(),
+            user: user.clone(),
+            owner_token_account: owner_token_account.clone(),
+            metadata: metadata.clone(),
+            mint: mint.clone(),
+            burner: burner.clone(),
+            token_program: token_program.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ApproveUseAuthorityInstructionAccountIndices {
+    pub use_authority_record: usize,
+    pub owner: usize,
+    pub payer: usize,
+    pub user: usize,
+    pub owner_token_account: usize,
+    pub metadata: usize,
+    pub mint: usize,
+    pub burner: usize,
+    pub token_program: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ApproveUseAuthority {
+    type ArrangedAccountIndices = ApproveUseAuthorityInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 11 {
+            return None;
+        }
+
+        Some(ApproveUseAuthorityInstructionAccountIndices {
+            use_authority_record: 0,
+            owner: 1,
+            payer: 2,
+            user: 3,
+            owner_token_account: 4,
+            metadata: 5,
+            mint: 6,
+            burner: 7,
+            token_program: 8,
+            system_program: 9,
+            rent: 10,
+            remaining_accounts: 11..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ApproveUseAuthority {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "use_authority_record",
+            "owner",
+            "payer",
+            "user",
+            "owner_token_account",
+            "metadata",
+            "mint",
+            "burner",
+            "token_program",
+            "system_program",
+            "rent",
+        ]
+    }
+}
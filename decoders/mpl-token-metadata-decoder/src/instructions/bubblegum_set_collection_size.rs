@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -17,6 +18,7 @@ pub struct BubblegumSetCollectionSizeInstructionAccounts {
     pub collection_mint: solana_pubkey::Pubkey,
     pub bubblegum_signer: solana_pubkey::Pubkey,
     pub collection_authority_record: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for BubblegumSetCollectionSize {
@@ -25,7 +27,7 @@ impl carbon_core::deserialize::ArrangeAccounts for BubblegumSetCollectionSize {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [collection_metadata, collection_authority, collection_mint, bubblegum_signer, collection_authority_record, _remaining @ ..] =
+        let [collection_metadata, collection_authority, collection_mint, bubblegum_signer, collection_authority_record, remaining @ ..] =
             accounts
         else {
             return None;
@@ -37,6 +39,81 @@ impl carbon_core::deserialize::ArrangeAccounts for BubblegumSetCollectionSize {
             collection_mint: collection_mint.pubkey,
             bubblegum_signer: bubblegum_signer.pubkey,
             collection_authority_record: collection_authority_record.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct BubblegumSetCollectionSizeInstructionAccountMetas {
+    pub collection_metadata: solana_instruction::AccountMeta,
+    pub collection_authority: solana_instruction::AccountMeta,
+    pub collection_mint: solana_instruction::AccountMeta,
+    pub bubblegum_signer: solana_instruction::AccountMeta,
+    pub collection_authority_record: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for BubblegumSetCollectionSize {
+    type ArrangedAccountMetas = BubblegumSetCollectionSizeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [collection_metadata, collection_authority, collection_mint, bubblegum_signer, collection_authority_record, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(BubblegumSetCollectionSizeInstructionAccountMetas {
+            collection_metadata: collection_metadata.clone(),
+            collection_authority: collection_authority.clone(),
+            collection_mint: collection_mint.clone(),
+            bubblegum_signer: bubblegum_signer.clone(),
+            collection_authority_record: collection_authority_record.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct BubblegumSetCollectionSizeInstructionAccountIndices {
+    pub collection_metadata: usize,
+    pub collection_authority: usize,
+    pub collection_mint: usize,
+    pub bubblegum_signer: usize,
+    pub collection_authority_record: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for BubblegumSetCollectionSize {
+    type ArrangedAccountIndices = BubblegumSetCollectionSizeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(BubblegumSetCollectionSizeInstructionAccountIndices {
+            collection_metadata: 0,
+            collection_authority: 1,
+            collection_mint: 2,
+            bubblegum_signer: 3,
+            collection_authority_record: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for BubblegumSetCollectionSize {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "collection_metadata",
+            "collection_authority",
+            "collection_mint",
+            "bubblegum_signer",
+            "collection_authority_record",
+        ]
+    }
+}
@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -26,6 +27,7 @@ pub struct BurnInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub sysvar_instructions: solana_pubkey::Pubkey,
     pub spl_token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Burn {
@@ -34,7 +36,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Burn {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [authority, collection_metadata, metadata, edition, mint, token, master_edition, master_edition_mint, master_edition_token, edition_marker, token_record, system_program, sysvar_instructions, spl_token_program, _remaining @ ..] =
+        let [authority, collection_metadata, metadata, edition, mint, token, master_edition, master_edition_mint, master_edition_token, edition_marker, token_record, system_program, sysvar_instructions, spl_token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -55,6 +57,126 @@ impl carbon_core::deserialize::ArrangeAccounts for Burn {
             system_program: system_program.pubkey,
             sysvar_instructions: sysvar_instructions.pubkey,
             spl_token_program: spl_token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct BurnInstructionAccountMetas {
+    pub authority: solana_instruction::AccountMeta,
+    pub collection_metadata: solana_instruction::AccountMeta,
+    pub metadata: solana_instruction::AccountMeta,
+    pub edition: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub token: solana_instruction::AccountMeta,
+    pub master_edition: solana_instruction::AccountMeta,
+    pub master_edition_mint: solana_instruction::AccountMeta,
+    pub master_edition_token: solana_instruction::AccountMeta,
+    pub edition_marker: solana_instruction::AccountMeta,
+    pub token_record: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub sysvar_instructions: solana_instruction::AccountMeta,
+    pub spl_token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Burn {
+    type ArrangedAccountMetas = BurnInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [authority, collection_metadata, metadata, edition, mint, token, master_edition, master_edition_mint, master_edition_token, edition_marker, token_record, system_program, sysvar_instructions, spl_token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(BurnInstructionAccountMetas {
+            authority: authority.clone(),
+            collection_metadata: collection_metadata.clone(),
+            metadata: metadata.clone(),
+            edition: edition.clone(),
+            mint: mint.clone(),
+            token: token.clone(),
+            master_edition: master_edition.clone(),
+            master_edition_mint: master_edition_mint.clone(),
+            master_edition_token: master_edition_token.clone(),
+            edition_marker: edition_marker.clone(),
+            token_record: token_record.clone(),
+            system_program: system_program.clone(),
+            sysvar_instructions: sysvar_instructions.clone(),
+            spl_token_program: spl_token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct BurnInstructionAccountIndices {
+    pub authority: usize,
+    pub collection_metadata: usize,
+    pub metadata: usize,
+    pub edition: usize,
+    pub mint: usize,
+    pub token: usize,
+    pub master_edition: usize,
+    pub master_edition_mint: usize,
+    pub master_edition_token: usize,
+    pub edition_marker: usize,
+    pub token_record: usize,
+    pub system_program: usize,
+    pub sysvar_instructions: usize,
+    pub spl_token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Burn {
+    type ArrangedAccountIndices = BurnInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 14 {
+            return None;
+        }
+
+        Some(BurnInstructionAccountIndices {
+            authority: 0,
+            collection_metadata: 1,
+            metadata: 2,
+            edition: 3,
+            mint: 4,
+            token: 5,
+            master_edition: 6,
+            master_edition_mint: 7,
+            master_edition_token: 8,
+            edition_marker: 9,
+            token_record: 10,
+            system_program: 11,
+            sysvar_instructions: 12,
+            spl_token_program: 13,
+            remaining_accounts: 14..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Burn {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "authority",
+            "collection_metadata",
+            "metadata",
+            "edition",
+            "mint",
+            "token",
+            "master_edition",
+            "master_edition_mint",
+            "master_edition_token",
+            "edition_marker",
+            "token_record",
+            "system_program",
+            "sysvar_instructions",
+            "spl_token_program",
+        ]
+    }
+}
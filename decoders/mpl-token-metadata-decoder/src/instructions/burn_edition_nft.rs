@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -17,6 +18,7 @@ pub struct BurnEditionNftInstructionAccounts {
     pub print_edition_account: solana_pubkey::Pubkey,
     pub edition_marker_account: solana_pubkey::Pubkey,
     pub spl_token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for BurnEditionNft {
@@ -25,7 +27,7 @@ impl carbon_core::deserialize::ArrangeAccounts for BurnEditionNft {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [metadata, owner, print_edition_mint, master_edition_mint, print_edition_token_account, master_edition_token_account, master_edition_account, print_edition_account, edition_marker_account, spl_token_program, _remaining @ ..] =
+        let [metadata, owner, print_edition_mint, master_edition_mint, print_edition_token_account, master_edition_token_account, master_edition_account, print_edition_account, edition_marker_account, spl_token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -42,6 +44,106 @@ impl carbon_core::deserialize::ArrangeAccounts for BurnEditionNft {
             print_edition_account: print_edition_account.pubkey,
             edition_marker_account: edition_marker_account.pubkey,
             spl_token_program: spl_token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct BurnEditionNftInstructionAccountMetas {
+    pub metadata: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub print_edition_mint: solana_instruction::AccountMeta,
+    pub master_edition_mint: solana_instruction::AccountMeta,
+    pub print_edition_token_account: solana_instruction::AccountMeta,
+    pub master_edition_token_account: solana_instruction::AccountMeta,
+    pub master_edition_account: solana_instruction::AccountMeta,
+    pub print_edition_account: solana_instruction::AccountMeta,
+    pub edition_marker_account: solana_instruction::AccountMeta,
+    pub spl_token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for BurnEditionNft {
+    type ArrangedAccountMetas = BurnEditionNftInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [metadata, owner, print_edition_mint, master_edition_mint, print_edition_token_account, master_edition_token_account, master_edition_account, print_edition_account, edition_marker_account, spl_token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(BurnEditionNftInstructionAccountMetas {
+            metadata: metadata.clone(),
+            owner: owner.clone(),
+            print_edition_mint: print_edition_mint.clone(),
+            master_edition_mint: master_edition_mint.clone(),
+            print_edition_token_account: print_edition_token_account.clone(),
+            master_edition_token_account: master_edition_token_account.clone(),
+            master_edition_account: master_edition_account.clone(),
+            print_edition_account: print_edition_account.clone(),
+            edition_marker_account: edition_marker_account.clone(),
+            spl_token_program: spl_token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct BurnEditionNftInstructionAccountIndices {
+    pub metadata: usize,
+    pub owner: usize,
+    pub print_edition_mint: usize,
+    pub master_edition_mint: usize,
+    pub print_edition_token_account: usize,
+    pub master_edition_token_account: usize,
+    pub master_edition_account: usize,
+    pub print_edition_account: usize,
+    pub edition_marker_account: usize,
+    pub spl_token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for BurnEditionNft {
+    type ArrangedAccountIndices = BurnEditionNftInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 10 {
+            return None;
+        }
+
+        Some(BurnEditionNftInstructionAccountIndices {
+            metadata: 0,
+            owner: 1,
+            print_edition_mint: 2,
+            master_edition_mint: 3,
+            print_edition_token_account: 4,
+            master_edition_token_account: 5,
+            master_edition_account: 6,
+            print_edition_account: 7,
+            edition_marker_account: 8,
+            spl_token_program: 9,
+            remaining_accounts: 10..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for BurnEditionNft {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "metadata",
+            "owner",
+            "print_edition_mint",
+            "master_edition_mint",
+            "print_edition_token_account",
+            "master_edition_token_account",
+            "master_edition_account",
+            "print_edition_account",
+            "edition_marker_account",
+            "spl_token_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct BurnNftInstructionAccounts {
     pub master_edition_account: solana_pubkey::Pubkey,
     pub spl_token_program: solana_pubkey::Pubkey,
     pub collection_metadata: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for BurnNft {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for BurnNft {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [metadata, owner, mint, token_account, master_edition_account, spl_token_program, collection_metadata, _remaining @ ..] =
+        let [metadata, owner, mint, token_account, master_edition_account, spl_token_program, collection_metadata, remaining @ ..] =
             accounts
         else {
             return None;
@@ -36,6 +38,91 @@ impl carbon_core::deserialize::ArrangeAccounts for BurnNft {
             master_edition_account: master_edition_account.pubkey,
             spl_token_program: spl_token_program.pubkey,
             collection_metadata: collection_metadata.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct BurnNftInstructionAccountMetas {
+    pub metadata: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub token_account: solana_instruction::AccountMeta,
+    pub master_edition_account: solana_instruction::AccountMeta,
+    pub spl_token_program: solana_instruction::AccountMeta,
+    pub collection_metadata: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for BurnNft {
+    type ArrangedAccountMetas = BurnNftInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [metadata, owner, mint, token_account, master_edition_account, spl_token_program, collection_metadata, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(BurnNftInstructionAccountMetas {
+            metadata: metadata.clone(),
+            owner: owner.clone(),
+            mint: mint.clone(),
+            token_account: token_account.clone(),
+            master_edition_account: master_edition_account.clone(),
+            spl_token_program: spl_token_program.clone(),
+            collection_metadata: collection_metadata.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct BurnNftInstructionAccountIndices {
+    pub metadata: usize,
+    pub owner: usize,
+    pub mint: usize,
+    pub token_account: usize,
+    pub master_edition_account: usize,
+    pub spl_token_program: usize,
+    pub collection_metadata: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for BurnNft {
+    type ArrangedAccountIndices = BurnNftInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 7 {
+            return None;
+        }
+
+        Some(BurnNftInstructionAccountIndices {
+            metadata: 0,
+            owner: 1,
+            mint: 2,
+            token_account: 3,
+            master_edition_account: 4,
+            spl_token_program: 5,
+            collection_metadata: 6,
+            remaining_accounts: 7..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for BurnNft {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "metadata",
+            "owner",
+            "mint",
+            "token_account",
+            "master_edition_account",
+            "spl_token_program",
+            "collection_metadata",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +13,7 @@ pub struct CloseAccountsInstructionAccounts {
     pub mint: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
     pub destination: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CloseAccounts {
@@ -20,7 +22,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CloseAccounts {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [metadata, edition, mint, authority, destination, _remaining @ ..] = accounts else {
+        let [metadata, edition, mint, authority, destination, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -30,6 +32,73 @@ impl carbon_core::deserialize::ArrangeAccounts for CloseAccounts {
             mint: mint.pubkey,
             authority: authority.pubkey,
             destination: destination.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CloseAccountsInstructionAccountMetas {
+    pub metadata: solana_instruction::AccountMeta,
+    pub edition: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub destination: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CloseAccounts {
+    type ArrangedAccountMetas = CloseAccountsInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [metadata, edition, mint, authority, destination, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(CloseAccountsInstructionAccountMetas {
+            metadata: metadata.clone(),
+            edition: edition.clone(),
+            mint: mint.clone(),
+            authority: authority.clone(),
+            destination: destination.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CloseAccountsInstructionAccountIndices {
+    pub metadata: usize,
+    pub edition: usize,
+    pub mint: usize,
+    pub authority: usize,
+    pub destination: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CloseAccounts {
+    type ArrangedAccountIndices = CloseAccountsInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(CloseAccountsInstructionAccountIndices {
+            metadata: 0,
+            edition: 1,
+            mint: 2,
+            authority: 3,
+            destination: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CloseAccounts {
+    fn account_names() -> &'static [&'static str] {
+        &["metadata", "edition", "mint", "authority", "destination"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -10,6 +11,7 @@ pub struct ConvertMasterEditionV1ToV2InstructionAccounts {
     pub master_edition: solana_pubkey::Pubkey,
     pub one_time_auth: solana_pubkey::Pubkey,
     pub printing_mint: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ConvertMasterEditionV1ToV2 {
@@ -18,7 +20,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ConvertMasterEditionV1ToV2 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [master_edition, one_time_auth, printing_mint, _remaining @ ..] = accounts else {
+        let [master_edition, one_time_auth, printing_mint, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -26,6 +28,65 @@ impl carbon_core::deserialize::ArrangeAccounts for ConvertMasterEditionV1ToV2 {
             master_edition: master_edition.pubkey,
             one_time_auth: one_time_auth.pubkey,
             printing_mint: printing_mint.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ConvertMasterEditionV1ToV2InstructionAccountMetas {
+    pub master_edition: solana_instruction::AccountMeta,
+    pub one_time_auth: solana_instruction::AccountMeta,
+    pub printing_mint: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ConvertMasterEditionV1ToV2 {
+    type ArrangedAccountMetas = ConvertMasterEditionV1ToV2InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [master_edition, one_time_auth, printing_mint, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(ConvertMasterEditionV1ToV2InstructionAccountMetas {
+            master_edition: master_edition.clone(),
+            one_time_auth: one_time_auth.clone(),
+            printing_mint: printing_mint.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ConvertMasterEditionV1ToV2InstructionAccountIndices {
+    pub master_edition: usize,
+    pub one_time_auth: usize,
+    pub printing_mint: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ConvertMasterEditionV1ToV2 {
+    type ArrangedAccountIndices = ConvertMasterEditionV1ToV2InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(ConvertMasterEditionV1ToV2InstructionAccountIndices {
+            master_edition: 0,
+            one_time_auth: 1,
+            printing_mint: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ConvertMasterEditionV1ToV2 {
+    fn account_names() -> &'static [&'static str] {
+        &["master_edition", "one_time_auth", "printing_mint"]
+    }
+}
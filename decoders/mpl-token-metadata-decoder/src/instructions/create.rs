@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -21,6 +22,7 @@ pub struct CreateInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub sysvar_instructions: solana_pubkey::Pubkey,
     pub spl_token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Create {
@@ -29,7 +31,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Create {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [metadata, master_edition, mint, authority, payer, update_authority, system_program, sysvar_instructions, spl_token_program, _remaining @ ..] =
+        let [metadata, master_edition, mint, authority, payer, update_authority, system_program, sysvar_instructions, spl_token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -45,6 +47,101 @@ impl carbon_core::deserialize::ArrangeAccounts for Create {
             system_program: system_program.pubkey,
             sysvar_instructions: sysvar_instructions.pubkey,
             spl_token_program: spl_token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CreateInstructionAccountMetas {
+    pub metadata: solana_instruction::AccountMeta,
+    pub master_edition: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub update_authority: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub sysvar_instructions: solana_instruction::AccountMeta,
+    pub spl_token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Create {
+    type ArrangedAccountMetas = CreateInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [metadata, master_edition, mint, authority, payer, update_authority, system_program, sysvar_instructions, spl_token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CreateInstructionAccountMetas {
+            metadata: metadata.clone(),
+            master_edition: master_edition.clone(),
+            mint: mint.clone(),
+            authority: authority.clone(),
+            payer: payer.clone(),
+            update_authority: update_authority.clone(),
+            system_program: system_program.clone(),
+            sysvar_instructions: sysvar_instructions.clone(),
+            spl_token_program: spl_token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CreateInstructionAccountIndices {
+    pub metadata: usize,
+    pub master_edition: usize,
+    pub mint: usize,
+    pub authority: usize,
+    pub payer: usize,
+    pub update_authority: usize,
+    pub system_program: usize,
+    pub sysvar_instructions: usize,
+    pub spl_token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Create {
+    type ArrangedAccountIndices = CreateInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 9 {
+            return None;
+        }
+
+        Some(CreateInstructionAccountIndices {
+            metadata: 0,
+            master_edition: 1,
+            mint: 2,
+            authority: 3,
+            payer: 4,
+            update_authority: 5,
+            system_program: 6,
+            sysvar_instructions: 7,
+            spl_token_program: 8,
+            remaining_accounts: 9..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Create {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "metadata",
+            "master_edition",
+            "mint",
+            "authority",
+            "payer",
+            "update_authority",
+            "system_program",
+            "sysvar_instructions",
+            "spl_token_program",
+        ]
+    }
+}
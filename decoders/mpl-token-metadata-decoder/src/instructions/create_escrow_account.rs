@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -16,6 +17,7 @@ pub struct CreateEscrowAccountInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub sysvar_instructions: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CreateEscrowAccount {
@@ -24,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateEscrowAccount {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [escrow, metadata, mint, token_account, edition, payer, system_program, sysvar_instructions, authority, _remaining @ ..] =
+        let [escrow, metadata, mint, token_account, edition, payer, system_program, sysvar_instructions, authority, remaining @ ..] =
             accounts
         else {
             return None;
@@ -40,6 +42,101 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateEscrowAccount {
             system_program: system_program.pubkey,
             sysvar_instructions: sysvar_instructions.pubkey,
             authority: authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CreateEscrowAccountInstructionAccountMetas {
+    pub escrow: solana_instruction::AccountMeta,
+    pub metadata: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub token_account: solana_instruction::AccountMeta,
+    pub edition: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub sysvar_instructions: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CreateEscrowAccount {
+    type ArrangedAccountMetas = CreateEscrowAccountInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [escrow, metadata, mint, token_account, edition, payer, system_program, sysvar_instructions, authority, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CreateEscrowAccountInstructionAccountMetas {
+            escrow: escrow.clone(),
+            metadata: metadata.clone(),
+            mint: mint.clone(),
+            token_account: token_account.clone(),
+            edition: edition.clone(),
+            payer: payer.clone(),
+            system_program: system_program.clone(),
+            sysvar_instructions: sysvar_instructions.clone(),
+            authority: authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CreateEscrowAccountInstructionAccountIndices {
+    pub escrow: usize,
+    pub metadata: usize,
+    pub mint: usize,
+    pub token_account: usize,
+    pub edition: usize,
+    pub payer: usize,
+    pub system_program: usize,
+    pub sysvar_instructions: usize,
+    pub authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CreateEscrowAccount {
+    type ArrangedAccountIndices = CreateEscrowAccountInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 9 {
+            return None;
+        }
+
+        Some(CreateEscrowAccountInstructionAccountIndices {
+            escrow: 0,
+            metadata: 1,
+            mint: 2,
+            token_account: 3,
+            edition: 4,
+            payer: 5,
+            system_program: 6,
+            sysvar_instructions: 7,
+            authority: 8,
+            remaining_accounts: 9..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CreateEscrowAccount {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "escrow",
+            "metadata",
+            "mint",
+            "token_account",
+            "edition",
+            "payer",
+            "system_program",
+            "sysvar_instructions",
+            "authority",
+        ]
+    }
+}
@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -21,6 +22,7 @@ pub struct CreateMasterEditionV3InstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CreateMasterEditionV3 {
@@ -29,7 +31,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateMasterEditionV3 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [edition, mint, update_authority, mint_authority, payer, metadata, token_program, system_program, rent, _remaining @ ..] =
+        let [edition, mint, update_authority, mint_authority, payer, metadata, token_program, system_program, rent, remaining @ ..] =
             accounts
         else {
             return None;
@@ -45,6 +47,101 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateMasterEditionV3 {
             token_program: token_program.pubkey,
             system_program: system_program.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CreateMasterEditionV3InstructionAccountMetas {
+    pub edition: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub update_authority: solana_instruction::AccountMeta,
+    pub mint_authority: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub metadata: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CreateMasterEditionV3 {
+    type ArrangedAccountMetas = CreateMasterEditionV3InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [edition, mint, update_authority, mint_authority, payer, metadata, token_program, system_program, rent, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CreateMasterEditionV3InstructionAccountMetas {
+            edition: edition.clone(),
+            mint: mint.clone(),
+            update_authority: update_authority.clone(),
+            mint_authority: mint_authority.clone(),
+            payer: payer.clone(),
+            metadata: metadata.clone(),
+            token_program: token_program.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CreateMasterEditionV3InstructionAccountIndices {
+    pub edition: usize,
+    pub mint: usize,
+    pub update_authority: usize,
+    pub mint_authority: usize,
+    pub payer: usize,
+    pub metadata: usize,
+    pub token_program: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CreateMasterEditionV3 {
+    type ArrangedAccountIndices = CreateMasterEditionV3InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 9 {
+            return None;
+        }
+
+        Some(CreateMasterEditionV3InstructionAccountIndices {
+            edition: 0,
+            mint: 1,
+            update_authority: 2,
+            mint_authority: 3,
+            payer: 4,
+            metadata: 5,
+            token_program: 6,
+            system_program: 7,
+            rent: 8,
+            remaining_accounts: 9..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CreateMasterEditionV3 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "edition",
+            "mint",
+            "update_authority",
+            "mint_authority",
+            "payer",
+            "metadata",
+            "token_program",
+            "system_program",
+            "rent",
+        ]
+    }
+}
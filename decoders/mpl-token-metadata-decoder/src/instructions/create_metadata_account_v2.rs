@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct CreateMetadataAccountV2InstructionAccounts {
     pub update_authority: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CreateMetadataAccountV2 {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateMetadataAccountV2 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [metadata, mint, mint_authority, payer, update_authority, system_program, rent, _remaining @ ..] =
+        let [metadata, mint, mint_authority, payer, update_authority, system_program, rent, remaining @ ..] =
             accounts
         else {
             return None;
@@ -36,6 +38,91 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateMetadataAccountV2 {
             update_authority: update_authority.pubkey,
             system_program: system_program.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CreateMetadataAccountV2InstructionAccountMetas {
+    pub metadata: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub mint_authority: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub update_authority: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CreateMetadataAccountV2 {
+    type ArrangedAccountMetas = CreateMetadataAccountV2InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [metadata, mint, mint_authority, payer, update_authority, system_program, rent, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CreateMetadataAccountV2InstructionAccountMetas {
+            metadata: metadata.clone(),
+            mint: mint.clone(),
+            mint_authority: mint_authority.clone(),
+            payer: payer.clone(),
+            update_authority: update_authority.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CreateMetadataAccountV2InstructionAccountIndices {
+    pub metadata: usize,
+    pub mint: usize,
+    pub mint_authority: usize,
+    pub payer: usize,
+    pub update_authority: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CreateMetadataAccountV2 {
+    type ArrangedAccountIndices = CreateMetadataAccountV2InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 7 {
+            return None;
+        }
+
+        Some(CreateMetadataAccountV2InstructionAccountIndices {
+            metadata: 0,
+            mint: 1,
+            mint_authority: 2,
+            payer: 3,
+            update_authority: 4,
+            system_program: 5,
+            rent: 6,
+            remaining_accounts: 7..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CreateMetadataAccountV2 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "metadata",
+            "mint",
+            "mint_authority",
+            "payer",
+            "update_authority",
+            "system_program",
+            "rent",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -20,6 +21,7 @@ pub struct DeprecatedCreateMasterEditionInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub one_time_printing_authorization_mint_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for DeprecatedCreateMasterEdition {
@@ -28,7 +30,7 @@ impl carbon_core::deserialize::ArrangeAccounts for DeprecatedCreateMasterEdition
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [edition, mint, printing_mint, one_time_printing_authorization_mint, update_authority, printing_mint_authority, mint_authority, metadata, payer, token_program, system_program, rent, one_time_printing_authorization_mint_authority, _remaining @ ..] =
+        let [edition, mint, printing_mint, one_time_printing_authorization_mint, update_authority, printing_mint_authority, mint_authority, metadata, payer, token_program, system_program, rent, one_time_printing_authorization_mint_authority, remaining @ ..] =
             accounts
         else {
             return None;
@@ -49,6 +51,122 @@ impl carbon_core::deserialize::ArrangeAccounts for DeprecatedCreateMasterEdition
             rent: rent.pubkey,
             one_time_printing_authorization_mint_authority:
                 one_time_printing_authorization_mint_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DeprecatedCreateMasterEditionInstructionAccountMetas {
+    pub edition: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub printing_mint: solana_instruction::AccountMeta,
+    pub one_time_printing_authorization_mint: solana_instruction::AccountMeta,
+    pub update_authority: solana_instruction::AccountMeta,
+    pub printing_mint_authority: solana_instruction::AccountMeta,
+    pub mint_authority: solana_instruction::AccountMeta,
+    pub metadata: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub one_time_printing_authorization_mint_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for DeprecatedCreateMasterEdition {
+    type ArrangedAccountMetas = DeprecatedCreateMasterEditionInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [edition, mint, printing_mint, one_time_printing_authorization_mint, update_authority, printing_mint_authority, mint_authority, metadata, payer, token_program, system_program, rent, one_time_printing_authorization_mint_authority, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(DeprecatedCreateMasterEditionInstructionAccountMetas {
+            edition: edition.clone(),
+            mint: mint.clone(),
+            printing_mint: printing_mint.clone(),
+            one_time_printing_authorization_mint: one_time_printing_authorization_mint.clone(),
+            update_authority: update_authority.clone(),
+            printing_mint_authority: printing_mint_authority.clone(),
+            mint_authority: mint_authority.clone(),
+            metadata: metadata.clone(),
+            payer: payer.clone(),
+            token_program: token_program.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            one_time_printing_authorization_mint_authority:
+                one_time_printing_authorization_mint_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DeprecatedCreateMasterEditionInstructionAccountIndices {
+    pub edition: usize,
+    pub mint: usize,
+    pub printing_mint: usize,
+    pub one_time_printing_authorization_mint: usize,
+    pub update_authority: usize,
+    pub printing_mint_authority: usize,
+    pub mint_authority: usize,
+    pub metadata: usize,
+    pub payer: usize,
+    pub token_program: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub one_time_printing_authorization_mint_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for DeprecatedCreateMasterEdition {
+    type ArrangedAccountIndices = DeprecatedCreateMasterEditionInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 13 {
+            return None;
+        }
+
+        Some(DeprecatedCreateMasterEditionInstructionAccountIndices {
+            edition: 0,
+            mint: 1,
+            printing_mint: 2,
+            one_time_printing_authorization_mint: 3,
+            update_authority: 4,
+            printing_mint_authority: 5,
+            mint_authority: 6,
+            metadata: 7,
+            payer: 8,
+            token_program: 9,
+            system_program: 10,
+            rent: 11,
+            one_time_printing_authorization_mint_authority: 12,
+            remaining_accounts: 13..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for DeprecatedCreateMasterEdition {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "edition",
+            "mint",
+            "printing_mint",
+            "one_time_printing_authorization_mint",
+            "update_authority",
+            "printing_mint_authority",
+            "mint_authority",
+            "metadata",
+            "payer",
+            "token_program",
+            "system_program",
+            "rent",
+            "one_time_printing_authorization_mint_authority",
+        ]
+    }
+}
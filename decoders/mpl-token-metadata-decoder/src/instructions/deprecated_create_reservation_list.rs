@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -15,6 +16,7 @@ pub struct DeprecatedCreateReservationListInstructionAccounts {
     pub metadata: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for DeprecatedCreateReservationList {
@@ -23,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for DeprecatedCreateReservationLi
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [reservation_list, payer, update_authority, master_edition, resource, metadata, system_program, rent, _remaining @ ..] =
+        let [reservation_list, payer, update_authority, master_edition, resource, metadata, system_program, rent, remaining @ ..] =
             accounts
         else {
             return None;
@@ -38,6 +40,96 @@ impl carbon_core::deserialize::ArrangeAccounts for DeprecatedCreateReservationLi
             metadata: metadata.pubkey,
             system_program: system_program.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DeprecatedCreateReservationListInstructionAccountMetas {
+    pub reservation_list: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub update_authority: solana_instruction::AccountMeta,
+    pub master_edition: solana_instruction::AccountMeta,
+    pub resource: solana_instruction::AccountMeta,
+    pub metadata: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for DeprecatedCreateReservationList {
+    type ArrangedAccountMetas = DeprecatedCreateReservationListInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [reservation_list, payer, update_authority, master_edition, resource, metadata, system_program, rent, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(DeprecatedCreateReservationListInstructionAccountMetas {
+            reservation_list: reservation_list.clone(),
+            payer: payer.clone(),
+            update_authority: update_authority.clone(),
+            master_edition: master_edition.clone(),
+            resource: resource.clone(),
+            metadata: metadata.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DeprecatedCreateReservationListInstructionAccountIndices {
+    pub reservation_list: usize,
+    pub payer: usize,
+    pub update_authority: usize,
+    pub master_edition: usize,
+    pub resource: usize,
+    pub metadata: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for DeprecatedCreateReservationList {
+    type ArrangedAccountIndices = DeprecatedCreateReservationListInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(DeprecatedCreateReservationListInstructionAccountIndices {
+            reservation_list: 0,
+            payer: 1,
+            update_authority: 2,
+            master_edition: 3,
+            resource: 4,
+            metadata: 5,
+            system_program: 6,
+            rent: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for DeprecatedCreateReservationList {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "reservation_list",
+            "payer",
+            "update_authority",
+            "master_edition",
+            "resource",
+            "metadata",
+            "system_program",
+            "rent",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct DeprecatedMintPrintingTokensInstructionAccounts {
     pub master_edition: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for DeprecatedMintPrintingTokens {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for DeprecatedMintPrintingTokens
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [destination, printing_mint, update_authority, metadata, master_edition, token_program, rent, _remaining @ ..] =
+        let [destination, printing_mint, update_authority, metadata, master_edition, token_program, rent, remaining @ ..] =
             accounts
         else {
             return None;
@@ -36,6 +38,91 @@ impl carbon_core::deserialize::ArrangeAccounts for DeprecatedMintPrintingTokens
             master_edition: master_edition.pubkey,
             token_program: token_program.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DeprecatedMintPrintingTokensInstructionAccountMetas {
+    pub destination: solana_instruction::AccountMeta,
+    pub printing_mint: solana_instruction::AccountMeta,
+    pub update_authority: solana_instruction::AccountMeta,
+    pub metadata: solana_instruction::AccountMeta,
+    pub master_edition: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for DeprecatedMintPrintingTokens {
+    type ArrangedAccountMetas = DeprecatedMintPrintingTokensInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [destination, printing_mint, update_authority, metadata, master_edition, token_program, rent, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(DeprecatedMintPrintingTokensInstructionAccountMetas {
+            destination: destination.clone(),
+            printing_mint: printing_mint.clone(),
+            update_authority: update_authority.clone(),
+            metadata: metadata.clone(),
+            master_edition: master_edition.clone(),
+            token_program: token_program.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DeprecatedMintPrintingTokensInstructionAccountIndices {
+    pub destination: usize,
+    pub printing_mint: usize,
+    pub update_authority: usize,
+    pub metadata: usize,
+    pub master_edition: usize,
+    pub token_program: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for DeprecatedMintPrintingTokens {
+    type ArrangedAccountIndices = DeprecatedMintPrintingTokensInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 7 {
+            return None;
+        }
+
+        Some(DeprecatedMintPrintingTokensInstructionAccountIndices {
+            destination: 0,
+            printing_mint: 1,
+            update_authority: 2,
+            metadata: 3,
+            master_edition: 4,
+            token_program: 5,
+            rent: 6,
+            remaining_accounts: 7..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for DeprecatedMintPrintingTokens {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "destination",
+            "printing_mint",
+            "update_authority",
+            "metadata",
+            "master_edition",
+            "token_program",
+            "rent",
+        ]
+    }
+}
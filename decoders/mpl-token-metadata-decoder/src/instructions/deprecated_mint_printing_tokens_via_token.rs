@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -16,6 +17,7 @@ pub struct DeprecatedMintPrintingTokensViaTokenInstructionAccounts {
     pub master_edition: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for DeprecatedMintPrintingTokensViaToken {
@@ -24,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for DeprecatedMintPrintingTokensV
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [destination, token, one_time_printing_authorization_mint, printing_mint, burn_authority, metadata, master_edition, token_program, rent, _remaining @ ..] =
+        let [destination, token, one_time_printing_authorization_mint, printing_mint, burn_authority, metadata, master_edition, token_program, rent, remaining @ ..] =
             accounts
         else {
             return None;
@@ -40,6 +42,105 @@ impl carbon_core::deserialize::ArrangeAccounts for DeprecatedMintPrintingTokensV
             master_edition: master_edition.pubkey,
             token_program: token_program.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DeprecatedMintPrintingTokensViaTokenInstructionAccountMetas {
+    pub destination: solana_instruction::AccountMeta,
+    pub token: solana_instruction::AccountMeta,
+    pub one_time_printing_authorization_mint: solana_instruction::AccountMeta,
+    pub printing_mint: solana_instruction::AccountMeta,
+    pub burn_authority: solana_instruction::AccountMeta,
+    pub metadata: solana_instruction::AccountMeta,
+    pub master_edition: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for DeprecatedMintPrintingTokensViaToken {
+    type ArrangedAccountMetas = DeprecatedMintPrintingTokensViaTokenInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [destination, token, one_time_printing_authorization_mint, printing_mint, burn_authority, metadata, master_edition, token_program, rent, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(
+            DeprecatedMintPrintingTokensViaTokenInstructionAccountMetas {
+                destination: destination.clone(),
+                token: token.clone(),
+                one_time_printing_authorization_mint: one_time_printing_authorization_mint.clone(),
+                printing_mint: printing_mint.clone(),
+                burn_authority: burn_authority.clone(),
+                metadata: metadata.clone(),
+                master_edition: master_edition.clone(),
+                token_program: token_program.clone(),
+                rent: rent.clone(),
+                remaining_accounts: remaining.to_vec(),
+            },
+        )
+    }
+}
+
+pub struct DeprecatedMintPrintingTokensViaTokenInstructionAccountIndices {
+    pub destination: usize,
+    pub token: usize,
+    pub one_time_printing_authorization_mint: usize,
+    pub printing_mint: usize,
+    pub burn_authority: usize,
+    pub metadata: usize,
+    pub master_edition: usize,
+    pub token_program: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for DeprecatedMintPrintingTokensViaToken {
+    type ArrangedAccountIndices = DeprecatedMintPrintingTokensViaTokenInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 9 {
+            return None;
+        }
+
+        Some(
+            DeprecatedMintPrintingTokensViaTokenInstructionAccountIndices {
+                destination: 0,
+                token: 1,
+                one_time_printing_authorization_mint: 2,
+                printing_mint: 3,
+                burn_authority: 4,
+                metadata: 5,
+                master_edition: 6,
+                token_program: 7,
+                rent: 8,
+                remaining_accounts: 9..accounts.len(),
+            },
+        )
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for DeprecatedMintPrintingTokensViaToken {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "destination",
+            "token",
+            "one_time_printing_authorization_mint",
+            "printing_mint",
+            "burn_authority",
+            "metadata",
+            "master_edition",
+            "token_program",
+            "rent",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -10,6 +11,7 @@ pub struct DeprecatedSetReservationListInstructionAccounts {
     pub master_edition: solana_pubkey::Pubkey,
     pub reservation_list: solana_pubkey::Pubkey,
     pub resource: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for DeprecatedSetReservationList {
@@ -18,7 +20,7 @@ impl carbon_core::deserialize::ArrangeAccounts for DeprecatedSetReservationList
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [master_edition, reservation_list, resource, _remaining @ ..] = accounts else {
+        let [master_edition, reservation_list, resource, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -26,6 +28,65 @@ impl carbon_core::deserialize::ArrangeAccounts for DeprecatedSetReservationList
             master_edition: master_edition.pubkey,
             reservation_list: reservation_list.pubkey,
             resource: resource.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DeprecatedSetReservationListInstructionAccountMetas {
+    pub master_edition: solana_instruction::AccountMeta,
+    pub reservation_list: solana_instruction::AccountMeta,
+    pub resource: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for DeprecatedSetReservationList {
+    type ArrangedAccountMetas = DeprecatedSetReservationListInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [master_edition, reservation_list, resource, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(DeprecatedSetReservationListInstructionAccountMetas {
+            master_edition: master_edition.clone(),
+            reservation_list: reservation_list.clone(),
+            resource: resource.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DeprecatedSetReservationListInstructionAccountIndices {
+    pub master_edition: usize,
+    pub reservation_list: usize,
+    pub resource: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for DeprecatedSetReservationList {
+    type ArrangedAccountIndices = DeprecatedSetReservationListInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(DeprecatedSetReservationListInstructionAccountIndices {
+            master_edition: 0,
+            reservation_list: 1,
+            resource: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for DeprecatedSetReservationList {
+    fn account_names() -> &'static [&'static str] {
+        &["master_edition", "reservation_list", "resource"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +13,7 @@ pub struct FreezeDelegatedAccountInstructionAccounts {
     pub edition: solana_pubkey::Pubkey,
     pub mint: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for FreezeDelegatedAccount {
@@ -20,7 +22,7 @@ impl carbon_core::deserialize::ArrangeAccounts for FreezeDelegatedAccount {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [delegate, token_account, edition, mint, token_program, _remaining @ ..] = accounts
+        let [delegate, token_account, edition, mint, token_program, remaining @ ..] = accounts
         else {
             return None;
         };
@@ -31,6 +33,80 @@ impl carbon_core::deserialize::ArrangeAccounts for FreezeDelegatedAccount {
             edition: edition.pubkey,
             mint: mint.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct FreezeDelegatedAccountInstructionAccountMetas {
+    pub delegate: solana_instruction::AccountMeta,
+    pub token_account: solana_instruction::AccountMeta,
+    pub edition: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for FreezeDelegatedAccount {
+    type ArrangedAccountMetas = FreezeDelegatedAccountInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [delegate, token_account, edition, mint, token_program, remaining @ ..] = accounts
+        else {
+            return None;
+        };
+
+        Some(FreezeDelegatedAccountInstructionAccountMetas {
+            delegate: delegate.clone(),
+            token_account: token_account.clone(),
+            edition: edition.clone(),
+            mint: mint.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct FreezeDelegatedAccountInstructionAccountIndices {
+    pub delegate: usize,
+    pub token_account: usize,
+    pub edition: usize,
+    pub mint: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for FreezeDelegatedAccount {
+    type ArrangedAccountIndices = FreezeDelegatedAccountInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(FreezeDelegatedAccountInstructionAccountIndices {
+            delegate: 0,
+            token_account: 1,
+            edition: 2,
+            mint: 3,
+            token_program: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for FreezeDelegatedAccount {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "delegate",
+            "token_account",
+            "edition",
+            "mint",
+            "token_program",
+        ]
+    }
+}
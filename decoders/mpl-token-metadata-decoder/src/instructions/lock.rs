@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -25,6 +26,7 @@ pub struct LockInstructionAccounts {
     pub spl_token_program: solana_pubkey::Pubkey,
     pub authorization_rules_program: solana_pubkey::Pubkey,
     pub authorization_rules: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Lock {
@@ -33,7 +35,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Lock {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [authority, token_owner, token, mint, metadata, edition, token_record, payer, system_program, sysvar_instructions, spl_token_program, authorization_rules_program, authorization_rules, _remaining @ ..] =
+        let [authority, token_owner, token, mint, metadata, edition, token_record, payer, system_program, sysvar_instructions, spl_token_program, authorization_rules_program, authorization_rules, remaining @ ..] =
             accounts
         else {
             return None;
@@ -53,6 +55,121 @@ impl carbon_core::deserialize::ArrangeAccounts for Lock {
             spl_token_program: spl_token_program.pubkey,
             authorization_rules_program: authorization_rules_program.pubkey,
             authorization_rules: authorization_rules.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct LockInstructionAccountMetas {
+    pub authority: solana_instruction::AccountMeta,
+    pub token_owner: solana_instruction::AccountMeta,
+    pub token: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub metadata: solana_instruction::AccountMeta,
+    pub edition: solana_instruction::AccountMeta,
+    pub token_record: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub sysvar_instructions: solana_instruction::AccountMeta,
+    pub spl_token_program: solana_instruction::AccountMeta,
+    pub authorization_rules_program: solana_instruction::AccountMeta,
+    pub authorization_rules: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Lock {
+    type ArrangedAccountMetas = LockInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [authority, token_owner, token, mint, metadata, edition, token_record, payer, system_program, sysvar_instructions, spl_token_program, authorization_rules_program, authorization_rules, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(LockInstructionAccountMetas {
+            authority: authority.clone(),
+            token_owner: token_owner.clone(),
+            token: token.clone(),
+            mint: mint.clone(),
+            metadata: metadata.clone(),
+            edition: edition.clone(),
+            token_record: token_record.clone(),
+            payer: payer.clone(),
+            system_program: system_program.clone(),
+            sysvar_instructions: sysvar_instructions.clone(),
+            spl_token_program: spl_token_program.clone(),
+            authorization_rules_program: authorization_rules_program.clone(),
+            authorization_rules: authorization_rules.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct LockInstructionAccountIndices {
+    pub authority: usize,
+    pub token_owner: usize,
+    pub token: usize,
+    pub mint: usize,
+    pub metadata: usize,
+    pub edition: usize,
+    pub token_record: usize,
+    pub payer: usize,
+    pub system_program: usize,
+    pub sysvar_instructions: usize,
+    pub spl_token_program: usize,
+    pub authorization_rules_program: usize,
+    pub authorization_rules: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Lock {
+    type ArrangedAccountIndices = LockInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 13 {
+            return None;
+        }
+
+        Some(LockInstructionAccountIndices {
+            authority: 0,
+            token_owner: 1,
+            token: 2,
+            mint: 3,
+            metadata: 4,
+            edition: 5,
+            token_record: 6,
+            payer: 7,
+            system_program: 8,
+            sysvar_instructions: 9,
+            spl_token_program: 10,
+            authorization_rules_program: 11,
+            authorization_rules: 12,
+            remaining_accounts: 13..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Lock {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "authority",
+            "token_owner",
+            "token",
+            "mint",
+            "metadata",
+            "edition",
+            "token_record",
+            "payer",
+            "system_program",
+            "sysvar_instructions",
+            "spl_token_program",
+            "authorization_rules_program",
+            "authorization_rules",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -22,6 +23,7 @@ pub struct MigrateInstructionAccounts {
     pub spl_token_program: solana_pubkey::Pubkey,
     pub authorization_rules_program: solana_pubkey::Pubkey,
     pub authorization_rules: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Migrate {
@@ -30,7 +32,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Migrate {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [metadata, edition, token, token_owner, mint, payer, authority, collection_metadata, delegate_record, token_record, system_program, sysvar_instructions, spl_token_program, authorization_rules_program, authorization_rules, _remaining @ ..] =
+        let [metadata, edition, token, token_owner, mint, payer, authority, collection_metadata, delegate_record, token_record, system_program, sysvar_instructions, spl_token_program, authorization_rules_program, authorization_rules, remaining @ ..] =
             accounts
         else {
             return None;
@@ -52,6 +54,131 @@ impl carbon_core::deserialize::ArrangeAccounts for Migrate {
             spl_token_program: spl_token_program.pubkey,
             authorization_rules_program: authorization_rules_program.pubkey,
             authorization_rules: authorization_rules.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct MigrateInstructionAccountMetas {
+    pub metadata: solana_instruction::AccountMeta,
+    pub edition: solana_instruction::AccountMeta,
+    pub token: solana_instruction::AccountMeta,
+    pub token_owner: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub collection_metadata: solana_instruction::AccountMeta,
+    pub delegate_record: solana_instruction::AccountMeta,
+    pub token_record: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub sysvar_instructions: solana_instruction::AccountMeta,
+    pub spl_token_program: solana_instruction::AccountMeta,
+    pub authorization_rules_program: solana_instruction::AccountMeta,
+    pub authorization_rules: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Migrate {
+    type ArrangedAccountMetas = MigrateInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [metadata, edition, token, token_owner, mint, payer, authority, collection_metadata, delegate_record, token_record, system_program, sysvar_instructions, spl_token_program, authorization_rules_program, authorization_rules, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(MigrateInstructionAccountMetas {
+            metadata: metadata.clone(),
+            edition: edition.clone(),
+            token: token.clone(),
+            token_owner: token_owner.clone(),
+            mint: mint.clone(),
+            payer: payer.clone(),
+            authority: authority.clone(),
+            collection_metadata: collection_metadata.clone(),
+            delegate_record: delegate_record.clone(),
+            token_record: token_record.clone(),
+            system_program: system_program.clone(),
+            sysvar_instructions: sysvar_instructions.clone(),
+            spl_token_program: spl_token_program.clone(),
+            authorization_rules_program: authorization_rules_program.clone(),
+            authorization_rules: authorization_rules.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct MigrateInstructionAccountIndices {
+    pub metadata: usize,
+    pub edition: usize,
+    pub token: usize,
+    pub token_owner: usize,
+    pub mint: usize,
+    pub payer: usize,
+    pub authority: usize,
+    pub collection_metadata: usize,
+    pub delegate_record: usize,
+    pub token_record: usize,
+    pub system_program: usize,
+    pub sysvar_instructions: usize,
+    pub spl_token_program: usize,
+    pub authorization_rules_program: usize,
+    pub authorization_rules: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Migrate {
+    type ArrangedAccountIndices = MigrateInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 15 {
+            return None;
+        }
+
+        Some(MigrateInstructionAccountIndices {
+            metadata: 0,
+            edition: 1,
+            token: 2,
+            token_owner: 3,
+            mint: 4,
+            payer: 5,
+            authority: 6,
+            collection_metadata: 7,
+            delegate_record: 8,
+            token_record: 9,
+            system_program: 10,
+            sysvar_instructions: 11,
+            spl_token_program: 12,
+            authorization_rules_program: 13,
+            authorization_rules: 14,
+            remaining_accounts: 15..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Migrate {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "metadata",
+            "edition",
+            "token",
+            "token_owner",
+            "mint",
+            "payer",
+            "authority",
+            "collection_metadata",
+            "delegate_record",
+            "token_record",
+            "system_program",
+            "sysvar_instructions",
+            "spl_token_program",
+            "authorization_rules_program",
+            "authorization_rules",
+        ]
+    }
+}
@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -27,6 +28,7 @@ pub struct MintInstructionAccounts {
     pub spl_ata_program: solana_pubkey::Pubkey,
     pub authorization_rules_program: solana_pubkey::Pubkey,
     pub authorization_rules: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Mint {
@@ -35,7 +37,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Mint {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [token, token_owner, metadata, master_edition, token_record, mint, authority, delegate_record, payer, system_program, sysvar_instructions, spl_token_program, spl_ata_program, authorization_rules_program, authorization_rules, _remaining @ ..] =
+        let [token, token_owner, metadata, master_edition, token_record, mint, authority, delegate_record, payer, system_program, sysvar_instructions, spl_token_program, spl_ata_program, authorization_rules_program, authorization_rules, remaining @ ..] =
             accounts
         else {
             return None;
@@ -57,6 +59,131 @@ impl carbon_core::deserialize::ArrangeAccounts for Mint {
             spl_ata_program: spl_ata_program.pubkey,
             authorization_rules_program: authorization_rules_program.pubkey,
             authorization_rules: authorization_rules.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct MintInstructionAccountMetas {
+    pub token: solana_instruction::AccountMeta,
+    pub token_owner: solana_instruction::AccountMeta,
+    pub metadata: solana_instruction::AccountMeta,
+    pub master_edition: solana_instruction::AccountMeta,
+    pub token_record: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub delegate_record: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub sysvar_instructions: solana_instruction::AccountMeta,
+    pub spl_token_program: solana_instruction::AccountMeta,
+    pub spl_ata_program: solana_instruction::AccountMeta,
+    pub authorization_rules_program: solana_instruction::AccountMeta,
+    pub authorization_rules: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Mint {
+    type ArrangedAccountMetas = MintInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [token, token_owner, metadata, master_edition, token_record, mint, authority, delegate_record, payer, system_program, sysvar_instructions, spl_token_program, spl_ata_program, authorization_rules_program, authorization_rules, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(MintInstructionAccountMetas {
+            token: token.clone(),
+            token_owner: token_owner.clone(),
+            metadata: metadata.clone(),
+            master_edition: master_edition.clone(),
+            token_record: token_record.clone(),
+            mint: mint.clone(),
+            authority: authority.clone(),
+            delegate_record: delegate_record.clone(),
+            payer: payer.clone(),
+            system_program: system_program.clone(),
+            sysvar_instructions: sysvar_instructions.clone(),
+            spl_token_program: spl_token_program.clone(),
+            spl_ata_program: spl_ata_program.clone(),
+            authorization_rules_program: authorization_rules_program.clone(),
+            authorization_rules: authorization_rules.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct MintInstructionAccountIndices {
+    pub token: usize,
+    pub token_owner: usize,
+    pub metadata: usize,
+    pub master_edition: usize,
+    pub token_record: usize,
+    pub mint: usize,
+    pub authority: usize,
+    pub delegate_record: usize,
+    pub payer: usize,
+    pub system_program: usize,
+    pub sysvar_instructions: usize,
+    pub spl_token_program: usize,
+    pub spl_ata_program: usize,
+    pub authorization_rules_program: usize,
+    pub authorization_rules: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Mint {
+    type ArrangedAccountIndices = MintInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 15 {
+            return None;
+        }
+
+        Some(MintInstructionAccountIndices {
+            token: 0,
+            token_owner: 1,
+            metadata: 2,
+            master_edition: 3,
+            token_record: 4,
+            mint: 5,
+            authority: 6,
+            delegate_record: 7,
+            payer: 8,
+            system_program: 9,
+            sysvar_instructions: 10,
+            spl_token_program: 11,
+            spl_ata_program: 12,
+            authorization_rules_program: 13,
+            authorization_rules: 14,
+            remaining_accounts: 15..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Mint {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "token",
+            "token_owner",
+            "metadata",
+            "master_edition",
+            "token_record",
+            "mint",
+            "authority",
+            "delegate_record",
+            "payer",
+            "system_program",
+            "sysvar_instructions",
+            "spl_token_program",
+            "spl_ata_program",
+            "authorization_rules_program",
+            "authorization_rules",
+        ]
+    }
+}
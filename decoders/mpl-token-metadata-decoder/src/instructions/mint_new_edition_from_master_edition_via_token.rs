@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -27,6 +28,7 @@ pub struct MintNewEditionFromMasterEditionViaTokenInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for MintNewEditionFromMasterEditionViaToken {
@@ -35,7 +37,7 @@ impl carbon_core::deserialize::ArrangeAccounts for MintNewEditionFromMasterEditi
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [new_metadata, new_edition, master_edition, new_mint, edition_mark_pda, new_mint_authority, payer, token_account_owner, token_account, new_metadata_update_authority, metadata, token_program, system_program, rent, _remaining @ ..] =
+        let [new_metadata, new_edition, master_edition, new_mint, edition_mark_pda, new_mint_authority, payer, token_account_owner, token_account, new_metadata_update_authority, metadata, token_program, system_program, rent, remaining @ ..] =
             accounts
         else {
             return None;
@@ -56,6 +58,130 @@ impl carbon_core::deserialize::ArrangeAccounts for MintNewEditionFromMasterEditi
             token_program: token_program.pubkey,
             system_program: system_program.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct MintNewEditionFromMasterEditionViaTokenInstructionAccountMetas {
+    pub new_metadata: solana_instruction::AccountMeta,
+    pub new_edition: solana_instruction::AccountMeta,
+    pub master_edition: solana_instruction::AccountMeta,
+    pub new_mint: solana_instruction::AccountMeta,
+    pub edition_mark_pda: solana_instruction::AccountMeta,
+    pub new_mint_authority: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub token_account_owner: solana_instruction::AccountMeta,
+    pub token_account: solana_instruction::AccountMeta,
+    pub new_metadata_update_authority: solana_instruction::AccountMeta,
+    pub metadata: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for MintNewEditionFromMasterEditionViaToken {
+    type ArrangedAccountMetas = MintNewEditionFromMasterEditionViaTokenInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [new_metadata, new_edition, master_edition, new_mint, edition_mark_pda, new_mint_authority, payer, token_account_owner, token_account, new_metadata_update_authority, metadata, token_program, system_program, rent, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(
+            MintNewEditionFromMasterEditionViaTokenInstructionAccountMetas {
+                new_metadata: new_metadata.clone(),
+                new_edition: new_edition.clone(),
+                master_edition: master_edition.clone(),
+                new_mint: new_mint.clone(),
+                edition_mark_pda: edition_mark_pda.clone(),
+                new_mint_authority: new_mint_authority.clone(),
+                payer: payer.clone(),
+                token_account_owner: token_account_owner.clone(),
+                token_account: token_account.clone(),
+                new_metadata_update_authority: new_metadata_update_authority.clone(),
+                metadata: metadata.clone(),
+                token_program: token_program.clone(),
+                system_program: system_program.clone(),
+                rent: rent.clone(),
+                remaining_accounts: remaining.to_vec(),
+            },
+        )
+    }
+}
+
+pub struct MintNewEditionFromMasterEditionViaTokenInstructionAccountIndices {
+    pub new_metadata: usize,
+    pub new_edition: usize,
+    pub master_edition: usize,
+    pub new_mint: usize,
+    pub edition_mark_pda: usize,
+    pub new_mint_authority: usize,
+    pub payer: usize,
+    pub token_account_owner: usize,
+    pub token_account: usize,
+    pub new_metadata_update_authority: usize,
+    pub metadata: usize,
+    pub token_program: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for MintNewEditionFromMasterEditionViaToken {
+    type ArrangedAccountIndices = MintNewEditionFromMasterEditionViaTokenInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 14 {
+            return None;
+        }
+
+        Some(
+            MintNewEditionFromMasterEditionViaTokenInstructionAccountIndices {
+                new_metadata: 0,
+                new_edition: 1,
+                master_edition: 2,
+                new_mint: 3,
+                edition_mark_pda: 4,
+                new_mint_authority: 5,
+                payer: 6,
+                token_account_owner: 7,
+                token_account: 8,
+                new_metadata_update_authority: 9,
+                metadata: 10,
+                token_program: 11,
+                system_program: 12,
+                rent: 13,
+                remaining_accounts: 14..accounts.len(),
+            },
+        )
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for MintNewEditionFromMasterEditionViaToken {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "new_metadata",
+            "new_edition",
+            "master_edition",
+            "new_mint",
+            "edition_mark_pda",
+            "new_mint_authority",
+            "payer",
+            "token_account_owner",
+            "token_account",
+            "new_metadata_update_authority",
+            "metadata",
+            "token_program",
+            "system_program",
+            "rent",
+        ]
+    }
+}
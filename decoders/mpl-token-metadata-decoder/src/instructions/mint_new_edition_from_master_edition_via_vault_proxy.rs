@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -30,6 +31,7 @@ pub struct MintNewEditionFromMasterEditionViaVaultProxyInstructionAccounts {
     pub token_vault_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for MintNewEditionFromMasterEditionViaVaultProxy {
@@ -38,7 +40,7 @@ impl carbon_core::deserialize::ArrangeAccounts for MintNewEditionFromMasterEditi
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [new_metadata, new_edition, master_edition, new_mint, edition_mark_pda, new_mint_authority, payer, vault_authority, safety_deposit_store, safety_deposit_box, vault, new_metadata_update_authority, metadata, token_program, token_vault_program, system_program, rent, _remaining @ ..] =
+        let [new_metadata, new_edition, master_edition, new_mint, edition_mark_pda, new_mint_authority, payer, vault_authority, safety_deposit_store, safety_deposit_box, vault, new_metadata_update_authority, metadata, token_program, token_vault_program, system_program, rent, remaining @ ..] =
             accounts
         else {
             return None;
@@ -63,7 +65,151 @@ impl carbon_core::deserialize::ArrangeAccounts for MintNewEditionFromMasterEditi
                 token_vault_program: token_vault_program.pubkey,
                 system_program: system_program.pubkey,
                 rent: rent.pubkey,
+                remaining_accounts: remaining.to_vec(),
             },
         )
     }
 }
+
+pub struct MintNewEditionFromMasterEditionViaVaultProxyInstructionAccountMetas {
+    pub new_metadata: solana_instruction::AccountMeta,
+    pub new_edition: solana_instruction::AccountMeta,
+    pub master_edition: solana_instruction::AccountMeta,
+    pub new_mint: solana_instruction::AccountMeta,
+    pub edition_mark_pda: solana_instruction::AccountMeta,
+    pub new_mint_authority: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub vault_authority: solana_instruction::AccountMeta,
+    pub safety_deposit_store: solana_instruction::AccountMeta,
+    pub safety_deposit_box: solana_instruction::AccountMeta,
+    pub vault: solana_instruction::AccountMeta,
+    pub new_metadata_update_authority: solana_instruction::AccountMeta,
+    pub metadata: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub token_vault_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas
+    for MintNewEditionFromMasterEditionViaVaultProxy
+{
+    type ArrangedAccountMetas = MintNewEditionFromMasterEditionViaVaultProxyInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [new_metadata, new_edition, master_edition, new_mint, edition_mark_pda, new_mint_authority, payer, vault_authority, safety_deposit_store, safety_deposit_box, vault, new_metadata_update_authority, metadata, token_program, token_vault_program, system_program, rent, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(
+            MintNewEditionFromMasterEditionViaVaultProxyInstructionAccountMetas {
+                new_metadata: new_metadata.clone(),
+                new_edition: new_edition.clone(),
+                master_edition: master_edition.clone(),
+                new_mint: new_mint.clone(),
+                edition_mark_pda: edition_mark_pda.clone(),
+                new_mint_authority: new_mint_authority.clone(),
+                payer: payer.clone(),
+                vault_authority: vault_authority.clone(),
+                safety_deposit_store: safety_deposit_store.clone(),
+                safety_deposit_box: safety_deposit_box.clone(),
+                vault: vault.clone(),
+                new_metadata_update_authority: new_metadata_update_authority.clone(),
+                metadata: metadata.clone(),
+                token_program: token_program.clone(),
+                token_vault_program: token_vault_program.clone(),
+                system_program: system_program.clone(),
+                rent: rent.clone(),
+                remaining_accounts: remaining.to_vec(),
+            },
+        )
+    }
+}
+
+pub struct MintNewEditionFromMasterEditionViaVaultProxyInstructionAccountIndices {
+    pub new_metadata: usize,
+    pub new_edition: usize,
+    pub master_edition: usize,
+    pub new_mint: usize,
+    pub edition_mark_pda: usize,
+    pub new_mint_authority: usize,
+    pub payer: usize,
+    pub vault_authority: usize,
+    pub safety_deposit_store: usize,
+    pub safety_deposit_box: usize,
+    pub vault: usize,
+    pub new_metadata_update_authority: usize,
+    pub metadata: usize,
+    pub token_program: usize,
+    pub token_vault_program: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices
+    for MintNewEditionFromMasterEditionViaVaultProxy
+{
+    type ArrangedAccountIndices =
+        MintNewEditionFromMasterEditionViaVaultProxyInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 17 {
+            return None;
+        }
+
+        Some(
+            MintNewEditionFromMasterEditionViaVaultProxyInstructionAccountIndices {
+                new_metadata: 0,
+                new_edition: 1,
+                master_edition: 2,
+                new_mint: 3,
+                edition_mark_pda: 4,
+                new_mint_authority: 5,
+                payer: 6,
+                vault_authority: 7,
+                safety_deposit_store: 8,
+                safety_deposit_box: 9,
+                vault: 10,
+                new_metadata_update_authority: 11,
+                metadata: 12,
+                token_program: 13,
+                token_vault_program: 14,
+                system_program: 15,
+                rent: 16,
+                remaining_accounts: 17..accounts.len(),
+            },
+        )
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for MintNewEditionFromMasterEditionViaVaultProxy {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "new_metadata",
+            "new_edition",
+            "master_edition",
+            "new_mint",
+            "edition_mark_pda",
+            "new_mint_authority",
+            "payer",
+            "vault_authority",
+            "safety_deposit_store",
+            "safety_deposit_box",
+            "vault",
+            "new_metadata_update_authority",
+            "metadata",
+            "token_program",
+            "token_vault_program",
+            "system_program",
+            "rent",
+        ]
+    }
+}
@@ -134,15 +134,25 @@ pub enum TokenMetadataInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for TokenMetadataDecoder {
     type InstructionType = TokenMetadataInstruction;
 
-    fn decode_instruction(
+    fn decoder_name(&self) -> &'static str {
+        "token_metadata"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[super::PROGRAM_ID]
+    }
+
+    fn try_decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
-    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+    ) -> carbon_core::error::CarbonResult<
+        Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>>,
+    > {
         if !instruction.program_id.eq(&PROGRAM_ID) {
-            return None;
+            return Ok(None);
         }
 
-        carbon_core::try_decode_instructions!(instruction,
+        Ok(carbon_core::try_decode_instructions!(instruction,
             TokenMetadataInstruction::CreateMetadataAccount => create_metadata_account::CreateMetadataAccount,
             TokenMetadataInstruction::UpdateMetadataAccount => update_metadata_account::UpdateMetadataAccount,
             TokenMetadataInstruction::DeprecatedCreateMasterEdition => deprecated_create_master_edition::DeprecatedCreateMasterEdition,
@@ -201,6 +211,6 @@ impl carbon_core::instruction::InstructionDecoder<'_> for TokenMetadataDecoder {
             TokenMetadataInstruction::Print => print::Print,
             TokenMetadataInstruction::Resize => resize::Resize,
             TokenMetadataInstruction::CloseAccounts => close_accounts::CloseAccounts,
-        )
+        ))
     }
 }
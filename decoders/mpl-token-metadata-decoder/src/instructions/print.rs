@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -30,6 +31,7 @@ pub struct PrintInstructionAccounts {
     pub spl_ata_program: solana_pubkey::Pubkey,
     pub sysvar_instructions: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Print {
@@ -38,7 +40,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Print {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [edition_metadata, edition, edition_mint, edition_token_account_owner, edition_token_account, edition_mint_authority, edition_token_record, master_edition, edition_marker_pda, payer, master_token_account_owner, master_token_account, master_metadata, update_authority, spl_token_program, spl_ata_program, sysvar_instructions, system_program, _remaining @ ..] =
+        let [edition_metadata, edition, edition_mint, edition_token_account_owner, edition_token_account, edition_mint_authority, edition_token_record, master_edition, edition_marker_pda, payer, master_token_account_owner, master_token_account, master_metadata, update_authority, spl_token_program, spl_ata_program, sysvar_instructions, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -63,6 +65,146 @@ impl carbon_core::deserialize::ArrangeAccounts for Print {
             spl_ata_program: spl_ata_program.pubkey,
             sysvar_instructions: sysvar_instructions.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct PrintInstructionAccountMetas {
+    pub edition_metadata: solana_instruction::AccountMeta,
+    pub edition: solana_instruction::AccountMeta,
+    pub edition_mint: solana_instruction::AccountMeta,
+    pub edition_token_account_owner: solana_instruction::AccountMeta,
+    pub edition_token_account: solana_instruction::AccountMeta,
+    pub edition_mint_authority: solana_instruction::AccountMeta,
+    pub edition_token_record: solana_instruction::AccountMeta,
+    pub master_edition: solana_instruction::AccountMeta,
+    pub edition_marker_pda: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub master_token_account_owner: solana_instruction::AccountMeta,
+    pub master_token_account: solana_instruction::AccountMeta,
+    pub master_metadata: solana_instruction::AccountMeta,
+    pub update_authority: solana_instruction::AccountMeta,
+    pub spl_token_program: solana_instruction::AccountMeta,
+    pub spl_ata_program: solana_instruction::AccountMeta,
+    pub sysvar_instructions: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Print {
+    type ArrangedAccountMetas = PrintInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [edition_metadata, edition, edition_mint, edition_token_account_owner, edition_token_account, edition_mint_authority, edition_token_record, master_edition, edition_marker_pda, payer, master_token_account_owner, master_token_account, master_metadata, update_authority, spl_token_program, spl_ata_program, sysvar_instructions, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(PrintInstructionAccountMetas {
+            edition_metadata: edition_metadata.clone(),
+            edition: edition.clone(),
+            edition_mint: edition_mint.clone(),
+            edition_token_account_owner: edition_token_account_owner.clone(),
+            edition_token_account: edition_token_account.clone(),
+            edition_mint_authority: edition_mint_authority.clone(),
+            edition_token_record: edition_token_record.clone(),
+            master_edition: master_edition.clone(),
+            edition_marker_pda: edition_marker_pda.clone(),
+            payer: payer.clone(),
+            master_token_account_owner: master_token_account_owner.clone(),
+            master_token_account: master_token_account.clone(),
+            master_metadata: master_metadata.clone(),
+            update_authority: update_authority.clone(),
+            spl_token_program: spl_token_program.clone(),
+            spl_ata_program: spl_ata_program.clone(),
+            sysvar_instructions: sysvar_instructions.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct PrintInstructionAccountIndices {
+    pub edition_metadata: usize,
+    pub edition: usize,
+    pub edition_mint: usize,
+    pub edition_token_account_owner: usize,
+    pub edition_token_account: usize,
+    pub edition_mint_authority: usize,
+    pub edition_token_record: usize,
+    pub master_edition: usize,
+    pub edition_marker_pda: usize,
+    pub payer: usize,
+    pub master_token_account_owner: usize,
+    pub master_token_account: usize,
+    pub master_metadata: usize,
+    pub update_authority: usize,
+    pub spl_token_program: usize,
+    pub spl_ata_program: usize,
+    pub sysvar_instructions: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Print {
+    type ArrangedAccountIndices = PrintInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 18 {
+            return None;
+        }
+
+        Some(PrintInstructionAccountIndices {
+            edition_metadata: 0,
+            edition: 1,
+            edition_mint: 2,
+            edition_token_account_owner: 3,
+            edition_token_account: 4,
+            edition_mint_authority: 5,
+            edition_token_record: 6,
+            master_edition: 7,
+            edition_marker_pda: 8,
+            payer: 9,
+            master_token_account_owner: 10,
+            master_token_account: 11,
+            master_metadata: 12,
+            update_authority: 13,
+            spl_token_program: 14,
+            spl_ata_program: 15,
+            sysvar_instructions: 16,
+            system_program: 17,
+            remaining_accounts: 18..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Print {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "edition_metadata",
+            "edition",
+            "edition_mint",
+            "edition_token_account_owner",
+            "edition_token_account",
+            "edition_mint_authority",
+            "edition_token_record",
+            "master_edition",
+            "edition_marker_pda",
+            "payer",
+            "master_token_account_owner",
+            "master_token_account",
+            "master_metadata",
+            "update_authority",
+            "spl_token_program",
+            "spl_ata_program",
+            "sysvar_instructions",
+            "system_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -8,6 +9,7 @@ pub struct PuffMetadata {}
 
 pub struct PuffMetadataInstructionAccounts {
     pub metadata: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for PuffMetadata {
@@ -16,12 +18,63 @@ impl carbon_core::deserialize::ArrangeAccounts for PuffMetadata {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [metadata, _remaining @ ..] = accounts else {
+        let [metadata, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(PuffMetadataInstructionAccounts {
             metadata: metadata.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct PuffMetadataInstructionAccountMetas {
+    pub metadata: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for PuffMetadata {
+    type ArrangedAccountMetas = PuffMetadataInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [metadata, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(PuffMetadataInstructionAccountMetas {
+            metadata: metadata.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct PuffMetadataInstructionAccountIndices {
+    pub metadata: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for PuffMetadata {
+    type ArrangedAccountIndices = PuffMetadataInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.is_empty() {
+            return None;
+        }
+
+        Some(PuffMetadataInstructionAccountIndices {
+            metadata: 0,
+            remaining_accounts: 1..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for PuffMetadata {
+    fn account_names() -> &'static [&'static str] {
+        &["metadata"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct ResizeInstructionAccounts {
     pub authority: solana_pubkey::Pubkey,
     pub token: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Resize {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Resize {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [metadata, edition, mint, payer, authority, token, system_program, _remaining @ ..] =
+        let [metadata, edition, mint, payer, authority, token, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -36,6 +38,91 @@ impl carbon_core::deserialize::ArrangeAccounts for Resize {
             authority: authority.pubkey,
             token: token.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ResizeInstructionAccountMetas {
+    pub metadata: solana_instruction::AccountMeta,
+    pub edition: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub token: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Resize {
+    type ArrangedAccountMetas = ResizeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [metadata, edition, mint, payer, authority, token, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(ResizeInstructionAccountMetas {
+            metadata: metadata.clone(),
+            edition: edition.clone(),
+            mint: mint.clone(),
+            payer: payer.clone(),
+            authority: authority.clone(),
+            token: token.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ResizeInstructionAccountIndices {
+    pub metadata: usize,
+    pub edition: usize,
+    pub mint: usize,
+    pub payer: usize,
+    pub authority: usize,
+    pub token: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Resize {
+    type ArrangedAccountIndices = ResizeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 7 {
+            return None;
+        }
+
+        Some(ResizeInstructionAccountIndices {
+            metadata: 0,
+            edition: 1,
+            mint: 2,
+            payer: 3,
+            authority: 4,
+            token: 5,
+            system_program: 6,
+            remaining_accounts: 7..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Resize {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "metadata",
+            "edition",
+            "mint",
+            "payer",
+            "authority",
+            "token",
+            "system_program",
+        ]
+    }
+}
@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -26,6 +27,7 @@ pub struct RevokeInstructionAccounts {
     pub spl_token_program: solana_pubkey::Pubkey,
     pub authorization_rules_program: solana_pubkey::Pubkey,
     pub authorization_rules: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Revoke {
@@ -34,7 +36,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Revoke {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [delegate_record, delegate, metadata, master_edition, token_record, mint, token, authority, payer, system_program, sysvar_instructions, spl_token_program, authorization_rules_program, authorization_rules, _remaining @ ..] =
+        let [delegate_record, delegate, metadata, master_edition, token_record, mint, token, authority, payer, system_program, sysvar_instructions, spl_token_program, authorization_rules_program, authorization_rules, remaining @ ..] =
             accounts
         else {
             return None;
@@ -55,6 +57,126 @@ impl carbon_core::deserialize::ArrangeAccounts for Revoke {
             spl_token_program: spl_token_program.pubkey,
             authorization_rules_program: authorization_rules_program.pubkey,
             authorization_rules: authorization_rules.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct RevokeInstructionAccountMetas {
+    pub delegate_record: solana_instruction::AccountMeta,
+    pub delegate: solana_instruction::AccountMeta,
+    pub metadata: solana_instruction::AccountMeta,
+    pub master_edition: solana_instruction::AccountMeta,
+    pub token_record: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub token: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub sysvar_instructions: solana_instruction::AccountMeta,
+    pub spl_token_program: solana_instruction::AccountMeta,
+    pub authorization_rules_program: solana_instruction::AccountMeta,
+    pub authorization_rules: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Revoke {
+    type ArrangedAccountMetas = RevokeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [delegate_record, delegate, metadata, master_edition, token_record, mint, token, authority, payer, system_program, sysvar_instructions, spl_token_program, authorization_rules_program, authorization_rules, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(RevokeInstructionAccountMetas {
+            delegate_record: delegate_record.clone(),
+            delegate: delegate.clone(),
+            metadata: metadata.clone(),
+            master_edition: master_edition.clone(),
+            token_record: token_record.clone(),
+            mint: mint.clone(),
+            token: token.clone(),
+            authority: authority.clone(),
+            payer: payer.clone(),
+            system_program: system_program.clone(),
+            sysvar_instructions: sysvar_instructions.clone(),
+            spl_token_program: spl_token_program.clone(),
+            authorization_rules_program: authorization_rules_program.clone(),
+            authorization_rules: authorization_rules.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct RevokeInstructionAccountIndices {
+    pub delegate_record: usize,
+    pub delegate: usize,
+    pub metadata: usize,
+    pub master_edition: usize,
+    pub token_record: usize,
+    pub mint: usize,
+    pub token: usize,
+    pub authority: usize,
+    pub payer: usize,
+    pub system_program: usize,
+    pub sysvar_instructions: usize,
+    pub spl_token_program: usize,
+    pub authorization_rules_program: usize,
+    pub authorization_rules: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Revoke {
+    type ArrangedAccountIndices = RevokeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 14 {
+            return None;
+        }
+
+        Some(RevokeInstructionAccountIndices {
+            delegate_record: 0,
+            delegate: 1,
+            metadata: 2,
+            master_edition: 3,
+            token_record: 4,
+            mint: 5,
+            token: 6,
+            authority: 7,
+            payer: 8,
+            system_program: 9,
+            sysvar_instructions: 10,
+            spl_token_program: 11,
+            authorization_rules_program: 12,
+            authorization_rules: 13,
+            remaining_accounts: 14..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Revoke {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "delegate_record",
+            "delegate",
+            "metadata",
+            "master_edition",
+            "token_record",
+            "mint",
+            "token",
+            "authority",
+            "payer",
+            "system_program",
+            "sysvar_instructions",
+            "spl_token_program",
+            "authorization_rules_program",
+            "authorization_rules",
+        ]
+    }
+}
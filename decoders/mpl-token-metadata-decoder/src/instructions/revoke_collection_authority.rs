@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +13,7 @@ pub struct RevokeCollectionAuthorityInstructionAccounts {
     pub revoke_authority: solana_pubkey::Pubkey,
     pub metadata: solana_pubkey::Pubkey,
     pub mint: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for RevokeCollectionAuthority {
@@ -20,7 +22,7 @@ impl carbon_core::deserialize::ArrangeAccounts for RevokeCollectionAuthority {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [collection_authority_record, delegate_authority, revoke_authority, metadata, mint, _remaining @ ..] =
+        let [collection_authority_record, delegate_authority, revoke_authority, metadata, mint, remaining @ ..] =
             accounts
         else {
             return None;
@@ -32,6 +34,81 @@ impl carbon_core::deserialize::ArrangeAccounts for RevokeCollectionAuthority {
             revoke_authority: revoke_authority.pubkey,
             metadata: metadata.pubkey,
             mint: mint.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct RevokeCollectionAuthorityInstructionAccountMetas {
+    pub collection_authority_record: solana_instruction::AccountMeta,
+    pub delegate_authority: solana_instruction::AccountMeta,
+    pub revoke_authority: solana_instruction::AccountMeta,
+    pub metadata: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for RevokeCollectionAuthority {
+    type ArrangedAccountMetas = RevokeCollectionAuthorityInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [collection_authority_record, delegate_authority, revoke_authority, metadata, mint, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(RevokeCollectionAuthorityInstructionAccountMetas {
+            collection_authority_record: collection_authority_record.clone(),
+            delegate_authority: delegate_authority.clone(),
+            revoke_authority: revoke_authority.clone(),
+            metadata: metadata.clone(),
+            mint: mint.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct RevokeCollectionAuthorityInstructionAccountIndices {
+    pub collection_authority_record: usize,
+    pub delegate_authority: usize,
+    pub revoke_authority: usize,
+    pub metadata: usize,
+    pub mint: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for RevokeCollectionAuthority {
+    type ArrangedAccountIndices = RevokeCollectionAuthorityInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(RevokeCollectionAuthorityInstructionAccountIndices {
+            collection_authority_record: 0,
+            delegate_authority: 1,
+            revoke_authority: 2,
+            metadata: 3,
+            mint: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for RevokeCollectionAuthority {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "collection_authority_record",
+            "delegate_authority",
+            "revoke_authority",
+            "metadata",
+            "mint",
+        ]
+    }
+}
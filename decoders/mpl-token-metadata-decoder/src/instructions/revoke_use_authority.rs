@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -16,6 +17,7 @@ pub struct RevokeUseAuthorityInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for RevokeUseAuthority {
@@ -24,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for RevokeUseAuthority {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [use_authority_record, owner, user, owner_token_account, mint, metadata, token_program, system_program, rent, _remaining @ ..] =
+        let [use_authority_record, owner, user, owner_token_account, mint, metadata, token_program, system_program, rent, remaining @ ..] =
             accounts
         else {
             return None;
@@ -40,6 +42,101 @@ impl carbon_core::deserialize::ArrangeAccounts for RevokeUseAuthority {
             token_program: token_program.pubkey,
             system_program: system_program.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct RevokeUseAuthorityInstructionAccountMetas {
+    pub use_authority_record: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub owner_token_account: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub metadata: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for RevokeUseAuthority {
+    type ArrangedAccountMetas = RevokeUseAuthorityInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [use_authority_record, owner, user, owner_token_account, mint, metadata, token_program, system_program, rent, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(RevokeUseAuthorityInstructionAccountMetas {
+            use_authority_record: use_authority_record.clone(),
+            owner: owner.clone(),
+            user: user.clone(),
+            owner_token_account: owner_token_account.clone(),
+            mint: mint.clone(),
+            metadata: metadata.clone(),
+            token_program: token_program.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct RevokeUseAuthorityInstructionAccountIndices {
+    pub use_authority_record: usize,
+    pub owner: usize,
+    pub user: usize,
+    pub owner_token_account: usize,
+    pub mint: usize,
+    pub metadata: usize,
+    pub token_program: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for RevokeUseAuthority {
+    type ArrangedAccountIndices = RevokeUseAuthorityInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 9 {
+            return None;
+        }
+
+        Some(RevokeUseAuthorityInstructionAccountIndices {
+            use_authority_record: 0,
+            owner: 1,
+            user: 2,
+            owner_token_account: 3,
+            mint: 4,
+            metadata: 5,
+            token_program: 6,
+            system_program: 7,
+            rent: 8,
+            remaining_accounts: 9..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for RevokeUseAuthority {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "use_authority_record",
+            "owner",
+            "user",
+            "owner_token_account",
+            "mint",
+            "metadata",
+            "token_program",
+            "system_program",
+            "rent",
+        ]
+    }
+}
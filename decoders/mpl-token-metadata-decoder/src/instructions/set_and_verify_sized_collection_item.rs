@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -15,6 +16,7 @@ pub struct SetAndVerifySizedCollectionItemInstructionAccounts {
     pub collection: solana_pubkey::Pubkey,
     pub collection_master_edition_account: solana_pubkey::Pubkey,
     pub collection_authority_record: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SetAndVerifySizedCollectionItem {
@@ -23,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for SetAndVerifySizedCollectionIt
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [metadata, collection_authority, payer, update_authority, collection_mint, collection, collection_master_edition_account, collection_authority_record, _remaining @ ..] =
+        let [metadata, collection_authority, payer, update_authority, collection_mint, collection, collection_master_edition_account, collection_authority_record, remaining @ ..] =
             accounts
         else {
             return None;
@@ -38,6 +40,96 @@ impl carbon_core::deserialize::ArrangeAccounts for SetAndVerifySizedCollectionIt
             collection: collection.pubkey,
             collection_master_edition_account: collection_master_edition_account.pubkey,
             collection_authority_record: collection_authority_record.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SetAndVerifySizedCollectionItemInstructionAccountMetas {
+    pub metadata: solana_instruction::AccountMeta,
+    pub collection_authority: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub update_authority: solana_instruction::AccountMeta,
+    pub collection_mint: solana_instruction::AccountMeta,
+    pub collection: solana_instruction::AccountMeta,
+    pub collection_master_edition_account: solana_instruction::AccountMeta,
+    pub collection_authority_record: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SetAndVerifySizedCollectionItem {
+    type ArrangedAccountMetas = SetAndVerifySizedCollectionItemInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [metadata, collection_authority, payer, update_authority, collection_mint, collection, collection_master_edition_account, collection_authority_record, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(SetAndVerifySizedCollectionItemInstructionAccountMetas {
+            metadata: metadata.clone(),
+            collection_authority: collection_authority.clone(),
+            payer: payer.clone(),
+            update_authority: update_authority.clone(),
+            collection_mint: collection_mint.clone(),
+            collection: collection.clone(),
+            collection_master_edition_account: collection_master_edition_account.clone(),
+            collection_authority_record: collection_authority_record.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SetAndVerifySizedCollectionItemInstructionAccountIndices {
+    pub metadata: usize,
+    pub collection_authority: usize,
+    pub payer: usize,
+    pub update_authority: usize,
+    pub collection_mint: usize,
+    pub collection: usize,
+    pub collection_master_edition_account: usize,
+    pub collection_authority_record: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SetAndVerifySizedCollectionItem {
+    type ArrangedAccountIndices = SetAndVerifySizedCollectionItemInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(SetAndVerifySizedCollectionItemInstructionAccountIndices {
+            metadata: 0,
+            collection_authority: 1,
+            payer: 2,
+            update_authority: 3,
+            collection_mint: 4,
+            collection: 5,
+            collection_master_edition_account: 6,
+            collection_authority_record: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SetAndVerifySizedCollectionItem {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "metadata",
+            "collection_authority",
+            "payer",
+            "update_authority",
+            "collection_mint",
+            "collection",
+            "collection_master_edition_account",
+            "collection_authority_record",
+        ]
+    }
+}
@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -16,6 +17,7 @@ pub struct SetCollectionSizeInstructionAccounts {
     pub collection_authority: solana_pubkey::Pubkey,
     pub collection_mint: solana_pubkey::Pubkey,
     pub collection_authority_record: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SetCollectionSize {
@@ -24,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for SetCollectionSize {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [collection_metadata, collection_authority, collection_mint, collection_authority_record, _remaining @ ..] =
+        let [collection_metadata, collection_authority, collection_mint, collection_authority_record, remaining @ ..] =
             accounts
         else {
             return None;
@@ -35,6 +37,76 @@ impl carbon_core::deserialize::ArrangeAccounts for SetCollectionSize {
             collection_authority: collection_authority.pubkey,
             collection_mint: collection_mint.pubkey,
             collection_authority_record: collection_authority_record.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SetCollectionSizeInstructionAccountMetas {
+    pub collection_metadata: solana_instruction::AccountMeta,
+    pub collection_authority: solana_instruction::AccountMeta,
+    pub collection_mint: solana_instruction::AccountMeta,
+    pub collection_authority_record: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SetCollectionSize {
+    type ArrangedAccountMetas = SetCollectionSizeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [collection_metadata, collection_authority, collection_mint, collection_authority_record, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(SetCollectionSizeInstructionAccountMetas {
+            collection_metadata: collection_metadata.clone(),
+            collection_authority: collection_authority.clone(),
+            collection_mint: collection_mint.clone(),
+            collection_authority_record: collection_authority_record.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SetCollectionSizeInstructionAccountIndices {
+    pub collection_metadata: usize,
+    pub collection_authority: usize,
+    pub collection_mint: usize,
+    pub collection_authority_record: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SetCollectionSize {
+    type ArrangedAccountIndices = SetCollectionSizeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(SetCollectionSizeInstructionAccountIndices {
+            collection_metadata: 0,
+            collection_authority: 1,
+            collection_mint: 2,
+            collection_authority_record: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SetCollectionSize {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "collection_metadata",
+            "collection_authority",
+            "collection_mint",
+            "collection_authority_record",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +12,7 @@ pub struct SetTokenStandardInstructionAccounts {
     pub update_authority: solana_pubkey::Pubkey,
     pub mint: solana_pubkey::Pubkey,
     pub edition: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SetTokenStandard {
@@ -19,7 +21,7 @@ impl carbon_core::deserialize::ArrangeAccounts for SetTokenStandard {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [metadata, update_authority, mint, edition, _remaining @ ..] = accounts else {
+        let [metadata, update_authority, mint, edition, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +30,69 @@ impl carbon_core::deserialize::ArrangeAccounts for SetTokenStandard {
             update_authority: update_authority.pubkey,
             mint: mint.pubkey,
             edition: edition.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SetTokenStandardInstructionAccountMetas {
+    pub metadata: solana_instruction::AccountMeta,
+    pub update_authority: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub edition: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SetTokenStandard {
+    type ArrangedAccountMetas = SetTokenStandardInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [metadata, update_authority, mint, edition, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(SetTokenStandardInstructionAccountMetas {
+            metadata: metadata.clone(),
+            update_authority: update_authority.clone(),
+            mint: mint.clone(),
+            edition: edition.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SetTokenStandardInstructionAccountIndices {
+    pub metadata: usize,
+    pub update_authority: usize,
+    pub mint: usize,
+    pub edition: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SetTokenStandard {
+    type ArrangedAccountIndices = SetTokenStandardInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(SetTokenStandardInstructionAccountIndices {
+            metadata: 0,
+            update_authority: 1,
+            mint: 2,
+            edition: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SetTokenStandard {
+    fn account_names() -> &'static [&'static str] {
+        &["metadata", "update_authority", "mint", "edition"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -9,6 +10,7 @@ pub struct SignMetadata {}
 pub struct SignMetadataInstructionAccounts {
     pub metadata: solana_pubkey::Pubkey,
     pub creator: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SignMetadata {
@@ -17,13 +19,68 @@ impl carbon_core::deserialize::ArrangeAccounts for SignMetadata {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [metadata, creator, _remaining @ ..] = accounts else {
+        let [metadata, creator, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(SignMetadataInstructionAccounts {
             metadata: metadata.pubkey,
             creator: creator.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SignMetadataInstructionAccountMetas {
+    pub metadata: solana_instruction::AccountMeta,
+    pub creator: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SignMetadata {
+    type ArrangedAccountMetas = SignMetadataInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [metadata, creator, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(SignMetadataInstructionAccountMetas {
+            metadata: metadata.clone(),
+            creator: creator.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SignMetadataInstructionAccountIndices {
+    pub metadata: usize,
+    pub creator: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SignMetadata {
+    type ArrangedAccountIndices = SignMetadataInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(SignMetadataInstructionAccountIndices {
+            metadata: 0,
+            creator: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SignMetadata {
+    fn account_names() -> &'static [&'static str] {
+        &["metadata", "creator"]
+    }
+}
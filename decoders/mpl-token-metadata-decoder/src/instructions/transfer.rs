@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -29,6 +30,7 @@ pub struct TransferInstructionAccounts {
     pub spl_ata_program: solana_pubkey::Pubkey,
     pub authorization_rules_program: solana_pubkey::Pubkey,
     pub authorization_rules: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Transfer {
@@ -37,7 +39,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Transfer {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [token, token_owner, destination, destination_owner, mint, metadata, edition, owner_token_record, destination_token_record, authority, payer, system_program, sysvar_instructions, spl_token_program, spl_ata_program, authorization_rules_program, authorization_rules, _remaining @ ..] =
+        let [token, token_owner, destination, destination_owner, mint, metadata, edition, owner_token_record, destination_token_record, authority, payer, system_program, sysvar_instructions, spl_token_program, spl_ata_program, authorization_rules_program, authorization_rules, remaining @ ..] =
             accounts
         else {
             return None;
@@ -61,6 +63,141 @@ impl carbon_core::deserialize::ArrangeAccounts for Transfer {
             spl_ata_program: spl_ata_program.pubkey,
             authorization_rules_program: authorization_rules_program.pubkey,
             authorization_rules: authorization_rules.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct TransferInstructionAccountMetas {
+    pub token: solana_instruction::AccountMeta,
+    pub token_owner: solana_instruction::AccountMeta,
+    pub destination: solana_instruction::AccountMeta,
+    pub destination_owner: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub metadata: solana_instruction::AccountMeta,
+    pub edition: solana_instruction::AccountMeta,
+    pub owner_token_record: solana_instruction::AccountMeta,
+    pub destination_token_record: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub sysvar_instructions: solana_instruction::AccountMeta,
+    pub spl_token_program: solana_instruction::AccountMeta,
+    pub spl_ata_program: solana_instruction::AccountMeta,
+    pub authorization_rules_program: solana_instruction::AccountMeta,
+    pub authorization_rules: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Transfer {
+    type ArrangedAccountMetas = TransferInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [token, token_owner, destination, destination_owner, mint, metadata, edition, owner_token_record, destination_token_record, authority, payer, system_program, sysvar_instructions, spl_token_program, spl_ata_program, authorization_rules_program, authorization_rules, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(TransferInstructionAccountMetas {
+            token: token.clone(),
+            token_owner: token_owner.clone(),
+            destination: destination.clone(),
+            destination_owner: destination_owner.clone(),
+            mint: mint.clone(),
+            metadata: metadata.clone(),
+            edition: edition.clone(),
+            owner_token_record: owner_token_record.clone(),
+            destination_token_record: destination_token_record.clone(),
+            authority: authority.clone(),
+            payer: payer.clone(),
+            system_program: system_program.clone(),
+            sysvar_instructions: sysvar_instructions.clone(),
+            spl_token_program: spl_token_program.clone(),
+            spl_ata_program: spl_ata_program.clone(),
+            authorization_rules_program: authorization_rules_program.clone(),
+            authorization_rules: authorization_rules.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct TransferInstructionAccountIndices {
+    pub token: usize,
+    pub token_owner: usize,
+    pub destination: usize,
+    pub destination_owner: usize,
+    pub mint: usize,
+    pub metadata: usize,
+    pub edition: usize,
+    pub owner_token_record: usize,
+    pub destination_token_record: usize,
+    pub authority: usize,
+    pub payer: usize,
+    pub system_program: usize,
+    pub sysvar_instructions: usize,
+    pub spl_token_program: usize,
+    pub spl_ata_program: usize,
+    pub authorization_rules_program: usize,
+    pub authorization_rules: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Transfer {
+    type ArrangedAccountIndices = TransferInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 17 {
+            return None;
+        }
+
+        Some(TransferInstructionAccountIndices {
+            token: 0,
+            token_owner: 1,
+            destination: 2,
+            destination_owner: 3,
+            mint: 4,
+            metadata: 5,
+            edition: 6,
+            owner_token_record: 7,
+            destination_token_record: 8,
+            authority: 9,
+            payer: 10,
+            system_program: 11,
+            sysvar_instructions: 12,
+            spl_token_program: 13,
+            spl_ata_program: 14,
+            authorization_rules_program: 15,
+            authorization_rules: 16,
+            remaining_accounts: 17..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Transfer {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "token",
+            "token_owner",
+            "destination",
+            "destination_owner",
+            "mint",
+            "metadata",
+            "edition",
+            "owner_token_record",
+            "destination_token_record",
+            "authority",
+            "payer",
+            "system_program",
+            "sysvar_instructions",
+            "spl_token_program",
+            "spl_ata_program",
+            "authorization_rules_program",
+            "authorization_rules",
+        ]
+    }
+}
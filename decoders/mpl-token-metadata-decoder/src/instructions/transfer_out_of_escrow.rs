@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -25,6 +26,7 @@ pub struct TransferOutOfEscrowInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub sysvar_instructions: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for TransferOutOfEscrow {
@@ -33,7 +35,7 @@ impl carbon_core::deserialize::ArrangeAccounts for TransferOutOfEscrow {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [escrow, metadata, payer, attribute_mint, attribute_src, attribute_dst, escrow_mint, escrow_account, system_program, ata_program, token_program, sysvar_instructions, authority, _remaining @ ..] =
+        let [escrow, metadata, payer, attribute_mint, attribute_src, attribute_dst, escrow_mint, escrow_account, system_program, ata_program, token_program, sysvar_instructions, authority, remaining @ ..] =
             accounts
         else {
             return None;
@@ -53,6 +55,121 @@ impl carbon_core::deserialize::ArrangeAccounts for TransferOutOfEscrow {
             token_program: token_program.pubkey,
             sysvar_instructions: sysvar_instructions.pubkey,
             authority: authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct TransferOutOfEscrowInstructionAccountMetas {
+    pub escrow: solana_instruction::AccountMeta,
+    pub metadata: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub attribute_mint: solana_instruction::AccountMeta,
+    pub attribute_src: solana_instruction::AccountMeta,
+    pub attribute_dst: solana_instruction::AccountMeta,
+    pub escrow_mint: solana_instruction::AccountMeta,
+    pub escrow_account: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub ata_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub sysvar_instructions: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for TransferOutOfEscrow {
+    type ArrangedAccountMetas = TransferOutOfEscrowInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [escrow, metadata, payer, attribute_mint, attribute_src, attribute_dst, escrow_mint, escrow_account, system_program, ata_program, token_program, sysvar_instructions, authority, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(TransferOutOfEscrowInstructionAccountMetas {
+            escrow: escrow.clone(),
+            metadata: metadata.clone(),
+            payer: payer.clone(),
+            attribute_mint: attribute_mint.clone(),
+            attribute_src: attribute_src.clone(),
+            attribute_dst: attribute_dst.clone(),
+            escrow_mint: escrow_mint.clone(),
+            escrow_account: escrow_account.clone(),
+            system_program: system_program.clone(),
+            ata_program: ata_program.clone(),
+            token_program: token_program.clone(),
+            sysvar_instructions: sysvar_instructions.clone(),
+            authority: authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct TransferOutOfEscrowInstructionAccountIndices {
+    pub escrow: usize,
+    pub metadata: usize,
+    pub payer: usize,
+    pub attribute_mint: usize,
+    pub attribute_src: usize,
+    pub attribute_dst: usize,
+    pub escrow_mint: usize,
+    pub escrow_account: usize,
+    pub system_program: usize,
+    pub ata_program: usize,
+    pub token_program: usize,
+    pub sysvar_instructions: usize,
+    pub authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for TransferOutOfEscrow {
+    type ArrangedAccountIndices = TransferOutOfEscrowInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 13 {
+            return None;
+        }
+
+        Some(TransferOutOfEscrowInstructionAccountIndices {
+            escrow: 0,
+            metadata: 1,
+            payer: 2,
+            attribute_mint: 3,
+            attribute_src: 4,
+            attribute_dst: 5,
+            escrow_mint: 6,
+            escrow_account: 7,
+            system_program: 8,
+            ata_program: 9,
+            token_program: 10,
+            sysvar_instructions: 11,
+            authority: 12,
+            remaining_accounts: 13..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for TransferOutOfEscrow {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "escrow",
+            "metadata",
+            "payer",
+            "attribute_mint",
+            "attribute_src",
+            "attribute_dst",
+            "escrow_mint",
+            "escrow_account",
+            "system_program",
+            "ata_program",
+            "token_program",
+            "sysvar_instructions",
+            "authority",
+        ]
+    }
+}
@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -19,6 +20,7 @@ pub struct UnverifyInstructionAccounts {
     pub collection_metadata: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub sysvar_instructions: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Unverify {
@@ -27,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Unverify {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [authority, delegate_record, metadata, collection_mint, collection_metadata, system_program, sysvar_instructions, _remaining @ ..] =
+        let [authority, delegate_record, metadata, collection_mint, collection_metadata, system_program, sysvar_instructions, remaining @ ..] =
             accounts
         else {
             return None;
@@ -41,6 +43,91 @@ impl carbon_core::deserialize::ArrangeAccounts for Unverify {
             collection_metadata: collection_metadata.pubkey,
             system_program: system_program.pubkey,
             sysvar_instructions: sysvar_instructions.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UnverifyInstructionAccountMetas {
+    pub authority: solana_instruction::AccountMeta,
+    pub delegate_record: solana_instruction::AccountMeta,
+    pub metadata: solana_instruction::AccountMeta,
+    pub collection_mint: solana_instruction::AccountMeta,
+    pub collection_metadata: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub sysvar_instructions: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Unverify {
+    type ArrangedAccountMetas = UnverifyInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [authority, delegate_record, metadata, collection_mint, collection_metadata, system_program, sysvar_instructions, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(UnverifyInstructionAccountMetas {
+            authority: authority.clone(),
+            delegate_record: delegate_record.clone(),
+            metadata: metadata.clone(),
+            collection_mint: collection_mint.clone(),
+            collection_metadata: collection_metadata.clone(),
+            system_program: system_program.clone(),
+            sysvar_instructions: sysvar_instructions.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UnverifyInstructionAccountIndices {
+    pub authority: usize,
+    pub delegate_record: usize,
+    pub metadata: usize,
+    pub collection_mint: usize,
+    pub collection_metadata: usize,
+    pub system_program: usize,
+    pub sysvar_instructions: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Unverify {
+    type ArrangedAccountIndices = UnverifyInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 7 {
+            return None;
+        }
+
+        Some(UnverifyInstructionAccountIndices {
+            authority: 0,
+            delegate_record: 1,
+            metadata: 2,
+            collection_mint: 3,
+            collection_metadata: 4,
+            system_program: 5,
+            sysvar_instructions: 6,
+            remaining_accounts: 7..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Unverify {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "authority",
+            "delegate_record",
+            "metadata",
+            "collection_mint",
+            "collection_metadata",
+            "system_program",
+            "sysvar_instructions",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +14,7 @@ pub struct UnverifyCollectionInstructionAccounts {
     pub collection: solana_pubkey::Pubkey,
     pub collection_master_edition_account: solana_pubkey::Pubkey,
     pub collection_authority_record: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UnverifyCollection {
@@ -21,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UnverifyCollection {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [metadata, collection_authority, collection_mint, collection, collection_master_edition_account, collection_authority_record, _remaining @ ..] =
+        let [metadata, collection_authority, collection_mint, collection, collection_master_edition_account, collection_authority_record, remaining @ ..] =
             accounts
         else {
             return None;
@@ -34,6 +36,86 @@ impl carbon_core::deserialize::ArrangeAccounts for UnverifyCollection {
             collection: collection.pubkey,
             collection_master_edition_account: collection_master_edition_account.pubkey,
             collection_authority_record: collection_authority_record.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UnverifyCollectionInstructionAccountMetas {
+    pub metadata: solana_instruction::AccountMeta,
+    pub collection_authority: solana_instruction::AccountMeta,
+    pub collection_mint: solana_instruction::AccountMeta,
+    pub collection: solana_instruction::AccountMeta,
+    pub collection_master_edition_account: solana_instruction::AccountMeta,
+    pub collection_authority_record: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UnverifyCollection {
+    type ArrangedAccountMetas = UnverifyCollectionInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [metadata, collection_authority, collection_mint, collection, collection_master_edition_account, collection_authority_record, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(UnverifyCollectionInstructionAccountMetas {
+            metadata: metadata.clone(),
+            collection_authority: collection_authority.clone(),
+            collection_mint: collection_mint.clone(),
+            collection: collection.clone(),
+            collection_master_edition_account: collection_master_edition_account.clone(),
+            collection_authority_record: collection_authority_record.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UnverifyCollectionInstructionAccountIndices {
+    pub metadata: usize,
+    pub collection_authority: usize,
+    pub collection_mint: usize,
+    pub collection: usize,
+    pub collection_master_edition_account: usize,
+    pub collection_authority_record: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UnverifyCollection {
+    type ArrangedAccountIndices = UnverifyCollectionInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(UnverifyCollectionInstructionAccountIndices {
+            metadata: 0,
+            collection_authority: 1,
+            collection_mint: 2,
+            collection: 3,
+            collection_master_edition_account: 4,
+            collection_authority_record: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UnverifyCollection {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "metadata",
+            "collection_authority",
+            "collection_mint",
+            "collection",
+            "collection_master_edition_account",
+            "collection_authority_record",
+        ]
+    }
+}
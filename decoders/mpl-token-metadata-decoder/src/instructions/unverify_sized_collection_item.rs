@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct UnverifySizedCollectionItemInstructionAccounts {
     pub collection: solana_pubkey::Pubkey,
     pub collection_master_edition_account: solana_pubkey::Pubkey,
     pub collection_authority_record: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UnverifySizedCollectionItem {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UnverifySizedCollectionItem {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [metadata, collection_authority, payer, collection_mint, collection, collection_master_edition_account, collection_authority_record, _remaining @ ..] =
+        let [metadata, collection_authority, payer, collection_mint, collection, collection_master_edition_account, collection_authority_record, remaining @ ..] =
             accounts
         else {
             return None;
@@ -36,6 +38,91 @@ impl carbon_core::deserialize::ArrangeAccounts for UnverifySizedCollectionItem {
             collection: collection.pubkey,
             collection_master_edition_account: collection_master_edition_account.pubkey,
             collection_authority_record: collection_authority_record.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UnverifySizedCollectionItemInstructionAccountMetas {
+    pub metadata: solana_instruction::AccountMeta,
+    pub collection_authority: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub collection_mint: solana_instruction::AccountMeta,
+    pub collection: solana_instruction::AccountMeta,
+    pub collection_master_edition_account: solana_instruction::AccountMeta,
+    pub collection_authority_record: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UnverifySizedCollectionItem {
+    type ArrangedAccountMetas = UnverifySizedCollectionItemInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [metadata, collection_authority, payer, collection_mint, collection, collection_master_edition_account, collection_authority_record, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(UnverifySizedCollectionItemInstructionAccountMetas {
+            metadata: metadata.clone(),
+            collection_authority: collection_authority.clone(),
+            payer: payer.clone(),
+            collection_mint: collection_mint.clone(),
+            collection: collection.clone(),
+            collection_master_edition_account: collection_master_edition_account.clone(),
+            collection_authority_record: collection_authority_record.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UnverifySizedCollectionItemInstructionAccountIndices {
+    pub metadata: usize,
+    pub collection_authority: usize,
+    pub payer: usize,
+    pub collection_mint: usize,
+    pub collection: usize,
+    pub collection_master_edition_account: usize,
+    pub collection_authority_record: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UnverifySizedCollectionItem {
+    type ArrangedAccountIndices = UnverifySizedCollectionItemInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 7 {
+            return None;
+        }
+
+        Some(UnverifySizedCollectionItemInstructionAccountIndices {
+            metadata: 0,
+            collection_authority: 1,
+            payer: 2,
+            collection_mint: 3,
+            collection: 4,
+            collection_master_edition_account: 5,
+            collection_authority_record: 6,
+            remaining_accounts: 7..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UnverifySizedCollectionItem {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "metadata",
+            "collection_authority",
+            "payer",
+            "collection_mint",
+            "collection",
+            "collection_master_edition_account",
+            "collection_authority_record",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -9,6 +10,7 @@ pub struct UpdateMetadataAccount {}
 pub struct UpdateMetadataAccountInstructionAccounts {
     pub metadata: solana_pubkey::Pubkey,
     pub update_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateMetadataAccount {
@@ -17,13 +19,68 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateMetadataAccount {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [metadata, update_authority, _remaining @ ..] = accounts else {
+        let [metadata, update_authority, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(UpdateMetadataAccountInstructionAccounts {
             metadata: metadata.pubkey,
             update_authority: update_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateMetadataAccountInstructionAccountMetas {
+    pub metadata: solana_instruction::AccountMeta,
+    pub update_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateMetadataAccount {
+    type ArrangedAccountMetas = UpdateMetadataAccountInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [metadata, update_authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateMetadataAccountInstructionAccountMetas {
+            metadata: metadata.clone(),
+            update_authority: update_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateMetadataAccountInstructionAccountIndices {
+    pub metadata: usize,
+    pub update_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateMetadataAccount {
+    type ArrangedAccountIndices = UpdateMetadataAccountInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(UpdateMetadataAccountInstructionAccountIndices {
+            metadata: 0,
+            update_authority: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateMetadataAccount {
+    fn account_names() -> &'static [&'static str] {
+        &["metadata", "update_authority"]
+    }
+}
@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct UpdateMetadataAccountV2 {
 pub struct UpdateMetadataAccountV2InstructionAccounts {
     pub metadata: solana_pubkey::Pubkey,
     pub update_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateMetadataAccountV2 {
@@ -22,13 +24,68 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateMetadataAccountV2 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [metadata, update_authority, _remaining @ ..] = accounts else {
+        let [metadata, update_authority, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(UpdateMetadataAccountV2InstructionAccounts {
             metadata: metadata.pubkey,
             update_authority: update_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateMetadataAccountV2InstructionAccountMetas {
+    pub metadata: solana_instruction::AccountMeta,
+    pub update_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateMetadataAccountV2 {
+    type ArrangedAccountMetas = UpdateMetadataAccountV2InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [metadata, update_authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateMetadataAccountV2InstructionAccountMetas {
+            metadata: metadata.clone(),
+            update_authority: update_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateMetadataAccountV2InstructionAccountIndices {
+    pub metadata: usize,
+    pub update_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateMetadataAccountV2 {
+    type ArrangedAccountIndices = UpdateMetadataAccountV2InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(UpdateMetadataAccountV2InstructionAccountIndices {
+            metadata: 0,
+            update_authority: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateMetadataAccountV2 {
+    fn account_names() -> &'static [&'static str] {
+        &["metadata", "update_authority"]
+    }
+}
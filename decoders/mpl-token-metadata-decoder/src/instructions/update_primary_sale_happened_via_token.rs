@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -10,6 +11,7 @@ pub struct UpdatePrimarySaleHappenedViaTokenInstructionAccounts {
     pub metadata: solana_pubkey::Pubkey,
     pub owner: solana_pubkey::Pubkey,
     pub token: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdatePrimarySaleHappenedViaToken {
@@ -18,7 +20,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdatePrimarySaleHappenedViaT
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [metadata, owner, token, _remaining @ ..] = accounts else {
+        let [metadata, owner, token, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -26,6 +28,65 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdatePrimarySaleHappenedViaT
             metadata: metadata.pubkey,
             owner: owner.pubkey,
             token: token.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdatePrimarySaleHappenedViaTokenInstructionAccountMetas {
+    pub metadata: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub token: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdatePrimarySaleHappenedViaToken {
+    type ArrangedAccountMetas = UpdatePrimarySaleHappenedViaTokenInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [metadata, owner, token, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdatePrimarySaleHappenedViaTokenInstructionAccountMetas {
+            metadata: metadata.clone(),
+            owner: owner.clone(),
+            token: token.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdatePrimarySaleHappenedViaTokenInstructionAccountIndices {
+    pub metadata: usize,
+    pub owner: usize,
+    pub token: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdatePrimarySaleHappenedViaToken {
+    type ArrangedAccountIndices = UpdatePrimarySaleHappenedViaTokenInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(UpdatePrimarySaleHappenedViaTokenInstructionAccountIndices {
+            metadata: 0,
+            owner: 1,
+            token: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdatePrimarySaleHappenedViaToken {
+    fn account_names() -> &'static [&'static str] {
+        &["metadata", "owner", "token"]
+    }
+}
@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -23,6 +24,7 @@ pub struct UtilizeInstructionAccounts {
     pub rent: solana_pubkey::Pubkey,
     pub use_authority_record: solana_pubkey::Pubkey,
     pub burner: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Utilize {
@@ -31,7 +33,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Utilize {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [metadata, token_account, mint, use_authority, owner, token_program, ata_program, system_program, rent, use_authority_record, burner, _remaining @ ..] =
+        let [metadata, token_account, mint, use_authority, owner, token_program, ata_program, system_program, rent, use_authority_record, burner, remaining @ ..] =
             accounts
         else {
             return None;
@@ -49,6 +51,111 @@ impl carbon_core::deserialize::ArrangeAccounts for Utilize {
             rent: rent.pubkey,
             use_authority_record: use_authority_record.pubkey,
             burner: burner.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UtilizeInstructionAccountMetas {
+    pub metadata: solana_instruction::AccountMeta,
+    pub token_account: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub use_authority: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub ata_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub use_authority_record: solana_instruction::AccountMeta,
+    pub burner: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Utilize {
+    type ArrangedAccountMetas = UtilizeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [metadata, token_account, mint, use_authority, owner, token_program, ata_program, system_program, rent, use_authority_record, burner, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(UtilizeInstructionAccountMetas {
+            metadata: metadata.clone(),
+            token_account: token_account.clone(),
+            mint: mint.clone(),
+            use_authority: use_authority.clone(),
+            owner: owner.clone(),
+            token_program: token_program.clone(),
+            ata_program: ata_program.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            use_authority_record: use_authority_record.clone(),
+            burner: burner.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UtilizeInstructionAccountIndices {
+    pub metadata: usize,
+    pub token_account: usize,
+    pub mint: usize,
+    pub use_authority: usize,
+    pub owner: usize,
+    pub token_program: usize,
+    pub ata_program: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub use_authority_record: usize,
+    pub burner: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Utilize {
+    type ArrangedAccountIndices = UtilizeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 11 {
+            return None;
+        }
+
+        Some(UtilizeInstructionAccountIndices {
+            metadata: 0,
+            token_account: 1,
+            mint: 2,
+            use_authority: 3,
+            owner: 4,
+            token_program: 5,
+            ata_program: 6,
+            system_program: 7,
+            rent: 8,
+            use_authority_record: 9,
+            burner: 10,
+            remaining_accounts: 11..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Utilize {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "metadata",
+            "token_account",
+            "mint",
+            "use_authority",
+            "owner",
+            "token_program",
+            "ata_program",
+            "system_program",
+            "rent",
+            "use_authority_record",
+            "burner",
+        ]
+    }
+}
@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -20,6 +21,7 @@ pub struct VerifyInstructionAccounts {
     pub collection_master_edition: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub sysvar_instructions: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Verify {
@@ -28,7 +30,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Verify {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [authority, delegate_record, metadata, collection_mint, collection_metadata, collection_master_edition, system_program, sysvar_instructions, _remaining @ ..] =
+        let [authority, delegate_record, metadata, collection_mint, collection_metadata, collection_master_edition, system_program, sysvar_instructions, remaining @ ..] =
             accounts
         else {
             return None;
@@ -43,6 +45,96 @@ impl carbon_core::deserialize::ArrangeAccounts for Verify {
             collection_master_edition: collection_master_edition.pubkey,
             system_program: system_program.pubkey,
             sysvar_instructions: sysvar_instructions.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct VerifyInstructionAccountMetas {
+    pub authority: solana_instruction::AccountMeta,
+    pub delegate_record: solana_instruction::AccountMeta,
+    pub metadata: solana_instruction::AccountMeta,
+    pub collection_mint: solana_instruction::AccountMeta,
+    pub collection_metadata: solana_instruction::AccountMeta,
+    pub collection_master_edition: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub sysvar_instructions: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Verify {
+    type ArrangedAccountMetas = VerifyInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [authority, delegate_record, metadata, collection_mint, collection_metadata, collection_master_edition, system_program, sysvar_instructions, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(VerifyInstructionAccountMetas {
+            authority: authority.clone(),
+            delegate_record: delegate_record.clone(),
+            metadata: metadata.clone(),
+            collection_mint: collection_mint.clone(),
+            collection_metadata: collection_metadata.clone(),
+            collection_master_edition: collection_master_edition.clone(),
+            system_program: system_program.clone(),
+            sysvar_instructions: sysvar_instructions.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct VerifyInstructionAccountIndices {
+    pub authority: usize,
+    pub delegate_record: usize,
+    pub metadata: usize,
+    pub collection_mint: usize,
+    pub collection_metadata: usize,
+    pub collection_master_edition: usize,
+    pub system_program: usize,
+    pub sysvar_instructions: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Verify {
+    type ArrangedAccountIndices = VerifyInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(VerifyInstructionAccountIndices {
+            authority: 0,
+            delegate_record: 1,
+            metadata: 2,
+            collection_mint: 3,
+            collection_metadata: 4,
+            collection_master_edition: 5,
+            system_program: 6,
+            sysvar_instructions: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Verify {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "authority",
+            "delegate_record",
+            "metadata",
+            "collection_mint",
+            "collection_metadata",
+            "collection_master_edition",
+            "system_program",
+            "sysvar_instructions",
+        ]
+    }
+}
@@ -4,6 +4,7 @@ use {
     std::hash::{Hash, Hasher},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
 pub struct Payload {
     pub map: std::collections::HashMap<String, PayloadType>,
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -17,6 +18,7 @@ pub struct CreateInstructionAccounts {
     pub account_class: solana_pubkey::Pubkey,
     pub parent_name_record: solana_pubkey::Pubkey,
     pub parent_name_record_class: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Create {
@@ -25,7 +27,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Create {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [system_program, funding_account, name_record, account_class, parent_name_record, parent_name_record_class, _remaining @ ..] =
+        let [system_program, funding_account, name_record, account_class, parent_name_record, parent_name_record_class, remaining @ ..] =
             accounts
         else {
             return None;
@@ -38,6 +40,86 @@ impl carbon_core::deserialize::ArrangeAccounts for Create {
             account_class: account_class.pubkey,
             parent_name_record: parent_name_record.pubkey,
             parent_name_record_class: parent_name_record_class.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CreateInstructionAccountMetas {
+    pub system_program: solana_instruction::AccountMeta,
+    pub funding_account: solana_instruction::AccountMeta,
+    pub name_record: solana_instruction::AccountMeta,
+    pub account_class: solana_instruction::AccountMeta,
+    pub parent_name_record: solana_instruction::AccountMeta,
+    pub parent_name_record_class: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Create {
+    type ArrangedAccountMetas = CreateInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [system_program, funding_account, name_record, account_class, parent_name_record, parent_name_record_class, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CreateInstructionAccountMetas {
+            system_program: system_program.clone(),
+            funding_account: funding_account.clone(),
+            name_record: name_record.clone(),
+            account_class: account_class.clone(),
+            parent_name_record: parent_name_record.clone(),
+            parent_name_record_class: parent_name_record_class.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CreateInstructionAccountIndices {
+    pub system_program: usize,
+    pub funding_account: usize,
+    pub name_record: usize,
+    pub account_class: usize,
+    pub parent_name_record: usize,
+    pub parent_name_record_class: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Create {
+    type ArrangedAccountIndices = CreateInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(CreateInstructionAccountIndices {
+            system_program: 0,
+            funding_account: 1,
+            name_record: 2,
+            account_class: 3,
+            parent_name_record: 4,
+            parent_name_record_class: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Create {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "system_program",
+            "funding_account",
+            "name_record",
+            "account_class",
+            "parent_name_record",
+            "parent_name_record_class",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -10,6 +11,7 @@ pub struct DeleteInstructionAccounts {
     pub name_record: solana_pubkey::Pubkey,
     pub owner: solana_pubkey::Pubkey,
     pub refund_account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Delete {
@@ -18,7 +20,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Delete {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [name_record, owner, refund_account, _remaining @ ..] = accounts else {
+        let [name_record, owner, refund_account, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -26,6 +28,65 @@ impl carbon_core::deserialize::ArrangeAccounts for Delete {
             name_record: name_record.pubkey,
             owner: owner.pubkey,
             refund_account: refund_account.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DeleteInstructionAccountMetas {
+    pub name_record: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub refund_account: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Delete {
+    type ArrangedAccountMetas = DeleteInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [name_record, owner, refund_account, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(DeleteInstructionAccountMetas {
+            name_record: name_record.clone(),
+            owner: owner.clone(),
+            refund_account: refund_account.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DeleteInstructionAccountIndices {
+    pub name_record: usize,
+    pub owner: usize,
+    pub refund_account: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Delete {
+    type ArrangedAccountIndices = DeleteInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(DeleteInstructionAccountIndices {
+            name_record: 0,
+            owner: 1,
+            refund_account: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Delete {
+    fn account_names() -> &'static [&'static str] {
+        &["name_record", "owner", "refund_account"]
+    }
+}
@@ -28,20 +28,30 @@ pub enum NameInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for NameDecoder {
     type InstructionType = NameInstruction;
 
-    fn decode_instruction(
+    fn decoder_name(&self) -> &'static str {
+        "name"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[super::PROGRAM_ID]
+    }
+
+    fn try_decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
-    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+    ) -> carbon_core::error::CarbonResult<
+        Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>>,
+    > {
         if !instruction.program_id.eq(&PROGRAM_ID) {
-            return None;
+            return Ok(None);
         }
 
-        carbon_core::try_decode_instructions!(instruction,
+        Ok(carbon_core::try_decode_instructions!(instruction,
             NameInstruction::Create => create::Create,
             NameInstruction::Update => update::Update,
             NameInstruction::Transfer => transfer::Transfer,
             NameInstruction::Delete => delete::Delete,
             NameInstruction::Realloc => realloc::Realloc,
-        )
+        ))
     }
 }
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +14,7 @@ pub struct ReallocInstructionAccounts {
     pub payer: solana_pubkey::Pubkey,
     pub name_record: solana_pubkey::Pubkey,
     pub owner: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Realloc {
@@ -21,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Realloc {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [system_program, payer, name_record, owner, _remaining @ ..] = accounts else {
+        let [system_program, payer, name_record, owner, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -30,6 +32,69 @@ impl carbon_core::deserialize::ArrangeAccounts for Realloc {
             payer: payer.pubkey,
             name_record: name_record.pubkey,
             owner: owner.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ReallocInstructionAccountMetas {
+    pub system_program: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub name_record: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Realloc {
+    type ArrangedAccountMetas = ReallocInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [system_program, payer, name_record, owner, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(ReallocInstructionAccountMetas {
+            system_program: system_program.clone(),
+            payer: payer.clone(),
+            name_record: name_record.clone(),
+            owner: owner.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ReallocInstructionAccountIndices {
+    pub system_program: usize,
+    pub payer: usize,
+    pub name_record: usize,
+    pub owner: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Realloc {
+    type ArrangedAccountIndices = ReallocInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(ReallocInstructionAccountIndices {
+            system_program: 0,
+            payer: 1,
+            name_record: 2,
+            owner: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Realloc {
+    fn account_names() -> &'static [&'static str] {
+        &["system_program", "payer", "name_record", "owner"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +13,7 @@ pub struct TransferInstructionAccounts {
     pub name_record: solana_pubkey::Pubkey,
     pub owner: solana_pubkey::Pubkey,
     pub parent_name_record: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Transfer {
@@ -20,7 +22,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Transfer {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [name_record, owner, parent_name_record, _remaining @ ..] = accounts else {
+        let [name_record, owner, parent_name_record, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +30,65 @@ impl carbon_core::deserialize::ArrangeAccounts for Transfer {
             name_record: name_record.pubkey,
             owner: owner.pubkey,
             parent_name_record: parent_name_record.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct TransferInstructionAccountMetas {
+    pub name_record: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub parent_name_record: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Transfer {
+    type ArrangedAccountMetas = TransferInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [name_record, owner, parent_name_record, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(TransferInstructionAccountMetas {
+            name_record: name_record.clone(),
+            owner: owner.clone(),
+            parent_name_record: parent_name_record.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct TransferInstructionAccountIndices {
+    pub name_record: usize,
+    pub owner: usize,
+    pub parent_name_record: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Transfer {
+    type ArrangedAccountIndices = TransferInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(TransferInstructionAccountIndices {
+            name_record: 0,
+            owner: 1,
+            parent_name_record: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Transfer {
+    fn account_names() -> &'static [&'static str] {
+        &["name_record", "owner", "parent_name_record"]
+    }
+}
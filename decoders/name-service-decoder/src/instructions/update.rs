@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +14,7 @@ pub struct UpdateInstructionAccounts {
     pub name_record: solana_pubkey::Pubkey,
     pub owner: solana_pubkey::Pubkey,
     pub parent_name_record: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Update {
@@ -21,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Update {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [name_record, owner, parent_name_record, _remaining @ ..] = accounts else {
+        let [name_record, owner, parent_name_record, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -29,6 +31,65 @@ impl carbon_core::deserialize::ArrangeAccounts for Update {
             name_record: name_record.pubkey,
             owner: owner.pubkey,
             parent_name_record: parent_name_record.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateInstructionAccountMetas {
+    pub name_record: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub parent_name_record: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Update {
+    type ArrangedAccountMetas = UpdateInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [name_record, owner, parent_name_record, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateInstructionAccountMetas {
+            name_record: name_record.clone(),
+            owner: owner.clone(),
+            parent_name_record: parent_name_record.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateInstructionAccountIndices {
+    pub name_record: usize,
+    pub owner: usize,
+    pub parent_name_record: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Update {
+    type ArrangedAccountIndices = UpdateInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(UpdateInstructionAccountIndices {
+            name_record: 0,
+            owner: 1,
+            parent_name_record: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Update {
+    fn account_names() -> &'static [&'static str] {
+        &["name_record", "owner", "parent_name_record"]
+    }
+}
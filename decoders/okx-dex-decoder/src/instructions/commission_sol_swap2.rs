@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -19,6 +20,7 @@ pub struct CommissionSolSwap2InstructionAccounts {
     pub destination_mint: solana_pubkey::Pubkey,
     pub commission_account: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CommissionSolSwap2 {
@@ -27,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CommissionSolSwap2 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [payer, source_token_account, destination_token_account, source_mint, destination_mint, commission_account, system_program, _remaining @ ..] =
+        let [payer, source_token_account, destination_token_account, source_mint, destination_mint, commission_account, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -41,6 +43,91 @@ impl carbon_core::deserialize::ArrangeAccounts for CommissionSolSwap2 {
             destination_mint: destination_mint.pubkey,
             commission_account: commission_account.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CommissionSolSwap2InstructionAccountMetas {
+    pub payer: solana_instruction::AccountMeta,
+    pub source_token_account: solana_instruction::AccountMeta,
+    pub destination_token_account: solana_instruction::AccountMeta,
+    pub source_mint: solana_instruction::AccountMeta,
+    pub destination_mint: solana_instruction::AccountMeta,
+    pub commission_account: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CommissionSolSwap2 {
+    type ArrangedAccountMetas = CommissionSolSwap2InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [payer, source_token_account, destination_token_account, source_mint, destination_mint, commission_account, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CommissionSolSwap2InstructionAccountMetas {
+            payer: payer.clone(),
+            source_token_account: source_token_account.clone(),
+            destination_token_account: destination_token_account.clone(),
+            source_mint: source_mint.clone(),
+            destination_mint: destination_mint.clone(),
+            commission_account: commission_account.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CommissionSolSwap2InstructionAccountIndices {
+    pub payer: usize,
+    pub source_token_account: usize,
+    pub destination_token_account: usize,
+    pub source_mint: usize,
+    pub destination_mint: usize,
+    pub commission_account: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CommissionSolSwap2 {
+    type ArrangedAccountIndices = CommissionSolSwap2InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 7 {
+            return None;
+        }
+
+        Some(CommissionSolSwap2InstructionAccountIndices {
+            payer: 0,
+            source_token_account: 1,
+            destination_token_account: 2,
+            source_mint: 3,
+            destination_mint: 4,
+            commission_account: 5,
+            system_program: 6,
+            remaining_accounts: 7..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CommissionSolSwap2 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "payer",
+            "source_token_account",
+            "destination_token_account",
+            "source_mint",
+            "destination_mint",
+            "commission_account",
+            "system_program",
+        ]
+    }
+}
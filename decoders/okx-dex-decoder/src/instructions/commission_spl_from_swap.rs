@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -26,6 +27,7 @@ pub struct CommissionSplFromSwapInstructionAccounts {
     pub token_2022_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub commission_token_account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CommissionSplFromSwap {
@@ -34,7 +36,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CommissionSplFromSwap {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [payer, source_token_account, destination_token_account, source_mint, destination_mint, bridge_program, associated_token_program, token_program, token_2022_program, system_program, commission_token_account, _remaining @ ..] =
+        let [payer, source_token_account, destination_token_account, source_mint, destination_mint, bridge_program, associated_token_program, token_program, token_2022_program, system_program, commission_token_account, remaining @ ..] =
             accounts
         else {
             return None;
@@ -52,6 +54,111 @@ impl carbon_core::deserialize::ArrangeAccounts for CommissionSplFromSwap {
             token_2022_program: token_2022_program.pubkey,
             system_program: system_program.pubkey,
             commission_token_account: commission_token_account.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CommissionSplFromSwapInstructionAccountMetas {
+    pub payer: solana_instruction::AccountMeta,
+    pub source_token_account: solana_instruction::AccountMeta,
+    pub destination_token_account: solana_instruction::AccountMeta,
+    pub source_mint: solana_instruction::AccountMeta,
+    pub destination_mint: solana_instruction::AccountMeta,
+    pub bridge_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub token_2022_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub commission_token_account: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CommissionSplFromSwap {
+    type ArrangedAccountMetas = CommissionSplFromSwapInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [payer, source_token_account, destination_token_account, source_mint, destination_mint, bridge_program, associated_token_program, token_program, token_2022_program, system_program, commission_token_account, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CommissionSplFromSwapInstructionAccountMetas {
+            payer: payer.clone(),
+            source_token_account: source_token_account.clone(),
+            destination_token_account: destination_token_account.clone(),
+            source_mint: source_mint.clone(),
+            destination_mint: destination_mint.clone(),
+            bridge_program: bridge_program.clone(),
+            associated_token_program: associated_token_program.clone(),
+            token_program: token_program.clone(),
+            token_2022_program: token_2022_program.clone(),
+            system_program: system_program.clone(),
+            commission_token_account: commission_token_account.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CommissionSplFromSwapInstructionAccountIndices {
+    pub payer: usize,
+    pub source_token_account: usize,
+    pub destination_token_account: usize,
+    pub source_mint: usize,
+    pub destination_mint: usize,
+    pub bridge_program: usize,
+    pub associated_token_program: usize,
+    pub token_program: usize,
+    pub token_2022_program: usize,
+    pub system_program: usize,
+    pub commission_token_account: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CommissionSplFromSwap {
+    type ArrangedAccountIndices = CommissionSplFromSwapInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 11 {
+            return None;
+        }
+
+        Some(CommissionSplFromSwapInstructionAccountIndices {
+            payer: 0,
+            source_token_account: 1,
+            destination_token_account: 2,
+            source_mint: 3,
+            destination_mint: 4,
+            bridge_program: 5,
+            associated_token_program: 6,
+            token_program: 7,
+            token_2022_program: 8,
+            system_program: 9,
+            commission_token_account: 10,
+            remaining_accounts: 11..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CommissionSplFromSwap {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "payer",
+            "source_token_account",
+            "destination_token_account",
+            "source_mint",
+            "destination_mint",
+            "bridge_program",
+            "associated_token_program",
+            "token_program",
+            "token_2022_program",
+            "system_program",
+            "commission_token_account",
+        ]
+    }
+}
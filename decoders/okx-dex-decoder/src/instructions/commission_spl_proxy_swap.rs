@@ -2,6 +2,7 @@ use super::super::types::*;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -27,6 +28,7 @@ pub struct CommissionSplProxySwapInstructionAccounts {
     pub destination_token_program: solana_pubkey::Pubkey,
     pub associated_token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CommissionSplProxySwap {
@@ -35,7 +37,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CommissionSplProxySwap {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [payer, source_token_account, destination_token_account, source_mint, destination_mint, commission_token_account, sa_authority, source_token_sa, destination_token_sa, source_token_program, destination_token_program, associated_token_program, system_program, _remaining @ ..] =
+        let [payer, source_token_account, destination_token_account, source_mint, destination_mint, commission_token_account, sa_authority, source_token_sa, destination_token_sa, source_token_program, destination_token_program, associated_token_program, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -55,6 +57,121 @@ impl carbon_core::deserialize::ArrangeAccounts for CommissionSplProxySwap {
             destination_token_program: destination_token_program.pubkey,
             associated_token_program: associated_token_program.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CommissionSplProxySwapInstructionAccountMetas {
+    pub payer: solana_instruction::AccountMeta,
+    pub source_token_account: solana_instruction::AccountMeta,
+    pub destination_token_account: solana_instruction::AccountMeta,
+    pub source_mint: solana_instruction::AccountMeta,
+    pub destination_mint: solana_instruction::AccountMeta,
+    pub commission_token_account: solana_instruction::AccountMeta,
+    pub sa_authority: solana_instruction::AccountMeta,
+    pub source_token_sa: solana_instruction::AccountMeta,
+    pub destination_token_sa: solana_instruction::AccountMeta,
+    pub source_token_program: solana_instruction::AccountMeta,
+    pub destination_token_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CommissionSplProxySwap {
+    type ArrangedAccountMetas = CommissionSplProxySwapInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [payer, source_token_account, destination_token_account, source_mint, destination_mint, commission_token_account, sa_authority, source_token_sa, destination_token_sa, source_token_program, destination_token_program, associated_token_program, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CommissionSplProxySwapInstructionAccountMetas {
+            payer: payer.clone(),
+            source_token_account: source_token_account.clone(),
+            destination_token_account: destination_token_account.clone(),
+            source_mint: source_mint.clone(),
+            destination_mint: destination_mint.clone(),
+            commission_token_account: commission_token_account.clone(),
+            sa_authority: sa_authority.clone(),
+            source_token_sa: source_token_sa.clone(),
+            destination_token_sa: destination_token_sa.clone(),
+            source_token_program: source_token_program.clone(),
+            destination_token_program: destination_token_program.clone(),
+            associated_token_program: associated_token_program.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CommissionSplProxySwapInstructionAccountIndices {
+    pub payer: usize,
+    pub source_token_account: usize,
+    pub destination_token_account: usize,
+    pub source_mint: usize,
+    pub destination_mint: usize,
+    pub commission_token_account: usize,
+    pub sa_authority: usize,
+    pub source_token_sa: usize,
+    pub destination_token_sa: usize,
+    pub source_token_program: usize,
+    pub destination_token_program: usize,
+    pub associated_token_program: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CommissionSplProxySwap {
+    type ArrangedAccountIndices = CommissionSplProxySwapInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 13 {
+            return None;
+        }
+
+        Some(CommissionSplProxySwapInstructionAccountIndices {
+            payer: 0,
+            source_token_account: 1,
+            destination_token_account: 2,
+            source_mint: 3,
+            destination_mint: 4,
+            commission_token_account: 5,
+            sa_authority: 6,
+            source_token_sa: 7,
+            destination_token_sa: 8,
+            source_token_program: 9,
+            destination_token_program: 10,
+            associated_token_program: 11,
+            system_program: 12,
+            remaining_accounts: 13..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CommissionSplProxySwap {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "payer",
+            "source_token_account",
+            "destination_token_account",
+            "source_mint",
+            "destination_mint",
+            "commission_token_account",
+            "sa_authority",
+            "source_token_sa",
+            "destination_token_sa",
+            "source_token_program",
+            "destination_token_program",
+            "associated_token_program",
+            "system_program",
+        ]
+    }
+}
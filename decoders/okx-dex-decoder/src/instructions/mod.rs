@@ -44,15 +44,25 @@ pub enum OkxDexInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for OkxDexDecoder {
     type InstructionType = OkxDexInstruction;
 
-    fn decode_instruction(
+    fn decoder_name(&self) -> &'static str {
+        "okx_dex"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[super::PROGRAM_ID]
+    }
+
+    fn try_decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
-    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+    ) -> carbon_core::error::CarbonResult<
+        Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>>,
+    > {
         if !instruction.program_id.eq(&PROGRAM_ID) {
-            return None;
+            return Ok(None);
         }
 
-        carbon_core::try_decode_instructions!(instruction,
+        Ok(carbon_core::try_decode_instructions!(instruction,
             OkxDexInstruction::CommissionSolFromSwap => commission_sol_from_swap::CommissionSolFromSwap,
             OkxDexInstruction::CommissionSolProxySwap => commission_sol_proxy_swap::CommissionSolProxySwap,
             OkxDexInstruction::CommissionSolSwap => commission_sol_swap::CommissionSolSwap,
@@ -66,6 +76,6 @@ impl carbon_core::instruction::InstructionDecoder<'_> for OkxDexDecoder {
             OkxDexInstruction::Swap => swap::Swap,
             OkxDexInstruction::Swap2 => swap2::Swap2,
             OkxDexInstruction::SwapEvent => swap_event::SwapEvent,
-        )
+        ))
     }
 }
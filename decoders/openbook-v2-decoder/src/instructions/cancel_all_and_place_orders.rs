@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -29,6 +30,7 @@ pub struct CancelAllAndPlaceOrdersInstructionAccounts {
     pub oracle_a: solana_pubkey::Pubkey,
     pub oracle_b: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CancelAllAndPlaceOrders {
@@ -37,7 +39,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CancelAllAndPlaceOrders {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [signer, open_orders_account, open_orders_admin, user_quote_account, user_base_account, market, bids, asks, event_heap, market_quote_vault, market_base_vault, oracle_a, oracle_b, token_program, _remaining @ ..] =
+        let [signer, open_orders_account, open_orders_admin, user_quote_account, user_base_account, market, bids, asks, event_heap, market_quote_vault, market_base_vault, oracle_a, oracle_b, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -58,6 +60,126 @@ impl carbon_core::deserialize::ArrangeAccounts for CancelAllAndPlaceOrders {
             oracle_a: oracle_a.pubkey,
             oracle_b: oracle_b.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CancelAllAndPlaceOrdersInstructionAccountMetas {
+    pub signer: solana_instruction::AccountMeta,
+    pub open_orders_account: solana_instruction::AccountMeta,
+    pub open_orders_admin: solana_instruction::AccountMeta,
+    pub user_quote_account: solana_instruction::AccountMeta,
+    pub user_base_account: solana_instruction::AccountMeta,
+    pub market: solana_instruction::AccountMeta,
+    pub bids: solana_instruction::AccountMeta,
+    pub asks: solana_instruction::AccountMeta,
+    pub event_heap: solana_instruction::AccountMeta,
+    pub market_quote_vault: solana_instruction::AccountMeta,
+    pub market_base_vault: solana_instruction::AccountMeta,
+    pub oracle_a: solana_instruction::AccountMeta,
+    pub oracle_b: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CancelAllAndPlaceOrders {
+    type ArrangedAccountMetas = CancelAllAndPlaceOrdersInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [signer, open_orders_account, open_orders_admin, user_quote_account, user_base_account, market, bids, asks, event_heap, market_quote_vault, market_base_vault, oracle_a, oracle_b, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CancelAllAndPlaceOrdersInstructionAccountMetas {
+            signer: signer.clone(),
+            open_orders_account: open_orders_account.clone(),
+            open_orders_admin: open_orders_admin.clone(),
+            user_quote_account: user_quote_account.clone(),
+            user_base_account: user_base_account.clone(),
+            market: market.clone(),
+            bids: bids.clone(),
+            asks: asks.clone(),
+            event_heap: event_heap.clone(),
+            market_quote_vault: market_quote_vault.clone(),
+            market_base_vault: market_base_vault.clone(),
+            oracle_a: oracle_a.clone(),
+            oracle_b: oracle_b.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CancelAllAndPlaceOrdersInstructionAccountIndices {
+    pub signer: usize,
+    pub open_orders_account: usize,
+    pub open_orders_admin: usize,
+    pub user_quote_account: usize,
+    pub user_base_account: usize,
+    pub market: usize,
+    pub bids: usize,
+    pub asks: usize,
+    pub event_heap: usize,
+    pub market_quote_vault: usize,
+    pub market_base_vault: usize,
+    pub oracle_a: usize,
+    pub oracle_b: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CancelAllAndPlaceOrders {
+    type ArrangedAccountIndices = CancelAllAndPlaceOrdersInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 14 {
+            return None;
+        }
+
+        Some(CancelAllAndPlaceOrdersInstructionAccountIndices {
+            signer: 0,
+            open_orders_account: 1,
+            open_orders_admin: 2,
+            user_quote_account: 3,
+            user_base_account: 4,
+            market: 5,
+            bids: 6,
+            asks: 7,
+            event_heap: 8,
+            market_quote_vault: 9,
+            market_base_vault: 10,
+            oracle_a: 11,
+            oracle_b: 12,
+            token_program: 13,
+            remaining_accounts: 14..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CancelAllAndPlaceOrders {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "signer",
+            "open_orders_account",
+            "open_orders_admin",
+            "user_quote_account",
+            "user_base_account",
+            "market",
+            "bids",
+            "asks",
+            "event_heap",
+            "market_quote_vault",
+            "market_base_vault",
+            "oracle_a",
+            "oracle_b",
+            "token_program",
+        ]
+    }
+}
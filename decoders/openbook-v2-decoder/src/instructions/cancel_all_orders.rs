@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -18,6 +19,7 @@ pub struct CancelAllOrdersInstructionAccounts {
     pub market: solana_pubkey::Pubkey,
     pub bids: solana_pubkey::Pubkey,
     pub asks: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CancelAllOrders {
@@ -26,7 +28,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CancelAllOrders {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [signer, open_orders_account, market, bids, asks, _remaining @ ..] = accounts else {
+        let [signer, open_orders_account, market, bids, asks, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -36,6 +38,73 @@ impl carbon_core::deserialize::ArrangeAccounts for CancelAllOrders {
             market: market.pubkey,
             bids: bids.pubkey,
             asks: asks.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CancelAllOrdersInstructionAccountMetas {
+    pub signer: solana_instruction::AccountMeta,
+    pub open_orders_account: solana_instruction::AccountMeta,
+    pub market: solana_instruction::AccountMeta,
+    pub bids: solana_instruction::AccountMeta,
+    pub asks: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CancelAllOrders {
+    type ArrangedAccountMetas = CancelAllOrdersInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [signer, open_orders_account, market, bids, asks, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(CancelAllOrdersInstructionAccountMetas {
+            signer: signer.clone(),
+            open_orders_account: open_orders_account.clone(),
+            market: market.clone(),
+            bids: bids.clone(),
+            asks: asks.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CancelAllOrdersInstructionAccountIndices {
+    pub signer: usize,
+    pub open_orders_account: usize,
+    pub market: usize,
+    pub bids: usize,
+    pub asks: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CancelAllOrders {
+    type ArrangedAccountIndices = CancelAllOrdersInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(CancelAllOrdersInstructionAccountIndices {
+            signer: 0,
+            open_orders_account: 1,
+            market: 2,
+            bids: 3,
+            asks: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CancelAllOrders {
+    fn account_names() -> &'static [&'static str] {
+        &["signer", "open_orders_account", "market", "bids", "asks"]
+    }
+}
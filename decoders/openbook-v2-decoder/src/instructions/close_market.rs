@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct CloseMarketInstructionAccounts {
     pub event_heap: solana_pubkey::Pubkey,
     pub sol_destination: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CloseMarket {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CloseMarket {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [close_market_admin, market, bids, asks, event_heap, sol_destination, token_program, _remaining @ ..] =
+        let [close_market_admin, market, bids, asks, event_heap, sol_destination, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -36,6 +38,91 @@ impl carbon_core::deserialize::ArrangeAccounts for CloseMarket {
             event_heap: event_heap.pubkey,
             sol_destination: sol_destination.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CloseMarketInstructionAccountMetas {
+    pub close_market_admin: solana_instruction::AccountMeta,
+    pub market: solana_instruction::AccountMeta,
+    pub bids: solana_instruction::AccountMeta,
+    pub asks: solana_instruction::AccountMeta,
+    pub event_heap: solana_instruction::AccountMeta,
+    pub sol_destination: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CloseMarket {
+    type ArrangedAccountMetas = CloseMarketInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [close_market_admin, market, bids, asks, event_heap, sol_destination, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CloseMarketInstructionAccountMetas {
+            close_market_admin: close_market_admin.clone(),
+            market: market.clone(),
+            bids: bids.clone(),
+            asks: asks.clone(),
+            event_heap: event_heap.clone(),
+            sol_destination: sol_destination.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CloseMarketInstructionAccountIndices {
+    pub close_market_admin: usize,
+    pub market: usize,
+    pub bids: usize,
+    pub asks: usize,
+    pub event_heap: usize,
+    pub sol_destination: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CloseMarket {
+    type ArrangedAccountIndices = CloseMarketInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 7 {
+            return None;
+        }
+
+        Some(CloseMarketInstructionAccountIndices {
+            close_market_admin: 0,
+            market: 1,
+            bids: 2,
+            asks: 3,
+            event_heap: 4,
+            sol_destination: 5,
+            token_program: 6,
+            remaining_accounts: 7..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CloseMarket {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "close_market_admin",
+            "market",
+            "bids",
+            "asks",
+            "event_heap",
+            "sol_destination",
+            "token_program",
+        ]
+    }
+}
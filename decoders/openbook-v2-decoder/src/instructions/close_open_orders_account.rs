@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +13,7 @@ pub struct CloseOpenOrdersAccountInstructionAccounts {
     pub open_orders_account: solana_pubkey::Pubkey,
     pub sol_destination: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CloseOpenOrdersAccount {
@@ -20,7 +22,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CloseOpenOrdersAccount {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [owner, open_orders_indexer, open_orders_account, sol_destination, system_program, _remaining @ ..] =
+        let [owner, open_orders_indexer, open_orders_account, sol_destination, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -32,6 +34,81 @@ impl carbon_core::deserialize::ArrangeAccounts for CloseOpenOrdersAccount {
             open_orders_account: open_orders_account.pubkey,
             sol_destination: sol_destination.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CloseOpenOrdersAccountInstructionAccountMetas {
+    pub owner: solana_instruction::AccountMeta,
+    pub open_orders_indexer: solana_instruction::AccountMeta,
+    pub open_orders_account: solana_instruction::AccountMeta,
+    pub sol_destination: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CloseOpenOrdersAccount {
+    type ArrangedAccountMetas = CloseOpenOrdersAccountInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [owner, open_orders_indexer, open_orders_account, sol_destination, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CloseOpenOrdersAccountInstructionAccountMetas {
+            owner: owner.clone(),
+            open_orders_indexer: open_orders_indexer.clone(),
+            open_orders_account: open_orders_account.clone(),
+            sol_destination: sol_destination.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CloseOpenOrdersAccountInstructionAccountIndices {
+    pub owner: usize,
+    pub open_orders_indexer: usize,
+    pub open_orders_account: usize,
+    pub sol_destination: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CloseOpenOrdersAccount {
+    type ArrangedAccountIndices = CloseOpenOrdersAccountInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(CloseOpenOrdersAccountInstructionAccountIndices {
+            owner: 0,
+            open_orders_indexer: 1,
+            open_orders_account: 2,
+            sol_destination: 3,
+            system_program: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CloseOpenOrdersAccount {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "owner",
+            "open_orders_indexer",
+            "open_orders_account",
+            "sol_destination",
+            "system_program",
+        ]
+    }
+}
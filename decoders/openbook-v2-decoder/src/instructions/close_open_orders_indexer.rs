@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +12,7 @@ pub struct CloseOpenOrdersIndexerInstructionAccounts {
     pub open_orders_indexer: solana_pubkey::Pubkey,
     pub sol_destination: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CloseOpenOrdersIndexer {
@@ -19,8 +21,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CloseOpenOrdersIndexer {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [owner, open_orders_indexer, sol_destination, token_program, _remaining @ ..] =
-            accounts
+        let [owner, open_orders_indexer, sol_destination, token_program, remaining @ ..] = accounts
         else {
             return None;
         };
@@ -30,6 +31,75 @@ impl carbon_core::deserialize::ArrangeAccounts for CloseOpenOrdersIndexer {
             open_orders_indexer: open_orders_indexer.pubkey,
             sol_destination: sol_destination.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CloseOpenOrdersIndexerInstructionAccountMetas {
+    pub owner: solana_instruction::AccountMeta,
+    pub open_orders_indexer: solana_instruction::AccountMeta,
+    pub sol_destination: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CloseOpenOrdersIndexer {
+    type ArrangedAccountMetas = CloseOpenOrdersIndexerInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [owner, open_orders_indexer, sol_destination, token_program, remaining @ ..] = accounts
+        else {
+            return None;
+        };
+
+        Some(CloseOpenOrdersIndexerInstructionAccountMetas {
+            owner: owner.clone(),
+            open_orders_indexer: open_orders_indexer.clone(),
+            sol_destination: sol_destination.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CloseOpenOrdersIndexerInstructionAccountIndices {
+    pub owner: usize,
+    pub open_orders_indexer: usize,
+    pub sol_destination: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CloseOpenOrdersIndexer {
+    type ArrangedAccountIndices = CloseOpenOrdersIndexerInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(CloseOpenOrdersIndexerInstructionAccountIndices {
+            owner: 0,
+            open_orders_indexer: 1,
+            sol_destination: 2,
+            token_program: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CloseOpenOrdersIndexer {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "owner",
+            "open_orders_indexer",
+            "sol_destination",
+            "token_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +13,7 @@ pub struct ConsumeGivenEventsInstructionAccounts {
     pub consume_events_admin: solana_pubkey::Pubkey,
     pub market: solana_pubkey::Pubkey,
     pub event_heap: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ConsumeGivenEvents {
@@ -20,7 +22,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ConsumeGivenEvents {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [consume_events_admin, market, event_heap, _remaining @ ..] = accounts else {
+        let [consume_events_admin, market, event_heap, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +30,65 @@ impl carbon_core::deserialize::ArrangeAccounts for ConsumeGivenEvents {
             consume_events_admin: consume_events_admin.pubkey,
             market: market.pubkey,
             event_heap: event_heap.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ConsumeGivenEventsInstructionAccountMetas {
+    pub consume_events_admin: solana_instruction::AccountMeta,
+    pub market: solana_instruction::AccountMeta,
+    pub event_heap: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ConsumeGivenEvents {
+    type ArrangedAccountMetas = ConsumeGivenEventsInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [consume_events_admin, market, event_heap, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(ConsumeGivenEventsInstructionAccountMetas {
+            consume_events_admin: consume_events_admin.clone(),
+            market: market.clone(),
+            event_heap: event_heap.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ConsumeGivenEventsInstructionAccountIndices {
+    pub consume_events_admin: usize,
+    pub market: usize,
+    pub event_heap: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ConsumeGivenEvents {
+    type ArrangedAccountIndices = ConsumeGivenEventsInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(ConsumeGivenEventsInstructionAccountIndices {
+            consume_events_admin: 0,
+            market: 1,
+            event_heap: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ConsumeGivenEvents {
+    fn account_names() -> &'static [&'static str] {
+        &["consume_events_admin", "market", "event_heap"]
+    }
+}
@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
 #[carbon(discriminator = "0x67e261ebc8bcfbfe")]
 pub struct CreateMarket {
@@ -37,6 +38,7 @@ pub struct CreateMarketInstructionAccounts {
     pub close_market_admin: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CreateMarket {
@@ -45,7 +47,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateMarket {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [market, market_authority, bids, asks, event_heap, payer, market_base_vault, market_quote_vault, base_mint, quote_mint, system_program, token_program, associated_token_program, oracle_a, oracle_b, collect_fee_admin, open_orders_admin, consume_events_admin, close_market_admin, event_authority, program, _remaining @ ..] =
+        let [market, market_authority, bids, asks, event_heap, payer, market_base_vault, market_quote_vault, base_mint, quote_mint, system_program, token_program, associated_token_program, oracle_a, oracle_b, collect_fee_admin, open_orders_admin, consume_events_admin, close_market_admin, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -73,6 +75,161 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateMarket {
             close_market_admin: close_market_admin.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CreateMarketInstructionAccountMetas {
+    pub market: solana_instruction::AccountMeta,
+    pub market_authority: solana_instruction::AccountMeta,
+    pub bids: solana_instruction::AccountMeta,
+    pub asks: solana_instruction::AccountMeta,
+    pub event_heap: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub market_base_vault: solana_instruction::AccountMeta,
+    pub market_quote_vault: solana_instruction::AccountMeta,
+    pub base_mint: solana_instruction::AccountMeta,
+    pub quote_mint: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub oracle_a: solana_instruction::AccountMeta,
+    pub oracle_b: solana_instruction::AccountMeta,
+    pub collect_fee_admin: solana_instruction::AccountMeta,
+    pub open_orders_admin: solana_instruction::AccountMeta,
+    pub consume_events_admin: solana_instruction::AccountMeta,
+    pub close_market_admin: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CreateMarket {
+    type ArrangedAccountMetas = CreateMarketInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [market, market_authority, bids, asks, event_heap, payer, market_base_vault, market_quote_vault, base_mint, quote_mint, system_program, token_program, associated_token_program, oracle_a, oracle_b, collect_fee_admin, open_orders_admin, consume_events_admin, close_market_admin, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CreateMarketInstructionAccountMetas {
+            market: market.clone(),
+            market_authority: market_authority.clone(),
+            bids: bids.clone(),
+            asks: asks.clone(),
+            event_heap: event_heap.clone(),
+            payer: payer.clone(),
+            market_base_vault: market_base_vault.clone(),
+            market_quote_vault: market_quote_vault.clone(),
+            base_mint: base_mint.clone(),
+            quote_mint: quote_mint.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            associated_token_program: associated_token_program.clone(),
+            oracle_a: oracle_a.clone(),
+            oracle_b: oracle_b.clone(),
+            collect_fee_admin: collect_fee_admin.clone(),
+            open_orders_admin: open_orders_admin.clone(),
+            consume_events_admin: consume_events_admin.clone(),
+            close_market_admin: close_market_admin.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CreateMarketInstructionAccountIndices {
+    pub market: usize,
+    pub market_authority: usize,
+    pub bids: usize,
+    pub asks: usize,
+    pub event_heap: usize,
+    pub payer: usize,
+    pub market_base_vault: usize,
+    pub market_quote_vault: usize,
+    pub base_mint: usize,
+    pub quote_mint: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub associated_token_program: usize,
+    pub oracle_a: usize,
+    pub oracle_b: usize,
+    pub collect_fee_admin: usize,
+    pub open_orders_admin: usize,
+    pub consume_events_admin: usize,
+    pub close_market_admin: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CreateMarket {
+    type ArrangedAccountIndices = CreateMarketInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 21 {
+            return None;
+        }
+
+        Some(CreateMarketInstructionAccountIndices {
+            market: 0,
+            market_authority: 1,
+            bids: 2,
+            asks: 3,
+            event_heap: 4,
+            payer: 5,
+            market_base_vault: 6,
+            market_quote_vault: 7,
+            base_mint: 8,
+            quote_mint: 9,
+            system_program: 10,
+            token_program: 11,
+            associated_token_program: 12,
+            oracle_a: 13,
+            oracle_b: 14,
+            collect_fee_admin: 15,
+            open_orders_admin: 16,
+            consume_events_admin: 17,
+            close_market_admin: 18,
+            event_authority: 19,
+            program: 20,
+            remaining_accounts: 21..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CreateMarket {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "market",
+            "market_authority",
+            "bids",
+            "asks",
+            "event_heap",
+            "payer",
+            "market_base_vault",
+            "market_quote_vault",
+            "base_mint",
+            "quote_mint",
+            "system_program",
+            "token_program",
+            "associated_token_program",
+            "oracle_a",
+            "oracle_b",
+            "collect_fee_admin",
+            "open_orders_admin",
+            "consume_events_admin",
+            "close_market_admin",
+            "event_authority",
+            "program",
+        ]
+    }
+}
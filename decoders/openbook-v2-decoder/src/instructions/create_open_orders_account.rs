@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -16,6 +17,7 @@ pub struct CreateOpenOrdersAccountInstructionAccounts {
     pub open_orders_account: solana_pubkey::Pubkey,
     pub market: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CreateOpenOrdersAccount {
@@ -24,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateOpenOrdersAccount {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [payer, owner, delegate_account, open_orders_indexer, open_orders_account, market, system_program, _remaining @ ..] =
+        let [payer, owner, delegate_account, open_orders_indexer, open_orders_account, market, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -38,6 +40,91 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateOpenOrdersAccount {
             open_orders_account: open_orders_account.pubkey,
             market: market.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CreateOpenOrdersAccountInstructionAccountMetas {
+    pub payer: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub delegate_account: solana_instruction::AccountMeta,
+    pub open_orders_indexer: solana_instruction::AccountMeta,
+    pub open_orders_account: solana_instruction::AccountMeta,
+    pub market: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CreateOpenOrdersAccount {
+    type ArrangedAccountMetas = CreateOpenOrdersAccountInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [payer, owner, delegate_account, open_orders_indexer, open_orders_account, market, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CreateOpenOrdersAccountInstructionAccountMetas {
+            payer: payer.clone(),
+            owner: owner.clone(),
+            delegate_account: delegate_account.clone(),
+            open_orders_indexer: open_orders_indexer.clone(),
+            open_orders_account: open_orders_account.clone(),
+            market: market.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CreateOpenOrdersAccountInstructionAccountIndices {
+    pub payer: usize,
+    pub owner: usize,
+    pub delegate_account: usize,
+    pub open_orders_indexer: usize,
+    pub open_orders_account: usize,
+    pub market: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CreateOpenOrdersAccount {
+    type ArrangedAccountIndices = CreateOpenOrdersAccountInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 7 {
+            return None;
+        }
+
+        Some(CreateOpenOrdersAccountInstructionAccountIndices {
+            payer: 0,
+            owner: 1,
+            delegate_account: 2,
+            open_orders_indexer: 3,
+            open_orders_account: 4,
+            market: 5,
+            system_program: 6,
+            remaining_accounts: 7..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CreateOpenOrdersAccount {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "payer",
+            "owner",
+            "delegate_account",
+            "open_orders_indexer",
+            "open_orders_account",
+            "market",
+            "system_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +12,7 @@ pub struct CreateOpenOrdersIndexerInstructionAccounts {
     pub owner: solana_pubkey::Pubkey,
     pub open_orders_indexer: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CreateOpenOrdersIndexer {
@@ -19,7 +21,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateOpenOrdersIndexer {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [payer, owner, open_orders_indexer, system_program, _remaining @ ..] = accounts else {
+        let [payer, owner, open_orders_indexer, system_program, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +30,69 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateOpenOrdersIndexer {
             owner: owner.pubkey,
             open_orders_indexer: open_orders_indexer.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CreateOpenOrdersIndexerInstructionAccountMetas {
+    pub payer: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub open_orders_indexer: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CreateOpenOrdersIndexer {
+    type ArrangedAccountMetas = CreateOpenOrdersIndexerInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [payer, owner, open_orders_indexer, system_program, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(CreateOpenOrdersIndexerInstructionAccountMetas {
+            payer: payer.clone(),
+            owner: owner.clone(),
+            open_orders_indexer: open_orders_indexer.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CreateOpenOrdersIndexerInstructionAccountIndices {
+    pub payer: usize,
+    pub owner: usize,
+    pub open_orders_indexer: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CreateOpenOrdersIndexer {
+    type ArrangedAccountIndices = CreateOpenOrdersIndexerInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(CreateOpenOrdersIndexerInstructionAccountIndices {
+            payer: 0,
+            owner: 1,
+            open_orders_indexer: 2,
+            system_program: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CreateOpenOrdersIndexer {
+    fn account_names() -> &'static [&'static str] {
+        &["payer", "owner", "open_orders_indexer", "system_program"]
+    }
+}
@@ -85,15 +85,25 @@ pub enum OpenbookV2Instruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for OpenbookV2Decoder {
     type InstructionType = OpenbookV2Instruction;
 
-    fn decode_instruction(
+    fn decoder_name(&self) -> &'static str {
+        "openbook_v2"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[super::PROGRAM_ID]
+    }
+
+    fn try_decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
-    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+    ) -> carbon_core::error::CarbonResult<
+        Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>>,
+    > {
         if !instruction.program_id.eq(&PROGRAM_ID) {
-            return None;
+            return Ok(None);
         }
 
-        carbon_core::try_decode_instructions!(instruction,
+        Ok(carbon_core::try_decode_instructions!(instruction,
             OpenbookV2Instruction::CreateMarket => create_market::CreateMarket,
             OpenbookV2Instruction::CloseMarket => close_market::CloseMarket,
             OpenbookV2Instruction::CreateOpenOrdersIndexer => create_open_orders_indexer::CreateOpenOrdersIndexer,
@@ -131,6 +141,6 @@ impl carbon_core::instruction::InstructionDecoder<'_> for OpenbookV2Decoder {
             OpenbookV2Instruction::SettleFundsLogEvent => settle_funds_log_event::SettleFundsLogEvent,
             OpenbookV2Instruction::SweepFeesLogEvent => sweep_fees_log_event::SweepFeesLogEvent,
             OpenbookV2Instruction::OpenOrdersPositionLogEvent => open_orders_position_log_event::OpenOrdersPositionLogEvent,
-        )
+        ))
     }
 }
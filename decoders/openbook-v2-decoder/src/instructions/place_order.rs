@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -24,6 +25,7 @@ pub struct PlaceOrderInstructionAccounts {
     pub oracle_a: solana_pubkey::Pubkey,
     pub oracle_b: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for PlaceOrder {
@@ -32,7 +34,7 @@ impl carbon_core::deserialize::ArrangeAccounts for PlaceOrder {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [signer, open_orders_account, open_orders_admin, user_token_account, market, bids, asks, event_heap, market_vault, oracle_a, oracle_b, token_program, _remaining @ ..] =
+        let [signer, open_orders_account, open_orders_admin, user_token_account, market, bids, asks, event_heap, market_vault, oracle_a, oracle_b, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -51,6 +53,116 @@ impl carbon_core::deserialize::ArrangeAccounts for PlaceOrder {
             oracle_a: oracle_a.pubkey,
             oracle_b: oracle_b.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct PlaceOrderInstructionAccountMetas {
+    pub signer: solana_instruction::AccountMeta,
+    pub open_orders_account: solana_instruction::AccountMeta,
+    pub open_orders_admin: solana_instruction::AccountMeta,
+    pub user_token_account: solana_instruction::AccountMeta,
+    pub market: solana_instruction::AccountMeta,
+    pub bids: solana_instruction::AccountMeta,
+    pub asks: solana_instruction::AccountMeta,
+    pub event_heap: solana_instruction::AccountMeta,
+    pub market_vault: solana_instruction::AccountMeta,
+    pub oracle_a: solana_instruction::AccountMeta,
+    pub oracle_b: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for PlaceOrder {
+    type ArrangedAccountMetas = PlaceOrderInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [signer, open_orders_account, open_orders_admin, user_token_account, market, bids, asks, event_heap, market_vault, oracle_a, oracle_b, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(PlaceOrderInstructionAccountMetas {
+            signer: signer.clone(),
+            open_orders_account: open_orders_account.clone(),
+            open_orders_admin: open_orders_admin.clone(),
+            user_token_account: user_token_account.clone(),
+            market: market.clone(),
+            bids: bids.clone(),
+            asks: asks.clone(),
+            event_heap: event_heap.clone(),
+            market_vault: market_vault.clone(),
+            oracle_a: oracle_a.clone(),
+            oracle_b: oracle_b.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct PlaceOrderInstructionAccountIndices {
+    pub signer: usize,
+    pub open_orders_account: usize,
+    pub open_orders_admin: usize,
+    pub user_token_account: usize,
+    pub market: usize,
+    pub bids: usize,
+    pub asks: usize,
+    pub event_heap: usize,
+    pub market_vault: usize,
+    pub oracle_a: usize,
+    pub oracle_b: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for PlaceOrder {
+    type ArrangedAccountIndices = PlaceOrderInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 12 {
+            return None;
+        }
+
+        Some(PlaceOrderInstructionAccountIndices {
+            signer: 0,
+            open_orders_account: 1,
+            open_orders_admin: 2,
+            user_token_account: 3,
+            market: 4,
+            bids: 5,
+            asks: 6,
+            event_heap: 7,
+            market_vault: 8,
+            oracle_a: 9,
+            oracle_b: 10,
+            token_program: 11,
+            remaining_accounts: 12..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for PlaceOrder {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "signer",
+            "open_orders_account",
+            "open_orders_admin",
+            "user_token_account",
+            "market",
+            "bids",
+            "asks",
+            "event_heap",
+            "market_vault",
+            "oracle_a",
+            "oracle_b",
+            "token_program",
+        ]
+    }
+}
@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -28,6 +29,7 @@ pub struct PlaceTakeOrderInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub open_orders_admin: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for PlaceTakeOrder {
@@ -36,7 +38,7 @@ impl carbon_core::deserialize::ArrangeAccounts for PlaceTakeOrder {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [signer, penalty_payer, market, market_authority, bids, asks, market_base_vault, market_quote_vault, event_heap, user_base_account, user_quote_account, oracle_a, oracle_b, token_program, system_program, open_orders_admin, _remaining @ ..] =
+        let [signer, penalty_payer, market, market_authority, bids, asks, market_base_vault, market_quote_vault, event_heap, user_base_account, user_quote_account, oracle_a, oracle_b, token_program, system_program, open_orders_admin, remaining @ ..] =
             accounts
         else {
             return None;
@@ -59,6 +61,136 @@ impl carbon_core::deserialize::ArrangeAccounts for PlaceTakeOrder {
             token_program: token_program.pubkey,
             system_program: system_program.pubkey,
             open_orders_admin: open_orders_admin.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct PlaceTakeOrderInstructionAccountMetas {
+    pub signer: solana_instruction::AccountMeta,
+    pub penalty_payer: solana_instruction::AccountMeta,
+    pub market: solana_instruction::AccountMeta,
+    pub market_authority: solana_instruction::AccountMeta,
+    pub bids: solana_instruction::AccountMeta,
+    pub asks: solana_instruction::AccountMeta,
+    pub market_base_vault: solana_instruction::AccountMeta,
+    pub market_quote_vault: solana_instruction::AccountMeta,
+    pub event_heap: solana_instruction::AccountMeta,
+    pub user_base_account: solana_instruction::AccountMeta,
+    pub user_quote_account: solana_instruction::AccountMeta,
+    pub oracle_a: solana_instruction::AccountMeta,
+    pub oracle_b: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub open_orders_admin: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for PlaceTakeOrder {
+    type ArrangedAccountMetas = PlaceTakeOrderInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [signer, penalty_payer, market, market_authority, bids, asks, market_base_vault, market_quote_vault, event_heap, user_base_account, user_quote_account, oracle_a, oracle_b, token_program, system_program, open_orders_admin, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(PlaceTakeOrderInstructionAccountMetas {
+            signer: signer.clone(),
+            penalty_payer: penalty_payer.clone(),
+            market: market.clone(),
+            market_authority: market_authority.clone(),
+            bids: bids.clone(),
+            asks: asks.clone(),
+            market_base_vault: market_base_vault.clone(),
+            market_quote_vault: market_quote_vault.clone(),
+            event_heap: event_heap.clone(),
+            user_base_account: user_base_account.clone(),
+            user_quote_account: user_quote_account.clone(),
+            oracle_a: oracle_a.clone(),
+            oracle_b: oracle_b.clone(),
+            token_program: token_program.clone(),
+            system_program: system_program.clone(),
+            open_orders_admin: open_orders_admin.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct PlaceTakeOrderInstructionAccountIndices {
+    pub signer: usize,
+    pub penalty_payer: usize,
+    pub market: usize,
+    pub market_authority: usize,
+    pub bids: usize,
+    pub asks: usize,
+    pub market_base_vault: usize,
+    pub market_quote_vault: usize,
+    pub event_heap: usize,
+    pub user_base_account: usize,
+    pub user_quote_account: usize,
+    pub oracle_a: usize,
+    pub oracle_b: usize,
+    pub token_program: usize,
+    pub system_program: usize,
+    pub open_orders_admin: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for PlaceTakeOrder {
+    type ArrangedAccountIndices = PlaceTakeOrderInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 16 {
+            return None;
+        }
+
+        Some(PlaceTakeOrderInstructionAccountIndices {
+            signer: 0,
+            penalty_payer: 1,
+            market: 2,
+            market_authority: 3,
+            bids: 4,
+            asks: 5,
+            market_base_vault: 6,
+            market_quote_vault: 7,
+            event_heap: 8,
+            user_base_account: 9,
+            user_quote_account: 10,
+            oracle_a: 11,
+            oracle_b: 12,
+            token_program: 13,
+            system_program: 14,
+            open_orders_admin: 15,
+            remaining_accounts: 16..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for PlaceTakeOrder {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "signer",
+            "penalty_payer",
+            "market",
+            "market_authority",
+            "bids",
+            "asks",
+            "market_base_vault",
+            "market_quote_vault",
+            "event_heap",
+            "user_base_account",
+            "user_quote_account",
+            "oracle_a",
+            "oracle_b",
+            "token_program",
+            "system_program",
+            "open_orders_admin",
+        ]
+    }
+}
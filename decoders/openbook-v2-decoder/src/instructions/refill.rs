@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -18,6 +19,7 @@ pub struct RefillInstructionAccounts {
     pub market_base_vault: solana_pubkey::Pubkey,
     pub market_quote_vault: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Refill {
@@ -26,7 +28,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Refill {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [owner, user_base_account, user_quote_account, open_orders_account, market, market_base_vault, market_quote_vault, token_program, _remaining @ ..] =
+        let [owner, user_base_account, user_quote_account, open_orders_account, market, market_base_vault, market_quote_vault, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -41,6 +43,96 @@ impl carbon_core::deserialize::ArrangeAccounts for Refill {
             market_base_vault: market_base_vault.pubkey,
             market_quote_vault: market_quote_vault.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct RefillInstructionAccountMetas {
+    pub owner: solana_instruction::AccountMeta,
+    pub user_base_account: solana_instruction::AccountMeta,
+    pub user_quote_account: solana_instruction::AccountMeta,
+    pub open_orders_account: solana_instruction::AccountMeta,
+    pub market: solana_instruction::AccountMeta,
+    pub market_base_vault: solana_instruction::AccountMeta,
+    pub market_quote_vault: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Refill {
+    type ArrangedAccountMetas = RefillInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [owner, user_base_account, user_quote_account, open_orders_account, market, market_base_vault, market_quote_vault, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(RefillInstructionAccountMetas {
+            owner: owner.clone(),
+            user_base_account: user_base_account.clone(),
+            user_quote_account: user_quote_account.clone(),
+            open_orders_account: open_orders_account.clone(),
+            market: market.clone(),
+            market_base_vault: market_base_vault.clone(),
+            market_quote_vault: market_quote_vault.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct RefillInstructionAccountIndices {
+    pub owner: usize,
+    pub user_base_account: usize,
+    pub user_quote_account: usize,
+    pub open_orders_account: usize,
+    pub market: usize,
+    pub market_base_vault: usize,
+    pub market_quote_vault: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Refill {
+    type ArrangedAccountIndices = RefillInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(RefillInstructionAccountIndices {
+            owner: 0,
+            user_base_account: 1,
+            user_quote_account: 2,
+            open_orders_account: 3,
+            market: 4,
+            market_base_vault: 5,
+            market_quote_vault: 6,
+            token_program: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Refill {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "owner",
+            "user_base_account",
+            "user_quote_account",
+            "open_orders_account",
+            "market",
+            "market_base_vault",
+            "market_quote_vault",
+            "token_program",
+        ]
+    }
+}
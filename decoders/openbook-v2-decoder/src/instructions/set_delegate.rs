@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -10,6 +11,7 @@ pub struct SetDelegateInstructionAccounts {
     pub owner: solana_pubkey::Pubkey,
     pub open_orders_account: solana_pubkey::Pubkey,
     pub delegate_account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SetDelegate {
@@ -18,7 +20,7 @@ impl carbon_core::deserialize::ArrangeAccounts for SetDelegate {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [owner, open_orders_account, delegate_account, _remaining @ ..] = accounts else {
+        let [owner, open_orders_account, delegate_account, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -26,6 +28,65 @@ impl carbon_core::deserialize::ArrangeAccounts for SetDelegate {
             owner: owner.pubkey,
             open_orders_account: open_orders_account.pubkey,
             delegate_account: delegate_account.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SetDelegateInstructionAccountMetas {
+    pub owner: solana_instruction::AccountMeta,
+    pub open_orders_account: solana_instruction::AccountMeta,
+    pub delegate_account: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SetDelegate {
+    type ArrangedAccountMetas = SetDelegateInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [owner, open_orders_account, delegate_account, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(SetDelegateInstructionAccountMetas {
+            owner: owner.clone(),
+            open_orders_account: open_orders_account.clone(),
+            delegate_account: delegate_account.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SetDelegateInstructionAccountIndices {
+    pub owner: usize,
+    pub open_orders_account: usize,
+    pub delegate_account: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SetDelegate {
+    type ArrangedAccountIndices = SetDelegateInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(SetDelegateInstructionAccountIndices {
+            owner: 0,
+            open_orders_account: 1,
+            delegate_account: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SetDelegate {
+    fn account_names() -> &'static [&'static str] {
+        &["owner", "open_orders_account", "delegate_account"]
+    }
+}
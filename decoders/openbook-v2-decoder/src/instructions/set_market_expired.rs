@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -9,6 +10,7 @@ pub struct SetMarketExpired {}
 pub struct SetMarketExpiredInstructionAccounts {
     pub close_market_admin: solana_pubkey::Pubkey,
     pub market: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SetMarketExpired {
@@ -17,13 +19,68 @@ impl carbon_core::deserialize::ArrangeAccounts for SetMarketExpired {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [close_market_admin, market, _remaining @ ..] = accounts else {
+        let [close_market_admin, market, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(SetMarketExpiredInstructionAccounts {
             close_market_admin: close_market_admin.pubkey,
             market: market.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SetMarketExpiredInstructionAccountMetas {
+    pub close_market_admin: solana_instruction::AccountMeta,
+    pub market: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SetMarketExpired {
+    type ArrangedAccountMetas = SetMarketExpiredInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [close_market_admin, market, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(SetMarketExpiredInstructionAccountMetas {
+            close_market_admin: close_market_admin.clone(),
+            market: market.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SetMarketExpiredInstructionAccountIndices {
+    pub close_market_admin: usize,
+    pub market: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SetMarketExpired {
+    type ArrangedAccountIndices = SetMarketExpiredInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(SetMarketExpiredInstructionAccountIndices {
+            close_market_admin: 0,
+            market: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SetMarketExpired {
+    fn account_names() -> &'static [&'static str] {
+        &["close_market_admin", "market"]
+    }
+}
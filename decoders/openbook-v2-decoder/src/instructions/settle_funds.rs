@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -19,6 +20,7 @@ pub struct SettleFundsInstructionAccounts {
     pub referrer_account: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SettleFunds {
@@ -27,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for SettleFunds {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [owner, penalty_payer, open_orders_account, market, market_authority, market_base_vault, market_quote_vault, user_base_account, user_quote_account, referrer_account, token_program, system_program, _remaining @ ..] =
+        let [owner, penalty_payer, open_orders_account, market, market_authority, market_base_vault, market_quote_vault, user_base_account, user_quote_account, referrer_account, token_program, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -46,6 +48,116 @@ impl carbon_core::deserialize::ArrangeAccounts for SettleFunds {
             referrer_account: referrer_account.pubkey,
             token_program: token_program.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SettleFundsInstructionAccountMetas {
+    pub owner: solana_instruction::AccountMeta,
+    pub penalty_payer: solana_instruction::AccountMeta,
+    pub open_orders_account: solana_instruction::AccountMeta,
+    pub market: solana_instruction::AccountMeta,
+    pub market_authority: solana_instruction::AccountMeta,
+    pub market_base_vault: solana_instruction::AccountMeta,
+    pub market_quote_vault: solana_instruction::AccountMeta,
+    pub user_base_account: solana_instruction::AccountMeta,
+    pub user_quote_account: solana_instruction::AccountMeta,
+    pub referrer_account: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SettleFunds {
+    type ArrangedAccountMetas = SettleFundsInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [owner, penalty_payer, open_orders_account, market, market_authority, market_base_vault, market_quote_vault, user_base_account, user_quote_account, referrer_account, token_program, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(SettleFundsInstructionAccountMetas {
+            owner: owner.clone(),
+            penalty_payer: penalty_payer.clone(),
+            open_orders_account: open_orders_account.clone(),
+            market: market.clone(),
+            market_authority: market_authority.clone(),
+            market_base_vault: market_base_vault.clone(),
+            market_quote_vault: market_quote_vault.clone(),
+            user_base_account: user_base_account.clone(),
+            user_quote_account: user_quote_account.clone(),
+            referrer_account: referrer_account.clone(),
+            token_program: token_program.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SettleFundsInstructionAccountIndices {
+    pub owner: usize,
+    pub penalty_payer: usize,
+    pub open_orders_account: usize,
+    pub market: usize,
+    pub market_authority: usize,
+    pub market_base_vault: usize,
+    pub market_quote_vault: usize,
+    pub user_base_account: usize,
+    pub user_quote_account: usize,
+    pub referrer_account: usize,
+    pub token_program: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SettleFunds {
+    type ArrangedAccountIndices = SettleFundsInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 12 {
+            return None;
+        }
+
+        Some(SettleFundsInstructionAccountIndices {
+            owner: 0,
+            penalty_payer: 1,
+            open_orders_account: 2,
+            market: 3,
+            market_authority: 4,
+            market_base_vault: 5,
+            market_quote_vault: 6,
+            user_base_account: 7,
+            user_quote_account: 8,
+            referrer_account: 9,
+            token_program: 10,
+            system_program: 11,
+            remaining_accounts: 12..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SettleFunds {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "owner",
+            "penalty_payer",
+            "open_orders_account",
+            "market",
+            "market_authority",
+            "market_base_vault",
+            "market_quote_vault",
+            "user_base_account",
+            "user_quote_account",
+            "referrer_account",
+            "token_program",
+            "system_program",
+        ]
+    }
+}
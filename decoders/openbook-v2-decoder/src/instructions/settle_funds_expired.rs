@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -20,6 +21,7 @@ pub struct SettleFundsExpiredInstructionAccounts {
     pub referrer_account: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SettleFundsExpired {
@@ -28,7 +30,7 @@ impl carbon_core::deserialize::ArrangeAccounts for SettleFundsExpired {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [close_market_admin, owner, penalty_payer, open_orders_account, market, market_authority, market_base_vault, market_quote_vault, user_base_account, user_quote_account, referrer_account, token_program, system_program, _remaining @ ..] =
+        let [close_market_admin, owner, penalty_payer, open_orders_account, market, market_authority, market_base_vault, market_quote_vault, user_base_account, user_quote_account, referrer_account, token_program, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -48,6 +50,121 @@ impl carbon_core::deserialize::ArrangeAccounts for SettleFundsExpired {
             referrer_account: referrer_account.pubkey,
             token_program: token_program.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SettleFundsExpiredInstructionAccountMetas {
+    pub close_market_admin: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub penalty_payer: solana_instruction::AccountMeta,
+    pub open_orders_account: solana_instruction::AccountMeta,
+    pub market: solana_instruction::AccountMeta,
+    pub market_authority: solana_instruction::AccountMeta,
+    pub market_base_vault: solana_instruction::AccountMeta,
+    pub market_quote_vault: solana_instruction::AccountMeta,
+    pub user_base_account: solana_instruction::AccountMeta,
+    pub user_quote_account: solana_instruction::AccountMeta,
+    pub referrer_account: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SettleFundsExpired {
+    type ArrangedAccountMetas = SettleFundsExpiredInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [close_market_admin, owner, penalty_payer, open_orders_account, market, market_authority, market_base_vault, market_quote_vault, user_base_account, user_quote_account, referrer_account, token_program, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(SettleFundsExpiredInstructionAccountMetas {
+            close_market_admin: close_market_admin.clone(),
+            owner: owner.clone(),
+            penalty_payer: penalty_payer.clone(),
+            open_orders_account: open_orders_account.clone(),
+            market: market.clone(),
+            market_authority: market_authority.clone(),
+            market_base_vault: market_base_vault.clone(),
+            market_quote_vault: market_quote_vault.clone(),
+            user_base_account: user_base_account.clone(),
+            user_quote_account: user_quote_account.clone(),
+            referrer_account: referrer_account.clone(),
+            token_program: token_program.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SettleFundsExpiredInstructionAccountIndices {
+    pub close_market_admin: usize,
+    pub owner: usize,
+    pub penalty_payer: usize,
+    pub open_orders_account: usize,
+    pub market: usize,
+    pub market_authority: usize,
+    pub market_base_vault: usize,
+    pub market_quote_vault: usize,
+    pub user_base_account: usize,
+    pub user_quote_account: usize,
+    pub referrer_account: usize,
+    pub token_program: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SettleFundsExpired {
+    type ArrangedAccountIndices = SettleFundsExpiredInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 13 {
+            return None;
+        }
+
+        Some(SettleFundsExpiredInstructionAccountIndices {
+            close_market_admin: 0,
+            owner: 1,
+            penalty_payer: 2,
+            open_orders_account: 3,
+            market: 4,
+            market_authority: 5,
+            market_base_vault: 6,
+            market_quote_vault: 7,
+            user_base_account: 8,
+            user_quote_account: 9,
+            referrer_account: 10,
+            token_program: 11,
+            system_program: 12,
+            remaining_accounts: 13..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SettleFundsExpired {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "close_market_admin",
+            "owner",
+            "penalty_payer",
+            "open_orders_account",
+            "market",
+            "market_authority",
+            "market_base_vault",
+            "market_quote_vault",
+            "user_base_account",
+            "user_quote_account",
+            "referrer_account",
+            "token_program",
+            "system_program",
+        ]
+    }
+}
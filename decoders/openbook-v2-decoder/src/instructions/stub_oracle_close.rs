@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +12,7 @@ pub struct StubOracleCloseInstructionAccounts {
     pub oracle: solana_pubkey::Pubkey,
     pub sol_destination: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for StubOracleClose {
@@ -19,7 +21,7 @@ impl carbon_core::deserialize::ArrangeAccounts for StubOracleClose {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [owner, oracle, sol_destination, token_program, _remaining @ ..] = accounts else {
+        let [owner, oracle, sol_destination, token_program, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +30,69 @@ impl carbon_core::deserialize::ArrangeAccounts for StubOracleClose {
             oracle: oracle.pubkey,
             sol_destination: sol_destination.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct StubOracleCloseInstructionAccountMetas {
+    pub owner: solana_instruction::AccountMeta,
+    pub oracle: solana_instruction::AccountMeta,
+    pub sol_destination: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for StubOracleClose {
+    type ArrangedAccountMetas = StubOracleCloseInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [owner, oracle, sol_destination, token_program, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(StubOracleCloseInstructionAccountMetas {
+            owner: owner.clone(),
+            oracle: oracle.clone(),
+            sol_destination: sol_destination.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct StubOracleCloseInstructionAccountIndices {
+    pub owner: usize,
+    pub oracle: usize,
+    pub sol_destination: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for StubOracleClose {
+    type ArrangedAccountIndices = StubOracleCloseInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(StubOracleCloseInstructionAccountIndices {
+            owner: 0,
+            oracle: 1,
+            sol_destination: 2,
+            token_program: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for StubOracleClose {
+    fn account_names() -> &'static [&'static str] {
+        &["owner", "oracle", "sol_destination", "token_program"]
+    }
+}
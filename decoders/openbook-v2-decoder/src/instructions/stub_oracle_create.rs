@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
 #[carbon(discriminator = "0xac3f65538d4cc7d8")]
 pub struct StubOracleCreate {
@@ -12,6 +13,7 @@ pub struct StubOracleCreateInstructionAccounts {
     pub oracle: solana_pubkey::Pubkey,
     pub mint: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for StubOracleCreate {
@@ -20,7 +22,7 @@ impl carbon_core::deserialize::ArrangeAccounts for StubOracleCreate {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [payer, owner, oracle, mint, system_program, _remaining @ ..] = accounts else {
+        let [payer, owner, oracle, mint, system_program, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -30,6 +32,73 @@ impl carbon_core::deserialize::ArrangeAccounts for StubOracleCreate {
             oracle: oracle.pubkey,
             mint: mint.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct StubOracleCreateInstructionAccountMetas {
+    pub payer: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub oracle: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for StubOracleCreate {
+    type ArrangedAccountMetas = StubOracleCreateInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [payer, owner, oracle, mint, system_program, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(StubOracleCreateInstructionAccountMetas {
+            payer: payer.clone(),
+            owner: owner.clone(),
+            oracle: oracle.clone(),
+            mint: mint.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct StubOracleCreateInstructionAccountIndices {
+    pub payer: usize,
+    pub owner: usize,
+    pub oracle: usize,
+    pub mint: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for StubOracleCreate {
+    type ArrangedAccountIndices = StubOracleCreateInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(StubOracleCreateInstructionAccountIndices {
+            payer: 0,
+            owner: 1,
+            oracle: 2,
+            mint: 3,
+            system_program: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for StubOracleCreate {
+    fn account_names() -> &'static [&'static str] {
+        &["payer", "owner", "oracle", "mint", "system_program"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
 #[carbon(discriminator = "0x6dc64f7941caa18e")]
 pub struct StubOracleSet {
@@ -9,6 +10,7 @@ pub struct StubOracleSet {
 pub struct StubOracleSetInstructionAccounts {
     pub owner: solana_pubkey::Pubkey,
     pub oracle: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for StubOracleSet {
@@ -17,13 +19,68 @@ impl carbon_core::deserialize::ArrangeAccounts for StubOracleSet {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [owner, oracle, _remaining @ ..] = accounts else {
+        let [owner, oracle, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(StubOracleSetInstructionAccounts {
             owner: owner.pubkey,
             oracle: oracle.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct StubOracleSetInstructionAccountMetas {
+    pub owner: solana_instruction::AccountMeta,
+    pub oracle: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for StubOracleSet {
+    type ArrangedAccountMetas = StubOracleSetInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [owner, oracle, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(StubOracleSetInstructionAccountMetas {
+            owner: owner.clone(),
+            oracle: oracle.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct StubOracleSetInstructionAccountIndices {
+    pub owner: usize,
+    pub oracle: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for StubOracleSet {
+    type ArrangedAccountIndices = StubOracleSetInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(StubOracleSetInstructionAccountIndices {
+            owner: 0,
+            oracle: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for StubOracleSet {
+    fn account_names() -> &'static [&'static str] {
+        &["owner", "oracle"]
+    }
+}
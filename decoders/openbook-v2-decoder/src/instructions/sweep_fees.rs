@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +14,7 @@ pub struct SweepFeesInstructionAccounts {
     pub market_quote_vault: solana_pubkey::Pubkey,
     pub token_receiver_account: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SweepFees {
@@ -21,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for SweepFees {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [collect_fee_admin, market, market_authority, market_quote_vault, token_receiver_account, token_program, _remaining @ ..] =
+        let [collect_fee_admin, market, market_authority, market_quote_vault, token_receiver_account, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -34,6 +36,86 @@ impl carbon_core::deserialize::ArrangeAccounts for SweepFees {
             market_quote_vault: market_quote_vault.pubkey,
             token_receiver_account: token_receiver_account.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SweepFeesInstructionAccountMetas {
+    pub collect_fee_admin: solana_instruction::AccountMeta,
+    pub market: solana_instruction::AccountMeta,
+    pub market_authority: solana_instruction::AccountMeta,
+    pub market_quote_vault: solana_instruction::AccountMeta,
+    pub token_receiver_account: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SweepFees {
+    type ArrangedAccountMetas = SweepFeesInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [collect_fee_admin, market, market_authority, market_quote_vault, token_receiver_account, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(SweepFeesInstructionAccountMetas {
+            collect_fee_admin: collect_fee_admin.clone(),
+            market: market.clone(),
+            market_authority: market_authority.clone(),
+            market_quote_vault: market_quote_vault.clone(),
+            token_receiver_account: token_receiver_account.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SweepFeesInstructionAccountIndices {
+    pub collect_fee_admin: usize,
+    pub market: usize,
+    pub market_authority: usize,
+    pub market_quote_vault: usize,
+    pub token_receiver_account: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SweepFees {
+    type ArrangedAccountIndices = SweepFeesInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(SweepFeesInstructionAccountIndices {
+            collect_fee_admin: 0,
+            market: 1,
+            market_authority: 2,
+            market_quote_vault: 3,
+            token_receiver_account: 4,
+            token_program: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SweepFees {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "collect_fee_admin",
+            "market",
+            "market_authority",
+            "market_quote_vault",
+            "token_receiver_account",
+            "token_program",
+        ]
+    }
+}
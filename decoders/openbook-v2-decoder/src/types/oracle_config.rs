@@ -3,6 +3,7 @@ use {
     serde_big_array::BigArray,
 };
 
+#[carbon_core::carbon_serde]
 #[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, Clone)]
 pub struct OracleConfig {
     pub conf_filter: f64,
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct CloseBundledPositionInstructionAccounts {
     pub position_bundle_token_account: solana_pubkey::Pubkey,
     pub position_bundle_authority: solana_pubkey::Pubkey,
     pub receiver: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CloseBundledPosition {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CloseBundledPosition {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [bundled_position, position_bundle, position_bundle_token_account, position_bundle_authority, receiver, _remaining @ ..] =
+        let [bundled_position, position_bundle, position_bundle_token_account, position_bundle_authority, receiver, remaining @ ..] =
             accounts
         else {
             return None;
@@ -34,6 +36,81 @@ impl carbon_core::deserialize::ArrangeAccounts for CloseBundledPosition {
             position_bundle_token_account: position_bundle_token_account.pubkey,
             position_bundle_authority: position_bundle_authority.pubkey,
             receiver: receiver.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CloseBundledPositionInstructionAccountMetas {
+    pub bundled_position: solana_instruction::AccountMeta,
+    pub position_bundle: solana_instruction::AccountMeta,
+    pub position_bundle_token_account: solana_instruction::AccountMeta,
+    pub position_bundle_authority: solana_instruction::AccountMeta,
+    pub receiver: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CloseBundledPosition {
+    type ArrangedAccountMetas = CloseBundledPositionInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [bundled_position, position_bundle, position_bundle_token_account, position_bundle_authority, receiver, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CloseBundledPositionInstructionAccountMetas {
+            bundled_position: bundled_position.clone(),
+            position_bundle: position_bundle.clone(),
+            position_bundle_token_account: position_bundle_token_account.clone(),
+            position_bundle_authority: position_bundle_authority.clone(),
+            receiver: receiver.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CloseBundledPositionInstructionAccountIndices {
+    pub bundled_position: usize,
+    pub position_bundle: usize,
+    pub position_bundle_token_account: usize,
+    pub position_bundle_authority: usize,
+    pub receiver: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CloseBundledPosition {
+    type ArrangedAccountIndices = CloseBundledPositionInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(CloseBundledPositionInstructionAccountIndices {
+            bundled_position: 0,
+            position_bundle: 1,
+            position_bundle_token_account: 2,
+            position_bundle_authority: 3,
+            receiver: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CloseBundledPosition {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "bundled_position",
+            "position_bundle",
+            "position_bundle_token_account",
+            "position_bundle_authority",
+            "receiver",
+        ]
+    }
+}
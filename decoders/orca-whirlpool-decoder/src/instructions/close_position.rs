@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +14,7 @@ pub struct ClosePositionInstructionAccounts {
     pub position_mint: solana_pubkey::Pubkey,
     pub position_token_account: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ClosePosition {
@@ -21,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ClosePosition {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [position_authority, receiver, position, position_mint, position_token_account, token_program, _remaining @ ..] =
+        let [position_authority, receiver, position, position_mint, position_token_account, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -34,6 +36,86 @@ impl carbon_core::deserialize::ArrangeAccounts for ClosePosition {
             position_mint: position_mint.pubkey,
             position_token_account: position_token_account.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ClosePositionInstructionAccountMetas {
+    pub position_authority: solana_instruction::AccountMeta,
+    pub receiver: solana_instruction::AccountMeta,
+    pub position: solana_instruction::AccountMeta,
+    pub position_mint: solana_instruction::AccountMeta,
+    pub position_token_account: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ClosePosition {
+    type ArrangedAccountMetas = ClosePositionInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [position_authority, receiver, position, position_mint, position_token_account, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(ClosePositionInstructionAccountMetas {
+            position_authority: position_authority.clone(),
+            receiver: receiver.clone(),
+            position: position.clone(),
+            position_mint: position_mint.clone(),
+            position_token_account: position_token_account.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ClosePositionInstructionAccountIndices {
+    pub position_authority: usize,
+    pub receiver: usize,
+    pub position: usize,
+    pub position_mint: usize,
+    pub position_token_account: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ClosePosition {
+    type ArrangedAccountIndices = ClosePositionInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(ClosePositionInstructionAccountIndices {
+            position_authority: 0,
+            receiver: 1,
+            position: 2,
+            position_mint: 3,
+            position_token_account: 4,
+            token_program: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ClosePosition {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "position_authority",
+            "receiver",
+            "position",
+            "position_mint",
+            "position_token_account",
+            "token_program",
+        ]
+    }
+}
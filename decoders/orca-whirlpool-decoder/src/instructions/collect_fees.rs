@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -16,6 +17,7 @@ pub struct CollectFeesInstructionAccounts {
     pub token_owner_account_b: solana_pubkey::Pubkey,
     pub token_vault_b: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CollectFees {
@@ -24,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CollectFees {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [whirlpool, position_authority, position, position_token_account, token_owner_account_a, token_vault_a, token_owner_account_b, token_vault_b, token_program, _remaining @ ..] =
+        let [whirlpool, position_authority, position, position_token_account, token_owner_account_a, token_vault_a, token_owner_account_b, token_vault_b, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -40,6 +42,101 @@ impl carbon_core::deserialize::ArrangeAccounts for CollectFees {
             token_owner_account_b: token_owner_account_b.pubkey,
             token_vault_b: token_vault_b.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CollectFeesInstructionAccountMetas {
+    pub whirlpool: solana_instruction::AccountMeta,
+    pub position_authority: solana_instruction::AccountMeta,
+    pub position: solana_instruction::AccountMeta,
+    pub position_token_account: solana_instruction::AccountMeta,
+    pub token_owner_account_a: solana_instruction::AccountMeta,
+    pub token_vault_a: solana_instruction::AccountMeta,
+    pub token_owner_account_b: solana_instruction::AccountMeta,
+    pub token_vault_b: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CollectFees {
+    type ArrangedAccountMetas = CollectFeesInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [whirlpool, position_authority, position, position_token_account, token_owner_account_a, token_vault_a, token_owner_account_b, token_vault_b, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CollectFeesInstructionAccountMetas {
+            whirlpool: whirlpool.clone(),
+            position_authority: position_authority.clone(),
+            position: position.clone(),
+            position_token_account: position_token_account.clone(),
+            token_owner_account_a: token_owner_account_a.clone(),
+            token_vault_a: token_vault_a.clone(),
+            token_owner_account_b: token_owner_account_b.clone(),
+            token_vault_b: token_vault_b.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CollectFeesInstructionAccountIndices {
+    pub whirlpool: usize,
+    pub position_authority: usize,
+    pub position: usize,
+    pub position_token_account: usize,
+    pub token_owner_account_a: usize,
+    pub token_vault_a: usize,
+    pub token_owner_account_b: usize,
+    pub token_vault_b: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CollectFees {
+    type ArrangedAccountIndices = CollectFeesInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 9 {
+            return None;
+        }
+
+        Some(CollectFeesInstructionAccountIndices {
+            whirlpool: 0,
+            position_authority: 1,
+            position: 2,
+            position_token_account: 3,
+            token_owner_account_a: 4,
+            token_vault_a: 5,
+            token_owner_account_b: 6,
+            token_vault_b: 7,
+            token_program: 8,
+            remaining_accounts: 9..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CollectFees {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "whirlpool",
+            "position_authority",
+            "position",
+            "position_token_account",
+            "token_owner_account_a",
+            "token_vault_a",
+            "token_owner_account_b",
+            "token_vault_b",
+            "token_program",
+        ]
+    }
+}
@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -25,6 +26,7 @@ pub struct CollectFeesV2InstructionAccounts {
     pub token_program_a: solana_pubkey::Pubkey,
     pub token_program_b: solana_pubkey::Pubkey,
     pub memo_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CollectFeesV2 {
@@ -33,7 +35,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CollectFeesV2 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [whirlpool, position_authority, position, position_token_account, token_mint_a, token_mint_b, token_owner_account_a, token_vault_a, token_owner_account_b, token_vault_b, token_program_a, token_program_b, memo_program, _remaining @ ..] =
+        let [whirlpool, position_authority, position, position_token_account, token_mint_a, token_mint_b, token_owner_account_a, token_vault_a, token_owner_account_b, token_vault_b, token_program_a, token_program_b, memo_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -53,6 +55,121 @@ impl carbon_core::deserialize::ArrangeAccounts for CollectFeesV2 {
             token_program_a: token_program_a.pubkey,
             token_program_b: token_program_b.pubkey,
             memo_program: memo_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CollectFeesV2InstructionAccountMetas {
+    pub whirlpool: solana_instruction::AccountMeta,
+    pub position_authority: solana_instruction::AccountMeta,
+    pub position: solana_instruction::AccountMeta,
+    pub position_token_account: solana_instruction::AccountMeta,
+    pub token_mint_a: solana_instruction::AccountMeta,
+    pub token_mint_b: solana_instruction::AccountMeta,
+    pub token_owner_account_a: solana_instruction::AccountMeta,
+    pub token_vault_a: solana_instruction::AccountMeta,
+    pub token_owner_account_b: solana_instruction::AccountMeta,
+    pub token_vault_b: solana_instruction::AccountMeta,
+    pub token_program_a: solana_instruction::AccountMeta,
+    pub token_program_b: solana_instruction::AccountMeta,
+    pub memo_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CollectFeesV2 {
+    type ArrangedAccountMetas = CollectFeesV2InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [whirlpool, position_authority, position, position_token_account, token_mint_a, token_mint_b, token_owner_account_a, token_vault_a, token_owner_account_b, token_vault_b, token_program_a, token_program_b, memo_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CollectFeesV2InstructionAccountMetas {
+            whirlpool: whirlpool.clone(),
+            position_authority: position_authority.clone(),
+            position: position.clone(),
+            position_token_account: position_token_account.clone(),
+            token_mint_a: token_mint_a.clone(),
+            token_mint_b: token_mint_b.clone(),
+            token_owner_account_a: token_owner_account_a.clone(),
+            token_vault_a: token_vault_a.clone(),
+            token_owner_account_b: token_owner_account_b.clone(),
+            token_vault_b: token_vault_b.clone(),
+            token_program_a: token_program_a.clone(),
+            token_program_b: token_program_b.clone(),
+            memo_program: memo_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CollectFeesV2InstructionAccountIndices {
+    pub whirlpool: usize,
+    pub position_authority: usize,
+    pub position: usize,
+    pub position_token_account: usize,
+    pub token_mint_a: usize,
+    pub token_mint_b: usize,
+    pub token_owner_account_a: usize,
+    pub token_vault_a: usize,
+    pub token_owner_account_b: usize,
+    pub token_vault_b: usize,
+    pub token_program_a: usize,
+    pub token_program_b: usize,
+    pub memo_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CollectFeesV2 {
+    type ArrangedAccountIndices = CollectFeesV2InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 13 {
+            return None;
+        }
+
+        Some(CollectFeesV2InstructionAccountIndices {
+            whirlpool: 0,
+            position_authority: 1,
+            position: 2,
+            position_token_account: 3,
+            token_mint_a: 4,
+            token_mint_b: 5,
+            token_owner_account_a: 6,
+            token_vault_a: 7,
+            token_owner_account_b: 8,
+            token_vault_b: 9,
+            token_program_a: 10,
+            token_program_b: 11,
+            memo_program: 12,
+            remaining_accounts: 13..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CollectFeesV2 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "whirlpool",
+            "position_authority",
+            "position",
+            "position_token_account",
+            "token_mint_a",
+            "token_mint_b",
+            "token_owner_account_a",
+            "token_vault_a",
+            "token_owner_account_b",
+            "token_vault_b",
+            "token_program_a",
+            "token_program_b",
+            "memo_program",
+        ]
+    }
+}
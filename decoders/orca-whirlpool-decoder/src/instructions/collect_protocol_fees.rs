@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -15,6 +16,7 @@ pub struct CollectProtocolFeesInstructionAccounts {
     pub token_destination_a: solana_pubkey::Pubkey,
     pub token_destination_b: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CollectProtocolFees {
@@ -23,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CollectProtocolFees {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [whirlpools_config, whirlpool, collect_protocol_fees_authority, token_vault_a, token_vault_b, token_destination_a, token_destination_b, token_program, _remaining @ ..] =
+        let [whirlpools_config, whirlpool, collect_protocol_fees_authority, token_vault_a, token_vault_b, token_destination_a, token_destination_b, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -38,6 +40,96 @@ impl carbon_core::deserialize::ArrangeAccounts for CollectProtocolFees {
             token_destination_a: token_destination_a.pubkey,
             token_destination_b: token_destination_b.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CollectProtocolFeesInstructionAccountMetas {
+    pub whirlpools_config: solana_instruction::AccountMeta,
+    pub whirlpool: solana_instruction::AccountMeta,
+    pub collect_protocol_fees_authority: solana_instruction::AccountMeta,
+    pub token_vault_a: solana_instruction::AccountMeta,
+    pub token_vault_b: solana_instruction::AccountMeta,
+    pub token_destination_a: solana_instruction::AccountMeta,
+    pub token_destination_b: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CollectProtocolFees {
+    type ArrangedAccountMetas = CollectProtocolFeesInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [whirlpools_config, whirlpool, collect_protocol_fees_authority, token_vault_a, token_vault_b, token_destination_a, token_destination_b, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CollectProtocolFeesInstructionAccountMetas {
+            whirlpools_config: whirlpools_config.clone(),
+            whirlpool: whirlpool.clone(),
+            collect_protocol_fees_authority: collect_protocol_fees_authority.clone(),
+            token_vault_a: token_vault_a.clone(),
+            token_vault_b: token_vault_b.clone(),
+            token_destination_a: token_destination_a.clone(),
+            token_destination_b: token_destination_b.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CollectProtocolFeesInstructionAccountIndices {
+    pub whirlpools_config: usize,
+    pub whirlpool: usize,
+    pub collect_protocol_fees_authority: usize,
+    pub token_vault_a: usize,
+    pub token_vault_b: usize,
+    pub token_destination_a: usize,
+    pub token_destination_b: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CollectProtocolFees {
+    type ArrangedAccountIndices = CollectProtocolFeesInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(CollectProtocolFeesInstructionAccountIndices {
+            whirlpools_config: 0,
+            whirlpool: 1,
+            collect_protocol_fees_authority: 2,
+            token_vault_a: 3,
+            token_vault_b: 4,
+            token_destination_a: 5,
+            token_destination_b: 6,
+            token_program: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CollectProtocolFees {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "whirlpools_config",
+            "whirlpool",
+            "collect_protocol_fees_authority",
+            "token_vault_a",
+            "token_vault_b",
+            "token_destination_a",
+            "token_destination_b",
+            "token_program",
+        ]
+    }
+}
@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -24,6 +25,7 @@ pub struct CollectProtocolFeesV2InstructionAccounts {
     pub token_program_a: solana_pubkey::Pubkey,
     pub token_program_b: solana_pubkey::Pubkey,
     pub memo_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CollectProtocolFeesV2 {
@@ -32,7 +34,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CollectProtocolFeesV2 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [whirlpools_config, whirlpool, collect_protocol_fees_authority, token_mint_a, token_mint_b, token_vault_a, token_vault_b, token_destination_a, token_destination_b, token_program_a, token_program_b, memo_program, _remaining @ ..] =
+        let [whirlpools_config, whirlpool, collect_protocol_fees_authority, token_mint_a, token_mint_b, token_vault_a, token_vault_b, token_destination_a, token_destination_b, token_program_a, token_program_b, memo_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -51,6 +53,116 @@ impl carbon_core::deserialize::ArrangeAccounts for CollectProtocolFeesV2 {
             token_program_a: token_program_a.pubkey,
             token_program_b: token_program_b.pubkey,
             memo_program: memo_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CollectProtocolFeesV2InstructionAccountMetas {
+    pub whirlpools_config: solana_instruction::AccountMeta,
+    pub whirlpool: solana_instruction::AccountMeta,
+    pub collect_protocol_fees_authority: solana_instruction::AccountMeta,
+    pub token_mint_a: solana_instruction::AccountMeta,
+    pub token_mint_b: solana_instruction::AccountMeta,
+    pub token_vault_a: solana_instruction::AccountMeta,
+    pub token_vault_b: solana_instruction::AccountMeta,
+    pub token_destination_a: solana_instruction::AccountMeta,
+    pub token_destination_b: solana_instruction::AccountMeta,
+    pub token_program_a: solana_instruction::AccountMeta,
+    pub token_program_b: solana_instruction::AccountMeta,
+    pub memo_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CollectProtocolFeesV2 {
+    type ArrangedAccountMetas = CollectProtocolFeesV2InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [whirlpools_config, whirlpool, collect_protocol_fees_authority, token_mint_a, token_mint_b, token_vault_a, token_vault_b, token_destination_a, token_destination_b, token_program_a, token_program_b, memo_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CollectProtocolFeesV2InstructionAccountMetas {
+            whirlpools_config: whirlpools_config.clone(),
+            whirlpool: whirlpool.clone(),
+            collect_protocol_fees_authority: collect_protocol_fees_authority.clone(),
+            token_mint_a: token_mint_a.clone(),
+            token_mint_b: token_mint_b.clone(),
+            token_vault_a: token_vault_a.clone(),
+            token_vault_b: token_vault_b.clone(),
+            token_destination_a: token_destination_a.clone(),
+            token_destination_b: token_destination_b.clone(),
+            token_program_a: token_program_a.clone(),
+            token_program_b: token_program_b.clone(),
+            memo_program: memo_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CollectProtocolFeesV2InstructionAccountIndices {
+    pub whirlpools_config: usize,
+    pub whirlpool: usize,
+    pub collect_protocol_fees_authority: usize,
+    pub token_mint_a: usize,
+    pub token_mint_b: usize,
+    pub token_vault_a: usize,
+    pub token_vault_b: usize,
+    pub token_destination_a: usize,
+    pub token_destination_b: usize,
+    pub token_program_a: usize,
+    pub token_program_b: usize,
+    pub memo_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CollectProtocolFeesV2 {
+    type ArrangedAccountIndices = CollectProtocolFeesV2InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 12 {
+            return None;
+        }
+
+        Some(CollectProtocolFeesV2InstructionAccountIndices {
+            whirlpools_config: 0,
+            whirlpool: 1,
+            collect_protocol_fees_authority: 2,
+            token_mint_a: 3,
+            token_mint_b: 4,
+            token_vault_a: 5,
+            token_vault_b: 6,
+            token_destination_a: 7,
+            token_destination_b: 8,
+            token_program_a: 9,
+            token_program_b: 10,
+            memo_program: 11,
+            remaining_accounts: 12..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CollectProtocolFeesV2 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "whirlpools_config",
+            "whirlpool",
+            "collect_protocol_fees_authority",
+            "token_mint_a",
+            "token_mint_b",
+            "token_vault_a",
+            "token_vault_b",
+            "token_destination_a",
+            "token_destination_b",
+            "token_program_a",
+            "token_program_b",
+            "memo_program",
+        ]
+    }
+}
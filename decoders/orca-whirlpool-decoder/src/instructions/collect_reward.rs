@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -16,6 +17,7 @@ pub struct CollectRewardInstructionAccounts {
     pub reward_owner_account: solana_pubkey::Pubkey,
     pub reward_vault: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CollectReward {
@@ -24,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CollectReward {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [whirlpool, position_authority, position, position_token_account, reward_owner_account, reward_vault, token_program, _remaining @ ..] =
+        let [whirlpool, position_authority, position, position_token_account, reward_owner_account, reward_vault, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -38,6 +40,91 @@ impl carbon_core::deserialize::ArrangeAccounts for CollectReward {
             reward_owner_account: reward_owner_account.pubkey,
             reward_vault: reward_vault.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CollectRewardInstructionAccountMetas {
+    pub whirlpool: solana_instruction::AccountMeta,
+    pub position_authority: solana_instruction::AccountMeta,
+    pub position: solana_instruction::AccountMeta,
+    pub position_token_account: solana_instruction::AccountMeta,
+    pub reward_owner_account: solana_instruction::AccountMeta,
+    pub reward_vault: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CollectReward {
+    type ArrangedAccountMetas = CollectRewardInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [whirlpool, position_authority, position, position_token_account, reward_owner_account, reward_vault, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CollectRewardInstructionAccountMetas {
+            whirlpool: whirlpool.clone(),
+            position_authority: position_authority.clone(),
+            position: position.clone(),
+            position_token_account: position_token_account.clone(),
+            reward_owner_account: reward_owner_account.clone(),
+            reward_vault: reward_vault.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CollectRewardInstructionAccountIndices {
+    pub whirlpool: usize,
+    pub position_authority: usize,
+    pub position: usize,
+    pub position_token_account: usize,
+    pub reward_owner_account: usize,
+    pub reward_vault: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CollectReward {
+    type ArrangedAccountIndices = CollectRewardInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 7 {
+            return None;
+        }
+
+        Some(CollectRewardInstructionAccountIndices {
+            whirlpool: 0,
+            position_authority: 1,
+            position: 2,
+            position_token_account: 3,
+            reward_owner_account: 4,
+            reward_vault: 5,
+            token_program: 6,
+            remaining_accounts: 7..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CollectReward {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "whirlpool",
+            "position_authority",
+            "position",
+            "position_token_account",
+            "reward_owner_account",
+            "reward_vault",
+            "token_program",
+        ]
+    }
+}
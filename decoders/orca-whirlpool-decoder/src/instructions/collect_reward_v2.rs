@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -22,6 +23,7 @@ pub struct CollectRewardV2InstructionAccounts {
     pub reward_vault: solana_pubkey::Pubkey,
     pub reward_token_program: solana_pubkey::Pubkey,
     pub memo_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CollectRewardV2 {
@@ -30,7 +32,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CollectRewardV2 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [whirlpool, position_authority, position, position_token_account, reward_owner_account, reward_mint, reward_vault, reward_token_program, memo_program, _remaining @ ..] =
+        let [whirlpool, position_authority, position, position_token_account, reward_owner_account, reward_mint, reward_vault, reward_token_program, memo_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -46,6 +48,101 @@ impl carbon_core::deserialize::ArrangeAccounts for CollectRewardV2 {
             reward_vault: reward_vault.pubkey,
             reward_token_program: reward_token_program.pubkey,
             memo_program: memo_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CollectRewardV2InstructionAccountMetas {
+    pub whirlpool: solana_instruction::AccountMeta,
+    pub position_authority: solana_instruction::AccountMeta,
+    pub position: solana_instruction::AccountMeta,
+    pub position_token_account: solana_instruction::AccountMeta,
+    pub reward_owner_account: solana_instruction::AccountMeta,
+    pub reward_mint: solana_instruction::AccountMeta,
+    pub reward_vault: solana_instruction::AccountMeta,
+    pub reward_token_program: solana_instruction::AccountMeta,
+    pub memo_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CollectRewardV2 {
+    type ArrangedAccountMetas = CollectRewardV2InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [whirlpool, position_authority, position, position_token_account, reward_owner_account, reward_mint, reward_vault, reward_token_program, memo_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CollectRewardV2InstructionAccountMetas {
+            whirlpool: whirlpool.clone(),
+            position_authority: position_authority.clone(),
+            position: position.clone(),
+            position_token_account: position_token_account.clone(),
+            reward_owner_account: reward_owner_account.clone(),
+            reward_mint: reward_mint.clone(),
+            reward_vault: reward_vault.clone(),
+            reward_token_program: reward_token_program.clone(),
+            memo_program: memo_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CollectRewardV2InstructionAccountIndices {
+    pub whirlpool: usize,
+    pub position_authority: usize,
+    pub position: usize,
+    pub position_token_account: usize,
+    pub reward_owner_account: usize,
+    pub reward_mint: usize,
+    pub reward_vault: usize,
+    pub reward_token_program: usize,
+    pub memo_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CollectRewardV2 {
+    type ArrangedAccountIndices = CollectRewardV2InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 9 {
+            return None;
+        }
+
+        Some(CollectRewardV2InstructionAccountIndices {
+            whirlpool: 0,
+            position_authority: 1,
+            position: 2,
+            position_token_account: 3,
+            reward_owner_account: 4,
+            reward_mint: 5,
+            reward_vault: 6,
+            reward_token_program: 7,
+            memo_program: 8,
+            remaining_accounts: 9..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CollectRewardV2 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "whirlpool",
+            "position_authority",
+            "position",
+            "position_token_account",
+            "reward_owner_account",
+            "reward_mint",
+            "reward_vault",
+            "reward_token_program",
+            "memo_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +14,7 @@ pub struct DeletePositionBundleInstructionAccounts {
     pub position_bundle_owner: solana_pubkey::Pubkey,
     pub receiver: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for DeletePositionBundle {
@@ -21,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for DeletePositionBundle {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [position_bundle, position_bundle_mint, position_bundle_token_account, position_bundle_owner, receiver, token_program, _remaining @ ..] =
+        let [position_bundle, position_bundle_mint, position_bundle_token_account, position_bundle_owner, receiver, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -34,6 +36,86 @@ impl carbon_core::deserialize::ArrangeAccounts for DeletePositionBundle {
             position_bundle_owner: position_bundle_owner.pubkey,
             receiver: receiver.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DeletePositionBundleInstructionAccountMetas {
+    pub position_bundle: solana_instruction::AccountMeta,
+    pub position_bundle_mint: solana_instruction::AccountMeta,
+    pub position_bundle_token_account: solana_instruction::AccountMeta,
+    pub position_bundle_owner: solana_instruction::AccountMeta,
+    pub receiver: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for DeletePositionBundle {
+    type ArrangedAccountMetas = DeletePositionBundleInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [position_bundle, position_bundle_mint, position_bundle_token_account, position_bundle_owner, receiver, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(DeletePositionBundleInstructionAccountMetas {
+            position_bundle: position_bundle.clone(),
+            position_bundle_mint: position_bundle_mint.clone(),
+            position_bundle_token_account: position_bundle_token_account.clone(),
+            position_bundle_owner: position_bundle_owner.clone(),
+            receiver: receiver.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DeletePositionBundleInstructionAccountIndices {
+    pub position_bundle: usize,
+    pub position_bundle_mint: usize,
+    pub position_bundle_token_account: usize,
+    pub position_bundle_owner: usize,
+    pub receiver: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for DeletePositionBundle {
+    type ArrangedAccountIndices = DeletePositionBundleInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(DeletePositionBundleInstructionAccountIndices {
+            position_bundle: 0,
+            position_bundle_mint: 1,
+            position_bundle_token_account: 2,
+            position_bundle_owner: 3,
+            receiver: 4,
+            token_program: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for DeletePositionBundle {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "position_bundle",
+            "position_bundle_mint",
+            "position_bundle_token_account",
+            "position_bundle_owner",
+            "receiver",
+            "token_program",
+        ]
+    }
+}
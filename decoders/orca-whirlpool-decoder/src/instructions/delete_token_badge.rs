@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +14,7 @@ pub struct DeleteTokenBadgeInstructionAccounts {
     pub token_mint: solana_pubkey::Pubkey,
     pub token_badge: solana_pubkey::Pubkey,
     pub receiver: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for DeleteTokenBadge {
@@ -21,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for DeleteTokenBadge {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [whirlpools_config, whirlpools_config_extension, token_badge_authority, token_mint, token_badge, receiver, _remaining @ ..] =
+        let [whirlpools_config, whirlpools_config_extension, token_badge_authority, token_mint, token_badge, receiver, remaining @ ..] =
             accounts
         else {
             return None;
@@ -34,6 +36,86 @@ impl carbon_core::deserialize::ArrangeAccounts for DeleteTokenBadge {
             token_mint: token_mint.pubkey,
             token_badge: token_badge.pubkey,
             receiver: receiver.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DeleteTokenBadgeInstructionAccountMetas {
+    pub whirlpools_config: solana_instruction::AccountMeta,
+    pub whirlpools_config_extension: solana_instruction::AccountMeta,
+    pub token_badge_authority: solana_instruction::AccountMeta,
+    pub token_mint: solana_instruction::AccountMeta,
+    pub token_badge: solana_instruction::AccountMeta,
+    pub receiver: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for DeleteTokenBadge {
+    type ArrangedAccountMetas = DeleteTokenBadgeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [whirlpools_config, whirlpools_config_extension, token_badge_authority, token_mint, token_badge, receiver, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(DeleteTokenBadgeInstructionAccountMetas {
+            whirlpools_config: whirlpools_config.clone(),
+            whirlpools_config_extension: whirlpools_config_extension.clone(),
+            token_badge_authority: token_badge_authority.clone(),
+            token_mint: token_mint.clone(),
+            token_badge: token_badge.clone(),
+            receiver: receiver.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DeleteTokenBadgeInstructionAccountIndices {
+    pub whirlpools_config: usize,
+    pub whirlpools_config_extension: usize,
+    pub token_badge_authority: usize,
+    pub token_mint: usize,
+    pub token_badge: usize,
+    pub receiver: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for DeleteTokenBadge {
+    type ArrangedAccountIndices = DeleteTokenBadgeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(DeleteTokenBadgeInstructionAccountIndices {
+            whirlpools_config: 0,
+            whirlpools_config_extension: 1,
+            token_badge_authority: 2,
+            token_mint: 3,
+            token_badge: 4,
+            receiver: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for DeleteTokenBadge {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "whirlpools_config",
+            "whirlpools_config_extension",
+            "token_badge_authority",
+            "token_mint",
+            "token_badge",
+            "receiver",
+        ]
+    }
+}
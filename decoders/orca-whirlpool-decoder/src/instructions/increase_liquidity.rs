@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -22,6 +23,7 @@ pub struct IncreaseLiquidityInstructionAccounts {
     pub token_vault_b: solana_pubkey::Pubkey,
     pub tick_array_lower: solana_pubkey::Pubkey,
     pub tick_array_upper: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for IncreaseLiquidity {
@@ -30,7 +32,7 @@ impl carbon_core::deserialize::ArrangeAccounts for IncreaseLiquidity {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [whirlpool, token_program, position_authority, position, position_token_account, token_owner_account_a, token_owner_account_b, token_vault_a, token_vault_b, tick_array_lower, tick_array_upper, _remaining @ ..] =
+        let [whirlpool, token_program, position_authority, position, position_token_account, token_owner_account_a, token_owner_account_b, token_vault_a, token_vault_b, tick_array_lower, tick_array_upper, remaining @ ..] =
             accounts
         else {
             return None;
@@ -48,6 +50,111 @@ impl carbon_core::deserialize::ArrangeAccounts for IncreaseLiquidity {
             token_vault_b: token_vault_b.pubkey,
             tick_array_lower: tick_array_lower.pubkey,
             tick_array_upper: tick_array_upper.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct IncreaseLiquidityInstructionAccountMetas {
+    pub whirlpool: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub position_authority: solana_instruction::AccountMeta,
+    pub position: solana_instruction::AccountMeta,
+    pub position_token_account: solana_instruction::AccountMeta,
+    pub token_owner_account_a: solana_instruction::AccountMeta,
+    pub token_owner_account_b: solana_instruction::AccountMeta,
+    pub token_vault_a: solana_instruction::AccountMeta,
+    pub token_vault_b: solana_instruction::AccountMeta,
+    pub tick_array_lower: solana_instruction::AccountMeta,
+    pub tick_array_upper: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for IncreaseLiquidity {
+    type ArrangedAccountMetas = IncreaseLiquidityInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [whirlpool, token_program, position_authority, position, position_token_account, token_owner_account_a, token_owner_account_b, token_vault_a, token_vault_b, tick_array_lower, tick_array_upper, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(IncreaseLiquidityInstructionAccountMetas {
+            whirlpool: whirlpool.clone(),
+            token_program: token_program.clone(),
+            position_authority: position_authority.clone(),
+            position: position.clone(),
+            position_token_account: position_token_account.clone(),
+            token_owner_account_a: token_owner_account_a.clone(),
+            token_owner_account_b: token_owner_account_b.clone(),
+            token_vault_a: token_vault_a.clone(),
+            token_vault_b: token_vault_b.clone(),
+            tick_array_lower: tick_array_lower.clone(),
+            tick_array_upper: tick_array_upper.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct IncreaseLiquidityInstructionAccountIndices {
+    pub whirlpool: usize,
+    pub token_program: usize,
+    pub position_authority: usize,
+    pub position: usize,
+    pub position_token_account: usize,
+    pub token_owner_account_a: usize,
+    pub token_owner_account_b: usize,
+    pub token_vault_a: usize,
+    pub token_vault_b: usize,
+    pub tick_array_lower: usize,
+    pub tick_array_upper: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for IncreaseLiquidity {
+    type ArrangedAccountIndices = IncreaseLiquidityInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 11 {
+            return None;
+        }
+
+        Some(IncreaseLiquidityInstructionAccountIndices {
+            whirlpool: 0,
+            token_program: 1,
+            position_authority: 2,
+            position: 3,
+            position_token_account: 4,
+            token_owner_account_a: 5,
+            token_owner_account_b: 6,
+            token_vault_a: 7,
+            token_vault_b: 8,
+            tick_array_lower: 9,
+            tick_array_upper: 10,
+            remaining_accounts: 11..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for IncreaseLiquidity {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "whirlpool",
+            "token_program",
+            "position_authority",
+            "position",
+            "position_token_account",
+            "token_owner_account_a",
+            "token_owner_account_b",
+            "token_vault_a",
+            "token_vault_b",
+            "tick_array_lower",
+            "tick_array_upper",
+        ]
+    }
+}
@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -30,6 +31,7 @@ pub struct IncreaseLiquidityV2InstructionAccounts {
     pub token_vault_b: solana_pubkey::Pubkey,
     pub tick_array_lower: solana_pubkey::Pubkey,
     pub tick_array_upper: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for IncreaseLiquidityV2 {
@@ -38,7 +40,7 @@ impl carbon_core::deserialize::ArrangeAccounts for IncreaseLiquidityV2 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [whirlpool, token_program_a, token_program_b, memo_program, position_authority, position, position_token_account, token_mint_a, token_mint_b, token_owner_account_a, token_owner_account_b, token_vault_a, token_vault_b, tick_array_lower, tick_array_upper, _remaining @ ..] =
+        let [whirlpool, token_program_a, token_program_b, memo_program, position_authority, position, position_token_account, token_mint_a, token_mint_b, token_owner_account_a, token_owner_account_b, token_vault_a, token_vault_b, tick_array_lower, tick_array_upper, remaining @ ..] =
             accounts
         else {
             return None;
@@ -60,6 +62,131 @@ impl carbon_core::deserialize::ArrangeAccounts for IncreaseLiquidityV2 {
             token_vault_b: token_vault_b.pubkey,
             tick_array_lower: tick_array_lower.pubkey,
             tick_array_upper: tick_array_upper.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct IncreaseLiquidityV2InstructionAccountMetas {
+    pub whirlpool: solana_instruction::AccountMeta,
+    pub token_program_a: solana_instruction::AccountMeta,
+    pub token_program_b: solana_instruction::AccountMeta,
+    pub memo_program: solana_instruction::AccountMeta,
+    pub position_authority: solana_instruction::AccountMeta,
+    pub position: solana_instruction::AccountMeta,
+    pub position_token_account: solana_instruction::AccountMeta,
+    pub token_mint_a: solana_instruction::AccountMeta,
+    pub token_mint_b: solana_instruction::AccountMeta,
+    pub token_owner_account_a: solana_instruction::AccountMeta,
+    pub token_owner_account_b: solana_instruction::AccountMeta,
+    pub token_vault_a: solana_instruction::AccountMeta,
+    pub token_vault_b: solana_instruction::AccountMeta,
+    pub tick_array_lower: solana_instruction::AccountMeta,
+    pub tick_array_upper: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for IncreaseLiquidityV2 {
+    type ArrangedAccountMetas = IncreaseLiquidityV2InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [whirlpool, token_program_a, token_program_b, memo_program, position_authority, position, position_token_account, token_mint_a, token_mint_b, token_owner_account_a, token_owner_account_b, token_vault_a, token_vault_b, tick_array_lower, tick_array_upper, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(IncreaseLiquidityV2InstructionAccountMetas {
+            whirlpool: whirlpool.clone(),
+            token_program_a: token_program_a.clone(),
+            token_program_b: token_program_b.clone(),
+            memo_program: memo_program.clone(),
+            position_authority: position_authority.clone(),
+            position: position.clone(),
+            position_token_account: position_token_account.clone(),
+            token_mint_a: token_mint_a.clone(),
+            token_mint_b: token_mint_b.clone(),
+            token_owner_account_a: token_owner_account_a.clone(),
+            token_owner_account_b: token_owner_account_b.clone(),
+            token_vault_a: token_vault_a.clone(),
+            token_vault_b: token_vault_b.clone(),
+            tick_array_lower: tick_array_lower.clone(),
+            tick_array_upper: tick_array_upper.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct IncreaseLiquidityV2InstructionAccountIndices {
+    pub whirlpool: usize,
+    pub token_program_a: usize,
+    pub token_program_b: usize,
+    pub memo_program: usize,
+    pub position_authority: usize,
+    pub position: usize,
+    pub position_token_account: usize,
+    pub token_mint_a: usize,
+    pub token_mint_b: usize,
+    pub token_owner_account_a: usize,
+    pub token_owner_account_b: usize,
+    pub token_vault_a: usize,
+    pub token_vault_b: usize,
+    pub tick_array_lower: usize,
+    pub tick_array_upper: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for IncreaseLiquidityV2 {
+    type ArrangedAccountIndices = IncreaseLiquidityV2InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 15 {
+            return None;
+        }
+
+        Some(IncreaseLiquidityV2InstructionAccountIndices {
+            whirlpool: 0,
+            token_program_a: 1,
+            token_program_b: 2,
+            memo_program: 3,
+            position_authority: 4,
+            position: 5,
+            position_token_account: 6,
+            token_mint_a: 7,
+            token_mint_b: 8,
+            token_owner_account_a: 9,
+            token_owner_account_b: 10,
+            token_vault_a: 11,
+            token_vault_b: 12,
+            tick_array_lower: 13,
+            tick_array_upper: 14,
+            remaining_accounts: 15..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for IncreaseLiquidityV2 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "whirlpool",
+            "token_program_a",
+            "token_program_b",
+            "memo_program",
+            "position_authority",
+            "position",
+            "position_token_account",
+            "token_mint_a",
+            "token_mint_b",
+            "token_owner_account_a",
+            "token_owner_account_b",
+            "token_vault_a",
+            "token_vault_b",
+            "tick_array_lower",
+            "tick_array_upper",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -15,6 +16,7 @@ pub struct InitializeConfigInstructionAccounts {
     pub config: solana_pubkey::Pubkey,
     pub funder: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeConfig {
@@ -23,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeConfig {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [config, funder, system_program, _remaining @ ..] = accounts else {
+        let [config, funder, system_program, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -31,6 +33,65 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeConfig {
             config: config.pubkey,
             funder: funder.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeConfigInstructionAccountMetas {
+    pub config: solana_instruction::AccountMeta,
+    pub funder: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeConfig {
+    type ArrangedAccountMetas = InitializeConfigInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [config, funder, system_program, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(InitializeConfigInstructionAccountMetas {
+            config: config.clone(),
+            funder: funder.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeConfigInstructionAccountIndices {
+    pub config: usize,
+    pub funder: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeConfig {
+    type ArrangedAccountIndices = InitializeConfigInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(InitializeConfigInstructionAccountIndices {
+            config: 0,
+            funder: 1,
+            system_program: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeConfig {
+    fn account_names() -> &'static [&'static str] {
+        &["config", "funder", "system_program"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +13,7 @@ pub struct InitializeConfigExtensionInstructionAccounts {
     pub funder: solana_pubkey::Pubkey,
     pub fee_authority: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeConfigExtension {
@@ -20,7 +22,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeConfigExtension {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [config, config_extension, funder, fee_authority, system_program, _remaining @ ..] =
+        let [config, config_extension, funder, fee_authority, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -32,6 +34,81 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeConfigExtension {
             funder: funder.pubkey,
             fee_authority: fee_authority.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeConfigExtensionInstructionAccountMetas {
+    pub config: solana_instruction::AccountMeta,
+    pub config_extension: solana_instruction::AccountMeta,
+    pub funder: solana_instruction::AccountMeta,
+    pub fee_authority: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeConfigExtension {
+    type ArrangedAccountMetas = InitializeConfigExtensionInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [config, config_extension, funder, fee_authority, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeConfigExtensionInstructionAccountMetas {
+            config: config.clone(),
+            config_extension: config_extension.clone(),
+            funder: funder.clone(),
+            fee_authority: fee_authority.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeConfigExtensionInstructionAccountIndices {
+    pub config: usize,
+    pub config_extension: usize,
+    pub funder: usize,
+    pub fee_authority: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeConfigExtension {
+    type ArrangedAccountIndices = InitializeConfigExtensionInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(InitializeConfigExtensionInstructionAccountIndices {
+            config: 0,
+            config_extension: 1,
+            funder: 2,
+            fee_authority: 3,
+            system_program: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeConfigExtension {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "config",
+            "config_extension",
+            "funder",
+            "fee_authority",
+            "system_program",
+        ]
+    }
+}
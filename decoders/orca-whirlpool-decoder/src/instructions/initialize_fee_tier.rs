@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -15,6 +16,7 @@ pub struct InitializeFeeTierInstructionAccounts {
     pub funder: solana_pubkey::Pubkey,
     pub fee_authority: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeFeeTier {
@@ -23,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeFeeTier {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [config, fee_tier, funder, fee_authority, system_program, _remaining @ ..] = accounts
+        let [config, fee_tier, funder, fee_authority, system_program, remaining @ ..] = accounts
         else {
             return None;
         };
@@ -34,6 +36,80 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeFeeTier {
             funder: funder.pubkey,
             fee_authority: fee_authority.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeFeeTierInstructionAccountMetas {
+    pub config: solana_instruction::AccountMeta,
+    pub fee_tier: solana_instruction::AccountMeta,
+    pub funder: solana_instruction::AccountMeta,
+    pub fee_authority: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeFeeTier {
+    type ArrangedAccountMetas = InitializeFeeTierInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [config, fee_tier, funder, fee_authority, system_program, remaining @ ..] = accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeFeeTierInstructionAccountMetas {
+            config: config.clone(),
+            fee_tier: fee_tier.clone(),
+            funder: funder.clone(),
+            fee_authority: fee_authority.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeFeeTierInstructionAccountIndices {
+    pub config: usize,
+    pub fee_tier: usize,
+    pub funder: usize,
+    pub fee_authority: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeFeeTier {
+    type ArrangedAccountIndices = InitializeFeeTierInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(InitializeFeeTierInstructionAccountIndices {
+            config: 0,
+            fee_tier: 1,
+            funder: 2,
+            fee_authority: 3,
+            system_program: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeFeeTier {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "config",
+            "fee_tier",
+            "funder",
+            "fee_authority",
+            "system_program",
+        ]
+    }
+}
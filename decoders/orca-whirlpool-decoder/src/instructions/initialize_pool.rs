@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -25,6 +26,7 @@ pub struct InitializePoolInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializePool {
@@ -33,7 +35,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializePool {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [whirlpools_config, token_mint_a, token_mint_b, funder, whirlpool, token_vault_a, token_vault_b, fee_tier, token_program, system_program, rent, _remaining @ ..] =
+        let [whirlpools_config, token_mint_a, token_mint_b, funder, whirlpool, token_vault_a, token_vault_b, fee_tier, token_program, system_program, rent, remaining @ ..] =
             accounts
         else {
             return None;
@@ -51,6 +53,111 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializePool {
             token_program: token_program.pubkey,
             system_program: system_program.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializePoolInstructionAccountMetas {
+    pub whirlpools_config: solana_instruction::AccountMeta,
+    pub token_mint_a: solana_instruction::AccountMeta,
+    pub token_mint_b: solana_instruction::AccountMeta,
+    pub funder: solana_instruction::AccountMeta,
+    pub whirlpool: solana_instruction::AccountMeta,
+    pub token_vault_a: solana_instruction::AccountMeta,
+    pub token_vault_b: solana_instruction::AccountMeta,
+    pub fee_tier: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializePool {
+    type ArrangedAccountMetas = InitializePoolInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [whirlpools_config, token_mint_a, token_mint_b, funder, whirlpool, token_vault_a, token_vault_b, fee_tier, token_program, system_program, rent, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializePoolInstructionAccountMetas {
+            whirlpools_config: whirlpools_config.clone(),
+            token_mint_a: token_mint_a.clone(),
+            token_mint_b: token_mint_b.clone(),
+            funder: funder.clone(),
+            whirlpool: whirlpool.clone(),
+            token_vault_a: token_vault_a.clone(),
+            token_vault_b: token_vault_b.clone(),
+            fee_tier: fee_tier.clone(),
+            token_program: token_program.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializePoolInstructionAccountIndices {
+    pub whirlpools_config: usize,
+    pub token_mint_a: usize,
+    pub token_mint_b: usize,
+    pub funder: usize,
+    pub whirlpool: usize,
+    pub token_vault_a: usize,
+    pub token_vault_b: usize,
+    pub fee_tier: usize,
+    pub token_program: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializePool {
+    type ArrangedAccountIndices = InitializePoolInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 11 {
+            return None;
+        }
+
+        Some(InitializePoolInstructionAccountIndices {
+            whirlpools_config: 0,
+            token_mint_a: 1,
+            token_mint_b: 2,
+            funder: 3,
+            whirlpool: 4,
+            token_vault_a: 5,
+            token_vault_b: 6,
+            fee_tier: 7,
+            token_program: 8,
+            system_program: 9,
+            rent: 10,
+            remaining_accounts: 11..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializePool {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "whirlpools_config",
+            "token_mint_a",
+            "token_mint_b",
+            "funder",
+            "whirlpool",
+            "token_vault_a",
+            "token_vault_b",
+            "fee_tier",
+            "token_program",
+            "system_program",
+            "rent",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -24,6 +25,7 @@ pub struct InitializePoolV2InstructionAccounts {
     pub token_program_b: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializePoolV2 {
@@ -32,7 +34,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializePoolV2 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [whirlpools_config, token_mint_a, token_mint_b, token_badge_a, token_badge_b, funder, whirlpool, token_vault_a, token_vault_b, fee_tier, token_program_a, token_program_b, system_program, rent, _remaining @ ..] =
+        let [whirlpools_config, token_mint_a, token_mint_b, token_badge_a, token_badge_b, funder, whirlpool, token_vault_a, token_vault_b, fee_tier, token_program_a, token_program_b, system_program, rent, remaining @ ..] =
             accounts
         else {
             return None;
@@ -53,6 +55,126 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializePoolV2 {
             token_program_b: token_program_b.pubkey,
             system_program: system_program.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializePoolV2InstructionAccountMetas {
+    pub whirlpools_config: solana_instruction::AccountMeta,
+    pub token_mint_a: solana_instruction::AccountMeta,
+    pub token_mint_b: solana_instruction::AccountMeta,
+    pub token_badge_a: solana_instruction::AccountMeta,
+    pub token_badge_b: solana_instruction::AccountMeta,
+    pub funder: solana_instruction::AccountMeta,
+    pub whirlpool: solana_instruction::AccountMeta,
+    pub token_vault_a: solana_instruction::AccountMeta,
+    pub token_vault_b: solana_instruction::AccountMeta,
+    pub fee_tier: solana_instruction::AccountMeta,
+    pub token_program_a: solana_instruction::AccountMeta,
+    pub token_program_b: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializePoolV2 {
+    type ArrangedAccountMetas = InitializePoolV2InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [whirlpools_config, token_mint_a, token_mint_b, token_badge_a, token_badge_b, funder, whirlpool, token_vault_a, token_vault_b, fee_tier, token_program_a, token_program_b, system_program, rent, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializePoolV2InstructionAccountMetas {
+            whirlpools_config: whirlpools_config.clone(),
+            token_mint_a: token_mint_a.clone(),
+            token_mint_b: token_mint_b.clone(),
+            token_badge_a: token_badge_a.clone(),
+            token_badge_b: token_badge_b.clone(),
+            funder: funder.clone(),
+            whirlpool: whirlpool.clone(),
+            token_vault_a: token_vault_a.clone(),
+            token_vault_b: token_vault_b.clone(),
+            fee_tier: fee_tier.clone(),
+            token_program_a: token_program_a.clone(),
+            token_program_b: token_program_b.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializePoolV2InstructionAccountIndices {
+    pub whirlpools_config: usize,
+    pub token_mint_a: usize,
+    pub token_mint_b: usize,
+    pub token_badge_a: usize,
+    pub token_badge_b: usize,
+    pub funder: usize,
+    pub whirlpool: usize,
+    pub token_vault_a: usize,
+    pub token_vault_b: usize,
+    pub fee_tier: usize,
+    pub token_program_a: usize,
+    pub token_program_b: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializePoolV2 {
+    type ArrangedAccountIndices = InitializePoolV2InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 14 {
+            return None;
+        }
+
+        Some(InitializePoolV2InstructionAccountIndices {
+            whirlpools_config: 0,
+            token_mint_a: 1,
+            token_mint_b: 2,
+            token_badge_a: 3,
+            token_badge_b: 4,
+            funder: 5,
+            whirlpool: 6,
+            token_vault_a: 7,
+            token_vault_b: 8,
+            fee_tier: 9,
+            token_program_a: 10,
+            token_program_b: 11,
+            system_program: 12,
+            rent: 13,
+            remaining_accounts: 14..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializePoolV2 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "whirlpools_config",
+            "token_mint_a",
+            "token_mint_b",
+            "token_badge_a",
+            "token_badge_b",
+            "funder",
+            "whirlpool",
+            "token_vault_a",
+            "token_vault_b",
+            "fee_tier",
+            "token_program_a",
+            "token_program_b",
+            "system_program",
+            "rent",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -16,6 +17,7 @@ pub struct InitializePositionBundleInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub associated_token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializePositionBundle {
@@ -24,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializePositionBundle {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [position_bundle, position_bundle_mint, position_bundle_token_account, position_bundle_owner, funder, token_program, system_program, rent, associated_token_program, _remaining @ ..] =
+        let [position_bundle, position_bundle_mint, position_bundle_token_account, position_bundle_owner, funder, token_program, system_program, rent, associated_token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -40,6 +42,101 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializePositionBundle {
             system_program: system_program.pubkey,
             rent: rent.pubkey,
             associated_token_program: associated_token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializePositionBundleInstructionAccountMetas {
+    pub position_bundle: solana_instruction::AccountMeta,
+    pub position_bundle_mint: solana_instruction::AccountMeta,
+    pub position_bundle_token_account: solana_instruction::AccountMeta,
+    pub position_bundle_owner: solana_instruction::AccountMeta,
+    pub funder: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializePositionBundle {
+    type ArrangedAccountMetas = InitializePositionBundleInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [position_bundle, position_bundle_mint, position_bundle_token_account, position_bundle_owner, funder, token_program, system_program, rent, associated_token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializePositionBundleInstructionAccountMetas {
+            position_bundle: position_bundle.clone(),
+            position_bundle_mint: position_bundle_mint.clone(),
+            position_bundle_token_account: position_bundle_token_account.clone(),
+            position_bundle_owner: position_bundle_owner.clone(),
+            funder: funder.clone(),
+            token_program: token_program.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            associated_token_program: associated_token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializePositionBundleInstructionAccountIndices {
+    pub position_bundle: usize,
+    pub position_bundle_mint: usize,
+    pub position_bundle_token_account: usize,
+    pub position_bundle_owner: usize,
+    pub funder: usize,
+    pub token_program: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub associated_token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializePositionBundle {
+    type ArrangedAccountIndices = InitializePositionBundleInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 9 {
+            return None;
+        }
+
+        Some(InitializePositionBundleInstructionAccountIndices {
+            position_bundle: 0,
+            position_bundle_mint: 1,
+            position_bundle_token_account: 2,
+            position_bundle_owner: 3,
+            funder: 4,
+            token_program: 5,
+            system_program: 6,
+            rent: 7,
+            associated_token_program: 8,
+            remaining_accounts: 9..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializePositionBundle {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "position_bundle",
+            "position_bundle_mint",
+            "position_bundle_token_account",
+            "position_bundle_owner",
+            "funder",
+            "token_program",
+            "system_program",
+            "rent",
+            "associated_token_program",
+        ]
+    }
+}
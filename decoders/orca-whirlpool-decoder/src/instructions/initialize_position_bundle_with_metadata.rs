@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -19,6 +20,7 @@ pub struct InitializePositionBundleWithMetadataInstructionAccounts {
     pub rent: solana_pubkey::Pubkey,
     pub associated_token_program: solana_pubkey::Pubkey,
     pub metadata_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializePositionBundleWithMetadata {
@@ -27,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializePositionBundleWithM
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [position_bundle, position_bundle_mint, position_bundle_metadata, position_bundle_token_account, position_bundle_owner, funder, metadata_update_auth, token_program, system_program, rent, associated_token_program, metadata_program, _remaining @ ..] =
+        let [position_bundle, position_bundle_mint, position_bundle_metadata, position_bundle_token_account, position_bundle_owner, funder, metadata_update_auth, token_program, system_program, rent, associated_token_program, metadata_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -46,6 +48,120 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializePositionBundleWithM
             rent: rent.pubkey,
             associated_token_program: associated_token_program.pubkey,
             metadata_program: metadata_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializePositionBundleWithMetadataInstructionAccountMetas {
+    pub position_bundle: solana_instruction::AccountMeta,
+    pub position_bundle_mint: solana_instruction::AccountMeta,
+    pub position_bundle_metadata: solana_instruction::AccountMeta,
+    pub position_bundle_token_account: solana_instruction::AccountMeta,
+    pub position_bundle_owner: solana_instruction::AccountMeta,
+    pub funder: solana_instruction::AccountMeta,
+    pub metadata_update_auth: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub metadata_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializePositionBundleWithMetadata {
+    type ArrangedAccountMetas = InitializePositionBundleWithMetadataInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [position_bundle, position_bundle_mint, position_bundle_metadata, position_bundle_token_account, position_bundle_owner, funder, metadata_update_auth, token_program, system_program, rent, associated_token_program, metadata_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(
+            InitializePositionBundleWithMetadataInstructionAccountMetas {
+                position_bundle: position_bundle.clone(),
+                position_bundle_mint: position_bundle_mint.clone(),
+                position_bundle_metadata: position_bundle_metadata.clone(),
+                position_bundle_token_account: position_bundle_token_account.clone(),
+                position_bundle_owner: position_bundle_owner.clone(),
+                funder: funder.clone(),
+                metadata_update_auth: metadata_update_auth.clone(),
+                token_program: token_program.clone(),
+                system_program: system_program.clone(),
+                rent: rent.clone(),
+                associated_token_program: associated_token_program.clone(),
+                metadata_program: metadata_program.clone(),
+                remaining_accounts: remaining.to_vec(),
+            },
+        )
+    }
+}
+
+pub struct InitializePositionBundleWithMetadataInstructionAccountIndices {
+    pub position_bundle: usize,
+    pub position_bundle_mint: usize,
+    pub position_bundle_metadata: usize,
+    pub position_bundle_token_account: usize,
+    pub position_bundle_owner: usize,
+    pub funder: usize,
+    pub metadata_update_auth: usize,
+    pub token_program: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub associated_token_program: usize,
+    pub metadata_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializePositionBundleWithMetadata {
+    type ArrangedAccountIndices = InitializePositionBundleWithMetadataInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 12 {
+            return None;
+        }
+
+        Some(
+            InitializePositionBundleWithMetadataInstructionAccountIndices {
+                position_bundle: 0,
+                position_bundle_mint: 1,
+                position_bundle_metadata: 2,
+                position_bundle_token_account: 3,
+                position_bundle_owner: 4,
+                funder: 5,
+                metadata_update_auth: 6,
+                token_program: 7,
+                system_program: 8,
+                rent: 9,
+                associated_token_program: 10,
+                metadata_program: 11,
+                remaining_accounts: 12..accounts.len(),
+            },
+        )
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializePositionBundleWithMetadata {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "position_bundle",
+            "position_bundle_mint",
+            "position_bundle_metadata",
+            "position_bundle_token_account",
+            "position_bundle_owner",
+            "funder",
+            "metadata_update_auth",
+            "token_program",
+            "system_program",
+            "rent",
+            "associated_token_program",
+            "metadata_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -17,6 +18,7 @@ pub struct InitializeRewardInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeReward {
@@ -25,7 +27,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeReward {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [reward_authority, funder, whirlpool, reward_mint, reward_vault, token_program, system_program, rent, _remaining @ ..] =
+        let [reward_authority, funder, whirlpool, reward_mint, reward_vault, token_program, system_program, rent, remaining @ ..] =
             accounts
         else {
             return None;
@@ -40,6 +42,96 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeReward {
             token_program: token_program.pubkey,
             system_program: system_program.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeRewardInstructionAccountMetas {
+    pub reward_authority: solana_instruction::AccountMeta,
+    pub funder: solana_instruction::AccountMeta,
+    pub whirlpool: solana_instruction::AccountMeta,
+    pub reward_mint: solana_instruction::AccountMeta,
+    pub reward_vault: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeReward {
+    type ArrangedAccountMetas = InitializeRewardInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [reward_authority, funder, whirlpool, reward_mint, reward_vault, token_program, system_program, rent, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeRewardInstructionAccountMetas {
+            reward_authority: reward_authority.clone(),
+            funder: funder.clone(),
+            whirlpool: whirlpool.clone(),
+            reward_mint: reward_mint.clone(),
+            reward_vault: reward_vault.clone(),
+            token_program: token_program.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeRewardInstructionAccountIndices {
+    pub reward_authority: usize,
+    pub funder: usize,
+    pub whirlpool: usize,
+    pub reward_mint: usize,
+    pub reward_vault: usize,
+    pub token_program: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeReward {
+    type ArrangedAccountIndices = InitializeRewardInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(InitializeRewardInstructionAccountIndices {
+            reward_authority: 0,
+            funder: 1,
+            whirlpool: 2,
+            reward_mint: 3,
+            reward_vault: 4,
+            token_program: 5,
+            system_program: 6,
+            rent: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeReward {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "reward_authority",
+            "funder",
+            "whirlpool",
+            "reward_mint",
+            "reward_vault",
+            "token_program",
+            "system_program",
+            "rent",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -18,6 +19,7 @@ pub struct InitializeRewardV2InstructionAccounts {
     pub reward_token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeRewardV2 {
@@ -26,7 +28,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeRewardV2 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [reward_authority, funder, whirlpool, reward_mint, reward_token_badge, reward_vault, reward_token_program, system_program, rent, _remaining @ ..] =
+        let [reward_authority, funder, whirlpool, reward_mint, reward_token_badge, reward_vault, reward_token_program, system_program, rent, remaining @ ..] =
             accounts
         else {
             return None;
@@ -42,6 +44,101 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeRewardV2 {
             reward_token_program: reward_token_program.pubkey,
             system_program: system_program.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeRewardV2InstructionAccountMetas {
+    pub reward_authority: solana_instruction::AccountMeta,
+    pub funder: solana_instruction::AccountMeta,
+    pub whirlpool: solana_instruction::AccountMeta,
+    pub reward_mint: solana_instruction::AccountMeta,
+    pub reward_token_badge: solana_instruction::AccountMeta,
+    pub reward_vault: solana_instruction::AccountMeta,
+    pub reward_token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeRewardV2 {
+    type ArrangedAccountMetas = InitializeRewardV2InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [reward_authority, funder, whirlpool, reward_mint, reward_token_badge, reward_vault, reward_token_program, system_program, rent, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeRewardV2InstructionAccountMetas {
+            reward_authority: reward_authority.clone(),
+            funder: funder.clone(),
+            whirlpool: whirlpool.clone(),
+            reward_mint: reward_mint.clone(),
+            reward_token_badge: reward_token_badge.clone(),
+            reward_vault: reward_vault.clone(),
+            reward_token_program: reward_token_program.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeRewardV2InstructionAccountIndices {
+    pub reward_authority: usize,
+    pub funder: usize,
+    pub whirlpool: usize,
+    pub reward_mint: usize,
+    pub reward_token_badge: usize,
+    pub reward_vault: usize,
+    pub reward_token_program: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeRewardV2 {
+    type ArrangedAccountIndices = InitializeRewardV2InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 9 {
+            return None;
+        }
+
+        Some(InitializeRewardV2InstructionAccountIndices {
+            reward_authority: 0,
+            funder: 1,
+            whirlpool: 2,
+            reward_mint: 3,
+            reward_token_badge: 4,
+            reward_vault: 5,
+            reward_token_program: 6,
+            system_program: 7,
+            rent: 8,
+            remaining_accounts: 9..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeRewardV2 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "reward_authority",
+            "funder",
+            "whirlpool",
+            "reward_mint",
+            "reward_token_badge",
+            "reward_vault",
+            "reward_token_program",
+            "system_program",
+            "rent",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +14,7 @@ pub struct InitializeTickArrayInstructionAccounts {
     pub funder: solana_pubkey::Pubkey,
     pub tick_array: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeTickArray {
@@ -21,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeTickArray {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [whirlpool, funder, tick_array, system_program, _remaining @ ..] = accounts else {
+        let [whirlpool, funder, tick_array, system_program, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -30,6 +32,69 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeTickArray {
             funder: funder.pubkey,
             tick_array: tick_array.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeTickArrayInstructionAccountMetas {
+    pub whirlpool: solana_instruction::AccountMeta,
+    pub funder: solana_instruction::AccountMeta,
+    pub tick_array: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeTickArray {
+    type ArrangedAccountMetas = InitializeTickArrayInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [whirlpool, funder, tick_array, system_program, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(InitializeTickArrayInstructionAccountMetas {
+            whirlpool: whirlpool.clone(),
+            funder: funder.clone(),
+            tick_array: tick_array.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeTickArrayInstructionAccountIndices {
+    pub whirlpool: usize,
+    pub funder: usize,
+    pub tick_array: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeTickArray {
+    type ArrangedAccountIndices = InitializeTickArrayInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(InitializeTickArrayInstructionAccountIndices {
+            whirlpool: 0,
+            funder: 1,
+            tick_array: 2,
+            system_program: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeTickArray {
+    fn account_names() -> &'static [&'static str] {
+        &["whirlpool", "funder", "tick_array", "system_program"]
+    }
+}
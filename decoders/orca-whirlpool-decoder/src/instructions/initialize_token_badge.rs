@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct InitializeTokenBadgeInstructionAccounts {
     pub token_badge: solana_pubkey::Pubkey,
     pub funder: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeTokenBadge {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeTokenBadge {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [whirlpools_config, whirlpools_config_extension, token_badge_authority, token_mint, token_badge, funder, system_program, _remaining @ ..] =
+        let [whirlpools_config, whirlpools_config_extension, token_badge_authority, token_mint, token_badge, funder, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -36,6 +38,91 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeTokenBadge {
             token_badge: token_badge.pubkey,
             funder: funder.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeTokenBadgeInstructionAccountMetas {
+    pub whirlpools_config: solana_instruction::AccountMeta,
+    pub whirlpools_config_extension: solana_instruction::AccountMeta,
+    pub token_badge_authority: solana_instruction::AccountMeta,
+    pub token_mint: solana_instruction::AccountMeta,
+    pub token_badge: solana_instruction::AccountMeta,
+    pub funder: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeTokenBadge {
+    type ArrangedAccountMetas = InitializeTokenBadgeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [whirlpools_config, whirlpools_config_extension, token_badge_authority, token_mint, token_badge, funder, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeTokenBadgeInstructionAccountMetas {
+            whirlpools_config: whirlpools_config.clone(),
+            whirlpools_config_extension: whirlpools_config_extension.clone(),
+            token_badge_authority: token_badge_authority.clone(),
+            token_mint: token_mint.clone(),
+            token_badge: token_badge.clone(),
+            funder: funder.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeTokenBadgeInstructionAccountIndices {
+    pub whirlpools_config: usize,
+    pub whirlpools_config_extension: usize,
+    pub token_badge_authority: usize,
+    pub token_mint: usize,
+    pub token_badge: usize,
+    pub funder: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeTokenBadge {
+    type ArrangedAccountIndices = InitializeTokenBadgeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 7 {
+            return None;
+        }
+
+        Some(InitializeTokenBadgeInstructionAccountIndices {
+            whirlpools_config: 0,
+            whirlpools_config_extension: 1,
+            token_badge_authority: 2,
+            token_mint: 3,
+            token_badge: 4,
+            funder: 5,
+            system_program: 6,
+            remaining_accounts: 7..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeTokenBadge {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "whirlpools_config",
+            "whirlpools_config_extension",
+            "token_badge_authority",
+            "token_mint",
+            "token_badge",
+            "funder",
+            "system_program",
+        ]
+    }
+}
@@ -118,15 +118,25 @@ pub enum OrcaWhirlpoolInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for OrcaWhirlpoolDecoder {
     type InstructionType = OrcaWhirlpoolInstruction;
 
-    fn decode_instruction(
+    fn decoder_name(&self) -> &'static str {
+        "orca_whirlpool"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[super::PROGRAM_ID]
+    }
+
+    fn try_decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
-    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+    ) -> carbon_core::error::CarbonResult<
+        Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>>,
+    > {
         if !instruction.program_id.eq(&PROGRAM_ID) {
-            return None;
+            return Ok(None);
         }
 
-        carbon_core::try_decode_instructions!(instruction,
+        Ok(carbon_core::try_decode_instructions!(instruction,
             OrcaWhirlpoolInstruction::InitializeConfig => initialize_config::InitializeConfig,
             OrcaWhirlpoolInstruction::InitializePool => initialize_pool::InitializePool,
             OrcaWhirlpoolInstruction::InitializeTickArray => initialize_tick_array::InitializeTickArray,
@@ -173,6 +183,6 @@ impl carbon_core::instruction::InstructionDecoder<'_> for OrcaWhirlpoolDecoder {
             OrcaWhirlpoolInstruction::SetTokenBadgeAuthority => set_token_badge_authority::SetTokenBadgeAuthority,
             OrcaWhirlpoolInstruction::InitializeTokenBadge => initialize_token_badge::InitializeTokenBadge,
             OrcaWhirlpoolInstruction::DeleteTokenBadge => delete_token_badge::DeleteTokenBadge,
-        )
+        ))
     }
 }
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -19,6 +20,7 @@ pub struct OpenBundledPositionInstructionAccounts {
     pub funder: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for OpenBundledPosition {
@@ -27,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for OpenBundledPosition {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [bundled_position, position_bundle, position_bundle_token_account, position_bundle_authority, whirlpool, funder, system_program, rent, _remaining @ ..] =
+        let [bundled_position, position_bundle, position_bundle_token_account, position_bundle_authority, whirlpool, funder, system_program, rent, remaining @ ..] =
             accounts
         else {
             return None;
@@ -42,6 +44,96 @@ impl carbon_core::deserialize::ArrangeAccounts for OpenBundledPosition {
             funder: funder.pubkey,
             system_program: system_program.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct OpenBundledPositionInstructionAccountMetas {
+    pub bundled_position: solana_instruction::AccountMeta,
+    pub position_bundle: solana_instruction::AccountMeta,
+    pub position_bundle_token_account: solana_instruction::AccountMeta,
+    pub position_bundle_authority: solana_instruction::AccountMeta,
+    pub whirlpool: solana_instruction::AccountMeta,
+    pub funder: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for OpenBundledPosition {
+    type ArrangedAccountMetas = OpenBundledPositionInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [bundled_position, position_bundle, position_bundle_token_account, position_bundle_authority, whirlpool, funder, system_program, rent, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(OpenBundledPositionInstructionAccountMetas {
+            bundled_position: bundled_position.clone(),
+            position_bundle: position_bundle.clone(),
+            position_bundle_token_account: position_bundle_token_account.clone(),
+            position_bundle_authority: position_bundle_authority.clone(),
+            whirlpool: whirlpool.clone(),
+            funder: funder.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct OpenBundledPositionInstructionAccountIndices {
+    pub bundled_position: usize,
+    pub position_bundle: usize,
+    pub position_bundle_token_account: usize,
+    pub position_bundle_authority: usize,
+    pub whirlpool: usize,
+    pub funder: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for OpenBundledPosition {
+    type ArrangedAccountIndices = OpenBundledPositionInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(OpenBundledPositionInstructionAccountIndices {
+            bundled_position: 0,
+            position_bundle: 1,
+            position_bundle_token_account: 2,
+            position_bundle_authority: 3,
+            whirlpool: 4,
+            funder: 5,
+            system_program: 6,
+            rent: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for OpenBundledPosition {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "bundled_position",
+            "position_bundle",
+            "position_bundle_token_account",
+            "position_bundle_authority",
+            "whirlpool",
+            "funder",
+            "system_program",
+            "rent",
+        ]
+    }
+}
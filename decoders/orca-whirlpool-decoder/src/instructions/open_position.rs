@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -24,6 +25,7 @@ pub struct OpenPositionInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub associated_token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for OpenPosition {
@@ -32,7 +34,7 @@ impl carbon_core::deserialize::ArrangeAccounts for OpenPosition {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [funder, owner, position, position_mint, position_token_account, whirlpool, token_program, system_program, rent, associated_token_program, _remaining @ ..] =
+        let [funder, owner, position, position_mint, position_token_account, whirlpool, token_program, system_program, rent, associated_token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -49,6 +51,106 @@ impl carbon_core::deserialize::ArrangeAccounts for OpenPosition {
             system_program: system_program.pubkey,
             rent: rent.pubkey,
             associated_token_program: associated_token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct OpenPositionInstructionAccountMetas {
+    pub funder: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub position: solana_instruction::AccountMeta,
+    pub position_mint: solana_instruction::AccountMeta,
+    pub position_token_account: solana_instruction::AccountMeta,
+    pub whirlpool: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for OpenPosition {
+    type ArrangedAccountMetas = OpenPositionInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [funder, owner, position, position_mint, position_token_account, whirlpool, token_program, system_program, rent, associated_token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(OpenPositionInstructionAccountMetas {
+            funder: funder.clone(),
+            owner: owner.clone(),
+            position: position.clone(),
+            position_mint: position_mint.clone(),
+            position_token_account: position_token_account.clone(),
+            whirlpool: whirlpool.clone(),
+            token_program: token_program.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            associated_token_program: associated_token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct OpenPositionInstructionAccountIndices {
+    pub funder: usize,
+    pub owner: usize,
+    pub position: usize,
+    pub position_mint: usize,
+    pub position_token_account: usize,
+    pub whirlpool: usize,
+    pub token_program: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub associated_token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for OpenPosition {
+    type ArrangedAccountIndices = OpenPositionInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 10 {
+            return None;
+        }
+
+        Some(OpenPositionInstructionAccountIndices {
+            funder: 0,
+            owner: 1,
+            position: 2,
+            position_mint: 3,
+            position_token_account: 4,
+            whirlpool: 5,
+            token_program: 6,
+            system_program: 7,
+            rent: 8,
+            associated_token_program: 9,
+            remaining_accounts: 10..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for OpenPosition {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "funder",
+            "owner",
+            "position",
+            "position_mint",
+            "position_token_account",
+            "whirlpool",
+            "token_program",
+            "system_program",
+            "rent",
+            "associated_token_program",
+        ]
+    }
+}
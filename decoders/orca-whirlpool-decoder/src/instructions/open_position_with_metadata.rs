@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -27,6 +28,7 @@ pub struct OpenPositionWithMetadataInstructionAccounts {
     pub associated_token_program: solana_pubkey::Pubkey,
     pub metadata_program: solana_pubkey::Pubkey,
     pub metadata_update_auth: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for OpenPositionWithMetadata {
@@ -35,7 +37,7 @@ impl carbon_core::deserialize::ArrangeAccounts for OpenPositionWithMetadata {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [funder, owner, position, position_mint, position_metadata_account, position_token_account, whirlpool, token_program, system_program, rent, associated_token_program, metadata_program, metadata_update_auth, _remaining @ ..] =
+        let [funder, owner, position, position_mint, position_metadata_account, position_token_account, whirlpool, token_program, system_program, rent, associated_token_program, metadata_program, metadata_update_auth, remaining @ ..] =
             accounts
         else {
             return None;
@@ -55,6 +57,121 @@ impl carbon_core::deserialize::ArrangeAccounts for OpenPositionWithMetadata {
             associated_token_program: associated_token_program.pubkey,
             metadata_program: metadata_program.pubkey,
             metadata_update_auth: metadata_update_auth.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct OpenPositionWithMetadataInstructionAccountMetas {
+    pub funder: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub position: solana_instruction::AccountMeta,
+    pub position_mint: solana_instruction::AccountMeta,
+    pub position_metadata_account: solana_instruction::AccountMeta,
+    pub position_token_account: solana_instruction::AccountMeta,
+    pub whirlpool: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub metadata_program: solana_instruction::AccountMeta,
+    pub metadata_update_auth: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for OpenPositionWithMetadata {
+    type ArrangedAccountMetas = OpenPositionWithMetadataInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [funder, owner, position, position_mint, position_metadata_account, position_token_account, whirlpool, token_program, system_program, rent, associated_token_program, metadata_program, metadata_update_auth, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(OpenPositionWithMetadataInstructionAccountMetas {
+            funder: funder.clone(),
+            owner: owner.clone(),
+            position: position.clone(),
+            position_mint: position_mint.clone(),
+            position_metadata_account: position_metadata_account.clone(),
+            position_token_account: position_token_account.clone(),
+            whirlpool: whirlpool.clone(),
+            token_program: token_program.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            associated_token_program: associated_token_program.clone(),
+            metadata_program: metadata_program.clone(),
+            metadata_update_auth: metadata_update_auth.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct OpenPositionWithMetadataInstructionAccountIndices {
+    pub funder: usize,
+    pub owner: usize,
+    pub position: usize,
+    pub position_mint: usize,
+    pub position_metadata_account: usize,
+    pub position_token_account: usize,
+    pub whirlpool: usize,
+    pub token_program: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub associated_token_program: usize,
+    pub metadata_program: usize,
+    pub metadata_update_auth: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for OpenPositionWithMetadata {
+    type ArrangedAccountIndices = OpenPositionWithMetadataInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 13 {
+            return None;
+        }
+
+        Some(OpenPositionWithMetadataInstructionAccountIndices {
+            funder: 0,
+            owner: 1,
+            position: 2,
+            position_mint: 3,
+            position_metadata_account: 4,
+            position_token_account: 5,
+            whirlpool: 6,
+            token_program: 7,
+            system_program: 8,
+            rent: 9,
+            associated_token_program: 10,
+            metadata_program: 11,
+            metadata_update_auth: 12,
+            remaining_accounts: 13..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for OpenPositionWithMetadata {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "funder",
+            "owner",
+            "position",
+            "position_mint",
+            "position_metadata_account",
+            "position_token_account",
+            "whirlpool",
+            "token_program",
+            "system_program",
+            "rent",
+            "associated_token_program",
+            "metadata_program",
+            "metadata_update_auth",
+        ]
+    }
+}
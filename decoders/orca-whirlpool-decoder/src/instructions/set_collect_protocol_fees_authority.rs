@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -10,6 +11,7 @@ pub struct SetCollectProtocolFeesAuthorityInstructionAccounts {
     pub whirlpools_config: solana_pubkey::Pubkey,
     pub collect_protocol_fees_authority: solana_pubkey::Pubkey,
     pub new_collect_protocol_fees_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SetCollectProtocolFeesAuthority {
@@ -18,7 +20,7 @@ impl carbon_core::deserialize::ArrangeAccounts for SetCollectProtocolFeesAuthori
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [whirlpools_config, collect_protocol_fees_authority, new_collect_protocol_fees_authority, _remaining @ ..] =
+        let [whirlpools_config, collect_protocol_fees_authority, new_collect_protocol_fees_authority, remaining @ ..] =
             accounts
         else {
             return None;
@@ -28,6 +30,71 @@ impl carbon_core::deserialize::ArrangeAccounts for SetCollectProtocolFeesAuthori
             whirlpools_config: whirlpools_config.pubkey,
             collect_protocol_fees_authority: collect_protocol_fees_authority.pubkey,
             new_collect_protocol_fees_authority: new_collect_protocol_fees_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SetCollectProtocolFeesAuthorityInstructionAccountMetas {
+    pub whirlpools_config: solana_instruction::AccountMeta,
+    pub collect_protocol_fees_authority: solana_instruction::AccountMeta,
+    pub new_collect_protocol_fees_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SetCollectProtocolFeesAuthority {
+    type ArrangedAccountMetas = SetCollectProtocolFeesAuthorityInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [whirlpools_config, collect_protocol_fees_authority, new_collect_protocol_fees_authority, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(SetCollectProtocolFeesAuthorityInstructionAccountMetas {
+            whirlpools_config: whirlpools_config.clone(),
+            collect_protocol_fees_authority: collect_protocol_fees_authority.clone(),
+            new_collect_protocol_fees_authority: new_collect_protocol_fees_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SetCollectProtocolFeesAuthorityInstructionAccountIndices {
+    pub whirlpools_config: usize,
+    pub collect_protocol_fees_authority: usize,
+    pub new_collect_protocol_fees_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SetCollectProtocolFeesAuthority {
+    type ArrangedAccountIndices = SetCollectProtocolFeesAuthorityInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(SetCollectProtocolFeesAuthorityInstructionAccountIndices {
+            whirlpools_config: 0,
+            collect_protocol_fees_authority: 1,
+            new_collect_protocol_fees_authority: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SetCollectProtocolFeesAuthority {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "whirlpools_config",
+            "collect_protocol_fees_authority",
+            "new_collect_protocol_fees_authority",
+        ]
+    }
+}
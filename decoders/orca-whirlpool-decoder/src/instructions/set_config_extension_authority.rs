@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +12,7 @@ pub struct SetConfigExtensionAuthorityInstructionAccounts {
     pub whirlpools_config_extension: solana_pubkey::Pubkey,
     pub config_extension_authority: solana_pubkey::Pubkey,
     pub new_config_extension_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SetConfigExtensionAuthority {
@@ -19,7 +21,7 @@ impl carbon_core::deserialize::ArrangeAccounts for SetConfigExtensionAuthority {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [whirlpools_config, whirlpools_config_extension, config_extension_authority, new_config_extension_authority, _remaining @ ..] =
+        let [whirlpools_config, whirlpools_config_extension, config_extension_authority, new_config_extension_authority, remaining @ ..] =
             accounts
         else {
             return None;
@@ -30,6 +32,76 @@ impl carbon_core::deserialize::ArrangeAccounts for SetConfigExtensionAuthority {
             whirlpools_config_extension: whirlpools_config_extension.pubkey,
             config_extension_authority: config_extension_authority.pubkey,
             new_config_extension_authority: new_config_extension_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SetConfigExtensionAuthorityInstructionAccountMetas {
+    pub whirlpools_config: solana_instruction::AccountMeta,
+    pub whirlpools_config_extension: solana_instruction::AccountMeta,
+    pub config_extension_authority: solana_instruction::AccountMeta,
+    pub new_config_extension_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SetConfigExtensionAuthority {
+    type ArrangedAccountMetas = SetConfigExtensionAuthorityInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [whirlpools_config, whirlpools_config_extension, config_extension_authority, new_config_extension_authority, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(SetConfigExtensionAuthorityInstructionAccountMetas {
+            whirlpools_config: whirlpools_config.clone(),
+            whirlpools_config_extension: whirlpools_config_extension.clone(),
+            config_extension_authority: config_extension_authority.clone(),
+            new_config_extension_authority: new_config_extension_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SetConfigExtensionAuthorityInstructionAccountIndices {
+    pub whirlpools_config: usize,
+    pub whirlpools_config_extension: usize,
+    pub config_extension_authority: usize,
+    pub new_config_extension_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SetConfigExtensionAuthority {
+    type ArrangedAccountIndices = SetConfigExtensionAuthorityInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(SetConfigExtensionAuthorityInstructionAccountIndices {
+            whirlpools_config: 0,
+            whirlpools_config_extension: 1,
+            config_extension_authority: 2,
+            new_config_extension_authority: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SetConfigExtensionAuthority {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "whirlpools_config",
+            "whirlpools_config_extension",
+            "config_extension_authority",
+            "new_config_extension_authority",
+        ]
+    }
+}
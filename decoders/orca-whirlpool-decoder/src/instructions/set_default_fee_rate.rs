@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +13,7 @@ pub struct SetDefaultFeeRateInstructionAccounts {
     pub whirlpools_config: solana_pubkey::Pubkey,
     pub fee_tier: solana_pubkey::Pubkey,
     pub fee_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SetDefaultFeeRate {
@@ -20,7 +22,7 @@ impl carbon_core::deserialize::ArrangeAccounts for SetDefaultFeeRate {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [whirlpools_config, fee_tier, fee_authority, _remaining @ ..] = accounts else {
+        let [whirlpools_config, fee_tier, fee_authority, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +30,65 @@ impl carbon_core::deserialize::ArrangeAccounts for SetDefaultFeeRate {
             whirlpools_config: whirlpools_config.pubkey,
             fee_tier: fee_tier.pubkey,
             fee_authority: fee_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SetDefaultFeeRateInstructionAccountMetas {
+    pub whirlpools_config: solana_instruction::AccountMeta,
+    pub fee_tier: solana_instruction::AccountMeta,
+    pub fee_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SetDefaultFeeRate {
+    type ArrangedAccountMetas = SetDefaultFeeRateInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [whirlpools_config, fee_tier, fee_authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(SetDefaultFeeRateInstructionAccountMetas {
+            whirlpools_config: whirlpools_config.clone(),
+            fee_tier: fee_tier.clone(),
+            fee_authority: fee_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SetDefaultFeeRateInstructionAccountIndices {
+    pub whirlpools_config: usize,
+    pub fee_tier: usize,
+    pub fee_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SetDefaultFeeRate {
+    type ArrangedAccountIndices = SetDefaultFeeRateInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(SetDefaultFeeRateInstructionAccountIndices {
+            whirlpools_config: 0,
+            fee_tier: 1,
+            fee_authority: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SetDefaultFeeRate {
+    fn account_names() -> &'static [&'static str] {
+        &["whirlpools_config", "fee_tier", "fee_authority"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +12,7 @@ pub struct SetDefaultProtocolFeeRate {
 pub struct SetDefaultProtocolFeeRateInstructionAccounts {
     pub whirlpools_config: solana_pubkey::Pubkey,
     pub fee_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SetDefaultProtocolFeeRate {
@@ -19,13 +21,68 @@ impl carbon_core::deserialize::ArrangeAccounts for SetDefaultProtocolFeeRate {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [whirlpools_config, fee_authority, _remaining @ ..] = accounts else {
+        let [whirlpools_config, fee_authority, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(SetDefaultProtocolFeeRateInstructionAccounts {
             whirlpools_config: whirlpools_config.pubkey,
             fee_authority: fee_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SetDefaultProtocolFeeRateInstructionAccountMetas {
+    pub whirlpools_config: solana_instruction::AccountMeta,
+    pub fee_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SetDefaultProtocolFeeRate {
+    type ArrangedAccountMetas = SetDefaultProtocolFeeRateInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [whirlpools_config, fee_authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(SetDefaultProtocolFeeRateInstructionAccountMetas {
+            whirlpools_config: whirlpools_config.clone(),
+            fee_authority: fee_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SetDefaultProtocolFeeRateInstructionAccountIndices {
+    pub whirlpools_config: usize,
+    pub fee_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SetDefaultProtocolFeeRate {
+    type ArrangedAccountIndices = SetDefaultProtocolFeeRateInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(SetDefaultProtocolFeeRateInstructionAccountIndices {
+            whirlpools_config: 0,
+            fee_authority: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SetDefaultProtocolFeeRate {
+    fn account_names() -> &'static [&'static str] {
+        &["whirlpools_config", "fee_authority"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -10,6 +11,7 @@ pub struct SetFeeAuthorityInstructionAccounts {
     pub whirlpools_config: solana_pubkey::Pubkey,
     pub fee_authority: solana_pubkey::Pubkey,
     pub new_fee_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SetFeeAuthority {
@@ -18,8 +20,7 @@ impl carbon_core::deserialize::ArrangeAccounts for SetFeeAuthority {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [whirlpools_config, fee_authority, new_fee_authority, _remaining @ ..] = accounts
-        else {
+        let [whirlpools_config, fee_authority, new_fee_authority, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -27,6 +28,65 @@ impl carbon_core::deserialize::ArrangeAccounts for SetFeeAuthority {
             whirlpools_config: whirlpools_config.pubkey,
             fee_authority: fee_authority.pubkey,
             new_fee_authority: new_fee_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SetFeeAuthorityInstructionAccountMetas {
+    pub whirlpools_config: solana_instruction::AccountMeta,
+    pub fee_authority: solana_instruction::AccountMeta,
+    pub new_fee_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SetFeeAuthority {
+    type ArrangedAccountMetas = SetFeeAuthorityInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [whirlpools_config, fee_authority, new_fee_authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(SetFeeAuthorityInstructionAccountMetas {
+            whirlpools_config: whirlpools_config.clone(),
+            fee_authority: fee_authority.clone(),
+            new_fee_authority: new_fee_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SetFeeAuthorityInstructionAccountIndices {
+    pub whirlpools_config: usize,
+    pub fee_authority: usize,
+    pub new_fee_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SetFeeAuthority {
+    type ArrangedAccountIndices = SetFeeAuthorityInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(SetFeeAuthorityInstructionAccountIndices {
+            whirlpools_config: 0,
+            fee_authority: 1,
+            new_fee_authority: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SetFeeAuthority {
+    fn account_names() -> &'static [&'static str] {
+        &["whirlpools_config", "fee_authority", "new_fee_authority"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +13,7 @@ pub struct SetFeeRateInstructionAccounts {
     pub whirlpools_config: solana_pubkey::Pubkey,
     pub whirlpool: solana_pubkey::Pubkey,
     pub fee_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SetFeeRate {
@@ -20,7 +22,7 @@ impl carbon_core::deserialize::ArrangeAccounts for SetFeeRate {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [whirlpools_config, whirlpool, fee_authority, _remaining @ ..] = accounts else {
+        let [whirlpools_config, whirlpool, fee_authority, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +30,65 @@ impl carbon_core::deserialize::ArrangeAccounts for SetFeeRate {
             whirlpools_config: whirlpools_config.pubkey,
             whirlpool: whirlpool.pubkey,
             fee_authority: fee_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SetFeeRateInstructionAccountMetas {
+    pub whirlpools_config: solana_instruction::AccountMeta,
+    pub whirlpool: solana_instruction::AccountMeta,
+    pub fee_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SetFeeRate {
+    type ArrangedAccountMetas = SetFeeRateInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [whirlpools_config, whirlpool, fee_authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(SetFeeRateInstructionAccountMetas {
+            whirlpools_config: whirlpools_config.clone(),
+            whirlpool: whirlpool.clone(),
+            fee_authority: fee_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SetFeeRateInstructionAccountIndices {
+    pub whirlpools_config: usize,
+    pub whirlpool: usize,
+    pub fee_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SetFeeRate {
+    type ArrangedAccountIndices = SetFeeRateInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(SetFeeRateInstructionAccountIndices {
+            whirlpools_config: 0,
+            whirlpool: 1,
+            fee_authority: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SetFeeRate {
+    fn account_names() -> &'static [&'static str] {
+        &["whirlpools_config", "whirlpool", "fee_authority"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +13,7 @@ pub struct SetRewardAuthorityInstructionAccounts {
     pub whirlpool: solana_pubkey::Pubkey,
     pub reward_authority: solana_pubkey::Pubkey,
     pub new_reward_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SetRewardAuthority {
@@ -20,7 +22,7 @@ impl carbon_core::deserialize::ArrangeAccounts for SetRewardAuthority {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [whirlpool, reward_authority, new_reward_authority, _remaining @ ..] = accounts else {
+        let [whirlpool, reward_authority, new_reward_authority, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +30,65 @@ impl carbon_core::deserialize::ArrangeAccounts for SetRewardAuthority {
             whirlpool: whirlpool.pubkey,
             reward_authority: reward_authority.pubkey,
             new_reward_authority: new_reward_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SetRewardAuthorityInstructionAccountMetas {
+    pub whirlpool: solana_instruction::AccountMeta,
+    pub reward_authority: solana_instruction::AccountMeta,
+    pub new_reward_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SetRewardAuthority {
+    type ArrangedAccountMetas = SetRewardAuthorityInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [whirlpool, reward_authority, new_reward_authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(SetRewardAuthorityInstructionAccountMetas {
+            whirlpool: whirlpool.clone(),
+            reward_authority: reward_authority.clone(),
+            new_reward_authority: new_reward_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SetRewardAuthorityInstructionAccountIndices {
+    pub whirlpool: usize,
+    pub reward_authority: usize,
+    pub new_reward_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SetRewardAuthority {
+    type ArrangedAccountIndices = SetRewardAuthorityInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(SetRewardAuthorityInstructionAccountIndices {
+            whirlpool: 0,
+            reward_authority: 1,
+            new_reward_authority: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SetRewardAuthority {
+    fn account_names() -> &'static [&'static str] {
+        &["whirlpool", "reward_authority", "new_reward_authority"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +14,7 @@ pub struct SetRewardAuthorityBySuperAuthorityInstructionAccounts {
     pub whirlpool: solana_pubkey::Pubkey,
     pub reward_emissions_super_authority: solana_pubkey::Pubkey,
     pub new_reward_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SetRewardAuthorityBySuperAuthority {
@@ -21,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for SetRewardAuthorityBySuperAuth
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [whirlpools_config, whirlpool, reward_emissions_super_authority, new_reward_authority, _remaining @ ..] =
+        let [whirlpools_config, whirlpool, reward_emissions_super_authority, new_reward_authority, remaining @ ..] =
             accounts
         else {
             return None;
@@ -32,6 +34,78 @@ impl carbon_core::deserialize::ArrangeAccounts for SetRewardAuthorityBySuperAuth
             whirlpool: whirlpool.pubkey,
             reward_emissions_super_authority: reward_emissions_super_authority.pubkey,
             new_reward_authority: new_reward_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SetRewardAuthorityBySuperAuthorityInstructionAccountMetas {
+    pub whirlpools_config: solana_instruction::AccountMeta,
+    pub whirlpool: solana_instruction::AccountMeta,
+    pub reward_emissions_super_authority: solana_instruction::AccountMeta,
+    pub new_reward_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SetRewardAuthorityBySuperAuthority {
+    type ArrangedAccountMetas = SetRewardAuthorityBySuperAuthorityInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [whirlpools_config, whirlpool, reward_emissions_super_authority, new_reward_authority, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(SetRewardAuthorityBySuperAuthorityInstructionAccountMetas {
+            whirlpools_config: whirlpools_config.clone(),
+            whirlpool: whirlpool.clone(),
+            reward_emissions_super_authority: reward_emissions_super_authority.clone(),
+            new_reward_authority: new_reward_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SetRewardAuthorityBySuperAuthorityInstructionAccountIndices {
+    pub whirlpools_config: usize,
+    pub whirlpool: usize,
+    pub reward_emissions_super_authority: usize,
+    pub new_reward_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SetRewardAuthorityBySuperAuthority {
+    type ArrangedAccountIndices = SetRewardAuthorityBySuperAuthorityInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(
+            SetRewardAuthorityBySuperAuthorityInstructionAccountIndices {
+                whirlpools_config: 0,
+                whirlpool: 1,
+                reward_emissions_super_authority: 2,
+                new_reward_authority: 3,
+                remaining_accounts: 4..accounts.len(),
+            },
+        )
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SetRewardAuthorityBySuperAuthority {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "whirlpools_config",
+            "whirlpool",
+            "reward_emissions_super_authority",
+            "new_reward_authority",
+        ]
+    }
+}
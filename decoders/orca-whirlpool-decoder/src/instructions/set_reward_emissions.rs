@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +14,7 @@ pub struct SetRewardEmissionsInstructionAccounts {
     pub whirlpool: solana_pubkey::Pubkey,
     pub reward_authority: solana_pubkey::Pubkey,
     pub reward_vault: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SetRewardEmissions {
@@ -21,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for SetRewardEmissions {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [whirlpool, reward_authority, reward_vault, _remaining @ ..] = accounts else {
+        let [whirlpool, reward_authority, reward_vault, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -29,6 +31,65 @@ impl carbon_core::deserialize::ArrangeAccounts for SetRewardEmissions {
             whirlpool: whirlpool.pubkey,
             reward_authority: reward_authority.pubkey,
             reward_vault: reward_vault.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SetRewardEmissionsInstructionAccountMetas {
+    pub whirlpool: solana_instruction::AccountMeta,
+    pub reward_authority: solana_instruction::AccountMeta,
+    pub reward_vault: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SetRewardEmissions {
+    type ArrangedAccountMetas = SetRewardEmissionsInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [whirlpool, reward_authority, reward_vault, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(SetRewardEmissionsInstructionAccountMetas {
+            whirlpool: whirlpool.clone(),
+            reward_authority: reward_authority.clone(),
+            reward_vault: reward_vault.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SetRewardEmissionsInstructionAccountIndices {
+    pub whirlpool: usize,
+    pub reward_authority: usize,
+    pub reward_vault: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SetRewardEmissions {
+    type ArrangedAccountIndices = SetRewardEmissionsInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(SetRewardEmissionsInstructionAccountIndices {
+            whirlpool: 0,
+            reward_authority: 1,
+            reward_vault: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SetRewardEmissions {
+    fn account_names() -> &'static [&'static str] {
+        &["whirlpool", "reward_authority", "reward_vault"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -10,6 +11,7 @@ pub struct SetRewardEmissionsSuperAuthorityInstructionAccounts {
     pub whirlpools_config: solana_pubkey::Pubkey,
     pub reward_emissions_super_authority: solana_pubkey::Pubkey,
     pub new_reward_emissions_super_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SetRewardEmissionsSuperAuthority {
@@ -18,7 +20,7 @@ impl carbon_core::deserialize::ArrangeAccounts for SetRewardEmissionsSuperAuthor
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [whirlpools_config, reward_emissions_super_authority, new_reward_emissions_super_authority, _remaining @ ..] =
+        let [whirlpools_config, reward_emissions_super_authority, new_reward_emissions_super_authority, remaining @ ..] =
             accounts
         else {
             return None;
@@ -28,6 +30,71 @@ impl carbon_core::deserialize::ArrangeAccounts for SetRewardEmissionsSuperAuthor
             whirlpools_config: whirlpools_config.pubkey,
             reward_emissions_super_authority: reward_emissions_super_authority.pubkey,
             new_reward_emissions_super_authority: new_reward_emissions_super_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SetRewardEmissionsSuperAuthorityInstructionAccountMetas {
+    pub whirlpools_config: solana_instruction::AccountMeta,
+    pub reward_emissions_super_authority: solana_instruction::AccountMeta,
+    pub new_reward_emissions_super_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SetRewardEmissionsSuperAuthority {
+    type ArrangedAccountMetas = SetRewardEmissionsSuperAuthorityInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [whirlpools_config, reward_emissions_super_authority, new_reward_emissions_super_authority, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(SetRewardEmissionsSuperAuthorityInstructionAccountMetas {
+            whirlpools_config: whirlpools_config.clone(),
+            reward_emissions_super_authority: reward_emissions_super_authority.clone(),
+            new_reward_emissions_super_authority: new_reward_emissions_super_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SetRewardEmissionsSuperAuthorityInstructionAccountIndices {
+    pub whirlpools_config: usize,
+    pub reward_emissions_super_authority: usize,
+    pub new_reward_emissions_super_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SetRewardEmissionsSuperAuthority {
+    type ArrangedAccountIndices = SetRewardEmissionsSuperAuthorityInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(SetRewardEmissionsSuperAuthorityInstructionAccountIndices {
+            whirlpools_config: 0,
+            reward_emissions_super_authority: 1,
+            new_reward_emissions_super_authority: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SetRewardEmissionsSuperAuthority {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "whirlpools_config",
+            "reward_emissions_super_authority",
+            "new_reward_emissions_super_authority",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +14,7 @@ pub struct SetRewardEmissionsV2InstructionAccounts {
     pub whirlpool: solana_pubkey::Pubkey,
     pub reward_authority: solana_pubkey::Pubkey,
     pub reward_vault: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SetRewardEmissionsV2 {
@@ -21,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for SetRewardEmissionsV2 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [whirlpool, reward_authority, reward_vault, _remaining @ ..] = accounts else {
+        let [whirlpool, reward_authority, reward_vault, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -29,6 +31,65 @@ impl carbon_core::deserialize::ArrangeAccounts for SetRewardEmissionsV2 {
             whirlpool: whirlpool.pubkey,
             reward_authority: reward_authority.pubkey,
             reward_vault: reward_vault.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SetRewardEmissionsV2InstructionAccountMetas {
+    pub whirlpool: solana_instruction::AccountMeta,
+    pub reward_authority: solana_instruction::AccountMeta,
+    pub reward_vault: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SetRewardEmissionsV2 {
+    type ArrangedAccountMetas = SetRewardEmissionsV2InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [whirlpool, reward_authority, reward_vault, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(SetRewardEmissionsV2InstructionAccountMetas {
+            whirlpool: whirlpool.clone(),
+            reward_authority: reward_authority.clone(),
+            reward_vault: reward_vault.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SetRewardEmissionsV2InstructionAccountIndices {
+    pub whirlpool: usize,
+    pub reward_authority: usize,
+    pub reward_vault: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SetRewardEmissionsV2 {
+    type ArrangedAccountIndices = SetRewardEmissionsV2InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(SetRewardEmissionsV2InstructionAccountIndices {
+            whirlpool: 0,
+            reward_authority: 1,
+            reward_vault: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SetRewardEmissionsV2 {
+    fn account_names() -> &'static [&'static str] {
+        &["whirlpool", "reward_authority", "reward_vault"]
+    }
+}
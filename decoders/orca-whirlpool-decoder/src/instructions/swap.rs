@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -55,3 +56,107 @@ impl carbon_core::deserialize::ArrangeAccounts for Swap {
         })
     }
 }
+
+pub struct SwapInstructionAccountMetas {
+    pub token_program: solana_instruction::AccountMeta,
+    pub token_authority: solana_instruction::AccountMeta,
+    pub whirlpool: solana_instruction::AccountMeta,
+    pub token_owner_account_a: solana_instruction::AccountMeta,
+    pub token_vault_a: solana_instruction::AccountMeta,
+    pub token_owner_account_b: solana_instruction::AccountMeta,
+    pub token_vault_b: solana_instruction::AccountMeta,
+    pub tick_array0: solana_instruction::AccountMeta,
+    pub tick_array1: solana_instruction::AccountMeta,
+    pub tick_array2: solana_instruction::AccountMeta,
+    pub oracle: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Swap {
+    type ArrangedAccountMetas = SwapInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [token_program, token_authority, whirlpool, token_owner_account_a, token_vault_a, token_owner_account_b, token_vault_b, tick_array0, tick_array1, tick_array2, oracle, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(SwapInstructionAccountMetas {
+            token_program: token_program.clone(),
+            token_authority: token_authority.clone(),
+            whirlpool: whirlpool.clone(),
+            token_owner_account_a: token_owner_account_a.clone(),
+            token_vault_a: token_vault_a.clone(),
+            token_owner_account_b: token_owner_account_b.clone(),
+            token_vault_b: token_vault_b.clone(),
+            tick_array0: tick_array0.clone(),
+            tick_array1: tick_array1.clone(),
+            tick_array2: tick_array2.clone(),
+            oracle: oracle.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SwapInstructionAccountIndices {
+    pub token_program: usize,
+    pub token_authority: usize,
+    pub whirlpool: usize,
+    pub token_owner_account_a: usize,
+    pub token_vault_a: usize,
+    pub token_owner_account_b: usize,
+    pub token_vault_b: usize,
+    pub tick_array0: usize,
+    pub tick_array1: usize,
+    pub tick_array2: usize,
+    pub oracle: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Swap {
+    type ArrangedAccountIndices = SwapInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 11 {
+            return None;
+        }
+
+        Some(SwapInstructionAccountIndices {
+            token_program: 0,
+            token_authority: 1,
+            whirlpool: 2,
+            token_owner_account_a: 3,
+            token_vault_a: 4,
+            token_owner_account_b: 5,
+            token_vault_b: 6,
+            tick_array0: 7,
+            tick_array1: 8,
+            tick_array2: 9,
+            oracle: 10,
+            remaining_accounts: 11..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Swap {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "token_program",
+            "token_authority",
+            "whirlpool",
+            "token_owner_account_a",
+            "token_vault_a",
+            "token_owner_account_b",
+            "token_vault_b",
+            "tick_array0",
+            "tick_array1",
+            "tick_array2",
+            "oracle",
+        ]
+    }
+}
@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -67,3 +68,127 @@ impl carbon_core::deserialize::ArrangeAccounts for SwapV2 {
         })
     }
 }
+
+pub struct SwapV2InstructionAccountMetas {
+    pub token_program_a: solana_instruction::AccountMeta,
+    pub token_program_b: solana_instruction::AccountMeta,
+    pub memo_program: solana_instruction::AccountMeta,
+    pub token_authority: solana_instruction::AccountMeta,
+    pub whirlpool: solana_instruction::AccountMeta,
+    pub token_mint_a: solana_instruction::AccountMeta,
+    pub token_mint_b: solana_instruction::AccountMeta,
+    pub token_owner_account_a: solana_instruction::AccountMeta,
+    pub token_vault_a: solana_instruction::AccountMeta,
+    pub token_owner_account_b: solana_instruction::AccountMeta,
+    pub token_vault_b: solana_instruction::AccountMeta,
+    pub tick_array0: solana_instruction::AccountMeta,
+    pub tick_array1: solana_instruction::AccountMeta,
+    pub tick_array2: solana_instruction::AccountMeta,
+    pub oracle: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SwapV2 {
+    type ArrangedAccountMetas = SwapV2InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [token_program_a, token_program_b, memo_program, token_authority, whirlpool, token_mint_a, token_mint_b, token_owner_account_a, token_vault_a, token_owner_account_b, token_vault_b, tick_array0, tick_array1, tick_array2, oracle, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(SwapV2InstructionAccountMetas {
+            token_program_a: token_program_a.clone(),
+            token_program_b: token_program_b.clone(),
+            memo_program: memo_program.clone(),
+            token_authority: token_authority.clone(),
+            whirlpool: whirlpool.clone(),
+            token_mint_a: token_mint_a.clone(),
+            token_mint_b: token_mint_b.clone(),
+            token_owner_account_a: token_owner_account_a.clone(),
+            token_vault_a: token_vault_a.clone(),
+            token_owner_account_b: token_owner_account_b.clone(),
+            token_vault_b: token_vault_b.clone(),
+            tick_array0: tick_array0.clone(),
+            tick_array1: tick_array1.clone(),
+            tick_array2: tick_array2.clone(),
+            oracle: oracle.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SwapV2InstructionAccountIndices {
+    pub token_program_a: usize,
+    pub token_program_b: usize,
+    pub memo_program: usize,
+    pub token_authority: usize,
+    pub whirlpool: usize,
+    pub token_mint_a: usize,
+    pub token_mint_b: usize,
+    pub token_owner_account_a: usize,
+    pub token_vault_a: usize,
+    pub token_owner_account_b: usize,
+    pub token_vault_b: usize,
+    pub tick_array0: usize,
+    pub tick_array1: usize,
+    pub tick_array2: usize,
+    pub oracle: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SwapV2 {
+    type ArrangedAccountIndices = SwapV2InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 15 {
+            return None;
+        }
+
+        Some(SwapV2InstructionAccountIndices {
+            token_program_a: 0,
+            token_program_b: 1,
+            memo_program: 2,
+            token_authority: 3,
+            whirlpool: 4,
+            token_mint_a: 5,
+            token_mint_b: 6,
+            token_owner_account_a: 7,
+            token_vault_a: 8,
+            token_owner_account_b: 9,
+            token_vault_b: 10,
+            tick_array0: 11,
+            tick_array1: 12,
+            tick_array2: 13,
+            oracle: 14,
+            remaining_accounts: 15..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SwapV2 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "token_program_a",
+            "token_program_b",
+            "memo_program",
+            "token_authority",
+            "whirlpool",
+            "token_mint_a",
+            "token_mint_b",
+            "token_owner_account_a",
+            "token_vault_a",
+            "token_owner_account_b",
+            "token_vault_b",
+            "tick_array0",
+            "tick_array1",
+            "tick_array2",
+            "oracle",
+        ]
+    }
+}
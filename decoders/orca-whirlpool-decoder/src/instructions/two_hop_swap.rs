@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -75,3 +76,152 @@ impl carbon_core::deserialize::ArrangeAccounts for TwoHopSwap {
         })
     }
 }
+
+pub struct TwoHopSwapInstructionAccountMetas {
+    pub token_program: solana_instruction::AccountMeta,
+    pub token_authority: solana_instruction::AccountMeta,
+    pub whirlpool_one: solana_instruction::AccountMeta,
+    pub whirlpool_two: solana_instruction::AccountMeta,
+    pub token_owner_account_one_a: solana_instruction::AccountMeta,
+    pub token_vault_one_a: solana_instruction::AccountMeta,
+    pub token_owner_account_one_b: solana_instruction::AccountMeta,
+    pub token_vault_one_b: solana_instruction::AccountMeta,
+    pub token_owner_account_two_a: solana_instruction::AccountMeta,
+    pub token_vault_two_a: solana_instruction::AccountMeta,
+    pub token_owner_account_two_b: solana_instruction::AccountMeta,
+    pub token_vault_two_b: solana_instruction::AccountMeta,
+    pub tick_array_one0: solana_instruction::AccountMeta,
+    pub tick_array_one1: solana_instruction::AccountMeta,
+    pub tick_array_one2: solana_instruction::AccountMeta,
+    pub tick_array_two0: solana_instruction::AccountMeta,
+    pub tick_array_two1: solana_instruction::AccountMeta,
+    pub tick_array_two2: solana_instruction::AccountMeta,
+    pub oracle_one: solana_instruction::AccountMeta,
+    pub oracle_two: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for TwoHopSwap {
+    type ArrangedAccountMetas = TwoHopSwapInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [token_program, token_authority, whirlpool_one, whirlpool_two, token_owner_account_one_a, token_vault_one_a, token_owner_account_one_b, token_vault_one_b, token_owner_account_two_a, token_vault_two_a, token_owner_account_two_b, token_vault_two_b, tick_array_one0, tick_array_one1, tick_array_one2, tick_array_two0, tick_array_two1, tick_array_two2, oracle_one, oracle_two, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(TwoHopSwapInstructionAccountMetas {
+            token_program: token_program.clone(),
+            token_authority: token_authority.clone(),
+            whirlpool_one: whirlpool_one.clone(),
+            whirlpool_two: whirlpool_two.clone(),
+            token_owner_account_one_a: token_owner_account_one_a.clone(),
+            token_vault_one_a: token_vault_one_a.clone(),
+            token_owner_account_one_b: token_owner_account_one_b.clone(),
+            token_vault_one_b: token_vault_one_b.clone(),
+            token_owner_account_two_a: token_owner_account_two_a.clone(),
+            token_vault_two_a: token_vault_two_a.clone(),
+            token_owner_account_two_b: token_owner_account_two_b.clone(),
+            token_vault_two_b: token_vault_two_b.clone(),
+            tick_array_one0: tick_array_one0.clone(),
+            tick_array_one1: tick_array_one1.clone(),
+            tick_array_one2: tick_array_one2.clone(),
+            tick_array_two0: tick_array_two0.clone(),
+            tick_array_two1: tick_array_two1.clone(),
+            tick_array_two2: tick_array_two2.clone(),
+            oracle_one: oracle_one.clone(),
+            oracle_two: oracle_two.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct TwoHopSwapInstructionAccountIndices {
+    pub token_program: usize,
+    pub token_authority: usize,
+    pub whirlpool_one: usize,
+    pub whirlpool_two: usize,
+    pub token_owner_account_one_a: usize,
+    pub token_vault_one_a: usize,
+    pub token_owner_account_one_b: usize,
+    pub token_vault_one_b: usize,
+    pub token_owner_account_two_a: usize,
+    pub token_vault_two_a: usize,
+    pub token_owner_account_two_b: usize,
+    pub token_vault_two_b: usize,
+    pub tick_array_one0: usize,
+    pub tick_array_one1: usize,
+    pub tick_array_one2: usize,
+    pub tick_array_two0: usize,
+    pub tick_array_two1: usize,
+    pub tick_array_two2: usize,
+    pub oracle_one: usize,
+    pub oracle_two: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for TwoHopSwap {
+    type ArrangedAccountIndices = TwoHopSwapInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 20 {
+            return None;
+        }
+
+        Some(TwoHopSwapInstructionAccountIndices {
+            token_program: 0,
+            token_authority: 1,
+            whirlpool_one: 2,
+            whirlpool_two: 3,
+            token_owner_account_one_a: 4,
+            token_vault_one_a: 5,
+            token_owner_account_one_b: 6,
+            token_vault_one_b: 7,
+            token_owner_account_two_a: 8,
+            token_vault_two_a: 9,
+            token_owner_account_two_b: 10,
+            token_vault_two_b: 11,
+            tick_array_one0: 12,
+            tick_array_one1: 13,
+            tick_array_one2: 14,
+            tick_array_two0: 15,
+            tick_array_two1: 16,
+            tick_array_two2: 17,
+            oracle_one: 18,
+            oracle_two: 19,
+            remaining_accounts: 20..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for TwoHopSwap {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "token_program",
+            "token_authority",
+            "whirlpool_one",
+            "whirlpool_two",
+            "token_owner_account_one_a",
+            "token_vault_one_a",
+            "token_owner_account_one_b",
+            "token_vault_one_b",
+            "token_owner_account_two_a",
+            "token_vault_two_a",
+            "token_owner_account_two_b",
+            "token_vault_two_b",
+            "tick_array_one0",
+            "tick_array_one1",
+            "tick_array_one2",
+            "tick_array_two0",
+            "tick_array_two1",
+            "tick_array_two2",
+            "oracle_one",
+            "oracle_two",
+        ]
+    }
+}
@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -87,3 +88,172 @@ impl carbon_core::deserialize::ArrangeAccounts for TwoHopSwapV2 {
         })
     }
 }
+
+pub struct TwoHopSwapV2InstructionAccountMetas {
+    pub whirlpool_one: solana_instruction::AccountMeta,
+    pub whirlpool_two: solana_instruction::AccountMeta,
+    pub token_mint_input: solana_instruction::AccountMeta,
+    pub token_mint_intermediate: solana_instruction::AccountMeta,
+    pub token_mint_output: solana_instruction::AccountMeta,
+    pub token_program_input: solana_instruction::AccountMeta,
+    pub token_program_intermediate: solana_instruction::AccountMeta,
+    pub token_program_output: solana_instruction::AccountMeta,
+    pub token_owner_account_input: solana_instruction::AccountMeta,
+    pub token_vault_one_input: solana_instruction::AccountMeta,
+    pub token_vault_one_intermediate: solana_instruction::AccountMeta,
+    pub token_vault_two_intermediate: solana_instruction::AccountMeta,
+    pub token_vault_two_output: solana_instruction::AccountMeta,
+    pub token_owner_account_output: solana_instruction::AccountMeta,
+    pub token_authority: solana_instruction::AccountMeta,
+    pub tick_array_one0: solana_instruction::AccountMeta,
+    pub tick_array_one1: solana_instruction::AccountMeta,
+    pub tick_array_one2: solana_instruction::AccountMeta,
+    pub tick_array_two0: solana_instruction::AccountMeta,
+    pub tick_array_two1: solana_instruction::AccountMeta,
+    pub tick_array_two2: solana_instruction::AccountMeta,
+    pub oracle_one: solana_instruction::AccountMeta,
+    pub oracle_two: solana_instruction::AccountMeta,
+    pub memo_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for TwoHopSwapV2 {
+    type ArrangedAccountMetas = TwoHopSwapV2InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [whirlpool_one, whirlpool_two, token_mint_input, token_mint_intermediate, token_mint_output, token_program_input, token_program_intermediate, token_program_output, token_owner_account_input, token_vault_one_input, token_vault_one_intermediate, token_vault_two_intermediate, token_vault_two_output, token_owner_account_output, token_authority, tick_array_one0, tick_array_one1, tick_array_one2, tick_array_two0, tick_array_two1, tick_array_two2, oracle_one, oracle_two, memo_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(TwoHopSwapV2InstructionAccountMetas {
+            whirlpool_one: whirlpool_one.clone(),
+            whirlpool_two: whirlpool_two.clone(),
+            token_mint_input: token_mint_input.clone(),
+            token_mint_intermediate: token_mint_intermediate.clone(),
+            token_mint_output: token_mint_output.clone(),
+            token_program_input: token_program_input.clone(),
+            token_program_intermediate: token_program_intermediate.clone(),
+            token_program_output: token_program_output.clone(),
+            token_owner_account_input: token_owner_account_input.clone(),
+            token_vault_one_input: token_vault_one_input.clone(),
+            token_vault_one_intermediate: token_vault_one_intermediate.clone(),
+            token_vault_two_intermediate: token_vault_two_intermediate.clone(),
+            token_vault_two_output: token_vault_two_output.clone(),
+            token_owner_account_output: token_owner_account_output.clone(),
+            token_authority: token_authority.clone(),
+            tick_array_one0: tick_array_one0.clone(),
+            tick_array_one1: tick_array_one1.clone(),
+            tick_array_one2: tick_array_one2.clone(),
+            tick_array_two0: tick_array_two0.clone(),
+            tick_array_two1: tick_array_two1.clone(),
+            tick_array_two2: tick_array_two2.clone(),
+            oracle_one: oracle_one.clone(),
+            oracle_two: oracle_two.clone(),
+            memo_program: memo_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct TwoHopSwapV2InstructionAccountIndices {
+    pub whirlpool_one: usize,
+    pub whirlpool_two: usize,
+    pub token_mint_input: usize,
+    pub token_mint_intermediate: usize,
+    pub token_mint_output: usize,
+    pub token_program_input: usize,
+    pub token_program_intermediate: usize,
+    pub token_program_output: usize,
+    pub token_owner_account_input: usize,
+    pub token_vault_one_input: usize,
+    pub token_vault_one_intermediate: usize,
+    pub token_vault_two_intermediate: usize,
+    pub token_vault_two_output: usize,
+    pub token_owner_account_output: usize,
+    pub token_authority: usize,
+    pub tick_array_one0: usize,
+    pub tick_array_one1: usize,
+    pub tick_array_one2: usize,
+    pub tick_array_two0: usize,
+    pub tick_array_two1: usize,
+    pub tick_array_two2: usize,
+    pub oracle_one: usize,
+    pub oracle_two: usize,
+    pub memo_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for TwoHopSwapV2 {
+    type ArrangedAccountIndices = TwoHopSwapV2InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 24 {
+            return None;
+        }
+
+        Some(TwoHopSwapV2InstructionAccountIndices {
+            whirlpool_one: 0,
+            whirlpool_two: 1,
+            token_mint_input: 2,
+            token_mint_intermediate: 3,
+            token_mint_output: 4,
+            token_program_input: 5,
+            token_program_intermediate: 6,
+            token_program_output: 7,
+            token_owner_account_input: 8,
+            token_vault_one_input: 9,
+            token_vault_one_intermediate: 10,
+            token_vault_two_intermediate: 11,
+            token_vault_two_output: 12,
+            token_owner_account_output: 13,
+            token_authority: 14,
+            tick_array_one0: 15,
+            tick_array_one1: 16,
+            tick_array_one2: 17,
+            tick_array_two0: 18,
+            tick_array_two1: 19,
+            tick_array_two2: 20,
+            oracle_one: 21,
+            oracle_two: 22,
+            memo_program: 23,
+            remaining_accounts: 24..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for TwoHopSwapV2 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "whirlpool_one",
+            "whirlpool_two",
+            "token_mint_input",
+            "token_mint_intermediate",
+            "token_mint_output",
+            "token_program_input",
+            "token_program_intermediate",
+            "token_program_output",
+            "token_owner_account_input",
+            "token_vault_one_input",
+            "token_vault_one_intermediate",
+            "token_vault_two_intermediate",
+            "token_vault_two_output",
+            "token_owner_account_output",
+            "token_authority",
+            "tick_array_one0",
+            "tick_array_one1",
+            "tick_array_one2",
+            "tick_array_two0",
+            "tick_array_two1",
+            "tick_array_two2",
+            "oracle_one",
+            "oracle_two",
+            "memo_program",
+        ]
+    }
+}
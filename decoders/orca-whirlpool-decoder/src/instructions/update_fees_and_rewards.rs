@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +12,7 @@ pub struct UpdateFeesAndRewardsInstructionAccounts {
     pub position: solana_pubkey::Pubkey,
     pub tick_array_lower: solana_pubkey::Pubkey,
     pub tick_array_upper: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateFeesAndRewards {
@@ -19,7 +21,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateFeesAndRewards {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [whirlpool, position, tick_array_lower, tick_array_upper, _remaining @ ..] = accounts
+        let [whirlpool, position, tick_array_lower, tick_array_upper, remaining @ ..] = accounts
         else {
             return None;
         };
@@ -29,6 +31,75 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateFeesAndRewards {
             position: position.pubkey,
             tick_array_lower: tick_array_lower.pubkey,
             tick_array_upper: tick_array_upper.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateFeesAndRewardsInstructionAccountMetas {
+    pub whirlpool: solana_instruction::AccountMeta,
+    pub position: solana_instruction::AccountMeta,
+    pub tick_array_lower: solana_instruction::AccountMeta,
+    pub tick_array_upper: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateFeesAndRewards {
+    type ArrangedAccountMetas = UpdateFeesAndRewardsInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [whirlpool, position, tick_array_lower, tick_array_upper, remaining @ ..] = accounts
+        else {
+            return None;
+        };
+
+        Some(UpdateFeesAndRewardsInstructionAccountMetas {
+            whirlpool: whirlpool.clone(),
+            position: position.clone(),
+            tick_array_lower: tick_array_lower.clone(),
+            tick_array_upper: tick_array_upper.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateFeesAndRewardsInstructionAccountIndices {
+    pub whirlpool: usize,
+    pub position: usize,
+    pub tick_array_lower: usize,
+    pub tick_array_upper: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateFeesAndRewards {
+    type ArrangedAccountIndices = UpdateFeesAndRewardsInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(UpdateFeesAndRewardsInstructionAccountIndices {
+            whirlpool: 0,
+            position: 1,
+            tick_array_lower: 2,
+            tick_array_upper: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateFeesAndRewards {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "whirlpool",
+            "position",
+            "tick_array_lower",
+            "tick_array_upper",
+        ]
+    }
+}
@@ -1,6 +1,7 @@
 use super::super::types::*;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -19,6 +20,7 @@ pub struct CancelMultipleOrdersByIdInstructionAccounts {
     pub base_vault: solana_pubkey::Pubkey,
     pub quote_vault: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CancelMultipleOrdersById {
@@ -27,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CancelMultipleOrdersById {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [phoenix_program, log_authority, market, trader, base_account, quote_account, base_vault, quote_vault, token_program, _remaining @ ..] =
+        let [phoenix_program, log_authority, market, trader, base_account, quote_account, base_vault, quote_vault, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -43,6 +45,101 @@ impl carbon_core::deserialize::ArrangeAccounts for CancelMultipleOrdersById {
             base_vault: base_vault.pubkey,
             quote_vault: quote_vault.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CancelMultipleOrdersByIdInstructionAccountMetas {
+    pub phoenix_program: solana_instruction::AccountMeta,
+    pub log_authority: solana_instruction::AccountMeta,
+    pub market: solana_instruction::AccountMeta,
+    pub trader: solana_instruction::AccountMeta,
+    pub base_account: solana_instruction::AccountMeta,
+    pub quote_account: solana_instruction::AccountMeta,
+    pub base_vault: solana_instruction::AccountMeta,
+    pub quote_vault: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CancelMultipleOrdersById {
+    type ArrangedAccountMetas = CancelMultipleOrdersByIdInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [phoenix_program, log_authority, market, trader, base_account, quote_account, base_vault, quote_vault, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CancelMultipleOrdersByIdInstructionAccountMetas {
+            phoenix_program: phoenix_program.clone(),
+            log_authority: log_authority.clone(),
+            market: market.clone(),
+            trader: trader.clone(),
+            base_account: base_account.clone(),
+            quote_account: quote_account.clone(),
+            base_vault: base_vault.clone(),
+            quote_vault: quote_vault.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CancelMultipleOrdersByIdInstructionAccountIndices {
+    pub phoenix_program: usize,
+    pub log_authority: usize,
+    pub market: usize,
+    pub trader: usize,
+    pub base_account: usize,
+    pub quote_account: usize,
+    pub base_vault: usize,
+    pub quote_vault: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CancelMultipleOrdersById {
+    type ArrangedAccountIndices = CancelMultipleOrdersByIdInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 9 {
+            return None;
+        }
+
+        Some(CancelMultipleOrdersByIdInstructionAccountIndices {
+            phoenix_program: 0,
+            log_authority: 1,
+            market: 2,
+            trader: 3,
+            base_account: 4,
+            quote_account: 5,
+            base_vault: 6,
+            quote_vault: 7,
+            token_program: 8,
+            remaining_accounts: 9..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CancelMultipleOrdersById {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "phoenix_program",
+            "log_authority",
+            "market",
+            "trader",
+            "base_account",
+            "quote_account",
+            "base_vault",
+            "quote_vault",
+            "token_program",
+        ]
+    }
+}
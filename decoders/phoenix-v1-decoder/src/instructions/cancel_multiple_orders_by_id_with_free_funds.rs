@@ -1,6 +1,7 @@
 use super::super::types::*;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct CancelMultipleOrdersByIdWithFreeFundsInstructionAccounts {
     pub log_authority: solana_pubkey::Pubkey,
     pub market: solana_pubkey::Pubkey,
     pub trader: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CancelMultipleOrdersByIdWithFreeFunds {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CancelMultipleOrdersByIdWithF
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [phoenix_program, log_authority, market, trader, _remaining @ ..] = accounts else {
+        let [phoenix_program, log_authority, market, trader, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -31,6 +33,73 @@ impl carbon_core::deserialize::ArrangeAccounts for CancelMultipleOrdersByIdWithF
             log_authority: log_authority.pubkey,
             market: market.pubkey,
             trader: trader.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CancelMultipleOrdersByIdWithFreeFundsInstructionAccountMetas {
+    pub phoenix_program: solana_instruction::AccountMeta,
+    pub log_authority: solana_instruction::AccountMeta,
+    pub market: solana_instruction::AccountMeta,
+    pub trader: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CancelMultipleOrdersByIdWithFreeFunds {
+    type ArrangedAccountMetas = CancelMultipleOrdersByIdWithFreeFundsInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [phoenix_program, log_authority, market, trader, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(
+            CancelMultipleOrdersByIdWithFreeFundsInstructionAccountMetas {
+                phoenix_program: phoenix_program.clone(),
+                log_authority: log_authority.clone(),
+                market: market.clone(),
+                trader: trader.clone(),
+                remaining_accounts: remaining.to_vec(),
+            },
+        )
+    }
+}
+
+pub struct CancelMultipleOrdersByIdWithFreeFundsInstructionAccountIndices {
+    pub phoenix_program: usize,
+    pub log_authority: usize,
+    pub market: usize,
+    pub trader: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CancelMultipleOrdersByIdWithFreeFunds {
+    type ArrangedAccountIndices = CancelMultipleOrdersByIdWithFreeFundsInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(
+            CancelMultipleOrdersByIdWithFreeFundsInstructionAccountIndices {
+                phoenix_program: 0,
+                log_authority: 1,
+                market: 2,
+                trader: 3,
+                remaining_accounts: 4..accounts.len(),
+            },
+        )
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CancelMultipleOrdersByIdWithFreeFunds {
+    fn account_names() -> &'static [&'static str] {
+        &["phoenix_program", "log_authority", "market", "trader"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +13,7 @@ pub struct ChangeFeeRecipientInstructionAccounts {
     pub market: solana_pubkey::Pubkey,
     pub market_authority: solana_pubkey::Pubkey,
     pub new_fee_recipient: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ChangeFeeRecipient {
@@ -20,7 +22,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ChangeFeeRecipient {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [phoenix_program, log_authority, market, market_authority, new_fee_recipient, _remaining @ ..] =
+        let [phoenix_program, log_authority, market, market_authority, new_fee_recipient, remaining @ ..] =
             accounts
         else {
             return None;
@@ -32,6 +34,81 @@ impl carbon_core::deserialize::ArrangeAccounts for ChangeFeeRecipient {
             market: market.pubkey,
             market_authority: market_authority.pubkey,
             new_fee_recipient: new_fee_recipient.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ChangeFeeRecipientInstructionAccountMetas {
+    pub phoenix_program: solana_instruction::AccountMeta,
+    pub log_authority: solana_instruction::AccountMeta,
+    pub market: solana_instruction::AccountMeta,
+    pub market_authority: solana_instruction::AccountMeta,
+    pub new_fee_recipient: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ChangeFeeRecipient {
+    type ArrangedAccountMetas = ChangeFeeRecipientInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [phoenix_program, log_authority, market, market_authority, new_fee_recipient, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(ChangeFeeRecipientInstructionAccountMetas {
+            phoenix_program: phoenix_program.clone(),
+            log_authority: log_authority.clone(),
+            market: market.clone(),
+            market_authority: market_authority.clone(),
+            new_fee_recipient: new_fee_recipient.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ChangeFeeRecipientInstructionAccountIndices {
+    pub phoenix_program: usize,
+    pub log_authority: usize,
+    pub market: usize,
+    pub market_authority: usize,
+    pub new_fee_recipient: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ChangeFeeRecipient {
+    type ArrangedAccountIndices = ChangeFeeRecipientInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(ChangeFeeRecipientInstructionAccountIndices {
+            phoenix_program: 0,
+            log_authority: 1,
+            market: 2,
+            market_authority: 3,
+            new_fee_recipient: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ChangeFeeRecipient {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "phoenix_program",
+            "log_authority",
+            "market",
+            "market_authority",
+            "new_fee_recipient",
+        ]
+    }
+}
@@ -1,6 +1,7 @@
 use super::super::types::*;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct ChangeMarketStatusInstructionAccounts {
     pub log_authority: solana_pubkey::Pubkey,
     pub market: solana_pubkey::Pubkey,
     pub market_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ChangeMarketStatus {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ChangeMarketStatus {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [phoenix_program, log_authority, market, market_authority, _remaining @ ..] = accounts
+        let [phoenix_program, log_authority, market, market_authority, remaining @ ..] = accounts
         else {
             return None;
         };
@@ -32,6 +34,75 @@ impl carbon_core::deserialize::ArrangeAccounts for ChangeMarketStatus {
             log_authority: log_authority.pubkey,
             market: market.pubkey,
             market_authority: market_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ChangeMarketStatusInstructionAccountMetas {
+    pub phoenix_program: solana_instruction::AccountMeta,
+    pub log_authority: solana_instruction::AccountMeta,
+    pub market: solana_instruction::AccountMeta,
+    pub market_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ChangeMarketStatus {
+    type ArrangedAccountMetas = ChangeMarketStatusInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [phoenix_program, log_authority, market, market_authority, remaining @ ..] = accounts
+        else {
+            return None;
+        };
+
+        Some(ChangeMarketStatusInstructionAccountMetas {
+            phoenix_program: phoenix_program.clone(),
+            log_authority: log_authority.clone(),
+            market: market.clone(),
+            market_authority: market_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ChangeMarketStatusInstructionAccountIndices {
+    pub phoenix_program: usize,
+    pub log_authority: usize,
+    pub market: usize,
+    pub market_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ChangeMarketStatus {
+    type ArrangedAccountIndices = ChangeMarketStatusInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(ChangeMarketStatusInstructionAccountIndices {
+            phoenix_program: 0,
+            log_authority: 1,
+            market: 2,
+            market_authority: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ChangeMarketStatus {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "phoenix_program",
+            "log_authority",
+            "market",
+            "market_authority",
+        ]
+    }
+}
@@ -1,6 +1,7 @@
 use super::super::types::*;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -15,6 +16,7 @@ pub struct ChangeSeatStatusInstructionAccounts {
     pub market: solana_pubkey::Pubkey,
     pub market_authority: solana_pubkey::Pubkey,
     pub seat: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ChangeSeatStatus {
@@ -23,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ChangeSeatStatus {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [phoenix_program, log_authority, market, market_authority, seat, _remaining @ ..] =
+        let [phoenix_program, log_authority, market, market_authority, seat, remaining @ ..] =
             accounts
         else {
             return None;
@@ -35,6 +37,81 @@ impl carbon_core::deserialize::ArrangeAccounts for ChangeSeatStatus {
             market: market.pubkey,
             market_authority: market_authority.pubkey,
             seat: seat.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ChangeSeatStatusInstructionAccountMetas {
+    pub phoenix_program: solana_instruction::AccountMeta,
+    pub log_authority: solana_instruction::AccountMeta,
+    pub market: solana_instruction::AccountMeta,
+    pub market_authority: solana_instruction::AccountMeta,
+    pub seat: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ChangeSeatStatus {
+    type ArrangedAccountMetas = ChangeSeatStatusInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [phoenix_program, log_authority, market, market_authority, seat, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(ChangeSeatStatusInstructionAccountMetas {
+            phoenix_program: phoenix_program.clone(),
+            log_authority: log_authority.clone(),
+            market: market.clone(),
+            market_authority: market_authority.clone(),
+            seat: seat.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ChangeSeatStatusInstructionAccountIndices {
+    pub phoenix_program: usize,
+    pub log_authority: usize,
+    pub market: usize,
+    pub market_authority: usize,
+    pub seat: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ChangeSeatStatus {
+    type ArrangedAccountIndices = ChangeSeatStatusInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(ChangeSeatStatusInstructionAccountIndices {
+            phoenix_program: 0,
+            log_authority: 1,
+            market: 2,
+            market_authority: 3,
+            seat: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ChangeSeatStatus {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "phoenix_program",
+            "log_authority",
+            "market",
+            "market_authority",
+            "seat",
+        ]
+    }
+}
@@ -1,6 +1,7 @@
 use super::super::types::*;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -20,6 +21,7 @@ pub struct DepositFundsInstructionAccounts {
     pub base_vault: solana_pubkey::Pubkey,
     pub quote_vault: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for DepositFunds {
@@ -28,7 +30,7 @@ impl carbon_core::deserialize::ArrangeAccounts for DepositFunds {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [phoenix_program, log_authority, market, trader, seat, base_account, quote_account, base_vault, quote_vault, token_program, _remaining @ ..] =
+        let [phoenix_program, log_authority, market, trader, seat, base_account, quote_account, base_vault, quote_vault, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -45,6 +47,106 @@ impl carbon_core::deserialize::ArrangeAccounts for DepositFunds {
             base_vault: base_vault.pubkey,
             quote_vault: quote_vault.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DepositFundsInstructionAccountMetas {
+    pub phoenix_program: solana_instruction::AccountMeta,
+    pub log_authority: solana_instruction::AccountMeta,
+    pub market: solana_instruction::AccountMeta,
+    pub trader: solana_instruction::AccountMeta,
+    pub seat: solana_instruction::AccountMeta,
+    pub base_account: solana_instruction::AccountMeta,
+    pub quote_account: solana_instruction::AccountMeta,
+    pub base_vault: solana_instruction::AccountMeta,
+    pub quote_vault: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for DepositFunds {
+    type ArrangedAccountMetas = DepositFundsInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [phoenix_program, log_authority, market, trader, seat, base_account, quote_account, base_vault, quote_vault, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(DepositFundsInstructionAccountMetas {
+            phoenix_program: phoenix_program.clone(),
+            log_authority: log_authority.clone(),
+            market: market.clone(),
+            trader: trader.clone(),
+            seat: seat.clone(),
+            base_account: base_account.clone(),
+            quote_account: quote_account.clone(),
+            base_vault: base_vault.clone(),
+            quote_vault: quote_vault.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DepositFundsInstructionAccountIndices {
+    pub phoenix_program: usize,
+    pub log_authority: usize,
+    pub market: usize,
+    pub trader: usize,
+    pub seat: usize,
+    pub base_account: usize,
+    pub quote_account: usize,
+    pub base_vault: usize,
+    pub quote_vault: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for DepositFunds {
+    type ArrangedAccountIndices = DepositFundsInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 10 {
+            return None;
+        }
+
+        Some(DepositFundsInstructionAccountIndices {
+            phoenix_program: 0,
+            log_authority: 1,
+            market: 2,
+            trader: 3,
+            seat: 4,
+            base_account: 5,
+            quote_account: 6,
+            base_vault: 7,
+            quote_vault: 8,
+            token_program: 9,
+            remaining_accounts: 10..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for DepositFunds {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "phoenix_program",
+            "log_authority",
+            "market",
+            "trader",
+            "seat",
+            "base_account",
+            "quote_account",
+            "base_vault",
+            "quote_vault",
+            "token_program",
+        ]
+    }
+}
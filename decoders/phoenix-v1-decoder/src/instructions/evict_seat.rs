@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -18,6 +19,7 @@ pub struct EvictSeatInstructionAccounts {
     pub base_vault: solana_pubkey::Pubkey,
     pub quote_vault: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for EvictSeat {
@@ -26,7 +28,7 @@ impl carbon_core::deserialize::ArrangeAccounts for EvictSeat {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [phoenix_program, log_authority, market, market_authority, trader, seat, base_account, quote_account, base_vault, quote_vault, token_program, _remaining @ ..] =
+        let [phoenix_program, log_authority, market, market_authority, trader, seat, base_account, quote_account, base_vault, quote_vault, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -44,6 +46,111 @@ impl carbon_core::deserialize::ArrangeAccounts for EvictSeat {
             base_vault: base_vault.pubkey,
             quote_vault: quote_vault.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct EvictSeatInstructionAccountMetas {
+    pub phoenix_program: solana_instruction::AccountMeta,
+    pub log_authority: solana_instruction::AccountMeta,
+    pub market: solana_instruction::AccountMeta,
+    pub market_authority: solana_instruction::AccountMeta,
+    pub trader: solana_instruction::AccountMeta,
+    pub seat: solana_instruction::AccountMeta,
+    pub base_account: solana_instruction::AccountMeta,
+    pub quote_account: solana_instruction::AccountMeta,
+    pub base_vault: solana_instruction::AccountMeta,
+    pub quote_vault: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for EvictSeat {
+    type ArrangedAccountMetas = EvictSeatInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [phoenix_program, log_authority, market, market_authority, trader, seat, base_account, quote_account, base_vault, quote_vault, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(EvictSeatInstructionAccountMetas {
+            phoenix_program: phoenix_program.clone(),
+            log_authority: log_authority.clone(),
+            market: market.clone(),
+            market_authority: market_authority.clone(),
+            trader: trader.clone(),
+            seat: seat.clone(),
+            base_account: base_account.clone(),
+            quote_account: quote_account.clone(),
+            base_vault: base_vault.clone(),
+            quote_vault: quote_vault.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct EvictSeatInstructionAccountIndices {
+    pub phoenix_program: usize,
+    pub log_authority: usize,
+    pub market: usize,
+    pub market_authority: usize,
+    pub trader: usize,
+    pub seat: usize,
+    pub base_account: usize,
+    pub quote_account: usize,
+    pub base_vault: usize,
+    pub quote_vault: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for EvictSeat {
+    type ArrangedAccountIndices = EvictSeatInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 11 {
+            return None;
+        }
+
+        Some(EvictSeatInstructionAccountIndices {
+            phoenix_program: 0,
+            log_authority: 1,
+            market: 2,
+            market_authority: 3,
+            trader: 4,
+            seat: 5,
+            base_account: 6,
+            quote_account: 7,
+            base_vault: 8,
+            quote_vault: 9,
+            token_program: 10,
+            remaining_accounts: 11..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for EvictSeat {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "phoenix_program",
+            "log_authority",
+            "market",
+            "market_authority",
+            "trader",
+            "seat",
+            "base_account",
+            "quote_account",
+            "base_vault",
+            "quote_vault",
+            "token_program",
+        ]
+    }
+}
@@ -1,6 +1,7 @@
 use super::super::types::*;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -20,6 +21,7 @@ pub struct InitializeMarketInstructionAccounts {
     pub quote_vault: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeMarket {
@@ -28,7 +30,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeMarket {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [phoenix_program, log_authority, market, market_creator, base_mint, quote_mint, base_vault, quote_vault, system_program, token_program, _remaining @ ..] =
+        let [phoenix_program, log_authority, market, market_creator, base_mint, quote_mint, base_vault, quote_vault, system_program, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -45,6 +47,106 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeMarket {
             quote_vault: quote_vault.pubkey,
             system_program: system_program.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeMarketInstructionAccountMetas {
+    pub phoenix_program: solana_instruction::AccountMeta,
+    pub log_authority: solana_instruction::AccountMeta,
+    pub market: solana_instruction::AccountMeta,
+    pub market_creator: solana_instruction::AccountMeta,
+    pub base_mint: solana_instruction::AccountMeta,
+    pub quote_mint: solana_instruction::AccountMeta,
+    pub base_vault: solana_instruction::AccountMeta,
+    pub quote_vault: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeMarket {
+    type ArrangedAccountMetas = InitializeMarketInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [phoenix_program, log_authority, market, market_creator, base_mint, quote_mint, base_vault, quote_vault, system_program, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeMarketInstructionAccountMetas {
+            phoenix_program: phoenix_program.clone(),
+            log_authority: log_authority.clone(),
+            market: market.clone(),
+            market_creator: market_creator.clone(),
+            base_mint: base_mint.clone(),
+            quote_mint: quote_mint.clone(),
+            base_vault: base_vault.clone(),
+            quote_vault: quote_vault.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeMarketInstructionAccountIndices {
+    pub phoenix_program: usize,
+    pub log_authority: usize,
+    pub market: usize,
+    pub market_creator: usize,
+    pub base_mint: usize,
+    pub quote_mint: usize,
+    pub base_vault: usize,
+    pub quote_vault: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeMarket {
+    type ArrangedAccountIndices = InitializeMarketInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 10 {
+            return None;
+        }
+
+        Some(InitializeMarketInstructionAccountIndices {
+            phoenix_program: 0,
+            log_authority: 1,
+            market: 2,
+            market_creator: 3,
+            base_mint: 4,
+            quote_mint: 5,
+            base_vault: 6,
+            quote_vault: 7,
+            system_program: 8,
+            token_program: 9,
+            remaining_accounts: 10..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeMarket {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "phoenix_program",
+            "log_authority",
+            "market",
+            "market_creator",
+            "base_mint",
+            "quote_mint",
+            "base_vault",
+            "quote_vault",
+            "system_program",
+            "token_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -8,6 +9,7 @@ pub struct Log {}
 
 pub struct LogInstructionAccounts {
     pub log_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Log {
@@ -16,12 +18,63 @@ impl carbon_core::deserialize::ArrangeAccounts for Log {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [log_authority, _remaining @ ..] = accounts else {
+        let [log_authority, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(LogInstructionAccounts {
             log_authority: log_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct LogInstructionAccountMetas {
+    pub log_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Log {
+    type ArrangedAccountMetas = LogInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [log_authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(LogInstructionAccountMetas {
+            log_authority: log_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct LogInstructionAccountIndices {
+    pub log_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Log {
+    type ArrangedAccountIndices = LogInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.is_empty() {
+            return None;
+        }
+
+        Some(LogInstructionAccountIndices {
+            log_authority: 0,
+            remaining_accounts: 1..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Log {
+    fn account_names() -> &'static [&'static str] {
+        &["log_authority"]
+    }
+}
@@ -78,15 +78,25 @@ pub enum PhoenixInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for PhoenixDecoder {
     type InstructionType = PhoenixInstruction;
 
-    fn decode_instruction(
+    fn decoder_name(&self) -> &'static str {
+        "phoenix"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[super::PROGRAM_ID]
+    }
+
+    fn try_decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
-    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+    ) -> carbon_core::error::CarbonResult<
+        Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>>,
+    > {
         if !instruction.program_id.eq(&PROGRAM_ID) {
-            return None;
+            return Ok(None);
         }
 
-        carbon_core::try_decode_instructions!(instruction,
+        Ok(carbon_core::try_decode_instructions!(instruction,
             PhoenixInstruction::Swap => swap::Swap,
             PhoenixInstruction::SwapWithFreeFunds => swap_with_free_funds::SwapWithFreeFunds,
             PhoenixInstruction::PlaceLimitOrder => place_limit_order::PlaceLimitOrder,
@@ -115,6 +125,6 @@ impl carbon_core::instruction::InstructionDecoder<'_> for PhoenixDecoder {
             PhoenixInstruction::ForceCancelOrders => force_cancel_orders::ForceCancelOrders,
             PhoenixInstruction::CollectFees => collect_fees::CollectFees,
             PhoenixInstruction::ChangeFeeRecipient => change_fee_recipient::ChangeFeeRecipient,
-        )
+        ))
     }
 }
@@ -1,6 +1,7 @@
 use super::super::types::*;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -15,6 +16,7 @@ pub struct PlaceMultiplePostOnlyOrdersWithFreeFundsInstructionAccounts {
     pub market: solana_pubkey::Pubkey,
     pub trader: solana_pubkey::Pubkey,
     pub seat: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for PlaceMultiplePostOnlyOrdersWithFreeFunds {
@@ -23,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for PlaceMultiplePostOnlyOrdersWi
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [phoenix_program, log_authority, market, trader, seat, _remaining @ ..] = accounts
+        let [phoenix_program, log_authority, market, trader, seat, remaining @ ..] = accounts
         else {
             return None;
         };
@@ -35,7 +37,85 @@ impl carbon_core::deserialize::ArrangeAccounts for PlaceMultiplePostOnlyOrdersWi
                 market: market.pubkey,
                 trader: trader.pubkey,
                 seat: seat.pubkey,
+                remaining_accounts: remaining.to_vec(),
             },
         )
     }
 }
+
+pub struct PlaceMultiplePostOnlyOrdersWithFreeFundsInstructionAccountMetas {
+    pub phoenix_program: solana_instruction::AccountMeta,
+    pub log_authority: solana_instruction::AccountMeta,
+    pub market: solana_instruction::AccountMeta,
+    pub trader: solana_instruction::AccountMeta,
+    pub seat: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for PlaceMultiplePostOnlyOrdersWithFreeFunds {
+    type ArrangedAccountMetas = PlaceMultiplePostOnlyOrdersWithFreeFundsInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [phoenix_program, log_authority, market, trader, seat, remaining @ ..] = accounts
+        else {
+            return None;
+        };
+
+        Some(
+            PlaceMultiplePostOnlyOrdersWithFreeFundsInstructionAccountMetas {
+                phoenix_program: phoenix_program.clone(),
+                log_authority: log_authority.clone(),
+                market: market.clone(),
+                trader: trader.clone(),
+                seat: seat.clone(),
+                remaining_accounts: remaining.to_vec(),
+            },
+        )
+    }
+}
+
+pub struct PlaceMultiplePostOnlyOrdersWithFreeFundsInstructionAccountIndices {
+    pub phoenix_program: usize,
+    pub log_authority: usize,
+    pub market: usize,
+    pub trader: usize,
+    pub seat: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for PlaceMultiplePostOnlyOrdersWithFreeFunds {
+    type ArrangedAccountIndices = PlaceMultiplePostOnlyOrdersWithFreeFundsInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(
+            PlaceMultiplePostOnlyOrdersWithFreeFundsInstructionAccountIndices {
+                phoenix_program: 0,
+                log_authority: 1,
+                market: 2,
+                trader: 3,
+                seat: 4,
+                remaining_accounts: 5..accounts.len(),
+            },
+        )
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for PlaceMultiplePostOnlyOrdersWithFreeFunds {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "phoenix_program",
+            "log_authority",
+            "market",
+            "trader",
+            "seat",
+        ]
+    }
+}
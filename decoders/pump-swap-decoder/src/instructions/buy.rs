@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -27,6 +29,7 @@ pub struct BuyInstructionAccounts {
     pub associated_token_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Buy {
@@ -35,7 +38,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Buy {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [pool, user, global_config, base_mint, quote_mint, user_base_token_account, user_quote_token_account, pool_base_token_account, pool_quote_token_account, protocol_fee_recipient, protocol_fee_recipient_token_account, base_token_program, quote_token_program, system_program, associated_token_program, event_authority, program, _remaining @ ..] =
+        let [pool, user, global_config, base_mint, quote_mint, user_base_token_account, user_quote_token_account, pool_base_token_account, pool_quote_token_account, protocol_fee_recipient, protocol_fee_recipient_token_account, base_token_program, quote_token_program, system_program, associated_token_program, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -59,6 +62,141 @@ impl carbon_core::deserialize::ArrangeAccounts for Buy {
             associated_token_program: associated_token_program.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct BuyInstructionAccountMetas {
+    pub pool: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub global_config: solana_instruction::AccountMeta,
+    pub base_mint: solana_instruction::AccountMeta,
+    pub quote_mint: solana_instruction::AccountMeta,
+    pub user_base_token_account: solana_instruction::AccountMeta,
+    pub user_quote_token_account: solana_instruction::AccountMeta,
+    pub pool_base_token_account: solana_instruction::AccountMeta,
+    pub pool_quote_token_account: solana_instruction::AccountMeta,
+    pub protocol_fee_recipient: solana_instruction::AccountMeta,
+    pub protocol_fee_recipient_token_account: solana_instruction::AccountMeta,
+    pub base_token_program: solana_instruction::AccountMeta,
+    pub quote_token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Buy {
+    type ArrangedAccountMetas = BuyInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [pool, user, global_config, base_mint, quote_mint, user_base_token_account, user_quote_token_account, pool_base_token_account, pool_quote_token_account, protocol_fee_recipient, protocol_fee_recipient_token_account, base_token_program, quote_token_program, system_program, associated_token_program, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(BuyInstructionAccountMetas {
+            pool: pool.clone(),
+            user: user.clone(),
+            global_config: global_config.clone(),
+            base_mint: base_mint.clone(),
+            quote_mint: quote_mint.clone(),
+            user_base_token_account: user_base_token_account.clone(),
+            user_quote_token_account: user_quote_token_account.clone(),
+            pool_base_token_account: pool_base_token_account.clone(),
+            pool_quote_token_account: pool_quote_token_account.clone(),
+            protocol_fee_recipient: protocol_fee_recipient.clone(),
+            protocol_fee_recipient_token_account: protocol_fee_recipient_token_account.clone(),
+            base_token_program: base_token_program.clone(),
+            quote_token_program: quote_token_program.clone(),
+            system_program: system_program.clone(),
+            associated_token_program: associated_token_program.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct BuyInstructionAccountIndices {
+    pub pool: usize,
+    pub user: usize,
+    pub global_config: usize,
+    pub base_mint: usize,
+    pub quote_mint: usize,
+    pub user_base_token_account: usize,
+    pub user_quote_token_account: usize,
+    pub pool_base_token_account: usize,
+    pub pool_quote_token_account: usize,
+    pub protocol_fee_recipient: usize,
+    pub protocol_fee_recipient_token_account: usize,
+    pub base_token_program: usize,
+    pub quote_token_program: usize,
+    pub system_program: usize,
+    pub associated_token_program: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Buy {
+    type ArrangedAccountIndices = BuyInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 17 {
+            return None;
+        }
+
+        Some(BuyInstructionAccountIndices {
+            pool: 0,
+            user: 1,
+            global_config: 2,
+            base_mint: 3,
+            quote_mint: 4,
+            user_base_token_account: 5,
+            user_quote_token_account: 6,
+            pool_base_token_account: 7,
+            pool_quote_token_account: 8,
+            protocol_fee_recipient: 9,
+            protocol_fee_recipient_token_account: 10,
+            base_token_program: 11,
+            quote_token_program: 12,
+            system_program: 13,
+            associated_token_program: 14,
+            event_authority: 15,
+            program: 16,
+            remaining_accounts: 17..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Buy {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "pool",
+            "user",
+            "global_config",
+            "base_mint",
+            "quote_mint",
+            "user_base_token_account",
+            "user_quote_token_account",
+            "pool_base_token_account",
+            "pool_quote_token_account",
+            "protocol_fee_recipient",
+            "protocol_fee_recipient_token_account",
+            "base_token_program",
+            "quote_token_program",
+            "system_program",
+            "associated_token_program",
+            "event_authority",
+            "program",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -17,6 +19,7 @@ pub struct DisableInstructionAccounts {
     pub global_config: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Disable {
@@ -25,7 +28,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Disable {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin, global_config, event_authority, program, _remaining @ ..] = accounts else {
+        let [admin, global_config, event_authority, program, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -34,6 +37,69 @@ impl carbon_core::deserialize::ArrangeAccounts for Disable {
             global_config: global_config.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DisableInstructionAccountMetas {
+    pub admin: solana_instruction::AccountMeta,
+    pub global_config: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Disable {
+    type ArrangedAccountMetas = DisableInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin, global_config, event_authority, program, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(DisableInstructionAccountMetas {
+            admin: admin.clone(),
+            global_config: global_config.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DisableInstructionAccountIndices {
+    pub admin: usize,
+    pub global_config: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Disable {
+    type ArrangedAccountIndices = DisableInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(DisableInstructionAccountIndices {
+            admin: 0,
+            global_config: 1,
+            event_authority: 2,
+            program: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Disable {
+    fn account_names() -> &'static [&'static str] {
+        &["admin", "global_config", "event_authority", "program"]
+    }
+}
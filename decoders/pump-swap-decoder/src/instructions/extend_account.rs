@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +14,7 @@ pub struct ExtendAccountInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ExtendAccount {
@@ -20,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ExtendAccount {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [account, user, system_program, event_authority, program, _remaining @ ..] = accounts
+        let [account, user, system_program, event_authority, program, remaining @ ..] = accounts
         else {
             return None;
         };
@@ -31,6 +34,80 @@ impl carbon_core::deserialize::ArrangeAccounts for ExtendAccount {
             system_program: system_program.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ExtendAccountInstructionAccountMetas {
+    pub account: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ExtendAccount {
+    type ArrangedAccountMetas = ExtendAccountInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [account, user, system_program, event_authority, program, remaining @ ..] = accounts
+        else {
+            return None;
+        };
+
+        Some(ExtendAccountInstructionAccountMetas {
+            account: account.clone(),
+            user: user.clone(),
+            system_program: system_program.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ExtendAccountInstructionAccountIndices {
+    pub account: usize,
+    pub user: usize,
+    pub system_program: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ExtendAccount {
+    type ArrangedAccountIndices = ExtendAccountInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(ExtendAccountInstructionAccountIndices {
+            account: 0,
+            user: 1,
+            system_program: 2,
+            event_authority: 3,
+            program: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ExtendAccount {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "account",
+            "user",
+            "system_program",
+            "event_authority",
+            "program",
+        ]
+    }
+}
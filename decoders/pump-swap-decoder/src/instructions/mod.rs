@@ -58,15 +58,25 @@ pub enum PumpSwapInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for PumpSwapDecoder {
     type InstructionType = PumpSwapInstruction;
 
-    fn decode_instruction(
+    fn decoder_name(&self) -> &'static str {
+        "pump_swap"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[super::PROGRAM_ID]
+    }
+
+    fn try_decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
-    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+    ) -> carbon_core::error::CarbonResult<
+        Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>>,
+    > {
         if !instruction.program_id.eq(&PROGRAM_ID) {
-            return None;
+            return Ok(None);
         }
 
-        carbon_core::try_decode_instructions!(instruction,
+        Ok(carbon_core::try_decode_instructions!(instruction,
             PumpSwapInstruction::Buy => buy::Buy,
             PumpSwapInstruction::CreateConfig => create_config::CreateConfig,
             PumpSwapInstruction::CreatePool => create_pool::CreatePool,
@@ -87,6 +97,6 @@ impl carbon_core::instruction::InstructionDecoder<'_> for PumpSwapDecoder {
             PumpSwapInstruction::UpdateAdminEvent => update_admin_event::UpdateAdminEvent,
             PumpSwapInstruction::UpdateFeeConfigEvent => update_fee_config_event::UpdateFeeConfigEvent,
             PumpSwapInstruction::WithdrawEvent => withdraw_event::WithdrawEvent,
-        )
+        ))
     }
 }
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +14,7 @@ pub struct UpdateAdminInstructionAccounts {
     pub new_admin: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateAdmin {
@@ -20,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateAdmin {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin, global_config, new_admin, event_authority, program, _remaining @ ..] = accounts
+        let [admin, global_config, new_admin, event_authority, program, remaining @ ..] = accounts
         else {
             return None;
         };
@@ -31,6 +34,80 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateAdmin {
             new_admin: new_admin.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateAdminInstructionAccountMetas {
+    pub admin: solana_instruction::AccountMeta,
+    pub global_config: solana_instruction::AccountMeta,
+    pub new_admin: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateAdmin {
+    type ArrangedAccountMetas = UpdateAdminInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin, global_config, new_admin, event_authority, program, remaining @ ..] = accounts
+        else {
+            return None;
+        };
+
+        Some(UpdateAdminInstructionAccountMetas {
+            admin: admin.clone(),
+            global_config: global_config.clone(),
+            new_admin: new_admin.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateAdminInstructionAccountIndices {
+    pub admin: usize,
+    pub global_config: usize,
+    pub new_admin: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateAdmin {
+    type ArrangedAccountIndices = UpdateAdminInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(UpdateAdminInstructionAccountIndices {
+            admin: 0,
+            global_config: 1,
+            new_admin: 2,
+            event_authority: 3,
+            program: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateAdmin {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "admin",
+            "global_config",
+            "new_admin",
+            "event_authority",
+            "program",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -15,6 +17,7 @@ pub struct UpdateFeeConfigInstructionAccounts {
     pub global_config: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateFeeConfig {
@@ -23,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateFeeConfig {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin, global_config, event_authority, program, _remaining @ ..] = accounts else {
+        let [admin, global_config, event_authority, program, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -32,6 +35,69 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateFeeConfig {
             global_config: global_config.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateFeeConfigInstructionAccountMetas {
+    pub admin: solana_instruction::AccountMeta,
+    pub global_config: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateFeeConfig {
+    type ArrangedAccountMetas = UpdateFeeConfigInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin, global_config, event_authority, program, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateFeeConfigInstructionAccountMetas {
+            admin: admin.clone(),
+            global_config: global_config.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateFeeConfigInstructionAccountIndices {
+    pub admin: usize,
+    pub global_config: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateFeeConfig {
+    type ArrangedAccountIndices = UpdateFeeConfigInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(UpdateFeeConfigInstructionAccountIndices {
+            admin: 0,
+            global_config: 1,
+            event_authority: 2,
+            program: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateFeeConfig {
+    fn account_names() -> &'static [&'static str] {
+        &["admin", "global_config", "event_authority", "program"]
+    }
+}
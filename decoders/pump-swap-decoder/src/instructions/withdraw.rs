@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -26,6 +28,7 @@ pub struct WithdrawInstructionAccounts {
     pub token_2022_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Withdraw {
@@ -34,7 +37,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Withdraw {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [pool, global_config, user, base_mint, quote_mint, lp_mint, user_base_token_account, user_quote_token_account, user_pool_token_account, pool_base_token_account, pool_quote_token_account, token_program, token_2022_program, event_authority, program, _remaining @ ..] =
+        let [pool, global_config, user, base_mint, quote_mint, lp_mint, user_base_token_account, user_quote_token_account, user_pool_token_account, pool_base_token_account, pool_quote_token_account, token_program, token_2022_program, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -56,6 +59,131 @@ impl carbon_core::deserialize::ArrangeAccounts for Withdraw {
             token_2022_program: token_2022_program.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct WithdrawInstructionAccountMetas {
+    pub pool: solana_instruction::AccountMeta,
+    pub global_config: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub base_mint: solana_instruction::AccountMeta,
+    pub quote_mint: solana_instruction::AccountMeta,
+    pub lp_mint: solana_instruction::AccountMeta,
+    pub user_base_token_account: solana_instruction::AccountMeta,
+    pub user_quote_token_account: solana_instruction::AccountMeta,
+    pub user_pool_token_account: solana_instruction::AccountMeta,
+    pub pool_base_token_account: solana_instruction::AccountMeta,
+    pub pool_quote_token_account: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub token_2022_program: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Withdraw {
+    type ArrangedAccountMetas = WithdrawInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [pool, global_config, user, base_mint, quote_mint, lp_mint, user_base_token_account, user_quote_token_account, user_pool_token_account, pool_base_token_account, pool_quote_token_account, token_program, token_2022_program, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(WithdrawInstructionAccountMetas {
+            pool: pool.clone(),
+            global_config: global_config.clone(),
+            user: user.clone(),
+            base_mint: base_mint.clone(),
+            quote_mint: quote_mint.clone(),
+            lp_mint: lp_mint.clone(),
+            user_base_token_account: user_base_token_account.clone(),
+            user_quote_token_account: user_quote_token_account.clone(),
+            user_pool_token_account: user_pool_token_account.clone(),
+            pool_base_token_account: pool_base_token_account.clone(),
+            pool_quote_token_account: pool_quote_token_account.clone(),
+            token_program: token_program.clone(),
+            token_2022_program: token_2022_program.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct WithdrawInstructionAccountIndices {
+    pub pool: usize,
+    pub global_config: usize,
+    pub user: usize,
+    pub base_mint: usize,
+    pub quote_mint: usize,
+    pub lp_mint: usize,
+    pub user_base_token_account: usize,
+    pub user_quote_token_account: usize,
+    pub user_pool_token_account: usize,
+    pub pool_base_token_account: usize,
+    pub pool_quote_token_account: usize,
+    pub token_program: usize,
+    pub token_2022_program: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Withdraw {
+    type ArrangedAccountIndices = WithdrawInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 15 {
+            return None;
+        }
+
+        Some(WithdrawInstructionAccountIndices {
+            pool: 0,
+            global_config: 1,
+            user: 2,
+            base_mint: 3,
+            quote_mint: 4,
+            lp_mint: 5,
+            user_base_token_account: 6,
+            user_quote_token_account: 7,
+            user_pool_token_account: 8,
+            pool_base_token_account: 9,
+            pool_quote_token_account: 10,
+            token_program: 11,
+            token_2022_program: 12,
+            event_authority: 13,
+            program: 14,
+            remaining_accounts: 15..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Withdraw {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "pool",
+            "global_config",
+            "user",
+            "base_mint",
+            "quote_mint",
+            "lp_mint",
+            "user_base_token_account",
+            "user_quote_token_account",
+            "user_pool_token_account",
+            "pool_base_token_account",
+            "pool_quote_token_account",
+            "token_program",
+            "token_2022_program",
+            "event_authority",
+            "program",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -23,6 +25,7 @@ pub struct BuyInstructionAccounts {
     pub rent: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Buy {
@@ -31,7 +34,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Buy {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [global, fee_recipient, mint, bonding_curve, associated_bonding_curve, associated_user, user, system_program, token_program, rent, event_authority, program, _remaining @ ..] =
+        let [global, fee_recipient, mint, bonding_curve, associated_bonding_curve, associated_user, user, system_program, token_program, rent, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -50,6 +53,118 @@ impl carbon_core::deserialize::ArrangeAccounts for Buy {
             rent: rent.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+#[derive(Debug, PartialEq)]
+pub struct BuyInstructionAccountMetas {
+    pub global: solana_instruction::AccountMeta,
+    pub fee_recipient: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub bonding_curve: solana_instruction::AccountMeta,
+    pub associated_bonding_curve: solana_instruction::AccountMeta,
+    pub associated_user: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Buy {
+    type ArrangedAccountMetas = BuyInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [global, fee_recipient, mint, bonding_curve, associated_bonding_curve, associated_user, user, system_program, token_program, rent, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(BuyInstructionAccountMetas {
+            global: global.clone(),
+            fee_recipient: fee_recipient.clone(),
+            mint: mint.clone(),
+            bonding_curve: bonding_curve.clone(),
+            associated_bonding_curve: associated_bonding_curve.clone(),
+            associated_user: associated_user.clone(),
+            user: user.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            rent: rent.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct BuyInstructionAccountIndices {
+    pub global: usize,
+    pub fee_recipient: usize,
+    pub mint: usize,
+    pub bonding_curve: usize,
+    pub associated_bonding_curve: usize,
+    pub associated_user: usize,
+    pub user: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub rent: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Buy {
+    type ArrangedAccountIndices = BuyInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 12 {
+            return None;
+        }
+
+        Some(BuyInstructionAccountIndices {
+            global: 0,
+            fee_recipient: 1,
+            mint: 2,
+            bonding_curve: 3,
+            associated_bonding_curve: 4,
+            associated_user: 5,
+            user: 6,
+            system_program: 7,
+            token_program: 8,
+            rent: 9,
+            event_authority: 10,
+            program: 11,
+            remaining_accounts: 12..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Buy {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "global",
+            "fee_recipient",
+            "mint",
+            "bonding_curve",
+            "associated_bonding_curve",
+            "associated_user",
+            "user",
+            "system_program",
+            "token_program",
+            "rent",
+            "event_authority",
+            "program",
+        ]
+    }
+}
@@ -1,6 +1,8 @@
 use alloc::string::String;
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -28,6 +30,7 @@ pub struct CreateInstructionAccounts {
     pub rent: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Create {
@@ -36,7 +39,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Create {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [mint, mint_authority, bonding_curve, associated_bonding_curve, global, mpl_token_metadata, metadata, user, system_program, token_program, associated_token_program, rent, event_authority, program, _remaining @ ..] =
+        let [mint, mint_authority, bonding_curve, associated_bonding_curve, global, mpl_token_metadata, metadata, user, system_program, token_program, associated_token_program, rent, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -57,6 +60,128 @@ impl carbon_core::deserialize::ArrangeAccounts for Create {
             rent: rent.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+#[derive(Debug, PartialEq)]
+pub struct CreateInstructionAccountMetas {
+    pub mint: solana_instruction::AccountMeta,
+    pub mint_authority: solana_instruction::AccountMeta,
+    pub bonding_curve: solana_instruction::AccountMeta,
+    pub associated_bonding_curve: solana_instruction::AccountMeta,
+    pub global: solana_instruction::AccountMeta,
+    pub mpl_token_metadata: solana_instruction::AccountMeta,
+    pub metadata: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Create {
+    type ArrangedAccountMetas = CreateInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [mint, mint_authority, bonding_curve, associated_bonding_curve, global, mpl_token_metadata, metadata, user, system_program, token_program, associated_token_program, rent, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CreateInstructionAccountMetas {
+            mint: mint.clone(),
+            mint_authority: mint_authority.clone(),
+            bonding_curve: bonding_curve.clone(),
+            associated_bonding_curve: associated_bonding_curve.clone(),
+            global: global.clone(),
+            mpl_token_metadata: mpl_token_metadata.clone(),
+            metadata: metadata.clone(),
+            user: user.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            associated_token_program: associated_token_program.clone(),
+            rent: rent.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct CreateInstructionAccountIndices {
+    pub mint: usize,
+    pub mint_authority: usize,
+    pub bonding_curve: usize,
+    pub associated_bonding_curve: usize,
+    pub global: usize,
+    pub mpl_token_metadata: usize,
+    pub metadata: usize,
+    pub user: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub associated_token_program: usize,
+    pub rent: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Create {
+    type ArrangedAccountIndices = CreateInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 14 {
+            return None;
+        }
+
+        Some(CreateInstructionAccountIndices {
+            mint: 0,
+            mint_authority: 1,
+            bonding_curve: 2,
+            associated_bonding_curve: 3,
+            global: 4,
+            mpl_token_metadata: 5,
+            metadata: 6,
+            user: 7,
+            system_program: 8,
+            token_program: 9,
+            associated_token_program: 10,
+            rent: 11,
+            event_authority: 12,
+            program: 13,
+            remaining_accounts: 14..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Create {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "mint",
+            "mint_authority",
+            "bonding_curve",
+            "associated_bonding_curve",
+            "global",
+            "mpl_token_metadata",
+            "metadata",
+            "user",
+            "system_program",
+            "token_program",
+            "associated_token_program",
+            "rent",
+            "event_authority",
+            "program",
+        ]
+    }
+}
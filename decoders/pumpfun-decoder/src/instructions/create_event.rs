@@ -1,6 +1,7 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
 use alloc::string::String;
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
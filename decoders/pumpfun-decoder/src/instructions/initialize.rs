@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +13,7 @@ pub struct InitializeInstructionAccounts {
     pub global: solana_pubkey::Pubkey,
     pub user: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Initialize {
@@ -19,7 +22,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Initialize {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [global, user, system_program, _remaining @ ..] = accounts else {
+        let [global, user, system_program, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -27,6 +30,67 @@ impl carbon_core::deserialize::ArrangeAccounts for Initialize {
             global: global.pubkey,
             user: user.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+#[derive(Debug, PartialEq)]
+pub struct InitializeInstructionAccountMetas {
+    pub global: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Initialize {
+    type ArrangedAccountMetas = InitializeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [global, user, system_program, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(InitializeInstructionAccountMetas {
+            global: global.clone(),
+            user: user.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct InitializeInstructionAccountIndices {
+    pub global: usize,
+    pub user: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Initialize {
+    type ArrangedAccountIndices = InitializeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(InitializeInstructionAccountIndices {
+            global: 0,
+            user: 1,
+            system_program: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Initialize {
+    fn account_names() -> &'static [&'static str] {
+        &["global", "user", "system_program"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -32,6 +34,7 @@ pub struct MigrateInstructionAccounts {
     pub pump_amm_event_authority: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Migrate {
@@ -40,7 +43,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Migrate {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [global, withdraw_authority, mint, bonding_curve, associated_bonding_curve, user, system_program, token_program, pump_amm, pool, pool_authority, pool_authority_mint_account, pool_authority_wsol_account, amm_global_config, wsol_mint, lp_mint, user_pool_token_account, pool_base_token_account, pool_quote_token_account, token_2022_program, associated_token_program, pump_amm_event_authority, event_authority, program, _remaining @ ..] =
+        let [global, withdraw_authority, mint, bonding_curve, associated_bonding_curve, user, system_program, token_program, pump_amm, pool, pool_authority, pool_authority_mint_account, pool_authority_wsol_account, amm_global_config, wsol_mint, lp_mint, user_pool_token_account, pool_base_token_account, pool_quote_token_account, token_2022_program, associated_token_program, pump_amm_event_authority, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -71,6 +74,178 @@ impl carbon_core::deserialize::ArrangeAccounts for Migrate {
             pump_amm_event_authority: pump_amm_event_authority.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+#[derive(Debug, PartialEq)]
+pub struct MigrateInstructionAccountMetas {
+    pub global: solana_instruction::AccountMeta,
+    pub withdraw_authority: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub bonding_curve: solana_instruction::AccountMeta,
+    pub associated_bonding_curve: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub pump_amm: solana_instruction::AccountMeta,
+    pub pool: solana_instruction::AccountMeta,
+    pub pool_authority: solana_instruction::AccountMeta,
+    pub pool_authority_mint_account: solana_instruction::AccountMeta,
+    pub pool_authority_wsol_account: solana_instruction::AccountMeta,
+    pub amm_global_config: solana_instruction::AccountMeta,
+    pub wsol_mint: solana_instruction::AccountMeta,
+    pub lp_mint: solana_instruction::AccountMeta,
+    pub user_pool_token_account: solana_instruction::AccountMeta,
+    pub pool_base_token_account: solana_instruction::AccountMeta,
+    pub pool_quote_token_account: solana_instruction::AccountMeta,
+    pub token_2022_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub pump_amm_event_authority: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Migrate {
+    type ArrangedAccountMetas = MigrateInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [global, withdraw_authority, mint, bonding_curve, associated_bonding_curve, user, system_program, token_program, pump_amm, pool, pool_authority, pool_authority_mint_account, pool_authority_wsol_account, amm_global_config, wsol_mint, lp_mint, user_pool_token_account, pool_base_token_account, pool_quote_token_account, token_2022_program, associated_token_program, pump_amm_event_authority, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(MigrateInstructionAccountMetas {
+            global: global.clone(),
+            withdraw_authority: withdraw_authority.clone(),
+            mint: mint.clone(),
+            bonding_curve: bonding_curve.clone(),
+            associated_bonding_curve: associated_bonding_curve.clone(),
+            user: user.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            pump_amm: pump_amm.clone(),
+            pool: pool.clone(),
+            pool_authority: pool_authority.clone(),
+            pool_authority_mint_account: pool_authority_mint_account.clone(),
+            pool_authority_wsol_account: pool_authority_wsol_account.clone(),
+            amm_global_config: amm_global_config.clone(),
+            wsol_mint: wsol_mint.clone(),
+            lp_mint: lp_mint.clone(),
+            user_pool_token_account: user_pool_token_account.clone(),
+            pool_base_token_account: pool_base_token_account.clone(),
+            pool_quote_token_account: pool_quote_token_account.clone(),
+            token_2022_program: token_2022_program.clone(),
+            associated_token_program: associated_token_program.clone(),
+            pump_amm_event_authority: pump_amm_event_authority.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct MigrateInstructionAccountIndices {
+    pub global: usize,
+    pub withdraw_authority: usize,
+    pub mint: usize,
+    pub bonding_curve: usize,
+    pub associated_bonding_curve: usize,
+    pub user: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub pump_amm: usize,
+    pub pool: usize,
+    pub pool_authority: usize,
+    pub pool_authority_mint_account: usize,
+    pub pool_authority_wsol_account: usize,
+    pub amm_global_config: usize,
+    pub wsol_mint: usize,
+    pub lp_mint: usize,
+    pub user_pool_token_account: usize,
+    pub pool_base_token_account: usize,
+    pub pool_quote_token_account: usize,
+    pub token_2022_program: usize,
+    pub associated_token_program: usize,
+    pub pump_amm_event_authority: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Migrate {
+    type ArrangedAccountIndices = MigrateInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 24 {
+            return None;
+        }
+
+        Some(MigrateInstructionAccountIndices {
+            global: 0,
+            withdraw_authority: 1,
+            mint: 2,
+            bonding_curve: 3,
+            associated_bonding_curve: 4,
+            user: 5,
+            system_program: 6,
+            token_program: 7,
+            pump_amm: 8,
+            pool: 9,
+            pool_authority: 10,
+            pool_authority_mint_account: 11,
+            pool_authority_wsol_account: 12,
+            amm_global_config: 13,
+            wsol_mint: 14,
+            lp_mint: 15,
+            user_pool_token_account: 16,
+            pool_base_token_account: 17,
+            pool_quote_token_account: 18,
+            token_2022_program: 19,
+            associated_token_program: 20,
+            pump_amm_event_authority: 21,
+            event_authority: 22,
+            program: 23,
+            remaining_accounts: 24..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Migrate {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "global",
+            "withdraw_authority",
+            "mint",
+            "bonding_curve",
+            "associated_bonding_curve",
+            "user",
+            "system_program",
+            "token_program",
+            "pump_amm",
+            "pool",
+            "pool_authority",
+            "pool_authority_mint_account",
+            "pool_authority_wsol_account",
+            "amm_global_config",
+            "wsol_mint",
+            "lp_mint",
+            "user_pool_token_account",
+            "pool_base_token_account",
+            "pool_quote_token_account",
+            "token_2022_program",
+            "associated_token_program",
+            "pump_amm_event_authority",
+            "event_authority",
+            "program",
+        ]
+    }
+}
@@ -50,15 +50,25 @@ pub enum PumpfunInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for PumpfunDecoder {
     type InstructionType = PumpfunInstruction;
 
-    fn decode_instruction(
+    fn decoder_name(&self) -> &'static str {
+        "pumpfun"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[super::PROGRAM_ID]
+    }
+
+    fn try_decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
-    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+    ) -> carbon_core::error::CarbonResult<
+        Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>>,
+    > {
         if !instruction.program_id.eq(&PROGRAM_ID) {
-            return None;
+            return Ok(None);
         }
 
-        carbon_core::try_decode_instructions!(instruction,
+        Ok(carbon_core::try_decode_instructions!(instruction,
             PumpfunInstruction::Buy => buy::Buy,
             PumpfunInstruction::Create => create::Create,
             PumpfunInstruction::ExtendAccount => extend_account::ExtendAccount,
@@ -75,7 +85,7 @@ impl carbon_core::instruction::InstructionDecoder<'_> for PumpfunDecoder {
             PumpfunInstruction::SetParamsEvent => set_params_event::SetParamsEvent,
             PumpfunInstruction::TradeEvent => trade_event::TradeEvent,
             PumpfunInstruction::UpdateGlobalAuthorityEvent => update_global_authority_event::UpdateGlobalAuthorityEvent,
-        )
+        ))
     }
 }
 
@@ -160,6 +170,7 @@ mod tests {
             rent: Pubkey::from_str_const("SysvarRent111111111111111111111111111111111"),
             event_authority: Pubkey::from_str_const("Ce6TQqeHC9p8KetsN6JsjHK7UTZk7nasjjnr7XxXp9F1"),
             program: Pubkey::from_str_const("6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P"),
+            remaining_accounts: vec![],
         };
 
         // Act
@@ -253,6 +264,7 @@ mod tests {
             token_program: Pubkey::from_str_const("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
             event_authority: Pubkey::from_str_const("Ce6TQqeHC9p8KetsN6JsjHK7UTZk7nasjjnr7XxXp9F1"),
             program: Pubkey::from_str_const("6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P"),
+            remaining_accounts: vec![],
         };
 
         // Act
@@ -360,6 +372,7 @@ mod tests {
             rent: Pubkey::from_str_const("SysvarRent111111111111111111111111111111111"),
             event_authority: Pubkey::from_str_const("Ce6TQqeHC9p8KetsN6JsjHK7UTZk7nasjjnr7XxXp9F1"),
             program: Pubkey::from_str_const("6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P"),
+            remaining_accounts: vec![],
         };
 
         // Act
@@ -448,6 +461,7 @@ mod tests {
             rent: Pubkey::from_str_const("SysvarRent111111111111111111111111111111111"),
             event_authority: Pubkey::from_str_const("Ce6TQqeHC9p8KetsN6JsjHK7UTZk7nasjjnr7XxXp9F1"),
             program: Pubkey::from_str_const("6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P"),
+            remaining_accounts: vec![],
         };
 
         // Act
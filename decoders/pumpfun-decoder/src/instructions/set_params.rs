@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -22,6 +24,7 @@ pub struct SetParamsInstructionAccounts {
     pub authority: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SetParams {
@@ -30,7 +33,7 @@ impl carbon_core::deserialize::ArrangeAccounts for SetParams {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [global, authority, event_authority, program, _remaining @ ..] = accounts else {
+        let [global, authority, event_authority, program, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -39,6 +42,71 @@ impl carbon_core::deserialize::ArrangeAccounts for SetParams {
             authority: authority.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+#[derive(Debug, PartialEq)]
+pub struct SetParamsInstructionAccountMetas {
+    pub global: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SetParams {
+    type ArrangedAccountMetas = SetParamsInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [global, authority, event_authority, program, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(SetParamsInstructionAccountMetas {
+            global: global.clone(),
+            authority: authority.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct SetParamsInstructionAccountIndices {
+    pub global: usize,
+    pub authority: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SetParams {
+    type ArrangedAccountIndices = SetParamsInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(SetParamsInstructionAccountIndices {
+            global: 0,
+            authority: 1,
+            event_authority: 2,
+            program: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SetParams {
+    fn account_names() -> &'static [&'static str] {
+        &["global", "authority", "event_authority", "program"]
+    }
+}
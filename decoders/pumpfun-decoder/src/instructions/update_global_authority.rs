@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +15,7 @@ pub struct UpdateGlobalAuthorityInstructionAccounts {
     pub new_authority: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateGlobalAuthority {
@@ -21,8 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateGlobalAuthority {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [global, authority, new_authority, event_authority, program, _remaining @ ..] =
-            accounts
+        let [global, authority, new_authority, event_authority, program, remaining @ ..] = accounts
         else {
             return None;
         };
@@ -33,6 +35,82 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateGlobalAuthority {
             new_authority: new_authority.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+#[derive(Debug, PartialEq)]
+pub struct UpdateGlobalAuthorityInstructionAccountMetas {
+    pub global: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub new_authority: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateGlobalAuthority {
+    type ArrangedAccountMetas = UpdateGlobalAuthorityInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [global, authority, new_authority, event_authority, program, remaining @ ..] = accounts
+        else {
+            return None;
+        };
+
+        Some(UpdateGlobalAuthorityInstructionAccountMetas {
+            global: global.clone(),
+            authority: authority.clone(),
+            new_authority: new_authority.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct UpdateGlobalAuthorityInstructionAccountIndices {
+    pub global: usize,
+    pub authority: usize,
+    pub new_authority: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateGlobalAuthority {
+    type ArrangedAccountIndices = UpdateGlobalAuthorityInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(UpdateGlobalAuthorityInstructionAccountIndices {
+            global: 0,
+            authority: 1,
+            new_authority: 2,
+            event_authority: 3,
+            program: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateGlobalAuthority {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "global",
+            "authority",
+            "new_authority",
+            "event_authority",
+            "program",
+        ]
+    }
+}
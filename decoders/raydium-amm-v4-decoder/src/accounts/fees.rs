@@ -2,6 +2,7 @@ use carbon_core::{borsh, CarbonDeserialize};
 
 pub const FEES_SIZE: usize = core::mem::size_of::<Fees>();
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Deserialize, serde::Serialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -26,6 +28,7 @@ pub struct AdminCancelOrdersInstructionAccounts {
     pub serum_event_q: solana_pubkey::Pubkey,
     pub serum_bids: solana_pubkey::Pubkey,
     pub serum_asks: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for AdminCancelOrders {
@@ -34,7 +37,7 @@ impl carbon_core::deserialize::ArrangeAccounts for AdminCancelOrders {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [token_program, amm, amm_authority, amm_open_orders, amm_target_orders, pool_coin_token_account, pool_pc_token_account, amm_owner_account, amm_config, serum_program, serum_market, serum_coin_vault_account, serum_pc_vault_account, serum_vault_signer, serum_event_q, serum_bids, serum_asks, _remaining @ ..] =
+        let [token_program, amm, amm_authority, amm_open_orders, amm_target_orders, pool_coin_token_account, pool_pc_token_account, amm_owner_account, amm_config, serum_program, serum_market, serum_coin_vault_account, serum_pc_vault_account, serum_vault_signer, serum_event_q, serum_bids, serum_asks, remaining @ ..] =
             accounts
         else {
             return None;
@@ -58,6 +61,141 @@ impl carbon_core::deserialize::ArrangeAccounts for AdminCancelOrders {
             serum_event_q: serum_event_q.pubkey,
             serum_bids: serum_bids.pubkey,
             serum_asks: serum_asks.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct AdminCancelOrdersInstructionAccountMetas {
+    pub token_program: solana_instruction::AccountMeta,
+    pub amm: solana_instruction::AccountMeta,
+    pub amm_authority: solana_instruction::AccountMeta,
+    pub amm_open_orders: solana_instruction::AccountMeta,
+    pub amm_target_orders: solana_instruction::AccountMeta,
+    pub pool_coin_token_account: solana_instruction::AccountMeta,
+    pub pool_pc_token_account: solana_instruction::AccountMeta,
+    pub amm_owner_account: solana_instruction::AccountMeta,
+    pub amm_config: solana_instruction::AccountMeta,
+    pub serum_program: solana_instruction::AccountMeta,
+    pub serum_market: solana_instruction::AccountMeta,
+    pub serum_coin_vault_account: solana_instruction::AccountMeta,
+    pub serum_pc_vault_account: solana_instruction::AccountMeta,
+    pub serum_vault_signer: solana_instruction::AccountMeta,
+    pub serum_event_q: solana_instruction::AccountMeta,
+    pub serum_bids: solana_instruction::AccountMeta,
+    pub serum_asks: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for AdminCancelOrders {
+    type ArrangedAccountMetas = AdminCancelOrdersInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [token_program, amm, amm_authority, amm_open_orders, amm_target_orders, pool_coin_token_account, pool_pc_token_account, amm_owner_account, amm_config, serum_program, serum_market, serum_coin_vault_account, serum_pc_vault_account, serum_vault_signer, serum_event_q, serum_bids, serum_asks, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(AdminCancelOrdersInstructionAccountMetas {
+            token_program: token_program.clone(),
+            amm: amm.clone(),
+            amm_authority: amm_authority.clone(),
+            amm_open_orders: amm_open_orders.clone(),
+            amm_target_orders: amm_target_orders.clone(),
+            pool_coin_token_account: pool_coin_token_account.clone(),
+            pool_pc_token_account: pool_pc_token_account.clone(),
+            amm_owner_account: amm_owner_account.clone(),
+            amm_config: amm_config.clone(),
+            serum_program: serum_program.clone(),
+            serum_market: serum_market.clone(),
+            serum_coin_vault_account: serum_coin_vault_account.clone(),
+            serum_pc_vault_account: serum_pc_vault_account.clone(),
+            serum_vault_signer: serum_vault_signer.clone(),
+            serum_event_q: serum_event_q.clone(),
+            serum_bids: serum_bids.clone(),
+            serum_asks: serum_asks.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct AdminCancelOrdersInstructionAccountIndices {
+    pub token_program: usize,
+    pub amm: usize,
+    pub amm_authority: usize,
+    pub amm_open_orders: usize,
+    pub amm_target_orders: usize,
+    pub pool_coin_token_account: usize,
+    pub pool_pc_token_account: usize,
+    pub amm_owner_account: usize,
+    pub amm_config: usize,
+    pub serum_program: usize,
+    pub serum_market: usize,
+    pub serum_coin_vault_account: usize,
+    pub serum_pc_vault_account: usize,
+    pub serum_vault_signer: usize,
+    pub serum_event_q: usize,
+    pub serum_bids: usize,
+    pub serum_asks: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for AdminCancelOrders {
+    type ArrangedAccountIndices = AdminCancelOrdersInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 17 {
+            return None;
+        }
+
+        Some(AdminCancelOrdersInstructionAccountIndices {
+            token_program: 0,
+            amm: 1,
+            amm_authority: 2,
+            amm_open_orders: 3,
+            amm_target_orders: 4,
+            pool_coin_token_account: 5,
+            pool_pc_token_account: 6,
+            amm_owner_account: 7,
+            amm_config: 8,
+            serum_program: 9,
+            serum_market: 10,
+            serum_coin_vault_account: 11,
+            serum_pc_vault_account: 12,
+            serum_vault_signer: 13,
+            serum_event_q: 14,
+            serum_bids: 15,
+            serum_asks: 16,
+            remaining_accounts: 17..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for AdminCancelOrders {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "token_program",
+            "amm",
+            "amm_authority",
+            "amm_open_orders",
+            "amm_target_orders",
+            "pool_coin_token_account",
+            "pool_pc_token_account",
+            "amm_owner_account",
+            "amm_config",
+            "serum_program",
+            "serum_market",
+            "serum_coin_vault_account",
+            "serum_pc_vault_account",
+            "serum_vault_signer",
+            "serum_event_q",
+            "serum_bids",
+            "serum_asks",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +14,7 @@ pub struct CreateConfigAccountInstructionAccounts {
     pub owner: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CreateConfigAccount {
@@ -20,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateConfigAccount {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin, amm_config, owner, system_program, rent, _remaining @ ..] = accounts else {
+        let [admin, amm_config, owner, system_program, rent, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -30,6 +33,73 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateConfigAccount {
             owner: owner.pubkey,
             system_program: system_program.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CreateConfigAccountInstructionAccountMetas {
+    pub admin: solana_instruction::AccountMeta,
+    pub amm_config: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CreateConfigAccount {
+    type ArrangedAccountMetas = CreateConfigAccountInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin, amm_config, owner, system_program, rent, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(CreateConfigAccountInstructionAccountMetas {
+            admin: admin.clone(),
+            amm_config: amm_config.clone(),
+            owner: owner.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CreateConfigAccountInstructionAccountIndices {
+    pub admin: usize,
+    pub amm_config: usize,
+    pub owner: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CreateConfigAccount {
+    type ArrangedAccountIndices = CreateConfigAccountInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(CreateConfigAccountInstructionAccountIndices {
+            admin: 0,
+            amm_config: 1,
+            owner: 2,
+            system_program: 3,
+            rent: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CreateConfigAccount {
+    fn account_names() -> &'static [&'static str] {
+        &["admin", "amm_config", "owner", "system_program", "rent"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -25,6 +27,7 @@ pub struct DepositInstructionAccounts {
     pub user_lp_token_account: solana_pubkey::Pubkey,
     pub user_owner: solana_pubkey::Pubkey,
     pub serum_event_queue: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Deposit {
@@ -33,7 +36,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Deposit {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [token_program, amm, amm_authority, amm_open_orders, amm_target_orders, lp_mint_address, pool_coin_token_account, pool_pc_token_account, serum_market, user_coin_token_account, user_pc_token_account, user_lp_token_account, user_owner, serum_event_queue, _remaining @ ..] =
+        let [token_program, amm, amm_authority, amm_open_orders, amm_target_orders, lp_mint_address, pool_coin_token_account, pool_pc_token_account, serum_market, user_coin_token_account, user_pc_token_account, user_lp_token_account, user_owner, serum_event_queue, remaining @ ..] =
             accounts
         else {
             return None;
@@ -54,6 +57,126 @@ impl carbon_core::deserialize::ArrangeAccounts for Deposit {
             user_lp_token_account: user_lp_token_account.pubkey,
             user_owner: user_owner.pubkey,
             serum_event_queue: serum_event_queue.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DepositInstructionAccountMetas {
+    pub token_program: solana_instruction::AccountMeta,
+    pub amm: solana_instruction::AccountMeta,
+    pub amm_authority: solana_instruction::AccountMeta,
+    pub amm_open_orders: solana_instruction::AccountMeta,
+    pub amm_target_orders: solana_instruction::AccountMeta,
+    pub lp_mint_address: solana_instruction::AccountMeta,
+    pub pool_coin_token_account: solana_instruction::AccountMeta,
+    pub pool_pc_token_account: solana_instruction::AccountMeta,
+    pub serum_market: solana_instruction::AccountMeta,
+    pub user_coin_token_account: solana_instruction::AccountMeta,
+    pub user_pc_token_account: solana_instruction::AccountMeta,
+    pub user_lp_token_account: solana_instruction::AccountMeta,
+    pub user_owner: solana_instruction::AccountMeta,
+    pub serum_event_queue: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Deposit {
+    type ArrangedAccountMetas = DepositInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [token_program, amm, amm_authority, amm_open_orders, amm_target_orders, lp_mint_address, pool_coin_token_account, pool_pc_token_account, serum_market, user_coin_token_account, user_pc_token_account, user_lp_token_account, user_owner, serum_event_queue, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(DepositInstructionAccountMetas {
+            token_program: token_program.clone(),
+            amm: amm.clone(),
+            amm_authority: amm_authority.clone(),
+            amm_open_orders: amm_open_orders.clone(),
+            amm_target_orders: amm_target_orders.clone(),
+            lp_mint_address: lp_mint_address.clone(),
+            pool_coin_token_account: pool_coin_token_account.clone(),
+            pool_pc_token_account: pool_pc_token_account.clone(),
+            serum_market: serum_market.clone(),
+            user_coin_token_account: user_coin_token_account.clone(),
+            user_pc_token_account: user_pc_token_account.clone(),
+            user_lp_token_account: user_lp_token_account.clone(),
+            user_owner: user_owner.clone(),
+            serum_event_queue: serum_event_queue.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DepositInstructionAccountIndices {
+    pub token_program: usize,
+    pub amm: usize,
+    pub amm_authority: usize,
+    pub amm_open_orders: usize,
+    pub amm_target_orders: usize,
+    pub lp_mint_address: usize,
+    pub pool_coin_token_account: usize,
+    pub pool_pc_token_account: usize,
+    pub serum_market: usize,
+    pub user_coin_token_account: usize,
+    pub user_pc_token_account: usize,
+    pub user_lp_token_account: usize,
+    pub user_owner: usize,
+    pub serum_event_queue: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Deposit {
+    type ArrangedAccountIndices = DepositInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 14 {
+            return None;
+        }
+
+        Some(DepositInstructionAccountIndices {
+            token_program: 0,
+            amm: 1,
+            amm_authority: 2,
+            amm_open_orders: 3,
+            amm_target_orders: 4,
+            lp_mint_address: 5,
+            pool_coin_token_account: 6,
+            pool_pc_token_account: 7,
+            serum_market: 8,
+            user_coin_token_account: 9,
+            user_pc_token_account: 10,
+            user_lp_token_account: 11,
+            user_owner: 12,
+            serum_event_queue: 13,
+            remaining_accounts: 14..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Deposit {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "token_program",
+            "amm",
+            "amm_authority",
+            "amm_open_orders",
+            "amm_target_orders",
+            "lp_mint_address",
+            "pool_coin_token_account",
+            "pool_pc_token_account",
+            "serum_market",
+            "user_coin_token_account",
+            "user_pc_token_account",
+            "user_lp_token_account",
+            "user_owner",
+            "serum_event_queue",
+        ]
+    }
+}
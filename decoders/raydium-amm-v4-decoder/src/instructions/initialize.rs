@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -28,6 +30,7 @@ pub struct InitializeInstructionAccounts {
     pub serum_program: solana_pubkey::Pubkey,
     pub serum_market: solana_pubkey::Pubkey,
     pub user_wallet: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Initialize {
@@ -36,7 +39,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Initialize {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [token_program, system_program, rent, amm, amm_authority, amm_open_orders, lp_mint_address, coin_mint_address, pc_mint_address, pool_coin_token_account, pool_pc_token_account, pool_withdraw_queue, pool_target_orders_account, user_lp_token_account, pool_temp_lp_token_account, serum_program, serum_market, user_wallet, _remaining @ ..] =
+        let [token_program, system_program, rent, amm, amm_authority, amm_open_orders, lp_mint_address, coin_mint_address, pc_mint_address, pool_coin_token_account, pool_pc_token_account, pool_withdraw_queue, pool_target_orders_account, user_lp_token_account, pool_temp_lp_token_account, serum_program, serum_market, user_wallet, remaining @ ..] =
             accounts
         else {
             return None;
@@ -61,6 +64,146 @@ impl carbon_core::deserialize::ArrangeAccounts for Initialize {
             serum_program: serum_program.pubkey,
             serum_market: serum_market.pubkey,
             user_wallet: user_wallet.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeInstructionAccountMetas {
+    pub token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub amm: solana_instruction::AccountMeta,
+    pub amm_authority: solana_instruction::AccountMeta,
+    pub amm_open_orders: solana_instruction::AccountMeta,
+    pub lp_mint_address: solana_instruction::AccountMeta,
+    pub coin_mint_address: solana_instruction::AccountMeta,
+    pub pc_mint_address: solana_instruction::AccountMeta,
+    pub pool_coin_token_account: solana_instruction::AccountMeta,
+    pub pool_pc_token_account: solana_instruction::AccountMeta,
+    pub pool_withdraw_queue: solana_instruction::AccountMeta,
+    pub pool_target_orders_account: solana_instruction::AccountMeta,
+    pub user_lp_token_account: solana_instruction::AccountMeta,
+    pub pool_temp_lp_token_account: solana_instruction::AccountMeta,
+    pub serum_program: solana_instruction::AccountMeta,
+    pub serum_market: solana_instruction::AccountMeta,
+    pub user_wallet: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Initialize {
+    type ArrangedAccountMetas = InitializeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [token_program, system_program, rent, amm, amm_authority, amm_open_orders, lp_mint_address, coin_mint_address, pc_mint_address, pool_coin_token_account, pool_pc_token_account, pool_withdraw_queue, pool_target_orders_account, user_lp_token_account, pool_temp_lp_token_account, serum_program, serum_market, user_wallet, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeInstructionAccountMetas {
+            token_program: token_program.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            amm: amm.clone(),
+            amm_authority: amm_authority.clone(),
+            amm_open_orders: amm_open_orders.clone(),
+            lp_mint_address: lp_mint_address.clone(),
+            coin_mint_address: coin_mint_address.clone(),
+            pc_mint_address: pc_mint_address.clone(),
+            pool_coin_token_account: pool_coin_token_account.clone(),
+            pool_pc_token_account: pool_pc_token_account.clone(),
+            pool_withdraw_queue: pool_withdraw_queue.clone(),
+            pool_target_orders_account: pool_target_orders_account.clone(),
+            user_lp_token_account: user_lp_token_account.clone(),
+            pool_temp_lp_token_account: pool_temp_lp_token_account.clone(),
+            serum_program: serum_program.clone(),
+            serum_market: serum_market.clone(),
+            user_wallet: user_wallet.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeInstructionAccountIndices {
+    pub token_program: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub amm: usize,
+    pub amm_authority: usize,
+    pub amm_open_orders: usize,
+    pub lp_mint_address: usize,
+    pub coin_mint_address: usize,
+    pub pc_mint_address: usize,
+    pub pool_coin_token_account: usize,
+    pub pool_pc_token_account: usize,
+    pub pool_withdraw_queue: usize,
+    pub pool_target_orders_account: usize,
+    pub user_lp_token_account: usize,
+    pub pool_temp_lp_token_account: usize,
+    pub serum_program: usize,
+    pub serum_market: usize,
+    pub user_wallet: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Initialize {
+    type ArrangedAccountIndices = InitializeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 18 {
+            return None;
+        }
+
+        Some(InitializeInstructionAccountIndices {
+            token_program: 0,
+            system_program: 1,
+            rent: 2,
+            amm: 3,
+            amm_authority: 4,
+            amm_open_orders: 5,
+            lp_mint_address: 6,
+            coin_mint_address: 7,
+            pc_mint_address: 8,
+            pool_coin_token_account: 9,
+            pool_pc_token_account: 10,
+            pool_withdraw_queue: 11,
+            pool_target_orders_account: 12,
+            user_lp_token_account: 13,
+            pool_temp_lp_token_account: 14,
+            serum_program: 15,
+            serum_market: 16,
+            user_wallet: 17,
+            remaining_accounts: 18..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Initialize {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "token_program",
+            "system_program",
+            "rent",
+            "amm",
+            "amm_authority",
+            "amm_open_orders",
+            "lp_mint_address",
+            "coin_mint_address",
+            "pc_mint_address",
+            "pool_coin_token_account",
+            "pool_pc_token_account",
+            "pool_withdraw_queue",
+            "pool_target_orders_account",
+            "user_lp_token_account",
+            "pool_temp_lp_token_account",
+            "serum_program",
+            "serum_market",
+            "user_wallet",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -33,6 +35,7 @@ pub struct Initialize2InstructionAccounts {
     pub user_token_coin: solana_pubkey::Pubkey,
     pub user_token_pc: solana_pubkey::Pubkey,
     pub user_lp_token_account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Initialize2 {
@@ -41,7 +44,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Initialize2 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [token_program, spl_associated_token_account, system_program, rent, amm, amm_authority, amm_open_orders, lp_mint, coin_mint, pc_mint, pool_coin_token_account, pool_pc_token_account, pool_withdraw_queue, amm_target_orders, pool_temp_lp, serum_program, serum_market, user_wallet, user_token_coin, user_token_pc, user_lp_token_account, _remaining @ ..] =
+        let [token_program, spl_associated_token_account, system_program, rent, amm, amm_authority, amm_open_orders, lp_mint, coin_mint, pc_mint, pool_coin_token_account, pool_pc_token_account, pool_withdraw_queue, amm_target_orders, pool_temp_lp, serum_program, serum_market, user_wallet, user_token_coin, user_token_pc, user_lp_token_account, remaining @ ..] =
             accounts
         else {
             return None;
@@ -69,6 +72,161 @@ impl carbon_core::deserialize::ArrangeAccounts for Initialize2 {
             user_token_coin: user_token_coin.pubkey,
             user_token_pc: user_token_pc.pubkey,
             user_lp_token_account: user_lp_token_account.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct Initialize2InstructionAccountMetas {
+    pub token_program: solana_instruction::AccountMeta,
+    pub spl_associated_token_account: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub amm: solana_instruction::AccountMeta,
+    pub amm_authority: solana_instruction::AccountMeta,
+    pub amm_open_orders: solana_instruction::AccountMeta,
+    pub lp_mint: solana_instruction::AccountMeta,
+    pub coin_mint: solana_instruction::AccountMeta,
+    pub pc_mint: solana_instruction::AccountMeta,
+    pub pool_coin_token_account: solana_instruction::AccountMeta,
+    pub pool_pc_token_account: solana_instruction::AccountMeta,
+    pub pool_withdraw_queue: solana_instruction::AccountMeta,
+    pub amm_target_orders: solana_instruction::AccountMeta,
+    pub pool_temp_lp: solana_instruction::AccountMeta,
+    pub serum_program: solana_instruction::AccountMeta,
+    pub serum_market: solana_instruction::AccountMeta,
+    pub user_wallet: solana_instruction::AccountMeta,
+    pub user_token_coin: solana_instruction::AccountMeta,
+    pub user_token_pc: solana_instruction::AccountMeta,
+    pub user_lp_token_account: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Initialize2 {
+    type ArrangedAccountMetas = Initialize2InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [token_program, spl_associated_token_account, system_program, rent, amm, amm_authority, amm_open_orders, lp_mint, coin_mint, pc_mint, pool_coin_token_account, pool_pc_token_account, pool_withdraw_queue, amm_target_orders, pool_temp_lp, serum_program, serum_market, user_wallet, user_token_coin, user_token_pc, user_lp_token_account, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(Initialize2InstructionAccountMetas {
+            token_program: token_program.clone(),
+            spl_associated_token_account: spl_associated_token_account.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            amm: amm.clone(),
+            amm_authority: amm_authority.clone(),
+            amm_open_orders: amm_open_orders.clone(),
+            lp_mint: lp_mint.clone(),
+            coin_mint: coin_mint.clone(),
+            pc_mint: pc_mint.clone(),
+            pool_coin_token_account: pool_coin_token_account.clone(),
+            pool_pc_token_account: pool_pc_token_account.clone(),
+            pool_withdraw_queue: pool_withdraw_queue.clone(),
+            amm_target_orders: amm_target_orders.clone(),
+            pool_temp_lp: pool_temp_lp.clone(),
+            serum_program: serum_program.clone(),
+            serum_market: serum_market.clone(),
+            user_wallet: user_wallet.clone(),
+            user_token_coin: user_token_coin.clone(),
+            user_token_pc: user_token_pc.clone(),
+            user_lp_token_account: user_lp_token_account.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct Initialize2InstructionAccountIndices {
+    pub token_program: usize,
+    pub spl_associated_token_account: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub amm: usize,
+    pub amm_authority: usize,
+    pub amm_open_orders: usize,
+    pub lp_mint: usize,
+    pub coin_mint: usize,
+    pub pc_mint: usize,
+    pub pool_coin_token_account: usize,
+    pub pool_pc_token_account: usize,
+    pub pool_withdraw_queue: usize,
+    pub amm_target_orders: usize,
+    pub pool_temp_lp: usize,
+    pub serum_program: usize,
+    pub serum_market: usize,
+    pub user_wallet: usize,
+    pub user_token_coin: usize,
+    pub user_token_pc: usize,
+    pub user_lp_token_account: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Initialize2 {
+    type ArrangedAccountIndices = Initialize2InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 21 {
+            return None;
+        }
+
+        Some(Initialize2InstructionAccountIndices {
+            token_program: 0,
+            spl_associated_token_account: 1,
+            system_program: 2,
+            rent: 3,
+            amm: 4,
+            amm_authority: 5,
+            amm_open_orders: 6,
+            lp_mint: 7,
+            coin_mint: 8,
+            pc_mint: 9,
+            pool_coin_token_account: 10,
+            pool_pc_token_account: 11,
+            pool_withdraw_queue: 12,
+            amm_target_orders: 13,
+            pool_temp_lp: 14,
+            serum_program: 15,
+            serum_market: 16,
+            user_wallet: 17,
+            user_token_coin: 18,
+            user_token_pc: 19,
+            user_lp_token_account: 20,
+            remaining_accounts: 21..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Initialize2 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "token_program",
+            "spl_associated_token_account",
+            "system_program",
+            "rent",
+            "amm",
+            "amm_authority",
+            "amm_open_orders",
+            "lp_mint",
+            "coin_mint",
+            "pc_mint",
+            "pool_coin_token_account",
+            "pool_pc_token_account",
+            "pool_withdraw_queue",
+            "amm_target_orders",
+            "pool_temp_lp",
+            "serum_program",
+            "serum_market",
+            "user_wallet",
+            "user_token_coin",
+            "user_token_pc",
+            "user_lp_token_account",
+        ]
+    }
+}
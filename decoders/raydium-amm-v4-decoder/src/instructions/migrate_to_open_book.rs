@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -28,6 +30,7 @@ pub struct MigrateToOpenBookInstructionAccounts {
     pub new_serum_program: solana_pubkey::Pubkey,
     pub new_serum_market: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for MigrateToOpenBook {
@@ -36,7 +39,7 @@ impl carbon_core::deserialize::ArrangeAccounts for MigrateToOpenBook {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [token_program, system_program, rent, amm, amm_authority, amm_open_orders, amm_token_coin, amm_token_pc, amm_target_orders, serum_program, serum_market, serum_bids, serum_asks, serum_event_queue, serum_coin_vault, serum_pc_vault, serum_vault_signer, new_amm_open_orders, new_serum_program, new_serum_market, admin, _remaining @ ..] =
+        let [token_program, system_program, rent, amm, amm_authority, amm_open_orders, amm_token_coin, amm_token_pc, amm_target_orders, serum_program, serum_market, serum_bids, serum_asks, serum_event_queue, serum_coin_vault, serum_pc_vault, serum_vault_signer, new_amm_open_orders, new_serum_program, new_serum_market, admin, remaining @ ..] =
             accounts
         else {
             return None;
@@ -64,6 +67,161 @@ impl carbon_core::deserialize::ArrangeAccounts for MigrateToOpenBook {
             new_serum_program: new_serum_program.pubkey,
             new_serum_market: new_serum_market.pubkey,
             admin: admin.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct MigrateToOpenBookInstructionAccountMetas {
+    pub token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub amm: solana_instruction::AccountMeta,
+    pub amm_authority: solana_instruction::AccountMeta,
+    pub amm_open_orders: solana_instruction::AccountMeta,
+    pub amm_token_coin: solana_instruction::AccountMeta,
+    pub amm_token_pc: solana_instruction::AccountMeta,
+    pub amm_target_orders: solana_instruction::AccountMeta,
+    pub serum_program: solana_instruction::AccountMeta,
+    pub serum_market: solana_instruction::AccountMeta,
+    pub serum_bids: solana_instruction::AccountMeta,
+    pub serum_asks: solana_instruction::AccountMeta,
+    pub serum_event_queue: solana_instruction::AccountMeta,
+    pub serum_coin_vault: solana_instruction::AccountMeta,
+    pub serum_pc_vault: solana_instruction::AccountMeta,
+    pub serum_vault_signer: solana_instruction::AccountMeta,
+    pub new_amm_open_orders: solana_instruction::AccountMeta,
+    pub new_serum_program: solana_instruction::AccountMeta,
+    pub new_serum_market: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for MigrateToOpenBook {
+    type ArrangedAccountMetas = MigrateToOpenBookInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [token_program, system_program, rent, amm, amm_authority, amm_open_orders, amm_token_coin, amm_token_pc, amm_target_orders, serum_program, serum_market, serum_bids, serum_asks, serum_event_queue, serum_coin_vault, serum_pc_vault, serum_vault_signer, new_amm_open_orders, new_serum_program, new_serum_market, admin, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(MigrateToOpenBookInstructionAccountMetas {
+            token_program: token_program.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            amm: amm.clone(),
+            amm_authority: amm_authority.clone(),
+            amm_open_orders: amm_open_orders.clone(),
+            amm_token_coin: amm_token_coin.clone(),
+            amm_token_pc: amm_token_pc.clone(),
+            amm_target_orders: amm_target_orders.clone(),
+            serum_program: serum_program.clone(),
+            serum_market: serum_market.clone(),
+            serum_bids: serum_bids.clone(),
+            serum_asks: serum_asks.clone(),
+            serum_event_queue: serum_event_queue.clone(),
+            serum_coin_vault: serum_coin_vault.clone(),
+            serum_pc_vault: serum_pc_vault.clone(),
+            serum_vault_signer: serum_vault_signer.clone(),
+            new_amm_open_orders: new_amm_open_orders.clone(),
+            new_serum_program: new_serum_program.clone(),
+            new_serum_market: new_serum_market.clone(),
+            admin: admin.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct MigrateToOpenBookInstructionAccountIndices {
+    pub token_program: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub amm: usize,
+    pub amm_authority: usize,
+    pub amm_open_orders: usize,
+    pub amm_token_coin: usize,
+    pub amm_token_pc: usize,
+    pub amm_target_orders: usize,
+    pub serum_program: usize,
+    pub serum_market: usize,
+    pub serum_bids: usize,
+    pub serum_asks: usize,
+    pub serum_event_queue: usize,
+    pub serum_coin_vault: usize,
+    pub serum_pc_vault: usize,
+    pub serum_vault_signer: usize,
+    pub new_amm_open_orders: usize,
+    pub new_serum_program: usize,
+    pub new_serum_market: usize,
+    pub admin: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for MigrateToOpenBook {
+    type ArrangedAccountIndices = MigrateToOpenBookInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 21 {
+            return None;
+        }
+
+        Some(MigrateToOpenBookInstructionAccountIndices {
+            token_program: 0,
+            system_program: 1,
+            rent: 2,
+            amm: 3,
+            amm_authority: 4,
+            amm_open_orders: 5,
+            amm_token_coin: 6,
+            amm_token_pc: 7,
+            amm_target_orders: 8,
+            serum_program: 9,
+            serum_market: 10,
+            serum_bids: 11,
+            serum_asks: 12,
+            serum_event_queue: 13,
+            serum_coin_vault: 14,
+            serum_pc_vault: 15,
+            serum_vault_signer: 16,
+            new_amm_open_orders: 17,
+            new_serum_program: 18,
+            new_serum_market: 19,
+            admin: 20,
+            remaining_accounts: 21..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for MigrateToOpenBook {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "token_program",
+            "system_program",
+            "rent",
+            "amm",
+            "amm_authority",
+            "amm_open_orders",
+            "amm_token_coin",
+            "amm_token_pc",
+            "amm_target_orders",
+            "serum_program",
+            "serum_market",
+            "serum_bids",
+            "serum_asks",
+            "serum_event_queue",
+            "serum_coin_vault",
+            "serum_pc_vault",
+            "serum_vault_signer",
+            "new_amm_open_orders",
+            "new_serum_program",
+            "new_serum_market",
+            "admin",
+        ]
+    }
+}
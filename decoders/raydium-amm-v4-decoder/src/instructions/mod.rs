@@ -50,15 +50,25 @@ pub enum RaydiumAmmV4Instruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for RaydiumAmmV4Decoder {
     type InstructionType = RaydiumAmmV4Instruction;
 
-    fn decode_instruction(
+    fn decoder_name(&self) -> &'static str {
+        "raydium_amm_v4"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[super::PROGRAM_ID]
+    }
+
+    fn try_decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
-    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+    ) -> carbon_core::error::CarbonResult<
+        Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>>,
+    > {
         if !instruction.program_id.eq(&PROGRAM_ID) {
-            return None;
+            return Ok(None);
         }
 
-        carbon_core::try_decode_instructions!(instruction,
+        Ok(carbon_core::try_decode_instructions!(instruction,
             RaydiumAmmV4Instruction::Initialize => initialize::Initialize,
             RaydiumAmmV4Instruction::Initialize2 => initialize2::Initialize2,
             RaydiumAmmV4Instruction::MonitorStep => monitor_step::MonitorStep,
@@ -75,6 +85,6 @@ impl carbon_core::instruction::InstructionDecoder<'_> for RaydiumAmmV4Decoder {
             RaydiumAmmV4Instruction::AdminCancelOrders => admin_cancel_orders::AdminCancelOrders,
             RaydiumAmmV4Instruction::CreateConfigAccount => create_config_account::CreateConfigAccount,
             RaydiumAmmV4Instruction::UpdateConfigAccount => update_config_account::UpdateConfigAccount,
-        )
+        ))
     }
 }
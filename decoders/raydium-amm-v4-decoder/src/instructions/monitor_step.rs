@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -30,6 +32,7 @@ pub struct MonitorStepInstructionAccounts {
     pub serum_event_q: solana_pubkey::Pubkey,
     pub serum_bids: solana_pubkey::Pubkey,
     pub serum_asks: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for MonitorStep {
@@ -38,7 +41,7 @@ impl carbon_core::deserialize::ArrangeAccounts for MonitorStep {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [token_program, rent, clock, amm, amm_authority, amm_open_orders, amm_target_orders, pool_coin_token_account, pool_pc_token_account, pool_withdraw_queue, serum_program, serum_market, serum_coin_vault_account, serum_pc_vault_account, serum_vault_signer, serum_req_q, serum_event_q, serum_bids, serum_asks, _remaining @ ..] =
+        let [token_program, rent, clock, amm, amm_authority, amm_open_orders, amm_target_orders, pool_coin_token_account, pool_pc_token_account, pool_withdraw_queue, serum_program, serum_market, serum_coin_vault_account, serum_pc_vault_account, serum_vault_signer, serum_req_q, serum_event_q, serum_bids, serum_asks, remaining @ ..] =
             accounts
         else {
             return None;
@@ -64,6 +67,151 @@ impl carbon_core::deserialize::ArrangeAccounts for MonitorStep {
             serum_event_q: serum_event_q.pubkey,
             serum_bids: serum_bids.pubkey,
             serum_asks: serum_asks.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct MonitorStepInstructionAccountMetas {
+    pub token_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub clock: solana_instruction::AccountMeta,
+    pub amm: solana_instruction::AccountMeta,
+    pub amm_authority: solana_instruction::AccountMeta,
+    pub amm_open_orders: solana_instruction::AccountMeta,
+    pub amm_target_orders: solana_instruction::AccountMeta,
+    pub pool_coin_token_account: solana_instruction::AccountMeta,
+    pub pool_pc_token_account: solana_instruction::AccountMeta,
+    pub pool_withdraw_queue: solana_instruction::AccountMeta,
+    pub serum_program: solana_instruction::AccountMeta,
+    pub serum_market: solana_instruction::AccountMeta,
+    pub serum_coin_vault_account: solana_instruction::AccountMeta,
+    pub serum_pc_vault_account: solana_instruction::AccountMeta,
+    pub serum_vault_signer: solana_instruction::AccountMeta,
+    pub serum_req_q: solana_instruction::AccountMeta,
+    pub serum_event_q: solana_instruction::AccountMeta,
+    pub serum_bids: solana_instruction::AccountMeta,
+    pub serum_asks: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for MonitorStep {
+    type ArrangedAccountMetas = MonitorStepInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [token_program, rent, clock, amm, amm_authority, amm_open_orders, amm_target_orders, pool_coin_token_account, pool_pc_token_account, pool_withdraw_queue, serum_program, serum_market, serum_coin_vault_account, serum_pc_vault_account, serum_vault_signer, serum_req_q, serum_event_q, serum_bids, serum_asks, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(MonitorStepInstructionAccountMetas {
+            token_program: token_program.clone(),
+            rent: rent.clone(),
+            clock: clock.clone(),
+            amm: amm.clone(),
+            amm_authority: amm_authority.clone(),
+            amm_open_orders: amm_open_orders.clone(),
+            amm_target_orders: amm_target_orders.clone(),
+            pool_coin_token_account: pool_coin_token_account.clone(),
+            pool_pc_token_account: pool_pc_token_account.clone(),
+            pool_withdraw_queue: pool_withdraw_queue.clone(),
+            serum_program: serum_program.clone(),
+            serum_market: serum_market.clone(),
+            serum_coin_vault_account: serum_coin_vault_account.clone(),
+            serum_pc_vault_account: serum_pc_vault_account.clone(),
+            serum_vault_signer: serum_vault_signer.clone(),
+            serum_req_q: serum_req_q.clone(),
+            serum_event_q: serum_event_q.clone(),
+            serum_bids: serum_bids.clone(),
+            serum_asks: serum_asks.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct MonitorStepInstructionAccountIndices {
+    pub token_program: usize,
+    pub rent: usize,
+    pub clock: usize,
+    pub amm: usize,
+    pub amm_authority: usize,
+    pub amm_open_orders: usize,
+    pub amm_target_orders: usize,
+    pub pool_coin_token_account: usize,
+    pub pool_pc_token_account: usize,
+    pub pool_withdraw_queue: usize,
+    pub serum_program: usize,
+    pub serum_market: usize,
+    pub serum_coin_vault_account: usize,
+    pub serum_pc_vault_account: usize,
+    pub serum_vault_signer: usize,
+    pub serum_req_q: usize,
+    pub serum_event_q: usize,
+    pub serum_bids: usize,
+    pub serum_asks: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for MonitorStep {
+    type ArrangedAccountIndices = MonitorStepInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 19 {
+            return None;
+        }
+
+        Some(MonitorStepInstructionAccountIndices {
+            token_program: 0,
+            rent: 1,
+            clock: 2,
+            amm: 3,
+            amm_authority: 4,
+            amm_open_orders: 5,
+            amm_target_orders: 6,
+            pool_coin_token_account: 7,
+            pool_pc_token_account: 8,
+            pool_withdraw_queue: 9,
+            serum_program: 10,
+            serum_market: 11,
+            serum_coin_vault_account: 12,
+            serum_pc_vault_account: 13,
+            serum_vault_signer: 14,
+            serum_req_q: 15,
+            serum_event_q: 16,
+            serum_bids: 17,
+            serum_asks: 18,
+            remaining_accounts: 19..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for MonitorStep {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "token_program",
+            "rent",
+            "clock",
+            "amm",
+            "amm_authority",
+            "amm_open_orders",
+            "amm_target_orders",
+            "pool_coin_token_account",
+            "pool_pc_token_account",
+            "pool_withdraw_queue",
+            "serum_program",
+            "serum_market",
+            "serum_coin_vault_account",
+            "serum_pc_vault_account",
+            "serum_vault_signer",
+            "serum_req_q",
+            "serum_event_q",
+            "serum_bids",
+            "serum_asks",
+        ]
+    }
+}
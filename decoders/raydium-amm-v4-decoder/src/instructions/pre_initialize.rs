@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -23,6 +25,7 @@ pub struct PreInitializeInstructionAccounts {
     pub pool_temp_lp_token_account: solana_pubkey::Pubkey,
     pub serum_market: solana_pubkey::Pubkey,
     pub user_wallet: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for PreInitialize {
@@ -31,7 +34,7 @@ impl carbon_core::deserialize::ArrangeAccounts for PreInitialize {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [token_program, system_program, rent, amm_target_orders, pool_withdraw_queue, amm_authority, lp_mint_address, coin_mint_address, pc_mint_address, pool_coin_token_account, pool_pc_token_account, pool_temp_lp_token_account, serum_market, user_wallet, _remaining @ ..] =
+        let [token_program, system_program, rent, amm_target_orders, pool_withdraw_queue, amm_authority, lp_mint_address, coin_mint_address, pc_mint_address, pool_coin_token_account, pool_pc_token_account, pool_temp_lp_token_account, serum_market, user_wallet, remaining @ ..] =
             accounts
         else {
             return None;
@@ -52,6 +55,126 @@ impl carbon_core::deserialize::ArrangeAccounts for PreInitialize {
             pool_temp_lp_token_account: pool_temp_lp_token_account.pubkey,
             serum_market: serum_market.pubkey,
             user_wallet: user_wallet.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct PreInitializeInstructionAccountMetas {
+    pub token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub amm_target_orders: solana_instruction::AccountMeta,
+    pub pool_withdraw_queue: solana_instruction::AccountMeta,
+    pub amm_authority: solana_instruction::AccountMeta,
+    pub lp_mint_address: solana_instruction::AccountMeta,
+    pub coin_mint_address: solana_instruction::AccountMeta,
+    pub pc_mint_address: solana_instruction::AccountMeta,
+    pub pool_coin_token_account: solana_instruction::AccountMeta,
+    pub pool_pc_token_account: solana_instruction::AccountMeta,
+    pub pool_temp_lp_token_account: solana_instruction::AccountMeta,
+    pub serum_market: solana_instruction::AccountMeta,
+    pub user_wallet: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for PreInitialize {
+    type ArrangedAccountMetas = PreInitializeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [token_program, system_program, rent, amm_target_orders, pool_withdraw_queue, amm_authority, lp_mint_address, coin_mint_address, pc_mint_address, pool_coin_token_account, pool_pc_token_account, pool_temp_lp_token_account, serum_market, user_wallet, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(PreInitializeInstructionAccountMetas {
+            token_program: token_program.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            amm_target_orders: amm_target_orders.clone(),
+            pool_withdraw_queue: pool_withdraw_queue.clone(),
+            amm_authority: amm_authority.clone(),
+            lp_mint_address: lp_mint_address.clone(),
+            coin_mint_address: coin_mint_address.clone(),
+            pc_mint_address: pc_mint_address.clone(),
+            pool_coin_token_account: pool_coin_token_account.clone(),
+            pool_pc_token_account: pool_pc_token_account.clone(),
+            pool_temp_lp_token_account: pool_temp_lp_token_account.clone(),
+            serum_market: serum_market.clone(),
+            user_wallet: user_wallet.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct PreInitializeInstructionAccountIndices {
+    pub token_program: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub amm_target_orders: usize,
+    pub pool_withdraw_queue: usize,
+    pub amm_authority: usize,
+    pub lp_mint_address: usize,
+    pub coin_mint_address: usize,
+    pub pc_mint_address: usize,
+    pub pool_coin_token_account: usize,
+    pub pool_pc_token_account: usize,
+    pub pool_temp_lp_token_account: usize,
+    pub serum_market: usize,
+    pub user_wallet: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for PreInitialize {
+    type ArrangedAccountIndices = PreInitializeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 14 {
+            return None;
+        }
+
+        Some(PreInitializeInstructionAccountIndices {
+            token_program: 0,
+            system_program: 1,
+            rent: 2,
+            amm_target_orders: 3,
+            pool_withdraw_queue: 4,
+            amm_authority: 5,
+            lp_mint_address: 6,
+            coin_mint_address: 7,
+            pc_mint_address: 8,
+            pool_coin_token_account: 9,
+            pool_pc_token_account: 10,
+            pool_temp_lp_token_account: 11,
+            serum_market: 12,
+            user_wallet: 13,
+            remaining_accounts: 14..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for PreInitialize {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "token_program",
+            "system_program",
+            "rent",
+            "amm_target_orders",
+            "pool_withdraw_queue",
+            "amm_authority",
+            "lp_mint_address",
+            "coin_mint_address",
+            "pc_mint_address",
+            "pool_coin_token_account",
+            "pool_pc_token_account",
+            "pool_temp_lp_token_account",
+            "serum_market",
+            "user_wallet",
+        ]
+    }
+}
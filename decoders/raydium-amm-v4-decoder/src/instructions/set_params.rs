@@ -1,9 +1,11 @@
+use alloc::vec::Vec;
 use {
     super::super::types::*,
     crate::accounts::fees::Fees,
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -35,6 +37,7 @@ pub struct SetParamsInstructionAccounts {
     pub serum_asks: solana_pubkey::Pubkey,
     pub amm_admin_account: solana_pubkey::Pubkey,
     pub new_amm_open_orders_account: Option<solana_pubkey::Pubkey>,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SetParams {
@@ -43,12 +46,15 @@ impl carbon_core::deserialize::ArrangeAccounts for SetParams {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [token_program, amm, amm_authority, amm_open_orders, amm_target_orders, amm_coin_vault, amm_pc_vault, serum_program, serum_market, serum_coin_vault, serum_pc_vault, serum_vault_signer, serum_event_queue, serum_bids, serum_asks, amm_admin_account, _remaining @ ..] =
+        let [token_program, amm, amm_authority, amm_open_orders, amm_target_orders, amm_coin_vault, amm_pc_vault, serum_program, serum_market, serum_coin_vault, serum_pc_vault, serum_vault_signer, serum_event_queue, serum_bids, serum_asks, amm_admin_account, rest @ ..] =
             accounts
         else {
             return None;
         };
-        let new_amm_open_orders_account = accounts.get(16);
+        let (new_amm_open_orders_account, remaining) = match rest {
+            [account, remaining @ ..] => (Some(account), remaining),
+            [] => (None, rest),
+        };
 
         Some(SetParamsInstructionAccounts {
             token_program: token_program.pubkey,
@@ -68,6 +74,147 @@ impl carbon_core::deserialize::ArrangeAccounts for SetParams {
             serum_asks: serum_asks.pubkey,
             amm_admin_account: amm_admin_account.pubkey,
             new_amm_open_orders_account: new_amm_open_orders_account.map(|a| a.pubkey),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SetParamsInstructionAccountMetas {
+    pub token_program: solana_instruction::AccountMeta,
+    pub amm: solana_instruction::AccountMeta,
+    pub amm_authority: solana_instruction::AccountMeta,
+    pub amm_open_orders: solana_instruction::AccountMeta,
+    pub amm_target_orders: solana_instruction::AccountMeta,
+    pub amm_coin_vault: solana_instruction::AccountMeta,
+    pub amm_pc_vault: solana_instruction::AccountMeta,
+    pub serum_program: solana_instruction::AccountMeta,
+    pub serum_market: solana_instruction::AccountMeta,
+    pub serum_coin_vault: solana_instruction::AccountMeta,
+    pub serum_pc_vault: solana_instruction::AccountMeta,
+    pub serum_vault_signer: solana_instruction::AccountMeta,
+    pub serum_event_queue: solana_instruction::AccountMeta,
+    pub serum_bids: solana_instruction::AccountMeta,
+    pub serum_asks: solana_instruction::AccountMeta,
+    pub amm_admin_account: solana_instruction::AccountMeta,
+    pub new_amm_open_orders_account: Option<solana_instruction::AccountMeta>,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SetParams {
+    type ArrangedAccountMetas = SetParamsInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [token_program, amm, amm_authority, amm_open_orders, amm_target_orders, amm_coin_vault, amm_pc_vault, serum_program, serum_market, serum_coin_vault, serum_pc_vault, serum_vault_signer, serum_event_queue, serum_bids, serum_asks, amm_admin_account, rest @ ..] =
+            accounts
+        else {
+            return None;
+        };
+        let (new_amm_open_orders_account, remaining) = match rest {
+            [account, remaining @ ..] => (Some(account), remaining),
+            [] => (None, rest),
+        };
+
+        Some(SetParamsInstructionAccountMetas {
+            token_program: token_program.clone(),
+            amm: amm.clone(),
+            amm_authority: amm_authority.clone(),
+            amm_open_orders: amm_open_orders.clone(),
+            amm_target_orders: amm_target_orders.clone(),
+            amm_coin_vault: amm_coin_vault.clone(),
+            amm_pc_vault: amm_pc_vault.clone(),
+            serum_program: serum_program.clone(),
+            serum_market: serum_market.clone(),
+            serum_coin_vault: serum_coin_vault.clone(),
+            serum_pc_vault: serum_pc_vault.clone(),
+            serum_vault_signer: serum_vault_signer.clone(),
+            serum_event_queue: serum_event_queue.clone(),
+            serum_bids: serum_bids.clone(),
+            serum_asks: serum_asks.clone(),
+            amm_admin_account: amm_admin_account.clone(),
+            new_amm_open_orders_account: new_amm_open_orders_account.cloned(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SetParamsInstructionAccountIndices {
+    pub token_program: usize,
+    pub amm: usize,
+    pub amm_authority: usize,
+    pub amm_open_orders: usize,
+    pub amm_target_orders: usize,
+    pub amm_coin_vault: usize,
+    pub amm_pc_vault: usize,
+    pub serum_program: usize,
+    pub serum_market: usize,
+    pub serum_coin_vault: usize,
+    pub serum_pc_vault: usize,
+    pub serum_vault_signer: usize,
+    pub serum_event_queue: usize,
+    pub serum_bids: usize,
+    pub serum_asks: usize,
+    pub amm_admin_account: usize,
+    pub new_amm_open_orders_account: Option<usize>,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SetParams {
+    type ArrangedAccountIndices = SetParamsInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 16 {
+            return None;
+        }
+        let new_amm_open_orders_account = (accounts.len() > 16).then_some(16);
+
+        Some(SetParamsInstructionAccountIndices {
+            token_program: 0,
+            amm: 1,
+            amm_authority: 2,
+            amm_open_orders: 3,
+            amm_target_orders: 4,
+            amm_coin_vault: 5,
+            amm_pc_vault: 6,
+            serum_program: 7,
+            serum_market: 8,
+            serum_coin_vault: 9,
+            serum_pc_vault: 10,
+            serum_vault_signer: 11,
+            serum_event_queue: 12,
+            serum_bids: 13,
+            serum_asks: 14,
+            amm_admin_account: 15,
+            new_amm_open_orders_account,
+            remaining_accounts: new_amm_open_orders_account.map_or(16, |index| index + 1)
+                ..accounts.len(),
         })
     }
 }
+
+impl carbon_core::deserialize::NamedAccounts for SetParams {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "token_program",
+            "amm",
+            "amm_authority",
+            "amm_open_orders",
+            "amm_target_orders",
+            "amm_coin_vault",
+            "amm_pc_vault",
+            "serum_program",
+            "serum_market",
+            "serum_coin_vault",
+            "serum_pc_vault",
+            "serum_vault_signer",
+            "serum_event_queue",
+            "serum_bids",
+            "serum_asks",
+            "amm_admin_account",
+            "new_amm_open_orders_account",
+        ]
+    }
+}
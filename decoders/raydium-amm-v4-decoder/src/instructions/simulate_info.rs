@@ -1,8 +1,10 @@
+use alloc::vec::Vec;
 use {
     super::super::types::*,
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -22,6 +24,7 @@ pub struct SimulateInfoInstructionAccounts {
     pub lp_mint_address: solana_pubkey::Pubkey,
     pub serum_market: solana_pubkey::Pubkey,
     pub serum_event_queue: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SimulateInfo {
@@ -30,7 +33,7 @@ impl carbon_core::deserialize::ArrangeAccounts for SimulateInfo {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [amm, amm_authority, amm_open_orders, pool_coin_token_account, pool_pc_token_account, lp_mint_address, serum_market, serum_event_queue, _remaining @ ..] =
+        let [amm, amm_authority, amm_open_orders, pool_coin_token_account, pool_pc_token_account, lp_mint_address, serum_market, serum_event_queue, remaining @ ..] =
             accounts
         else {
             return None;
@@ -45,6 +48,96 @@ impl carbon_core::deserialize::ArrangeAccounts for SimulateInfo {
             lp_mint_address: lp_mint_address.pubkey,
             serum_market: serum_market.pubkey,
             serum_event_queue: serum_event_queue.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SimulateInfoInstructionAccountMetas {
+    pub amm: solana_instruction::AccountMeta,
+    pub amm_authority: solana_instruction::AccountMeta,
+    pub amm_open_orders: solana_instruction::AccountMeta,
+    pub pool_coin_token_account: solana_instruction::AccountMeta,
+    pub pool_pc_token_account: solana_instruction::AccountMeta,
+    pub lp_mint_address: solana_instruction::AccountMeta,
+    pub serum_market: solana_instruction::AccountMeta,
+    pub serum_event_queue: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SimulateInfo {
+    type ArrangedAccountMetas = SimulateInfoInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [amm, amm_authority, amm_open_orders, pool_coin_token_account, pool_pc_token_account, lp_mint_address, serum_market, serum_event_queue, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(SimulateInfoInstructionAccountMetas {
+            amm: amm.clone(),
+            amm_authority: amm_authority.clone(),
+            amm_open_orders: amm_open_orders.clone(),
+            pool_coin_token_account: pool_coin_token_account.clone(),
+            pool_pc_token_account: pool_pc_token_account.clone(),
+            lp_mint_address: lp_mint_address.clone(),
+            serum_market: serum_market.clone(),
+            serum_event_queue: serum_event_queue.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SimulateInfoInstructionAccountIndices {
+    pub amm: usize,
+    pub amm_authority: usize,
+    pub amm_open_orders: usize,
+    pub pool_coin_token_account: usize,
+    pub pool_pc_token_account: usize,
+    pub lp_mint_address: usize,
+    pub serum_market: usize,
+    pub serum_event_queue: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SimulateInfo {
+    type ArrangedAccountIndices = SimulateInfoInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(SimulateInfoInstructionAccountIndices {
+            amm: 0,
+            amm_authority: 1,
+            amm_open_orders: 2,
+            pool_coin_token_account: 3,
+            pool_pc_token_account: 4,
+            lp_mint_address: 5,
+            serum_market: 6,
+            serum_event_queue: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SimulateInfo {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "amm",
+            "amm_authority",
+            "amm_open_orders",
+            "pool_coin_token_account",
+            "pool_pc_token_account",
+            "lp_mint_address",
+            "serum_market",
+            "serum_event_queue",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -29,6 +31,7 @@ pub struct SwapBaseInInstructionAccounts {
     pub uer_source_token_account: solana_pubkey::Pubkey,
     pub uer_destination_token_account: solana_pubkey::Pubkey,
     pub user_source_owner: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SwapBaseIn {
@@ -39,7 +42,7 @@ impl carbon_core::deserialize::ArrangeAccounts for SwapBaseIn {
     ) -> Option<Self::ArrangedAccounts> {
         match accounts.len() {
             17 => {
-                let [token_program, amm, amm_authority, amm_open_orders, pool_coin_token_account, pool_pc_token_account, serum_program, serum_market, serum_bids, serum_asks, serum_event_queue, serum_coin_vault_account, serum_pc_vault_account, serum_vault_signer, uer_source_token_account, uer_destination_token_account, user_source_owner, _remaining @ ..] =
+                let [token_program, amm, amm_authority, amm_open_orders, pool_coin_token_account, pool_pc_token_account, serum_program, serum_market, serum_bids, serum_asks, serum_event_queue, serum_coin_vault_account, serum_pc_vault_account, serum_vault_signer, uer_source_token_account, uer_destination_token_account, user_source_owner, remaining @ ..] =
                     accounts
                 else {
                     return None;
@@ -64,10 +67,11 @@ impl carbon_core::deserialize::ArrangeAccounts for SwapBaseIn {
                     uer_source_token_account: uer_source_token_account.pubkey,
                     uer_destination_token_account: uer_destination_token_account.pubkey,
                     user_source_owner: user_source_owner.pubkey,
+                    remaining_accounts: remaining.to_vec(),
                 })
             }
             18 => {
-                let [token_program, amm, amm_authority, amm_open_orders, amm_target_orders, pool_coin_token_account, pool_pc_token_account, serum_program, serum_market, serum_bids, serum_asks, serum_event_queue, serum_coin_vault_account, serum_pc_vault_account, serum_vault_signer, uer_source_token_account, uer_destination_token_account, user_source_owner, _remaining @ ..] =
+                let [token_program, amm, amm_authority, amm_open_orders, amm_target_orders, pool_coin_token_account, pool_pc_token_account, serum_program, serum_market, serum_bids, serum_asks, serum_event_queue, serum_coin_vault_account, serum_pc_vault_account, serum_vault_signer, uer_source_token_account, uer_destination_token_account, user_source_owner, remaining @ ..] =
                     accounts
                 else {
                     return None;
@@ -92,9 +96,186 @@ impl carbon_core::deserialize::ArrangeAccounts for SwapBaseIn {
                     uer_source_token_account: uer_source_token_account.pubkey,
                     uer_destination_token_account: uer_destination_token_account.pubkey,
                     user_source_owner: user_source_owner.pubkey,
+                    remaining_accounts: remaining.to_vec(),
                 })
             }
             _ => None,
         }
     }
 }
+
+pub struct SwapBaseInInstructionAccountMetas {
+    pub token_program: solana_instruction::AccountMeta,
+    pub amm: solana_instruction::AccountMeta,
+    pub amm_authority: solana_instruction::AccountMeta,
+    pub amm_open_orders: solana_instruction::AccountMeta,
+    pub amm_target_orders: Option<solana_instruction::AccountMeta>,
+    pub pool_coin_token_account: solana_instruction::AccountMeta,
+    pub pool_pc_token_account: solana_instruction::AccountMeta,
+    pub serum_program: solana_instruction::AccountMeta,
+    pub serum_market: solana_instruction::AccountMeta,
+    pub serum_bids: solana_instruction::AccountMeta,
+    pub serum_asks: solana_instruction::AccountMeta,
+    pub serum_event_queue: solana_instruction::AccountMeta,
+    pub serum_coin_vault_account: solana_instruction::AccountMeta,
+    pub serum_pc_vault_account: solana_instruction::AccountMeta,
+    pub serum_vault_signer: solana_instruction::AccountMeta,
+    pub uer_source_token_account: solana_instruction::AccountMeta,
+    pub uer_destination_token_account: solana_instruction::AccountMeta,
+    pub user_source_owner: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SwapBaseIn {
+    type ArrangedAccountMetas = SwapBaseInInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        match accounts.len() {
+            17 => {
+                let [token_program, amm, amm_authority, amm_open_orders, pool_coin_token_account, pool_pc_token_account, serum_program, serum_market, serum_bids, serum_asks, serum_event_queue, serum_coin_vault_account, serum_pc_vault_account, serum_vault_signer, uer_source_token_account, uer_destination_token_account, user_source_owner, remaining @ ..] =
+                    accounts
+                else {
+                    return None;
+                };
+
+                Some(SwapBaseInInstructionAccountMetas {
+                    token_program: token_program.clone(),
+                    amm: amm.clone(),
+                    amm_authority: amm_authority.clone(),
+                    amm_open_orders: amm_open_orders.clone(),
+                    amm_target_orders: None,
+                    pool_coin_token_account: pool_coin_token_account.clone(),
+                    pool_pc_token_account: pool_pc_token_account.clone(),
+                    serum_program: serum_program.clone(),
+                    serum_market: serum_market.clone(),
+                    serum_bids: serum_bids.clone(),
+                    serum_asks: serum_asks.clone(),
+                    serum_event_queue: serum_event_queue.clone(),
+                    serum_coin_vault_account: serum_coin_vault_account.clone(),
+                    serum_pc_vault_account: serum_pc_vault_account.clone(),
+                    serum_vault_signer: serum_vault_signer.clone(),
+                    uer_source_token_account: uer_source_token_account.clone(),
+                    uer_destination_token_account: uer_destination_token_account.clone(),
+                    user_source_owner: user_source_owner.clone(),
+                    remaining_accounts: remaining.to_vec(),
+                })
+            }
+            18 => {
+                let [token_program, amm, amm_authority, amm_open_orders, amm_target_orders, pool_coin_token_account, pool_pc_token_account, serum_program, serum_market, serum_bids, serum_asks, serum_event_queue, serum_coin_vault_account, serum_pc_vault_account, serum_vault_signer, uer_source_token_account, uer_destination_token_account, user_source_owner, remaining @ ..] =
+                    accounts
+                else {
+                    return None;
+                };
+
+                Some(SwapBaseInInstructionAccountMetas {
+                    token_program: token_program.clone(),
+                    amm: amm.clone(),
+                    amm_authority: amm_authority.clone(),
+                    amm_open_orders: amm_open_orders.clone(),
+                    amm_target_orders: Some(amm_target_orders.clone()),
+                    pool_coin_token_account: pool_coin_token_account.clone(),
+                    pool_pc_token_account: pool_pc_token_account.clone(),
+                    serum_program: serum_program.clone(),
+                    serum_market: serum_market.clone(),
+                    serum_bids: serum_bids.clone(),
+                    serum_asks: serum_asks.clone(),
+                    serum_event_queue: serum_event_queue.clone(),
+                    serum_coin_vault_account: serum_coin_vault_account.clone(),
+                    serum_pc_vault_account: serum_pc_vault_account.clone(),
+                    serum_vault_signer: serum_vault_signer.clone(),
+                    uer_source_token_account: uer_source_token_account.clone(),
+                    uer_destination_token_account: uer_destination_token_account.clone(),
+                    user_source_owner: user_source_owner.clone(),
+                    remaining_accounts: remaining.to_vec(),
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+pub struct SwapBaseInInstructionAccountIndices {
+    pub token_program: usize,
+    pub amm: usize,
+    pub amm_authority: usize,
+    pub amm_open_orders: usize,
+    pub amm_target_orders: Option<usize>,
+    pub pool_coin_token_account: usize,
+    pub pool_pc_token_account: usize,
+    pub serum_program: usize,
+    pub serum_market: usize,
+    pub serum_bids: usize,
+    pub serum_asks: usize,
+    pub serum_event_queue: usize,
+    pub serum_coin_vault_account: usize,
+    pub serum_pc_vault_account: usize,
+    pub serum_vault_signer: usize,
+    pub uer_source_token_account: usize,
+    pub uer_destination_token_account: usize,
+    pub user_source_owner: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SwapBaseIn {
+    type ArrangedAccountIndices = SwapBaseInInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        // The optional account shifts every account after it by one.
+        let (amm_target_orders, next) = match accounts.len() {
+            17 => (None, 4),
+            18 => (Some(4), 5),
+            _ => return None,
+        };
+
+        Some(SwapBaseInInstructionAccountIndices {
+            token_program: 0,
+            amm: 1,
+            amm_authority: 2,
+            amm_open_orders: 3,
+            amm_target_orders,
+            pool_coin_token_account: next,
+            pool_pc_token_account: next + 1,
+            serum_program: next + 2,
+            serum_market: next + 3,
+            serum_bids: next + 4,
+            serum_asks: next + 5,
+            serum_event_queue: next + 6,
+            serum_coin_vault_account: next + 7,
+            serum_pc_vault_account: next + 8,
+            serum_vault_signer: next + 9,
+            uer_source_token_account: next + 10,
+            uer_destination_token_account: next + 11,
+            user_source_owner: next + 12,
+            remaining_accounts: accounts.len()..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SwapBaseIn {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "token_program",
+            "amm",
+            "amm_authority",
+            "amm_open_orders",
+            "amm_target_orders",
+            "pool_coin_token_account",
+            "pool_pc_token_account",
+            "serum_program",
+            "serum_market",
+            "serum_bids",
+            "serum_asks",
+            "serum_event_queue",
+            "serum_coin_vault_account",
+            "serum_pc_vault_account",
+            "serum_vault_signer",
+            "uer_source_token_account",
+            "uer_destination_token_account",
+            "user_source_owner",
+        ]
+    }
+}
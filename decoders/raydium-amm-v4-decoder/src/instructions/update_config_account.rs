@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +14,7 @@ pub struct UpdateConfigAccount {
 pub struct UpdateConfigAccountInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub amm_config: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateConfigAccount {
@@ -20,13 +23,68 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateConfigAccount {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin, amm_config, _remaining @ ..] = accounts else {
+        let [admin, amm_config, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(UpdateConfigAccountInstructionAccounts {
             admin: admin.pubkey,
             amm_config: amm_config.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateConfigAccountInstructionAccountMetas {
+    pub admin: solana_instruction::AccountMeta,
+    pub amm_config: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateConfigAccount {
+    type ArrangedAccountMetas = UpdateConfigAccountInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin, amm_config, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateConfigAccountInstructionAccountMetas {
+            admin: admin.clone(),
+            amm_config: amm_config.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateConfigAccountInstructionAccountIndices {
+    pub admin: usize,
+    pub amm_config: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateConfigAccount {
+    type ArrangedAccountIndices = UpdateConfigAccountInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(UpdateConfigAccountInstructionAccountIndices {
+            admin: 0,
+            amm_config: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateConfigAccount {
+    fn account_names() -> &'static [&'static str] {
+        &["admin", "amm_config"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -31,6 +33,7 @@ pub struct WithdrawInstructionAccounts {
     pub serum_event_q: solana_pubkey::Pubkey,
     pub serum_bids: solana_pubkey::Pubkey,
     pub serum_asks: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Withdraw {
@@ -39,7 +42,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Withdraw {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [token_program, amm, amm_authority, amm_open_orders, amm_target_orders, lp_mint_address, pool_coin_token_account, pool_pc_token_account, pool_withdraw_queue, pool_temp_lp_token_account, serum_program, serum_market, serum_coin_vault_account, serum_pc_vault_account, serum_vault_signer, user_lp_token_account, uer_coin_token_account, uer_pc_token_account, user_owner, serum_event_q, serum_bids, serum_asks, _remaining @ ..] =
+        let [token_program, amm, amm_authority, amm_open_orders, amm_target_orders, lp_mint_address, pool_coin_token_account, pool_pc_token_account, pool_withdraw_queue, pool_temp_lp_token_account, serum_program, serum_market, serum_coin_vault_account, serum_pc_vault_account, serum_vault_signer, user_lp_token_account, uer_coin_token_account, uer_pc_token_account, user_owner, serum_event_q, serum_bids, serum_asks, remaining @ ..] =
             accounts
         else {
             return None;
@@ -68,6 +71,166 @@ impl carbon_core::deserialize::ArrangeAccounts for Withdraw {
             serum_event_q: serum_event_q.pubkey,
             serum_bids: serum_bids.pubkey,
             serum_asks: serum_asks.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct WithdrawInstructionAccountMetas {
+    pub token_program: solana_instruction::AccountMeta,
+    pub amm: solana_instruction::AccountMeta,
+    pub amm_authority: solana_instruction::AccountMeta,
+    pub amm_open_orders: solana_instruction::AccountMeta,
+    pub amm_target_orders: solana_instruction::AccountMeta,
+    pub lp_mint_address: solana_instruction::AccountMeta,
+    pub pool_coin_token_account: solana_instruction::AccountMeta,
+    pub pool_pc_token_account: solana_instruction::AccountMeta,
+    pub pool_withdraw_queue: solana_instruction::AccountMeta,
+    pub pool_temp_lp_token_account: solana_instruction::AccountMeta,
+    pub serum_program: solana_instruction::AccountMeta,
+    pub serum_market: solana_instruction::AccountMeta,
+    pub serum_coin_vault_account: solana_instruction::AccountMeta,
+    pub serum_pc_vault_account: solana_instruction::AccountMeta,
+    pub serum_vault_signer: solana_instruction::AccountMeta,
+    pub user_lp_token_account: solana_instruction::AccountMeta,
+    pub uer_coin_token_account: solana_instruction::AccountMeta,
+    pub uer_pc_token_account: solana_instruction::AccountMeta,
+    pub user_owner: solana_instruction::AccountMeta,
+    pub serum_event_q: solana_instruction::AccountMeta,
+    pub serum_bids: solana_instruction::AccountMeta,
+    pub serum_asks: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Withdraw {
+    type ArrangedAccountMetas = WithdrawInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [token_program, amm, amm_authority, amm_open_orders, amm_target_orders, lp_mint_address, pool_coin_token_account, pool_pc_token_account, pool_withdraw_queue, pool_temp_lp_token_account, serum_program, serum_market, serum_coin_vault_account, serum_pc_vault_account, serum_vault_signer, user_lp_token_account, uer_coin_token_account, uer_pc_token_account, user_owner, serum_event_q, serum_bids, serum_asks, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(WithdrawInstructionAccountMetas {
+            token_program: token_program.clone(),
+            amm: amm.clone(),
+            amm_authority: amm_authority.clone(),
+            amm_open_orders: amm_open_orders.clone(),
+            amm_target_orders: amm_target_orders.clone(),
+            lp_mint_address: lp_mint_address.clone(),
+            pool_coin_token_account: pool_coin_token_account.clone(),
+            pool_pc_token_account: pool_pc_token_account.clone(),
+            pool_withdraw_queue: pool_withdraw_queue.clone(),
+            pool_temp_lp_token_account: pool_temp_lp_token_account.clone(),
+            serum_program: serum_program.clone(),
+            serum_market: serum_market.clone(),
+            serum_coin_vault_account: serum_coin_vault_account.clone(),
+            serum_pc_vault_account: serum_pc_vault_account.clone(),
+            serum_vault_signer: serum_vault_signer.clone(),
+            user_lp_token_account: user_lp_token_account.clone(),
+            uer_coin_token_account: uer_coin_token_account.clone(),
+            uer_pc_token_account: uer_pc_token_account.clone(),
+            user_owner: user_owner.clone(),
+            serum_event_q: serum_event_q.clone(),
+            serum_bids: serum_bids.clone(),
+            serum_asks: serum_asks.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct WithdrawInstructionAccountIndices {
+    pub token_program: usize,
+    pub amm: usize,
+    pub amm_authority: usize,
+    pub amm_open_orders: usize,
+    pub amm_target_orders: usize,
+    pub lp_mint_address: usize,
+    pub pool_coin_token_account: usize,
+    pub pool_pc_token_account: usize,
+    pub pool_withdraw_queue: usize,
+    pub pool_temp_lp_token_account: usize,
+    pub serum_program: usize,
+    pub serum_market: usize,
+    pub serum_coin_vault_account: usize,
+    pub serum_pc_vault_account: usize,
+    pub serum_vault_signer: usize,
+    pub user_lp_token_account: usize,
+    pub uer_coin_token_account: usize,
+    pub uer_pc_token_account: usize,
+    pub user_owner: usize,
+    pub serum_event_q: usize,
+    pub serum_bids: usize,
+    pub serum_asks: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Withdraw {
+    type ArrangedAccountIndices = WithdrawInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 22 {
+            return None;
+        }
+
+        Some(WithdrawInstructionAccountIndices {
+            token_program: 0,
+            amm: 1,
+            amm_authority: 2,
+            amm_open_orders: 3,
+            amm_target_orders: 4,
+            lp_mint_address: 5,
+            pool_coin_token_account: 6,
+            pool_pc_token_account: 7,
+            pool_withdraw_queue: 8,
+            pool_temp_lp_token_account: 9,
+            serum_program: 10,
+            serum_market: 11,
+            serum_coin_vault_account: 12,
+            serum_pc_vault_account: 13,
+            serum_vault_signer: 14,
+            user_lp_token_account: 15,
+            uer_coin_token_account: 16,
+            uer_pc_token_account: 17,
+            user_owner: 18,
+            serum_event_q: 19,
+            serum_bids: 20,
+            serum_asks: 21,
+            remaining_accounts: 22..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Withdraw {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "token_program",
+            "amm",
+            "amm_authority",
+            "amm_open_orders",
+            "amm_target_orders",
+            "lp_mint_address",
+            "pool_coin_token_account",
+            "pool_pc_token_account",
+            "pool_withdraw_queue",
+            "pool_temp_lp_token_account",
+            "serum_program",
+            "serum_market",
+            "serum_coin_vault_account",
+            "serum_pc_vault_account",
+            "serum_vault_signer",
+            "user_lp_token_account",
+            "uer_coin_token_account",
+            "uer_pc_token_account",
+            "user_owner",
+            "serum_event_q",
+            "serum_bids",
+            "serum_asks",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -24,6 +26,7 @@ pub struct WithdrawPnlInstructionAccounts {
     pub serum_coin_vault_account: solana_pubkey::Pubkey,
     pub serum_pc_vault_account: solana_pubkey::Pubkey,
     pub serum_vault_signer: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for WithdrawPnl {
@@ -32,7 +35,7 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawPnl {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [token_program, amm, amm_config, amm_authority, amm_open_orders, pool_coin_token_account, pool_pc_token_account, coin_pnl_token_account, pc_pnl_token_account, pnl_owner_account, amm_target_orders, serum_program, serum_market, serum_event_queue, serum_coin_vault_account, serum_pc_vault_account, serum_vault_signer, _remaining @ ..] =
+        let [token_program, amm, amm_config, amm_authority, amm_open_orders, pool_coin_token_account, pool_pc_token_account, coin_pnl_token_account, pc_pnl_token_account, pnl_owner_account, amm_target_orders, serum_program, serum_market, serum_event_queue, serum_coin_vault_account, serum_pc_vault_account, serum_vault_signer, remaining @ ..] =
             accounts
         else {
             return None;
@@ -56,6 +59,141 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawPnl {
             serum_coin_vault_account: serum_coin_vault_account.pubkey,
             serum_pc_vault_account: serum_pc_vault_account.pubkey,
             serum_vault_signer: serum_vault_signer.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct WithdrawPnlInstructionAccountMetas {
+    pub token_program: solana_instruction::AccountMeta,
+    pub amm: solana_instruction::AccountMeta,
+    pub amm_config: solana_instruction::AccountMeta,
+    pub amm_authority: solana_instruction::AccountMeta,
+    pub amm_open_orders: solana_instruction::AccountMeta,
+    pub pool_coin_token_account: solana_instruction::AccountMeta,
+    pub pool_pc_token_account: solana_instruction::AccountMeta,
+    pub coin_pnl_token_account: solana_instruction::AccountMeta,
+    pub pc_pnl_token_account: solana_instruction::AccountMeta,
+    pub pnl_owner_account: solana_instruction::AccountMeta,
+    pub amm_target_orders: solana_instruction::AccountMeta,
+    pub serum_program: solana_instruction::AccountMeta,
+    pub serum_market: solana_instruction::AccountMeta,
+    pub serum_event_queue: solana_instruction::AccountMeta,
+    pub serum_coin_vault_account: solana_instruction::AccountMeta,
+    pub serum_pc_vault_account: solana_instruction::AccountMeta,
+    pub serum_vault_signer: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for WithdrawPnl {
+    type ArrangedAccountMetas = WithdrawPnlInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [token_program, amm, amm_config, amm_authority, amm_open_orders, pool_coin_token_account, pool_pc_token_account, coin_pnl_token_account, pc_pnl_token_account, pnl_owner_account, amm_target_orders, serum_program, serum_market, serum_event_queue, serum_coin_vault_account, serum_pc_vault_account, serum_vault_signer, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(WithdrawPnlInstructionAccountMetas {
+            token_program: token_program.clone(),
+            amm: amm.clone(),
+            amm_config: amm_config.clone(),
+            amm_authority: amm_authority.clone(),
+            amm_open_orders: amm_open_orders.clone(),
+            pool_coin_token_account: pool_coin_token_account.clone(),
+            pool_pc_token_account: pool_pc_token_account.clone(),
+            coin_pnl_token_account: coin_pnl_token_account.clone(),
+            pc_pnl_token_account: pc_pnl_token_account.clone(),
+            pnl_owner_account: pnl_owner_account.clone(),
+            amm_target_orders: amm_target_orders.clone(),
+            serum_program: serum_program.clone(),
+            serum_market: serum_market.clone(),
+            serum_event_queue: serum_event_queue.clone(),
+            serum_coin_vault_account: serum_coin_vault_account.clone(),
+            serum_pc_vault_account: serum_pc_vault_account.clone(),
+            serum_vault_signer: serum_vault_signer.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct WithdrawPnlInstructionAccountIndices {
+    pub token_program: usize,
+    pub amm: usize,
+    pub amm_config: usize,
+    pub amm_authority: usize,
+    pub amm_open_orders: usize,
+    pub pool_coin_token_account: usize,
+    pub pool_pc_token_account: usize,
+    pub coin_pnl_token_account: usize,
+    pub pc_pnl_token_account: usize,
+    pub pnl_owner_account: usize,
+    pub amm_target_orders: usize,
+    pub serum_program: usize,
+    pub serum_market: usize,
+    pub serum_event_queue: usize,
+    pub serum_coin_vault_account: usize,
+    pub serum_pc_vault_account: usize,
+    pub serum_vault_signer: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for WithdrawPnl {
+    type ArrangedAccountIndices = WithdrawPnlInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 17 {
+            return None;
+        }
+
+        Some(WithdrawPnlInstructionAccountIndices {
+            token_program: 0,
+            amm: 1,
+            amm_config: 2,
+            amm_authority: 3,
+            amm_open_orders: 4,
+            pool_coin_token_account: 5,
+            pool_pc_token_account: 6,
+            coin_pnl_token_account: 7,
+            pc_pnl_token_account: 8,
+            pnl_owner_account: 9,
+            amm_target_orders: 10,
+            serum_program: 11,
+            serum_market: 12,
+            serum_event_queue: 13,
+            serum_coin_vault_account: 14,
+            serum_pc_vault_account: 15,
+            serum_vault_signer: 16,
+            remaining_accounts: 17..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for WithdrawPnl {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "token_program",
+            "amm",
+            "amm_config",
+            "amm_authority",
+            "amm_open_orders",
+            "pool_coin_token_account",
+            "pool_pc_token_account",
+            "coin_pnl_token_account",
+            "pc_pnl_token_account",
+            "pnl_owner_account",
+            "amm_target_orders",
+            "serum_program",
+            "serum_market",
+            "serum_event_queue",
+            "serum_coin_vault_account",
+            "serum_pc_vault_account",
+            "serum_vault_signer",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -15,6 +17,7 @@ pub struct WithdrawSrmInstructionAccounts {
     pub amm_authority: solana_pubkey::Pubkey,
     pub srm_token: solana_pubkey::Pubkey,
     pub dest_srm_token: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for WithdrawSrm {
@@ -23,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawSrm {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [token_program, amm, amm_owner_account, amm_authority, srm_token, dest_srm_token, _remaining @ ..] =
+        let [token_program, amm, amm_owner_account, amm_authority, srm_token, dest_srm_token, remaining @ ..] =
             accounts
         else {
             return None;
@@ -36,6 +39,86 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawSrm {
             amm_authority: amm_authority.pubkey,
             srm_token: srm_token.pubkey,
             dest_srm_token: dest_srm_token.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct WithdrawSrmInstructionAccountMetas {
+    pub token_program: solana_instruction::AccountMeta,
+    pub amm: solana_instruction::AccountMeta,
+    pub amm_owner_account: solana_instruction::AccountMeta,
+    pub amm_authority: solana_instruction::AccountMeta,
+    pub srm_token: solana_instruction::AccountMeta,
+    pub dest_srm_token: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for WithdrawSrm {
+    type ArrangedAccountMetas = WithdrawSrmInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [token_program, amm, amm_owner_account, amm_authority, srm_token, dest_srm_token, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(WithdrawSrmInstructionAccountMetas {
+            token_program: token_program.clone(),
+            amm: amm.clone(),
+            amm_owner_account: amm_owner_account.clone(),
+            amm_authority: amm_authority.clone(),
+            srm_token: srm_token.clone(),
+            dest_srm_token: dest_srm_token.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct WithdrawSrmInstructionAccountIndices {
+    pub token_program: usize,
+    pub amm: usize,
+    pub amm_owner_account: usize,
+    pub amm_authority: usize,
+    pub srm_token: usize,
+    pub dest_srm_token: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for WithdrawSrm {
+    type ArrangedAccountIndices = WithdrawSrmInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(WithdrawSrmInstructionAccountIndices {
+            token_program: 0,
+            amm: 1,
+            amm_owner_account: 2,
+            amm_authority: 3,
+            srm_token: 4,
+            dest_srm_token: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for WithdrawSrm {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "token_program",
+            "amm",
+            "amm_owner_account",
+            "amm_authority",
+            "srm_token",
+            "dest_srm_token",
+        ]
+    }
+}
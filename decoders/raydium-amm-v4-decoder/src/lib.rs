@@ -1,5 +1,7 @@
 #![no_std]
 
+extern crate alloc;
+
 use solana_pubkey::Pubkey;
 
 pub struct RaydiumAmmV4Decoder;
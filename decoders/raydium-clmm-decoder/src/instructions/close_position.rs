@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +15,7 @@ pub struct ClosePositionInstructionAccounts {
     pub personal_position: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ClosePosition {
@@ -21,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ClosePosition {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [nft_owner, position_nft_mint, position_nft_account, personal_position, system_program, token_program, _remaining @ ..] =
+        let [nft_owner, position_nft_mint, position_nft_account, personal_position, system_program, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -34,6 +37,86 @@ impl carbon_core::deserialize::ArrangeAccounts for ClosePosition {
             personal_position: personal_position.pubkey,
             system_program: system_program.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ClosePositionInstructionAccountMetas {
+    pub nft_owner: solana_instruction::AccountMeta,
+    pub position_nft_mint: solana_instruction::AccountMeta,
+    pub position_nft_account: solana_instruction::AccountMeta,
+    pub personal_position: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ClosePosition {
+    type ArrangedAccountMetas = ClosePositionInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [nft_owner, position_nft_mint, position_nft_account, personal_position, system_program, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(ClosePositionInstructionAccountMetas {
+            nft_owner: nft_owner.clone(),
+            position_nft_mint: position_nft_mint.clone(),
+            position_nft_account: position_nft_account.clone(),
+            personal_position: personal_position.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ClosePositionInstructionAccountIndices {
+    pub nft_owner: usize,
+    pub position_nft_mint: usize,
+    pub position_nft_account: usize,
+    pub personal_position: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ClosePosition {
+    type ArrangedAccountIndices = ClosePositionInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(ClosePositionInstructionAccountIndices {
+            nft_owner: 0,
+            position_nft_mint: 1,
+            position_nft_account: 2,
+            personal_position: 3,
+            system_program: 4,
+            token_program: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ClosePosition {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "nft_owner",
+            "position_nft_mint",
+            "position_nft_account",
+            "personal_position",
+            "system_program",
+            "token_program",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -21,6 +23,7 @@ pub struct CollectFundFeeInstructionAccounts {
     pub recipient_token_account1: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub token_program2022: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CollectFundFee {
@@ -29,7 +32,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CollectFundFee {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [owner, pool_state, amm_config, token_vault0, token_vault1, vault0_mint, vault1_mint, recipient_token_account0, recipient_token_account1, token_program, token_program2022, _remaining @ ..] =
+        let [owner, pool_state, amm_config, token_vault0, token_vault1, vault0_mint, vault1_mint, recipient_token_account0, recipient_token_account1, token_program, token_program2022, remaining @ ..] =
             accounts
         else {
             return None;
@@ -47,6 +50,111 @@ impl carbon_core::deserialize::ArrangeAccounts for CollectFundFee {
             recipient_token_account1: recipient_token_account1.pubkey,
             token_program: token_program.pubkey,
             token_program2022: token_program2022.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CollectFundFeeInstructionAccountMetas {
+    pub owner: solana_instruction::AccountMeta,
+    pub pool_state: solana_instruction::AccountMeta,
+    pub amm_config: solana_instruction::AccountMeta,
+    pub token_vault0: solana_instruction::AccountMeta,
+    pub token_vault1: solana_instruction::AccountMeta,
+    pub vault0_mint: solana_instruction::AccountMeta,
+    pub vault1_mint: solana_instruction::AccountMeta,
+    pub recipient_token_account0: solana_instruction::AccountMeta,
+    pub recipient_token_account1: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub token_program2022: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CollectFundFee {
+    type ArrangedAccountMetas = CollectFundFeeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [owner, pool_state, amm_config, token_vault0, token_vault1, vault0_mint, vault1_mint, recipient_token_account0, recipient_token_account1, token_program, token_program2022, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CollectFundFeeInstructionAccountMetas {
+            owner: owner.clone(),
+            pool_state: pool_state.clone(),
+            amm_config: amm_config.clone(),
+            token_vault0: token_vault0.clone(),
+            token_vault1: token_vault1.clone(),
+            vault0_mint: vault0_mint.clone(),
+            vault1_mint: vault1_mint.clone(),
+            recipient_token_account0: recipient_token_account0.clone(),
+            recipient_token_account1: recipient_token_account1.clone(),
+            token_program: token_program.clone(),
+            token_program2022: token_program2022.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CollectFundFeeInstructionAccountIndices {
+    pub owner: usize,
+    pub pool_state: usize,
+    pub amm_config: usize,
+    pub token_vault0: usize,
+    pub token_vault1: usize,
+    pub vault0_mint: usize,
+    pub vault1_mint: usize,
+    pub recipient_token_account0: usize,
+    pub recipient_token_account1: usize,
+    pub token_program: usize,
+    pub token_program2022: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CollectFundFee {
+    type ArrangedAccountIndices = CollectFundFeeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 11 {
+            return None;
+        }
+
+        Some(CollectFundFeeInstructionAccountIndices {
+            owner: 0,
+            pool_state: 1,
+            amm_config: 2,
+            token_vault0: 3,
+            token_vault1: 4,
+            vault0_mint: 5,
+            vault1_mint: 6,
+            recipient_token_account0: 7,
+            recipient_token_account1: 8,
+            token_program: 9,
+            token_program2022: 10,
+            remaining_accounts: 11..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CollectFundFee {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "owner",
+            "pool_state",
+            "amm_config",
+            "token_vault0",
+            "token_vault1",
+            "vault0_mint",
+            "vault1_mint",
+            "recipient_token_account0",
+            "recipient_token_account1",
+            "token_program",
+            "token_program2022",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -17,6 +19,7 @@ pub struct CollectRemainingRewardsInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub token_program2022: solana_pubkey::Pubkey,
     pub memo_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CollectRemainingRewards {
@@ -25,7 +28,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CollectRemainingRewards {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [reward_funder, funder_token_account, pool_state, reward_token_vault, reward_vault_mint, token_program, token_program2022, memo_program, _remaining @ ..] =
+        let [reward_funder, funder_token_account, pool_state, reward_token_vault, reward_vault_mint, token_program, token_program2022, memo_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -40,6 +43,96 @@ impl carbon_core::deserialize::ArrangeAccounts for CollectRemainingRewards {
             token_program: token_program.pubkey,
             token_program2022: token_program2022.pubkey,
             memo_program: memo_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CollectRemainingRewardsInstructionAccountMetas {
+    pub reward_funder: solana_instruction::AccountMeta,
+    pub funder_token_account: solana_instruction::AccountMeta,
+    pub pool_state: solana_instruction::AccountMeta,
+    pub reward_token_vault: solana_instruction::AccountMeta,
+    pub reward_vault_mint: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub token_program2022: solana_instruction::AccountMeta,
+    pub memo_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CollectRemainingRewards {
+    type ArrangedAccountMetas = CollectRemainingRewardsInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [reward_funder, funder_token_account, pool_state, reward_token_vault, reward_vault_mint, token_program, token_program2022, memo_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CollectRemainingRewardsInstructionAccountMetas {
+            reward_funder: reward_funder.clone(),
+            funder_token_account: funder_token_account.clone(),
+            pool_state: pool_state.clone(),
+            reward_token_vault: reward_token_vault.clone(),
+            reward_vault_mint: reward_vault_mint.clone(),
+            token_program: token_program.clone(),
+            token_program2022: token_program2022.clone(),
+            memo_program: memo_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CollectRemainingRewardsInstructionAccountIndices {
+    pub reward_funder: usize,
+    pub funder_token_account: usize,
+    pub pool_state: usize,
+    pub reward_token_vault: usize,
+    pub reward_vault_mint: usize,
+    pub token_program: usize,
+    pub token_program2022: usize,
+    pub memo_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CollectRemainingRewards {
+    type ArrangedAccountIndices = CollectRemainingRewardsInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(CollectRemainingRewardsInstructionAccountIndices {
+            reward_funder: 0,
+            funder_token_account: 1,
+            pool_state: 2,
+            reward_token_vault: 3,
+            reward_vault_mint: 4,
+            token_program: 5,
+            token_program2022: 6,
+            memo_program: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CollectRemainingRewards {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "reward_funder",
+            "funder_token_account",
+            "pool_state",
+            "reward_token_vault",
+            "reward_vault_mint",
+            "token_program",
+            "token_program2022",
+            "memo_program",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -16,6 +18,7 @@ pub struct CreateAmmConfigInstructionAccounts {
     pub owner: solana_pubkey::Pubkey,
     pub amm_config: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CreateAmmConfig {
@@ -24,7 +27,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateAmmConfig {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [owner, amm_config, system_program, _remaining @ ..] = accounts else {
+        let [owner, amm_config, system_program, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -32,6 +35,65 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateAmmConfig {
             owner: owner.pubkey,
             amm_config: amm_config.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CreateAmmConfigInstructionAccountMetas {
+    pub owner: solana_instruction::AccountMeta,
+    pub amm_config: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CreateAmmConfig {
+    type ArrangedAccountMetas = CreateAmmConfigInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [owner, amm_config, system_program, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(CreateAmmConfigInstructionAccountMetas {
+            owner: owner.clone(),
+            amm_config: amm_config.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CreateAmmConfigInstructionAccountIndices {
+    pub owner: usize,
+    pub amm_config: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CreateAmmConfig {
+    type ArrangedAccountIndices = CreateAmmConfigInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(CreateAmmConfigInstructionAccountIndices {
+            owner: 0,
+            amm_config: 1,
+            system_program: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CreateAmmConfig {
+    fn account_names() -> &'static [&'static str] {
+        &["owner", "amm_config", "system_program"]
+    }
+}
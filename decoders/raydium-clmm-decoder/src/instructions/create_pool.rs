@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -23,6 +25,7 @@ pub struct CreatePoolInstructionAccounts {
     pub token_program1: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CreatePool {
@@ -31,7 +34,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CreatePool {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [pool_creator, amm_config, pool_state, token_mint0, token_mint1, token_vault0, token_vault1, observation_state, tick_array_bitmap, token_program0, token_program1, system_program, rent, _remaining @ ..] =
+        let [pool_creator, amm_config, pool_state, token_mint0, token_mint1, token_vault0, token_vault1, observation_state, tick_array_bitmap, token_program0, token_program1, system_program, rent, remaining @ ..] =
             accounts
         else {
             return None;
@@ -51,6 +54,121 @@ impl carbon_core::deserialize::ArrangeAccounts for CreatePool {
             token_program1: token_program1.pubkey,
             system_program: system_program.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CreatePoolInstructionAccountMetas {
+    pub pool_creator: solana_instruction::AccountMeta,
+    pub amm_config: solana_instruction::AccountMeta,
+    pub pool_state: solana_instruction::AccountMeta,
+    pub token_mint0: solana_instruction::AccountMeta,
+    pub token_mint1: solana_instruction::AccountMeta,
+    pub token_vault0: solana_instruction::AccountMeta,
+    pub token_vault1: solana_instruction::AccountMeta,
+    pub observation_state: solana_instruction::AccountMeta,
+    pub tick_array_bitmap: solana_instruction::AccountMeta,
+    pub token_program0: solana_instruction::AccountMeta,
+    pub token_program1: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CreatePool {
+    type ArrangedAccountMetas = CreatePoolInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [pool_creator, amm_config, pool_state, token_mint0, token_mint1, token_vault0, token_vault1, observation_state, tick_array_bitmap, token_program0, token_program1, system_program, rent, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CreatePoolInstructionAccountMetas {
+            pool_creator: pool_creator.clone(),
+            amm_config: amm_config.clone(),
+            pool_state: pool_state.clone(),
+            token_mint0: token_mint0.clone(),
+            token_mint1: token_mint1.clone(),
+            token_vault0: token_vault0.clone(),
+            token_vault1: token_vault1.clone(),
+            observation_state: observation_state.clone(),
+            tick_array_bitmap: tick_array_bitmap.clone(),
+            token_program0: token_program0.clone(),
+            token_program1: token_program1.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CreatePoolInstructionAccountIndices {
+    pub pool_creator: usize,
+    pub amm_config: usize,
+    pub pool_state: usize,
+    pub token_mint0: usize,
+    pub token_mint1: usize,
+    pub token_vault0: usize,
+    pub token_vault1: usize,
+    pub observation_state: usize,
+    pub tick_array_bitmap: usize,
+    pub token_program0: usize,
+    pub token_program1: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CreatePool {
+    type ArrangedAccountIndices = CreatePoolInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 13 {
+            return None;
+        }
+
+        Some(CreatePoolInstructionAccountIndices {
+            pool_creator: 0,
+            amm_config: 1,
+            pool_state: 2,
+            token_mint0: 3,
+            token_mint1: 4,
+            token_vault0: 5,
+            token_vault1: 6,
+            observation_state: 7,
+            tick_array_bitmap: 8,
+            token_program0: 9,
+            token_program1: 10,
+            system_program: 11,
+            rent: 12,
+            remaining_accounts: 13..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CreatePool {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "pool_creator",
+            "amm_config",
+            "pool_state",
+            "token_mint0",
+            "token_mint1",
+            "token_vault0",
+            "token_vault1",
+            "observation_state",
+            "tick_array_bitmap",
+            "token_program0",
+            "token_program1",
+            "system_program",
+            "rent",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -23,6 +25,7 @@ pub struct DecreaseLiquidityInstructionAccounts {
     pub recipient_token_account0: solana_pubkey::Pubkey,
     pub recipient_token_account1: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for DecreaseLiquidity {
@@ -31,7 +34,7 @@ impl carbon_core::deserialize::ArrangeAccounts for DecreaseLiquidity {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [nft_owner, nft_account, personal_position, pool_state, protocol_position, token_vault0, token_vault1, tick_array_lower, tick_array_upper, recipient_token_account0, recipient_token_account1, token_program, _remaining @ ..] =
+        let [nft_owner, nft_account, personal_position, pool_state, protocol_position, token_vault0, token_vault1, tick_array_lower, tick_array_upper, recipient_token_account0, recipient_token_account1, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -50,6 +53,116 @@ impl carbon_core::deserialize::ArrangeAccounts for DecreaseLiquidity {
             recipient_token_account0: recipient_token_account0.pubkey,
             recipient_token_account1: recipient_token_account1.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DecreaseLiquidityInstructionAccountMetas {
+    pub nft_owner: solana_instruction::AccountMeta,
+    pub nft_account: solana_instruction::AccountMeta,
+    pub personal_position: solana_instruction::AccountMeta,
+    pub pool_state: solana_instruction::AccountMeta,
+    pub protocol_position: solana_instruction::AccountMeta,
+    pub token_vault0: solana_instruction::AccountMeta,
+    pub token_vault1: solana_instruction::AccountMeta,
+    pub tick_array_lower: solana_instruction::AccountMeta,
+    pub tick_array_upper: solana_instruction::AccountMeta,
+    pub recipient_token_account0: solana_instruction::AccountMeta,
+    pub recipient_token_account1: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for DecreaseLiquidity {
+    type ArrangedAccountMetas = DecreaseLiquidityInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [nft_owner, nft_account, personal_position, pool_state, protocol_position, token_vault0, token_vault1, tick_array_lower, tick_array_upper, recipient_token_account0, recipient_token_account1, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(DecreaseLiquidityInstructionAccountMetas {
+            nft_owner: nft_owner.clone(),
+            nft_account: nft_account.clone(),
+            personal_position: personal_position.clone(),
+            pool_state: pool_state.clone(),
+            protocol_position: protocol_position.clone(),
+            token_vault0: token_vault0.clone(),
+            token_vault1: token_vault1.clone(),
+            tick_array_lower: tick_array_lower.clone(),
+            tick_array_upper: tick_array_upper.clone(),
+            recipient_token_account0: recipient_token_account0.clone(),
+            recipient_token_account1: recipient_token_account1.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DecreaseLiquidityInstructionAccountIndices {
+    pub nft_owner: usize,
+    pub nft_account: usize,
+    pub personal_position: usize,
+    pub pool_state: usize,
+    pub protocol_position: usize,
+    pub token_vault0: usize,
+    pub token_vault1: usize,
+    pub tick_array_lower: usize,
+    pub tick_array_upper: usize,
+    pub recipient_token_account0: usize,
+    pub recipient_token_account1: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for DecreaseLiquidity {
+    type ArrangedAccountIndices = DecreaseLiquidityInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 12 {
+            return None;
+        }
+
+        Some(DecreaseLiquidityInstructionAccountIndices {
+            nft_owner: 0,
+            nft_account: 1,
+            personal_position: 2,
+            pool_state: 3,
+            protocol_position: 4,
+            token_vault0: 5,
+            token_vault1: 6,
+            tick_array_lower: 7,
+            tick_array_upper: 8,
+            recipient_token_account0: 9,
+            recipient_token_account1: 10,
+            token_program: 11,
+            remaining_accounts: 12..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for DecreaseLiquidity {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "nft_owner",
+            "nft_account",
+            "personal_position",
+            "pool_state",
+            "protocol_position",
+            "token_vault0",
+            "token_vault1",
+            "tick_array_lower",
+            "tick_array_upper",
+            "recipient_token_account0",
+            "recipient_token_account1",
+            "token_program",
+        ]
+    }
+}
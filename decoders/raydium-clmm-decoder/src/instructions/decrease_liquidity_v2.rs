@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -27,6 +29,7 @@ pub struct DecreaseLiquidityV2InstructionAccounts {
     pub memo_program: solana_pubkey::Pubkey,
     pub vault0_mint: solana_pubkey::Pubkey,
     pub vault1_mint: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for DecreaseLiquidityV2 {
@@ -35,7 +38,7 @@ impl carbon_core::deserialize::ArrangeAccounts for DecreaseLiquidityV2 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [nft_owner, nft_account, personal_position, pool_state, protocol_position, token_vault0, token_vault1, tick_array_lower, tick_array_upper, recipient_token_account0, recipient_token_account1, token_program, token_program2022, memo_program, vault0_mint, vault1_mint, _remaining @ ..] =
+        let [nft_owner, nft_account, personal_position, pool_state, protocol_position, token_vault0, token_vault1, tick_array_lower, tick_array_upper, recipient_token_account0, recipient_token_account1, token_program, token_program2022, memo_program, vault0_mint, vault1_mint, remaining @ ..] =
             accounts
         else {
             return None;
@@ -58,6 +61,136 @@ impl carbon_core::deserialize::ArrangeAccounts for DecreaseLiquidityV2 {
             memo_program: memo_program.pubkey,
             vault0_mint: vault0_mint.pubkey,
             vault1_mint: vault1_mint.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DecreaseLiquidityV2InstructionAccountMetas {
+    pub nft_owner: solana_instruction::AccountMeta,
+    pub nft_account: solana_instruction::AccountMeta,
+    pub personal_position: solana_instruction::AccountMeta,
+    pub pool_state: solana_instruction::AccountMeta,
+    pub protocol_position: solana_instruction::AccountMeta,
+    pub token_vault0: solana_instruction::AccountMeta,
+    pub token_vault1: solana_instruction::AccountMeta,
+    pub tick_array_lower: solana_instruction::AccountMeta,
+    pub tick_array_upper: solana_instruction::AccountMeta,
+    pub recipient_token_account0: solana_instruction::AccountMeta,
+    pub recipient_token_account1: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub token_program2022: solana_instruction::AccountMeta,
+    pub memo_program: solana_instruction::AccountMeta,
+    pub vault0_mint: solana_instruction::AccountMeta,
+    pub vault1_mint: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for DecreaseLiquidityV2 {
+    type ArrangedAccountMetas = DecreaseLiquidityV2InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [nft_owner, nft_account, personal_position, pool_state, protocol_position, token_vault0, token_vault1, tick_array_lower, tick_array_upper, recipient_token_account0, recipient_token_account1, token_program, token_program2022, memo_program, vault0_mint, vault1_mint, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(DecreaseLiquidityV2InstructionAccountMetas {
+            nft_owner: nft_owner.clone(),
+            nft_account: nft_account.clone(),
+            personal_position: personal_position.clone(),
+            pool_state: pool_state.clone(),
+            protocol_position: protocol_position.clone(),
+            token_vault0: token_vault0.clone(),
+            token_vault1: token_vault1.clone(),
+            tick_array_lower: tick_array_lower.clone(),
+            tick_array_upper: tick_array_upper.clone(),
+            recipient_token_account0: recipient_token_account0.clone(),
+            recipient_token_account1: recipient_token_account1.clone(),
+            token_program: token_program.clone(),
+            token_program2022: token_program2022.clone(),
+            memo_program: memo_program.clone(),
+            vault0_mint: vault0_mint.clone(),
+            vault1_mint: vault1_mint.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DecreaseLiquidityV2InstructionAccountIndices {
+    pub nft_owner: usize,
+    pub nft_account: usize,
+    pub personal_position: usize,
+    pub pool_state: usize,
+    pub protocol_position: usize,
+    pub token_vault0: usize,
+    pub token_vault1: usize,
+    pub tick_array_lower: usize,
+    pub tick_array_upper: usize,
+    pub recipient_token_account0: usize,
+    pub recipient_token_account1: usize,
+    pub token_program: usize,
+    pub token_program2022: usize,
+    pub memo_program: usize,
+    pub vault0_mint: usize,
+    pub vault1_mint: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for DecreaseLiquidityV2 {
+    type ArrangedAccountIndices = DecreaseLiquidityV2InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 16 {
+            return None;
+        }
+
+        Some(DecreaseLiquidityV2InstructionAccountIndices {
+            nft_owner: 0,
+            nft_account: 1,
+            personal_position: 2,
+            pool_state: 3,
+            protocol_position: 4,
+            token_vault0: 5,
+            token_vault1: 6,
+            tick_array_lower: 7,
+            tick_array_upper: 8,
+            recipient_token_account0: 9,
+            recipient_token_account1: 10,
+            token_program: 11,
+            token_program2022: 12,
+            memo_program: 13,
+            vault0_mint: 14,
+            vault1_mint: 15,
+            remaining_accounts: 16..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for DecreaseLiquidityV2 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "nft_owner",
+            "nft_account",
+            "personal_position",
+            "pool_state",
+            "protocol_position",
+            "token_vault0",
+            "token_vault1",
+            "tick_array_lower",
+            "tick_array_upper",
+            "recipient_token_account0",
+            "recipient_token_account1",
+            "token_program",
+            "token_program2022",
+            "memo_program",
+            "vault0_mint",
+            "vault1_mint",
+        ]
+    }
+}
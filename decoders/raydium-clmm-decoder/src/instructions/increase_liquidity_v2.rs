@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -27,6 +29,7 @@ pub struct IncreaseLiquidityV2InstructionAccounts {
     pub token_program2022: solana_pubkey::Pubkey,
     pub vault0_mint: solana_pubkey::Pubkey,
     pub vault1_mint: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for IncreaseLiquidityV2 {
@@ -35,7 +38,7 @@ impl carbon_core::deserialize::ArrangeAccounts for IncreaseLiquidityV2 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [nft_owner, nft_account, pool_state, protocol_position, personal_position, tick_array_lower, tick_array_upper, token_account0, token_account1, token_vault0, token_vault1, token_program, token_program2022, vault0_mint, vault1_mint, _remaining @ ..] =
+        let [nft_owner, nft_account, pool_state, protocol_position, personal_position, tick_array_lower, tick_array_upper, token_account0, token_account1, token_vault0, token_vault1, token_program, token_program2022, vault0_mint, vault1_mint, remaining @ ..] =
             accounts
         else {
             return None;
@@ -57,6 +60,131 @@ impl carbon_core::deserialize::ArrangeAccounts for IncreaseLiquidityV2 {
             token_program2022: token_program2022.pubkey,
             vault0_mint: vault0_mint.pubkey,
             vault1_mint: vault1_mint.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct IncreaseLiquidityV2InstructionAccountMetas {
+    pub nft_owner: solana_instruction::AccountMeta,
+    pub nft_account: solana_instruction::AccountMeta,
+    pub pool_state: solana_instruction::AccountMeta,
+    pub protocol_position: solana_instruction::AccountMeta,
+    pub personal_position: solana_instruction::AccountMeta,
+    pub tick_array_lower: solana_instruction::AccountMeta,
+    pub tick_array_upper: solana_instruction::AccountMeta,
+    pub token_account0: solana_instruction::AccountMeta,
+    pub token_account1: solana_instruction::AccountMeta,
+    pub token_vault0: solana_instruction::AccountMeta,
+    pub token_vault1: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub token_program2022: solana_instruction::AccountMeta,
+    pub vault0_mint: solana_instruction::AccountMeta,
+    pub vault1_mint: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for IncreaseLiquidityV2 {
+    type ArrangedAccountMetas = IncreaseLiquidityV2InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [nft_owner, nft_account, pool_state, protocol_position, personal_position, tick_array_lower, tick_array_upper, token_account0, token_account1, token_vault0, token_vault1, token_program, token_program2022, vault0_mint, vault1_mint, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(IncreaseLiquidityV2InstructionAccountMetas {
+            nft_owner: nft_owner.clone(),
+            nft_account: nft_account.clone(),
+            pool_state: pool_state.clone(),
+            protocol_position: protocol_position.clone(),
+            personal_position: personal_position.clone(),
+            tick_array_lower: tick_array_lower.clone(),
+            tick_array_upper: tick_array_upper.clone(),
+            token_account0: token_account0.clone(),
+            token_account1: token_account1.clone(),
+            token_vault0: token_vault0.clone(),
+            token_vault1: token_vault1.clone(),
+            token_program: token_program.clone(),
+            token_program2022: token_program2022.clone(),
+            vault0_mint: vault0_mint.clone(),
+            vault1_mint: vault1_mint.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct IncreaseLiquidityV2InstructionAccountIndices {
+    pub nft_owner: usize,
+    pub nft_account: usize,
+    pub pool_state: usize,
+    pub protocol_position: usize,
+    pub personal_position: usize,
+    pub tick_array_lower: usize,
+    pub tick_array_upper: usize,
+    pub token_account0: usize,
+    pub token_account1: usize,
+    pub token_vault0: usize,
+    pub token_vault1: usize,
+    pub token_program: usize,
+    pub token_program2022: usize,
+    pub vault0_mint: usize,
+    pub vault1_mint: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for IncreaseLiquidityV2 {
+    type ArrangedAccountIndices = IncreaseLiquidityV2InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 15 {
+            return None;
+        }
+
+        Some(IncreaseLiquidityV2InstructionAccountIndices {
+            nft_owner: 0,
+            nft_account: 1,
+            pool_state: 2,
+            protocol_position: 3,
+            personal_position: 4,
+            tick_array_lower: 5,
+            tick_array_upper: 6,
+            token_account0: 7,
+            token_account1: 8,
+            token_vault0: 9,
+            token_vault1: 10,
+            token_program: 11,
+            token_program2022: 12,
+            vault0_mint: 13,
+            vault1_mint: 14,
+            remaining_accounts: 15..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for IncreaseLiquidityV2 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "nft_owner",
+            "nft_account",
+            "pool_state",
+            "protocol_position",
+            "personal_position",
+            "tick_array_lower",
+            "tick_array_upper",
+            "token_account0",
+            "token_account1",
+            "token_vault0",
+            "token_vault1",
+            "token_program",
+            "token_program2022",
+            "vault0_mint",
+            "vault1_mint",
+        ]
+    }
+}
@@ -1,8 +1,10 @@
+use alloc::vec::Vec;
 use {
     super::super::types::*,
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -22,6 +24,7 @@ pub struct InitializeRewardInstructionAccounts {
     pub reward_token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeReward {
@@ -30,7 +33,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeReward {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [reward_funder, funder_token_account, amm_config, pool_state, operation_state, reward_token_mint, reward_token_vault, reward_token_program, system_program, rent, _remaining @ ..] =
+        let [reward_funder, funder_token_account, amm_config, pool_state, operation_state, reward_token_mint, reward_token_vault, reward_token_program, system_program, rent, remaining @ ..] =
             accounts
         else {
             return None;
@@ -47,6 +50,106 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeReward {
             reward_token_program: reward_token_program.pubkey,
             system_program: system_program.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeRewardInstructionAccountMetas {
+    pub reward_funder: solana_instruction::AccountMeta,
+    pub funder_token_account: solana_instruction::AccountMeta,
+    pub amm_config: solana_instruction::AccountMeta,
+    pub pool_state: solana_instruction::AccountMeta,
+    pub operation_state: solana_instruction::AccountMeta,
+    pub reward_token_mint: solana_instruction::AccountMeta,
+    pub reward_token_vault: solana_instruction::AccountMeta,
+    pub reward_token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeReward {
+    type ArrangedAccountMetas = InitializeRewardInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [reward_funder, funder_token_account, amm_config, pool_state, operation_state, reward_token_mint, reward_token_vault, reward_token_program, system_program, rent, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeRewardInstructionAccountMetas {
+            reward_funder: reward_funder.clone(),
+            funder_token_account: funder_token_account.clone(),
+            amm_config: amm_config.clone(),
+            pool_state: pool_state.clone(),
+            operation_state: operation_state.clone(),
+            reward_token_mint: reward_token_mint.clone(),
+            reward_token_vault: reward_token_vault.clone(),
+            reward_token_program: reward_token_program.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeRewardInstructionAccountIndices {
+    pub reward_funder: usize,
+    pub funder_token_account: usize,
+    pub amm_config: usize,
+    pub pool_state: usize,
+    pub operation_state: usize,
+    pub reward_token_mint: usize,
+    pub reward_token_vault: usize,
+    pub reward_token_program: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeReward {
+    type ArrangedAccountIndices = InitializeRewardInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 10 {
+            return None;
+        }
+
+        Some(InitializeRewardInstructionAccountIndices {
+            reward_funder: 0,
+            funder_token_account: 1,
+            amm_config: 2,
+            pool_state: 3,
+            operation_state: 4,
+            reward_token_mint: 5,
+            reward_token_vault: 6,
+            reward_token_program: 7,
+            system_program: 8,
+            rent: 9,
+            remaining_accounts: 10..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeReward {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "reward_funder",
+            "funder_token_account",
+            "amm_config",
+            "pool_state",
+            "operation_state",
+            "reward_token_mint",
+            "reward_token_vault",
+            "reward_token_program",
+            "system_program",
+            "rent",
+        ]
+    }
+}
@@ -88,15 +88,25 @@ pub enum RaydiumClmmInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for RaydiumClmmDecoder {
     type InstructionType = RaydiumClmmInstruction;
 
-    fn decode_instruction(
+    fn decoder_name(&self) -> &'static str {
+        "raydium_clmm"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[super::PROGRAM_ID]
+    }
+
+    fn try_decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
-    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+    ) -> carbon_core::error::CarbonResult<
+        Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>>,
+    > {
         if !instruction.program_id.eq(&PROGRAM_ID) {
-            return None;
+            return Ok(None);
         }
 
-        carbon_core::try_decode_instructions!(instruction,
+        Ok(carbon_core::try_decode_instructions!(instruction,
             RaydiumClmmInstruction::CreateAmmConfig => create_amm_config::CreateAmmConfig,
             RaydiumClmmInstruction::UpdateAmmConfig => update_amm_config::UpdateAmmConfig,
             RaydiumClmmInstruction::CreatePool => create_pool::CreatePool,
@@ -132,6 +142,6 @@ impl carbon_core::instruction::InstructionDecoder<'_> for RaydiumClmmDecoder {
             RaydiumClmmInstruction::CollectProtocolFeeEvent => collect_protocol_fee_event::CollectProtocolFeeEvent,
             RaydiumClmmInstruction::SwapEvent => swap_event::SwapEvent,
             RaydiumClmmInstruction::LiquidityChangeEvent => liquidity_change_event::LiquidityChangeEvent,
-        )
+        ))
     }
 }
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -39,6 +41,7 @@ pub struct OpenPositionV2InstructionAccounts {
     pub token_program2022: solana_pubkey::Pubkey,
     pub vault0_mint: solana_pubkey::Pubkey,
     pub vault1_mint: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for OpenPositionV2 {
@@ -47,7 +50,7 @@ impl carbon_core::deserialize::ArrangeAccounts for OpenPositionV2 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [payer, position_nft_owner, position_nft_mint, position_nft_account, metadata_account, pool_state, protocol_position, tick_array_lower, tick_array_upper, personal_position, token_account0, token_account1, token_vault0, token_vault1, rent, system_program, token_program, associated_token_program, metadata_program, token_program2022, vault0_mint, vault1_mint, _remaining @ ..] =
+        let [payer, position_nft_owner, position_nft_mint, position_nft_account, metadata_account, pool_state, protocol_position, tick_array_lower, tick_array_upper, personal_position, token_account0, token_account1, token_vault0, token_vault1, rent, system_program, token_program, associated_token_program, metadata_program, token_program2022, vault0_mint, vault1_mint, remaining @ ..] =
             accounts
         else {
             return None;
@@ -76,6 +79,166 @@ impl carbon_core::deserialize::ArrangeAccounts for OpenPositionV2 {
             token_program2022: token_program2022.pubkey,
             vault0_mint: vault0_mint.pubkey,
             vault1_mint: vault1_mint.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct OpenPositionV2InstructionAccountMetas {
+    pub payer: solana_instruction::AccountMeta,
+    pub position_nft_owner: solana_instruction::AccountMeta,
+    pub position_nft_mint: solana_instruction::AccountMeta,
+    pub position_nft_account: solana_instruction::AccountMeta,
+    pub metadata_account: solana_instruction::AccountMeta,
+    pub pool_state: solana_instruction::AccountMeta,
+    pub protocol_position: solana_instruction::AccountMeta,
+    pub tick_array_lower: solana_instruction::AccountMeta,
+    pub tick_array_upper: solana_instruction::AccountMeta,
+    pub personal_position: solana_instruction::AccountMeta,
+    pub token_account0: solana_instruction::AccountMeta,
+    pub token_account1: solana_instruction::AccountMeta,
+    pub token_vault0: solana_instruction::AccountMeta,
+    pub token_vault1: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub metadata_program: solana_instruction::AccountMeta,
+    pub token_program2022: solana_instruction::AccountMeta,
+    pub vault0_mint: solana_instruction::AccountMeta,
+    pub vault1_mint: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for OpenPositionV2 {
+    type ArrangedAccountMetas = OpenPositionV2InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [payer, position_nft_owner, position_nft_mint, position_nft_account, metadata_account, pool_state, protocol_position, tick_array_lower, tick_array_upper, personal_position, token_account0, token_account1, token_vault0, token_vault1, rent, system_program, token_program, associated_token_program, metadata_program, token_program2022, vault0_mint, vault1_mint, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(OpenPositionV2InstructionAccountMetas {
+            payer: payer.clone(),
+            position_nft_owner: position_nft_owner.clone(),
+            position_nft_mint: position_nft_mint.clone(),
+            position_nft_account: position_nft_account.clone(),
+            metadata_account: metadata_account.clone(),
+            pool_state: pool_state.clone(),
+            protocol_position: protocol_position.clone(),
+            tick_array_lower: tick_array_lower.clone(),
+            tick_array_upper: tick_array_upper.clone(),
+            personal_position: personal_position.clone(),
+            token_account0: token_account0.clone(),
+            token_account1: token_account1.clone(),
+            token_vault0: token_vault0.clone(),
+            token_vault1: token_vault1.clone(),
+            rent: rent.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            associated_token_program: associated_token_program.clone(),
+            metadata_program: metadata_program.clone(),
+            token_program2022: token_program2022.clone(),
+            vault0_mint: vault0_mint.clone(),
+            vault1_mint: vault1_mint.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct OpenPositionV2InstructionAccountIndices {
+    pub payer: usize,
+    pub position_nft_owner: usize,
+    pub position_nft_mint: usize,
+    pub position_nft_account: usize,
+    pub metadata_account: usize,
+    pub pool_state: usize,
+    pub protocol_position: usize,
+    pub tick_array_lower: usize,
+    pub tick_array_upper: usize,
+    pub personal_position: usize,
+    pub token_account0: usize,
+    pub token_account1: usize,
+    pub token_vault0: usize,
+    pub token_vault1: usize,
+    pub rent: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub associated_token_program: usize,
+    pub metadata_program: usize,
+    pub token_program2022: usize,
+    pub vault0_mint: usize,
+    pub vault1_mint: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for OpenPositionV2 {
+    type ArrangedAccountIndices = OpenPositionV2InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 22 {
+            return None;
+        }
+
+        Some(OpenPositionV2InstructionAccountIndices {
+            payer: 0,
+            position_nft_owner: 1,
+            position_nft_mint: 2,
+            position_nft_account: 3,
+            metadata_account: 4,
+            pool_state: 5,
+            protocol_position: 6,
+            tick_array_lower: 7,
+            tick_array_upper: 8,
+            personal_position: 9,
+            token_account0: 10,
+            token_account1: 11,
+            token_vault0: 12,
+            token_vault1: 13,
+            rent: 14,
+            system_program: 15,
+            token_program: 16,
+            associated_token_program: 17,
+            metadata_program: 18,
+            token_program2022: 19,
+            vault0_mint: 20,
+            vault1_mint: 21,
+            remaining_accounts: 22..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for OpenPositionV2 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "payer",
+            "position_nft_owner",
+            "position_nft_mint",
+            "position_nft_account",
+            "metadata_account",
+            "pool_state",
+            "protocol_position",
+            "tick_array_lower",
+            "tick_array_upper",
+            "personal_position",
+            "token_account0",
+            "token_account1",
+            "token_vault0",
+            "token_vault1",
+            "rent",
+            "system_program",
+            "token_program",
+            "associated_token_program",
+            "metadata_program",
+            "token_program2022",
+            "vault0_mint",
+            "vault1_mint",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -37,6 +39,7 @@ pub struct OpenPositionWithToken22NftInstructionAccounts {
     pub token_program2022: solana_pubkey::Pubkey,
     pub vault0_mint: solana_pubkey::Pubkey,
     pub vault1_mint: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for OpenPositionWithToken22Nft {
@@ -45,7 +48,7 @@ impl carbon_core::deserialize::ArrangeAccounts for OpenPositionWithToken22Nft {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [payer, position_nft_owner, position_nft_mint, position_nft_account, pool_state, protocol_position, tick_array_lower, tick_array_upper, personal_position, token_account0, token_account1, token_vault0, token_vault1, rent, system_program, token_program, associated_token_program, token_program2022, vault0_mint, vault1_mint, _remaining @ ..] =
+        let [payer, position_nft_owner, position_nft_mint, position_nft_account, pool_state, protocol_position, tick_array_lower, tick_array_upper, personal_position, token_account0, token_account1, token_vault0, token_vault1, rent, system_program, token_program, associated_token_program, token_program2022, vault0_mint, vault1_mint, remaining @ ..] =
             accounts
         else {
             return None;
@@ -72,6 +75,156 @@ impl carbon_core::deserialize::ArrangeAccounts for OpenPositionWithToken22Nft {
             token_program2022: token_program2022.pubkey,
             vault0_mint: vault0_mint.pubkey,
             vault1_mint: vault1_mint.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct OpenPositionWithToken22NftInstructionAccountMetas {
+    pub payer: solana_instruction::AccountMeta,
+    pub position_nft_owner: solana_instruction::AccountMeta,
+    pub position_nft_mint: solana_instruction::AccountMeta,
+    pub position_nft_account: solana_instruction::AccountMeta,
+    pub pool_state: solana_instruction::AccountMeta,
+    pub protocol_position: solana_instruction::AccountMeta,
+    pub tick_array_lower: solana_instruction::AccountMeta,
+    pub tick_array_upper: solana_instruction::AccountMeta,
+    pub personal_position: solana_instruction::AccountMeta,
+    pub token_account0: solana_instruction::AccountMeta,
+    pub token_account1: solana_instruction::AccountMeta,
+    pub token_vault0: solana_instruction::AccountMeta,
+    pub token_vault1: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub token_program2022: solana_instruction::AccountMeta,
+    pub vault0_mint: solana_instruction::AccountMeta,
+    pub vault1_mint: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for OpenPositionWithToken22Nft {
+    type ArrangedAccountMetas = OpenPositionWithToken22NftInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [payer, position_nft_owner, position_nft_mint, position_nft_account, pool_state, protocol_position, tick_array_lower, tick_array_upper, personal_position, token_account0, token_account1, token_vault0, token_vault1, rent, system_program, token_program, associated_token_program, token_program2022, vault0_mint, vault1_mint, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(OpenPositionWithToken22NftInstructionAccountMetas {
+            payer: payer.clone(),
+            position_nft_owner: position_nft_owner.clone(),
+            position_nft_mint: position_nft_mint.clone(),
+            position_nft_account: position_nft_account.clone(),
+            pool_state: pool_state.clone(),
+            protocol_position: protocol_position.clone(),
+            tick_array_lower: tick_array_lower.clone(),
+            tick_array_upper: tick_array_upper.clone(),
+            personal_position: personal_position.clone(),
+            token_account0: token_account0.clone(),
+            token_account1: token_account1.clone(),
+            token_vault0: token_vault0.clone(),
+            token_vault1: token_vault1.clone(),
+            rent: rent.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            associated_token_program: associated_token_program.clone(),
+            token_program2022: token_program2022.clone(),
+            vault0_mint: vault0_mint.clone(),
+            vault1_mint: vault1_mint.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct OpenPositionWithToken22NftInstructionAccountIndices {
+    pub payer: usize,
+    pub position_nft_owner: usize,
+    pub position_nft_mint: usize,
+    pub position_nft_account: usize,
+    pub pool_state: usize,
+    pub protocol_position: usize,
+    pub tick_array_lower: usize,
+    pub tick_array_upper: usize,
+    pub personal_position: usize,
+    pub token_account0: usize,
+    pub token_account1: usize,
+    pub token_vault0: usize,
+    pub token_vault1: usize,
+    pub rent: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub associated_token_program: usize,
+    pub token_program2022: usize,
+    pub vault0_mint: usize,
+    pub vault1_mint: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for OpenPositionWithToken22Nft {
+    type ArrangedAccountIndices = OpenPositionWithToken22NftInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 20 {
+            return None;
+        }
+
+        Some(OpenPositionWithToken22NftInstructionAccountIndices {
+            payer: 0,
+            position_nft_owner: 1,
+            position_nft_mint: 2,
+            position_nft_account: 3,
+            pool_state: 4,
+            protocol_position: 5,
+            tick_array_lower: 6,
+            tick_array_upper: 7,
+            personal_position: 8,
+            token_account0: 9,
+            token_account1: 10,
+            token_vault0: 11,
+            token_vault1: 12,
+            rent: 13,
+            system_program: 14,
+            token_program: 15,
+            associated_token_program: 16,
+            token_program2022: 17,
+            vault0_mint: 18,
+            vault1_mint: 19,
+            remaining_accounts: 20..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for OpenPositionWithToken22Nft {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "payer",
+            "position_nft_owner",
+            "position_nft_mint",
+            "position_nft_account",
+            "pool_state",
+            "protocol_position",
+            "tick_array_lower",
+            "tick_array_upper",
+            "personal_position",
+            "token_account0",
+            "token_account1",
+            "token_vault0",
+            "token_vault1",
+            "rent",
+            "system_program",
+            "token_program",
+            "associated_token_program",
+            "token_program2022",
+            "vault0_mint",
+            "vault1_mint",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -18,6 +20,7 @@ pub struct SetRewardParamsInstructionAccounts {
     pub operation_state: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub token_program2022: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SetRewardParams {
@@ -26,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for SetRewardParams {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [authority, amm_config, pool_state, operation_state, token_program, token_program2022, _remaining @ ..] =
+        let [authority, amm_config, pool_state, operation_state, token_program, token_program2022, remaining @ ..] =
             accounts
         else {
             return None;
@@ -39,6 +42,86 @@ impl carbon_core::deserialize::ArrangeAccounts for SetRewardParams {
             operation_state: operation_state.pubkey,
             token_program: token_program.pubkey,
             token_program2022: token_program2022.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SetRewardParamsInstructionAccountMetas {
+    pub authority: solana_instruction::AccountMeta,
+    pub amm_config: solana_instruction::AccountMeta,
+    pub pool_state: solana_instruction::AccountMeta,
+    pub operation_state: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub token_program2022: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SetRewardParams {
+    type ArrangedAccountMetas = SetRewardParamsInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [authority, amm_config, pool_state, operation_state, token_program, token_program2022, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(SetRewardParamsInstructionAccountMetas {
+            authority: authority.clone(),
+            amm_config: amm_config.clone(),
+            pool_state: pool_state.clone(),
+            operation_state: operation_state.clone(),
+            token_program: token_program.clone(),
+            token_program2022: token_program2022.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SetRewardParamsInstructionAccountIndices {
+    pub authority: usize,
+    pub amm_config: usize,
+    pub pool_state: usize,
+    pub operation_state: usize,
+    pub token_program: usize,
+    pub token_program2022: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SetRewardParams {
+    type ArrangedAccountIndices = SetRewardParamsInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(SetRewardParamsInstructionAccountIndices {
+            authority: 0,
+            amm_config: 1,
+            pool_state: 2,
+            operation_state: 3,
+            token_program: 4,
+            token_program2022: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SetRewardParams {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "authority",
+            "amm_config",
+            "pool_state",
+            "operation_state",
+            "token_program",
+            "token_program2022",
+        ]
+    }
+}
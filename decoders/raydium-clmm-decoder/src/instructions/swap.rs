@@ -1,6 +1,7 @@
 use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -53,3 +54,102 @@ impl carbon_core::deserialize::ArrangeAccounts for Swap {
         })
     }
 }
+
+pub struct SwapInstructionAccountMetas {
+    pub payer: solana_instruction::AccountMeta,
+    pub amm_config: solana_instruction::AccountMeta,
+    pub pool_state: solana_instruction::AccountMeta,
+    pub input_token_account: solana_instruction::AccountMeta,
+    pub output_token_account: solana_instruction::AccountMeta,
+    pub input_vault: solana_instruction::AccountMeta,
+    pub output_vault: solana_instruction::AccountMeta,
+    pub observation_state: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub tick_array: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Swap {
+    type ArrangedAccountMetas = SwapInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [payer, amm_config, pool_state, input_token_account, output_token_account, input_vault, output_vault, observation_state, token_program, tick_array, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(SwapInstructionAccountMetas {
+            payer: payer.clone(),
+            amm_config: amm_config.clone(),
+            pool_state: pool_state.clone(),
+            input_token_account: input_token_account.clone(),
+            output_token_account: output_token_account.clone(),
+            input_vault: input_vault.clone(),
+            output_vault: output_vault.clone(),
+            observation_state: observation_state.clone(),
+            token_program: token_program.clone(),
+            tick_array: tick_array.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SwapInstructionAccountIndices {
+    pub payer: usize,
+    pub amm_config: usize,
+    pub pool_state: usize,
+    pub input_token_account: usize,
+    pub output_token_account: usize,
+    pub input_vault: usize,
+    pub output_vault: usize,
+    pub observation_state: usize,
+    pub token_program: usize,
+    pub tick_array: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Swap {
+    type ArrangedAccountIndices = SwapInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 10 {
+            return None;
+        }
+
+        Some(SwapInstructionAccountIndices {
+            payer: 0,
+            amm_config: 1,
+            pool_state: 2,
+            input_token_account: 3,
+            output_token_account: 4,
+            input_vault: 5,
+            output_vault: 6,
+            observation_state: 7,
+            token_program: 8,
+            tick_array: 9,
+            remaining_accounts: 10..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Swap {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "payer",
+            "amm_config",
+            "pool_state",
+            "input_token_account",
+            "output_token_account",
+            "input_vault",
+            "output_vault",
+            "observation_state",
+            "token_program",
+            "tick_array",
+        ]
+    }
+}
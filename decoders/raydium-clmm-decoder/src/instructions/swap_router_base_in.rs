@@ -1,6 +1,7 @@
 use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -43,3 +44,82 @@ impl carbon_core::deserialize::ArrangeAccounts for SwapRouterBaseIn {
         })
     }
 }
+
+pub struct SwapRouterBaseInInstructionAccountMetas {
+    pub payer: solana_instruction::AccountMeta,
+    pub input_token_account: solana_instruction::AccountMeta,
+    pub input_token_mint: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub token_program2022: solana_instruction::AccountMeta,
+    pub memo_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SwapRouterBaseIn {
+    type ArrangedAccountMetas = SwapRouterBaseInInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [payer, input_token_account, input_token_mint, token_program, token_program2022, memo_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(SwapRouterBaseInInstructionAccountMetas {
+            payer: payer.clone(),
+            input_token_account: input_token_account.clone(),
+            input_token_mint: input_token_mint.clone(),
+            token_program: token_program.clone(),
+            token_program2022: token_program2022.clone(),
+            memo_program: memo_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SwapRouterBaseInInstructionAccountIndices {
+    pub payer: usize,
+    pub input_token_account: usize,
+    pub input_token_mint: usize,
+    pub token_program: usize,
+    pub token_program2022: usize,
+    pub memo_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SwapRouterBaseIn {
+    type ArrangedAccountIndices = SwapRouterBaseInInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(SwapRouterBaseInInstructionAccountIndices {
+            payer: 0,
+            input_token_account: 1,
+            input_token_mint: 2,
+            token_program: 3,
+            token_program2022: 4,
+            memo_program: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SwapRouterBaseIn {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "payer",
+            "input_token_account",
+            "input_token_mint",
+            "token_program",
+            "token_program2022",
+            "memo_program",
+        ]
+    }
+}
@@ -1,6 +1,7 @@
 use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -60,3 +61,119 @@ impl carbon_core::deserialize::ArrangeAccounts for SwapV2 {
         })
     }
 }
+
+#[derive(Debug)]
+pub struct SwapV2InstructionAccountMetas {
+    pub payer: solana_instruction::AccountMeta,
+    pub amm_config: solana_instruction::AccountMeta,
+    pub pool_state: solana_instruction::AccountMeta,
+    pub input_token_account: solana_instruction::AccountMeta,
+    pub output_token_account: solana_instruction::AccountMeta,
+    pub input_vault: solana_instruction::AccountMeta,
+    pub output_vault: solana_instruction::AccountMeta,
+    pub observation_state: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub token_program2022: solana_instruction::AccountMeta,
+    pub memo_program: solana_instruction::AccountMeta,
+    pub input_vault_mint: solana_instruction::AccountMeta,
+    pub output_vault_mint: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SwapV2 {
+    type ArrangedAccountMetas = SwapV2InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [payer, amm_config, pool_state, input_token_account, output_token_account, input_vault, output_vault, observation_state, token_program, token_program2022, memo_program, input_vault_mint, output_vault_mint, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(SwapV2InstructionAccountMetas {
+            payer: payer.clone(),
+            amm_config: amm_config.clone(),
+            pool_state: pool_state.clone(),
+            input_token_account: input_token_account.clone(),
+            output_token_account: output_token_account.clone(),
+            input_vault: input_vault.clone(),
+            output_vault: output_vault.clone(),
+            observation_state: observation_state.clone(),
+            token_program: token_program.clone(),
+            token_program2022: token_program2022.clone(),
+            memo_program: memo_program.clone(),
+            input_vault_mint: input_vault_mint.clone(),
+            output_vault_mint: output_vault_mint.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct SwapV2InstructionAccountIndices {
+    pub payer: usize,
+    pub amm_config: usize,
+    pub pool_state: usize,
+    pub input_token_account: usize,
+    pub output_token_account: usize,
+    pub input_vault: usize,
+    pub output_vault: usize,
+    pub observation_state: usize,
+    pub token_program: usize,
+    pub token_program2022: usize,
+    pub memo_program: usize,
+    pub input_vault_mint: usize,
+    pub output_vault_mint: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SwapV2 {
+    type ArrangedAccountIndices = SwapV2InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 13 {
+            return None;
+        }
+
+        Some(SwapV2InstructionAccountIndices {
+            payer: 0,
+            amm_config: 1,
+            pool_state: 2,
+            input_token_account: 3,
+            output_token_account: 4,
+            input_vault: 5,
+            output_vault: 6,
+            observation_state: 7,
+            token_program: 8,
+            token_program2022: 9,
+            memo_program: 10,
+            input_vault_mint: 11,
+            output_vault_mint: 12,
+            remaining_accounts: 13..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SwapV2 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "payer",
+            "amm_config",
+            "pool_state",
+            "input_token_account",
+            "output_token_account",
+            "input_vault",
+            "output_vault",
+            "observation_state",
+            "token_program",
+            "token_program2022",
+            "memo_program",
+            "input_vault_mint",
+            "output_vault_mint",
+        ]
+    }
+}
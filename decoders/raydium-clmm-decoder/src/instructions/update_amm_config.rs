@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +14,7 @@ pub struct UpdateAmmConfig {
 pub struct UpdateAmmConfigInstructionAccounts {
     pub owner: solana_pubkey::Pubkey,
     pub amm_config: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateAmmConfig {
@@ -20,13 +23,68 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateAmmConfig {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [owner, amm_config, _remaining @ ..] = accounts else {
+        let [owner, amm_config, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(UpdateAmmConfigInstructionAccounts {
             owner: owner.pubkey,
             amm_config: amm_config.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateAmmConfigInstructionAccountMetas {
+    pub owner: solana_instruction::AccountMeta,
+    pub amm_config: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateAmmConfig {
+    type ArrangedAccountMetas = UpdateAmmConfigInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [owner, amm_config, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateAmmConfigInstructionAccountMetas {
+            owner: owner.clone(),
+            amm_config: amm_config.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateAmmConfigInstructionAccountIndices {
+    pub owner: usize,
+    pub amm_config: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateAmmConfig {
+    type ArrangedAccountIndices = UpdateAmmConfigInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(UpdateAmmConfigInstructionAccountIndices {
+            owner: 0,
+            amm_config: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateAmmConfig {
+    fn account_names() -> &'static [&'static str] {
+        &["owner", "amm_config"]
+    }
+}
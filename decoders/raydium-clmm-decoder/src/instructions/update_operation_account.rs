@@ -1,6 +1,7 @@
 use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct UpdateOperationAccountInstructionAccounts {
     pub owner: solana_pubkey::Pubkey,
     pub operation_state: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateOperationAccount {
@@ -22,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateOperationAccount {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [owner, operation_state, system_program, _remaining @ ..] = accounts else {
+        let [owner, operation_state, system_program, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -30,6 +32,65 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateOperationAccount {
             owner: owner.pubkey,
             operation_state: operation_state.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateOperationAccountInstructionAccountMetas {
+    pub owner: solana_instruction::AccountMeta,
+    pub operation_state: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateOperationAccount {
+    type ArrangedAccountMetas = UpdateOperationAccountInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [owner, operation_state, system_program, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateOperationAccountInstructionAccountMetas {
+            owner: owner.clone(),
+            operation_state: operation_state.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateOperationAccountInstructionAccountIndices {
+    pub owner: usize,
+    pub operation_state: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateOperationAccount {
+    type ArrangedAccountIndices = UpdateOperationAccountInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(UpdateOperationAccountInstructionAccountIndices {
+            owner: 0,
+            operation_state: 1,
+            system_program: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateOperationAccount {
+    fn account_names() -> &'static [&'static str] {
+        &["owner", "operation_state", "system_program"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +13,7 @@ pub struct UpdatePoolStatus {
 pub struct UpdatePoolStatusInstructionAccounts {
     pub authority: solana_pubkey::Pubkey,
     pub pool_state: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdatePoolStatus {
@@ -19,13 +22,68 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdatePoolStatus {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [authority, pool_state, _remaining @ ..] = accounts else {
+        let [authority, pool_state, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(UpdatePoolStatusInstructionAccounts {
             authority: authority.pubkey,
             pool_state: pool_state.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdatePoolStatusInstructionAccountMetas {
+    pub authority: solana_instruction::AccountMeta,
+    pub pool_state: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdatePoolStatus {
+    type ArrangedAccountMetas = UpdatePoolStatusInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [authority, pool_state, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdatePoolStatusInstructionAccountMetas {
+            authority: authority.clone(),
+            pool_state: pool_state.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdatePoolStatusInstructionAccountIndices {
+    pub authority: usize,
+    pub pool_state: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdatePoolStatus {
+    type ArrangedAccountIndices = UpdatePoolStatusInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(UpdatePoolStatusInstructionAccountIndices {
+            authority: 0,
+            pool_state: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdatePoolStatus {
+    fn account_names() -> &'static [&'static str] {
+        &["authority", "pool_state"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -8,6 +10,7 @@ pub struct UpdateRewardInfos {}
 
 pub struct UpdateRewardInfosInstructionAccounts {
     pub pool_state: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateRewardInfos {
@@ -16,12 +19,63 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateRewardInfos {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [pool_state, _remaining @ ..] = accounts else {
+        let [pool_state, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(UpdateRewardInfosInstructionAccounts {
             pool_state: pool_state.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateRewardInfosInstructionAccountMetas {
+    pub pool_state: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateRewardInfos {
+    type ArrangedAccountMetas = UpdateRewardInfosInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [pool_state, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateRewardInfosInstructionAccountMetas {
+            pool_state: pool_state.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateRewardInfosInstructionAccountIndices {
+    pub pool_state: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateRewardInfos {
+    type ArrangedAccountIndices = UpdateRewardInfosInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.is_empty() {
+            return None;
+        }
+
+        Some(UpdateRewardInfosInstructionAccountIndices {
+            pool_state: 0,
+            remaining_accounts: 1..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateRewardInfos {
+    fn account_names() -> &'static [&'static str] {
+        &["pool_state"]
+    }
+}
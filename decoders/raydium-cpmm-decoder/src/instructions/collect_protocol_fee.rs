@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -22,6 +24,7 @@ pub struct CollectProtocolFeeInstructionAccounts {
     pub recipient_token1_account: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub token_program2022: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CollectProtocolFee {
@@ -30,7 +33,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CollectProtocolFee {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [owner, authority, pool_state, amm_config, token0_vault, token1_vault, vault0_mint, vault1_mint, recipient_token0_account, recipient_token1_account, token_program, token_program2022, _remaining @ ..] =
+        let [owner, authority, pool_state, amm_config, token0_vault, token1_vault, vault0_mint, vault1_mint, recipient_token0_account, recipient_token1_account, token_program, token_program2022, remaining @ ..] =
             accounts
         else {
             return None;
@@ -49,6 +52,116 @@ impl carbon_core::deserialize::ArrangeAccounts for CollectProtocolFee {
             recipient_token1_account: recipient_token1_account.pubkey,
             token_program: token_program.pubkey,
             token_program2022: token_program2022.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CollectProtocolFeeInstructionAccountMetas {
+    pub owner: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub pool_state: solana_instruction::AccountMeta,
+    pub amm_config: solana_instruction::AccountMeta,
+    pub token0_vault: solana_instruction::AccountMeta,
+    pub token1_vault: solana_instruction::AccountMeta,
+    pub vault0_mint: solana_instruction::AccountMeta,
+    pub vault1_mint: solana_instruction::AccountMeta,
+    pub recipient_token0_account: solana_instruction::AccountMeta,
+    pub recipient_token1_account: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub token_program2022: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CollectProtocolFee {
+    type ArrangedAccountMetas = CollectProtocolFeeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [owner, authority, pool_state, amm_config, token0_vault, token1_vault, vault0_mint, vault1_mint, recipient_token0_account, recipient_token1_account, token_program, token_program2022, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CollectProtocolFeeInstructionAccountMetas {
+            owner: owner.clone(),
+            authority: authority.clone(),
+            pool_state: pool_state.clone(),
+            amm_config: amm_config.clone(),
+            token0_vault: token0_vault.clone(),
+            token1_vault: token1_vault.clone(),
+            vault0_mint: vault0_mint.clone(),
+            vault1_mint: vault1_mint.clone(),
+            recipient_token0_account: recipient_token0_account.clone(),
+            recipient_token1_account: recipient_token1_account.clone(),
+            token_program: token_program.clone(),
+            token_program2022: token_program2022.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CollectProtocolFeeInstructionAccountIndices {
+    pub owner: usize,
+    pub authority: usize,
+    pub pool_state: usize,
+    pub amm_config: usize,
+    pub token0_vault: usize,
+    pub token1_vault: usize,
+    pub vault0_mint: usize,
+    pub vault1_mint: usize,
+    pub recipient_token0_account: usize,
+    pub recipient_token1_account: usize,
+    pub token_program: usize,
+    pub token_program2022: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CollectProtocolFee {
+    type ArrangedAccountIndices = CollectProtocolFeeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 12 {
+            return None;
+        }
+
+        Some(CollectProtocolFeeInstructionAccountIndices {
+            owner: 0,
+            authority: 1,
+            pool_state: 2,
+            amm_config: 3,
+            token0_vault: 4,
+            token1_vault: 5,
+            vault0_mint: 6,
+            vault1_mint: 7,
+            recipient_token0_account: 8,
+            recipient_token1_account: 9,
+            token_program: 10,
+            token_program2022: 11,
+            remaining_accounts: 12..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CollectProtocolFee {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "owner",
+            "authority",
+            "pool_state",
+            "amm_config",
+            "token0_vault",
+            "token1_vault",
+            "vault0_mint",
+            "vault1_mint",
+            "recipient_token0_account",
+            "recipient_token1_account",
+            "token_program",
+            "token_program2022",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -24,6 +26,7 @@ pub struct DepositInstructionAccounts {
     pub vault0_mint: solana_pubkey::Pubkey,
     pub vault1_mint: solana_pubkey::Pubkey,
     pub lp_mint: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Deposit {
@@ -32,7 +35,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Deposit {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [owner, authority, pool_state, owner_lp_token, token0_account, token1_account, token0_vault, token1_vault, token_program, token_program2022, vault0_mint, vault1_mint, lp_mint, _remaining @ ..] =
+        let [owner, authority, pool_state, owner_lp_token, token0_account, token1_account, token0_vault, token1_vault, token_program, token_program2022, vault0_mint, vault1_mint, lp_mint, remaining @ ..] =
             accounts
         else {
             return None;
@@ -52,6 +55,121 @@ impl carbon_core::deserialize::ArrangeAccounts for Deposit {
             vault0_mint: vault0_mint.pubkey,
             vault1_mint: vault1_mint.pubkey,
             lp_mint: lp_mint.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DepositInstructionAccountMetas {
+    pub owner: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub pool_state: solana_instruction::AccountMeta,
+    pub owner_lp_token: solana_instruction::AccountMeta,
+    pub token0_account: solana_instruction::AccountMeta,
+    pub token1_account: solana_instruction::AccountMeta,
+    pub token0_vault: solana_instruction::AccountMeta,
+    pub token1_vault: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub token_program2022: solana_instruction::AccountMeta,
+    pub vault0_mint: solana_instruction::AccountMeta,
+    pub vault1_mint: solana_instruction::AccountMeta,
+    pub lp_mint: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Deposit {
+    type ArrangedAccountMetas = DepositInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [owner, authority, pool_state, owner_lp_token, token0_account, token1_account, token0_vault, token1_vault, token_program, token_program2022, vault0_mint, vault1_mint, lp_mint, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(DepositInstructionAccountMetas {
+            owner: owner.clone(),
+            authority: authority.clone(),
+            pool_state: pool_state.clone(),
+            owner_lp_token: owner_lp_token.clone(),
+            token0_account: token0_account.clone(),
+            token1_account: token1_account.clone(),
+            token0_vault: token0_vault.clone(),
+            token1_vault: token1_vault.clone(),
+            token_program: token_program.clone(),
+            token_program2022: token_program2022.clone(),
+            vault0_mint: vault0_mint.clone(),
+            vault1_mint: vault1_mint.clone(),
+            lp_mint: lp_mint.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DepositInstructionAccountIndices {
+    pub owner: usize,
+    pub authority: usize,
+    pub pool_state: usize,
+    pub owner_lp_token: usize,
+    pub token0_account: usize,
+    pub token1_account: usize,
+    pub token0_vault: usize,
+    pub token1_vault: usize,
+    pub token_program: usize,
+    pub token_program2022: usize,
+    pub vault0_mint: usize,
+    pub vault1_mint: usize,
+    pub lp_mint: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Deposit {
+    type ArrangedAccountIndices = DepositInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 13 {
+            return None;
+        }
+
+        Some(DepositInstructionAccountIndices {
+            owner: 0,
+            authority: 1,
+            pool_state: 2,
+            owner_lp_token: 3,
+            token0_account: 4,
+            token1_account: 5,
+            token0_vault: 6,
+            token1_vault: 7,
+            token_program: 8,
+            token_program2022: 9,
+            vault0_mint: 10,
+            vault1_mint: 11,
+            lp_mint: 12,
+            remaining_accounts: 13..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Deposit {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "owner",
+            "authority",
+            "pool_state",
+            "owner_lp_token",
+            "token0_account",
+            "token1_account",
+            "token0_vault",
+            "token1_vault",
+            "token_program",
+            "token_program2022",
+            "vault0_mint",
+            "vault1_mint",
+            "lp_mint",
+        ]
+    }
+}
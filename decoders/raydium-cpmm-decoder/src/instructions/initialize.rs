@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -31,6 +33,7 @@ pub struct InitializeInstructionAccounts {
     pub associated_token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Initialize {
@@ -39,7 +42,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Initialize {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [creator, amm_config, authority, pool_state, token0_mint, token1_mint, lp_mint, creator_token0, creator_token1, creator_lp_token, token0_vault, token1_vault, create_pool_fee, observation_state, token_program, token0_program, token1_program, associated_token_program, system_program, rent, _remaining @ ..] =
+        let [creator, amm_config, authority, pool_state, token0_mint, token1_mint, lp_mint, creator_token0, creator_token1, creator_lp_token, token0_vault, token1_vault, create_pool_fee, observation_state, token_program, token0_program, token1_program, associated_token_program, system_program, rent, remaining @ ..] =
             accounts
         else {
             return None;
@@ -66,6 +69,156 @@ impl carbon_core::deserialize::ArrangeAccounts for Initialize {
             associated_token_program: associated_token_program.pubkey,
             system_program: system_program.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeInstructionAccountMetas {
+    pub creator: solana_instruction::AccountMeta,
+    pub amm_config: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub pool_state: solana_instruction::AccountMeta,
+    pub token0_mint: solana_instruction::AccountMeta,
+    pub token1_mint: solana_instruction::AccountMeta,
+    pub lp_mint: solana_instruction::AccountMeta,
+    pub creator_token0: solana_instruction::AccountMeta,
+    pub creator_token1: solana_instruction::AccountMeta,
+    pub creator_lp_token: solana_instruction::AccountMeta,
+    pub token0_vault: solana_instruction::AccountMeta,
+    pub token1_vault: solana_instruction::AccountMeta,
+    pub create_pool_fee: solana_instruction::AccountMeta,
+    pub observation_state: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub token0_program: solana_instruction::AccountMeta,
+    pub token1_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Initialize {
+    type ArrangedAccountMetas = InitializeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [creator, amm_config, authority, pool_state, token0_mint, token1_mint, lp_mint, creator_token0, creator_token1, creator_lp_token, token0_vault, token1_vault, create_pool_fee, observation_state, token_program, token0_program, token1_program, associated_token_program, system_program, rent, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeInstructionAccountMetas {
+            creator: creator.clone(),
+            amm_config: amm_config.clone(),
+            authority: authority.clone(),
+            pool_state: pool_state.clone(),
+            token0_mint: token0_mint.clone(),
+            token1_mint: token1_mint.clone(),
+            lp_mint: lp_mint.clone(),
+            creator_token0: creator_token0.clone(),
+            creator_token1: creator_token1.clone(),
+            creator_lp_token: creator_lp_token.clone(),
+            token0_vault: token0_vault.clone(),
+            token1_vault: token1_vault.clone(),
+            create_pool_fee: create_pool_fee.clone(),
+            observation_state: observation_state.clone(),
+            token_program: token_program.clone(),
+            token0_program: token0_program.clone(),
+            token1_program: token1_program.clone(),
+            associated_token_program: associated_token_program.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeInstructionAccountIndices {
+    pub creator: usize,
+    pub amm_config: usize,
+    pub authority: usize,
+    pub pool_state: usize,
+    pub token0_mint: usize,
+    pub token1_mint: usize,
+    pub lp_mint: usize,
+    pub creator_token0: usize,
+    pub creator_token1: usize,
+    pub creator_lp_token: usize,
+    pub token0_vault: usize,
+    pub token1_vault: usize,
+    pub create_pool_fee: usize,
+    pub observation_state: usize,
+    pub token_program: usize,
+    pub token0_program: usize,
+    pub token1_program: usize,
+    pub associated_token_program: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Initialize {
+    type ArrangedAccountIndices = InitializeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 20 {
+            return None;
+        }
+
+        Some(InitializeInstructionAccountIndices {
+            creator: 0,
+            amm_config: 1,
+            authority: 2,
+            pool_state: 3,
+            token0_mint: 4,
+            token1_mint: 5,
+            lp_mint: 6,
+            creator_token0: 7,
+            creator_token1: 8,
+            creator_lp_token: 9,
+            token0_vault: 10,
+            token1_vault: 11,
+            create_pool_fee: 12,
+            observation_state: 13,
+            token_program: 14,
+            token0_program: 15,
+            token1_program: 16,
+            associated_token_program: 17,
+            system_program: 18,
+            rent: 19,
+            remaining_accounts: 20..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Initialize {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "creator",
+            "amm_config",
+            "authority",
+            "pool_state",
+            "token0_mint",
+            "token1_mint",
+            "lp_mint",
+            "creator_token0",
+            "creator_token1",
+            "creator_lp_token",
+            "token0_vault",
+            "token1_vault",
+            "create_pool_fee",
+            "observation_state",
+            "token_program",
+            "token0_program",
+            "token1_program",
+            "associated_token_program",
+            "system_program",
+            "rent",
+        ]
+    }
+}
@@ -42,15 +42,25 @@ pub enum RaydiumCpmmInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for RaydiumCpmmDecoder {
     type InstructionType = RaydiumCpmmInstruction;
 
-    fn decode_instruction(
+    fn decoder_name(&self) -> &'static str {
+        "raydium_cpmm"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[super::PROGRAM_ID]
+    }
+
+    fn try_decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
-    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+    ) -> carbon_core::error::CarbonResult<
+        Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>>,
+    > {
         if !instruction.program_id.eq(&PROGRAM_ID) {
-            return None;
+            return Ok(None);
         }
 
-        carbon_core::try_decode_instructions!(instruction,
+        Ok(carbon_core::try_decode_instructions!(instruction,
             RaydiumCpmmInstruction::CreateAmmConfig => create_amm_config::CreateAmmConfig,
             RaydiumCpmmInstruction::UpdateAmmConfig => update_amm_config::UpdateAmmConfig,
             RaydiumCpmmInstruction::UpdatePoolStatus => update_pool_status::UpdatePoolStatus,
@@ -63,6 +73,6 @@ impl carbon_core::instruction::InstructionDecoder<'_> for RaydiumCpmmDecoder {
             RaydiumCpmmInstruction::SwapBaseOutput => swap_base_output::SwapBaseOutput,
             RaydiumCpmmInstruction::LpChangeEvent => lp_change_event::LpChangeEvent,
             RaydiumCpmmInstruction::SwapEvent => swap_event::SwapEvent,
-        )
+        ))
     }
 }
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -23,6 +25,7 @@ pub struct SwapBaseInputInstructionAccounts {
     pub input_token_mint: solana_pubkey::Pubkey,
     pub output_token_mint: solana_pubkey::Pubkey,
     pub observation_state: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SwapBaseInput {
@@ -31,7 +34,7 @@ impl carbon_core::deserialize::ArrangeAccounts for SwapBaseInput {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [payer, authority, amm_config, pool_state, input_token_account, output_token_account, input_vault, output_vault, input_token_program, output_token_program, input_token_mint, output_token_mint, observation_state, _remaining @ ..] =
+        let [payer, authority, amm_config, pool_state, input_token_account, output_token_account, input_vault, output_vault, input_token_program, output_token_program, input_token_mint, output_token_mint, observation_state, remaining @ ..] =
             accounts
         else {
             return None;
@@ -51,6 +54,121 @@ impl carbon_core::deserialize::ArrangeAccounts for SwapBaseInput {
             input_token_mint: input_token_mint.pubkey,
             output_token_mint: output_token_mint.pubkey,
             observation_state: observation_state.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SwapBaseInputInstructionAccountMetas {
+    pub payer: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub amm_config: solana_instruction::AccountMeta,
+    pub pool_state: solana_instruction::AccountMeta,
+    pub input_token_account: solana_instruction::AccountMeta,
+    pub output_token_account: solana_instruction::AccountMeta,
+    pub input_vault: solana_instruction::AccountMeta,
+    pub output_vault: solana_instruction::AccountMeta,
+    pub input_token_program: solana_instruction::AccountMeta,
+    pub output_token_program: solana_instruction::AccountMeta,
+    pub input_token_mint: solana_instruction::AccountMeta,
+    pub output_token_mint: solana_instruction::AccountMeta,
+    pub observation_state: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SwapBaseInput {
+    type ArrangedAccountMetas = SwapBaseInputInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [payer, authority, amm_config, pool_state, input_token_account, output_token_account, input_vault, output_vault, input_token_program, output_token_program, input_token_mint, output_token_mint, observation_state, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(SwapBaseInputInstructionAccountMetas {
+            payer: payer.clone(),
+            authority: authority.clone(),
+            amm_config: amm_config.clone(),
+            pool_state: pool_state.clone(),
+            input_token_account: input_token_account.clone(),
+            output_token_account: output_token_account.clone(),
+            input_vault: input_vault.clone(),
+            output_vault: output_vault.clone(),
+            input_token_program: input_token_program.clone(),
+            output_token_program: output_token_program.clone(),
+            input_token_mint: input_token_mint.clone(),
+            output_token_mint: output_token_mint.clone(),
+            observation_state: observation_state.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SwapBaseInputInstructionAccountIndices {
+    pub payer: usize,
+    pub authority: usize,
+    pub amm_config: usize,
+    pub pool_state: usize,
+    pub input_token_account: usize,
+    pub output_token_account: usize,
+    pub input_vault: usize,
+    pub output_vault: usize,
+    pub input_token_program: usize,
+    pub output_token_program: usize,
+    pub input_token_mint: usize,
+    pub output_token_mint: usize,
+    pub observation_state: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SwapBaseInput {
+    type ArrangedAccountIndices = SwapBaseInputInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 13 {
+            return None;
+        }
+
+        Some(SwapBaseInputInstructionAccountIndices {
+            payer: 0,
+            authority: 1,
+            amm_config: 2,
+            pool_state: 3,
+            input_token_account: 4,
+            output_token_account: 5,
+            input_vault: 6,
+            output_vault: 7,
+            input_token_program: 8,
+            output_token_program: 9,
+            input_token_mint: 10,
+            output_token_mint: 11,
+            observation_state: 12,
+            remaining_accounts: 13..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SwapBaseInput {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "payer",
+            "authority",
+            "amm_config",
+            "pool_state",
+            "input_token_account",
+            "output_token_account",
+            "input_vault",
+            "output_vault",
+            "input_token_program",
+            "output_token_program",
+            "input_token_mint",
+            "output_token_mint",
+            "observation_state",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -10,7 +12,7 @@ pub struct BuyExactOut {
     pub share_fee_rate: u64,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Hash, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
 pub struct BuyExactOutInstructionAccounts {
     pub payer: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
@@ -27,6 +29,7 @@ pub struct BuyExactOutInstructionAccounts {
     pub quote_token_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for BuyExactOut {
@@ -35,7 +38,7 @@ impl carbon_core::deserialize::ArrangeAccounts for BuyExactOut {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [payer, authority, global_config, platform_config, pool_state, user_base_token, user_quote_token, base_vault, quote_vault, base_token_mint, quote_token_mint, base_token_program, quote_token_program, event_authority, program, _remaining @ ..] =
+        let [payer, authority, global_config, platform_config, pool_state, user_base_token, user_quote_token, base_vault, quote_vault, base_token_mint, quote_token_mint, base_token_program, quote_token_program, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -57,6 +60,133 @@ impl carbon_core::deserialize::ArrangeAccounts for BuyExactOut {
             quote_token_program: quote_token_program.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
+pub struct BuyExactOutInstructionAccountMetas {
+    pub payer: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub global_config: solana_instruction::AccountMeta,
+    pub platform_config: solana_instruction::AccountMeta,
+    pub pool_state: solana_instruction::AccountMeta,
+    pub user_base_token: solana_instruction::AccountMeta,
+    pub user_quote_token: solana_instruction::AccountMeta,
+    pub base_vault: solana_instruction::AccountMeta,
+    pub quote_vault: solana_instruction::AccountMeta,
+    pub base_token_mint: solana_instruction::AccountMeta,
+    pub quote_token_mint: solana_instruction::AccountMeta,
+    pub base_token_program: solana_instruction::AccountMeta,
+    pub quote_token_program: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for BuyExactOut {
+    type ArrangedAccountMetas = BuyExactOutInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [payer, authority, global_config, platform_config, pool_state, user_base_token, user_quote_token, base_vault, quote_vault, base_token_mint, quote_token_mint, base_token_program, quote_token_program, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(BuyExactOutInstructionAccountMetas {
+            payer: payer.clone(),
+            authority: authority.clone(),
+            global_config: global_config.clone(),
+            platform_config: platform_config.clone(),
+            pool_state: pool_state.clone(),
+            user_base_token: user_base_token.clone(),
+            user_quote_token: user_quote_token.clone(),
+            base_vault: base_vault.clone(),
+            quote_vault: quote_vault.clone(),
+            base_token_mint: base_token_mint.clone(),
+            quote_token_mint: quote_token_mint.clone(),
+            base_token_program: base_token_program.clone(),
+            quote_token_program: quote_token_program.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
+pub struct BuyExactOutInstructionAccountIndices {
+    pub payer: usize,
+    pub authority: usize,
+    pub global_config: usize,
+    pub platform_config: usize,
+    pub pool_state: usize,
+    pub user_base_token: usize,
+    pub user_quote_token: usize,
+    pub base_vault: usize,
+    pub quote_vault: usize,
+    pub base_token_mint: usize,
+    pub quote_token_mint: usize,
+    pub base_token_program: usize,
+    pub quote_token_program: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for BuyExactOut {
+    type ArrangedAccountIndices = BuyExactOutInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 15 {
+            return None;
+        }
+
+        Some(BuyExactOutInstructionAccountIndices {
+            payer: 0,
+            authority: 1,
+            global_config: 2,
+            platform_config: 3,
+            pool_state: 4,
+            user_base_token: 5,
+            user_quote_token: 6,
+            base_vault: 7,
+            quote_vault: 8,
+            base_token_mint: 9,
+            quote_token_mint: 10,
+            base_token_program: 11,
+            quote_token_program: 12,
+            event_authority: 13,
+            program: 14,
+            remaining_accounts: 15..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for BuyExactOut {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "payer",
+            "authority",
+            "global_config",
+            "platform_config",
+            "pool_state",
+            "user_base_token",
+            "user_quote_token",
+            "base_vault",
+            "quote_vault",
+            "base_token_mint",
+            "quote_token_mint",
+            "base_token_program",
+            "quote_token_program",
+            "event_authority",
+            "program",
+        ]
+    }
+}
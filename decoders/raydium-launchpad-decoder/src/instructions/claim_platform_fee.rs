@@ -1,12 +1,14 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x9c27d0874ced3d48")]
 pub struct ClaimPlatformFee {}
 
-#[derive(Debug, PartialEq, Eq, Clone, Hash, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ClaimPlatformFeeInstructionAccounts {
     pub platform_fee_wallet: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
@@ -18,6 +20,7 @@ pub struct ClaimPlatformFeeInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub associated_token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ClaimPlatformFee {
@@ -26,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ClaimPlatformFee {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [platform_fee_wallet, authority, pool_state, platform_config, quote_vault, recipient_token_account, quote_mint, token_program, system_program, associated_token_program, _remaining @ ..] =
+        let [platform_fee_wallet, authority, pool_state, platform_config, quote_vault, recipient_token_account, quote_mint, token_program, system_program, associated_token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -43,6 +46,108 @@ impl carbon_core::deserialize::ArrangeAccounts for ClaimPlatformFee {
             token_program: token_program.pubkey,
             system_program: system_program.pubkey,
             associated_token_program: associated_token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ClaimPlatformFeeInstructionAccountMetas {
+    pub platform_fee_wallet: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub pool_state: solana_instruction::AccountMeta,
+    pub platform_config: solana_instruction::AccountMeta,
+    pub quote_vault: solana_instruction::AccountMeta,
+    pub recipient_token_account: solana_instruction::AccountMeta,
+    pub quote_mint: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ClaimPlatformFee {
+    type ArrangedAccountMetas = ClaimPlatformFeeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [platform_fee_wallet, authority, pool_state, platform_config, quote_vault, recipient_token_account, quote_mint, token_program, system_program, associated_token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(ClaimPlatformFeeInstructionAccountMetas {
+            platform_fee_wallet: platform_fee_wallet.clone(),
+            authority: authority.clone(),
+            pool_state: pool_state.clone(),
+            platform_config: platform_config.clone(),
+            quote_vault: quote_vault.clone(),
+            recipient_token_account: recipient_token_account.clone(),
+            quote_mint: quote_mint.clone(),
+            token_program: token_program.clone(),
+            system_program: system_program.clone(),
+            associated_token_program: associated_token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ClaimPlatformFeeInstructionAccountIndices {
+    pub platform_fee_wallet: usize,
+    pub authority: usize,
+    pub pool_state: usize,
+    pub platform_config: usize,
+    pub quote_vault: usize,
+    pub recipient_token_account: usize,
+    pub quote_mint: usize,
+    pub token_program: usize,
+    pub system_program: usize,
+    pub associated_token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ClaimPlatformFee {
+    type ArrangedAccountIndices = ClaimPlatformFeeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 10 {
+            return None;
+        }
+
+        Some(ClaimPlatformFeeInstructionAccountIndices {
+            platform_fee_wallet: 0,
+            authority: 1,
+            pool_state: 2,
+            platform_config: 3,
+            quote_vault: 4,
+            recipient_token_account: 5,
+            quote_mint: 6,
+            token_program: 7,
+            system_program: 8,
+            associated_token_program: 9,
+            remaining_accounts: 10..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ClaimPlatformFee {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "platform_fee_wallet",
+            "authority",
+            "pool_state",
+            "platform_config",
+            "quote_vault",
+            "recipient_token_account",
+            "quote_mint",
+            "token_program",
+            "system_program",
+            "associated_token_program",
+        ]
+    }
+}
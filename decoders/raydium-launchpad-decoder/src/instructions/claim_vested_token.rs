@@ -1,12 +1,14 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x3121681ebd9d4f23")]
 pub struct ClaimVestedToken {}
 
-#[derive(Debug, PartialEq, Eq, Clone, Hash, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ClaimVestedTokenInstructionAccounts {
     pub beneficiary: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
@@ -18,6 +20,7 @@ pub struct ClaimVestedTokenInstructionAccounts {
     pub base_token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub associated_token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ClaimVestedToken {
@@ -26,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ClaimVestedToken {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [beneficiary, authority, pool_state, vesting_record, base_vault, user_base_token, base_token_mint, base_token_program, system_program, associated_token_program, _remaining @ ..] =
+        let [beneficiary, authority, pool_state, vesting_record, base_vault, user_base_token, base_token_mint, base_token_program, system_program, associated_token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -43,6 +46,108 @@ impl carbon_core::deserialize::ArrangeAccounts for ClaimVestedToken {
             base_token_program: base_token_program.pubkey,
             system_program: system_program.pubkey,
             associated_token_program: associated_token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ClaimVestedTokenInstructionAccountMetas {
+    pub beneficiary: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub pool_state: solana_instruction::AccountMeta,
+    pub vesting_record: solana_instruction::AccountMeta,
+    pub base_vault: solana_instruction::AccountMeta,
+    pub user_base_token: solana_instruction::AccountMeta,
+    pub base_token_mint: solana_instruction::AccountMeta,
+    pub base_token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ClaimVestedToken {
+    type ArrangedAccountMetas = ClaimVestedTokenInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [beneficiary, authority, pool_state, vesting_record, base_vault, user_base_token, base_token_mint, base_token_program, system_program, associated_token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(ClaimVestedTokenInstructionAccountMetas {
+            beneficiary: beneficiary.clone(),
+            authority: authority.clone(),
+            pool_state: pool_state.clone(),
+            vesting_record: vesting_record.clone(),
+            base_vault: base_vault.clone(),
+            user_base_token: user_base_token.clone(),
+            base_token_mint: base_token_mint.clone(),
+            base_token_program: base_token_program.clone(),
+            system_program: system_program.clone(),
+            associated_token_program: associated_token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ClaimVestedTokenInstructionAccountIndices {
+    pub beneficiary: usize,
+    pub authority: usize,
+    pub pool_state: usize,
+    pub vesting_record: usize,
+    pub base_vault: usize,
+    pub user_base_token: usize,
+    pub base_token_mint: usize,
+    pub base_token_program: usize,
+    pub system_program: usize,
+    pub associated_token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ClaimVestedToken {
+    type ArrangedAccountIndices = ClaimVestedTokenInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 10 {
+            return None;
+        }
+
+        Some(ClaimVestedTokenInstructionAccountIndices {
+            beneficiary: 0,
+            authority: 1,
+            pool_state: 2,
+            vesting_record: 3,
+            base_vault: 4,
+            user_base_token: 5,
+            base_token_mint: 6,
+            base_token_program: 7,
+            system_program: 8,
+            associated_token_program: 9,
+            remaining_accounts: 10..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ClaimVestedToken {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "beneficiary",
+            "authority",
+            "pool_state",
+            "vesting_record",
+            "base_vault",
+            "user_base_token",
+            "base_token_mint",
+            "base_token_program",
+            "system_program",
+            "associated_token_program",
+        ]
+    }
+}
@@ -1,12 +1,14 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xffba96dfeb76c9ba")]
 pub struct CollectMigrateFee {}
 
-#[derive(Debug, PartialEq, Eq, Clone, Hash, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
 pub struct CollectMigrateFeeInstructionAccounts {
     pub owner: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
@@ -16,6 +18,7 @@ pub struct CollectMigrateFeeInstructionAccounts {
     pub quote_mint: solana_pubkey::Pubkey,
     pub recipient_token_account: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CollectMigrateFee {
@@ -24,7 +27,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CollectMigrateFee {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [owner, authority, pool_state, global_config, quote_vault, quote_mint, recipient_token_account, token_program, _remaining @ ..] =
+        let [owner, authority, pool_state, global_config, quote_vault, quote_mint, recipient_token_account, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -39,6 +42,98 @@ impl carbon_core::deserialize::ArrangeAccounts for CollectMigrateFee {
             quote_mint: quote_mint.pubkey,
             recipient_token_account: recipient_token_account.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CollectMigrateFeeInstructionAccountMetas {
+    pub owner: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub pool_state: solana_instruction::AccountMeta,
+    pub global_config: solana_instruction::AccountMeta,
+    pub quote_vault: solana_instruction::AccountMeta,
+    pub quote_mint: solana_instruction::AccountMeta,
+    pub recipient_token_account: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CollectMigrateFee {
+    type ArrangedAccountMetas = CollectMigrateFeeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [owner, authority, pool_state, global_config, quote_vault, quote_mint, recipient_token_account, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CollectMigrateFeeInstructionAccountMetas {
+            owner: owner.clone(),
+            authority: authority.clone(),
+            pool_state: pool_state.clone(),
+            global_config: global_config.clone(),
+            quote_vault: quote_vault.clone(),
+            quote_mint: quote_mint.clone(),
+            recipient_token_account: recipient_token_account.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CollectMigrateFeeInstructionAccountIndices {
+    pub owner: usize,
+    pub authority: usize,
+    pub pool_state: usize,
+    pub global_config: usize,
+    pub quote_vault: usize,
+    pub quote_mint: usize,
+    pub recipient_token_account: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CollectMigrateFee {
+    type ArrangedAccountIndices = CollectMigrateFeeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(CollectMigrateFeeInstructionAccountIndices {
+            owner: 0,
+            authority: 1,
+            pool_state: 2,
+            global_config: 3,
+            quote_vault: 4,
+            quote_mint: 5,
+            recipient_token_account: 6,
+            token_program: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CollectMigrateFee {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "owner",
+            "authority",
+            "pool_state",
+            "global_config",
+            "quote_vault",
+            "quote_mint",
+            "recipient_token_account",
+            "token_program",
+        ]
+    }
+}
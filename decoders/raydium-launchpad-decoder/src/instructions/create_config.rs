@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,7 +13,7 @@ pub struct CreateConfig {
     pub trade_fee_rate: u64,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Hash, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
 pub struct CreateConfigInstructionAccounts {
     pub owner: solana_pubkey::Pubkey,
     pub global_config: solana_pubkey::Pubkey,
@@ -21,6 +23,7 @@ pub struct CreateConfigInstructionAccounts {
     pub migrate_to_amm_wallet: solana_pubkey::Pubkey,
     pub migrate_to_cpswap_wallet: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CreateConfig {
@@ -29,7 +32,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateConfig {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [owner, global_config, quote_token_mint, protocol_fee_owner, migrate_fee_owner, migrate_to_amm_wallet, migrate_to_cpswap_wallet, system_program, _remaining @ ..] =
+        let [owner, global_config, quote_token_mint, protocol_fee_owner, migrate_fee_owner, migrate_to_amm_wallet, migrate_to_cpswap_wallet, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -44,6 +47,98 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateConfig {
             migrate_to_amm_wallet: migrate_to_amm_wallet.pubkey,
             migrate_to_cpswap_wallet: migrate_to_cpswap_wallet.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CreateConfigInstructionAccountMetas {
+    pub owner: solana_instruction::AccountMeta,
+    pub global_config: solana_instruction::AccountMeta,
+    pub quote_token_mint: solana_instruction::AccountMeta,
+    pub protocol_fee_owner: solana_instruction::AccountMeta,
+    pub migrate_fee_owner: solana_instruction::AccountMeta,
+    pub migrate_to_amm_wallet: solana_instruction::AccountMeta,
+    pub migrate_to_cpswap_wallet: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CreateConfig {
+    type ArrangedAccountMetas = CreateConfigInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [owner, global_config, quote_token_mint, protocol_fee_owner, migrate_fee_owner, migrate_to_amm_wallet, migrate_to_cpswap_wallet, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CreateConfigInstructionAccountMetas {
+            owner: owner.clone(),
+            global_config: global_config.clone(),
+            quote_token_mint: quote_token_mint.clone(),
+            protocol_fee_owner: protocol_fee_owner.clone(),
+            migrate_fee_owner: migrate_fee_owner.clone(),
+            migrate_to_amm_wallet: migrate_to_amm_wallet.clone(),
+            migrate_to_cpswap_wallet: migrate_to_cpswap_wallet.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CreateConfigInstructionAccountIndices {
+    pub owner: usize,
+    pub global_config: usize,
+    pub quote_token_mint: usize,
+    pub protocol_fee_owner: usize,
+    pub migrate_fee_owner: usize,
+    pub migrate_to_amm_wallet: usize,
+    pub migrate_to_cpswap_wallet: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CreateConfig {
+    type ArrangedAccountIndices = CreateConfigInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(CreateConfigInstructionAccountIndices {
+            owner: 0,
+            global_config: 1,
+            quote_token_mint: 2,
+            protocol_fee_owner: 3,
+            migrate_fee_owner: 4,
+            migrate_to_amm_wallet: 5,
+            migrate_to_cpswap_wallet: 6,
+            system_program: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CreateConfig {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "owner",
+            "global_config",
+            "quote_token_mint",
+            "protocol_fee_owner",
+            "migrate_fee_owner",
+            "migrate_to_amm_wallet",
+            "migrate_to_cpswap_wallet",
+            "system_program",
+        ]
+    }
+}
@@ -1,7 +1,9 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -10,13 +12,14 @@ pub struct CreatePlatformConfig {
     pub platform_params: PlatformParams,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Hash, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
 pub struct CreatePlatformConfigInstructionAccounts {
     pub platform_admin: solana_pubkey::Pubkey,
     pub platform_fee_wallet: solana_pubkey::Pubkey,
     pub platform_nft_wallet: solana_pubkey::Pubkey,
     pub platform_config: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CreatePlatformConfig {
@@ -25,7 +28,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CreatePlatformConfig {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [platform_admin, platform_fee_wallet, platform_nft_wallet, platform_config, system_program, _remaining @ ..] =
+        let [platform_admin, platform_fee_wallet, platform_nft_wallet, platform_config, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -37,6 +40,83 @@ impl carbon_core::deserialize::ArrangeAccounts for CreatePlatformConfig {
             platform_nft_wallet: platform_nft_wallet.pubkey,
             platform_config: platform_config.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CreatePlatformConfigInstructionAccountMetas {
+    pub platform_admin: solana_instruction::AccountMeta,
+    pub platform_fee_wallet: solana_instruction::AccountMeta,
+    pub platform_nft_wallet: solana_instruction::AccountMeta,
+    pub platform_config: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CreatePlatformConfig {
+    type ArrangedAccountMetas = CreatePlatformConfigInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [platform_admin, platform_fee_wallet, platform_nft_wallet, platform_config, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CreatePlatformConfigInstructionAccountMetas {
+            platform_admin: platform_admin.clone(),
+            platform_fee_wallet: platform_fee_wallet.clone(),
+            platform_nft_wallet: platform_nft_wallet.clone(),
+            platform_config: platform_config.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CreatePlatformConfigInstructionAccountIndices {
+    pub platform_admin: usize,
+    pub platform_fee_wallet: usize,
+    pub platform_nft_wallet: usize,
+    pub platform_config: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CreatePlatformConfig {
+    type ArrangedAccountIndices = CreatePlatformConfigInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(CreatePlatformConfigInstructionAccountIndices {
+            platform_admin: 0,
+            platform_fee_wallet: 1,
+            platform_nft_wallet: 2,
+            platform_config: 3,
+            system_program: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CreatePlatformConfig {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "platform_admin",
+            "platform_fee_wallet",
+            "platform_nft_wallet",
+            "platform_config",
+            "system_program",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -8,13 +10,14 @@ pub struct CreateVestingAccount {
     pub share_amount: u64,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Hash, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
 pub struct CreateVestingAccountInstructionAccounts {
     pub creator: solana_pubkey::Pubkey,
     pub beneficiary: solana_pubkey::Pubkey,
     pub pool_state: solana_pubkey::Pubkey,
     pub vesting_record: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CreateVestingAccount {
@@ -23,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateVestingAccount {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [creator, beneficiary, pool_state, vesting_record, system_program, _remaining @ ..] =
+        let [creator, beneficiary, pool_state, vesting_record, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -35,6 +38,83 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateVestingAccount {
             pool_state: pool_state.pubkey,
             vesting_record: vesting_record.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CreateVestingAccountInstructionAccountMetas {
+    pub creator: solana_instruction::AccountMeta,
+    pub beneficiary: solana_instruction::AccountMeta,
+    pub pool_state: solana_instruction::AccountMeta,
+    pub vesting_record: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CreateVestingAccount {
+    type ArrangedAccountMetas = CreateVestingAccountInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [creator, beneficiary, pool_state, vesting_record, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CreateVestingAccountInstructionAccountMetas {
+            creator: creator.clone(),
+            beneficiary: beneficiary.clone(),
+            pool_state: pool_state.clone(),
+            vesting_record: vesting_record.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CreateVestingAccountInstructionAccountIndices {
+    pub creator: usize,
+    pub beneficiary: usize,
+    pub pool_state: usize,
+    pub vesting_record: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CreateVestingAccount {
+    type ArrangedAccountIndices = CreateVestingAccountInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(CreateVestingAccountInstructionAccountIndices {
+            creator: 0,
+            beneficiary: 1,
+            pool_state: 2,
+            vesting_record: 3,
+            system_program: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CreateVestingAccount {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "creator",
+            "beneficiary",
+            "pool_state",
+            "vesting_record",
+            "system_program",
+        ]
+    }
+}
@@ -1,7 +1,9 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,7 +14,7 @@ pub struct Initialize {
     pub vesting_param: VestingParams,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Hash, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
 pub struct InitializeInstructionAccounts {
     pub payer: solana_pubkey::Pubkey,
     pub creator: solana_pubkey::Pubkey,
@@ -32,6 +34,7 @@ pub struct InitializeInstructionAccounts {
     pub rent_program: solana_pubkey::Pubkey,
     pub event_authority: solana_pubkey::Pubkey,
     pub program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Initialize {
@@ -40,7 +43,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Initialize {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [payer, creator, global_config, platform_config, authority, pool_state, base_mint, quote_mint, base_vault, quote_vault, metadata_account, base_token_program, quote_token_program, metadata_program, system_program, rent_program, event_authority, program, _remaining @ ..] =
+        let [payer, creator, global_config, platform_config, authority, pool_state, base_mint, quote_mint, base_vault, quote_vault, metadata_account, base_token_program, quote_token_program, metadata_program, system_program, rent_program, event_authority, program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -65,6 +68,148 @@ impl carbon_core::deserialize::ArrangeAccounts for Initialize {
             rent_program: rent_program.pubkey,
             event_authority: event_authority.pubkey,
             program: program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
+pub struct InitializeInstructionAccountMetas {
+    pub payer: solana_instruction::AccountMeta,
+    pub creator: solana_instruction::AccountMeta,
+    pub global_config: solana_instruction::AccountMeta,
+    pub platform_config: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub pool_state: solana_instruction::AccountMeta,
+    pub base_mint: solana_instruction::AccountMeta,
+    pub quote_mint: solana_instruction::AccountMeta,
+    pub base_vault: solana_instruction::AccountMeta,
+    pub quote_vault: solana_instruction::AccountMeta,
+    pub metadata_account: solana_instruction::AccountMeta,
+    pub base_token_program: solana_instruction::AccountMeta,
+    pub quote_token_program: solana_instruction::AccountMeta,
+    pub metadata_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent_program: solana_instruction::AccountMeta,
+    pub event_authority: solana_instruction::AccountMeta,
+    pub program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Initialize {
+    type ArrangedAccountMetas = InitializeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [payer, creator, global_config, platform_config, authority, pool_state, base_mint, quote_mint, base_vault, quote_vault, metadata_account, base_token_program, quote_token_program, metadata_program, system_program, rent_program, event_authority, program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeInstructionAccountMetas {
+            payer: payer.clone(),
+            creator: creator.clone(),
+            global_config: global_config.clone(),
+            platform_config: platform_config.clone(),
+            authority: authority.clone(),
+            pool_state: pool_state.clone(),
+            base_mint: base_mint.clone(),
+            quote_mint: quote_mint.clone(),
+            base_vault: base_vault.clone(),
+            quote_vault: quote_vault.clone(),
+            metadata_account: metadata_account.clone(),
+            base_token_program: base_token_program.clone(),
+            quote_token_program: quote_token_program.clone(),
+            metadata_program: metadata_program.clone(),
+            system_program: system_program.clone(),
+            rent_program: rent_program.clone(),
+            event_authority: event_authority.clone(),
+            program: program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
+pub struct InitializeInstructionAccountIndices {
+    pub payer: usize,
+    pub creator: usize,
+    pub global_config: usize,
+    pub platform_config: usize,
+    pub authority: usize,
+    pub pool_state: usize,
+    pub base_mint: usize,
+    pub quote_mint: usize,
+    pub base_vault: usize,
+    pub quote_vault: usize,
+    pub metadata_account: usize,
+    pub base_token_program: usize,
+    pub quote_token_program: usize,
+    pub metadata_program: usize,
+    pub system_program: usize,
+    pub rent_program: usize,
+    pub event_authority: usize,
+    pub program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Initialize {
+    type ArrangedAccountIndices = InitializeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 18 {
+            return None;
+        }
+
+        Some(InitializeInstructionAccountIndices {
+            payer: 0,
+            creator: 1,
+            global_config: 2,
+            platform_config: 3,
+            authority: 4,
+            pool_state: 5,
+            base_mint: 6,
+            quote_mint: 7,
+            base_vault: 8,
+            quote_vault: 9,
+            metadata_account: 10,
+            base_token_program: 11,
+            quote_token_program: 12,
+            metadata_program: 13,
+            system_program: 14,
+            rent_program: 15,
+            event_authority: 16,
+            program: 17,
+            remaining_accounts: 18..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Initialize {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "payer",
+            "creator",
+            "global_config",
+            "platform_config",
+            "authority",
+            "pool_state",
+            "base_mint",
+            "quote_mint",
+            "base_vault",
+            "quote_vault",
+            "metadata_account",
+            "base_token_program",
+            "quote_token_program",
+            "metadata_program",
+            "system_program",
+            "rent_program",
+            "event_authority",
+            "program",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -10,7 +12,7 @@ pub struct MigrateToAmm {
     pub market_vault_signer_nonce: u8,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Hash, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
 pub struct MigrateToAmmInstructionAccounts {
     pub payer: solana_pubkey::Pubkey,
     pub base_mint: solana_pubkey::Pubkey,
@@ -44,6 +46,7 @@ pub struct MigrateToAmmInstructionAccounts {
     pub associated_token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub rent_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for MigrateToAmm {
@@ -52,7 +55,7 @@ impl carbon_core::deserialize::ArrangeAccounts for MigrateToAmm {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [payer, base_mint, quote_mint, openbook_program, market, request_queue, event_queue, bids, asks, market_vault_signer, market_base_vault, market_quote_vault, amm_program, amm_pool, amm_authority, amm_open_orders, amm_lp_mint, amm_base_vault, amm_quote_vault, amm_target_orders, amm_config, amm_create_fee_destination, authority, pool_state, global_config, base_vault, quote_vault, pool_lp_token, spl_token_program, associated_token_program, system_program, rent_program, _remaining @ ..] =
+        let [payer, base_mint, quote_mint, openbook_program, market, request_queue, event_queue, bids, asks, market_vault_signer, market_base_vault, market_quote_vault, amm_program, amm_pool, amm_authority, amm_open_orders, amm_lp_mint, amm_base_vault, amm_quote_vault, amm_target_orders, amm_config, amm_create_fee_destination, authority, pool_state, global_config, base_vault, quote_vault, pool_lp_token, spl_token_program, associated_token_program, system_program, rent_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -91,6 +94,218 @@ impl carbon_core::deserialize::ArrangeAccounts for MigrateToAmm {
             associated_token_program: associated_token_program.pubkey,
             system_program: system_program.pubkey,
             rent_program: rent_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
+pub struct MigrateToAmmInstructionAccountMetas {
+    pub payer: solana_instruction::AccountMeta,
+    pub base_mint: solana_instruction::AccountMeta,
+    pub quote_mint: solana_instruction::AccountMeta,
+    pub openbook_program: solana_instruction::AccountMeta,
+    pub market: solana_instruction::AccountMeta,
+    pub request_queue: solana_instruction::AccountMeta,
+    pub event_queue: solana_instruction::AccountMeta,
+    pub bids: solana_instruction::AccountMeta,
+    pub asks: solana_instruction::AccountMeta,
+    pub market_vault_signer: solana_instruction::AccountMeta,
+    pub market_base_vault: solana_instruction::AccountMeta,
+    pub market_quote_vault: solana_instruction::AccountMeta,
+    pub amm_program: solana_instruction::AccountMeta,
+    pub amm_pool: solana_instruction::AccountMeta,
+    pub amm_authority: solana_instruction::AccountMeta,
+    pub amm_open_orders: solana_instruction::AccountMeta,
+    pub amm_lp_mint: solana_instruction::AccountMeta,
+    pub amm_base_vault: solana_instruction::AccountMeta,
+    pub amm_quote_vault: solana_instruction::AccountMeta,
+    pub amm_target_orders: solana_instruction::AccountMeta,
+    pub amm_config: solana_instruction::AccountMeta,
+    pub amm_create_fee_destination: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub pool_state: solana_instruction::AccountMeta,
+    pub global_config: solana_instruction::AccountMeta,
+    pub base_vault: solana_instruction::AccountMeta,
+    pub quote_vault: solana_instruction::AccountMeta,
+    pub pool_lp_token: solana_instruction::AccountMeta,
+    pub spl_token_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for MigrateToAmm {
+    type ArrangedAccountMetas = MigrateToAmmInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [payer, base_mint, quote_mint, openbook_program, market, request_queue, event_queue, bids, asks, market_vault_signer, market_base_vault, market_quote_vault, amm_program, amm_pool, amm_authority, amm_open_orders, amm_lp_mint, amm_base_vault, amm_quote_vault, amm_target_orders, amm_config, amm_create_fee_destination, authority, pool_state, global_config, base_vault, quote_vault, pool_lp_token, spl_token_program, associated_token_program, system_program, rent_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(MigrateToAmmInstructionAccountMetas {
+            payer: payer.clone(),
+            base_mint: base_mint.clone(),
+            quote_mint: quote_mint.clone(),
+            openbook_program: openbook_program.clone(),
+            market: market.clone(),
+            request_queue: request_queue.clone(),
+            event_queue: event_queue.clone(),
+            bids: bids.clone(),
+            asks: asks.clone(),
+            market_vault_signer: market_vault_signer.clone(),
+            market_base_vault: market_base_vault.clone(),
+            market_quote_vault: market_quote_vault.clone(),
+            amm_program: amm_program.clone(),
+            amm_pool: amm_pool.clone(),
+            amm_authority: amm_authority.clone(),
+            amm_open_orders: amm_open_orders.clone(),
+            amm_lp_mint: amm_lp_mint.clone(),
+            amm_base_vault: amm_base_vault.clone(),
+            amm_quote_vault: amm_quote_vault.clone(),
+            amm_target_orders: amm_target_orders.clone(),
+            amm_config: amm_config.clone(),
+            amm_create_fee_destination: amm_create_fee_destination.clone(),
+            authority: authority.clone(),
+            pool_state: pool_state.clone(),
+            global_config: global_config.clone(),
+            base_vault: base_vault.clone(),
+            quote_vault: quote_vault.clone(),
+            pool_lp_token: pool_lp_token.clone(),
+            spl_token_program: spl_token_program.clone(),
+            associated_token_program: associated_token_program.clone(),
+            system_program: system_program.clone(),
+            rent_program: rent_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
+pub struct MigrateToAmmInstructionAccountIndices {
+    pub payer: usize,
+    pub base_mint: usize,
+    pub quote_mint: usize,
+    pub openbook_program: usize,
+    pub market: usize,
+    pub request_queue: usize,
+    pub event_queue: usize,
+    pub bids: usize,
+    pub asks: usize,
+    pub market_vault_signer: usize,
+    pub market_base_vault: usize,
+    pub market_quote_vault: usize,
+    pub amm_program: usize,
+    pub amm_pool: usize,
+    pub amm_authority: usize,
+    pub amm_open_orders: usize,
+    pub amm_lp_mint: usize,
+    pub amm_base_vault: usize,
+    pub amm_quote_vault: usize,
+    pub amm_target_orders: usize,
+    pub amm_config: usize,
+    pub amm_create_fee_destination: usize,
+    pub authority: usize,
+    pub pool_state: usize,
+    pub global_config: usize,
+    pub base_vault: usize,
+    pub quote_vault: usize,
+    pub pool_lp_token: usize,
+    pub spl_token_program: usize,
+    pub associated_token_program: usize,
+    pub system_program: usize,
+    pub rent_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for MigrateToAmm {
+    type ArrangedAccountIndices = MigrateToAmmInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 32 {
+            return None;
+        }
+
+        Some(MigrateToAmmInstructionAccountIndices {
+            payer: 0,
+            base_mint: 1,
+            quote_mint: 2,
+            openbook_program: 3,
+            market: 4,
+            request_queue: 5,
+            event_queue: 6,
+            bids: 7,
+            asks: 8,
+            market_vault_signer: 9,
+            market_base_vault: 10,
+            market_quote_vault: 11,
+            amm_program: 12,
+            amm_pool: 13,
+            amm_authority: 14,
+            amm_open_orders: 15,
+            amm_lp_mint: 16,
+            amm_base_vault: 17,
+            amm_quote_vault: 18,
+            amm_target_orders: 19,
+            amm_config: 20,
+            amm_create_fee_destination: 21,
+            authority: 22,
+            pool_state: 23,
+            global_config: 24,
+            base_vault: 25,
+            quote_vault: 26,
+            pool_lp_token: 27,
+            spl_token_program: 28,
+            associated_token_program: 29,
+            system_program: 30,
+            rent_program: 31,
+            remaining_accounts: 32..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for MigrateToAmm {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "payer",
+            "base_mint",
+            "quote_mint",
+            "openbook_program",
+            "market",
+            "request_queue",
+            "event_queue",
+            "bids",
+            "asks",
+            "market_vault_signer",
+            "market_base_vault",
+            "market_quote_vault",
+            "amm_program",
+            "amm_pool",
+            "amm_authority",
+            "amm_open_orders",
+            "amm_lp_mint",
+            "amm_base_vault",
+            "amm_quote_vault",
+            "amm_target_orders",
+            "amm_config",
+            "amm_create_fee_destination",
+            "authority",
+            "pool_state",
+            "global_config",
+            "base_vault",
+            "quote_vault",
+            "pool_lp_token",
+            "spl_token_program",
+            "associated_token_program",
+            "system_program",
+            "rent_program",
+        ]
+    }
+}
@@ -1,12 +1,14 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0x885cc8671cda908c")]
 pub struct MigrateToCpswap {}
 
-#[derive(Debug, PartialEq, Eq, Clone, Hash, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
 pub struct MigrateToCpswapInstructionAccounts {
     pub payer: solana_pubkey::Pubkey,
     pub base_mint: solana_pubkey::Pubkey,
@@ -36,6 +38,7 @@ pub struct MigrateToCpswapInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub rent_program: solana_pubkey::Pubkey,
     pub metadata_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for MigrateToCpswap {
@@ -44,7 +47,7 @@ impl carbon_core::deserialize::ArrangeAccounts for MigrateToCpswap {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [payer, base_mint, quote_mint, platform_config, cpswap_program, cpswap_pool, cpswap_authority, cpswap_lp_mint, cpswap_base_vault, cpswap_quote_vault, cpswap_config, cpswap_create_pool_fee, cpswap_observation, lock_program, lock_authority, lock_lp_vault, authority, pool_state, global_config, base_vault, quote_vault, pool_lp_token, base_token_program, quote_token_program, associated_token_program, system_program, rent_program, metadata_program, _remaining @ ..] =
+        let [payer, base_mint, quote_mint, platform_config, cpswap_program, cpswap_pool, cpswap_authority, cpswap_lp_mint, cpswap_base_vault, cpswap_quote_vault, cpswap_config, cpswap_create_pool_fee, cpswap_observation, lock_program, lock_authority, lock_lp_vault, authority, pool_state, global_config, base_vault, quote_vault, pool_lp_token, base_token_program, quote_token_program, associated_token_program, system_program, rent_program, metadata_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -79,6 +82,198 @@ impl carbon_core::deserialize::ArrangeAccounts for MigrateToCpswap {
             system_program: system_program.pubkey,
             rent_program: rent_program.pubkey,
             metadata_program: metadata_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
+pub struct MigrateToCpswapInstructionAccountMetas {
+    pub payer: solana_instruction::AccountMeta,
+    pub base_mint: solana_instruction::AccountMeta,
+    pub quote_mint: solana_instruction::AccountMeta,
+    pub platform_config: solana_instruction::AccountMeta,
+    pub cpswap_program: solana_instruction::AccountMeta,
+    pub cpswap_pool: solana_instruction::AccountMeta,
+    pub cpswap_authority: solana_instruction::AccountMeta,
+    pub cpswap_lp_mint: solana_instruction::AccountMeta,
+    pub cpswap_base_vault: solana_instruction::AccountMeta,
+    pub cpswap_quote_vault: solana_instruction::AccountMeta,
+    pub cpswap_config: solana_instruction::AccountMeta,
+    pub cpswap_create_pool_fee: solana_instruction::AccountMeta,
+    pub cpswap_observation: solana_instruction::AccountMeta,
+    pub lock_program: solana_instruction::AccountMeta,
+    pub lock_authority: solana_instruction::AccountMeta,
+    pub lock_lp_vault: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub pool_state: solana_instruction::AccountMeta,
+    pub global_config: solana_instruction::AccountMeta,
+    pub base_vault: solana_instruction::AccountMeta,
+    pub quote_vault: solana_instruction::AccountMeta,
+    pub pool_lp_token: solana_instruction::AccountMeta,
+    pub base_token_program: solana_instruction::AccountMeta,
+    pub quote_token_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent_program: solana_instruction::AccountMeta,
+    pub metadata_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for MigrateToCpswap {
+    type ArrangedAccountMetas = MigrateToCpswapInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [payer, base_mint, quote_mint, platform_config, cpswap_program, cpswap_pool, cpswap_authority, cpswap_lp_mint, cpswap_base_vault, cpswap_quote_vault, cpswap_config, cpswap_create_pool_fee, cpswap_observation, lock_program, lock_authority, lock_lp_vault, authority, pool_state, global_config, base_vault, quote_vault, pool_lp_token, base_token_program, quote_token_program, associated_token_program, system_program, rent_program, metadata_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(MigrateToCpswapInstructionAccountMetas {
+            payer: payer.clone(),
+            base_mint: base_mint.clone(),
+            quote_mint: quote_mint.clone(),
+            platform_config: platform_config.clone(),
+            cpswap_program: cpswap_program.clone(),
+            cpswap_pool: cpswap_pool.clone(),
+            cpswap_authority: cpswap_authority.clone(),
+            cpswap_lp_mint: cpswap_lp_mint.clone(),
+            cpswap_base_vault: cpswap_base_vault.clone(),
+            cpswap_quote_vault: cpswap_quote_vault.clone(),
+            cpswap_config: cpswap_config.clone(),
+            cpswap_create_pool_fee: cpswap_create_pool_fee.clone(),
+            cpswap_observation: cpswap_observation.clone(),
+            lock_program: lock_program.clone(),
+            lock_authority: lock_authority.clone(),
+            lock_lp_vault: lock_lp_vault.clone(),
+            authority: authority.clone(),
+            pool_state: pool_state.clone(),
+            global_config: global_config.clone(),
+            base_vault: base_vault.clone(),
+            quote_vault: quote_vault.clone(),
+            pool_lp_token: pool_lp_token.clone(),
+            base_token_program: base_token_program.clone(),
+            quote_token_program: quote_token_program.clone(),
+            associated_token_program: associated_token_program.clone(),
+            system_program: system_program.clone(),
+            rent_program: rent_program.clone(),
+            metadata_program: metadata_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
+pub struct MigrateToCpswapInstructionAccountIndices {
+    pub payer: usize,
+    pub base_mint: usize,
+    pub quote_mint: usize,
+    pub platform_config: usize,
+    pub cpswap_program: usize,
+    pub cpswap_pool: usize,
+    pub cpswap_authority: usize,
+    pub cpswap_lp_mint: usize,
+    pub cpswap_base_vault: usize,
+    pub cpswap_quote_vault: usize,
+    pub cpswap_config: usize,
+    pub cpswap_create_pool_fee: usize,
+    pub cpswap_observation: usize,
+    pub lock_program: usize,
+    pub lock_authority: usize,
+    pub lock_lp_vault: usize,
+    pub authority: usize,
+    pub pool_state: usize,
+    pub global_config: usize,
+    pub base_vault: usize,
+    pub quote_vault: usize,
+    pub pool_lp_token: usize,
+    pub base_token_program: usize,
+    pub quote_token_program: usize,
+    pub associated_token_program: usize,
+    pub system_program: usize,
+    pub rent_program: usize,
+    pub metadata_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for MigrateToCpswap {
+    type ArrangedAccountIndices = MigrateToCpswapInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 28 {
+            return None;
+        }
+
+        Some(MigrateToCpswapInstructionAccountIndices {
+            payer: 0,
+            base_mint: 1,
+            quote_mint: 2,
+            platform_config: 3,
+            cpswap_program: 4,
+            cpswap_pool: 5,
+            cpswap_authority: 6,
+            cpswap_lp_mint: 7,
+            cpswap_base_vault: 8,
+            cpswap_quote_vault: 9,
+            cpswap_config: 10,
+            cpswap_create_pool_fee: 11,
+            cpswap_observation: 12,
+            lock_program: 13,
+            lock_authority: 14,
+            lock_lp_vault: 15,
+            authority: 16,
+            pool_state: 17,
+            global_config: 18,
+            base_vault: 19,
+            quote_vault: 20,
+            pool_lp_token: 21,
+            base_token_program: 22,
+            quote_token_program: 23,
+            associated_token_program: 24,
+            system_program: 25,
+            rent_program: 26,
+            metadata_program: 27,
+            remaining_accounts: 28..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for MigrateToCpswap {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "payer",
+            "base_mint",
+            "quote_mint",
+            "platform_config",
+            "cpswap_program",
+            "cpswap_pool",
+            "cpswap_authority",
+            "cpswap_lp_mint",
+            "cpswap_base_vault",
+            "cpswap_quote_vault",
+            "cpswap_config",
+            "cpswap_create_pool_fee",
+            "cpswap_observation",
+            "lock_program",
+            "lock_authority",
+            "lock_lp_vault",
+            "authority",
+            "pool_state",
+            "global_config",
+            "base_vault",
+            "quote_vault",
+            "pool_lp_token",
+            "base_token_program",
+            "quote_token_program",
+            "associated_token_program",
+            "system_program",
+            "rent_program",
+            "metadata_program",
+        ]
+    }
+}
@@ -58,15 +58,25 @@ pub enum RaydiumLaunchpadInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for RaydiumLaunchpadDecoder {
     type InstructionType = RaydiumLaunchpadInstruction;
 
-    fn decode_instruction(
+    fn decoder_name(&self) -> &'static str {
+        "raydium_launchpad"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[super::PROGRAM_ID]
+    }
+
+    fn try_decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
-    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+    ) -> carbon_core::error::CarbonResult<
+        Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>>,
+    > {
         if !instruction.program_id.eq(&PROGRAM_ID) {
-            return None;
+            return Ok(None);
         }
 
-        carbon_core::try_decode_instructions!(instruction,
+        Ok(carbon_core::try_decode_instructions!(instruction,
             RaydiumLaunchpadInstruction::BuyExactIn => buy_exact_in::BuyExactIn,
             RaydiumLaunchpadInstruction::BuyExactOut => buy_exact_out::BuyExactOut,
             RaydiumLaunchpadInstruction::ClaimPlatformFee => claim_platform_fee::ClaimPlatformFee,
@@ -87,6 +97,6 @@ impl carbon_core::instruction::InstructionDecoder<'_> for RaydiumLaunchpadDecode
             RaydiumLaunchpadInstruction::CreateVestingEvent => create_vesting_event::CreateVestingEvent,
             RaydiumLaunchpadInstruction::PoolCreateEvent => pool_create_event::PoolCreateEvent,
             RaydiumLaunchpadInstruction::TradeEvent => trade_event::TradeEvent,
-        )
+        ))
     }
 }
@@ -1,7 +1,9 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -10,10 +12,11 @@ pub struct UpdatePlatformConfig {
     pub param: PlatformConfigParam,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Hash, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
 pub struct UpdatePlatformConfigInstructionAccounts {
     pub platform_admin: solana_pubkey::Pubkey,
     pub platform_config: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdatePlatformConfig {
@@ -22,13 +25,70 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdatePlatformConfig {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [platform_admin, platform_config, _remaining @ ..] = accounts else {
+        let [platform_admin, platform_config, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(UpdatePlatformConfigInstructionAccounts {
             platform_admin: platform_admin.pubkey,
             platform_config: platform_config.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
+pub struct UpdatePlatformConfigInstructionAccountMetas {
+    pub platform_admin: solana_instruction::AccountMeta,
+    pub platform_config: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdatePlatformConfig {
+    type ArrangedAccountMetas = UpdatePlatformConfigInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [platform_admin, platform_config, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdatePlatformConfigInstructionAccountMetas {
+            platform_admin: platform_admin.clone(),
+            platform_config: platform_config.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
+pub struct UpdatePlatformConfigInstructionAccountIndices {
+    pub platform_admin: usize,
+    pub platform_config: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdatePlatformConfig {
+    type ArrangedAccountIndices = UpdatePlatformConfigInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(UpdatePlatformConfigInstructionAccountIndices {
+            platform_admin: 0,
+            platform_config: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdatePlatformConfig {
+    fn account_names() -> &'static [&'static str] {
+        &["platform_admin", "platform_config"]
+    }
+}
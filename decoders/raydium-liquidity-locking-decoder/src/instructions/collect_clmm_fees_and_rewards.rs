@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -27,6 +29,7 @@ pub struct CollectClmmFeesAndRewardsInstructionAccounts {
     pub memo_program: solana_pubkey::Pubkey,
     pub vault0_mint: solana_pubkey::Pubkey,
     pub vault1_mint: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CollectClmmFeesAndRewards {
@@ -35,7 +38,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CollectClmmFeesAndRewards {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [authority, fee_nft_owner, fee_nft_account, locked_position, clmm_program, locked_nft_account, personal_position, pool_state, protocol_position, token0_vault, token1_vault, tick_array_lower, tick_array_upper, recipient_token0_account, recipient_token1_account, token_program, token_program2022, memo_program, vault0_mint, vault1_mint, _remaining @ ..] =
+        let [authority, fee_nft_owner, fee_nft_account, locked_position, clmm_program, locked_nft_account, personal_position, pool_state, protocol_position, token0_vault, token1_vault, tick_array_lower, tick_array_upper, recipient_token0_account, recipient_token1_account, token_program, token_program2022, memo_program, vault0_mint, vault1_mint, remaining @ ..] =
             accounts
         else {
             return None;
@@ -62,6 +65,156 @@ impl carbon_core::deserialize::ArrangeAccounts for CollectClmmFeesAndRewards {
             memo_program: memo_program.pubkey,
             vault0_mint: vault0_mint.pubkey,
             vault1_mint: vault1_mint.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CollectClmmFeesAndRewardsInstructionAccountMetas {
+    pub authority: solana_instruction::AccountMeta,
+    pub fee_nft_owner: solana_instruction::AccountMeta,
+    pub fee_nft_account: solana_instruction::AccountMeta,
+    pub locked_position: solana_instruction::AccountMeta,
+    pub clmm_program: solana_instruction::AccountMeta,
+    pub locked_nft_account: solana_instruction::AccountMeta,
+    pub personal_position: solana_instruction::AccountMeta,
+    pub pool_state: solana_instruction::AccountMeta,
+    pub protocol_position: solana_instruction::AccountMeta,
+    pub token0_vault: solana_instruction::AccountMeta,
+    pub token1_vault: solana_instruction::AccountMeta,
+    pub tick_array_lower: solana_instruction::AccountMeta,
+    pub tick_array_upper: solana_instruction::AccountMeta,
+    pub recipient_token0_account: solana_instruction::AccountMeta,
+    pub recipient_token1_account: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub token_program2022: solana_instruction::AccountMeta,
+    pub memo_program: solana_instruction::AccountMeta,
+    pub vault0_mint: solana_instruction::AccountMeta,
+    pub vault1_mint: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CollectClmmFeesAndRewards {
+    type ArrangedAccountMetas = CollectClmmFeesAndRewardsInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [authority, fee_nft_owner, fee_nft_account, locked_position, clmm_program, locked_nft_account, personal_position, pool_state, protocol_position, token0_vault, token1_vault, tick_array_lower, tick_array_upper, recipient_token0_account, recipient_token1_account, token_program, token_program2022, memo_program, vault0_mint, vault1_mint, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CollectClmmFeesAndRewardsInstructionAccountMetas {
+            authority: authority.clone(),
+            fee_nft_owner: fee_nft_owner.clone(),
+            fee_nft_account: fee_nft_account.clone(),
+            locked_position: locked_position.clone(),
+            clmm_program: clmm_program.clone(),
+            locked_nft_account: locked_nft_account.clone(),
+            personal_position: personal_position.clone(),
+            pool_state: pool_state.clone(),
+            protocol_position: protocol_position.clone(),
+            token0_vault: token0_vault.clone(),
+            token1_vault: token1_vault.clone(),
+            tick_array_lower: tick_array_lower.clone(),
+            tick_array_upper: tick_array_upper.clone(),
+            recipient_token0_account: recipient_token0_account.clone(),
+            recipient_token1_account: recipient_token1_account.clone(),
+            token_program: token_program.clone(),
+            token_program2022: token_program2022.clone(),
+            memo_program: memo_program.clone(),
+            vault0_mint: vault0_mint.clone(),
+            vault1_mint: vault1_mint.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CollectClmmFeesAndRewardsInstructionAccountIndices {
+    pub authority: usize,
+    pub fee_nft_owner: usize,
+    pub fee_nft_account: usize,
+    pub locked_position: usize,
+    pub clmm_program: usize,
+    pub locked_nft_account: usize,
+    pub personal_position: usize,
+    pub pool_state: usize,
+    pub protocol_position: usize,
+    pub token0_vault: usize,
+    pub token1_vault: usize,
+    pub tick_array_lower: usize,
+    pub tick_array_upper: usize,
+    pub recipient_token0_account: usize,
+    pub recipient_token1_account: usize,
+    pub token_program: usize,
+    pub token_program2022: usize,
+    pub memo_program: usize,
+    pub vault0_mint: usize,
+    pub vault1_mint: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CollectClmmFeesAndRewards {
+    type ArrangedAccountIndices = CollectClmmFeesAndRewardsInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 20 {
+            return None;
+        }
+
+        Some(CollectClmmFeesAndRewardsInstructionAccountIndices {
+            authority: 0,
+            fee_nft_owner: 1,
+            fee_nft_account: 2,
+            locked_position: 3,
+            clmm_program: 4,
+            locked_nft_account: 5,
+            personal_position: 6,
+            pool_state: 7,
+            protocol_position: 8,
+            token0_vault: 9,
+            token1_vault: 10,
+            tick_array_lower: 11,
+            tick_array_upper: 12,
+            recipient_token0_account: 13,
+            recipient_token1_account: 14,
+            token_program: 15,
+            token_program2022: 16,
+            memo_program: 17,
+            vault0_mint: 18,
+            vault1_mint: 19,
+            remaining_accounts: 20..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CollectClmmFeesAndRewards {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "authority",
+            "fee_nft_owner",
+            "fee_nft_account",
+            "locked_position",
+            "clmm_program",
+            "locked_nft_account",
+            "personal_position",
+            "pool_state",
+            "protocol_position",
+            "token0_vault",
+            "token1_vault",
+            "tick_array_lower",
+            "tick_array_upper",
+            "recipient_token0_account",
+            "recipient_token1_account",
+            "token_program",
+            "token_program2022",
+            "memo_program",
+            "vault0_mint",
+            "vault1_mint",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -27,6 +29,7 @@ pub struct CollectCpFeesInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub token_program2022: solana_pubkey::Pubkey,
     pub memo_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CollectCpFees {
@@ -35,7 +38,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CollectCpFees {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [authority, fee_nft_owner, fee_nft_account, locked_liquidity, cp_swap_program, cp_authority, pool_state, lp_mint, recipient_token0_account, recipient_token1_account, token0_vault, token1_vault, vault0_mint, vault1_mint, locked_lp_vault, token_program, token_program2022, memo_program, _remaining @ ..] =
+        let [authority, fee_nft_owner, fee_nft_account, locked_liquidity, cp_swap_program, cp_authority, pool_state, lp_mint, recipient_token0_account, recipient_token1_account, token0_vault, token1_vault, vault0_mint, vault1_mint, locked_lp_vault, token_program, token_program2022, memo_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -60,6 +63,146 @@ impl carbon_core::deserialize::ArrangeAccounts for CollectCpFees {
             token_program: token_program.pubkey,
             token_program2022: token_program2022.pubkey,
             memo_program: memo_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CollectCpFeesInstructionAccountMetas {
+    pub authority: solana_instruction::AccountMeta,
+    pub fee_nft_owner: solana_instruction::AccountMeta,
+    pub fee_nft_account: solana_instruction::AccountMeta,
+    pub locked_liquidity: solana_instruction::AccountMeta,
+    pub cp_swap_program: solana_instruction::AccountMeta,
+    pub cp_authority: solana_instruction::AccountMeta,
+    pub pool_state: solana_instruction::AccountMeta,
+    pub lp_mint: solana_instruction::AccountMeta,
+    pub recipient_token0_account: solana_instruction::AccountMeta,
+    pub recipient_token1_account: solana_instruction::AccountMeta,
+    pub token0_vault: solana_instruction::AccountMeta,
+    pub token1_vault: solana_instruction::AccountMeta,
+    pub vault0_mint: solana_instruction::AccountMeta,
+    pub vault1_mint: solana_instruction::AccountMeta,
+    pub locked_lp_vault: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub token_program2022: solana_instruction::AccountMeta,
+    pub memo_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CollectCpFees {
+    type ArrangedAccountMetas = CollectCpFeesInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [authority, fee_nft_owner, fee_nft_account, locked_liquidity, cp_swap_program, cp_authority, pool_state, lp_mint, recipient_token0_account, recipient_token1_account, token0_vault, token1_vault, vault0_mint, vault1_mint, locked_lp_vault, token_program, token_program2022, memo_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CollectCpFeesInstructionAccountMetas {
+            authority: authority.clone(),
+            fee_nft_owner: fee_nft_owner.clone(),
+            fee_nft_account: fee_nft_account.clone(),
+            locked_liquidity: locked_liquidity.clone(),
+            cp_swap_program: cp_swap_program.clone(),
+            cp_authority: cp_authority.clone(),
+            pool_state: pool_state.clone(),
+            lp_mint: lp_mint.clone(),
+            recipient_token0_account: recipient_token0_account.clone(),
+            recipient_token1_account: recipient_token1_account.clone(),
+            token0_vault: token0_vault.clone(),
+            token1_vault: token1_vault.clone(),
+            vault0_mint: vault0_mint.clone(),
+            vault1_mint: vault1_mint.clone(),
+            locked_lp_vault: locked_lp_vault.clone(),
+            token_program: token_program.clone(),
+            token_program2022: token_program2022.clone(),
+            memo_program: memo_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CollectCpFeesInstructionAccountIndices {
+    pub authority: usize,
+    pub fee_nft_owner: usize,
+    pub fee_nft_account: usize,
+    pub locked_liquidity: usize,
+    pub cp_swap_program: usize,
+    pub cp_authority: usize,
+    pub pool_state: usize,
+    pub lp_mint: usize,
+    pub recipient_token0_account: usize,
+    pub recipient_token1_account: usize,
+    pub token0_vault: usize,
+    pub token1_vault: usize,
+    pub vault0_mint: usize,
+    pub vault1_mint: usize,
+    pub locked_lp_vault: usize,
+    pub token_program: usize,
+    pub token_program2022: usize,
+    pub memo_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CollectCpFees {
+    type ArrangedAccountIndices = CollectCpFeesInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 18 {
+            return None;
+        }
+
+        Some(CollectCpFeesInstructionAccountIndices {
+            authority: 0,
+            fee_nft_owner: 1,
+            fee_nft_account: 2,
+            locked_liquidity: 3,
+            cp_swap_program: 4,
+            cp_authority: 5,
+            pool_state: 6,
+            lp_mint: 7,
+            recipient_token0_account: 8,
+            recipient_token1_account: 9,
+            token0_vault: 10,
+            token1_vault: 11,
+            vault0_mint: 12,
+            vault1_mint: 13,
+            locked_lp_vault: 14,
+            token_program: 15,
+            token_program2022: 16,
+            memo_program: 17,
+            remaining_accounts: 18..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CollectCpFees {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "authority",
+            "fee_nft_owner",
+            "fee_nft_account",
+            "locked_liquidity",
+            "cp_swap_program",
+            "cp_authority",
+            "pool_state",
+            "lp_mint",
+            "recipient_token0_account",
+            "recipient_token1_account",
+            "token0_vault",
+            "token1_vault",
+            "vault0_mint",
+            "vault1_mint",
+            "locked_lp_vault",
+            "token_program",
+            "token_program2022",
+            "memo_program",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -27,6 +29,7 @@ pub struct LockClmmPositionInstructionAccounts {
     pub fee_nft_token_program: solana_pubkey::Pubkey,
     pub locked_nft_token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for LockClmmPosition {
@@ -35,7 +38,7 @@ impl carbon_core::deserialize::ArrangeAccounts for LockClmmPosition {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [authority, payer, position_nft_owner, fee_nft_owner, position_nft_account, personal_position, position_nft_mint, locked_nft_account, locked_position, fee_nft_mint, fee_nft_account, metadata_account, metadata_program, associated_token_program, rent, fee_nft_token_program, locked_nft_token_program, system_program, _remaining @ ..] =
+        let [authority, payer, position_nft_owner, fee_nft_owner, position_nft_account, personal_position, position_nft_mint, locked_nft_account, locked_position, fee_nft_mint, fee_nft_account, metadata_account, metadata_program, associated_token_program, rent, fee_nft_token_program, locked_nft_token_program, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -60,6 +63,146 @@ impl carbon_core::deserialize::ArrangeAccounts for LockClmmPosition {
             fee_nft_token_program: fee_nft_token_program.pubkey,
             locked_nft_token_program: locked_nft_token_program.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct LockClmmPositionInstructionAccountMetas {
+    pub authority: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub position_nft_owner: solana_instruction::AccountMeta,
+    pub fee_nft_owner: solana_instruction::AccountMeta,
+    pub position_nft_account: solana_instruction::AccountMeta,
+    pub personal_position: solana_instruction::AccountMeta,
+    pub position_nft_mint: solana_instruction::AccountMeta,
+    pub locked_nft_account: solana_instruction::AccountMeta,
+    pub locked_position: solana_instruction::AccountMeta,
+    pub fee_nft_mint: solana_instruction::AccountMeta,
+    pub fee_nft_account: solana_instruction::AccountMeta,
+    pub metadata_account: solana_instruction::AccountMeta,
+    pub metadata_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub fee_nft_token_program: solana_instruction::AccountMeta,
+    pub locked_nft_token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for LockClmmPosition {
+    type ArrangedAccountMetas = LockClmmPositionInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [authority, payer, position_nft_owner, fee_nft_owner, position_nft_account, personal_position, position_nft_mint, locked_nft_account, locked_position, fee_nft_mint, fee_nft_account, metadata_account, metadata_program, associated_token_program, rent, fee_nft_token_program, locked_nft_token_program, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(LockClmmPositionInstructionAccountMetas {
+            authority: authority.clone(),
+            payer: payer.clone(),
+            position_nft_owner: position_nft_owner.clone(),
+            fee_nft_owner: fee_nft_owner.clone(),
+            position_nft_account: position_nft_account.clone(),
+            personal_position: personal_position.clone(),
+            position_nft_mint: position_nft_mint.clone(),
+            locked_nft_account: locked_nft_account.clone(),
+            locked_position: locked_position.clone(),
+            fee_nft_mint: fee_nft_mint.clone(),
+            fee_nft_account: fee_nft_account.clone(),
+            metadata_account: metadata_account.clone(),
+            metadata_program: metadata_program.clone(),
+            associated_token_program: associated_token_program.clone(),
+            rent: rent.clone(),
+            fee_nft_token_program: fee_nft_token_program.clone(),
+            locked_nft_token_program: locked_nft_token_program.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct LockClmmPositionInstructionAccountIndices {
+    pub authority: usize,
+    pub payer: usize,
+    pub position_nft_owner: usize,
+    pub fee_nft_owner: usize,
+    pub position_nft_account: usize,
+    pub personal_position: usize,
+    pub position_nft_mint: usize,
+    pub locked_nft_account: usize,
+    pub locked_position: usize,
+    pub fee_nft_mint: usize,
+    pub fee_nft_account: usize,
+    pub metadata_account: usize,
+    pub metadata_program: usize,
+    pub associated_token_program: usize,
+    pub rent: usize,
+    pub fee_nft_token_program: usize,
+    pub locked_nft_token_program: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for LockClmmPosition {
+    type ArrangedAccountIndices = LockClmmPositionInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 18 {
+            return None;
+        }
+
+        Some(LockClmmPositionInstructionAccountIndices {
+            authority: 0,
+            payer: 1,
+            position_nft_owner: 2,
+            fee_nft_owner: 3,
+            position_nft_account: 4,
+            personal_position: 5,
+            position_nft_mint: 6,
+            locked_nft_account: 7,
+            locked_position: 8,
+            fee_nft_mint: 9,
+            fee_nft_account: 10,
+            metadata_account: 11,
+            metadata_program: 12,
+            associated_token_program: 13,
+            rent: 14,
+            fee_nft_token_program: 15,
+            locked_nft_token_program: 16,
+            system_program: 17,
+            remaining_accounts: 18..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for LockClmmPosition {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "authority",
+            "payer",
+            "position_nft_owner",
+            "fee_nft_owner",
+            "position_nft_account",
+            "personal_position",
+            "position_nft_mint",
+            "locked_nft_account",
+            "locked_position",
+            "fee_nft_mint",
+            "fee_nft_account",
+            "metadata_account",
+            "metadata_program",
+            "associated_token_program",
+            "rent",
+            "fee_nft_token_program",
+            "locked_nft_token_program",
+            "system_program",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -29,6 +31,7 @@ pub struct LockCpLiquidityInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub associated_token_program: solana_pubkey::Pubkey,
     pub metadata_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for LockCpLiquidity {
@@ -37,7 +40,7 @@ impl carbon_core::deserialize::ArrangeAccounts for LockCpLiquidity {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [authority, payer, liquidity_owner, fee_nft_owner, fee_nft_mint, fee_nft_account, pool_state, locked_liquidity, lp_mint, liquidity_owner_lp, locked_lp_vault, token0_vault, token1_vault, metadata_account, rent, system_program, token_program, associated_token_program, metadata_program, _remaining @ ..] =
+        let [authority, payer, liquidity_owner, fee_nft_owner, fee_nft_mint, fee_nft_account, pool_state, locked_liquidity, lp_mint, liquidity_owner_lp, locked_lp_vault, token0_vault, token1_vault, metadata_account, rent, system_program, token_program, associated_token_program, metadata_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -63,6 +66,151 @@ impl carbon_core::deserialize::ArrangeAccounts for LockCpLiquidity {
             token_program: token_program.pubkey,
             associated_token_program: associated_token_program.pubkey,
             metadata_program: metadata_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct LockCpLiquidityInstructionAccountMetas {
+    pub authority: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub liquidity_owner: solana_instruction::AccountMeta,
+    pub fee_nft_owner: solana_instruction::AccountMeta,
+    pub fee_nft_mint: solana_instruction::AccountMeta,
+    pub fee_nft_account: solana_instruction::AccountMeta,
+    pub pool_state: solana_instruction::AccountMeta,
+    pub locked_liquidity: solana_instruction::AccountMeta,
+    pub lp_mint: solana_instruction::AccountMeta,
+    pub liquidity_owner_lp: solana_instruction::AccountMeta,
+    pub locked_lp_vault: solana_instruction::AccountMeta,
+    pub token0_vault: solana_instruction::AccountMeta,
+    pub token1_vault: solana_instruction::AccountMeta,
+    pub metadata_account: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub metadata_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for LockCpLiquidity {
+    type ArrangedAccountMetas = LockCpLiquidityInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [authority, payer, liquidity_owner, fee_nft_owner, fee_nft_mint, fee_nft_account, pool_state, locked_liquidity, lp_mint, liquidity_owner_lp, locked_lp_vault, token0_vault, token1_vault, metadata_account, rent, system_program, token_program, associated_token_program, metadata_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(LockCpLiquidityInstructionAccountMetas {
+            authority: authority.clone(),
+            payer: payer.clone(),
+            liquidity_owner: liquidity_owner.clone(),
+            fee_nft_owner: fee_nft_owner.clone(),
+            fee_nft_mint: fee_nft_mint.clone(),
+            fee_nft_account: fee_nft_account.clone(),
+            pool_state: pool_state.clone(),
+            locked_liquidity: locked_liquidity.clone(),
+            lp_mint: lp_mint.clone(),
+            liquidity_owner_lp: liquidity_owner_lp.clone(),
+            locked_lp_vault: locked_lp_vault.clone(),
+            token0_vault: token0_vault.clone(),
+            token1_vault: token1_vault.clone(),
+            metadata_account: metadata_account.clone(),
+            rent: rent.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            associated_token_program: associated_token_program.clone(),
+            metadata_program: metadata_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct LockCpLiquidityInstructionAccountIndices {
+    pub authority: usize,
+    pub payer: usize,
+    pub liquidity_owner: usize,
+    pub fee_nft_owner: usize,
+    pub fee_nft_mint: usize,
+    pub fee_nft_account: usize,
+    pub pool_state: usize,
+    pub locked_liquidity: usize,
+    pub lp_mint: usize,
+    pub liquidity_owner_lp: usize,
+    pub locked_lp_vault: usize,
+    pub token0_vault: usize,
+    pub token1_vault: usize,
+    pub metadata_account: usize,
+    pub rent: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub associated_token_program: usize,
+    pub metadata_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for LockCpLiquidity {
+    type ArrangedAccountIndices = LockCpLiquidityInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 19 {
+            return None;
+        }
+
+        Some(LockCpLiquidityInstructionAccountIndices {
+            authority: 0,
+            payer: 1,
+            liquidity_owner: 2,
+            fee_nft_owner: 3,
+            fee_nft_mint: 4,
+            fee_nft_account: 5,
+            pool_state: 6,
+            locked_liquidity: 7,
+            lp_mint: 8,
+            liquidity_owner_lp: 9,
+            locked_lp_vault: 10,
+            token0_vault: 11,
+            token1_vault: 12,
+            metadata_account: 13,
+            rent: 14,
+            system_program: 15,
+            token_program: 16,
+            associated_token_program: 17,
+            metadata_program: 18,
+            remaining_accounts: 19..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for LockCpLiquidity {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "authority",
+            "payer",
+            "liquidity_owner",
+            "fee_nft_owner",
+            "fee_nft_mint",
+            "fee_nft_account",
+            "pool_state",
+            "locked_liquidity",
+            "lp_mint",
+            "liquidity_owner_lp",
+            "locked_lp_vault",
+            "token0_vault",
+            "token1_vault",
+            "metadata_account",
+            "rent",
+            "system_program",
+            "token_program",
+            "associated_token_program",
+            "metadata_program",
+        ]
+    }
+}
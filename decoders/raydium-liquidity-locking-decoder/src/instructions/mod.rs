@@ -28,20 +28,30 @@ pub enum RaydiumLiquidityLockingInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for RaydiumLiquidityLockingDecoder {
     type InstructionType = RaydiumLiquidityLockingInstruction;
 
-    fn decode_instruction(
+    fn decoder_name(&self) -> &'static str {
+        "raydium_liquidity_locking"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[super::PROGRAM_ID]
+    }
+
+    fn try_decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
-    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+    ) -> carbon_core::error::CarbonResult<
+        Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>>,
+    > {
         if !instruction.program_id.eq(&PROGRAM_ID) {
-            return None;
+            return Ok(None);
         }
 
-        carbon_core::try_decode_instructions!(instruction,
+        Ok(carbon_core::try_decode_instructions!(instruction,
             RaydiumLiquidityLockingInstruction::LockClmmPosition => lock_clmm_position::LockClmmPosition,
             RaydiumLiquidityLockingInstruction::CollectClmmFeesAndRewards => collect_clmm_fees_and_rewards::CollectClmmFeesAndRewards,
             RaydiumLiquidityLockingInstruction::LockCpLiquidity => lock_cp_liquidity::LockCpLiquidity,
             RaydiumLiquidityLockingInstruction::CollectCpFees => collect_cp_fees::CollectCpFees,
             RaydiumLiquidityLockingInstruction::SettleCpFeeEvent => settle_cp_fee_event::SettleCpFeeEvent,
-        )
+        ))
     }
 }
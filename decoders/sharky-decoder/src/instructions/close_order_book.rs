@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -9,6 +10,7 @@ pub struct CloseOrderBook {}
 pub struct CloseOrderBookInstructionAccounts {
     pub order_book: solana_pubkey::Pubkey,
     pub payer: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CloseOrderBook {
@@ -17,13 +19,68 @@ impl carbon_core::deserialize::ArrangeAccounts for CloseOrderBook {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [order_book, payer, _remaining @ ..] = accounts else {
+        let [order_book, payer, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(CloseOrderBookInstructionAccounts {
             order_book: order_book.pubkey,
             payer: payer.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CloseOrderBookInstructionAccountMetas {
+    pub order_book: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CloseOrderBook {
+    type ArrangedAccountMetas = CloseOrderBookInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [order_book, payer, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(CloseOrderBookInstructionAccountMetas {
+            order_book: order_book.clone(),
+            payer: payer.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CloseOrderBookInstructionAccountIndices {
+    pub order_book: usize,
+    pub payer: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CloseOrderBook {
+    type ArrangedAccountIndices = CloseOrderBookInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(CloseOrderBookInstructionAccountIndices {
+            order_book: 0,
+            payer: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CloseOrderBook {
+    fn account_names() -> &'static [&'static str] {
+        &["order_book", "payer"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +12,7 @@ pub struct CreateNftList {
 pub struct CreateNftListInstructionAccounts {
     pub nft_list: solana_pubkey::Pubkey,
     pub payer: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CreateNftList {
@@ -19,13 +21,68 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateNftList {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [nft_list, payer, _remaining @ ..] = accounts else {
+        let [nft_list, payer, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(CreateNftListInstructionAccounts {
             nft_list: nft_list.pubkey,
             payer: payer.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CreateNftListInstructionAccountMetas {
+    pub nft_list: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CreateNftList {
+    type ArrangedAccountMetas = CreateNftListInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [nft_list, payer, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(CreateNftListInstructionAccountMetas {
+            nft_list: nft_list.clone(),
+            payer: payer.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CreateNftListInstructionAccountIndices {
+    pub nft_list: usize,
+    pub payer: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CreateNftList {
+    type ArrangedAccountIndices = CreateNftListInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(CreateNftListInstructionAccountIndices {
+            nft_list: 0,
+            payer: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CreateNftList {
+    fn account_names() -> &'static [&'static str] {
+        &["nft_list", "payer"]
+    }
+}
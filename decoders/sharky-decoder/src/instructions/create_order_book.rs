@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -19,6 +20,7 @@ pub struct CreateOrderBookInstructionAccounts {
     pub order_book: solana_pubkey::Pubkey,
     pub payer: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CreateOrderBook {
@@ -27,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateOrderBook {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [order_book, payer, system_program, _remaining @ ..] = accounts else {
+        let [order_book, payer, system_program, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -35,6 +37,65 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateOrderBook {
             order_book: order_book.pubkey,
             payer: payer.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CreateOrderBookInstructionAccountMetas {
+    pub order_book: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CreateOrderBook {
+    type ArrangedAccountMetas = CreateOrderBookInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [order_book, payer, system_program, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(CreateOrderBookInstructionAccountMetas {
+            order_book: order_book.clone(),
+            payer: payer.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CreateOrderBookInstructionAccountIndices {
+    pub order_book: usize,
+    pub payer: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CreateOrderBook {
+    type ArrangedAccountIndices = CreateOrderBookInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(CreateOrderBookInstructionAccountIndices {
+            order_book: 0,
+            payer: 1,
+            system_program: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CreateOrderBook {
+    fn account_names() -> &'static [&'static str] {
+        &["order_book", "payer", "system_program"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +14,7 @@ pub struct CreateProgramVersionInstructionAccounts {
     pub program_version: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CreateProgramVersion {
@@ -21,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateProgramVersion {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [authority, program_version, system_program, rent, _remaining @ ..] = accounts else {
+        let [authority, program_version, system_program, rent, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -30,6 +32,69 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateProgramVersion {
             program_version: program_version.pubkey,
             system_program: system_program.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CreateProgramVersionInstructionAccountMetas {
+    pub authority: solana_instruction::AccountMeta,
+    pub program_version: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CreateProgramVersion {
+    type ArrangedAccountMetas = CreateProgramVersionInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [authority, program_version, system_program, rent, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(CreateProgramVersionInstructionAccountMetas {
+            authority: authority.clone(),
+            program_version: program_version.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CreateProgramVersionInstructionAccountIndices {
+    pub authority: usize,
+    pub program_version: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CreateProgramVersion {
+    type ArrangedAccountIndices = CreateProgramVersionInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(CreateProgramVersionInstructionAccountIndices {
+            authority: 0,
+            program_version: 1,
+            system_program: 2,
+            rent: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CreateProgramVersion {
+    fn account_names() -> &'static [&'static str] {
+        &["authority", "program_version", "system_program", "rent"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -30,6 +31,7 @@ pub struct ExtendLoanV3InstructionAccounts {
     pub associated_token_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub mpl_token_metadata_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ExtendLoanV3 {
@@ -38,7 +40,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ExtendLoanV3 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [loan, new_loan, borrower, borrower_collateral_token_account, lender, new_lender, escrow, escrow_collateral_token_account, new_escrow, new_escrow_collateral_token_account, value_mint, collateral_mint, order_book, fee_authority, metadata, edition, system_program, token_program, associated_token_program, rent, mpl_token_metadata_program, _remaining @ ..] =
+        let [loan, new_loan, borrower, borrower_collateral_token_account, lender, new_lender, escrow, escrow_collateral_token_account, new_escrow, new_escrow_collateral_token_account, value_mint, collateral_mint, order_book, fee_authority, metadata, edition, system_program, token_program, associated_token_program, rent, mpl_token_metadata_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -66,6 +68,161 @@ impl carbon_core::deserialize::ArrangeAccounts for ExtendLoanV3 {
             associated_token_program: associated_token_program.pubkey,
             rent: rent.pubkey,
             mpl_token_metadata_program: mpl_token_metadata_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ExtendLoanV3InstructionAccountMetas {
+    pub loan: solana_instruction::AccountMeta,
+    pub new_loan: solana_instruction::AccountMeta,
+    pub borrower: solana_instruction::AccountMeta,
+    pub borrower_collateral_token_account: solana_instruction::AccountMeta,
+    pub lender: solana_instruction::AccountMeta,
+    pub new_lender: solana_instruction::AccountMeta,
+    pub escrow: solana_instruction::AccountMeta,
+    pub escrow_collateral_token_account: solana_instruction::AccountMeta,
+    pub new_escrow: solana_instruction::AccountMeta,
+    pub new_escrow_collateral_token_account: solana_instruction::AccountMeta,
+    pub value_mint: solana_instruction::AccountMeta,
+    pub collateral_mint: solana_instruction::AccountMeta,
+    pub order_book: solana_instruction::AccountMeta,
+    pub fee_authority: solana_instruction::AccountMeta,
+    pub metadata: solana_instruction::AccountMeta,
+    pub edition: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub mpl_token_metadata_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ExtendLoanV3 {
+    type ArrangedAccountMetas = ExtendLoanV3InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [loan, new_loan, borrower, borrower_collateral_token_account, lender, new_lender, escrow, escrow_collateral_token_account, new_escrow, new_escrow_collateral_token_account, value_mint, collateral_mint, order_book, fee_authority, metadata, edition, system_program, token_program, associated_token_program, rent, mpl_token_metadata_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(ExtendLoanV3InstructionAccountMetas {
+            loan: loan.clone(),
+            new_loan: new_loan.clone(),
+            borrower: borrower.clone(),
+            borrower_collateral_token_account: borrower_collateral_token_account.clone(),
+            lender: lender.clone(),
+            new_lender: new_lender.clone(),
+            escrow: escrow.clone(),
+            escrow_collateral_token_account: escrow_collateral_token_account.clone(),
+            new_escrow: new_escrow.clone(),
+            new_escrow_collateral_token_account: new_escrow_collateral_token_account.clone(),
+            value_mint: value_mint.clone(),
+            collateral_mint: collateral_mint.clone(),
+            order_book: order_book.clone(),
+            fee_authority: fee_authority.clone(),
+            metadata: metadata.clone(),
+            edition: edition.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            associated_token_program: associated_token_program.clone(),
+            rent: rent.clone(),
+            mpl_token_metadata_program: mpl_token_metadata_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ExtendLoanV3InstructionAccountIndices {
+    pub loan: usize,
+    pub new_loan: usize,
+    pub borrower: usize,
+    pub borrower_collateral_token_account: usize,
+    pub lender: usize,
+    pub new_lender: usize,
+    pub escrow: usize,
+    pub escrow_collateral_token_account: usize,
+    pub new_escrow: usize,
+    pub new_escrow_collateral_token_account: usize,
+    pub value_mint: usize,
+    pub collateral_mint: usize,
+    pub order_book: usize,
+    pub fee_authority: usize,
+    pub metadata: usize,
+    pub edition: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub associated_token_program: usize,
+    pub rent: usize,
+    pub mpl_token_metadata_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ExtendLoanV3 {
+    type ArrangedAccountIndices = ExtendLoanV3InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 21 {
+            return None;
+        }
+
+        Some(ExtendLoanV3InstructionAccountIndices {
+            loan: 0,
+            new_loan: 1,
+            borrower: 2,
+            borrower_collateral_token_account: 3,
+            lender: 4,
+            new_lender: 5,
+            escrow: 6,
+            escrow_collateral_token_account: 7,
+            new_escrow: 8,
+            new_escrow_collateral_token_account: 9,
+            value_mint: 10,
+            collateral_mint: 11,
+            order_book: 12,
+            fee_authority: 13,
+            metadata: 14,
+            edition: 15,
+            system_program: 16,
+            token_program: 17,
+            associated_token_program: 18,
+            rent: 19,
+            mpl_token_metadata_program: 20,
+            remaining_accounts: 21..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ExtendLoanV3 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "loan",
+            "new_loan",
+            "borrower",
+            "borrower_collateral_token_account",
+            "lender",
+            "new_lender",
+            "escrow",
+            "escrow_collateral_token_account",
+            "new_escrow",
+            "new_escrow_collateral_token_account",
+            "value_mint",
+            "collateral_mint",
+            "order_book",
+            "fee_authority",
+            "metadata",
+            "edition",
+            "system_program",
+            "token_program",
+            "associated_token_program",
+            "rent",
+            "mpl_token_metadata_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -32,6 +33,7 @@ pub struct ExtendLoanV3CompressedInstructionAccounts {
     pub mpl_bubblegum_program: solana_pubkey::Pubkey,
     pub compression_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ExtendLoanV3Compressed {
@@ -40,7 +42,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ExtendLoanV3Compressed {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [loan, new_loan, borrower, lender, new_lender, escrow, new_escrow, value_mint, order_book, fee_authority, tree_authority, log_wrapper, merkle_tree, system_program, token_program, mpl_bubblegum_program, compression_program, rent, _remaining @ ..] =
+        let [loan, new_loan, borrower, lender, new_lender, escrow, new_escrow, value_mint, order_book, fee_authority, tree_authority, log_wrapper, merkle_tree, system_program, token_program, mpl_bubblegum_program, compression_program, rent, remaining @ ..] =
             accounts
         else {
             return None;
@@ -65,6 +67,146 @@ impl carbon_core::deserialize::ArrangeAccounts for ExtendLoanV3Compressed {
             mpl_bubblegum_program: mpl_bubblegum_program.pubkey,
             compression_program: compression_program.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ExtendLoanV3CompressedInstructionAccountMetas {
+    pub loan: solana_instruction::AccountMeta,
+    pub new_loan: solana_instruction::AccountMeta,
+    pub borrower: solana_instruction::AccountMeta,
+    pub lender: solana_instruction::AccountMeta,
+    pub new_lender: solana_instruction::AccountMeta,
+    pub escrow: solana_instruction::AccountMeta,
+    pub new_escrow: solana_instruction::AccountMeta,
+    pub value_mint: solana_instruction::AccountMeta,
+    pub order_book: solana_instruction::AccountMeta,
+    pub fee_authority: solana_instruction::AccountMeta,
+    pub tree_authority: solana_instruction::AccountMeta,
+    pub log_wrapper: solana_instruction::AccountMeta,
+    pub merkle_tree: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub mpl_bubblegum_program: solana_instruction::AccountMeta,
+    pub compression_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ExtendLoanV3Compressed {
+    type ArrangedAccountMetas = ExtendLoanV3CompressedInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [loan, new_loan, borrower, lender, new_lender, escrow, new_escrow, value_mint, order_book, fee_authority, tree_authority, log_wrapper, merkle_tree, system_program, token_program, mpl_bubblegum_program, compression_program, rent, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(ExtendLoanV3CompressedInstructionAccountMetas {
+            loan: loan.clone(),
+            new_loan: new_loan.clone(),
+            borrower: borrower.clone(),
+            lender: lender.clone(),
+            new_lender: new_lender.clone(),
+            escrow: escrow.clone(),
+            new_escrow: new_escrow.clone(),
+            value_mint: value_mint.clone(),
+            order_book: order_book.clone(),
+            fee_authority: fee_authority.clone(),
+            tree_authority: tree_authority.clone(),
+            log_wrapper: log_wrapper.clone(),
+            merkle_tree: merkle_tree.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            mpl_bubblegum_program: mpl_bubblegum_program.clone(),
+            compression_program: compression_program.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ExtendLoanV3CompressedInstructionAccountIndices {
+    pub loan: usize,
+    pub new_loan: usize,
+    pub borrower: usize,
+    pub lender: usize,
+    pub new_lender: usize,
+    pub escrow: usize,
+    pub new_escrow: usize,
+    pub value_mint: usize,
+    pub order_book: usize,
+    pub fee_authority: usize,
+    pub tree_authority: usize,
+    pub log_wrapper: usize,
+    pub merkle_tree: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub mpl_bubblegum_program: usize,
+    pub compression_program: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ExtendLoanV3Compressed {
+    type ArrangedAccountIndices = ExtendLoanV3CompressedInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 18 {
+            return None;
+        }
+
+        Some(ExtendLoanV3CompressedInstructionAccountIndices {
+            loan: 0,
+            new_loan: 1,
+            borrower: 2,
+            lender: 3,
+            new_lender: 4,
+            escrow: 5,
+            new_escrow: 6,
+            value_mint: 7,
+            order_book: 8,
+            fee_authority: 9,
+            tree_authority: 10,
+            log_wrapper: 11,
+            merkle_tree: 12,
+            system_program: 13,
+            token_program: 14,
+            mpl_bubblegum_program: 15,
+            compression_program: 16,
+            rent: 17,
+            remaining_accounts: 18..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ExtendLoanV3Compressed {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "loan",
+            "new_loan",
+            "borrower",
+            "lender",
+            "new_lender",
+            "escrow",
+            "new_escrow",
+            "value_mint",
+            "order_book",
+            "fee_authority",
+            "tree_authority",
+            "log_wrapper",
+            "merkle_tree",
+            "system_program",
+            "token_program",
+            "mpl_bubblegum_program",
+            "compression_program",
+            "rent",
+        ]
+    }
+}
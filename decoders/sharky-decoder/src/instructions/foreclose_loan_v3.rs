@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -22,6 +23,7 @@ pub struct ForecloseLoanV3InstructionAccounts {
     pub associated_token_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub mpl_token_metadata_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ForecloseLoanV3 {
@@ -30,7 +32,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ForecloseLoanV3 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [loan, escrow, escrow_collateral_token_account, collateral_mint, borrower, lender, lender_collateral_token_account, borrower_collateral_token_account, metadata, edition, system_program, token_program, associated_token_program, rent, mpl_token_metadata_program, _remaining @ ..] =
+        let [loan, escrow, escrow_collateral_token_account, collateral_mint, borrower, lender, lender_collateral_token_account, borrower_collateral_token_account, metadata, edition, system_program, token_program, associated_token_program, rent, mpl_token_metadata_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -52,6 +54,131 @@ impl carbon_core::deserialize::ArrangeAccounts for ForecloseLoanV3 {
             associated_token_program: associated_token_program.pubkey,
             rent: rent.pubkey,
             mpl_token_metadata_program: mpl_token_metadata_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ForecloseLoanV3InstructionAccountMetas {
+    pub loan: solana_instruction::AccountMeta,
+    pub escrow: solana_instruction::AccountMeta,
+    pub escrow_collateral_token_account: solana_instruction::AccountMeta,
+    pub collateral_mint: solana_instruction::AccountMeta,
+    pub borrower: solana_instruction::AccountMeta,
+    pub lender: solana_instruction::AccountMeta,
+    pub lender_collateral_token_account: solana_instruction::AccountMeta,
+    pub borrower_collateral_token_account: solana_instruction::AccountMeta,
+    pub metadata: solana_instruction::AccountMeta,
+    pub edition: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub mpl_token_metadata_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ForecloseLoanV3 {
+    type ArrangedAccountMetas = ForecloseLoanV3InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [loan, escrow, escrow_collateral_token_account, collateral_mint, borrower, lender, lender_collateral_token_account, borrower_collateral_token_account, metadata, edition, system_program, token_program, associated_token_program, rent, mpl_token_metadata_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(ForecloseLoanV3InstructionAccountMetas {
+            loan: loan.clone(),
+            escrow: escrow.clone(),
+            escrow_collateral_token_account: escrow_collateral_token_account.clone(),
+            collateral_mint: collateral_mint.clone(),
+            borrower: borrower.clone(),
+            lender: lender.clone(),
+            lender_collateral_token_account: lender_collateral_token_account.clone(),
+            borrower_collateral_token_account: borrower_collateral_token_account.clone(),
+            metadata: metadata.clone(),
+            edition: edition.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            associated_token_program: associated_token_program.clone(),
+            rent: rent.clone(),
+            mpl_token_metadata_program: mpl_token_metadata_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ForecloseLoanV3InstructionAccountIndices {
+    pub loan: usize,
+    pub escrow: usize,
+    pub escrow_collateral_token_account: usize,
+    pub collateral_mint: usize,
+    pub borrower: usize,
+    pub lender: usize,
+    pub lender_collateral_token_account: usize,
+    pub borrower_collateral_token_account: usize,
+    pub metadata: usize,
+    pub edition: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub associated_token_program: usize,
+    pub rent: usize,
+    pub mpl_token_metadata_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ForecloseLoanV3 {
+    type ArrangedAccountIndices = ForecloseLoanV3InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 15 {
+            return None;
+        }
+
+        Some(ForecloseLoanV3InstructionAccountIndices {
+            loan: 0,
+            escrow: 1,
+            escrow_collateral_token_account: 2,
+            collateral_mint: 3,
+            borrower: 4,
+            lender: 5,
+            lender_collateral_token_account: 6,
+            borrower_collateral_token_account: 7,
+            metadata: 8,
+            edition: 9,
+            system_program: 10,
+            token_program: 11,
+            associated_token_program: 12,
+            rent: 13,
+            mpl_token_metadata_program: 14,
+            remaining_accounts: 15..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ForecloseLoanV3 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "loan",
+            "escrow",
+            "escrow_collateral_token_account",
+            "collateral_mint",
+            "borrower",
+            "lender",
+            "lender_collateral_token_account",
+            "borrower_collateral_token_account",
+            "metadata",
+            "edition",
+            "system_program",
+            "token_program",
+            "associated_token_program",
+            "rent",
+            "mpl_token_metadata_program",
+        ]
+    }
+}
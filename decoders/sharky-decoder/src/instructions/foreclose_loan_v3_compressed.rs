@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -25,6 +26,7 @@ pub struct ForecloseLoanV3CompressedInstructionAccounts {
     pub mpl_bubblegum_program: solana_pubkey::Pubkey,
     pub compression_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ForecloseLoanV3Compressed {
@@ -33,7 +35,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ForecloseLoanV3Compressed {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [loan, escrow, borrower, lender, tree_authority, log_wrapper, merkle_tree, system_program, token_program, mpl_bubblegum_program, compression_program, rent, _remaining @ ..] =
+        let [loan, escrow, borrower, lender, tree_authority, log_wrapper, merkle_tree, system_program, token_program, mpl_bubblegum_program, compression_program, rent, remaining @ ..] =
             accounts
         else {
             return None;
@@ -52,6 +54,116 @@ impl carbon_core::deserialize::ArrangeAccounts for ForecloseLoanV3Compressed {
             mpl_bubblegum_program: mpl_bubblegum_program.pubkey,
             compression_program: compression_program.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ForecloseLoanV3CompressedInstructionAccountMetas {
+    pub loan: solana_instruction::AccountMeta,
+    pub escrow: solana_instruction::AccountMeta,
+    pub borrower: solana_instruction::AccountMeta,
+    pub lender: solana_instruction::AccountMeta,
+    pub tree_authority: solana_instruction::AccountMeta,
+    pub log_wrapper: solana_instruction::AccountMeta,
+    pub merkle_tree: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub mpl_bubblegum_program: solana_instruction::AccountMeta,
+    pub compression_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ForecloseLoanV3Compressed {
+    type ArrangedAccountMetas = ForecloseLoanV3CompressedInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [loan, escrow, borrower, lender, tree_authority, log_wrapper, merkle_tree, system_program, token_program, mpl_bubblegum_program, compression_program, rent, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(ForecloseLoanV3CompressedInstructionAccountMetas {
+            loan: loan.clone(),
+            escrow: escrow.clone(),
+            borrower: borrower.clone(),
+            lender: lender.clone(),
+            tree_authority: tree_authority.clone(),
+            log_wrapper: log_wrapper.clone(),
+            merkle_tree: merkle_tree.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            mpl_bubblegum_program: mpl_bubblegum_program.clone(),
+            compression_program: compression_program.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ForecloseLoanV3CompressedInstructionAccountIndices {
+    pub loan: usize,
+    pub escrow: usize,
+    pub borrower: usize,
+    pub lender: usize,
+    pub tree_authority: usize,
+    pub log_wrapper: usize,
+    pub merkle_tree: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub mpl_bubblegum_program: usize,
+    pub compression_program: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ForecloseLoanV3Compressed {
+    type ArrangedAccountIndices = ForecloseLoanV3CompressedInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 12 {
+            return None;
+        }
+
+        Some(ForecloseLoanV3CompressedInstructionAccountIndices {
+            loan: 0,
+            escrow: 1,
+            borrower: 2,
+            lender: 3,
+            tree_authority: 4,
+            log_wrapper: 5,
+            merkle_tree: 6,
+            system_program: 7,
+            token_program: 8,
+            mpl_bubblegum_program: 9,
+            compression_program: 10,
+            rent: 11,
+            remaining_accounts: 12..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ForecloseLoanV3Compressed {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "loan",
+            "escrow",
+            "borrower",
+            "lender",
+            "tree_authority",
+            "log_wrapper",
+            "merkle_tree",
+            "system_program",
+            "token_program",
+            "mpl_bubblegum_program",
+            "compression_program",
+            "rent",
+        ]
+    }
+}
@@ -54,15 +54,25 @@ pub enum SharkyInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for SharkyDecoder {
     type InstructionType = SharkyInstruction;
 
-    fn decode_instruction(
+    fn decoder_name(&self) -> &'static str {
+        "sharky"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[super::PROGRAM_ID]
+    }
+
+    fn try_decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
-    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+    ) -> carbon_core::error::CarbonResult<
+        Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>>,
+    > {
         if !instruction.program_id.eq(&PROGRAM_ID) {
-            return None;
+            return Ok(None);
         }
 
-        carbon_core::try_decode_instructions!(instruction,
+        Ok(carbon_core::try_decode_instructions!(instruction,
             SharkyInstruction::CreateOrderBook => create_order_book::CreateOrderBook,
             SharkyInstruction::UpdateOrderBook => update_order_book::UpdateOrderBook,
             SharkyInstruction::CloseOrderBook => close_order_book::CloseOrderBook,
@@ -81,6 +91,6 @@ impl carbon_core::instruction::InstructionDecoder<'_> for SharkyDecoder {
             SharkyInstruction::CloseNftList => close_nft_list::CloseNftList,
             SharkyInstruction::CreateProgramVersion => create_program_version::CreateProgramVersion,
             SharkyInstruction::UpdateProgramVersion => update_program_version::UpdateProgramVersion,
-        )
+        ))
     }
 }
@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -25,6 +26,7 @@ pub struct OfferLoanInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub associated_token_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for OfferLoan {
@@ -33,7 +35,7 @@ impl carbon_core::deserialize::ArrangeAccounts for OfferLoan {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [lender, lender_value_token_account, value_mint, loan, escrow, escrow_token_account, order_book, system_program, token_program, associated_token_program, rent, _remaining @ ..] =
+        let [lender, lender_value_token_account, value_mint, loan, escrow, escrow_token_account, order_book, system_program, token_program, associated_token_program, rent, remaining @ ..] =
             accounts
         else {
             return None;
@@ -51,6 +53,111 @@ impl carbon_core::deserialize::ArrangeAccounts for OfferLoan {
             token_program: token_program.pubkey,
             associated_token_program: associated_token_program.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct OfferLoanInstructionAccountMetas {
+    pub lender: solana_instruction::AccountMeta,
+    pub lender_value_token_account: solana_instruction::AccountMeta,
+    pub value_mint: solana_instruction::AccountMeta,
+    pub loan: solana_instruction::AccountMeta,
+    pub escrow: solana_instruction::AccountMeta,
+    pub escrow_token_account: solana_instruction::AccountMeta,
+    pub order_book: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for OfferLoan {
+    type ArrangedAccountMetas = OfferLoanInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [lender, lender_value_token_account, value_mint, loan, escrow, escrow_token_account, order_book, system_program, token_program, associated_token_program, rent, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(OfferLoanInstructionAccountMetas {
+            lender: lender.clone(),
+            lender_value_token_account: lender_value_token_account.clone(),
+            value_mint: value_mint.clone(),
+            loan: loan.clone(),
+            escrow: escrow.clone(),
+            escrow_token_account: escrow_token_account.clone(),
+            order_book: order_book.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            associated_token_program: associated_token_program.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct OfferLoanInstructionAccountIndices {
+    pub lender: usize,
+    pub lender_value_token_account: usize,
+    pub value_mint: usize,
+    pub loan: usize,
+    pub escrow: usize,
+    pub escrow_token_account: usize,
+    pub order_book: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub associated_token_program: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for OfferLoan {
+    type ArrangedAccountIndices = OfferLoanInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 11 {
+            return None;
+        }
+
+        Some(OfferLoanInstructionAccountIndices {
+            lender: 0,
+            lender_value_token_account: 1,
+            value_mint: 2,
+            loan: 3,
+            escrow: 4,
+            escrow_token_account: 5,
+            order_book: 6,
+            system_program: 7,
+            token_program: 8,
+            associated_token_program: 9,
+            rent: 10,
+            remaining_accounts: 11..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for OfferLoan {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "lender",
+            "lender_value_token_account",
+            "value_mint",
+            "loan",
+            "escrow",
+            "escrow_token_account",
+            "order_book",
+            "system_program",
+            "token_program",
+            "associated_token_program",
+            "rent",
+        ]
+    }
+}
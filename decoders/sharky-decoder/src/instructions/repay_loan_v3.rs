@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -24,6 +25,7 @@ pub struct RepayLoanV3InstructionAccounts {
     pub associated_token_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
     pub mpl_token_metadata_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for RepayLoanV3 {
@@ -32,7 +34,7 @@ impl carbon_core::deserialize::ArrangeAccounts for RepayLoanV3 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [loan, borrower, borrower_collateral_token_account, lender, escrow, escrow_collateral_token_account, value_mint, collateral_mint, order_book, fee_authority, metadata, edition, system_program, token_program, associated_token_program, rent, mpl_token_metadata_program, _remaining @ ..] =
+        let [loan, borrower, borrower_collateral_token_account, lender, escrow, escrow_collateral_token_account, value_mint, collateral_mint, order_book, fee_authority, metadata, edition, system_program, token_program, associated_token_program, rent, mpl_token_metadata_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -56,6 +58,141 @@ impl carbon_core::deserialize::ArrangeAccounts for RepayLoanV3 {
             associated_token_program: associated_token_program.pubkey,
             rent: rent.pubkey,
             mpl_token_metadata_program: mpl_token_metadata_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct RepayLoanV3InstructionAccountMetas {
+    pub loan: solana_instruction::AccountMeta,
+    pub borrower: solana_instruction::AccountMeta,
+    pub borrower_collateral_token_account: solana_instruction::AccountMeta,
+    pub lender: solana_instruction::AccountMeta,
+    pub escrow: solana_instruction::AccountMeta,
+    pub escrow_collateral_token_account: solana_instruction::AccountMeta,
+    pub value_mint: solana_instruction::AccountMeta,
+    pub collateral_mint: solana_instruction::AccountMeta,
+    pub order_book: solana_instruction::AccountMeta,
+    pub fee_authority: solana_instruction::AccountMeta,
+    pub metadata: solana_instruction::AccountMeta,
+    pub edition: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub mpl_token_metadata_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for RepayLoanV3 {
+    type ArrangedAccountMetas = RepayLoanV3InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [loan, borrower, borrower_collateral_token_account, lender, escrow, escrow_collateral_token_account, value_mint, collateral_mint, order_book, fee_authority, metadata, edition, system_program, token_program, associated_token_program, rent, mpl_token_metadata_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(RepayLoanV3InstructionAccountMetas {
+            loan: loan.clone(),
+            borrower: borrower.clone(),
+            borrower_collateral_token_account: borrower_collateral_token_account.clone(),
+            lender: lender.clone(),
+            escrow: escrow.clone(),
+            escrow_collateral_token_account: escrow_collateral_token_account.clone(),
+            value_mint: value_mint.clone(),
+            collateral_mint: collateral_mint.clone(),
+            order_book: order_book.clone(),
+            fee_authority: fee_authority.clone(),
+            metadata: metadata.clone(),
+            edition: edition.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            associated_token_program: associated_token_program.clone(),
+            rent: rent.clone(),
+            mpl_token_metadata_program: mpl_token_metadata_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct RepayLoanV3InstructionAccountIndices {
+    pub loan: usize,
+    pub borrower: usize,
+    pub borrower_collateral_token_account: usize,
+    pub lender: usize,
+    pub escrow: usize,
+    pub escrow_collateral_token_account: usize,
+    pub value_mint: usize,
+    pub collateral_mint: usize,
+    pub order_book: usize,
+    pub fee_authority: usize,
+    pub metadata: usize,
+    pub edition: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub associated_token_program: usize,
+    pub rent: usize,
+    pub mpl_token_metadata_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for RepayLoanV3 {
+    type ArrangedAccountIndices = RepayLoanV3InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 17 {
+            return None;
+        }
+
+        Some(RepayLoanV3InstructionAccountIndices {
+            loan: 0,
+            borrower: 1,
+            borrower_collateral_token_account: 2,
+            lender: 3,
+            escrow: 4,
+            escrow_collateral_token_account: 5,
+            value_mint: 6,
+            collateral_mint: 7,
+            order_book: 8,
+            fee_authority: 9,
+            metadata: 10,
+            edition: 11,
+            system_program: 12,
+            token_program: 13,
+            associated_token_program: 14,
+            rent: 15,
+            mpl_token_metadata_program: 16,
+            remaining_accounts: 17..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for RepayLoanV3 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "loan",
+            "borrower",
+            "borrower_collateral_token_account",
+            "lender",
+            "escrow",
+            "escrow_collateral_token_account",
+            "value_mint",
+            "collateral_mint",
+            "order_book",
+            "fee_authority",
+            "metadata",
+            "edition",
+            "system_program",
+            "token_program",
+            "associated_token_program",
+            "rent",
+            "mpl_token_metadata_program",
+        ]
+    }
+}
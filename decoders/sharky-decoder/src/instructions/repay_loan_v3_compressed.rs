@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -28,6 +29,7 @@ pub struct RepayLoanV3CompressedInstructionAccounts {
     pub mpl_bubblegum_program: solana_pubkey::Pubkey,
     pub compression_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for RepayLoanV3Compressed {
@@ -36,7 +38,7 @@ impl carbon_core::deserialize::ArrangeAccounts for RepayLoanV3Compressed {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [loan, borrower, lender, escrow, value_mint, order_book, fee_authority, tree_authority, log_wrapper, merkle_tree, system_program, token_program, mpl_bubblegum_program, compression_program, rent, _remaining @ ..] =
+        let [loan, borrower, lender, escrow, value_mint, order_book, fee_authority, tree_authority, log_wrapper, merkle_tree, system_program, token_program, mpl_bubblegum_program, compression_program, rent, remaining @ ..] =
             accounts
         else {
             return None;
@@ -58,6 +60,131 @@ impl carbon_core::deserialize::ArrangeAccounts for RepayLoanV3Compressed {
             mpl_bubblegum_program: mpl_bubblegum_program.pubkey,
             compression_program: compression_program.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct RepayLoanV3CompressedInstructionAccountMetas {
+    pub loan: solana_instruction::AccountMeta,
+    pub borrower: solana_instruction::AccountMeta,
+    pub lender: solana_instruction::AccountMeta,
+    pub escrow: solana_instruction::AccountMeta,
+    pub value_mint: solana_instruction::AccountMeta,
+    pub order_book: solana_instruction::AccountMeta,
+    pub fee_authority: solana_instruction::AccountMeta,
+    pub tree_authority: solana_instruction::AccountMeta,
+    pub log_wrapper: solana_instruction::AccountMeta,
+    pub merkle_tree: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub mpl_bubblegum_program: solana_instruction::AccountMeta,
+    pub compression_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for RepayLoanV3Compressed {
+    type ArrangedAccountMetas = RepayLoanV3CompressedInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [loan, borrower, lender, escrow, value_mint, order_book, fee_authority, tree_authority, log_wrapper, merkle_tree, system_program, token_program, mpl_bubblegum_program, compression_program, rent, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(RepayLoanV3CompressedInstructionAccountMetas {
+            loan: loan.clone(),
+            borrower: borrower.clone(),
+            lender: lender.clone(),
+            escrow: escrow.clone(),
+            value_mint: value_mint.clone(),
+            order_book: order_book.clone(),
+            fee_authority: fee_authority.clone(),
+            tree_authority: tree_authority.clone(),
+            log_wrapper: log_wrapper.clone(),
+            merkle_tree: merkle_tree.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            mpl_bubblegum_program: mpl_bubblegum_program.clone(),
+            compression_program: compression_program.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct RepayLoanV3CompressedInstructionAccountIndices {
+    pub loan: usize,
+    pub borrower: usize,
+    pub lender: usize,
+    pub escrow: usize,
+    pub value_mint: usize,
+    pub order_book: usize,
+    pub fee_authority: usize,
+    pub tree_authority: usize,
+    pub log_wrapper: usize,
+    pub merkle_tree: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub mpl_bubblegum_program: usize,
+    pub compression_program: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for RepayLoanV3Compressed {
+    type ArrangedAccountIndices = RepayLoanV3CompressedInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 15 {
+            return None;
+        }
+
+        Some(RepayLoanV3CompressedInstructionAccountIndices {
+            loan: 0,
+            borrower: 1,
+            lender: 2,
+            escrow: 3,
+            value_mint: 4,
+            order_book: 5,
+            fee_authority: 6,
+            tree_authority: 7,
+            log_wrapper: 8,
+            merkle_tree: 9,
+            system_program: 10,
+            token_program: 11,
+            mpl_bubblegum_program: 12,
+            compression_program: 13,
+            rent: 14,
+            remaining_accounts: 15..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for RepayLoanV3Compressed {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "loan",
+            "borrower",
+            "lender",
+            "escrow",
+            "value_mint",
+            "order_book",
+            "fee_authority",
+            "tree_authority",
+            "log_wrapper",
+            "merkle_tree",
+            "system_program",
+            "token_program",
+            "mpl_bubblegum_program",
+            "compression_program",
+            "rent",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -15,6 +16,7 @@ pub struct RescindLoanInstructionAccounts {
     pub escrow_token_account: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for RescindLoan {
@@ -23,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for RescindLoan {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [loan, lender_value_token_account, lender, value_mint, escrow, escrow_token_account, system_program, token_program, _remaining @ ..] =
+        let [loan, lender_value_token_account, lender, value_mint, escrow, escrow_token_account, system_program, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -38,6 +40,96 @@ impl carbon_core::deserialize::ArrangeAccounts for RescindLoan {
             escrow_token_account: escrow_token_account.pubkey,
             system_program: system_program.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct RescindLoanInstructionAccountMetas {
+    pub loan: solana_instruction::AccountMeta,
+    pub lender_value_token_account: solana_instruction::AccountMeta,
+    pub lender: solana_instruction::AccountMeta,
+    pub value_mint: solana_instruction::AccountMeta,
+    pub escrow: solana_instruction::AccountMeta,
+    pub escrow_token_account: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for RescindLoan {
+    type ArrangedAccountMetas = RescindLoanInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [loan, lender_value_token_account, lender, value_mint, escrow, escrow_token_account, system_program, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(RescindLoanInstructionAccountMetas {
+            loan: loan.clone(),
+            lender_value_token_account: lender_value_token_account.clone(),
+            lender: lender.clone(),
+            value_mint: value_mint.clone(),
+            escrow: escrow.clone(),
+            escrow_token_account: escrow_token_account.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct RescindLoanInstructionAccountIndices {
+    pub loan: usize,
+    pub lender_value_token_account: usize,
+    pub lender: usize,
+    pub value_mint: usize,
+    pub escrow: usize,
+    pub escrow_token_account: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for RescindLoan {
+    type ArrangedAccountIndices = RescindLoanInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(RescindLoanInstructionAccountIndices {
+            loan: 0,
+            lender_value_token_account: 1,
+            lender: 2,
+            value_mint: 3,
+            escrow: 4,
+            escrow_token_account: 5,
+            system_program: 6,
+            token_program: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for RescindLoan {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "loan",
+            "lender_value_token_account",
+            "lender",
+            "value_mint",
+            "escrow",
+            "escrow_token_account",
+            "system_program",
+            "token_program",
+        ]
+    }
+}
@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -28,6 +29,7 @@ pub struct TakeLoanV3CompressedInstructionAccounts {
     pub mpl_bubblegum_program: solana_pubkey::Pubkey,
     pub compression_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for TakeLoanV3Compressed {
@@ -36,7 +38,7 @@ impl carbon_core::deserialize::ArrangeAccounts for TakeLoanV3Compressed {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [lender, borrower, loan, escrow, order_book, collateral_mint, tree_authority, log_wrapper, merkle_tree, system_program, token_program, mpl_bubblegum_program, compression_program, rent, _remaining @ ..] =
+        let [lender, borrower, loan, escrow, order_book, collateral_mint, tree_authority, log_wrapper, merkle_tree, system_program, token_program, mpl_bubblegum_program, compression_program, rent, remaining @ ..] =
             accounts
         else {
             return None;
@@ -57,6 +59,126 @@ impl carbon_core::deserialize::ArrangeAccounts for TakeLoanV3Compressed {
             mpl_bubblegum_program: mpl_bubblegum_program.pubkey,
             compression_program: compression_program.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct TakeLoanV3CompressedInstructionAccountMetas {
+    pub lender: solana_instruction::AccountMeta,
+    pub borrower: solana_instruction::AccountMeta,
+    pub loan: solana_instruction::AccountMeta,
+    pub escrow: solana_instruction::AccountMeta,
+    pub order_book: solana_instruction::AccountMeta,
+    pub collateral_mint: solana_instruction::AccountMeta,
+    pub tree_authority: solana_instruction::AccountMeta,
+    pub log_wrapper: solana_instruction::AccountMeta,
+    pub merkle_tree: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub mpl_bubblegum_program: solana_instruction::AccountMeta,
+    pub compression_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for TakeLoanV3Compressed {
+    type ArrangedAccountMetas = TakeLoanV3CompressedInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [lender, borrower, loan, escrow, order_book, collateral_mint, tree_authority, log_wrapper, merkle_tree, system_program, token_program, mpl_bubblegum_program, compression_program, rent, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(TakeLoanV3CompressedInstructionAccountMetas {
+            lender: lender.clone(),
+            borrower: borrower.clone(),
+            loan: loan.clone(),
+            escrow: escrow.clone(),
+            order_book: order_book.clone(),
+            collateral_mint: collateral_mint.clone(),
+            tree_authority: tree_authority.clone(),
+            log_wrapper: log_wrapper.clone(),
+            merkle_tree: merkle_tree.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            mpl_bubblegum_program: mpl_bubblegum_program.clone(),
+            compression_program: compression_program.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct TakeLoanV3CompressedInstructionAccountIndices {
+    pub lender: usize,
+    pub borrower: usize,
+    pub loan: usize,
+    pub escrow: usize,
+    pub order_book: usize,
+    pub collateral_mint: usize,
+    pub tree_authority: usize,
+    pub log_wrapper: usize,
+    pub merkle_tree: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub mpl_bubblegum_program: usize,
+    pub compression_program: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for TakeLoanV3Compressed {
+    type ArrangedAccountIndices = TakeLoanV3CompressedInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 14 {
+            return None;
+        }
+
+        Some(TakeLoanV3CompressedInstructionAccountIndices {
+            lender: 0,
+            borrower: 1,
+            loan: 2,
+            escrow: 3,
+            order_book: 4,
+            collateral_mint: 5,
+            tree_authority: 6,
+            log_wrapper: 7,
+            merkle_tree: 8,
+            system_program: 9,
+            token_program: 10,
+            mpl_bubblegum_program: 11,
+            compression_program: 12,
+            rent: 13,
+            remaining_accounts: 14..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for TakeLoanV3Compressed {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "lender",
+            "borrower",
+            "loan",
+            "escrow",
+            "order_book",
+            "collateral_mint",
+            "tree_authority",
+            "log_wrapper",
+            "merkle_tree",
+            "system_program",
+            "token_program",
+            "mpl_bubblegum_program",
+            "compression_program",
+            "rent",
+        ]
+    }
+}
@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +15,7 @@ pub struct UpdateNftList {
 pub struct UpdateNftListInstructionAccounts {
     pub nft_list: solana_pubkey::Pubkey,
     pub payer: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateNftList {
@@ -22,13 +24,68 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateNftList {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [nft_list, payer, _remaining @ ..] = accounts else {
+        let [nft_list, payer, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(UpdateNftListInstructionAccounts {
             nft_list: nft_list.pubkey,
             payer: payer.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateNftListInstructionAccountMetas {
+    pub nft_list: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateNftList {
+    type ArrangedAccountMetas = UpdateNftListInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [nft_list, payer, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateNftListInstructionAccountMetas {
+            nft_list: nft_list.clone(),
+            payer: payer.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateNftListInstructionAccountIndices {
+    pub nft_list: usize,
+    pub payer: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateNftList {
+    type ArrangedAccountIndices = UpdateNftListInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(UpdateNftListInstructionAccountIndices {
+            nft_list: 0,
+            payer: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateNftList {
+    fn account_names() -> &'static [&'static str] {
+        &["nft_list", "payer"]
+    }
+}
@@ -3,6 +3,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -18,6 +19,7 @@ pub struct UpdateOrderBook {
 pub struct UpdateOrderBookInstructionAccounts {
     pub order_book: solana_pubkey::Pubkey,
     pub payer: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateOrderBook {
@@ -26,13 +28,68 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateOrderBook {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [order_book, payer, _remaining @ ..] = accounts else {
+        let [order_book, payer, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(UpdateOrderBookInstructionAccounts {
             order_book: order_book.pubkey,
             payer: payer.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateOrderBookInstructionAccountMetas {
+    pub order_book: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateOrderBook {
+    type ArrangedAccountMetas = UpdateOrderBookInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [order_book, payer, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateOrderBookInstructionAccountMetas {
+            order_book: order_book.clone(),
+            payer: payer.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateOrderBookInstructionAccountIndices {
+    pub order_book: usize,
+    pub payer: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateOrderBook {
+    type ArrangedAccountIndices = UpdateOrderBookInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(UpdateOrderBookInstructionAccountIndices {
+            order_book: 0,
+            payer: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateOrderBook {
+    fn account_names() -> &'static [&'static str] {
+        &["order_book", "payer"]
+    }
+}
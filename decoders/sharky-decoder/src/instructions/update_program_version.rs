@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +12,7 @@ pub struct UpdateProgramVersion {
 pub struct UpdateProgramVersionInstructionAccounts {
     pub authority: solana_pubkey::Pubkey,
     pub program_version: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateProgramVersion {
@@ -19,13 +21,68 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateProgramVersion {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [authority, program_version, _remaining @ ..] = accounts else {
+        let [authority, program_version, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(UpdateProgramVersionInstructionAccounts {
             authority: authority.pubkey,
             program_version: program_version.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateProgramVersionInstructionAccountMetas {
+    pub authority: solana_instruction::AccountMeta,
+    pub program_version: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateProgramVersion {
+    type ArrangedAccountMetas = UpdateProgramVersionInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [authority, program_version, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateProgramVersionInstructionAccountMetas {
+            authority: authority.clone(),
+            program_version: program_version.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateProgramVersionInstructionAccountIndices {
+    pub authority: usize,
+    pub program_version: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateProgramVersion {
+    type ArrangedAccountIndices = UpdateProgramVersionInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(UpdateProgramVersionInstructionAccountIndices {
+            authority: 0,
+            program_version: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateProgramVersion {
+    fn account_names() -> &'static [&'static str] {
+        &["authority", "program_version"]
+    }
+}
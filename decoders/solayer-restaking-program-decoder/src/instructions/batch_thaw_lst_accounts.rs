@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -15,6 +16,7 @@ pub struct BatchThawLstAccountsInstructionAccounts {
     pub associated_token_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for BatchThawLstAccounts {
@@ -23,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for BatchThawLstAccounts {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [signer, solayer_admin, lst_mint, rst_mint, pool, associated_token_program, token_program, system_program, _remaining @ ..] =
+        let [signer, solayer_admin, lst_mint, rst_mint, pool, associated_token_program, token_program, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -38,6 +40,96 @@ impl carbon_core::deserialize::ArrangeAccounts for BatchThawLstAccounts {
             associated_token_program: associated_token_program.pubkey,
             token_program: token_program.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct BatchThawLstAccountsInstructionAccountMetas {
+    pub signer: solana_instruction::AccountMeta,
+    pub solayer_admin: solana_instruction::AccountMeta,
+    pub lst_mint: solana_instruction::AccountMeta,
+    pub rst_mint: solana_instruction::AccountMeta,
+    pub pool: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for BatchThawLstAccounts {
+    type ArrangedAccountMetas = BatchThawLstAccountsInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [signer, solayer_admin, lst_mint, rst_mint, pool, associated_token_program, token_program, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(BatchThawLstAccountsInstructionAccountMetas {
+            signer: signer.clone(),
+            solayer_admin: solayer_admin.clone(),
+            lst_mint: lst_mint.clone(),
+            rst_mint: rst_mint.clone(),
+            pool: pool.clone(),
+            associated_token_program: associated_token_program.clone(),
+            token_program: token_program.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct BatchThawLstAccountsInstructionAccountIndices {
+    pub signer: usize,
+    pub solayer_admin: usize,
+    pub lst_mint: usize,
+    pub rst_mint: usize,
+    pub pool: usize,
+    pub associated_token_program: usize,
+    pub token_program: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for BatchThawLstAccounts {
+    type ArrangedAccountIndices = BatchThawLstAccountsInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(BatchThawLstAccountsInstructionAccountIndices {
+            signer: 0,
+            solayer_admin: 1,
+            lst_mint: 2,
+            rst_mint: 3,
+            pool: 4,
+            associated_token_program: 5,
+            token_program: 6,
+            system_program: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for BatchThawLstAccounts {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "signer",
+            "solayer_admin",
+            "lst_mint",
+            "rst_mint",
+            "pool",
+            "associated_token_program",
+            "token_program",
+            "system_program",
+        ]
+    }
+}
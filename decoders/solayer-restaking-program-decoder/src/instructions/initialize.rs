@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -16,6 +17,7 @@ pub struct InitializeInstructionAccounts {
     pub associated_token_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Initialize {
@@ -24,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Initialize {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [signer, solayer_admin, lst_mint, lst_vault, rst_mint, pool, associated_token_program, token_program, system_program, _remaining @ ..] =
+        let [signer, solayer_admin, lst_mint, lst_vault, rst_mint, pool, associated_token_program, token_program, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -40,6 +42,101 @@ impl carbon_core::deserialize::ArrangeAccounts for Initialize {
             associated_token_program: associated_token_program.pubkey,
             token_program: token_program.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeInstructionAccountMetas {
+    pub signer: solana_instruction::AccountMeta,
+    pub solayer_admin: solana_instruction::AccountMeta,
+    pub lst_mint: solana_instruction::AccountMeta,
+    pub lst_vault: solana_instruction::AccountMeta,
+    pub rst_mint: solana_instruction::AccountMeta,
+    pub pool: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Initialize {
+    type ArrangedAccountMetas = InitializeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [signer, solayer_admin, lst_mint, lst_vault, rst_mint, pool, associated_token_program, token_program, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeInstructionAccountMetas {
+            signer: signer.clone(),
+            solayer_admin: solayer_admin.clone(),
+            lst_mint: lst_mint.clone(),
+            lst_vault: lst_vault.clone(),
+            rst_mint: rst_mint.clone(),
+            pool: pool.clone(),
+            associated_token_program: associated_token_program.clone(),
+            token_program: token_program.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeInstructionAccountIndices {
+    pub signer: usize,
+    pub solayer_admin: usize,
+    pub lst_mint: usize,
+    pub lst_vault: usize,
+    pub rst_mint: usize,
+    pub pool: usize,
+    pub associated_token_program: usize,
+    pub token_program: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Initialize {
+    type ArrangedAccountIndices = InitializeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 9 {
+            return None;
+        }
+
+        Some(InitializeInstructionAccountIndices {
+            signer: 0,
+            solayer_admin: 1,
+            lst_mint: 2,
+            lst_vault: 3,
+            rst_mint: 4,
+            pool: 5,
+            associated_token_program: 6,
+            token_program: 7,
+            system_program: 8,
+            remaining_accounts: 9..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Initialize {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "signer",
+            "solayer_admin",
+            "lst_mint",
+            "lst_vault",
+            "rst_mint",
+            "pool",
+            "associated_token_program",
+            "token_program",
+            "system_program",
+        ]
+    }
+}
@@ -24,18 +24,28 @@ pub enum SolayerRestakingProgramInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for SolayerRestakingProgramDecoder {
     type InstructionType = SolayerRestakingProgramInstruction;
 
-    fn decode_instruction(
+    fn decoder_name(&self) -> &'static str {
+        "solayer_restaking_program"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[super::PROGRAM_ID]
+    }
+
+    fn try_decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
-    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+    ) -> carbon_core::error::CarbonResult<
+        Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>>,
+    > {
         if !instruction.program_id.eq(&PROGRAM_ID) {
-            return None;
+            return Ok(None);
         }
-        carbon_core::try_decode_instructions!(instruction,
+        Ok(carbon_core::try_decode_instructions!(instruction,
             SolayerRestakingProgramInstruction::Initialize => initialize::Initialize,
             SolayerRestakingProgramInstruction::Restake => restake::Restake,
             SolayerRestakingProgramInstruction::Unrestake => unrestake::Unrestake,
             SolayerRestakingProgramInstruction::BatchThawLstAccounts => batch_thaw_lst_accounts::BatchThawLstAccounts,
-        )
+        ))
     }
 }
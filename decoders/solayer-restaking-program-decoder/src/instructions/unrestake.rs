@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -19,6 +20,7 @@ pub struct UnrestakeInstructionAccounts {
     pub associated_token_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Unrestake {
@@ -27,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Unrestake {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [signer, lst_mint, lst_ata, rst_ata, rst_mint, vault, pool, associated_token_program, token_program, system_program, _remaining @ ..] =
+        let [signer, lst_mint, lst_ata, rst_ata, rst_mint, vault, pool, associated_token_program, token_program, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -44,6 +46,106 @@ impl carbon_core::deserialize::ArrangeAccounts for Unrestake {
             associated_token_program: associated_token_program.pubkey,
             token_program: token_program.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UnrestakeInstructionAccountMetas {
+    pub signer: solana_instruction::AccountMeta,
+    pub lst_mint: solana_instruction::AccountMeta,
+    pub lst_ata: solana_instruction::AccountMeta,
+    pub rst_ata: solana_instruction::AccountMeta,
+    pub rst_mint: solana_instruction::AccountMeta,
+    pub vault: solana_instruction::AccountMeta,
+    pub pool: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Unrestake {
+    type ArrangedAccountMetas = UnrestakeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [signer, lst_mint, lst_ata, rst_ata, rst_mint, vault, pool, associated_token_program, token_program, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(UnrestakeInstructionAccountMetas {
+            signer: signer.clone(),
+            lst_mint: lst_mint.clone(),
+            lst_ata: lst_ata.clone(),
+            rst_ata: rst_ata.clone(),
+            rst_mint: rst_mint.clone(),
+            vault: vault.clone(),
+            pool: pool.clone(),
+            associated_token_program: associated_token_program.clone(),
+            token_program: token_program.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UnrestakeInstructionAccountIndices {
+    pub signer: usize,
+    pub lst_mint: usize,
+    pub lst_ata: usize,
+    pub rst_ata: usize,
+    pub rst_mint: usize,
+    pub vault: usize,
+    pub pool: usize,
+    pub associated_token_program: usize,
+    pub token_program: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Unrestake {
+    type ArrangedAccountIndices = UnrestakeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 10 {
+            return None;
+        }
+
+        Some(UnrestakeInstructionAccountIndices {
+            signer: 0,
+            lst_mint: 1,
+            lst_ata: 2,
+            rst_ata: 3,
+            rst_mint: 4,
+            vault: 5,
+            pool: 6,
+            associated_token_program: 7,
+            token_program: 8,
+            system_program: 9,
+            remaining_accounts: 10..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Unrestake {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "signer",
+            "lst_mint",
+            "lst_ata",
+            "rst_ata",
+            "rst_mint",
+            "vault",
+            "pool",
+            "associated_token_program",
+            "token_program",
+            "system_program",
+        ]
+    }
+}
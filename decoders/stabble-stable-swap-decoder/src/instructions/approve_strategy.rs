@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +12,7 @@ pub struct ApproveStrategyInstructionAccounts {
     pub vault: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
     pub strategy: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ApproveStrategy {
@@ -19,7 +21,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ApproveStrategy {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [pool, vault, admin, strategy, _remaining @ ..] = accounts else {
+        let [pool, vault, admin, strategy, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +30,69 @@ impl carbon_core::deserialize::ArrangeAccounts for ApproveStrategy {
             vault: vault.pubkey,
             admin: admin.pubkey,
             strategy: strategy.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ApproveStrategyInstructionAccountMetas {
+    pub pool: solana_instruction::AccountMeta,
+    pub vault: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub strategy: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ApproveStrategy {
+    type ArrangedAccountMetas = ApproveStrategyInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [pool, vault, admin, strategy, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(ApproveStrategyInstructionAccountMetas {
+            pool: pool.clone(),
+            vault: vault.clone(),
+            admin: admin.clone(),
+            strategy: strategy.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ApproveStrategyInstructionAccountIndices {
+    pub pool: usize,
+    pub vault: usize,
+    pub admin: usize,
+    pub strategy: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ApproveStrategy {
+    type ArrangedAccountIndices = ApproveStrategyInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(ApproveStrategyInstructionAccountIndices {
+            pool: 0,
+            vault: 1,
+            admin: 2,
+            strategy: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ApproveStrategy {
+    fn account_names() -> &'static [&'static str] {
+        &["pool", "vault", "admin", "strategy"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +14,7 @@ pub struct ChangeAmpFactorInstructionAccounts {
     pub pool: solana_pubkey::Pubkey,
     pub vault: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ChangeAmpFactor {
@@ -21,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ChangeAmpFactor {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [pool, vault, admin, _remaining @ ..] = accounts else {
+        let [pool, vault, admin, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -29,6 +31,65 @@ impl carbon_core::deserialize::ArrangeAccounts for ChangeAmpFactor {
             pool: pool.pubkey,
             vault: vault.pubkey,
             admin: admin.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ChangeAmpFactorInstructionAccountMetas {
+    pub pool: solana_instruction::AccountMeta,
+    pub vault: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ChangeAmpFactor {
+    type ArrangedAccountMetas = ChangeAmpFactorInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [pool, vault, admin, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(ChangeAmpFactorInstructionAccountMetas {
+            pool: pool.clone(),
+            vault: vault.clone(),
+            admin: admin.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ChangeAmpFactorInstructionAccountIndices {
+    pub pool: usize,
+    pub vault: usize,
+    pub admin: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ChangeAmpFactor {
+    type ArrangedAccountIndices = ChangeAmpFactorInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(ChangeAmpFactorInstructionAccountIndices {
+            pool: 0,
+            vault: 1,
+            admin: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ChangeAmpFactor {
+    fn account_names() -> &'static [&'static str] {
+        &["pool", "vault", "admin"]
+    }
+}
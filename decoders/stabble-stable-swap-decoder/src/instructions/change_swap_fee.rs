@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +12,7 @@ pub struct ChangeSwapFee {
 pub struct ChangeSwapFeeInstructionAccounts {
     pub owner: solana_pubkey::Pubkey,
     pub pool: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ChangeSwapFee {
@@ -19,13 +21,68 @@ impl carbon_core::deserialize::ArrangeAccounts for ChangeSwapFee {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [owner, pool, _remaining @ ..] = accounts else {
+        let [owner, pool, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(ChangeSwapFeeInstructionAccounts {
             owner: owner.pubkey,
             pool: pool.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ChangeSwapFeeInstructionAccountMetas {
+    pub owner: solana_instruction::AccountMeta,
+    pub pool: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ChangeSwapFee {
+    type ArrangedAccountMetas = ChangeSwapFeeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [owner, pool, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(ChangeSwapFeeInstructionAccountMetas {
+            owner: owner.clone(),
+            pool: pool.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ChangeSwapFeeInstructionAccountIndices {
+    pub owner: usize,
+    pub pool: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ChangeSwapFee {
+    type ArrangedAccountIndices = ChangeSwapFeeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(ChangeSwapFeeInstructionAccountIndices {
+            owner: 0,
+            pool: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ChangeSwapFee {
+    fn account_names() -> &'static [&'static str] {
+        &["owner", "pool"]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -16,6 +17,7 @@ pub struct CreateStrategy {
 pub struct CreateStrategyInstructionAccounts {
     pub owner_only: solana_pubkey::Pubkey,
     pub strategy: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CreateStrategy {
@@ -24,13 +26,68 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateStrategy {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [owner_only, strategy, _remaining @ ..] = accounts else {
+        let [owner_only, strategy, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(CreateStrategyInstructionAccounts {
             owner_only: owner_only.pubkey,
             strategy: strategy.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CreateStrategyInstructionAccountMetas {
+    pub owner_only: solana_instruction::AccountMeta,
+    pub strategy: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CreateStrategy {
+    type ArrangedAccountMetas = CreateStrategyInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [owner_only, strategy, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(CreateStrategyInstructionAccountMetas {
+            owner_only: owner_only.clone(),
+            strategy: strategy.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CreateStrategyInstructionAccountIndices {
+    pub owner_only: usize,
+    pub strategy: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CreateStrategy {
+    type ArrangedAccountIndices = CreateStrategyInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(CreateStrategyInstructionAccountIndices {
+            owner_only: 0,
+            strategy: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CreateStrategy {
+    fn account_names() -> &'static [&'static str] {
+        &["owner_only", "strategy"]
+    }
+}
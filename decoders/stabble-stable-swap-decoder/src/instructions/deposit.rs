@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -19,6 +20,7 @@ pub struct DepositInstructionAccounts {
     pub vault_authority: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub token_program_2022: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Deposit {
@@ -27,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Deposit {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [user, user_pool_token, mint, pool, pool_authority, vault, vault_authority, token_program, token_program_2022, _remaining @ ..] =
+        let [user, user_pool_token, mint, pool, pool_authority, vault, vault_authority, token_program, token_program_2022, remaining @ ..] =
             accounts
         else {
             return None;
@@ -43,6 +45,101 @@ impl carbon_core::deserialize::ArrangeAccounts for Deposit {
             vault_authority: vault_authority.pubkey,
             token_program: token_program.pubkey,
             token_program_2022: token_program_2022.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DepositInstructionAccountMetas {
+    pub user: solana_instruction::AccountMeta,
+    pub user_pool_token: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub pool: solana_instruction::AccountMeta,
+    pub pool_authority: solana_instruction::AccountMeta,
+    pub vault: solana_instruction::AccountMeta,
+    pub vault_authority: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub token_program_2022: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Deposit {
+    type ArrangedAccountMetas = DepositInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [user, user_pool_token, mint, pool, pool_authority, vault, vault_authority, token_program, token_program_2022, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(DepositInstructionAccountMetas {
+            user: user.clone(),
+            user_pool_token: user_pool_token.clone(),
+            mint: mint.clone(),
+            pool: pool.clone(),
+            pool_authority: pool_authority.clone(),
+            vault: vault.clone(),
+            vault_authority: vault_authority.clone(),
+            token_program: token_program.clone(),
+            token_program_2022: token_program_2022.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DepositInstructionAccountIndices {
+    pub user: usize,
+    pub user_pool_token: usize,
+    pub mint: usize,
+    pub pool: usize,
+    pub pool_authority: usize,
+    pub vault: usize,
+    pub vault_authority: usize,
+    pub token_program: usize,
+    pub token_program_2022: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Deposit {
+    type ArrangedAccountIndices = DepositInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 9 {
+            return None;
+        }
+
+        Some(DepositInstructionAccountIndices {
+            user: 0,
+            user_pool_token: 1,
+            mint: 2,
+            pool: 3,
+            pool_authority: 4,
+            vault: 5,
+            vault_authority: 6,
+            token_program: 7,
+            token_program_2022: 8,
+            remaining_accounts: 9..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Deposit {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "user",
+            "user_pool_token",
+            "mint",
+            "pool",
+            "pool_authority",
+            "vault",
+            "vault_authority",
+            "token_program",
+            "token_program_2022",
+        ]
+    }
+}
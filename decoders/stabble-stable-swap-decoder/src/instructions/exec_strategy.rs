@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +13,7 @@ pub struct ExecStrategy {
 pub struct ExecStrategyInstructionAccounts {
     pub strategy: solana_pubkey::Pubkey,
     pub pool: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ExecStrategy {
@@ -20,13 +22,68 @@ impl carbon_core::deserialize::ArrangeAccounts for ExecStrategy {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [strategy, pool, _remaining @ ..] = accounts else {
+        let [strategy, pool, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(ExecStrategyInstructionAccounts {
             strategy: strategy.pubkey,
             pool: pool.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ExecStrategyInstructionAccountMetas {
+    pub strategy: solana_instruction::AccountMeta,
+    pub pool: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ExecStrategy {
+    type ArrangedAccountMetas = ExecStrategyInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [strategy, pool, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(ExecStrategyInstructionAccountMetas {
+            strategy: strategy.clone(),
+            pool: pool.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ExecStrategyInstructionAccountIndices {
+    pub strategy: usize,
+    pub pool: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ExecStrategy {
+    type ArrangedAccountIndices = ExecStrategyInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(ExecStrategyInstructionAccountIndices {
+            strategy: 0,
+            pool: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ExecStrategy {
+    fn account_names() -> &'static [&'static str] {
+        &["strategy", "pool"]
+    }
+}
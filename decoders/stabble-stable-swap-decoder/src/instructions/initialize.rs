@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -17,6 +18,7 @@ pub struct InitializeInstructionAccounts {
     pub pool_authority: solana_pubkey::Pubkey,
     pub withdraw_authority: solana_pubkey::Pubkey,
     pub vault: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Initialize {
@@ -25,7 +27,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Initialize {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [owner, mint, pool, pool_authority, withdraw_authority, vault, _remaining @ ..] =
+        let [owner, mint, pool, pool_authority, withdraw_authority, vault, remaining @ ..] =
             accounts
         else {
             return None;
@@ -38,6 +40,86 @@ impl carbon_core::deserialize::ArrangeAccounts for Initialize {
             pool_authority: pool_authority.pubkey,
             withdraw_authority: withdraw_authority.pubkey,
             vault: vault.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeInstructionAccountMetas {
+    pub owner: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub pool: solana_instruction::AccountMeta,
+    pub pool_authority: solana_instruction::AccountMeta,
+    pub withdraw_authority: solana_instruction::AccountMeta,
+    pub vault: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Initialize {
+    type ArrangedAccountMetas = InitializeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [owner, mint, pool, pool_authority, withdraw_authority, vault, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeInstructionAccountMetas {
+            owner: owner.clone(),
+            mint: mint.clone(),
+            pool: pool.clone(),
+            pool_authority: pool_authority.clone(),
+            withdraw_authority: withdraw_authority.clone(),
+            vault: vault.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeInstructionAccountIndices {
+    pub owner: usize,
+    pub mint: usize,
+    pub pool: usize,
+    pub pool_authority: usize,
+    pub withdraw_authority: usize,
+    pub vault: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Initialize {
+    type ArrangedAccountIndices = InitializeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(InitializeInstructionAccountIndices {
+            owner: 0,
+            mint: 1,
+            pool: 2,
+            pool_authority: 3,
+            withdraw_authority: 4,
+            vault: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Initialize {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "owner",
+            "mint",
+            "pool",
+            "pool_authority",
+            "withdraw_authority",
+            "vault",
+        ]
+    }
+}
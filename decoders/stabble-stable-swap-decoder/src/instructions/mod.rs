@@ -56,15 +56,25 @@ pub enum StableSwapInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for StableSwapDecoder {
     type InstructionType = StableSwapInstruction;
 
-    fn decode_instruction(
+    fn decoder_name(&self) -> &'static str {
+        "stable_swap"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[super::PROGRAM_ID]
+    }
+
+    fn try_decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
-    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+    ) -> carbon_core::error::CarbonResult<
+        Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>>,
+    > {
         if !instruction.program_id.eq(&PROGRAM_ID) {
-            return None;
+            return Ok(None);
         }
 
-        carbon_core::try_decode_instructions!(instruction,
+        Ok(carbon_core::try_decode_instructions!(instruction,
             StableSwapInstruction::AcceptOwner => accept_owner::AcceptOwner,
             StableSwapInstruction::ApproveStrategy => approve_strategy::ApproveStrategy,
             StableSwapInstruction::ChangeAmpFactor => change_amp_factor::ChangeAmpFactor,
@@ -84,6 +94,6 @@ impl carbon_core::instruction::InstructionDecoder<'_> for StableSwapDecoder {
             StableSwapInstruction::Withdraw => withdraw::Withdraw,
             StableSwapInstruction::PoolBalanceUpdatedEvent => pool_balance_updated_event::PoolBalanceUpdatedEvent,
             StableSwapInstruction::PoolUpdatedEvent => pool_updated_event::PoolUpdatedEvent,
-        )
+        ))
     }
 }
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -25,6 +26,7 @@ pub struct SwapV2InstructionAccounts {
     pub vault_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub token_2022_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SwapV2 {
@@ -33,7 +35,7 @@ impl carbon_core::deserialize::ArrangeAccounts for SwapV2 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [user, mint_in, mint_out, user_token_in, user_token_out, vault_token_in, vault_token_out, beneficiary_token_out, pool, withdraw_authority, vault, vault_authority, vault_program, token_program, token_2022_program, _remaining @ ..] =
+        let [user, mint_in, mint_out, user_token_in, user_token_out, vault_token_in, vault_token_out, beneficiary_token_out, pool, withdraw_authority, vault, vault_authority, vault_program, token_program, token_2022_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -55,6 +57,131 @@ impl carbon_core::deserialize::ArrangeAccounts for SwapV2 {
             vault_program: vault_program.pubkey,
             token_program: token_program.pubkey,
             token_2022_program: token_2022_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SwapV2InstructionAccountMetas {
+    pub user: solana_instruction::AccountMeta,
+    pub mint_in: solana_instruction::AccountMeta,
+    pub mint_out: solana_instruction::AccountMeta,
+    pub user_token_in: solana_instruction::AccountMeta,
+    pub user_token_out: solana_instruction::AccountMeta,
+    pub vault_token_in: solana_instruction::AccountMeta,
+    pub vault_token_out: solana_instruction::AccountMeta,
+    pub beneficiary_token_out: solana_instruction::AccountMeta,
+    pub pool: solana_instruction::AccountMeta,
+    pub withdraw_authority: solana_instruction::AccountMeta,
+    pub vault: solana_instruction::AccountMeta,
+    pub vault_authority: solana_instruction::AccountMeta,
+    pub vault_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub token_2022_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SwapV2 {
+    type ArrangedAccountMetas = SwapV2InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [user, mint_in, mint_out, user_token_in, user_token_out, vault_token_in, vault_token_out, beneficiary_token_out, pool, withdraw_authority, vault, vault_authority, vault_program, token_program, token_2022_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(SwapV2InstructionAccountMetas {
+            user: user.clone(),
+            mint_in: mint_in.clone(),
+            mint_out: mint_out.clone(),
+            user_token_in: user_token_in.clone(),
+            user_token_out: user_token_out.clone(),
+            vault_token_in: vault_token_in.clone(),
+            vault_token_out: vault_token_out.clone(),
+            beneficiary_token_out: beneficiary_token_out.clone(),
+            pool: pool.clone(),
+            withdraw_authority: withdraw_authority.clone(),
+            vault: vault.clone(),
+            vault_authority: vault_authority.clone(),
+            vault_program: vault_program.clone(),
+            token_program: token_program.clone(),
+            token_2022_program: token_2022_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SwapV2InstructionAccountIndices {
+    pub user: usize,
+    pub mint_in: usize,
+    pub mint_out: usize,
+    pub user_token_in: usize,
+    pub user_token_out: usize,
+    pub vault_token_in: usize,
+    pub vault_token_out: usize,
+    pub beneficiary_token_out: usize,
+    pub pool: usize,
+    pub withdraw_authority: usize,
+    pub vault: usize,
+    pub vault_authority: usize,
+    pub vault_program: usize,
+    pub token_program: usize,
+    pub token_2022_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SwapV2 {
+    type ArrangedAccountIndices = SwapV2InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 15 {
+            return None;
+        }
+
+        Some(SwapV2InstructionAccountIndices {
+            user: 0,
+            mint_in: 1,
+            mint_out: 2,
+            user_token_in: 3,
+            user_token_out: 4,
+            vault_token_in: 5,
+            vault_token_out: 6,
+            beneficiary_token_out: 7,
+            pool: 8,
+            withdraw_authority: 9,
+            vault: 10,
+            vault_authority: 11,
+            vault_program: 12,
+            token_program: 13,
+            token_2022_program: 14,
+            remaining_accounts: 15..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SwapV2 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "user",
+            "mint_in",
+            "mint_out",
+            "user_token_in",
+            "user_token_out",
+            "vault_token_in",
+            "vault_token_out",
+            "beneficiary_token_out",
+            "pool",
+            "withdraw_authority",
+            "vault",
+            "vault_authority",
+            "vault_program",
+            "token_program",
+            "token_2022_program",
+        ]
+    }
+}
@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -20,6 +21,7 @@ pub struct WithdrawInstructionAccounts {
     pub vault_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub token_program_2022: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Withdraw {
@@ -28,7 +30,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Withdraw {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [user, user_pool_token, mint, pool, withdraw_authority, vault, vault_authority, vault_program, token_program, token_program_2022, _remaining @ ..] =
+        let [user, user_pool_token, mint, pool, withdraw_authority, vault, vault_authority, vault_program, token_program, token_program_2022, remaining @ ..] =
             accounts
         else {
             return None;
@@ -45,6 +47,106 @@ impl carbon_core::deserialize::ArrangeAccounts for Withdraw {
             vault_program: vault_program.pubkey,
             token_program: token_program.pubkey,
             token_program_2022: token_program_2022.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct WithdrawInstructionAccountMetas {
+    pub user: solana_instruction::AccountMeta,
+    pub user_pool_token: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub pool: solana_instruction::AccountMeta,
+    pub withdraw_authority: solana_instruction::AccountMeta,
+    pub vault: solana_instruction::AccountMeta,
+    pub vault_authority: solana_instruction::AccountMeta,
+    pub vault_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub token_program_2022: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Withdraw {
+    type ArrangedAccountMetas = WithdrawInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [user, user_pool_token, mint, pool, withdraw_authority, vault, vault_authority, vault_program, token_program, token_program_2022, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(WithdrawInstructionAccountMetas {
+            user: user.clone(),
+            user_pool_token: user_pool_token.clone(),
+            mint: mint.clone(),
+            pool: pool.clone(),
+            withdraw_authority: withdraw_authority.clone(),
+            vault: vault.clone(),
+            vault_authority: vault_authority.clone(),
+            vault_program: vault_program.clone(),
+            token_program: token_program.clone(),
+            token_program_2022: token_program_2022.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct WithdrawInstructionAccountIndices {
+    pub user: usize,
+    pub user_pool_token: usize,
+    pub mint: usize,
+    pub pool: usize,
+    pub withdraw_authority: usize,
+    pub vault: usize,
+    pub vault_authority: usize,
+    pub vault_program: usize,
+    pub token_program: usize,
+    pub token_program_2022: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Withdraw {
+    type ArrangedAccountIndices = WithdrawInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 10 {
+            return None;
+        }
+
+        Some(WithdrawInstructionAccountIndices {
+            user: 0,
+            user_pool_token: 1,
+            mint: 2,
+            pool: 3,
+            withdraw_authority: 4,
+            vault: 5,
+            vault_authority: 6,
+            vault_program: 7,
+            token_program: 8,
+            token_program_2022: 9,
+            remaining_accounts: 10..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Withdraw {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "user",
+            "user_pool_token",
+            "mint",
+            "pool",
+            "withdraw_authority",
+            "vault",
+            "vault_authority",
+            "vault_program",
+            "token_program",
+            "token_program_2022",
+        ]
+    }
+}
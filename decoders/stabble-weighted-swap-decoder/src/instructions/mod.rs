@@ -48,15 +48,25 @@ pub enum WeightedSwapInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for WeightedSwapDecoder {
     type InstructionType = WeightedSwapInstruction;
 
-    fn decode_instruction(
+    fn decoder_name(&self) -> &'static str {
+        "weighted_swap"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[super::PROGRAM_ID]
+    }
+
+    fn try_decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
-    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+    ) -> carbon_core::error::CarbonResult<
+        Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>>,
+    > {
         if !instruction.program_id.eq(&PROGRAM_ID) {
-            return None;
+            return Ok(None);
         }
 
-        carbon_core::try_decode_instructions!(instruction,
+        Ok(carbon_core::try_decode_instructions!(instruction,
             WeightedSwapInstruction::AcceptOwner => accept_owner::AcceptOwner,
             WeightedSwapInstruction::ChangeMaxSupply => change_max_supply::ChangeMaxSupply,
             WeightedSwapInstruction::ChangeSwapFee => change_swap_fee::ChangeSwapFee,
@@ -72,6 +82,6 @@ impl carbon_core::instruction::InstructionDecoder<'_> for WeightedSwapDecoder {
             WeightedSwapInstruction::Withdraw => withdraw::Withdraw,
             WeightedSwapInstruction::PoolBalanceUpdatedEvent => pool_balance_updated_event::PoolBalanceUpdatedEvent,
             WeightedSwapInstruction::PoolUpdatedEvent => pool_updated_event::PoolUpdatedEvent,
-        )
+        ))
     }
 }
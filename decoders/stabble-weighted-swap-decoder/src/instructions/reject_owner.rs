@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -9,6 +10,7 @@ pub struct RejectOwner {}
 pub struct RejectOwnerInstructionAccounts {
     pub pending_owner: solana_pubkey::Pubkey,
     pub pool: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for RejectOwner {
@@ -17,13 +19,68 @@ impl carbon_core::deserialize::ArrangeAccounts for RejectOwner {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [pending_owner, pool, _remaining @ ..] = accounts else {
+        let [pending_owner, pool, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(RejectOwnerInstructionAccounts {
             pending_owner: pending_owner.pubkey,
             pool: pool.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct RejectOwnerInstructionAccountMetas {
+    pub pending_owner: solana_instruction::AccountMeta,
+    pub pool: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for RejectOwner {
+    type ArrangedAccountMetas = RejectOwnerInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [pending_owner, pool, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(RejectOwnerInstructionAccountMetas {
+            pending_owner: pending_owner.clone(),
+            pool: pool.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct RejectOwnerInstructionAccountIndices {
+    pub pending_owner: usize,
+    pub pool: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for RejectOwner {
+    type ArrangedAccountIndices = RejectOwnerInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(RejectOwnerInstructionAccountIndices {
+            pending_owner: 0,
+            pool: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for RejectOwner {
+    fn account_names() -> &'static [&'static str] {
+        &["pending_owner", "pool"]
+    }
+}
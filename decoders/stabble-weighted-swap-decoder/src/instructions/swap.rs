@@ -1,5 +1,6 @@
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -22,6 +23,7 @@ pub struct SwapInstructionAccounts {
     pub vault_authority: solana_pubkey::Pubkey,
     pub vault_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Swap {
@@ -30,7 +32,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Swap {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [user, user_token_in, user_token_out, vault_token_in, vault_token_out, beneficiary_token_out, pool, withdraw_authority, vault, vault_authority, vault_program, token_program, _remaining @ ..] =
+        let [user, user_token_in, user_token_out, vault_token_in, vault_token_out, beneficiary_token_out, pool, withdraw_authority, vault, vault_authority, vault_program, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -49,6 +51,116 @@ impl carbon_core::deserialize::ArrangeAccounts for Swap {
             vault_authority: vault_authority.pubkey,
             vault_program: vault_program.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SwapInstructionAccountMetas {
+    pub user: solana_instruction::AccountMeta,
+    pub user_token_in: solana_instruction::AccountMeta,
+    pub user_token_out: solana_instruction::AccountMeta,
+    pub vault_token_in: solana_instruction::AccountMeta,
+    pub vault_token_out: solana_instruction::AccountMeta,
+    pub beneficiary_token_out: solana_instruction::AccountMeta,
+    pub pool: solana_instruction::AccountMeta,
+    pub withdraw_authority: solana_instruction::AccountMeta,
+    pub vault: solana_instruction::AccountMeta,
+    pub vault_authority: solana_instruction::AccountMeta,
+    pub vault_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Swap {
+    type ArrangedAccountMetas = SwapInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [user, user_token_in, user_token_out, vault_token_in, vault_token_out, beneficiary_token_out, pool, withdraw_authority, vault, vault_authority, vault_program, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(SwapInstructionAccountMetas {
+            user: user.clone(),
+            user_token_in: user_token_in.clone(),
+            user_token_out: user_token_out.clone(),
+            vault_token_in: vault_token_in.clone(),
+            vault_token_out: vault_token_out.clone(),
+            beneficiary_token_out: beneficiary_token_out.clone(),
+            pool: pool.clone(),
+            withdraw_authority: withdraw_authority.clone(),
+            vault: vault.clone(),
+            vault_authority: vault_authority.clone(),
+            vault_program: vault_program.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SwapInstructionAccountIndices {
+    pub user: usize,
+    pub user_token_in: usize,
+    pub user_token_out: usize,
+    pub vault_token_in: usize,
+    pub vault_token_out: usize,
+    pub beneficiary_token_out: usize,
+    pub pool: usize,
+    pub withdraw_authority: usize,
+    pub vault: usize,
+    pub vault_authority: usize,
+    pub vault_program: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Swap {
+    type ArrangedAccountIndices = SwapInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 12 {
+            return None;
+        }
+
+        Some(SwapInstructionAccountIndices {
+            user: 0,
+            user_token_in: 1,
+            user_token_out: 2,
+            vault_token_in: 3,
+            vault_token_out: 4,
+            beneficiary_token_out: 5,
+            pool: 6,
+            withdraw_authority: 7,
+            vault: 8,
+            vault_authority: 9,
+            vault_program: 10,
+            token_program: 11,
+            remaining_accounts: 12..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Swap {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "user",
+            "user_token_in",
+            "user_token_out",
+            "vault_token_in",
+            "vault_token_out",
+            "beneficiary_token_out",
+            "pool",
+            "withdraw_authority",
+            "vault",
+            "vault_authority",
+            "vault_program",
+            "token_program",
+        ]
+    }
+}
@@ -1,7 +1,9 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -15,6 +17,7 @@ pub struct AuthorizeInstructionAccounts {
     pub stake: solana_pubkey::Pubkey,
     pub clock: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Authorize {
@@ -23,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Authorize {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [stake, clock, authority, _remaining @ ..] = accounts else {
+        let [stake, clock, authority, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -31,6 +34,65 @@ impl carbon_core::deserialize::ArrangeAccounts for Authorize {
             stake: stake.pubkey,
             clock: clock.pubkey,
             authority: authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct AuthorizeInstructionAccountMetas {
+    pub stake: solana_instruction::AccountMeta,
+    pub clock: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Authorize {
+    type ArrangedAccountMetas = AuthorizeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [stake, clock, authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(AuthorizeInstructionAccountMetas {
+            stake: stake.clone(),
+            clock: clock.clone(),
+            authority: authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct AuthorizeInstructionAccountIndices {
+    pub stake: usize,
+    pub clock: usize,
+    pub authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Authorize {
+    type ArrangedAccountIndices = AuthorizeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(AuthorizeInstructionAccountIndices {
+            stake: 0,
+            clock: 1,
+            authority: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Authorize {
+    fn account_names() -> &'static [&'static str] {
+        &["stake", "clock", "authority"]
+    }
+}
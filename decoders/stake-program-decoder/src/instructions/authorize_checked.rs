@@ -1,7 +1,9 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -15,6 +17,7 @@ pub struct AuthorizeCheckedInstructionAccounts {
     pub clock: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
     pub new_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for AuthorizeChecked {
@@ -23,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for AuthorizeChecked {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [stake, clock, authority, new_authority, _remaining @ ..] = accounts else {
+        let [stake, clock, authority, new_authority, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -32,6 +35,69 @@ impl carbon_core::deserialize::ArrangeAccounts for AuthorizeChecked {
             clock: clock.pubkey,
             authority: authority.pubkey,
             new_authority: new_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct AuthorizeCheckedInstructionAccountMetas {
+    pub stake: solana_instruction::AccountMeta,
+    pub clock: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub new_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for AuthorizeChecked {
+    type ArrangedAccountMetas = AuthorizeCheckedInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [stake, clock, authority, new_authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(AuthorizeCheckedInstructionAccountMetas {
+            stake: stake.clone(),
+            clock: clock.clone(),
+            authority: authority.clone(),
+            new_authority: new_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct AuthorizeCheckedInstructionAccountIndices {
+    pub stake: usize,
+    pub clock: usize,
+    pub authority: usize,
+    pub new_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for AuthorizeChecked {
+    type ArrangedAccountIndices = AuthorizeCheckedInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(AuthorizeCheckedInstructionAccountIndices {
+            stake: 0,
+            clock: 1,
+            authority: 2,
+            new_authority: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for AuthorizeChecked {
+    fn account_names() -> &'static [&'static str] {
+        &["stake", "clock", "authority", "new_authority"]
+    }
+}
@@ -1,8 +1,10 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use alloc::string::String;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -18,6 +20,7 @@ pub struct AuthorizeCheckedWithSeedInstructionAccounts {
     pub authority_base: solana_pubkey::Pubkey,
     pub clock: solana_pubkey::Pubkey,
     pub new_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for AuthorizeCheckedWithSeed {
@@ -26,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for AuthorizeCheckedWithSeed {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [stake, authority_base, clock, new_authority, _remaining @ ..] = accounts else {
+        let [stake, authority_base, clock, new_authority, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -35,6 +38,69 @@ impl carbon_core::deserialize::ArrangeAccounts for AuthorizeCheckedWithSeed {
             authority_base: authority_base.pubkey,
             clock: clock.pubkey,
             new_authority: new_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct AuthorizeCheckedWithSeedInstructionAccountMetas {
+    pub stake: solana_instruction::AccountMeta,
+    pub authority_base: solana_instruction::AccountMeta,
+    pub clock: solana_instruction::AccountMeta,
+    pub new_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for AuthorizeCheckedWithSeed {
+    type ArrangedAccountMetas = AuthorizeCheckedWithSeedInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [stake, authority_base, clock, new_authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(AuthorizeCheckedWithSeedInstructionAccountMetas {
+            stake: stake.clone(),
+            authority_base: authority_base.clone(),
+            clock: clock.clone(),
+            new_authority: new_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct AuthorizeCheckedWithSeedInstructionAccountIndices {
+    pub stake: usize,
+    pub authority_base: usize,
+    pub clock: usize,
+    pub new_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for AuthorizeCheckedWithSeed {
+    type ArrangedAccountIndices = AuthorizeCheckedWithSeedInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(AuthorizeCheckedWithSeedInstructionAccountIndices {
+            stake: 0,
+            authority_base: 1,
+            clock: 2,
+            new_authority: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for AuthorizeCheckedWithSeed {
+    fn account_names() -> &'static [&'static str] {
+        &["stake", "authority_base", "clock", "new_authority"]
+    }
+}
@@ -1,8 +1,10 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use alloc::string::String;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -18,6 +20,7 @@ pub struct AuthorizeWithSeedInstructionAccounts {
     pub stake: solana_pubkey::Pubkey,
     pub authority_base: solana_pubkey::Pubkey,
     pub clock: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for AuthorizeWithSeed {
@@ -26,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for AuthorizeWithSeed {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [stake, authority_base, clock, _remaining @ ..] = accounts else {
+        let [stake, authority_base, clock, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -34,6 +37,65 @@ impl carbon_core::deserialize::ArrangeAccounts for AuthorizeWithSeed {
             stake: stake.pubkey,
             authority_base: authority_base.pubkey,
             clock: clock.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct AuthorizeWithSeedInstructionAccountMetas {
+    pub stake: solana_instruction::AccountMeta,
+    pub authority_base: solana_instruction::AccountMeta,
+    pub clock: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for AuthorizeWithSeed {
+    type ArrangedAccountMetas = AuthorizeWithSeedInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [stake, authority_base, clock, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(AuthorizeWithSeedInstructionAccountMetas {
+            stake: stake.clone(),
+            authority_base: authority_base.clone(),
+            clock: clock.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct AuthorizeWithSeedInstructionAccountIndices {
+    pub stake: usize,
+    pub authority_base: usize,
+    pub clock: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for AuthorizeWithSeed {
+    type ArrangedAccountIndices = AuthorizeWithSeedInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(AuthorizeWithSeedInstructionAccountIndices {
+            stake: 0,
+            authority_base: 1,
+            clock: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for AuthorizeWithSeed {
+    fn account_names() -> &'static [&'static str] {
+        &["stake", "authority_base", "clock"]
+    }
+}
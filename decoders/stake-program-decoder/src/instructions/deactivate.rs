@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -10,6 +12,7 @@ pub struct DeactivateInstructionAccounts {
     pub stake: solana_pubkey::Pubkey,
     pub clock: solana_pubkey::Pubkey,
     pub stake_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Deactivate {
@@ -18,7 +21,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Deactivate {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [stake, clock, stake_authority, _remaining @ ..] = accounts else {
+        let [stake, clock, stake_authority, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -26,6 +29,65 @@ impl carbon_core::deserialize::ArrangeAccounts for Deactivate {
             stake: stake.pubkey,
             clock: clock.pubkey,
             stake_authority: stake_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DeactivateInstructionAccountMetas {
+    pub stake: solana_instruction::AccountMeta,
+    pub clock: solana_instruction::AccountMeta,
+    pub stake_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Deactivate {
+    type ArrangedAccountMetas = DeactivateInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [stake, clock, stake_authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(DeactivateInstructionAccountMetas {
+            stake: stake.clone(),
+            clock: clock.clone(),
+            stake_authority: stake_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DeactivateInstructionAccountIndices {
+    pub stake: usize,
+    pub clock: usize,
+    pub stake_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Deactivate {
+    type ArrangedAccountIndices = DeactivateInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(DeactivateInstructionAccountIndices {
+            stake: 0,
+            clock: 1,
+            stake_authority: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Deactivate {
+    fn account_names() -> &'static [&'static str] {
+        &["stake", "clock", "stake_authority"]
+    }
+}
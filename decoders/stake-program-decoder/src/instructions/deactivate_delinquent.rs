@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -10,6 +12,7 @@ pub struct DeactivateDelinquentInstructionAccounts {
     pub stake: solana_pubkey::Pubkey,
     pub vote: solana_pubkey::Pubkey,
     pub reference_vote: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for DeactivateDelinquent {
@@ -18,7 +21,7 @@ impl carbon_core::deserialize::ArrangeAccounts for DeactivateDelinquent {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [stake, vote, reference_vote, _remaining @ ..] = accounts else {
+        let [stake, vote, reference_vote, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -26,6 +29,65 @@ impl carbon_core::deserialize::ArrangeAccounts for DeactivateDelinquent {
             stake: stake.pubkey,
             vote: vote.pubkey,
             reference_vote: reference_vote.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DeactivateDelinquentInstructionAccountMetas {
+    pub stake: solana_instruction::AccountMeta,
+    pub vote: solana_instruction::AccountMeta,
+    pub reference_vote: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for DeactivateDelinquent {
+    type ArrangedAccountMetas = DeactivateDelinquentInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [stake, vote, reference_vote, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(DeactivateDelinquentInstructionAccountMetas {
+            stake: stake.clone(),
+            vote: vote.clone(),
+            reference_vote: reference_vote.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DeactivateDelinquentInstructionAccountIndices {
+    pub stake: usize,
+    pub vote: usize,
+    pub reference_vote: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for DeactivateDelinquent {
+    type ArrangedAccountIndices = DeactivateDelinquentInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(DeactivateDelinquentInstructionAccountIndices {
+            stake: 0,
+            vote: 1,
+            reference_vote: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for DeactivateDelinquent {
+    fn account_names() -> &'static [&'static str] {
+        &["stake", "vote", "reference_vote"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +15,7 @@ pub struct DelegateStakeInstructionAccounts {
     pub stake_history: solana_pubkey::Pubkey,
     pub stake_config: solana_pubkey::Pubkey,
     pub stake_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for DelegateStake {
@@ -21,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for DelegateStake {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [stake, vote, clock, stake_history, stake_config, stake_authority, _remaining @ ..] =
+        let [stake, vote, clock, stake_history, stake_config, stake_authority, remaining @ ..] =
             accounts
         else {
             return None;
@@ -34,6 +37,86 @@ impl carbon_core::deserialize::ArrangeAccounts for DelegateStake {
             stake_history: stake_history.pubkey,
             stake_config: stake_config.pubkey,
             stake_authority: stake_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DelegateStakeInstructionAccountMetas {
+    pub stake: solana_instruction::AccountMeta,
+    pub vote: solana_instruction::AccountMeta,
+    pub clock: solana_instruction::AccountMeta,
+    pub stake_history: solana_instruction::AccountMeta,
+    pub stake_config: solana_instruction::AccountMeta,
+    pub stake_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for DelegateStake {
+    type ArrangedAccountMetas = DelegateStakeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [stake, vote, clock, stake_history, stake_config, stake_authority, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(DelegateStakeInstructionAccountMetas {
+            stake: stake.clone(),
+            vote: vote.clone(),
+            clock: clock.clone(),
+            stake_history: stake_history.clone(),
+            stake_config: stake_config.clone(),
+            stake_authority: stake_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DelegateStakeInstructionAccountIndices {
+    pub stake: usize,
+    pub vote: usize,
+    pub clock: usize,
+    pub stake_history: usize,
+    pub stake_config: usize,
+    pub stake_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for DelegateStake {
+    type ArrangedAccountIndices = DelegateStakeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(DelegateStakeInstructionAccountIndices {
+            stake: 0,
+            vote: 1,
+            clock: 2,
+            stake_history: 3,
+            stake_config: 4,
+            stake_authority: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for DelegateStake {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "stake",
+            "vote",
+            "clock",
+            "stake_history",
+            "stake_config",
+            "stake_authority",
+        ]
+    }
+}
@@ -1,19 +1,63 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
 #[carbon(discriminator = "0xc541074997698569")]
 pub struct GetMinimumDelegation {}
 
-pub struct GetMinimumDelegationInstructionAccounts {}
+pub struct GetMinimumDelegationInstructionAccounts {
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
 
 impl carbon_core::deserialize::ArrangeAccounts for GetMinimumDelegation {
     type ArrangedAccounts = GetMinimumDelegationInstructionAccounts;
 
     fn arrange_accounts(
-        _accounts: &[solana_instruction::AccountMeta],
+        accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        Some(GetMinimumDelegationInstructionAccounts {})
+        Some(GetMinimumDelegationInstructionAccounts {
+            remaining_accounts: accounts.to_vec(),
+        })
+    }
+}
+
+pub struct GetMinimumDelegationInstructionAccountMetas {
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for GetMinimumDelegation {
+    type ArrangedAccountMetas = GetMinimumDelegationInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        Some(GetMinimumDelegationInstructionAccountMetas {
+            remaining_accounts: accounts.to_vec(),
+        })
+    }
+}
+
+pub struct GetMinimumDelegationInstructionAccountIndices {
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for GetMinimumDelegation {
+    type ArrangedAccountIndices = GetMinimumDelegationInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        Some(GetMinimumDelegationInstructionAccountIndices {
+            remaining_accounts: 0..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for GetMinimumDelegation {
+    fn account_names() -> &'static [&'static str] {
+        &[]
     }
 }
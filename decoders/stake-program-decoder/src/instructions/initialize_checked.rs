@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +13,7 @@ pub struct InitializeCheckedInstructionAccounts {
     pub rent: solana_pubkey::Pubkey,
     pub stake_authority: solana_pubkey::Pubkey,
     pub withdraw_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeChecked {
@@ -19,7 +22,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeChecked {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [stake, rent, stake_authority, withdraw_authority, _remaining @ ..] = accounts else {
+        let [stake, rent, stake_authority, withdraw_authority, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +31,69 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeChecked {
             rent: rent.pubkey,
             stake_authority: stake_authority.pubkey,
             withdraw_authority: withdraw_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeCheckedInstructionAccountMetas {
+    pub stake: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub stake_authority: solana_instruction::AccountMeta,
+    pub withdraw_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeChecked {
+    type ArrangedAccountMetas = InitializeCheckedInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [stake, rent, stake_authority, withdraw_authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(InitializeCheckedInstructionAccountMetas {
+            stake: stake.clone(),
+            rent: rent.clone(),
+            stake_authority: stake_authority.clone(),
+            withdraw_authority: withdraw_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeCheckedInstructionAccountIndices {
+    pub stake: usize,
+    pub rent: usize,
+    pub stake_authority: usize,
+    pub withdraw_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeChecked {
+    type ArrangedAccountIndices = InitializeCheckedInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(InitializeCheckedInstructionAccountIndices {
+            stake: 0,
+            rent: 1,
+            stake_authority: 2,
+            withdraw_authority: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeChecked {
+    fn account_names() -> &'static [&'static str] {
+        &["stake", "rent", "stake_authority", "withdraw_authority"]
+    }
+}
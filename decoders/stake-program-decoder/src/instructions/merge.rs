@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +14,7 @@ pub struct MergeInstructionAccounts {
     pub clock: solana_pubkey::Pubkey,
     pub stake_history: solana_pubkey::Pubkey,
     pub stake_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Merge {
@@ -20,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Merge {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [to, from, clock, stake_history, stake_authority, _remaining @ ..] = accounts else {
+        let [to, from, clock, stake_history, stake_authority, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -30,6 +33,73 @@ impl carbon_core::deserialize::ArrangeAccounts for Merge {
             clock: clock.pubkey,
             stake_history: stake_history.pubkey,
             stake_authority: stake_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct MergeInstructionAccountMetas {
+    pub to: solana_instruction::AccountMeta,
+    pub from: solana_instruction::AccountMeta,
+    pub clock: solana_instruction::AccountMeta,
+    pub stake_history: solana_instruction::AccountMeta,
+    pub stake_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Merge {
+    type ArrangedAccountMetas = MergeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [to, from, clock, stake_history, stake_authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(MergeInstructionAccountMetas {
+            to: to.clone(),
+            from: from.clone(),
+            clock: clock.clone(),
+            stake_history: stake_history.clone(),
+            stake_authority: stake_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct MergeInstructionAccountIndices {
+    pub to: usize,
+    pub from: usize,
+    pub clock: usize,
+    pub stake_history: usize,
+    pub stake_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Merge {
+    type ArrangedAccountIndices = MergeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(MergeInstructionAccountIndices {
+            to: 0,
+            from: 1,
+            clock: 2,
+            stake_history: 3,
+            stake_authority: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Merge {
+    fn account_names() -> &'static [&'static str] {
+        &["to", "from", "clock", "stake_history", "stake_authority"]
+    }
+}
@@ -48,15 +48,25 @@ pub enum StakeProgramInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for StakeProgramDecoder {
     type InstructionType = StakeProgramInstruction;
 
-    fn decode_instruction(
+    fn decoder_name(&self) -> &'static str {
+        "stake_program"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[super::PROGRAM_ID]
+    }
+
+    fn try_decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
-    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+    ) -> carbon_core::error::CarbonResult<
+        Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>>,
+    > {
         if !instruction.program_id.eq(&PROGRAM_ID) {
-            return None;
+            return Ok(None);
         }
 
-        carbon_core::try_decode_instructions!(instruction,
+        Ok(carbon_core::try_decode_instructions!(instruction,
             StakeProgramInstruction::Initialize => initialize::Initialize,
             StakeProgramInstruction::Authorize => authorize::Authorize,
             StakeProgramInstruction::DelegateStake => delegate_stake::DelegateStake,
@@ -72,6 +82,6 @@ impl carbon_core::instruction::InstructionDecoder<'_> for StakeProgramDecoder {
             StakeProgramInstruction::SetLockupChecked => set_lockup_checked::SetLockupChecked,
             StakeProgramInstruction::GetMinimumDelegation => get_minimum_delegation::GetMinimumDelegation,
             StakeProgramInstruction::DeactivateDelinquent => deactivate_delinquent::DeactivateDelinquent,
-        )
+        ))
     }
 }
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +15,7 @@ pub struct SetLockup {
 pub struct SetLockupInstructionAccounts {
     pub stake: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SetLockup {
@@ -21,13 +24,68 @@ impl carbon_core::deserialize::ArrangeAccounts for SetLockup {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [stake, authority, _remaining @ ..] = accounts else {
+        let [stake, authority, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(SetLockupInstructionAccounts {
             stake: stake.pubkey,
             authority: authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SetLockupInstructionAccountMetas {
+    pub stake: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SetLockup {
+    type ArrangedAccountMetas = SetLockupInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [stake, authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(SetLockupInstructionAccountMetas {
+            stake: stake.clone(),
+            authority: authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SetLockupInstructionAccountIndices {
+    pub stake: usize,
+    pub authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SetLockup {
+    type ArrangedAccountIndices = SetLockupInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(SetLockupInstructionAccountIndices {
+            stake: 0,
+            authority: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SetLockup {
+    fn account_names() -> &'static [&'static str] {
+        &["stake", "authority"]
+    }
+}
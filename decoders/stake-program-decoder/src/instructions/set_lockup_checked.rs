@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +14,7 @@ pub struct SetLockupChecked {
 pub struct SetLockupCheckedInstructionAccounts {
     pub stake: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SetLockupChecked {
@@ -20,13 +23,68 @@ impl carbon_core::deserialize::ArrangeAccounts for SetLockupChecked {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [stake, authority, _remaining @ ..] = accounts else {
+        let [stake, authority, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(SetLockupCheckedInstructionAccounts {
             stake: stake.pubkey,
             authority: authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SetLockupCheckedInstructionAccountMetas {
+    pub stake: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SetLockupChecked {
+    type ArrangedAccountMetas = SetLockupCheckedInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [stake, authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(SetLockupCheckedInstructionAccountMetas {
+            stake: stake.clone(),
+            authority: authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SetLockupCheckedInstructionAccountIndices {
+    pub stake: usize,
+    pub authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SetLockupChecked {
+    type ArrangedAccountIndices = SetLockupCheckedInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(SetLockupCheckedInstructionAccountIndices {
+            stake: 0,
+            authority: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SetLockupChecked {
+    fn account_names() -> &'static [&'static str] {
+        &["stake", "authority"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +14,7 @@ pub struct SplitInstructionAccounts {
     pub from: solana_pubkey::Pubkey,
     pub to: solana_pubkey::Pubkey,
     pub stake_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Split {
@@ -20,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Split {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [from, to, stake_authority, _remaining @ ..] = accounts else {
+        let [from, to, stake_authority, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +31,65 @@ impl carbon_core::deserialize::ArrangeAccounts for Split {
             from: from.pubkey,
             to: to.pubkey,
             stake_authority: stake_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SplitInstructionAccountMetas {
+    pub from: solana_instruction::AccountMeta,
+    pub to: solana_instruction::AccountMeta,
+    pub stake_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Split {
+    type ArrangedAccountMetas = SplitInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [from, to, stake_authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(SplitInstructionAccountMetas {
+            from: from.clone(),
+            to: to.clone(),
+            stake_authority: stake_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SplitInstructionAccountIndices {
+    pub from: usize,
+    pub to: usize,
+    pub stake_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Split {
+    type ArrangedAccountIndices = SplitInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(SplitInstructionAccountIndices {
+            from: 0,
+            to: 1,
+            stake_authority: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Split {
+    fn account_names() -> &'static [&'static str] {
+        &["from", "to", "stake_authority"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +16,7 @@ pub struct WithdrawInstructionAccounts {
     pub clock: solana_pubkey::Pubkey,
     pub stake_history: solana_pubkey::Pubkey,
     pub withdraw_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Withdraw {
@@ -22,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Withdraw {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [from, to, clock, stake_history, withdraw_authority, _remaining @ ..] = accounts else {
+        let [from, to, clock, stake_history, withdraw_authority, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -32,6 +35,73 @@ impl carbon_core::deserialize::ArrangeAccounts for Withdraw {
             clock: clock.pubkey,
             stake_history: stake_history.pubkey,
             withdraw_authority: withdraw_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct WithdrawInstructionAccountMetas {
+    pub from: solana_instruction::AccountMeta,
+    pub to: solana_instruction::AccountMeta,
+    pub clock: solana_instruction::AccountMeta,
+    pub stake_history: solana_instruction::AccountMeta,
+    pub withdraw_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Withdraw {
+    type ArrangedAccountMetas = WithdrawInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [from, to, clock, stake_history, withdraw_authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(WithdrawInstructionAccountMetas {
+            from: from.clone(),
+            to: to.clone(),
+            clock: clock.clone(),
+            stake_history: stake_history.clone(),
+            withdraw_authority: withdraw_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct WithdrawInstructionAccountIndices {
+    pub from: usize,
+    pub to: usize,
+    pub clock: usize,
+    pub stake_history: usize,
+    pub withdraw_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Withdraw {
+    type ArrangedAccountIndices = WithdrawInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(WithdrawInstructionAccountIndices {
+            from: 0,
+            to: 1,
+            clock: 2,
+            stake_history: 3,
+            withdraw_authority: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Withdraw {
+    fn account_names() -> &'static [&'static str] {
+        &["from", "to", "clock", "stake_history", "withdraw_authority"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -10,6 +12,7 @@ pub struct AdvanceNonceAccountInstructionAccounts {
     pub nonce_account: solana_pubkey::Pubkey,
     pub recent_blockhashes_sysvar: solana_pubkey::Pubkey,
     pub nonce_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for AdvanceNonceAccount {
@@ -18,7 +21,7 @@ impl carbon_core::deserialize::ArrangeAccounts for AdvanceNonceAccount {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [nonce_account, recent_blockhashes_sysvar, nonce_authority, _remaining @ ..] = accounts
+        let [nonce_account, recent_blockhashes_sysvar, nonce_authority, remaining @ ..] = accounts
         else {
             return None;
         };
@@ -27,6 +30,70 @@ impl carbon_core::deserialize::ArrangeAccounts for AdvanceNonceAccount {
             nonce_account: nonce_account.pubkey,
             recent_blockhashes_sysvar: recent_blockhashes_sysvar.pubkey,
             nonce_authority: nonce_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct AdvanceNonceAccountInstructionAccountMetas {
+    pub nonce_account: solana_instruction::AccountMeta,
+    pub recent_blockhashes_sysvar: solana_instruction::AccountMeta,
+    pub nonce_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for AdvanceNonceAccount {
+    type ArrangedAccountMetas = AdvanceNonceAccountInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [nonce_account, recent_blockhashes_sysvar, nonce_authority, remaining @ ..] = accounts
+        else {
+            return None;
+        };
+
+        Some(AdvanceNonceAccountInstructionAccountMetas {
+            nonce_account: nonce_account.clone(),
+            recent_blockhashes_sysvar: recent_blockhashes_sysvar.clone(),
+            nonce_authority: nonce_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct AdvanceNonceAccountInstructionAccountIndices {
+    pub nonce_account: usize,
+    pub recent_blockhashes_sysvar: usize,
+    pub nonce_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for AdvanceNonceAccount {
+    type ArrangedAccountIndices = AdvanceNonceAccountInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(AdvanceNonceAccountInstructionAccountIndices {
+            nonce_account: 0,
+            recent_blockhashes_sysvar: 1,
+            nonce_authority: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for AdvanceNonceAccount {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "nonce_account",
+            "recent_blockhashes_sysvar",
+            "nonce_authority",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -10,6 +12,7 @@ pub struct Allocate {
 
 pub struct AllocateInstructionAccounts {
     pub new_account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Allocate {
@@ -18,12 +21,63 @@ impl carbon_core::deserialize::ArrangeAccounts for Allocate {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [new_account, _remaining @ ..] = accounts else {
+        let [new_account, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(AllocateInstructionAccounts {
             new_account: new_account.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct AllocateInstructionAccountMetas {
+    pub new_account: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Allocate {
+    type ArrangedAccountMetas = AllocateInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [new_account, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(AllocateInstructionAccountMetas {
+            new_account: new_account.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct AllocateInstructionAccountIndices {
+    pub new_account: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Allocate {
+    type ArrangedAccountIndices = AllocateInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.is_empty() {
+            return None;
+        }
+
+        Some(AllocateInstructionAccountIndices {
+            new_account: 0,
+            remaining_accounts: 1..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Allocate {
+    fn account_names() -> &'static [&'static str] {
+        &["new_account"]
+    }
+}
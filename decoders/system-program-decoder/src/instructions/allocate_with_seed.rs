@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, deserialize::U64PrefixString, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
 #[carbon(discriminator = "0x09000000")]
 pub struct AllocateWithSeed {
@@ -12,6 +14,7 @@ pub struct AllocateWithSeed {
 pub struct AllocateWithSeedInstructionAccounts {
     pub new_account: solana_pubkey::Pubkey,
     pub base_account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for AllocateWithSeed {
@@ -20,13 +23,68 @@ impl carbon_core::deserialize::ArrangeAccounts for AllocateWithSeed {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [new_account, base_account, _remaining @ ..] = accounts else {
+        let [new_account, base_account, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(AllocateWithSeedInstructionAccounts {
             new_account: new_account.pubkey,
             base_account: base_account.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct AllocateWithSeedInstructionAccountMetas {
+    pub new_account: solana_instruction::AccountMeta,
+    pub base_account: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for AllocateWithSeed {
+    type ArrangedAccountMetas = AllocateWithSeedInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [new_account, base_account, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(AllocateWithSeedInstructionAccountMetas {
+            new_account: new_account.clone(),
+            base_account: base_account.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct AllocateWithSeedInstructionAccountIndices {
+    pub new_account: usize,
+    pub base_account: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for AllocateWithSeed {
+    type ArrangedAccountIndices = AllocateWithSeedInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(AllocateWithSeedInstructionAccountIndices {
+            new_account: 0,
+            base_account: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for AllocateWithSeed {
+    fn account_names() -> &'static [&'static str] {
+        &["new_account", "base_account"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, deserialize::U64PrefixString, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
 #[carbon(discriminator = "0x0a000000")]
 pub struct AssignWithSeed {
@@ -11,6 +13,7 @@ pub struct AssignWithSeed {
 pub struct AssignWithSeedInstructionAccounts {
     pub account: solana_pubkey::Pubkey,
     pub base_account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for AssignWithSeed {
@@ -19,13 +22,68 @@ impl carbon_core::deserialize::ArrangeAccounts for AssignWithSeed {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [account, base_account, _remaining @ ..] = accounts else {
+        let [account, base_account, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(AssignWithSeedInstructionAccounts {
             account: account.pubkey,
             base_account: base_account.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct AssignWithSeedInstructionAccountMetas {
+    pub account: solana_instruction::AccountMeta,
+    pub base_account: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for AssignWithSeed {
+    type ArrangedAccountMetas = AssignWithSeedInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [account, base_account, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(AssignWithSeedInstructionAccountMetas {
+            account: account.clone(),
+            base_account: base_account.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct AssignWithSeedInstructionAccountIndices {
+    pub account: usize,
+    pub base_account: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for AssignWithSeed {
+    type ArrangedAccountIndices = AssignWithSeedInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(AssignWithSeedInstructionAccountIndices {
+            account: 0,
+            base_account: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for AssignWithSeed {
+    fn account_names() -> &'static [&'static str] {
+        &["account", "base_account"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +13,7 @@ pub struct AuthorizeNonceAccount {
 pub struct AuthorizeNonceAccountInstructionAccounts {
     pub nonce_account: solana_pubkey::Pubkey,
     pub nonce_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for AuthorizeNonceAccount {
@@ -19,13 +22,68 @@ impl carbon_core::deserialize::ArrangeAccounts for AuthorizeNonceAccount {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [nonce_account, nonce_authority, _remaining @ ..] = accounts else {
+        let [nonce_account, nonce_authority, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(AuthorizeNonceAccountInstructionAccounts {
             nonce_account: nonce_account.pubkey,
             nonce_authority: nonce_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct AuthorizeNonceAccountInstructionAccountMetas {
+    pub nonce_account: solana_instruction::AccountMeta,
+    pub nonce_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for AuthorizeNonceAccount {
+    type ArrangedAccountMetas = AuthorizeNonceAccountInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [nonce_account, nonce_authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(AuthorizeNonceAccountInstructionAccountMetas {
+            nonce_account: nonce_account.clone(),
+            nonce_authority: nonce_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct AuthorizeNonceAccountInstructionAccountIndices {
+    pub nonce_account: usize,
+    pub nonce_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for AuthorizeNonceAccount {
+    type ArrangedAccountIndices = AuthorizeNonceAccountInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(AuthorizeNonceAccountInstructionAccountIndices {
+            nonce_account: 0,
+            nonce_authority: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for AuthorizeNonceAccount {
+    fn account_names() -> &'static [&'static str] {
+        &["nonce_account", "nonce_authority"]
+    }
+}
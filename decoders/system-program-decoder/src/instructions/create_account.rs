@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +15,7 @@ pub struct CreateAccount {
 pub struct CreateAccountInstructionAccounts {
     pub payer: solana_pubkey::Pubkey,
     pub new_account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CreateAccount {
@@ -21,13 +24,68 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateAccount {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [payer, new_account, _remaining @ ..] = accounts else {
+        let [payer, new_account, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(CreateAccountInstructionAccounts {
             payer: payer.pubkey,
             new_account: new_account.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CreateAccountInstructionAccountMetas {
+    pub payer: solana_instruction::AccountMeta,
+    pub new_account: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CreateAccount {
+    type ArrangedAccountMetas = CreateAccountInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [payer, new_account, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(CreateAccountInstructionAccountMetas {
+            payer: payer.clone(),
+            new_account: new_account.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CreateAccountInstructionAccountIndices {
+    pub payer: usize,
+    pub new_account: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CreateAccount {
+    type ArrangedAccountIndices = CreateAccountInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(CreateAccountInstructionAccountIndices {
+            payer: 0,
+            new_account: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CreateAccount {
+    fn account_names() -> &'static [&'static str] {
+        &["payer", "new_account"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, deserialize::U64PrefixString, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
 #[carbon(discriminator = "0x03000000")]
 pub struct CreateAccountWithSeed {
@@ -15,6 +17,7 @@ pub struct CreateAccountWithSeedInstructionAccounts {
     pub payer: solana_pubkey::Pubkey,
     pub new_account: solana_pubkey::Pubkey,
     pub base_account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CreateAccountWithSeed {
@@ -23,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateAccountWithSeed {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [payer, new_account, _remaining @ ..] = accounts else {
+        let [payer, new_account, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -31,6 +34,67 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateAccountWithSeed {
             payer: payer.pubkey,
             new_account: new_account.pubkey,
             base_account: accounts.get(2).unwrap_or(payer).pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+#[derive(Debug, PartialEq)]
+pub struct CreateAccountWithSeedInstructionAccountMetas {
+    pub payer: solana_instruction::AccountMeta,
+    pub new_account: solana_instruction::AccountMeta,
+    pub base_account: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CreateAccountWithSeed {
+    type ArrangedAccountMetas = CreateAccountWithSeedInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [payer, new_account, base_account, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(CreateAccountWithSeedInstructionAccountMetas {
+            payer: payer.clone(),
+            new_account: new_account.clone(),
+            base_account: base_account.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct CreateAccountWithSeedInstructionAccountIndices {
+    pub payer: usize,
+    pub new_account: usize,
+    pub base_account: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CreateAccountWithSeed {
+    type ArrangedAccountIndices = CreateAccountWithSeedInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(CreateAccountWithSeedInstructionAccountIndices {
+            payer: 0,
+            new_account: 1,
+            base_account: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CreateAccountWithSeed {
+    fn account_names() -> &'static [&'static str] {
+        &["payer", "new_account", "base_account"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +14,7 @@ pub struct InitializeNonceAccountInstructionAccounts {
     pub nonce_account: solana_pubkey::Pubkey,
     pub recent_blockhashes_sysvar: solana_pubkey::Pubkey,
     pub rent_sysvar: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeNonceAccount {
@@ -20,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeNonceAccount {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [nonce_account, recent_blockhashes_sysvar, rent_sysvar, _remaining @ ..] = accounts
+        let [nonce_account, recent_blockhashes_sysvar, rent_sysvar, remaining @ ..] = accounts
         else {
             return None;
         };
@@ -29,6 +32,66 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeNonceAccount {
             nonce_account: nonce_account.pubkey,
             recent_blockhashes_sysvar: recent_blockhashes_sysvar.pubkey,
             rent_sysvar: rent_sysvar.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeNonceAccountInstructionAccountMetas {
+    pub nonce_account: solana_instruction::AccountMeta,
+    pub recent_blockhashes_sysvar: solana_instruction::AccountMeta,
+    pub rent_sysvar: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeNonceAccount {
+    type ArrangedAccountMetas = InitializeNonceAccountInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [nonce_account, recent_blockhashes_sysvar, rent_sysvar, remaining @ ..] = accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeNonceAccountInstructionAccountMetas {
+            nonce_account: nonce_account.clone(),
+            recent_blockhashes_sysvar: recent_blockhashes_sysvar.clone(),
+            rent_sysvar: rent_sysvar.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeNonceAccountInstructionAccountIndices {
+    pub nonce_account: usize,
+    pub recent_blockhashes_sysvar: usize,
+    pub rent_sysvar: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeNonceAccount {
+    type ArrangedAccountIndices = InitializeNonceAccountInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(InitializeNonceAccountInstructionAccountIndices {
+            nonce_account: 0,
+            recent_blockhashes_sysvar: 1,
+            rent_sysvar: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeNonceAccount {
+    fn account_names() -> &'static [&'static str] {
+        &["nonce_account", "recent_blockhashes_sysvar", "rent_sysvar"]
+    }
+}
@@ -35,18 +35,28 @@ pub enum SystemProgramInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for SystemProgramDecoder {
     type InstructionType = SystemProgramInstruction;
 
-    fn decode_instruction(
+    fn decoder_name(&self) -> &'static str {
+        "system_program"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[solana_program::system_program::ID]
+    }
+
+    fn try_decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
-    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+    ) -> carbon_core::error::CarbonResult<
+        Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>>,
+    > {
         if !instruction
             .program_id
             .eq(&solana_program::system_program::id())
         {
-            return None;
+            return Ok(None);
         }
 
-        carbon_core::try_decode_instructions!(instruction,
+        Ok(carbon_core::try_decode_instructions!(instruction,
             SystemProgramInstruction::CreateAccount => create_account::CreateAccount,
             SystemProgramInstruction::Assign => assign::Assign,
             SystemProgramInstruction::TransferSol => transfer_sol::TransferSol,
@@ -60,7 +70,7 @@ impl carbon_core::instruction::InstructionDecoder<'_> for SystemProgramDecoder {
             SystemProgramInstruction::AssignWithSeed => assign_with_seed::AssignWithSeed,
             SystemProgramInstruction::TransferSolWithSeed => transfer_sol_with_seed::TransferSolWithSeed,
             SystemProgramInstruction::UpgradeNonceAccount => upgrade_nonce_account::UpgradeNonceAccount,
-        )
+        ))
     }
 }
 
@@ -116,6 +126,7 @@ mod tests {
                 base_account: solana_pubkey::Pubkey::from_str_const(
                     "6bBmDxYqXeFbXN8SmtjTpiA3SrEDKsxK8RG6yhPGpa9G",
                 ),
+                remaining_accounts: vec![],
             };
 
         // Act
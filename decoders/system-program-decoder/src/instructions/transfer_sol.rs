@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +13,7 @@ pub struct TransferSol {
 pub struct TransferSolInstructionAccounts {
     pub source: solana_pubkey::Pubkey,
     pub destination: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for TransferSol {
@@ -19,13 +22,68 @@ impl carbon_core::deserialize::ArrangeAccounts for TransferSol {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [source, destination, _remaining @ ..] = accounts else {
+        let [source, destination, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(TransferSolInstructionAccounts {
             source: source.pubkey,
             destination: destination.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct TransferSolInstructionAccountMetas {
+    pub source: solana_instruction::AccountMeta,
+    pub destination: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for TransferSol {
+    type ArrangedAccountMetas = TransferSolInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [source, destination, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(TransferSolInstructionAccountMetas {
+            source: source.clone(),
+            destination: destination.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct TransferSolInstructionAccountIndices {
+    pub source: usize,
+    pub destination: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for TransferSol {
+    type ArrangedAccountIndices = TransferSolInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(TransferSolInstructionAccountIndices {
+            source: 0,
+            destination: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for TransferSol {
+    fn account_names() -> &'static [&'static str] {
+        &["source", "destination"]
+    }
+}
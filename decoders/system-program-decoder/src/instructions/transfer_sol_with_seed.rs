@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, deserialize::U64PrefixString, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
 #[carbon(discriminator = "0x0b000000")]
 pub struct TransferSolWithSeed {
@@ -12,6 +14,7 @@ pub struct TransferSolWithSeedInstructionAccounts {
     pub source: solana_pubkey::Pubkey,
     pub base_account: solana_pubkey::Pubkey,
     pub destination: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for TransferSolWithSeed {
@@ -20,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for TransferSolWithSeed {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [source, base_account, destination, _remaining @ ..] = accounts else {
+        let [source, base_account, destination, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +31,65 @@ impl carbon_core::deserialize::ArrangeAccounts for TransferSolWithSeed {
             source: source.pubkey,
             base_account: base_account.pubkey,
             destination: destination.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct TransferSolWithSeedInstructionAccountMetas {
+    pub source: solana_instruction::AccountMeta,
+    pub base_account: solana_instruction::AccountMeta,
+    pub destination: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for TransferSolWithSeed {
+    type ArrangedAccountMetas = TransferSolWithSeedInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [source, base_account, destination, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(TransferSolWithSeedInstructionAccountMetas {
+            source: source.clone(),
+            base_account: base_account.clone(),
+            destination: destination.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct TransferSolWithSeedInstructionAccountIndices {
+    pub source: usize,
+    pub base_account: usize,
+    pub destination: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for TransferSolWithSeed {
+    type ArrangedAccountIndices = TransferSolWithSeedInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(TransferSolWithSeedInstructionAccountIndices {
+            source: 0,
+            base_account: 1,
+            destination: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for TransferSolWithSeed {
+    fn account_names() -> &'static [&'static str] {
+        &["source", "base_account", "destination"]
+    }
+}
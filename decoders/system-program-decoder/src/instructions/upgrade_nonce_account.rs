@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -8,6 +10,7 @@ pub struct UpgradeNonceAccount {}
 
 pub struct UpgradeNonceAccountInstructionAccounts {
     pub nonce_account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpgradeNonceAccount {
@@ -16,12 +19,63 @@ impl carbon_core::deserialize::ArrangeAccounts for UpgradeNonceAccount {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [nonce_account, _remaining @ ..] = accounts else {
+        let [nonce_account, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(UpgradeNonceAccountInstructionAccounts {
             nonce_account: nonce_account.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpgradeNonceAccountInstructionAccountMetas {
+    pub nonce_account: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpgradeNonceAccount {
+    type ArrangedAccountMetas = UpgradeNonceAccountInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [nonce_account, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpgradeNonceAccountInstructionAccountMetas {
+            nonce_account: nonce_account.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpgradeNonceAccountInstructionAccountIndices {
+    pub nonce_account: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpgradeNonceAccount {
+    type ArrangedAccountIndices = UpgradeNonceAccountInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.is_empty() {
+            return None;
+        }
+
+        Some(UpgradeNonceAccountInstructionAccountIndices {
+            nonce_account: 0,
+            remaining_accounts: 1..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpgradeNonceAccount {
+    fn account_names() -> &'static [&'static str] {
+        &["nonce_account"]
+    }
+}
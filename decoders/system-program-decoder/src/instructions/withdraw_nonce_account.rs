@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +16,7 @@ pub struct WithdrawNonceAccountInstructionAccounts {
     pub recent_blockhashes_sysvar: solana_pubkey::Pubkey,
     pub rent_sysvar: solana_pubkey::Pubkey,
     pub nonce_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for WithdrawNonceAccount {
@@ -22,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawNonceAccount {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [nonce_account, recipient_account, recent_blockhashes_sysvar, rent_sysvar, nonce_authority, _remaining @ ..] =
+        let [nonce_account, recipient_account, recent_blockhashes_sysvar, rent_sysvar, nonce_authority, remaining @ ..] =
             accounts
         else {
             return None;
@@ -34,6 +37,81 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawNonceAccount {
             recent_blockhashes_sysvar: recent_blockhashes_sysvar.pubkey,
             rent_sysvar: rent_sysvar.pubkey,
             nonce_authority: nonce_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct WithdrawNonceAccountInstructionAccountMetas {
+    pub nonce_account: solana_instruction::AccountMeta,
+    pub recipient_account: solana_instruction::AccountMeta,
+    pub recent_blockhashes_sysvar: solana_instruction::AccountMeta,
+    pub rent_sysvar: solana_instruction::AccountMeta,
+    pub nonce_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for WithdrawNonceAccount {
+    type ArrangedAccountMetas = WithdrawNonceAccountInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [nonce_account, recipient_account, recent_blockhashes_sysvar, rent_sysvar, nonce_authority, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(WithdrawNonceAccountInstructionAccountMetas {
+            nonce_account: nonce_account.clone(),
+            recipient_account: recipient_account.clone(),
+            recent_blockhashes_sysvar: recent_blockhashes_sysvar.clone(),
+            rent_sysvar: rent_sysvar.clone(),
+            nonce_authority: nonce_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct WithdrawNonceAccountInstructionAccountIndices {
+    pub nonce_account: usize,
+    pub recipient_account: usize,
+    pub recent_blockhashes_sysvar: usize,
+    pub rent_sysvar: usize,
+    pub nonce_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for WithdrawNonceAccount {
+    type ArrangedAccountIndices = WithdrawNonceAccountInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(WithdrawNonceAccountInstructionAccountIndices {
+            nonce_account: 0,
+            recipient_account: 1,
+            recent_blockhashes_sysvar: 2,
+            rent_sysvar: 3,
+            nonce_authority: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for WithdrawNonceAccount {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "nonce_account",
+            "recipient_account",
+            "recent_blockhashes_sysvar",
+            "rent_sysvar",
+            "nonce_authority",
+        ]
+    }
+}
@@ -0,0 +1,208 @@
+//! Parses the extensions stored after the base state of Token-2022 mints and
+//! token accounts.
+//!
+//! An account with extensions is laid out as its base state, zero padding up
+//! to the length of a token account, one byte with the account type and then
+//! the extensions as type-length-value entries: a `u16` extension type, a
+//! `u16` length and the extension data.
+
+use {
+    super::layout::Reader,
+    crate::types::{AccountState, Extension, TransferFee},
+    alloc::vec::Vec,
+};
+
+/// The length of a token account, after which the account type is stored.
+pub const BASE_ACCOUNT_LENGTH: usize = 165;
+
+/// The length of a multisig account, which an account with extensions never
+/// has so that the two can be told apart.
+pub const MULTISIG_LENGTH: usize = 355;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum AccountType {
+    Mint = 1,
+    Account = 2,
+}
+
+/// Splits `data` into the base state of an account of `account_type`, which
+/// is `base_length` bytes long, and the account's extensions.
+///
+/// Returns `None` if `data` is not an account of `account_type` or if its
+/// extensions are malformed.
+pub fn split_extensions(
+    data: &[u8],
+    account_type: AccountType,
+    base_length: usize,
+) -> Option<(&[u8], Vec<Extension>)> {
+    if data.len() == base_length {
+        return Some((data, Vec::new()));
+    }
+    if data.len() <= BASE_ACCOUNT_LENGTH || data.len() == MULTISIG_LENGTH {
+        return None;
+    }
+    if data[BASE_ACCOUNT_LENGTH] != account_type as u8 {
+        return None;
+    }
+
+    let extensions = unpack_extensions(&data[BASE_ACCOUNT_LENGTH + 1..])?;
+    Some((&data[..base_length], extensions))
+}
+
+/// Parses the type-length-value entries following the account type.
+///
+/// Entries are read until the data ends or an uninitialized entry is found.
+/// Extensions this decoder does not know of, or whose data does not have the
+/// expected layout, are returned as `Extension::Unknown`.
+pub fn unpack_extensions(tlv_data: &[u8]) -> Option<Vec<Extension>> {
+    let mut reader = Reader::new(tlv_data);
+    let mut extensions = Vec::new();
+
+    while let (Some(type_id), Some(length)) = (reader.u16(), reader.u16()) {
+        if type_id == 0 {
+            break;
+        }
+        let data = reader.take(length as usize)?;
+        extensions.push(decode_extension(type_id, data));
+    }
+
+    Some(extensions)
+}
+
+fn decode_extension(type_id: u16, data: &[u8]) -> Extension {
+    let mut reader = Reader::new(data);
+    match read_extension(type_id, &mut reader) {
+        Some(extension) if reader.is_empty() => extension,
+        _ => Extension::Unknown {
+            type_id,
+            data: data.to_vec(),
+        },
+    }
+}
+
+fn read_extension(type_id: u16, reader: &mut Reader) -> Option<Extension> {
+    let extension = match type_id {
+        1 => Extension::TransferFeeConfig {
+            transfer_fee_config_authority: reader.pubkey()?,
+            withdraw_withheld_authority: reader.pubkey()?,
+            withheld_amount: reader.u64()?,
+            older_transfer_fee: read_transfer_fee(reader)?,
+            newer_transfer_fee: read_transfer_fee(reader)?,
+        },
+        2 => Extension::TransferFeeAmount {
+            withheld_amount: reader.u64()?,
+        },
+        3 => Extension::MintCloseAuthority {
+            close_authority: reader.pubkey()?,
+        },
+        4 => Extension::ConfidentialTransferMint {
+            authority: reader.optional_pubkey()?,
+            auto_approve_new_accounts: reader.bool()?,
+            auditor_elgamal_pubkey: reader.optional_pubkey()?,
+        },
+        5 => Extension::ConfidentialTransferAccount {
+            approved: reader.bool()?,
+            elgamal_pubkey: reader.pubkey()?,
+            pending_balance_low: reader.array()?,
+            pending_balance_high: reader.array()?,
+            available_balance: reader.array()?,
+            decryptable_available_balance: reader.array()?,
+            allow_confidential_credits: reader.bool()?,
+            allow_non_confidential_credits: reader.bool()?,
+            pending_balance_credit_counter: reader.u64()?,
+            maximum_pending_balance_credit_counter: reader.u64()?,
+            expected_pending_balance_credit_counter: reader.u64()?,
+            actual_pending_balance_credit_counter: reader.u64()?,
+        },
+        6 => Extension::DefaultAccountState {
+            state: read_account_state(reader)?,
+        },
+        7 => Extension::ImmutableOwner {},
+        8 => Extension::MemoTransfer {
+            require_incoming_transfer_memos: reader.bool()?,
+        },
+        9 => Extension::NonTransferable {},
+        10 => Extension::InterestBearingConfig {
+            rate_authority: reader.pubkey()?,
+            initialization_timestamp: reader.u64()?,
+            pre_update_average_rate: reader.i16()?,
+            last_update_timestamp: reader.u64()?,
+            current_rate: reader.i16()?,
+        },
+        11 => Extension::CpiGuard {
+            lock_cpi: reader.bool()?,
+        },
+        12 => Extension::PermanentDelegate {
+            delegate: reader.pubkey()?,
+        },
+        13 => Extension::NonTransferableAccount {},
+        14 => Extension::TransferHook {
+            authority: reader.pubkey()?,
+            program_id: reader.pubkey()?,
+        },
+        15 => Extension::TransferHookAccount {
+            transferring: reader.bool()?,
+        },
+        16 => Extension::ConfidentialTransferFee {
+            authority: reader.optional_pubkey()?,
+            elgamal_pubkey: reader.pubkey()?,
+            harvest_to_mint_enabled: reader.bool()?,
+            withheld_amount: reader.array()?,
+        },
+        17 => Extension::ConfidentialTransferFeeAmount {
+            withheld_amount: reader.array()?,
+        },
+        18 => Extension::MetadataPointer {
+            authority: reader.optional_pubkey()?,
+            metadata_address: reader.optional_pubkey()?,
+        },
+        19 => Extension::TokenMetadata {
+            update_authority: reader.optional_pubkey()?,
+            mint: reader.pubkey()?,
+            name: reader.string()?,
+            symbol: reader.string()?,
+            uri: reader.string()?,
+            additional_metadata: reader.string_pairs()?,
+        },
+        20 => Extension::GroupPointer {
+            authority: reader.optional_pubkey()?,
+            group_address: reader.optional_pubkey()?,
+        },
+        21 => Extension::TokenGroup {
+            update_authority: reader.optional_pubkey()?,
+            mint: reader.pubkey()?,
+            size: reader.u64()?,
+            max_size: reader.u64()?,
+        },
+        22 => Extension::GroupMemberPointer {
+            authority: reader.optional_pubkey()?,
+            member_address: reader.optional_pubkey()?,
+        },
+        23 => Extension::TokenGroupMember {
+            mint: reader.pubkey()?,
+            group: reader.pubkey()?,
+            member_number: reader.u64()?,
+        },
+        _ => return None,
+    };
+
+    Some(extension)
+}
+
+fn read_transfer_fee(reader: &mut Reader) -> Option<TransferFee> {
+    Some(TransferFee {
+        epoch: reader.u64()?,
+        maximum_fee: reader.u64()?,
+        transfer_fee_basis_points: reader.u16()?,
+    })
+}
+
+pub(crate) fn read_account_state(reader: &mut Reader) -> Option<AccountState> {
+    match reader.u8()? {
+        0 => Some(AccountState::Uninitialized),
+        1 => Some(AccountState::Initialized),
+        2 => Some(AccountState::Frozen),
+        _ => None,
+    }
+}
@@ -0,0 +1,109 @@
+//! Reads the fixed little-endian layout the Token-2022 program stores its
+//! accounts and extensions in, which is not borsh.
+
+use {
+    alloc::{string::String, vec::Vec},
+    solana_pubkey::Pubkey,
+};
+
+pub(crate) struct Reader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    pub(crate) fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub(crate) fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        if self.data.len() < len {
+            return None;
+        }
+        let (taken, rest) = self.data.split_at(len);
+        self.data = rest;
+        Some(taken)
+    }
+
+    pub(crate) fn array<const N: usize>(&mut self) -> Option<[u8; N]> {
+        self.take(N)?.try_into().ok()
+    }
+
+    pub(crate) fn u8(&mut self) -> Option<u8> {
+        Some(self.array::<1>()?[0])
+    }
+
+    pub(crate) fn bool(&mut self) -> Option<bool> {
+        match self.u8()? {
+            0 => Some(false),
+            1 => Some(true),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn u16(&mut self) -> Option<u16> {
+        Some(u16::from_le_bytes(self.array()?))
+    }
+
+    pub(crate) fn i16(&mut self) -> Option<i16> {
+        Some(i16::from_le_bytes(self.array()?))
+    }
+
+    pub(crate) fn u32(&mut self) -> Option<u32> {
+        Some(u32::from_le_bytes(self.array()?))
+    }
+
+    pub(crate) fn u64(&mut self) -> Option<u64> {
+        Some(u64::from_le_bytes(self.array()?))
+    }
+
+    pub(crate) fn pubkey(&mut self) -> Option<Pubkey> {
+        Some(Pubkey::new_from_array(self.array()?))
+    }
+
+    /// Reads an `OptionalNonZeroPubkey`, where the zero key means `None`.
+    pub(crate) fn optional_pubkey(&mut self) -> Option<Option<Pubkey>> {
+        let pubkey = self.pubkey()?;
+        Some((pubkey != Pubkey::default()).then_some(pubkey))
+    }
+
+    /// Reads a `COption`, a `u32` tag followed by the value, which is present
+    /// even when the tag is 0.
+    fn c_option<T>(&mut self, read: impl FnOnce(&mut Self) -> Option<T>) -> Option<Option<T>> {
+        let tag = self.u32()?;
+        let value = read(self)?;
+        match tag {
+            0 => Some(None),
+            1 => Some(Some(value)),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn c_option_pubkey(&mut self) -> Option<Option<Pubkey>> {
+        self.c_option(Self::pubkey)
+    }
+
+    pub(crate) fn c_option_u64(&mut self) -> Option<Option<u64>> {
+        self.c_option(Self::u64)
+    }
+
+    /// Reads a borsh string, as used by the token metadata extension.
+    pub(crate) fn string(&mut self) -> Option<String> {
+        let len = self.u32()? as usize;
+        String::from_utf8(self.take(len)?.to_vec()).ok()
+    }
+
+    /// Reads a borsh vector of string pairs, as used by the token metadata
+    /// extension.
+    pub(crate) fn string_pairs(&mut self) -> Option<Vec<(String, String)>> {
+        let len = self.u32()? as usize;
+        let mut pairs = Vec::new();
+        for _ in 0..len {
+            pairs.push((self.string()?, self.string()?));
+        }
+        Some(pairs)
+    }
+}
@@ -1,4 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use {
+    super::layout::Reader,
+    carbon_core::{borsh, CarbonDeserialize},
+};
 
 #[derive(CarbonDeserialize, Debug)]
 #[carbon(discriminator = "0x6a5edd53c00a4a4a")]
@@ -9,3 +12,23 @@ pub struct Mint {
     pub is_initialized: bool,
     pub freeze_authority: Option<solana_pubkey::Pubkey>,
 }
+
+impl Mint {
+    pub const LEN: usize = 82;
+
+    /// Reads a mint in the layout the Token-2022 program stores it in.
+    pub fn unpack(data: &[u8]) -> Option<Self> {
+        if data.len() != Self::LEN {
+            return None;
+        }
+        let mut reader = Reader::new(data);
+
+        Some(Self {
+            mint_authority: reader.c_option_pubkey()?,
+            supply: reader.u64()?,
+            decimals: reader.u8()?,
+            is_initialized: reader.bool()?,
+            freeze_authority: reader.c_option_pubkey()?,
+        })
+    }
+}
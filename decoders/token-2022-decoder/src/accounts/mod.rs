@@ -1,15 +1,25 @@
 use {
     super::Token2022Decoder,
-    crate::PROGRAM_ID,
-    carbon_core::{account::AccountDecoder, deserialize::CarbonDeserialize},
+    crate::{types::Extension, PROGRAM_ID},
+    alloc::vec::Vec,
+    carbon_core::account::AccountDecoder,
+    extensions::AccountType,
 };
+pub mod extensions;
+mod layout;
 pub mod mint;
 pub mod multisig;
 pub mod token;
 
 pub enum Token2022Account {
-    Mint(mint::Mint),
-    Token(token::Token),
+    Mint {
+        mint: mint::Mint,
+        extensions: Vec<Extension>,
+    },
+    Token {
+        token: token::Token,
+        extensions: Vec<Extension>,
+    },
     Multisig(multisig::Multisig),
 }
 
@@ -23,27 +33,37 @@ impl AccountDecoder<'_> for Token2022Decoder {
             return None;
         }
 
-        if let Some(decoded_account) = mint::Mint::deserialize(account.data.as_slice()) {
-            return Some(carbon_core::account::DecodedAccount {
-                lamports: account.lamports,
-                data: Token2022Account::Mint(decoded_account),
-                owner: account.owner,
-                executable: account.executable,
-                rent_epoch: account.rent_epoch,
-            });
+        let data = account.data.as_slice();
+
+        if let Some((base, extensions)) =
+            extensions::split_extensions(data, AccountType::Mint, mint::Mint::LEN)
+        {
+            if let Some(mint) = mint::Mint::unpack(base) {
+                return Some(carbon_core::account::DecodedAccount {
+                    lamports: account.lamports,
+                    data: Token2022Account::Mint { mint, extensions },
+                    owner: account.owner,
+                    executable: account.executable,
+                    rent_epoch: account.rent_epoch,
+                });
+            }
         }
 
-        if let Some(decoded_account) = token::Token::deserialize(account.data.as_slice()) {
-            return Some(carbon_core::account::DecodedAccount {
-                lamports: account.lamports,
-                data: Token2022Account::Token(decoded_account),
-                owner: account.owner,
-                executable: account.executable,
-                rent_epoch: account.rent_epoch,
-            });
+        if let Some((base, extensions)) =
+            extensions::split_extensions(data, AccountType::Account, token::Token::LEN)
+        {
+            if let Some(token) = token::Token::unpack(base) {
+                return Some(carbon_core::account::DecodedAccount {
+                    lamports: account.lamports,
+                    data: Token2022Account::Token { token, extensions },
+                    owner: account.owner,
+                    executable: account.executable,
+                    rent_epoch: account.rent_epoch,
+                });
+            }
         }
 
-        if let Some(decoded_account) = multisig::Multisig::deserialize(account.data.as_slice()) {
+        if let Some(decoded_account) = multisig::Multisig::unpack(data) {
             return Some(carbon_core::account::DecodedAccount {
                 lamports: account.lamports,
                 data: Token2022Account::Multisig(decoded_account),
@@ -56,3 +76,150 @@ impl AccountDecoder<'_> for Token2022Decoder {
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::types::{AccountState, TransferFee},
+        alloc::vec,
+        solana_pubkey::Pubkey,
+    };
+
+    fn tlv_entry(type_id: u16, data: &[u8]) -> Vec<u8> {
+        [
+            &type_id.to_le_bytes()[..],
+            &(data.len() as u16).to_le_bytes(),
+            data,
+        ]
+        .concat()
+    }
+
+    fn transfer_fee_bytes(transfer_fee: &TransferFee) -> Vec<u8> {
+        [
+            &transfer_fee.epoch.to_le_bytes()[..],
+            &transfer_fee.maximum_fee.to_le_bytes(),
+            &transfer_fee.transfer_fee_basis_points.to_le_bytes(),
+        ]
+        .concat()
+    }
+
+    fn account(data: Vec<u8>) -> solana_account::Account {
+        solana_account::Account {
+            lamports: 1_461_600,
+            data,
+            owner: PROGRAM_ID,
+            executable: false,
+            rent_epoch: u64::MAX,
+        }
+    }
+
+    #[test]
+    fn test_decode_mint_with_transfer_fee_config() {
+        // Arrange
+        let mint_authority = Pubkey::new_unique();
+        let fee_authority = Pubkey::new_unique();
+        let withdraw_authority = Pubkey::new_unique();
+        let older_transfer_fee = TransferFee {
+            epoch: 500,
+            maximum_fee: 1_000_000,
+            transfer_fee_basis_points: 50,
+        };
+        let newer_transfer_fee = TransferFee {
+            epoch: 600,
+            maximum_fee: 2_000_000,
+            transfer_fee_basis_points: 100,
+        };
+        let mut data = [
+            &1u32.to_le_bytes()[..],
+            mint_authority.as_ref(),
+            &1_000_000_000u64.to_le_bytes(),
+            &[6, 1],
+            &0u32.to_le_bytes(),
+            &[0; 32],
+        ]
+        .concat();
+        data.resize(extensions::BASE_ACCOUNT_LENGTH, 0);
+        data.push(AccountType::Mint as u8);
+        data.extend(tlv_entry(
+            1,
+            &[
+                fee_authority.as_ref(),
+                withdraw_authority.as_ref(),
+                &25u64.to_le_bytes(),
+                &transfer_fee_bytes(&older_transfer_fee),
+                &transfer_fee_bytes(&newer_transfer_fee),
+            ]
+            .concat(),
+        ));
+        data.extend(tlv_entry(200, &[1, 2, 3]));
+
+        // Act
+        let decoded_account = Token2022Decoder
+            .decode_account(&account(data))
+            .expect("decode mint");
+
+        // Assert
+        let Token2022Account::Mint { mint, extensions } = decoded_account.data else {
+            panic!("expected a mint");
+        };
+        assert_eq!(mint.mint_authority, Some(mint_authority));
+        assert_eq!(mint.supply, 1_000_000_000);
+        assert_eq!(mint.decimals, 6);
+        assert!(mint.is_initialized);
+        assert_eq!(mint.freeze_authority, None);
+        assert_eq!(
+            extensions,
+            vec![
+                Extension::TransferFeeConfig {
+                    transfer_fee_config_authority: fee_authority,
+                    withdraw_withheld_authority: withdraw_authority,
+                    withheld_amount: 25,
+                    older_transfer_fee,
+                    newer_transfer_fee,
+                },
+                Extension::Unknown {
+                    type_id: 200,
+                    data: vec![1, 2, 3],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decode_token_account_without_extensions() {
+        // Arrange
+        let mint = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let data = [
+            mint.as_ref(),
+            owner.as_ref(),
+            &42u64.to_le_bytes(),
+            &0u32.to_le_bytes(),
+            &[0; 32],
+            &[AccountState::Frozen as u8],
+            &0u32.to_le_bytes(),
+            &0u64.to_le_bytes(),
+            &0u64.to_le_bytes(),
+            &0u32.to_le_bytes(),
+            &[0; 32],
+        ]
+        .concat();
+
+        // Act
+        let decoded_account = Token2022Decoder
+            .decode_account(&account(data))
+            .expect("decode token account");
+
+        // Assert
+        let Token2022Account::Token { token, extensions } = decoded_account.data else {
+            panic!("expected a token account");
+        };
+        assert_eq!(token.mint, mint);
+        assert_eq!(token.owner, owner);
+        assert_eq!(token.amount, 42);
+        assert_eq!(token.state, AccountState::Frozen);
+        assert_eq!(token.is_native, None);
+        assert!(extensions.is_empty());
+    }
+}
@@ -1,4 +1,7 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use {
+    super::layout::Reader,
+    carbon_core::{borsh, CarbonDeserialize},
+};
 
 #[derive(CarbonDeserialize, Debug)]
 #[carbon(discriminator = "0x032c5eecdd46ac85")]
@@ -8,3 +11,22 @@ pub struct Multisig {
     pub is_initialized: bool,
     pub signers: [solana_pubkey::Pubkey; 11],
 }
+
+impl Multisig {
+    pub const LEN: usize = 355;
+
+    /// Reads a multisig in the layout the Token-2022 program stores it in.
+    pub fn unpack(data: &[u8]) -> Option<Self> {
+        if data.len() != Self::LEN {
+            return None;
+        }
+        let mut reader = Reader::new(data);
+
+        Some(Self {
+            m: reader.u8()?,
+            n: reader.u8()?,
+            is_initialized: reader.bool()?,
+            signers: core::array::from_fn(|_| reader.pubkey().unwrap_or_default()),
+        })
+    }
+}
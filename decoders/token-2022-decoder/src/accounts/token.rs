@@ -1,5 +1,5 @@
 use {
-    super::super::types::*,
+    super::{super::types::*, extensions::read_account_state, layout::Reader},
     carbon_core::{borsh, CarbonDeserialize},
 };
 
@@ -15,3 +15,27 @@ pub struct Token {
     pub delegated_amount: u64,
     pub close_authority: Option<solana_pubkey::Pubkey>,
 }
+
+impl Token {
+    pub const LEN: usize = 165;
+
+    /// Reads a token account in the layout the Token-2022 program stores it
+    /// in.
+    pub fn unpack(data: &[u8]) -> Option<Self> {
+        if data.len() != Self::LEN {
+            return None;
+        }
+        let mut reader = Reader::new(data);
+
+        Some(Self {
+            mint: reader.pubkey()?,
+            owner: reader.pubkey()?,
+            amount: reader.u64()?,
+            delegate: reader.c_option_pubkey()?,
+            state: read_account_state(&mut reader)?,
+            is_native: reader.c_option_u64()?,
+            delegated_amount: reader.u64()?,
+            close_authority: reader.c_option_pubkey()?,
+        })
+    }
+}
@@ -1,8 +1,10 @@
+use alloc::vec::Vec;
 use {
     super::super::types::*,
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -17,6 +19,7 @@ pub struct ApplyConfidentialPendingBalance {
 pub struct ApplyConfidentialPendingBalanceInstructionAccounts {
     pub token: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ApplyConfidentialPendingBalance {
@@ -25,13 +28,68 @@ impl carbon_core::deserialize::ArrangeAccounts for ApplyConfidentialPendingBalan
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [token, authority, _remaining @ ..] = accounts else {
+        let [token, authority, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(ApplyConfidentialPendingBalanceInstructionAccounts {
             token: token.pubkey,
             authority: authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ApplyConfidentialPendingBalanceInstructionAccountMetas {
+    pub token: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ApplyConfidentialPendingBalance {
+    type ArrangedAccountMetas = ApplyConfidentialPendingBalanceInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [token, authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(ApplyConfidentialPendingBalanceInstructionAccountMetas {
+            token: token.clone(),
+            authority: authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ApplyConfidentialPendingBalanceInstructionAccountIndices {
+    pub token: usize,
+    pub authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ApplyConfidentialPendingBalance {
+    type ArrangedAccountIndices = ApplyConfidentialPendingBalanceInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(ApplyConfidentialPendingBalanceInstructionAccountIndices {
+            token: 0,
+            authority: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ApplyConfidentialPendingBalance {
+    fn account_names() -> &'static [&'static str] {
+        &["token", "authority"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +14,7 @@ pub struct ApproveInstructionAccounts {
     pub source: solana_pubkey::Pubkey,
     pub delegate: solana_pubkey::Pubkey,
     pub owner: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Approve {
@@ -20,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Approve {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [source, delegate, owner, _remaining @ ..] = accounts else {
+        let [source, delegate, owner, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +31,65 @@ impl carbon_core::deserialize::ArrangeAccounts for Approve {
             source: source.pubkey,
             delegate: delegate.pubkey,
             owner: owner.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ApproveInstructionAccountMetas {
+    pub source: solana_instruction::AccountMeta,
+    pub delegate: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Approve {
+    type ArrangedAccountMetas = ApproveInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [source, delegate, owner, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(ApproveInstructionAccountMetas {
+            source: source.clone(),
+            delegate: delegate.clone(),
+            owner: owner.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ApproveInstructionAccountIndices {
+    pub source: usize,
+    pub delegate: usize,
+    pub owner: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Approve {
+    type ArrangedAccountIndices = ApproveInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(ApproveInstructionAccountIndices {
+            source: 0,
+            delegate: 1,
+            owner: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Approve {
+    fn account_names() -> &'static [&'static str] {
+        &["source", "delegate", "owner"]
+    }
+}
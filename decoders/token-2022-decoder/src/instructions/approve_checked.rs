@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +16,7 @@ pub struct ApproveCheckedInstructionAccounts {
     pub mint: solana_pubkey::Pubkey,
     pub delegate: solana_pubkey::Pubkey,
     pub owner: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ApproveChecked {
@@ -22,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ApproveChecked {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [source, mint, delegate, owner, _remaining @ ..] = accounts else {
+        let [source, mint, delegate, owner, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -31,6 +34,69 @@ impl carbon_core::deserialize::ArrangeAccounts for ApproveChecked {
             mint: mint.pubkey,
             delegate: delegate.pubkey,
             owner: owner.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ApproveCheckedInstructionAccountMetas {
+    pub source: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub delegate: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ApproveChecked {
+    type ArrangedAccountMetas = ApproveCheckedInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [source, mint, delegate, owner, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(ApproveCheckedInstructionAccountMetas {
+            source: source.clone(),
+            mint: mint.clone(),
+            delegate: delegate.clone(),
+            owner: owner.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ApproveCheckedInstructionAccountIndices {
+    pub source: usize,
+    pub mint: usize,
+    pub delegate: usize,
+    pub owner: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ApproveChecked {
+    type ArrangedAccountIndices = ApproveCheckedInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(ApproveCheckedInstructionAccountIndices {
+            source: 0,
+            mint: 1,
+            delegate: 2,
+            owner: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ApproveChecked {
+    fn account_names() -> &'static [&'static str] {
+        &["source", "mint", "delegate", "owner"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +14,7 @@ pub struct ApproveConfidentialTransferAccountInstructionAccounts {
     pub token: solana_pubkey::Pubkey,
     pub mint: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ApproveConfidentialTransferAccount {
@@ -20,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ApproveConfidentialTransferAc
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [token, mint, authority, _remaining @ ..] = accounts else {
+        let [token, mint, authority, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +31,67 @@ impl carbon_core::deserialize::ArrangeAccounts for ApproveConfidentialTransferAc
             token: token.pubkey,
             mint: mint.pubkey,
             authority: authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ApproveConfidentialTransferAccountInstructionAccountMetas {
+    pub token: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ApproveConfidentialTransferAccount {
+    type ArrangedAccountMetas = ApproveConfidentialTransferAccountInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [token, mint, authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(ApproveConfidentialTransferAccountInstructionAccountMetas {
+            token: token.clone(),
+            mint: mint.clone(),
+            authority: authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ApproveConfidentialTransferAccountInstructionAccountIndices {
+    pub token: usize,
+    pub mint: usize,
+    pub authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ApproveConfidentialTransferAccount {
+    type ArrangedAccountIndices = ApproveConfidentialTransferAccountInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(
+            ApproveConfidentialTransferAccountInstructionAccountIndices {
+                token: 0,
+                mint: 1,
+                authority: 2,
+                remaining_accounts: 3..accounts.len(),
+            },
+        )
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ApproveConfidentialTransferAccount {
+    fn account_names() -> &'static [&'static str] {
+        &["token", "mint", "authority"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +14,7 @@ pub struct BurnInstructionAccounts {
     pub account: solana_pubkey::Pubkey,
     pub mint: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Burn {
@@ -20,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Burn {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [account, mint, authority, _remaining @ ..] = accounts else {
+        let [account, mint, authority, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +31,65 @@ impl carbon_core::deserialize::ArrangeAccounts for Burn {
             account: account.pubkey,
             mint: mint.pubkey,
             authority: authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct BurnInstructionAccountMetas {
+    pub account: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Burn {
+    type ArrangedAccountMetas = BurnInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [account, mint, authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(BurnInstructionAccountMetas {
+            account: account.clone(),
+            mint: mint.clone(),
+            authority: authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct BurnInstructionAccountIndices {
+    pub account: usize,
+    pub mint: usize,
+    pub authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Burn {
+    type ArrangedAccountIndices = BurnInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(BurnInstructionAccountIndices {
+            account: 0,
+            mint: 1,
+            authority: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Burn {
+    fn account_names() -> &'static [&'static str] {
+        &["account", "mint", "authority"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -10,6 +12,7 @@ pub struct CloseAccountInstructionAccounts {
     pub account: solana_pubkey::Pubkey,
     pub destination: solana_pubkey::Pubkey,
     pub owner: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CloseAccount {
@@ -18,7 +21,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CloseAccount {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [account, destination, owner, _remaining @ ..] = accounts else {
+        let [account, destination, owner, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -26,6 +29,65 @@ impl carbon_core::deserialize::ArrangeAccounts for CloseAccount {
             account: account.pubkey,
             destination: destination.pubkey,
             owner: owner.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CloseAccountInstructionAccountMetas {
+    pub account: solana_instruction::AccountMeta,
+    pub destination: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CloseAccount {
+    type ArrangedAccountMetas = CloseAccountInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [account, destination, owner, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(CloseAccountInstructionAccountMetas {
+            account: account.clone(),
+            destination: destination.clone(),
+            owner: owner.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CloseAccountInstructionAccountIndices {
+    pub account: usize,
+    pub destination: usize,
+    pub owner: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CloseAccount {
+    type ArrangedAccountIndices = CloseAccountInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(CloseAccountInstructionAccountIndices {
+            account: 0,
+            destination: 1,
+            owner: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CloseAccount {
+    fn account_names() -> &'static [&'static str] {
+        &["account", "destination", "owner"]
+    }
+}
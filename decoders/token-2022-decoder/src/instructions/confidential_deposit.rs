@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +16,7 @@ pub struct ConfidentialDepositInstructionAccounts {
     pub token: solana_pubkey::Pubkey,
     pub mint: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ConfidentialDeposit {
@@ -22,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ConfidentialDeposit {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [token, mint, authority, _remaining @ ..] = accounts else {
+        let [token, mint, authority, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -30,6 +33,65 @@ impl carbon_core::deserialize::ArrangeAccounts for ConfidentialDeposit {
             token: token.pubkey,
             mint: mint.pubkey,
             authority: authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ConfidentialDepositInstructionAccountMetas {
+    pub token: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ConfidentialDeposit {
+    type ArrangedAccountMetas = ConfidentialDepositInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [token, mint, authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(ConfidentialDepositInstructionAccountMetas {
+            token: token.clone(),
+            mint: mint.clone(),
+            authority: authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ConfidentialDepositInstructionAccountIndices {
+    pub token: usize,
+    pub mint: usize,
+    pub authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ConfidentialDeposit {
+    type ArrangedAccountIndices = ConfidentialDepositInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(ConfidentialDepositInstructionAccountIndices {
+            token: 0,
+            mint: 1,
+            authority: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ConfidentialDeposit {
+    fn account_names() -> &'static [&'static str] {
+        &["token", "mint", "authority"]
+    }
+}
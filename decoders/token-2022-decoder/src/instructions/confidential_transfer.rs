@@ -1,8 +1,10 @@
+use alloc::vec::Vec;
 use {
     carbon_core::{borsh, CarbonDeserialize},
     serde_big_array::BigArray,
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -25,6 +27,7 @@ pub struct ConfidentialTransferInstructionAccounts {
     pub ciphertext_validity_record: solana_pubkey::Pubkey,
     pub range_record: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ConfidentialTransfer {
@@ -33,7 +36,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ConfidentialTransfer {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [source_token, mint, destination_token, instructions_sysvar, equality_record, ciphertext_validity_record, range_record, authority, _remaining @ ..] =
+        let [source_token, mint, destination_token, instructions_sysvar, equality_record, ciphertext_validity_record, range_record, authority, remaining @ ..] =
             accounts
         else {
             return None;
@@ -48,6 +51,96 @@ impl carbon_core::deserialize::ArrangeAccounts for ConfidentialTransfer {
             ciphertext_validity_record: ciphertext_validity_record.pubkey,
             range_record: range_record.pubkey,
             authority: authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ConfidentialTransferInstructionAccountMetas {
+    pub source_token: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub destination_token: solana_instruction::AccountMeta,
+    pub instructions_sysvar: solana_instruction::AccountMeta,
+    pub equality_record: solana_instruction::AccountMeta,
+    pub ciphertext_validity_record: solana_instruction::AccountMeta,
+    pub range_record: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ConfidentialTransfer {
+    type ArrangedAccountMetas = ConfidentialTransferInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [source_token, mint, destination_token, instructions_sysvar, equality_record, ciphertext_validity_record, range_record, authority, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(ConfidentialTransferInstructionAccountMetas {
+            source_token: source_token.clone(),
+            mint: mint.clone(),
+            destination_token: destination_token.clone(),
+            instructions_sysvar: instructions_sysvar.clone(),
+            equality_record: equality_record.clone(),
+            ciphertext_validity_record: ciphertext_validity_record.clone(),
+            range_record: range_record.clone(),
+            authority: authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ConfidentialTransferInstructionAccountIndices {
+    pub source_token: usize,
+    pub mint: usize,
+    pub destination_token: usize,
+    pub instructions_sysvar: usize,
+    pub equality_record: usize,
+    pub ciphertext_validity_record: usize,
+    pub range_record: usize,
+    pub authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ConfidentialTransfer {
+    type ArrangedAccountIndices = ConfidentialTransferInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(ConfidentialTransferInstructionAccountIndices {
+            source_token: 0,
+            mint: 1,
+            destination_token: 2,
+            instructions_sysvar: 3,
+            equality_record: 4,
+            ciphertext_validity_record: 5,
+            range_record: 6,
+            authority: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ConfidentialTransfer {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "source_token",
+            "mint",
+            "destination_token",
+            "instructions_sysvar",
+            "equality_record",
+            "ciphertext_validity_record",
+            "range_record",
+            "authority",
+        ]
+    }
+}
@@ -1,8 +1,10 @@
+use alloc::vec::Vec;
 use {
     carbon_core::{borsh, CarbonDeserialize},
     serde_big_array::BigArray,
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -24,6 +26,7 @@ pub struct ConfidentialWithdrawInstructionAccounts {
     pub equality_record: solana_pubkey::Pubkey,
     pub range_record: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ConfidentialWithdraw {
@@ -32,7 +35,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ConfidentialWithdraw {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [token, mint, instructions_sysvar, equality_record, range_record, authority, _remaining @ ..] =
+        let [token, mint, instructions_sysvar, equality_record, range_record, authority, remaining @ ..] =
             accounts
         else {
             return None;
@@ -45,6 +48,86 @@ impl carbon_core::deserialize::ArrangeAccounts for ConfidentialWithdraw {
             equality_record: equality_record.pubkey,
             range_record: range_record.pubkey,
             authority: authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ConfidentialWithdrawInstructionAccountMetas {
+    pub token: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub instructions_sysvar: solana_instruction::AccountMeta,
+    pub equality_record: solana_instruction::AccountMeta,
+    pub range_record: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ConfidentialWithdraw {
+    type ArrangedAccountMetas = ConfidentialWithdrawInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [token, mint, instructions_sysvar, equality_record, range_record, authority, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(ConfidentialWithdrawInstructionAccountMetas {
+            token: token.clone(),
+            mint: mint.clone(),
+            instructions_sysvar: instructions_sysvar.clone(),
+            equality_record: equality_record.clone(),
+            range_record: range_record.clone(),
+            authority: authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ConfidentialWithdrawInstructionAccountIndices {
+    pub token: usize,
+    pub mint: usize,
+    pub instructions_sysvar: usize,
+    pub equality_record: usize,
+    pub range_record: usize,
+    pub authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ConfidentialWithdraw {
+    type ArrangedAccountIndices = ConfidentialWithdrawInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(ConfidentialWithdrawInstructionAccountIndices {
+            token: 0,
+            mint: 1,
+            instructions_sysvar: 2,
+            equality_record: 3,
+            range_record: 4,
+            authority: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ConfidentialWithdraw {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "token",
+            "mint",
+            "instructions_sysvar",
+            "equality_record",
+            "range_record",
+            "authority",
+        ]
+    }
+}
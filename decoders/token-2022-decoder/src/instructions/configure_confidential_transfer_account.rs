@@ -1,8 +1,10 @@
+use alloc::vec::Vec;
 use {
     carbon_core::{borsh, CarbonDeserialize},
     serde_big_array::BigArray,
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -21,6 +23,7 @@ pub struct ConfigureConfidentialTransferAccountInstructionAccounts {
     pub instructions_sysvar_or_context_state: solana_pubkey::Pubkey,
     pub record: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ConfigureConfidentialTransferAccount {
@@ -29,7 +32,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ConfigureConfidentialTransfer
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [token, mint, instructions_sysvar_or_context_state, record, authority, _remaining @ ..] =
+        let [token, mint, instructions_sysvar_or_context_state, record, authority, remaining @ ..] =
             accounts
         else {
             return None;
@@ -41,6 +44,85 @@ impl carbon_core::deserialize::ArrangeAccounts for ConfigureConfidentialTransfer
             instructions_sysvar_or_context_state: instructions_sysvar_or_context_state.pubkey,
             record: record.pubkey,
             authority: authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ConfigureConfidentialTransferAccountInstructionAccountMetas {
+    pub token: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub instructions_sysvar_or_context_state: solana_instruction::AccountMeta,
+    pub record: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ConfigureConfidentialTransferAccount {
+    type ArrangedAccountMetas = ConfigureConfidentialTransferAccountInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [token, mint, instructions_sysvar_or_context_state, record, authority, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(
+            ConfigureConfidentialTransferAccountInstructionAccountMetas {
+                token: token.clone(),
+                mint: mint.clone(),
+                instructions_sysvar_or_context_state: instructions_sysvar_or_context_state.clone(),
+                record: record.clone(),
+                authority: authority.clone(),
+                remaining_accounts: remaining.to_vec(),
+            },
+        )
+    }
+}
+
+pub struct ConfigureConfidentialTransferAccountInstructionAccountIndices {
+    pub token: usize,
+    pub mint: usize,
+    pub instructions_sysvar_or_context_state: usize,
+    pub record: usize,
+    pub authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ConfigureConfidentialTransferAccount {
+    type ArrangedAccountIndices = ConfigureConfidentialTransferAccountInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(
+            ConfigureConfidentialTransferAccountInstructionAccountIndices {
+                token: 0,
+                mint: 1,
+                instructions_sysvar_or_context_state: 2,
+                record: 3,
+                authority: 4,
+                remaining_accounts: 5..accounts.len(),
+            },
+        )
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ConfigureConfidentialTransferAccount {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "token",
+            "mint",
+            "instructions_sysvar_or_context_state",
+            "record",
+            "authority",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -10,6 +12,7 @@ pub struct CreateNativeMintInstructionAccounts {
     pub payer: solana_pubkey::Pubkey,
     pub native_mint: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CreateNativeMint {
@@ -18,7 +21,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateNativeMint {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [payer, native_mint, system_program, _remaining @ ..] = accounts else {
+        let [payer, native_mint, system_program, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -26,6 +29,65 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateNativeMint {
             payer: payer.pubkey,
             native_mint: native_mint.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CreateNativeMintInstructionAccountMetas {
+    pub payer: solana_instruction::AccountMeta,
+    pub native_mint: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CreateNativeMint {
+    type ArrangedAccountMetas = CreateNativeMintInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [payer, native_mint, system_program, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(CreateNativeMintInstructionAccountMetas {
+            payer: payer.clone(),
+            native_mint: native_mint.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CreateNativeMintInstructionAccountIndices {
+    pub payer: usize,
+    pub native_mint: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CreateNativeMint {
+    type ArrangedAccountIndices = CreateNativeMintInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(CreateNativeMintInstructionAccountIndices {
+            payer: 0,
+            native_mint: 1,
+            system_program: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CreateNativeMint {
+    fn account_names() -> &'static [&'static str] {
+        &["payer", "native_mint", "system_program"]
+    }
+}
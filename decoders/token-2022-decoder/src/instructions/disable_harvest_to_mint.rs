@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +13,7 @@ pub struct DisableHarvestToMint {
 pub struct DisableHarvestToMintInstructionAccounts {
     pub mint: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for DisableHarvestToMint {
@@ -19,13 +22,68 @@ impl carbon_core::deserialize::ArrangeAccounts for DisableHarvestToMint {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [mint, authority, _remaining @ ..] = accounts else {
+        let [mint, authority, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(DisableHarvestToMintInstructionAccounts {
             mint: mint.pubkey,
             authority: authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DisableHarvestToMintInstructionAccountMetas {
+    pub mint: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for DisableHarvestToMint {
+    type ArrangedAccountMetas = DisableHarvestToMintInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [mint, authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(DisableHarvestToMintInstructionAccountMetas {
+            mint: mint.clone(),
+            authority: authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DisableHarvestToMintInstructionAccountIndices {
+    pub mint: usize,
+    pub authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for DisableHarvestToMint {
+    type ArrangedAccountIndices = DisableHarvestToMintInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(DisableHarvestToMintInstructionAccountIndices {
+            mint: 0,
+            authority: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for DisableHarvestToMint {
+    fn account_names() -> &'static [&'static str] {
+        &["mint", "authority"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +13,7 @@ pub struct DisableMemoTransfers {
 pub struct DisableMemoTransfersInstructionAccounts {
     pub token: solana_pubkey::Pubkey,
     pub owner: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for DisableMemoTransfers {
@@ -19,13 +22,68 @@ impl carbon_core::deserialize::ArrangeAccounts for DisableMemoTransfers {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [token, owner, _remaining @ ..] = accounts else {
+        let [token, owner, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(DisableMemoTransfersInstructionAccounts {
             token: token.pubkey,
             owner: owner.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DisableMemoTransfersInstructionAccountMetas {
+    pub token: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for DisableMemoTransfers {
+    type ArrangedAccountMetas = DisableMemoTransfersInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [token, owner, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(DisableMemoTransfersInstructionAccountMetas {
+            token: token.clone(),
+            owner: owner.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DisableMemoTransfersInstructionAccountIndices {
+    pub token: usize,
+    pub owner: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for DisableMemoTransfers {
+    type ArrangedAccountIndices = DisableMemoTransfersInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(DisableMemoTransfersInstructionAccountIndices {
+            token: 0,
+            owner: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for DisableMemoTransfers {
+    fn account_names() -> &'static [&'static str] {
+        &["token", "owner"]
+    }
+}
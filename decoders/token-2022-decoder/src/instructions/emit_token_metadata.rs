@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +13,7 @@ pub struct EmitTokenMetadata {
 
 pub struct EmitTokenMetadataInstructionAccounts {
     pub metadata: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for EmitTokenMetadata {
@@ -19,12 +22,63 @@ impl carbon_core::deserialize::ArrangeAccounts for EmitTokenMetadata {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [metadata, _remaining @ ..] = accounts else {
+        let [metadata, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(EmitTokenMetadataInstructionAccounts {
             metadata: metadata.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct EmitTokenMetadataInstructionAccountMetas {
+    pub metadata: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for EmitTokenMetadata {
+    type ArrangedAccountMetas = EmitTokenMetadataInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [metadata, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(EmitTokenMetadataInstructionAccountMetas {
+            metadata: metadata.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct EmitTokenMetadataInstructionAccountIndices {
+    pub metadata: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for EmitTokenMetadata {
+    type ArrangedAccountIndices = EmitTokenMetadataInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.is_empty() {
+            return None;
+        }
+
+        Some(EmitTokenMetadataInstructionAccountIndices {
+            metadata: 0,
+            remaining_accounts: 1..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for EmitTokenMetadata {
+    fn account_names() -> &'static [&'static str] {
+        &["metadata"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +16,7 @@ pub struct EmptyConfidentialTransferAccountInstructionAccounts {
     pub instructions_sysvar_or_context_state: solana_pubkey::Pubkey,
     pub record: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for EmptyConfidentialTransferAccount {
@@ -22,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for EmptyConfidentialTransferAcco
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [token, instructions_sysvar_or_context_state, record, authority, _remaining @ ..] =
+        let [token, instructions_sysvar_or_context_state, record, authority, remaining @ ..] =
             accounts
         else {
             return None;
@@ -33,6 +36,76 @@ impl carbon_core::deserialize::ArrangeAccounts for EmptyConfidentialTransferAcco
             instructions_sysvar_or_context_state: instructions_sysvar_or_context_state.pubkey,
             record: record.pubkey,
             authority: authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct EmptyConfidentialTransferAccountInstructionAccountMetas {
+    pub token: solana_instruction::AccountMeta,
+    pub instructions_sysvar_or_context_state: solana_instruction::AccountMeta,
+    pub record: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for EmptyConfidentialTransferAccount {
+    type ArrangedAccountMetas = EmptyConfidentialTransferAccountInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [token, instructions_sysvar_or_context_state, record, authority, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(EmptyConfidentialTransferAccountInstructionAccountMetas {
+            token: token.clone(),
+            instructions_sysvar_or_context_state: instructions_sysvar_or_context_state.clone(),
+            record: record.clone(),
+            authority: authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct EmptyConfidentialTransferAccountInstructionAccountIndices {
+    pub token: usize,
+    pub instructions_sysvar_or_context_state: usize,
+    pub record: usize,
+    pub authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for EmptyConfidentialTransferAccount {
+    type ArrangedAccountIndices = EmptyConfidentialTransferAccountInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(EmptyConfidentialTransferAccountInstructionAccountIndices {
+            token: 0,
+            instructions_sysvar_or_context_state: 1,
+            record: 2,
+            authority: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for EmptyConfidentialTransferAccount {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "token",
+            "instructions_sysvar_or_context_state",
+            "record",
+            "authority",
+        ]
+    }
+}
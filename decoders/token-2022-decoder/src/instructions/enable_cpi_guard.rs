@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +13,7 @@ pub struct EnableCpiGuard {
 pub struct EnableCpiGuardInstructionAccounts {
     pub token: solana_pubkey::Pubkey,
     pub owner: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for EnableCpiGuard {
@@ -19,13 +22,68 @@ impl carbon_core::deserialize::ArrangeAccounts for EnableCpiGuard {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [token, owner, _remaining @ ..] = accounts else {
+        let [token, owner, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(EnableCpiGuardInstructionAccounts {
             token: token.pubkey,
             owner: owner.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct EnableCpiGuardInstructionAccountMetas {
+    pub token: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for EnableCpiGuard {
+    type ArrangedAccountMetas = EnableCpiGuardInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [token, owner, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(EnableCpiGuardInstructionAccountMetas {
+            token: token.clone(),
+            owner: owner.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct EnableCpiGuardInstructionAccountIndices {
+    pub token: usize,
+    pub owner: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for EnableCpiGuard {
+    type ArrangedAccountIndices = EnableCpiGuardInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(EnableCpiGuardInstructionAccountIndices {
+            token: 0,
+            owner: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for EnableCpiGuard {
+    fn account_names() -> &'static [&'static str] {
+        &["token", "owner"]
+    }
+}
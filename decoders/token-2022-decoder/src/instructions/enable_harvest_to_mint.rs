@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +13,7 @@ pub struct EnableHarvestToMint {
 pub struct EnableHarvestToMintInstructionAccounts {
     pub mint: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for EnableHarvestToMint {
@@ -19,13 +22,68 @@ impl carbon_core::deserialize::ArrangeAccounts for EnableHarvestToMint {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [mint, authority, _remaining @ ..] = accounts else {
+        let [mint, authority, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(EnableHarvestToMintInstructionAccounts {
             mint: mint.pubkey,
             authority: authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct EnableHarvestToMintInstructionAccountMetas {
+    pub mint: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for EnableHarvestToMint {
+    type ArrangedAccountMetas = EnableHarvestToMintInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [mint, authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(EnableHarvestToMintInstructionAccountMetas {
+            mint: mint.clone(),
+            authority: authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct EnableHarvestToMintInstructionAccountIndices {
+    pub mint: usize,
+    pub authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for EnableHarvestToMint {
+    type ArrangedAccountIndices = EnableHarvestToMintInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(EnableHarvestToMintInstructionAccountIndices {
+            mint: 0,
+            authority: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for EnableHarvestToMint {
+    fn account_names() -> &'static [&'static str] {
+        &["mint", "authority"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +13,7 @@ pub struct EnableMemoTransfers {
 pub struct EnableMemoTransfersInstructionAccounts {
     pub token: solana_pubkey::Pubkey,
     pub owner: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for EnableMemoTransfers {
@@ -19,13 +22,68 @@ impl carbon_core::deserialize::ArrangeAccounts for EnableMemoTransfers {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [token, owner, _remaining @ ..] = accounts else {
+        let [token, owner, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(EnableMemoTransfersInstructionAccounts {
             token: token.pubkey,
             owner: owner.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct EnableMemoTransfersInstructionAccountMetas {
+    pub token: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for EnableMemoTransfers {
+    type ArrangedAccountMetas = EnableMemoTransfersInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [token, owner, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(EnableMemoTransfersInstructionAccountMetas {
+            token: token.clone(),
+            owner: owner.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct EnableMemoTransfersInstructionAccountIndices {
+    pub token: usize,
+    pub owner: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for EnableMemoTransfers {
+    type ArrangedAccountIndices = EnableMemoTransfersInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(EnableMemoTransfersInstructionAccountIndices {
+            token: 0,
+            owner: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for EnableMemoTransfers {
+    fn account_names() -> &'static [&'static str] {
+        &["token", "owner"]
+    }
+}
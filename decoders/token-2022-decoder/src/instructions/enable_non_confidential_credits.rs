@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +13,7 @@ pub struct EnableNonConfidentialCredits {
 pub struct EnableNonConfidentialCreditsInstructionAccounts {
     pub token: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for EnableNonConfidentialCredits {
@@ -19,13 +22,68 @@ impl carbon_core::deserialize::ArrangeAccounts for EnableNonConfidentialCredits
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [token, authority, _remaining @ ..] = accounts else {
+        let [token, authority, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(EnableNonConfidentialCreditsInstructionAccounts {
             token: token.pubkey,
             authority: authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct EnableNonConfidentialCreditsInstructionAccountMetas {
+    pub token: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for EnableNonConfidentialCredits {
+    type ArrangedAccountMetas = EnableNonConfidentialCreditsInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [token, authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(EnableNonConfidentialCreditsInstructionAccountMetas {
+            token: token.clone(),
+            authority: authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct EnableNonConfidentialCreditsInstructionAccountIndices {
+    pub token: usize,
+    pub authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for EnableNonConfidentialCredits {
+    type ArrangedAccountIndices = EnableNonConfidentialCreditsInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(EnableNonConfidentialCreditsInstructionAccountIndices {
+            token: 0,
+            authority: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for EnableNonConfidentialCredits {
+    fn account_names() -> &'static [&'static str] {
+        &["token", "authority"]
+    }
+}
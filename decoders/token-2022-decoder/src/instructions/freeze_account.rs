@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -10,6 +12,7 @@ pub struct FreezeAccountInstructionAccounts {
     pub account: solana_pubkey::Pubkey,
     pub mint: solana_pubkey::Pubkey,
     pub owner: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for FreezeAccount {
@@ -18,7 +21,7 @@ impl carbon_core::deserialize::ArrangeAccounts for FreezeAccount {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [account, mint, owner, _remaining @ ..] = accounts else {
+        let [account, mint, owner, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -26,6 +29,65 @@ impl carbon_core::deserialize::ArrangeAccounts for FreezeAccount {
             account: account.pubkey,
             mint: mint.pubkey,
             owner: owner.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct FreezeAccountInstructionAccountMetas {
+    pub account: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for FreezeAccount {
+    type ArrangedAccountMetas = FreezeAccountInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [account, mint, owner, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(FreezeAccountInstructionAccountMetas {
+            account: account.clone(),
+            mint: mint.clone(),
+            owner: owner.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct FreezeAccountInstructionAccountIndices {
+    pub account: usize,
+    pub mint: usize,
+    pub owner: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for FreezeAccount {
+    type ArrangedAccountIndices = FreezeAccountInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(FreezeAccountInstructionAccountIndices {
+            account: 0,
+            mint: 1,
+            owner: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for FreezeAccount {
+    fn account_names() -> &'static [&'static str] {
+        &["account", "mint", "owner"]
+    }
+}
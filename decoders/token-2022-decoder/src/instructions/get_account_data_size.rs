@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -8,6 +10,7 @@ pub struct GetAccountDataSize {}
 
 pub struct GetAccountDataSizeInstructionAccounts {
     pub mint: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for GetAccountDataSize {
@@ -16,10 +19,63 @@ impl carbon_core::deserialize::ArrangeAccounts for GetAccountDataSize {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [mint, _remaining @ ..] = accounts else {
+        let [mint, remaining @ ..] = accounts else {
             return None;
         };
 
-        Some(GetAccountDataSizeInstructionAccounts { mint: mint.pubkey })
+        Some(GetAccountDataSizeInstructionAccounts {
+            mint: mint.pubkey,
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct GetAccountDataSizeInstructionAccountMetas {
+    pub mint: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for GetAccountDataSize {
+    type ArrangedAccountMetas = GetAccountDataSizeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [mint, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(GetAccountDataSizeInstructionAccountMetas {
+            mint: mint.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct GetAccountDataSizeInstructionAccountIndices {
+    pub mint: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for GetAccountDataSize {
+    type ArrangedAccountIndices = GetAccountDataSizeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.is_empty() {
+            return None;
+        }
+
+        Some(GetAccountDataSizeInstructionAccountIndices {
+            mint: 0,
+            remaining_accounts: 1..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for GetAccountDataSize {
+    fn account_names() -> &'static [&'static str] {
+        &["mint"]
     }
 }
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -10,6 +12,7 @@ pub struct HarvestWithheldTokensToMint {
 
 pub struct HarvestWithheldTokensToMintInstructionAccounts {
     pub mint: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for HarvestWithheldTokensToMint {
@@ -18,10 +21,63 @@ impl carbon_core::deserialize::ArrangeAccounts for HarvestWithheldTokensToMint {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [mint, _remaining @ ..] = accounts else {
+        let [mint, remaining @ ..] = accounts else {
             return None;
         };
 
-        Some(HarvestWithheldTokensToMintInstructionAccounts { mint: mint.pubkey })
+        Some(HarvestWithheldTokensToMintInstructionAccounts {
+            mint: mint.pubkey,
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct HarvestWithheldTokensToMintInstructionAccountMetas {
+    pub mint: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for HarvestWithheldTokensToMint {
+    type ArrangedAccountMetas = HarvestWithheldTokensToMintInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [mint, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(HarvestWithheldTokensToMintInstructionAccountMetas {
+            mint: mint.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct HarvestWithheldTokensToMintInstructionAccountIndices {
+    pub mint: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for HarvestWithheldTokensToMint {
+    type ArrangedAccountIndices = HarvestWithheldTokensToMintInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.is_empty() {
+            return None;
+        }
+
+        Some(HarvestWithheldTokensToMintInstructionAccountIndices {
+            mint: 0,
+            remaining_accounts: 1..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for HarvestWithheldTokensToMint {
+    fn account_names() -> &'static [&'static str] {
+        &["mint"]
     }
 }
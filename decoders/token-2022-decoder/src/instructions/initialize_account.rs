@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +13,7 @@ pub struct InitializeAccountInstructionAccounts {
     pub mint: solana_pubkey::Pubkey,
     pub owner: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeAccount {
@@ -19,7 +22,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeAccount {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [account, mint, owner, rent, _remaining @ ..] = accounts else {
+        let [account, mint, owner, rent, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +31,69 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeAccount {
             mint: mint.pubkey,
             owner: owner.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeAccountInstructionAccountMetas {
+    pub account: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeAccount {
+    type ArrangedAccountMetas = InitializeAccountInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [account, mint, owner, rent, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(InitializeAccountInstructionAccountMetas {
+            account: account.clone(),
+            mint: mint.clone(),
+            owner: owner.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeAccountInstructionAccountIndices {
+    pub account: usize,
+    pub mint: usize,
+    pub owner: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeAccount {
+    type ArrangedAccountIndices = InitializeAccountInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(InitializeAccountInstructionAccountIndices {
+            account: 0,
+            mint: 1,
+            owner: 2,
+            rent: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeAccount {
+    fn account_names() -> &'static [&'static str] {
+        &["account", "mint", "owner", "rent"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +14,7 @@ pub struct InitializeAccount2InstructionAccounts {
     pub account: solana_pubkey::Pubkey,
     pub mint: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeAccount2 {
@@ -20,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeAccount2 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [account, mint, rent, _remaining @ ..] = accounts else {
+        let [account, mint, rent, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +31,65 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeAccount2 {
             account: account.pubkey,
             mint: mint.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeAccount2InstructionAccountMetas {
+    pub account: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeAccount2 {
+    type ArrangedAccountMetas = InitializeAccount2InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [account, mint, rent, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(InitializeAccount2InstructionAccountMetas {
+            account: account.clone(),
+            mint: mint.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeAccount2InstructionAccountIndices {
+    pub account: usize,
+    pub mint: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeAccount2 {
+    type ArrangedAccountIndices = InitializeAccount2InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(InitializeAccount2InstructionAccountIndices {
+            account: 0,
+            mint: 1,
+            rent: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeAccount2 {
+    fn account_names() -> &'static [&'static str] {
+        &["account", "mint", "rent"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +13,7 @@ pub struct InitializeAccount3 {
 pub struct InitializeAccount3InstructionAccounts {
     pub account: solana_pubkey::Pubkey,
     pub mint: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeAccount3 {
@@ -19,13 +22,68 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeAccount3 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [account, mint, _remaining @ ..] = accounts else {
+        let [account, mint, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(InitializeAccount3InstructionAccounts {
             account: account.pubkey,
             mint: mint.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeAccount3InstructionAccountMetas {
+    pub account: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeAccount3 {
+    type ArrangedAccountMetas = InitializeAccount3InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [account, mint, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(InitializeAccount3InstructionAccountMetas {
+            account: account.clone(),
+            mint: mint.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeAccount3InstructionAccountIndices {
+    pub account: usize,
+    pub mint: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeAccount3 {
+    type ArrangedAccountIndices = InitializeAccount3InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(InitializeAccount3InstructionAccountIndices {
+            account: 0,
+            mint: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeAccount3 {
+    fn account_names() -> &'static [&'static str] {
+        &["account", "mint"]
+    }
+}
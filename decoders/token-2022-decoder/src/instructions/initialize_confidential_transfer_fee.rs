@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +14,7 @@ pub struct InitializeConfidentialTransferFee {
 
 pub struct InitializeConfidentialTransferFeeInstructionAccounts {
     pub mint: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeConfidentialTransferFee {
@@ -20,10 +23,63 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeConfidentialTransfe
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [mint, _remaining @ ..] = accounts else {
+        let [mint, remaining @ ..] = accounts else {
             return None;
         };
 
-        Some(InitializeConfidentialTransferFeeInstructionAccounts { mint: mint.pubkey })
+        Some(InitializeConfidentialTransferFeeInstructionAccounts {
+            mint: mint.pubkey,
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeConfidentialTransferFeeInstructionAccountMetas {
+    pub mint: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeConfidentialTransferFee {
+    type ArrangedAccountMetas = InitializeConfidentialTransferFeeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [mint, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(InitializeConfidentialTransferFeeInstructionAccountMetas {
+            mint: mint.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeConfidentialTransferFeeInstructionAccountIndices {
+    pub mint: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeConfidentialTransferFee {
+    type ArrangedAccountIndices = InitializeConfidentialTransferFeeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.is_empty() {
+            return None;
+        }
+
+        Some(InitializeConfidentialTransferFeeInstructionAccountIndices {
+            mint: 0,
+            remaining_accounts: 1..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeConfidentialTransferFee {
+    fn account_names() -> &'static [&'static str] {
+        &["mint"]
     }
 }
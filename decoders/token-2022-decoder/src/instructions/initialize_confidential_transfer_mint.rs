@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +15,7 @@ pub struct InitializeConfidentialTransferMint {
 
 pub struct InitializeConfidentialTransferMintInstructionAccounts {
     pub mint: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeConfidentialTransferMint {
@@ -21,10 +24,65 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeConfidentialTransfe
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [mint, _remaining @ ..] = accounts else {
+        let [mint, remaining @ ..] = accounts else {
             return None;
         };
 
-        Some(InitializeConfidentialTransferMintInstructionAccounts { mint: mint.pubkey })
+        Some(InitializeConfidentialTransferMintInstructionAccounts {
+            mint: mint.pubkey,
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeConfidentialTransferMintInstructionAccountMetas {
+    pub mint: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeConfidentialTransferMint {
+    type ArrangedAccountMetas = InitializeConfidentialTransferMintInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [mint, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(InitializeConfidentialTransferMintInstructionAccountMetas {
+            mint: mint.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeConfidentialTransferMintInstructionAccountIndices {
+    pub mint: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeConfidentialTransferMint {
+    type ArrangedAccountIndices = InitializeConfidentialTransferMintInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.is_empty() {
+            return None;
+        }
+
+        Some(
+            InitializeConfidentialTransferMintInstructionAccountIndices {
+                mint: 0,
+                remaining_accounts: 1..accounts.len(),
+            },
+        )
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeConfidentialTransferMint {
+    fn account_names() -> &'static [&'static str] {
+        &["mint"]
     }
 }
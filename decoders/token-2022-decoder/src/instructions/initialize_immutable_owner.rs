@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -8,6 +10,7 @@ pub struct InitializeImmutableOwner {}
 
 pub struct InitializeImmutableOwnerInstructionAccounts {
     pub account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeImmutableOwner {
@@ -16,12 +19,63 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeImmutableOwner {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [account, _remaining @ ..] = accounts else {
+        let [account, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(InitializeImmutableOwnerInstructionAccounts {
             account: account.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeImmutableOwnerInstructionAccountMetas {
+    pub account: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeImmutableOwner {
+    type ArrangedAccountMetas = InitializeImmutableOwnerInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [account, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(InitializeImmutableOwnerInstructionAccountMetas {
+            account: account.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeImmutableOwnerInstructionAccountIndices {
+    pub account: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeImmutableOwner {
+    type ArrangedAccountIndices = InitializeImmutableOwnerInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.is_empty() {
+            return None;
+        }
+
+        Some(InitializeImmutableOwnerInstructionAccountIndices {
+            account: 0,
+            remaining_accounts: 1..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeImmutableOwner {
+    fn account_names() -> &'static [&'static str] {
+        &["account"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +13,7 @@ pub struct InitializeMultisig {
 pub struct InitializeMultisigInstructionAccounts {
     pub multisig: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeMultisig {
@@ -19,13 +22,68 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeMultisig {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [multisig, rent, _remaining @ ..] = accounts else {
+        let [multisig, rent, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(InitializeMultisigInstructionAccounts {
             multisig: multisig.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeMultisigInstructionAccountMetas {
+    pub multisig: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeMultisig {
+    type ArrangedAccountMetas = InitializeMultisigInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [multisig, rent, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(InitializeMultisigInstructionAccountMetas {
+            multisig: multisig.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeMultisigInstructionAccountIndices {
+    pub multisig: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeMultisig {
+    type ArrangedAccountIndices = InitializeMultisigInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(InitializeMultisigInstructionAccountIndices {
+            multisig: 0,
+            rent: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeMultisig {
+    fn account_names() -> &'static [&'static str] {
+        &["multisig", "rent"]
+    }
+}
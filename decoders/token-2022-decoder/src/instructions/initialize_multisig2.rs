@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -10,6 +12,7 @@ pub struct InitializeMultisig2 {
 
 pub struct InitializeMultisig2InstructionAccounts {
     pub multisig: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeMultisig2 {
@@ -18,12 +21,63 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeMultisig2 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [multisig, _remaining @ ..] = accounts else {
+        let [multisig, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(InitializeMultisig2InstructionAccounts {
             multisig: multisig.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeMultisig2InstructionAccountMetas {
+    pub multisig: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeMultisig2 {
+    type ArrangedAccountMetas = InitializeMultisig2InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [multisig, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(InitializeMultisig2InstructionAccountMetas {
+            multisig: multisig.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeMultisig2InstructionAccountIndices {
+    pub multisig: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeMultisig2 {
+    type ArrangedAccountIndices = InitializeMultisig2InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.is_empty() {
+            return None;
+        }
+
+        Some(InitializeMultisig2InstructionAccountIndices {
+            multisig: 0,
+            remaining_accounts: 1..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeMultisig2 {
+    fn account_names() -> &'static [&'static str] {
+        &["multisig"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +15,7 @@ pub struct InitializeTokenGroupInstructionAccounts {
     pub group: solana_pubkey::Pubkey,
     pub mint: solana_pubkey::Pubkey,
     pub mint_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeTokenGroup {
@@ -21,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeTokenGroup {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [group, mint, mint_authority, _remaining @ ..] = accounts else {
+        let [group, mint, mint_authority, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -29,6 +32,65 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeTokenGroup {
             group: group.pubkey,
             mint: mint.pubkey,
             mint_authority: mint_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeTokenGroupInstructionAccountMetas {
+    pub group: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub mint_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeTokenGroup {
+    type ArrangedAccountMetas = InitializeTokenGroupInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [group, mint, mint_authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(InitializeTokenGroupInstructionAccountMetas {
+            group: group.clone(),
+            mint: mint.clone(),
+            mint_authority: mint_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeTokenGroupInstructionAccountIndices {
+    pub group: usize,
+    pub mint: usize,
+    pub mint_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeTokenGroup {
+    type ArrangedAccountIndices = InitializeTokenGroupInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(InitializeTokenGroupInstructionAccountIndices {
+            group: 0,
+            mint: 1,
+            mint_authority: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeTokenGroup {
+    fn account_names() -> &'static [&'static str] {
+        &["group", "mint", "mint_authority"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +14,7 @@ pub struct InitializeTokenGroupMemberInstructionAccounts {
     pub member_mint_authority: solana_pubkey::Pubkey,
     pub group: solana_pubkey::Pubkey,
     pub group_update_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeTokenGroupMember {
@@ -20,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeTokenGroupMember {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [member, member_mint, member_mint_authority, group, group_update_authority, _remaining @ ..] =
+        let [member, member_mint, member_mint_authority, group, group_update_authority, remaining @ ..] =
             accounts
         else {
             return None;
@@ -32,6 +35,81 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeTokenGroupMember {
             member_mint_authority: member_mint_authority.pubkey,
             group: group.pubkey,
             group_update_authority: group_update_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeTokenGroupMemberInstructionAccountMetas {
+    pub member: solana_instruction::AccountMeta,
+    pub member_mint: solana_instruction::AccountMeta,
+    pub member_mint_authority: solana_instruction::AccountMeta,
+    pub group: solana_instruction::AccountMeta,
+    pub group_update_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeTokenGroupMember {
+    type ArrangedAccountMetas = InitializeTokenGroupMemberInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [member, member_mint, member_mint_authority, group, group_update_authority, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeTokenGroupMemberInstructionAccountMetas {
+            member: member.clone(),
+            member_mint: member_mint.clone(),
+            member_mint_authority: member_mint_authority.clone(),
+            group: group.clone(),
+            group_update_authority: group_update_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeTokenGroupMemberInstructionAccountIndices {
+    pub member: usize,
+    pub member_mint: usize,
+    pub member_mint_authority: usize,
+    pub group: usize,
+    pub group_update_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeTokenGroupMember {
+    type ArrangedAccountIndices = InitializeTokenGroupMemberInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(InitializeTokenGroupMemberInstructionAccountIndices {
+            member: 0,
+            member_mint: 1,
+            member_mint_authority: 2,
+            group: 3,
+            group_update_authority: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeTokenGroupMember {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "member",
+            "member_mint",
+            "member_mint_authority",
+            "group",
+            "group_update_authority",
+        ]
+    }
+}
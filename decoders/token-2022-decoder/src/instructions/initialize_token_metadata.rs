@@ -1,6 +1,8 @@
 use alloc::string::String;
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -16,6 +18,7 @@ pub struct InitializeTokenMetadataInstructionAccounts {
     pub update_authority: solana_pubkey::Pubkey,
     pub mint: solana_pubkey::Pubkey,
     pub mint_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeTokenMetadata {
@@ -24,7 +27,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeTokenMetadata {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [metadata, update_authority, mint, mint_authority, _remaining @ ..] = accounts else {
+        let [metadata, update_authority, mint, mint_authority, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -33,6 +36,69 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeTokenMetadata {
             update_authority: update_authority.pubkey,
             mint: mint.pubkey,
             mint_authority: mint_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeTokenMetadataInstructionAccountMetas {
+    pub metadata: solana_instruction::AccountMeta,
+    pub update_authority: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub mint_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeTokenMetadata {
+    type ArrangedAccountMetas = InitializeTokenMetadataInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [metadata, update_authority, mint, mint_authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(InitializeTokenMetadataInstructionAccountMetas {
+            metadata: metadata.clone(),
+            update_authority: update_authority.clone(),
+            mint: mint.clone(),
+            mint_authority: mint_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeTokenMetadataInstructionAccountIndices {
+    pub metadata: usize,
+    pub update_authority: usize,
+    pub mint: usize,
+    pub mint_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeTokenMetadata {
+    type ArrangedAccountIndices = InitializeTokenMetadataInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(InitializeTokenMetadataInstructionAccountIndices {
+            metadata: 0,
+            update_authority: 1,
+            mint: 2,
+            mint_authority: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeTokenMetadata {
+    fn account_names() -> &'static [&'static str] {
+        &["metadata", "update_authority", "mint", "mint_authority"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +14,7 @@ pub struct MintToInstructionAccounts {
     pub mint: solana_pubkey::Pubkey,
     pub token: solana_pubkey::Pubkey,
     pub mint_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for MintTo {
@@ -20,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for MintTo {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [mint, token, mint_authority, _remaining @ ..] = accounts else {
+        let [mint, token, mint_authority, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +31,65 @@ impl carbon_core::deserialize::ArrangeAccounts for MintTo {
             mint: mint.pubkey,
             token: token.pubkey,
             mint_authority: mint_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct MintToInstructionAccountMetas {
+    pub mint: solana_instruction::AccountMeta,
+    pub token: solana_instruction::AccountMeta,
+    pub mint_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for MintTo {
+    type ArrangedAccountMetas = MintToInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [mint, token, mint_authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(MintToInstructionAccountMetas {
+            mint: mint.clone(),
+            token: token.clone(),
+            mint_authority: mint_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct MintToInstructionAccountIndices {
+    pub mint: usize,
+    pub token: usize,
+    pub mint_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for MintTo {
+    type ArrangedAccountIndices = MintToInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(MintToInstructionAccountIndices {
+            mint: 0,
+            token: 1,
+            mint_authority: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for MintTo {
+    fn account_names() -> &'static [&'static str] {
+        &["mint", "token", "mint_authority"]
+    }
+}
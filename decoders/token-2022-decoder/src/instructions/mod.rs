@@ -182,15 +182,25 @@ pub enum Token2022Instruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for Token2022Decoder {
     type InstructionType = Token2022Instruction;
 
-    fn decode_instruction(
+    fn decoder_name(&self) -> &'static str {
+        "token2022"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[super::PROGRAM_ID]
+    }
+
+    fn try_decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
-    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+    ) -> carbon_core::error::CarbonResult<
+        Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>>,
+    > {
         if !instruction.program_id.eq(&PROGRAM_ID) {
-            return None;
+            return Ok(None);
         }
 
-        carbon_core::try_decode_instructions!(instruction,
+        Ok(carbon_core::try_decode_instructions!(instruction,
             Token2022Instruction::InitializeMint => initialize_mint::InitializeMint,
             Token2022Instruction::InitializeAccount => initialize_account::InitializeAccount,
             Token2022Instruction::InitializeMultisig => initialize_multisig::InitializeMultisig,
@@ -273,6 +283,6 @@ impl carbon_core::instruction::InstructionDecoder<'_> for Token2022Decoder {
             Token2022Instruction::UpdateTokenGroupMaxSize => update_token_group_max_size::UpdateTokenGroupMaxSize,
             Token2022Instruction::UpdateTokenGroupUpdateAuthority => update_token_group_update_authority::UpdateTokenGroupUpdateAuthority,
             Token2022Instruction::InitializeTokenGroupMember => initialize_token_group_member::InitializeTokenGroupMember,
-        )
+        ))
     }
 }
@@ -4,6 +4,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -17,6 +18,7 @@ pub struct ReallocateInstructionAccounts {
     pub payer: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub owner: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Reallocate {
@@ -25,7 +27,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Reallocate {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [token, payer, system_program, owner, _remaining @ ..] = accounts else {
+        let [token, payer, system_program, owner, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -34,6 +36,69 @@ impl carbon_core::deserialize::ArrangeAccounts for Reallocate {
             payer: payer.pubkey,
             system_program: system_program.pubkey,
             owner: owner.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ReallocateInstructionAccountMetas {
+    pub token: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Reallocate {
+    type ArrangedAccountMetas = ReallocateInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [token, payer, system_program, owner, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(ReallocateInstructionAccountMetas {
+            token: token.clone(),
+            payer: payer.clone(),
+            system_program: system_program.clone(),
+            owner: owner.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ReallocateInstructionAccountIndices {
+    pub token: usize,
+    pub payer: usize,
+    pub system_program: usize,
+    pub owner: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Reallocate {
+    type ArrangedAccountIndices = ReallocateInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(ReallocateInstructionAccountIndices {
+            token: 0,
+            payer: 1,
+            system_program: 2,
+            owner: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Reallocate {
+    fn account_names() -> &'static [&'static str] {
+        &["token", "payer", "system_program", "owner"]
+    }
+}
@@ -1,6 +1,8 @@
 use alloc::string::String;
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +15,7 @@ pub struct RemoveTokenMetadataKey {
 pub struct RemoveTokenMetadataKeyInstructionAccounts {
     pub metadata: solana_pubkey::Pubkey,
     pub update_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for RemoveTokenMetadataKey {
@@ -21,13 +24,68 @@ impl carbon_core::deserialize::ArrangeAccounts for RemoveTokenMetadataKey {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [metadata, update_authority, _remaining @ ..] = accounts else {
+        let [metadata, update_authority, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(RemoveTokenMetadataKeyInstructionAccounts {
             metadata: metadata.pubkey,
             update_authority: update_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct RemoveTokenMetadataKeyInstructionAccountMetas {
+    pub metadata: solana_instruction::AccountMeta,
+    pub update_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for RemoveTokenMetadataKey {
+    type ArrangedAccountMetas = RemoveTokenMetadataKeyInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [metadata, update_authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(RemoveTokenMetadataKeyInstructionAccountMetas {
+            metadata: metadata.clone(),
+            update_authority: update_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct RemoveTokenMetadataKeyInstructionAccountIndices {
+    pub metadata: usize,
+    pub update_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for RemoveTokenMetadataKey {
+    type ArrangedAccountIndices = RemoveTokenMetadataKeyInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(RemoveTokenMetadataKeyInstructionAccountIndices {
+            metadata: 0,
+            update_authority: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for RemoveTokenMetadataKey {
+    fn account_names() -> &'static [&'static str] {
+        &["metadata", "update_authority"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -9,6 +11,7 @@ pub struct Revoke {}
 pub struct RevokeInstructionAccounts {
     pub source: solana_pubkey::Pubkey,
     pub owner: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Revoke {
@@ -17,13 +20,68 @@ impl carbon_core::deserialize::ArrangeAccounts for Revoke {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [source, owner, _remaining @ ..] = accounts else {
+        let [source, owner, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(RevokeInstructionAccounts {
             source: source.pubkey,
             owner: owner.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct RevokeInstructionAccountMetas {
+    pub source: solana_instruction::AccountMeta,
+    pub owner: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Revoke {
+    type ArrangedAccountMetas = RevokeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [source, owner, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(RevokeInstructionAccountMetas {
+            source: source.clone(),
+            owner: owner.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct RevokeInstructionAccountIndices {
+    pub source: usize,
+    pub owner: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Revoke {
+    type ArrangedAccountIndices = RevokeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(RevokeInstructionAccountIndices {
+            source: 0,
+            owner: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Revoke {
+    fn account_names() -> &'static [&'static str] {
+        &["source", "owner"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +15,7 @@ pub struct SetTransferFee {
 pub struct SetTransferFeeInstructionAccounts {
     pub mint: solana_pubkey::Pubkey,
     pub transfer_fee_config_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SetTransferFee {
@@ -21,13 +24,68 @@ impl carbon_core::deserialize::ArrangeAccounts for SetTransferFee {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [mint, transfer_fee_config_authority, _remaining @ ..] = accounts else {
+        let [mint, transfer_fee_config_authority, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(SetTransferFeeInstructionAccounts {
             mint: mint.pubkey,
             transfer_fee_config_authority: transfer_fee_config_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SetTransferFeeInstructionAccountMetas {
+    pub mint: solana_instruction::AccountMeta,
+    pub transfer_fee_config_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SetTransferFee {
+    type ArrangedAccountMetas = SetTransferFeeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [mint, transfer_fee_config_authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(SetTransferFeeInstructionAccountMetas {
+            mint: mint.clone(),
+            transfer_fee_config_authority: transfer_fee_config_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SetTransferFeeInstructionAccountIndices {
+    pub mint: usize,
+    pub transfer_fee_config_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SetTransferFee {
+    type ArrangedAccountIndices = SetTransferFeeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(SetTransferFeeInstructionAccountIndices {
+            mint: 0,
+            transfer_fee_config_authority: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SetTransferFee {
+    fn account_names() -> &'static [&'static str] {
+        &["mint", "transfer_fee_config_authority"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -9,12 +11,13 @@ pub struct TransferChecked {
     pub decimals: u8,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Hash, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
 pub struct TransferCheckedInstructionAccounts {
     pub source: solana_pubkey::Pubkey,
     pub mint: solana_pubkey::Pubkey,
     pub destination: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for TransferChecked {
@@ -23,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for TransferChecked {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [source, mint, destination, authority, _remaining @ ..] = accounts else {
+        let [source, mint, destination, authority, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -32,6 +35,71 @@ impl carbon_core::deserialize::ArrangeAccounts for TransferChecked {
             mint: mint.pubkey,
             destination: destination.pubkey,
             authority: authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
+pub struct TransferCheckedInstructionAccountMetas {
+    pub source: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub destination: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for TransferChecked {
+    type ArrangedAccountMetas = TransferCheckedInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [source, mint, destination, authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(TransferCheckedInstructionAccountMetas {
+            source: source.clone(),
+            mint: mint.clone(),
+            destination: destination.clone(),
+            authority: authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
+pub struct TransferCheckedInstructionAccountIndices {
+    pub source: usize,
+    pub mint: usize,
+    pub destination: usize,
+    pub authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for TransferChecked {
+    type ArrangedAccountIndices = TransferCheckedInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(TransferCheckedInstructionAccountIndices {
+            source: 0,
+            mint: 1,
+            destination: 2,
+            authority: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for TransferChecked {
+    fn account_names() -> &'static [&'static str] {
+        &["source", "mint", "destination", "authority"]
+    }
+}
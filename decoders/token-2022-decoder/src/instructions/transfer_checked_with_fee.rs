@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -16,6 +18,7 @@ pub struct TransferCheckedWithFeeInstructionAccounts {
     pub mint: solana_pubkey::Pubkey,
     pub destination: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for TransferCheckedWithFee {
@@ -24,7 +27,7 @@ impl carbon_core::deserialize::ArrangeAccounts for TransferCheckedWithFee {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [source, mint, destination, authority, _remaining @ ..] = accounts else {
+        let [source, mint, destination, authority, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -33,6 +36,69 @@ impl carbon_core::deserialize::ArrangeAccounts for TransferCheckedWithFee {
             mint: mint.pubkey,
             destination: destination.pubkey,
             authority: authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct TransferCheckedWithFeeInstructionAccountMetas {
+    pub source: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub destination: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for TransferCheckedWithFee {
+    type ArrangedAccountMetas = TransferCheckedWithFeeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [source, mint, destination, authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(TransferCheckedWithFeeInstructionAccountMetas {
+            source: source.clone(),
+            mint: mint.clone(),
+            destination: destination.clone(),
+            authority: authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct TransferCheckedWithFeeInstructionAccountIndices {
+    pub source: usize,
+    pub mint: usize,
+    pub destination: usize,
+    pub authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for TransferCheckedWithFee {
+    type ArrangedAccountIndices = TransferCheckedWithFeeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(TransferCheckedWithFeeInstructionAccountIndices {
+            source: 0,
+            mint: 1,
+            destination: 2,
+            authority: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for TransferCheckedWithFee {
+    fn account_names() -> &'static [&'static str] {
+        &["source", "mint", "destination", "authority"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +15,7 @@ pub struct UpdateConfidentialTransferMint {
 pub struct UpdateConfidentialTransferMintInstructionAccounts {
     pub mint: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateConfidentialTransferMint {
@@ -21,13 +24,68 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateConfidentialTransferMin
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [mint, authority, _remaining @ ..] = accounts else {
+        let [mint, authority, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(UpdateConfidentialTransferMintInstructionAccounts {
             mint: mint.pubkey,
             authority: authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateConfidentialTransferMintInstructionAccountMetas {
+    pub mint: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateConfidentialTransferMint {
+    type ArrangedAccountMetas = UpdateConfidentialTransferMintInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [mint, authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateConfidentialTransferMintInstructionAccountMetas {
+            mint: mint.clone(),
+            authority: authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateConfidentialTransferMintInstructionAccountIndices {
+    pub mint: usize,
+    pub authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateConfidentialTransferMint {
+    type ArrangedAccountIndices = UpdateConfidentialTransferMintInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(UpdateConfidentialTransferMintInstructionAccountIndices {
+            mint: 0,
+            authority: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateConfidentialTransferMint {
+    fn account_names() -> &'static [&'static str] {
+        &["mint", "authority"]
+    }
+}
@@ -1,8 +1,10 @@
+use alloc::vec::Vec;
 use {
     super::super::types::*,
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -15,6 +17,7 @@ pub struct UpdateDefaultAccountState {
 pub struct UpdateDefaultAccountStateInstructionAccounts {
     pub mint: solana_pubkey::Pubkey,
     pub freeze_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateDefaultAccountState {
@@ -23,13 +26,68 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateDefaultAccountState {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [mint, freeze_authority, _remaining @ ..] = accounts else {
+        let [mint, freeze_authority, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(UpdateDefaultAccountStateInstructionAccounts {
             mint: mint.pubkey,
             freeze_authority: freeze_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateDefaultAccountStateInstructionAccountMetas {
+    pub mint: solana_instruction::AccountMeta,
+    pub freeze_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateDefaultAccountState {
+    type ArrangedAccountMetas = UpdateDefaultAccountStateInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [mint, freeze_authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateDefaultAccountStateInstructionAccountMetas {
+            mint: mint.clone(),
+            freeze_authority: freeze_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateDefaultAccountStateInstructionAccountIndices {
+    pub mint: usize,
+    pub freeze_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateDefaultAccountState {
+    type ArrangedAccountIndices = UpdateDefaultAccountStateInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(UpdateDefaultAccountStateInstructionAccountIndices {
+            mint: 0,
+            freeze_authority: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateDefaultAccountState {
+    fn account_names() -> &'static [&'static str] {
+        &["mint", "freeze_authority"]
+    }
+}
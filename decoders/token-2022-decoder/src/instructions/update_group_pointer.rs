@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +14,7 @@ pub struct UpdateGroupPointer {
 pub struct UpdateGroupPointerInstructionAccounts {
     pub mint: solana_pubkey::Pubkey,
     pub group_pointer_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateGroupPointer {
@@ -20,13 +23,68 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateGroupPointer {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [mint, group_pointer_authority, _remaining @ ..] = accounts else {
+        let [mint, group_pointer_authority, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(UpdateGroupPointerInstructionAccounts {
             mint: mint.pubkey,
             group_pointer_authority: group_pointer_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateGroupPointerInstructionAccountMetas {
+    pub mint: solana_instruction::AccountMeta,
+    pub group_pointer_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateGroupPointer {
+    type ArrangedAccountMetas = UpdateGroupPointerInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [mint, group_pointer_authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateGroupPointerInstructionAccountMetas {
+            mint: mint.clone(),
+            group_pointer_authority: group_pointer_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateGroupPointerInstructionAccountIndices {
+    pub mint: usize,
+    pub group_pointer_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateGroupPointer {
+    type ArrangedAccountIndices = UpdateGroupPointerInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(UpdateGroupPointerInstructionAccountIndices {
+            mint: 0,
+            group_pointer_authority: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateGroupPointer {
+    fn account_names() -> &'static [&'static str] {
+        &["mint", "group_pointer_authority"]
+    }
+}
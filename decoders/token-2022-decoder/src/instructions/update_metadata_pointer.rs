@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +14,7 @@ pub struct UpdateMetadataPointer {
 pub struct UpdateMetadataPointerInstructionAccounts {
     pub mint: solana_pubkey::Pubkey,
     pub metadata_pointer_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateMetadataPointer {
@@ -20,13 +23,68 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateMetadataPointer {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [mint, metadata_pointer_authority, _remaining @ ..] = accounts else {
+        let [mint, metadata_pointer_authority, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(UpdateMetadataPointerInstructionAccounts {
             mint: mint.pubkey,
             metadata_pointer_authority: metadata_pointer_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateMetadataPointerInstructionAccountMetas {
+    pub mint: solana_instruction::AccountMeta,
+    pub metadata_pointer_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateMetadataPointer {
+    type ArrangedAccountMetas = UpdateMetadataPointerInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [mint, metadata_pointer_authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateMetadataPointerInstructionAccountMetas {
+            mint: mint.clone(),
+            metadata_pointer_authority: metadata_pointer_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateMetadataPointerInstructionAccountIndices {
+    pub mint: usize,
+    pub metadata_pointer_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateMetadataPointer {
+    type ArrangedAccountIndices = UpdateMetadataPointerInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(UpdateMetadataPointerInstructionAccountIndices {
+            mint: 0,
+            metadata_pointer_authority: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateMetadataPointer {
+    fn account_names() -> &'static [&'static str] {
+        &["mint", "metadata_pointer_authority"]
+    }
+}
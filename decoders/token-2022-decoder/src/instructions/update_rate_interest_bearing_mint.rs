@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +14,7 @@ pub struct UpdateRateInterestBearingMint {
 pub struct UpdateRateInterestBearingMintInstructionAccounts {
     pub mint: solana_pubkey::Pubkey,
     pub rate_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateRateInterestBearingMint {
@@ -20,13 +23,68 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateRateInterestBearingMint
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [mint, rate_authority, _remaining @ ..] = accounts else {
+        let [mint, rate_authority, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(UpdateRateInterestBearingMintInstructionAccounts {
             mint: mint.pubkey,
             rate_authority: rate_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateRateInterestBearingMintInstructionAccountMetas {
+    pub mint: solana_instruction::AccountMeta,
+    pub rate_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateRateInterestBearingMint {
+    type ArrangedAccountMetas = UpdateRateInterestBearingMintInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [mint, rate_authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateRateInterestBearingMintInstructionAccountMetas {
+            mint: mint.clone(),
+            rate_authority: rate_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateRateInterestBearingMintInstructionAccountIndices {
+    pub mint: usize,
+    pub rate_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateRateInterestBearingMint {
+    type ArrangedAccountIndices = UpdateRateInterestBearingMintInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(UpdateRateInterestBearingMintInstructionAccountIndices {
+            mint: 0,
+            rate_authority: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateRateInterestBearingMint {
+    fn account_names() -> &'static [&'static str] {
+        &["mint", "rate_authority"]
+    }
+}
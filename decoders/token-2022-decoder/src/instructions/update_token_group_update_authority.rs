@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +13,7 @@ pub struct UpdateTokenGroupUpdateAuthority {
 pub struct UpdateTokenGroupUpdateAuthorityInstructionAccounts {
     pub group: solana_pubkey::Pubkey,
     pub update_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateTokenGroupUpdateAuthority {
@@ -19,13 +22,68 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateTokenGroupUpdateAuthori
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [group, update_authority, _remaining @ ..] = accounts else {
+        let [group, update_authority, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(UpdateTokenGroupUpdateAuthorityInstructionAccounts {
             group: group.pubkey,
             update_authority: update_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateTokenGroupUpdateAuthorityInstructionAccountMetas {
+    pub group: solana_instruction::AccountMeta,
+    pub update_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateTokenGroupUpdateAuthority {
+    type ArrangedAccountMetas = UpdateTokenGroupUpdateAuthorityInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [group, update_authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateTokenGroupUpdateAuthorityInstructionAccountMetas {
+            group: group.clone(),
+            update_authority: update_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateTokenGroupUpdateAuthorityInstructionAccountIndices {
+    pub group: usize,
+    pub update_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateTokenGroupUpdateAuthority {
+    type ArrangedAccountIndices = UpdateTokenGroupUpdateAuthorityInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(UpdateTokenGroupUpdateAuthorityInstructionAccountIndices {
+            group: 0,
+            update_authority: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateTokenGroupUpdateAuthority {
+    fn account_names() -> &'static [&'static str] {
+        &["group", "update_authority"]
+    }
+}
@@ -1,9 +1,11 @@
+use alloc::vec::Vec;
 use {
     super::super::types::*,
     alloc::string::String,
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -16,6 +18,7 @@ pub struct UpdateTokenMetadataField {
 pub struct UpdateTokenMetadataFieldInstructionAccounts {
     pub metadata: solana_pubkey::Pubkey,
     pub update_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateTokenMetadataField {
@@ -24,13 +27,68 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateTokenMetadataField {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [metadata, update_authority, _remaining @ ..] = accounts else {
+        let [metadata, update_authority, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(UpdateTokenMetadataFieldInstructionAccounts {
             metadata: metadata.pubkey,
             update_authority: update_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateTokenMetadataFieldInstructionAccountMetas {
+    pub metadata: solana_instruction::AccountMeta,
+    pub update_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateTokenMetadataField {
+    type ArrangedAccountMetas = UpdateTokenMetadataFieldInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [metadata, update_authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateTokenMetadataFieldInstructionAccountMetas {
+            metadata: metadata.clone(),
+            update_authority: update_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateTokenMetadataFieldInstructionAccountIndices {
+    pub metadata: usize,
+    pub update_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateTokenMetadataField {
+    type ArrangedAccountIndices = UpdateTokenMetadataFieldInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(UpdateTokenMetadataFieldInstructionAccountIndices {
+            metadata: 0,
+            update_authority: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateTokenMetadataField {
+    fn account_names() -> &'static [&'static str] {
+        &["metadata", "update_authority"]
+    }
+}
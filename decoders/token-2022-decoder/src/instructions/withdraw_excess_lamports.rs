@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -10,6 +12,7 @@ pub struct WithdrawExcessLamportsInstructionAccounts {
     pub source_account: solana_pubkey::Pubkey,
     pub destination_account: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for WithdrawExcessLamports {
@@ -18,7 +21,7 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawExcessLamports {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [source_account, destination_account, authority, _remaining @ ..] = accounts else {
+        let [source_account, destination_account, authority, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -26,6 +29,65 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawExcessLamports {
             source_account: source_account.pubkey,
             destination_account: destination_account.pubkey,
             authority: authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct WithdrawExcessLamportsInstructionAccountMetas {
+    pub source_account: solana_instruction::AccountMeta,
+    pub destination_account: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for WithdrawExcessLamports {
+    type ArrangedAccountMetas = WithdrawExcessLamportsInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [source_account, destination_account, authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(WithdrawExcessLamportsInstructionAccountMetas {
+            source_account: source_account.clone(),
+            destination_account: destination_account.clone(),
+            authority: authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct WithdrawExcessLamportsInstructionAccountIndices {
+    pub source_account: usize,
+    pub destination_account: usize,
+    pub authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for WithdrawExcessLamports {
+    type ArrangedAccountIndices = WithdrawExcessLamportsInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(WithdrawExcessLamportsInstructionAccountIndices {
+            source_account: 0,
+            destination_account: 1,
+            authority: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for WithdrawExcessLamports {
+    fn account_names() -> &'static [&'static str] {
+        &["source_account", "destination_account", "authority"]
+    }
+}
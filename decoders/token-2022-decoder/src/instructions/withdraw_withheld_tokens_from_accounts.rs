@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +15,7 @@ pub struct WithdrawWithheldTokensFromAccountsInstructionAccounts {
     pub mint: solana_pubkey::Pubkey,
     pub fee_receiver: solana_pubkey::Pubkey,
     pub withdraw_withheld_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for WithdrawWithheldTokensFromAccounts {
@@ -21,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawWithheldTokensFromAcc
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [mint, fee_receiver, withdraw_withheld_authority, _remaining @ ..] = accounts else {
+        let [mint, fee_receiver, withdraw_withheld_authority, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -29,6 +32,67 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawWithheldTokensFromAcc
             mint: mint.pubkey,
             fee_receiver: fee_receiver.pubkey,
             withdraw_withheld_authority: withdraw_withheld_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct WithdrawWithheldTokensFromAccountsInstructionAccountMetas {
+    pub mint: solana_instruction::AccountMeta,
+    pub fee_receiver: solana_instruction::AccountMeta,
+    pub withdraw_withheld_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for WithdrawWithheldTokensFromAccounts {
+    type ArrangedAccountMetas = WithdrawWithheldTokensFromAccountsInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [mint, fee_receiver, withdraw_withheld_authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(WithdrawWithheldTokensFromAccountsInstructionAccountMetas {
+            mint: mint.clone(),
+            fee_receiver: fee_receiver.clone(),
+            withdraw_withheld_authority: withdraw_withheld_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct WithdrawWithheldTokensFromAccountsInstructionAccountIndices {
+    pub mint: usize,
+    pub fee_receiver: usize,
+    pub withdraw_withheld_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for WithdrawWithheldTokensFromAccounts {
+    type ArrangedAccountIndices = WithdrawWithheldTokensFromAccountsInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(
+            WithdrawWithheldTokensFromAccountsInstructionAccountIndices {
+                mint: 0,
+                fee_receiver: 1,
+                withdraw_withheld_authority: 2,
+                remaining_accounts: 3..accounts.len(),
+            },
+        )
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for WithdrawWithheldTokensFromAccounts {
+    fn account_names() -> &'static [&'static str] {
+        &["mint", "fee_receiver", "withdraw_withheld_authority"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +14,7 @@ pub struct WithdrawWithheldTokensFromMintInstructionAccounts {
     pub mint: solana_pubkey::Pubkey,
     pub fee_receiver: solana_pubkey::Pubkey,
     pub withdraw_withheld_authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for WithdrawWithheldTokensFromMint {
@@ -20,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawWithheldTokensFromMin
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [mint, fee_receiver, withdraw_withheld_authority, _remaining @ ..] = accounts else {
+        let [mint, fee_receiver, withdraw_withheld_authority, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +31,65 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawWithheldTokensFromMin
             mint: mint.pubkey,
             fee_receiver: fee_receiver.pubkey,
             withdraw_withheld_authority: withdraw_withheld_authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct WithdrawWithheldTokensFromMintInstructionAccountMetas {
+    pub mint: solana_instruction::AccountMeta,
+    pub fee_receiver: solana_instruction::AccountMeta,
+    pub withdraw_withheld_authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for WithdrawWithheldTokensFromMint {
+    type ArrangedAccountMetas = WithdrawWithheldTokensFromMintInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [mint, fee_receiver, withdraw_withheld_authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(WithdrawWithheldTokensFromMintInstructionAccountMetas {
+            mint: mint.clone(),
+            fee_receiver: fee_receiver.clone(),
+            withdraw_withheld_authority: withdraw_withheld_authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct WithdrawWithheldTokensFromMintInstructionAccountIndices {
+    pub mint: usize,
+    pub fee_receiver: usize,
+    pub withdraw_withheld_authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for WithdrawWithheldTokensFromMint {
+    type ArrangedAccountIndices = WithdrawWithheldTokensFromMintInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(WithdrawWithheldTokensFromMintInstructionAccountIndices {
+            mint: 0,
+            fee_receiver: 1,
+            withdraw_withheld_authority: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for WithdrawWithheldTokensFromMint {
+    fn account_names() -> &'static [&'static str] {
+        &["mint", "fee_receiver", "withdraw_withheld_authority"]
+    }
+}
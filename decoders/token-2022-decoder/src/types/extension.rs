@@ -4,6 +4,7 @@ use {
     carbon_core::{borsh, CarbonDeserialize},
 };
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -116,4 +117,8 @@ pub enum Extension {
         group: solana_pubkey::Pubkey,
         member_number: u64,
     },
+    Unknown {
+        type_id: u16,
+        data: Vec<u8>,
+    },
 }
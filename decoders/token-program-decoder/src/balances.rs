@@ -0,0 +1,386 @@
+//! Reconstructs the net token balance changes of a transaction from its SPL
+//! Token instructions and checks them against the token balances reported in
+//! the transaction's status metadata.
+//!
+//! [`TokenBalanceTracker`] applies the amounts moved by every `Transfer`,
+//! `TransferChecked`, `MintTo`, `MintToChecked`, `Burn` and `BurnChecked`
+//! instruction, inner ones included, to the token accounts involved. Its
+//! [`reconcile`](TokenBalanceTracker::reconcile) method then sums the changes
+//! per owner and mint, next to the change between the pre and post token
+//! balances of the meta. A difference means that the instructions do not
+//! explain the balances, for example because the datasource truncated the
+//! inner instructions.
+//!
+//! ```ignore
+//! let mut tracker = TokenBalanceTracker::new();
+//! tracker.track(&nested_instructions);
+//! let changes = tracker.reconcile(&transaction_metadata);
+//! for change in changes.discrepancies() {
+//!     log::warn!("unexplained token balance change: {:?}", change);
+//! }
+//! ```
+
+use {
+    crate::{
+        instructions::{
+            burn::Burn, burn_checked::BurnChecked, mint_to::MintTo, mint_to_checked::MintToChecked,
+            transfer::Transfer, transfer_checked::TransferChecked, TokenProgramInstruction,
+        },
+        TokenProgramDecoder,
+    },
+    alloc::{collections::BTreeMap, vec::Vec},
+    carbon_core::{
+        deserialize::ArrangeAccounts,
+        instruction::{InstructionDecoder, NestedInstructions},
+        transaction::TransactionMetadata,
+    },
+    core::str::FromStr,
+    solana_pubkey::Pubkey,
+};
+
+/// Accumulates the token amounts moved by SPL Token instructions, per token
+/// account.
+#[derive(Debug, Default, Clone)]
+pub struct TokenBalanceTracker {
+    deltas: BTreeMap<Pubkey, i128>,
+}
+
+impl TokenBalanceTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Applies every balance-changing SPL Token instruction of
+    /// `instructions`, inner instructions included.
+    pub fn track(&mut self, instructions: &NestedInstructions) {
+        for nested_instruction in instructions.flatten() {
+            self.track_instruction(&nested_instruction.instruction);
+        }
+    }
+
+    /// Applies `instruction` if it is a balance-changing SPL Token
+    /// instruction, and ignores it otherwise.
+    pub fn track_instruction(&mut self, instruction: &solana_instruction::Instruction) {
+        let Some(decoded_instruction) = TokenProgramDecoder.decode_instruction(instruction) else {
+            return;
+        };
+        let accounts = &decoded_instruction.accounts;
+
+        match decoded_instruction.data {
+            TokenProgramInstruction::Transfer(transfer) => {
+                if let Some(accounts) = Transfer::arrange_accounts(accounts) {
+                    self.apply(accounts.source, -i128::from(transfer.amount));
+                    self.apply(accounts.destination, i128::from(transfer.amount));
+                }
+            }
+            TokenProgramInstruction::TransferChecked(transfer) => {
+                if let Some(accounts) = TransferChecked::arrange_accounts(accounts) {
+                    self.apply(accounts.source, -i128::from(transfer.amount));
+                    self.apply(accounts.destination, i128::from(transfer.amount));
+                }
+            }
+            TokenProgramInstruction::MintTo(mint_to) => {
+                if let Some(accounts) = MintTo::arrange_accounts(accounts) {
+                    self.apply(accounts.account, i128::from(mint_to.amount));
+                }
+            }
+            TokenProgramInstruction::MintToChecked(mint_to) => {
+                if let Some(accounts) = MintToChecked::arrange_accounts(accounts) {
+                    self.apply(accounts.account, i128::from(mint_to.amount));
+                }
+            }
+            TokenProgramInstruction::Burn(burn) => {
+                if let Some(accounts) = Burn::arrange_accounts(accounts) {
+                    self.apply(accounts.account, -i128::from(burn.amount));
+                }
+            }
+            TokenProgramInstruction::BurnChecked(burn) => {
+                if let Some(accounts) = BurnChecked::arrange_accounts(accounts) {
+                    self.apply(accounts.account, -i128::from(burn.amount));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn apply(&mut self, token_account: Pubkey, amount: i128) {
+        *self.deltas.entry(token_account).or_default() += amount;
+    }
+
+    /// Returns the change of the balance of `token_account` applied so far.
+    pub fn delta(&self, token_account: &Pubkey) -> i128 {
+        self.deltas.get(token_account).copied().unwrap_or_default()
+    }
+
+    /// Sums the tracked changes per owner and mint and compares them with the
+    /// changes between the pre and post token balances of
+    /// `transaction_metadata`.
+    ///
+    /// The owner and mint of a token account are taken from those balances,
+    /// which only cover SPL Token accounts. A token account missing from the
+    /// pre balances started empty, and one missing from the post balances was
+    /// closed with no tokens left.
+    pub fn reconcile(&self, transaction_metadata: &TransactionMetadata) -> TokenBalanceChanges {
+        let account_keys = transaction_metadata.account_keys();
+        let meta = &transaction_metadata.meta;
+
+        // The owner, mint, pre and post amounts of each token account.
+        let mut token_accounts = BTreeMap::<Pubkey, (Pubkey, Pubkey, i128, i128)>::new();
+        for (balances, is_post) in [
+            (meta.pre_token_balances.as_deref(), false),
+            (meta.post_token_balances.as_deref(), true),
+        ] {
+            for balance in balances.unwrap_or_default() {
+                if !balance.program_id.is_empty()
+                    && Pubkey::from_str(&balance.program_id).ok() != Some(spl_token::ID)
+                {
+                    continue;
+                }
+                let (Some(token_account), Ok(owner), Ok(mint), Ok(amount)) = (
+                    account_keys.get(balance.account_index as usize),
+                    Pubkey::from_str(&balance.owner),
+                    Pubkey::from_str(&balance.mint),
+                    balance.ui_token_amount.amount.parse::<i128>(),
+                ) else {
+                    continue;
+                };
+
+                let entry = token_accounts
+                    .entry(*token_account)
+                    .or_insert((owner, mint, 0, 0));
+                if is_post {
+                    entry.3 = amount;
+                } else {
+                    entry.2 = amount;
+                }
+            }
+        }
+
+        let mut changes = BTreeMap::<(Pubkey, Pubkey), TokenBalanceChange>::new();
+        for (token_account, (owner, mint, pre_amount, post_amount)) in &token_accounts {
+            let change = changes
+                .entry((*owner, *mint))
+                .or_insert(TokenBalanceChange {
+                    owner: *owner,
+                    mint: *mint,
+                    instruction_delta: 0,
+                    meta_delta: 0,
+                });
+            change.instruction_delta += self.delta(token_account);
+            change.meta_delta += post_amount - pre_amount;
+        }
+
+        TokenBalanceChanges {
+            changes: changes.into_values().collect(),
+            untracked_accounts: self
+                .deltas
+                .iter()
+                .filter(|(token_account, delta)| {
+                    **delta != 0 && !token_accounts.contains_key(token_account)
+                })
+                .map(|(token_account, delta)| (*token_account, *delta))
+                .collect(),
+        }
+    }
+}
+
+/// The net change of the tokens of one mint held by one owner in a
+/// transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenBalanceChange {
+    pub owner: Pubkey,
+    pub mint: Pubkey,
+    /// The change derived from the transaction's SPL Token instructions.
+    pub instruction_delta: i128,
+    /// The change between the pre and post token balances of the meta.
+    pub meta_delta: i128,
+}
+
+impl TokenBalanceChange {
+    /// Returns whether the instructions explain the change of the balances.
+    pub fn is_consistent(&self) -> bool {
+        self.instruction_delta == self.meta_delta
+    }
+}
+
+/// The token balance changes of a transaction, as returned by
+/// [`TokenBalanceTracker::reconcile`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TokenBalanceChanges {
+    /// The changes per owner and mint, ordered by owner and then mint.
+    pub changes: Vec<TokenBalanceChange>,
+    /// The token accounts whose balance the instructions changed, but which
+    /// are missing from the token balances of the meta, with their change.
+    pub untracked_accounts: Vec<(Pubkey, i128)>,
+}
+
+impl TokenBalanceChanges {
+    /// Returns the changes the instructions do not explain.
+    pub fn discrepancies(&self) -> impl Iterator<Item = &TokenBalanceChange> {
+        self.changes.iter().filter(|change| !change.is_consistent())
+    }
+
+    /// Returns whether the instructions explain every balance change, and
+    /// every token account they change has a balance in the meta.
+    pub fn is_consistent(&self) -> bool {
+        self.discrepancies().next().is_none() && self.untracked_accounts.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        alloc::{string::ToString, vec},
+        carbon_core::instruction::InstructionMetadata,
+        solana_account_decoder_client_types::token::UiTokenAmount,
+        solana_instruction::{AccountMeta, Instruction},
+        solana_transaction_status::{TransactionStatusMeta, TransactionTokenBalance},
+    };
+
+    fn token_balance(
+        account_index: u8,
+        owner: Pubkey,
+        mint: Pubkey,
+        amount: u64,
+    ) -> TransactionTokenBalance {
+        TransactionTokenBalance {
+            account_index,
+            mint: mint.to_string(),
+            ui_token_amount: UiTokenAmount {
+                ui_amount: None,
+                decimals: 6,
+                amount: amount.to_string(),
+                ui_amount_string: amount.to_string(),
+            },
+            owner: owner.to_string(),
+            program_id: spl_token::ID.to_string(),
+        }
+    }
+
+    fn transfer(
+        source: Pubkey,
+        destination: Pubkey,
+        authority: Pubkey,
+        amount: u64,
+    ) -> Instruction {
+        Instruction {
+            program_id: spl_token::ID,
+            accounts: vec![
+                AccountMeta::new(source, false),
+                AccountMeta::new(destination, false),
+                AccountMeta::new_readonly(authority, true),
+            ],
+            data: [vec![3], amount.to_le_bytes().to_vec()].concat(),
+        }
+    }
+
+    #[test]
+    fn test_reconcile_transfers_with_token_balances() {
+        // Arrange
+        let [alice, bob, carol] = [
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+        let [alice_ata, bob_ata, carol_ata] = [
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+        let mint = Pubkey::new_unique();
+        let swap_program = Pubkey::new_unique();
+        let transaction_metadata = TransactionMetadata::builder()
+            .fee_payer(alice)
+            .account_keys(vec![
+                alice,
+                alice_ata,
+                bob_ata,
+                carol_ata,
+                bob,
+                swap_program,
+                spl_token::ID,
+            ])
+            .meta(TransactionStatusMeta {
+                pre_token_balances: Some(vec![
+                    token_balance(1, alice, mint, 1_000),
+                    token_balance(3, carol, mint, 50),
+                ]),
+                post_token_balances: Some(vec![
+                    token_balance(1, alice, mint, 700),
+                    token_balance(2, bob, mint, 200),
+                    token_balance(3, carol, mint, 150),
+                ]),
+                ..Default::default()
+            })
+            .build();
+        // Alice sends 300 tokens to Bob, and a program Bob calls forwards 100
+        // of them to Carol.
+        let metadata = |index: u32, stack_height: u32| InstructionMetadata {
+            transaction_metadata: transaction_metadata.clone(),
+            stack_height,
+            index,
+            accounts_resolved: true,
+            compute_units: None,
+            absolute_path: vec![],
+            transaction_context: None,
+        };
+        let instructions: NestedInstructions = vec![
+            (metadata(0, 1), transfer(alice_ata, bob_ata, alice, 300)),
+            (
+                metadata(1, 1),
+                Instruction {
+                    program_id: swap_program,
+                    accounts: vec![],
+                    data: vec![],
+                },
+            ),
+            (metadata(1, 2), transfer(bob_ata, carol_ata, bob, 100)),
+        ]
+        .into();
+
+        // Act
+        let mut tracker = TokenBalanceTracker::new();
+        tracker.track(&instructions);
+        let changes = tracker.reconcile(&transaction_metadata);
+        let mut partial_tracker = TokenBalanceTracker::new();
+        partial_tracker.track_instruction(&transfer(alice_ata, bob_ata, alice, 300));
+        let partial_changes = partial_tracker.reconcile(&transaction_metadata);
+
+        // Assert
+        let mut expected = vec![
+            TokenBalanceChange {
+                owner: alice,
+                mint,
+                instruction_delta: -300,
+                meta_delta: -300,
+            },
+            TokenBalanceChange {
+                owner: bob,
+                mint,
+                instruction_delta: 200,
+                meta_delta: 200,
+            },
+            TokenBalanceChange {
+                owner: carol,
+                mint,
+                instruction_delta: 100,
+                meta_delta: 100,
+            },
+        ];
+        expected.sort_by_key(|change| change.owner);
+        assert_eq!(changes.changes, expected);
+        assert!(changes.untracked_accounts.is_empty());
+        assert!(changes.is_consistent());
+
+        // Without the inner transfer, Bob and Carol's balances are unexplained.
+        let discrepancies: Vec<_> = partial_changes
+            .discrepancies()
+            .map(|change| (change.owner, change.instruction_delta, change.meta_delta))
+            .collect();
+        assert_eq!(discrepancies.len(), 2);
+        assert!(discrepancies.contains(&(bob, 300, 200)));
+        assert!(discrepancies.contains(&(carol, 0, 100)));
+        assert!(!partial_changes.is_consistent());
+    }
+}
@@ -67,15 +67,25 @@ pub enum TokenProgramInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for TokenProgramDecoder {
     type InstructionType = TokenProgramInstruction;
 
-    fn decode_instruction(
+    fn decoder_name(&self) -> &'static str {
+        "token_program"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[spl_token::ID]
+    }
+
+    fn try_decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
-    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+    ) -> carbon_core::error::CarbonResult<
+        Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>>,
+    > {
         if !instruction.program_id.eq(&spl_token::id()) {
-            return None;
+            return Ok(None);
         }
 
-        carbon_core::try_decode_instructions!(instruction,
+        Ok(carbon_core::try_decode_instructions!(instruction,
             TokenProgramInstruction::AmountToUiAmount => amount_to_ui_amount::AmountToUiAmount,
             TokenProgramInstruction::ApproveChecked => approve_checked::ApproveChecked,
             TokenProgramInstruction::Approve => approve::Approve,
@@ -101,6 +111,88 @@ impl carbon_core::instruction::InstructionDecoder<'_> for TokenProgramDecoder {
             TokenProgramInstruction::TransferChecked => transfer_checked::TransferChecked,
             TokenProgramInstruction::Transfer => transfer::Transfer,
             TokenProgramInstruction::UiAmountToAmount => ui_amount_to_amount::UiAmountToAmount,
-        )
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use carbon_core::{deserialize::ArrangeAccounts, instruction::InstructionDecoder};
+    use solana_instruction::AccountMeta;
+
+    use super::*;
+
+    #[test]
+    fn test_decode_transfer() {
+        // Arrange
+        let expected_ix = TokenProgramInstruction::Transfer(transfer::Transfer { amount: 1000000 });
+        let expected_accounts = vec![
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "7UX2i7SucgLMQcfZ75s3VXmZZY4YRUyJN9X1RgfMoDUi",
+                ),
+                false,
+            ),
+            AccountMeta::new(
+                solana_pubkey::Pubkey::from_str_const(
+                    "3aEn4fyzsuo6Ea7Ck7Tb8nGFvR6nPZEtt8iP9TFFBHrQ",
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_pubkey::Pubkey::from_str_const(
+                    "6bBmDxYqXeFbXN8SmtjTpiA3SrEDKsxK8RG6yhPGpa9G",
+                ),
+                true,
+            ),
+        ];
+
+        // Act
+        let decoder = TokenProgramDecoder;
+        let instruction = carbon_test_utils::read_instruction("tests/fixtures/transfer_ix.json")
+            .expect("read fixture");
+        let decoded = decoder
+            .decode_instruction(&instruction)
+            .expect("decode instruction");
+        let decoded_arranged_accounts =
+            transfer::Transfer::arrange_accounts(&instruction.accounts).expect("aranage accounts");
+
+        // Assert
+        assert_eq!(decoded.data, expected_ix);
+        assert_eq!(decoded.accounts, expected_accounts);
+        assert_eq!(decoded.program_id, spl_token::id());
+        assert_eq!(decoded.discriminator, vec![3]);
+        assert_eq!(
+            decoded_arranged_accounts.source,
+            expected_accounts[0].pubkey
+        );
+        assert_eq!(
+            decoded_arranged_accounts.destination,
+            expected_accounts[1].pubkey
+        );
+        assert_eq!(
+            decoded_arranged_accounts.authority,
+            expected_accounts[2].pubkey
+        );
+    }
+
+    #[test]
+    fn test_decode_sync_native() {
+        // Arrange
+        let expected_ix = TokenProgramInstruction::SyncNative(sync_native::SyncNative {});
+
+        // Act
+        let decoder = TokenProgramDecoder;
+        let instruction = carbon_test_utils::read_instruction("tests/fixtures/sync_native_ix.json")
+            .expect("read fixture");
+        let decoded = decoder
+            .decode_instruction(&instruction)
+            .expect("decode instruction");
+
+        // Assert
+        assert_eq!(decoded.data, expected_ix);
+        assert_eq!(decoded.discriminator, vec![17]);
+        assert_eq!(instruction.data.len(), 1);
     }
 }
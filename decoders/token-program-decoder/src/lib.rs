@@ -5,5 +5,6 @@ extern crate alloc;
 pub struct TokenProgramDecoder;
 
 pub mod accounts;
+pub mod balances;
 pub mod instructions;
 pub mod types;
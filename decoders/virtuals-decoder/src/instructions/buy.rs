@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -20,6 +22,7 @@ pub struct BuyInstructionAccounts {
     pub platform_prototype_virtuals_ata: solana_pubkey::Pubkey,
     pub vpool_virtuals_ata: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Buy {
@@ -28,7 +31,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Buy {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [user, vpool, token_mint, user_virtuals_ata, user_token_ata, vpool_token_ata, platform_prototype, platform_prototype_virtuals_ata, vpool_virtuals_ata, token_program, _remaining @ ..] =
+        let [user, vpool, token_mint, user_virtuals_ata, user_token_ata, vpool_token_ata, platform_prototype, platform_prototype_virtuals_ata, vpool_virtuals_ata, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -45,6 +48,106 @@ impl carbon_core::deserialize::ArrangeAccounts for Buy {
             platform_prototype_virtuals_ata: platform_prototype_virtuals_ata.pubkey,
             vpool_virtuals_ata: vpool_virtuals_ata.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct BuyInstructionAccountMetas {
+    pub user: solana_instruction::AccountMeta,
+    pub vpool: solana_instruction::AccountMeta,
+    pub token_mint: solana_instruction::AccountMeta,
+    pub user_virtuals_ata: solana_instruction::AccountMeta,
+    pub user_token_ata: solana_instruction::AccountMeta,
+    pub vpool_token_ata: solana_instruction::AccountMeta,
+    pub platform_prototype: solana_instruction::AccountMeta,
+    pub platform_prototype_virtuals_ata: solana_instruction::AccountMeta,
+    pub vpool_virtuals_ata: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Buy {
+    type ArrangedAccountMetas = BuyInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [user, vpool, token_mint, user_virtuals_ata, user_token_ata, vpool_token_ata, platform_prototype, platform_prototype_virtuals_ata, vpool_virtuals_ata, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(BuyInstructionAccountMetas {
+            user: user.clone(),
+            vpool: vpool.clone(),
+            token_mint: token_mint.clone(),
+            user_virtuals_ata: user_virtuals_ata.clone(),
+            user_token_ata: user_token_ata.clone(),
+            vpool_token_ata: vpool_token_ata.clone(),
+            platform_prototype: platform_prototype.clone(),
+            platform_prototype_virtuals_ata: platform_prototype_virtuals_ata.clone(),
+            vpool_virtuals_ata: vpool_virtuals_ata.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct BuyInstructionAccountIndices {
+    pub user: usize,
+    pub vpool: usize,
+    pub token_mint: usize,
+    pub user_virtuals_ata: usize,
+    pub user_token_ata: usize,
+    pub vpool_token_ata: usize,
+    pub platform_prototype: usize,
+    pub platform_prototype_virtuals_ata: usize,
+    pub vpool_virtuals_ata: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Buy {
+    type ArrangedAccountIndices = BuyInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 10 {
+            return None;
+        }
+
+        Some(BuyInstructionAccountIndices {
+            user: 0,
+            vpool: 1,
+            token_mint: 2,
+            user_virtuals_ata: 3,
+            user_token_ata: 4,
+            vpool_token_ata: 5,
+            platform_prototype: 6,
+            platform_prototype_virtuals_ata: 7,
+            vpool_virtuals_ata: 8,
+            token_program: 9,
+            remaining_accounts: 10..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Buy {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "user",
+            "vpool",
+            "token_mint",
+            "user_virtuals_ata",
+            "user_token_ata",
+            "vpool_token_ata",
+            "platform_prototype",
+            "platform_prototype_virtuals_ata",
+            "vpool_virtuals_ata",
+            "token_program",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -35,6 +37,7 @@ pub struct ClaimFeesInstructionAccounts {
     pub associated_token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub dynamic_amm_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ClaimFees {
@@ -43,7 +46,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ClaimFees {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [payer, vpool, virtuals_mint, token_mint, vpool_virtuals_ata, vpool_token_ata, platform, platform_virtuals_ata, platform_token_ata, creator_virtuals_ata, creator_token_ata, pool, lp_mint, lock_escrow, escrow_vault, token_program, virtuals_vault, token_vault, virtuals_token_vault, token_token_vault, virtuals_vault_lp_mint, token_vault_lp_mint, virtuals_vault_lp, token_vault_lp, vault_program, associated_token_program, system_program, dynamic_amm_program, _remaining @ ..] =
+        let [payer, vpool, virtuals_mint, token_mint, vpool_virtuals_ata, vpool_token_ata, platform, platform_virtuals_ata, platform_token_ata, creator_virtuals_ata, creator_token_ata, pool, lp_mint, lock_escrow, escrow_vault, token_program, virtuals_vault, token_vault, virtuals_token_vault, token_token_vault, virtuals_vault_lp_mint, token_vault_lp_mint, virtuals_vault_lp, token_vault_lp, vault_program, associated_token_program, system_program, dynamic_amm_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -78,6 +81,196 @@ impl carbon_core::deserialize::ArrangeAccounts for ClaimFees {
             associated_token_program: associated_token_program.pubkey,
             system_program: system_program.pubkey,
             dynamic_amm_program: dynamic_amm_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ClaimFeesInstructionAccountMetas {
+    pub payer: solana_instruction::AccountMeta,
+    pub vpool: solana_instruction::AccountMeta,
+    pub virtuals_mint: solana_instruction::AccountMeta,
+    pub token_mint: solana_instruction::AccountMeta,
+    pub vpool_virtuals_ata: solana_instruction::AccountMeta,
+    pub vpool_token_ata: solana_instruction::AccountMeta,
+    pub platform: solana_instruction::AccountMeta,
+    pub platform_virtuals_ata: solana_instruction::AccountMeta,
+    pub platform_token_ata: solana_instruction::AccountMeta,
+    pub creator_virtuals_ata: solana_instruction::AccountMeta,
+    pub creator_token_ata: solana_instruction::AccountMeta,
+    pub pool: solana_instruction::AccountMeta,
+    pub lp_mint: solana_instruction::AccountMeta,
+    pub lock_escrow: solana_instruction::AccountMeta,
+    pub escrow_vault: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub virtuals_vault: solana_instruction::AccountMeta,
+    pub token_vault: solana_instruction::AccountMeta,
+    pub virtuals_token_vault: solana_instruction::AccountMeta,
+    pub token_token_vault: solana_instruction::AccountMeta,
+    pub virtuals_vault_lp_mint: solana_instruction::AccountMeta,
+    pub token_vault_lp_mint: solana_instruction::AccountMeta,
+    pub virtuals_vault_lp: solana_instruction::AccountMeta,
+    pub token_vault_lp: solana_instruction::AccountMeta,
+    pub vault_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub dynamic_amm_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ClaimFees {
+    type ArrangedAccountMetas = ClaimFeesInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [payer, vpool, virtuals_mint, token_mint, vpool_virtuals_ata, vpool_token_ata, platform, platform_virtuals_ata, platform_token_ata, creator_virtuals_ata, creator_token_ata, pool, lp_mint, lock_escrow, escrow_vault, token_program, virtuals_vault, token_vault, virtuals_token_vault, token_token_vault, virtuals_vault_lp_mint, token_vault_lp_mint, virtuals_vault_lp, token_vault_lp, vault_program, associated_token_program, system_program, dynamic_amm_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(ClaimFeesInstructionAccountMetas {
+            payer: payer.clone(),
+            vpool: vpool.clone(),
+            virtuals_mint: virtuals_mint.clone(),
+            token_mint: token_mint.clone(),
+            vpool_virtuals_ata: vpool_virtuals_ata.clone(),
+            vpool_token_ata: vpool_token_ata.clone(),
+            platform: platform.clone(),
+            platform_virtuals_ata: platform_virtuals_ata.clone(),
+            platform_token_ata: platform_token_ata.clone(),
+            creator_virtuals_ata: creator_virtuals_ata.clone(),
+            creator_token_ata: creator_token_ata.clone(),
+            pool: pool.clone(),
+            lp_mint: lp_mint.clone(),
+            lock_escrow: lock_escrow.clone(),
+            escrow_vault: escrow_vault.clone(),
+            token_program: token_program.clone(),
+            virtuals_vault: virtuals_vault.clone(),
+            token_vault: token_vault.clone(),
+            virtuals_token_vault: virtuals_token_vault.clone(),
+            token_token_vault: token_token_vault.clone(),
+            virtuals_vault_lp_mint: virtuals_vault_lp_mint.clone(),
+            token_vault_lp_mint: token_vault_lp_mint.clone(),
+            virtuals_vault_lp: virtuals_vault_lp.clone(),
+            token_vault_lp: token_vault_lp.clone(),
+            vault_program: vault_program.clone(),
+            associated_token_program: associated_token_program.clone(),
+            system_program: system_program.clone(),
+            dynamic_amm_program: dynamic_amm_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ClaimFeesInstructionAccountIndices {
+    pub payer: usize,
+    pub vpool: usize,
+    pub virtuals_mint: usize,
+    pub token_mint: usize,
+    pub vpool_virtuals_ata: usize,
+    pub vpool_token_ata: usize,
+    pub platform: usize,
+    pub platform_virtuals_ata: usize,
+    pub platform_token_ata: usize,
+    pub creator_virtuals_ata: usize,
+    pub creator_token_ata: usize,
+    pub pool: usize,
+    pub lp_mint: usize,
+    pub lock_escrow: usize,
+    pub escrow_vault: usize,
+    pub token_program: usize,
+    pub virtuals_vault: usize,
+    pub token_vault: usize,
+    pub virtuals_token_vault: usize,
+    pub token_token_vault: usize,
+    pub virtuals_vault_lp_mint: usize,
+    pub token_vault_lp_mint: usize,
+    pub virtuals_vault_lp: usize,
+    pub token_vault_lp: usize,
+    pub vault_program: usize,
+    pub associated_token_program: usize,
+    pub system_program: usize,
+    pub dynamic_amm_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ClaimFees {
+    type ArrangedAccountIndices = ClaimFeesInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 28 {
+            return None;
+        }
+
+        Some(ClaimFeesInstructionAccountIndices {
+            payer: 0,
+            vpool: 1,
+            virtuals_mint: 2,
+            token_mint: 3,
+            vpool_virtuals_ata: 4,
+            vpool_token_ata: 5,
+            platform: 6,
+            platform_virtuals_ata: 7,
+            platform_token_ata: 8,
+            creator_virtuals_ata: 9,
+            creator_token_ata: 10,
+            pool: 11,
+            lp_mint: 12,
+            lock_escrow: 13,
+            escrow_vault: 14,
+            token_program: 15,
+            virtuals_vault: 16,
+            token_vault: 17,
+            virtuals_token_vault: 18,
+            token_token_vault: 19,
+            virtuals_vault_lp_mint: 20,
+            token_vault_lp_mint: 21,
+            virtuals_vault_lp: 22,
+            token_vault_lp: 23,
+            vault_program: 24,
+            associated_token_program: 25,
+            system_program: 26,
+            dynamic_amm_program: 27,
+            remaining_accounts: 28..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ClaimFees {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "payer",
+            "vpool",
+            "virtuals_mint",
+            "token_mint",
+            "vpool_virtuals_ata",
+            "vpool_token_ata",
+            "platform",
+            "platform_virtuals_ata",
+            "platform_token_ata",
+            "creator_virtuals_ata",
+            "creator_token_ata",
+            "pool",
+            "lp_mint",
+            "lock_escrow",
+            "escrow_vault",
+            "token_program",
+            "virtuals_vault",
+            "token_vault",
+            "virtuals_token_vault",
+            "token_token_vault",
+            "virtuals_vault_lp_mint",
+            "token_vault_lp_mint",
+            "virtuals_vault_lp",
+            "token_vault_lp",
+            "vault_program",
+            "associated_token_program",
+            "system_program",
+            "dynamic_amm_program",
+        ]
+    }
+}
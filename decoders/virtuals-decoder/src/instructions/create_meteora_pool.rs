@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -43,6 +45,7 @@ pub struct CreateMeteoraPoolInstructionAccounts {
     pub associated_token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub dynamic_amm_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CreateMeteoraPool {
@@ -51,7 +54,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateMeteoraPool {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [vpool, meteora_deployer, meteora_deployer_virtuals_ata, meteora_deployer_token_ata, vpool_virtuals_ata, vpool_token_ata, lock_escrow, escrow_vault, pool, config, lp_mint, virtuals_mint, token_mint, virtuals_vault, token_vault, virtuals_token_vault, token_token_vault, virtuals_vault_lp_mint, token_vault_lp_mint, virtuals_vault_lp, token_vault_lp, pool_virtuals_ata, pool_token_ata, meteora_deployer_pool_lp, protocol_virtuals_fee, protocol_token_fee, payer, token_metadata, rent, mint_metadata, metadata_program, vault_program, token_program, associated_token_program, system_program, dynamic_amm_program, _remaining @ ..] =
+        let [vpool, meteora_deployer, meteora_deployer_virtuals_ata, meteora_deployer_token_ata, vpool_virtuals_ata, vpool_token_ata, lock_escrow, escrow_vault, pool, config, lp_mint, virtuals_mint, token_mint, virtuals_vault, token_vault, virtuals_token_vault, token_token_vault, virtuals_vault_lp_mint, token_vault_lp_mint, virtuals_vault_lp, token_vault_lp, pool_virtuals_ata, pool_token_ata, meteora_deployer_pool_lp, protocol_virtuals_fee, protocol_token_fee, payer, token_metadata, rent, mint_metadata, metadata_program, vault_program, token_program, associated_token_program, system_program, dynamic_amm_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -94,6 +97,236 @@ impl carbon_core::deserialize::ArrangeAccounts for CreateMeteoraPool {
             associated_token_program: associated_token_program.pubkey,
             system_program: system_program.pubkey,
             dynamic_amm_program: dynamic_amm_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CreateMeteoraPoolInstructionAccountMetas {
+    pub vpool: solana_instruction::AccountMeta,
+    pub meteora_deployer: solana_instruction::AccountMeta,
+    pub meteora_deployer_virtuals_ata: solana_instruction::AccountMeta,
+    pub meteora_deployer_token_ata: solana_instruction::AccountMeta,
+    pub vpool_virtuals_ata: solana_instruction::AccountMeta,
+    pub vpool_token_ata: solana_instruction::AccountMeta,
+    pub lock_escrow: solana_instruction::AccountMeta,
+    pub escrow_vault: solana_instruction::AccountMeta,
+    pub pool: solana_instruction::AccountMeta,
+    pub config: solana_instruction::AccountMeta,
+    pub lp_mint: solana_instruction::AccountMeta,
+    pub virtuals_mint: solana_instruction::AccountMeta,
+    pub token_mint: solana_instruction::AccountMeta,
+    pub virtuals_vault: solana_instruction::AccountMeta,
+    pub token_vault: solana_instruction::AccountMeta,
+    pub virtuals_token_vault: solana_instruction::AccountMeta,
+    pub token_token_vault: solana_instruction::AccountMeta,
+    pub virtuals_vault_lp_mint: solana_instruction::AccountMeta,
+    pub token_vault_lp_mint: solana_instruction::AccountMeta,
+    pub virtuals_vault_lp: solana_instruction::AccountMeta,
+    pub token_vault_lp: solana_instruction::AccountMeta,
+    pub pool_virtuals_ata: solana_instruction::AccountMeta,
+    pub pool_token_ata: solana_instruction::AccountMeta,
+    pub meteora_deployer_pool_lp: solana_instruction::AccountMeta,
+    pub protocol_virtuals_fee: solana_instruction::AccountMeta,
+    pub protocol_token_fee: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub token_metadata: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub mint_metadata: solana_instruction::AccountMeta,
+    pub metadata_program: solana_instruction::AccountMeta,
+    pub vault_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub dynamic_amm_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CreateMeteoraPool {
+    type ArrangedAccountMetas = CreateMeteoraPoolInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [vpool, meteora_deployer, meteora_deployer_virtuals_ata, meteora_deployer_token_ata, vpool_virtuals_ata, vpool_token_ata, lock_escrow, escrow_vault, pool, config, lp_mint, virtuals_mint, token_mint, virtuals_vault, token_vault, virtuals_token_vault, token_token_vault, virtuals_vault_lp_mint, token_vault_lp_mint, virtuals_vault_lp, token_vault_lp, pool_virtuals_ata, pool_token_ata, meteora_deployer_pool_lp, protocol_virtuals_fee, protocol_token_fee, payer, token_metadata, rent, mint_metadata, metadata_program, vault_program, token_program, associated_token_program, system_program, dynamic_amm_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CreateMeteoraPoolInstructionAccountMetas {
+            vpool: vpool.clone(),
+            meteora_deployer: meteora_deployer.clone(),
+            meteora_deployer_virtuals_ata: meteora_deployer_virtuals_ata.clone(),
+            meteora_deployer_token_ata: meteora_deployer_token_ata.clone(),
+            vpool_virtuals_ata: vpool_virtuals_ata.clone(),
+            vpool_token_ata: vpool_token_ata.clone(),
+            lock_escrow: lock_escrow.clone(),
+            escrow_vault: escrow_vault.clone(),
+            pool: pool.clone(),
+            config: config.clone(),
+            lp_mint: lp_mint.clone(),
+            virtuals_mint: virtuals_mint.clone(),
+            token_mint: token_mint.clone(),
+            virtuals_vault: virtuals_vault.clone(),
+            token_vault: token_vault.clone(),
+            virtuals_token_vault: virtuals_token_vault.clone(),
+            token_token_vault: token_token_vault.clone(),
+            virtuals_vault_lp_mint: virtuals_vault_lp_mint.clone(),
+            token_vault_lp_mint: token_vault_lp_mint.clone(),
+            virtuals_vault_lp: virtuals_vault_lp.clone(),
+            token_vault_lp: token_vault_lp.clone(),
+            pool_virtuals_ata: pool_virtuals_ata.clone(),
+            pool_token_ata: pool_token_ata.clone(),
+            meteora_deployer_pool_lp: meteora_deployer_pool_lp.clone(),
+            protocol_virtuals_fee: protocol_virtuals_fee.clone(),
+            protocol_token_fee: protocol_token_fee.clone(),
+            payer: payer.clone(),
+            token_metadata: token_metadata.clone(),
+            rent: rent.clone(),
+            mint_metadata: mint_metadata.clone(),
+            metadata_program: metadata_program.clone(),
+            vault_program: vault_program.clone(),
+            token_program: token_program.clone(),
+            associated_token_program: associated_token_program.clone(),
+            system_program: system_program.clone(),
+            dynamic_amm_program: dynamic_amm_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CreateMeteoraPoolInstructionAccountIndices {
+    pub vpool: usize,
+    pub meteora_deployer: usize,
+    pub meteora_deployer_virtuals_ata: usize,
+    pub meteora_deployer_token_ata: usize,
+    pub vpool_virtuals_ata: usize,
+    pub vpool_token_ata: usize,
+    pub lock_escrow: usize,
+    pub escrow_vault: usize,
+    pub pool: usize,
+    pub config: usize,
+    pub lp_mint: usize,
+    pub virtuals_mint: usize,
+    pub token_mint: usize,
+    pub virtuals_vault: usize,
+    pub token_vault: usize,
+    pub virtuals_token_vault: usize,
+    pub token_token_vault: usize,
+    pub virtuals_vault_lp_mint: usize,
+    pub token_vault_lp_mint: usize,
+    pub virtuals_vault_lp: usize,
+    pub token_vault_lp: usize,
+    pub pool_virtuals_ata: usize,
+    pub pool_token_ata: usize,
+    pub meteora_deployer_pool_lp: usize,
+    pub protocol_virtuals_fee: usize,
+    pub protocol_token_fee: usize,
+    pub payer: usize,
+    pub token_metadata: usize,
+    pub rent: usize,
+    pub mint_metadata: usize,
+    pub metadata_program: usize,
+    pub vault_program: usize,
+    pub token_program: usize,
+    pub associated_token_program: usize,
+    pub system_program: usize,
+    pub dynamic_amm_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CreateMeteoraPool {
+    type ArrangedAccountIndices = CreateMeteoraPoolInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 36 {
+            return None;
+        }
+
+        Some(CreateMeteoraPoolInstructionAccountIndices {
+            vpool: 0,
+            meteora_deployer: 1,
+            meteora_deployer_virtuals_ata: 2,
+            meteora_deployer_token_ata: 3,
+            vpool_virtuals_ata: 4,
+            vpool_token_ata: 5,
+            lock_escrow: 6,
+            escrow_vault: 7,
+            pool: 8,
+            config: 9,
+            lp_mint: 10,
+            virtuals_mint: 11,
+            token_mint: 12,
+            virtuals_vault: 13,
+            token_vault: 14,
+            virtuals_token_vault: 15,
+            token_token_vault: 16,
+            virtuals_vault_lp_mint: 17,
+            token_vault_lp_mint: 18,
+            virtuals_vault_lp: 19,
+            token_vault_lp: 20,
+            pool_virtuals_ata: 21,
+            pool_token_ata: 22,
+            meteora_deployer_pool_lp: 23,
+            protocol_virtuals_fee: 24,
+            protocol_token_fee: 25,
+            payer: 26,
+            token_metadata: 27,
+            rent: 28,
+            mint_metadata: 29,
+            metadata_program: 30,
+            vault_program: 31,
+            token_program: 32,
+            associated_token_program: 33,
+            system_program: 34,
+            dynamic_amm_program: 35,
+            remaining_accounts: 36..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CreateMeteoraPool {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "vpool",
+            "meteora_deployer",
+            "meteora_deployer_virtuals_ata",
+            "meteora_deployer_token_ata",
+            "vpool_virtuals_ata",
+            "vpool_token_ata",
+            "lock_escrow",
+            "escrow_vault",
+            "pool",
+            "config",
+            "lp_mint",
+            "virtuals_mint",
+            "token_mint",
+            "virtuals_vault",
+            "token_vault",
+            "virtuals_token_vault",
+            "token_token_vault",
+            "virtuals_vault_lp_mint",
+            "token_vault_lp_mint",
+            "virtuals_vault_lp",
+            "token_vault_lp",
+            "pool_virtuals_ata",
+            "pool_token_ata",
+            "meteora_deployer_pool_lp",
+            "protocol_virtuals_fee",
+            "protocol_token_fee",
+            "payer",
+            "token_metadata",
+            "rent",
+            "mint_metadata",
+            "metadata_program",
+            "vault_program",
+            "token_program",
+            "associated_token_program",
+            "system_program",
+            "dynamic_amm_program",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -16,6 +18,7 @@ pub struct InitializeInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub associated_token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Initialize {
@@ -24,7 +27,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Initialize {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [payer, virtuals_mint, token_mint, vpool_virtuals_ata, vpool_token_ata, vpool, token_program, associated_token_program, system_program, _remaining @ ..] =
+        let [payer, virtuals_mint, token_mint, vpool_virtuals_ata, vpool_token_ata, vpool, token_program, associated_token_program, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -40,6 +43,101 @@ impl carbon_core::deserialize::ArrangeAccounts for Initialize {
             token_program: token_program.pubkey,
             associated_token_program: associated_token_program.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeInstructionAccountMetas {
+    pub payer: solana_instruction::AccountMeta,
+    pub virtuals_mint: solana_instruction::AccountMeta,
+    pub token_mint: solana_instruction::AccountMeta,
+    pub vpool_virtuals_ata: solana_instruction::AccountMeta,
+    pub vpool_token_ata: solana_instruction::AccountMeta,
+    pub vpool: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Initialize {
+    type ArrangedAccountMetas = InitializeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [payer, virtuals_mint, token_mint, vpool_virtuals_ata, vpool_token_ata, vpool, token_program, associated_token_program, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeInstructionAccountMetas {
+            payer: payer.clone(),
+            virtuals_mint: virtuals_mint.clone(),
+            token_mint: token_mint.clone(),
+            vpool_virtuals_ata: vpool_virtuals_ata.clone(),
+            vpool_token_ata: vpool_token_ata.clone(),
+            vpool: vpool.clone(),
+            token_program: token_program.clone(),
+            associated_token_program: associated_token_program.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeInstructionAccountIndices {
+    pub payer: usize,
+    pub virtuals_mint: usize,
+    pub token_mint: usize,
+    pub vpool_virtuals_ata: usize,
+    pub vpool_token_ata: usize,
+    pub vpool: usize,
+    pub token_program: usize,
+    pub associated_token_program: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Initialize {
+    type ArrangedAccountIndices = InitializeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 9 {
+            return None;
+        }
+
+        Some(InitializeInstructionAccountIndices {
+            payer: 0,
+            virtuals_mint: 1,
+            token_mint: 2,
+            vpool_virtuals_ata: 3,
+            vpool_token_ata: 4,
+            vpool: 5,
+            token_program: 6,
+            associated_token_program: 7,
+            system_program: 8,
+            remaining_accounts: 9..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Initialize {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "payer",
+            "virtuals_mint",
+            "token_mint",
+            "vpool_virtuals_ata",
+            "vpool_token_ata",
+            "vpool",
+            "token_program",
+            "associated_token_program",
+            "system_program",
+        ]
+    }
+}
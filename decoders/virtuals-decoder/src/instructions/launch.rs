@@ -1,6 +1,8 @@
 use alloc::string::String;
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -24,6 +26,7 @@ pub struct LaunchInstructionAccounts {
     pub associated_token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Launch {
@@ -32,7 +35,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Launch {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [creator, creator_virtuals_ata, token_mint, platform_prototype, platform_prototype_virtuals_ata, vpool, token_metadata, metadata_program, token_program, associated_token_program, system_program, rent, _remaining @ ..] =
+        let [creator, creator_virtuals_ata, token_mint, platform_prototype, platform_prototype_virtuals_ata, vpool, token_metadata, metadata_program, token_program, associated_token_program, system_program, rent, remaining @ ..] =
             accounts
         else {
             return None;
@@ -51,6 +54,116 @@ impl carbon_core::deserialize::ArrangeAccounts for Launch {
             associated_token_program: associated_token_program.pubkey,
             system_program: system_program.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct LaunchInstructionAccountMetas {
+    pub creator: solana_instruction::AccountMeta,
+    pub creator_virtuals_ata: solana_instruction::AccountMeta,
+    pub token_mint: solana_instruction::AccountMeta,
+    pub platform_prototype: solana_instruction::AccountMeta,
+    pub platform_prototype_virtuals_ata: solana_instruction::AccountMeta,
+    pub vpool: solana_instruction::AccountMeta,
+    pub token_metadata: solana_instruction::AccountMeta,
+    pub metadata_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Launch {
+    type ArrangedAccountMetas = LaunchInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [creator, creator_virtuals_ata, token_mint, platform_prototype, platform_prototype_virtuals_ata, vpool, token_metadata, metadata_program, token_program, associated_token_program, system_program, rent, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(LaunchInstructionAccountMetas {
+            creator: creator.clone(),
+            creator_virtuals_ata: creator_virtuals_ata.clone(),
+            token_mint: token_mint.clone(),
+            platform_prototype: platform_prototype.clone(),
+            platform_prototype_virtuals_ata: platform_prototype_virtuals_ata.clone(),
+            vpool: vpool.clone(),
+            token_metadata: token_metadata.clone(),
+            metadata_program: metadata_program.clone(),
+            token_program: token_program.clone(),
+            associated_token_program: associated_token_program.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct LaunchInstructionAccountIndices {
+    pub creator: usize,
+    pub creator_virtuals_ata: usize,
+    pub token_mint: usize,
+    pub platform_prototype: usize,
+    pub platform_prototype_virtuals_ata: usize,
+    pub vpool: usize,
+    pub token_metadata: usize,
+    pub metadata_program: usize,
+    pub token_program: usize,
+    pub associated_token_program: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Launch {
+    type ArrangedAccountIndices = LaunchInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 12 {
+            return None;
+        }
+
+        Some(LaunchInstructionAccountIndices {
+            creator: 0,
+            creator_virtuals_ata: 1,
+            token_mint: 2,
+            platform_prototype: 3,
+            platform_prototype_virtuals_ata: 4,
+            vpool: 5,
+            token_metadata: 6,
+            metadata_program: 7,
+            token_program: 8,
+            associated_token_program: 9,
+            system_program: 10,
+            rent: 11,
+            remaining_accounts: 12..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Launch {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "creator",
+            "creator_virtuals_ata",
+            "token_mint",
+            "platform_prototype",
+            "platform_prototype_virtuals_ata",
+            "vpool",
+            "token_metadata",
+            "metadata_program",
+            "token_program",
+            "associated_token_program",
+            "system_program",
+            "rent",
+        ]
+    }
+}
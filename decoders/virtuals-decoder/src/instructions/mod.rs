@@ -42,15 +42,25 @@ pub enum VirtualsInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for VirtualsDecoder {
     type InstructionType = VirtualsInstruction;
 
-    fn decode_instruction(
+    fn decoder_name(&self) -> &'static str {
+        "virtuals"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[super::PROGRAM_ID]
+    }
+
+    fn try_decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
-    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+    ) -> carbon_core::error::CarbonResult<
+        Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>>,
+    > {
         if !instruction.program_id.eq(&PROGRAM_ID) {
-            return None;
+            return Ok(None);
         }
 
-        carbon_core::try_decode_instructions!(instruction,
+        Ok(carbon_core::try_decode_instructions!(instruction,
             VirtualsInstruction::Buy => buy::Buy,
             VirtualsInstruction::ClaimFees => claim_fees::ClaimFees,
             VirtualsInstruction::CreateMeteoraPool => create_meteora_pool::CreateMeteoraPool,
@@ -63,6 +73,6 @@ impl carbon_core::instruction::InstructionDecoder<'_> for VirtualsDecoder {
             VirtualsInstruction::GraduationEvent => graduation_event::GraduationEvent,
             VirtualsInstruction::LaunchEvent => launch_event::LaunchEvent,
             VirtualsInstruction::SellEvent => sell_event::SellEvent,
-        )
+        ))
     }
 }
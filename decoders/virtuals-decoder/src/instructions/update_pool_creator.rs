@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -17,6 +19,7 @@ pub struct UpdatePoolCreatorInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub associated_token_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdatePoolCreator {
@@ -25,7 +28,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdatePoolCreator {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [creator, new_creator, virtuals_mint, token_mint, new_creator_virtuals_ata, new_creator_token_ata, vpool, token_program, associated_token_program, system_program, _remaining @ ..] =
+        let [creator, new_creator, virtuals_mint, token_mint, new_creator_virtuals_ata, new_creator_token_ata, vpool, token_program, associated_token_program, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -42,6 +45,106 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdatePoolCreator {
             token_program: token_program.pubkey,
             associated_token_program: associated_token_program.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdatePoolCreatorInstructionAccountMetas {
+    pub creator: solana_instruction::AccountMeta,
+    pub new_creator: solana_instruction::AccountMeta,
+    pub virtuals_mint: solana_instruction::AccountMeta,
+    pub token_mint: solana_instruction::AccountMeta,
+    pub new_creator_virtuals_ata: solana_instruction::AccountMeta,
+    pub new_creator_token_ata: solana_instruction::AccountMeta,
+    pub vpool: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub associated_token_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdatePoolCreator {
+    type ArrangedAccountMetas = UpdatePoolCreatorInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [creator, new_creator, virtuals_mint, token_mint, new_creator_virtuals_ata, new_creator_token_ata, vpool, token_program, associated_token_program, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(UpdatePoolCreatorInstructionAccountMetas {
+            creator: creator.clone(),
+            new_creator: new_creator.clone(),
+            virtuals_mint: virtuals_mint.clone(),
+            token_mint: token_mint.clone(),
+            new_creator_virtuals_ata: new_creator_virtuals_ata.clone(),
+            new_creator_token_ata: new_creator_token_ata.clone(),
+            vpool: vpool.clone(),
+            token_program: token_program.clone(),
+            associated_token_program: associated_token_program.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdatePoolCreatorInstructionAccountIndices {
+    pub creator: usize,
+    pub new_creator: usize,
+    pub virtuals_mint: usize,
+    pub token_mint: usize,
+    pub new_creator_virtuals_ata: usize,
+    pub new_creator_token_ata: usize,
+    pub vpool: usize,
+    pub token_program: usize,
+    pub associated_token_program: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdatePoolCreator {
+    type ArrangedAccountIndices = UpdatePoolCreatorInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 10 {
+            return None;
+        }
+
+        Some(UpdatePoolCreatorInstructionAccountIndices {
+            creator: 0,
+            new_creator: 1,
+            virtuals_mint: 2,
+            token_mint: 3,
+            new_creator_virtuals_ata: 4,
+            new_creator_token_ata: 5,
+            vpool: 6,
+            token_program: 7,
+            associated_token_program: 8,
+            system_program: 9,
+            remaining_accounts: 10..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdatePoolCreator {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "creator",
+            "new_creator",
+            "virtuals_mint",
+            "token_mint",
+            "new_creator_virtuals_ata",
+            "new_creator_token_ata",
+            "vpool",
+            "token_program",
+            "associated_token_program",
+            "system_program",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -9,6 +11,7 @@ pub struct AddMarketIndexes {}
 pub struct AddMarketIndexesInstructionAccounts {
     pub market_indexes: solana_pubkey::Pubkey,
     pub zeta_group: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for AddMarketIndexes {
@@ -17,13 +20,68 @@ impl carbon_core::deserialize::ArrangeAccounts for AddMarketIndexes {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [market_indexes, zeta_group, _remaining @ ..] = accounts else {
+        let [market_indexes, zeta_group, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(AddMarketIndexesInstructionAccounts {
             market_indexes: market_indexes.pubkey,
             zeta_group: zeta_group.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct AddMarketIndexesInstructionAccountMetas {
+    pub market_indexes: solana_instruction::AccountMeta,
+    pub zeta_group: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for AddMarketIndexes {
+    type ArrangedAccountMetas = AddMarketIndexesInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [market_indexes, zeta_group, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(AddMarketIndexesInstructionAccountMetas {
+            market_indexes: market_indexes.clone(),
+            zeta_group: zeta_group.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct AddMarketIndexesInstructionAccountIndices {
+    pub market_indexes: usize,
+    pub zeta_group: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for AddMarketIndexes {
+    type ArrangedAccountIndices = AddMarketIndexesInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(AddMarketIndexesInstructionAccountIndices {
+            market_indexes: 0,
+            zeta_group: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for AddMarketIndexes {
+    fn account_names() -> &'static [&'static str] {
+        &["market_indexes", "zeta_group"]
+    }
+}
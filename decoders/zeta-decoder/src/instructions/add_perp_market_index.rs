@@ -1,7 +1,9 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +15,7 @@ pub struct AddPerpMarketIndex {
 pub struct AddPerpMarketIndexInstructionAccounts {
     pub market_indexes: solana_pubkey::Pubkey,
     pub pricing: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for AddPerpMarketIndex {
@@ -21,13 +24,68 @@ impl carbon_core::deserialize::ArrangeAccounts for AddPerpMarketIndex {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [market_indexes, pricing, _remaining @ ..] = accounts else {
+        let [market_indexes, pricing, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(AddPerpMarketIndexInstructionAccounts {
             market_indexes: market_indexes.pubkey,
             pricing: pricing.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct AddPerpMarketIndexInstructionAccountMetas {
+    pub market_indexes: solana_instruction::AccountMeta,
+    pub pricing: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for AddPerpMarketIndex {
+    type ArrangedAccountMetas = AddPerpMarketIndexInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [market_indexes, pricing, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(AddPerpMarketIndexInstructionAccountMetas {
+            market_indexes: market_indexes.clone(),
+            pricing: pricing.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct AddPerpMarketIndexInstructionAccountIndices {
+    pub market_indexes: usize,
+    pub pricing: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for AddPerpMarketIndex {
+    type ArrangedAccountIndices = AddPerpMarketIndexInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(AddPerpMarketIndexInstructionAccountIndices {
+            market_indexes: 0,
+            pricing: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for AddPerpMarketIndex {
+    fn account_names() -> &'static [&'static str] {
+        &["market_indexes", "pricing"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -16,6 +18,7 @@ pub struct AdminCrankEventQueueInstructionAccounts {
     pub dex_program: solana_pubkey::Pubkey,
     pub serum_authority: solana_pubkey::Pubkey,
     pub open_orders: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for AdminCrankEventQueue {
@@ -24,7 +27,7 @@ impl carbon_core::deserialize::ArrangeAccounts for AdminCrankEventQueue {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, authority, market, event_queue, dex_program, serum_authority, open_orders, _remaining @ ..] =
+        let [state, authority, market, event_queue, dex_program, serum_authority, open_orders, remaining @ ..] =
             accounts
         else {
             return None;
@@ -38,6 +41,91 @@ impl carbon_core::deserialize::ArrangeAccounts for AdminCrankEventQueue {
             dex_program: dex_program.pubkey,
             serum_authority: serum_authority.pubkey,
             open_orders: open_orders.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct AdminCrankEventQueueInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub market: solana_instruction::AccountMeta,
+    pub event_queue: solana_instruction::AccountMeta,
+    pub dex_program: solana_instruction::AccountMeta,
+    pub serum_authority: solana_instruction::AccountMeta,
+    pub open_orders: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for AdminCrankEventQueue {
+    type ArrangedAccountMetas = AdminCrankEventQueueInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, authority, market, event_queue, dex_program, serum_authority, open_orders, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(AdminCrankEventQueueInstructionAccountMetas {
+            state: state.clone(),
+            authority: authority.clone(),
+            market: market.clone(),
+            event_queue: event_queue.clone(),
+            dex_program: dex_program.clone(),
+            serum_authority: serum_authority.clone(),
+            open_orders: open_orders.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct AdminCrankEventQueueInstructionAccountIndices {
+    pub state: usize,
+    pub authority: usize,
+    pub market: usize,
+    pub event_queue: usize,
+    pub dex_program: usize,
+    pub serum_authority: usize,
+    pub open_orders: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for AdminCrankEventQueue {
+    type ArrangedAccountIndices = AdminCrankEventQueueInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 7 {
+            return None;
+        }
+
+        Some(AdminCrankEventQueueInstructionAccountIndices {
+            state: 0,
+            authority: 1,
+            market: 2,
+            event_queue: 3,
+            dex_program: 4,
+            serum_authority: 5,
+            open_orders: 6,
+            remaining_accounts: 7..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for AdminCrankEventQueue {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "authority",
+            "market",
+            "event_queue",
+            "dex_program",
+            "serum_authority",
+            "open_orders",
+        ]
+    }
+}
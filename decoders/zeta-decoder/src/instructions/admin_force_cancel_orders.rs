@@ -1,7 +1,9 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +15,7 @@ pub struct AdminForceCancelOrders {
 pub struct AdminForceCancelOrdersInstructionAccounts {
     pub authority: solana_pubkey::Pubkey,
     pub cancel_accounts: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for AdminForceCancelOrders {
@@ -21,13 +24,68 @@ impl carbon_core::deserialize::ArrangeAccounts for AdminForceCancelOrders {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [authority, cancel_accounts, _remaining @ ..] = accounts else {
+        let [authority, cancel_accounts, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(AdminForceCancelOrdersInstructionAccounts {
             authority: authority.pubkey,
             cancel_accounts: cancel_accounts.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct AdminForceCancelOrdersInstructionAccountMetas {
+    pub authority: solana_instruction::AccountMeta,
+    pub cancel_accounts: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for AdminForceCancelOrders {
+    type ArrangedAccountMetas = AdminForceCancelOrdersInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [authority, cancel_accounts, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(AdminForceCancelOrdersInstructionAccountMetas {
+            authority: authority.clone(),
+            cancel_accounts: cancel_accounts.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct AdminForceCancelOrdersInstructionAccountIndices {
+    pub authority: usize,
+    pub cancel_accounts: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for AdminForceCancelOrders {
+    type ArrangedAccountIndices = AdminForceCancelOrdersInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(AdminForceCancelOrdersInstructionAccountIndices {
+            authority: 0,
+            cancel_accounts: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for AdminForceCancelOrders {
+    fn account_names() -> &'static [&'static str] {
+        &["authority", "cancel_accounts"]
+    }
+}
@@ -1,7 +1,9 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -22,6 +24,7 @@ pub struct AdminResetDexOpenOrdersInstructionAccounts {
     pub asks: solana_pubkey::Pubkey,
     pub serum_authority: solana_pubkey::Pubkey,
     pub event_queue: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for AdminResetDexOpenOrders {
@@ -30,7 +33,7 @@ impl carbon_core::deserialize::ArrangeAccounts for AdminResetDexOpenOrders {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, dex_program, open_orders, cross_margin_account, pricing_admin, authority, market, bids, asks, serum_authority, event_queue, _remaining @ ..] =
+        let [state, dex_program, open_orders, cross_margin_account, pricing_admin, authority, market, bids, asks, serum_authority, event_queue, remaining @ ..] =
             accounts
         else {
             return None;
@@ -48,6 +51,111 @@ impl carbon_core::deserialize::ArrangeAccounts for AdminResetDexOpenOrders {
             asks: asks.pubkey,
             serum_authority: serum_authority.pubkey,
             event_queue: event_queue.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct AdminResetDexOpenOrdersInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub dex_program: solana_instruction::AccountMeta,
+    pub open_orders: solana_instruction::AccountMeta,
+    pub cross_margin_account: solana_instruction::AccountMeta,
+    pub pricing_admin: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub market: solana_instruction::AccountMeta,
+    pub bids: solana_instruction::AccountMeta,
+    pub asks: solana_instruction::AccountMeta,
+    pub serum_authority: solana_instruction::AccountMeta,
+    pub event_queue: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for AdminResetDexOpenOrders {
+    type ArrangedAccountMetas = AdminResetDexOpenOrdersInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, dex_program, open_orders, cross_margin_account, pricing_admin, authority, market, bids, asks, serum_authority, event_queue, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(AdminResetDexOpenOrdersInstructionAccountMetas {
+            state: state.clone(),
+            dex_program: dex_program.clone(),
+            open_orders: open_orders.clone(),
+            cross_margin_account: cross_margin_account.clone(),
+            pricing_admin: pricing_admin.clone(),
+            authority: authority.clone(),
+            market: market.clone(),
+            bids: bids.clone(),
+            asks: asks.clone(),
+            serum_authority: serum_authority.clone(),
+            event_queue: event_queue.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct AdminResetDexOpenOrdersInstructionAccountIndices {
+    pub state: usize,
+    pub dex_program: usize,
+    pub open_orders: usize,
+    pub cross_margin_account: usize,
+    pub pricing_admin: usize,
+    pub authority: usize,
+    pub market: usize,
+    pub bids: usize,
+    pub asks: usize,
+    pub serum_authority: usize,
+    pub event_queue: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for AdminResetDexOpenOrders {
+    type ArrangedAccountIndices = AdminResetDexOpenOrdersInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 11 {
+            return None;
+        }
+
+        Some(AdminResetDexOpenOrdersInstructionAccountIndices {
+            state: 0,
+            dex_program: 1,
+            open_orders: 2,
+            cross_margin_account: 3,
+            pricing_admin: 4,
+            authority: 5,
+            market: 6,
+            bids: 7,
+            asks: 8,
+            serum_authority: 9,
+            event_queue: 10,
+            remaining_accounts: 11..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for AdminResetDexOpenOrders {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "dex_program",
+            "open_orders",
+            "cross_margin_account",
+            "pricing_admin",
+            "authority",
+            "market",
+            "bids",
+            "asks",
+            "serum_authority",
+            "event_queue",
+        ]
+    }
+}
@@ -1,7 +1,9 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +15,7 @@ pub struct AdminSetOrderState {
 pub struct AdminSetOrderStateInstructionAccounts {
     pub authority: solana_pubkey::Pubkey,
     pub cancel_accounts: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for AdminSetOrderState {
@@ -21,13 +24,68 @@ impl carbon_core::deserialize::ArrangeAccounts for AdminSetOrderState {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [authority, cancel_accounts, _remaining @ ..] = accounts else {
+        let [authority, cancel_accounts, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(AdminSetOrderStateInstructionAccounts {
             authority: authority.pubkey,
             cancel_accounts: cancel_accounts.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct AdminSetOrderStateInstructionAccountMetas {
+    pub authority: solana_instruction::AccountMeta,
+    pub cancel_accounts: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for AdminSetOrderState {
+    type ArrangedAccountMetas = AdminSetOrderStateInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [authority, cancel_accounts, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(AdminSetOrderStateInstructionAccountMetas {
+            authority: authority.clone(),
+            cancel_accounts: cancel_accounts.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct AdminSetOrderStateInstructionAccountIndices {
+    pub authority: usize,
+    pub cancel_accounts: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for AdminSetOrderState {
+    type ArrangedAccountIndices = AdminSetOrderStateInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(AdminSetOrderStateInstructionAccountIndices {
+            authority: 0,
+            cancel_accounts: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for AdminSetOrderState {
+    fn account_names() -> &'static [&'static str] {
+        &["authority", "cancel_accounts"]
+    }
+}
@@ -1,7 +1,9 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +15,7 @@ pub struct ApplyPerpFunding {
 pub struct ApplyPerpFundingInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub pricing: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ApplyPerpFunding {
@@ -21,13 +24,68 @@ impl carbon_core::deserialize::ArrangeAccounts for ApplyPerpFunding {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, pricing, _remaining @ ..] = accounts else {
+        let [state, pricing, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(ApplyPerpFundingInstructionAccounts {
             state: state.pubkey,
             pricing: pricing.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ApplyPerpFundingInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub pricing: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ApplyPerpFunding {
+    type ArrangedAccountMetas = ApplyPerpFundingInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, pricing, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(ApplyPerpFundingInstructionAccountMetas {
+            state: state.clone(),
+            pricing: pricing.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ApplyPerpFundingInstructionAccountIndices {
+    pub state: usize,
+    pub pricing: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ApplyPerpFunding {
+    type ArrangedAccountIndices = ApplyPerpFundingInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(ApplyPerpFundingInstructionAccountIndices {
+            state: 0,
+            pricing: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ApplyPerpFunding {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "pricing"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +14,7 @@ pub struct BurnVaultTokensInstructionAccounts {
     pub vault: solana_pubkey::Pubkey,
     pub serum_authority: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for BurnVaultTokens {
@@ -20,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for BurnVaultTokens {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, mint, vault, serum_authority, token_program, _remaining @ ..] = accounts else {
+        let [state, mint, vault, serum_authority, token_program, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -30,6 +33,73 @@ impl carbon_core::deserialize::ArrangeAccounts for BurnVaultTokens {
             vault: vault.pubkey,
             serum_authority: serum_authority.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct BurnVaultTokensInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub mint: solana_instruction::AccountMeta,
+    pub vault: solana_instruction::AccountMeta,
+    pub serum_authority: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for BurnVaultTokens {
+    type ArrangedAccountMetas = BurnVaultTokensInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, mint, vault, serum_authority, token_program, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(BurnVaultTokensInstructionAccountMetas {
+            state: state.clone(),
+            mint: mint.clone(),
+            vault: vault.clone(),
+            serum_authority: serum_authority.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct BurnVaultTokensInstructionAccountIndices {
+    pub state: usize,
+    pub mint: usize,
+    pub vault: usize,
+    pub serum_authority: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for BurnVaultTokens {
+    type ArrangedAccountIndices = BurnVaultTokensInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(BurnVaultTokensInstructionAccountIndices {
+            state: 0,
+            mint: 1,
+            vault: 2,
+            serum_authority: 3,
+            token_program: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for BurnVaultTokens {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "mint", "vault", "serum_authority", "token_program"]
+    }
+}
@@ -1,7 +1,9 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +16,7 @@ pub struct CancelOrderHalted {
 
 pub struct CancelOrderHaltedInstructionAccounts {
     pub cancel_accounts: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CancelOrderHalted {
@@ -22,12 +25,63 @@ impl carbon_core::deserialize::ArrangeAccounts for CancelOrderHalted {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [cancel_accounts, _remaining @ ..] = accounts else {
+        let [cancel_accounts, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(CancelOrderHaltedInstructionAccounts {
             cancel_accounts: cancel_accounts.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CancelOrderHaltedInstructionAccountMetas {
+    pub cancel_accounts: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CancelOrderHalted {
+    type ArrangedAccountMetas = CancelOrderHaltedInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [cancel_accounts, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(CancelOrderHaltedInstructionAccountMetas {
+            cancel_accounts: cancel_accounts.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CancelOrderHaltedInstructionAccountIndices {
+    pub cancel_accounts: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CancelOrderHalted {
+    type ArrangedAccountIndices = CancelOrderHaltedInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.is_empty() {
+            return None;
+        }
+
+        Some(CancelOrderHaltedInstructionAccountIndices {
+            cancel_accounts: 0,
+            remaining_accounts: 1..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CancelOrderHalted {
+    fn account_names() -> &'static [&'static str] {
+        &["cancel_accounts"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +16,7 @@ pub struct CancelTriggerOrderInstructionAccounts {
     pub payer: solana_pubkey::Pubkey,
     pub trigger_order: solana_pubkey::Pubkey,
     pub margin_account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CancelTriggerOrder {
@@ -22,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CancelTriggerOrder {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, admin, payer, trigger_order, margin_account, _remaining @ ..] = accounts else {
+        let [state, admin, payer, trigger_order, margin_account, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -32,6 +35,73 @@ impl carbon_core::deserialize::ArrangeAccounts for CancelTriggerOrder {
             payer: payer.pubkey,
             trigger_order: trigger_order.pubkey,
             margin_account: margin_account.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CancelTriggerOrderInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub trigger_order: solana_instruction::AccountMeta,
+    pub margin_account: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CancelTriggerOrder {
+    type ArrangedAccountMetas = CancelTriggerOrderInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, admin, payer, trigger_order, margin_account, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(CancelTriggerOrderInstructionAccountMetas {
+            state: state.clone(),
+            admin: admin.clone(),
+            payer: payer.clone(),
+            trigger_order: trigger_order.clone(),
+            margin_account: margin_account.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CancelTriggerOrderInstructionAccountIndices {
+    pub state: usize,
+    pub admin: usize,
+    pub payer: usize,
+    pub trigger_order: usize,
+    pub margin_account: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CancelTriggerOrder {
+    type ArrangedAccountIndices = CancelTriggerOrderInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(CancelTriggerOrderInstructionAccountIndices {
+            state: 0,
+            admin: 1,
+            payer: 2,
+            trigger_order: 3,
+            margin_account: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CancelTriggerOrder {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "admin", "payer", "trigger_order", "margin_account"]
+    }
+}
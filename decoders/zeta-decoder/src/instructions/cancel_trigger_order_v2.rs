@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +14,7 @@ pub struct CancelTriggerOrderV2InstructionAccounts {
     pub authority: solana_pubkey::Pubkey,
     pub trigger_order: solana_pubkey::Pubkey,
     pub margin_account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CancelTriggerOrderV2 {
@@ -20,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CancelTriggerOrderV2 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [authority, trigger_order, margin_account, _remaining @ ..] = accounts else {
+        let [authority, trigger_order, margin_account, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +31,65 @@ impl carbon_core::deserialize::ArrangeAccounts for CancelTriggerOrderV2 {
             authority: authority.pubkey,
             trigger_order: trigger_order.pubkey,
             margin_account: margin_account.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CancelTriggerOrderV2InstructionAccountMetas {
+    pub authority: solana_instruction::AccountMeta,
+    pub trigger_order: solana_instruction::AccountMeta,
+    pub margin_account: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CancelTriggerOrderV2 {
+    type ArrangedAccountMetas = CancelTriggerOrderV2InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [authority, trigger_order, margin_account, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(CancelTriggerOrderV2InstructionAccountMetas {
+            authority: authority.clone(),
+            trigger_order: trigger_order.clone(),
+            margin_account: margin_account.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CancelTriggerOrderV2InstructionAccountIndices {
+    pub authority: usize,
+    pub trigger_order: usize,
+    pub margin_account: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CancelTriggerOrderV2 {
+    type ArrangedAccountIndices = CancelTriggerOrderV2InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(CancelTriggerOrderV2InstructionAccountIndices {
+            authority: 0,
+            trigger_order: 1,
+            margin_account: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CancelTriggerOrderV2 {
+    fn account_names() -> &'static [&'static str] {
+        &["authority", "trigger_order", "margin_account"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +13,7 @@ pub struct ChooseAirdropCommunity {
 pub struct ChooseAirdropCommunityInstructionAccounts {
     pub cross_margin_account_manager: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ChooseAirdropCommunity {
@@ -19,13 +22,68 @@ impl carbon_core::deserialize::ArrangeAccounts for ChooseAirdropCommunity {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [cross_margin_account_manager, authority, _remaining @ ..] = accounts else {
+        let [cross_margin_account_manager, authority, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(ChooseAirdropCommunityInstructionAccounts {
             cross_margin_account_manager: cross_margin_account_manager.pubkey,
             authority: authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ChooseAirdropCommunityInstructionAccountMetas {
+    pub cross_margin_account_manager: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ChooseAirdropCommunity {
+    type ArrangedAccountMetas = ChooseAirdropCommunityInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [cross_margin_account_manager, authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(ChooseAirdropCommunityInstructionAccountMetas {
+            cross_margin_account_manager: cross_margin_account_manager.clone(),
+            authority: authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ChooseAirdropCommunityInstructionAccountIndices {
+    pub cross_margin_account_manager: usize,
+    pub authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ChooseAirdropCommunity {
+    type ArrangedAccountIndices = ChooseAirdropCommunityInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(ChooseAirdropCommunityInstructionAccountIndices {
+            cross_margin_account_manager: 0,
+            authority: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ChooseAirdropCommunity {
+    fn account_names() -> &'static [&'static str] {
+        &["cross_margin_account_manager", "authority"]
+    }
+}
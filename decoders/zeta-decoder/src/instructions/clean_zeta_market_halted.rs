@@ -1,7 +1,9 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -15,6 +17,7 @@ pub struct CleanZetaMarketHaltedInstructionAccounts {
     pub market: solana_pubkey::Pubkey,
     pub bids: solana_pubkey::Pubkey,
     pub asks: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CleanZetaMarketHalted {
@@ -23,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CleanZetaMarketHalted {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, market, bids, asks, _remaining @ ..] = accounts else {
+        let [state, market, bids, asks, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -32,6 +35,69 @@ impl carbon_core::deserialize::ArrangeAccounts for CleanZetaMarketHalted {
             market: market.pubkey,
             bids: bids.pubkey,
             asks: asks.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CleanZetaMarketHaltedInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub market: solana_instruction::AccountMeta,
+    pub bids: solana_instruction::AccountMeta,
+    pub asks: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CleanZetaMarketHalted {
+    type ArrangedAccountMetas = CleanZetaMarketHaltedInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, market, bids, asks, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(CleanZetaMarketHaltedInstructionAccountMetas {
+            state: state.clone(),
+            market: market.clone(),
+            bids: bids.clone(),
+            asks: asks.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CleanZetaMarketHaltedInstructionAccountIndices {
+    pub state: usize,
+    pub market: usize,
+    pub bids: usize,
+    pub asks: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CleanZetaMarketHalted {
+    type ArrangedAccountIndices = CleanZetaMarketHaltedInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(CleanZetaMarketHaltedInstructionAccountIndices {
+            state: 0,
+            market: 1,
+            bids: 2,
+            asks: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CleanZetaMarketHalted {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "market", "bids", "asks"]
+    }
+}
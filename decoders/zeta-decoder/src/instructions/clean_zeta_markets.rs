@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -9,6 +11,7 @@ pub struct CleanZetaMarkets {}
 pub struct CleanZetaMarketsInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub zeta_group: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CleanZetaMarkets {
@@ -17,13 +20,68 @@ impl carbon_core::deserialize::ArrangeAccounts for CleanZetaMarkets {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, zeta_group, _remaining @ ..] = accounts else {
+        let [state, zeta_group, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(CleanZetaMarketsInstructionAccounts {
             state: state.pubkey,
             zeta_group: zeta_group.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CleanZetaMarketsInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub zeta_group: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CleanZetaMarkets {
+    type ArrangedAccountMetas = CleanZetaMarketsInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, zeta_group, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(CleanZetaMarketsInstructionAccountMetas {
+            state: state.clone(),
+            zeta_group: zeta_group.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CleanZetaMarketsInstructionAccountIndices {
+    pub state: usize,
+    pub zeta_group: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CleanZetaMarkets {
+    type ArrangedAccountIndices = CleanZetaMarketsInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(CleanZetaMarketsInstructionAccountIndices {
+            state: 0,
+            zeta_group: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CleanZetaMarkets {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "zeta_group"]
+    }
+}
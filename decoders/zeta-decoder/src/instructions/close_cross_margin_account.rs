@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +14,7 @@ pub struct CloseCrossMarginAccountInstructionAccounts {
     pub cross_margin_account: solana_pubkey::Pubkey,
     pub cross_margin_account_manager: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CloseCrossMarginAccount {
@@ -20,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CloseCrossMarginAccount {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [cross_margin_account, cross_margin_account_manager, authority, _remaining @ ..] =
+        let [cross_margin_account, cross_margin_account_manager, authority, remaining @ ..] =
             accounts
         else {
             return None;
@@ -30,6 +33,71 @@ impl carbon_core::deserialize::ArrangeAccounts for CloseCrossMarginAccount {
             cross_margin_account: cross_margin_account.pubkey,
             cross_margin_account_manager: cross_margin_account_manager.pubkey,
             authority: authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CloseCrossMarginAccountInstructionAccountMetas {
+    pub cross_margin_account: solana_instruction::AccountMeta,
+    pub cross_margin_account_manager: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CloseCrossMarginAccount {
+    type ArrangedAccountMetas = CloseCrossMarginAccountInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [cross_margin_account, cross_margin_account_manager, authority, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CloseCrossMarginAccountInstructionAccountMetas {
+            cross_margin_account: cross_margin_account.clone(),
+            cross_margin_account_manager: cross_margin_account_manager.clone(),
+            authority: authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CloseCrossMarginAccountInstructionAccountIndices {
+    pub cross_margin_account: usize,
+    pub cross_margin_account_manager: usize,
+    pub authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CloseCrossMarginAccount {
+    type ArrangedAccountIndices = CloseCrossMarginAccountInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(CloseCrossMarginAccountInstructionAccountIndices {
+            cross_margin_account: 0,
+            cross_margin_account_manager: 1,
+            authority: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CloseCrossMarginAccount {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "cross_margin_account",
+            "cross_margin_account_manager",
+            "authority",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -9,6 +11,7 @@ pub struct CloseCrossMarginAccountManager {}
 pub struct CloseCrossMarginAccountManagerInstructionAccounts {
     pub cross_margin_account_manager: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CloseCrossMarginAccountManager {
@@ -17,13 +20,68 @@ impl carbon_core::deserialize::ArrangeAccounts for CloseCrossMarginAccountManage
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [cross_margin_account_manager, authority, _remaining @ ..] = accounts else {
+        let [cross_margin_account_manager, authority, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(CloseCrossMarginAccountManagerInstructionAccounts {
             cross_margin_account_manager: cross_margin_account_manager.pubkey,
             authority: authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CloseCrossMarginAccountManagerInstructionAccountMetas {
+    pub cross_margin_account_manager: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CloseCrossMarginAccountManager {
+    type ArrangedAccountMetas = CloseCrossMarginAccountManagerInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [cross_margin_account_manager, authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(CloseCrossMarginAccountManagerInstructionAccountMetas {
+            cross_margin_account_manager: cross_margin_account_manager.clone(),
+            authority: authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CloseCrossMarginAccountManagerInstructionAccountIndices {
+    pub cross_margin_account_manager: usize,
+    pub authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CloseCrossMarginAccountManager {
+    type ArrangedAccountIndices = CloseCrossMarginAccountManagerInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(CloseCrossMarginAccountManagerInstructionAccountIndices {
+            cross_margin_account_manager: 0,
+            authority: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CloseCrossMarginAccountManager {
+    fn account_names() -> &'static [&'static str] {
+        &["cross_margin_account_manager", "authority"]
+    }
+}
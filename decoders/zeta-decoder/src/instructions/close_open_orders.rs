@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -18,6 +20,7 @@ pub struct CloseOpenOrdersInstructionAccounts {
     pub market: solana_pubkey::Pubkey,
     pub serum_authority: solana_pubkey::Pubkey,
     pub open_orders_map: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CloseOpenOrders {
@@ -26,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CloseOpenOrders {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, zeta_group, dex_program, open_orders, margin_account, authority, market, serum_authority, open_orders_map, _remaining @ ..] =
+        let [state, zeta_group, dex_program, open_orders, margin_account, authority, market, serum_authority, open_orders_map, remaining @ ..] =
             accounts
         else {
             return None;
@@ -42,6 +45,101 @@ impl carbon_core::deserialize::ArrangeAccounts for CloseOpenOrders {
             market: market.pubkey,
             serum_authority: serum_authority.pubkey,
             open_orders_map: open_orders_map.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CloseOpenOrdersInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub zeta_group: solana_instruction::AccountMeta,
+    pub dex_program: solana_instruction::AccountMeta,
+    pub open_orders: solana_instruction::AccountMeta,
+    pub margin_account: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub market: solana_instruction::AccountMeta,
+    pub serum_authority: solana_instruction::AccountMeta,
+    pub open_orders_map: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CloseOpenOrders {
+    type ArrangedAccountMetas = CloseOpenOrdersInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, zeta_group, dex_program, open_orders, margin_account, authority, market, serum_authority, open_orders_map, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CloseOpenOrdersInstructionAccountMetas {
+            state: state.clone(),
+            zeta_group: zeta_group.clone(),
+            dex_program: dex_program.clone(),
+            open_orders: open_orders.clone(),
+            margin_account: margin_account.clone(),
+            authority: authority.clone(),
+            market: market.clone(),
+            serum_authority: serum_authority.clone(),
+            open_orders_map: open_orders_map.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CloseOpenOrdersInstructionAccountIndices {
+    pub state: usize,
+    pub zeta_group: usize,
+    pub dex_program: usize,
+    pub open_orders: usize,
+    pub margin_account: usize,
+    pub authority: usize,
+    pub market: usize,
+    pub serum_authority: usize,
+    pub open_orders_map: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CloseOpenOrders {
+    type ArrangedAccountIndices = CloseOpenOrdersInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 9 {
+            return None;
+        }
+
+        Some(CloseOpenOrdersInstructionAccountIndices {
+            state: 0,
+            zeta_group: 1,
+            dex_program: 2,
+            open_orders: 3,
+            margin_account: 4,
+            authority: 5,
+            market: 6,
+            serum_authority: 7,
+            open_orders_map: 8,
+            remaining_accounts: 9..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CloseOpenOrders {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "zeta_group",
+            "dex_program",
+            "open_orders",
+            "margin_account",
+            "authority",
+            "market",
+            "serum_authority",
+            "open_orders_map",
+        ]
+    }
+}
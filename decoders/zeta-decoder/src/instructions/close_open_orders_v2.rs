@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -17,6 +19,7 @@ pub struct CloseOpenOrdersV2InstructionAccounts {
     pub market: solana_pubkey::Pubkey,
     pub serum_authority: solana_pubkey::Pubkey,
     pub open_orders_map: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CloseOpenOrdersV2 {
@@ -25,7 +28,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CloseOpenOrdersV2 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, dex_program, open_orders, margin_account, authority, market, serum_authority, open_orders_map, _remaining @ ..] =
+        let [state, dex_program, open_orders, margin_account, authority, market, serum_authority, open_orders_map, remaining @ ..] =
             accounts
         else {
             return None;
@@ -40,6 +43,96 @@ impl carbon_core::deserialize::ArrangeAccounts for CloseOpenOrdersV2 {
             market: market.pubkey,
             serum_authority: serum_authority.pubkey,
             open_orders_map: open_orders_map.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CloseOpenOrdersV2InstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub dex_program: solana_instruction::AccountMeta,
+    pub open_orders: solana_instruction::AccountMeta,
+    pub margin_account: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub market: solana_instruction::AccountMeta,
+    pub serum_authority: solana_instruction::AccountMeta,
+    pub open_orders_map: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CloseOpenOrdersV2 {
+    type ArrangedAccountMetas = CloseOpenOrdersV2InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, dex_program, open_orders, margin_account, authority, market, serum_authority, open_orders_map, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CloseOpenOrdersV2InstructionAccountMetas {
+            state: state.clone(),
+            dex_program: dex_program.clone(),
+            open_orders: open_orders.clone(),
+            margin_account: margin_account.clone(),
+            authority: authority.clone(),
+            market: market.clone(),
+            serum_authority: serum_authority.clone(),
+            open_orders_map: open_orders_map.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CloseOpenOrdersV2InstructionAccountIndices {
+    pub state: usize,
+    pub dex_program: usize,
+    pub open_orders: usize,
+    pub margin_account: usize,
+    pub authority: usize,
+    pub market: usize,
+    pub serum_authority: usize,
+    pub open_orders_map: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CloseOpenOrdersV2 {
+    type ArrangedAccountIndices = CloseOpenOrdersV2InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(CloseOpenOrdersV2InstructionAccountIndices {
+            state: 0,
+            dex_program: 1,
+            open_orders: 2,
+            margin_account: 3,
+            authority: 4,
+            market: 5,
+            serum_authority: 6,
+            open_orders_map: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CloseOpenOrdersV2 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "dex_program",
+            "open_orders",
+            "margin_account",
+            "authority",
+            "market",
+            "serum_authority",
+            "open_orders_map",
+        ]
+    }
+}
@@ -1,7 +1,9 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -21,6 +23,7 @@ pub struct CloseOpenOrdersV3InstructionAccounts {
     pub market: solana_pubkey::Pubkey,
     pub serum_authority: solana_pubkey::Pubkey,
     pub open_orders_map: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CloseOpenOrdersV3 {
@@ -29,7 +32,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CloseOpenOrdersV3 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, pricing, dex_program, open_orders, cross_margin_account, authority, market, serum_authority, open_orders_map, _remaining @ ..] =
+        let [state, pricing, dex_program, open_orders, cross_margin_account, authority, market, serum_authority, open_orders_map, remaining @ ..] =
             accounts
         else {
             return None;
@@ -45,6 +48,101 @@ impl carbon_core::deserialize::ArrangeAccounts for CloseOpenOrdersV3 {
             market: market.pubkey,
             serum_authority: serum_authority.pubkey,
             open_orders_map: open_orders_map.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CloseOpenOrdersV3InstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub pricing: solana_instruction::AccountMeta,
+    pub dex_program: solana_instruction::AccountMeta,
+    pub open_orders: solana_instruction::AccountMeta,
+    pub cross_margin_account: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub market: solana_instruction::AccountMeta,
+    pub serum_authority: solana_instruction::AccountMeta,
+    pub open_orders_map: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CloseOpenOrdersV3 {
+    type ArrangedAccountMetas = CloseOpenOrdersV3InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, pricing, dex_program, open_orders, cross_margin_account, authority, market, serum_authority, open_orders_map, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CloseOpenOrdersV3InstructionAccountMetas {
+            state: state.clone(),
+            pricing: pricing.clone(),
+            dex_program: dex_program.clone(),
+            open_orders: open_orders.clone(),
+            cross_margin_account: cross_margin_account.clone(),
+            authority: authority.clone(),
+            market: market.clone(),
+            serum_authority: serum_authority.clone(),
+            open_orders_map: open_orders_map.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CloseOpenOrdersV3InstructionAccountIndices {
+    pub state: usize,
+    pub pricing: usize,
+    pub dex_program: usize,
+    pub open_orders: usize,
+    pub cross_margin_account: usize,
+    pub authority: usize,
+    pub market: usize,
+    pub serum_authority: usize,
+    pub open_orders_map: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CloseOpenOrdersV3 {
+    type ArrangedAccountIndices = CloseOpenOrdersV3InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 9 {
+            return None;
+        }
+
+        Some(CloseOpenOrdersV3InstructionAccountIndices {
+            state: 0,
+            pricing: 1,
+            dex_program: 2,
+            open_orders: 3,
+            cross_margin_account: 4,
+            authority: 5,
+            market: 6,
+            serum_authority: 7,
+            open_orders_map: 8,
+            remaining_accounts: 9..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CloseOpenOrdersV3 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "pricing",
+            "dex_program",
+            "open_orders",
+            "cross_margin_account",
+            "authority",
+            "market",
+            "serum_authority",
+            "open_orders_map",
+        ]
+    }
+}
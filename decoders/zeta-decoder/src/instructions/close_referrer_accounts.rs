@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -10,6 +12,7 @@ pub struct CloseReferrerAccountsInstructionAccounts {
     pub referrer_id_account: solana_pubkey::Pubkey,
     pub referrer_pubkey_account: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CloseReferrerAccounts {
@@ -18,7 +21,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CloseReferrerAccounts {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [referrer_id_account, referrer_pubkey_account, authority, _remaining @ ..] = accounts
+        let [referrer_id_account, referrer_pubkey_account, authority, remaining @ ..] = accounts
         else {
             return None;
         };
@@ -27,6 +30,70 @@ impl carbon_core::deserialize::ArrangeAccounts for CloseReferrerAccounts {
             referrer_id_account: referrer_id_account.pubkey,
             referrer_pubkey_account: referrer_pubkey_account.pubkey,
             authority: authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CloseReferrerAccountsInstructionAccountMetas {
+    pub referrer_id_account: solana_instruction::AccountMeta,
+    pub referrer_pubkey_account: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CloseReferrerAccounts {
+    type ArrangedAccountMetas = CloseReferrerAccountsInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [referrer_id_account, referrer_pubkey_account, authority, remaining @ ..] = accounts
+        else {
+            return None;
+        };
+
+        Some(CloseReferrerAccountsInstructionAccountMetas {
+            referrer_id_account: referrer_id_account.clone(),
+            referrer_pubkey_account: referrer_pubkey_account.clone(),
+            authority: authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CloseReferrerAccountsInstructionAccountIndices {
+    pub referrer_id_account: usize,
+    pub referrer_pubkey_account: usize,
+    pub authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CloseReferrerAccounts {
+    type ArrangedAccountIndices = CloseReferrerAccountsInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(CloseReferrerAccountsInstructionAccountIndices {
+            referrer_id_account: 0,
+            referrer_pubkey_account: 1,
+            authority: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CloseReferrerAccounts {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "referrer_id_account",
+            "referrer_pubkey_account",
+            "authority",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -10,6 +12,7 @@ pub struct CloseSpreadAccountInstructionAccounts {
     pub spread_account: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
     pub zeta_group: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CloseSpreadAccount {
@@ -18,7 +21,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CloseSpreadAccount {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [spread_account, authority, zeta_group, _remaining @ ..] = accounts else {
+        let [spread_account, authority, zeta_group, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -26,6 +29,65 @@ impl carbon_core::deserialize::ArrangeAccounts for CloseSpreadAccount {
             spread_account: spread_account.pubkey,
             authority: authority.pubkey,
             zeta_group: zeta_group.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CloseSpreadAccountInstructionAccountMetas {
+    pub spread_account: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub zeta_group: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CloseSpreadAccount {
+    type ArrangedAccountMetas = CloseSpreadAccountInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [spread_account, authority, zeta_group, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(CloseSpreadAccountInstructionAccountMetas {
+            spread_account: spread_account.clone(),
+            authority: authority.clone(),
+            zeta_group: zeta_group.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CloseSpreadAccountInstructionAccountIndices {
+    pub spread_account: usize,
+    pub authority: usize,
+    pub zeta_group: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CloseSpreadAccount {
+    type ArrangedAccountIndices = CloseSpreadAccountInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(CloseSpreadAccountInstructionAccountIndices {
+            spread_account: 0,
+            authority: 1,
+            zeta_group: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CloseSpreadAccount {
+    fn account_names() -> &'static [&'static str] {
+        &["spread_account", "authority", "zeta_group"]
+    }
+}
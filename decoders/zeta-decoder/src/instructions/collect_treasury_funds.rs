@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +16,7 @@ pub struct CollectTreasuryFundsInstructionAccounts {
     pub collection_token_account: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CollectTreasuryFunds {
@@ -22,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CollectTreasuryFunds {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, treasury_wallet, collection_token_account, token_program, admin, _remaining @ ..] =
+        let [state, treasury_wallet, collection_token_account, token_program, admin, remaining @ ..] =
             accounts
         else {
             return None;
@@ -34,6 +37,81 @@ impl carbon_core::deserialize::ArrangeAccounts for CollectTreasuryFunds {
             collection_token_account: collection_token_account.pubkey,
             token_program: token_program.pubkey,
             admin: admin.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CollectTreasuryFundsInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub treasury_wallet: solana_instruction::AccountMeta,
+    pub collection_token_account: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CollectTreasuryFunds {
+    type ArrangedAccountMetas = CollectTreasuryFundsInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, treasury_wallet, collection_token_account, token_program, admin, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CollectTreasuryFundsInstructionAccountMetas {
+            state: state.clone(),
+            treasury_wallet: treasury_wallet.clone(),
+            collection_token_account: collection_token_account.clone(),
+            token_program: token_program.clone(),
+            admin: admin.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CollectTreasuryFundsInstructionAccountIndices {
+    pub state: usize,
+    pub treasury_wallet: usize,
+    pub collection_token_account: usize,
+    pub token_program: usize,
+    pub admin: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CollectTreasuryFunds {
+    type ArrangedAccountIndices = CollectTreasuryFundsInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(CollectTreasuryFundsInstructionAccountIndices {
+            state: 0,
+            treasury_wallet: 1,
+            collection_token_account: 2,
+            token_program: 3,
+            admin: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CollectTreasuryFunds {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "treasury_wallet",
+            "collection_token_account",
+            "token_program",
+            "admin",
+        ]
+    }
+}
@@ -1,7 +1,9 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -18,6 +20,7 @@ pub struct CrankEventQueueInstructionAccounts {
     pub dex_program: solana_pubkey::Pubkey,
     pub serum_authority: solana_pubkey::Pubkey,
     pub perp_sync_queue: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for CrankEventQueue {
@@ -26,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for CrankEventQueue {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, pricing, market, event_queue, dex_program, serum_authority, perp_sync_queue, _remaining @ ..] =
+        let [state, pricing, market, event_queue, dex_program, serum_authority, perp_sync_queue, remaining @ ..] =
             accounts
         else {
             return None;
@@ -40,6 +43,91 @@ impl carbon_core::deserialize::ArrangeAccounts for CrankEventQueue {
             dex_program: dex_program.pubkey,
             serum_authority: serum_authority.pubkey,
             perp_sync_queue: perp_sync_queue.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct CrankEventQueueInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub pricing: solana_instruction::AccountMeta,
+    pub market: solana_instruction::AccountMeta,
+    pub event_queue: solana_instruction::AccountMeta,
+    pub dex_program: solana_instruction::AccountMeta,
+    pub serum_authority: solana_instruction::AccountMeta,
+    pub perp_sync_queue: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for CrankEventQueue {
+    type ArrangedAccountMetas = CrankEventQueueInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, pricing, market, event_queue, dex_program, serum_authority, perp_sync_queue, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CrankEventQueueInstructionAccountMetas {
+            state: state.clone(),
+            pricing: pricing.clone(),
+            market: market.clone(),
+            event_queue: event_queue.clone(),
+            dex_program: dex_program.clone(),
+            serum_authority: serum_authority.clone(),
+            perp_sync_queue: perp_sync_queue.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct CrankEventQueueInstructionAccountIndices {
+    pub state: usize,
+    pub pricing: usize,
+    pub market: usize,
+    pub event_queue: usize,
+    pub dex_program: usize,
+    pub serum_authority: usize,
+    pub perp_sync_queue: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for CrankEventQueue {
+    type ArrangedAccountIndices = CrankEventQueueInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 7 {
+            return None;
+        }
+
+        Some(CrankEventQueueInstructionAccountIndices {
+            state: 0,
+            pricing: 1,
+            market: 2,
+            event_queue: 3,
+            dex_program: 4,
+            serum_authority: 5,
+            perp_sync_queue: 6,
+            remaining_accounts: 7..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for CrankEventQueue {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "pricing",
+            "market",
+            "event_queue",
+            "dex_program",
+            "serum_authority",
+            "perp_sync_queue",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -18,6 +20,7 @@ pub struct DepositInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub state: solana_pubkey::Pubkey,
     pub greeks: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Deposit {
@@ -26,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Deposit {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [zeta_group, margin_account, vault, user_token_account, socialized_loss_account, authority, token_program, state, greeks, _remaining @ ..] =
+        let [zeta_group, margin_account, vault, user_token_account, socialized_loss_account, authority, token_program, state, greeks, remaining @ ..] =
             accounts
         else {
             return None;
@@ -42,6 +45,101 @@ impl carbon_core::deserialize::ArrangeAccounts for Deposit {
             token_program: token_program.pubkey,
             state: state.pubkey,
             greeks: greeks.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DepositInstructionAccountMetas {
+    pub zeta_group: solana_instruction::AccountMeta,
+    pub margin_account: solana_instruction::AccountMeta,
+    pub vault: solana_instruction::AccountMeta,
+    pub user_token_account: solana_instruction::AccountMeta,
+    pub socialized_loss_account: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub greeks: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Deposit {
+    type ArrangedAccountMetas = DepositInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [zeta_group, margin_account, vault, user_token_account, socialized_loss_account, authority, token_program, state, greeks, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(DepositInstructionAccountMetas {
+            zeta_group: zeta_group.clone(),
+            margin_account: margin_account.clone(),
+            vault: vault.clone(),
+            user_token_account: user_token_account.clone(),
+            socialized_loss_account: socialized_loss_account.clone(),
+            authority: authority.clone(),
+            token_program: token_program.clone(),
+            state: state.clone(),
+            greeks: greeks.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DepositInstructionAccountIndices {
+    pub zeta_group: usize,
+    pub margin_account: usize,
+    pub vault: usize,
+    pub user_token_account: usize,
+    pub socialized_loss_account: usize,
+    pub authority: usize,
+    pub token_program: usize,
+    pub state: usize,
+    pub greeks: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Deposit {
+    type ArrangedAccountIndices = DepositInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 9 {
+            return None;
+        }
+
+        Some(DepositInstructionAccountIndices {
+            zeta_group: 0,
+            margin_account: 1,
+            vault: 2,
+            user_token_account: 3,
+            socialized_loss_account: 4,
+            authority: 5,
+            token_program: 6,
+            state: 7,
+            greeks: 8,
+            remaining_accounts: 9..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Deposit {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "zeta_group",
+            "margin_account",
+            "vault",
+            "user_token_account",
+            "socialized_loss_account",
+            "authority",
+            "token_program",
+            "state",
+            "greeks",
+        ]
+    }
+}
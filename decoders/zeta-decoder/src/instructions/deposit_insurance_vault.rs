@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -17,6 +19,7 @@ pub struct DepositInsuranceVaultInstructionAccounts {
     pub socialized_loss_account: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for DepositInsuranceVault {
@@ -25,7 +28,7 @@ impl carbon_core::deserialize::ArrangeAccounts for DepositInsuranceVault {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, insurance_vault, insurance_deposit_account, user_token_account, zeta_vault, socialized_loss_account, authority, token_program, _remaining @ ..] =
+        let [state, insurance_vault, insurance_deposit_account, user_token_account, zeta_vault, socialized_loss_account, authority, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -40,6 +43,96 @@ impl carbon_core::deserialize::ArrangeAccounts for DepositInsuranceVault {
             socialized_loss_account: socialized_loss_account.pubkey,
             authority: authority.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DepositInsuranceVaultInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub insurance_vault: solana_instruction::AccountMeta,
+    pub insurance_deposit_account: solana_instruction::AccountMeta,
+    pub user_token_account: solana_instruction::AccountMeta,
+    pub zeta_vault: solana_instruction::AccountMeta,
+    pub socialized_loss_account: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for DepositInsuranceVault {
+    type ArrangedAccountMetas = DepositInsuranceVaultInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, insurance_vault, insurance_deposit_account, user_token_account, zeta_vault, socialized_loss_account, authority, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(DepositInsuranceVaultInstructionAccountMetas {
+            state: state.clone(),
+            insurance_vault: insurance_vault.clone(),
+            insurance_deposit_account: insurance_deposit_account.clone(),
+            user_token_account: user_token_account.clone(),
+            zeta_vault: zeta_vault.clone(),
+            socialized_loss_account: socialized_loss_account.clone(),
+            authority: authority.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DepositInsuranceVaultInstructionAccountIndices {
+    pub state: usize,
+    pub insurance_vault: usize,
+    pub insurance_deposit_account: usize,
+    pub user_token_account: usize,
+    pub zeta_vault: usize,
+    pub socialized_loss_account: usize,
+    pub authority: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for DepositInsuranceVault {
+    type ArrangedAccountIndices = DepositInsuranceVaultInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(DepositInsuranceVaultInstructionAccountIndices {
+            state: 0,
+            insurance_vault: 1,
+            insurance_deposit_account: 2,
+            user_token_account: 3,
+            zeta_vault: 4,
+            socialized_loss_account: 5,
+            authority: 6,
+            token_program: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for DepositInsuranceVault {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "insurance_vault",
+            "insurance_deposit_account",
+            "user_token_account",
+            "zeta_vault",
+            "socialized_loss_account",
+            "authority",
+            "token_program",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -18,6 +20,7 @@ pub struct DepositInsuranceVaultV2InstructionAccounts {
     pub socialized_loss_account: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for DepositInsuranceVaultV2 {
@@ -26,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for DepositInsuranceVaultV2 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, pricing, insurance_vault, insurance_deposit_account, user_token_account, zeta_vault, socialized_loss_account, authority, token_program, _remaining @ ..] =
+        let [state, pricing, insurance_vault, insurance_deposit_account, user_token_account, zeta_vault, socialized_loss_account, authority, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -42,6 +45,101 @@ impl carbon_core::deserialize::ArrangeAccounts for DepositInsuranceVaultV2 {
             socialized_loss_account: socialized_loss_account.pubkey,
             authority: authority.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DepositInsuranceVaultV2InstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub pricing: solana_instruction::AccountMeta,
+    pub insurance_vault: solana_instruction::AccountMeta,
+    pub insurance_deposit_account: solana_instruction::AccountMeta,
+    pub user_token_account: solana_instruction::AccountMeta,
+    pub zeta_vault: solana_instruction::AccountMeta,
+    pub socialized_loss_account: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for DepositInsuranceVaultV2 {
+    type ArrangedAccountMetas = DepositInsuranceVaultV2InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, pricing, insurance_vault, insurance_deposit_account, user_token_account, zeta_vault, socialized_loss_account, authority, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(DepositInsuranceVaultV2InstructionAccountMetas {
+            state: state.clone(),
+            pricing: pricing.clone(),
+            insurance_vault: insurance_vault.clone(),
+            insurance_deposit_account: insurance_deposit_account.clone(),
+            user_token_account: user_token_account.clone(),
+            zeta_vault: zeta_vault.clone(),
+            socialized_loss_account: socialized_loss_account.clone(),
+            authority: authority.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DepositInsuranceVaultV2InstructionAccountIndices {
+    pub state: usize,
+    pub pricing: usize,
+    pub insurance_vault: usize,
+    pub insurance_deposit_account: usize,
+    pub user_token_account: usize,
+    pub zeta_vault: usize,
+    pub socialized_loss_account: usize,
+    pub authority: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for DepositInsuranceVaultV2 {
+    type ArrangedAccountIndices = DepositInsuranceVaultV2InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 9 {
+            return None;
+        }
+
+        Some(DepositInsuranceVaultV2InstructionAccountIndices {
+            state: 0,
+            pricing: 1,
+            insurance_vault: 2,
+            insurance_deposit_account: 3,
+            user_token_account: 4,
+            zeta_vault: 5,
+            socialized_loss_account: 6,
+            authority: 7,
+            token_program: 8,
+            remaining_accounts: 9..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for DepositInsuranceVaultV2 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "pricing",
+            "insurance_vault",
+            "insurance_deposit_account",
+            "user_token_account",
+            "zeta_vault",
+            "socialized_loss_account",
+            "authority",
+            "token_program",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -18,6 +20,7 @@ pub struct DepositPermissionlessInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub state: solana_pubkey::Pubkey,
     pub pricing: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for DepositPermissionless {
@@ -26,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for DepositPermissionless {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [cross_margin_account, vault, deposit_token_acc, socialized_loss_account, authority, payer, token_program, state, pricing, _remaining @ ..] =
+        let [cross_margin_account, vault, deposit_token_acc, socialized_loss_account, authority, payer, token_program, state, pricing, remaining @ ..] =
             accounts
         else {
             return None;
@@ -42,6 +45,101 @@ impl carbon_core::deserialize::ArrangeAccounts for DepositPermissionless {
             token_program: token_program.pubkey,
             state: state.pubkey,
             pricing: pricing.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DepositPermissionlessInstructionAccountMetas {
+    pub cross_margin_account: solana_instruction::AccountMeta,
+    pub vault: solana_instruction::AccountMeta,
+    pub deposit_token_acc: solana_instruction::AccountMeta,
+    pub socialized_loss_account: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub pricing: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for DepositPermissionless {
+    type ArrangedAccountMetas = DepositPermissionlessInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [cross_margin_account, vault, deposit_token_acc, socialized_loss_account, authority, payer, token_program, state, pricing, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(DepositPermissionlessInstructionAccountMetas {
+            cross_margin_account: cross_margin_account.clone(),
+            vault: vault.clone(),
+            deposit_token_acc: deposit_token_acc.clone(),
+            socialized_loss_account: socialized_loss_account.clone(),
+            authority: authority.clone(),
+            payer: payer.clone(),
+            token_program: token_program.clone(),
+            state: state.clone(),
+            pricing: pricing.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DepositPermissionlessInstructionAccountIndices {
+    pub cross_margin_account: usize,
+    pub vault: usize,
+    pub deposit_token_acc: usize,
+    pub socialized_loss_account: usize,
+    pub authority: usize,
+    pub payer: usize,
+    pub token_program: usize,
+    pub state: usize,
+    pub pricing: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for DepositPermissionless {
+    type ArrangedAccountIndices = DepositPermissionlessInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 9 {
+            return None;
+        }
+
+        Some(DepositPermissionlessInstructionAccountIndices {
+            cross_margin_account: 0,
+            vault: 1,
+            deposit_token_acc: 2,
+            socialized_loss_account: 3,
+            authority: 4,
+            payer: 5,
+            token_program: 6,
+            state: 7,
+            pricing: 8,
+            remaining_accounts: 9..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for DepositPermissionless {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "cross_margin_account",
+            "vault",
+            "deposit_token_acc",
+            "socialized_loss_account",
+            "authority",
+            "payer",
+            "token_program",
+            "state",
+            "pricing",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -17,6 +19,7 @@ pub struct DepositV2InstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub state: solana_pubkey::Pubkey,
     pub pricing: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for DepositV2 {
@@ -25,7 +28,7 @@ impl carbon_core::deserialize::ArrangeAccounts for DepositV2 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [margin_account, vault, user_token_account, socialized_loss_account, authority, token_program, state, pricing, _remaining @ ..] =
+        let [margin_account, vault, user_token_account, socialized_loss_account, authority, token_program, state, pricing, remaining @ ..] =
             accounts
         else {
             return None;
@@ -40,6 +43,96 @@ impl carbon_core::deserialize::ArrangeAccounts for DepositV2 {
             token_program: token_program.pubkey,
             state: state.pubkey,
             pricing: pricing.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct DepositV2InstructionAccountMetas {
+    pub margin_account: solana_instruction::AccountMeta,
+    pub vault: solana_instruction::AccountMeta,
+    pub user_token_account: solana_instruction::AccountMeta,
+    pub socialized_loss_account: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub pricing: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for DepositV2 {
+    type ArrangedAccountMetas = DepositV2InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [margin_account, vault, user_token_account, socialized_loss_account, authority, token_program, state, pricing, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(DepositV2InstructionAccountMetas {
+            margin_account: margin_account.clone(),
+            vault: vault.clone(),
+            user_token_account: user_token_account.clone(),
+            socialized_loss_account: socialized_loss_account.clone(),
+            authority: authority.clone(),
+            token_program: token_program.clone(),
+            state: state.clone(),
+            pricing: pricing.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct DepositV2InstructionAccountIndices {
+    pub margin_account: usize,
+    pub vault: usize,
+    pub user_token_account: usize,
+    pub socialized_loss_account: usize,
+    pub authority: usize,
+    pub token_program: usize,
+    pub state: usize,
+    pub pricing: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for DepositV2 {
+    type ArrangedAccountIndices = DepositV2InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(DepositV2InstructionAccountIndices {
+            margin_account: 0,
+            vault: 1,
+            user_token_account: 2,
+            socialized_loss_account: 3,
+            authority: 4,
+            token_program: 5,
+            state: 6,
+            pricing: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for DepositV2 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "margin_account",
+            "vault",
+            "user_token_account",
+            "socialized_loss_account",
+            "authority",
+            "token_program",
+            "state",
+            "pricing",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +13,7 @@ pub struct EditDelegatedPubkey {
 pub struct EditDelegatedPubkeyInstructionAccounts {
     pub margin_account: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for EditDelegatedPubkey {
@@ -19,13 +22,68 @@ impl carbon_core::deserialize::ArrangeAccounts for EditDelegatedPubkey {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [margin_account, authority, _remaining @ ..] = accounts else {
+        let [margin_account, authority, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(EditDelegatedPubkeyInstructionAccounts {
             margin_account: margin_account.pubkey,
             authority: authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct EditDelegatedPubkeyInstructionAccountMetas {
+    pub margin_account: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for EditDelegatedPubkey {
+    type ArrangedAccountMetas = EditDelegatedPubkeyInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [margin_account, authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(EditDelegatedPubkeyInstructionAccountMetas {
+            margin_account: margin_account.clone(),
+            authority: authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct EditDelegatedPubkeyInstructionAccountIndices {
+    pub margin_account: usize,
+    pub authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for EditDelegatedPubkey {
+    type ArrangedAccountIndices = EditDelegatedPubkeyInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(EditDelegatedPubkeyInstructionAccountIndices {
+            margin_account: 0,
+            authority: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for EditDelegatedPubkey {
+    fn account_names() -> &'static [&'static str] {
+        &["margin_account", "authority"]
+    }
+}
@@ -1,7 +1,9 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +16,7 @@ pub struct EditMaTypeInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
     pub margin_account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for EditMaType {
@@ -22,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for EditMaType {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, admin, margin_account, _remaining @ ..] = accounts else {
+        let [state, admin, margin_account, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -30,6 +33,65 @@ impl carbon_core::deserialize::ArrangeAccounts for EditMaType {
             state: state.pubkey,
             admin: admin.pubkey,
             margin_account: margin_account.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct EditMaTypeInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub margin_account: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for EditMaType {
+    type ArrangedAccountMetas = EditMaTypeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, admin, margin_account, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(EditMaTypeInstructionAccountMetas {
+            state: state.clone(),
+            admin: admin.clone(),
+            margin_account: margin_account.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct EditMaTypeInstructionAccountIndices {
+    pub state: usize,
+    pub admin: usize,
+    pub margin_account: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for EditMaType {
+    type ArrangedAccountIndices = EditMaTypeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(EditMaTypeInstructionAccountIndices {
+            state: 0,
+            admin: 1,
+            margin_account: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for EditMaType {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "admin", "margin_account"]
+    }
+}
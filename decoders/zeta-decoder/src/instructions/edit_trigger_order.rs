@@ -1,7 +1,9 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -20,6 +22,7 @@ pub struct EditTriggerOrder {
 pub struct EditTriggerOrderInstructionAccounts {
     pub owner: solana_pubkey::Pubkey,
     pub trigger_order: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for EditTriggerOrder {
@@ -28,13 +31,68 @@ impl carbon_core::deserialize::ArrangeAccounts for EditTriggerOrder {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [owner, trigger_order, _remaining @ ..] = accounts else {
+        let [owner, trigger_order, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(EditTriggerOrderInstructionAccounts {
             owner: owner.pubkey,
             trigger_order: trigger_order.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct EditTriggerOrderInstructionAccountMetas {
+    pub owner: solana_instruction::AccountMeta,
+    pub trigger_order: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for EditTriggerOrder {
+    type ArrangedAccountMetas = EditTriggerOrderInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [owner, trigger_order, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(EditTriggerOrderInstructionAccountMetas {
+            owner: owner.clone(),
+            trigger_order: trigger_order.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct EditTriggerOrderInstructionAccountIndices {
+    pub owner: usize,
+    pub trigger_order: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for EditTriggerOrder {
+    type ArrangedAccountIndices = EditTriggerOrderInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(EditTriggerOrderInstructionAccountIndices {
+            owner: 0,
+            trigger_order: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for EditTriggerOrder {
+    fn account_names() -> &'static [&'static str] {
+        &["owner", "trigger_order"]
+    }
+}
@@ -1,7 +1,9 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -22,6 +24,7 @@ pub struct EditTriggerOrderV2InstructionAccounts {
     pub trigger_order: solana_pubkey::Pubkey,
     pub state: solana_pubkey::Pubkey,
     pub margin_account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for EditTriggerOrderV2 {
@@ -30,7 +33,7 @@ impl carbon_core::deserialize::ArrangeAccounts for EditTriggerOrderV2 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [owner, trigger_order, state, margin_account, _remaining @ ..] = accounts else {
+        let [owner, trigger_order, state, margin_account, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -39,6 +42,69 @@ impl carbon_core::deserialize::ArrangeAccounts for EditTriggerOrderV2 {
             trigger_order: trigger_order.pubkey,
             state: state.pubkey,
             margin_account: margin_account.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct EditTriggerOrderV2InstructionAccountMetas {
+    pub owner: solana_instruction::AccountMeta,
+    pub trigger_order: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub margin_account: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for EditTriggerOrderV2 {
+    type ArrangedAccountMetas = EditTriggerOrderV2InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [owner, trigger_order, state, margin_account, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(EditTriggerOrderV2InstructionAccountMetas {
+            owner: owner.clone(),
+            trigger_order: trigger_order.clone(),
+            state: state.clone(),
+            margin_account: margin_account.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct EditTriggerOrderV2InstructionAccountIndices {
+    pub owner: usize,
+    pub trigger_order: usize,
+    pub state: usize,
+    pub margin_account: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for EditTriggerOrderV2 {
+    type ArrangedAccountIndices = EditTriggerOrderV2InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(EditTriggerOrderV2InstructionAccountIndices {
+            owner: 0,
+            trigger_order: 1,
+            state: 2,
+            margin_account: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for EditTriggerOrderV2 {
+    fn account_names() -> &'static [&'static str] {
+        &["owner", "trigger_order", "state", "margin_account"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +14,7 @@ pub struct ExecuteTriggerOrderInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub trigger_order: solana_pubkey::Pubkey,
     pub place_order_accounts: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ExecuteTriggerOrder {
@@ -20,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ExecuteTriggerOrder {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin, trigger_order, place_order_accounts, _remaining @ ..] = accounts else {
+        let [admin, trigger_order, place_order_accounts, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +31,65 @@ impl carbon_core::deserialize::ArrangeAccounts for ExecuteTriggerOrder {
             admin: admin.pubkey,
             trigger_order: trigger_order.pubkey,
             place_order_accounts: place_order_accounts.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ExecuteTriggerOrderInstructionAccountMetas {
+    pub admin: solana_instruction::AccountMeta,
+    pub trigger_order: solana_instruction::AccountMeta,
+    pub place_order_accounts: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ExecuteTriggerOrder {
+    type ArrangedAccountMetas = ExecuteTriggerOrderInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin, trigger_order, place_order_accounts, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(ExecuteTriggerOrderInstructionAccountMetas {
+            admin: admin.clone(),
+            trigger_order: trigger_order.clone(),
+            place_order_accounts: place_order_accounts.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ExecuteTriggerOrderInstructionAccountIndices {
+    pub admin: usize,
+    pub trigger_order: usize,
+    pub place_order_accounts: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ExecuteTriggerOrder {
+    type ArrangedAccountIndices = ExecuteTriggerOrderInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(ExecuteTriggerOrderInstructionAccountIndices {
+            admin: 0,
+            trigger_order: 1,
+            place_order_accounts: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ExecuteTriggerOrder {
+    fn account_names() -> &'static [&'static str] {
+        &["admin", "trigger_order", "place_order_accounts"]
+    }
+}
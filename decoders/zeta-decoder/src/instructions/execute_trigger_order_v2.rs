@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +14,7 @@ pub struct ExecuteTriggerOrderV2InstructionAccounts {
     pub payer: solana_pubkey::Pubkey,
     pub trigger_order: solana_pubkey::Pubkey,
     pub place_order_accounts: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ExecuteTriggerOrderV2 {
@@ -20,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ExecuteTriggerOrderV2 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [payer, trigger_order, place_order_accounts, _remaining @ ..] = accounts else {
+        let [payer, trigger_order, place_order_accounts, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +31,65 @@ impl carbon_core::deserialize::ArrangeAccounts for ExecuteTriggerOrderV2 {
             payer: payer.pubkey,
             trigger_order: trigger_order.pubkey,
             place_order_accounts: place_order_accounts.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ExecuteTriggerOrderV2InstructionAccountMetas {
+    pub payer: solana_instruction::AccountMeta,
+    pub trigger_order: solana_instruction::AccountMeta,
+    pub place_order_accounts: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ExecuteTriggerOrderV2 {
+    type ArrangedAccountMetas = ExecuteTriggerOrderV2InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [payer, trigger_order, place_order_accounts, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(ExecuteTriggerOrderV2InstructionAccountMetas {
+            payer: payer.clone(),
+            trigger_order: trigger_order.clone(),
+            place_order_accounts: place_order_accounts.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ExecuteTriggerOrderV2InstructionAccountIndices {
+    pub payer: usize,
+    pub trigger_order: usize,
+    pub place_order_accounts: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ExecuteTriggerOrderV2 {
+    type ArrangedAccountIndices = ExecuteTriggerOrderV2InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(ExecuteTriggerOrderV2InstructionAccountIndices {
+            payer: 0,
+            trigger_order: 1,
+            place_order_accounts: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ExecuteTriggerOrderV2 {
+    fn account_names() -> &'static [&'static str] {
+        &["payer", "trigger_order", "place_order_accounts"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -8,14 +10,56 @@ pub struct ExpireSeries {
     pub settlement_nonce: u8,
 }
 
-pub struct ExpireSeriesInstructionAccounts {}
+pub struct ExpireSeriesInstructionAccounts {
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
 
 impl carbon_core::deserialize::ArrangeAccounts for ExpireSeries {
     type ArrangedAccounts = ExpireSeriesInstructionAccounts;
 
     fn arrange_accounts(
-        _accounts: &[solana_instruction::AccountMeta],
+        accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        Some(ExpireSeriesInstructionAccounts {})
+        Some(ExpireSeriesInstructionAccounts {
+            remaining_accounts: accounts.to_vec(),
+        })
+    }
+}
+
+pub struct ExpireSeriesInstructionAccountMetas {
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ExpireSeries {
+    type ArrangedAccountMetas = ExpireSeriesInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        Some(ExpireSeriesInstructionAccountMetas {
+            remaining_accounts: accounts.to_vec(),
+        })
+    }
+}
+
+pub struct ExpireSeriesInstructionAccountIndices {
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ExpireSeries {
+    type ArrangedAccountIndices = ExpireSeriesInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        Some(ExpireSeriesInstructionAccountIndices {
+            remaining_accounts: 0..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ExpireSeries {
+    fn account_names() -> &'static [&'static str] {
+        &[]
     }
 }
@@ -1,7 +1,9 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -10,14 +12,56 @@ pub struct ExpireSeriesOverride {
     pub args: ExpireSeriesOverrideArgs,
 }
 
-pub struct ExpireSeriesOverrideInstructionAccounts {}
+pub struct ExpireSeriesOverrideInstructionAccounts {
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
 
 impl carbon_core::deserialize::ArrangeAccounts for ExpireSeriesOverride {
     type ArrangedAccounts = ExpireSeriesOverrideInstructionAccounts;
 
     fn arrange_accounts(
-        _accounts: &[solana_instruction::AccountMeta],
+        accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        Some(ExpireSeriesOverrideInstructionAccounts {})
+        Some(ExpireSeriesOverrideInstructionAccounts {
+            remaining_accounts: accounts.to_vec(),
+        })
+    }
+}
+
+pub struct ExpireSeriesOverrideInstructionAccountMetas {
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ExpireSeriesOverride {
+    type ArrangedAccountMetas = ExpireSeriesOverrideInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        Some(ExpireSeriesOverrideInstructionAccountMetas {
+            remaining_accounts: accounts.to_vec(),
+        })
+    }
+}
+
+pub struct ExpireSeriesOverrideInstructionAccountIndices {
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ExpireSeriesOverride {
+    type ArrangedAccountIndices = ExpireSeriesOverrideInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        Some(ExpireSeriesOverrideInstructionAccountIndices {
+            remaining_accounts: 0..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ExpireSeriesOverride {
+    fn account_names() -> &'static [&'static str] {
+        &[]
     }
 }
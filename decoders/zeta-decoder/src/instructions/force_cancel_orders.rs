@@ -1,7 +1,9 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -17,6 +19,7 @@ pub struct ForceCancelOrdersInstructionAccounts {
     pub oracle_backup_feed: solana_pubkey::Pubkey,
     pub oracle_backup_program: solana_pubkey::Pubkey,
     pub cancel_accounts: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ForceCancelOrders {
@@ -25,7 +28,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ForceCancelOrders {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [zeta_group, greeks, oracle, oracle_backup_feed, oracle_backup_program, cancel_accounts, _remaining @ ..] =
+        let [zeta_group, greeks, oracle, oracle_backup_feed, oracle_backup_program, cancel_accounts, remaining @ ..] =
             accounts
         else {
             return None;
@@ -38,6 +41,86 @@ impl carbon_core::deserialize::ArrangeAccounts for ForceCancelOrders {
             oracle_backup_feed: oracle_backup_feed.pubkey,
             oracle_backup_program: oracle_backup_program.pubkey,
             cancel_accounts: cancel_accounts.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ForceCancelOrdersInstructionAccountMetas {
+    pub zeta_group: solana_instruction::AccountMeta,
+    pub greeks: solana_instruction::AccountMeta,
+    pub oracle: solana_instruction::AccountMeta,
+    pub oracle_backup_feed: solana_instruction::AccountMeta,
+    pub oracle_backup_program: solana_instruction::AccountMeta,
+    pub cancel_accounts: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ForceCancelOrders {
+    type ArrangedAccountMetas = ForceCancelOrdersInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [zeta_group, greeks, oracle, oracle_backup_feed, oracle_backup_program, cancel_accounts, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(ForceCancelOrdersInstructionAccountMetas {
+            zeta_group: zeta_group.clone(),
+            greeks: greeks.clone(),
+            oracle: oracle.clone(),
+            oracle_backup_feed: oracle_backup_feed.clone(),
+            oracle_backup_program: oracle_backup_program.clone(),
+            cancel_accounts: cancel_accounts.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ForceCancelOrdersInstructionAccountIndices {
+    pub zeta_group: usize,
+    pub greeks: usize,
+    pub oracle: usize,
+    pub oracle_backup_feed: usize,
+    pub oracle_backup_program: usize,
+    pub cancel_accounts: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ForceCancelOrders {
+    type ArrangedAccountIndices = ForceCancelOrdersInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(ForceCancelOrdersInstructionAccountIndices {
+            zeta_group: 0,
+            greeks: 1,
+            oracle: 2,
+            oracle_backup_feed: 3,
+            oracle_backup_program: 4,
+            cancel_accounts: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ForceCancelOrders {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "zeta_group",
+            "greeks",
+            "oracle",
+            "oracle_backup_feed",
+            "oracle_backup_program",
+            "cancel_accounts",
+        ]
+    }
+}
@@ -1,7 +1,9 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -16,6 +18,7 @@ pub struct ForceCancelOrdersV2InstructionAccounts {
     pub oracle_backup_feed: solana_pubkey::Pubkey,
     pub oracle_backup_program: solana_pubkey::Pubkey,
     pub cancel_accounts: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ForceCancelOrdersV2 {
@@ -24,7 +27,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ForceCancelOrdersV2 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [pricing, oracle, oracle_backup_feed, oracle_backup_program, cancel_accounts, _remaining @ ..] =
+        let [pricing, oracle, oracle_backup_feed, oracle_backup_program, cancel_accounts, remaining @ ..] =
             accounts
         else {
             return None;
@@ -36,6 +39,81 @@ impl carbon_core::deserialize::ArrangeAccounts for ForceCancelOrdersV2 {
             oracle_backup_feed: oracle_backup_feed.pubkey,
             oracle_backup_program: oracle_backup_program.pubkey,
             cancel_accounts: cancel_accounts.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ForceCancelOrdersV2InstructionAccountMetas {
+    pub pricing: solana_instruction::AccountMeta,
+    pub oracle: solana_instruction::AccountMeta,
+    pub oracle_backup_feed: solana_instruction::AccountMeta,
+    pub oracle_backup_program: solana_instruction::AccountMeta,
+    pub cancel_accounts: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ForceCancelOrdersV2 {
+    type ArrangedAccountMetas = ForceCancelOrdersV2InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [pricing, oracle, oracle_backup_feed, oracle_backup_program, cancel_accounts, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(ForceCancelOrdersV2InstructionAccountMetas {
+            pricing: pricing.clone(),
+            oracle: oracle.clone(),
+            oracle_backup_feed: oracle_backup_feed.clone(),
+            oracle_backup_program: oracle_backup_program.clone(),
+            cancel_accounts: cancel_accounts.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ForceCancelOrdersV2InstructionAccountIndices {
+    pub pricing: usize,
+    pub oracle: usize,
+    pub oracle_backup_feed: usize,
+    pub oracle_backup_program: usize,
+    pub cancel_accounts: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ForceCancelOrdersV2 {
+    type ArrangedAccountIndices = ForceCancelOrdersV2InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(ForceCancelOrdersV2InstructionAccountIndices {
+            pricing: 0,
+            oracle: 1,
+            oracle_backup_feed: 2,
+            oracle_backup_program: 3,
+            cancel_accounts: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ForceCancelOrdersV2 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "pricing",
+            "oracle",
+            "oracle_backup_feed",
+            "oracle_backup_program",
+            "cancel_accounts",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +16,7 @@ pub struct ForceCancelTriggerOrderInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub trigger_order: solana_pubkey::Pubkey,
     pub margin_account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ForceCancelTriggerOrder {
@@ -22,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ForceCancelTriggerOrder {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, admin, trigger_order, margin_account, _remaining @ ..] = accounts else {
+        let [state, admin, trigger_order, margin_account, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -31,6 +34,69 @@ impl carbon_core::deserialize::ArrangeAccounts for ForceCancelTriggerOrder {
             admin: admin.pubkey,
             trigger_order: trigger_order.pubkey,
             margin_account: margin_account.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ForceCancelTriggerOrderInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub trigger_order: solana_instruction::AccountMeta,
+    pub margin_account: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ForceCancelTriggerOrder {
+    type ArrangedAccountMetas = ForceCancelTriggerOrderInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, admin, trigger_order, margin_account, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(ForceCancelTriggerOrderInstructionAccountMetas {
+            state: state.clone(),
+            admin: admin.clone(),
+            trigger_order: trigger_order.clone(),
+            margin_account: margin_account.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ForceCancelTriggerOrderInstructionAccountIndices {
+    pub state: usize,
+    pub admin: usize,
+    pub trigger_order: usize,
+    pub margin_account: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ForceCancelTriggerOrder {
+    type ArrangedAccountIndices = ForceCancelTriggerOrderInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(ForceCancelTriggerOrderInstructionAccountIndices {
+            state: 0,
+            admin: 1,
+            trigger_order: 2,
+            margin_account: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ForceCancelTriggerOrder {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "admin", "trigger_order", "margin_account"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -15,6 +17,7 @@ pub struct InitializeCombinedInsuranceVaultInstructionAccounts {
     pub usdc_mint: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeCombinedInsuranceVault {
@@ -23,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeCombinedInsuranceVa
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, insurance_vault, token_program, usdc_mint, admin, system_program, _remaining @ ..] =
+        let [state, insurance_vault, token_program, usdc_mint, admin, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -36,6 +39,86 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeCombinedInsuranceVa
             usdc_mint: usdc_mint.pubkey,
             admin: admin.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeCombinedInsuranceVaultInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub insurance_vault: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub usdc_mint: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeCombinedInsuranceVault {
+    type ArrangedAccountMetas = InitializeCombinedInsuranceVaultInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, insurance_vault, token_program, usdc_mint, admin, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeCombinedInsuranceVaultInstructionAccountMetas {
+            state: state.clone(),
+            insurance_vault: insurance_vault.clone(),
+            token_program: token_program.clone(),
+            usdc_mint: usdc_mint.clone(),
+            admin: admin.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeCombinedInsuranceVaultInstructionAccountIndices {
+    pub state: usize,
+    pub insurance_vault: usize,
+    pub token_program: usize,
+    pub usdc_mint: usize,
+    pub admin: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeCombinedInsuranceVault {
+    type ArrangedAccountIndices = InitializeCombinedInsuranceVaultInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(InitializeCombinedInsuranceVaultInstructionAccountIndices {
+            state: 0,
+            insurance_vault: 1,
+            token_program: 2,
+            usdc_mint: 3,
+            admin: 4,
+            system_program: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeCombinedInsuranceVault {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "insurance_vault",
+            "token_program",
+            "usdc_mint",
+            "admin",
+            "system_program",
+        ]
+    }
+}
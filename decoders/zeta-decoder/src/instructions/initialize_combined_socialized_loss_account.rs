@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -15,6 +17,7 @@ pub struct InitializeCombinedSocializedLossAccountInstructionAccounts {
     pub usdc_mint: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeCombinedSocializedLossAccount {
@@ -23,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeCombinedSocializedL
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, socialized_loss_account, token_program, usdc_mint, admin, system_program, _remaining @ ..] =
+        let [state, socialized_loss_account, token_program, usdc_mint, admin, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -36,6 +39,90 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeCombinedSocializedL
             usdc_mint: usdc_mint.pubkey,
             admin: admin.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeCombinedSocializedLossAccountInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub socialized_loss_account: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub usdc_mint: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeCombinedSocializedLossAccount {
+    type ArrangedAccountMetas = InitializeCombinedSocializedLossAccountInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, socialized_loss_account, token_program, usdc_mint, admin, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(
+            InitializeCombinedSocializedLossAccountInstructionAccountMetas {
+                state: state.clone(),
+                socialized_loss_account: socialized_loss_account.clone(),
+                token_program: token_program.clone(),
+                usdc_mint: usdc_mint.clone(),
+                admin: admin.clone(),
+                system_program: system_program.clone(),
+                remaining_accounts: remaining.to_vec(),
+            },
+        )
+    }
+}
+
+pub struct InitializeCombinedSocializedLossAccountInstructionAccountIndices {
+    pub state: usize,
+    pub socialized_loss_account: usize,
+    pub token_program: usize,
+    pub usdc_mint: usize,
+    pub admin: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeCombinedSocializedLossAccount {
+    type ArrangedAccountIndices = InitializeCombinedSocializedLossAccountInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(
+            InitializeCombinedSocializedLossAccountInstructionAccountIndices {
+                state: 0,
+                socialized_loss_account: 1,
+                token_program: 2,
+                usdc_mint: 3,
+                admin: 4,
+                system_program: 5,
+                remaining_accounts: 6..accounts.len(),
+            },
+        )
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeCombinedSocializedLossAccount {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "socialized_loss_account",
+            "token_program",
+            "usdc_mint",
+            "admin",
+            "system_program",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -15,6 +17,7 @@ pub struct InitializeCrossMarginAccountInstructionAccounts {
     pub payer: solana_pubkey::Pubkey,
     pub zeta_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeCrossMarginAccount {
@@ -23,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeCrossMarginAccount
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [cross_margin_account, cross_margin_account_manager, authority, payer, zeta_program, system_program, _remaining @ ..] =
+        let [cross_margin_account, cross_margin_account_manager, authority, payer, zeta_program, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -36,6 +39,86 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeCrossMarginAccount
             payer: payer.pubkey,
             zeta_program: zeta_program.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeCrossMarginAccountInstructionAccountMetas {
+    pub cross_margin_account: solana_instruction::AccountMeta,
+    pub cross_margin_account_manager: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub zeta_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeCrossMarginAccount {
+    type ArrangedAccountMetas = InitializeCrossMarginAccountInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [cross_margin_account, cross_margin_account_manager, authority, payer, zeta_program, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeCrossMarginAccountInstructionAccountMetas {
+            cross_margin_account: cross_margin_account.clone(),
+            cross_margin_account_manager: cross_margin_account_manager.clone(),
+            authority: authority.clone(),
+            payer: payer.clone(),
+            zeta_program: zeta_program.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeCrossMarginAccountInstructionAccountIndices {
+    pub cross_margin_account: usize,
+    pub cross_margin_account_manager: usize,
+    pub authority: usize,
+    pub payer: usize,
+    pub zeta_program: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeCrossMarginAccount {
+    type ArrangedAccountIndices = InitializeCrossMarginAccountInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(InitializeCrossMarginAccountInstructionAccountIndices {
+            cross_margin_account: 0,
+            cross_margin_account_manager: 1,
+            authority: 2,
+            payer: 3,
+            zeta_program: 4,
+            system_program: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeCrossMarginAccount {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "cross_margin_account",
+            "cross_margin_account_manager",
+            "authority",
+            "payer",
+            "zeta_program",
+            "system_program",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +16,7 @@ pub struct InitializeCrossMarginAccountManagerV2InstructionAccounts {
     pub payer: solana_pubkey::Pubkey,
     pub zeta_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeCrossMarginAccountManagerV2 {
@@ -22,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeCrossMarginAccountM
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [cross_margin_account_manager, authority, payer, zeta_program, system_program, _remaining @ ..] =
+        let [cross_margin_account_manager, authority, payer, zeta_program, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -34,6 +37,85 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeCrossMarginAccountM
             payer: payer.pubkey,
             zeta_program: zeta_program.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeCrossMarginAccountManagerV2InstructionAccountMetas {
+    pub cross_margin_account_manager: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub zeta_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeCrossMarginAccountManagerV2 {
+    type ArrangedAccountMetas = InitializeCrossMarginAccountManagerV2InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [cross_margin_account_manager, authority, payer, zeta_program, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(
+            InitializeCrossMarginAccountManagerV2InstructionAccountMetas {
+                cross_margin_account_manager: cross_margin_account_manager.clone(),
+                authority: authority.clone(),
+                payer: payer.clone(),
+                zeta_program: zeta_program.clone(),
+                system_program: system_program.clone(),
+                remaining_accounts: remaining.to_vec(),
+            },
+        )
+    }
+}
+
+pub struct InitializeCrossMarginAccountManagerV2InstructionAccountIndices {
+    pub cross_margin_account_manager: usize,
+    pub authority: usize,
+    pub payer: usize,
+    pub zeta_program: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeCrossMarginAccountManagerV2 {
+    type ArrangedAccountIndices = InitializeCrossMarginAccountManagerV2InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(
+            InitializeCrossMarginAccountManagerV2InstructionAccountIndices {
+                cross_margin_account_manager: 0,
+                authority: 1,
+                payer: 2,
+                zeta_program: 3,
+                system_program: 4,
+                remaining_accounts: 5..accounts.len(),
+            },
+        )
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeCrossMarginAccountManagerV2 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "cross_margin_account_manager",
+            "authority",
+            "payer",
+            "zeta_program",
+            "system_program",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +16,7 @@ pub struct InitializeInsuranceDepositAccountInstructionAccounts {
     pub payer: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub whitelist_insurance_account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeInsuranceDepositAccount {
@@ -22,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeInsuranceDepositAcc
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [insurance_deposit_account, authority, payer, system_program, whitelist_insurance_account, _remaining @ ..] =
+        let [insurance_deposit_account, authority, payer, system_program, whitelist_insurance_account, remaining @ ..] =
             accounts
         else {
             return None;
@@ -34,6 +37,81 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeInsuranceDepositAcc
             payer: payer.pubkey,
             system_program: system_program.pubkey,
             whitelist_insurance_account: whitelist_insurance_account.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeInsuranceDepositAccountInstructionAccountMetas {
+    pub insurance_deposit_account: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub whitelist_insurance_account: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeInsuranceDepositAccount {
+    type ArrangedAccountMetas = InitializeInsuranceDepositAccountInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [insurance_deposit_account, authority, payer, system_program, whitelist_insurance_account, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeInsuranceDepositAccountInstructionAccountMetas {
+            insurance_deposit_account: insurance_deposit_account.clone(),
+            authority: authority.clone(),
+            payer: payer.clone(),
+            system_program: system_program.clone(),
+            whitelist_insurance_account: whitelist_insurance_account.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeInsuranceDepositAccountInstructionAccountIndices {
+    pub insurance_deposit_account: usize,
+    pub authority: usize,
+    pub payer: usize,
+    pub system_program: usize,
+    pub whitelist_insurance_account: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeInsuranceDepositAccount {
+    type ArrangedAccountIndices = InitializeInsuranceDepositAccountInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(InitializeInsuranceDepositAccountInstructionAccountIndices {
+            insurance_deposit_account: 0,
+            authority: 1,
+            payer: 2,
+            system_program: 3,
+            whitelist_insurance_account: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeInsuranceDepositAccount {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "insurance_deposit_account",
+            "authority",
+            "payer",
+            "system_program",
+            "whitelist_insurance_account",
+        ]
+    }
+}
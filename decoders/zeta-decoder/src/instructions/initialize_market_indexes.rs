@@ -1,7 +1,9 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -17,6 +19,7 @@ pub struct InitializeMarketIndexesInstructionAccounts {
     pub admin: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub pricing: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeMarketIndexes {
@@ -25,7 +28,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeMarketIndexes {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, market_indexes, admin, system_program, pricing, _remaining @ ..] = accounts
+        let [state, market_indexes, admin, system_program, pricing, remaining @ ..] = accounts
         else {
             return None;
         };
@@ -36,6 +39,80 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeMarketIndexes {
             admin: admin.pubkey,
             system_program: system_program.pubkey,
             pricing: pricing.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeMarketIndexesInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub market_indexes: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub pricing: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeMarketIndexes {
+    type ArrangedAccountMetas = InitializeMarketIndexesInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, market_indexes, admin, system_program, pricing, remaining @ ..] = accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeMarketIndexesInstructionAccountMetas {
+            state: state.clone(),
+            market_indexes: market_indexes.clone(),
+            admin: admin.clone(),
+            system_program: system_program.clone(),
+            pricing: pricing.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeMarketIndexesInstructionAccountIndices {
+    pub state: usize,
+    pub market_indexes: usize,
+    pub admin: usize,
+    pub system_program: usize,
+    pub pricing: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeMarketIndexes {
+    type ArrangedAccountIndices = InitializeMarketIndexesInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(InitializeMarketIndexesInstructionAccountIndices {
+            state: 0,
+            market_indexes: 1,
+            admin: 2,
+            system_program: 3,
+            pricing: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeMarketIndexes {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "market_indexes",
+            "admin",
+            "system_program",
+            "pricing",
+        ]
+    }
+}
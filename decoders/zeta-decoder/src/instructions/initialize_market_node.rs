@@ -1,7 +1,9 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -16,6 +18,7 @@ pub struct InitializeMarketNodeInstructionAccounts {
     pub greeks: solana_pubkey::Pubkey,
     pub payer: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeMarketNode {
@@ -24,7 +27,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeMarketNode {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [zeta_group, market_node, greeks, payer, system_program, _remaining @ ..] = accounts
+        let [zeta_group, market_node, greeks, payer, system_program, remaining @ ..] = accounts
         else {
             return None;
         };
@@ -35,6 +38,80 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeMarketNode {
             greeks: greeks.pubkey,
             payer: payer.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeMarketNodeInstructionAccountMetas {
+    pub zeta_group: solana_instruction::AccountMeta,
+    pub market_node: solana_instruction::AccountMeta,
+    pub greeks: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeMarketNode {
+    type ArrangedAccountMetas = InitializeMarketNodeInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [zeta_group, market_node, greeks, payer, system_program, remaining @ ..] = accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeMarketNodeInstructionAccountMetas {
+            zeta_group: zeta_group.clone(),
+            market_node: market_node.clone(),
+            greeks: greeks.clone(),
+            payer: payer.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeMarketNodeInstructionAccountIndices {
+    pub zeta_group: usize,
+    pub market_node: usize,
+    pub greeks: usize,
+    pub payer: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeMarketNode {
+    type ArrangedAccountIndices = InitializeMarketNodeInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(InitializeMarketNodeInstructionAccountIndices {
+            zeta_group: 0,
+            market_node: 1,
+            greeks: 2,
+            payer: 3,
+            system_program: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeMarketNode {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "zeta_group",
+            "market_node",
+            "greeks",
+            "payer",
+            "system_program",
+        ]
+    }
+}
@@ -1,7 +1,9 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -18,6 +20,7 @@ pub struct InitializeMarketPdaInstructionAccounts {
     pub market: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeMarketPda {
@@ -26,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeMarketPda {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, market_indexes, pricing, admin, market, system_program, rent, _remaining @ ..] =
+        let [state, market_indexes, pricing, admin, market, system_program, rent, remaining @ ..] =
             accounts
         else {
             return None;
@@ -40,6 +43,91 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeMarketPda {
             market: market.pubkey,
             system_program: system_program.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeMarketPdaInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub market_indexes: solana_instruction::AccountMeta,
+    pub pricing: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub market: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeMarketPda {
+    type ArrangedAccountMetas = InitializeMarketPdaInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, market_indexes, pricing, admin, market, system_program, rent, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeMarketPdaInstructionAccountMetas {
+            state: state.clone(),
+            market_indexes: market_indexes.clone(),
+            pricing: pricing.clone(),
+            admin: admin.clone(),
+            market: market.clone(),
+            system_program: system_program.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeMarketPdaInstructionAccountIndices {
+    pub state: usize,
+    pub market_indexes: usize,
+    pub pricing: usize,
+    pub admin: usize,
+    pub market: usize,
+    pub system_program: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeMarketPda {
+    type ArrangedAccountIndices = InitializeMarketPdaInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 7 {
+            return None;
+        }
+
+        Some(InitializeMarketPdaInstructionAccountIndices {
+            state: 0,
+            market_indexes: 1,
+            pricing: 2,
+            admin: 3,
+            market: 4,
+            system_program: 5,
+            rent: 6,
+            remaining_accounts: 7..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeMarketPda {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "market_indexes",
+            "pricing",
+            "admin",
+            "market",
+            "system_program",
+            "rent",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +14,7 @@ pub struct InitializeMarketStrikesInstructionAccounts {
     pub oracle: solana_pubkey::Pubkey,
     pub oracle_backup_feed: solana_pubkey::Pubkey,
     pub oracle_backup_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeMarketStrikes {
@@ -20,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeMarketStrikes {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, zeta_group, oracle, oracle_backup_feed, oracle_backup_program, _remaining @ ..] =
+        let [state, zeta_group, oracle, oracle_backup_feed, oracle_backup_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -32,6 +35,81 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeMarketStrikes {
             oracle: oracle.pubkey,
             oracle_backup_feed: oracle_backup_feed.pubkey,
             oracle_backup_program: oracle_backup_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeMarketStrikesInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub zeta_group: solana_instruction::AccountMeta,
+    pub oracle: solana_instruction::AccountMeta,
+    pub oracle_backup_feed: solana_instruction::AccountMeta,
+    pub oracle_backup_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeMarketStrikes {
+    type ArrangedAccountMetas = InitializeMarketStrikesInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, zeta_group, oracle, oracle_backup_feed, oracle_backup_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeMarketStrikesInstructionAccountMetas {
+            state: state.clone(),
+            zeta_group: zeta_group.clone(),
+            oracle: oracle.clone(),
+            oracle_backup_feed: oracle_backup_feed.clone(),
+            oracle_backup_program: oracle_backup_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeMarketStrikesInstructionAccountIndices {
+    pub state: usize,
+    pub zeta_group: usize,
+    pub oracle: usize,
+    pub oracle_backup_feed: usize,
+    pub oracle_backup_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeMarketStrikes {
+    type ArrangedAccountIndices = InitializeMarketStrikesInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(InitializeMarketStrikesInstructionAccountIndices {
+            state: 0,
+            zeta_group: 1,
+            oracle: 2,
+            oracle_backup_feed: 3,
+            oracle_backup_program: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeMarketStrikes {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "zeta_group",
+            "oracle",
+            "oracle_backup_feed",
+            "oracle_backup_program",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +16,7 @@ pub struct InitializeMarketTifEpochCycleInstructionAccounts {
     pub market: solana_pubkey::Pubkey,
     pub serum_authority: solana_pubkey::Pubkey,
     pub dex_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeMarketTifEpochCycle {
@@ -22,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeMarketTifEpochCycle
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, admin, market, serum_authority, dex_program, _remaining @ ..] = accounts else {
+        let [state, admin, market, serum_authority, dex_program, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -32,6 +35,73 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeMarketTifEpochCycle
             market: market.pubkey,
             serum_authority: serum_authority.pubkey,
             dex_program: dex_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeMarketTifEpochCycleInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub market: solana_instruction::AccountMeta,
+    pub serum_authority: solana_instruction::AccountMeta,
+    pub dex_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeMarketTifEpochCycle {
+    type ArrangedAccountMetas = InitializeMarketTifEpochCycleInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, admin, market, serum_authority, dex_program, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(InitializeMarketTifEpochCycleInstructionAccountMetas {
+            state: state.clone(),
+            admin: admin.clone(),
+            market: market.clone(),
+            serum_authority: serum_authority.clone(),
+            dex_program: dex_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeMarketTifEpochCycleInstructionAccountIndices {
+    pub state: usize,
+    pub admin: usize,
+    pub market: usize,
+    pub serum_authority: usize,
+    pub dex_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeMarketTifEpochCycle {
+    type ArrangedAccountIndices = InitializeMarketTifEpochCycleInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(InitializeMarketTifEpochCycleInstructionAccountIndices {
+            state: 0,
+            admin: 1,
+            market: 2,
+            serum_authority: 3,
+            dex_program: 4,
+            remaining_accounts: 5..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeMarketTifEpochCycle {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "admin", "market", "serum_authority", "dex_program"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -19,6 +21,7 @@ pub struct InitializeOpenOrdersInstructionAccounts {
     pub serum_authority: solana_pubkey::Pubkey,
     pub open_orders_map: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeOpenOrders {
@@ -27,7 +30,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeOpenOrders {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, zeta_group, dex_program, system_program, open_orders, margin_account, authority, payer, market, serum_authority, open_orders_map, rent, _remaining @ ..] =
+        let [state, zeta_group, dex_program, system_program, open_orders, margin_account, authority, payer, market, serum_authority, open_orders_map, rent, remaining @ ..] =
             accounts
         else {
             return None;
@@ -46,6 +49,116 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeOpenOrders {
             serum_authority: serum_authority.pubkey,
             open_orders_map: open_orders_map.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeOpenOrdersInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub zeta_group: solana_instruction::AccountMeta,
+    pub dex_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub open_orders: solana_instruction::AccountMeta,
+    pub margin_account: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub market: solana_instruction::AccountMeta,
+    pub serum_authority: solana_instruction::AccountMeta,
+    pub open_orders_map: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeOpenOrders {
+    type ArrangedAccountMetas = InitializeOpenOrdersInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, zeta_group, dex_program, system_program, open_orders, margin_account, authority, payer, market, serum_authority, open_orders_map, rent, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeOpenOrdersInstructionAccountMetas {
+            state: state.clone(),
+            zeta_group: zeta_group.clone(),
+            dex_program: dex_program.clone(),
+            system_program: system_program.clone(),
+            open_orders: open_orders.clone(),
+            margin_account: margin_account.clone(),
+            authority: authority.clone(),
+            payer: payer.clone(),
+            market: market.clone(),
+            serum_authority: serum_authority.clone(),
+            open_orders_map: open_orders_map.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeOpenOrdersInstructionAccountIndices {
+    pub state: usize,
+    pub zeta_group: usize,
+    pub dex_program: usize,
+    pub system_program: usize,
+    pub open_orders: usize,
+    pub margin_account: usize,
+    pub authority: usize,
+    pub payer: usize,
+    pub market: usize,
+    pub serum_authority: usize,
+    pub open_orders_map: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeOpenOrders {
+    type ArrangedAccountIndices = InitializeOpenOrdersInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 12 {
+            return None;
+        }
+
+        Some(InitializeOpenOrdersInstructionAccountIndices {
+            state: 0,
+            zeta_group: 1,
+            dex_program: 2,
+            system_program: 3,
+            open_orders: 4,
+            margin_account: 5,
+            authority: 6,
+            payer: 7,
+            market: 8,
+            serum_authority: 9,
+            open_orders_map: 10,
+            rent: 11,
+            remaining_accounts: 12..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeOpenOrders {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "zeta_group",
+            "dex_program",
+            "system_program",
+            "open_orders",
+            "margin_account",
+            "authority",
+            "payer",
+            "market",
+            "serum_authority",
+            "open_orders_map",
+            "rent",
+        ]
+    }
+}
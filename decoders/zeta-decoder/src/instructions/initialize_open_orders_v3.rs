@@ -1,7 +1,9 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -22,6 +24,7 @@ pub struct InitializeOpenOrdersV3InstructionAccounts {
     pub serum_authority: solana_pubkey::Pubkey,
     pub open_orders_map: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeOpenOrdersV3 {
@@ -30,7 +33,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeOpenOrdersV3 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, dex_program, system_program, open_orders, cross_margin_account, authority, payer, market, serum_authority, open_orders_map, rent, _remaining @ ..] =
+        let [state, dex_program, system_program, open_orders, cross_margin_account, authority, payer, market, serum_authority, open_orders_map, rent, remaining @ ..] =
             accounts
         else {
             return None;
@@ -48,6 +51,111 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeOpenOrdersV3 {
             serum_authority: serum_authority.pubkey,
             open_orders_map: open_orders_map.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeOpenOrdersV3InstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub dex_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub open_orders: solana_instruction::AccountMeta,
+    pub cross_margin_account: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub market: solana_instruction::AccountMeta,
+    pub serum_authority: solana_instruction::AccountMeta,
+    pub open_orders_map: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeOpenOrdersV3 {
+    type ArrangedAccountMetas = InitializeOpenOrdersV3InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, dex_program, system_program, open_orders, cross_margin_account, authority, payer, market, serum_authority, open_orders_map, rent, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeOpenOrdersV3InstructionAccountMetas {
+            state: state.clone(),
+            dex_program: dex_program.clone(),
+            system_program: system_program.clone(),
+            open_orders: open_orders.clone(),
+            cross_margin_account: cross_margin_account.clone(),
+            authority: authority.clone(),
+            payer: payer.clone(),
+            market: market.clone(),
+            serum_authority: serum_authority.clone(),
+            open_orders_map: open_orders_map.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeOpenOrdersV3InstructionAccountIndices {
+    pub state: usize,
+    pub dex_program: usize,
+    pub system_program: usize,
+    pub open_orders: usize,
+    pub cross_margin_account: usize,
+    pub authority: usize,
+    pub payer: usize,
+    pub market: usize,
+    pub serum_authority: usize,
+    pub open_orders_map: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeOpenOrdersV3 {
+    type ArrangedAccountIndices = InitializeOpenOrdersV3InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 11 {
+            return None;
+        }
+
+        Some(InitializeOpenOrdersV3InstructionAccountIndices {
+            state: 0,
+            dex_program: 1,
+            system_program: 2,
+            open_orders: 3,
+            cross_margin_account: 4,
+            authority: 5,
+            payer: 6,
+            market: 7,
+            serum_authority: 8,
+            open_orders_map: 9,
+            rent: 10,
+            remaining_accounts: 11..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeOpenOrdersV3 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "dex_program",
+            "system_program",
+            "open_orders",
+            "cross_margin_account",
+            "authority",
+            "payer",
+            "market",
+            "serum_authority",
+            "open_orders_map",
+            "rent",
+        ]
+    }
+}
@@ -1,7 +1,9 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -18,6 +20,7 @@ pub struct InitializePerpSyncQueueInstructionAccounts {
     pub perp_sync_queue: solana_pubkey::Pubkey,
     pub pricing: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializePerpSyncQueue {
@@ -26,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializePerpSyncQueue {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin, zeta_program, state, perp_sync_queue, pricing, system_program, _remaining @ ..] =
+        let [admin, zeta_program, state, perp_sync_queue, pricing, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -39,6 +42,86 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializePerpSyncQueue {
             perp_sync_queue: perp_sync_queue.pubkey,
             pricing: pricing.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializePerpSyncQueueInstructionAccountMetas {
+    pub admin: solana_instruction::AccountMeta,
+    pub zeta_program: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub perp_sync_queue: solana_instruction::AccountMeta,
+    pub pricing: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializePerpSyncQueue {
+    type ArrangedAccountMetas = InitializePerpSyncQueueInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin, zeta_program, state, perp_sync_queue, pricing, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializePerpSyncQueueInstructionAccountMetas {
+            admin: admin.clone(),
+            zeta_program: zeta_program.clone(),
+            state: state.clone(),
+            perp_sync_queue: perp_sync_queue.clone(),
+            pricing: pricing.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializePerpSyncQueueInstructionAccountIndices {
+    pub admin: usize,
+    pub zeta_program: usize,
+    pub state: usize,
+    pub perp_sync_queue: usize,
+    pub pricing: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializePerpSyncQueue {
+    type ArrangedAccountIndices = InitializePerpSyncQueueInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(InitializePerpSyncQueueInstructionAccountIndices {
+            admin: 0,
+            zeta_program: 1,
+            state: 2,
+            perp_sync_queue: 3,
+            pricing: 4,
+            system_program: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializePerpSyncQueue {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "admin",
+            "zeta_program",
+            "state",
+            "perp_sync_queue",
+            "pricing",
+            "system_program",
+        ]
+    }
+}
@@ -1,6 +1,8 @@
 use alloc::string::String;
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +16,7 @@ pub struct InitializeReferrerAccountsInstructionAccounts {
     pub referrer_id_account: solana_pubkey::Pubkey,
     pub referrer_pubkey_account: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeReferrerAccounts {
@@ -22,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeReferrerAccounts {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [authority, referrer_id_account, referrer_pubkey_account, system_program, _remaining @ ..] =
+        let [authority, referrer_id_account, referrer_pubkey_account, system_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -33,6 +36,76 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeReferrerAccounts {
             referrer_id_account: referrer_id_account.pubkey,
             referrer_pubkey_account: referrer_pubkey_account.pubkey,
             system_program: system_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeReferrerAccountsInstructionAccountMetas {
+    pub authority: solana_instruction::AccountMeta,
+    pub referrer_id_account: solana_instruction::AccountMeta,
+    pub referrer_pubkey_account: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeReferrerAccounts {
+    type ArrangedAccountMetas = InitializeReferrerAccountsInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [authority, referrer_id_account, referrer_pubkey_account, system_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeReferrerAccountsInstructionAccountMetas {
+            authority: authority.clone(),
+            referrer_id_account: referrer_id_account.clone(),
+            referrer_pubkey_account: referrer_pubkey_account.clone(),
+            system_program: system_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeReferrerAccountsInstructionAccountIndices {
+    pub authority: usize,
+    pub referrer_id_account: usize,
+    pub referrer_pubkey_account: usize,
+    pub system_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeReferrerAccounts {
+    type ArrangedAccountIndices = InitializeReferrerAccountsInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(InitializeReferrerAccountsInstructionAccountIndices {
+            authority: 0,
+            referrer_id_account: 1,
+            referrer_pubkey_account: 2,
+            system_program: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeReferrerAccounts {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "authority",
+            "referrer_id_account",
+            "referrer_pubkey_account",
+            "system_program",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +15,7 @@ pub struct InitializeSpreadAccountInstructionAccounts {
     pub zeta_program: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub zeta_group: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeSpreadAccount {
@@ -21,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeSpreadAccount {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [spread_account, authority, payer, zeta_program, system_program, zeta_group, _remaining @ ..] =
+        let [spread_account, authority, payer, zeta_program, system_program, zeta_group, remaining @ ..] =
             accounts
         else {
             return None;
@@ -34,6 +37,86 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeSpreadAccount {
             zeta_program: zeta_program.pubkey,
             system_program: system_program.pubkey,
             zeta_group: zeta_group.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeSpreadAccountInstructionAccountMetas {
+    pub spread_account: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub payer: solana_instruction::AccountMeta,
+    pub zeta_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub zeta_group: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeSpreadAccount {
+    type ArrangedAccountMetas = InitializeSpreadAccountInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [spread_account, authority, payer, zeta_program, system_program, zeta_group, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeSpreadAccountInstructionAccountMetas {
+            spread_account: spread_account.clone(),
+            authority: authority.clone(),
+            payer: payer.clone(),
+            zeta_program: zeta_program.clone(),
+            system_program: system_program.clone(),
+            zeta_group: zeta_group.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeSpreadAccountInstructionAccountIndices {
+    pub spread_account: usize,
+    pub authority: usize,
+    pub payer: usize,
+    pub zeta_program: usize,
+    pub system_program: usize,
+    pub zeta_group: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeSpreadAccount {
+    type ArrangedAccountIndices = InitializeSpreadAccountInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(InitializeSpreadAccountInstructionAccountIndices {
+            spread_account: 0,
+            authority: 1,
+            payer: 2,
+            zeta_program: 3,
+            system_program: 4,
+            zeta_group: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeSpreadAccount {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "spread_account",
+            "authority",
+            "payer",
+            "zeta_program",
+            "system_program",
+            "zeta_group",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -15,6 +17,7 @@ pub struct InitializeUnderlyingInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub underlying: solana_pubkey::Pubkey,
     pub underlying_mint: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeUnderlying {
@@ -23,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeUnderlying {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [admin, zeta_program, state, system_program, underlying, underlying_mint, _remaining @ ..] =
+        let [admin, zeta_program, state, system_program, underlying, underlying_mint, remaining @ ..] =
             accounts
         else {
             return None;
@@ -36,6 +39,86 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeUnderlying {
             system_program: system_program.pubkey,
             underlying: underlying.pubkey,
             underlying_mint: underlying_mint.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeUnderlyingInstructionAccountMetas {
+    pub admin: solana_instruction::AccountMeta,
+    pub zeta_program: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub underlying: solana_instruction::AccountMeta,
+    pub underlying_mint: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeUnderlying {
+    type ArrangedAccountMetas = InitializeUnderlyingInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [admin, zeta_program, state, system_program, underlying, underlying_mint, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeUnderlyingInstructionAccountMetas {
+            admin: admin.clone(),
+            zeta_program: zeta_program.clone(),
+            state: state.clone(),
+            system_program: system_program.clone(),
+            underlying: underlying.clone(),
+            underlying_mint: underlying_mint.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeUnderlyingInstructionAccountIndices {
+    pub admin: usize,
+    pub zeta_program: usize,
+    pub state: usize,
+    pub system_program: usize,
+    pub underlying: usize,
+    pub underlying_mint: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeUnderlying {
+    type ArrangedAccountIndices = InitializeUnderlyingInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(InitializeUnderlyingInstructionAccountIndices {
+            admin: 0,
+            zeta_program: 1,
+            state: 2,
+            system_program: 3,
+            underlying: 4,
+            underlying_mint: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeUnderlying {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "admin",
+            "zeta_program",
+            "state",
+            "system_program",
+            "underlying",
+            "underlying_mint",
+        ]
+    }
+}
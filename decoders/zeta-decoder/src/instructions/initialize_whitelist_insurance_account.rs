@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +16,7 @@ pub struct InitializeWhitelistInsuranceAccountInstructionAccounts {
     pub user: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub state: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeWhitelistInsuranceAccount {
@@ -22,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeWhitelistInsuranceA
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [whitelist_insurance_account, admin, user, system_program, state, _remaining @ ..] =
+        let [whitelist_insurance_account, admin, user, system_program, state, remaining @ ..] =
             accounts
         else {
             return None;
@@ -34,6 +37,83 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeWhitelistInsuranceA
             user: user.pubkey,
             system_program: system_program.pubkey,
             state: state.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeWhitelistInsuranceAccountInstructionAccountMetas {
+    pub whitelist_insurance_account: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeWhitelistInsuranceAccount {
+    type ArrangedAccountMetas = InitializeWhitelistInsuranceAccountInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [whitelist_insurance_account, admin, user, system_program, state, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeWhitelistInsuranceAccountInstructionAccountMetas {
+            whitelist_insurance_account: whitelist_insurance_account.clone(),
+            admin: admin.clone(),
+            user: user.clone(),
+            system_program: system_program.clone(),
+            state: state.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeWhitelistInsuranceAccountInstructionAccountIndices {
+    pub whitelist_insurance_account: usize,
+    pub admin: usize,
+    pub user: usize,
+    pub system_program: usize,
+    pub state: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeWhitelistInsuranceAccount {
+    type ArrangedAccountIndices = InitializeWhitelistInsuranceAccountInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(
+            InitializeWhitelistInsuranceAccountInstructionAccountIndices {
+                whitelist_insurance_account: 0,
+                admin: 1,
+                user: 2,
+                system_program: 3,
+                state: 4,
+                remaining_accounts: 5..accounts.len(),
+            },
+        )
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeWhitelistInsuranceAccount {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "whitelist_insurance_account",
+            "admin",
+            "user",
+            "system_program",
+            "state",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +16,7 @@ pub struct InitializeWhitelistTradingFeesAccountInstructionAccounts {
     pub user: solana_pubkey::Pubkey,
     pub system_program: solana_pubkey::Pubkey,
     pub state: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeWhitelistTradingFeesAccount {
@@ -22,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeWhitelistTradingFee
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [whitelist_trading_fees_account, admin, user, system_program, state, _remaining @ ..] =
+        let [whitelist_trading_fees_account, admin, user, system_program, state, remaining @ ..] =
             accounts
         else {
             return None;
@@ -34,6 +37,85 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeWhitelistTradingFee
             user: user.pubkey,
             system_program: system_program.pubkey,
             state: state.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeWhitelistTradingFeesAccountInstructionAccountMetas {
+    pub whitelist_trading_fees_account: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub user: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeWhitelistTradingFeesAccount {
+    type ArrangedAccountMetas = InitializeWhitelistTradingFeesAccountInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [whitelist_trading_fees_account, admin, user, system_program, state, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(
+            InitializeWhitelistTradingFeesAccountInstructionAccountMetas {
+                whitelist_trading_fees_account: whitelist_trading_fees_account.clone(),
+                admin: admin.clone(),
+                user: user.clone(),
+                system_program: system_program.clone(),
+                state: state.clone(),
+                remaining_accounts: remaining.to_vec(),
+            },
+        )
+    }
+}
+
+pub struct InitializeWhitelistTradingFeesAccountInstructionAccountIndices {
+    pub whitelist_trading_fees_account: usize,
+    pub admin: usize,
+    pub user: usize,
+    pub system_program: usize,
+    pub state: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeWhitelistTradingFeesAccount {
+    type ArrangedAccountIndices = InitializeWhitelistTradingFeesAccountInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 5 {
+            return None;
+        }
+
+        Some(
+            InitializeWhitelistTradingFeesAccountInstructionAccountIndices {
+                whitelist_trading_fees_account: 0,
+                admin: 1,
+                user: 2,
+                system_program: 3,
+                state: 4,
+                remaining_accounts: 5..accounts.len(),
+            },
+        )
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeWhitelistTradingFeesAccount {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "whitelist_trading_fees_account",
+            "admin",
+            "user",
+            "system_program",
+            "state",
+        ]
+    }
+}
@@ -1,7 +1,9 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -29,6 +31,7 @@ pub struct InitializeZetaGroupInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub usdc_mint: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeZetaGroup {
@@ -37,7 +40,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeZetaGroup {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, admin, system_program, underlying_mint, zeta_program, oracle, oracle_backup_feed, oracle_backup_program, zeta_group, greeks, perp_sync_queue, underlying, vault, insurance_vault, socialized_loss_account, token_program, usdc_mint, rent, _remaining @ ..] =
+        let [state, admin, system_program, underlying_mint, zeta_program, oracle, oracle_backup_feed, oracle_backup_program, zeta_group, greeks, perp_sync_queue, underlying, vault, insurance_vault, socialized_loss_account, token_program, usdc_mint, rent, remaining @ ..] =
             accounts
         else {
             return None;
@@ -62,6 +65,146 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeZetaGroup {
             token_program: token_program.pubkey,
             usdc_mint: usdc_mint.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeZetaGroupInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub underlying_mint: solana_instruction::AccountMeta,
+    pub zeta_program: solana_instruction::AccountMeta,
+    pub oracle: solana_instruction::AccountMeta,
+    pub oracle_backup_feed: solana_instruction::AccountMeta,
+    pub oracle_backup_program: solana_instruction::AccountMeta,
+    pub zeta_group: solana_instruction::AccountMeta,
+    pub greeks: solana_instruction::AccountMeta,
+    pub perp_sync_queue: solana_instruction::AccountMeta,
+    pub underlying: solana_instruction::AccountMeta,
+    pub vault: solana_instruction::AccountMeta,
+    pub insurance_vault: solana_instruction::AccountMeta,
+    pub socialized_loss_account: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub usdc_mint: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeZetaGroup {
+    type ArrangedAccountMetas = InitializeZetaGroupInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, admin, system_program, underlying_mint, zeta_program, oracle, oracle_backup_feed, oracle_backup_program, zeta_group, greeks, perp_sync_queue, underlying, vault, insurance_vault, socialized_loss_account, token_program, usdc_mint, rent, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeZetaGroupInstructionAccountMetas {
+            state: state.clone(),
+            admin: admin.clone(),
+            system_program: system_program.clone(),
+            underlying_mint: underlying_mint.clone(),
+            zeta_program: zeta_program.clone(),
+            oracle: oracle.clone(),
+            oracle_backup_feed: oracle_backup_feed.clone(),
+            oracle_backup_program: oracle_backup_program.clone(),
+            zeta_group: zeta_group.clone(),
+            greeks: greeks.clone(),
+            perp_sync_queue: perp_sync_queue.clone(),
+            underlying: underlying.clone(),
+            vault: vault.clone(),
+            insurance_vault: insurance_vault.clone(),
+            socialized_loss_account: socialized_loss_account.clone(),
+            token_program: token_program.clone(),
+            usdc_mint: usdc_mint.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeZetaGroupInstructionAccountIndices {
+    pub state: usize,
+    pub admin: usize,
+    pub system_program: usize,
+    pub underlying_mint: usize,
+    pub zeta_program: usize,
+    pub oracle: usize,
+    pub oracle_backup_feed: usize,
+    pub oracle_backup_program: usize,
+    pub zeta_group: usize,
+    pub greeks: usize,
+    pub perp_sync_queue: usize,
+    pub underlying: usize,
+    pub vault: usize,
+    pub insurance_vault: usize,
+    pub socialized_loss_account: usize,
+    pub token_program: usize,
+    pub usdc_mint: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeZetaGroup {
+    type ArrangedAccountIndices = InitializeZetaGroupInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 18 {
+            return None;
+        }
+
+        Some(InitializeZetaGroupInstructionAccountIndices {
+            state: 0,
+            admin: 1,
+            system_program: 2,
+            underlying_mint: 3,
+            zeta_program: 4,
+            oracle: 5,
+            oracle_backup_feed: 6,
+            oracle_backup_program: 7,
+            zeta_group: 8,
+            greeks: 9,
+            perp_sync_queue: 10,
+            underlying: 11,
+            vault: 12,
+            insurance_vault: 13,
+            socialized_loss_account: 14,
+            token_program: 15,
+            usdc_mint: 16,
+            rent: 17,
+            remaining_accounts: 18..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeZetaGroup {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "admin",
+            "system_program",
+            "underlying_mint",
+            "zeta_program",
+            "oracle",
+            "oracle_backup_feed",
+            "oracle_backup_program",
+            "zeta_group",
+            "greeks",
+            "perp_sync_queue",
+            "underlying",
+            "vault",
+            "insurance_vault",
+            "socialized_loss_account",
+            "token_program",
+            "usdc_mint",
+            "rent",
+        ]
+    }
+}
@@ -1,7 +1,9 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -31,6 +33,7 @@ pub struct InitializeZetaMarketInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeZetaMarket {
@@ -39,7 +42,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeZetaMarket {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, market_indexes, pricing, admin, market, request_queue, event_queue, bids, asks, base_mint, quote_mint, dex_base_vault, dex_quote_vault, vault_owner, mint_authority, serum_authority, dex_program, system_program, token_program, rent, _remaining @ ..] =
+        let [state, market_indexes, pricing, admin, market, request_queue, event_queue, bids, asks, base_mint, quote_mint, dex_base_vault, dex_quote_vault, vault_owner, mint_authority, serum_authority, dex_program, system_program, token_program, rent, remaining @ ..] =
             accounts
         else {
             return None;
@@ -66,6 +69,156 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeZetaMarket {
             system_program: system_program.pubkey,
             token_program: token_program.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeZetaMarketInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub market_indexes: solana_instruction::AccountMeta,
+    pub pricing: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub market: solana_instruction::AccountMeta,
+    pub request_queue: solana_instruction::AccountMeta,
+    pub event_queue: solana_instruction::AccountMeta,
+    pub bids: solana_instruction::AccountMeta,
+    pub asks: solana_instruction::AccountMeta,
+    pub base_mint: solana_instruction::AccountMeta,
+    pub quote_mint: solana_instruction::AccountMeta,
+    pub dex_base_vault: solana_instruction::AccountMeta,
+    pub dex_quote_vault: solana_instruction::AccountMeta,
+    pub vault_owner: solana_instruction::AccountMeta,
+    pub mint_authority: solana_instruction::AccountMeta,
+    pub serum_authority: solana_instruction::AccountMeta,
+    pub dex_program: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeZetaMarket {
+    type ArrangedAccountMetas = InitializeZetaMarketInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, market_indexes, pricing, admin, market, request_queue, event_queue, bids, asks, base_mint, quote_mint, dex_base_vault, dex_quote_vault, vault_owner, mint_authority, serum_authority, dex_program, system_program, token_program, rent, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeZetaMarketInstructionAccountMetas {
+            state: state.clone(),
+            market_indexes: market_indexes.clone(),
+            pricing: pricing.clone(),
+            admin: admin.clone(),
+            market: market.clone(),
+            request_queue: request_queue.clone(),
+            event_queue: event_queue.clone(),
+            bids: bids.clone(),
+            asks: asks.clone(),
+            base_mint: base_mint.clone(),
+            quote_mint: quote_mint.clone(),
+            dex_base_vault: dex_base_vault.clone(),
+            dex_quote_vault: dex_quote_vault.clone(),
+            vault_owner: vault_owner.clone(),
+            mint_authority: mint_authority.clone(),
+            serum_authority: serum_authority.clone(),
+            dex_program: dex_program.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeZetaMarketInstructionAccountIndices {
+    pub state: usize,
+    pub market_indexes: usize,
+    pub pricing: usize,
+    pub admin: usize,
+    pub market: usize,
+    pub request_queue: usize,
+    pub event_queue: usize,
+    pub bids: usize,
+    pub asks: usize,
+    pub base_mint: usize,
+    pub quote_mint: usize,
+    pub dex_base_vault: usize,
+    pub dex_quote_vault: usize,
+    pub vault_owner: usize,
+    pub mint_authority: usize,
+    pub serum_authority: usize,
+    pub dex_program: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeZetaMarket {
+    type ArrangedAccountIndices = InitializeZetaMarketInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 20 {
+            return None;
+        }
+
+        Some(InitializeZetaMarketInstructionAccountIndices {
+            state: 0,
+            market_indexes: 1,
+            pricing: 2,
+            admin: 3,
+            market: 4,
+            request_queue: 5,
+            event_queue: 6,
+            bids: 7,
+            asks: 8,
+            base_mint: 9,
+            quote_mint: 10,
+            dex_base_vault: 11,
+            dex_quote_vault: 12,
+            vault_owner: 13,
+            mint_authority: 14,
+            serum_authority: 15,
+            dex_program: 16,
+            system_program: 17,
+            token_program: 18,
+            rent: 19,
+            remaining_accounts: 20..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeZetaMarket {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "market_indexes",
+            "pricing",
+            "admin",
+            "market",
+            "request_queue",
+            "event_queue",
+            "bids",
+            "asks",
+            "base_mint",
+            "quote_mint",
+            "dex_base_vault",
+            "dex_quote_vault",
+            "vault_owner",
+            "mint_authority",
+            "serum_authority",
+            "dex_program",
+            "system_program",
+            "token_program",
+            "rent",
+        ]
+    }
+}
@@ -1,7 +1,9 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -17,6 +19,7 @@ pub struct InitializeZetaPricingInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeZetaPricing {
@@ -25,8 +28,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeZetaPricing {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, pricing, rent, system_program, token_program, admin, _remaining @ ..] =
-            accounts
+        let [state, pricing, rent, system_program, token_program, admin, remaining @ ..] = accounts
         else {
             return None;
         };
@@ -38,6 +40,85 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeZetaPricing {
             system_program: system_program.pubkey,
             token_program: token_program.pubkey,
             admin: admin.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeZetaPricingInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub pricing: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeZetaPricing {
+    type ArrangedAccountMetas = InitializeZetaPricingInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, pricing, rent, system_program, token_program, admin, remaining @ ..] = accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeZetaPricingInstructionAccountMetas {
+            state: state.clone(),
+            pricing: pricing.clone(),
+            rent: rent.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            admin: admin.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeZetaPricingInstructionAccountIndices {
+    pub state: usize,
+    pub pricing: usize,
+    pub rent: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub admin: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeZetaPricing {
+    type ArrangedAccountIndices = InitializeZetaPricingInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(InitializeZetaPricingInstructionAccountIndices {
+            state: 0,
+            pricing: 1,
+            rent: 2,
+            system_program: 3,
+            token_program: 4,
+            admin: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeZetaPricing {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "pricing",
+            "rent",
+            "system_program",
+            "token_program",
+            "admin",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +16,7 @@ pub struct InitializeZetaReferralsRewardsWalletInstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub usdc_mint: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeZetaReferralsRewardsWallet {
@@ -22,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeZetaReferralsReward
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, referrals_rewards_wallet, rent, system_program, token_program, usdc_mint, admin, _remaining @ ..] =
+        let [state, referrals_rewards_wallet, rent, system_program, token_program, usdc_mint, admin, remaining @ ..] =
             accounts
         else {
             return None;
@@ -36,6 +39,95 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeZetaReferralsReward
             token_program: token_program.pubkey,
             usdc_mint: usdc_mint.pubkey,
             admin: admin.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeZetaReferralsRewardsWalletInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub referrals_rewards_wallet: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub usdc_mint: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeZetaReferralsRewardsWallet {
+    type ArrangedAccountMetas = InitializeZetaReferralsRewardsWalletInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, referrals_rewards_wallet, rent, system_program, token_program, usdc_mint, admin, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(
+            InitializeZetaReferralsRewardsWalletInstructionAccountMetas {
+                state: state.clone(),
+                referrals_rewards_wallet: referrals_rewards_wallet.clone(),
+                rent: rent.clone(),
+                system_program: system_program.clone(),
+                token_program: token_program.clone(),
+                usdc_mint: usdc_mint.clone(),
+                admin: admin.clone(),
+                remaining_accounts: remaining.to_vec(),
+            },
+        )
+    }
+}
+
+pub struct InitializeZetaReferralsRewardsWalletInstructionAccountIndices {
+    pub state: usize,
+    pub referrals_rewards_wallet: usize,
+    pub rent: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub usdc_mint: usize,
+    pub admin: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeZetaReferralsRewardsWallet {
+    type ArrangedAccountIndices = InitializeZetaReferralsRewardsWalletInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 7 {
+            return None;
+        }
+
+        Some(
+            InitializeZetaReferralsRewardsWalletInstructionAccountIndices {
+                state: 0,
+                referrals_rewards_wallet: 1,
+                rent: 2,
+                system_program: 3,
+                token_program: 4,
+                usdc_mint: 5,
+                admin: 6,
+                remaining_accounts: 7..accounts.len(),
+            },
+        )
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeZetaReferralsRewardsWallet {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "referrals_rewards_wallet",
+            "rent",
+            "system_program",
+            "token_program",
+            "usdc_mint",
+            "admin",
+        ]
+    }
+}
@@ -1,7 +1,9 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -24,6 +26,7 @@ pub struct InitializeZetaSpecificMarketVaultsInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub rent: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeZetaSpecificMarketVaults {
@@ -32,7 +35,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeZetaSpecificMarketV
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, market_indexes, pricing, admin, market, base_mint, quote_mint, zeta_base_vault, zeta_quote_vault, serum_authority, system_program, token_program, rent, _remaining @ ..] =
+        let [state, market_indexes, pricing, admin, market, base_mint, quote_mint, zeta_base_vault, zeta_quote_vault, serum_authority, system_program, token_program, rent, remaining @ ..] =
             accounts
         else {
             return None;
@@ -52,6 +55,123 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeZetaSpecificMarketV
             system_program: system_program.pubkey,
             token_program: token_program.pubkey,
             rent: rent.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeZetaSpecificMarketVaultsInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub market_indexes: solana_instruction::AccountMeta,
+    pub pricing: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub market: solana_instruction::AccountMeta,
+    pub base_mint: solana_instruction::AccountMeta,
+    pub quote_mint: solana_instruction::AccountMeta,
+    pub zeta_base_vault: solana_instruction::AccountMeta,
+    pub zeta_quote_vault: solana_instruction::AccountMeta,
+    pub serum_authority: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeZetaSpecificMarketVaults {
+    type ArrangedAccountMetas = InitializeZetaSpecificMarketVaultsInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, market_indexes, pricing, admin, market, base_mint, quote_mint, zeta_base_vault, zeta_quote_vault, serum_authority, system_program, token_program, rent, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeZetaSpecificMarketVaultsInstructionAccountMetas {
+            state: state.clone(),
+            market_indexes: market_indexes.clone(),
+            pricing: pricing.clone(),
+            admin: admin.clone(),
+            market: market.clone(),
+            base_mint: base_mint.clone(),
+            quote_mint: quote_mint.clone(),
+            zeta_base_vault: zeta_base_vault.clone(),
+            zeta_quote_vault: zeta_quote_vault.clone(),
+            serum_authority: serum_authority.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            rent: rent.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeZetaSpecificMarketVaultsInstructionAccountIndices {
+    pub state: usize,
+    pub market_indexes: usize,
+    pub pricing: usize,
+    pub admin: usize,
+    pub market: usize,
+    pub base_mint: usize,
+    pub quote_mint: usize,
+    pub zeta_base_vault: usize,
+    pub zeta_quote_vault: usize,
+    pub serum_authority: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub rent: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeZetaSpecificMarketVaults {
+    type ArrangedAccountIndices = InitializeZetaSpecificMarketVaultsInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 13 {
+            return None;
+        }
+
+        Some(
+            InitializeZetaSpecificMarketVaultsInstructionAccountIndices {
+                state: 0,
+                market_indexes: 1,
+                pricing: 2,
+                admin: 3,
+                market: 4,
+                base_mint: 5,
+                quote_mint: 6,
+                zeta_base_vault: 7,
+                zeta_quote_vault: 8,
+                serum_authority: 9,
+                system_program: 10,
+                token_program: 11,
+                rent: 12,
+                remaining_accounts: 13..accounts.len(),
+            },
+        )
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeZetaSpecificMarketVaults {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "market_indexes",
+            "pricing",
+            "admin",
+            "market",
+            "base_mint",
+            "quote_mint",
+            "zeta_base_vault",
+            "zeta_quote_vault",
+            "serum_authority",
+            "system_program",
+            "token_program",
+            "rent",
+        ]
+    }
+}
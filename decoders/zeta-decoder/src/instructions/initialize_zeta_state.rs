@@ -1,7 +1,9 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -23,6 +25,7 @@ pub struct InitializeZetaStateInstructionAccounts {
     pub usdc_mint: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
     pub secondary_admin: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for InitializeZetaState {
@@ -31,7 +34,7 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeZetaState {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, mint_authority, serum_authority, treasury_wallet, referrals_admin, referrals_rewards_wallet, rent, system_program, token_program, usdc_mint, admin, secondary_admin, _remaining @ ..] =
+        let [state, mint_authority, serum_authority, treasury_wallet, referrals_admin, referrals_rewards_wallet, rent, system_program, token_program, usdc_mint, admin, secondary_admin, remaining @ ..] =
             accounts
         else {
             return None;
@@ -50,6 +53,116 @@ impl carbon_core::deserialize::ArrangeAccounts for InitializeZetaState {
             usdc_mint: usdc_mint.pubkey,
             admin: admin.pubkey,
             secondary_admin: secondary_admin.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct InitializeZetaStateInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub mint_authority: solana_instruction::AccountMeta,
+    pub serum_authority: solana_instruction::AccountMeta,
+    pub treasury_wallet: solana_instruction::AccountMeta,
+    pub referrals_admin: solana_instruction::AccountMeta,
+    pub referrals_rewards_wallet: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub usdc_mint: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub secondary_admin: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for InitializeZetaState {
+    type ArrangedAccountMetas = InitializeZetaStateInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, mint_authority, serum_authority, treasury_wallet, referrals_admin, referrals_rewards_wallet, rent, system_program, token_program, usdc_mint, admin, secondary_admin, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(InitializeZetaStateInstructionAccountMetas {
+            state: state.clone(),
+            mint_authority: mint_authority.clone(),
+            serum_authority: serum_authority.clone(),
+            treasury_wallet: treasury_wallet.clone(),
+            referrals_admin: referrals_admin.clone(),
+            referrals_rewards_wallet: referrals_rewards_wallet.clone(),
+            rent: rent.clone(),
+            system_program: system_program.clone(),
+            token_program: token_program.clone(),
+            usdc_mint: usdc_mint.clone(),
+            admin: admin.clone(),
+            secondary_admin: secondary_admin.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct InitializeZetaStateInstructionAccountIndices {
+    pub state: usize,
+    pub mint_authority: usize,
+    pub serum_authority: usize,
+    pub treasury_wallet: usize,
+    pub referrals_admin: usize,
+    pub referrals_rewards_wallet: usize,
+    pub rent: usize,
+    pub system_program: usize,
+    pub token_program: usize,
+    pub usdc_mint: usize,
+    pub admin: usize,
+    pub secondary_admin: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for InitializeZetaState {
+    type ArrangedAccountIndices = InitializeZetaStateInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 12 {
+            return None;
+        }
+
+        Some(InitializeZetaStateInstructionAccountIndices {
+            state: 0,
+            mint_authority: 1,
+            serum_authority: 2,
+            treasury_wallet: 3,
+            referrals_admin: 4,
+            referrals_rewards_wallet: 5,
+            rent: 6,
+            system_program: 7,
+            token_program: 8,
+            usdc_mint: 9,
+            admin: 10,
+            secondary_admin: 11,
+            remaining_accounts: 12..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for InitializeZetaState {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "mint_authority",
+            "serum_authority",
+            "treasury_wallet",
+            "referrals_admin",
+            "referrals_rewards_wallet",
+            "rent",
+            "system_program",
+            "token_program",
+            "usdc_mint",
+            "admin",
+            "secondary_admin",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -19,6 +21,7 @@ pub struct LiquidateInstructionAccounts {
     pub market: solana_pubkey::Pubkey,
     pub zeta_group: solana_pubkey::Pubkey,
     pub liquidated_margin_account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Liquidate {
@@ -27,7 +30,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Liquidate {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, liquidator, liquidator_margin_account, greeks, oracle, oracle_backup_feed, oracle_backup_program, market, zeta_group, liquidated_margin_account, _remaining @ ..] =
+        let [state, liquidator, liquidator_margin_account, greeks, oracle, oracle_backup_feed, oracle_backup_program, market, zeta_group, liquidated_margin_account, remaining @ ..] =
             accounts
         else {
             return None;
@@ -44,6 +47,106 @@ impl carbon_core::deserialize::ArrangeAccounts for Liquidate {
             market: market.pubkey,
             zeta_group: zeta_group.pubkey,
             liquidated_margin_account: liquidated_margin_account.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct LiquidateInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub liquidator: solana_instruction::AccountMeta,
+    pub liquidator_margin_account: solana_instruction::AccountMeta,
+    pub greeks: solana_instruction::AccountMeta,
+    pub oracle: solana_instruction::AccountMeta,
+    pub oracle_backup_feed: solana_instruction::AccountMeta,
+    pub oracle_backup_program: solana_instruction::AccountMeta,
+    pub market: solana_instruction::AccountMeta,
+    pub zeta_group: solana_instruction::AccountMeta,
+    pub liquidated_margin_account: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Liquidate {
+    type ArrangedAccountMetas = LiquidateInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, liquidator, liquidator_margin_account, greeks, oracle, oracle_backup_feed, oracle_backup_program, market, zeta_group, liquidated_margin_account, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(LiquidateInstructionAccountMetas {
+            state: state.clone(),
+            liquidator: liquidator.clone(),
+            liquidator_margin_account: liquidator_margin_account.clone(),
+            greeks: greeks.clone(),
+            oracle: oracle.clone(),
+            oracle_backup_feed: oracle_backup_feed.clone(),
+            oracle_backup_program: oracle_backup_program.clone(),
+            market: market.clone(),
+            zeta_group: zeta_group.clone(),
+            liquidated_margin_account: liquidated_margin_account.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct LiquidateInstructionAccountIndices {
+    pub state: usize,
+    pub liquidator: usize,
+    pub liquidator_margin_account: usize,
+    pub greeks: usize,
+    pub oracle: usize,
+    pub oracle_backup_feed: usize,
+    pub oracle_backup_program: usize,
+    pub market: usize,
+    pub zeta_group: usize,
+    pub liquidated_margin_account: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Liquidate {
+    type ArrangedAccountIndices = LiquidateInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 10 {
+            return None;
+        }
+
+        Some(LiquidateInstructionAccountIndices {
+            state: 0,
+            liquidator: 1,
+            liquidator_margin_account: 2,
+            greeks: 3,
+            oracle: 4,
+            oracle_backup_feed: 5,
+            oracle_backup_program: 6,
+            market: 7,
+            zeta_group: 8,
+            liquidated_margin_account: 9,
+            remaining_accounts: 10..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Liquidate {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "liquidator",
+            "liquidator_margin_account",
+            "greeks",
+            "oracle",
+            "oracle_backup_feed",
+            "oracle_backup_program",
+            "market",
+            "zeta_group",
+            "liquidated_margin_account",
+        ]
+    }
+}
@@ -1,7 +1,9 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -21,6 +23,7 @@ pub struct LiquidateV2InstructionAccounts {
     pub oracle_backup_program: solana_pubkey::Pubkey,
     pub market: solana_pubkey::Pubkey,
     pub liquidated_account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for LiquidateV2 {
@@ -29,7 +32,7 @@ impl carbon_core::deserialize::ArrangeAccounts for LiquidateV2 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, liquidator, liquidator_account, pricing, oracle, oracle_backup_feed, oracle_backup_program, market, liquidated_account, _remaining @ ..] =
+        let [state, liquidator, liquidator_account, pricing, oracle, oracle_backup_feed, oracle_backup_program, market, liquidated_account, remaining @ ..] =
             accounts
         else {
             return None;
@@ -45,6 +48,101 @@ impl carbon_core::deserialize::ArrangeAccounts for LiquidateV2 {
             oracle_backup_program: oracle_backup_program.pubkey,
             market: market.pubkey,
             liquidated_account: liquidated_account.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct LiquidateV2InstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub liquidator: solana_instruction::AccountMeta,
+    pub liquidator_account: solana_instruction::AccountMeta,
+    pub pricing: solana_instruction::AccountMeta,
+    pub oracle: solana_instruction::AccountMeta,
+    pub oracle_backup_feed: solana_instruction::AccountMeta,
+    pub oracle_backup_program: solana_instruction::AccountMeta,
+    pub market: solana_instruction::AccountMeta,
+    pub liquidated_account: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for LiquidateV2 {
+    type ArrangedAccountMetas = LiquidateV2InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, liquidator, liquidator_account, pricing, oracle, oracle_backup_feed, oracle_backup_program, market, liquidated_account, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(LiquidateV2InstructionAccountMetas {
+            state: state.clone(),
+            liquidator: liquidator.clone(),
+            liquidator_account: liquidator_account.clone(),
+            pricing: pricing.clone(),
+            oracle: oracle.clone(),
+            oracle_backup_feed: oracle_backup_feed.clone(),
+            oracle_backup_program: oracle_backup_program.clone(),
+            market: market.clone(),
+            liquidated_account: liquidated_account.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct LiquidateV2InstructionAccountIndices {
+    pub state: usize,
+    pub liquidator: usize,
+    pub liquidator_account: usize,
+    pub pricing: usize,
+    pub oracle: usize,
+    pub oracle_backup_feed: usize,
+    pub oracle_backup_program: usize,
+    pub market: usize,
+    pub liquidated_account: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for LiquidateV2 {
+    type ArrangedAccountIndices = LiquidateV2InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 9 {
+            return None;
+        }
+
+        Some(LiquidateV2InstructionAccountIndices {
+            state: 0,
+            liquidator: 1,
+            liquidator_account: 2,
+            pricing: 3,
+            oracle: 4,
+            oracle_backup_feed: 5,
+            oracle_backup_program: 6,
+            market: 7,
+            liquidated_account: 8,
+            remaining_accounts: 9..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for LiquidateV2 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "liquidator",
+            "liquidator_account",
+            "pricing",
+            "oracle",
+            "oracle_backup_feed",
+            "oracle_backup_program",
+            "market",
+            "liquidated_account",
+        ]
+    }
+}
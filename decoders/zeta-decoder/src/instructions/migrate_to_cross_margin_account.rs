@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -10,6 +12,7 @@ pub struct MigrateToCrossMarginAccountInstructionAccounts {
     pub cross_margin_account: solana_pubkey::Pubkey,
     pub pricing: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for MigrateToCrossMarginAccount {
@@ -18,7 +21,7 @@ impl carbon_core::deserialize::ArrangeAccounts for MigrateToCrossMarginAccount {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [cross_margin_account, pricing, authority, _remaining @ ..] = accounts else {
+        let [cross_margin_account, pricing, authority, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -26,6 +29,65 @@ impl carbon_core::deserialize::ArrangeAccounts for MigrateToCrossMarginAccount {
             cross_margin_account: cross_margin_account.pubkey,
             pricing: pricing.pubkey,
             authority: authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct MigrateToCrossMarginAccountInstructionAccountMetas {
+    pub cross_margin_account: solana_instruction::AccountMeta,
+    pub pricing: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for MigrateToCrossMarginAccount {
+    type ArrangedAccountMetas = MigrateToCrossMarginAccountInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [cross_margin_account, pricing, authority, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(MigrateToCrossMarginAccountInstructionAccountMetas {
+            cross_margin_account: cross_margin_account.clone(),
+            pricing: pricing.clone(),
+            authority: authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct MigrateToCrossMarginAccountInstructionAccountIndices {
+    pub cross_margin_account: usize,
+    pub pricing: usize,
+    pub authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for MigrateToCrossMarginAccount {
+    type ArrangedAccountIndices = MigrateToCrossMarginAccountInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(MigrateToCrossMarginAccountInstructionAccountIndices {
+            cross_margin_account: 0,
+            pricing: 1,
+            authority: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for MigrateToCrossMarginAccount {
+    fn account_names() -> &'static [&'static str] {
+        &["cross_margin_account", "pricing", "authority"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +13,7 @@ pub struct MigrateToNewCrossMarginAccountInstructionAccounts {
     pub old_cross_margin_account: solana_pubkey::Pubkey,
     pub pricing: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for MigrateToNewCrossMarginAccount {
@@ -19,7 +22,7 @@ impl carbon_core::deserialize::ArrangeAccounts for MigrateToNewCrossMarginAccoun
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [new_cross_margin_account, old_cross_margin_account, pricing, authority, _remaining @ ..] =
+        let [new_cross_margin_account, old_cross_margin_account, pricing, authority, remaining @ ..] =
             accounts
         else {
             return None;
@@ -30,6 +33,76 @@ impl carbon_core::deserialize::ArrangeAccounts for MigrateToNewCrossMarginAccoun
             old_cross_margin_account: old_cross_margin_account.pubkey,
             pricing: pricing.pubkey,
             authority: authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct MigrateToNewCrossMarginAccountInstructionAccountMetas {
+    pub new_cross_margin_account: solana_instruction::AccountMeta,
+    pub old_cross_margin_account: solana_instruction::AccountMeta,
+    pub pricing: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for MigrateToNewCrossMarginAccount {
+    type ArrangedAccountMetas = MigrateToNewCrossMarginAccountInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [new_cross_margin_account, old_cross_margin_account, pricing, authority, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(MigrateToNewCrossMarginAccountInstructionAccountMetas {
+            new_cross_margin_account: new_cross_margin_account.clone(),
+            old_cross_margin_account: old_cross_margin_account.clone(),
+            pricing: pricing.clone(),
+            authority: authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct MigrateToNewCrossMarginAccountInstructionAccountIndices {
+    pub new_cross_margin_account: usize,
+    pub old_cross_margin_account: usize,
+    pub pricing: usize,
+    pub authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for MigrateToNewCrossMarginAccount {
+    type ArrangedAccountIndices = MigrateToNewCrossMarginAccountInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(MigrateToNewCrossMarginAccountInstructionAccountIndices {
+            new_cross_margin_account: 0,
+            old_cross_margin_account: 1,
+            pricing: 2,
+            authority: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for MigrateToNewCrossMarginAccount {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "new_cross_margin_account",
+            "old_cross_margin_account",
+            "pricing",
+            "authority",
+        ]
+    }
+}
@@ -352,15 +352,25 @@ pub enum ZetaInstruction {
 impl carbon_core::instruction::InstructionDecoder<'_> for ZetaDecoder {
     type InstructionType = ZetaInstruction;
 
-    fn decode_instruction(
+    fn decoder_name(&self) -> &'static str {
+        "zeta"
+    }
+
+    fn program_ids(&self) -> &[solana_pubkey::Pubkey] {
+        &[super::PROGRAM_ID]
+    }
+
+    fn try_decode_instruction(
         &self,
         instruction: &solana_instruction::Instruction,
-    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+    ) -> carbon_core::error::CarbonResult<
+        Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>>,
+    > {
         if !instruction.program_id.eq(&PROGRAM_ID) {
-            return None;
+            return Ok(None);
         }
 
-        carbon_core::try_decode_instructions!(instruction,
+        Ok(carbon_core::try_decode_instructions!(instruction,
             ZetaInstruction::InitializeZetaPricing => initialize_zeta_pricing::InitializeZetaPricing,
             ZetaInstruction::UpdateZetaPricingPubkeys => update_zeta_pricing_pubkeys::UpdateZetaPricingPubkeys,
             ZetaInstruction::InitializeZetaGroup => initialize_zeta_group::InitializeZetaGroup,
@@ -510,6 +520,6 @@ impl carbon_core::instruction::InstructionDecoder<'_> for ZetaDecoder {
             ZetaInstruction::OrderCompleteEvent => order_complete_event::OrderCompleteEvent,
             ZetaInstruction::ApplyFundingEvent => apply_funding_event::ApplyFundingEvent,
             ZetaInstruction::PlaceMultiOrdersEvent => place_multi_orders_event::PlaceMultiOrdersEvent,
-        )
+        ))
     }
 }
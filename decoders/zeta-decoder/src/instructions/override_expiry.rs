@@ -1,7 +1,9 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +16,7 @@ pub struct OverrideExpiryInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
     pub zeta_group: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for OverrideExpiry {
@@ -22,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for OverrideExpiry {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, admin, zeta_group, _remaining @ ..] = accounts else {
+        let [state, admin, zeta_group, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -30,6 +33,65 @@ impl carbon_core::deserialize::ArrangeAccounts for OverrideExpiry {
             state: state.pubkey,
             admin: admin.pubkey,
             zeta_group: zeta_group.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct OverrideExpiryInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub zeta_group: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for OverrideExpiry {
+    type ArrangedAccountMetas = OverrideExpiryInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, admin, zeta_group, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(OverrideExpiryInstructionAccountMetas {
+            state: state.clone(),
+            admin: admin.clone(),
+            zeta_group: zeta_group.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct OverrideExpiryInstructionAccountIndices {
+    pub state: usize,
+    pub admin: usize,
+    pub zeta_group: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for OverrideExpiry {
+    type ArrangedAccountIndices = OverrideExpiryInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(OverrideExpiryInstructionAccountIndices {
+            state: 0,
+            admin: 1,
+            zeta_group: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for OverrideExpiry {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "admin", "zeta_group"]
+    }
+}
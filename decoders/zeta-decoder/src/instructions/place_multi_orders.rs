@@ -3,6 +3,7 @@ use super::super::types::*;
 use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -40,6 +41,7 @@ pub struct PlaceMultiOrdersInstructionAccounts {
     pub market_quote_mint: solana_pubkey::Pubkey,
     pub mint_authority: solana_pubkey::Pubkey,
     pub perp_sync_queue: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for PlaceMultiOrders {
@@ -48,7 +50,7 @@ impl carbon_core::deserialize::ArrangeAccounts for PlaceMultiOrders {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [authority, state, pricing, margin_account, dex_program, token_program, serum_authority, open_orders, rent, market, request_queue, event_queue, bids, asks, market_base_vault, market_quote_vault, zeta_base_vault, zeta_quote_vault, oracle, oracle_backup_feed, oracle_backup_program, market_base_mint, market_quote_mint, mint_authority, perp_sync_queue, _remaining @ ..] =
+        let [authority, state, pricing, margin_account, dex_program, token_program, serum_authority, open_orders, rent, market, request_queue, event_queue, bids, asks, market_base_vault, market_quote_vault, zeta_base_vault, zeta_quote_vault, oracle, oracle_backup_feed, oracle_backup_program, market_base_mint, market_quote_mint, mint_authority, perp_sync_queue, remaining @ ..] =
             accounts
         else {
             return None;
@@ -80,6 +82,181 @@ impl carbon_core::deserialize::ArrangeAccounts for PlaceMultiOrders {
             market_quote_mint: market_quote_mint.pubkey,
             mint_authority: mint_authority.pubkey,
             perp_sync_queue: perp_sync_queue.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct PlaceMultiOrdersInstructionAccountMetas {
+    pub authority: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub pricing: solana_instruction::AccountMeta,
+    pub margin_account: solana_instruction::AccountMeta,
+    pub dex_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub serum_authority: solana_instruction::AccountMeta,
+    pub open_orders: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub market: solana_instruction::AccountMeta,
+    pub request_queue: solana_instruction::AccountMeta,
+    pub event_queue: solana_instruction::AccountMeta,
+    pub bids: solana_instruction::AccountMeta,
+    pub asks: solana_instruction::AccountMeta,
+    pub market_base_vault: solana_instruction::AccountMeta,
+    pub market_quote_vault: solana_instruction::AccountMeta,
+    pub zeta_base_vault: solana_instruction::AccountMeta,
+    pub zeta_quote_vault: solana_instruction::AccountMeta,
+    pub oracle: solana_instruction::AccountMeta,
+    pub oracle_backup_feed: solana_instruction::AccountMeta,
+    pub oracle_backup_program: solana_instruction::AccountMeta,
+    pub market_base_mint: solana_instruction::AccountMeta,
+    pub market_quote_mint: solana_instruction::AccountMeta,
+    pub mint_authority: solana_instruction::AccountMeta,
+    pub perp_sync_queue: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for PlaceMultiOrders {
+    type ArrangedAccountMetas = PlaceMultiOrdersInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [authority, state, pricing, margin_account, dex_program, token_program, serum_authority, open_orders, rent, market, request_queue, event_queue, bids, asks, market_base_vault, market_quote_vault, zeta_base_vault, zeta_quote_vault, oracle, oracle_backup_feed, oracle_backup_program, market_base_mint, market_quote_mint, mint_authority, perp_sync_queue, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(PlaceMultiOrdersInstructionAccountMetas {
+            authority: authority.clone(),
+            state: state.clone(),
+            pricing: pricing.clone(),
+            margin_account: margin_account.clone(),
+            dex_program: dex_program.clone(),
+            token_program: token_program.clone(),
+            serum_authority: serum_authority.clone(),
+            open_orders: open_orders.clone(),
+            rent: rent.clone(),
+            market: market.clone(),
+            request_queue: request_queue.clone(),
+            event_queue: event_queue.clone(),
+            bids: bids.clone(),
+            asks: asks.clone(),
+            market_base_vault: market_base_vault.clone(),
+            market_quote_vault: market_quote_vault.clone(),
+            zeta_base_vault: zeta_base_vault.clone(),
+            zeta_quote_vault: zeta_quote_vault.clone(),
+            oracle: oracle.clone(),
+            oracle_backup_feed: oracle_backup_feed.clone(),
+            oracle_backup_program: oracle_backup_program.clone(),
+            market_base_mint: market_base_mint.clone(),
+            market_quote_mint: market_quote_mint.clone(),
+            mint_authority: mint_authority.clone(),
+            perp_sync_queue: perp_sync_queue.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct PlaceMultiOrdersInstructionAccountIndices {
+    pub authority: usize,
+    pub state: usize,
+    pub pricing: usize,
+    pub margin_account: usize,
+    pub dex_program: usize,
+    pub token_program: usize,
+    pub serum_authority: usize,
+    pub open_orders: usize,
+    pub rent: usize,
+    pub market: usize,
+    pub request_queue: usize,
+    pub event_queue: usize,
+    pub bids: usize,
+    pub asks: usize,
+    pub market_base_vault: usize,
+    pub market_quote_vault: usize,
+    pub zeta_base_vault: usize,
+    pub zeta_quote_vault: usize,
+    pub oracle: usize,
+    pub oracle_backup_feed: usize,
+    pub oracle_backup_program: usize,
+    pub market_base_mint: usize,
+    pub market_quote_mint: usize,
+    pub mint_authority: usize,
+    pub perp_sync_queue: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for PlaceMultiOrders {
+    type ArrangedAccountIndices = PlaceMultiOrdersInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 25 {
+            return None;
+        }
+
+        Some(PlaceMultiOrdersInstructionAccountIndices {
+            authority: 0,
+            state: 1,
+            pricing: 2,
+            margin_account: 3,
+            dex_program: 4,
+            token_program: 5,
+            serum_authority: 6,
+            open_orders: 7,
+            rent: 8,
+            market: 9,
+            request_queue: 10,
+            event_queue: 11,
+            bids: 12,
+            asks: 13,
+            market_base_vault: 14,
+            market_quote_vault: 15,
+            zeta_base_vault: 16,
+            zeta_quote_vault: 17,
+            oracle: 18,
+            oracle_backup_feed: 19,
+            oracle_backup_program: 20,
+            market_base_mint: 21,
+            market_quote_mint: 22,
+            mint_authority: 23,
+            perp_sync_queue: 24,
+            remaining_accounts: 25..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for PlaceMultiOrders {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "authority",
+            "state",
+            "pricing",
+            "margin_account",
+            "dex_program",
+            "token_program",
+            "serum_authority",
+            "open_orders",
+            "rent",
+            "market",
+            "request_queue",
+            "event_queue",
+            "bids",
+            "asks",
+            "market_base_vault",
+            "market_quote_vault",
+            "zeta_base_vault",
+            "zeta_quote_vault",
+            "oracle",
+            "oracle_backup_feed",
+            "oracle_backup_program",
+            "market_base_mint",
+            "market_quote_mint",
+            "mint_authority",
+            "perp_sync_queue",
+        ]
+    }
+}
@@ -1,8 +1,10 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use alloc::string::String;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -34,6 +36,7 @@ pub struct PlacePerpOrderInstructionAccounts {
     pub market_mint: solana_pubkey::Pubkey,
     pub mint_authority: solana_pubkey::Pubkey,
     pub perp_sync_queue: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for PlacePerpOrder {
@@ -42,7 +45,7 @@ impl carbon_core::deserialize::ArrangeAccounts for PlacePerpOrder {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, zeta_group, margin_account, authority, dex_program, token_program, serum_authority, greeks, open_orders, rent, market_accounts, oracle, oracle_backup_feed, oracle_backup_program, market_mint, mint_authority, perp_sync_queue, _remaining @ ..] =
+        let [state, zeta_group, margin_account, authority, dex_program, token_program, serum_authority, greeks, open_orders, rent, market_accounts, oracle, oracle_backup_feed, oracle_backup_program, market_mint, mint_authority, perp_sync_queue, remaining @ ..] =
             accounts
         else {
             return None;
@@ -66,6 +69,141 @@ impl carbon_core::deserialize::ArrangeAccounts for PlacePerpOrder {
             market_mint: market_mint.pubkey,
             mint_authority: mint_authority.pubkey,
             perp_sync_queue: perp_sync_queue.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct PlacePerpOrderInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub zeta_group: solana_instruction::AccountMeta,
+    pub margin_account: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub dex_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub serum_authority: solana_instruction::AccountMeta,
+    pub greeks: solana_instruction::AccountMeta,
+    pub open_orders: solana_instruction::AccountMeta,
+    pub rent: solana_instruction::AccountMeta,
+    pub market_accounts: solana_instruction::AccountMeta,
+    pub oracle: solana_instruction::AccountMeta,
+    pub oracle_backup_feed: solana_instruction::AccountMeta,
+    pub oracle_backup_program: solana_instruction::AccountMeta,
+    pub market_mint: solana_instruction::AccountMeta,
+    pub mint_authority: solana_instruction::AccountMeta,
+    pub perp_sync_queue: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for PlacePerpOrder {
+    type ArrangedAccountMetas = PlacePerpOrderInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, zeta_group, margin_account, authority, dex_program, token_program, serum_authority, greeks, open_orders, rent, market_accounts, oracle, oracle_backup_feed, oracle_backup_program, market_mint, mint_authority, perp_sync_queue, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(PlacePerpOrderInstructionAccountMetas {
+            state: state.clone(),
+            zeta_group: zeta_group.clone(),
+            margin_account: margin_account.clone(),
+            authority: authority.clone(),
+            dex_program: dex_program.clone(),
+            token_program: token_program.clone(),
+            serum_authority: serum_authority.clone(),
+            greeks: greeks.clone(),
+            open_orders: open_orders.clone(),
+            rent: rent.clone(),
+            market_accounts: market_accounts.clone(),
+            oracle: oracle.clone(),
+            oracle_backup_feed: oracle_backup_feed.clone(),
+            oracle_backup_program: oracle_backup_program.clone(),
+            market_mint: market_mint.clone(),
+            mint_authority: mint_authority.clone(),
+            perp_sync_queue: perp_sync_queue.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct PlacePerpOrderInstructionAccountIndices {
+    pub state: usize,
+    pub zeta_group: usize,
+    pub margin_account: usize,
+    pub authority: usize,
+    pub dex_program: usize,
+    pub token_program: usize,
+    pub serum_authority: usize,
+    pub greeks: usize,
+    pub open_orders: usize,
+    pub rent: usize,
+    pub market_accounts: usize,
+    pub oracle: usize,
+    pub oracle_backup_feed: usize,
+    pub oracle_backup_program: usize,
+    pub market_mint: usize,
+    pub mint_authority: usize,
+    pub perp_sync_queue: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for PlacePerpOrder {
+    type ArrangedAccountIndices = PlacePerpOrderInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 17 {
+            return None;
+        }
+
+        Some(PlacePerpOrderInstructionAccountIndices {
+            state: 0,
+            zeta_group: 1,
+            margin_account: 2,
+            authority: 3,
+            dex_program: 4,
+            token_program: 5,
+            serum_authority: 6,
+            greeks: 7,
+            open_orders: 8,
+            rent: 9,
+            market_accounts: 10,
+            oracle: 11,
+            oracle_backup_feed: 12,
+            oracle_backup_program: 13,
+            market_mint: 14,
+            mint_authority: 15,
+            perp_sync_queue: 16,
+            remaining_accounts: 17..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for PlacePerpOrder {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "zeta_group",
+            "margin_account",
+            "authority",
+            "dex_program",
+            "token_program",
+            "serum_authority",
+            "greeks",
+            "open_orders",
+            "rent",
+            "market_accounts",
+            "oracle",
+            "oracle_backup_feed",
+            "oracle_backup_program",
+            "market_mint",
+            "mint_authority",
+            "perp_sync_queue",
+        ]
+    }
+}
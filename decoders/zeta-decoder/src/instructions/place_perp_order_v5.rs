@@ -1,8 +1,10 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use alloc::string::String;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -23,6 +25,7 @@ pub struct PlacePerpOrderV5 {
 pub struct PlacePerpOrderV5InstructionAccounts {
     pub authority: solana_pubkey::Pubkey,
     pub place_order_accounts: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for PlacePerpOrderV5 {
@@ -31,13 +34,68 @@ impl carbon_core::deserialize::ArrangeAccounts for PlacePerpOrderV5 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [authority, place_order_accounts, _remaining @ ..] = accounts else {
+        let [authority, place_order_accounts, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(PlacePerpOrderV5InstructionAccounts {
             authority: authority.pubkey,
             place_order_accounts: place_order_accounts.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct PlacePerpOrderV5InstructionAccountMetas {
+    pub authority: solana_instruction::AccountMeta,
+    pub place_order_accounts: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for PlacePerpOrderV5 {
+    type ArrangedAccountMetas = PlacePerpOrderV5InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [authority, place_order_accounts, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(PlacePerpOrderV5InstructionAccountMetas {
+            authority: authority.clone(),
+            place_order_accounts: place_order_accounts.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct PlacePerpOrderV5InstructionAccountIndices {
+    pub authority: usize,
+    pub place_order_accounts: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for PlacePerpOrderV5 {
+    type ArrangedAccountIndices = PlacePerpOrderV5InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(PlacePerpOrderV5InstructionAccountIndices {
+            authority: 0,
+            place_order_accounts: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for PlacePerpOrderV5 {
+    fn account_names() -> &'static [&'static str] {
+        &["authority", "place_order_accounts"]
+    }
+}
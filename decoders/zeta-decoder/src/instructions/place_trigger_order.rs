@@ -1,8 +1,10 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use alloc::string::String;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -31,6 +33,7 @@ pub struct PlaceTriggerOrderInstructionAccounts {
     pub system_program: solana_pubkey::Pubkey,
     pub dex_program: solana_pubkey::Pubkey,
     pub market: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for PlaceTriggerOrder {
@@ -39,7 +42,7 @@ impl carbon_core::deserialize::ArrangeAccounts for PlaceTriggerOrder {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, open_orders, authority, margin_account, pricing, trigger_order, system_program, dex_program, market, _remaining @ ..] =
+        let [state, open_orders, authority, margin_account, pricing, trigger_order, system_program, dex_program, market, remaining @ ..] =
             accounts
         else {
             return None;
@@ -55,6 +58,101 @@ impl carbon_core::deserialize::ArrangeAccounts for PlaceTriggerOrder {
             system_program: system_program.pubkey,
             dex_program: dex_program.pubkey,
             market: market.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct PlaceTriggerOrderInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub open_orders: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub margin_account: solana_instruction::AccountMeta,
+    pub pricing: solana_instruction::AccountMeta,
+    pub trigger_order: solana_instruction::AccountMeta,
+    pub system_program: solana_instruction::AccountMeta,
+    pub dex_program: solana_instruction::AccountMeta,
+    pub market: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for PlaceTriggerOrder {
+    type ArrangedAccountMetas = PlaceTriggerOrderInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, open_orders, authority, margin_account, pricing, trigger_order, system_program, dex_program, market, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(PlaceTriggerOrderInstructionAccountMetas {
+            state: state.clone(),
+            open_orders: open_orders.clone(),
+            authority: authority.clone(),
+            margin_account: margin_account.clone(),
+            pricing: pricing.clone(),
+            trigger_order: trigger_order.clone(),
+            system_program: system_program.clone(),
+            dex_program: dex_program.clone(),
+            market: market.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct PlaceTriggerOrderInstructionAccountIndices {
+    pub state: usize,
+    pub open_orders: usize,
+    pub authority: usize,
+    pub margin_account: usize,
+    pub pricing: usize,
+    pub trigger_order: usize,
+    pub system_program: usize,
+    pub dex_program: usize,
+    pub market: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for PlaceTriggerOrder {
+    type ArrangedAccountIndices = PlaceTriggerOrderInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 9 {
+            return None;
+        }
+
+        Some(PlaceTriggerOrderInstructionAccountIndices {
+            state: 0,
+            open_orders: 1,
+            authority: 2,
+            margin_account: 3,
+            pricing: 4,
+            trigger_order: 5,
+            system_program: 6,
+            dex_program: 7,
+            market: 8,
+            remaining_accounts: 9..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for PlaceTriggerOrder {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "open_orders",
+            "authority",
+            "margin_account",
+            "pricing",
+            "trigger_order",
+            "system_program",
+            "dex_program",
+            "market",
+        ]
+    }
+}
@@ -3,6 +3,7 @@ use super::super::types::*;
 use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -22,6 +23,7 @@ pub struct PositionMovementInstructionAccounts {
     pub oracle: solana_pubkey::Pubkey,
     pub oracle_backup_feed: solana_pubkey::Pubkey,
     pub oracle_backup_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for PositionMovement {
@@ -30,7 +32,7 @@ impl carbon_core::deserialize::ArrangeAccounts for PositionMovement {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, zeta_group, margin_account, spread_account, authority, greeks, oracle, oracle_backup_feed, oracle_backup_program, _remaining @ ..] =
+        let [state, zeta_group, margin_account, spread_account, authority, greeks, oracle, oracle_backup_feed, oracle_backup_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -46,6 +48,101 @@ impl carbon_core::deserialize::ArrangeAccounts for PositionMovement {
             oracle: oracle.pubkey,
             oracle_backup_feed: oracle_backup_feed.pubkey,
             oracle_backup_program: oracle_backup_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct PositionMovementInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub zeta_group: solana_instruction::AccountMeta,
+    pub margin_account: solana_instruction::AccountMeta,
+    pub spread_account: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub greeks: solana_instruction::AccountMeta,
+    pub oracle: solana_instruction::AccountMeta,
+    pub oracle_backup_feed: solana_instruction::AccountMeta,
+    pub oracle_backup_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for PositionMovement {
+    type ArrangedAccountMetas = PositionMovementInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, zeta_group, margin_account, spread_account, authority, greeks, oracle, oracle_backup_feed, oracle_backup_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(PositionMovementInstructionAccountMetas {
+            state: state.clone(),
+            zeta_group: zeta_group.clone(),
+            margin_account: margin_account.clone(),
+            spread_account: spread_account.clone(),
+            authority: authority.clone(),
+            greeks: greeks.clone(),
+            oracle: oracle.clone(),
+            oracle_backup_feed: oracle_backup_feed.clone(),
+            oracle_backup_program: oracle_backup_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct PositionMovementInstructionAccountIndices {
+    pub state: usize,
+    pub zeta_group: usize,
+    pub margin_account: usize,
+    pub spread_account: usize,
+    pub authority: usize,
+    pub greeks: usize,
+    pub oracle: usize,
+    pub oracle_backup_feed: usize,
+    pub oracle_backup_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for PositionMovement {
+    type ArrangedAccountIndices = PositionMovementInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 9 {
+            return None;
+        }
+
+        Some(PositionMovementInstructionAccountIndices {
+            state: 0,
+            zeta_group: 1,
+            margin_account: 2,
+            spread_account: 3,
+            authority: 4,
+            greeks: 5,
+            oracle: 6,
+            oracle_backup_feed: 7,
+            oracle_backup_program: 8,
+            remaining_accounts: 9..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for PositionMovement {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "zeta_group",
+            "margin_account",
+            "spread_account",
+            "authority",
+            "greeks",
+            "oracle",
+            "oracle_backup_feed",
+            "oracle_backup_program",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +16,7 @@ pub struct PruneExpiredTifOrdersInstructionAccounts {
     pub bids: solana_pubkey::Pubkey,
     pub asks: solana_pubkey::Pubkey,
     pub event_queue: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for PruneExpiredTifOrders {
@@ -22,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for PruneExpiredTifOrders {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [dex_program, state, serum_authority, market, bids, asks, event_queue, _remaining @ ..] =
+        let [dex_program, state, serum_authority, market, bids, asks, event_queue, remaining @ ..] =
             accounts
         else {
             return None;
@@ -36,6 +39,91 @@ impl carbon_core::deserialize::ArrangeAccounts for PruneExpiredTifOrders {
             bids: bids.pubkey,
             asks: asks.pubkey,
             event_queue: event_queue.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct PruneExpiredTifOrdersInstructionAccountMetas {
+    pub dex_program: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub serum_authority: solana_instruction::AccountMeta,
+    pub market: solana_instruction::AccountMeta,
+    pub bids: solana_instruction::AccountMeta,
+    pub asks: solana_instruction::AccountMeta,
+    pub event_queue: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for PruneExpiredTifOrders {
+    type ArrangedAccountMetas = PruneExpiredTifOrdersInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [dex_program, state, serum_authority, market, bids, asks, event_queue, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(PruneExpiredTifOrdersInstructionAccountMetas {
+            dex_program: dex_program.clone(),
+            state: state.clone(),
+            serum_authority: serum_authority.clone(),
+            market: market.clone(),
+            bids: bids.clone(),
+            asks: asks.clone(),
+            event_queue: event_queue.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct PruneExpiredTifOrdersInstructionAccountIndices {
+    pub dex_program: usize,
+    pub state: usize,
+    pub serum_authority: usize,
+    pub market: usize,
+    pub bids: usize,
+    pub asks: usize,
+    pub event_queue: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for PruneExpiredTifOrders {
+    type ArrangedAccountIndices = PruneExpiredTifOrdersInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 7 {
+            return None;
+        }
+
+        Some(PruneExpiredTifOrdersInstructionAccountIndices {
+            dex_program: 0,
+            state: 1,
+            serum_authority: 2,
+            market: 3,
+            bids: 4,
+            asks: 5,
+            event_queue: 6,
+            remaining_accounts: 7..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for PruneExpiredTifOrders {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "dex_program",
+            "state",
+            "serum_authority",
+            "market",
+            "bids",
+            "asks",
+            "event_queue",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -13,6 +15,7 @@ pub struct RebalanceInsuranceVaultInstructionAccounts {
     pub treasury_wallet: solana_pubkey::Pubkey,
     pub socialized_loss_account: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for RebalanceInsuranceVault {
@@ -21,7 +24,7 @@ impl carbon_core::deserialize::ArrangeAccounts for RebalanceInsuranceVault {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, zeta_vault, insurance_vault, treasury_wallet, socialized_loss_account, token_program, _remaining @ ..] =
+        let [state, zeta_vault, insurance_vault, treasury_wallet, socialized_loss_account, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -34,6 +37,86 @@ impl carbon_core::deserialize::ArrangeAccounts for RebalanceInsuranceVault {
             treasury_wallet: treasury_wallet.pubkey,
             socialized_loss_account: socialized_loss_account.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct RebalanceInsuranceVaultInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub zeta_vault: solana_instruction::AccountMeta,
+    pub insurance_vault: solana_instruction::AccountMeta,
+    pub treasury_wallet: solana_instruction::AccountMeta,
+    pub socialized_loss_account: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for RebalanceInsuranceVault {
+    type ArrangedAccountMetas = RebalanceInsuranceVaultInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, zeta_vault, insurance_vault, treasury_wallet, socialized_loss_account, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(RebalanceInsuranceVaultInstructionAccountMetas {
+            state: state.clone(),
+            zeta_vault: zeta_vault.clone(),
+            insurance_vault: insurance_vault.clone(),
+            treasury_wallet: treasury_wallet.clone(),
+            socialized_loss_account: socialized_loss_account.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct RebalanceInsuranceVaultInstructionAccountIndices {
+    pub state: usize,
+    pub zeta_vault: usize,
+    pub insurance_vault: usize,
+    pub treasury_wallet: usize,
+    pub socialized_loss_account: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for RebalanceInsuranceVault {
+    type ArrangedAccountIndices = RebalanceInsuranceVaultInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(RebalanceInsuranceVaultInstructionAccountIndices {
+            state: 0,
+            zeta_vault: 1,
+            insurance_vault: 2,
+            treasury_wallet: 3,
+            socialized_loss_account: 4,
+            token_program: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for RebalanceInsuranceVault {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "zeta_vault",
+            "insurance_vault",
+            "treasury_wallet",
+            "socialized_loss_account",
+            "token_program",
+        ]
+    }
+}
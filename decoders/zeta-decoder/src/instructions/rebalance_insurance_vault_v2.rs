@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +16,7 @@ pub struct RebalanceInsuranceVaultV2InstructionAccounts {
     pub treasury_split_token_account: solana_pubkey::Pubkey,
     pub socialized_loss_account: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for RebalanceInsuranceVaultV2 {
@@ -22,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for RebalanceInsuranceVaultV2 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, zeta_vault, insurance_vault, treasury_wallet, treasury_split_token_account, socialized_loss_account, token_program, _remaining @ ..] =
+        let [state, zeta_vault, insurance_vault, treasury_wallet, treasury_split_token_account, socialized_loss_account, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -36,6 +39,91 @@ impl carbon_core::deserialize::ArrangeAccounts for RebalanceInsuranceVaultV2 {
             treasury_split_token_account: treasury_split_token_account.pubkey,
             socialized_loss_account: socialized_loss_account.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct RebalanceInsuranceVaultV2InstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub zeta_vault: solana_instruction::AccountMeta,
+    pub insurance_vault: solana_instruction::AccountMeta,
+    pub treasury_wallet: solana_instruction::AccountMeta,
+    pub treasury_split_token_account: solana_instruction::AccountMeta,
+    pub socialized_loss_account: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for RebalanceInsuranceVaultV2 {
+    type ArrangedAccountMetas = RebalanceInsuranceVaultV2InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, zeta_vault, insurance_vault, treasury_wallet, treasury_split_token_account, socialized_loss_account, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(RebalanceInsuranceVaultV2InstructionAccountMetas {
+            state: state.clone(),
+            zeta_vault: zeta_vault.clone(),
+            insurance_vault: insurance_vault.clone(),
+            treasury_wallet: treasury_wallet.clone(),
+            treasury_split_token_account: treasury_split_token_account.clone(),
+            socialized_loss_account: socialized_loss_account.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct RebalanceInsuranceVaultV2InstructionAccountIndices {
+    pub state: usize,
+    pub zeta_vault: usize,
+    pub insurance_vault: usize,
+    pub treasury_wallet: usize,
+    pub treasury_split_token_account: usize,
+    pub socialized_loss_account: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for RebalanceInsuranceVaultV2 {
+    type ArrangedAccountIndices = RebalanceInsuranceVaultV2InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 7 {
+            return None;
+        }
+
+        Some(RebalanceInsuranceVaultV2InstructionAccountIndices {
+            state: 0,
+            zeta_vault: 1,
+            insurance_vault: 2,
+            treasury_wallet: 3,
+            treasury_split_token_account: 4,
+            socialized_loss_account: 5,
+            token_program: 6,
+            remaining_accounts: 7..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for RebalanceInsuranceVaultV2 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "zeta_vault",
+            "insurance_vault",
+            "treasury_wallet",
+            "treasury_split_token_account",
+            "socialized_loss_account",
+            "token_program",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -9,6 +11,7 @@ pub struct ResetNumFlexUnderlyings {}
 pub struct ResetNumFlexUnderlyingsInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ResetNumFlexUnderlyings {
@@ -17,13 +20,68 @@ impl carbon_core::deserialize::ArrangeAccounts for ResetNumFlexUnderlyings {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, admin, _remaining @ ..] = accounts else {
+        let [state, admin, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(ResetNumFlexUnderlyingsInstructionAccounts {
             state: state.pubkey,
             admin: admin.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ResetNumFlexUnderlyingsInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ResetNumFlexUnderlyings {
+    type ArrangedAccountMetas = ResetNumFlexUnderlyingsInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, admin, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(ResetNumFlexUnderlyingsInstructionAccountMetas {
+            state: state.clone(),
+            admin: admin.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ResetNumFlexUnderlyingsInstructionAccountIndices {
+    pub state: usize,
+    pub admin: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ResetNumFlexUnderlyings {
+    type ArrangedAccountIndices = ResetNumFlexUnderlyingsInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(ResetNumFlexUnderlyingsInstructionAccountIndices {
+            state: 0,
+            admin: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ResetNumFlexUnderlyings {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "admin"]
+    }
+}
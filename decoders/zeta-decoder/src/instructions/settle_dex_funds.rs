@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -18,6 +20,7 @@ pub struct SettleDexFundsInstructionAccounts {
     pub serum_authority: solana_pubkey::Pubkey,
     pub dex_program: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SettleDexFunds {
@@ -26,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for SettleDexFunds {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, market, zeta_base_vault, zeta_quote_vault, dex_base_vault, dex_quote_vault, vault_owner, mint_authority, serum_authority, dex_program, token_program, _remaining @ ..] =
+        let [state, market, zeta_base_vault, zeta_quote_vault, dex_base_vault, dex_quote_vault, vault_owner, mint_authority, serum_authority, dex_program, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -44,6 +47,111 @@ impl carbon_core::deserialize::ArrangeAccounts for SettleDexFunds {
             serum_authority: serum_authority.pubkey,
             dex_program: dex_program.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SettleDexFundsInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub market: solana_instruction::AccountMeta,
+    pub zeta_base_vault: solana_instruction::AccountMeta,
+    pub zeta_quote_vault: solana_instruction::AccountMeta,
+    pub dex_base_vault: solana_instruction::AccountMeta,
+    pub dex_quote_vault: solana_instruction::AccountMeta,
+    pub vault_owner: solana_instruction::AccountMeta,
+    pub mint_authority: solana_instruction::AccountMeta,
+    pub serum_authority: solana_instruction::AccountMeta,
+    pub dex_program: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SettleDexFunds {
+    type ArrangedAccountMetas = SettleDexFundsInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, market, zeta_base_vault, zeta_quote_vault, dex_base_vault, dex_quote_vault, vault_owner, mint_authority, serum_authority, dex_program, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(SettleDexFundsInstructionAccountMetas {
+            state: state.clone(),
+            market: market.clone(),
+            zeta_base_vault: zeta_base_vault.clone(),
+            zeta_quote_vault: zeta_quote_vault.clone(),
+            dex_base_vault: dex_base_vault.clone(),
+            dex_quote_vault: dex_quote_vault.clone(),
+            vault_owner: vault_owner.clone(),
+            mint_authority: mint_authority.clone(),
+            serum_authority: serum_authority.clone(),
+            dex_program: dex_program.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SettleDexFundsInstructionAccountIndices {
+    pub state: usize,
+    pub market: usize,
+    pub zeta_base_vault: usize,
+    pub zeta_quote_vault: usize,
+    pub dex_base_vault: usize,
+    pub dex_quote_vault: usize,
+    pub vault_owner: usize,
+    pub mint_authority: usize,
+    pub serum_authority: usize,
+    pub dex_program: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SettleDexFunds {
+    type ArrangedAccountIndices = SettleDexFundsInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 11 {
+            return None;
+        }
+
+        Some(SettleDexFundsInstructionAccountIndices {
+            state: 0,
+            market: 1,
+            zeta_base_vault: 2,
+            zeta_quote_vault: 3,
+            dex_base_vault: 4,
+            dex_quote_vault: 5,
+            vault_owner: 6,
+            mint_authority: 7,
+            serum_authority: 8,
+            dex_program: 9,
+            token_program: 10,
+            remaining_accounts: 11..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SettleDexFunds {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "market",
+            "zeta_base_vault",
+            "zeta_quote_vault",
+            "dex_base_vault",
+            "dex_quote_vault",
+            "vault_owner",
+            "mint_authority",
+            "serum_authority",
+            "dex_program",
+            "token_program",
+        ]
+    }
+}
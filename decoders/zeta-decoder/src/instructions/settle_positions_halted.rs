@@ -1,7 +1,9 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +16,7 @@ pub struct SettlePositionsHaltedInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub pricing: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for SettlePositionsHalted {
@@ -22,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for SettlePositionsHalted {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, pricing, admin, _remaining @ ..] = accounts else {
+        let [state, pricing, admin, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -30,6 +33,65 @@ impl carbon_core::deserialize::ArrangeAccounts for SettlePositionsHalted {
             state: state.pubkey,
             pricing: pricing.pubkey,
             admin: admin.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct SettlePositionsHaltedInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub pricing: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for SettlePositionsHalted {
+    type ArrangedAccountMetas = SettlePositionsHaltedInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, pricing, admin, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(SettlePositionsHaltedInstructionAccountMetas {
+            state: state.clone(),
+            pricing: pricing.clone(),
+            admin: admin.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct SettlePositionsHaltedInstructionAccountIndices {
+    pub state: usize,
+    pub pricing: usize,
+    pub admin: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for SettlePositionsHalted {
+    type ArrangedAccountIndices = SettlePositionsHaltedInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(SettlePositionsHaltedInstructionAccountIndices {
+            state: 0,
+            pricing: 1,
+            admin: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for SettlePositionsHalted {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "pricing", "admin"]
+    }
+}
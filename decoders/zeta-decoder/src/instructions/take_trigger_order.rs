@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -20,6 +22,7 @@ pub struct TakeTriggerOrderInstructionAccounts {
     pub taker: solana_pubkey::Pubkey,
     pub taker_margin_account: solana_pubkey::Pubkey,
     pub order_margin_account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for TakeTriggerOrder {
@@ -28,7 +31,7 @@ impl carbon_core::deserialize::ArrangeAccounts for TakeTriggerOrder {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [trigger_order, state, pricing, oracle, oracle_backup_feed, oracle_backup_program, bids, asks, taker, taker_margin_account, order_margin_account, _remaining @ ..] =
+        let [trigger_order, state, pricing, oracle, oracle_backup_feed, oracle_backup_program, bids, asks, taker, taker_margin_account, order_margin_account, remaining @ ..] =
             accounts
         else {
             return None;
@@ -46,6 +49,111 @@ impl carbon_core::deserialize::ArrangeAccounts for TakeTriggerOrder {
             taker: taker.pubkey,
             taker_margin_account: taker_margin_account.pubkey,
             order_margin_account: order_margin_account.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct TakeTriggerOrderInstructionAccountMetas {
+    pub trigger_order: solana_instruction::AccountMeta,
+    pub state: solana_instruction::AccountMeta,
+    pub pricing: solana_instruction::AccountMeta,
+    pub oracle: solana_instruction::AccountMeta,
+    pub oracle_backup_feed: solana_instruction::AccountMeta,
+    pub oracle_backup_program: solana_instruction::AccountMeta,
+    pub bids: solana_instruction::AccountMeta,
+    pub asks: solana_instruction::AccountMeta,
+    pub taker: solana_instruction::AccountMeta,
+    pub taker_margin_account: solana_instruction::AccountMeta,
+    pub order_margin_account: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for TakeTriggerOrder {
+    type ArrangedAccountMetas = TakeTriggerOrderInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [trigger_order, state, pricing, oracle, oracle_backup_feed, oracle_backup_program, bids, asks, taker, taker_margin_account, order_margin_account, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(TakeTriggerOrderInstructionAccountMetas {
+            trigger_order: trigger_order.clone(),
+            state: state.clone(),
+            pricing: pricing.clone(),
+            oracle: oracle.clone(),
+            oracle_backup_feed: oracle_backup_feed.clone(),
+            oracle_backup_program: oracle_backup_program.clone(),
+            bids: bids.clone(),
+            asks: asks.clone(),
+            taker: taker.clone(),
+            taker_margin_account: taker_margin_account.clone(),
+            order_margin_account: order_margin_account.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct TakeTriggerOrderInstructionAccountIndices {
+    pub trigger_order: usize,
+    pub state: usize,
+    pub pricing: usize,
+    pub oracle: usize,
+    pub oracle_backup_feed: usize,
+    pub oracle_backup_program: usize,
+    pub bids: usize,
+    pub asks: usize,
+    pub taker: usize,
+    pub taker_margin_account: usize,
+    pub order_margin_account: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for TakeTriggerOrder {
+    type ArrangedAccountIndices = TakeTriggerOrderInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 11 {
+            return None;
+        }
+
+        Some(TakeTriggerOrderInstructionAccountIndices {
+            trigger_order: 0,
+            state: 1,
+            pricing: 2,
+            oracle: 3,
+            oracle_backup_feed: 4,
+            oracle_backup_program: 5,
+            bids: 6,
+            asks: 7,
+            taker: 8,
+            taker_margin_account: 9,
+            order_margin_account: 10,
+            remaining_accounts: 11..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for TakeTriggerOrder {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "trigger_order",
+            "state",
+            "pricing",
+            "oracle",
+            "oracle_backup_feed",
+            "oracle_backup_program",
+            "bids",
+            "asks",
+            "taker",
+            "taker_margin_account",
+            "order_margin_account",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -10,6 +12,7 @@ pub struct ToggleZetaGroupPerpsOnlyInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub zeta_group: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for ToggleZetaGroupPerpsOnly {
@@ -18,7 +21,7 @@ impl carbon_core::deserialize::ArrangeAccounts for ToggleZetaGroupPerpsOnly {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, zeta_group, admin, _remaining @ ..] = accounts else {
+        let [state, zeta_group, admin, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -26,6 +29,65 @@ impl carbon_core::deserialize::ArrangeAccounts for ToggleZetaGroupPerpsOnly {
             state: state.pubkey,
             zeta_group: zeta_group.pubkey,
             admin: admin.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct ToggleZetaGroupPerpsOnlyInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub zeta_group: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for ToggleZetaGroupPerpsOnly {
+    type ArrangedAccountMetas = ToggleZetaGroupPerpsOnlyInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, zeta_group, admin, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(ToggleZetaGroupPerpsOnlyInstructionAccountMetas {
+            state: state.clone(),
+            zeta_group: zeta_group.clone(),
+            admin: admin.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct ToggleZetaGroupPerpsOnlyInstructionAccountIndices {
+    pub state: usize,
+    pub zeta_group: usize,
+    pub admin: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for ToggleZetaGroupPerpsOnly {
+    type ArrangedAccountIndices = ToggleZetaGroupPerpsOnlyInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(ToggleZetaGroupPerpsOnlyInstructionAccountIndices {
+            state: 0,
+            zeta_group: 1,
+            admin: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for ToggleZetaGroupPerpsOnly {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "zeta_group", "admin"]
+    }
+}
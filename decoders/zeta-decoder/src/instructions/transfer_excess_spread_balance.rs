@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +13,7 @@ pub struct TransferExcessSpreadBalanceInstructionAccounts {
     pub margin_account: solana_pubkey::Pubkey,
     pub spread_account: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for TransferExcessSpreadBalance {
@@ -19,7 +22,7 @@ impl carbon_core::deserialize::ArrangeAccounts for TransferExcessSpreadBalance {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [zeta_group, margin_account, spread_account, authority, _remaining @ ..] = accounts
+        let [zeta_group, margin_account, spread_account, authority, remaining @ ..] = accounts
         else {
             return None;
         };
@@ -29,6 +32,75 @@ impl carbon_core::deserialize::ArrangeAccounts for TransferExcessSpreadBalance {
             margin_account: margin_account.pubkey,
             spread_account: spread_account.pubkey,
             authority: authority.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct TransferExcessSpreadBalanceInstructionAccountMetas {
+    pub zeta_group: solana_instruction::AccountMeta,
+    pub margin_account: solana_instruction::AccountMeta,
+    pub spread_account: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for TransferExcessSpreadBalance {
+    type ArrangedAccountMetas = TransferExcessSpreadBalanceInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [zeta_group, margin_account, spread_account, authority, remaining @ ..] = accounts
+        else {
+            return None;
+        };
+
+        Some(TransferExcessSpreadBalanceInstructionAccountMetas {
+            zeta_group: zeta_group.clone(),
+            margin_account: margin_account.clone(),
+            spread_account: spread_account.clone(),
+            authority: authority.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct TransferExcessSpreadBalanceInstructionAccountIndices {
+    pub zeta_group: usize,
+    pub margin_account: usize,
+    pub spread_account: usize,
+    pub authority: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for TransferExcessSpreadBalance {
+    type ArrangedAccountIndices = TransferExcessSpreadBalanceInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(TransferExcessSpreadBalanceInstructionAccountIndices {
+            zeta_group: 0,
+            margin_account: 1,
+            spread_account: 2,
+            authority: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for TransferExcessSpreadBalance {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "zeta_group",
+            "margin_account",
+            "spread_account",
+            "authority",
+        ]
+    }
+}
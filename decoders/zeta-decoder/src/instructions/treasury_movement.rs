@@ -1,7 +1,9 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -18,6 +20,7 @@ pub struct TreasuryMovementInstructionAccounts {
     pub referrals_rewards_wallet: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for TreasuryMovement {
@@ -26,7 +29,7 @@ impl carbon_core::deserialize::ArrangeAccounts for TreasuryMovement {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, insurance_vault, treasury_wallet, referrals_rewards_wallet, token_program, admin, _remaining @ ..] =
+        let [state, insurance_vault, treasury_wallet, referrals_rewards_wallet, token_program, admin, remaining @ ..] =
             accounts
         else {
             return None;
@@ -39,6 +42,86 @@ impl carbon_core::deserialize::ArrangeAccounts for TreasuryMovement {
             referrals_rewards_wallet: referrals_rewards_wallet.pubkey,
             token_program: token_program.pubkey,
             admin: admin.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct TreasuryMovementInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub insurance_vault: solana_instruction::AccountMeta,
+    pub treasury_wallet: solana_instruction::AccountMeta,
+    pub referrals_rewards_wallet: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for TreasuryMovement {
+    type ArrangedAccountMetas = TreasuryMovementInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, insurance_vault, treasury_wallet, referrals_rewards_wallet, token_program, admin, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(TreasuryMovementInstructionAccountMetas {
+            state: state.clone(),
+            insurance_vault: insurance_vault.clone(),
+            treasury_wallet: treasury_wallet.clone(),
+            referrals_rewards_wallet: referrals_rewards_wallet.clone(),
+            token_program: token_program.clone(),
+            admin: admin.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct TreasuryMovementInstructionAccountIndices {
+    pub state: usize,
+    pub insurance_vault: usize,
+    pub treasury_wallet: usize,
+    pub referrals_rewards_wallet: usize,
+    pub token_program: usize,
+    pub admin: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for TreasuryMovement {
+    type ArrangedAccountIndices = TreasuryMovementInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(TreasuryMovementInstructionAccountIndices {
+            state: 0,
+            insurance_vault: 1,
+            treasury_wallet: 2,
+            referrals_rewards_wallet: 3,
+            token_program: 4,
+            admin: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for TreasuryMovement {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "insurance_vault",
+            "treasury_wallet",
+            "referrals_rewards_wallet",
+            "token_program",
+            "admin",
+        ]
+    }
+}
@@ -1,7 +1,9 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -15,6 +17,7 @@ pub struct UpdateInterestRateInstructionAccounts {
     pub greeks: solana_pubkey::Pubkey,
     pub zeta_group: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateInterestRate {
@@ -23,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateInterestRate {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, greeks, zeta_group, admin, _remaining @ ..] = accounts else {
+        let [state, greeks, zeta_group, admin, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -32,6 +35,69 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateInterestRate {
             greeks: greeks.pubkey,
             zeta_group: zeta_group.pubkey,
             admin: admin.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateInterestRateInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub greeks: solana_instruction::AccountMeta,
+    pub zeta_group: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateInterestRate {
+    type ArrangedAccountMetas = UpdateInterestRateInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, greeks, zeta_group, admin, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateInterestRateInstructionAccountMetas {
+            state: state.clone(),
+            greeks: greeks.clone(),
+            zeta_group: zeta_group.clone(),
+            admin: admin.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateInterestRateInstructionAccountIndices {
+    pub state: usize,
+    pub greeks: usize,
+    pub zeta_group: usize,
+    pub admin: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateInterestRate {
+    type ArrangedAccountIndices = UpdateInterestRateInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(UpdateInterestRateInstructionAccountIndices {
+            state: 0,
+            greeks: 1,
+            zeta_group: 2,
+            admin: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateInterestRate {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "greeks", "zeta_group", "admin"]
+    }
+}
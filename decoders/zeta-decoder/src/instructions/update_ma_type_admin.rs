@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -10,6 +12,7 @@ pub struct UpdateMaTypeAdminInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
     pub new_admin: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateMaTypeAdmin {
@@ -18,7 +21,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateMaTypeAdmin {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, admin, new_admin, _remaining @ ..] = accounts else {
+        let [state, admin, new_admin, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -26,6 +29,65 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateMaTypeAdmin {
             state: state.pubkey,
             admin: admin.pubkey,
             new_admin: new_admin.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateMaTypeAdminInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub new_admin: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateMaTypeAdmin {
+    type ArrangedAccountMetas = UpdateMaTypeAdminInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, admin, new_admin, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateMaTypeAdminInstructionAccountMetas {
+            state: state.clone(),
+            admin: admin.clone(),
+            new_admin: new_admin.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateMaTypeAdminInstructionAccountIndices {
+    pub state: usize,
+    pub admin: usize,
+    pub new_admin: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateMaTypeAdmin {
+    type ArrangedAccountIndices = UpdateMaTypeAdminInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(UpdateMaTypeAdminInstructionAccountIndices {
+            state: 0,
+            admin: 1,
+            new_admin: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateMaTypeAdmin {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "admin", "new_admin"]
+    }
+}
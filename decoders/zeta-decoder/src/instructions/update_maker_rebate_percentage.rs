@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +13,7 @@ pub struct UpdateMakerRebatePercentage {
 pub struct UpdateMakerRebatePercentageInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateMakerRebatePercentage {
@@ -19,13 +22,68 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateMakerRebatePercentage {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, admin, _remaining @ ..] = accounts else {
+        let [state, admin, remaining @ ..] = accounts else {
             return None;
         };
 
         Some(UpdateMakerRebatePercentageInstructionAccounts {
             state: state.pubkey,
             admin: admin.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateMakerRebatePercentageInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateMakerRebatePercentage {
+    type ArrangedAccountMetas = UpdateMakerRebatePercentageInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, admin, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateMakerRebatePercentageInstructionAccountMetas {
+            state: state.clone(),
+            admin: admin.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateMakerRebatePercentageInstructionAccountIndices {
+    pub state: usize,
+    pub admin: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateMakerRebatePercentage {
+    type ArrangedAccountIndices = UpdateMakerRebatePercentageInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 2 {
+            return None;
+        }
+
+        Some(UpdateMakerRebatePercentageInstructionAccountIndices {
+            state: 0,
+            admin: 1,
+            remaining_accounts: 2..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateMakerRebatePercentage {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "admin"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +13,7 @@ pub struct UpdateOracleInstructionAccounts {
     pub zeta_group: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
     pub oracle: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateOracle {
@@ -19,7 +22,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateOracle {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, zeta_group, admin, oracle, _remaining @ ..] = accounts else {
+        let [state, zeta_group, admin, oracle, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +31,69 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateOracle {
             zeta_group: zeta_group.pubkey,
             admin: admin.pubkey,
             oracle: oracle.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateOracleInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub zeta_group: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub oracle: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateOracle {
+    type ArrangedAccountMetas = UpdateOracleInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, zeta_group, admin, oracle, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateOracleInstructionAccountMetas {
+            state: state.clone(),
+            zeta_group: zeta_group.clone(),
+            admin: admin.clone(),
+            oracle: oracle.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateOracleInstructionAccountIndices {
+    pub state: usize,
+    pub zeta_group: usize,
+    pub admin: usize,
+    pub oracle: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateOracle {
+    type ArrangedAccountIndices = UpdateOracleInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(UpdateOracleInstructionAccountIndices {
+            state: 0,
+            zeta_group: 1,
+            admin: 2,
+            oracle: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateOracle {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "zeta_group", "admin", "oracle"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -11,6 +13,7 @@ pub struct UpdateOracleBackupFeedInstructionAccounts {
     pub zeta_group: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
     pub oracle: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateOracleBackupFeed {
@@ -19,7 +22,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateOracleBackupFeed {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, zeta_group, admin, oracle, _remaining @ ..] = accounts else {
+        let [state, zeta_group, admin, oracle, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +31,69 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateOracleBackupFeed {
             zeta_group: zeta_group.pubkey,
             admin: admin.pubkey,
             oracle: oracle.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateOracleBackupFeedInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub zeta_group: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub oracle: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateOracleBackupFeed {
+    type ArrangedAccountMetas = UpdateOracleBackupFeedInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, zeta_group, admin, oracle, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateOracleBackupFeedInstructionAccountMetas {
+            state: state.clone(),
+            zeta_group: zeta_group.clone(),
+            admin: admin.clone(),
+            oracle: oracle.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateOracleBackupFeedInstructionAccountIndices {
+    pub state: usize,
+    pub zeta_group: usize,
+    pub admin: usize,
+    pub oracle: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateOracleBackupFeed {
+    type ArrangedAccountIndices = UpdateOracleBackupFeedInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 4 {
+            return None;
+        }
+
+        Some(UpdateOracleBackupFeedInstructionAccountIndices {
+            state: 0,
+            zeta_group: 1,
+            admin: 2,
+            oracle: 3,
+            remaining_accounts: 4..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateOracleBackupFeed {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "zeta_group", "admin", "oracle"]
+    }
+}
@@ -1,7 +1,9 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -15,6 +17,7 @@ pub struct UpdatePerpParametersInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub pricing: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdatePerpParameters {
@@ -23,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdatePerpParameters {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, pricing, admin, _remaining @ ..] = accounts else {
+        let [state, pricing, admin, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -31,6 +34,65 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdatePerpParameters {
             state: state.pubkey,
             pricing: pricing.pubkey,
             admin: admin.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdatePerpParametersInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub pricing: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdatePerpParameters {
+    type ArrangedAccountMetas = UpdatePerpParametersInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, pricing, admin, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdatePerpParametersInstructionAccountMetas {
+            state: state.clone(),
+            pricing: pricing.clone(),
+            admin: admin.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdatePerpParametersInstructionAccountIndices {
+    pub state: usize,
+    pub pricing: usize,
+    pub admin: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdatePerpParameters {
+    type ArrangedAccountIndices = UpdatePerpParametersInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(UpdatePerpParametersInstructionAccountIndices {
+            state: 0,
+            pricing: 1,
+            admin: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdatePerpParameters {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "pricing", "admin"]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -10,6 +12,7 @@ pub struct UpdatePricingAdminInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
     pub new_admin: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdatePricingAdmin {
@@ -18,7 +21,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdatePricingAdmin {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, admin, new_admin, _remaining @ ..] = accounts else {
+        let [state, admin, new_admin, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -26,6 +29,65 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdatePricingAdmin {
             state: state.pubkey,
             admin: admin.pubkey,
             new_admin: new_admin.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdatePricingAdminInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub new_admin: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdatePricingAdmin {
+    type ArrangedAccountMetas = UpdatePricingAdminInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, admin, new_admin, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdatePricingAdminInstructionAccountMetas {
+            state: state.clone(),
+            admin: admin.clone(),
+            new_admin: new_admin.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdatePricingAdminInstructionAccountIndices {
+    pub state: usize,
+    pub admin: usize,
+    pub new_admin: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdatePricingAdmin {
+    type ArrangedAccountIndices = UpdatePricingAdminInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(UpdatePricingAdminInstructionAccountIndices {
+            state: 0,
+            admin: 1,
+            new_admin: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdatePricingAdmin {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "admin", "new_admin"]
+    }
+}
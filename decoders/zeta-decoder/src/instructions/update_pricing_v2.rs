@@ -1,7 +1,9 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -19,6 +21,7 @@ pub struct UpdatePricingV2InstructionAccounts {
     pub perp_market: solana_pubkey::Pubkey,
     pub perp_bids: solana_pubkey::Pubkey,
     pub perp_asks: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdatePricingV2 {
@@ -27,7 +30,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdatePricingV2 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, pricing, oracle, oracle_backup_feed, oracle_backup_program, perp_market, perp_bids, perp_asks, _remaining @ ..] =
+        let [state, pricing, oracle, oracle_backup_feed, oracle_backup_program, perp_market, perp_bids, perp_asks, remaining @ ..] =
             accounts
         else {
             return None;
@@ -42,6 +45,96 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdatePricingV2 {
             perp_market: perp_market.pubkey,
             perp_bids: perp_bids.pubkey,
             perp_asks: perp_asks.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdatePricingV2InstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub pricing: solana_instruction::AccountMeta,
+    pub oracle: solana_instruction::AccountMeta,
+    pub oracle_backup_feed: solana_instruction::AccountMeta,
+    pub oracle_backup_program: solana_instruction::AccountMeta,
+    pub perp_market: solana_instruction::AccountMeta,
+    pub perp_bids: solana_instruction::AccountMeta,
+    pub perp_asks: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdatePricingV2 {
+    type ArrangedAccountMetas = UpdatePricingV2InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, pricing, oracle, oracle_backup_feed, oracle_backup_program, perp_market, perp_bids, perp_asks, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(UpdatePricingV2InstructionAccountMetas {
+            state: state.clone(),
+            pricing: pricing.clone(),
+            oracle: oracle.clone(),
+            oracle_backup_feed: oracle_backup_feed.clone(),
+            oracle_backup_program: oracle_backup_program.clone(),
+            perp_market: perp_market.clone(),
+            perp_bids: perp_bids.clone(),
+            perp_asks: perp_asks.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdatePricingV2InstructionAccountIndices {
+    pub state: usize,
+    pub pricing: usize,
+    pub oracle: usize,
+    pub oracle_backup_feed: usize,
+    pub oracle_backup_program: usize,
+    pub perp_market: usize,
+    pub perp_bids: usize,
+    pub perp_asks: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdatePricingV2 {
+    type ArrangedAccountIndices = UpdatePricingV2InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(UpdatePricingV2InstructionAccountIndices {
+            state: 0,
+            pricing: 1,
+            oracle: 2,
+            oracle_backup_feed: 3,
+            oracle_backup_program: 4,
+            perp_market: 5,
+            perp_bids: 6,
+            perp_asks: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdatePricingV2 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "pricing",
+            "oracle",
+            "oracle_backup_feed",
+            "oracle_backup_program",
+            "perp_market",
+            "perp_bids",
+            "perp_asks",
+        ]
+    }
+}
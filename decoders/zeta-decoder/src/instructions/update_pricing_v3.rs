@@ -1,7 +1,9 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -20,6 +22,7 @@ pub struct UpdatePricingV3InstructionAccounts {
     pub perp_bids: solana_pubkey::Pubkey,
     pub perp_asks: solana_pubkey::Pubkey,
     pub pricing_admin: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdatePricingV3 {
@@ -28,7 +31,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdatePricingV3 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, pricing, oracle, perp_market, perp_bids, perp_asks, pricing_admin, _remaining @ ..] =
+        let [state, pricing, oracle, perp_market, perp_bids, perp_asks, pricing_admin, remaining @ ..] =
             accounts
         else {
             return None;
@@ -42,6 +45,91 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdatePricingV3 {
             perp_bids: perp_bids.pubkey,
             perp_asks: perp_asks.pubkey,
             pricing_admin: pricing_admin.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdatePricingV3InstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub pricing: solana_instruction::AccountMeta,
+    pub oracle: solana_instruction::AccountMeta,
+    pub perp_market: solana_instruction::AccountMeta,
+    pub perp_bids: solana_instruction::AccountMeta,
+    pub perp_asks: solana_instruction::AccountMeta,
+    pub pricing_admin: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdatePricingV3 {
+    type ArrangedAccountMetas = UpdatePricingV3InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, pricing, oracle, perp_market, perp_bids, perp_asks, pricing_admin, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(UpdatePricingV3InstructionAccountMetas {
+            state: state.clone(),
+            pricing: pricing.clone(),
+            oracle: oracle.clone(),
+            perp_market: perp_market.clone(),
+            perp_bids: perp_bids.clone(),
+            perp_asks: perp_asks.clone(),
+            pricing_admin: pricing_admin.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdatePricingV3InstructionAccountIndices {
+    pub state: usize,
+    pub pricing: usize,
+    pub oracle: usize,
+    pub perp_market: usize,
+    pub perp_bids: usize,
+    pub perp_asks: usize,
+    pub pricing_admin: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdatePricingV3 {
+    type ArrangedAccountIndices = UpdatePricingV3InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 7 {
+            return None;
+        }
+
+        Some(UpdatePricingV3InstructionAccountIndices {
+            state: 0,
+            pricing: 1,
+            oracle: 2,
+            perp_market: 3,
+            perp_bids: 4,
+            perp_asks: 5,
+            pricing_admin: 6,
+            remaining_accounts: 7..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdatePricingV3 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "pricing",
+            "oracle",
+            "perp_market",
+            "perp_bids",
+            "perp_asks",
+            "pricing_admin",
+        ]
+    }
+}
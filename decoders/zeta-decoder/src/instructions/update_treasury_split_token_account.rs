@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -12,6 +14,7 @@ pub struct UpdateTreasurySplitTokenAccountInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
     pub treasury_split_token_account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateTreasurySplitTokenAccount {
@@ -20,7 +23,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateTreasurySplitTokenAccou
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, admin, treasury_split_token_account, _remaining @ ..] = accounts else {
+        let [state, admin, treasury_split_token_account, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -28,6 +31,65 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateTreasurySplitTokenAccou
             state: state.pubkey,
             admin: admin.pubkey,
             treasury_split_token_account: treasury_split_token_account.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateTreasurySplitTokenAccountInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub treasury_split_token_account: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateTreasurySplitTokenAccount {
+    type ArrangedAccountMetas = UpdateTreasurySplitTokenAccountInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, admin, treasury_split_token_account, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateTreasurySplitTokenAccountInstructionAccountMetas {
+            state: state.clone(),
+            admin: admin.clone(),
+            treasury_split_token_account: treasury_split_token_account.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateTreasurySplitTokenAccountInstructionAccountIndices {
+    pub state: usize,
+    pub admin: usize,
+    pub treasury_split_token_account: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateTreasurySplitTokenAccount {
+    type ArrangedAccountIndices = UpdateTreasurySplitTokenAccountInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(UpdateTreasurySplitTokenAccountInstructionAccountIndices {
+            state: 0,
+            admin: 1,
+            treasury_split_token_account: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateTreasurySplitTokenAccount {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "admin", "treasury_split_token_account"]
+    }
+}
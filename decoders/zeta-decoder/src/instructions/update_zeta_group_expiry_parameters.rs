@@ -1,7 +1,9 @@
 use super::super::types::*;
+use alloc::vec::Vec;
 
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -14,6 +16,7 @@ pub struct UpdateZetaGroupExpiryParametersInstructionAccounts {
     pub state: solana_pubkey::Pubkey,
     pub zeta_group: solana_pubkey::Pubkey,
     pub admin: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for UpdateZetaGroupExpiryParameters {
@@ -22,7 +25,7 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateZetaGroupExpiryParamete
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, zeta_group, admin, _remaining @ ..] = accounts else {
+        let [state, zeta_group, admin, remaining @ ..] = accounts else {
             return None;
         };
 
@@ -30,6 +33,65 @@ impl carbon_core::deserialize::ArrangeAccounts for UpdateZetaGroupExpiryParamete
             state: state.pubkey,
             zeta_group: zeta_group.pubkey,
             admin: admin.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct UpdateZetaGroupExpiryParametersInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub zeta_group: solana_instruction::AccountMeta,
+    pub admin: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for UpdateZetaGroupExpiryParameters {
+    type ArrangedAccountMetas = UpdateZetaGroupExpiryParametersInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, zeta_group, admin, remaining @ ..] = accounts else {
+            return None;
+        };
+
+        Some(UpdateZetaGroupExpiryParametersInstructionAccountMetas {
+            state: state.clone(),
+            zeta_group: zeta_group.clone(),
+            admin: admin.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct UpdateZetaGroupExpiryParametersInstructionAccountIndices {
+    pub state: usize,
+    pub zeta_group: usize,
+    pub admin: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for UpdateZetaGroupExpiryParameters {
+    type ArrangedAccountIndices = UpdateZetaGroupExpiryParametersInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 3 {
+            return None;
+        }
+
+        Some(UpdateZetaGroupExpiryParametersInstructionAccountIndices {
+            state: 0,
+            zeta_group: 1,
+            admin: 2,
+            remaining_accounts: 3..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for UpdateZetaGroupExpiryParameters {
+    fn account_names() -> &'static [&'static str] {
+        &["state", "zeta_group", "admin"]
+    }
+}
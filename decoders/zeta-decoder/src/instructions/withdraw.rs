@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -21,6 +23,7 @@ pub struct WithdrawInstructionAccounts {
     pub oracle_backup_feed: solana_pubkey::Pubkey,
     pub oracle_backup_program: solana_pubkey::Pubkey,
     pub socialized_loss_account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for Withdraw {
@@ -29,7 +32,7 @@ impl carbon_core::deserialize::ArrangeAccounts for Withdraw {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, zeta_group, vault, margin_account, user_token_account, token_program, authority, greeks, oracle, oracle_backup_feed, oracle_backup_program, socialized_loss_account, _remaining @ ..] =
+        let [state, zeta_group, vault, margin_account, user_token_account, token_program, authority, greeks, oracle, oracle_backup_feed, oracle_backup_program, socialized_loss_account, remaining @ ..] =
             accounts
         else {
             return None;
@@ -48,6 +51,116 @@ impl carbon_core::deserialize::ArrangeAccounts for Withdraw {
             oracle_backup_feed: oracle_backup_feed.pubkey,
             oracle_backup_program: oracle_backup_program.pubkey,
             socialized_loss_account: socialized_loss_account.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct WithdrawInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub zeta_group: solana_instruction::AccountMeta,
+    pub vault: solana_instruction::AccountMeta,
+    pub margin_account: solana_instruction::AccountMeta,
+    pub user_token_account: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub greeks: solana_instruction::AccountMeta,
+    pub oracle: solana_instruction::AccountMeta,
+    pub oracle_backup_feed: solana_instruction::AccountMeta,
+    pub oracle_backup_program: solana_instruction::AccountMeta,
+    pub socialized_loss_account: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for Withdraw {
+    type ArrangedAccountMetas = WithdrawInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, zeta_group, vault, margin_account, user_token_account, token_program, authority, greeks, oracle, oracle_backup_feed, oracle_backup_program, socialized_loss_account, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(WithdrawInstructionAccountMetas {
+            state: state.clone(),
+            zeta_group: zeta_group.clone(),
+            vault: vault.clone(),
+            margin_account: margin_account.clone(),
+            user_token_account: user_token_account.clone(),
+            token_program: token_program.clone(),
+            authority: authority.clone(),
+            greeks: greeks.clone(),
+            oracle: oracle.clone(),
+            oracle_backup_feed: oracle_backup_feed.clone(),
+            oracle_backup_program: oracle_backup_program.clone(),
+            socialized_loss_account: socialized_loss_account.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct WithdrawInstructionAccountIndices {
+    pub state: usize,
+    pub zeta_group: usize,
+    pub vault: usize,
+    pub margin_account: usize,
+    pub user_token_account: usize,
+    pub token_program: usize,
+    pub authority: usize,
+    pub greeks: usize,
+    pub oracle: usize,
+    pub oracle_backup_feed: usize,
+    pub oracle_backup_program: usize,
+    pub socialized_loss_account: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for Withdraw {
+    type ArrangedAccountIndices = WithdrawInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 12 {
+            return None;
+        }
+
+        Some(WithdrawInstructionAccountIndices {
+            state: 0,
+            zeta_group: 1,
+            vault: 2,
+            margin_account: 3,
+            user_token_account: 4,
+            token_program: 5,
+            authority: 6,
+            greeks: 7,
+            oracle: 8,
+            oracle_backup_feed: 9,
+            oracle_backup_program: 10,
+            socialized_loss_account: 11,
+            remaining_accounts: 12..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for Withdraw {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "zeta_group",
+            "vault",
+            "margin_account",
+            "user_token_account",
+            "token_program",
+            "authority",
+            "greeks",
+            "oracle",
+            "oracle_backup_feed",
+            "oracle_backup_program",
+            "socialized_loss_account",
+        ]
+    }
+}
@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -15,6 +17,7 @@ pub struct WithdrawInsuranceVaultInstructionAccounts {
     pub user_token_account: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for WithdrawInsuranceVault {
@@ -23,7 +26,7 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawInsuranceVault {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, insurance_vault, insurance_deposit_account, user_token_account, authority, token_program, _remaining @ ..] =
+        let [state, insurance_vault, insurance_deposit_account, user_token_account, authority, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -36,6 +39,86 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawInsuranceVault {
             user_token_account: user_token_account.pubkey,
             authority: authority.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct WithdrawInsuranceVaultInstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub insurance_vault: solana_instruction::AccountMeta,
+    pub insurance_deposit_account: solana_instruction::AccountMeta,
+    pub user_token_account: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for WithdrawInsuranceVault {
+    type ArrangedAccountMetas = WithdrawInsuranceVaultInstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, insurance_vault, insurance_deposit_account, user_token_account, authority, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(WithdrawInsuranceVaultInstructionAccountMetas {
+            state: state.clone(),
+            insurance_vault: insurance_vault.clone(),
+            insurance_deposit_account: insurance_deposit_account.clone(),
+            user_token_account: user_token_account.clone(),
+            authority: authority.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct WithdrawInsuranceVaultInstructionAccountIndices {
+    pub state: usize,
+    pub insurance_vault: usize,
+    pub insurance_deposit_account: usize,
+    pub user_token_account: usize,
+    pub authority: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for WithdrawInsuranceVault {
+    type ArrangedAccountIndices = WithdrawInsuranceVaultInstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 6 {
+            return None;
+        }
+
+        Some(WithdrawInsuranceVaultInstructionAccountIndices {
+            state: 0,
+            insurance_vault: 1,
+            insurance_deposit_account: 2,
+            user_token_account: 3,
+            authority: 4,
+            token_program: 5,
+            remaining_accounts: 6..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for WithdrawInsuranceVault {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "insurance_vault",
+            "insurance_deposit_account",
+            "user_token_account",
+            "authority",
+            "token_program",
+        ]
+    }
+}
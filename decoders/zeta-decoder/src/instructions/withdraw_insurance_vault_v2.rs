@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -16,6 +18,7 @@ pub struct WithdrawInsuranceVaultV2InstructionAccounts {
     pub user_token_account: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
     pub token_program: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for WithdrawInsuranceVaultV2 {
@@ -24,7 +27,7 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawInsuranceVaultV2 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, pricing, insurance_vault, insurance_deposit_account, user_token_account, authority, token_program, _remaining @ ..] =
+        let [state, pricing, insurance_vault, insurance_deposit_account, user_token_account, authority, token_program, remaining @ ..] =
             accounts
         else {
             return None;
@@ -38,6 +41,91 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawInsuranceVaultV2 {
             user_token_account: user_token_account.pubkey,
             authority: authority.pubkey,
             token_program: token_program.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct WithdrawInsuranceVaultV2InstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub pricing: solana_instruction::AccountMeta,
+    pub insurance_vault: solana_instruction::AccountMeta,
+    pub insurance_deposit_account: solana_instruction::AccountMeta,
+    pub user_token_account: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for WithdrawInsuranceVaultV2 {
+    type ArrangedAccountMetas = WithdrawInsuranceVaultV2InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, pricing, insurance_vault, insurance_deposit_account, user_token_account, authority, token_program, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(WithdrawInsuranceVaultV2InstructionAccountMetas {
+            state: state.clone(),
+            pricing: pricing.clone(),
+            insurance_vault: insurance_vault.clone(),
+            insurance_deposit_account: insurance_deposit_account.clone(),
+            user_token_account: user_token_account.clone(),
+            authority: authority.clone(),
+            token_program: token_program.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct WithdrawInsuranceVaultV2InstructionAccountIndices {
+    pub state: usize,
+    pub pricing: usize,
+    pub insurance_vault: usize,
+    pub insurance_deposit_account: usize,
+    pub user_token_account: usize,
+    pub authority: usize,
+    pub token_program: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for WithdrawInsuranceVaultV2 {
+    type ArrangedAccountIndices = WithdrawInsuranceVaultV2InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 7 {
+            return None;
+        }
+
+        Some(WithdrawInsuranceVaultV2InstructionAccountIndices {
+            state: 0,
+            pricing: 1,
+            insurance_vault: 2,
+            insurance_deposit_account: 3,
+            user_token_account: 4,
+            authority: 5,
+            token_program: 6,
+            remaining_accounts: 7..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for WithdrawInsuranceVaultV2 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "pricing",
+            "insurance_vault",
+            "insurance_deposit_account",
+            "user_token_account",
+            "authority",
+            "token_program",
+        ]
+    }
+}
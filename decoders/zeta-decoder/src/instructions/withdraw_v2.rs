@@ -1,5 +1,7 @@
+use alloc::vec::Vec;
 use carbon_core::{borsh, CarbonDeserialize};
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
@@ -17,6 +19,7 @@ pub struct WithdrawV2InstructionAccounts {
     pub token_program: solana_pubkey::Pubkey,
     pub authority: solana_pubkey::Pubkey,
     pub socialized_loss_account: solana_pubkey::Pubkey,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl carbon_core::deserialize::ArrangeAccounts for WithdrawV2 {
@@ -25,7 +28,7 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawV2 {
     fn arrange_accounts(
         accounts: &[solana_instruction::AccountMeta],
     ) -> Option<Self::ArrangedAccounts> {
-        let [state, pricing, vault, margin_account, user_token_account, token_program, authority, socialized_loss_account, _remaining @ ..] =
+        let [state, pricing, vault, margin_account, user_token_account, token_program, authority, socialized_loss_account, remaining @ ..] =
             accounts
         else {
             return None;
@@ -40,6 +43,96 @@ impl carbon_core::deserialize::ArrangeAccounts for WithdrawV2 {
             token_program: token_program.pubkey,
             authority: authority.pubkey,
             socialized_loss_account: socialized_loss_account.pubkey,
+            remaining_accounts: remaining.to_vec(),
         })
     }
 }
+
+pub struct WithdrawV2InstructionAccountMetas {
+    pub state: solana_instruction::AccountMeta,
+    pub pricing: solana_instruction::AccountMeta,
+    pub vault: solana_instruction::AccountMeta,
+    pub margin_account: solana_instruction::AccountMeta,
+    pub user_token_account: solana_instruction::AccountMeta,
+    pub token_program: solana_instruction::AccountMeta,
+    pub authority: solana_instruction::AccountMeta,
+    pub socialized_loss_account: solana_instruction::AccountMeta,
+    pub remaining_accounts: Vec<solana_instruction::AccountMeta>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountMetas for WithdrawV2 {
+    type ArrangedAccountMetas = WithdrawV2InstructionAccountMetas;
+
+    fn arrange_account_metas(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountMetas> {
+        let [state, pricing, vault, margin_account, user_token_account, token_program, authority, socialized_loss_account, remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(WithdrawV2InstructionAccountMetas {
+            state: state.clone(),
+            pricing: pricing.clone(),
+            vault: vault.clone(),
+            margin_account: margin_account.clone(),
+            user_token_account: user_token_account.clone(),
+            token_program: token_program.clone(),
+            authority: authority.clone(),
+            socialized_loss_account: socialized_loss_account.clone(),
+            remaining_accounts: remaining.to_vec(),
+        })
+    }
+}
+
+pub struct WithdrawV2InstructionAccountIndices {
+    pub state: usize,
+    pub pricing: usize,
+    pub vault: usize,
+    pub margin_account: usize,
+    pub user_token_account: usize,
+    pub token_program: usize,
+    pub authority: usize,
+    pub socialized_loss_account: usize,
+    pub remaining_accounts: core::ops::Range<usize>,
+}
+
+impl carbon_core::deserialize::ArrangeAccountIndices for WithdrawV2 {
+    type ArrangedAccountIndices = WithdrawV2InstructionAccountIndices;
+
+    fn arrange_account_indices(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccountIndices> {
+        if accounts.len() < 8 {
+            return None;
+        }
+
+        Some(WithdrawV2InstructionAccountIndices {
+            state: 0,
+            pricing: 1,
+            vault: 2,
+            margin_account: 3,
+            user_token_account: 4,
+            token_program: 5,
+            authority: 6,
+            socialized_loss_account: 7,
+            remaining_accounts: 8..accounts.len(),
+        })
+    }
+}
+
+impl carbon_core::deserialize::NamedAccounts for WithdrawV2 {
+    fn account_names() -> &'static [&'static str] {
+        &[
+            "state",
+            "pricing",
+            "vault",
+            "margin_account",
+            "user_token_account",
+            "token_program",
+            "authority",
+            "socialized_loss_account",
+        ]
+    }
+}
@@ -1,6 +1,7 @@
 use carbon_core::{borsh, CarbonDeserialize};
 use serde_big_array::BigArray;
 
+#[carbon_core::carbon_serde]
 #[derive(
     CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
 )]
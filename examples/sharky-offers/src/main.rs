@@ -84,6 +84,9 @@ impl Datasource for GpaBackfillDatasource {
                 pubkey,
                 account,
                 slot,
+                commitment: None,
+                is_snapshot: false,
+                source: None,
             })) {
                 log::error!("Failed to send account update: {:?}", e);
             }
@@ -226,4 +226,44 @@ impl Metrics for LogMetrics {
 
         Ok(())
     }
+
+    async fn update_gauge_with_labels(
+        &self,
+        name: &str,
+        labels: &[(&str, &str)],
+        value: f64,
+    ) -> CarbonResult<()> {
+        self.update_gauge(&labelled_name(name, labels), value).await
+    }
+
+    async fn increment_counter_with_labels(
+        &self,
+        name: &str,
+        labels: &[(&str, &str)],
+        value: u64,
+    ) -> CarbonResult<()> {
+        self.increment_counter(&labelled_name(name, labels), value)
+            .await
+    }
+
+    async fn record_histogram_with_labels(
+        &self,
+        name: &str,
+        labels: &[(&str, &str)],
+        value: f64,
+    ) -> CarbonResult<()> {
+        self.record_histogram(&labelled_name(name, labels), value)
+            .await
+    }
+}
+
+/// Returns the name a labelled metric is logged under, such as
+/// `instructions_decoded{decoder=drift}`.
+fn labelled_name(name: &str, labels: &[(&str, &str)]) -> String {
+    let labels: Vec<_> = labels
+        .iter()
+        .map(|(name, value)| format!("{name}={value}"))
+        .collect();
+
+    format!("{name}{{{}}}", labels.join(","))
 }
@@ -0,0 +1,257 @@
+//! HTTP endpoints for Kubernetes liveness and readiness probes.
+//!
+//! [`HealthServer`] is a `Metrics` implementation: added to a pipeline next to
+//! `PrometheusMetrics`, it follows the metrics the pipeline and its
+//! datasources report, and serves them on its own port:
+//!
+//! - `/healthz` answers `200 OK` as long as the process runs.
+//! - `/readyz` answers `200 OK` while the datasources are connected and the
+//!   pipeline keeps up, and `503 Service Unavailable` otherwise.
+//!
+//! Readiness is derived from the following metrics:
+//!
+//! - `updates_lag_slots`: how many slots the last processed update is behind
+//!   the newest received one. Above [`HealthServer::with_max_lag_slots`], the
+//!   pipeline is not ready.
+//! - `updates_processed`: when set with [`HealthServer::with_max_staleness`],
+//!   the pipeline is not ready once no update was processed for that long.
+//! - `datasource_connected`: a gauge datasources set to 1 while connected and 0
+//!   while reconnecting. Datasources that do not report it are assumed to be
+//!   connected.
+//!
+//! ```ignore
+//! let pipeline = Pipeline::builder()
+//!     .datasource(yellowstone_datasource)
+//!     .metrics(Arc::new(PrometheusMetrics::new()))
+//!     .metrics(Arc::new(HealthServer::new().with_max_lag_slots(50)))
+//!     .build()?;
+//! ```
+
+use {
+    crate::http::{read_request_path, write_response},
+    async_trait::async_trait,
+    carbon_core::{
+        error::{CarbonResult, Error},
+        metrics::Metrics,
+    },
+    std::{
+        net::{IpAddr, Ipv4Addr, SocketAddr},
+        sync::{Arc, Mutex, PoisonError},
+        time::{Duration, Instant},
+    },
+    tokio::{
+        net::{TcpListener, TcpStream},
+        task::JoinHandle,
+    },
+};
+
+/// The address the health endpoints listen on unless configured otherwise.
+pub const DEFAULT_HEALTH_BIND_ADDRESS: SocketAddr =
+    SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 9101);
+
+/// The slot lag above which the pipeline is not ready unless configured
+/// otherwise, about one minute of slots.
+pub const DEFAULT_MAX_LAG_SLOTS: u64 = 150;
+
+#[derive(Default)]
+struct HealthState {
+    connected: Option<bool>,
+    lag_slots: u64,
+    last_processed: Option<Instant>,
+}
+
+/// Serves `/healthz` and `/readyz` from the metrics reported by a pipeline.
+/// See the [module documentation](self) for the readiness conditions.
+///
+/// The server is started when the pipeline initializes its metrics and
+/// stopped when the pipeline shuts them down.
+pub struct HealthServer {
+    pub bind_address: SocketAddr,
+    pub max_lag_slots: u64,
+    pub max_staleness: Option<Duration>,
+    state: Arc<Mutex<HealthState>>,
+    server: tokio::sync::Mutex<Option<JoinHandle<()>>>,
+}
+
+impl Default for HealthServer {
+    fn default() -> Self {
+        Self {
+            bind_address: DEFAULT_HEALTH_BIND_ADDRESS,
+            max_lag_slots: DEFAULT_MAX_LAG_SLOTS,
+            max_staleness: None,
+            state: Arc::default(),
+            server: tokio::sync::Mutex::new(None),
+        }
+    }
+}
+
+impl HealthServer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the address the health endpoints listen on. Kubernetes probes
+    /// connect to the pod IP, so in a cluster bind to `0.0.0.0`.
+    pub fn with_bind_address(mut self, bind_address: impl Into<SocketAddr>) -> Self {
+        self.bind_address = bind_address.into();
+        self
+    }
+
+    /// Sets how many slots the last processed update may be behind the newest
+    /// received one while the pipeline is ready.
+    pub fn with_max_lag_slots(mut self, max_lag_slots: u64) -> Self {
+        self.max_lag_slots = max_lag_slots;
+        self
+    }
+
+    /// Reports the pipeline as not ready once no update was processed for
+    /// `max_staleness`, including before the first one. Only suited to
+    /// datasources that deliver updates continuously.
+    pub fn with_max_staleness(mut self, max_staleness: Duration) -> Self {
+        self.max_staleness = Some(max_staleness);
+        self
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, HealthState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// What `/readyz` checks, copied out of the server for its task.
+#[derive(Clone, Copy)]
+struct Readiness {
+    max_lag_slots: u64,
+    max_staleness: Option<Duration>,
+}
+
+impl Readiness {
+    /// Returns why the pipeline is not ready, if it is not.
+    fn check(&self, state: &HealthState) -> Result<(), String> {
+        if state.connected == Some(false) {
+            return Err("datasource disconnected".to_string());
+        }
+        if state.lag_slots > self.max_lag_slots {
+            return Err(format!(
+                "lagging {} slots behind, more than {}",
+                state.lag_slots, self.max_lag_slots
+            ));
+        }
+        if let Some(max_staleness) = self.max_staleness {
+            match state.last_processed {
+                Some(last_processed) if last_processed.elapsed() <= max_staleness => {}
+                Some(last_processed) => {
+                    return Err(format!(
+                        "no update processed for {:?}",
+                        last_processed.elapsed()
+                    ))
+                }
+                None => return Err("no update processed yet".to_string()),
+            }
+        }
+        Ok(())
+    }
+}
+
+async fn serve(listener: TcpListener, state: Arc<Mutex<HealthState>>, readiness: Readiness) {
+    loop {
+        let stream = match listener.accept().await {
+            Ok((stream, _)) => stream,
+            Err(e) => {
+                log::error!("Health server failed to accept a connection: {:?}", e);
+                continue;
+            }
+        };
+
+        let state = state.clone();
+        tokio::spawn(async move {
+            if let Err(e) = respond(stream, &state, readiness).await {
+                log::debug!("Health server failed to answer a probe: {:?}", e);
+            }
+        });
+    }
+}
+
+async fn respond(
+    mut stream: TcpStream,
+    state: &Mutex<HealthState>,
+    readiness: Readiness,
+) -> std::io::Result<()> {
+    let path = read_request_path(&mut stream).await?;
+    let (status, body) = match path.as_str() {
+        "/healthz" => ("200 OK", "ok".to_string()),
+        "/readyz" => {
+            let state = state.lock().unwrap_or_else(PoisonError::into_inner);
+            match readiness.check(&state) {
+                Ok(()) => ("200 OK", "ready".to_string()),
+                Err(reason) => ("503 Service Unavailable", reason),
+            }
+        }
+        _ => ("404 Not Found", "not found".to_string()),
+    };
+
+    write_response(stream, status, "text/plain", &body).await
+}
+
+#[async_trait]
+impl Metrics for HealthServer {
+    async fn initialize(&self) -> CarbonResult<()> {
+        let mut server = self.server.lock().await;
+
+        if server.is_some() {
+            return Ok(());
+        }
+
+        let listener = TcpListener::bind(self.bind_address).await.map_err(|e| {
+            Error::Custom(format!(
+                "Failed to bind health server to {}: {}",
+                self.bind_address, e
+            ))
+        })?;
+        let readiness = Readiness {
+            max_lag_slots: self.max_lag_slots,
+            max_staleness: self.max_staleness,
+        };
+        *server = Some(tokio::spawn(serve(listener, self.state.clone(), readiness)));
+
+        log::info!("Health server listening on {}", self.bind_address);
+
+        Ok(())
+    }
+
+    async fn flush(&self) -> CarbonResult<()> {
+        Ok(())
+    }
+
+    async fn shutdown(&self) -> CarbonResult<()> {
+        if let Some(server) = self.server.lock().await.take() {
+            server.abort();
+            // The listener is released once the aborted task has been dropped.
+            let _ = server.await;
+            log::info!("Health server on {} shut down", self.bind_address);
+        }
+
+        Ok(())
+    }
+
+    async fn update_gauge(&self, name: &str, value: f64) -> CarbonResult<()> {
+        match name {
+            "updates_lag_slots" => self.state().lag_slots = value as u64,
+            "datasource_connected" => self.state().connected = Some(value > 0.0),
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    async fn increment_counter(&self, name: &str, _value: u64) -> CarbonResult<()> {
+        if name == "updates_processed" {
+            self.state().last_processed = Some(Instant::now());
+        }
+
+        Ok(())
+    }
+
+    async fn record_histogram(&self, _name: &str, _value: f64) -> CarbonResult<()> {
+        Ok(())
+    }
+}
@@ -1,19 +1,108 @@
+pub mod health;
+mod http;
+
+pub use health::HealthServer;
 use {
     async_trait::async_trait,
     carbon_core::{
         error::{CarbonResult, Error},
         metrics::Metrics,
     },
-    metrics::{counter, gauge, histogram},
-    metrics_exporter_prometheus::PrometheusBuilder,
-    std::{collections::HashMap, net::SocketAddrV4, sync::Once},
-    tokio::sync::RwLock,
+    http::{read_request_path, write_response},
+    metrics::{counter, gauge, histogram, Label},
+    metrics_exporter_prometheus::{PrometheusBuilder, PrometheusHandle},
+    std::{
+        collections::HashMap,
+        net::{IpAddr, Ipv4Addr, SocketAddr},
+        sync::OnceLock,
+        time::Duration,
+    },
+    tokio::{
+        net::{TcpListener, TcpStream},
+        sync::{Mutex, RwLock},
+        task::JoinHandle,
+    },
 };
 
+/// The address the `/metrics` endpoint listens on unless configured
+/// otherwise.
+pub const DEFAULT_BIND_ADDRESS: SocketAddr = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 9100);
+
+/// How often the exporter drains histogram samples into their summaries,
+/// which otherwise accumulate between scrapes.
+const UPKEEP_INTERVAL: Duration = Duration::from_secs(5);
+
+/// The Prometheus recorder, or why it could not be installed, shared by every
+/// `PrometheusMetrics` of the process.
+static RECORDER: OnceLock<Result<PrometheusHandle, String>> = OnceLock::new();
+
+/// Installs the Prometheus recorder as the global `metrics` recorder the first
+/// time it is called, and returns a handle to it.
+fn recorder() -> CarbonResult<PrometheusHandle> {
+    RECORDER
+        .get_or_init(|| {
+            PrometheusBuilder::new()
+                .install_recorder()
+                .map_err(|e| e.to_string())
+        })
+        .clone()
+        .map_err(|e| Error::Custom(format!("Failed to install Prometheus recorder: {}", e)))
+}
+
+async fn serve(listener: TcpListener, handle: PrometheusHandle) {
+    let mut upkeep = tokio::time::interval(UPKEEP_INTERVAL);
+    loop {
+        tokio::select! {
+            _ = upkeep.tick() => handle.run_upkeep(),
+            accepted = listener.accept() => match accepted {
+                Ok((stream, _)) => {
+                    let handle = handle.clone();
+                    tokio::spawn(async move {
+                        if let Err(e) = respond(stream, &handle).await {
+                            log::debug!("Prometheus exporter failed to answer a scrape: {:?}", e);
+                        }
+                    });
+                }
+                Err(e) => {
+                    log::error!("Prometheus exporter failed to accept a connection: {:?}", e);
+                }
+            },
+        }
+    }
+}
+
+/// Answers any path with the rendered metrics, as the exporter of
+/// `metrics-exporter-prometheus` does.
+async fn respond(mut stream: TcpStream, handle: &PrometheusHandle) -> std::io::Result<()> {
+    read_request_path(&mut stream).await?;
+    write_response(
+        stream,
+        "200 OK",
+        "text/plain; version=0.0.4",
+        &handle.render(),
+    )
+    .await
+}
+
+/// A `Metrics` implementation that exposes all pipeline metrics in the
+/// Prometheus exposition format.
+///
+/// The HTTP exporter serving `/metrics` is started when the pipeline
+/// initializes its metrics and stopped when the pipeline shuts them down.
+///
+/// The underlying `metrics` recorder is process-global: the first instance to
+/// initialize installs it, and it stays installed after shutdown. Instances
+/// initialized later, for example by a restarted pipeline, reuse it and keep
+/// exposing the same metrics, each on its own `bind_address`.
+///
+/// Metrics recorded with labels are exposed as Prometheus labels, for example
+/// `instructions_decoded{decoder="drift"}`.
 pub struct PrometheusMetrics {
     pub counters: RwLock<HashMap<String, metrics::Counter>>,
     pub gauges: RwLock<HashMap<String, metrics::Gauge>>,
     pub histograms: RwLock<HashMap<String, metrics::Histogram>>,
+    pub bind_address: SocketAddr,
+    exporter: Mutex<Option<JoinHandle<()>>>,
 }
 
 impl Default for PrometheusMetrics {
@@ -22,6 +111,8 @@ impl Default for PrometheusMetrics {
             counters: RwLock::new(HashMap::new()),
             gauges: RwLock::new(HashMap::new()),
             histograms: RwLock::new(HashMap::new()),
+            bind_address: DEFAULT_BIND_ADDRESS,
+            exporter: Mutex::new(None),
         }
     }
 }
@@ -29,31 +120,35 @@ impl PrometheusMetrics {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Sets the address the `/metrics` HTTP endpoint listens on.
+    pub fn with_bind_address(mut self, bind_address: impl Into<SocketAddr>) -> Self {
+        self.bind_address = bind_address.into();
+        self
+    }
 }
 
 #[async_trait]
 impl Metrics for PrometheusMetrics {
     async fn initialize(&self) -> CarbonResult<()> {
-        static INIT: Once = Once::new();
+        let mut exporter = self.exporter.lock().await;
 
-        let mut result = Ok(());
-        INIT.call_once(|| {
-            let builder = PrometheusBuilder::new()
-                .with_http_listener("127.0.0.1:9100".parse::<SocketAddrV4>().unwrap());
+        if exporter.is_some() {
+            return Ok(());
+        }
 
-            match builder.install() {
-                Ok(_handle) => {
-                    log::info!("Prometheus exporter installed and listening on 127.0.0.1:9100");
-                }
-                Err(e) => {
-                    result = Err(Error::Custom(format!(
-                        "Failed to install Prometheus exporter: {}",
-                        e
-                    )));
-                }
-            }
-        });
-        result
+        let handle = recorder()?;
+        let listener = TcpListener::bind(self.bind_address).await.map_err(|e| {
+            Error::Custom(format!(
+                "Failed to bind Prometheus exporter to {}: {}",
+                self.bind_address, e
+            ))
+        })?;
+        *exporter = Some(tokio::spawn(serve(listener, handle)));
+
+        log::info!("Prometheus exporter listening on {}", self.bind_address);
+
+        Ok(())
     }
 
     async fn flush(&self) -> CarbonResult<()> {
@@ -61,6 +156,13 @@ impl Metrics for PrometheusMetrics {
     }
 
     async fn shutdown(&self) -> CarbonResult<()> {
+        if let Some(exporter) = self.exporter.lock().await.take() {
+            exporter.abort();
+            // The listener is released once the aborted task has been dropped.
+            let _ = exporter.await;
+            log::info!("Prometheus exporter on {} shut down", self.bind_address);
+        }
+
         Ok(())
     }
 
@@ -105,4 +207,46 @@ impl Metrics for PrometheusMetrics {
 
         Ok(())
     }
+
+    async fn update_gauge_with_labels(
+        &self,
+        name: &str,
+        labels: &[(&str, &str)],
+        value: f64,
+    ) -> CarbonResult<()> {
+        gauge!(name.to_string(), prometheus_labels(labels)).set(value);
+
+        Ok(())
+    }
+
+    async fn increment_counter_with_labels(
+        &self,
+        name: &str,
+        labels: &[(&str, &str)],
+        value: u64,
+    ) -> CarbonResult<()> {
+        counter!(name.to_string(), prometheus_labels(labels)).increment(value);
+
+        Ok(())
+    }
+
+    async fn record_histogram_with_labels(
+        &self,
+        name: &str,
+        labels: &[(&str, &str)],
+        value: f64,
+    ) -> CarbonResult<()> {
+        histogram!(name.to_string(), prometheus_labels(labels)).record(value);
+
+        Ok(())
+    }
+}
+
+/// Converts name and value pairs into the labels of a `metrics` key. The
+/// recorder keeps one series per distinct key.
+fn prometheus_labels(labels: &[(&str, &str)]) -> Vec<Label> {
+    labels
+        .iter()
+        .map(|(name, value)| Label::new(name.to_string(), value.to_string()))
+        .collect()
 }
@@ -0,0 +1,88 @@
+use {
+    carbon_core::metrics::Metrics,
+    carbon_prometheus_metrics::PrometheusMetrics,
+    std::net::{SocketAddr, TcpListener},
+    tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpStream,
+    },
+};
+
+fn unused_local_address() -> SocketAddr {
+    TcpListener::bind("127.0.0.1:0")
+        .and_then(|listener| listener.local_addr())
+        .expect("free local port")
+}
+
+async fn scrape(address: SocketAddr) -> std::io::Result<String> {
+    let mut stream = TcpStream::connect(address).await?;
+    stream
+        .write_all(b"GET /metrics HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+        .await?;
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await?;
+    Ok(response)
+}
+
+#[tokio::test]
+async fn test_metrics_endpoint_serves_exposition_format() {
+    // Arrange
+    let address = unused_local_address();
+    let metrics = PrometheusMetrics::new().with_bind_address(address);
+    metrics.initialize().await.expect("initialize exporter");
+
+    // Act
+    metrics
+        .increment_counter_with_labels("instructions_decoded", &[("decoder", "drift")], 3)
+        .await
+        .unwrap();
+    metrics.update_gauge("updates_queued", 7.0).await.unwrap();
+    metrics
+        .record_histogram("instruction_process_time_milliseconds", 1.5)
+        .await
+        .unwrap();
+    let response = scrape(address).await.expect("scrape /metrics");
+
+    // Assert
+    assert!(response.starts_with("HTTP/1.1 200 OK"));
+    assert!(response.contains("# TYPE instructions_decoded counter\n"));
+    assert!(response.contains("\ninstructions_decoded{decoder=\"drift\"} 3\n"));
+    assert!(response.contains("# TYPE updates_queued gauge\n"));
+    assert!(response.contains("\nupdates_queued 7\n"));
+    assert!(response.contains("\ninstruction_process_time_milliseconds_count 1\n"));
+
+    metrics.shutdown().await.expect("shutdown exporter");
+    assert!(scrape(address).await.is_err());
+}
+
+#[tokio::test]
+async fn test_exporter_restarts_after_shutdown() {
+    // Arrange
+    let first = PrometheusMetrics::new().with_bind_address(unused_local_address());
+    first.initialize().await.expect("initialize first exporter");
+    first
+        .increment_counter("updates_processed", 2)
+        .await
+        .unwrap();
+    first.shutdown().await.expect("shutdown first exporter");
+
+    // Act
+    let address = unused_local_address();
+    let second = PrometheusMetrics::new().with_bind_address(address);
+    second
+        .initialize()
+        .await
+        .expect("initialize second exporter");
+    second
+        .increment_counter("updates_processed", 1)
+        .await
+        .unwrap();
+    let response = scrape(address).await.expect("scrape /metrics");
+
+    // Assert
+    assert!(response.starts_with("HTTP/1.1 200 OK"));
+    assert!(response.contains("\nupdates_processed 3\n"));
+
+    second.shutdown().await.expect("shutdown second exporter");
+}
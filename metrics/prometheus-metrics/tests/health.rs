@@ -0,0 +1,91 @@
+use {
+    carbon_core::metrics::Metrics,
+    carbon_prometheus_metrics::HealthServer,
+    std::net::{SocketAddr, TcpListener},
+    tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpStream,
+    },
+};
+
+fn unused_local_address() -> SocketAddr {
+    TcpListener::bind("127.0.0.1:0")
+        .and_then(|listener| listener.local_addr())
+        .expect("free local port")
+}
+
+async fn probe(address: SocketAddr, path: &str) -> std::io::Result<String> {
+    let mut stream = TcpStream::connect(address).await?;
+    stream
+        .write_all(
+            format!("GET {path} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+                .as_bytes(),
+        )
+        .await?;
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await?;
+    Ok(response)
+}
+
+#[tokio::test]
+async fn test_readyz_is_unavailable_when_lag_exceeds_threshold() {
+    // Arrange
+    let address = unused_local_address();
+    let health = HealthServer::new()
+        .with_bind_address(address)
+        .with_max_lag_slots(100);
+    health.initialize().await.expect("initialize health server");
+    health
+        .update_gauge("datasource_connected", 1.0)
+        .await
+        .unwrap();
+    health
+        .update_gauge("updates_lag_slots", 20.0)
+        .await
+        .unwrap();
+    let ready = probe(address, "/readyz").await.expect("probe /readyz");
+
+    // Act
+    health
+        .update_gauge("updates_lag_slots", 500.0)
+        .await
+        .unwrap();
+    let lagging = probe(address, "/readyz").await.expect("probe /readyz");
+    let alive = probe(address, "/healthz").await.expect("probe /healthz");
+
+    // Assert
+    assert!(ready.starts_with("HTTP/1.1 200 OK"));
+    assert!(lagging.starts_with("HTTP/1.1 503 Service Unavailable"));
+    assert!(lagging.ends_with("lagging 500 slots behind, more than 100"));
+    assert!(alive.starts_with("HTTP/1.1 200 OK"));
+
+    health.shutdown().await.expect("shutdown health server");
+    assert!(probe(address, "/healthz").await.is_err());
+}
+
+#[tokio::test]
+async fn test_readyz_is_unavailable_while_datasource_disconnected() {
+    // Arrange
+    let address = unused_local_address();
+    let health = HealthServer::new().with_bind_address(address);
+    health.initialize().await.expect("initialize health server");
+
+    // Act
+    health
+        .update_gauge("datasource_connected", 0.0)
+        .await
+        .unwrap();
+    let disconnected = probe(address, "/readyz").await.expect("probe /readyz");
+    health
+        .update_gauge("datasource_connected", 1.0)
+        .await
+        .unwrap();
+    let reconnected = probe(address, "/readyz").await.expect("probe /readyz");
+
+    // Assert
+    assert!(disconnected.starts_with("HTTP/1.1 503 Service Unavailable"));
+    assert!(reconnected.starts_with("HTTP/1.1 200 OK"));
+
+    health.shutdown().await.expect("shutdown health server");
+}